@@ -9,7 +9,26 @@
 
 // Re-export names from modules into the v1 namespace
 pub use crate::constants::BUF_SIZE;
-pub use crate::dialects::{look_up, query, translate_zh_hans, BufWriter, Writer};
+pub use crate::dialects::{
+    look_up, look_up_segments, look_up_segments_with_options, look_up_with_options, query,
+    translate_zh_hans, translate_zh_hant, BufWriter, Dialect, LookupOptions, Writer,
+};
+pub use crate::fuzzy::{FuzzyRule, FuzzyRules};
+pub use crate::lattice::{DefaultCost, SegmentCost};
+pub use crate::learn::{ChoiceHistory, ChoiceStore, SliceStore};
+#[cfg(feature = "bigram")]
+pub use crate::predict::predict_next;
+pub use crate::predict::{predict_completions, Prediction, PredictionList, PREDICTION_LIST_SIZE};
+pub use crate::preedit::{ImeOutput, OutputSegment};
+pub use crate::reverse::{to_pinyin, PinyinStyle};
+pub use crate::segment::{
+    Candidates, RenderMode, Segment, SegmentKind, SegmentList, CANDIDATE_PAGE_SIZE,
+};
+pub use crate::session::{ImeSession, Key, KeyResult};
+pub use crate::shuangpin::ShuangpinLayout;
+pub use crate::syllable::{is_syllable, split_syllables, Syllable, SyllableKind, SyllableList};
+pub use crate::user_dict::{UserDict, UserEntry, USER_CIYU_MAX, USER_PINYIN_MAX};
+pub use crate::yue::translate_yue;
 
 /// These tests aim to cover all names exported in the v1 api
 #[cfg(test)]
@@ -18,6 +37,81 @@ mod tests {
 
     #[test]
     fn test_api_translate_zh_hans() {
-        assert_eq!(&translate_zh_hans(&"1"), &"1");
+        let mut sink = BufWriter::new();
+        assert_eq!(translate_zh_hans("1", &mut sink), "1");
+        sink.rewind();
+        assert_eq!(translate_zh_hant("toufa", &mut sink), "頭髮");
+    }
+
+    #[test]
+    fn test_api_look_up_with_options() {
+        let mut store = [UserEntry::EMPTY; 4];
+        let mut dict = UserDict::new(&mut store);
+        assert!(dict.add("zhangwei", "张伟"));
+        let mut rules = FuzzyRules::NONE;
+        rules.set(FuzzyRule::ZZh, true);
+        let opts = LookupOptions {
+            dict: Some(&dict),
+            context: "我",
+            fuzzy: rules,
+            ..LookupOptions::with_cost(DefaultCost)
+        };
+        let mut sink = BufWriter::new();
+        look_up_with_options("wo zhangwei zongguo", &opts, &mut sink);
+        assert_eq!(sink.to_s(), "我张伟中国");
+        let mut segs = SegmentList::new();
+        look_up_segments_with_options("zhangwei", &opts, &mut segs);
+        assert_eq!(segs.get(0).and_then(|s| s.text()), Some("张伟"));
+    }
+
+    #[test]
+    fn test_api_translate_yue() {
+        let mut sink = BufWriter::new();
+        assert_eq!(translate_yue("nei5hou2", &mut sink), "你好");
+    }
+
+    #[test]
+    fn test_api_predict_completions() {
+        let mut out = PredictionList::new();
+        predict_completions("guoz", Dialect::ZhHans, &mut out);
+        assert_eq!(out.get(0).map(|p| p.ciyu), Some("果汁"));
+    }
+
+    #[test]
+    #[cfg(feature = "bigram")]
+    fn test_api_predict_next() {
+        let mut out = PredictionList::new();
+        predict_next("我想", &mut out);
+        assert_eq!(out.get(0).map(|p| p.ciyu), Some("喝"));
+    }
+
+    #[test]
+    fn test_api_ime_output() {
+        let mut session = ImeSession::new();
+        let mut sink = BufWriter::new();
+        for c in "xianghe".chars() {
+            session.key(Key::Char(c), &mut sink);
+        }
+        {
+            let mut out = ImeOutput::new();
+            session.output(&mut out);
+            assert_eq!(out.preedit(), "xiang'he");
+            let seg: Option<&OutputSegment> = out.preedit_segments().next();
+            assert_eq!(seg.map(|s| s.text_in(out.preedit())), Some("xiang"));
+        }
+        session.key(Key::Commit, &mut sink);
+        let mut out = ImeOutput::new();
+        session.output(&mut out);
+        let seg = out.committed_segments().next();
+        assert_eq!(seg.map(|s| (s.start, s.end)), Some((0, 5)));
+    }
+
+    #[test]
+    fn test_api_to_pinyin() {
+        let mut sink = BufWriter::new();
+        assert_eq!(
+            to_pinyin("果汁", PinyinStyle::ToneMarks, &mut sink),
+            "guǒzhī"
+        );
     }
 }
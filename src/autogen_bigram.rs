@@ -0,0 +1,779 @@
+// This file is automatically generated. DO NOT MAKE EDITS HERE!
+// To make changes, see ../vocab/autogen-bigram.rb
+//
+// Copyright (c) 2021 Sam Blenny
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+#![forbid(unsafe_code)]
+#![allow(dead_code)]
+
+// The longest 词语 in the vocab has WORD_SIZE_MAX characters
+pub const WORD_SIZE_MAX: usize = 10;
+
+// u32 constants are murmur3 hash of the 词语 that have a next word, with
+// the same seed as autogen_hsk::MURMUR3_SEED
+pub const MURMUR3_SEED: u32 = 0;
+pub static PREV: &[u32] = &[
+    0x0242eacb, // 机场
+    0x03c7d913, // 知道
+    0x06d73fae, // 什么
+    0x0c2ac37e, // 我
+    0x1074ffda, // 好
+    0x110c23bd, // 他
+    0x11c72bea, // 猫
+    0x1219f759, // 早上
+    0x127a583a, // 洗手间
+    0x13d93c15, // 虽然
+    0x167d3db3, // 去
+    0x16bbbf5f, // 已经
+    0x187cd9e4, // 下午
+    0x1a28b2d5, // 公共汽车
+    0x1bbbce68, // 哥哥
+    0x1d3b625a, // 多少
+    0x1de77be3, // 打算
+    0x1e768f36, // 天气
+    0x200a96e7, // 在
+    0x20491437, // 几
+    0x2578f835, // 休息
+    0x309a7f7e, // 下雨
+    0x322c1266, // 老师
+    0x35c99fb2, // 新年
+    0x3662049e, // 春节
+    0x37010b67, // 身体
+    0x37b6bbcf, // 椅子
+    0x39d05428, // 早
+    0x3a0d769c, // 喝
+    0x4209cfa3, // 生病
+    0x4392b381, // 谢谢
+    0x456bf99b, // 听
+    0x45fe7fa3, // 买
+    0x4b88312d, // 很
+    0x4be3fcd0, // 可以
+    0x4caac80d, // 没有
+    0x4cae9260, // 做
+    0x4e2be313, // 来
+    0x4f2699cd, // 电影
+    0x505036e4, // 桌子
+    0x544df645, // 就
+    0x54aac1d4, // 一点儿
+    0x54ca735c, // 起来
+    0x5a1b2974, // 住
+    0x5b838fe8, // 正在
+    0x5c1a81e9, // 学习
+    0x5dacfb8f, // 开始
+    0x5ea013e6, // 觉得
+    0x61438c23, // 不
+    0x679b5e7d, // 多
+    0x67b6b880, // 饭店
+    0x6b2e3dfd, // 说
+    0x6c3850b7, // 商店
+    0x6cc72640, // 妈妈
+    0x6e35951d, // 医生
+    0x730a0b63, // 书
+    0x73359c84, // 弟弟
+    0x738a3601, // 一起
+    0x73f9ac41, // 太
+    0x795e36e1, // 问题
+    0x7a7aaca3, // 也
+    0x7da3de06, // 便宜
+    0x7e976ba6, // 本
+    0x82d379f0, // 飞机
+    0x838a1cb1, // 爸爸
+    0x846b1ace, // 有点儿
+    0x860f5589, // 三
+    0x864f57cf, // 叫
+    0x887e2678, // 还
+    0x88c1518d, // 要
+    0x8990fa66, // 现在
+    0x899958cc, // 那
+    0x8a8febc1, // 想
+    0x8df6719d, // 的
+    0x8ede2e97, // 吃饭
+    0x8f24783e, // 没
+    0x8f75fb94, // 和
+    0x92405116, // 钱包
+    0x92a553dc, // 贵
+    0x92bb61bc, // 明天
+    0x9507f68b, // 我们
+    0x95ab8942, // 离
+    0x9665009c, // 祝
+    0x96f3892a, // 认识
+    0x96ff7625, // 高兴
+    0x9a52b302, // 喜欢
+    0x9bcf6667, // 朋友
+    0x9bda7f55, // 请
+    0x9cef186f, // 都
+    0xa058367f, // 衣服
+    0xa1c2e3c2, // 中国
+    0xa28896c3, // 应该
+    0xa2bfed0e, // 件
+    0xa7c1e47f, // 如果
+    0xa97eebd1, // 公司
+    0xa9eb7a2d, // 会
+    0xaa681543, // 锻炼
+    0xac434aca, // 今天
+    0xadc5bd9e, // 手机
+    0xaf19da61, // 进
+    0xafe35978, // 得
+    0xafffe181, // 地铁
+    0xb4d0ae63, // 家
+    0xbb155f1f, // 医院
+    0xbb21b24d, // 回
+    0xbde6fdfd, // 等
+    0xbff03ab6, // 公园
+    0xc28122be, // 她
+    0xc7dc77d9, // 学生
+    0xc851b65f, // 所以
+    0xd3bdc423, // 是
+    0xd3e9db2d, // 一
+    0xd47d7a03, // 有
+    0xd6f92277, // 时间
+    0xd7a6834e, // 北京
+    0xd88b0734, // 能
+    0xd98751f5, // 感冒
+    0xdba3661e, // 大家
+    0xdd3f5080, // 看
+    0xdd9eb425, // 准备
+    0xdeba1ae3, // 帮
+    0xdf3a241c, // 了
+    0xe030514d, // 个
+    0xe1be3bfa, // 马上
+    0xe2f0fe9a, // 一定
+    0xe3e187e6, // 每天
+    0xe3f09947, // 爱
+    0xe42a616b, // 银行
+    0xe55fe39a, // 吃
+    0xe939d546, // 姐姐
+    0xe9464108, // 生日
+    0xeaa22c21, // 美国
+    0xedd89f19, // 昨天
+    0xefe907ea, // 坐
+    0xf1a2e7ef, // 出租车
+    0xf35f5419, // 这儿
+    0xf393e959, // 西瓜
+    0xf4501bfb, // 菜
+    0xf775e522, // 过
+    0xf88d52f8, // 咖啡
+    0xf920193a, // 两
+    0xf927190c, // 但是
+    0xf9c97fb3, // 汉语
+    0xfa614eaa, // 因为
+    0xfb1d13dc, // 谁
+    0xfd0c9f35, // 你
+    0xfdfa875b, // 八
+    0xff749f02, // 学校
+    0xffe89344, // 这
+];
+
+// 词语 in the same order as PREV
+pub static PREV_KEYS: &[&str] = &[
+    "机场",
+    "知道",
+    "什么",
+    "我",
+    "好",
+    "他",
+    "猫",
+    "早上",
+    "洗手间",
+    "虽然",
+    "去",
+    "已经",
+    "下午",
+    "公共汽车",
+    "哥哥",
+    "多少",
+    "打算",
+    "天气",
+    "在",
+    "几",
+    "休息",
+    "下雨",
+    "老师",
+    "新年",
+    "春节",
+    "身体",
+    "椅子",
+    "早",
+    "喝",
+    "生病",
+    "谢谢",
+    "听",
+    "买",
+    "很",
+    "可以",
+    "没有",
+    "做",
+    "来",
+    "电影",
+    "桌子",
+    "就",
+    "一点儿",
+    "起来",
+    "住",
+    "正在",
+    "学习",
+    "开始",
+    "觉得",
+    "不",
+    "多",
+    "饭店",
+    "说",
+    "商店",
+    "妈妈",
+    "医生",
+    "书",
+    "弟弟",
+    "一起",
+    "太",
+    "问题",
+    "也",
+    "便宜",
+    "本",
+    "飞机",
+    "爸爸",
+    "有点儿",
+    "三",
+    "叫",
+    "还",
+    "要",
+    "现在",
+    "那",
+    "想",
+    "的",
+    "吃饭",
+    "没",
+    "和",
+    "钱包",
+    "贵",
+    "明天",
+    "我们",
+    "离",
+    "祝",
+    "认识",
+    "高兴",
+    "喜欢",
+    "朋友",
+    "请",
+    "都",
+    "衣服",
+    "中国",
+    "应该",
+    "件",
+    "如果",
+    "公司",
+    "会",
+    "锻炼",
+    "今天",
+    "手机",
+    "进",
+    "得",
+    "地铁",
+    "家",
+    "医院",
+    "回",
+    "等",
+    "公园",
+    "她",
+    "学生",
+    "所以",
+    "是",
+    "一",
+    "有",
+    "时间",
+    "北京",
+    "能",
+    "感冒",
+    "大家",
+    "看",
+    "准备",
+    "帮",
+    "了",
+    "个",
+    "马上",
+    "一定",
+    "每天",
+    "爱",
+    "银行",
+    "吃",
+    "姐姐",
+    "生日",
+    "美国",
+    "昨天",
+    "坐",
+    "出租车",
+    "这儿",
+    "西瓜",
+    "菜",
+    "过",
+    "咖啡",
+    "两",
+    "但是",
+    "汉语",
+    "因为",
+    "谁",
+    "你",
+    "八",
+    "学校",
+    "这",
+];
+
+// Words that follow each word of PREV_KEYS in the corpus (joined by
+// "\t"), most common first
+pub static NEXT: &[&str] = &[
+    "离",  // 机场
+    "了",  // 知道
+    "颜色	名字",  // 什么
+    "想	的	喜欢	要	很	不	和	去	在",  // 我
+    "吗	学生	朋友",  // 好
+    "是	想	喜欢	说	坐	正在	每天	的	有",  // 他
+    "在",  // 猫
+    "跑步	喝",  // 早上
+    "在",  // 洗手间
+    "很",  // 虽然
+    "北京	过	中国	商店	公园	吃饭	看	医院	爬山",  // 去
+    "吃饭	知道	回",  // 已经
+    "三",  // 下午
+    "去",  // 公共汽车
+    "在",  // 哥哥
+    "钱",  // 多少
+    "去	明天",  // 打算
+    "很",  // 天气
+    "哪儿	学校	桌子	教室	做	学习	看	打电话	饭店",  // 在
+    "点",  // 几
+    "一下",  // 休息
+    "了	吗",  // 下雨
+    "和",  // 老师
+    "快乐",  // 新年
+    "快乐",  // 春节
+    "很	怎么样",  // 身体
+    "下面",  // 椅子
+    "点",  // 早
+    "茶	水	咖啡	果汁	牛奶	啤酒	了	什么	一",  // 喝
+    "了",  // 生病
+    "你",  // 谢谢
+    "音乐",  // 听
+    "东西	一	衣服	票	了",  // 买
+    "好	高兴	好吃	忙	累	冷	热	漂亮	难",  // 很
+    "进",  // 可以
+    "时间	钱	手机",  // 没有
+    "什么	的",  // 做
+    "的	吗",  // 来
+    "吧",  // 电影
+    "上",  // 桌子
+    "来	去",  // 就
+    "汉语	吧",  // 一点儿
+    "吧",  // 起来
+    "在",  // 住
+    "睡觉	做",  // 正在
+    "汉语",  // 学习
+    "学习",  // 开始
+    "很	有点儿	这	怎么样",  // 觉得
+    "太	喜欢	知道	认识	想	会	下雨",  // 不
+    "喝	休息	锻炼	啤酒",  // 多
+    "吃饭",  // 饭店
+    "汉语	一点儿	得	我",  // 说
+    "买",  // 商店
+    "去	都	做",  // 妈妈
+    "说",  // 医生
+    "在",  // 书
+    "在",  // 弟弟
+    "去	学习",  // 一起
+    "贵	难	好",  // 太
+    "很",  // 问题
+    "想	喜欢	是	去",  // 也
+    "一点儿",  // 便宜
+    "书",  // 本
+    "去",  // 飞机
+    "和	在	喜欢",  // 爸爸
+    "冷",  // 有点儿
+    "点	个人",  // 三
+    "什么	大卫",  // 叫
+    "没	想	要",  // 还
+    "一	去	买	多	什么",  // 要
+    "几	是	我们",  // 现在
+    "是",  // 那
+    "喝	去	吃	买	你	睡觉	休息	学习	看",  // 想
+    "书	手机	身体	汉语	朋友	老师	帮助	礼物	生日",  // 的
+    "吧	了",  // 吃饭
+    "去	吃饭	问题",  // 没
+    "妈妈	你	朋友	我	学生",  // 和
+    "在",  // 钱包
+    "了",  // 贵
+    "会	我	我们	不	去",  // 明天
+    "去	一起	坐	是	想	在	都	应该	也",  // 我们
+    "我	这儿",  // 离
+    "你",  // 祝
+    "你	他",  // 认识
+    "认识",  // 高兴
+    "喝	看	吃	听	打篮球	唱歌	跳舞	踢足球	什么",  // 喜欢
+    "一起",  // 朋友
+    "问	喝	坐	进	等	你",  // 请
+    "很	是	在	喝	喜欢",  // 都
+    "很	太",  // 衣服
+    "人	吗	菜	旅游",  // 中国
+    "多	早",  // 应该
+    "衣服",  // 件
+    "明天",  // 如果
+    "工作",  // 公司
+    "说	下雨	来",  // 会
+    "身体",  // 锻炼
+    "天气	下雨",  // 今天
+    "在",  // 手机
+    "来",  // 进
+    "很",  // 得
+    "去",  // 地铁
+    "有	在	很	了",  // 家
+    "工作",  // 医院
+    "家",  // 回
+    "一下",  // 等
+    "吧",  // 公园
+    "喜欢	想	是	正在",  // 她
+    "都",  // 学生
+    "我",  // 所以
+    "我	学生	好	下午	美国	中国	谁	什么	八",  // 是
+    "个	本	起来",  // 一
+    "一	时间	没有	两	三",  // 有
+    "吗",  // 时间
+    "学习",  // 北京
+    "帮	去",  // 能
+    "了",  // 感冒
+    "好	一",  // 大家
+    "电影	书	电视	了	报纸",  // 看
+    "去",  // 准备
+    "我",  // 帮
+    "一	图书馆	很",  // 了
+    "多少	问题	哥哥	妹妹	儿子	苹果	电影	菜	西瓜",  // 个
+    "就",  // 马上
+    "会",  // 一定
+    "早上	都",  // 每天
+    "你	我",  // 爱
+    "工作",  // 银行
+    "米饭	苹果	什么	水果	了",  // 吃
+    "在",  // 姐姐
+    "是	快乐",  // 生日
+    "人",  // 美国
+    "我",  // 昨天
+    "出租车	公共汽车	地铁	飞机",  // 坐
+    "去",  // 出租车
+    "很",  // 这儿
+    "很",  // 西瓜
+    "很",  // 菜
+    "中国	北京	美国",  // 过
+    "吧",  // 咖啡
+    "个",  // 两
+    "我",  // 但是
+    "不	很	吗	说	了",  // 汉语
+    "下雨",  // 因为
+    "的",  // 谁
+    "的	想	有	一起	和	好	很	喜欢	会",  // 你
+    "月",  // 八
+    "附近	离	学习",  // 学校
+    "个	是	件",  // 这
+];
+
+// Search keys of the words in NEXT, in the same order
+pub static NEXT_PINYIN: &[&str] = &[
+    "li",
+    "le",
+    "yanse	mingzi",
+    "xiang	de	xihuan	yao	hen	bu	he	qu	zai",
+    "ma	xuesheng	pengyou",
+    "shi	xiang	xihuan	shuo	zuo	zhengzai	meitian	de	you",
+    "zai",
+    "paobu	he",
+    "zai",
+    "hen",
+    "beijing	guo	zhongguo	shangdian	gongyuan	chifan	kan	yiyuan	pashan",
+    "chifan	zhidao	hui",
+    "san",
+    "qu",
+    "zai",
+    "qian",
+    "qu	mingtian",
+    "hen",
+    "nar	xuexiao	zhuozi	jiaoshi	zuo	xuexi	kan	dadianhua	fandian",
+    "dian",
+    "yixia",
+    "le	ma",
+    "he",
+    "kuaile",
+    "kuaile",
+    "hen	zenmeyang",
+    "xiamian",
+    "dian",
+    "cha	shui	kafei	guozhi	niunai	pijiu	le	shenme	yi",
+    "le",
+    "ni",
+    "yinyue",
+    "dongxi	yi	yifu	piao	le",
+    "hao	gaoxing	haochi	mang	lei	leng	re	piaoliang	nan",
+    "jin",
+    "shijian	qian	shouji",
+    "shenme	de",
+    "de	ma",
+    "ba",
+    "shang",
+    "lai	qu",
+    "hanyu	ba",
+    "ba",
+    "zai",
+    "shuijiao	zuo",
+    "hanyu",
+    "xuexi",
+    "hen	youdianr	zhe	zenmeyang",
+    "tai	xihuan	zhidao	renshi	xiang	hui	xiayu",
+    "he	xiuxi	duanlian	pijiu",
+    "chifan",
+    "hanyu	yidianr	de	wo",
+    "mai",
+    "qu	dou	zuo",
+    "shuo",
+    "zai",
+    "zai",
+    "qu	xuexi",
+    "gui	nan	hao",
+    "hen",
+    "xiang	xihuan	shi	qu",
+    "yidianr",
+    "shu",
+    "qu",
+    "he	zai	xihuan",
+    "leng",
+    "dian	geren",
+    "shenme	dawei",
+    "mei	xiang	yao",
+    "yi	qu	mai	duo	shenme",
+    "ji	shi	women",
+    "shi",
+    "he	qu	chi	mai	ni	shuijiao	xiuxi	xuexi	kan",
+    "shu	shouji	shenti	hanyu	pengyou	laoshi	bangzhu	liwu	shengri",
+    "ba	le",
+    "qu	chifan	wenti",
+    "mama	ni	pengyou	wo	xuesheng",
+    "zai",
+    "le",
+    "hui	wo	women	bu	qu",
+    "qu	yiqi	zuo	shi	xiang	zai	dou	yinggai	ye",
+    "wo	zher",
+    "ni",
+    "ni	ta",
+    "renshi",
+    "he	kan	chi	ting	dalanqiu	changge	tiaowu	tizuqiu	shenme",
+    "yiqi",
+    "wen	he	zuo	jin	deng	ni",
+    "hen	shi	zai	he	xihuan",
+    "hen	tai",
+    "ren	ma	cai	lvyou",
+    "duo	zao",
+    "yifu",
+    "mingtian",
+    "gongzuo",
+    "shuo	xiayu	lai",
+    "shenti",
+    "tianqi	xiayu",
+    "zai",
+    "lai",
+    "hen",
+    "qu",
+    "you	zai	hen	le",
+    "gongzuo",
+    "jia",
+    "yixia",
+    "ba",
+    "xihuan	xiang	shi	zhengzai",
+    "dou",
+    "wo",
+    "wo	xuesheng	hao	xiawu	meiguo	zhongguo	shei	shenme	ba",
+    "ge	ben	qilai",
+    "yi	shijian	meiyou	liang	san",
+    "ma",
+    "xuexi",
+    "bang	qu",
+    "le",
+    "hao	yi",
+    "dianying	shu	dianshi	le	baozhi",
+    "qu",
+    "wo",
+    "yi	tushuguan	hen",
+    "duoshao	wenti	gege	meimei	erzi	pingguo	dianying	cai	xigua",
+    "jiu",
+    "hui",
+    "zaoshang	dou",
+    "ni	wo",
+    "gongzuo",
+    "mifan	pingguo	shenme	shuiguo	le",
+    "zai",
+    "shi	kuaile",
+    "ren",
+    "wo",
+    "chuzuche	gonggongqiche	ditie	feiji",
+    "qu",
+    "hen",
+    "hen",
+    "hen",
+    "zhongguo	beijing	meiguo",
+    "ba",
+    "ge",
+    "wo",
+    "bu	hen	ma	shuo	le",
+    "xiayu",
+    "de",
+    "de	xiang	you	yiqi	he	hao	hen	xihuan	hui",
+    "yue",
+    "fujin	li	xuexi",
+    "ge	shi	jian",
+];
+
+// How many times each word in NEXT follows its word in the corpus
+pub static NEXT_COUNT: &[&[u32]] = &[
+    &[1],  // 机场
+    &[1],  // 知道
+    &[1, 1],  // 什么
+    &[19, 9, 7, 7, 6, 5, 3, 3, 3],  // 我
+    &[1, 1, 1],  // 好
+    &[2, 1, 1, 1, 1, 1, 1, 1, 1],  // 他
+    &[1],  // 猫
+    &[1, 1],  // 早上
+    &[1],  // 洗手间
+    &[1],  // 虽然
+    &[3, 3, 2, 2, 2, 2, 2, 2, 2],  // 去
+    &[1, 1, 1],  // 已经
+    &[1],  // 下午
+    &[1],  // 公共汽车
+    &[1],  // 哥哥
+    &[1],  // 多少
+    &[1, 1],  // 打算
+    &[3],  // 天气
+    &[2, 2, 2, 1, 1, 1, 1, 1, 1],  // 在
+    &[1],  // 几
+    &[1],  // 休息
+    &[1, 1],  // 下雨
+    &[1],  // 老师
+    &[1],  // 新年
+    &[1],  // 春节
+    &[1, 1],  // 身体
+    &[1],  // 椅子
+    &[1],  // 早
+    &[4, 4, 4, 2, 2, 2, 2, 1, 1],  // 喝
+    &[1],  // 生病
+    &[2],  // 谢谢
+    &[1],  // 听
+    &[1, 1, 1, 1, 1],  // 买
+    &[5, 4, 3, 2, 2, 1, 1, 1, 1],  // 很
+    &[1],  // 可以
+    &[1, 1, 1],  // 没有
+    &[1, 1],  // 做
+    &[1, 1],  // 来
+    &[2],  // 电影
+    &[2],  // 桌子
+    &[1, 1],  // 就
+    &[1, 1],  // 一点儿
+    &[1],  // 起来
+    &[1],  // 住
+    &[1, 1],  // 正在
+    &[4],  // 学习
+    &[1],  // 开始
+    &[1, 1, 1, 1],  // 觉得
+    &[2, 1, 1, 1, 1, 1, 1],  // 不
+    &[2, 1, 1, 1],  // 多
+    &[1],  // 饭店
+    &[3, 1, 1, 1],  // 说
+    &[1],  // 商店
+    &[1, 1, 1],  // 妈妈
+    &[1],  // 医生
+    &[1],  // 书
+    &[1],  // 弟弟
+    &[4, 1],  // 一起
+    &[2, 1, 1],  // 太
+    &[1],  // 问题
+    &[1, 1, 1, 1],  // 也
+    &[1],  // 便宜
+    &[2],  // 本
+    &[1],  // 飞机
+    &[1, 1, 1],  // 爸爸
+    &[1],  // 有点儿
+    &[1, 1],  // 三
+    &[1, 1],  // 叫
+    &[1, 1, 1],  // 还
+    &[3, 3, 1, 1, 1],  // 要
+    &[1, 1, 1],  // 现在
+    &[1],  // 那
+    &[10, 8, 3, 2, 2, 1, 1, 1, 1],  // 想
+    &[2, 2, 2, 2, 1, 1, 1, 1, 1],  // 的
+    &[1, 1],  // 吃饭
+    &[2, 1, 1],  // 没
+    &[2, 1, 1, 1, 1],  // 和
+    &[1],  // 钱包
+    &[2],  // 贵
+    &[1, 1, 1, 1, 1],  // 明天
+    &[5, 3, 3, 2, 1, 1, 1, 1, 1],  // 我们
+    &[1, 1],  // 离
+    &[1],  // 祝
+    &[2, 1],  // 认识
+    &[1],  // 高兴
+    &[4, 3, 1, 1, 1, 1, 1, 1, 1],  // 喜欢
+    &[1],  // 朋友
+    &[2, 1, 1, 1, 1, 1],  // 请
+    &[3, 2, 1, 1, 1],  // 都
+    &[1, 1],  // 衣服
+    &[1, 1, 1, 1],  // 中国
+    &[1, 1],  // 应该
+    &[2],  // 件
+    &[1],  // 如果
+    &[1],  // 公司
+    &[3, 1, 1],  // 会
+    &[1],  // 锻炼
+    &[3, 1],  // 今天
+    &[1],  // 手机
+    &[1],  // 进
+    &[1],  // 得
+    &[1],  // 地铁
+    &[1, 1, 1, 1],  // 家
+    &[1],  // 医院
+    &[1],  // 回
+    &[1],  // 等
+    &[1],  // 公园
+    &[2, 1, 1, 1],  // 她
+    &[1],  // 学生
+    &[1],  // 所以
+    &[5, 2, 2, 1, 1, 1, 1, 1, 1],  // 是
+    &[4, 2, 1],  // 一
+    &[2, 1, 1, 1, 1],  // 有
+    &[1],  // 时间
+    &[1],  // 北京
+    &[1, 1],  // 能
+    &[1],  // 感冒
+    &[1, 1],  // 大家
+    &[4, 1, 1, 1, 1],  // 看
+    &[1],  // 准备
+    &[2],  // 帮
+    &[4, 1, 1],  // 了
+    &[1, 1, 1, 1, 1, 1, 1, 1, 1],  // 个
+    &[1],  // 马上
+    &[1],  // 一定
+    &[2, 2],  // 每天
+    &[1, 1],  // 爱
+    &[1],  // 银行
+    &[1, 1, 1, 1, 1],  // 吃
+    &[1],  // 姐姐
+    &[1, 1],  // 生日
+    &[1],  // 美国
+    &[2],  // 昨天
+    &[1, 1, 1, 1],  // 坐
+    &[1],  // 出租车
+    &[1],  // 这儿
+    &[1],  // 西瓜
+    &[2],  // 菜
+    &[1, 1, 1],  // 过
+    &[1],  // 咖啡
+    &[1],  // 两
+    &[1],  // 但是
+    &[2, 2, 1, 1, 1],  // 汉语
+    &[1],  // 因为
+    &[1],  // 谁
+    &[5, 3, 2, 1, 1, 1, 1, 1, 1],  // 你
+    &[1],  // 八
+    &[1, 1, 1],  // 学校
+    &[4, 3, 2],  // 这
+];
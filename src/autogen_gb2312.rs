@@ -0,0 +1,9211 @@
+// This file is automatically generated. DO NOT MAKE EDITS HERE!
+// To make changes, see ../vocab/autogen-gb2312.rb
+//
+// Copyright (c) 2021 Sam Blenny
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+#![forbid(unsafe_code)]
+#![allow(dead_code)]
+
+// u32 constants are murmur3 hash of the pinyin syllables that GB 2312
+// characters are read as, with the same seed as autogen_hsk::MURMUR3_SEED
+pub const MURMUR3_SEED: u32 = 0;
+pub static PINYIN: &[u32] = &[
+    0x000050fb, // ruo
+    0x01105007, // niu
+    0x012707d9, // cai
+    0x0224acb9, // dui
+    0x028c89c3, // qian
+    0x02aaf006, // tang
+    0x044355b0, // hu
+    0x04bacedf, // huai
+    0x051bfb53, // bian
+    0x0522ff8e, // nian
+    0x057a2fc3, // pie
+    0x074eb454, // zui
+    0x0798c654, // seng
+    0x0839255e, // cha
+    0x094b176f, // lang
+    0x0b10072e, // mei
+    0x0c76073e, // nu
+    0x0e3956d7, // ning
+    0x0f1b04e5, // ceng
+    0x0fd58239, // cuo
+    0x10f96b81, // tuo
+    0x110d2925, // ta
+    0x11eccee8, // wei
+    0x126c172f, // chi
+    0x129f53dd, // ai
+    0x14dda0eb, // jun
+    0x15360e33, // pu
+    0x15ca03a8, // shuai
+    0x16a3f922, // shao
+    0x16c6930d, // zhun
+    0x17cdd84f, // qiong
+    0x181cff59, // tian
+    0x1943c14d, // san
+    0x1a11bb8b, // huang
+    0x1a1476e3, // ce
+    0x1bdf71d5, // yi
+    0x1c9bcac4, // zun
+    0x1ccc0ccb, // ma
+    0x1d10148b, // leng
+    0x1d3c361c, // mian
+    0x1dc58a0e, // kou
+    0x1df272d0, // la
+    0x1e97b218, // dai
+    0x1ed18529, // lou
+    0x1fde519c, // chuai
+    0x2131fe1b, // han
+    0x21974ab1, // juan
+    0x21c04be2, // xin
+    0x2271e4aa, // hou
+    0x2364c5db, // me
+    0x23ee1f9f, // qie
+    0x25143b9f, // pen
+    0x26702e30, // ang
+    0x26f4dd77, // lu
+    0x28ba020d, // he
+    0x2a6757ee, // yue
+    0x2b038801, // a
+    0x2b1ca8fb, // zhao
+    0x2bdeba4a, // nv
+    0x2bf6a428, // geng
+    0x2cbeea3b, // miao
+    0x2cc63e0d, // shuang
+    0x2f108ae4, // tun
+    0x3074029a, // diao
+    0x3112abbb, // jian
+    0x31835d23, // nen
+    0x338d840b, // kong
+    0x33f8679c, // shai
+    0x342e35e7, // nan
+    0x343026e6, // suan
+    0x3520b8db, // tui
+    0x35574bdc, // tiao
+    0x35a5eb79, // tou
+    0x35e0a247, // ruan
+    0x3630e479, // yu
+    0x3655284b, // kao
+    0x37a02e6e, // bo
+    0x38ae1fe8, // tu
+    0x38e42cc1, // shen
+    0x38e6cca1, // deng
+    0x391d2faa, // sai
+    0x3954f551, // cun
+    0x3a690e94, // ha
+    0x3a86a04b, // gan
+    0x3acad72a, // yun
+    0x3b1c3960, // kuang
+    0x3bb76bba, // qing
+    0x3bcb505e, // shei
+    0x3c0e18c9, // keng
+    0x3cf7a1a6, // kui
+    0x3d6a1add, // lie
+    0x3dfaad2a, // du
+    0x3e1b056e, // teng
+    0x3e7771c2, // hang
+    0x3e8160c4, // ku
+    0x3ea0533c, // da
+    0x3eb44680, // jiong
+    0x3ecd8f70, // bei
+    0x3ffcb9de, // chun
+    0x401d5c86, // kan
+    0x40cdb646, // can
+    0x41982ff6, // gei
+    0x41a7eb65, // qiu
+    0x423f8b81, // pang
+    0x4383a6cd, // yuan
+    0x440f8215, // xu
+    0x4497fe51, // shuan
+    0x44d6183f, // ye
+    0x46b85ca4, // quan
+    0x47e7e652, // che
+    0x47faa3fb, // ao
+    0x4802f27f, // ri
+    0x484f87dd, // mie
+    0x48d4ca5c, // pin
+    0x493f4988, // zuan
+    0x498ffd8f, // kua
+    0x49aeeed8, // nai
+    0x4b52ab6f, // dang
+    0x4b646cf1, // hen
+    0x4b881b3b, // chen
+    0x4c870bfd, // ti
+    0x4d0e6fa7, // ping
+    0x4d35ecd9, // feng
+    0x4d61f58e, // long
+    0x4dba017f, // zhen
+    0x4e296751, // nou
+    0x4e40f4bd, // cui
+    0x4ebfe8f7, // mi
+    0x4ee55a27, // er
+    0x4fd47286, // qiang
+    0x50603d0b, // cong
+    0x51e75c37, // ge
+    0x527b4d3d, // fen
+    0x533c42df, // sen
+    0x53ae2864, // qin
+    0x54da69f0, // jiao
+    0x54e46480, // pa
+    0x5570e771, // ju
+    0x56cd7969, // gai
+    0x573f589d, // ke
+    0x59345b2a, // zeng
+    0x5975bdcc, // li
+    0x5a32ea82, // qiao
+    0x5a7c6a5c, // chuang
+    0x5acf6db2, // luo
+    0x5b11b835, // na
+    0x5b21d9cd, // ming
+    0x5c0bf6ff, // ze
+    0x5c732326, // shui
+    0x5dfa15f6, // dou
+    0x5ece1f41, // liu
+    0x5fa74fc4, // kang
+    0x5fd4237b, // nei
+    0x6070885a, // xiao
+    0x60f66228, // jiang
+    0x6113d6a6, // ga
+    0x61521d7f, // ou
+    0x61d8d67e, // lai
+    0x623b0c5f, // zei
+    0x63b03952, // rui
+    0x64b347ac, // cu
+    0x6543a7da, // zen
+    0x65580ab2, // duo
+    0x65cc3c60, // qu
+    0x66903d6b, // gua
+    0x6754d280, // xian
+    0x67a25ec2, // mang
+    0x68ddc329, // niang
+    0x695bf5b7, // bai
+    0x69ebf985, // zang
+    0x6a47494c, // bin
+    0x6a741314, // rong
+    0x6b0548dd, // ba
+    0x6b230d9c, // hai
+    0x6ba31e94, // ren
+    0x6bb47f59, // zhuan
+    0x6c54b99a, // hong
+    0x6d6084c3, // she
+    0x6f8baca2, // rou
+    0x6ff005b3, // pou
+    0x7016c87d, // cheng
+    0x7052d470, // mao
+    0x7057c115, // tie
+    0x71e69095, // zhi
+    0x72cc6f1f, // neng
+    0x7320e0d1, // gong
+    0x732ec6fe, // lve
+    0x73353ca8, // ca
+    0x745f8573, // chao
+    0x74e40413, // yan
+    0x75041f3b, // shi
+    0x7505d5e5, // pai
+    0x757ffc05, // xie
+    0x7643871a, // weng
+    0x76952dd0, // suo
+    0x76959834, // rang
+    0x76fb9d72, // song
+    0x781d66a9, // mo
+    0x7886ef54, // zhuo
+    0x78b05f75, // zhe
+    0x7abf9117, // pei
+    0x7ae05d22, // zhua
+    0x7ae9cb6c, // xiang
+    0x7b6a60fc, // gao
+    0x7b9599ba, // zuo
+    0x7cd44817, // zou
+    0x7f26dc27, // zhuai
+    0x7f892426, // bi
+    0x80354a78, // bie
+    0x80405073, // hua
+    0x804744c4, // e
+    0x81705da0, // jie
+    0x8256cadb, // yong
+    0x826f241a, // ru
+    0x83aadb62, // yang
+    0x84510f7d, // o
+    0x845f8758, // te
+    0x8477cf48, // dong
+    0x8483fd69, // guo
+    0x84f0ab9a, // que
+    0x84fe8bc5, // guan
+    0x851bd243, // bing
+    0x856c81bd, // liao
+    0x8629b4dd, // meng
+    0x86e0f80b, // mou
+    0x86f8ea22, // duan
+    0x86fabfa6, // dun
+    0x883dbacf, // pan
+    0x88e3aa17, // nao
+    0x8957f473, // qun
+    0x8ba36723, // rao
+    0x8bd50111, // huo
+    0x8bebc618, // re
+    0x8bf2dc4d, // sheng
+    0x8c24074b, // gang
+    0x8e4b5ab3, // chan
+    0x8ef6f951, // yin
+    0x8ff7f7ec, // shan
+    0x91f327a3, // zha
+    0x9280d516, // nuo
+    0x92b1c3ab, // dan
+    0x9482e250, // lei
+    0x94969f2f, // po
+    0x94be0858, // reng
+    0x95ce8623, // biao
+    0x966629ee, // ka
+    0x96a40bd5, // wen
+    0x96da5ff3, // lian
+    0x971c2835, // cuan
+    0x972b02f1, // bang
+    0x978f4c48, // zhang
+    0x979a96ad, // ling
+    0x97c3d221, // tai
+    0x9816d70e, // piao
+    0x9935279e, // lan
+    0x9948f63a, // nie
+    0x994975ad, // man
+    0x9a7a8ce0, // ne
+    0x9b41271e, // jin
+    0x9c211f6f, // chu
+    0x9c5a0127, // chuo
+    0x9c6db128, // fan
+    0x9db153c2, // chuan
+    0x9e44fb32, // pao
+    0x9eb469c4, // hui
+    0xa03812ca, // ying
+    0xa108bd2d, // fang
+    0xa1ef34e1, // gui
+    0xa275d2c7, // jia
+    0xa2cffb98, // sun
+    0xa35c9329, // tong
+    0xa376d119, // mu
+    0xa47ca216, // nuan
+    0xa5fdcaff, // lun
+    0xa6343682, // gu
+    0xa64c34e0, // guang
+    0xa65a5200, // wang
+    0xa91c8ddf, // hun
+    0xab6285ba, // shun
+    0xab9ead85, // guai
+    0xabdff14c, // wo
+    0xac0a2f3f, // yao
+    0xad1f49ee, // sang
+    0xae4013dd, // jing
+    0xae707a35, // shou
+    0xae7d8902, // luan
+    0xaf94f6b0, // zan
+    0xb0290937, // zu
+    0xb0f18c03, // pi
+    0xb2319ed7, // fei
+    0xb2fa8b7f, // dao
+    0xb32ab687, // dian
+    0xb34e22e8, // mai
+    0xb3b96c38, // le
+    0xb462a1f6, // jiu
+    0xb5cf27a5, // wan
+    0xb6266fe7, // ken
+    0xb682d0b4, // gou
+    0xb7aa73a2, // sao
+    0xb7b8687a, // huan
+    0xb7fa807b, // you
+    0xb9b1afe5, // cou
+    0xba14ea01, // bu
+    0xba3b14f9, // zhai
+    0xbae17435, // kun
+    0xbbd4101c, // zong
+    0xbc2adf33, // xiong
+    0xbc4b39bf, // lin
+    0xbc60fbb0, // se
+    0xbc98b9f4, // chai
+    0xbcb0f2db, // lao
+    0xbd2caa64, // zhui
+    0xbd6fd794, // fou
+    0xbeecd8a3, // zheng
+    0xbfc246ee, // zai
+    0xbfcb1771, // wai
+    0xbfe32c31, // sha
+    0xc0a5b14c, // hei
+    0xc152449d, // xiu
+    0xc17173bf, // kai
+    0xc1fd490d, // ya
+    0xc2dfb63d, // xing
+    0xc3fafe97, // nong
+    0xc4aaac5e, // cen
+    0xc58b2017, // kuan
+    0xc92a3ab2, // peng
+    0xc9e7c288, // tao
+    0xca2f57ac, // hao
+    0xcbaac199, // en
+    0xcc65a9b7, // tan
+    0xcc9ebf6a, // cang
+    0xd004cd1a, // ji
+    0xd0cb7b07, // shu
+    0xd2846f17, // za
+    0xd29410ea, // qi
+    0xd2adf568, // yo
+    0xd2e71433, // zi
+    0xd33c3f80, // zhu
+    0xd35ffcb5, // miu
+    0xd3acde07, // nve
+    0xd44f3c2f, // ci
+    0xd4947e78, // fa
+    0xd5fb3b8f, // xue
+    0xd6bd32e0, // gun
+    0xd71666f8, // run
+    0xd72a3c76, // zhan
+    0xd7c48ae5, // ei
+    0xd93ffe6d, // sui
+    0xd94b205b, // lv
+    0xd959d0b8, // sou
+    0xd964aa5e, // an
+    0xda24d26a, // heng
+    0xdb01af22, // n
+    0xdbdcaf20, // xun
+    0xdc091cdb, // men
+    0xdc23a67d, // wu
+    0xde24b6a5, // chou
+    0xdec4ef10, // chong
+    0xe06102a9, // ran
+    0xe190623a, // jue
+    0xe1d1f626, // shua
+    0xe3b87f3d, // min
+    0xe3f66bf2, // diu
+    0xe450e90c, // wa
+    0xe60f35ed, // ban
+    0xe657e89e, // ding
+    0xe7202d60, // zhou
+    0xe9c6e495, // su
+    0xea9aba44, // fu
+    0xec18fbe3, // zhuang
+    0xec90a46b, // chang
+    0xee164044, // sa
+    0xee52907a, // lia
+    0xee85a44e, // de
+    0xee92354e, // gen
+    0xef157a07, // shang
+    0xef43bc12, // niao
+    0xef551fdf, // kuai
+    0xef559cd4, // tuan
+    0xf1f2bfbf, // ben
+    0xf249101c, // die
+    0xf296ff37, // ni
+    0xf3318256, // kuo
+    0xf3c94102, // zao
+    0xf427743c, // cao
+    0xf4858adf, // pian
+    0xf4df2a22, // qia
+    0xf5246271, // zhong
+    0xf5cb6679, // xuan
+    0xf60d2fe0, // liang
+    0xf6534f28, // nin
+    0xf6610b71, // di
+    0xf680bf70, // shuo
+    0xf7e0f597, // ting
+    0xf9356c6c, // nang
+    0xfc75a0c0, // si
+    0xfe255a33, // xia
+    0xfe76a9bf, // beng
+    0xfe864a54, // chui
+    0xffb809b8, // bao
+    0xfffc53b6, // xi
+];
+
+// Pinyin syllables in the same order as PINYIN
+pub static PINYIN_KEYS: &[&str] = &[
+    "ruo",
+    "niu",
+    "cai",
+    "dui",
+    "qian",
+    "tang",
+    "hu",
+    "huai",
+    "bian",
+    "nian",
+    "pie",
+    "zui",
+    "seng",
+    "cha",
+    "lang",
+    "mei",
+    "nu",
+    "ning",
+    "ceng",
+    "cuo",
+    "tuo",
+    "ta",
+    "wei",
+    "chi",
+    "ai",
+    "jun",
+    "pu",
+    "shuai",
+    "shao",
+    "zhun",
+    "qiong",
+    "tian",
+    "san",
+    "huang",
+    "ce",
+    "yi",
+    "zun",
+    "ma",
+    "leng",
+    "mian",
+    "kou",
+    "la",
+    "dai",
+    "lou",
+    "chuai",
+    "han",
+    "juan",
+    "xin",
+    "hou",
+    "me",
+    "qie",
+    "pen",
+    "ang",
+    "lu",
+    "he",
+    "yue",
+    "a",
+    "zhao",
+    "nv",
+    "geng",
+    "miao",
+    "shuang",
+    "tun",
+    "diao",
+    "jian",
+    "nen",
+    "kong",
+    "shai",
+    "nan",
+    "suan",
+    "tui",
+    "tiao",
+    "tou",
+    "ruan",
+    "yu",
+    "kao",
+    "bo",
+    "tu",
+    "shen",
+    "deng",
+    "sai",
+    "cun",
+    "ha",
+    "gan",
+    "yun",
+    "kuang",
+    "qing",
+    "shei",
+    "keng",
+    "kui",
+    "lie",
+    "du",
+    "teng",
+    "hang",
+    "ku",
+    "da",
+    "jiong",
+    "bei",
+    "chun",
+    "kan",
+    "can",
+    "gei",
+    "qiu",
+    "pang",
+    "yuan",
+    "xu",
+    "shuan",
+    "ye",
+    "quan",
+    "che",
+    "ao",
+    "ri",
+    "mie",
+    "pin",
+    "zuan",
+    "kua",
+    "nai",
+    "dang",
+    "hen",
+    "chen",
+    "ti",
+    "ping",
+    "feng",
+    "long",
+    "zhen",
+    "nou",
+    "cui",
+    "mi",
+    "er",
+    "qiang",
+    "cong",
+    "ge",
+    "fen",
+    "sen",
+    "qin",
+    "jiao",
+    "pa",
+    "ju",
+    "gai",
+    "ke",
+    "zeng",
+    "li",
+    "qiao",
+    "chuang",
+    "luo",
+    "na",
+    "ming",
+    "ze",
+    "shui",
+    "dou",
+    "liu",
+    "kang",
+    "nei",
+    "xiao",
+    "jiang",
+    "ga",
+    "ou",
+    "lai",
+    "zei",
+    "rui",
+    "cu",
+    "zen",
+    "duo",
+    "qu",
+    "gua",
+    "xian",
+    "mang",
+    "niang",
+    "bai",
+    "zang",
+    "bin",
+    "rong",
+    "ba",
+    "hai",
+    "ren",
+    "zhuan",
+    "hong",
+    "she",
+    "rou",
+    "pou",
+    "cheng",
+    "mao",
+    "tie",
+    "zhi",
+    "neng",
+    "gong",
+    "lve",
+    "ca",
+    "chao",
+    "yan",
+    "shi",
+    "pai",
+    "xie",
+    "weng",
+    "suo",
+    "rang",
+    "song",
+    "mo",
+    "zhuo",
+    "zhe",
+    "pei",
+    "zhua",
+    "xiang",
+    "gao",
+    "zuo",
+    "zou",
+    "zhuai",
+    "bi",
+    "bie",
+    "hua",
+    "e",
+    "jie",
+    "yong",
+    "ru",
+    "yang",
+    "o",
+    "te",
+    "dong",
+    "guo",
+    "que",
+    "guan",
+    "bing",
+    "liao",
+    "meng",
+    "mou",
+    "duan",
+    "dun",
+    "pan",
+    "nao",
+    "qun",
+    "rao",
+    "huo",
+    "re",
+    "sheng",
+    "gang",
+    "chan",
+    "yin",
+    "shan",
+    "zha",
+    "nuo",
+    "dan",
+    "lei",
+    "po",
+    "reng",
+    "biao",
+    "ka",
+    "wen",
+    "lian",
+    "cuan",
+    "bang",
+    "zhang",
+    "ling",
+    "tai",
+    "piao",
+    "lan",
+    "nie",
+    "man",
+    "ne",
+    "jin",
+    "chu",
+    "chuo",
+    "fan",
+    "chuan",
+    "pao",
+    "hui",
+    "ying",
+    "fang",
+    "gui",
+    "jia",
+    "sun",
+    "tong",
+    "mu",
+    "nuan",
+    "lun",
+    "gu",
+    "guang",
+    "wang",
+    "hun",
+    "shun",
+    "guai",
+    "wo",
+    "yao",
+    "sang",
+    "jing",
+    "shou",
+    "luan",
+    "zan",
+    "zu",
+    "pi",
+    "fei",
+    "dao",
+    "dian",
+    "mai",
+    "le",
+    "jiu",
+    "wan",
+    "ken",
+    "gou",
+    "sao",
+    "huan",
+    "you",
+    "cou",
+    "bu",
+    "zhai",
+    "kun",
+    "zong",
+    "xiong",
+    "lin",
+    "se",
+    "chai",
+    "lao",
+    "zhui",
+    "fou",
+    "zheng",
+    "zai",
+    "wai",
+    "sha",
+    "hei",
+    "xiu",
+    "kai",
+    "ya",
+    "xing",
+    "nong",
+    "cen",
+    "kuan",
+    "peng",
+    "tao",
+    "hao",
+    "en",
+    "tan",
+    "cang",
+    "ji",
+    "shu",
+    "za",
+    "qi",
+    "yo",
+    "zi",
+    "zhu",
+    "miu",
+    "nve",
+    "ci",
+    "fa",
+    "xue",
+    "gun",
+    "run",
+    "zhan",
+    "ei",
+    "sui",
+    "lv",
+    "sou",
+    "an",
+    "heng",
+    "n",
+    "xun",
+    "men",
+    "wu",
+    "chou",
+    "chong",
+    "ran",
+    "jue",
+    "shua",
+    "min",
+    "diu",
+    "wa",
+    "ban",
+    "ding",
+    "zhou",
+    "su",
+    "fu",
+    "zhuang",
+    "chang",
+    "sa",
+    "lia",
+    "de",
+    "gen",
+    "shang",
+    "niao",
+    "kuai",
+    "tuan",
+    "ben",
+    "die",
+    "ni",
+    "kuo",
+    "zao",
+    "cao",
+    "pian",
+    "qia",
+    "zhong",
+    "xuan",
+    "liang",
+    "nin",
+    "di",
+    "shuo",
+    "ting",
+    "nang",
+    "si",
+    "xia",
+    "beng",
+    "chui",
+    "bao",
+    "xi",
+];
+
+// Characters read as each syllable (joined by "\t"), sorted by descending
+// frequency, so the first choice is the most common character
+pub static ZI: &[&str] = &[
+    "若	弱	偌	箬",  // ruo
+    "牛	扭	纽	钮	妞	忸	狃",  // niu
+    "才	采	菜	彩	财	材	猜	裁	踩	蔡	睬",  // cai
+    "对	队	堆	兑	怼	碓	憝	镦",  // dui
+    "前	钱	千	乾	签	潜	牵	浅	欠	歉	迁	遣	谦	谴	铅	倩	嵌	虔	茜	钳	黔	骞	缱	堑	掮	悭	仟	阡	芊	愆	佥	钎	钤	芡	箝	扦	搴	慊	褰	椠	凵	岍	肷",  // qian
+    "堂	唐	躺	汤	糖	倘	趟	烫	塘	淌	膛	棠	搪	傥	螳	镗	帑	溏	饧	瑭	醣	樘	铴	耥	螗	羰",  // tang
+    "乎	呼	护	户	胡	忽	互	湖	虎	糊	狐	壶	蝴	惚	唬	弧	葫	浒	扈	沪	琥	瑚	笏	唿	祜	猢	槲	囫	斛	瓠	醐	滹	鹕	觳	怙	烀	煳	戽	岵	冱	轷	鹱	虍",  // hu
+    "怀	坏	徊	淮	槐	踝",  // huai
+    "边	变	便	编	遍	辩	辨	扁	鞭	辫	贬	汴	匾	蝙	弁	卞	砭	鳊	褊	煸	碥	窆	笾	苄	忭	缏",  // bian
+    "年	念	廿	撵	碾	辗	拈	黏	捻	蔫	辇	鲇	鲶	埝",  // nian
+    "撇	瞥	丿	苤	氕",  // pie
+    "最	嘴	罪	醉	蕞",  // zui
+    "僧",  // seng
+    "查	察	差	茶	插	叉	诧	岔	茬	碴	衩	搽	杈	槎	姹	汊	猹	檫	锸	馇	镲",  // cha
+    "浪	郎	朗	狼	廊	琅	螂	啷	榔	锒	阆	莨	稂	蒗",  // lang
+    "没	美	每	妹	梅	眉	媒	玫	煤	媚	枚	霉	昧	魅	楣	寐	莓	袂	酶	镁	湄	嵋	浼	镅	猸	鹛",  // mei
+    "怒	努	奴	弩	驽	孥	胬",  // nu
+    "宁	凝	拧	狞	柠	泞	咛	佞	甯	聍",  // ning
+    "曾	层	蹭	噌",  // ceng
+    "错	措	搓	挫	撮	磋	锉	蹉	厝	矬	嵯	痤	鹾	脞",  // cuo
+    "脱	托	拖	妥	陀	驼	唾	鸵	驮	椭	橐	沱	坨	砣	跎	佗	酡	柝	庹	鼍	乇	箨	沲",  // tuo
+    "他	她	它	塔	踏	塌	拓	榻	蹋	遢	挞	趿	獭	闼	铊	鳎	溻",  // ta
+    "为	位	未	微	委	味	维	围	威	卫	谓	唯	危	伟	尾	慰	伪	违	薇	喂	魏	韦	惟	尉	畏	胃	萎	巍	苇	蔚	偎	娓	囗	纬	猬	帷	猥	痿	桅	渭	闱	炜	逶	帏	诿	玮	韪	嵬	煨	圩	隈	葳	潍	艉	鲔	洧	涠	沩	軎",  // wei
+    "吃	持	迟	池	赤	尺	痴	耻	齿	斥	驰	翅	炽	哧	弛	嗤	侈	叱	啻	敕	墀	篪	饬	笞	茌	踟	蚩	魑	鸱	褫	彳	螭	坻	眵	媸	傺	瘛",  // chi
+    "爱	埃	艾	哀	挨	哎	碍	唉	矮	癌	暧	蔼	隘	霭	嗳	锿	捱	皑	嫒	瑷	嗌	砹",  // ai
+    "军	君	均	俊	菌	峻	郡	骏	钧	竣	浚	麇	皲	捃",  // jun
+    "普	扑	铺	谱	朴	仆	葡	浦	菩	脯	蒲	噗	瀑	曝	圃	匍	璞	溥	莆	蹼	濮	氆	攵	镤	镨	攴",  // pu
+    "摔	衰	帅	甩	蟀",  // shuai
+    "少	烧	绍	稍	哨	勺	梢	捎	邵	韶	艄	芍	苕	劭	潲	蛸	筲",  // shao
+    "准	谆	肫	窀",  // zhun
+    "穷	琼	穹	茕	蛩	邛	跫	芎	筇	銎",  // qiong
+    "天	田	甜	添	填	舔	恬	腆	阗	殄	忝	畋	掭",  // tian
+    "三	散	伞	叁	糁	毵	馓",  // san
+    "黄	皇	荒	慌	晃	谎	恍	惶	煌	凰	幌	潢	徨	璜	簧	磺	蝗	隍	肓	遑	湟	篁	蟥	癀	鳇",  // huang
+    "测	策	侧	册	厕	恻",  // ce
+    "一	以	意	已	义	议	衣	易	依	疑	医	异	伊	忆	移	艺	译	益	遗	亦	亿	椅	宜	仪	翼	役	姨	抑	谊	怡	溢	倚	毅	逸	矣	绎	乙	夷	蚁	裔	驿	疫	颐	咦	揖	翌	臆	贻	熠	噫	漪	奕	咿	旖	邑	呓	屹	彝	羿	诣	轶	弋	迤	懿	沂	壹	缢	弈	佚	蜴	胰	铱	悒	翳	痍	饴	肄	衤	癔	猗	挹	刈	翊	诒	钇	薏	圯	欹	佾	镒	埸	怿	苡	眙	劓	殪	瘗	嶷	镱	黟	峄	舣	酏",  // yi
+    "尊	遵	樽	鳟	撙",  // zun
+    "马	吗	妈	码	麻	嘛	骂	玛	蚂	蟆	嬷	犸	杩	唛",  // ma
+    "冷	愣	楞	棱	塄",  // leng
+    "面	免	眠	绵	棉	勉	缅	冕	腼	娩	湎	沔	眄	渑	黾	宀",  // mian
+    "口	扣	叩	寇	抠	蔻	芤	眍	筘",  // kou
+    "拉	啦	辣	腊	喇	垃	蜡	剌	邋	旯	瘌	砬",  // la
+    "代	带	待	呆	袋	戴	逮	黛	歹	贷	玳	怠	殆	岱	傣	呔	迨	骀	埭	甙	绐",  // dai
+    "楼	漏	搂	陋	喽	篓	髅	娄	镂	偻	蒌	蝼	瘘	耧	嵝",  // lou
+    "揣	啜	踹	嘬	膪	搋",  // chuai
+    "汉	含	喊	韩	寒	汗	函	翰	憾	涵	罕	旱	撼	悍	捍	憨	酣	鼾	邯	瀚	焊	颔	晗	菡	蚶	焓	邗	顸	阚	撖",  // han
+    "卷	倦	捐	绢	眷	娟	涓	鹃	镌	隽	蠲	狷	鄄	锩	桊",  // juan
+    "信	心	新	欣	辛	馨	薪	芯	衅	昕	鑫	锌	忻	歆	忄	囟",  // xin
+    "后	候	後	厚	猴	侯	吼	喉	逅	篌	瘊	鲎	堠	糇	骺",  // hou
+    "么",  // me
+    "且	切	窃	怯	妾	惬	锲	箧	挈	郄",  // qie
+    "盆	喷	湓",  // pen
+    "昂	肮	盎",  // ang
+    "录	路	露	陆	鲁	卢	炉	碌	鹿	芦	噜	禄	虏	漉	赂	颅	庐	璐	戮	辘	卤	麓	掳	鹭	橹	鲈	撸	潞	泸	胪	轳	垆	鸬	渌	辂	簏	栌	舻	氇	镥	逯",  // lu
+    "和	何	合	河	喝	核	呵	荷	赫	盒	贺	劾	鹤	褐	禾	嗬	涸	阖	阂	壑	诃	颌	菏	盍	曷	翮	蚵",  // he
+    "月	越	约	阅	跃	岳	悦	曰	粤	钺	刖	樾	龠	瀹",  // yue
+    "阿	啊	嗄	锕",  // a
+    "找	照	招	赵	召	罩	兆	昭	爪	诏	沼	肇	钊	啁	棹	笊",  // zhao
+    "女	钕	恧	衄",  // nv
+    "更	羹	耕	耿	庚	哽	梗	埂	赓	鲠	绠",  // geng
+    "描	妙	秒	庙	苗	渺	瞄	藐	缈	喵	淼	邈	杪	眇	鹋",  // miao
+    "双	爽	霜	孀",  // shuang
+    "吞	屯	臀	豚	饨	氽	暾",  // tun
+    "掉	调	吊	雕	钓	凋	刁	叼	貂	碉	铫	鲷	铞",  // diao
+    "件	见	间	建	简	渐	坚	检	键	肩	监	尖	健	减	剑	舰	箭	兼	艰	剪	奸	鉴	践	荐	捡	歼	贱	拣	溅	煎	俭	笺	碱	茧	缄	谏	柬	涧	睑	戬	饯	硷	锏	蒹	菅	翦	蹇	僭	腱	囝	犍	毽	裥	鞯	鹣	鲣	枧	搛	謇	缣	笕	湔	谫	戋	趼	楗	牮	踺",  // jian
+    "嫩	恁",  // nen
+    "空	恐	控	孔	倥	箜	崆",  // kong
+    "晒	筛	酾",  // shai
+    "男	难	南	喃	楠	囡	赧	腩	蝻",  // nan
+    "算	酸	蒜	狻",  // suan
+    "推	退	腿	颓	褪	蜕	煺",  // tui
+    "条	跳	挑	眺	迢	佻	窕	笤	粜	祧	鲦	髫	蜩	龆",  // tiao
+    "头	投	透	偷	骰	钭	亠",  // tou
+    "软	阮	朊",  // ruan
+    "于	与	语	雨	玉	於	预	余	遇	鱼	育	欲	域	宇	愈	予	郁	狱	御	愉	愚	浴	豫	寓	羽	誉	渔	喻	舆	裕	娱	俞	虞	馀	谕	逾	禹	屿	迂	瑜	榆	淤	隅	渝	臾	聿	驭	毓	腴	妪	盂	芋	揄	峪	禺	谀	觎	钰	欤	昱	煜	妤	圄	龉	萸	庾	瘀	伛	阈	竽	纡	鬻	燠	鹬	蝓	蜮	雩	瘐	舁	圉	饫	嵛	窳	俣	鹆	窬	蓣	狳	肀",  // yu
+    "考	靠	烤	拷	铐	犒	栲	尻",  // kao
+    "波	伯	博	播	玻	拨	勃	脖	膊	驳	卜	剥	搏	簸	饽	钵	跛	帛	菠	舶	箔	渤	铂	啵	礴	钹	亳	鹁	檗	踣",  // bo
+    "突	图	土	途	徒	涂	吐	兔	屠	秃	凸	荼	钍	菟	堍	酴",  // tu
+    "什	身	神	深	甚	审	伸	申	沈	慎	渗	呻	绅	婶	肾	莘	哂	蜃	砷	椹	渖	娠	诜	谂	矧	胂",  // shen
+    "等	灯	登	邓	瞪	凳	蹬	噔	镫	磴	戥	嶝	簦",  // deng
+    "赛	塞	腮	鳃	噻",  // sai
+    "存	村	寸	忖	皴",  // cun
+    "哈	蛤	铪",  // ha
+    "感	干	敢	赶	甘	杆	尴	肝	淦	竿	橄	赣	擀	柑	苷	秆	泔	旰	矸	绀	坩	疳	酐	澉",  // gan
+    "运	云	允	晕	芸	孕	韵	昀	匀	蕴	郓	酝	陨	熨	殒	耘	筠	纭	愠	氲	恽	郧	狁	韫",  // yun
+    "况	狂	矿	框	旷	眶	筐	匡	哐	邝	诳	诓	夼	圹	纩	贶",  // kuang
+    "清	情	轻	请	青	庆	倾	晴	卿	氢	顷	擎	蜻	氰	磬	罄	箐	鲭	黥	檠	圊	苘	謦",  // qing
+    "谁",  // shei
+    "坑	吭	铿",  // keng
+    "亏	愧	逵	溃	窥	奎	魁	葵	盔	馈	睽	喟	匮	聩	馗	篑	夔	岿	揆	愦	隗	暌	蝰	喹	蒉	跬	悝",  // kui
+    "列	烈	裂	猎	劣	咧	冽	趔	洌	鬣	捩	躐	埒",  // lie
+    "度	读	独	毒	督	渡	杜	肚	赌	堵	嘟	妒	睹	笃	渎	镀	犊	牍	黩	蠹	碡	髑	椟	芏",  // du
+    "疼	腾	藤	滕	誊",  // teng
+    "航	杭	珩	夯	沆	颃	绗",  // hang
+    "苦	哭	库	裤	酷	枯	窟	骷	绔	喾	堀	刳",  // ku
+    "大	打	达	答	搭	瘩	嗒	哒	沓	耷	褡	鞑	妲	靼	怛	笪",  // da
+    "炯	窘	迥	冂	扃	炅",  // jiong
+    "被	北	备	背	杯	悲	贝	辈	倍	卑	碑	惫	悖	狈	呗	蓓	孛	陂	焙	钡	碚	褙	鹎	鞴	邶	鐾",  // bei
+    "春	纯	唇	蠢	淳	醇	椿	鹑	莼	蝽",  // chun
+    "看	刊	堪	砍	坎	侃	槛	勘	瞰	龛	戡	莰",  // kan
+    "参	残	餐	惨	灿	惭	掺	蚕	孱	璨	粲	骖	黪",  // can
+    "给",  // gei
+    "求	球	秋	囚	丘	邱	俅	裘	酋	虬	蚯	鳅	泅	糗	遒	逑	巯	楸	蝤	犰	赇	鼽",  // qiu
+    "旁	胖	庞	乓	螃	滂	逄	耪",  // pang
+    "原	源	员	远	院	愿	元	园	圆	缘	援	怨	冤	渊	袁	猿	垣	鸳	苑	辕	沅	媛	鸢	爰	橼	塬	螈	鼋	掾	瑗	垸	眢	箢",  // yuan
+    "许	需	续	须	序	虚	绪	徐	叙	吁	勖	蓄	旭	嘘	絮	婿	恤	胥	墟	煦	蓿	戌	栩	诩	酗	顼	溆	盱	洫	醑	糈",  // xu
+    "拴	栓	涮	闩",  // shuan
+    "也	页	业	夜	爷	叶	野	耶	液	曳	冶	噎	腋	椰	掖	谒	烨	晔	靥	揶	邺	铘",  // ye
+    "全	权	圈	劝	泉	拳	荃	券	犬	蜷	痊	诠	颧	鬈	绻	铨	犭	醛	悛	畎	辁	筌",  // quan
+    "车	彻	扯	撤	澈	掣	砗	坼	屮",  // che
+    "奥	傲	澳	熬	鳌	袄	凹	懊	拗	敖	嗷	翱	遨	媪	坳	骜	螯	鏖	獒	聱	岙	鏊	廒",  // ao
+    "日",  // ri
+    "灭	蔑	篾	咩	乜	蠛",  // mie
+    "品	拼	贫	频	聘	嫔	颦	牝	姘	榀",  // pin
+    "钻	攥	纂	缵	躜",  // zuan
+    "夸	跨	垮	挎	胯	侉",  // kua
+    "奶	乃	耐	奈	鼐	氖	萘	柰	艿",  // nai
+    "当	党	荡	档	挡	铛	裆	宕	砀	凼	谠	菪",  // dang
+    "很	恨	狠	痕",  // hen
+    "沉	陈	臣	尘	晨	辰	趁	衬	嗔	琛	忱	碜	抻	谶	谌	宸	郴	榇	龀",  // chen
+    "题	体	提	替	梯	踢	蹄	屉	啼	涕	剃	惕	剔	嚏	倜	悌	醍	荑	鹈	锑	缇	逖	绨	裼",  // ti
+    "平	评	瓶	凭	屏	苹	萍	坪	乒	娉	枰	俜	鲆",  // ping
+    "风	封	疯	丰	凤	奉	峰	逢	锋	冯	缝	枫	蜂	讽	俸	烽	酆	沣	葑	砜	唪",  // feng
+    "龙	隆	笼	胧	拢	咙	垄	聋	珑	窿	陇	茏	栊	垅	砻	泷	癃",  // long
+    "真	阵	针	镇	珍	朕	震	振	侦	枕	贞	诊	斟	圳	甄	祯	臻	赈	疹	箴	缜	砧	桢	鸩	榛	轸	胗	蓁	畛	稹	浈",  // zhen
+    "耨",  // nou
+    "脆	翠	粹	催	摧	崔	悴	萃	啐	瘁	璀	淬	毳	榱",  // cui
+    "米	密	迷	秘	弥	蜜	咪	眯	谜	觅	靡	祢	谧	泌	糜	幂	嘧	宓	汨	芈	弭	麋	蘼	猕	醚	縻	冖	糸	敉	脒",  // mi
+    "而	儿	二	尔	耳	饵	迩	铒	贰	洱	珥	佴	鸸	鲕",  // er
+    "强	枪	墙	抢	腔	呛	跄	蔷	锵	羌	戕	襁	戗	镪	炝	樯	羟	蜣	嫱	丬	锖",  // qiang
+    "从	聪	匆	丛	葱	囱	淙	琮	枞	骢	苁	璁",  // cong
+    "个	各	格	哥	歌	革	隔	戈	割	阁	胳	葛	搁	鸽	咯	疙	圪	嗝	骼	硌	鬲	膈	铬	纥	舸	虼	袼	仡	哿	搿	塥	镉",  // ge
+    "分	份	纷	奋	粉	愤	芬	氛	坟	吩	粪	焚	忿	汾	鼢	酚	偾	棼	瀵	鲼",  // fen
+    "森",  // sen
+    "亲	侵	琴	秦	勤	钦	寝	芹	擒	沁	禽	噙	衾	揿	吣	螓	芩	嗪	檎	溱	锓",  // qin
+    "叫	交	教	脚	较	角	焦	娇	轿	骄	搅	郊	胶	狡	浇	绞	剿	缴	矫	蕉	饺	椒	礁	跤	侥	窖	皎	酵	佼	姣	蛟	醮	鲛	铰	湫	茭	鹪	噍	徼	挢	峤	敫	僬	艽",  // jiao
+    "怕	爬	帕	啪	趴	琶	杷	葩	筢",  // pa
+    "据	句	局	具	举	居	剧	巨	拒	聚	距	惧	俱	矩	菊	拘	疽	沮	鞠	驹	桔	橘	咀	锯	踞	掬	趄	炬	踽	遽	裾	狙	飓	倨	榉	龃	钜	莒	苣	雎	讵	锔	屦	苴	椐	榘	菹	窭	琚	犋	鞫	醵",  // ju
+    "该	改	概	盖	丐	溉	钙	赅	垓	陔	戤",  // gai
+    "可	科	克	客	刻	课	颗	渴	棵	柯	壳	磕	苛	瞌	珂	坷	恪	蝌	嗑	轲	颏	窠	锞	稞	溘	疴	缂	氪	髁	骒	岢	钶",  // ke
+    "增	赠	憎	锃	甑	缯	罾",  // zeng
+    "里	理	力	立	利	离	李	历	丽	例	礼	粒	厉	璃	莉	黎	哩	励	吏	栗	隶	狸	梨	漓	厘	沥	篱	俐	犁	砾	笠	雳	罹	鲤	戾	俚	砺	荔	鹂	喱	蠡	郦	蛎	逦	痢	呖	俪	藜	唳	莅	跞	骊	栎	锂	娌	蜊	醴	黧	枥	詈	疠	澧	粝	坜	猁	傈	溧	轹	鳢	蓠	缡	疬	嫠	苈	篥	鲡",  // li
+    "瞧	巧	桥	悄	敲	乔	翘	俏	憔	窍	侨	跷	樵	峭	鞘	撬	锹	橇	荞	诮	谯	愀	劁	缲	鞒	硗",  // qiao
+    "窗	床	创	闯	幢	疮	怆",  // chuang
+    "落	罗	络	洛	逻	裸	螺	萝	骆	锣	骡	摞	箩	猡	珞	蠃	漯	瘰	荦	雒	镙	泺	椤	倮	脶",  // luo
+    "那	拿	哪	纳	娜	呐	捺	钠	衲	肭	镎",  // na
+    "明	名	命	鸣	铭	冥	茗	瞑	酩	暝	溟	螟",  // ming
+    "则	责	泽	择	啧	仄	帻	箦	赜	舴	迮	昃	笮",  // ze
+    "水	睡	税	氵",  // shui
+    "都	斗	抖	豆	逗	兜	陡	窦	蚪	痘	篼	蔸",  // dou
+    "流	留	六	刘	柳	溜	浏	榴	琉	硫	瘤	馏	遛	绺	熘	旒	骝	鎏	镏	鹨	锍",  // liu
+    "康	抗	炕	扛	慷	亢	糠	伉	钪	闶",  // kang
+    "内	馁",  // nei
+    "小	笑	消	校	效	晓	销	萧	肖	孝	嚣	潇	宵	啸	箫	逍	淆	硝	哮	霄	绡	枭	筱	骁	哓	枵	魈	崤",  // xiao
+    "将	讲	江	蒋	奖	降	匠	僵	疆	酱	姜	浆	桨	绛	缰	犟	耩	糨	豇	洚	茳	礓",  // jiang
+    "尬	嘎	呷	噶	旮	尕	钆	尜",  // ga
+    "偶	欧	呕	鸥	殴	藕	耦	怄	讴	瓯	沤",  // ou
+    "来	赖	莱	睐	籁	癞	徕	赉	涞	濑	崃	铼",  // lai
+    "贼",  // zei
+    "瑞	锐	蕊	睿	芮	蕤	蚋	枘",  // rui
+    "粗	促	醋	簇	猝	蹙	蹴	酢	殂	徂	蔟",  // cu
+    "怎	谮",  // zen
+    "多	朵	躲	夺	堕	踱	哆	咄	跺	惰	掇	剁	铎	垛	舵	裰	柁	哚	缍",  // duo
+    "去	区	取	趣	曲	驱	屈	趋	娶	躯	渠	觑	瞿	蛐	蛆	岖	黢	衢	祛	阒	癯	劬	龋	麴	蕖	蘧	磲	诎	蠼	氍	璩	朐	鸲",  // qu
+    "挂	瓜	刮	寡	褂	卦	聒	剐	鸹	胍	诖	栝",  // gua
+    "现	先	显	线	限	鲜	险	县	闲	仙	献	陷	嫌	羡	弦	宪	贤	纤	掀	咸	衔	娴	馅	涎	腺	舷	酰	藓	霰	冼	锨	苋	痫	暹	跹	籼	蚬	燹	祆	鹇	猃	跣	岘	氙	筅	莶",  // xian
+    "忙	茫	芒	盲	氓	莽	蟒	邙	硭	漭",  // mang
+    "娘	酿",  // niang
+    "白	百	摆	败	拜	柏	掰	稗	佰	擘	捭",  // bai
+    "脏	葬	赃	奘	臧	驵",  // zang
+    "宾	彬	滨	斌	鬓	缤	濒	殡	槟	膑	摈	傧	镔	豳	髌	玢",  // bin
+    "容	荣	融	蓉	熔	绒	溶	茸	戎	冗	榕	嵘	狨	蝾	肜",  // rong
+    "把	吧	八	巴	爸	罢	拔	霸	坝	叭	扒	疤	芭	跋	靶	笆	岜	耙	粑	灞	魃	钯	捌	鲅	菝	茇",  // ba
+    "还	海	孩	害	咳	骇	嗨	骸	亥	氦	醢	胲",  // hai
+    "人	认	任	忍	仁	刃	韧	纫	稔	壬	亻	葚	荏	饪	仞	妊	衽	轫",  // ren
+    "转	专	砖	赚	撰	篆	馔	啭	颛",  // zhuan
+    "红	轰	洪	宏	鸿	弘	哄	虹	烘	泓	闳	讧	訇	薨	黉	蕻	荭",  // hong
+    "设	社	射	舍	涉	舌	蛇	摄	奢	赦	慑	麝	赊	佘	歙	猞	畲	厍	滠",  // she
+    "肉	柔	揉	蹂	糅	鞣",  // rou
+    "剖	掊	裒",  // pou
+    "成	程	城	称	承	乘	诚	撑	呈	惩	澄	橙	丞	逞	秤	瞠	骋	晟	裎	塍	柽	蛏	埕	枨	铖	酲",  // cheng
+    "毛	猫	贸	冒	貌	帽	矛	茂	茅	髦	卯	锚	袤	懋	瑁	铆	峁	牦	昴	耄	泖	瞀	旄	蟊	茆	蝥",  // mao
+    "铁	贴	帖	餮	萜",  // tie
+    "之	知	只	直	指	至	制	治	支	质	置	志	止	致	值	执	纸	织	职	智	址	枝	旨	植	殖	芝	秩	脂	吱	稚	汁	肢	滞	侄	徵	掷	挚	帜	峙	窒	趾	蜘	芷	痣	炙	咫	祉	痔	栀	桎	祗	栉	雉	踯	郅	陟	贽	鸷	骘	卮	枳	胝	帙	蛭	豸	酯	摭	踬	彘	跖	忮	轾	黹	埴	絷	轵	觯	夂	膣",  // zhi
+    "能",  // neng
+    "公	工	共	功	攻	宫	供	贡	恭	弓	躬	拱	巩	龚	蚣	觥	汞	肱	廾	珙",  // gong
+    "略	掠	锊",  // lve
+    "擦	嚓	礤",  // ca
+    "超	朝	潮	吵	抄	嘲	炒	晁	钞	巢	焯	怊	耖",  // chao
+    "眼	言	研	验	严	演	烟	厌	颜	沿	延	掩	燕	艳	咽	炎	盐	焰	宴	岩	淹	衍	雁	檐	焉	阎	奄	砚	俨	嫣	筵	彦	湮	阉	妍	胭	蜒	菸	晏	魇	腌	闫	谚	恹	唁	焱	堰	酽	鼹	偃	滟	谳	赝	兖	芫	餍	鄢	琰	厣	讠	崦	罨	郾",  // yan
+    "是	时	事	实	十	使	世	式	始	失	似	师	视	识	示	市	士	石	史	室	试	诗	势	释	食	适	施	氏	湿	侍	拾	尸	饰	逝	驶	誓	匙	狮	屎	矢	拭	蚀	仕	嗜	噬	柿	恃	虱	轼	舐	弑	螫	谥	礻	豕	豉	蓍	鲥	饣	筮	铈	莳	鲺	埘	贳	炻",  // shi
+    "派	排	拍	牌	徘	湃	俳	蒎	哌",  // pai
+    "些	写	谢	协	鞋	斜	胁	歇	泄	邪	械	携	屑	卸	谐	蟹	泻	懈	挟	亵	邂	偕	榭	蝎	楔	撷	燮	廨	瀣	薤	勰	躞	缬	獬	绁	榍	渫",  // xie
+    "翁	嗡	瓮	蓊	蕹",  // weng
+    "所	索	缩	锁	琐	嗦	梭	唆	娑	蓑	唢	睃	羧	嗍	桫",  // suo
+    "让	嚷	壤	攘	瓤	禳	穰",  // rang
+    "送	松	宋	耸	诵	嵩	颂	讼	悚	怂	忪	淞	崧	竦	菘	凇",  // song
+    "模	默	麽	莫	摸	末	摩	魔	漠	墨	陌	寞	磨	抹	沫	膜	蓦	谟	摹	馍	蘑	茉	殁	秣	嫫	瘼	貊	镆	耱	貘",  // mo
+    "桌	捉	卓	灼	拙	浊	酌	啄	镯	茁	濯	倬	擢	斫	涿	诼	浞	禚",  // zhuo
+    "这	着	者	著	折	哲	遮	浙	辙	辄	褶	蛰	蔗	蜇	摺	赭	谪	鹧	柘	磔	锗",  // zhe
+    "配	陪	佩	培	赔	裴	呸	沛	胚	辔	霈	帔	旆	醅	锫",  // pei
+    "抓",  // zhua
+    "想	向	相	像	象	响	香	项	乡	享	箱	详	祥	厢	巷	翔	湘	镶	橡	襄	饷	芗	飨	骧	鲞	庠	缃	葙	蟓",  // xiang
+    "高	告	搞	稿	糕	膏	羔	镐	槁	皋	篙	睾	诰	杲	缟	郜	藁	槔	锆",  // gao
+    "作	做	坐	座	左	昨	佐	琢	祚	胙	怍	唑	阼",  // zuo
+    "走	奏	揍	邹	驺	陬	诹	鄹	楱	鲰",  // zou
+    "拽",  // zhuai
+    "比	必	笔	毕	避	闭	彼	鼻	壁	逼	臂	币	碧	鄙	蔽	弊	毙	庇	璧	陛	婢	匕	敝	痹	弼	哔	愎	篦	裨	俾	荸	妣	跸	蓖	吡	毖	秕	髀	襞	铋	荜	薜	筚	嬖	畀	狴	庳	箅	濞	萆	滗	舭",  // bi
+    "别	憋	瘪	蹩	鳖",  // bie
+    "华	话	化	花	画	划	滑	哗	猾	桦	铧	骅",  // hua
+    "俄	恶	额	饿	鹅	娥	鄂	厄	遏	愕	噩	呃	扼	蛾	鳄	峨	讹	婀	谔	屙	颚	萼	锷	垩	轭	莪	鹗	腭	阏	锇	苊",  // e
+    "解	结	接	界	姐	节	街	介	阶	借	杰	洁	戒	届	截	皆	揭	劫	捷	竭	诫	睫	孑	颉	婕	芥	嗟	桀	碣	秸	拮	讦	疥	羯	疖	喈	蚧	鲒	卩	骱",  // jie
+    "用	永	拥	勇	涌	雍	庸	泳	佣	咏	墉	慵	恿	甬	踊	臃	俑	喁	蛹	壅	镛	痈	邕	饔	鳙",  // yong
+    "如	入	辱	乳	儒	汝	茹	褥	蠕	嚅	濡	孺	缛	溽	铷	蓐	襦	颥	洳	薷",  // ru
+    "样	阳	央	洋	养	杨	扬	仰	羊	痒	氧	漾	佯	鸯	鞅	恙	殃	秧	怏	泱	徉	烊	疡	炀	蛘",  // yang
+    "哦	噢	喔",  // o
+    "特	忑	忒	慝	铽",  // te
+    "动	东	懂	洞	冬	董	冻	咚	栋	侗	恫	胴	峒	鸫	氡	硐	岽	胨	垌",  // dong
+    "国	过	果	锅	郭	裹	蝈	帼	虢	椁	馘	蜾	埚	崞	猓	呙",  // guo
+    "却	确	缺	雀	瘸	鹊	阙	榷	阕	悫",  // que
+    "关	管	观	官	馆	惯	贯	灌	冠	罐	棺	莞	倌	盥	掼	鳏	鹳	涫",  // guan
+    "并	兵	病	冰	饼	柄	禀	秉	丙	炳	摒	冫	邴",  // bing
+    "料	聊	疗	辽	僚	寥	撩	廖	撂	缭	燎	镣	嘹	寮	獠	蓼	尥	鹩	钌",  // liao
+    "梦	盟	猛	蒙	孟	朦	萌	懵	虻	檬	锰	蜢	勐	蠓	甍	艋	瞢	礞	艨",  // meng
+    "某	谋	眸	牟	缪	哞	侔	鍪	蛑",  // mou
+    "断	段	端	短	缎	锻	椴	煅	簖",  // duan
+    "顿	盾	敦	蹲	吨	沌	钝	墩	遁	炖	盹	囤	趸	礅	砘",  // dun
+    "判	盘	叛	盼	潘	攀	畔	拚	磐	蟠	爿	襻	泮	袢",  // pan
+    "脑	闹	恼	挠	瑙	淖	呶	孬	铙	猱	垴	蛲	硇",  // nao
+    "群	裙	逡",  // qun
+    "绕	扰	饶	娆	桡	荛",  // rao
+    "活	或	火	伙	获	货	惑	霍	祸	豁	夥	嚯	攉	镬	蠖	砉	藿	钬	劐	锪	耠",  // huo
+    "热	惹",  // re
+    "生	声	省	圣	胜	升	剩	盛	牲	绳	笙	甥	眚	嵊",  // sheng
+    "刚	港	钢	岗	冈	缸	纲	杠	罡	肛	戆	筻",  // gang
+    "产	缠	颤	禅	阐	搀	忏	铲	蝉	馋	潺	谄	婵	谗	蟾	羼	廛	澶	躔	冁	骣	觇	蒇	镡",  // chan
+    "因	音	引	印	银	隐	阴	尹	饮	吟	淫	姻	殷	荫	瘾	茵	寅	胤	垠	蚓	喑	氤	夤	洇	狺	鄞	霪	堙	铟	廴	茚	吲",  // yin
+    "山	善	闪	衫	删	扇	珊	陕	杉	擅	讪	煽	膳	跚	姗	鳝	潸	汕	赡	缮	嬗	舢	苫	膻	疝	骟	鄯	埏	蟮	钐	剡	芟	彡",  // shan
+    "炸	扎	眨	诈	札	栅	乍	喳	渣	闸	吒	楂	榨	蚱	咤	铡	柞	揸	哳	砟	痄	齄",  // zha
+    "诺	挪	懦	喏	傩	糯	搦	锘",  // nuo
+    "但	单	弹	淡	蛋	担	旦	胆	丹	诞	耽	惮	郸	氮	掸	眈	澹	啖	殚	聃	箪	疸	儋	萏	瘅	赕",  // dan
+    "类	泪	雷	勒	累	蕾	垒	磊	擂	儡	羸	镭	耒	嘞	诔	缧	酹	嫘	檑",  // lei
+    "破	婆	迫	颇	坡	泼	泊	魄	珀	叵	粕	鄱	笸	皤	钋	钷",  // po
+    "仍	扔",  // reng
+    "表	标	彪	镖	婊	飙	裱	膘	镳	髟	杓	骠	飚	鳔	飑	灬	瘭",  // biao
+    "卡	咖	喀	咔	佧	胩",  // ka
+    "文	问	温	闻	稳	吻	纹	汶	蚊	雯	瘟	紊	刎	玟	阌	璺",  // wen
+    "脸	连	联	恋	练	怜	莲	帘	廉	链	炼	敛	涟	镰	琏	殓	濂	潋	奁	裢	楝	鲢	裣	蔹	臁	蠊",  // lian
+    "窜	篡	蹿	撺	爨	汆	镩",  // cuan
+    "帮	邦	棒	膀	傍	绑	榜	镑	磅	谤	梆	浜	蚌	蒡",  // bang
+    "长	张	章	掌	丈	帐	仗	障	涨	胀	账	杖	彰	蟑	瘴	璋	漳	樟	獐	嶂	幛	仉	鄣	嫜",  // zhang
+    "令	领	另	灵	零	凌	龄	铃	玲	陵	岭	伶	聆	菱	绫	翎	羚	棂	呤	泠	囹	苓	瓴	蛉	柃	酃	鲮",  // ling
+    "太	台	态	抬	泰	胎	苔	汰	跆	邰	钛	肽	薹	炱	酞	鲐",  // tai
+    "飘	票	漂	瞟	瓢	嫖	剽	缥	嘌	殍	螵",  // piao
+    "兰	蓝	烂	懒	栏	拦	览	篮	岚	滥	揽	婪	澜	阑	榄	缆	斓	褴	谰	镧	漤	罱",  // lan
+    "捏	孽	涅	蹑	聂	嗫	臬	啮	镍	镊	颞	蘖	陧",  // nie
+    "满	慢	漫	曼	蛮	瞒	蔓	馒	蹒	幔	谩	鳗	螨	缦	熳	颟	墁	镘	鞔",  // man
+    "呢	讷	疒",  // ne
+    "进	今	金	近	尽	紧	仅	禁	津	劲	锦	巾	斤	谨	晋	筋	浸	襟	靳	矜	瑾	烬	噤	妗	觐	堇	缙	槿	衿	馑	卺	荩	钅	廑	赆",  // jin
+    "出	处	除	初	楚	触	础	厨	储	褚	畜	橱	躇	锄	搐	矗	雏	杵	黜	怵	刍	绌	蹰	滁	蜍	楮	憷	亍	樗",  // chu
+    "绰	戳	龊	辍	辶	踔",  // chuo
+    "反	返	饭	翻	犯	范	凡	烦	番	繁	泛	帆	贩	藩	梵	樊	幡	蕃	矾	畈	燔	钒	蘩	蹯",  // fan
+    "传	穿	船	川	串	喘	钏	椽	舛	遄	氚	舡	巛",  // chuan
+    "跑	炮	泡	抛	袍	刨	咆	庖	疱	狍	匏	脬",  // pao
+    "会	回	挥	灰	毁	慧	辉	悔	恢	汇	惠	绘	贿	徽	晦	讳	秽	诲	蕙	晖	诙	彗	麾	卉	烩	喙	荟	茴	蛔	咴	洄	哕	珲	隳	恚	虺	浍	蟪	缋",  // hui
+    "应	影	英	硬	营	迎	映	鹰	赢	盈	婴	萤	樱	莹	蝇	颖	瑛	莺	缨	嬴	罂	荧	颍	萦	鹦	嘤	膺	滢	瀛	楹	茔	郢	蓥	媵	潆	璎	撄	瘿",  // ying
+    "方	放	房	防	仿	访	芳	妨	坊	彷	纺	肪	舫	枋	鲂	匚	邡	钫",  // fang
+    "规	归	鬼	贵	跪	桂	柜	轨	瑰	龟	诡	闺	桧	圭	刽	硅	傀	皈	癸	鲑	晷	炔	刿	鳜	宄	簋	庋	匦	妫",  // gui
+    "家	加	假	价	架	甲	夹	驾	嫁	佳	嘉	贾	颊	稼	伽	茄	迦	枷	袈	戛	钾	荚	珈	岬	胛	葭	痂	浃	郏	铗	蛱	镓	恝	笳	跏	瘕",  // jia
+    "孙	损	隼	笋	狲	荪	榫	飧",  // sun
+    "同	统	通	痛	童	铜	筒	桶	桐	捅	瞳	彤	恸	佟	嗵	仝	潼	僮	酮	茼	砼",  // tong
+    "木	目	母	姆	幕	慕	穆	墓	牧	暮	亩	拇	沐	苜	牡	睦	募	钼	仫	坶	毪",  // mu
+    "暖",  // nuan
+    "论	轮	伦	沦	仑	抡	纶	囵",  // lun
+    "故	古	姑	顾	股	骨	孤	鼓	固	谷	估	咕	辜	雇	箍	呱	菇	蛊	汩	沽	鹘	锢	轱	梏	鹄	鸪	崮	痼	毂	牯	诂	觚	钴	菰	瞽	蛄	嘏	罟	臌	酤	鲴	牿",  // gu
+    "光	广	逛	咣	犷	胱	桄",  // guang
+    "望	往	王	网	忘	亡	汪	妄	旺	枉	惘	罔	魍	辋",  // wang
+    "婚	魂	昏	混	浑	荤	馄	诨	阍	溷",  // hun
+    "顺	瞬	舜	吮",  // shun
+    "怪	乖	拐	掴",  // guai
+    "我	握	窝	沃	卧	涡	蜗	斡	龌	倭	渥	挝	幄	莴	肟	硪",  // wo
+    "要	摇	药	腰	咬	耀	遥	妖	邀	钥	尧	姚	谣	窑	吆	瑶	幺	夭	肴	舀	杳	窈	鹞	曜	徭	繇	鳐	爻	崾	珧	轺",  // yao
+    "桑	丧	嗓	搡	颡	磉",  // sang
+    "经	精	京	静	竟	惊	睛	警	境	景	镜	净	敬	井	径	竞	晶	靖	菁	颈	荆	兢	阱	茎	憬	痉	迳	鲸	旌	靓	泾	儆	胫	粳	獍	弪	婧	肼	刭	腈",  // jing
+    "手	首	受	收	守	授	瘦	售	寿	兽	狩	扌	绶	艏",  // shou
+    "乱	卵	挛	峦	孪	鸾	栾	銮	娈	滦	脔",  // luan
+    "咱	赞	暂	攒	簪	糌	趱	瓒	錾	昝",  // zan
+    "组	足	族	祖	阻	租	卒	诅	俎	镞",  // zu
+    "皮	批	屁	疲	披	匹	啤	脾	劈	譬	辟	僻	痞	癖	噼	琵	霹	毗	坯	媲	砒	丕	睥	枇	纰	鼙	圮	罴	邳	貔	铍	疋	蚍	淠	仳	蜱	郫	擗	陴	埤	庀	甓	芘",  // pi
+    "非	飞	费	菲	废	啡	肥	匪	肺	沸	妃	斐	绯	扉	吠	霏	翡	诽	腓	蜚	悱	痱	榧	鲱	狒	淝	芾	镄	篚",  // fei
+    "到	道	导	倒	刀	岛	盗	稻	叨	祷	蹈	捣	悼	焘	纛	氘	刂	忉",  // dao
+    "点	电	店	典	殿	颠	垫	甸	奠	惦	淀	癫	掂	佃	巅	踮	玷	滇	钿	靛	碘	簟	癜	阽	坫",  // dian
+    "买	卖	麦	埋	脉	迈	霾	荬	劢",  // mai
+    "了	乐	肋	叻	泐	仂	鳓",  // le
+    "就	酒	久	九	究	旧	救	舅	纠	揪	疚	咎	啾	鸠	赳	韭	柩	臼	厩	鹫	玖	灸	阄	桕	鬏	僦",  // jiu
+    "完	万	晚	湾	玩	碗	弯	挽	顽	宛	腕	婉	丸	惋	蜿	皖	纨	豌	剜	绾	烷	琬	畹	脘	菀	芄",  // wan
+    "肯	恳	啃	垦	龈	裉",  // ken
+    "够	构	狗	勾	购	沟	钩	苟	垢	篝	媾	佝	诟	枸	彀	觏	遘	岣	缑	鞲	笱",  // gou
+    "扫	嫂	骚	搔	臊	缫	瘙	埽	鳋",  // sao
+    "欢	换	环	缓	幻	唤	患	焕	桓	痪	鬟	宦	寰	浣	涣	豢	奂	圜	獾	漶	缳	洹	逭	锾	郇	鲩	萑	擐",  // huan
+    "有	又	由	友	游	右	油	优	尤	忧	犹	幽	邮	悠	幼	诱	佑	黝	铀	呦	酉	攸	柚	鱿	釉	疣	囿	猷	莠	宥	蝣	鼬	莜	牖	蚰	侑	尢	蚴	莸	铕	卣",  // you
+    "凑	辏	腠",  // cou
+    "不	部	步	布	补	怖	捕	簿	埠	埔	哺	卟	钚	逋	瓿	晡	钸	醭",  // bu
+    "摘	寨	宅	窄	债	斋	砦	瘵",  // zhai
+    "困	昆	捆	坤	鲲	阃	悃	锟	琨	髡	醌",  // kun
+    "总	宗	纵	踪	综	棕	粽	鬃	偬	腙",  // zong
+    "兄	雄	胸	凶	熊	匈	汹",  // xiong
+    "林	临	邻	淋	琳	拎	鳞	凛	麟	霖	吝	躏	粼	磷	嶙	赁	辚	遴	檩	蔺	懔	廪	啉	瞵	膦",  // lin
+    "色	瑟	涩	啬	铯	穑",  // se
+    "柴	拆	钗	豺	侪	虿	瘥",  // chai
+    "老	劳	牢	捞	姥	佬	唠	潦	烙	酪	崂	涝	痨	醪	栳	铑	铹	耢",  // lao
+    "追	坠	缀	锥	赘	惴	骓	隹	缒",  // zhui
+    "否	缶",  // fou
+    "正	政	整	证	争	征	睁	郑	挣	症	怔	蒸	拯	筝	峥	铮	帧	狰	诤	钲",  // zheng
+    "在	再	载	灾	宰	栽	哉	崽	甾",  // zai
+    "外	歪	崴",  // wai
+    "杀	沙	傻	莎	厦	啥	刹	纱	煞	砂	霎	鲨	裟	痧	铩	歃	唼",  // sha
+    "黑	嘿",  // hei
+    "修	秀	休	袖	羞	绣	嗅	朽	锈	庥	咻	馐	溴	岫	貅	髹	鸺",  // xiu
+    "开	凯	慨	揩	恺	楷	铠	忾	锴	剀	蒈	垲	锎",  // kai
+    "亚	呀	牙	雅	压	丫	哑	押	衙	讶	涯	鸦	鸭	崖	娅	芽	轧	吖	桠	蚜	伢	琊	睚	迓	揠	氩	砑	垭	岈	痖",  // ya
+    "性	行	星	形	幸	型	兴	醒	姓	刑	杏	腥	邢	猩	惺	悻	擤	荇	荥	陉	硎",  // xing
+    "弄	农	浓	哝	脓	侬",  // nong
+    "岑	涔",  // cen
+    "宽	款	髋",  // kuan
+    "朋	碰	鹏	捧	蓬	彭	棚	篷	膨	砰	澎	怦	抨	烹	嘭	硼	堋	蟛",  // peng
+    "讨	套	逃	涛	桃	掏	陶	萄	滔	淘	啕	韬	绦	饕	洮	鼗",  // tao
+    "好	号	毫	豪	耗	浩	郝	嚎	皓	昊	壕	蒿	嗥	濠	薅	颢	蚝	灏	貉	嚆",  // hao
+    "恩	摁	蒽",  // en
+    "谈	坦	探	叹	坛	贪	摊	滩	炭	毯	谭	潭	瘫	碳	痰	檀	坍	袒	忐	覃	昙	郯	钽	锬",  // tan
+    "藏	苍	仓	舱	沧	伧",  // cang
+    "己	机	几	记	及	基	计	即	际	极	级	击	集	纪	急	技	济	继	激	既	辑	积	吉	迹	鸡	寂	寄	季	挤	籍	绩	疾	忌	妓	祭	饥	肌	圾	藉	剂	稽	脊	嫉	畸	叽	姬	棘	缉	讥	冀	唧	羁	矶	悸	髻	汲	伎	嵇	稷	戟	箕	偈	骥	暨	诘	霁	岌	瘠	屐	犄	跻	荠	亟	觊	蓟	咭	鲫	麂	楫	畿	芨	乩	玑	赍	笈	蒺	齑	殛	笄	佶	虮	芰	哜	蕺	洎	戢	跽	剞	墼	掎	彐	丌	嵴	鲚",  // ji
+    "书	数	术	树	述	属	束	熟	输	舒	殊	叔	鼠	署	疏	梳	恕	竖	暑	淑	抒	曙	墅	赎	薯	漱	蔬	倏	枢	庶	孰	蜀	塾	殳	戍	姝	黍	秫	澍	纾	菽	沭	摅	毹	腧",  // shu
+    "杂	砸	咋	咂	匝	拶",  // za
+    "起	其	气	期	器	七	奇	齐	妻	启	企	弃	汽	旗	骑	欺	岂	泣	棋	漆	凄	戚	歧	契	乞	祈	崎	琪	琦	迄	栖	砌	麒	祁	祺	淇	绮	脐	憩	蹊	沏	綦	嘁	亓	鳍	俟	杞	颀	岐	讫	萋	柒	葺	畦	耆	骐	屺	蕲	萁	槭	碛	蛴	圻	芪	綮	汔	芑	桤	蜞",  // qi
+    "哟	唷",  // yo
+    "自	子	字	资	紫	姿	仔	滋	兹	姊	籽	咨	孜	渍	恣	髭	龇	梓	滓	谘	辎	眦	孳	笫	锱	淄	秭	觜	缁	訾	趑	赀	粢	嵫	耔	鲻",  // zi
+    "主	住	注	助	朱	逐	珠	猪	驻	竹	诸	祝	筑	柱	烛	煮	嘱	株	蛛	铸	瞩	伫	诛	贮	侏	拄	箸	蛀	竺	丶	洙	躅	茱	炷	渚	槠	铢	杼	翥	苎	麈	潴	橥	舳	邾	疰	瘃",  // zhu
+    "谬",  // miu
+    "虐	疟",  // nve
+    "此	次	词	刺	辞	磁	慈	赐	伺	茨	瓷	雌	祠	疵	呲	糍	鹚	茈",  // ci
+    "发	法	乏	罚	伐	阀	筏	砝	珐	垡",  // fa
+    "学	雪	血	薛	削	穴	靴	谑	踅	鳕	泶",  // xue
+    "滚	棍	衮	鲧	辊	磙	绲	丨",  // gun
+    "润	闰",  // run
+    "站	战	展	占	沾	盏	粘	斩	绽	栈	毡	詹	湛	瞻	崭	蘸	谵	旃	搌",  // zhan
+    "诶",  // ei
+    "随	虽	岁	碎	遂	隧	髓	祟	绥	穗	邃	隋	燧	睢	荽	谇	濉	眭",  // sui
+    "律	虑	绿	旅	率	吕	履	驴	缕	侣	屡	滤	褛	捋	铝	榈	闾	氯	膂	稆",  // lv
+    "搜	艘	嗽	嗖	飕	擞	叟	馊	薮	嗾	溲	瞍	锼	螋",  // sou
+    "安	案	暗	按	岸	俺	黯	庵	鞍	谙	氨	胺	鹌	桉	犴	揞	铵	埯",  // an
+    "恒	横	衡	哼	亨	蘅	桁",  // heng
+    "嗯",  // n
+    "寻	讯	迅	训	巡	询	循	勋	逊	熏	旬	殉	驯	醺	薰	汛	峋	徇	浔	埙	荀	巽	鲟	荨	洵	窨	蕈	曛	恂	獯",  // xun
+    "们	门	闷	懑	扪	焖	钔",  // men
+    "无	物	五	务	武	午	屋	舞	误	吴	悟	乌	伍	雾	污	呜	吾	巫	捂	梧	勿	晤	侮	兀	妩	芜	唔	毋	邬	诬	坞	鹉	戊	杌	蜈	骛	鹜	仵	忤	焐	怃	钨	牾	寤	庑	婺	痦	浯	阢	鼯	圬	迕	芴	鋈",  // wu
+    "抽	丑	仇	臭	愁	筹	酬	绸	瞅	畴	踌	稠	惆	俦	雠	瘳	帱",  // chou
+    "冲	充	虫	崇	宠	憧	忡	铳	舂	茺	艟",  // chong
+    "然	染	燃	冉	髯	苒	蚺",  // ran
+    "觉	决	绝	爵	嚼	掘	诀	倔	撅	厥	孓	崛	攫	抉	獗	谲	蹶	噘	珏	噱	矍	蕨	镢	橛	桷	劂	爝	觖",  // jue
+    "刷	耍	唰",  // shua
+    "民	敏	悯	抿	闽	泯	皿	闵	岷	珉	愍	缗	鳘	苠",  // min
+    "丢	铥",  // diu
+    "瓦	娃	挖	哇	蛙	袜	洼	娲	佤	腽",  // wa
+    "半	办	般	版	班	板	伴	搬	扮	斑	瓣	颁	坂	阪	绊	扳	拌	瘢	癍	钣	舨",  // ban
+    "定	顶	丁	盯	订	钉	叮	鼎	锭	酊	仃	啶	腚	铤	玎	碇	疔	耵",  // ding
+    "周	州	洲	宙	皱	舟	骤	咒	昼	粥	轴	肘	帚	绉	纣	诌	胄	妯	籀	荮	酎",  // zhou
+    "诉	速	苏	素	宿	俗	肃	塑	粟	稣	酥	簌	溯	夙	愫	谡	蔌	嗉	觫	涑	僳",  // su
+    "服	夫	父	复	府	福	佛	副	妇	负	富	符	附	付	浮	傅	伏	抚	幅	腐	扶	肤	弗	覆	赴	腹	咐	赋	辅	拂	俯	甫	俘	斧	辐	缚	芙	敷	袱	釜	绂	腑	孵	蝠	阜	驸	氟	孚	匐	馥	讣	呒	呋	凫	蜉	拊	怫	趺	赙	涪	茯	桴	蚨	黼	鲋	苻	幞	稃	麸	罘	跗	祓	蝮	黻	阝	郛	莩	菔	滏	绋	砩	艴	鳆",  // fu
+    "装	状	庄	撞	壮	妆	桩",  // zhuang
+    "常	场	唱	厂	昌	尝	畅	肠	偿	敞	倡	怅	娼	嫦	猖	徜	氅	伥	菖	阊	惝	苌	鲳	鬯	昶",  // chang
+    "萨	洒	撒	飒	仨	挲	卅	脎",  // sa
+    "俩",  // lia
+    "的	地	得	德	锝",  // de
+    "跟	根	亘	艮	哏	茛",  // gen
+    "上	伤	商	尚	赏	裳	晌	熵	觞	殇	墒	垧	绱",  // shang
+    "鸟	尿	袅	茑	嬲	脲",  // niao
+    "快	块	筷	侩	脍	哙	狯	蒯	郐",  // kuai
+    "团	湍	抟	疃	彖",  // tuan
+    "本	奔	笨	贲	苯	锛	畚	坌",  // ben
+    "爹	跌	叠	蝶	碟	迭	谍	喋	牒	嗲	堞	耋	蹀	鲽	垤	瓞	揲",  // die
+    "你	尼	泥	拟	逆	妮	腻	匿	溺	昵	倪	霓	旎	睨	怩	猊	铌	鲵	伲	坭",  // ni
+    "括	扩	阔	廓	蛞",  // kuo
+    "早	造	遭	糟	澡	躁	燥	灶	皂	枣	噪	凿	蚤	藻	唣",  // zao
+    "草	操	曹	糙	槽	嘈	漕	艹	螬	艚",  // cao
+    "篇	片	偏	骗	翩	蹁	骈	胼	谝	犏",  // pian
+    "恰	掐	洽	袷	髂	葜",  // qia
+    "中	种	重	终	众	钟	忠	衷	肿	仲	锺	盅	踵	冢	螽	舯",  // zhong
+    "选	宣	旋	悬	玄	喧	轩	眩	炫	暄	绚	萱	漩	渲	璇	癣	铉	揎	煊	泫	儇	镟	楦	谖	碹	痃",  // xuan
+    "两	量	亮	良	梁	凉	辆	粮	谅	粱	踉	晾	魉	椋	墚",  // liang
+    "您",  // nin
+    "第	底	弟	低	帝	敌	蒂	递	抵	迪	滴	堤	笛	娣	狄	嘀	邸	缔	谛	翟	涤	嫡	棣	诋	睇	荻	砥	氐	柢	籴	觌	镝	骶	羝	碲",  // di
+    "说	烁	硕	朔	铄	搠	妁	槊	蒴",  // shuo
+    "听	停	庭	挺	厅	廷	亭	艇	婷	汀	霆	蜓	町	葶	烃	梃	莛",  // ting
+    "囊	囔	攮	曩	馕",  // nang
+    "四	死	斯	思	司	丝	私	寺	撕	肆	厮	嘶	嗣	泗	饲	祀	巳	咝	蛳	驷	鸶	纟	笥	汜	澌	姒	缌	锶	兕	耜	厶",  // si
+    "下	夏	吓	峡	霞	瞎	狭	侠	虾	辖	匣	暇	遐	黠	瑕	狎	罅	柙	硖",  // xia
+    "崩	蹦	绷	甭	迸	嘣	泵	甏",  // beng
+    "吹	垂	锤	捶	炊	椎	槌	陲	棰",  // chui
+    "报	包	保	抱	宝	暴	爆	胞	薄	饱	堡	鲍	豹	苞	鸨	褒	葆	雹	孢	煲	褓	龅	趵	勹",  // bao
+    "西	系	喜	息	希	细	习	席	戏	吸	洗	惜	析	袭	悉	熙	稀	嘻	夕	牺	晰	膝	媳	兮	溪	昔	熄	锡	隙	嬉	皙	犀	唏	禧	玺	奚	曦	淅	蟋	徙	熹	羲	翕	汐	蜥	檄	烯	樨	矽	屣	铣	僖	欷	郗	葸	硒	浠	螅	阋	觋	隰	舄	禊	饩	穸	粞	鼷	菥	蓰	舾	醯",  // xi
+];
+
+// Frequency weights for the choices in ZI, in the same order
+pub static ZI_WEIGHT: &[&[u32]] = &[
+    &[16123, 7670, 243, 5],  // ruo
+    &[10910, 3681, 2244, 1727, 685, 81, 0],  // niu
+    &[60339, 11023, 8409, 7464, 6839, 5559, 4847, 3302, 1884, 1637, 554],  // cai
+    &[180589, 26904, 6428, 586, 22, 17, 1, 0],  // dui
+    &[108106, 28880, 18692, 5639, 4508, 4385, 3582, 3309, 2499, 2440, 2195, 1659, 1599, 1059, 866, 735, 691, 611, 524, 208, 117, 63, 51, 50, 46, 42, 39, 38, 38, 37, 29, 24, 16, 13, 12, 11, 5, 3, 2, 1, 0, 0, 0],  // qian
+    &[13419, 7888, 6206, 3762, 2688, 1764, 1691, 1343, 1035, 971, 815, 759, 198, 131, 44, 21, 12, 7, 6, 3, 2, 0, 0, 0, 0, 0],  // tang
+    &[27165, 14746, 13071, 12591, 12340, 11829, 8709, 7595, 5460, 4475, 2746, 1391, 1241, 910, 594, 511, 482, 452, 382, 358, 251, 241, 119, 106, 97, 48, 47, 45, 43, 40, 37, 15, 14, 10, 9, 8, 8, 8, 1, 0, 0, 0, 0],  // hu
+    &[16023, 10446, 700, 576, 431, 163],  // huai
+    &[60559, 43188, 40000, 17914, 7908, 4154, 2021, 1527, 1393, 998, 609, 365, 240, 188, 68, 59, 38, 16, 7, 6, 4, 4, 4, 1, 1, 0],  // bian
+    &[143329, 19634, 423, 355, 345, 330, 296, 266, 239, 144, 37, 24, 19, 1],  // nian
+    &[1037, 937, 1, 1, 0],  // pie
+    &[76718, 15908, 11415, 5455, 21],  // zui
+    &[1955],  // seng
+    &[18296, 14867, 10000, 8633, 5183, 1649, 1122, 663, 328, 182, 139, 123, 112, 44, 40, 16, 9, 5, 5, 3, 1],  // cha
+    &[8917, 7252, 4945, 4330, 2319, 436, 377, 248, 128, 20, 10, 4, 1, 0],  // lang
+    &[178513, 82681, 36289, 9809, 6911, 6057, 3861, 2403, 2293, 2006, 1970, 1495, 1422, 1126, 753, 285, 181, 169, 144, 58, 48, 43, 10, 7, 0, 0],  // mei
+    &[6386, 5793, 5328, 146, 42, 10, 1],  // nu
+    &[9416, 4258, 869, 323, 245, 188, 186, 97, 2, 1],  // ning
+    &[20000, 12219, 568, 92],  // ceng
+    &[21330, 3712, 756, 750, 432, 408, 59, 59, 25, 11, 7, 3, 3, 2],  // cuo
+    &[9940, 8334, 4590, 2069, 973, 906, 814, 407, 395, 343, 96, 83, 54, 52, 49, 48, 36, 9, 7, 5, 2, 1, 0],  // tuo
+    &[482020, 254462, 69113, 7033, 3524, 1777, 1373, 587, 274, 128, 109, 70, 54, 17, 4, 3, 2],  // ta
+    &[200000, 50542, 24672, 22153, 17426, 16886, 15513, 14884, 12503, 12079, 8760, 8006, 7026, 6788, 5211, 4026, 3973, 3414, 3222, 3169, 2871, 2610, 1968, 1676, 1617, 1574, 616, 579, 515, 448, 448, 297, 295, 274, 258, 256, 209, 163, 153, 151, 105, 99, 80, 65, 58, 58, 46, 37, 31, 20, 15, 11, 8, 5, 4, 3, 1, 0, 0],  // wei
+    &[41508, 26700, 5950, 3555, 3199, 3117, 2738, 2671, 2561, 2175, 1430, 1427, 678, 644, 490, 427, 346, 173, 80, 76, 60, 60, 55, 40, 35, 28, 22, 18, 17, 15, 12, 12, 8, 4, 3, 2, 2],  // chi
+    &[60751, 5192, 4290, 4070, 2599, 2473, 2438, 1811, 1684, 576, 507, 429, 242, 228, 222, 201, 187, 150, 11, 3, 0, 0],  // ai
+    &[59930, 8370, 5393, 3178, 1272, 1047, 715, 516, 472, 118, 106, 17, 10, 7],  // jun
+    &[10860, 4354, 4032, 1659, 1639, 1557, 1525, 1518, 1123, 693, 555, 490, 401, 329, 131, 116, 75, 71, 54, 43, 26, 3, 2, 1, 1, 0],  // pu
+    &[2043, 2008, 1932, 1588, 145],  // shuai
+    &[40000, 7833, 6018, 4454, 1688, 837, 658, 405, 273, 228, 75, 39, 17, 10, 2, 2, 1],  // shao
+    &[23037, 172, 20, 2],  // zhun
+    &[6070, 1150, 356, 42, 12, 10, 7, 5, 2, 1],  // qiong
+    &[162876, 13499, 3534, 2482, 1789, 672, 376, 307, 41, 24, 22, 4, 0],  // tian
+    &[83481, 10000, 1558, 578, 16, 11, 8],  // san
+    &[20546, 14921, 4801, 4026, 2551, 2082, 1807, 1492, 1388, 1223, 546, 337, 303, 297, 250, 171, 133, 84, 71, 46, 45, 33, 12, 0, 0],  // huang
+    &[9521, 8137, 4377, 2601, 1676, 166],  // ce
+    &[1057155, 219424, 94844, 88848, 37599, 26946, 22599, 17441, 16332, 14354, 13589, 12819, 10558, 9356, 9274, 8628, 8198, 8049, 7277, 5916, 4825, 4249, 3833, 3703, 3602, 3007, 2928, 2482, 1742, 1502, 1319, 1266, 1236, 1234, 1178, 1174, 1153, 1098, 1058, 743, 688, 599, 539, 512, 472, 369, 307, 274, 246, 226, 223, 217, 216, 209, 181, 180, 179, 173, 145, 137, 131, 129, 119, 114, 104, 94, 93, 82, 78, 65, 62, 43, 42, 42, 26, 24, 20, 20, 19, 16, 14, 13, 13, 11, 11, 10, 9, 9, 6, 5, 4, 4, 3, 3, 2, 2, 2, 1, 1, 1, 0, 0, 0],  // yi
+    &[5883, 2131, 124, 24, 5],  // zun
+    &[40710, 21635, 20152, 9542, 8582, 7369, 7255, 4019, 774, 706, 487, 13, 3, 0],  // ma
+    &[18729, 2558, 817, 514, 7],  // leng
+    &[122851, 9401, 3454, 2177, 2073, 1784, 793, 269, 235, 118, 89, 35, 14, 2, 2, 0],  // mian
+    &[57116, 2792, 1138, 528, 237, 39, 3, 2, 1],  // kou
+    &[39645, 6000, 2375, 2123, 1961, 1287, 1031, 192, 143, 52, 11, 1],  // la
+    &[46173, 37798, 10000, 8788, 8197, 6761, 1418, 1411, 926, 820, 674, 403, 256, 121, 34, 29, 22, 2, 1, 1, 1],  // dai
+    &[18887, 2138, 1759, 1112, 784, 193, 181, 154, 88, 75, 25, 24, 4, 2, 1],  // lou
+    &[820, 408, 271, 69, 1, 0],  // chuai
+    &[12215, 9780, 8824, 6517, 6315, 6305, 6079, 2251, 1916, 1392, 1077, 655, 571, 544, 428, 420, 324, 296, 241, 189, 171, 141, 71, 27, 13, 11, 8, 7, 6, 1],  // han
+    &[4534, 2458, 1035, 877, 726, 683, 254, 208, 132, 84, 27, 21, 1, 1, 0],  // juan
+    &[167717, 137159, 63424, 4848, 4311, 1543, 912, 588, 449, 119, 77, 51, 40, 28, 13, 7],  // xin
+    &[159552, 53054, 13630, 6193, 2469, 2342, 1593, 1561, 348, 14, 8, 3, 2, 2, 1],  // hou
+    &[206659],  // me
+    &[34780, 20153, 1565, 1494, 459, 235, 61, 32, 28, 4],  // qie
+    &[2651, 2227, 1],  // pen
+    &[1769, 736, 183],  // ang
+    &[50640, 44443, 10000, 9863, 8550, 4437, 2428, 1245, 1158, 1061, 816, 790, 639, 632, 407, 391, 364, 339, 269, 257, 185, 165, 137, 123, 96, 46, 42, 37, 33, 33, 27, 11, 10, 8, 6, 4, 3, 2, 1, 1, 0],  // lu
+    &[200000, 55004, 42225, 16438, 16000, 11000, 8821, 3966, 3362, 2981, 2376, 2024, 1184, 754, 706, 311, 247, 167, 127, 124, 96, 72, 26, 24, 14, 9, 6],  // he
+    &[64588, 25322, 23902, 3630, 3280, 2634, 2269, 2129, 256, 41, 9, 5, 4, 1],  // yue
+    &[23237, 16566, 60, 7],  // a
+    &[35538, 23178, 9542, 8112, 4487, 2384, 1562, 1074, 957, 640, 565, 404, 340, 42, 30, 10],  // zhao
+    &[81607, 12, 6, 1],  // nv
+    &[45892, 1103, 858, 777, 650, 493, 326, 166, 58, 18, 5],  // geng
+    &[6367, 6168, 2614, 2536, 1627, 914, 691, 155, 148, 86, 43, 22, 13, 7, 0],  // miao
+    &[20920, 2153, 1096, 116],  // shuang
+    &[2572, 514, 404, 352, 177, 10, 6],  // tun
+    &[17290, 15475, 2510, 1896, 1034, 444, 396, 376, 147, 117, 11, 6, 2],  // diao
+    &[100540, 93676, 74567, 29092, 16976, 13430, 12105, 7372, 6993, 6640, 6473, 5600, 5040, 4689, 4656, 3897, 2930, 2841, 2496, 2494, 2222, 2058, 1966, 1475, 1368, 1306, 1134, 880, 817, 737, 337, 334, 309, 280, 263, 225, 201, 180, 175, 126, 92, 82, 68, 61, 42, 41, 38, 35, 30, 25, 25, 17, 10, 8, 5, 5, 4, 3, 3, 3, 3, 2, 1, 1, 1, 0, 0, 0],  // jian
+    &[1444, 457],  // nen
+    &[30000, 12736, 9260, 5802, 21, 13, 2],  // kong
+    &[1391, 410, 1],  // shai
+    &[33630, 33054, 32333, 1964, 830, 103, 58, 15, 0],  // nan
+    &[33194, 3534, 395, 8],  // suan
+    &[17841, 9685, 7436, 869, 593, 175, 2],  // tui
+    &[32894, 15678, 4447, 558, 386, 132, 94, 44, 14, 9, 8, 5, 2, 1],  // tiao
+    &[110169, 11345, 10883, 7846, 204, 3, 1],  // tou
+    &[13224, 953, 5],  // ruan
+    &[122757, 80229, 27620, 16090, 13397, 11885, 10886, 10842, 10638, 10213, 8723, 8636, 7908, 6403, 4410, 4395, 3897, 3438, 3219, 2490, 2423, 2337, 2199, 1854, 1848, 1822, 1530, 1308, 1181, 946, 886, 615, 606, 512, 465, 418, 397, 391, 347, 300, 284, 282, 246, 227, 222, 207, 185, 178, 150, 143, 137, 117, 81, 75, 73, 72, 68, 60, 52, 47, 46, 44, 43, 43, 38, 34, 32, 22, 17, 17, 16, 15, 14, 13, 11, 6, 6, 5, 5, 4, 4, 3, 3, 2, 1, 1, 0, 0, 0],  // yu
+    &[21194, 11555, 1161, 979, 222, 52, 9, 7],  // kao
+    &[12962, 9675, 6173, 4111, 4019, 3564, 3252, 3061, 2493, 2067, 1771, 1669, 880, 328, 260, 256, 244, 139, 137, 107, 89, 68, 63, 53, 50, 27, 21, 14, 3, 0],  // bo
+    &[22303, 21617, 19546, 5637, 5337, 3849, 2403, 1784, 1708, 1263, 598, 123, 23, 5, 4, 2],  // tu
+    &[100000, 83565, 41927, 29964, 18832, 7982, 7934, 4594, 2857, 1832, 906, 804, 766, 539, 239, 149, 109, 101, 36, 36, 29, 26, 4, 2, 1, 0],  // shen
+    &[61820, 12003, 10328, 4511, 2618, 1498, 629, 158, 29, 24, 8, 2, 0],  // deng
+    &[6510, 4395, 664, 38, 7],  // sai
+    &[27291, 12767, 2050, 381, 22],  // cun
+    &[14489, 775, 10],  // ha
+    &[61968, 30000, 15026, 11233, 3302, 2139, 1369, 1006, 766, 491, 388, 144, 80, 65, 65, 63, 27, 26, 25, 13, 6, 5, 2, 0],  // gan
+    &[24166, 16309, 5277, 2355, 1442, 1231, 1131, 1060, 1027, 747, 394, 272, 220, 211, 158, 128, 122, 92, 82, 57, 24, 15, 7, 6],  // yun
+    &[16954, 7789, 2876, 1737, 1155, 812, 508, 342, 160, 45, 37, 32, 9, 6, 6, 3],  // kuang
+    &[168582, 95388, 34503, 30701, 22171, 11024, 4886, 2330, 1997, 623, 623, 571, 184, 102, 84, 69, 28, 13, 7, 6, 1, 0, 0],  // qing
+    &[20000],  // shei
+    &[1422, 977, 172],  // keng
+    &[2826, 2085, 1494, 1363, 838, 824, 637, 612, 406, 239, 220, 111, 96, 38, 37, 37, 34, 33, 24, 17, 15, 11, 6, 4, 3, 1, 0],  // kui
+    &[20558, 10795, 4519, 1896, 1619, 1389, 185, 151, 24, 23, 21, 3, 2],  // lie
+    &[42726, 19062, 18372, 6025, 4275, 3674, 3433, 3175, 2256, 1575, 1349, 1188, 1133, 552, 359, 205, 147, 69, 35, 28, 17, 10, 5, 0],  // du
+    &[4353, 3881, 1281, 213, 99],  // teng
+    &[8735, 1045, 88, 50, 25, 5, 0],  // hang
+    &[21704, 12809, 7240, 4322, 2748, 2359, 483, 175, 49, 41, 14, 5],  // ku
+    &[293661, 71659, 27728, 11599, 4005, 551, 547, 341, 246, 238, 65, 65, 56, 36, 9, 2],  // da
+    &[531, 527, 195, 3, 3, 0],  // jiong
+    &[73464, 37933, 22206, 10000, 9694, 9131, 7456, 4746, 3053, 2147, 1235, 989, 536, 519, 389, 259, 64, 35, 34, 29, 7, 2, 1, 1, 0, 0],  // bei
+    &[13656, 7742, 4155, 1929, 293, 283, 136, 62, 37, 1],  // chun
+    &[171256, 3984, 2778, 1871, 1008, 839, 514, 375, 159, 156, 19, 0],  // kan
+    &[20000, 5877, 5651, 3679, 3325, 779, 430, 397, 102, 93, 65, 5, 0],  // can
+    &[73993],  // gei
+    &[28137, 17401, 8290, 1180, 1000, 777, 676, 258, 210, 99, 95, 91, 65, 64, 38, 17, 7, 6, 6, 0, 0, 0],  // qiu
+    &[12578, 3437, 2262, 291, 231, 68, 6, 5],  // pang
+    &[52023, 44787, 38089, 37864, 27351, 19019, 18327, 14042, 6796, 6758, 4382, 3815, 1422, 1163, 1087, 449, 424, 341, 321, 192, 145, 99, 56, 17, 12, 3, 3, 3, 2, 2, 0, 0, 0],  // yuan
+    &[47000, 21392, 17771, 15852, 14596, 10025, 5415, 4133, 2796, 2005, 1446, 1228, 1194, 908, 867, 616, 574, 434, 433, 424, 372, 337, 192, 127, 114, 31, 14, 4, 3, 1, 0],  // xu
+    &[440, 314, 224, 127],  // shuan
+    &[243333, 61007, 34167, 27317, 18213, 12039, 10366, 3052, 2567, 464, 334, 287, 266, 236, 195, 109, 109, 100, 99, 68, 15, 0],  // ye
+    &[67903, 20004, 5374, 3969, 3362, 3085, 1296, 847, 708, 476, 215, 185, 126, 94, 63, 38, 36, 13, 6, 2, 0, 0],  // quan
+    &[45614, 4737, 3339, 3184, 559, 175, 17, 2, 0],  // che
+    &[8357, 3629, 3259, 1473, 1207, 550, 537, 465, 464, 456, 197, 96, 95, 78, 76, 69, 46, 29, 19, 11, 7, 6, 3],  // ao
+    &[109440],  // ri
+    &[6415, 1065, 95, 83, 61, 0],  // mie
+    &[19301, 3221, 2901, 2758, 724, 112, 99, 75, 59, 0],  // pin
+    &[2252, 474, 164, 22, 6],  // zuan
+    &[2659, 2220, 946, 304, 151, 141],  // kua
+    &[7515, 4512, 3870, 3359, 217, 20, 3, 3, 2],  // nai
+    &[50089, 23077, 6446, 6000, 2411, 217, 213, 78, 12, 10, 2, 1],  // dang
+    &[108110, 7125, 4501, 3054],  // hen
+    &[18753, 14195, 7213, 7150, 6769, 2220, 2051, 2027, 378, 272, 198, 73, 62, 54, 28, 17, 15, 3, 1],  // chen
+    &[89462, 56893, 51207, 7730, 3095, 2290, 881, 813, 768, 723, 711, 657, 631, 225, 135, 55, 39, 15, 14, 8, 6, 4, 4, 4],  // ti
+    &[51666, 9483, 5809, 4374, 2517, 2218, 1193, 864, 304, 58, 10, 1, 1],  // ping
+    &[39509, 10636, 4794, 4585, 4392, 3405, 3336, 2637, 2295, 2063, 2000, 1594, 1568, 1464, 187, 131, 20, 6, 1, 1, 0],  // feng
+    &[12202, 8112, 1807, 1145, 1129, 804, 502, 452, 322, 214, 163, 36, 23, 14, 6, 1, 0],  // long
+    &[71231, 12400, 7104, 5898, 4936, 4631, 4592, 3414, 2886, 1693, 1494, 930, 786, 784, 366, 363, 196, 177, 103, 76, 71, 70, 54, 50, 33, 29, 9, 7, 5, 3, 0],  // zhen
+    &[15],  // nou
+    &[3429, 2728, 1896, 1602, 1066, 1029, 579, 268, 216, 130, 104, 39, 20, 1],  // cui
+    &[16120, 14025, 9640, 8531, 2507, 2205, 1583, 1183, 1117, 677, 427, 332, 273, 236, 146, 74, 58, 52, 41, 32, 32, 31, 19, 17, 15, 14, 8, 6, 5, 2],  // mi
+    &[147221, 95889, 65456, 39941, 10778, 148, 55, 45, 41, 38, 5, 1, 0, 0],  // er
+    &[29000, 10386, 8863, 4819, 1864, 454, 439, 404, 193, 84, 53, 39, 32, 22, 14, 13, 13, 6, 2, 1, 0],  // qiang
+    &[103413, 5125, 4511, 2629, 880, 214, 78, 55, 49, 15, 1, 1],  // cong
+    &[423143, 29915, 24697, 19796, 15825, 14162, 6682, 3115, 2996, 2268, 2236, 1611, 1568, 1399, 1354, 558, 332, 166, 132, 68, 26, 21, 20, 18, 13, 5, 4, 2, 2, 2, 1, 0],  // ge
+    &[80000, 15031, 6037, 5436, 4439, 4164, 2575, 2562, 1632, 1438, 949, 794, 393, 104, 52, 49, 3, 2, 0, 0],  // fen
+    &[6764],  // sen
+    &[43341, 4606, 4440, 4313, 2942, 1619, 1262, 585, 513, 361, 332, 167, 86, 43, 6, 5, 3, 2, 2, 0, 0],  // qin
+    &[45520, 30485, 29537, 18593, 14286, 14241, 3497, 2320, 1949, 1799, 1360, 1195, 1157, 784, 740, 699, 628, 609, 488, 467, 454, 443, 326, 242, 237, 226, 196, 182, 125, 99, 86, 86, 77, 57, 31, 6, 5, 4, 4, 3, 2, 1, 0, 0],  // jiao
+    &[24670, 5113, 2626, 1486, 1202, 229, 53, 28, 4],  // pa
+    &[25548, 23371, 18621, 18101, 16322, 12596, 7739, 7375, 5441, 5137, 4669, 3118, 2255, 2049, 1753, 1601, 997, 708, 564, 523, 378, 375, 324, 314, 211, 179, 155, 151, 136, 101, 89, 70, 65, 63, 47, 47, 30, 29, 22, 18, 11, 11, 6, 4, 4, 4, 3, 3, 2, 1, 1, 0],  // ju
+    &[41295, 33324, 11725, 8845, 748, 85, 78, 20, 17, 1, 0],  // gai
+    &[209170, 39131, 30443, 23166, 22368, 9126, 5103, 3098, 2445, 2432, 1677, 1296, 444, 312, 297, 168, 160, 159, 124, 75, 75, 65, 32, 23, 17, 16, 11, 9, 6, 5, 2, 0],  // ke
+    &[10569, 896, 890, 108, 11, 10, 4],  // zeng
+    &[211111, 88133, 69283, 41663, 38365, 30874, 26390, 19563, 15131, 14852, 12110, 3880, 3804, 3794, 3093, 3032, 2507, 2144, 1130, 1026, 944, 647, 631, 601, 478, 421, 407, 334, 282, 267, 232, 217, 198, 131, 125, 106, 102, 91, 89, 75, 69, 59, 59, 49, 47, 42, 39, 39, 39, 38, 38, 36, 28, 26, 24, 23, 23, 22, 19, 14, 14, 12, 8, 7, 7, 6, 5, 5, 5, 4, 4, 3, 2, 0, 0, 0],  // li
+    &[6558, 5918, 5894, 5873, 4366, 3405, 763, 759, 582, 524, 492, 367, 321, 299, 197, 170, 159, 54, 52, 40, 23, 8, 7, 1, 1, 0],  // qiao
+    &[18061, 13777, 10166, 2449, 996, 392, 190],  // chuang
+    &[20000, 19693, 7585, 7095, 2998, 2684, 1385, 840, 590, 563, 499, 242, 172, 42, 37, 18, 15, 9, 4, 4, 3, 2, 1, 0, 0],  // luo
+    &[246504, 25747, 25558, 8263, 4000, 1204, 250, 82, 74, 1, 1],  // na
+    &[85422, 58262, 42404, 3261, 1624, 991, 985, 144, 111, 22, 21, 10],  // ming
+    &[26392, 13060, 8990, 8633, 574, 68, 13, 4, 2, 2, 1, 1, 0],  // ze
+    &[182411, 18833, 2106, 53],  // shui
+    &[153000, 6039, 3794, 3769, 1829, 1665, 638, 532, 158, 121, 6, 1],  // dou
+    &[33181, 24012, 22360, 12807, 3145, 3000, 676, 505, 465, 436, 250, 179, 153, 118, 51, 14, 12, 12, 8, 1, 0],  // liu
+    &[11968, 8343, 1176, 879, 566, 448, 126, 21, 2, 1],  // kang
+    &[49779, 127],  // nei
+    &[158827, 66781, 21750, 12000, 9603, 9139, 3484, 2593, 2405, 1980, 1092, 1068, 1058, 1050, 921, 541, 327, 313, 308, 304, 119, 119, 101, 62, 14, 6, 6, 2],  // xiao
+    &[57996, 24327, 22362, 4943, 4693, 3728, 1859, 1623, 1211, 1199, 1062, 857, 342, 341, 237, 185, 34, 17, 7, 1, 0, 0],  // jiang
+    &[1368, 930, 222, 75, 56, 32, 1, 0],  // ga
+    &[6646, 6384, 801, 727, 330, 189, 120, 90, 42, 42, 24],  // ou
+    &[408571, 5327, 5081, 250, 247, 170, 55, 36, 19, 11, 6, 2],  // lai
+    &[2791],  // zei
+    &[4113, 1704, 684, 465, 89, 25, 14, 1],  // rui
+    &[5125, 4189, 982, 857, 256, 224, 50, 19, 9, 7, 4],  // cu
+    &[50065, 2],  // zen
+    &[134480, 6377, 4260, 3883, 1171, 964, 689, 419, 383, 348, 251, 233, 222, 206, 204, 15, 4, 3, 3],  // duo
+    &[190671, 124494, 25879, 9404, 9015, 3857, 3271, 1833, 1610, 1476, 736, 453, 222, 150, 126, 111, 67, 61, 57, 50, 32, 31, 16, 12, 6, 5, 3, 2, 2, 1, 0, 0, 0],  // qu
+    &[7912, 3985, 1879, 1691, 637, 521, 115, 107, 28, 2, 1, 1],  // gua
+    &[107160, 54837, 21745, 21498, 11656, 8794, 8092, 6084, 6029, 4961, 4727, 4711, 2976, 2197, 1778, 1773, 1768, 1354, 1323, 1063, 985, 565, 395, 273, 247, 178, 77, 74, 55, 45, 37, 36, 33, 26, 26, 20, 16, 15, 12, 9, 7, 5, 2, 1, 1, 0],  // xian
+    &[16292, 3738, 1791, 1444, 1272, 601, 147, 32, 9, 0],  // mang
+    &[18986, 715],  // niang
+    &[54567, 24259, 8987, 8562, 7522, 2000, 307, 89, 44, 40, 19],  // bai
+    &[3102, 1913, 377, 98, 81, 1],  // zang
+    &[2942, 1313, 953, 492, 428, 279, 236, 227, 220, 92, 69, 27, 6, 3, 1, 0],  // bin
+    &[28189, 5693, 3819, 1704, 1505, 984, 806, 544, 350, 240, 192, 34, 6, 2, 0],  // rong
+    &[82906, 28122, 24979, 24063, 9385, 8866, 3205, 2421, 1078, 1022, 672, 604, 597, 491, 287, 174, 123, 115, 50, 23, 12, 8, 7, 2, 1, 0],  // ba
+    &[105137, 45875, 36666, 15414, 1702, 711, 603, 599, 306, 105, 6, 0],  // hai
+    &[525771, 45841, 42299, 9637, 3501, 603, 429, 124, 102, 100, 96, 75, 48, 47, 45, 32, 28, 8],  // ren
+    &[25297, 14888, 2062, 1780, 1066, 94, 78, 38, 32],  // zhuan
+    &[31716, 4228, 3042, 2748, 2732, 2239, 2037, 1529, 774, 215, 74, 44, 42, 40, 4, 4, 0],  // hong
+    &[30302, 26320, 11160, 5088, 4504, 3129, 2066, 1872, 796, 494, 476, 144, 93, 32, 17, 10, 7, 4, 0],  // she
+    &[11214, 7770, 1374, 192, 67, 18],  // rou
+    &[834, 8, 2],  // pou
+    &[109482, 45137, 23642, 11753, 11522, 5747, 5680, 2203, 1972, 1411, 1019, 478, 392, 344, 227, 164, 158, 82, 16, 13, 5, 4, 3, 3, 0, 0],  // cheng
+    &[18413, 5727, 5491, 5317, 3778, 3766, 3050, 1015, 838, 594, 225, 142, 107, 59, 48, 37, 37, 29, 20, 18, 6, 5, 4, 4, 3, 2],  // mao
+    &[11369, 6243, 1197, 31, 1],  // tie
+    &[131263, 101996, 100000, 48488, 43661, 40938, 36651, 22511, 21934, 19200, 16497, 16158, 15144, 14910, 14204, 12079, 11518, 10298, 10067, 7922, 4283, 4048, 2974, 2949, 1913, 1507, 1342, 1304, 1263, 1100, 1070, 1042, 997, 806, 802, 798, 656, 617, 582, 520, 444, 383, 329, 249, 225, 188, 117, 102, 94, 92, 88, 85, 82, 56, 40, 38, 32, 26, 25, 22, 19, 19, 18, 17, 16, 13, 11, 10, 9, 5, 4, 4, 4, 2, 2, 1, 1, 0, 0],  // zhi
+    &[161045],  // neng
+    &[60602, 52175, 28020, 22773, 9224, 7886, 6979, 2636, 2400, 1555, 1320, 1096, 497, 308, 81, 72, 65, 46, 2, 2],  // gong
+    &[12756, 1417, 0],  // lve
+    &[4777, 440, 0],  // ca
+    &[11202, 10000, 4980, 3444, 2537, 2150, 1498, 1075, 704, 622, 167, 0, 0],  // chao
+    &[66370, 32330, 18534, 17544, 15300, 14056, 13458, 5397, 5068, 5015, 4856, 3848, 3549, 2459, 2223, 1840, 1755, 1605, 1520, 1491, 1216, 1041, 1017, 898, 855, 645, 482, 447, 347, 338, 336, 330, 265, 249, 248, 240, 235, 226, 216, 213, 185, 176, 143, 132, 105, 73, 72, 71, 69, 59, 40, 35, 34, 29, 16, 10, 7, 6, 4, 4, 2, 2, 0],  // yan
+    &[856323, 244747, 141452, 91333, 83917, 67772, 53180, 37485, 35469, 30973, 30000, 29557, 27770, 27439, 27303, 25835, 24978, 20183, 19454, 17145, 16716, 15404, 15000, 10493, 10140, 9173, 9049, 4461, 4108, 3679, 3454, 3302, 3207, 2520, 2473, 2255, 1598, 1257, 1006, 845, 606, 536, 438, 375, 358, 352, 240, 234, 124, 81, 48, 46, 42, 28, 28, 17, 15, 9, 6, 4, 2, 1, 1, 0, 0, 0],  // shi
+    &[13319, 13000, 8070, 6315, 685, 288, 39, 1, 0],  // pai
+    &[105754, 39552, 14948, 9753, 5516, 3407, 2755, 2539, 1844, 1796, 1595, 1320, 1254, 1133, 1118, 651, 624, 588, 527, 402, 343, 272, 176, 164, 132, 63, 48, 36, 23, 20, 12, 6, 3, 2, 2, 2, 0],  // xie
+    &[1479, 1013, 261, 20, 1],  // weng
+    &[112914, 12392, 4637, 4306, 922, 910, 767, 236, 146, 111, 59, 47, 5, 1, 0],  // suo
+    &[53562, 2131, 565, 510, 47, 23, 2],  // rang
+    &[20827, 12724, 7898, 1665, 1157, 859, 756, 438, 267, 175, 98, 46, 19, 18, 10, 8],  // song
+    &[15442, 13668, 11046, 9316, 7821, 5626, 4064, 3731, 3325, 3298, 3213, 2883, 2881, 2018, 1306, 968, 530, 334, 285, 276, 221, 214, 69, 33, 29, 8, 4, 3, 0, 0],  // mo
+    &[10576, 3053, 1669, 998, 942, 624, 473, 220, 209, 84, 49, 45, 42, 30, 19, 6, 1, 0],  // zhuo
+    &[463995, 250311, 68822, 17673, 6686, 5631, 2417, 865, 392, 266, 226, 102, 97, 79, 78, 58, 38, 25, 17, 13, 5],  // zhe
+    &[10607, 6011, 3474, 3346, 1808, 562, 368, 357, 250, 37, 11, 10, 4, 4, 1],  // pei
+    &[8908],  // zhua
+    &[153265, 61840, 59368, 52631, 48388, 20526, 19296, 12047, 9398, 5138, 5125, 4203, 2889, 1996, 1803, 1676, 1044, 616, 596, 304, 198, 78, 35, 12, 10, 9, 9, 1, 0],  // xiang
+    &[71477, 34790, 8941, 4256, 1729, 820, 260, 122, 92, 90, 89, 75, 54, 27, 13, 3, 3, 1, 1],  // gao
+    &[127166, 59904, 34356, 12712, 11483, 7819, 1826, 995, 266, 24, 18, 3, 0],  // zuo
+    &[81017, 4153, 613, 377, 17, 13, 2, 2, 0, 0],  // zou
+    &[993],  // zhuai
+    &[53691, 33027, 11150, 9709, 7213, 7133, 6578, 5503, 4859, 3698, 3638, 2432, 1511, 1135, 1108, 820, 801, 713, 443, 345, 310, 274, 217, 197, 150, 73, 57, 55, 53, 52, 47, 34, 32, 22, 18, 14, 14, 13, 12, 8, 5, 5, 5, 4, 4, 3, 2, 2, 1, 0, 0, 0],  // bi
+    &[60595, 821, 351, 157, 156],  // bie
+    &[211778, 93884, 42475, 40408, 14199, 6000, 5437, 2012, 394, 115, 71, 24],  // hua
+    &[6799, 5793, 5146, 3338, 1068, 927, 910, 867, 702, 683, 677, 552, 448, 426, 259, 193, 152, 96, 95, 94, 94, 82, 51, 50, 48, 41, 32, 17, 6, 4, 0],  // e
+    &[46206, 45164, 44805, 37296, 20000, 18460, 13278, 12441, 8153, 7141, 5975, 4743, 3825, 3335, 3245, 3015, 3009, 2355, 1756, 1578, 593, 521, 334, 305, 278, 244, 136, 114, 94, 81, 69, 50, 35, 30, 20, 10, 4, 1, 0, 0],  // jie
+    &[137380, 15606, 8010, 6053, 3460, 2459, 2209, 1490, 930, 374, 294, 177, 165, 158, 104, 101, 69, 67, 57, 40, 26, 8, 5, 4, 0],  // yong
+    &[130112, 49076, 2665, 2191, 1677, 869, 588, 374, 266, 211, 205, 144, 33, 14, 6, 5, 4, 3, 2, 1],  // ru
+    &[131119, 20188, 10246, 10049, 9488, 9283, 7813, 5084, 4440, 1088, 1052, 621, 581, 304, 274, 241, 210, 205, 129, 98, 97, 90, 48, 26, 0],  // yang
+    &[2000, 1807, 1146],  // o
+    &[47815, 243, 206, 2, 1],  // te
+    &[94214, 58310, 9541, 7048, 6219, 2619, 1846, 1022, 836, 146, 92, 72, 37, 25, 7, 3, 1, 1, 0],  // dong
+    &[215543, 190000, 65101, 2908, 2229, 2106, 162, 33, 28, 16, 6, 5, 3, 1, 1, 0],  // guo
+    &[49755, 26006, 7953, 1551, 625, 283, 251, 89, 32, 1],  // que
+    &[67488, 33709, 25881, 23569, 12237, 6986, 2612, 2484, 2000, 1602, 865, 215, 178, 123, 67, 50, 21, 0],  // guan
+    &[59894, 17247, 17037, 9243, 1862, 1024, 707, 471, 394, 369, 111, 1, 0],  // bing
+    &[15573, 7818, 1881, 1465, 778, 735, 635, 618, 384, 328, 168, 149, 98, 67, 46, 28, 8, 5, 3],  // liao
+    &[18094, 6137, 5307, 4000, 2056, 1246, 534, 373, 348, 247, 41, 34, 24, 13, 8, 3, 2, 0, 0],  // meng
+    &[16175, 4908, 788, 593, 269, 61, 6, 1, 0],  // mou
+    &[20806, 18024, 13118, 10163, 746, 499, 21, 6, 0],  // duan
+    &[15695, 3072, 2095, 1959, 1017, 672, 563, 385, 290, 242, 204, 71, 36, 8, 4],  // dun
+    &[12006, 11736, 2180, 2178, 1982, 1087, 761, 484, 297, 203, 46, 16, 11, 4],  // pan
+    &[17237, 8299, 3080, 747, 146, 114, 66, 54, 17, 8, 3, 1, 0],  // nao
+    &[14553, 3383, 81],  // qun
+    &[3945, 3020, 1666, 88, 7, 4],  // rao
+    &[47371, 44983, 27203, 9693, 9107, 4739, 3949, 2538, 1922, 602, 276, 34, 18, 11, 9, 5, 2, 1, 0, 0, 0],  // huo
+    &[23184, 2037],  // re
+    &[171125, 72804, 10857, 9449, 8603, 8456, 5075, 3408, 2507, 2074, 383, 250, 3, 2],  // sheng
+    &[23168, 8154, 3167, 1751, 1399, 1232, 1166, 562, 138, 113, 16, 0],  // gang
+    &[27916, 3244, 3000, 1001, 785, 542, 499, 389, 374, 290, 215, 108, 81, 65, 33, 24, 13, 7, 3, 1, 1, 1, 0, 0],  // chan
+    &[84156, 29556, 19678, 16485, 10228, 8020, 7207, 3803, 3000, 2859, 1707, 1639, 1189, 1018, 672, 535, 296, 251, 99, 97, 66, 59, 40, 39, 15, 7, 7, 3, 1, 0, 0, 0],  // yin
+    &[40199, 10604, 8088, 2447, 2214, 2052, 1663, 886, 832, 797, 563, 447, 284, 250, 249, 105, 92, 85, 79, 67, 49, 38, 31, 30, 29, 26, 3, 3, 3, 2, 1, 1, 0],  // shan
+    &[5983, 5694, 1335, 837, 671, 584, 578, 554, 431, 354, 242, 241, 202, 78, 67, 66, 51, 12, 4, 3, 1, 0],  // zha
+    &[8286, 1552, 566, 393, 75, 70, 19, 1],  // nuo
+    &[98595, 26023, 10000, 9157, 5615, 5049, 4973, 4186, 3903, 2455, 886, 346, 237, 235, 188, 104, 102, 44, 42, 22, 19, 12, 11, 5, 3, 0],  // dan
+    &[26362, 12001, 9253, 7679, 4000, 1090, 671, 645, 323, 132, 61, 59, 43, 27, 17, 7, 7, 2, 1],  // lei
+    &[16579, 9468, 5572, 3003, 2720, 1924, 1392, 1133, 463, 105, 49, 18, 18, 15, 3, 3],  // po
+    &[15675, 3839],  // reng
+    &[58549, 56806, 1594, 379, 365, 107, 97, 87, 78, 73, 29, 27, 25, 11, 2, 1, 1],  // biao
+    &[10000, 3000, 1106, 392, 0, 0],  // ka
+    &[143238, 96057, 14214, 12000, 6416, 4160, 2284, 974, 972, 638, 300, 246, 43, 8, 1, 1],  // wen
+    &[35010, 34263, 24336, 9191, 6574, 4971, 3819, 2253, 2093, 1858, 1619, 754, 371, 281, 166, 160, 56, 49, 44, 39, 35, 9, 4, 0, 0, 0],  // lian
+    &[1191, 286, 265, 91, 11, 5, 1],  // cuan
+    &[14375, 2855, 2579, 2000, 1673, 1385, 1264, 417, 317, 205, 190, 115, 106, 11],  // bang
+    &[51863, 45579, 23258, 6966, 5822, 3507, 2914, 2647, 1797, 1407, 1360, 780, 438, 321, 145, 131, 90, 84, 26, 25, 17, 1, 1, 0],  // zhang
+    &[28319, 28312, 21934, 13963, 6143, 3947, 3152, 2811, 2744, 1210, 951, 628, 551, 335, 289, 254, 164, 109, 94, 49, 43, 31, 27, 12, 4, 3, 0],  // ling
+    &[63709, 39686, 19235, 7801, 3106, 1555, 553, 389, 107, 58, 33, 25, 6, 3, 0, 0],  // tai
+    &[7097, 7022, 4053, 537, 346, 330, 155, 149, 64, 22, 0],  // piao
+    &[14450, 8408, 6408, 3162, 2603, 2004, 1825, 1592, 1102, 986, 716, 543, 486, 476, 396, 291, 153, 126, 8, 6, 1, 0],  // lan
+    &[1753, 635, 604, 378, 372, 187, 121, 79, 45, 22, 8, 7, 2],  // nie
+    &[33380, 17431, 6958, 5221, 2045, 1567, 652, 469, 242, 132, 119, 54, 24, 11, 10, 7, 6, 1, 1],  // man
+    &[45844, 976, 2],  // ne
+    &[93535, 41758, 31751, 28600, 22808, 22354, 15328, 8264, 4211, 4000, 2934, 2528, 2205, 2094, 1932, 1777, 1658, 762, 570, 385, 283, 282, 186, 152, 104, 98, 66, 34, 34, 27, 22, 14, 11, 2, 0],  // jin
+    &[209372, 49196, 19048, 16266, 14799, 6292, 5017, 2603, 2198, 825, 776, 682, 369, 288, 279, 250, 207, 101, 83, 82, 72, 38, 30, 20, 17, 13, 11, 7, 7],  // chu
+    &[778, 577, 190, 90, 53, 5],  // chuo
+    &[41331, 34217, 18609, 11412, 10452, 9475, 8552, 7758, 4709, 4274, 3538, 1576, 1255, 661, 338, 281, 187, 184, 40, 22, 12, 4, 1, 1],  // fan
+    &[20000, 19873, 11167, 6352, 4588, 2343, 214, 59, 25, 3, 3, 2, 0],  // chuan
+    &[17431, 4755, 3537, 2781, 1710, 360, 271, 52, 27, 13, 3, 2],  // pao
+    &[190000, 186348, 8536, 6871, 5167, 4429, 4346, 3672, 3582, 3083, 2713, 1765, 1214, 936, 711, 641, 434, 313, 259, 177, 122, 105, 91, 88, 80, 57, 53, 35, 30, 26, 21, 12, 9, 3, 3, 2, 1, 1, 0],  // hui
+    &[33361, 25618, 22549, 9328, 8263, 7623, 4430, 2513, 2379, 2082, 1902, 1488, 1257, 1170, 1164, 789, 657, 638, 411, 333, 333, 324, 308, 277, 221, 185, 143, 128, 75, 65, 60, 50, 30, 8, 6, 5, 4, 2],  // ying
+    &[112841, 48126, 31124, 12620, 8330, 8101, 3932, 2183, 1401, 742, 441, 146, 82, 21, 9, 2, 1, 1],  // fang
+    &[15645, 10102, 8675, 8282, 3415, 2966, 2459, 2144, 2140, 2046, 790, 557, 336, 296, 234, 172, 138, 134, 55, 34, 24, 11, 6, 6, 3, 3, 2, 0, 0],  // gui
+    &[149869, 51484, 12454, 11532, 10605, 4682, 3690, 3032, 3032, 2739, 2627, 2459, 1497, 648, 606, 516, 439, 369, 177, 162, 141, 114, 80, 72, 64, 63, 63, 52, 7, 7, 7, 6, 5, 5, 3, 0],  // jia
+    &[10577, 3787, 261, 245, 55, 46, 13, 5],  // sun
+    &[95012, 40882, 40675, 16918, 6455, 2536, 2070, 1992, 1269, 641, 507, 299, 237, 212, 90, 71, 51, 45, 34, 3, 0],  // tong
+    &[139247, 80563, 25615, 6532, 6215, 3373, 2996, 2101, 1715, 1583, 483, 427, 381, 374, 329, 277, 235, 5, 2, 1, 0],  // mu
+    &[4867],  // nuan
+    &[53487, 7041, 5874, 892, 780, 278, 82, 48],  // lun
+    &[25454, 16130, 12806, 11153, 8598, 7397, 7336, 7328, 5781, 4154, 3273, 1861, 1312, 1153, 558, 334, 307, 298, 249, 176, 155, 112, 106, 90, 53, 46, 35, 35, 25, 18, 15, 13, 11, 10, 8, 8, 5, 5, 2, 2, 1, 0],  // gu
+    &[55918, 15199, 1624, 260, 111, 33, 2],  // guang
+    &[44908, 35147, 32472, 27410, 13389, 8376, 2505, 1783, 1503, 927, 470, 152, 24, 3],  // wang
+    &[11596, 6006, 5269, 4850, 3427, 177, 149, 63, 26, 11],  // hun
+    &[10979, 3396, 619, 277],  // shun
+    &[15793, 2111, 1841, 47],  // guai
+    &[841127, 7102, 3048, 2993, 2919, 678, 379, 225, 187, 184, 142, 67, 40, 23, 0, 0],  // wo
+    &[200000, 11787, 8100, 5431, 4069, 3934, 3636, 2397, 2244, 1571, 1568, 1191, 997, 778, 497, 490, 489, 486, 263, 212, 135, 103, 101, 44, 6, 5, 3, 2, 1, 0, 0],  // yao
+    &[2565, 2335, 2084, 147, 11, 1],  // sang
+    &[106117, 80671, 24714, 22125, 17908, 17203, 16942, 14602, 13763, 12618, 8204, 5576, 5480, 4991, 4939, 3395, 2998, 1857, 1436, 1198, 939, 743, 485, 361, 341, 224, 220, 200, 138, 138, 68, 58, 38, 24, 10, 6, 2, 2, 1, 0],  // jing
+    &[90324, 48647, 40286, 21628, 9382, 5883, 3725, 2959, 2083, 1852, 99, 96, 35, 6],  // shou
+    &[15906, 657, 232, 188, 110, 102, 79, 77, 17, 11, 5],  // luan
+    &[7448, 4971, 4017, 559, 280, 13, 13, 11, 9, 5],  // zan
+    &[22870, 18595, 12312, 8234, 4155, 2994, 646, 540, 51, 10],  // zu
+    &[15475, 11232, 3544, 3108, 2594, 2275, 1727, 1641, 965, 934, 924, 852, 744, 299, 282, 248, 233, 156, 153, 108, 70, 55, 39, 35, 26, 19, 18, 15, 9, 8, 5, 5, 5, 4, 3, 3, 2, 2, 1, 1, 1, 1, 0],  // pi
+    &[35572, 30929, 13001, 4433, 3599, 3592, 2675, 1401, 1152, 848, 780, 491, 273, 272, 254, 186, 160, 145, 87, 68, 33, 24, 17, 14, 6, 6, 3, 1, 1],  // fei
+    &[363654, 163055, 26034, 15948, 9505, 8040, 2156, 1427, 1087, 1082, 1045, 877, 733, 132, 58, 28, 8, 0],  // dao
+    &[100821, 49389, 11531, 6703, 3070, 1810, 1091, 756, 553, 447, 431, 326, 320, 224, 208, 155, 136, 124, 63, 47, 22, 22, 10, 1, 1],  // dian
+    &[15087, 9181, 5305, 3000, 2488, 2306, 116, 3, 1],  // mai
+    &[774967, 17430, 332, 12, 3, 2, 0],  // le
+    &[273481, 24242, 21152, 20743, 20457, 11386, 9118, 4315, 2302, 1018, 737, 321, 171, 165, 122, 115, 106, 93, 82, 81, 55, 46, 40, 17, 6, 1],  // jiu
+    &[43470, 31066, 28414, 14884, 14248, 4561, 3842, 2091, 1675, 1064, 996, 901, 786, 337, 182, 176, 124, 82, 73, 48, 28, 9, 8, 7, 4, 0],  // wan
+    &[13067, 1272, 563, 162, 23, 2],  // ken
+    &[19758, 12867, 8940, 2714, 2444, 2155, 902, 664, 350, 111, 57, 55, 31, 25, 7, 5, 3, 2, 1, 1, 0],  // gou
+    &[4500, 2637, 1954, 364, 280, 9, 3, 1, 0],  // sao
+    &[27647, 14381, 10856, 7675, 5632, 3179, 2268, 611, 480, 272, 261, 202, 104, 89, 80, 32, 31, 25, 22, 13, 5, 4, 4, 4, 2, 2, 1, 0],  // huan
+    &[512230, 105907, 51993, 30055, 16897, 11851, 8931, 7872, 5701, 5508, 5480, 4088, 3554, 3526, 2545, 2381, 962, 464, 266, 246, 129, 104, 95, 78, 59, 59, 57, 35, 30, 29, 24, 23, 18, 12, 10, 8, 4, 2, 1, 1, 0],  // you
+    &[2533, 11, 5],  // cou
+    &[376503, 65674, 28886, 27866, 7091, 4272, 3662, 473, 340, 268, 256, 59, 20, 10, 3, 1, 1, 0],  // bu
+    &[2669, 1837, 1617, 1391, 1361, 1122, 17, 0],  // zhai
+    &[7402, 1281, 1025, 662, 18, 16, 13, 11, 9, 7, 3],  // kun
+    &[54540, 6766, 4543, 3004, 2090, 632, 135, 119, 14, 0],  // zong
+    &[9620, 6778, 6237, 3697, 3660, 728, 690],  // xiong
+    &[31352, 9990, 3074, 2425, 2265, 841, 567, 507, 406, 348, 340, 189, 172, 161, 108, 97, 74, 29, 28, 16, 11, 9, 8, 1, 0],  // lin
+    &[51000, 1922, 1534, 143, 93, 9],  // se
+    &[2524, 1942, 444, 71, 28, 4, 3],  // chai
+    &[88687, 7607, 3297, 1173, 970, 589, 477, 413, 398, 175, 101, 61, 53, 26, 10, 3, 1, 0],  // lao
+    &[11185, 3701, 667, 326, 309, 224, 24, 7, 5],  // zhui
+    &[18294, 16],  // fou
+    &[79902, 42499, 26422, 23916, 22641, 6838, 3385, 2888, 2315, 1794, 1694, 1399, 616, 599, 252, 224, 185, 183, 28, 2],  // zheng
+    &[594798, 68301, 18842, 3135, 1158, 896, 607, 314, 4],  // zai
+    &[73116, 2463, 30],  // wai
+    &[19065, 15134, 5557, 2915, 2217, 1791, 1750, 1504, 1040, 666, 469, 177, 175, 48, 14, 9, 1],  // sha
+    &[31711, 2950],  // hei
+    &[17903, 7637, 7067, 4351, 3421, 1414, 988, 757, 462, 260, 116, 22, 19, 14, 4, 3, 1],  // xiu
+    &[118969, 2425, 1780, 335, 294, 233, 76, 55, 14, 4, 2, 1, 1],  // kai
+    &[17478, 10000, 7857, 7365, 6415, 3715, 2134, 2113, 2097, 1960, 1595, 1537, 1354, 1056, 939, 663, 210, 104, 94, 42, 28, 20, 13, 9, 6, 6, 6, 4, 1, 0],  // ya
+    &[50320, 50000, 32140, 27774, 13647, 13456, 12064, 11348, 6739, 5277, 1501, 786, 611, 466, 407, 335, 65, 13, 10, 6, 2],  // xing
+    &[11045, 10177, 3980, 411, 156, 148],  // nong
+    &[119, 61],  // cen
+    &[5623, 4532, 12],  // kuan
+    &[17960, 7003, 2763, 2349, 1951, 1709, 1347, 1059, 873, 655, 470, 348, 322, 301, 171, 33, 6, 1],  // peng
+    &[15445, 9423, 8441, 3490, 3426, 2729, 2012, 1297, 1018, 791, 166, 123, 67, 45, 20, 1],  // tao
+    &[152057, 24681, 8920, 3188, 2220, 1903, 607, 576, 552, 265, 182, 133, 104, 28, 25, 22, 20, 14, 11, 0],  // hao
+    &[7146, 107, 3],  // en
+    &[25948, 9880, 7444, 6996, 3056, 2827, 2224, 1897, 1805, 1137, 980, 779, 679, 374, 367, 361, 324, 279, 246, 177, 98, 1, 1, 0],  // tan
+    &[8504, 4941, 1597, 1165, 987, 79],  // cang
+    &[87892, 68676, 56968, 46699, 33909, 29774, 27860, 27629, 21738, 21682, 19220, 19124, 17022, 15383, 14569, 14040, 14000, 13750, 12993, 12058, 8417, 8416, 7132, 6774, 6629, 5710, 5141, 4651, 4416, 2998, 2420, 2408, 2363, 1937, 1710, 1605, 1528, 1278, 1173, 1086, 1029, 1012, 885, 711, 707, 705, 691, 680, 550, 506, 478, 386, 376, 345, 320, 259, 224, 197, 190, 185, 183, 142, 134, 134, 113, 113, 110, 106, 93, 87, 86, 79, 71, 65, 49, 46, 45, 44, 34, 33, 32, 30, 29, 27, 27, 26, 23, 13, 13, 6, 6, 4, 4, 3, 3, 2, 2, 1, 1, 1, 1, 0, 0, 0],  // ji
+    &[47166, 39000, 20503, 15363, 12113, 11624, 9910, 9828, 8684, 4986, 4977, 4916, 4894, 3554, 2059, 1550, 1517, 1346, 1102, 1079, 539, 487, 464, 445, 413, 412, 406, 395, 391, 243, 236, 191, 164, 94, 84, 81, 61, 33, 29, 21, 13, 9, 2, 1, 1],  // shu
+    &[11216, 1796, 659, 273, 144, 48],  // za
+    &[140967, 79278, 65503, 32819, 24594, 19528, 16000, 8044, 7691, 7291, 7111, 6523, 5279, 4565, 4503, 3222, 2449, 2286, 2261, 2087, 1932, 1630, 1347, 1332, 1163, 1103, 873, 855, 657, 574, 508, 475, 445, 401, 350, 305, 234, 228, 215, 207, 140, 135, 130, 110, 103, 88, 85, 71, 64, 51, 51, 35, 33, 32, 25, 22, 17, 14, 13, 8, 7, 7, 5, 5, 5, 3, 0, 0, 0],  // qi
+    &[1294, 228],  // yo
+    &[175322, 130504, 44884, 20583, 4511, 4287, 2754, 2393, 2040, 1190, 431, 397, 348, 279, 200, 153, 121, 105, 102, 46, 42, 29, 28, 18, 17, 13, 12, 12, 11, 11, 6, 4, 4, 3, 1, 0],  // zi
+    &[74468, 47899, 21266, 11375, 10571, 6429, 5689, 5567, 4456, 4262, 4203, 4026, 3105, 2715, 1771, 1397, 1140, 971, 633, 554, 365, 311, 251, 234, 199, 178, 133, 80, 75, 74, 72, 58, 46, 42, 40, 25, 21, 20, 12, 10, 8, 3, 1, 1, 0, 0, 0],  // zhu
+    &[1102],  // miu
+    &[798, 76],  // nve
+    &[75642, 68436, 9289, 7327, 5029, 4760, 1757, 1653, 1578, 1146, 851, 332, 304, 139, 97, 16, 12, 0],  // ci
+    &[127246, 79765, 3458, 2511, 1343, 618, 178, 36, 23, 4],  // fa
+    &[134972, 13447, 10000, 1853, 1383, 1079, 879, 211, 188, 38, 0],  // xue
+    &[4712, 1885, 166, 15, 5, 3, 1, 0],  // gun
+    &[3389, 90],  // run
+    &[192842, 46445, 25556, 8106, 1758, 1292, 1288, 943, 740, 654, 550, 546, 516, 493, 441, 253, 27, 10, 3],  // zhan
+    &[52],  // ei
+    &[23091, 20606, 17046, 6065, 1330, 486, 415, 387, 366, 289, 246, 194, 40, 25, 10, 6, 0, 0],  // sui
+    &[11397, 8376, 7561, 7289, 5065, 2337, 1415, 1267, 958, 931, 795, 470, 246, 207, 162, 113, 98, 57, 16, 0],  // lv
+    &[3408, 1595, 821, 306, 217, 153, 140, 122, 23, 6, 6, 2, 1, 1],  // sou
+    &[57385, 23506, 15285, 14058, 9657, 1752, 1334, 436, 364, 169, 141, 113, 49, 15, 10, 2, 2, 0],  // an
+    &[5542, 4778, 3339, 2982, 1532, 9, 8],  // heng
+    &[3989],  // n
+    &[10891, 6562, 5886, 4976, 2899, 2462, 2072, 1704, 1463, 872, 705, 488, 394, 327, 218, 142, 85, 65, 59, 58, 49, 37, 20, 16, 14, 14, 12, 4, 3, 0],  // xun
+    &[136720, 65521, 3197, 107, 78, 54, 1],  // men
+    &[107076, 47116, 38063, 24612, 19073, 13690, 13630, 12091, 10076, 9711, 6044, 5479, 3986, 3332, 2840, 1906, 1649, 981, 968, 887, 875, 841, 768, 678, 672, 648, 638, 411, 335, 311, 195, 183, 117, 87, 79, 65, 46, 43, 41, 37, 26, 16, 15, 14, 12, 5, 5, 3, 2, 2, 1, 1, 0, 0],  // wu
+    &[8253, 3288, 3146, 3027, 2828, 1723, 999, 986, 868, 450, 370, 356, 302, 8, 7, 1, 0],  // chou
+    &[15519, 11534, 3927, 2962, 1257, 337, 277, 70, 35, 0, 0],  // chong
+    &[153950, 3867, 3186, 492, 159, 28, 2],  // ran
+    &[56645, 31985, 19550, 2706, 1252, 865, 430, 397, 336, 264, 254, 247, 214, 200, 136, 87, 75, 73, 65, 51, 42, 34, 29, 28, 26, 2, 1, 0],  // jue
+    &[2467, 1579, 188],  // shua
+    &[54062, 5190, 733, 497, 183, 141, 132, 45, 32, 3, 3, 2, 1, 0],  // min
+    &[4595, 0],  // diu
+    &[5450, 2822, 1994, 1763, 1560, 1028, 490, 180, 2, 0],  // wa
+    &[27746, 23937, 19472, 16923, 16393, 14041, 5195, 4336, 2846, 2249, 1491, 600, 594, 575, 534, 514, 448, 31, 7, 3, 2],  // ban
+    &[90877, 9907, 6634, 3739, 2602, 1611, 1258, 1000, 198, 115, 92, 65, 49, 48, 43, 23, 5, 4],  // ding
+    &[29096, 9533, 8310, 4401, 2783, 1618, 1520, 1399, 898, 826, 805, 519, 257, 165, 128, 120, 66, 24, 1, 0, 0],  // zhou
+    &[20173, 15652, 11947, 9537, 5210, 4846, 3228, 1906, 617, 594, 485, 408, 365, 104, 96, 14, 6, 5, 5, 4, 3],  // su
+    &[29669, 26907, 23478, 21673, 19685, 16024, 13292, 12052, 11308, 10776, 10631, 7561, 7282, 6837, 5580, 4570, 4003, 3740, 3577, 3240, 3138, 2925, 2694, 2402, 2205, 2003, 1975, 1683, 1656, 1553, 1526, 1095, 932, 913, 892, 829, 643, 580, 430, 405, 316, 271, 199, 184, 183, 171, 123, 106, 95, 82, 63, 55, 34, 29, 29, 21, 20, 16, 14, 13, 12, 12, 11, 9, 9, 8, 8, 6, 6, 5, 5, 4, 4, 3, 2, 2, 2, 2, 2, 1, 1, 0, 0],  // fu
+    &[25154, 11306, 7284, 5986, 4344, 1801, 1281],  // zhuang
+    &[56955, 40797, 10737, 4959, 4081, 3559, 1989, 1874, 1818, 1079, 884, 678, 372, 341, 278, 90, 67, 24, 18, 12, 10, 5, 2, 1, 0],  // chang
+    &[5050, 2478, 2000, 129, 123, 118, 108, 0],  // sa
+    &[5000],  // lia
+    &[2213855, 199786, 150000, 28313, 5],  // de
+    &[35558, 27623, 174, 44, 22, 7],  // gen
+    &[410877, 17656, 14272, 7755, 5057, 1147, 984, 392, 57, 56, 13, 5, 2],  // shang
+    &[7986, 1614, 473, 6, 6, 1],  // niao
+    &[39988, 16585, 901, 56, 47, 19, 18, 14, 1],  // kuai
+    &[16776, 270, 23, 4, 2],  // tuan
+    &[99838, 5465, 3650, 204, 111, 47, 19, 16],  // ben
+    &[3990, 2229, 2212, 2146, 1863, 675, 608, 433, 108, 102, 17, 13, 8, 6, 5, 4, 2],  // die
+    &[313574, 12087, 4902, 3126, 1908, 1601, 1260, 619, 608, 534, 465, 335, 202, 180, 88, 8, 3, 3, 2, 0],  // ni
+    &[8155, 4655, 2626, 734, 10],  // kuo
+    &[31910, 20059, 6139, 3032, 1753, 1385, 965, 822, 696, 686, 646, 642, 441, 272, 11],  // zao
+    &[13706, 8028, 1855, 580, 533, 529, 106, 12, 1, 0],  // cao
+    &[58155, 27585, 7139, 4920, 886, 22, 19, 19, 8, 0],  // pian
+    &[4412, 669, 397, 2, 2, 0],  // qia
+    &[295709, 60000, 56438, 25445, 17464, 14263, 4055, 1732, 1033, 987, 300, 203, 162, 103, 1, 1],  // zhong
+    &[21555, 10544, 5000, 2686, 2047, 1736, 777, 715, 500, 386, 381, 351, 273, 253, 227, 40, 39, 26, 21, 12, 11, 6, 4, 1, 1, 0],  // xuan
+    &[89730, 25771, 18957, 10535, 6013, 5890, 4237, 3361, 2332, 467, 458, 385, 29, 10, 1],  // liang
+    &[18982],  // nin
+    &[62738, 20804, 16125, 15964, 13451, 8343, 5386, 5179, 4206, 4100, 3964, 1067, 1039, 905, 699, 634, 556, 447, 405, 239, 219, 204, 157, 80, 63, 55, 41, 39, 24, 14, 8, 7, 4, 3, 2],  // di
+    &[335382, 1294, 1109, 874, 46, 36, 29, 5, 4],  // shuo
+    &[58370, 17183, 7954, 6963, 6867, 4062, 3743, 2315, 2175, 273, 233, 181, 112, 61, 9, 2, 0],  // ting
+    &[1025, 309, 37, 15, 8],  // nang
+    &[47888, 47860, 41803, 40811, 25610, 10600, 7741, 2775, 1812, 1399, 1358, 831, 530, 463, 307, 195, 120, 112, 69, 47, 36, 34, 17, 10, 10, 8, 4, 4, 3, 2, 1],  // si
+    &[232387, 8565, 5000, 2711, 2562, 1930, 1609, 1587, 1446, 719, 701, 592, 260, 189, 154, 55, 52, 3, 0],  // xia
+    &[1485, 1092, 834, 447, 402, 112, 86, 2],  // beng
+    &[7954, 3479, 689, 418, 367, 185, 139, 39, 18],  // chui
+    &[39746, 27740, 27354, 11458, 9006, 7225, 5458, 2754, 2585, 2425, 1965, 1799, 784, 438, 212, 172, 146, 124, 106, 50, 48, 7, 4, 2],  // bao
+    &[66760, 50910, 29876, 24200, 20325, 18743, 14561, 10483, 9815, 9300, 8559, 6223, 5473, 5111, 4527, 4343, 3093, 3035, 2531, 1865, 1860, 1838, 1772, 1646, 1565, 1183, 1036, 984, 949, 503, 414, 313, 249, 247, 220, 197, 197, 186, 159, 121, 114, 90, 90, 82, 69, 58, 37, 30, 25, 19, 18, 13, 13, 12, 12, 10, 10, 10, 6, 5, 4, 3, 2, 1, 1, 1, 1, 0, 0, 0, 0],  // xi
+];
+
+// Choices in ZI with a tone number after the syllable: 1-4, or 5 for
+// neutral tone. Choices with more than one reading list them all, joined
+// by "|".
+pub static ZI_TONED: &[&str] = &[
+    "ruo4	ruo4	ruo4	ruo4",
+    "niu2	niu3	niu3	niu3	niu1	niu3	niu3",
+    "cai2	cai3	cai4	cai3	cai2	cai2	cai1	cai2	cai3	cai4	cai3",
+    "dui4	dui4	dui1	dui4	dui4	dui4	dui4	dui4",
+    "qian2	qian2	qian1	qian2	qian1	qian2	qian1	qian3	qian4	qian4	qian1	qian3	qian1	qian3	qian1	qian4	qian4	qian2	qian4	qian2	qian2	qian1	qian3	qian4	qian2	qian1	qian1	qian1	qian1	qian1	qian1	qian1	qian2	qian4	qian2	qian1	qian1	qian4	qian1	qian4	qian3	qian1	qian3",
+    "tang2	tang2	tang3	tang1	tang2	tang3	tang4	tang4	tang2	tang3	tang2	tang2	tang2	tang3	tang2	tang1	tang3	tang2	tang2	tang2	tang2	tang2	tang1	tang1	tang2	tang1",
+    "hu1	hu1	hu4	hu4	hu2	hu1	hu4	hu2	hu3	hu2	hu2	hu2	hu2	hu1	hu3	hu2	hu2	hu3	hu4	hu4	hu3	hu2	hu4	hu1	hu4	hu2	hu2	hu2	hu2	hu4	hu2	hu1	hu2	hu2	hu4	hu1	hu2	hu4	hu4	hu4	hu1	hu4	hu1",
+    "huai2	huai4	huai2	huai2	huai2	huai2",
+    "bian1	bian4	bian4	bian1	bian4	bian4	bian4	bian3	bian1	bian4	bian3	bian4	bian3	bian1	bian4	bian4	bian1	bian1	bian3	bian1	bian3	bian3	bian1	bian4	bian4	bian4",
+    "nian2	nian4	nian4	nian3	nian3	nian3	nian1	nian2	nian3	nian1	nian3	nian2	nian2	nian4",
+    "pie1	pie1	pie3	pie3	pie1",
+    "zui4	zui3	zui4	zui4	zui4",
+    "seng1",
+    "cha2	cha2	cha4	cha2	cha1	cha1	cha4	cha4	cha2	cha2	cha3	cha2	cha1	cha2	cha4	cha4	cha2	cha2	cha1	cha1	cha3",
+    "lang4	lang2	lang3	lang2	lang2	lang2	lang2	lang1	lang2	lang2	lang2	lang4	lang2	lang4",
+    "mei2	mei3	mei3	mei4	mei2	mei2	mei2	mei2	mei2	mei4	mei2	mei2	mei4	mei4	mei2	mei4	mei2	mei4	mei2	mei3	mei2	mei2	mei3	mei2	mei2	mei2",
+    "nu4	nu3	nu2	nu3	nu2	nu2	nu3",
+    "ning2	ning2	ning2	ning2	ning2	ning4	ning2	ning4	ning2	ning2",
+    "ceng2	ceng2	ceng4	ceng1",
+    "cuo4	cuo4	cuo1	cuo4	cuo1	cuo1	cuo4	cuo1	cuo4	cuo2	cuo2	cuo2	cuo2	cuo3",
+    "tuo1	tuo1	tuo1	tuo3	tuo2	tuo2	tuo4	tuo2	tuo2	tuo3	tuo2	tuo2	tuo2	tuo2	tuo2	tuo2	tuo2	tuo4	tuo3	tuo2	tuo1	tuo4	tuo2",
+    "ta1	ta1	ta1	ta3	ta4	ta1	ta4	ta4	ta4	ta4	ta4	ta1	ta3	ta4	ta1	ta3	ta1",
+    "wei4	wei4	wei4	wei1	wei3	wei4	wei2	wei2	wei1	wei4	wei4	wei2	wei1	wei3	wei3	wei4	wei3	wei2	wei1	wei4	wei4	wei2	wei2	wei4	wei4	wei4	wei1	wei1	wei3	wei4	wei1	wei3	wei2	wei3	wei4	wei2	wei3	wei3	wei2	wei4	wei2	wei3	wei1	wei2	wei3	wei3	wei3	wei2	wei1	wei2	wei1	wei1	wei2	wei3	wei3	wei3	wei2	wei2	wei4",
+    "chi1	chi2	chi2	chi2	chi4	chi3	chi1	chi3	chi3	chi4	chi2	chi4	chi4	chi1	chi2	chi1	chi3	chi4	chi4	chi4	chi2	chi2	chi4	chi1	chi2	chi2	chi1	chi1	chi1	chi3	chi4	chi1	chi2	chi1	chi1	chi4	chi4",
+    "ai4	ai1	ai4	ai1	ai1	ai1	ai4	ai1	ai3	ai2	ai4	ai3	ai4	ai3	ai1	ai1	ai2	ai2	ai4	ai4	ai4	ai4",
+    "jun1	jun1	jun1	jun4	jun1	jun4	jun4	jun4	jun1	jun4	jun4	jun1	jun1	jun4",
+    "pu3	pu1	pu4	pu3	pu3	pu1	pu2	pu3	pu2	pu2	pu2	pu1	pu4	pu4	pu3	pu2	pu2	pu3	pu2	pu3	pu2	pu3	pu1	pu2	pu3	pu1",
+    "shuai1	shuai1	shuai4	shuai3	shuai4",
+    "shao3	shao1	shao4	shao1	shao4	shao2	shao1	shao1	shao4	shao2	shao1	shao2	shao2	shao4	shao4	shao1	shao1",
+    "zhun3	zhun1	zhun1	zhun1",
+    "qiong2	qiong2	qiong2	qiong2	qiong2	qiong2	qiong2	qiong1	qiong2	qiong2",
+    "tian1	tian2	tian2	tian1	tian2	tian3	tian2	tian3	tian2	tian3	tian3	tian2	tian4",
+    "san1	san4	san3	san1	san3	san1	san3",
+    "huang2	huang2	huang1	huang1	huang3	huang3	huang3	huang2	huang2	huang2	huang3	huang2	huang2	huang2	huang2	huang2	huang2	huang2	huang1	huang2	huang2	huang2	huang2	huang2	huang2",
+    "ce4	ce4	ce4	ce4	ce4	ce4",
+    "yi1	yi3	yi4	yi3	yi4	yi4	yi1	yi4	yi1	yi2	yi1	yi4	yi1	yi4	yi2	yi4	yi4	yi4	yi2	yi4	yi4	yi3	yi2	yi2	yi4	yi4	yi2	yi4	yi4	yi2	yi4	yi3	yi4	yi4	yi3	yi4	yi3	yi2	yi3	yi4	yi4	yi4	yi2	yi2	yi1	yi4	yi4	yi2	yi4	yi1	yi1	yi4	yi1	yi3	yi4	yi4	yi4	yi2	yi4	yi4	yi4	yi4	yi2	yi4	yi2	yi1	yi4	yi4	yi4	yi4	yi2	yi1	yi4	yi4	yi2	yi2	yi4	yi1	yi4	yi1	yi4	yi4	yi4	yi2	yi3	yi4	yi2	yi1	yi4	yi4	yi4	yi4	yi3	yi2	yi4	yi4	yi4	yi2	yi4	yi1	yi4	yi3	yi3",
+    "zun1	zun1	zun1	zun1	zun3",
+    "ma3	ma5	ma1	ma3	ma2	ma5	ma4	ma3	ma3	ma2	ma1	ma4	ma4	ma4",
+    "leng3	leng4	leng2	leng2	leng2",
+    "mian4	mian3	mian2	mian2	mian2	mian3	mian3	mian3	mian3	mian3	mian3	mian3	mian3	mian3	mian3	mian2",
+    "kou3	kou4	kou4	kou4	kou1	kou4	kou1	kou1	kou4",
+    "la1	la5	la4	la4	la3	la1	la4	la2	la1	la2	la4	la2",
+    "dai4	dai4	dai4	dai1	dai4	dai4	dai3	dai4	dai3	dai4	dai4	dai4	dai4	dai4	dai3	dai1	dai4	dai4	dai4	dai4	dai4",
+    "lou2	lou4	lou3	lou4	lou2	lou3	lou2	lou2	lou4	lou2	lou2	lou2	lou4	lou2	lou3",
+    "chuai1	chuai4	chuai4	chuai4	chuai4	chuai1",
+    "han4	han2	han3	han2	han2	han4	han2	han4	han4	han2	han3	han4	han4	han4	han4	han1	han1	han1	han2	han4	han4	han4	han2	han4	han1	han2	han2	han1	han3	han4",
+    "juan3	juan4	juan1	juan4	juan4	juan1	juan1	juan1	juan1	juan4	juan1	juan4	juan4	juan3	juan4",
+    "xin4	xin1	xin1	xin1	xin1	xin1	xin1	xin1	xin4	xin1	xin1	xin1	xin1	xin1	xin5	xin4",
+    "hou4	hou4	hou4	hou4	hou2	hou2	hou3	hou2	hou4	hou2	hou2	hou4	hou4	hou2	hou2",
+    "me5",
+    "qie3	qie4	qie4	qie4	qie4	qie4	qie4	qie4	qie4	qie4",
+    "pen2	pen1	pen2",
+    "ang2	ang1	ang4",
+    "lu4	lu4	lu4	lu4	lu3	lu2	lu2	lu4	lu4	lu2	lu1	lu4	lu3	lu4	lu4	lu2	lu2	lu4	lu4	lu4	lu3	lu4	lu3	lu4	lu3	lu2	lu1	lu4	lu2	lu2	lu2	lu2	lu2	lu4	lu4	lu4	lu2	lu2	lu5	lu3	lu4",
+    "he2	he2	he2	he2	he1	he2	he1	he2	he4	he2	he4	he2	he4	he4	he2	he1	he2	he2	he2	he4	he1	he2	he2	he2	he2	he2	he2",
+    "yue4	yue4	yue1	yue4	yue4	yue4	yue4	yue1	yue4	yue4	yue4	yue4	yue4	yue4",
+    "a1	a5	a2	a1",
+    "zhao3	zhao4	zhao1	zhao4	zhao4	zhao4	zhao4	zhao1	zhao3	zhao4	zhao3	zhao4	zhao1	zhao1	zhao4	zhao4",
+    "nv3	nv3	nv4	nv4",
+    "geng4	geng1	geng1	geng3	geng1	geng3	geng3	geng3	geng1	geng3	geng3",
+    "miao2	miao4	miao3	miao4	miao2	miao3	miao2	miao3	miao3	miao1	miao3	miao3	miao3	miao3	miao2",
+    "shuang1	shuang3	shuang1	shuang1",
+    "tun1	tun2	tun2	tun2	tun2	tun3	tun1",
+    "diao4	diao4	diao4	diao1	diao4	diao1	diao1	diao1	diao1	diao1	diao4	diao1	diao4",
+    "jian4	jian4	jian1	jian4	jian3	jian4	jian1	jian3	jian4	jian1	jian1	jian1	jian4	jian3	jian4	jian4	jian4	jian1	jian1	jian3	jian1	jian4	jian4	jian4	jian3	jian1	jian4	jian3	jian4	jian1	jian3	jian1	jian3	jian3	jian1	jian4	jian3	jian4	jian3	jian3	jian4	jian3	jian3	jian1	jian1	jian3	jian3	jian4	jian4	jian3	jian1	jian4	jian3	jian1	jian1	jian1	jian3	jian1	jian3	jian1	jian3	jian1	jian3	jian1	jian3	jian4	jian4	jian4",
+    "nen4	nen4",
+    "kong1	kong3	kong4	kong3	kong1	kong1	kong1",
+    "shai4	shai1	shai1",
+    "nan2	nan2	nan2	nan2	nan2	nan1	nan3	nan3	nan3",
+    "suan4	suan1	suan4	suan1",
+    "tui1	tui4	tui3	tui2	tui4	tui4	tui4",
+    "tiao2	tiao4	tiao1	tiao4	tiao2	tiao1	tiao3	tiao2	tiao4	tiao1	tiao2	tiao2	tiao2	tiao2",
+    "tou2	tou2	tou4	tou1	tou2	tou3	tou2",
+    "ruan3	ruan3	ruan3",
+    "yu2	yu3	yu3	yu3	yu4	yu2	yu4	yu2	yu4	yu2	yu4	yu4	yu4	yu3	yu4	yu3	yu4	yu4	yu4	yu2	yu2	yu4	yu4	yu4	yu3	yu4	yu2	yu4	yu2	yu4	yu2	yu2	yu2	yu2	yu4	yu2	yu3	yu3	yu1	yu2	yu2	yu1	yu2	yu2	yu2	yu4	yu4	yu4	yu2	yu4	yu2	yu4	yu2	yu4	yu2	yu2	yu2	yu4	yu2	yu4	yu4	yu2	yu3	yu3	yu2	yu3	yu1	yu3	yu4	yu2	yu1	yu4	yu4	yu4	yu2	yu4	yu2	yu3	yu2	yu3	yu4	yu2	yu3	yu3	yu4	yu2	yu4	yu2	yu4",
+    "kao3	kao4	kao3	kao3	kao4	kao4	kao3	kao1",
+    "bo1	bo2	bo2	bo1	bo1	bo1	bo2	bo2	bo2	bo2	bo5	bo1	bo2	bo3	bo1	bo1	bo3	bo2	bo1	bo2	bo2	bo2	bo2	bo1	bo2	bo2	bo2	bo2	bo4	bo2",
+    "tu1	tu2	tu3	tu2	tu2	tu2	tu3	tu4	tu2	tu1	tu1	tu2	tu3	tu2	tu4	tu2",
+    "shen2	shen1	shen2	shen1	shen2	shen3	shen1	shen1	shen3	shen4	shen4	shen1	shen1	shen3	shen4	shen1	shen3	shen4	shen1	shen4	shen3	shen1	shen1	shen3	shen3	shen4",
+    "deng3	deng1	deng1	deng4	deng4	deng4	deng1	deng1	deng4	deng4	deng3	deng4	deng1",
+    "sai4	sai1	sai1	sai1	sai1",
+    "cun2	cun1	cun4	cun3	cun1",
+    "ha1	ha2	ha1",
+    "gan3	gan4	gan3	gan3	gan1	gan1	gan1	gan1	gan4	gan1	gan3	gan4	gan3	gan1	gan1	gan3	gan1	gan4	gan1	gan4	gan1	gan1	gan1	gan3",
+    "yun4	yun2	yun3	yun1	yun2	yun4	yun4	yun2	yun2	yun4	yun4	yun4	yun3	yun4	yun3	yun2	yun2	yun2	yun4	yun1	yun4	yun2	yun3	yun4",
+    "kuang4	kuang2	kuang4	kuang1	kuang4	kuang4	kuang1	kuang1	kuang1	kuang4	kuang2	kuang1	kuang3	kuang4	kuang4	kuang4",
+    "qing1	qing2	qing1	qing3	qing1	qing4	qing1	qing2	qing1	qing1	qing3	qing2	qing1	qing2	qing4	qing4	qing4	qing1	qing2	qing2	qing1	qing3	qing4",
+    "shei2",
+    "keng1	keng1	keng1",
+    "kui1	kui4	kui2	kui4	kui1	kui2	kui2	kui2	kui1	kui4	kui2	kui4	kui4	kui4	kui2	kui4	kui2	kui1	kui2	kui4	kui2	kui2	kui2	kui2	kui4	kui3	kui1",
+    "lie4	lie4	lie4	lie4	lie4	lie3	lie4	lie4	lie4	lie4	lie4	lie4	lie4",
+    "du4	du2	du2	du2	du1	du4	du4	du4	du3	du3	du1	du4	du3	du3	du2	du4	du2	du2	du2	du4	du2	du2	du2	du4",
+    "teng2	teng2	teng2	teng2	teng2",
+    "hang2	hang2	hang2	hang1	hang4	hang2	hang2",
+    "ku3	ku1	ku4	ku4	ku4	ku1	ku1	ku1	ku4	ku4	ku1	ku1",
+    "da4	da3	da2	da2	da1	da5	da1	da2	da2	da1	da1	da2	da2	da2	da2	da2",
+    "jiong3	jiong3	jiong3	jiong1	jiong1	jiong3",
+    "bei4	bei3	bei4	bei4	bei1	bei1	bei4	bei4	bei4	bei1	bei1	bei4	bei4	bei4	bei5	bei4	bei4	bei1	bei4	bei4	bei4	bei4	bei1	bei4	bei4	bei4",
+    "chun1	chun2	chun2	chun3	chun2	chun2	chun1	chun2	chun2	chun1",
+    "kan4	kan1	kan1	kan3	kan3	kan3	kan3	kan1	kan4	kan1	kan1	kan3",
+    "can1	can2	can1	can3	can4	can2	can4	can2	can4	can4	can4	can1	can3",
+    "gei3",
+    "qiu2	qiu2	qiu1	qiu2	qiu1	qiu1	qiu2	qiu2	qiu2	qiu2	qiu1	qiu1	qiu2	qiu3	qiu2	qiu2	qiu2	qiu1	qiu2	qiu2	qiu2	qiu2",
+    "pang2	pang4	pang2	pang1	pang2	pang1	pang2	pang3",
+    "yuan2	yuan2	yuan2	yuan3	yuan4	yuan4	yuan2	yuan2	yuan2	yuan2	yuan2	yuan4	yuan1	yuan1	yuan2	yuan2	yuan2	yuan1	yuan4	yuan2	yuan2	yuan4	yuan1	yuan2	yuan2	yuan2	yuan2	yuan2	yuan4	yuan4	yuan4	yuan1	yuan1",
+    "xu3	xu1	xu4	xu1	xu4	xu1	xu4	xu2	xu4	xu1	xu4	xu4	xu4	xu1	xu4	xu4	xu4	xu1	xu1	xu4	xu5	xu1	xu3	xu3	xu4	xu1	xu4	xu1	xu4	xu3	xu3",
+    "shuan1	shuan1	shuan4	shuan1",
+    "ye3	ye4	ye4	ye4	ye2	ye4	ye3	ye2	ye4	ye4	ye3	ye1	ye4	ye1	ye1	ye4	ye4	ye4	ye4	ye2	ye4	ye2",
+    "quan2	quan2	quan1	quan4	quan2	quan2	quan2	quan4	quan3	quan2	quan2	quan2	quan2	quan2	quan3	quan2	quan3	quan2	quan1	quan3	quan2	quan2",
+    "che1	che4	che3	che4	che4	che4	che1	che4	che4",
+    "ao4	ao4	ao4	ao2	ao2	ao3	ao1	ao4	ao3	ao2	ao2	ao2	ao2	ao3	ao4	ao4	ao2	ao2	ao2	ao2	ao4	ao4	ao2",
+    "ri4",
+    "mie4	mie4	mie4	mie1	mie1	mie4",
+    "pin3	pin1	pin2	pin2	pin4	pin2	pin2	pin4	pin1	pin3",
+    "zuan1	zuan4	zuan3	zuan3	zuan1",
+    "kua1	kua4	kua3	kua4	kua4	kua3",
+    "nai3	nai3	nai4	nai4	nai4	nai3	nai4	nai4	nai3",
+    "dang1	dang3	dang4	dang4	dang3	dang1	dang1	dang4	dang4	dang4	dang3	dang4",
+    "hen3	hen4	hen3	hen2",
+    "chen2	chen2	chen2	chen2	chen2	chen2	chen4	chen4	chen1	chen1	chen2	chen3	chen1	chen4	chen2	chen2	chen1	chen4	chen4",
+    "ti2	ti3	ti2	ti4	ti1	ti1	ti2	ti4	ti2	ti4	ti4	ti4	ti1	ti4	ti4	ti4	ti2	ti2	ti2	ti1	ti2	ti4	ti2	ti4",
+    "ping2	ping2	ping2	ping2	ping2	ping2	ping2	ping2	ping1	ping1	ping2	ping1	ping2",
+    "feng1	feng1	feng1	feng1	feng4	feng4	feng1	feng2	feng1	feng2	feng4	feng1	feng1	feng3	feng4	feng1	feng1	feng1	feng1	feng1	feng3",
+    "long2	long2	long2	long2	long3	long2	long3	long2	long2	long2	long3	long2	long2	long3	long2	long2	long2",
+    "zhen1	zhen4	zhen1	zhen4	zhen1	zhen4	zhen4	zhen4	zhen1	zhen3	zhen1	zhen3	zhen1	zhen4	zhen1	zhen1	zhen1	zhen4	zhen3	zhen1	zhen3	zhen1	zhen1	zhen4	zhen1	zhen3	zhen1	zhen1	zhen3	zhen3	zhen1",
+    "nou4",
+    "cui4	cui4	cui4	cui1	cui1	cui1	cui4	cui4	cui4	cui4	cui3	cui4	cui4	cui1",
+    "mi3	mi4	mi2	mi4	mi2	mi4	mi1	mi1	mi2	mi4	mi2	mi2	mi4	mi4	mi2	mi4	mi4	mi4	mi4	mi3	mi3	mi2	mi2	mi2	mi2	mi2	mi4	mi4	mi3	mi3",
+    "er2	er2	er4	er3	er3	er3	er3	er3	er4	er3	er3	er4	er2	er2",
+    "qiang2	qiang1	qiang2	qiang3	qiang1	qiang1	qiang1	qiang2	qiang1	qiang1	qiang1	qiang3	qiang1	qiang1	qiang4	qiang2	qiang3	qiang1	qiang2	qiang2	qiang1",
+    "cong2	cong1	cong1	cong2	cong1	cong1	cong2	cong2	cong1	cong1	cong1	cong1",
+    "ge4	ge4	ge2	ge1	ge1	ge2	ge2	ge1	ge1	ge2	ge1	ge2	ge1	ge1	ge1	ge1	ge1	ge2	ge2	ge4	ge2	ge2	ge4	ge1	ge3	ge4	ge1	ge1	ge3	ge2	ge2	ge2",
+    "fen1	fen4	fen1	fen4	fen3	fen4	fen1	fen1	fen2	fen1	fen4	fen2	fen4	fen2	fen2	fen1	fen4	fen2	fen4	fen4",
+    "sen1",
+    "qin1	qin1	qin2	qin2	qin2	qin1	qin3	qin2	qin2	qin4	qin2	qin2	qin1	qin4	qin4	qin2	qin2	qin2	qin2	qin2	qin3",
+    "jiao4	jiao1	jiao4	jiao3	jiao4	jiao3	jiao1	jiao1	jiao4	jiao1	jiao3	jiao1	jiao1	jiao3	jiao1	jiao3	jiao3	jiao3	jiao3	jiao1	jiao3	jiao1	jiao1	jiao1	jiao3	jiao4	jiao3	jiao4	jiao3	jiao1	jiao1	jiao4	jiao1	jiao3	jiao3	jiao1	jiao1	jiao4	jiao3	jiao3	jiao4	jiao3	jiao1	jiao1",
+    "pa4	pa2	pa4	pa1	pa1	pa2	pa2	pa1	pa2",
+    "ju4	ju4	ju2	ju4	ju3	ju1	ju4	ju4	ju4	ju4	ju4	ju4	ju4	ju3	ju2	ju1	ju1	ju3	ju1	ju1	ju2	ju2	ju3	ju4	ju4	ju1	ju1	ju4	ju3	ju4	ju1	ju1	ju4	ju4	ju3	ju3	ju4	ju3	ju4	ju1	ju4	ju1	ju4	ju1	ju1	ju3	ju1	ju4	ju1	ju4	ju1	ju4",
+    "gai1	gai3	gai4	gai4	gai4	gai4	gai4	gai1	gai1	gai1	gai4",
+    "ke3	ke1	ke4	ke4	ke4	ke4	ke1	ke3	ke1	ke1	ke2	ke1	ke1	ke1	ke1	ke3	ke4	ke1	ke1	ke1	ke1	ke1	ke4	ke1	ke4	ke1	ke4	ke4	ke1	ke4	ke3	ke1",
+    "zeng1	zeng4	zeng1	zeng4	zeng4	zeng1	zeng1",
+    "li3	li3	li4	li4	li4	li2	li3	li4	li4	li4	li3	li4	li4	li2	li4	li2	li1	li4	li4	li4	li4	li2	li2	li2	li2	li4	li2	li4	li2	li4	li4	li4	li2	li3	li4	li3	li4	li4	li2	li2	li2	li4	li4	li3	li4	li4	li4	li2	li4	li4	li4	li2	li4	li3	li3	li2	li3	li2	li4	li4	li4	li3	li4	li4	li4	li4	li4	li4	li3	li2	li2	li4	li2	li4	li4	li2",
+    "qiao2	qiao3	qiao2	qiao1	qiao1	qiao2	qiao4	qiao4	qiao2	qiao4	qiao2	qiao1	qiao2	qiao4	qiao4	qiao4	qiao1	qiao1	qiao2	qiao4	qiao2	qiao3	qiao1	qiao1	qiao2	qiao1",
+    "chuang1	chuang2	chuang4	chuang3	chuang2	chuang1	chuang4",
+    "luo4	luo1	luo4	luo4	luo2	luo3	luo2	luo2	luo4	luo2	luo2	luo4	luo2	luo2	luo4	luo3	luo4	luo3	luo4	luo4	luo2	luo4	luo2	luo3	luo2",
+    "na4	na2	na3	na4	na4	na4	na4	na4	na4	na4	na2",
+    "ming2	ming2	ming4	ming2	ming2	ming2	ming2	ming2	ming3	ming2	ming2	ming2",
+    "ze2	ze2	ze2	ze2	ze2	ze4	ze2	ze2	ze2	ze2	ze2	ze4	ze2",
+    "shui3	shui4	shui4	shui5",
+    "dou1	dou4	dou3	dou4	dou4	dou1	dou3	dou4	dou3	dou4	dou1	dou1",
+    "liu2	liu2	liu4	liu2	liu3	liu1	liu2	liu2	liu2	liu2	liu2	liu2	liu2	liu3	liu1	liu2	liu2	liu2	liu2	liu4	liu3",
+    "kang1	kang4	kang4	kang2	kang1	kang4	kang1	kang4	kang4	kang1",
+    "nei4	nei3",
+    "xiao3	xiao4	xiao1	xiao4	xiao4	xiao3	xiao1	xiao1	xiao4	xiao4	xiao1	xiao1	xiao1	xiao4	xiao1	xiao1	xiao2	xiao1	xiao1	xiao1	xiao1	xiao1	xiao3	xiao1	xiao1	xiao1	xiao1	xiao2",
+    "jiang1	jiang3	jiang1	jiang3	jiang3	jiang4	jiang4	jiang1	jiang1	jiang4	jiang1	jiang1	jiang3	jiang4	jiang1	jiang4	jiang3	jiang4	jiang1	jiang4	jiang1	jiang1",
+    "ga4	ga1	ga1	ga2	ga1	ga3	ga2	ga2",
+    "ou3	ou1	ou3	ou1	ou1	ou3	ou3	ou4	ou1	ou1	ou1",
+    "lai2	lai4	lai2	lai4	lai4	lai4	lai2	lai4	lai2	lai4	lai2	lai2",
+    "zei2",
+    "rui4	rui4	rui3	rui4	rui4	rui2	rui4	rui4",
+    "cu1	cu4	cu4	cu4	cu4	cu4	cu4	cu4	cu2	cu2	cu4",
+    "zen3	zen4",
+    "duo1	duo3	duo3	duo2	duo4	duo2	duo1	duo1	duo4	duo4	duo1	duo4	duo2	duo3	duo4	duo1	duo4	duo3	duo3",
+    "qu4	qu1	qu3	qu4	qu1	qu1	qu1	qu1	qu3	qu1	qu2	qu4	qu2	qu1	qu1	qu1	qu1	qu2	qu1	qu4	qu2	qu2	qu3	qu1	qu2	qu2	qu2	qu1	qu2	qu2	qu2	qu2	qu2",
+    "gua4	gua1	gua1	gua3	gua4	gua4	gua1	gua3	gua1	gua1	gua4	gua1",
+    "xian4	xian1	xian3	xian4	xian4	xian1	xian3	xian4	xian2	xian1	xian4	xian4	xian2	xian4	xian2	xian4	xian2	xian1	xian1	xian2	xian2	xian2	xian4	xian2	xian4	xian2	xian1	xian3	xian4	xian3	xian1	xian4	xian2	xian1	xian1	xian1	xian3	xian3	xian1	xian2	xian3	xian3	xian4	xian1	xian3	xian1",
+    "mang2	mang2	mang2	mang2	mang2	mang3	mang3	mang2	mang2	mang3",
+    "niang2	niang4",
+    "bai2	bai3	bai3	bai4	bai4	bai3	bai1	bai4	bai3	bai1	bai3",
+    "zang4	zang4	zang1	zang4	zang1	zang3",
+    "bin1	bin1	bin1	bin1	bin4	bin1	bin1	bin4	bin1	bin4	bin4	bin1	bin1	bin1	bin4	bin1",
+    "rong2	rong2	rong2	rong2	rong2	rong2	rong2	rong1	rong2	rong3	rong2	rong2	rong2	rong2	rong2",
+    "ba3	ba5	ba1	ba1	ba4	ba4	ba2	ba4	ba4	ba1	ba1	ba1	ba1	ba2	ba3	ba1	ba1	ba4	ba1	ba4	ba2	ba3	ba1	ba4	ba2	ba2",
+    "hai2	hai3	hai2	hai4	hai1	hai4	hai1	hai2	hai4	hai4	hai3	hai3",
+    "ren2	ren4	ren4	ren3	ren2	ren4	ren4	ren4	ren3	ren2	ren2	ren4	ren3	ren4	ren4	ren4	ren4	ren4",
+    "zhuan3	zhuan1	zhuan1	zhuan4	zhuan4	zhuan4	zhuan4	zhuan4	zhuan1",
+    "hong2	hong1	hong2	hong2	hong2	hong2	hong1	hong2	hong1	hong2	hong2	hong4	hong1	hong1	hong2	hong2	hong2",
+    "she4	she4	she4	she3	she4	she2	she2	she4	she1	she4	she4	she4	she1	she2	she4	she1	she1	she4	she4",
+    "rou4	rou2	rou2	rou2	rou2	rou2",
+    "pou1	pou2	pou2",
+    "cheng2	cheng2	cheng2	cheng1	cheng2	cheng2	cheng2	cheng1	cheng2	cheng2	cheng2	cheng2	cheng2	cheng3	cheng4	cheng1	cheng3	cheng2	cheng2	cheng2	cheng1	cheng1	cheng2	cheng2	cheng2	cheng2",
+    "mao2	mao1	mao4	mao4	mao4	mao4	mao2	mao4	mao2	mao2	mao3	mao2	mao4	mao4	mao4	mao3	mao3	mao2	mao3	mao4	mao3	mao4	mao2	mao2	mao2	mao2",
+    "tie3	tie1	tie1	tie4	tie1",
+    "zhi1	zhi1	zhi3	zhi2	zhi3	zhi4	zhi4	zhi4	zhi1	zhi4	zhi4	zhi4	zhi3	zhi4	zhi2	zhi2	zhi3	zhi1	zhi2	zhi4	zhi3	zhi1	zhi3	zhi2	zhi2	zhi1	zhi4	zhi1	zhi1	zhi4	zhi1	zhi1	zhi4	zhi2	zhi3	zhi4	zhi4	zhi4	zhi4	zhi4	zhi3	zhi1	zhi3	zhi4	zhi4	zhi3	zhi3	zhi4	zhi1	zhi4	zhi1	zhi4	zhi4	zhi2	zhi4	zhi4	zhi4	zhi4	zhi4	zhi1	zhi3	zhi1	zhi4	zhi4	zhi4	zhi3	zhi2	zhi4	zhi4	zhi2	zhi4	zhi4	zhi3	zhi2	zhi2	zhi3	zhi4	zhi3	zhi4",
+    "neng2",
+    "gong1	gong1	gong4	gong1	gong1	gong1	gong1	gong4	gong1	gong1	gong1	gong3	gong3	gong1	gong1	gong1	gong3	gong1	gong3	gong3",
+    "lve4	lve4	lve4",
+    "ca1	ca1	ca3",
+    "chao1	chao2	chao2	chao3	chao1	chao2	chao3	chao2	chao1	chao2	chao1	chao1	chao4",
+    "yan3	yan2	yan2	yan4	yan2	yan3	yan1	yan4	yan2	yan2	yan2	yan3	yan4	yan4	yan4	yan2	yan2	yan4	yan4	yan2	yan1	yan3	yan4	yan2	yan1	yan2	yan3	yan4	yan3	yan1	yan2	yan4	yan1	yan1	yan2	yan1	yan2	yan1	yan4	yan3	yan1	yan2	yan4	yan1	yan4	yan4	yan4	yan4	yan3	yan3	yan4	yan4	yan4	yan3	yan2	yan4	yan1	yan3	yan3	yan2	yan1	yan3	yan3",
+    "shi4	shi2	shi4	shi2	shi2	shi3	shi4	shi4	shi3	shi1	shi4	shi1	shi4	shi2	shi4	shi4	shi4	shi2	shi3	shi4	shi4	shi1	shi4	shi4	shi2	shi4	shi1	shi4	shi1	shi4	shi2	shi1	shi4	shi4	shi3	shi4	shi5	shi1	shi3	shi3	shi4	shi2	shi4	shi4	shi4	shi4	shi4	shi1	shi4	shi4	shi4	shi4	shi4	shi4	shi3	shi4	shi1	shi2	shi2	shi4	shi4	shi2	shi1	shi2	shi4	shi2",
+    "pai4	pai2	pai1	pai2	pai2	pai4	pai2	pai4	pai4",
+    "xie1	xie3	xie4	xie2	xie2	xie2	xie2	xie1	xie4	xie2	xie4	xie2	xie4	xie4	xie2	xie4	xie4	xie4	xie2	xie4	xie4	xie2	xie4	xie1	xie1	xie2	xie4	xie4	xie4	xie4	xie2	xie4	xie2	xie4	xie4	xie4	xie4",
+    "weng1	weng1	weng4	weng3	weng4",
+    "suo3	suo3	suo1	suo3	suo3	suo5	suo1	suo1	suo1	suo1	suo3	suo1	suo1	suo1	suo1",
+    "rang4	rang3	rang3	rang3	rang2	rang2	rang2",
+    "song4	song1	song4	song3	song4	song1	song4	song4	song3	song3	song1	song1	song1	song3	song1	song1",
+    "mo2	mo4	mo2	mo4	mo1	mo4	mo2	mo2	mo4	mo4	mo4	mo4	mo2	mo3	mo4	mo2	mo4	mo2	mo2	mo2	mo2	mo4	mo4	mo4	mo2	mo4	mo4	mo4	mo4	mo4",
+    "zhuo1	zhuo1	zhuo1	zhuo2	zhuo1	zhuo2	zhuo2	zhuo2	zhuo2	zhuo2	zhuo2	zhuo1	zhuo2	zhuo2	zhuo1	zhuo2	zhuo2	zhuo2",
+    "zhe4	zhe5	zhe3	zhe5	zhe2	zhe2	zhe1	zhe4	zhe2	zhe2	zhe3	zhe2	zhe4	zhe1	zhe2	zhe3	zhe2	zhe4	zhe4	zhe2	zhe3",
+    "pei4	pei2	pei4	pei2	pei2	pei2	pei1	pei4	pei1	pei4	pei4	pei4	pei4	pei1	pei2",
+    "zhua1",
+    "xiang3	xiang4	xiang1	xiang4	xiang4	xiang3	xiang1	xiang4	xiang1	xiang3	xiang1	xiang2	xiang2	xiang1	xiang4	xiang2	xiang1	xiang1	xiang4	xiang1	xiang3	xiang1	xiang3	xiang1	xiang3	xiang2	xiang1	xiang1	xiang4",
+    "gao1	gao4	gao3	gao3	gao1	gao1	gao1	gao3	gao3	gao1	gao1	gao1	gao4	gao3	gao3	gao4	gao3	gao1	gao4",
+    "zuo4	zuo4	zuo4	zuo4	zuo3	zuo2	zuo3	zuo2	zuo4	zuo4	zuo4	zuo4	zuo4",
+    "zou3	zou4	zou4	zou1	zou1	zou1	zou1	zou1	zou4	zou1",
+    "zhuai1",
+    "bi3	bi4	bi3	bi4	bi4	bi4	bi3	bi2	bi4	bi1	bi4	bi4	bi4	bi3	bi4	bi4	bi4	bi4	bi4	bi4	bi4	bi3	bi4	bi4	bi4	bi4	bi4	bi4	bi4	bi3	bi2	bi3	bi4	bi4	bi3	bi4	bi3	bi4	bi4	bi4	bi4	bi4	bi4	bi4	bi4	bi4	bi4	bi4	bi4	bi4	bi4	bi3",
+    "bie2	bie1	bie3	bie2	bie1",
+    "hua2	hua4	hua4	hua1	hua4	hua4	hua2	hua1	hua2	hua4	hua2	hua2",
+    "e2	e4	e2	e4	e2	e2	e4	e4	e4	e4	e4	e4	e4	e2	e4	e2	e2	e1	e4	e1	e4	e4	e4	e4	e4	e2	e4	e4	e4	e2	e4",
+    "jie3	jie2	jie1	jie4	jie3	jie2	jie1	jie4	jie1	jie4	jie2	jie2	jie4	jie4	jie2	jie1	jie1	jie2	jie2	jie2	jie4	jie2	jie2	jie2	jie2	jie4	jie1	jie2	jie2	jie1	jie2	jie2	jie4	jie2	jie1	jie1	jie4	jie2	jie2	jie4",
+    "yong4	yong3	yong1	yong3	yong3	yong1	yong1	yong3	yong1	yong3	yong1	yong1	yong3	yong3	yong3	yong1	yong3	yong2	yong3	yong1	yong1	yong1	yong1	yong1	yong1",
+    "ru2	ru4	ru3	ru3	ru2	ru3	ru2	ru4	ru2	ru2	ru2	ru2	ru4	ru4	ru2	ru4	ru2	ru2	ru4	ru2",
+    "yang4	yang2	yang1	yang2	yang3	yang2	yang2	yang3	yang2	yang3	yang3	yang4	yang2	yang1	yang1	yang4	yang1	yang1	yang4	yang1	yang2	yang2	yang2	yang2	yang2",
+    "o2	o1	o1",
+    "te4	te4	te4	te4	te4",
+    "dong4	dong1	dong3	dong4	dong1	dong3	dong4	dong1	dong4	dong4	dong4	dong4	dong4	dong1	dong1	dong4	dong1	dong4	dong4",
+    "guo2	guo4	guo3	guo1	guo1	guo3	guo1	guo2	guo2	guo3	guo2	guo3	guo1	guo1	guo3	guo1",
+    "que4	que4	que1	que4	que2	que4	que1	que4	que4	que4",
+    "guan1	guan3	guan1	guan1	guan3	guan4	guan4	guan4	guan1	guan4	guan1	guan3	guan1	guan4	guan4	guan1	guan4	guan4",
+    "bing4	bing1	bing4	bing1	bing3	bing3	bing3	bing3	bing3	bing3	bing3	bing1	bing3",
+    "liao4	liao2	liao2	liao2	liao2	liao2	liao1	liao4	liao4	liao2	liao2	liao4	liao2	liao2	liao2	liao3	liao4	liao2	liao3",
+    "meng4	meng2	meng3	meng2	meng4	meng2	meng2	meng3	meng2	meng2	meng3	meng3	meng3	meng3	meng2	meng3	meng2	meng2	meng2",
+    "mou3	mou2	mou2	mou2	mou2	mou1	mou2	mou2	mou2",
+    "duan4	duan4	duan1	duan3	duan4	duan4	duan4	duan4	duan4",
+    "dun4	dun4	dun1	dun1	dun1	dun4	dun4	dun1	dun4	dun4	dun3	dun4	dun3	dun1	dun4",
+    "pan4	pan2	pan4	pan4	pan1	pan1	pan4	pan4	pan2	pan2	pan2	pan4	pan4	pan4",
+    "nao3	nao4	nao3	nao2	nao3	nao4	nao2	nao1	nao2	nao2	nao3	nao2	nao2",
+    "qun2	qun2	qun1",
+    "rao4	rao3	rao2	rao2	rao2	rao2",
+    "huo2	huo4	huo3	huo3	huo4	huo4	huo4	huo4	huo4	huo1	huo3	huo4	huo1	huo4	huo4	huo4	huo4	huo3	huo1	huo1	huo1",
+    "re4	re3",
+    "sheng1	sheng1	sheng3	sheng4	sheng4	sheng1	sheng4	sheng4	sheng1	sheng2	sheng1	sheng1	sheng3	sheng4",
+    "gang1	gang3	gang1	gang3	gang1	gang1	gang1	gang1	gang1	gang1	gang4	gang4",
+    "chan3	chan2	chan4	chan2	chan3	chan1	chan4	chan3	chan2	chan2	chan2	chan3	chan2	chan2	chan2	chan4	chan2	chan2	chan2	chan3	chan3	chan1	chan3	chan2",
+    "yin1	yin1	yin3	yin4	yin2	yin3	yin1	yin3	yin3	yin2	yin2	yin1	yin1	yin1	yin3	yin1	yin2	yin4	yin2	yin3	yin1	yin1	yin2	yin1	yin2	yin2	yin2	yin1	yin1	yin3	yin4	yin3",
+    "shan1	shan4	shan3	shan1	shan1	shan4	shan1	shan3	shan1	shan4	shan4	shan1	shan4	shan1	shan1	shan4	shan1	shan4	shan4	shan4	shan4	shan1	shan1	shan1	shan4	shan4	shan4	shan1	shan4	shan1	shan4	shan1	shan1",
+    "zha4	zha1	zha3	zha4	zha2	zha4	zha4	zha1	zha1	zha2	zha1	zha1	zha4	zha4	zha4	zha2	zha4	zha1	zha1	zha3	zha4	zha1",
+    "nuo4	nuo2	nuo4	nuo4	nuo2	nuo4	nuo4	nuo4",
+    "dan4	dan1	dan4	dan4	dan4	dan1	dan4	dan3	dan1	dan4	dan1	dan4	dan1	dan4	dan3	dan1	dan4	dan4	dan1	dan1	dan1	dan3	dan1	dan4	dan1	dan3",
+    "lei4	lei4	lei2	lei1	lei4	lei3	lei3	lei3	lei2	lei3	lei2	lei2	lei3	lei5	lei3	lei2	lei4	lei2	lei2",
+    "po4	po2	po4	po3	po1	po1	po1	po4	po4	po3	po4	po2	po3	po2	po1	po3",
+    "reng2	reng1",
+    "biao3	biao1	biao1	biao1	biao3	biao1	biao3	biao1	biao1	biao1	biao1	biao1	biao1	biao4	biao1	biao1	biao1",
+    "ka3	ka1	ka1	ka1	ka3	ka3",
+    "wen2	wen4	wen1	wen2	wen3	wen3	wen2	wen4	wen2	wen2	wen1	wen3	wen3	wen2	wen2	wen4",
+    "lian3	lian2	lian2	lian4	lian4	lian2	lian2	lian2	lian2	lian4	lian4	lian3	lian2	lian2	lian3	lian4	lian2	lian4	lian2	lian2	lian4	lian2	lian3	lian3	lian2	lian2",
+    "cuan4	cuan4	cuan1	cuan1	cuan4	cuan1	cuan1",
+    "bang1	bang1	bang4	bang3	bang4	bang3	bang3	bang4	bang4	bang4	bang1	bang1	bang4	bang4",
+    "zhang3	zhang1	zhang1	zhang3	zhang4	zhang4	zhang4	zhang4	zhang3	zhang4	zhang4	zhang4	zhang1	zhang1	zhang4	zhang1	zhang1	zhang1	zhang1	zhang4	zhang4	zhang3	zhang1	zhang1",
+    "ling4	ling3	ling4	ling2	ling2	ling2	ling2	ling2	ling2	ling2	ling3	ling2	ling2	ling2	ling2	ling2	ling2	ling2	ling4	ling2	ling2	ling2	ling2	ling2	ling2	ling2	ling2",
+    "tai4	tai2	tai4	tai2	tai4	tai1	tai2	tai4	tai2	tai2	tai4	tai4	tai2	tai2	tai4	tai2",
+    "piao1	piao4	piao4	piao3	piao2	piao2	piao1	piao1	piao4	piao3	piao1",
+    "lan2	lan2	lan4	lan3	lan2	lan2	lan3	lan2	lan2	lan4	lan3	lan2	lan2	lan2	lan3	lan3	lan2	lan2	lan2	lan2	lan3	lan3",
+    "nie1	nie4	nie4	nie4	nie4	nie4	nie4	nie4	nie4	nie4	nie4	nie4	nie4",
+    "man3	man4	man4	man4	man2	man2	man4	man2	man2	man4	man2	man2	man3	man4	man4	man1	man4	man4	man2",
+    "ne5	ne4	ne4",
+    "jin4	jin1	jin1	jin4	jin3	jin3	jin3	jin4	jin1	jin4	jin3	jin1	jin1	jin3	jin4	jin1	jin4	jin1	jin4	jin1	jin3	jin4	jin4	jin4	jin4	jin3	jin4	jin3	jin1	jin3	jin3	jin4	jin1	jin3	jin4",
+    "chu1	chu4	chu2	chu1	chu3	chu4	chu3	chu2	chu3	chu3	chu4	chu2	chu2	chu2	chu4	chu4	chu2	chu3	chu4	chu4	chu2	chu4	chu2	chu2	chu2	chu3	chu4	chu4	chu1",
+    "chuo4	chuo1	chuo4	chuo4	chuo4	chuo1",
+    "fan3	fan3	fan4	fan1	fan4	fan4	fan2	fan2	fan1	fan2	fan4	fan1	fan4	fan1	fan4	fan2	fan1	fan1	fan2	fan4	fan2	fan2	fan2	fan2",
+    "chuan2	chuan1	chuan2	chuan1	chuan4	chuan3	chuan4	chuan2	chuan3	chuan2	chuan1	chuan2	chuan1",
+    "pao3	pao4	pao4	pao1	pao2	pao2	pao2	pao2	pao4	pao2	pao2	pao1",
+    "hui4	hui2	hui1	hui1	hui3	hui4	hui1	hui3	hui1	hui4	hui4	hui4	hui4	hui1	hui4	hui4	hui4	hui4	hui4	hui1	hui1	hui4	hui1	hui4	hui4	hui4	hui4	hui2	hui2	hui1	hui2	hui4	hui1	hui1	hui4	hui1	hui4	hui4	hui4",
+    "ying1	ying3	ying1	ying4	ying2	ying2	ying4	ying1	ying2	ying2	ying1	ying2	ying1	ying2	ying2	ying3	ying1	ying1	ying1	ying2	ying1	ying2	ying3	ying2	ying1	ying1	ying1	ying2	ying2	ying2	ying2	ying3	ying2	ying4	ying2	ying1	ying1	ying3",
+    "fang1	fang4	fang2	fang2	fang3	fang3	fang1	fang2	fang1	fang3	fang3	fang2	fang3	fang1	fang2	fang1	fang1	fang1",
+    "gui1	gui1	gui3	gui4	gui4	gui4	gui4	gui3	gui1	gui1	gui3	gui1	gui4	gui1	gui4	gui1	gui1	gui1	gui3	gui1	gui3	gui4	gui4	gui4	gui3	gui3	gui3	gui3	gui1",
+    "jia1	jia1	jia3	jia4	jia4	jia3	jia1	jia4	jia4	jia1	jia1	jia3	jia2	jia4	jia1	jia1	jia1	jia1	jia1	jia2	jia3	jia2	jia1	jia3	jia3	jia1	jia1	jia1	jia2	jia2	jia2	jia1	jia2	jia1	jia1	jia3",
+    "sun1	sun3	sun3	sun3	sun1	sun1	sun3	sun1",
+    "tong2	tong3	tong1	tong4	tong2	tong2	tong3	tong3	tong2	tong3	tong2	tong2	tong4	tong2	tong1	tong2	tong2	tong2	tong2	tong2	tong2",
+    "mu4	mu4	mu3	mu3	mu4	mu4	mu4	mu4	mu4	mu4	mu3	mu3	mu4	mu4	mu3	mu4	mu4	mu4	mu4	mu3	mu2",
+    "nuan3",
+    "lun4	lun2	lun2	lun2	lun2	lun1	lun2	lun2",
+    "gu4	gu3	gu1	gu4	gu3	gu3	gu1	gu3	gu4	gu3	gu1	gu1	gu1	gu4	gu1	gu1	gu1	gu3	gu3	gu1	gu3	gu4	gu1	gu4	gu3	gu1	gu4	gu4	gu3	gu3	gu3	gu1	gu3	gu1	gu3	gu1	gu3	gu3	gu3	gu1	gu4	gu4",
+    "guang1	guang3	guang4	guang1	guang3	guang1	guang1",
+    "wang4	wang3	wang2	wang3	wang4	wang2	wang1	wang4	wang4	wang3	wang3	wang3	wang3	wang3",
+    "hun1	hun2	hun1	hun4	hun2	hun1	hun2	hun4	hun1	hun4",
+    "shun4	shun4	shun4	shun3",
+    "guai4	guai1	guai3	guai1",
+    "wo3	wo4	wo1	wo4	wo4	wo1	wo1	wo4	wo4	wo1	wo4	wo1	wo4	wo1	wo4	wo4",
+    "yao4	yao2	yao4	yao1	yao3	yao4	yao2	yao1	yao1	yao4	yao2	yao2	yao2	yao2	yao1	yao2	yao1	yao1	yao2	yao3	yao3	yao3	yao4	yao4	yao2	yao2	yao2	yao2	yao3	yao2	yao2",
+    "sang1	sang4	sang3	sang3	sang3	sang3",
+    "jing1	jing1	jing1	jing4	jing4	jing1	jing1	jing3	jing4	jing3	jing4	jing4	jing4	jing3	jing4	jing4	jing1	jing4	jing1	jing3	jing1	jing1	jing3	jing1	jing3	jing4	jing4	jing1	jing1	jing4	jing1	jing3	jing4	jing1	jing4	jing4	jing4	jing3	jing3	jing1",
+    "shou3	shou3	shou4	shou1	shou3	shou4	shou4	shou4	shou4	shou4	shou4	shou5	shou4	shou3",
+    "luan4	luan3	luan2	luan2	luan2	luan2	luan2	luan2	luan2	luan2	luan2",
+    "zan2	zan4	zan4	zan3	zan1	zan1	zan3	zan4	zan4	zan3",
+    "zu3	zu2	zu2	zu3	zu3	zu1	zu2	zu3	zu3	zu2",
+    "pi2	pi1	pi4	pi2	pi1	pi3	pi2	pi2	pi1	pi4	pi4	pi4	pi3	pi3	pi1	pi2	pi1	pi2	pi1	pi4	pi1	pi1	pi4	pi2	pi1	pi2	pi3	pi2	pi1	pi2	pi1	pi3	pi2	pi4	pi3	pi2	pi2	pi3	pi2	pi2	pi3	pi4	pi2",
+    "fei1	fei1	fei4	fei1	fei4	fei1	fei2	fei3	fei4	fei4	fei1	fei3	fei1	fei1	fei4	fei1	fei3	fei3	fei2	fei1	fei3	fei4	fei3	fei1	fei4	fei2	fei4	fei4	fei3",
+    "dao4	dao4	dao3	dao4	dao1	dao3	dao4	dao4	dao1	dao3	dao3	dao3	dao4	dao4	dao4	dao1	dao1	dao1",
+    "dian3	dian4	dian4	dian3	dian4	dian1	dian4	dian1	dian4	dian4	dian4	dian1	dian1	dian4	dian1	dian3	dian4	dian1	dian4	dian4	dian3	dian4	dian4	dian4	dian4",
+    "mai3	mai4	mai4	mai2	mai4	mai4	mai2	mai3	mai4",
+    "le5	le4	le1	le4	le4	le4	le4",
+    "jiu4	jiu3	jiu3	jiu3	jiu1	jiu4	jiu4	jiu4	jiu1	jiu1	jiu4	jiu4	jiu1	jiu1	jiu1	jiu3	jiu4	jiu4	jiu4	jiu4	jiu3	jiu3	jiu1	jiu4	jiu1	jiu4",
+    "wan2	wan4	wan3	wan1	wan2	wan3	wan1	wan3	wan2	wan3	wan4	wan3	wan2	wan3	wan1	wan3	wan2	wan1	wan1	wan3	wan2	wan3	wan3	wan3	wan3	wan2",
+    "ken3	ken3	ken3	ken3	ken3	ken4",
+    "gou4	gou4	gou3	gou1	gou4	gou1	gou1	gou3	gou4	gou1	gou4	gou1	gou4	gou3	gou4	gou4	gou4	gou3	gou1	gou1	gou3",
+    "sao3	sao3	sao1	sao1	sao1	sao1	sao4	sao4	sao1",
+    "huan1	huan4	huan2	huan3	huan4	huan4	huan4	huan4	huan2	huan4	huan2	huan4	huan2	huan4	huan4	huan4	huan4	huan2	huan1	huan4	huan2	huan2	huan4	huan2	huan2	huan4	huan2	huan4",
+    "you3	you4	you2	you3	you2	you4	you2	you1	you2	you1	you2	you1	you2	you1	you4	you4	you4	you3	you2	you1	you3	you1	you4	you2	you4	you2	you4	you2	you3	you4	you2	you4	you2	you3	you2	you4	you2	you4	you2	you3	you3",
+    "cou4	cou4	cou4",
+    "bu4	bu4	bu4	bu4	bu3	bu4	bu3	bu4	bu4	bu4	bu3	bu3	bu4	bu1	bu4	bu1	bu1	bu2",
+    "zhai1	zhai4	zhai2	zhai3	zhai4	zhai1	zhai4	zhai4",
+    "kun4	kun1	kun3	kun1	kun1	kun3	kun3	kun1	kun1	kun1	kun1",
+    "zong3	zong1	zong4	zong1	zong1	zong1	zong4	zong1	zong3	zong1",
+    "xiong1	xiong2	xiong1	xiong1	xiong2	xiong1	xiong1",
+    "lin2	lin2	lin2	lin2	lin2	lin1	lin2	lin3	lin2	lin2	lin4	lin4	lin2	lin2	lin2	lin4	lin2	lin2	lin3	lin4	lin3	lin3	lin2	lin2	lin4",
+    "se4	se4	se4	se4	se4	se4",
+    "chai2	chai1	chai1	chai2	chai2	chai4	chai4",
+    "lao3	lao2	lao2	lao1	lao3	lao3	lao2	lao3	lao4	lao4	lao2	lao4	lao2	lao2	lao3	lao3	lao2	lao4",
+    "zhui1	zhui4	zhui4	zhui1	zhui4	zhui4	zhui1	zhui1	zhui4",
+    "fou3	fou3",
+    "zheng4	zheng4	zheng3	zheng4	zheng1	zheng1	zheng1	zheng4	zheng1	zheng4	zheng1	zheng1	zheng3	zheng1	zheng1	zheng1	zheng4	zheng1	zheng1	zheng1",
+    "zai4	zai4	zai4	zai1	zai3	zai1	zai1	zai3	zai1",
+    "wai4	wai1	wai3",
+    "sha1	sha1	sha3	sha1	sha4	sha4	sha1	sha1	sha1	sha1	sha4	sha1	sha1	sha1	sha1	sha4	sha4",
+    "hei1	hei1",
+    "xiu1	xiu4	xiu1	xiu4	xiu1	xiu4	xiu4	xiu3	xiu4	xiu1	xiu1	xiu1	xiu4	xiu4	xiu1	xiu1	xiu1",
+    "kai1	kai3	kai3	kai1	kai3	kai3	kai3	kai4	kai3	kai3	kai3	kai3	kai1",
+    "ya4	ya5	ya2	ya3	ya1	ya1	ya3	ya1	ya2	ya4	ya2	ya1	ya1	ya2	ya4	ya2	ya4	ya1	ya1	ya2	ya2	ya2	ya2	ya4	ya4	ya4	ya4	ya1	ya2	ya3",
+    "xing4	xing2	xing1	xing2	xing4	xing2	xing4	xing3	xing4	xing2	xing4	xing1	xing2	xing1	xing1	xing4	xing3	xing4	xing2	xing2	xing2",
+    "nong4	nong2	nong2	nong2	nong2	nong2",
+    "cen2	cen2",
+    "kuan1	kuan3	kuan1",
+    "peng2	peng4	peng2	peng3	peng2	peng2	peng2	peng2	peng2	peng1	peng1	peng1	peng1	peng1	peng1	peng2	peng2	peng2",
+    "tao3	tao4	tao2	tao1	tao2	tao1	tao2	tao2	tao1	tao2	tao2	tao1	tao1	tao1	tao2	tao2",
+    "hao3	hao4	hao2	hao2	hao4	hao4	hao3	hao2	hao4	hao4	hao2	hao1	hao2	hao2	hao1	hao4	hao2	hao4	hao2	hao1",
+    "en1	en4	en1",
+    "tan2	tan3	tan4	tan4	tan2	tan1	tan1	tan1	tan4	tan3	tan2	tan2	tan1	tan4	tan2	tan2	tan1	tan3	tan3	tan2	tan2	tan2	tan3	tan2",
+    "cang2	cang1	cang1	cang1	cang1	cang1",
+    "ji3	ji1	ji3	ji4	ji2	ji1	ji4	ji2	ji4	ji2	ji2	ji1	ji2	ji4	ji2	ji4	ji4	ji4	ji1	ji4	ji2	ji1	ji2	ji1	ji1	ji4	ji4	ji4	ji3	ji2	ji1	ji2	ji4	ji4	ji4	ji1	ji1	ji1	ji2	ji4	ji1	ji2	ji2	ji1	ji1	ji1	ji2	ji1	ji1	ji4	ji1	ji1	ji1	ji4	ji4	ji2	ji4	ji1	ji4	ji3	ji1	ji4	ji4	ji4	ji2	ji4	ji2	ji2	ji1	ji1	ji1	ji4	ji2	ji4	ji4	ji1	ji4	ji3	ji2	ji1	ji1	ji1	ji1	ji1	ji2	ji2	ji1	ji2	ji1	ji2	ji3	ji4	ji4	ji2	ji4	ji2	ji4	ji1	ji1	ji3	ji4	ji1	ji3	ji4",
+    "shu1	shu4	shu4	shu4	shu4	shu3	shu4	shu2	shu1	shu1	shu1	shu1	shu3	shu3	shu1	shu1	shu4	shu4	shu3	shu1	shu1	shu3	shu4	shu2	shu3	shu4	shu1	shu1	shu1	shu4	shu2	shu3	shu2	shu1	shu4	shu1	shu3	shu2	shu4	shu1	shu1	shu4	shu1	shu1	shu4",
+    "za2	za2	za3	za1	za1	za1",
+    "qi3	qi2	qi4	qi1	qi4	qi1	qi2	qi2	qi1	qi3	qi3	qi4	qi4	qi2	qi2	qi1	qi3	qi4	qi2	qi1	qi1	qi1	qi2	qi4	qi3	qi2	qi2	qi2	qi2	qi4	qi1	qi4	qi2	qi2	qi2	qi2	qi3	qi2	qi4	qi1	qi1	qi2	qi1	qi2	qi2	qi2	qi3	qi2	qi2	qi4	qi1	qi1	qi4	qi2	qi2	qi2	qi3	qi2	qi2	qi1	qi4	qi2	qi2	qi2	qi3	qi4	qi3	qi1	qi2",
+    "yo1	yo1",
+    "zi4	zi5	zi4	zi1	zi3	zi1	zi3	zi1	zi1	zi3	zi3	zi1	zi1	zi4	zi4	zi1	zi1	zi3	zi3	zi1	zi1	zi4	zi1	zi3	zi1	zi1	zi3	zi1	zi1	zi1	zi1	zi1	zi1	zi1	zi3	zi1",
+    "zhu3	zhu4	zhu4	zhu4	zhu1	zhu2	zhu1	zhu1	zhu4	zhu2	zhu1	zhu4	zhu4	zhu4	zhu2	zhu3	zhu3	zhu1	zhu1	zhu4	zhu3	zhu4	zhu1	zhu4	zhu1	zhu3	zhu4	zhu4	zhu2	zhu3	zhu1	zhu2	zhu1	zhu4	zhu3	zhu1	zhu1	zhu4	zhu4	zhu4	zhu3	zhu1	zhu1	zhu2	zhu1	zhu4	zhu2",
+    "miu4",
+    "nve4	nve4",
+    "ci3	ci4	ci2	ci4	ci2	ci2	ci2	ci4	ci4	ci2	ci2	ci2	ci2	ci1	ci1	ci2	ci2	ci2",
+    "fa1	fa3	fa2	fa2	fa2	fa2	fa2	fa2	fa4	fa2",
+    "xue2	xue3	xue4	xue1	xue1	xue2	xue1	xue4	xue2	xue3	xue2",
+    "gun3	gun4	gun3	gun3	gun3	gun3	gun3	gun3",
+    "run4	run4",
+    "zhan4	zhan4	zhan3	zhan4	zhan1	zhan3	zhan1	zhan3	zhan4	zhan4	zhan1	zhan1	zhan4	zhan1	zhan3	zhan4	zhan1	zhan1	zhan3",
+    "ei2",
+    "sui2	sui1	sui4	sui4	sui4	sui4	sui3	sui4	sui2	sui4	sui4	sui2	sui4	sui1	sui1	sui4	sui1	sui1",
+    "lv4	lv4	lv4	lv3	lv4	lv3	lv3	lv2	lv3	lv3	lv3	lv4	lv3	lv3	lv3	lv2	lv2	lv4	lv3	lv3",
+    "sou1	sou1	sou4	sou1	sou1	sou3	sou3	sou1	sou3	sou3	sou1	sou3	sou1	sou1",
+    "an1	an4	an4	an4	an4	an3	an4	an1	an1	an1	an1	an4	an1	an1	an4	an3	an3	an3",
+    "heng2	heng2	heng2	heng1	heng1	heng2	heng2",
+    "n2",
+    "xun2	xun4	xun4	xun4	xun2	xun2	xun2	xun1	xun4	xun1	xun2	xun4	xun2	xun1	xun1	xun4	xun2	xun4	xun2	xun1	xun2	xun4	xun2	xun2	xun2	xun1	xun4	xun1	xun2	xun1",
+    "men5	men2	men4	men4	men2	men4	men2",
+    "wu2	wu4	wu3	wu4	wu3	wu3	wu1	wu3	wu4	wu2	wu4	wu1	wu3	wu4	wu1	wu1	wu2	wu1	wu3	wu2	wu4	wu4	wu3	wu4	wu3	wu2	wu2	wu2	wu1	wu1	wu4	wu3	wu4	wu4	wu2	wu4	wu4	wu3	wu3	wu4	wu3	wu1	wu3	wu4	wu3	wu4	wu4	wu2	wu4	wu2	wu1	wu4	wu4	wu4",
+    "chou1	chou3	chou2	chou4	chou2	chou2	chou2	chou2	chou3	chou2	chou2	chou2	chou2	chou2	chou2	chou1	chou2",
+    "chong1	chong1	chong2	chong2	chong3	chong1	chong1	chong4	chong1	chong1	chong1",
+    "ran2	ran3	ran2	ran3	ran2	ran3	ran2",
+    "jue2	jue2	jue2	jue2	jue2	jue2	jue2	jue2	jue1	jue2	jue2	jue2	jue2	jue2	jue2	jue2	jue2	jue1	jue2	jue2	jue2	jue2	jue2	jue2	jue2	jue2	jue2	jue2",
+    "shua1	shua3	shua1",
+    "min2	min3	min3	min3	min3	min3	min3	min3	min2	min2	min3	min2	min3	min2",
+    "diu1	diu1",
+    "wa3	wa2	wa1	wa5	wa1	wa4	wa1	wa1	wa3	wa4",
+    "ban4	ban4	ban1	ban3	ban1	ban3	ban4	ban1	ban4	ban1	ban4	ban1	ban3	ban3	ban4	ban1	ban4	ban1	ban1	ban3	ban3",
+    "ding4	ding3	ding1	ding1	ding4	ding1	ding1	ding3	ding4	ding1	ding1	ding4	ding4	ding4	ding1	ding4	ding1	ding1",
+    "zhou1	zhou1	zhou1	zhou4	zhou4	zhou1	zhou4	zhou4	zhou4	zhou1	zhou2	zhou3	zhou3	zhou4	zhou4	zhou1	zhou4	zhou2	zhou4	zhou4	zhou4",
+    "su4	su4	su1	su4	su4	su2	su4	su4	su4	su1	su1	su4	su4	su4	su4	su4	su4	su4	su4	su4	su4",
+    "fu2	fu1	fu4	fu4	fu3	fu2	fu2	fu4	fu4	fu4	fu4	fu2	fu4	fu4	fu2	fu4	fu2	fu3	fu2	fu3	fu2	fu1	fu2	fu4	fu4	fu4	fu4	fu4	fu3	fu2	fu3	fu3	fu2	fu3	fu2	fu4	fu2	fu1	fu2	fu3	fu2	fu3	fu1	fu2	fu4	fu4	fu2	fu2	fu2	fu4	fu4	fu3	fu1	fu2	fu2	fu3	fu2	fu1	fu4	fu2	fu2	fu2	fu2	fu3	fu4	fu2	fu2	fu1	fu1	fu2	fu1	fu2	fu4	fu2	fu4	fu2	fu2	fu2	fu3	fu2	fu2	fu2	fu4",
+    "zhuang1	zhuang4	zhuang1	zhuang4	zhuang4	zhuang1	zhuang1",
+    "chang2	chang3	chang4	chang3	chang1	chang2	chang4	chang2	chang2	chang3	chang4	chang4	chang1	chang2	chang1	chang2	chang3	chang1	chang1	chang1	chang3	chang2	chang1	chang4	chang3",
+    "sa4	sa3	sa1	sa4	sa1	sa1	sa4	sa4",
+    "lia3",
+    "de5	de5	de2	de2	de2",
+    "gen1	gen1	gen4	gen3	gen2	gen4",
+    "shang4	shang1	shang1	shang4	shang3	shang5	shang3	shang1	shang1	shang1	shang1	shang3	shang4",
+    "niao3	niao4	niao3	niao3	niao3	niao4",
+    "kuai4	kuai4	kuai4	kuai4	kuai4	kuai4	kuai4	kuai3	kuai4",
+    "tuan2	tuan1	tuan2	tuan3	tuan4",
+    "ben3	ben1	ben4	ben1	ben3	ben1	ben3	ben4",
+    "die1	die1	die2	die2	die2	die2	die2	die2	die2	die1	die2	die2	die2	die2	die2	die2	die2",
+    "ni3	ni2	ni2	ni3	ni4	ni1	ni4	ni4	ni4	ni4	ni2	ni2	ni3	ni4	ni2	ni2	ni2	ni2	ni4	ni2",
+    "kuo4	kuo4	kuo4	kuo4	kuo4",
+    "zao3	zao4	zao1	zao1	zao3	zao4	zao4	zao4	zao4	zao3	zao4	zao2	zao3	zao3	zao4",
+    "cao3	cao1	cao2	cao1	cao2	cao2	cao2	cao5	cao2	cao2",
+    "pian1	pian4	pian1	pian4	pian1	pian2	pian2	pian2	pian2	pian1",
+    "qia4	qia1	qia4	qia1	qia4	qia1",
+    "zhong1	zhong3	zhong4	zhong1	zhong4	zhong1	zhong1	zhong1	zhong3	zhong4	zhong1	zhong1	zhong3	zhong3	zhong1	zhong1",
+    "xuan3	xuan1	xuan2	xuan2	xuan2	xuan1	xuan1	xuan4	xuan4	xuan1	xuan4	xuan1	xuan2	xuan4	xuan2	xuan3	xuan4	xuan1	xuan1	xuan4	xuan1	xuan4	xuan4	xuan1	xuan4	xuan2",
+    "liang3	liang4	liang4	liang2	liang2	liang2	liang4	liang2	liang4	liang2	liang2	liang4	liang3	liang2	liang2",
+    "nin2",
+    "di4	di3	di4	di1	di4	di2	di4	di4	di3	di2	di1	di1	di2	di4	di2	di2	di3	di4	di4	di2	di2	di2	di4	di3	di4	di2	di3	di1	di3	di2	di2	di1	di3	di1	di4",
+    "shuo1	shuo4	shuo4	shuo4	shuo4	shuo4	shuo4	shuo4	shuo4",
+    "ting1	ting2	ting2	ting3	ting1	ting2	ting2	ting3	ting2	ting1	ting2	ting2	ting1	ting2	ting1	ting3	ting2",
+    "nang2	nang1	nang3	nang3	nang2",
+    "si4	si3	si1	si1	si1	si1	si1	si4	si1	si4	si1	si1	si4	si4	si4	si4	si4	si1	si1	si4	si1	si1	si4	si4	si1	si4	si1	si1	si4	si4	si1",
+    "xia4	xia4	xia4	xia2	xia2	xia1	xia2	xia2	xia1	xia2	xia2	xia2	xia2	xia2	xia2	xia2	xia4	xia2	xia2",
+    "beng1	beng4	beng1	beng2	beng4	beng1	beng4	beng4",
+    "chui1	chui2	chui2	chui2	chui1	chui2	chui2	chui2	chui2",
+    "bao4	bao1	bao3	bao4	bao3	bao4	bao4	bao1	bao2	bao3	bao3	bao4	bao4	bao1	bao3	bao1	bao3	bao2	bao1	bao1	bao3	bao1	bao4	bao1",
+    "xi1	xi4	xi3	xi1	xi1	xi4	xi2	xi2	xi4	xi1	xi3	xi1	xi1	xi2	xi1	xi1	xi1	xi1	xi1	xi1	xi1	xi1	xi2	xi1	xi1	xi1	xi1	xi1	xi4	xi1	xi1	xi1	xi1	xi3	xi3	xi1	xi1	xi1	xi1	xi3	xi1	xi1	xi1	xi1	xi1	xi2	xi1	xi1	xi4	xi3	xi3	xi1	xi1	xi1	xi3	xi1	xi1	xi1	xi4	xi2	xi2	xi4	xi4	xi4	xi1	xi1	xi1	xi1	xi3	xi1	xi1",
+];
+
+// Choices in ZI spelled with traditional characters, in the same order
+pub static ZI_HANT: &[&str] = &[
+    "若	弱	偌	箬",
+    "牛	扭	紐	鈕	妞	忸	狃",
+    "才	採	菜	彩	財	材	猜	裁	踩	蔡	睬",
+    "對	隊	堆	兌	懟	碓	憝	鐓",
+    "前	錢	千	乾	簽	潛	牽	淺	欠	歉	遷	遣	謙	譴	鉛	倩	嵌	虔	茜	鉗	黔	騫	繾	塹	掮	慳	仟	阡	芊	愆	僉	釺	鈐	芡	箝	扦	搴	慊	褰	槧	凵	岍	肷",
+    "堂	唐	躺	湯	糖	倘	趟	燙	塘	淌	膛	棠	搪	儻	螳	鏜	帑	溏	餳	瑭	醣	樘	鐋	耥	螗	羰",
+    "乎	呼	護	戶	胡	忽	互	湖	虎	糊	狐	壺	蝴	惚	唬	弧	葫	滸	扈	滬	琥	瑚	笏	唿	祜	猢	槲	囫	斛	瓠	醐	滹	鶘	觳	怙	烀	煳	戽	岵	冱	軤	鸌	虍",
+    "懷	壞	徊	淮	槐	踝",
+    "邊	變	便	編	遍	辯	辨	扁	鞭	辮	貶	汴	匾	蝙	弁	卞	砭	鯿	褊	煸	碥	窆	籩	苄	忭	緶",
+    "年	念	廿	攆	碾	輾	拈	黏	捻	蔫	輦	鯰	鯰	埝",
+    "撇	瞥	丿	苤	氕",
+    "最	嘴	罪	醉	蕞",
+    "僧",
+    "查	察	差	茶	插	叉	詫	岔	茬	碴	衩	搽	杈	槎	奼	汊	猹	檫	鍤	餷	鑔",
+    "浪	郎	朗	狼	廊	琅	螂	啷	榔	鋃	閬	莨	稂	蒗",
+    "沒	美	每	妹	梅	眉	媒	玫	煤	媚	枚	霉	昧	魅	楣	寐	莓	袂	酶	鎂	湄	嵋	浼	鎇	猸	鶥",
+    "怒	努	奴	弩	駑	孥	胬",
+    "寧	凝	擰	獰	檸	濘	嚀	佞	甯	聹",
+    "曾	層	蹭	噌",
+    "錯	措	搓	挫	撮	磋	銼	蹉	厝	矬	嵯	痤	鹺	脞",
+    "脫	托	拖	妥	陀	駝	唾	鴕	馱	橢	橐	沱	坨	砣	跎	佗	酡	柝	庹	鼉	乇	籜	沲",
+    "他	她	它	塔	踏	塌	拓	榻	蹋	遢	撻	趿	獺	闥	鉈	鰨	溻",
+    "為	位	未	微	委	味	維	圍	威	衛	謂	唯	危	偉	尾	慰	偽	違	薇	喂	魏	韋	惟	尉	畏	胃	萎	巍	葦	蔚	偎	娓	囗	緯	蝟	帷	猥	痿	桅	渭	闈	煒	逶	幃	諉	瑋	韙	嵬	煨	圩	隈	葳	濰	艉	鮪	洧	潿	溈	軎",
+    "吃	持	遲	池	赤	尺	痴	恥	齒	斥	馳	翅	熾	哧	弛	嗤	侈	叱	啻	敕	墀	篪	飭	笞	茌	踟	蚩	魑	鴟	褫	彳	螭	坻	眵	媸	傺	瘛",
+    "愛	埃	艾	哀	挨	哎	礙	唉	矮	癌	曖	藹	隘	靄	噯	鎄	捱	皚	嬡	璦	嗌	砹",
+    "軍	君	均	俊	菌	峻	郡	駿	鈞	竣	浚	麇	皸	捃",
+    "普	撲	鋪	譜	樸	僕	葡	浦	菩	脯	蒲	噗	瀑	曝	圃	匍	璞	溥	莆	蹼	濮	氆	攵	鏷	鐠	攴",
+    "摔	衰	帥	甩	蟀",
+    "少	燒	紹	稍	哨	勺	梢	捎	邵	韶	艄	芍	苕	劭	潲	蛸	筲",
+    "準	諄	肫	窀",
+    "窮	瓊	穹	煢	蛩	邛	跫	芎	筇	銎",
+    "天	田	甜	添	填	舔	恬	腆	闐	殄	忝	畋	掭",
+    "三	散	傘	叄	糝	毿	饊",
+    "黃	皇	荒	慌	晃	謊	恍	惶	煌	凰	幌	潢	徨	璜	簧	磺	蝗	隍	肓	遑	湟	篁	蟥	癀	鰉",
+    "測	策	側	冊	廁	惻",
+    "一	以	意	已	義	議	衣	易	依	疑	醫	異	伊	憶	移	藝	譯	益	遺	亦	億	椅	宜	儀	翼	役	姨	抑	誼	怡	溢	倚	毅	逸	矣	繹	乙	夷	蟻	裔	驛	疫	頤	咦	揖	翌	臆	貽	熠	噫	漪	奕	咿	旖	邑	囈	屹	彝	羿	詣	軼	弋	迤	懿	沂	壹	縊	弈	佚	蜴	胰	銥	悒	翳	痍	飴	肄	衤	癔	猗	挹	刈	翊	詒	釔	薏	圯	欹	佾	鎰	埸	懌	苡	眙	劓	殪	瘞	嶷	鐿	黟	嶧	艤	酏",
+    "尊	遵	樽	鱒	撙",
+    "馬	嗎	媽	碼	麻	嘛	罵	瑪	螞	蟆	嬤	獁	榪	嘜",
+    "冷	愣	楞	稜	塄",
+    "面	免	眠	綿	棉	勉	緬	冕	腼	娩	湎	沔	眄	澠	黽	宀",
+    "口	扣	叩	寇	摳	蔻	芤	瞘	筘",
+    "拉	啦	辣	臘	喇	垃	蠟	剌	邋	旯	瘌	砬",
+    "代	帶	待	呆	袋	戴	逮	黛	歹	貸	玳	怠	殆	岱	傣	呔	迨	駘	埭	甙	紿",
+    "樓	漏	摟	陋	嘍	簍	髏	婁	鏤	僂	蔞	螻	瘻	耬	嶁",
+    "揣	啜	踹	嘬	膪	搋",
+    "漢	含	喊	韓	寒	汗	函	翰	憾	涵	罕	旱	撼	悍	捍	憨	酣	鼾	邯	瀚	焊	頷	晗	菡	蚶	焓	邗	頇	闞	撖",
+    "卷	倦	捐	絹	眷	娟	涓	鵑	鐫	雋	蠲	狷	鄄	錈	桊",
+    "信	心	新	欣	辛	馨	薪	芯	釁	昕	鑫	鋅	忻	歆	忄	囟",
+    "後	候	後	厚	猴	侯	吼	喉	逅	篌	瘊	鱟	堠	餱	骺",
+    "麼",
+    "且	切	竊	怯	妾	愜	鍥	篋	挈	郄",
+    "盆	噴	湓",
+    "昂	骯	盎",
+    "錄	路	露	陸	魯	盧	爐	碌	鹿	蘆	嚕	祿	虜	漉	賂	顱	廬	璐	戮	轆	鹵	麓	擄	鷺	櫓	鱸	擼	潞	瀘	臚	轤	壚	鸕	淥	輅	簏	櫨	艫	氌	鑥	逯",
+    "和	何	合	河	喝	核	呵	荷	赫	盒	賀	劾	鶴	褐	禾	嗬	涸	闔	閡	壑	訶	頜	菏	盍	曷	翮	蚵",
+    "月	越	約	閱	躍	岳	悅	曰	粵	鉞	刖	樾	龠	瀹",
+    "阿	啊	嗄	錒",
+    "找	照	招	趙	召	罩	兆	昭	爪	詔	沼	肇	釗	啁	棹	笊",
+    "女	釹	恧	衄",
+    "更	羹	耕	耿	庚	哽	梗	埂	賡	鯁	綆",
+    "描	妙	秒	廟	苗	渺	瞄	藐	緲	喵	淼	邈	杪	眇	鶓",
+    "雙	爽	霜	孀",
+    "吞	屯	臀	豚	飩	氽	暾",
+    "掉	調	吊	雕	釣	凋	刁	叼	貂	碉	銚	鯛	銱",
+    "件	見	間	建	簡	漸	堅	檢	鍵	肩	監	尖	健	減	劍	艦	箭	兼	艱	剪	奸	鑒	踐	薦	撿	殲	賤	揀	濺	煎	儉	箋	鹼	繭	緘	諫	柬	澗	瞼	戩	餞	礆	鐧	蒹	菅	翦	蹇	僭	腱	囝	犍	毽	襇	韉	鶼	鰹	梘	搛	謇	縑	筧	湔	謭	戔	趼	楗	牮	踺",
+    "嫩	恁",
+    "空	恐	控	孔	倥	箜	崆",
+    "曬	篩	釃",
+    "男	難	南	喃	楠	囡	赧	腩	蝻",
+    "算	酸	蒜	狻",
+    "推	退	腿	頹	褪	蛻	煺",
+    "條	跳	挑	眺	迢	佻	窕	笤	糶	祧	鰷	髫	蜩	齠",
+    "頭	投	透	偷	骰	鈄	亠",
+    "軟	阮	朊",
+    "於	與	語	雨	玉	於	預	餘	遇	魚	育	欲	域	宇	愈	予	鬱	獄	御	愉	愚	浴	豫	寓	羽	譽	漁	喻	輿	裕	娛	俞	虞	餘	諭	逾	禹	嶼	迂	瑜	榆	淤	隅	渝	臾	聿	馭	毓	腴	嫗	盂	芋	揄	峪	禺	諛	覦	鈺	歟	昱	煜	妤	圄	齬	萸	庾	瘀	傴	閾	竽	紆	鬻	燠	鷸	蝓	蜮	雩	瘐	舁	圉	飫	嵛	窳	俁	鵒	窬	蕷	狳	肀",
+    "考	靠	烤	拷	銬	犒	栲	尻",
+    "波	伯	博	播	玻	撥	勃	脖	膊	駁	卜	剝	搏	簸	餑	鉢	跛	帛	菠	舶	箔	渤	鉑	啵	礡	鈸	亳	鵓	檗	踣",
+    "突	圖	土	途	徒	塗	吐	兔	屠	禿	凸	荼	釷	菟	堍	酴",
+    "什	身	神	深	甚	審	伸	申	沈	慎	滲	呻	紳	嬸	腎	莘	哂	蜃	砷	椹	瀋	娠	詵	諗	矧	胂",
+    "等	燈	登	鄧	瞪	凳	蹬	噔	鐙	磴	戥	嶝	簦",
+    "賽	塞	腮	鰓	噻",
+    "存	村	寸	忖	皴",
+    "哈	蛤	鉿",
+    "感	幹	敢	趕	甘	桿	尷	肝	淦	竿	橄	贛	擀	柑	苷	稈	泔	旰	矸	紺	坩	疳	酐	澉",
+    "運	雲	允	暈	芸	孕	韻	昀	勻	蘊	鄆	醖	隕	熨	殞	耘	筠	紜	慍	氳	惲	鄖	狁	韞",
+    "況	狂	礦	框	曠	眶	筐	匡	哐	鄺	誑	誆	夼	壙	纊	貺",
+    "清	情	輕	請	青	慶	傾	晴	卿	氫	頃	擎	蜻	氰	磬	罄	箐	鯖	黥	檠	圊	苘	謦",
+    "誰",
+    "坑	吭	鏗",
+    "虧	愧	逵	潰	窺	奎	魁	葵	盔	饋	睽	喟	匱	聵	馗	簣	夔	巋	揆	憒	隗	暌	蝰	喹	蕢	跬	悝",
+    "列	烈	裂	獵	劣	咧	冽	趔	洌	鬣	捩	躐	埒",
+    "度	讀	獨	毒	督	渡	杜	肚	賭	堵	嘟	妒	睹	篤	瀆	鍍	犢	牘	黷	蠹	碡	髑	櫝	芏",
+    "疼	騰	藤	滕	謄",
+    "航	杭	珩	夯	沆	頏	絎",
+    "苦	哭	庫	褲	酷	枯	窟	骷	絝	嚳	堀	刳",
+    "大	打	達	答	搭	瘩	嗒	噠	沓	耷	褡	韃	妲	靼	怛	笪",
+    "炯	窘	迥	冂	扃	炅",
+    "被	北	備	背	杯	悲	貝	輩	倍	卑	碑	憊	悖	狽	唄	蓓	孛	陂	焙	鋇	碚	褙	鵯	鞴	邶	鐾",
+    "春	純	唇	蠢	淳	醇	椿	鶉	蒓	蝽",
+    "看	刊	堪	砍	坎	侃	檻	勘	瞰	龕	戡	莰",
+    "參	殘	餐	慘	燦	慚	摻	蠶	孱	璨	粲	驂	黲",
+    "給",
+    "求	球	秋	囚	丘	邱	俅	裘	酋	虯	蚯	鰍	泅	糗	遒	逑	巰	楸	蝤	犰	賕	鼽",
+    "旁	胖	龐	乓	螃	滂	逄	耪",
+    "原	源	員	遠	院	願	元	園	圓	緣	援	怨	冤	淵	袁	猿	垣	鴛	苑	轅	沅	媛	鳶	爰	櫞	塬	螈	黿	掾	瑗	垸	眢	箢",
+    "許	需	續	須	序	虛	緒	徐	敘	吁	勖	蓄	旭	噓	絮	婿	恤	胥	墟	煦	蓿	戌	栩	詡	酗	頊	漵	盱	洫	醑	糈",
+    "拴	栓	涮	閂",
+    "也	頁	業	夜	爺	葉	野	耶	液	曳	冶	噎	腋	椰	掖	謁	燁	曄	靨	揶	鄴	鋣",
+    "全	權	圈	勸	泉	拳	荃	券	犬	蜷	痊	詮	顴	鬈	綣	銓	犭	醛	悛	畎	輇	筌",
+    "車	徹	扯	撤	澈	掣	硨	坼	屮",
+    "奧	傲	澳	熬	鰲	襖	凹	懊	拗	敖	嗷	翱	遨	媼	坳	驁	螯	鏖	獒	聱	嶴	鏊	廒",
+    "日",
+    "滅	蔑	篾	咩	乜	蠛",
+    "品	拼	貧	頻	聘	嬪	顰	牝	姘	榀",
+    "鑽	攥	纂	纘	躦",
+    "誇	跨	垮	挎	胯	侉",
+    "奶	乃	耐	奈	鼐	氖	萘	柰	艿",
+    "當	黨	蕩	檔	擋	鐺	襠	宕	碭	凼	讜	菪",
+    "很	恨	狠	痕",
+    "沉	陳	臣	塵	晨	辰	趁	襯	嗔	琛	忱	磣	抻	讖	諶	宸	郴	櫬	齔",
+    "題	體	提	替	梯	踢	蹄	屜	啼	涕	剃	惕	剔	嚏	倜	悌	醍	荑	鵜	銻	緹	逖	綈	裼",
+    "平	評	瓶	憑	屏	蘋	萍	坪	乒	娉	枰	俜	鮃",
+    "風	封	瘋	豐	鳳	奉	峰	逢	鋒	馮	縫	楓	蜂	諷	俸	烽	酆	灃	葑	碸	唪",
+    "龍	隆	籠	朧	攏	嚨	壟	聾	瓏	窿	隴	蘢	櫳	壠	礱	瀧	癃",
+    "真	陣	針	鎮	珍	朕	震	振	偵	枕	貞	診	斟	圳	甄	禎	臻	賑	疹	箴	縝	砧	楨	鴆	榛	軫	胗	蓁	畛	稹	湞",
+    "耨",
+    "脆	翠	粹	催	摧	崔	悴	萃	啐	瘁	璀	淬	毳	榱",
+    "米	密	迷	秘	彌	蜜	咪	眯	謎	覓	靡	禰	謐	泌	糜	冪	嘧	宓	汨	羋	弭	麋	蘼	獼	醚	縻	冖	糸	敉	脒",
+    "而	兒	二	爾	耳	餌	邇	鉺	貳	洱	珥	佴	鴯	鮞",
+    "強	槍	牆	搶	腔	嗆	蹌	薔	鏘	羌	戕	襁	戧	鏹	熗	檣	羥	蜣	嬙	丬	錆",
+    "從	聰	匆	叢	蔥	囪	淙	琮	樅	驄	蓯	璁",
+    "個	各	格	哥	歌	革	隔	戈	割	閣	胳	葛	擱	鴿	咯	疙	圪	嗝	骼	硌	鬲	膈	鉻	紇	舸	虼	袼	仡	哿	搿	塥	鎘",
+    "分	份	紛	奮	粉	憤	芬	氛	墳	吩	糞	焚	忿	汾	鼢	酚	僨	棼	瀵	鱝",
+    "森",
+    "親	侵	琴	秦	勤	欽	寢	芹	擒	沁	禽	噙	衾	撳	唚	螓	芩	嗪	檎	溱	鋟",
+    "叫	交	教	腳	較	角	焦	嬌	轎	驕	攪	郊	膠	狡	澆	絞	剿	繳	矯	蕉	餃	椒	礁	跤	僥	窖	皎	酵	佼	姣	蛟	醮	鮫	鉸	湫	茭	鷦	噍	徼	撟	嶠	敫	僬	艽",
+    "怕	爬	帕	啪	趴	琶	杷	葩	筢",
+    "據	句	局	具	舉	居	劇	巨	拒	聚	距	懼	俱	矩	菊	拘	疽	沮	鞠	駒	桔	橘	咀	鋸	踞	掬	趄	炬	踽	遽	裾	狙	颶	倨	櫸	齟	鉅	莒	苣	雎	詎	鋦	屨	苴	椐	榘	菹	窶	琚	犋	鞫	醵",
+    "該	改	概	蓋	丐	溉	鈣	賅	垓	陔	戤",
+    "可	科	克	客	刻	課	顆	渴	棵	柯	殼	磕	苛	瞌	珂	坷	恪	蝌	嗑	軻	頦	窠	錁	稞	溘	痾	緙	氪	髁	騍	岢	鈳",
+    "增	贈	憎	鋥	甑	繒	罾",
+    "裡	理	力	立	利	離	李	歷	麗	例	禮	粒	厲	璃	莉	黎	哩	勵	吏	栗	隸	狸	梨	漓	釐	瀝	籬	俐	犁	礫	笠	靂	罹	鯉	戾	俚	礪	荔	鸝	喱	蠡	酈	蠣	邐	痢	嚦	儷	藜	唳	蒞	躒	驪	櫟	鋰	娌	蜊	醴	黧	櫪	詈	癘	澧	糲	壢	猁	傈	溧	轢	鱧	蘺	縭	癧	嫠	藶	篥	鱺",
+    "瞧	巧	橋	悄	敲	喬	翹	俏	憔	竅	僑	蹺	樵	峭	鞘	撬	鍬	橇	蕎	誚	譙	愀	劁	繰	鞽	磽",
+    "窗	床	創	闖	幢	瘡	愴",
+    "落	羅	絡	洛	邏	裸	螺	蘿	駱	鑼	騾	摞	籮	玀	珞	蠃	漯	瘰	犖	雒	鏍	濼	欏	倮	腡",
+    "那	拿	哪	納	娜	吶	捺	鈉	衲	肭	鎿",
+    "明	名	命	鳴	銘	冥	茗	瞑	酩	暝	溟	螟",
+    "則	責	澤	擇	嘖	仄	幘	簀	賾	舴	迮	昃	笮",
+    "水	睡	稅	氵",
+    "都	鬥	抖	豆	逗	兜	陡	竇	蚪	痘	篼	蔸",
+    "流	留	六	劉	柳	溜	瀏	榴	琉	硫	瘤	餾	遛	綹	熘	旒	騮	鎏	鎦	鷚	鋶",
+    "康	抗	炕	扛	慷	亢	糠	伉	鈧	閌",
+    "內	餒",
+    "小	笑	消	校	效	曉	銷	蕭	肖	孝	囂	瀟	宵	嘯	簫	逍	淆	硝	哮	霄	綃	梟	筱	驍	嘵	枵	魈	崤",
+    "將	講	江	蔣	獎	降	匠	僵	疆	醬	姜	漿	槳	絳	繮	犟	耩	糨	豇	洚	茳	礓",
+    "尬	嘎	呷	噶	旮	尕	釓	尜",
+    "偶	歐	嘔	鷗	毆	藕	耦	慪	謳	甌	漚",
+    "來	賴	萊	睞	籟	癩	徠	賚	淶	瀨	崍	錸",
+    "賊",
+    "瑞	銳	蕊	睿	芮	蕤	蚋	枘",
+    "粗	促	醋	簇	猝	蹙	蹴	酢	殂	徂	蔟",
+    "怎	譖",
+    "多	朵	躲	奪	墮	踱	哆	咄	跺	惰	掇	剁	鐸	垛	舵	裰	柁	哚	綞",
+    "去	區	取	趣	曲	驅	屈	趨	娶	軀	渠	覷	瞿	蛐	蛆	嶇	黢	衢	祛	闃	癯	劬	齲	麴	蕖	蘧	磲	詘	蠼	氍	璩	朐	鴝",
+    "掛	瓜	刮	寡	褂	卦	聒	剮	鴰	胍	詿	栝",
+    "現	先	顯	線	限	鮮	險	縣	閒	仙	獻	陷	嫌	羨	弦	憲	賢	纖	掀	鹹	銜	嫻	餡	涎	腺	舷	酰	蘚	霰	冼	鍁	莧	癇	暹	躚	秈	蜆	燹	祆	鷳	獫	跣	峴	氙	筅	薟",
+    "忙	茫	芒	盲	氓	莽	蟒	邙	硭	漭",
+    "娘	釀",
+    "白	百	擺	敗	拜	柏	掰	稗	佰	擘	捭",
+    "髒	葬	贓	奘	臧	駔",
+    "賓	彬	濱	斌	鬢	繽	瀕	殯	檳	臏	擯	儐	鑌	豳	髕	玢",
+    "容	榮	融	蓉	熔	絨	溶	茸	戎	冗	榕	嶸	狨	蠑	肜",
+    "把	吧	八	巴	爸	罷	拔	霸	壩	叭	扒	疤	芭	跋	靶	笆	岜	耙	粑	灞	魃	鈀	捌	鮁	菝	茇",
+    "還	海	孩	害	咳	駭	嗨	骸	亥	氦	醢	胲",
+    "人	認	任	忍	仁	刃	韌	紉	稔	壬	亻	葚	荏	飪	仞	妊	衽	軔",
+    "轉	專	磚	賺	撰	篆	饌	囀	顓",
+    "紅	轟	洪	宏	鴻	弘	哄	虹	烘	泓	閎	訌	訇	薨	黌	蕻	葒",
+    "設	社	射	舍	涉	舌	蛇	攝	奢	赦	懾	麝	賒	佘	歙	猞	畲	厙	灄",
+    "肉	柔	揉	蹂	糅	鞣",
+    "剖	掊	裒",
+    "成	程	城	稱	承	乘	誠	撐	呈	懲	澄	橙	丞	逞	秤	瞠	騁	晟	裎	塍	檉	蟶	埕	棖	鋮	酲",
+    "毛	貓	貿	冒	貌	帽	矛	茂	茅	髦	卯	錨	袤	懋	瑁	鉚	峁	氂	昴	耄	泖	瞀	旄	蟊	茆	蝥",
+    "鐵	貼	帖	餮	萜",
+    "之	知	只	直	指	至	制	治	支	質	置	志	止	致	值	執	紙	織	職	智	址	枝	旨	植	殖	芝	秩	脂	吱	稚	汁	肢	滯	侄	徵	擲	摯	幟	峙	窒	趾	蜘	芷	痣	炙	咫	祉	痔	梔	桎	祗	櫛	雉	躑	郅	陟	贄	鷙	騭	卮	枳	胝	帙	蛭	豸	酯	摭	躓	彘	跖	忮	輊	黹	埴	縶	軹	觶	夂	膣",
+    "能",
+    "公	工	共	功	攻	宮	供	貢	恭	弓	躬	拱	鞏	龔	蚣	觥	汞	肱	廾	珙",
+    "略	掠	鋝",
+    "擦	嚓	礤",
+    "超	朝	潮	吵	抄	嘲	炒	晁	鈔	巢	焯	怊	耖",
+    "眼	言	研	驗	嚴	演	煙	厭	顏	沿	延	掩	燕	豔	咽	炎	鹽	焰	宴	岩	淹	衍	雁	檐	焉	閻	奄	硯	儼	嫣	筵	彥	湮	閹	妍	胭	蜒	菸	晏	魘	醃	閆	諺	懨	唁	焱	堰	釅	鼴	偃	灧	讞	贋	兗	芫	饜	鄢	琰	厴	訁	崦	罨	郾",
+    "是	時	事	實	十	使	世	式	始	失	似	師	視	識	示	市	士	石	史	室	試	詩	勢	釋	食	適	施	氏	濕	侍	拾	屍	飾	逝	駛	誓	匙	獅	屎	矢	拭	蝕	仕	嗜	噬	柿	恃	蝨	軾	舐	弒	螫	謚	礻	豕	豉	蓍	鰣	飠	筮	鈰	蒔	鯴	塒	貰	炻",
+    "派	排	拍	牌	徘	湃	俳	蒎	哌",
+    "些	寫	謝	協	鞋	斜	脅	歇	洩	邪	械	攜	屑	卸	諧	蟹	瀉	懈	挾	褻	邂	偕	榭	蠍	楔	擷	燮	廨	瀣	薤	勰	躞	纈	獬	紲	榍	渫",
+    "翁	嗡	甕	蓊	蕹",
+    "所	索	縮	鎖	瑣	嗦	梭	唆	娑	蓑	嗩	睃	羧	嗍	桫",
+    "讓	嚷	壤	攘	瓤	禳	穰",
+    "送	鬆	宋	聳	誦	嵩	頌	訟	悚	慫	忪	淞	崧	竦	菘	凇",
+    "模	默	麽	莫	摸	末	摩	魔	漠	墨	陌	寞	磨	抹	沫	膜	驀	謨	摹	饃	蘑	茉	歿	秣	嫫	瘼	貊	鏌	耱	貘",
+    "桌	捉	卓	灼	拙	濁	酌	啄	鐲	茁	濯	倬	擢	斫	涿	諑	浞	禚",
+    "這	著	者	著	折	哲	遮	浙	轍	輒	褶	蟄	蔗	蜇	摺	赭	謫	鷓	柘	磔	鍺",
+    "配	陪	佩	培	賠	裴	呸	沛	胚	轡	霈	帔	旆	醅	錇",
+    "抓",
+    "想	向	相	像	象	響	香	項	鄉	享	箱	詳	祥	廂	巷	翔	湘	鑲	橡	襄	餉	薌	饗	驤	鮝	庠	緗	葙	蟓",
+    "高	告	搞	稿	糕	膏	羔	鎬	槁	皋	篙	睪	誥	杲	縞	郜	藁	槔	鋯",
+    "作	做	坐	座	左	昨	佐	琢	祚	胙	怍	唑	阼",
+    "走	奏	揍	鄒	騶	陬	諏	鄹	楱	鯫",
+    "拽",
+    "比	必	筆	畢	避	閉	彼	鼻	壁	逼	臂	幣	碧	鄙	蔽	弊	斃	庇	璧	陛	婢	匕	敝	痹	弼	嗶	愎	篦	裨	俾	荸	妣	蹕	蓖	吡	毖	秕	髀	襞	鉍	蓽	薜	篳	嬖	畀	狴	庳	箅	濞	萆	潷	舭",
+    "別	憋	癟	蹩	鱉",
+    "華	話	化	花	畫	劃	滑	嘩	猾	樺	鏵	驊",
+    "俄	惡	額	餓	鵝	娥	鄂	厄	遏	愕	噩	呃	扼	蛾	鰐	峨	訛	婀	諤	屙	顎	萼	鍔	堊	軛	莪	鶚	齶	閼	鋨	苊",
+    "解	結	接	界	姐	節	街	介	階	借	傑	潔	戒	屆	截	皆	揭	劫	捷	竭	誡	睫	孑	頡	婕	芥	嗟	桀	碣	秸	拮	訐	疥	羯	癤	喈	蚧	鮚	卩	骱",
+    "用	永	擁	勇	湧	雍	庸	泳	傭	詠	墉	慵	恿	甬	踴	臃	俑	喁	蛹	壅	鏞	癰	邕	饔	鱅",
+    "如	入	辱	乳	儒	汝	茹	褥	蠕	嚅	濡	孺	縟	溽	銣	蓐	襦	顬	洳	薷",
+    "樣	陽	央	洋	養	楊	揚	仰	羊	癢	氧	漾	佯	鴦	鞅	恙	殃	秧	怏	泱	徉	烊	瘍	煬	蛘",
+    "哦	噢	喔",
+    "特	忑	忒	慝	鋱",
+    "動	東	懂	洞	冬	董	凍	咚	棟	侗	恫	胴	峒	鶇	氡	硐	崬	腖	垌",
+    "國	過	果	鍋	郭	裹	蟈	幗	虢	槨	馘	蜾	堝	崞	猓	咼",
+    "卻	確	缺	雀	瘸	鵲	闕	榷	闋	愨",
+    "關	管	觀	官	館	慣	貫	灌	冠	罐	棺	莞	倌	盥	摜	鰥	鸛	涫",
+    "並	兵	病	冰	餅	柄	稟	秉	丙	炳	摒	冫	邴",
+    "料	聊	療	遼	僚	寥	撩	廖	撂	繚	燎	鐐	嘹	寮	獠	蓼	尥	鷯	釕",
+    "夢	盟	猛	蒙	孟	朦	萌	懵	虻	檬	錳	蜢	勐	蠓	甍	艋	瞢	礞	艨",
+    "某	謀	眸	牟	繆	哞	侔	鍪	蛑",
+    "斷	段	端	短	緞	鍛	椴	煅	籪",
+    "頓	盾	敦	蹲	噸	沌	鈍	墩	遁	燉	盹	囤	躉	礅	砘",
+    "判	盤	叛	盼	潘	攀	畔	拚	磐	蟠	爿	襻	泮	袢",
+    "腦	鬧	惱	撓	瑙	淖	呶	孬	鐃	猱	堖	蟯	硇",
+    "群	裙	逡",
+    "繞	擾	饒	嬈	橈	蕘",
+    "活	或	火	伙	獲	貨	惑	霍	禍	豁	夥	嚯	攉	鑊	蠖	砉	藿	鈥	劐	鍃	耠",
+    "熱	惹",
+    "生	聲	省	聖	勝	升	剩	盛	牲	繩	笙	甥	眚	嵊",
+    "剛	港	鋼	崗	岡	缸	綱	槓	罡	肛	戇	筻",
+    "產	纏	顫	禪	闡	攙	懺	鏟	蟬	饞	潺	諂	嬋	讒	蟾	羼	廛	澶	躔	囅	驏	覘	蕆	鐔",
+    "因	音	引	印	銀	隱	陰	尹	飲	吟	淫	姻	殷	蔭	癮	茵	寅	胤	垠	蚓	喑	氤	夤	洇	狺	鄞	霪	堙	銦	廴	茚	吲",
+    "山	善	閃	衫	刪	扇	珊	陝	杉	擅	訕	煽	膳	跚	姍	鱔	潸	汕	贍	繕	嬗	舢	苫	羶	疝	騸	鄯	埏	蟮	釤	剡	芟	彡",
+    "炸	扎	眨	詐	札	柵	乍	喳	渣	閘	吒	楂	榨	蚱	吒	鍘	柞	揸	哳	砟	痄	齄",
+    "諾	挪	懦	喏	儺	糯	搦	鍩",
+    "但	單	彈	淡	蛋	擔	旦	膽	丹	誕	耽	憚	鄲	氮	撣	眈	澹	啖	殫	聃	簞	疸	儋	萏	癉	賧",
+    "類	淚	雷	勒	累	蕾	壘	磊	擂	儡	羸	鐳	耒	嘞	誄	縲	酹	嫘	檑",
+    "破	婆	迫	頗	坡	潑	泊	魄	珀	叵	粕	鄱	笸	皤	釙	鉕",
+    "仍	扔",
+    "表	標	彪	鏢	婊	飆	裱	膘	鑣	髟	杓	驃	飈	鰾	颮	灬	瘭",
+    "卡	咖	喀	咔	佧	胩",
+    "文	問	溫	聞	穩	吻	紋	汶	蚊	雯	瘟	紊	刎	玟	閿	璺",
+    "臉	連	聯	戀	練	憐	蓮	簾	廉	鏈	煉	斂	漣	鐮	璉	殮	濂	瀲	奩	褳	楝	鰱	襝	蘞	臁	蠊",
+    "竄	篡	躥	攛	爨	汆	鑹",
+    "幫	邦	棒	膀	傍	綁	榜	鎊	磅	謗	梆	浜	蚌	蒡",
+    "長	張	章	掌	丈	帳	仗	障	漲	脹	賬	杖	彰	蟑	瘴	璋	漳	樟	獐	嶂	幛	仉	鄣	嫜",
+    "令	領	另	靈	零	凌	齡	鈴	玲	陵	嶺	伶	聆	菱	綾	翎	羚	櫺	呤	泠	囹	苓	瓴	蛉	柃	酃	鯪",
+    "太	台	態	抬	泰	胎	苔	汰	跆	邰	鈦	肽	薹	炱	酞	鮐",
+    "飄	票	漂	瞟	瓢	嫖	剽	縹	嘌	殍	螵",
+    "蘭	藍	爛	懶	欄	攔	覽	籃	嵐	濫	攬	婪	瀾	闌	欖	纜	斕	襤	讕	鑭	灠	罱",
+    "捏	孽	涅	躡	聶	囁	臬	嚙	鎳	鑷	顳	櫱	隉",
+    "滿	慢	漫	曼	蠻	瞞	蔓	饅	蹣	幔	謾	鰻	蟎	縵	熳	顢	墁	鏝	鞔",
+    "呢	訥	疒",
+    "進	今	金	近	盡	緊	僅	禁	津	勁	錦	巾	斤	謹	晉	筋	浸	襟	靳	矜	瑾	燼	噤	妗	覲	堇	縉	槿	衿	饉	卺	藎	釒	廑	贐",
+    "出	處	除	初	楚	觸	礎	廚	儲	褚	畜	櫥	躇	鋤	搐	矗	雛	杵	黜	怵	芻	絀	躕	滁	蜍	楮	憷	亍	樗",
+    "綽	戳	齪	輟	辶	踔",
+    "反	返	飯	翻	犯	範	凡	煩	番	繁	泛	帆	販	藩	梵	樊	幡	蕃	礬	畈	燔	釩	蘩	蹯",
+    "傳	穿	船	川	串	喘	釧	椽	舛	遄	氚	舡	巛",
+    "跑	炮	泡	拋	袍	刨	咆	庖	皰	狍	匏	脬",
+    "會	回	揮	灰	毀	慧	輝	悔	恢	匯	惠	繪	賄	徽	晦	諱	穢	誨	蕙	暉	詼	彗	麾	卉	燴	喙	薈	茴	蛔	咴	洄	噦	琿	隳	恚	虺	澮	蟪	繢",
+    "應	影	英	硬	營	迎	映	鷹	贏	盈	嬰	螢	櫻	瑩	蠅	穎	瑛	鶯	纓	嬴	罌	熒	潁	縈	鸚	嚶	膺	瀅	瀛	楹	塋	郢	鎣	媵	瀠	瓔	攖	癭",
+    "方	放	房	防	仿	訪	芳	妨	坊	彷	紡	肪	舫	枋	魴	匚	邡	鈁",
+    "規	歸	鬼	貴	跪	桂	櫃	軌	瑰	龜	詭	閨	檜	圭	劊	硅	傀	皈	癸	鮭	晷	炔	劌	鱖	宄	簋	庋	匭	媯",
+    "家	加	假	價	架	甲	夾	駕	嫁	佳	嘉	賈	頰	稼	伽	茄	迦	枷	袈	戛	鉀	莢	珈	岬	胛	葭	痂	浹	郟	鋏	蛺	鎵	恝	笳	跏	瘕",
+    "孫	損	隼	筍	猻	蓀	榫	飧",
+    "同	統	通	痛	童	銅	筒	桶	桐	捅	瞳	彤	慟	佟	嗵	仝	潼	僮	酮	茼	砼",
+    "木	目	母	姆	幕	慕	穆	墓	牧	暮	畝	拇	沐	苜	牡	睦	募	鉬	仫	坶	毪",
+    "暖",
+    "論	輪	倫	淪	侖	掄	綸	圇",
+    "故	古	姑	顧	股	骨	孤	鼓	固	谷	估	咕	辜	雇	箍	呱	菇	蠱	汩	沽	鶻	錮	軲	梏	鵠	鴣	崮	痼	轂	牯	詁	觚	鈷	菰	瞽	蛄	嘏	罟	臌	酤	鯝	牿",
+    "光	廣	逛	咣	獷	胱	桄",
+    "望	往	王	網	忘	亡	汪	妄	旺	枉	惘	罔	魍	輞",
+    "婚	魂	昏	混	渾	葷	餛	諢	閽	溷",
+    "順	瞬	舜	吮",
+    "怪	乖	拐	摑",
+    "我	握	窩	沃	臥	渦	蝸	斡	齷	倭	渥	撾	幄	萵	肟	硪",
+    "要	搖	藥	腰	咬	耀	遙	妖	邀	鑰	堯	姚	謠	窯	吆	瑤	幺	夭	肴	舀	杳	窈	鷂	曜	徭	繇	鰩	爻	崾	珧	軺",
+    "桑	喪	嗓	搡	顙	磉",
+    "經	精	京	靜	竟	驚	睛	警	境	景	鏡	淨	敬	井	徑	競	晶	靖	菁	頸	荊	兢	阱	莖	憬	痙	逕	鯨	旌	靚	涇	儆	脛	粳	獍	弳	婧	肼	剄	腈",
+    "手	首	受	收	守	授	瘦	售	壽	獸	狩	扌	綬	艏",
+    "亂	卵	攣	巒	孿	鸞	欒	鑾	孌	灤	臠",
+    "咱	讚	暫	攢	簪	糌	趲	瓚	鏨	昝",
+    "組	足	族	祖	阻	租	卒	詛	俎	鏃",
+    "皮	批	屁	疲	披	匹	啤	脾	劈	譬	辟	僻	痞	癖	噼	琵	霹	毗	坯	媲	砒	丕	睥	枇	紕	鼙	圮	羆	邳	貔	鈹	疋	蚍	淠	仳	蜱	郫	擗	陴	埤	庀	甓	芘",
+    "非	飛	費	菲	廢	啡	肥	匪	肺	沸	妃	斐	緋	扉	吠	霏	翡	誹	腓	蜚	悱	痱	榧	鯡	狒	淝	芾	鐨	篚",
+    "到	道	導	倒	刀	島	盜	稻	叨	禱	蹈	搗	悼	燾	纛	氘	刂	忉",
+    "點	電	店	典	殿	顛	墊	甸	奠	惦	澱	癲	掂	佃	巔	踮	玷	滇	鈿	靛	碘	簟	癜	阽	坫",
+    "買	賣	麥	埋	脈	邁	霾	蕒	勱",
+    "了	樂	肋	叻	泐	仂	鰳",
+    "就	酒	久	九	究	舊	救	舅	糾	揪	疚	咎	啾	鳩	赳	韭	柩	臼	廄	鷲	玖	灸	鬮	桕	鬏	僦",
+    "完	萬	晚	灣	玩	碗	彎	輓	頑	宛	腕	婉	丸	惋	蜿	皖	紈	豌	剜	綰	烷	琬	畹	脘	菀	芄",
+    "肯	懇	啃	墾	齦	裉",
+    "夠	構	狗	勾	購	溝	鈎	苟	垢	篝	媾	佝	詬	枸	彀	覯	遘	岣	緱	鞲	笱",
+    "掃	嫂	騷	搔	臊	繅	瘙	埽	鰠",
+    "歡	換	環	緩	幻	喚	患	煥	桓	瘓	鬟	宦	寰	浣	渙	豢	奐	圜	獾	漶	繯	洹	逭	鍰	郇	鯇	萑	擐",
+    "有	又	由	友	游	右	油	優	尤	憂	猶	幽	郵	悠	幼	誘	佑	黝	鈾	呦	酉	攸	柚	魷	釉	疣	囿	猷	莠	宥	蝣	鼬	莜	牖	蚰	侑	尢	蚴	蕕	銪	卣",
+    "湊	輳	腠",
+    "不	部	步	布	補	怖	捕	簿	埠	埔	哺	卟	鈈	逋	瓿	晡	鈽	醭",
+    "摘	寨	宅	窄	債	齋	砦	瘵",
+    "困	昆	捆	坤	鯤	閫	悃	錕	琨	髡	醌",
+    "總	宗	縱	蹤	綜	棕	粽	鬃	傯	腙",
+    "兄	雄	胸	凶	熊	匈	洶",
+    "林	臨	鄰	淋	琳	拎	鱗	凜	麟	霖	吝	躪	粼	磷	嶙	賃	轔	遴	檁	藺	懍	廩	啉	瞵	膦",
+    "色	瑟	澀	嗇	銫	穡",
+    "柴	拆	釵	豺	儕	蠆	瘥",
+    "老	勞	牢	撈	姥	佬	嘮	潦	烙	酪	嶗	澇	癆	醪	栳	銠	鐒	耮",
+    "追	墜	綴	錐	贅	惴	騅	隹	縋",
+    "否	缶",
+    "正	政	整	證	爭	徵	睜	鄭	掙	症	怔	蒸	拯	箏	崢	錚	幀	猙	諍	鉦",
+    "在	再	載	災	宰	栽	哉	崽	甾",
+    "外	歪	崴",
+    "殺	沙	傻	莎	廈	啥	剎	紗	煞	砂	霎	鯊	裟	痧	鎩	歃	唼",
+    "黑	嘿",
+    "修	秀	休	袖	羞	繡	嗅	朽	鏽	庥	咻	饈	溴	岫	貅	髹	鵂",
+    "開	凱	慨	揩	愷	楷	鎧	愾	鍇	剴	蒈	塏	鐦",
+    "亞	呀	牙	雅	壓	丫	啞	押	衙	訝	涯	鴉	鴨	崖	婭	芽	軋	吖	椏	蚜	伢	琊	睚	迓	揠	氬	砑	埡	岈	瘂",
+    "性	行	星	形	幸	型	興	醒	姓	刑	杏	腥	邢	猩	惺	悻	擤	荇	滎	陘	硎",
+    "弄	農	濃	噥	膿	儂",
+    "岑	涔",
+    "寬	款	髖",
+    "朋	碰	鵬	捧	蓬	彭	棚	篷	膨	砰	澎	怦	抨	烹	嘭	硼	堋	蟛",
+    "討	套	逃	濤	桃	掏	陶	萄	滔	淘	啕	韜	縧	饕	洮	鞀",
+    "好	號	毫	豪	耗	浩	郝	嚎	皓	昊	壕	蒿	嗥	濠	薅	顥	蠔	灝	貉	嚆",
+    "恩	摁	蒽",
+    "談	坦	探	嘆	壇	貪	攤	灘	炭	毯	譚	潭	癱	碳	痰	檀	坍	袒	忐	覃	曇	郯	鉭	錟",
+    "藏	蒼	倉	艙	滄	傖",
+    "己	機	幾	記	及	基	計	即	際	極	級	擊	集	紀	急	技	濟	繼	激	既	輯	積	吉	跡	雞	寂	寄	季	擠	籍	績	疾	忌	妓	祭	飢	肌	圾	藉	劑	稽	脊	嫉	畸	嘰	姬	棘	緝	譏	冀	唧	羈	磯	悸	髻	汲	伎	嵇	稷	戟	箕	偈	驥	暨	詰	霽	岌	瘠	屐	犄	躋	薺	亟	覬	薊	咭	鯽	麂	楫	畿	芨	乩	璣	賫	笈	蒺	齏	殛	笄	佶	蟣	芰	嚌	蕺	洎	戢	跽	剞	墼	掎	彐	丌	嵴	鱭",
+    "書	數	術	樹	述	屬	束	熟	輸	舒	殊	叔	鼠	署	疏	梳	恕	竪	暑	淑	抒	曙	墅	贖	薯	漱	蔬	倏	樞	庶	孰	蜀	塾	殳	戍	姝	黍	秫	澍	紓	菽	沭	攄	毹	腧",
+    "雜	砸	咋	咂	匝	拶",
+    "起	其	氣	期	器	七	奇	齊	妻	啟	企	棄	汽	旗	騎	欺	豈	泣	棋	漆	淒	戚	歧	契	乞	祈	崎	琪	琦	迄	棲	砌	麒	祁	祺	淇	綺	臍	憩	蹊	沏	綦	嘁	亓	鰭	俟	杞	頎	岐	訖	萋	柒	葺	畦	耆	騏	屺	蘄	萁	槭	磧	蠐	圻	芪	綮	汔	芑	榿	蜞",
+    "喲	唷",
+    "自	子	字	資	紫	姿	仔	滋	茲	姊	籽	咨	孜	漬	恣	髭	齜	梓	滓	諮	輜	眥	孳	笫	錙	淄	秭	觜	緇	訾	趑	貲	粢	嵫	耔	鯔",
+    "主	住	注	助	朱	逐	珠	豬	駐	竹	諸	祝	築	柱	燭	煮	囑	株	蛛	鑄	矚	佇	誅	貯	侏	拄	箸	蛀	竺	丶	洙	躅	茱	炷	渚	櫧	銖	杼	翥	苧	麈	瀦	櫫	舳	邾	疰	瘃",
+    "謬",
+    "虐	瘧",
+    "此	次	詞	刺	辭	磁	慈	賜	伺	茨	瓷	雌	祠	疵	呲	糍	鷀	茈",
+    "發	法	乏	罰	伐	閥	筏	砝	琺	垡",
+    "學	雪	血	薛	削	穴	靴	謔	踅	鱈	澩",
+    "滾	棍	袞	鯀	輥	磙	緄	丨",
+    "潤	閏",
+    "站	戰	展	占	沾	盞	粘	斬	綻	棧	氈	詹	湛	瞻	嶄	蘸	譫	旃	搌",
+    "誒",
+    "隨	雖	歲	碎	遂	隧	髓	祟	綏	穗	邃	隋	燧	睢	荽	誶	濉	眭",
+    "律	慮	綠	旅	率	呂	履	驢	縷	侶	屢	濾	褸	捋	鋁	櫚	閭	氯	膂	穭",
+    "搜	艘	嗽	嗖	颼	擻	叟	餿	藪	嗾	溲	瞍	鎪	螋",
+    "安	案	暗	按	岸	俺	黯	庵	鞍	諳	氨	胺	鵪	桉	犴	揞	銨	垵",
+    "恆	橫	衡	哼	亨	蘅	桁",
+    "嗯",
+    "尋	訊	迅	訓	巡	詢	循	勳	遜	熏	旬	殉	馴	醺	薰	汛	峋	徇	潯	塤	荀	巽	鱘	蕁	洵	窨	蕈	曛	恂	獯",
+    "們	門	悶	懣	捫	燜	鍆",
+    "無	物	五	務	武	午	屋	舞	誤	吳	悟	烏	伍	霧	污	嗚	吾	巫	捂	梧	勿	晤	侮	兀	嫵	蕪	唔	毋	鄔	誣	塢	鵡	戊	杌	蜈	騖	鶩	仵	忤	焐	憮	鎢	牾	寤	廡	婺	痦	浯	阢	鼯	圬	迕	芴	鋈",
+    "抽	醜	仇	臭	愁	籌	酬	綢	瞅	疇	躊	稠	惆	儔	讎	瘳	幬",
+    "衝	充	蟲	崇	寵	憧	忡	銃	舂	茺	艟",
+    "然	染	燃	冉	髯	苒	蚺",
+    "覺	決	絕	爵	嚼	掘	訣	倔	撅	厥	孓	崛	攫	抉	獗	譎	蹶	噘	珏	噱	矍	蕨	鐝	橛	桷	劂	爝	觖",
+    "刷	耍	唰",
+    "民	敏	憫	抿	閩	泯	皿	閔	岷	珉	愍	緡	鰵	苠",
+    "丟	銩",
+    "瓦	娃	挖	哇	蛙	襪	窪	媧	佤	膃",
+    "半	辦	般	版	班	板	伴	搬	扮	斑	瓣	頒	阪	阪	絆	扳	拌	瘢	癍	鈑	舨",
+    "定	頂	丁	盯	訂	釘	叮	鼎	錠	酊	仃	啶	腚	鋌	玎	碇	疔	耵",
+    "周	州	洲	宙	皺	舟	驟	咒	晝	粥	軸	肘	帚	縐	紂	謅	胄	妯	籀	葤	酎",
+    "訴	速	蘇	素	宿	俗	肅	塑	粟	穌	酥	簌	溯	夙	愫	謖	蔌	嗉	觫	涑	僳",
+    "服	夫	父	復	府	福	佛	副	婦	負	富	符	附	付	浮	傅	伏	撫	幅	腐	扶	膚	弗	覆	赴	腹	咐	賦	輔	拂	俯	甫	俘	斧	輻	縛	芙	敷	袱	釜	紱	腑	孵	蝠	阜	駙	氟	孚	匐	馥	訃	嘸	呋	鳧	蜉	拊	怫	趺	賻	涪	茯	桴	蚨	黼	鮒	苻	幞	稃	麩	罘	跗	祓	蝮	黻	阝	郛	莩	菔	滏	紼	砩	艴	鰒",
+    "裝	狀	莊	撞	壯	妝	樁",
+    "常	場	唱	廠	昌	嘗	暢	腸	償	敞	倡	悵	娼	嫦	猖	徜	氅	倀	菖	閶	惝	萇	鯧	鬯	昶",
+    "薩	灑	撒	颯	仨	挲	卅	脎",
+    "倆",
+    "的	地	得	德	鍀",
+    "跟	根	亙	艮	哏	茛",
+    "上	傷	商	尚	賞	裳	晌	熵	觴	殤	墒	垧	緔",
+    "鳥	尿	裊	蔦	嬲	脲",
+    "快	塊	筷	儈	膾	噲	獪	蒯	鄶",
+    "團	湍	摶	疃	彖",
+    "本	奔	笨	賁	苯	錛	畚	坌",
+    "爹	跌	疊	蝶	碟	迭	諜	喋	牒	嗲	堞	耋	蹀	鰈	垤	瓞	揲",
+    "你	尼	泥	擬	逆	妮	膩	匿	溺	暱	倪	霓	旎	睨	怩	猊	鈮	鯢	伲	坭",
+    "括	擴	闊	廓	蛞",
+    "早	造	遭	糟	澡	躁	燥	灶	皂	棗	噪	鑿	蚤	藻	唣",
+    "草	操	曹	糙	槽	嘈	漕	艹	螬	艚",
+    "篇	片	偏	騙	翩	蹁	駢	胼	諞	犏",
+    "恰	掐	洽	袷	髂	葜",
+    "中	種	重	終	眾	鐘	忠	衷	腫	仲	鍾	盅	踵	冢	螽	舯",
+    "選	宣	旋	懸	玄	喧	軒	眩	炫	暄	絢	萱	漩	渲	璇	癬	鉉	揎	煊	泫	儇	鏇	楦	諼	碹	痃",
+    "兩	量	亮	良	梁	涼	輛	糧	諒	粱	踉	晾	魎	椋	墚",
+    "您",
+    "第	底	弟	低	帝	敵	蒂	遞	抵	迪	滴	堤	笛	娣	狄	嘀	邸	締	諦	翟	滌	嫡	棣	詆	睇	荻	砥	氐	柢	糴	覿	鏑	骶	羝	碲",
+    "說	爍	碩	朔	鑠	搠	妁	槊	蒴",
+    "聽	停	庭	挺	廳	廷	亭	艇	婷	汀	霆	蜓	町	葶	烴	梃	莛",
+    "囊	囔	攮	曩	饢",
+    "四	死	斯	思	司	絲	私	寺	撕	肆	廝	嘶	嗣	泗	飼	祀	巳	噝	螄	駟	鷥	糹	笥	汜	澌	姒	緦	鍶	兕	耜	厶",
+    "下	夏	嚇	峽	霞	瞎	狹	俠	蝦	轄	匣	暇	遐	黠	瑕	狎	罅	柙	硤",
+    "崩	蹦	繃	甭	迸	嘣	泵	甏",
+    "吹	垂	錘	捶	炊	椎	槌	陲	棰",
+    "報	包	保	抱	寶	暴	爆	胞	薄	飽	堡	鮑	豹	苞	鴇	褒	葆	雹	孢	煲	褓	齙	趵	勹",
+    "西	系	喜	息	希	細	習	席	戲	吸	洗	惜	析	襲	悉	熙	稀	嘻	夕	犧	晰	膝	媳	兮	溪	昔	熄	錫	隙	嬉	皙	犀	唏	禧	璽	奚	曦	淅	蟋	徙	熹	羲	翕	汐	蜥	檄	烯	樨	矽	屣	銑	僖	欷	郗	葸	硒	浠	螅	鬩	覡	隰	舄	禊	餼	穸	粞	鼷	菥	蓰	舾	醯",
+];
+
+// Tuples are (pinyin syllable, 字) for each line of gb2312.tsv
+#[cfg(test)]
+pub static PINYIN_ZI_TEST_DATA: &[(&str, &str)] = &[
+    ("a", "啊"),
+    ("a", "阿"),
+    ("ai", "埃"),
+    ("ai", "挨"),
+    ("ai", "哎"),
+    ("ai", "唉"),
+    ("ai", "哀"),
+    ("ai", "皑"),
+    ("ai", "癌"),
+    ("ai", "蔼"),
+    ("ai", "矮"),
+    ("ai", "艾"),
+    ("ai", "碍"),
+    ("ai", "爱"),
+    ("ai", "隘"),
+    ("an", "鞍"),
+    ("an", "氨"),
+    ("an", "安"),
+    ("an", "俺"),
+    ("an", "按"),
+    ("an", "暗"),
+    ("an", "岸"),
+    ("an", "胺"),
+    ("an", "案"),
+    ("ang", "肮"),
+    ("ang", "昂"),
+    ("ang", "盎"),
+    ("ao", "凹"),
+    ("ao", "敖"),
+    ("ao", "熬"),
+    ("ao", "翱"),
+    ("ao", "袄"),
+    ("ao", "傲"),
+    ("ao", "奥"),
+    ("ao", "懊"),
+    ("ao", "澳"),
+    ("ba", "芭"),
+    ("ba", "捌"),
+    ("ba", "扒"),
+    ("ba", "叭"),
+    ("ba", "吧"),
+    ("ba", "笆"),
+    ("ba", "八"),
+    ("ba", "疤"),
+    ("ba", "巴"),
+    ("ba", "拔"),
+    ("ba", "跋"),
+    ("ba", "靶"),
+    ("ba", "把"),
+    ("ba", "耙"),
+    ("ba", "坝"),
+    ("ba", "霸"),
+    ("ba", "罢"),
+    ("ba", "爸"),
+    ("bai", "白"),
+    ("bai", "柏"),
+    ("bai", "百"),
+    ("bai", "摆"),
+    ("bai", "佰"),
+    ("bai", "败"),
+    ("bai", "拜"),
+    ("bai", "稗"),
+    ("ban", "斑"),
+    ("ban", "班"),
+    ("ban", "搬"),
+    ("ban", "扳"),
+    ("ban", "般"),
+    ("ban", "颁"),
+    ("ban", "板"),
+    ("ban", "版"),
+    ("ban", "扮"),
+    ("ban", "拌"),
+    ("ban", "伴"),
+    ("ban", "瓣"),
+    ("ban", "半"),
+    ("ban", "办"),
+    ("ban", "绊"),
+    ("bang", "邦"),
+    ("bang", "帮"),
+    ("bang", "梆"),
+    ("bang", "榜"),
+    ("bang", "膀"),
+    ("bang", "绑"),
+    ("bang", "棒"),
+    ("bang", "磅"),
+    ("bang", "蚌"),
+    ("bang", "镑"),
+    ("bang", "傍"),
+    ("bang", "谤"),
+    ("bao", "苞"),
+    ("bao", "胞"),
+    ("bao", "包"),
+    ("bao", "褒"),
+    ("bo", "剥"),
+    ("bao", "薄"),
+    ("bao", "雹"),
+    ("bao", "保"),
+    ("bao", "堡"),
+    ("bao", "饱"),
+    ("bao", "宝"),
+    ("bao", "抱"),
+    ("bao", "报"),
+    ("bao", "暴"),
+    ("bao", "豹"),
+    ("bao", "鲍"),
+    ("bao", "爆"),
+    ("bei", "杯"),
+    ("bei", "碑"),
+    ("bei", "悲"),
+    ("bei", "卑"),
+    ("bei", "北"),
+    ("bei", "辈"),
+    ("bei", "背"),
+    ("bei", "贝"),
+    ("bei", "钡"),
+    ("bei", "倍"),
+    ("bei", "狈"),
+    ("bei", "备"),
+    ("bei", "惫"),
+    ("bei", "焙"),
+    ("bei", "被"),
+    ("ben", "奔"),
+    ("ben", "苯"),
+    ("ben", "本"),
+    ("ben", "笨"),
+    ("beng", "崩"),
+    ("beng", "绷"),
+    ("beng", "甭"),
+    ("beng", "泵"),
+    ("beng", "蹦"),
+    ("beng", "迸"),
+    ("bi", "逼"),
+    ("bi", "鼻"),
+    ("bi", "比"),
+    ("bi", "鄙"),
+    ("bi", "笔"),
+    ("bi", "彼"),
+    ("bi", "碧"),
+    ("bi", "蓖"),
+    ("bi", "蔽"),
+    ("bi", "毕"),
+    ("bi", "毙"),
+    ("bi", "毖"),
+    ("bi", "币"),
+    ("bi", "庇"),
+    ("bi", "痹"),
+    ("bi", "闭"),
+    ("bi", "敝"),
+    ("bi", "弊"),
+    ("bi", "必"),
+    ("pi", "辟"),
+    ("bi", "壁"),
+    ("bi", "臂"),
+    ("bi", "避"),
+    ("bi", "陛"),
+    ("bian", "鞭"),
+    ("bian", "边"),
+    ("bian", "编"),
+    ("bian", "贬"),
+    ("bian", "扁"),
+    ("bian", "便"),
+    ("bian", "变"),
+    ("bian", "卞"),
+    ("bian", "辨"),
+    ("bian", "辩"),
+    ("bian", "辫"),
+    ("bian", "遍"),
+    ("biao", "标"),
+    ("biao", "彪"),
+    ("biao", "膘"),
+    ("biao", "表"),
+    ("bie", "鳖"),
+    ("bie", "憋"),
+    ("bie", "别"),
+    ("bie", "瘪"),
+    ("bin", "彬"),
+    ("bin", "斌"),
+    ("bin", "濒"),
+    ("bin", "滨"),
+    ("bin", "宾"),
+    ("bin", "摈"),
+    ("bing", "兵"),
+    ("bing", "冰"),
+    ("bing", "柄"),
+    ("bing", "丙"),
+    ("bing", "秉"),
+    ("bing", "饼"),
+    ("bing", "炳"),
+    ("bing", "病"),
+    ("bing", "并"),
+    ("bo", "玻"),
+    ("bo", "菠"),
+    ("bo", "播"),
+    ("bo", "拨"),
+    ("bo", "钵"),
+    ("bo", "波"),
+    ("bo", "博"),
+    ("bo", "勃"),
+    ("bo", "搏"),
+    ("bo", "铂"),
+    ("bo", "箔"),
+    ("bo", "伯"),
+    ("bo", "帛"),
+    ("bo", "舶"),
+    ("bo", "脖"),
+    ("bo", "膊"),
+    ("bo", "渤"),
+    ("po", "泊"),
+    ("bo", "驳"),
+    ("bu", "捕"),
+    ("bo", "卜"),
+    ("bu", "哺"),
+    ("bu", "补"),
+    ("bu", "埠"),
+    ("bu", "不"),
+    ("bu", "布"),
+    ("bu", "步"),
+    ("bu", "簿"),
+    ("bu", "部"),
+    ("bu", "怖"),
+    ("ca", "擦"),
+    ("cai", "猜"),
+    ("cai", "裁"),
+    ("cai", "材"),
+    ("cai", "才"),
+    ("cai", "财"),
+    ("cai", "睬"),
+    ("cai", "踩"),
+    ("cai", "采"),
+    ("cai", "彩"),
+    ("cai", "菜"),
+    ("cai", "蔡"),
+    ("can", "餐"),
+    ("can", "参"),
+    ("can", "蚕"),
+    ("can", "残"),
+    ("can", "惭"),
+    ("can", "惨"),
+    ("can", "灿"),
+    ("cang", "苍"),
+    ("cang", "舱"),
+    ("cang", "仓"),
+    ("cang", "沧"),
+    ("cang", "藏"),
+    ("cao", "操"),
+    ("cao", "糙"),
+    ("cao", "槽"),
+    ("cao", "曹"),
+    ("cao", "草"),
+    ("ce", "厕"),
+    ("ce", "策"),
+    ("ce", "侧"),
+    ("ce", "册"),
+    ("ce", "测"),
+    ("ceng", "层"),
+    ("ceng", "蹭"),
+    ("cha", "插"),
+    ("cha", "叉"),
+    ("cha", "茬"),
+    ("cha", "茶"),
+    ("cha", "查"),
+    ("cha", "碴"),
+    ("cha", "搽"),
+    ("cha", "察"),
+    ("cha", "岔"),
+    ("cha", "差"),
+    ("cha", "诧"),
+    ("chai", "拆"),
+    ("chai", "柴"),
+    ("chai", "豺"),
+    ("chan", "搀"),
+    ("can", "掺"),
+    ("chan", "蝉"),
+    ("chan", "馋"),
+    ("chan", "谗"),
+    ("chan", "缠"),
+    ("chan", "铲"),
+    ("chan", "产"),
+    ("chan", "阐"),
+    ("chan", "颤"),
+    ("chang", "昌"),
+    ("chang", "猖"),
+    ("chang", "场"),
+    ("chang", "尝"),
+    ("chang", "常"),
+    ("zhang", "长"),
+    ("chang", "偿"),
+    ("chang", "肠"),
+    ("chang", "厂"),
+    ("chang", "敞"),
+    ("chang", "畅"),
+    ("chang", "唱"),
+    ("chang", "倡"),
+    ("chao", "超"),
+    ("chao", "抄"),
+    ("chao", "钞"),
+    ("chao", "朝"),
+    ("chao", "嘲"),
+    ("chao", "潮"),
+    ("chao", "巢"),
+    ("chao", "吵"),
+    ("chao", "炒"),
+    ("che", "车"),
+    ("che", "扯"),
+    ("che", "撤"),
+    ("che", "掣"),
+    ("che", "彻"),
+    ("che", "澈"),
+    ("chen", "郴"),
+    ("chen", "臣"),
+    ("chen", "辰"),
+    ("chen", "尘"),
+    ("chen", "晨"),
+    ("chen", "忱"),
+    ("chen", "沉"),
+    ("chen", "陈"),
+    ("chen", "趁"),
+    ("chen", "衬"),
+    ("cheng", "撑"),
+    ("cheng", "称"),
+    ("cheng", "城"),
+    ("cheng", "橙"),
+    ("cheng", "成"),
+    ("cheng", "呈"),
+    ("cheng", "乘"),
+    ("cheng", "程"),
+    ("cheng", "惩"),
+    ("cheng", "澄"),
+    ("cheng", "诚"),
+    ("cheng", "承"),
+    ("cheng", "逞"),
+    ("cheng", "骋"),
+    ("cheng", "秤"),
+    ("chi", "吃"),
+    ("chi", "痴"),
+    ("chi", "持"),
+    ("shi", "匙"),
+    ("chi", "池"),
+    ("chi", "迟"),
+    ("chi", "弛"),
+    ("chi", "驰"),
+    ("chi", "耻"),
+    ("chi", "齿"),
+    ("chi", "侈"),
+    ("chi", "尺"),
+    ("chi", "赤"),
+    ("chi", "翅"),
+    ("chi", "斥"),
+    ("chi", "炽"),
+    ("chong", "充"),
+    ("chong", "冲"),
+    ("chong", "虫"),
+    ("chong", "崇"),
+    ("chong", "宠"),
+    ("chou", "抽"),
+    ("chou", "酬"),
+    ("chou", "畴"),
+    ("chou", "踌"),
+    ("chou", "稠"),
+    ("chou", "愁"),
+    ("chou", "筹"),
+    ("chou", "仇"),
+    ("chou", "绸"),
+    ("chou", "瞅"),
+    ("chou", "丑"),
+    ("chou", "臭"),
+    ("chu", "初"),
+    ("chu", "出"),
+    ("chu", "橱"),
+    ("chu", "厨"),
+    ("chu", "躇"),
+    ("chu", "锄"),
+    ("chu", "雏"),
+    ("chu", "滁"),
+    ("chu", "除"),
+    ("chu", "楚"),
+    ("chu", "础"),
+    ("chu", "储"),
+    ("chu", "矗"),
+    ("chu", "搐"),
+    ("chu", "触"),
+    ("chu", "处"),
+    ("chuai", "揣"),
+    ("chuan", "川"),
+    ("chuan", "穿"),
+    ("chuan", "椽"),
+    ("chuan", "传"),
+    ("chuan", "船"),
+    ("chuan", "喘"),
+    ("chuan", "串"),
+    ("chuang", "疮"),
+    ("chuang", "窗"),
+    ("chuang", "幢"),
+    ("chuang", "床"),
+    ("chuang", "闯"),
+    ("chuang", "创"),
+    ("chui", "吹"),
+    ("chui", "炊"),
+    ("chui", "捶"),
+    ("chui", "锤"),
+    ("chui", "垂"),
+    ("chun", "春"),
+    ("chun", "椿"),
+    ("chun", "醇"),
+    ("chun", "唇"),
+    ("chun", "淳"),
+    ("chun", "纯"),
+    ("chun", "蠢"),
+    ("chuo", "戳"),
+    ("chuo", "绰"),
+    ("ci", "疵"),
+    ("ci", "茨"),
+    ("ci", "磁"),
+    ("ci", "雌"),
+    ("ci", "辞"),
+    ("ci", "慈"),
+    ("ci", "瓷"),
+    ("ci", "词"),
+    ("ci", "此"),
+    ("ci", "刺"),
+    ("ci", "赐"),
+    ("ci", "次"),
+    ("cong", "聪"),
+    ("cong", "葱"),
+    ("cong", "囱"),
+    ("cong", "匆"),
+    ("cong", "从"),
+    ("cong", "丛"),
+    ("cou", "凑"),
+    ("cu", "粗"),
+    ("cu", "醋"),
+    ("cu", "簇"),
+    ("cu", "促"),
+    ("cuan", "蹿"),
+    ("cuan", "篡"),
+    ("cuan", "窜"),
+    ("cui", "摧"),
+    ("cui", "崔"),
+    ("cui", "催"),
+    ("cui", "脆"),
+    ("cui", "瘁"),
+    ("cui", "粹"),
+    ("cui", "淬"),
+    ("cui", "翠"),
+    ("cun", "村"),
+    ("cun", "存"),
+    ("cun", "寸"),
+    ("cuo", "磋"),
+    ("cuo", "撮"),
+    ("cuo", "搓"),
+    ("cuo", "措"),
+    ("cuo", "挫"),
+    ("cuo", "错"),
+    ("da", "搭"),
+    ("da", "达"),
+    ("da", "答"),
+    ("da", "瘩"),
+    ("da", "打"),
+    ("da", "大"),
+    ("dai", "呆"),
+    ("dai", "歹"),
+    ("dai", "傣"),
+    ("dai", "戴"),
+    ("dai", "带"),
+    ("dai", "殆"),
+    ("dai", "代"),
+    ("dai", "贷"),
+    ("dai", "袋"),
+    ("dai", "待"),
+    ("dai", "逮"),
+    ("dai", "怠"),
+    ("dan", "耽"),
+    ("dan", "担"),
+    ("dan", "丹"),
+    ("dan", "单"),
+    ("dan", "郸"),
+    ("dan", "掸"),
+    ("dan", "胆"),
+    ("dan", "旦"),
+    ("dan", "氮"),
+    ("dan", "但"),
+    ("dan", "惮"),
+    ("dan", "淡"),
+    ("dan", "诞"),
+    ("dan", "弹"),
+    ("dan", "蛋"),
+    ("dang", "当"),
+    ("dang", "挡"),
+    ("dang", "党"),
+    ("dang", "荡"),
+    ("dang", "档"),
+    ("dao", "刀"),
+    ("dao", "捣"),
+    ("dao", "蹈"),
+    ("dao", "倒"),
+    ("dao", "岛"),
+    ("dao", "祷"),
+    ("dao", "导"),
+    ("dao", "到"),
+    ("dao", "稻"),
+    ("dao", "悼"),
+    ("dao", "道"),
+    ("dao", "盗"),
+    ("de", "德"),
+    ("de", "得"),
+    ("de", "的"),
+    ("deng", "蹬"),
+    ("deng", "灯"),
+    ("deng", "登"),
+    ("deng", "等"),
+    ("deng", "瞪"),
+    ("deng", "凳"),
+    ("deng", "邓"),
+    ("di", "堤"),
+    ("di", "低"),
+    ("di", "滴"),
+    ("di", "迪"),
+    ("di", "敌"),
+    ("di", "笛"),
+    ("di", "狄"),
+    ("di", "涤"),
+    ("di", "翟"),
+    ("di", "嫡"),
+    ("di", "抵"),
+    ("di", "底"),
+    ("de", "地"),
+    ("di", "蒂"),
+    ("di", "第"),
+    ("di", "帝"),
+    ("di", "弟"),
+    ("di", "递"),
+    ("di", "缔"),
+    ("dian", "颠"),
+    ("dian", "掂"),
+    ("dian", "滇"),
+    ("dian", "碘"),
+    ("dian", "点"),
+    ("dian", "典"),
+    ("dian", "靛"),
+    ("dian", "垫"),
+    ("dian", "电"),
+    ("dian", "佃"),
+    ("dian", "甸"),
+    ("dian", "店"),
+    ("dian", "惦"),
+    ("dian", "奠"),
+    ("dian", "淀"),
+    ("dian", "殿"),
+    ("diao", "碉"),
+    ("diao", "叼"),
+    ("diao", "雕"),
+    ("diao", "凋"),
+    ("diao", "刁"),
+    ("diao", "掉"),
+    ("diao", "吊"),
+    ("diao", "钓"),
+    ("diao", "调"),
+    ("die", "跌"),
+    ("die", "爹"),
+    ("die", "碟"),
+    ("die", "蝶"),
+    ("die", "迭"),
+    ("die", "谍"),
+    ("die", "叠"),
+    ("ding", "丁"),
+    ("ding", "盯"),
+    ("ding", "叮"),
+    ("ding", "钉"),
+    ("ding", "顶"),
+    ("ding", "鼎"),
+    ("ding", "锭"),
+    ("ding", "定"),
+    ("ding", "订"),
+    ("diu", "丢"),
+    ("dong", "东"),
+    ("dong", "冬"),
+    ("dong", "董"),
+    ("dong", "懂"),
+    ("dong", "动"),
+    ("dong", "栋"),
+    ("dong", "侗"),
+    ("dong", "恫"),
+    ("dong", "冻"),
+    ("dong", "洞"),
+    ("dou", "兜"),
+    ("dou", "抖"),
+    ("dou", "斗"),
+    ("dou", "陡"),
+    ("dou", "豆"),
+    ("dou", "逗"),
+    ("dou", "痘"),
+    ("dou", "都"),
+    ("du", "督"),
+    ("du", "毒"),
+    ("du", "犊"),
+    ("du", "独"),
+    ("du", "读"),
+    ("du", "堵"),
+    ("du", "睹"),
+    ("du", "赌"),
+    ("du", "杜"),
+    ("du", "镀"),
+    ("du", "肚"),
+    ("du", "度"),
+    ("du", "渡"),
+    ("du", "妒"),
+    ("duan", "端"),
+    ("duan", "短"),
+    ("duan", "锻"),
+    ("duan", "段"),
+    ("duan", "断"),
+    ("duan", "缎"),
+    ("dui", "堆"),
+    ("dui", "兑"),
+    ("dui", "队"),
+    ("dui", "对"),
+    ("dun", "墩"),
+    ("dun", "吨"),
+    ("dun", "蹲"),
+    ("dun", "敦"),
+    ("dun", "顿"),
+    ("dun", "囤"),
+    ("dun", "钝"),
+    ("dun", "盾"),
+    ("dun", "遁"),
+    ("duo", "掇"),
+    ("duo", "哆"),
+    ("duo", "多"),
+    ("duo", "夺"),
+    ("duo", "垛"),
+    ("duo", "躲"),
+    ("duo", "朵"),
+    ("duo", "跺"),
+    ("duo", "舵"),
+    ("duo", "剁"),
+    ("duo", "惰"),
+    ("duo", "堕"),
+    ("e", "蛾"),
+    ("e", "峨"),
+    ("e", "鹅"),
+    ("e", "俄"),
+    ("e", "额"),
+    ("e", "讹"),
+    ("e", "娥"),
+    ("e", "恶"),
+    ("e", "厄"),
+    ("e", "扼"),
+    ("e", "遏"),
+    ("e", "鄂"),
+    ("e", "饿"),
+    ("en", "恩"),
+    ("er", "而"),
+    ("er", "儿"),
+    ("er", "耳"),
+    ("er", "尔"),
+    ("er", "饵"),
+    ("er", "洱"),
+    ("er", "二"),
+    ("er", "贰"),
+    ("fa", "发"),
+    ("fa", "罚"),
+    ("fa", "筏"),
+    ("fa", "伐"),
+    ("fa", "乏"),
+    ("fa", "阀"),
+    ("fa", "法"),
+    ("fa", "珐"),
+    ("fan", "藩"),
+    ("fan", "帆"),
+    ("fan", "番"),
+    ("fan", "翻"),
+    ("fan", "樊"),
+    ("fan", "矾"),
+    ("fan", "钒"),
+    ("fan", "繁"),
+    ("fan", "凡"),
+    ("fan", "烦"),
+    ("fan", "反"),
+    ("fan", "返"),
+    ("fan", "范"),
+    ("fan", "贩"),
+    ("fan", "犯"),
+    ("fan", "饭"),
+    ("fan", "泛"),
+    ("fang", "坊"),
+    ("fang", "芳"),
+    ("fang", "方"),
+    ("fang", "肪"),
+    ("fang", "房"),
+    ("fang", "防"),
+    ("fang", "妨"),
+    ("fang", "仿"),
+    ("fang", "访"),
+    ("fang", "纺"),
+    ("fang", "放"),
+    ("fei", "菲"),
+    ("fei", "非"),
+    ("fei", "啡"),
+    ("fei", "飞"),
+    ("fei", "肥"),
+    ("fei", "匪"),
+    ("fei", "诽"),
+    ("fei", "吠"),
+    ("fei", "肺"),
+    ("fei", "废"),
+    ("fei", "沸"),
+    ("fei", "费"),
+    ("fen", "芬"),
+    ("fen", "酚"),
+    ("fen", "吩"),
+    ("fen", "氛"),
+    ("fen", "分"),
+    ("fen", "纷"),
+    ("fen", "坟"),
+    ("fen", "焚"),
+    ("fen", "汾"),
+    ("fen", "粉"),
+    ("fen", "奋"),
+    ("fen", "份"),
+    ("fen", "忿"),
+    ("fen", "愤"),
+    ("fen", "粪"),
+    ("feng", "丰"),
+    ("feng", "封"),
+    ("feng", "枫"),
+    ("feng", "蜂"),
+    ("feng", "峰"),
+    ("feng", "锋"),
+    ("feng", "风"),
+    ("feng", "疯"),
+    ("feng", "烽"),
+    ("feng", "逢"),
+    ("feng", "冯"),
+    ("feng", "缝"),
+    ("feng", "讽"),
+    ("feng", "奉"),
+    ("feng", "凤"),
+    ("fu", "佛"),
+    ("fou", "否"),
+    ("fu", "夫"),
+    ("fu", "敷"),
+    ("fu", "肤"),
+    ("fu", "孵"),
+    ("fu", "扶"),
+    ("fu", "拂"),
+    ("fu", "辐"),
+    ("fu", "幅"),
+    ("fu", "氟"),
+    ("fu", "符"),
+    ("fu", "伏"),
+    ("fu", "俘"),
+    ("fu", "服"),
+    ("fu", "浮"),
+    ("fu", "涪"),
+    ("fu", "福"),
+    ("fu", "袱"),
+    ("fu", "弗"),
+    ("fu", "甫"),
+    ("fu", "抚"),
+    ("fu", "辅"),
+    ("fu", "俯"),
+    ("fu", "釜"),
+    ("fu", "斧"),
+    ("pu", "脯"),
+    ("fu", "腑"),
+    ("fu", "府"),
+    ("fu", "腐"),
+    ("fu", "赴"),
+    ("fu", "副"),
+    ("fu", "覆"),
+    ("fu", "赋"),
+    ("fu", "复"),
+    ("fu", "傅"),
+    ("fu", "付"),
+    ("fu", "阜"),
+    ("fu", "父"),
+    ("fu", "腹"),
+    ("fu", "负"),
+    ("fu", "富"),
+    ("fu", "讣"),
+    ("fu", "附"),
+    ("fu", "妇"),
+    ("fu", "缚"),
+    ("fu", "咐"),
+    ("ga", "噶"),
+    ("ga", "嘎"),
+    ("gai", "该"),
+    ("gai", "改"),
+    ("gai", "概"),
+    ("gai", "钙"),
+    ("gai", "盖"),
+    ("gai", "溉"),
+    ("gan", "干"),
+    ("gan", "甘"),
+    ("gan", "杆"),
+    ("gan", "柑"),
+    ("gan", "竿"),
+    ("gan", "肝"),
+    ("gan", "赶"),
+    ("gan", "感"),
+    ("gan", "秆"),
+    ("gan", "敢"),
+    ("gan", "赣"),
+    ("gang", "冈"),
+    ("gang", "刚"),
+    ("gang", "钢"),
+    ("gang", "缸"),
+    ("gang", "肛"),
+    ("gang", "纲"),
+    ("gang", "岗"),
+    ("gang", "港"),
+    ("gang", "杠"),
+    ("gao", "篙"),
+    ("gao", "皋"),
+    ("gao", "高"),
+    ("gao", "膏"),
+    ("gao", "羔"),
+    ("gao", "糕"),
+    ("gao", "搞"),
+    ("gao", "镐"),
+    ("gao", "稿"),
+    ("gao", "告"),
+    ("ge", "哥"),
+    ("ge", "歌"),
+    ("ge", "搁"),
+    ("ge", "戈"),
+    ("ge", "鸽"),
+    ("ge", "胳"),
+    ("ge", "疙"),
+    ("ge", "割"),
+    ("ge", "革"),
+    ("ge", "葛"),
+    ("ge", "格"),
+    ("ha", "蛤"),
+    ("ge", "阁"),
+    ("ge", "隔"),
+    ("ge", "铬"),
+    ("ge", "个"),
+    ("ge", "各"),
+    ("gei", "给"),
+    ("gen", "根"),
+    ("gen", "跟"),
+    ("geng", "耕"),
+    ("geng", "更"),
+    ("geng", "庚"),
+    ("geng", "羹"),
+    ("geng", "埂"),
+    ("geng", "耿"),
+    ("geng", "梗"),
+    ("gong", "工"),
+    ("gong", "攻"),
+    ("gong", "功"),
+    ("gong", "恭"),
+    ("gong", "龚"),
+    ("gong", "供"),
+    ("gong", "躬"),
+    ("gong", "公"),
+    ("gong", "宫"),
+    ("gong", "弓"),
+    ("gong", "巩"),
+    ("gong", "汞"),
+    ("gong", "拱"),
+    ("gong", "贡"),
+    ("gong", "共"),
+    ("gou", "钩"),
+    ("gou", "勾"),
+    ("gou", "沟"),
+    ("gou", "苟"),
+    ("gou", "狗"),
+    ("gou", "垢"),
+    ("gou", "构"),
+    ("gou", "购"),
+    ("gou", "够"),
+    ("gu", "辜"),
+    ("gu", "菇"),
+    ("gu", "咕"),
+    ("gu", "箍"),
+    ("gu", "估"),
+    ("gu", "沽"),
+    ("gu", "孤"),
+    ("gu", "姑"),
+    ("gu", "鼓"),
+    ("gu", "古"),
+    ("gu", "蛊"),
+    ("gu", "骨"),
+    ("gu", "谷"),
+    ("gu", "股"),
+    ("gu", "故"),
+    ("gu", "顾"),
+    ("gu", "固"),
+    ("gu", "雇"),
+    ("gua", "刮"),
+    ("gua", "瓜"),
+    ("gua", "剐"),
+    ("gua", "寡"),
+    ("gua", "挂"),
+    ("gua", "褂"),
+    ("guai", "乖"),
+    ("guai", "拐"),
+    ("guai", "怪"),
+    ("guan", "棺"),
+    ("guan", "关"),
+    ("guan", "官"),
+    ("guan", "冠"),
+    ("guan", "观"),
+    ("guan", "管"),
+    ("guan", "馆"),
+    ("guan", "罐"),
+    ("guan", "惯"),
+    ("guan", "灌"),
+    ("guan", "贯"),
+    ("guang", "光"),
+    ("guang", "广"),
+    ("guang", "逛"),
+    ("gui", "瑰"),
+    ("gui", "规"),
+    ("gui", "圭"),
+    ("gui", "硅"),
+    ("gui", "归"),
+    ("gui", "龟"),
+    ("gui", "闺"),
+    ("gui", "轨"),
+    ("gui", "鬼"),
+    ("gui", "诡"),
+    ("gui", "癸"),
+    ("gui", "桂"),
+    ("gui", "柜"),
+    ("gui", "跪"),
+    ("gui", "贵"),
+    ("gui", "刽"),
+    ("gun", "辊"),
+    ("gun", "滚"),
+    ("gun", "棍"),
+    ("guo", "锅"),
+    ("guo", "郭"),
+    ("guo", "国"),
+    ("guo", "果"),
+    ("guo", "裹"),
+    ("guo", "过"),
+    ("ha", "哈"),
+    ("hai", "骸"),
+    ("hai", "孩"),
+    ("hai", "海"),
+    ("hai", "氦"),
+    ("hai", "亥"),
+    ("hai", "害"),
+    ("hai", "骇"),
+    ("han", "酣"),
+    ("han", "憨"),
+    ("han", "邯"),
+    ("han", "韩"),
+    ("han", "含"),
+    ("han", "涵"),
+    ("han", "寒"),
+    ("han", "函"),
+    ("han", "喊"),
+    ("han", "罕"),
+    ("han", "翰"),
+    ("han", "撼"),
+    ("han", "捍"),
+    ("han", "旱"),
+    ("han", "憾"),
+    ("han", "悍"),
+    ("han", "焊"),
+    ("han", "汗"),
+    ("han", "汉"),
+    ("hang", "夯"),
+    ("hang", "杭"),
+    ("hang", "航"),
+    ("hao", "壕"),
+    ("hao", "嚎"),
+    ("hao", "豪"),
+    ("hao", "毫"),
+    ("hao", "郝"),
+    ("hao", "好"),
+    ("hao", "耗"),
+    ("hao", "号"),
+    ("hao", "浩"),
+    ("he", "呵"),
+    ("he", "喝"),
+    ("he", "荷"),
+    ("he", "菏"),
+    ("he", "核"),
+    ("he", "禾"),
+    ("he", "和"),
+    ("he", "何"),
+    ("he", "合"),
+    ("he", "盒"),
+    ("hao", "貉"),
+    ("he", "阂"),
+    ("he", "河"),
+    ("he", "涸"),
+    ("he", "赫"),
+    ("he", "褐"),
+    ("he", "鹤"),
+    ("he", "贺"),
+    ("hei", "嘿"),
+    ("hei", "黑"),
+    ("hen", "痕"),
+    ("hen", "很"),
+    ("hen", "狠"),
+    ("hen", "恨"),
+    ("heng", "哼"),
+    ("heng", "亨"),
+    ("heng", "横"),
+    ("heng", "衡"),
+    ("heng", "恒"),
+    ("hong", "轰"),
+    ("hong", "哄"),
+    ("hong", "烘"),
+    ("hong", "虹"),
+    ("hong", "鸿"),
+    ("hong", "洪"),
+    ("hong", "宏"),
+    ("hong", "弘"),
+    ("hong", "红"),
+    ("hou", "喉"),
+    ("hou", "侯"),
+    ("hou", "猴"),
+    ("hou", "吼"),
+    ("hou", "厚"),
+    ("hou", "候"),
+    ("hou", "后"),
+    ("hu", "呼"),
+    ("hu", "乎"),
+    ("hu", "忽"),
+    ("hu", "瑚"),
+    ("hu", "壶"),
+    ("hu", "葫"),
+    ("hu", "胡"),
+    ("hu", "蝴"),
+    ("hu", "狐"),
+    ("hu", "糊"),
+    ("hu", "湖"),
+    ("hu", "弧"),
+    ("hu", "虎"),
+    ("hu", "唬"),
+    ("hu", "护"),
+    ("hu", "互"),
+    ("hu", "沪"),
+    ("hu", "户"),
+    ("hua", "花"),
+    ("hua", "哗"),
+    ("hua", "华"),
+    ("hua", "猾"),
+    ("hua", "滑"),
+    ("hua", "画"),
+    ("hua", "划"),
+    ("hua", "化"),
+    ("hua", "话"),
+    ("huai", "槐"),
+    ("huai", "徊"),
+    ("huai", "怀"),
+    ("huai", "淮"),
+    ("huai", "坏"),
+    ("huan", "欢"),
+    ("huan", "环"),
+    ("huan", "桓"),
+    ("hai", "还"),
+    ("huan", "缓"),
+    ("huan", "换"),
+    ("huan", "患"),
+    ("huan", "唤"),
+    ("huan", "痪"),
+    ("huan", "豢"),
+    ("huan", "焕"),
+    ("huan", "涣"),
+    ("huan", "宦"),
+    ("huan", "幻"),
+    ("huang", "荒"),
+    ("huang", "慌"),
+    ("huang", "黄"),
+    ("huang", "磺"),
+    ("huang", "蝗"),
+    ("huang", "簧"),
+    ("huang", "皇"),
+    ("huang", "凰"),
+    ("huang", "惶"),
+    ("huang", "煌"),
+    ("huang", "晃"),
+    ("huang", "幌"),
+    ("huang", "恍"),
+    ("huang", "谎"),
+    ("hui", "灰"),
+    ("hui", "挥"),
+    ("hui", "辉"),
+    ("hui", "徽"),
+    ("hui", "恢"),
+    ("hui", "蛔"),
+    ("hui", "回"),
+    ("hui", "毁"),
+    ("hui", "悔"),
+    ("hui", "慧"),
+    ("hui", "卉"),
+    ("hui", "惠"),
+    ("hui", "晦"),
+    ("hui", "贿"),
+    ("hui", "秽"),
+    ("hui", "会"),
+    ("hui", "烩"),
+    ("hui", "汇"),
+    ("hui", "讳"),
+    ("hui", "诲"),
+    ("hui", "绘"),
+    ("hun", "荤"),
+    ("hun", "昏"),
+    ("hun", "婚"),
+    ("hun", "魂"),
+    ("hun", "浑"),
+    ("hun", "混"),
+    ("huo", "豁"),
+    ("huo", "活"),
+    ("huo", "伙"),
+    ("huo", "火"),
+    ("huo", "获"),
+    ("huo", "或"),
+    ("huo", "惑"),
+    ("huo", "霍"),
+    ("huo", "货"),
+    ("huo", "祸"),
+    ("ji", "击"),
+    ("ji", "圾"),
+    ("ji", "基"),
+    ("ji", "机"),
+    ("ji", "畸"),
+    ("ji", "稽"),
+    ("ji", "积"),
+    ("ji", "箕"),
+    ("ji", "肌"),
+    ("ji", "饥"),
+    ("ji", "迹"),
+    ("ji", "激"),
+    ("ji", "讥"),
+    ("ji", "鸡"),
+    ("ji", "姬"),
+    ("ji", "绩"),
+    ("ji", "缉"),
+    ("ji", "吉"),
+    ("ji", "极"),
+    ("ji", "棘"),
+    ("ji", "辑"),
+    ("ji", "籍"),
+    ("ji", "集"),
+    ("ji", "及"),
+    ("ji", "急"),
+    ("ji", "疾"),
+    ("ji", "汲"),
+    ("ji", "即"),
+    ("ji", "嫉"),
+    ("ji", "级"),
+    ("ji", "挤"),
+    ("ji", "几"),
+    ("ji", "脊"),
+    ("ji", "己"),
+    ("ji", "蓟"),
+    ("ji", "技"),
+    ("ji", "冀"),
+    ("ji", "季"),
+    ("ji", "伎"),
+    ("ji", "祭"),
+    ("ji", "剂"),
+    ("ji", "悸"),
+    ("ji", "济"),
+    ("ji", "寄"),
+    ("ji", "寂"),
+    ("ji", "计"),
+    ("ji", "记"),
+    ("ji", "既"),
+    ("ji", "忌"),
+    ("ji", "际"),
+    ("ji", "妓"),
+    ("ji", "继"),
+    ("ji", "纪"),
+    ("jia", "嘉"),
+    ("jia", "枷"),
+    ("jia", "夹"),
+    ("jia", "佳"),
+    ("jia", "家"),
+    ("jia", "加"),
+    ("jia", "荚"),
+    ("jia", "颊"),
+    ("jia", "贾"),
+    ("jia", "甲"),
+    ("jia", "钾"),
+    ("jia", "假"),
+    ("jia", "稼"),
+    ("jia", "价"),
+    ("jia", "架"),
+    ("jia", "驾"),
+    ("jia", "嫁"),
+    ("jian", "歼"),
+    ("jian", "监"),
+    ("jian", "坚"),
+    ("jian", "尖"),
+    ("jian", "笺"),
+    ("jian", "间"),
+    ("jian", "煎"),
+    ("jian", "兼"),
+    ("jian", "肩"),
+    ("jian", "艰"),
+    ("jian", "奸"),
+    ("jian", "缄"),
+    ("jian", "茧"),
+    ("jian", "检"),
+    ("jian", "柬"),
+    ("jian", "碱"),
+    ("jian", "硷"),
+    ("jian", "拣"),
+    ("jian", "捡"),
+    ("jian", "简"),
+    ("jian", "俭"),
+    ("jian", "剪"),
+    ("jian", "减"),
+    ("jian", "荐"),
+    ("kan", "槛"),
+    ("jian", "鉴"),
+    ("jian", "践"),
+    ("jian", "贱"),
+    ("jian", "见"),
+    ("jian", "键"),
+    ("jian", "箭"),
+    ("jian", "件"),
+    ("jian", "健"),
+    ("jian", "舰"),
+    ("jian", "剑"),
+    ("jian", "饯"),
+    ("jian", "渐"),
+    ("jian", "溅"),
+    ("jian", "涧"),
+    ("jian", "建"),
+    ("jiang", "僵"),
+    ("jiang", "姜"),
+    ("jiang", "将"),
+    ("jiang", "浆"),
+    ("jiang", "江"),
+    ("jiang", "疆"),
+    ("jiang", "蒋"),
+    ("jiang", "桨"),
+    ("jiang", "奖"),
+    ("jiang", "讲"),
+    ("jiang", "匠"),
+    ("jiang", "酱"),
+    ("jiang", "降"),
+    ("jiao", "蕉"),
+    ("jiao", "椒"),
+    ("jiao", "礁"),
+    ("jiao", "焦"),
+    ("jiao", "胶"),
+    ("jiao", "交"),
+    ("jiao", "郊"),
+    ("jiao", "浇"),
+    ("jiao", "骄"),
+    ("jiao", "娇"),
+    ("jue", "嚼"),
+    ("jiao", "搅"),
+    ("jiao", "铰"),
+    ("jiao", "矫"),
+    ("jiao", "侥"),
+    ("jiao", "脚"),
+    ("jiao", "狡"),
+    ("jiao", "角"),
+    ("jiao", "饺"),
+    ("jiao", "缴"),
+    ("jiao", "绞"),
+    ("jiao", "剿"),
+    ("jiao", "教"),
+    ("jiao", "酵"),
+    ("jiao", "轿"),
+    ("jiao", "较"),
+    ("jiao", "叫"),
+    ("jiao", "窖"),
+    ("jie", "揭"),
+    ("jie", "接"),
+    ("jie", "皆"),
+    ("jie", "秸"),
+    ("jie", "街"),
+    ("jie", "阶"),
+    ("jie", "截"),
+    ("jie", "劫"),
+    ("jie", "节"),
+    ("ju", "桔"),
+    ("jie", "杰"),
+    ("jie", "捷"),
+    ("jie", "睫"),
+    ("jie", "竭"),
+    ("jie", "洁"),
+    ("jie", "结"),
+    ("jie", "解"),
+    ("jie", "姐"),
+    ("jie", "戒"),
+    ("ji", "藉"),
+    ("jie", "芥"),
+    ("jie", "界"),
+    ("jie", "借"),
+    ("jie", "介"),
+    ("jie", "疥"),
+    ("jie", "诫"),
+    ("jie", "届"),
+    ("jin", "巾"),
+    ("jin", "筋"),
+    ("jin", "斤"),
+    ("jin", "金"),
+    ("jin", "今"),
+    ("jin", "津"),
+    ("jin", "襟"),
+    ("jin", "紧"),
+    ("jin", "锦"),
+    ("jin", "仅"),
+    ("jin", "谨"),
+    ("jin", "进"),
+    ("jin", "靳"),
+    ("jin", "晋"),
+    ("jin", "禁"),
+    ("jin", "近"),
+    ("jin", "烬"),
+    ("jin", "浸"),
+    ("jin", "尽"),
+    ("jin", "劲"),
+    ("jing", "荆"),
+    ("jing", "兢"),
+    ("jing", "茎"),
+    ("jing", "睛"),
+    ("jing", "晶"),
+    ("jing", "鲸"),
+    ("jing", "京"),
+    ("jing", "惊"),
+    ("jing", "精"),
+    ("jing", "粳"),
+    ("jing", "经"),
+    ("jing", "井"),
+    ("jing", "警"),
+    ("jing", "景"),
+    ("jing", "颈"),
+    ("jing", "静"),
+    ("jing", "境"),
+    ("jing", "敬"),
+    ("jing", "镜"),
+    ("jing", "径"),
+    ("jing", "痉"),
+    ("jing", "靖"),
+    ("jing", "竟"),
+    ("jing", "竞"),
+    ("jing", "净"),
+    ("jiong", "炯"),
+    ("jiong", "窘"),
+    ("jiu", "揪"),
+    ("jiu", "究"),
+    ("jiu", "纠"),
+    ("jiu", "玖"),
+    ("jiu", "韭"),
+    ("jiu", "久"),
+    ("jiu", "灸"),
+    ("jiu", "九"),
+    ("jiu", "酒"),
+    ("jiu", "厩"),
+    ("jiu", "救"),
+    ("jiu", "旧"),
+    ("jiu", "臼"),
+    ("jiu", "舅"),
+    ("jiu", "咎"),
+    ("jiu", "就"),
+    ("jiu", "疚"),
+    ("ju", "鞠"),
+    ("ju", "拘"),
+    ("ju", "狙"),
+    ("ju", "疽"),
+    ("ju", "居"),
+    ("ju", "驹"),
+    ("ju", "菊"),
+    ("ju", "局"),
+    ("ju", "咀"),
+    ("ju", "矩"),
+    ("ju", "举"),
+    ("ju", "沮"),
+    ("ju", "聚"),
+    ("ju", "拒"),
+    ("ju", "据"),
+    ("ju", "巨"),
+    ("ju", "具"),
+    ("ju", "距"),
+    ("ju", "踞"),
+    ("ju", "锯"),
+    ("ju", "俱"),
+    ("ju", "句"),
+    ("ju", "惧"),
+    ("ju", "炬"),
+    ("ju", "剧"),
+    ("juan", "捐"),
+    ("juan", "鹃"),
+    ("juan", "娟"),
+    ("juan", "倦"),
+    ("juan", "眷"),
+    ("juan", "卷"),
+    ("juan", "绢"),
+    ("jue", "撅"),
+    ("jue", "攫"),
+    ("jue", "抉"),
+    ("jue", "掘"),
+    ("jue", "倔"),
+    ("jue", "爵"),
+    ("jue", "觉"),
+    ("jue", "决"),
+    ("jue", "诀"),
+    ("jue", "绝"),
+    ("jun", "均"),
+    ("jun", "菌"),
+    ("jun", "钧"),
+    ("jun", "军"),
+    ("jun", "君"),
+    ("jun", "峻"),
+    ("jun", "俊"),
+    ("jun", "竣"),
+    ("jun", "浚"),
+    ("jun", "郡"),
+    ("jun", "骏"),
+    ("ka", "喀"),
+    ("ka", "咖"),
+    ("ka", "卡"),
+    ("ge", "咯"),
+    ("kai", "开"),
+    ("kai", "揩"),
+    ("kai", "楷"),
+    ("kai", "凯"),
+    ("kai", "慨"),
+    ("kan", "刊"),
+    ("kan", "堪"),
+    ("kan", "勘"),
+    ("kan", "坎"),
+    ("kan", "砍"),
+    ("kan", "看"),
+    ("kang", "康"),
+    ("kang", "慷"),
+    ("kang", "糠"),
+    ("kang", "扛"),
+    ("kang", "抗"),
+    ("kang", "亢"),
+    ("kang", "炕"),
+    ("kao", "考"),
+    ("kao", "拷"),
+    ("kao", "烤"),
+    ("kao", "靠"),
+    ("ke", "坷"),
+    ("ke", "苛"),
+    ("ke", "柯"),
+    ("ke", "棵"),
+    ("ke", "磕"),
+    ("ke", "颗"),
+    ("ke", "科"),
+    ("ke", "壳"),
+    ("hai", "咳"),
+    ("ke", "可"),
+    ("ke", "渴"),
+    ("ke", "克"),
+    ("ke", "刻"),
+    ("ke", "客"),
+    ("ke", "课"),
+    ("ken", "肯"),
+    ("ken", "啃"),
+    ("ken", "垦"),
+    ("ken", "恳"),
+    ("keng", "坑"),
+    ("keng", "吭"),
+    ("kong", "空"),
+    ("kong", "恐"),
+    ("kong", "孔"),
+    ("kong", "控"),
+    ("kou", "抠"),
+    ("kou", "口"),
+    ("kou", "扣"),
+    ("kou", "寇"),
+    ("ku", "枯"),
+    ("ku", "哭"),
+    ("ku", "窟"),
+    ("ku", "苦"),
+    ("ku", "酷"),
+    ("ku", "库"),
+    ("ku", "裤"),
+    ("kua", "夸"),
+    ("kua", "垮"),
+    ("kua", "挎"),
+    ("kua", "跨"),
+    ("kua", "胯"),
+    ("kuai", "块"),
+    ("kuai", "筷"),
+    ("kuai", "侩"),
+    ("kuai", "快"),
+    ("kuan", "宽"),
+    ("kuan", "款"),
+    ("kuang", "匡"),
+    ("kuang", "筐"),
+    ("kuang", "狂"),
+    ("kuang", "框"),
+    ("kuang", "矿"),
+    ("kuang", "眶"),
+    ("kuang", "旷"),
+    ("kuang", "况"),
+    ("kui", "亏"),
+    ("kui", "盔"),
+    ("kui", "岿"),
+    ("kui", "窥"),
+    ("kui", "葵"),
+    ("kui", "奎"),
+    ("kui", "魁"),
+    ("gui", "傀"),
+    ("kui", "馈"),
+    ("kui", "愧"),
+    ("kui", "溃"),
+    ("kun", "坤"),
+    ("kun", "昆"),
+    ("kun", "捆"),
+    ("kun", "困"),
+    ("kuo", "括"),
+    ("kuo", "扩"),
+    ("kuo", "廓"),
+    ("kuo", "阔"),
+    ("la", "垃"),
+    ("la", "拉"),
+    ("la", "喇"),
+    ("la", "蜡"),
+    ("la", "腊"),
+    ("la", "辣"),
+    ("la", "啦"),
+    ("lai", "莱"),
+    ("lai", "来"),
+    ("lai", "赖"),
+    ("lan", "蓝"),
+    ("lan", "婪"),
+    ("lan", "栏"),
+    ("lan", "拦"),
+    ("lan", "篮"),
+    ("lan", "阑"),
+    ("lan", "兰"),
+    ("lan", "澜"),
+    ("lan", "谰"),
+    ("lan", "揽"),
+    ("lan", "览"),
+    ("lan", "懒"),
+    ("lan", "缆"),
+    ("lan", "烂"),
+    ("lan", "滥"),
+    ("lang", "琅"),
+    ("lang", "榔"),
+    ("lang", "狼"),
+    ("lang", "廊"),
+    ("lang", "郎"),
+    ("lang", "朗"),
+    ("lang", "浪"),
+    ("lao", "捞"),
+    ("lao", "劳"),
+    ("lao", "牢"),
+    ("lao", "老"),
+    ("lao", "佬"),
+    ("lao", "姥"),
+    ("lao", "酪"),
+    ("lao", "烙"),
+    ("lao", "涝"),
+    ("lei", "勒"),
+    ("le", "乐"),
+    ("lei", "雷"),
+    ("lei", "镭"),
+    ("lei", "蕾"),
+    ("lei", "磊"),
+    ("lei", "累"),
+    ("lei", "儡"),
+    ("lei", "垒"),
+    ("lei", "擂"),
+    ("le", "肋"),
+    ("lei", "类"),
+    ("lei", "泪"),
+    ("leng", "棱"),
+    ("leng", "楞"),
+    ("leng", "冷"),
+    ("li", "厘"),
+    ("li", "梨"),
+    ("li", "犁"),
+    ("li", "黎"),
+    ("li", "篱"),
+    ("li", "狸"),
+    ("li", "离"),
+    ("li", "漓"),
+    ("li", "理"),
+    ("li", "李"),
+    ("li", "里"),
+    ("li", "鲤"),
+    ("li", "礼"),
+    ("li", "莉"),
+    ("li", "荔"),
+    ("li", "吏"),
+    ("li", "栗"),
+    ("li", "丽"),
+    ("li", "厉"),
+    ("li", "励"),
+    ("li", "砾"),
+    ("li", "历"),
+    ("li", "利"),
+    ("li", "傈"),
+    ("li", "例"),
+    ("li", "俐"),
+    ("li", "痢"),
+    ("li", "立"),
+    ("li", "粒"),
+    ("li", "沥"),
+    ("li", "隶"),
+    ("li", "力"),
+    ("li", "璃"),
+    ("li", "哩"),
+    ("lia", "俩"),
+    ("lian", "联"),
+    ("lian", "莲"),
+    ("lian", "连"),
+    ("lian", "镰"),
+    ("lian", "廉"),
+    ("lian", "怜"),
+    ("lian", "涟"),
+    ("lian", "帘"),
+    ("lian", "敛"),
+    ("lian", "脸"),
+    ("lian", "链"),
+    ("lian", "恋"),
+    ("lian", "炼"),
+    ("lian", "练"),
+    ("liang", "粮"),
+    ("liang", "凉"),
+    ("liang", "梁"),
+    ("liang", "粱"),
+    ("liang", "良"),
+    ("liang", "两"),
+    ("liang", "辆"),
+    ("liang", "量"),
+    ("liang", "晾"),
+    ("liang", "亮"),
+    ("liang", "谅"),
+    ("liao", "撩"),
+    ("liao", "聊"),
+    ("liao", "僚"),
+    ("liao", "疗"),
+    ("liao", "燎"),
+    ("liao", "寥"),
+    ("liao", "辽"),
+    ("lao", "潦"),
+    ("le", "了"),
+    ("liao", "撂"),
+    ("liao", "镣"),
+    ("liao", "廖"),
+    ("liao", "料"),
+    ("lie", "列"),
+    ("lie", "裂"),
+    ("lie", "烈"),
+    ("lie", "劣"),
+    ("lie", "猎"),
+    ("lin", "琳"),
+    ("lin", "林"),
+    ("lin", "磷"),
+    ("lin", "霖"),
+    ("lin", "临"),
+    ("lin", "邻"),
+    ("lin", "鳞"),
+    ("lin", "淋"),
+    ("lin", "凛"),
+    ("lin", "赁"),
+    ("lin", "吝"),
+    ("lin", "拎"),
+    ("ling", "玲"),
+    ("ling", "菱"),
+    ("ling", "零"),
+    ("ling", "龄"),
+    ("ling", "铃"),
+    ("ling", "伶"),
+    ("ling", "羚"),
+    ("ling", "凌"),
+    ("ling", "灵"),
+    ("ling", "陵"),
+    ("ling", "岭"),
+    ("ling", "领"),
+    ("ling", "另"),
+    ("ling", "令"),
+    ("liu", "溜"),
+    ("liu", "琉"),
+    ("liu", "榴"),
+    ("liu", "硫"),
+    ("liu", "馏"),
+    ("liu", "留"),
+    ("liu", "刘"),
+    ("liu", "瘤"),
+    ("liu", "流"),
+    ("liu", "柳"),
+    ("liu", "六"),
+    ("long", "龙"),
+    ("long", "聋"),
+    ("long", "咙"),
+    ("long", "笼"),
+    ("long", "窿"),
+    ("long", "隆"),
+    ("long", "垄"),
+    ("long", "拢"),
+    ("long", "陇"),
+    ("lou", "楼"),
+    ("lou", "娄"),
+    ("lou", "搂"),
+    ("lou", "篓"),
+    ("lou", "漏"),
+    ("lou", "陋"),
+    ("lu", "芦"),
+    ("lu", "卢"),
+    ("lu", "颅"),
+    ("lu", "庐"),
+    ("lu", "炉"),
+    ("lu", "掳"),
+    ("lu", "卤"),
+    ("lu", "虏"),
+    ("lu", "鲁"),
+    ("lu", "麓"),
+    ("lu", "碌"),
+    ("lu", "露"),
+    ("lu", "路"),
+    ("lu", "赂"),
+    ("lu", "鹿"),
+    ("lu", "潞"),
+    ("lu", "禄"),
+    ("lu", "录"),
+    ("lu", "陆"),
+    ("lu", "戮"),
+    ("lv", "驴"),
+    ("lv", "吕"),
+    ("lv", "铝"),
+    ("lv", "侣"),
+    ("lv", "旅"),
+    ("lv", "履"),
+    ("lv", "屡"),
+    ("lv", "缕"),
+    ("lv", "虑"),
+    ("lv", "氯"),
+    ("lv", "律"),
+    ("lv", "率"),
+    ("lv", "滤"),
+    ("lv", "绿"),
+    ("luan", "峦"),
+    ("luan", "挛"),
+    ("luan", "孪"),
+    ("luan", "滦"),
+    ("luan", "卵"),
+    ("luan", "乱"),
+    ("lve", "掠"),
+    ("lve", "略"),
+    ("lun", "抡"),
+    ("lun", "轮"),
+    ("lun", "伦"),
+    ("lun", "仑"),
+    ("lun", "沦"),
+    ("lun", "纶"),
+    ("lun", "论"),
+    ("luo", "萝"),
+    ("luo", "螺"),
+    ("luo", "罗"),
+    ("luo", "逻"),
+    ("luo", "锣"),
+    ("luo", "箩"),
+    ("luo", "骡"),
+    ("luo", "裸"),
+    ("luo", "落"),
+    ("luo", "洛"),
+    ("luo", "骆"),
+    ("luo", "络"),
+    ("ma", "妈"),
+    ("ma", "麻"),
+    ("ma", "玛"),
+    ("ma", "码"),
+    ("ma", "蚂"),
+    ("ma", "马"),
+    ("ma", "骂"),
+    ("ma", "嘛"),
+    ("ma", "吗"),
+    ("mai", "埋"),
+    ("mai", "买"),
+    ("mai", "麦"),
+    ("mai", "卖"),
+    ("mai", "迈"),
+    ("mai", "脉"),
+    ("man", "瞒"),
+    ("man", "馒"),
+    ("man", "蛮"),
+    ("man", "满"),
+    ("man", "蔓"),
+    ("man", "曼"),
+    ("man", "慢"),
+    ("man", "漫"),
+    ("man", "谩"),
+    ("mang", "芒"),
+    ("mang", "茫"),
+    ("mang", "盲"),
+    ("mang", "氓"),
+    ("mang", "忙"),
+    ("mang", "莽"),
+    ("mao", "猫"),
+    ("mao", "茅"),
+    ("mao", "锚"),
+    ("mao", "毛"),
+    ("mao", "矛"),
+    ("mao", "铆"),
+    ("mao", "卯"),
+    ("mao", "茂"),
+    ("mao", "冒"),
+    ("mao", "帽"),
+    ("mao", "貌"),
+    ("mao", "贸"),
+    ("me", "么"),
+    ("mei", "玫"),
+    ("mei", "枚"),
+    ("mei", "梅"),
+    ("mei", "酶"),
+    ("mei", "霉"),
+    ("mei", "煤"),
+    ("mei", "没"),
+    ("mei", "眉"),
+    ("mei", "媒"),
+    ("mei", "镁"),
+    ("mei", "每"),
+    ("mei", "美"),
+    ("mei", "昧"),
+    ("mei", "寐"),
+    ("mei", "妹"),
+    ("mei", "媚"),
+    ("men", "门"),
+    ("men", "闷"),
+    ("men", "们"),
+    ("meng", "萌"),
+    ("meng", "蒙"),
+    ("meng", "檬"),
+    ("meng", "盟"),
+    ("meng", "锰"),
+    ("meng", "猛"),
+    ("meng", "梦"),
+    ("meng", "孟"),
+    ("mi", "眯"),
+    ("mi", "醚"),
+    ("mi", "靡"),
+    ("mi", "糜"),
+    ("mi", "迷"),
+    ("mi", "谜"),
+    ("mi", "弥"),
+    ("mi", "米"),
+    ("mi", "秘"),
+    ("mi", "觅"),
+    ("mi", "泌"),
+    ("mi", "蜜"),
+    ("mi", "密"),
+    ("mi", "幂"),
+    ("mian", "棉"),
+    ("mian", "眠"),
+    ("mian", "绵"),
+    ("mian", "冕"),
+    ("mian", "免"),
+    ("mian", "勉"),
+    ("mian", "娩"),
+    ("mian", "缅"),
+    ("mian", "面"),
+    ("miao", "苗"),
+    ("miao", "描"),
+    ("miao", "瞄"),
+    ("miao", "藐"),
+    ("miao", "秒"),
+    ("miao", "渺"),
+    ("miao", "庙"),
+    ("miao", "妙"),
+    ("mie", "蔑"),
+    ("mie", "灭"),
+    ("min", "民"),
+    ("min", "抿"),
+    ("min", "皿"),
+    ("min", "敏"),
+    ("min", "悯"),
+    ("min", "闽"),
+    ("ming", "明"),
+    ("ming", "螟"),
+    ("ming", "鸣"),
+    ("ming", "铭"),
+    ("ming", "名"),
+    ("ming", "命"),
+    ("miu", "谬"),
+    ("mo", "摸"),
+    ("mo", "摹"),
+    ("mo", "蘑"),
+    ("mo", "模"),
+    ("mo", "膜"),
+    ("mo", "磨"),
+    ("mo", "摩"),
+    ("mo", "魔"),
+    ("mo", "抹"),
+    ("mo", "末"),
+    ("mo", "莫"),
+    ("mo", "墨"),
+    ("mo", "默"),
+    ("mo", "沫"),
+    ("mo", "漠"),
+    ("mo", "寞"),
+    ("mo", "陌"),
+    ("mou", "谋"),
+    ("mou", "牟"),
+    ("mou", "某"),
+    ("mu", "拇"),
+    ("mu", "牡"),
+    ("mu", "亩"),
+    ("mu", "姆"),
+    ("mu", "母"),
+    ("mu", "墓"),
+    ("mu", "暮"),
+    ("mu", "幕"),
+    ("mu", "募"),
+    ("mu", "慕"),
+    ("mu", "木"),
+    ("mu", "目"),
+    ("mu", "睦"),
+    ("mu", "牧"),
+    ("mu", "穆"),
+    ("na", "拿"),
+    ("na", "哪"),
+    ("na", "呐"),
+    ("na", "钠"),
+    ("na", "那"),
+    ("na", "娜"),
+    ("na", "纳"),
+    ("nai", "氖"),
+    ("nai", "乃"),
+    ("nai", "奶"),
+    ("nai", "耐"),
+    ("nai", "奈"),
+    ("nan", "南"),
+    ("nan", "男"),
+    ("nan", "难"),
+    ("nang", "囊"),
+    ("nao", "挠"),
+    ("nao", "脑"),
+    ("nao", "恼"),
+    ("nao", "闹"),
+    ("nao", "淖"),
+    ("ne", "呢"),
+    ("nei", "馁"),
+    ("nei", "内"),
+    ("nen", "嫩"),
+    ("neng", "能"),
+    ("ni", "妮"),
+    ("ni", "霓"),
+    ("ni", "倪"),
+    ("ni", "泥"),
+    ("ni", "尼"),
+    ("ni", "拟"),
+    ("ni", "你"),
+    ("ni", "匿"),
+    ("ni", "腻"),
+    ("ni", "逆"),
+    ("ni", "溺"),
+    ("nian", "蔫"),
+    ("nian", "拈"),
+    ("nian", "年"),
+    ("nian", "碾"),
+    ("nian", "撵"),
+    ("nian", "捻"),
+    ("nian", "念"),
+    ("niang", "娘"),
+    ("niang", "酿"),
+    ("niao", "鸟"),
+    ("niao", "尿"),
+    ("nie", "捏"),
+    ("nie", "聂"),
+    ("nie", "孽"),
+    ("nie", "啮"),
+    ("nie", "镊"),
+    ("nie", "镍"),
+    ("nie", "涅"),
+    ("nin", "您"),
+    ("ning", "柠"),
+    ("ning", "狞"),
+    ("ning", "凝"),
+    ("ning", "宁"),
+    ("ning", "拧"),
+    ("ning", "泞"),
+    ("niu", "牛"),
+    ("niu", "扭"),
+    ("niu", "钮"),
+    ("niu", "纽"),
+    ("nong", "脓"),
+    ("nong", "浓"),
+    ("nong", "农"),
+    ("nong", "弄"),
+    ("nu", "奴"),
+    ("nu", "努"),
+    ("nu", "怒"),
+    ("nv", "女"),
+    ("nuan", "暖"),
+    ("nve", "虐"),
+    ("nve", "疟"),
+    ("nuo", "挪"),
+    ("nuo", "懦"),
+    ("nuo", "糯"),
+    ("nuo", "诺"),
+    ("o", "哦"),
+    ("ou", "欧"),
+    ("ou", "鸥"),
+    ("ou", "殴"),
+    ("ou", "藕"),
+    ("ou", "呕"),
+    ("ou", "偶"),
+    ("ou", "沤"),
+    ("pa", "啪"),
+    ("pa", "趴"),
+    ("pa", "爬"),
+    ("pa", "帕"),
+    ("pa", "怕"),
+    ("pa", "琶"),
+    ("pai", "拍"),
+    ("pai", "排"),
+    ("pai", "牌"),
+    ("pai", "徘"),
+    ("pai", "湃"),
+    ("pai", "派"),
+    ("pan", "攀"),
+    ("pan", "潘"),
+    ("pan", "盘"),
+    ("pan", "磐"),
+    ("pan", "盼"),
+    ("pan", "畔"),
+    ("pan", "判"),
+    ("pan", "叛"),
+    ("pang", "乓"),
+    ("pang", "庞"),
+    ("pang", "旁"),
+    ("pang", "耪"),
+    ("pang", "胖"),
+    ("pao", "抛"),
+    ("pao", "咆"),
+    ("pao", "刨"),
+    ("pao", "炮"),
+    ("pao", "袍"),
+    ("pao", "跑"),
+    ("pao", "泡"),
+    ("pei", "呸"),
+    ("pei", "胚"),
+    ("pei", "培"),
+    ("pei", "裴"),
+    ("pei", "赔"),
+    ("pei", "陪"),
+    ("pei", "配"),
+    ("pei", "佩"),
+    ("pei", "沛"),
+    ("pen", "喷"),
+    ("pen", "盆"),
+    ("peng", "砰"),
+    ("peng", "抨"),
+    ("peng", "烹"),
+    ("peng", "澎"),
+    ("peng", "彭"),
+    ("peng", "蓬"),
+    ("peng", "棚"),
+    ("peng", "硼"),
+    ("peng", "篷"),
+    ("peng", "膨"),
+    ("peng", "朋"),
+    ("peng", "鹏"),
+    ("peng", "捧"),
+    ("peng", "碰"),
+    ("pi", "坯"),
+    ("pi", "砒"),
+    ("pi", "霹"),
+    ("pi", "批"),
+    ("pi", "披"),
+    ("pi", "劈"),
+    ("pi", "琵"),
+    ("pi", "毗"),
+    ("pi", "啤"),
+    ("pi", "脾"),
+    ("pi", "疲"),
+    ("pi", "皮"),
+    ("pi", "匹"),
+    ("pi", "痞"),
+    ("pi", "僻"),
+    ("pi", "屁"),
+    ("pi", "譬"),
+    ("pian", "篇"),
+    ("pian", "偏"),
+    ("pian", "片"),
+    ("pian", "骗"),
+    ("piao", "飘"),
+    ("piao", "漂"),
+    ("piao", "瓢"),
+    ("piao", "票"),
+    ("pie", "撇"),
+    ("pie", "瞥"),
+    ("pin", "拼"),
+    ("pin", "频"),
+    ("pin", "贫"),
+    ("pin", "品"),
+    ("pin", "聘"),
+    ("ping", "乒"),
+    ("ping", "坪"),
+    ("ping", "苹"),
+    ("ping", "萍"),
+    ("ping", "平"),
+    ("ping", "凭"),
+    ("ping", "瓶"),
+    ("ping", "评"),
+    ("ping", "屏"),
+    ("po", "坡"),
+    ("po", "泼"),
+    ("po", "颇"),
+    ("po", "婆"),
+    ("po", "破"),
+    ("po", "魄"),
+    ("po", "迫"),
+    ("po", "粕"),
+    ("pou", "剖"),
+    ("pu", "扑"),
+    ("pu", "铺"),
+    ("pu", "仆"),
+    ("pu", "莆"),
+    ("pu", "葡"),
+    ("pu", "菩"),
+    ("pu", "蒲"),
+    ("bu", "埔"),
+    ("pu", "朴"),
+    ("pu", "圃"),
+    ("pu", "普"),
+    ("pu", "浦"),
+    ("pu", "谱"),
+    ("pu", "曝"),
+    ("pu", "瀑"),
+    ("qi", "期"),
+    ("qi", "欺"),
+    ("qi", "栖"),
+    ("qi", "戚"),
+    ("qi", "妻"),
+    ("qi", "七"),
+    ("qi", "凄"),
+    ("qi", "漆"),
+    ("qi", "柒"),
+    ("qi", "沏"),
+    ("qi", "其"),
+    ("qi", "棋"),
+    ("qi", "奇"),
+    ("qi", "歧"),
+    ("qi", "畦"),
+    ("qi", "崎"),
+    ("qi", "脐"),
+    ("qi", "齐"),
+    ("qi", "旗"),
+    ("qi", "祈"),
+    ("qi", "祁"),
+    ("qi", "骑"),
+    ("qi", "起"),
+    ("qi", "岂"),
+    ("qi", "乞"),
+    ("qi", "企"),
+    ("qi", "启"),
+    ("qi", "契"),
+    ("qi", "砌"),
+    ("qi", "器"),
+    ("qi", "气"),
+    ("qi", "迄"),
+    ("qi", "弃"),
+    ("qi", "汽"),
+    ("qi", "泣"),
+    ("qi", "讫"),
+    ("qia", "掐"),
+    ("qia", "恰"),
+    ("qia", "洽"),
+    ("qian", "牵"),
+    ("qian", "扦"),
+    ("qian", "钎"),
+    ("qian", "铅"),
+    ("qian", "千"),
+    ("qian", "迁"),
+    ("qian", "签"),
+    ("qian", "仟"),
+    ("qian", "谦"),
+    ("qian", "乾"),
+    ("qian", "黔"),
+    ("qian", "钱"),
+    ("qian", "钳"),
+    ("qian", "前"),
+    ("qian", "潜"),
+    ("qian", "遣"),
+    ("qian", "浅"),
+    ("qian", "谴"),
+    ("qian", "堑"),
+    ("qian", "嵌"),
+    ("qian", "欠"),
+    ("qian", "歉"),
+    ("qiang", "枪"),
+    ("qiang", "呛"),
+    ("qiang", "腔"),
+    ("qiang", "羌"),
+    ("qiang", "墙"),
+    ("qiang", "蔷"),
+    ("qiang", "强"),
+    ("qiang", "抢"),
+    ("qiao", "橇"),
+    ("qiao", "锹"),
+    ("qiao", "敲"),
+    ("qiao", "悄"),
+    ("qiao", "桥"),
+    ("qiao", "瞧"),
+    ("qiao", "乔"),
+    ("qiao", "侨"),
+    ("qiao", "巧"),
+    ("qiao", "鞘"),
+    ("qiao", "撬"),
+    ("qiao", "翘"),
+    ("qiao", "峭"),
+    ("qiao", "俏"),
+    ("qiao", "窍"),
+    ("qie", "切"),
+    ("jia", "茄"),
+    ("qie", "且"),
+    ("qie", "怯"),
+    ("qie", "窃"),
+    ("qin", "钦"),
+    ("qin", "侵"),
+    ("qin", "亲"),
+    ("qin", "秦"),
+    ("qin", "琴"),
+    ("qin", "勤"),
+    ("qin", "芹"),
+    ("qin", "擒"),
+    ("qin", "禽"),
+    ("qin", "寝"),
+    ("qin", "沁"),
+    ("qing", "青"),
+    ("qing", "轻"),
+    ("qing", "氢"),
+    ("qing", "倾"),
+    ("qing", "卿"),
+    ("qing", "清"),
+    ("qing", "擎"),
+    ("qing", "晴"),
+    ("qing", "氰"),
+    ("qing", "情"),
+    ("qing", "顷"),
+    ("qing", "请"),
+    ("qing", "庆"),
+    ("qiong", "琼"),
+    ("qiong", "穷"),
+    ("qiu", "秋"),
+    ("qiu", "丘"),
+    ("qiu", "邱"),
+    ("qiu", "球"),
+    ("qiu", "求"),
+    ("qiu", "囚"),
+    ("qiu", "酋"),
+    ("qiu", "泅"),
+    ("qu", "趋"),
+    ("qu", "区"),
+    ("qu", "蛆"),
+    ("qu", "曲"),
+    ("qu", "躯"),
+    ("qu", "屈"),
+    ("qu", "驱"),
+    ("qu", "渠"),
+    ("qu", "取"),
+    ("qu", "娶"),
+    ("qu", "龋"),
+    ("qu", "趣"),
+    ("qu", "去"),
+    ("quan", "圈"),
+    ("quan", "颧"),
+    ("quan", "权"),
+    ("quan", "醛"),
+    ("quan", "泉"),
+    ("quan", "全"),
+    ("quan", "痊"),
+    ("quan", "拳"),
+    ("quan", "犬"),
+    ("quan", "券"),
+    ("quan", "劝"),
+    ("que", "缺"),
+    ("gui", "炔"),
+    ("que", "瘸"),
+    ("que", "却"),
+    ("que", "鹊"),
+    ("que", "榷"),
+    ("que", "确"),
+    ("que", "雀"),
+    ("qun", "裙"),
+    ("qun", "群"),
+    ("ran", "然"),
+    ("ran", "燃"),
+    ("ran", "冉"),
+    ("ran", "染"),
+    ("rang", "瓤"),
+    ("rang", "壤"),
+    ("rang", "攘"),
+    ("rang", "嚷"),
+    ("rang", "让"),
+    ("rao", "饶"),
+    ("rao", "扰"),
+    ("rao", "绕"),
+    ("re", "惹"),
+    ("re", "热"),
+    ("ren", "壬"),
+    ("ren", "仁"),
+    ("ren", "人"),
+    ("ren", "忍"),
+    ("ren", "韧"),
+    ("ren", "任"),
+    ("ren", "认"),
+    ("ren", "刃"),
+    ("ren", "妊"),
+    ("ren", "纫"),
+    ("reng", "扔"),
+    ("reng", "仍"),
+    ("ri", "日"),
+    ("rong", "戎"),
+    ("rong", "茸"),
+    ("rong", "蓉"),
+    ("rong", "荣"),
+    ("rong", "融"),
+    ("rong", "熔"),
+    ("rong", "溶"),
+    ("rong", "容"),
+    ("rong", "绒"),
+    ("rong", "冗"),
+    ("rou", "揉"),
+    ("rou", "柔"),
+    ("rou", "肉"),
+    ("ru", "茹"),
+    ("ru", "蠕"),
+    ("ru", "儒"),
+    ("ru", "孺"),
+    ("ru", "如"),
+    ("ru", "辱"),
+    ("ru", "乳"),
+    ("ru", "汝"),
+    ("ru", "入"),
+    ("ru", "褥"),
+    ("ruan", "软"),
+    ("ruan", "阮"),
+    ("rui", "蕊"),
+    ("rui", "瑞"),
+    ("rui", "锐"),
+    ("run", "闰"),
+    ("run", "润"),
+    ("ruo", "若"),
+    ("ruo", "弱"),
+    ("sa", "撒"),
+    ("sa", "洒"),
+    ("sa", "萨"),
+    ("sai", "腮"),
+    ("sai", "鳃"),
+    ("sai", "塞"),
+    ("sai", "赛"),
+    ("san", "三"),
+    ("san", "叁"),
+    ("san", "伞"),
+    ("san", "散"),
+    ("sang", "桑"),
+    ("sang", "嗓"),
+    ("sang", "丧"),
+    ("sao", "搔"),
+    ("sao", "骚"),
+    ("sao", "扫"),
+    ("sao", "嫂"),
+    ("se", "瑟"),
+    ("se", "色"),
+    ("se", "涩"),
+    ("sen", "森"),
+    ("seng", "僧"),
+    ("sha", "莎"),
+    ("sha", "砂"),
+    ("sha", "杀"),
+    ("sha", "刹"),
+    ("sha", "沙"),
+    ("sha", "纱"),
+    ("sha", "傻"),
+    ("sha", "啥"),
+    ("sha", "煞"),
+    ("shai", "筛"),
+    ("shai", "晒"),
+    ("shan", "珊"),
+    ("shan", "苫"),
+    ("shan", "杉"),
+    ("shan", "山"),
+    ("shan", "删"),
+    ("shan", "煽"),
+    ("shan", "衫"),
+    ("shan", "闪"),
+    ("shan", "陕"),
+    ("shan", "擅"),
+    ("shan", "赡"),
+    ("shan", "膳"),
+    ("shan", "善"),
+    ("shan", "汕"),
+    ("shan", "扇"),
+    ("shan", "缮"),
+    ("shang", "墒"),
+    ("shang", "伤"),
+    ("shang", "商"),
+    ("shang", "赏"),
+    ("shang", "晌"),
+    ("shang", "上"),
+    ("shang", "尚"),
+    ("shang", "裳"),
+    ("shao", "梢"),
+    ("shao", "捎"),
+    ("shao", "稍"),
+    ("shao", "烧"),
+    ("shao", "芍"),
+    ("shao", "勺"),
+    ("shao", "韶"),
+    ("shao", "少"),
+    ("shao", "哨"),
+    ("shao", "邵"),
+    ("shao", "绍"),
+    ("she", "奢"),
+    ("she", "赊"),
+    ("she", "蛇"),
+    ("she", "舌"),
+    ("she", "舍"),
+    ("she", "赦"),
+    ("she", "摄"),
+    ("she", "射"),
+    ("she", "慑"),
+    ("she", "涉"),
+    ("she", "社"),
+    ("she", "设"),
+    ("shen", "砷"),
+    ("shen", "申"),
+    ("shen", "呻"),
+    ("shen", "伸"),
+    ("shen", "身"),
+    ("shen", "深"),
+    ("shen", "娠"),
+    ("shen", "绅"),
+    ("shen", "神"),
+    ("shen", "沈"),
+    ("shen", "审"),
+    ("shen", "婶"),
+    ("shen", "甚"),
+    ("shen", "肾"),
+    ("shen", "慎"),
+    ("shen", "渗"),
+    ("sheng", "声"),
+    ("sheng", "生"),
+    ("sheng", "甥"),
+    ("sheng", "牲"),
+    ("sheng", "升"),
+    ("sheng", "绳"),
+    ("sheng", "省"),
+    ("sheng", "盛"),
+    ("sheng", "剩"),
+    ("sheng", "胜"),
+    ("sheng", "圣"),
+    ("shi", "师"),
+    ("shi", "失"),
+    ("shi", "狮"),
+    ("shi", "施"),
+    ("shi", "湿"),
+    ("shi", "诗"),
+    ("shi", "尸"),
+    ("shi", "虱"),
+    ("shi", "十"),
+    ("shi", "石"),
+    ("shi", "拾"),
+    ("shi", "时"),
+    ("shen", "什"),
+    ("shi", "食"),
+    ("shi", "蚀"),
+    ("shi", "实"),
+    ("shi", "识"),
+    ("shi", "史"),
+    ("shi", "矢"),
+    ("shi", "使"),
+    ("shi", "屎"),
+    ("shi", "驶"),
+    ("shi", "始"),
+    ("shi", "式"),
+    ("shi", "示"),
+    ("shi", "士"),
+    ("shi", "世"),
+    ("shi", "柿"),
+    ("shi", "事"),
+    ("shi", "拭"),
+    ("shi", "誓"),
+    ("shi", "逝"),
+    ("shi", "势"),
+    ("shi", "是"),
+    ("shi", "嗜"),
+    ("shi", "噬"),
+    ("shi", "适"),
+    ("shi", "仕"),
+    ("shi", "侍"),
+    ("shi", "释"),
+    ("shi", "饰"),
+    ("shi", "氏"),
+    ("shi", "市"),
+    ("shi", "恃"),
+    ("shi", "室"),
+    ("shi", "视"),
+    ("shi", "试"),
+    ("shou", "收"),
+    ("shou", "手"),
+    ("shou", "首"),
+    ("shou", "守"),
+    ("shou", "寿"),
+    ("shou", "授"),
+    ("shou", "售"),
+    ("shou", "受"),
+    ("shou", "瘦"),
+    ("shou", "兽"),
+    ("shu", "蔬"),
+    ("shu", "枢"),
+    ("shu", "梳"),
+    ("shu", "殊"),
+    ("shu", "抒"),
+    ("shu", "输"),
+    ("shu", "叔"),
+    ("shu", "舒"),
+    ("shu", "淑"),
+    ("shu", "疏"),
+    ("shu", "书"),
+    ("shu", "赎"),
+    ("shu", "孰"),
+    ("shu", "熟"),
+    ("shu", "薯"),
+    ("shu", "暑"),
+    ("shu", "曙"),
+    ("shu", "署"),
+    ("shu", "蜀"),
+    ("shu", "黍"),
+    ("shu", "鼠"),
+    ("shu", "属"),
+    ("shu", "术"),
+    ("shu", "述"),
+    ("shu", "树"),
+    ("shu", "束"),
+    ("shu", "戍"),
+    ("shu", "竖"),
+    ("shu", "墅"),
+    ("shu", "庶"),
+    ("shu", "数"),
+    ("shu", "漱"),
+    ("shu", "恕"),
+    ("shua", "刷"),
+    ("shua", "耍"),
+    ("shuai", "摔"),
+    ("shuai", "衰"),
+    ("shuai", "甩"),
+    ("shuai", "帅"),
+    ("shuan", "栓"),
+    ("shuan", "拴"),
+    ("shuang", "霜"),
+    ("shuang", "双"),
+    ("shuang", "爽"),
+    ("shei", "谁"),
+    ("shui", "水"),
+    ("shui", "睡"),
+    ("shui", "税"),
+    ("shun", "吮"),
+    ("shun", "瞬"),
+    ("shun", "顺"),
+    ("shun", "舜"),
+    ("shuo", "说"),
+    ("shuo", "硕"),
+    ("shuo", "朔"),
+    ("shuo", "烁"),
+    ("si", "斯"),
+    ("si", "撕"),
+    ("si", "嘶"),
+    ("si", "思"),
+    ("si", "私"),
+    ("si", "司"),
+    ("si", "丝"),
+    ("si", "死"),
+    ("si", "肆"),
+    ("si", "寺"),
+    ("si", "嗣"),
+    ("si", "四"),
+    ("ci", "伺"),
+    ("shi", "似"),
+    ("si", "饲"),
+    ("si", "巳"),
+    ("song", "松"),
+    ("song", "耸"),
+    ("song", "怂"),
+    ("song", "颂"),
+    ("song", "送"),
+    ("song", "宋"),
+    ("song", "讼"),
+    ("song", "诵"),
+    ("sou", "搜"),
+    ("sou", "艘"),
+    ("sou", "擞"),
+    ("sou", "嗽"),
+    ("su", "苏"),
+    ("su", "酥"),
+    ("su", "俗"),
+    ("su", "素"),
+    ("su", "速"),
+    ("su", "粟"),
+    ("su", "僳"),
+    ("su", "塑"),
+    ("su", "溯"),
+    ("su", "宿"),
+    ("su", "诉"),
+    ("su", "肃"),
+    ("suan", "酸"),
+    ("suan", "蒜"),
+    ("suan", "算"),
+    ("sui", "虽"),
+    ("sui", "隋"),
+    ("sui", "随"),
+    ("sui", "绥"),
+    ("sui", "髓"),
+    ("sui", "碎"),
+    ("sui", "岁"),
+    ("sui", "穗"),
+    ("sui", "遂"),
+    ("sui", "隧"),
+    ("sui", "祟"),
+    ("sun", "孙"),
+    ("sun", "损"),
+    ("sun", "笋"),
+    ("suo", "蓑"),
+    ("suo", "梭"),
+    ("suo", "唆"),
+    ("suo", "缩"),
+    ("suo", "琐"),
+    ("suo", "索"),
+    ("suo", "锁"),
+    ("suo", "所"),
+    ("ta", "塌"),
+    ("ta", "他"),
+    ("ta", "它"),
+    ("ta", "她"),
+    ("ta", "塔"),
+    ("ta", "獭"),
+    ("ta", "挞"),
+    ("ta", "蹋"),
+    ("ta", "踏"),
+    ("tai", "胎"),
+    ("tai", "苔"),
+    ("tai", "抬"),
+    ("tai", "台"),
+    ("tai", "泰"),
+    ("tai", "酞"),
+    ("tai", "太"),
+    ("tai", "态"),
+    ("tai", "汰"),
+    ("tan", "坍"),
+    ("tan", "摊"),
+    ("tan", "贪"),
+    ("tan", "瘫"),
+    ("tan", "滩"),
+    ("tan", "坛"),
+    ("tan", "檀"),
+    ("tan", "痰"),
+    ("tan", "潭"),
+    ("tan", "谭"),
+    ("tan", "谈"),
+    ("tan", "坦"),
+    ("tan", "毯"),
+    ("tan", "袒"),
+    ("tan", "碳"),
+    ("tan", "探"),
+    ("tan", "叹"),
+    ("tan", "炭"),
+    ("tang", "汤"),
+    ("tang", "塘"),
+    ("tang", "搪"),
+    ("tang", "堂"),
+    ("tang", "棠"),
+    ("tang", "膛"),
+    ("tang", "唐"),
+    ("tang", "糖"),
+    ("tang", "倘"),
+    ("tang", "躺"),
+    ("tang", "淌"),
+    ("tang", "趟"),
+    ("tang", "烫"),
+    ("tao", "掏"),
+    ("tao", "涛"),
+    ("tao", "滔"),
+    ("tao", "绦"),
+    ("tao", "萄"),
+    ("tao", "桃"),
+    ("tao", "逃"),
+    ("tao", "淘"),
+    ("tao", "陶"),
+    ("tao", "讨"),
+    ("tao", "套"),
+    ("te", "特"),
+    ("teng", "藤"),
+    ("teng", "腾"),
+    ("teng", "疼"),
+    ("teng", "誊"),
+    ("ti", "梯"),
+    ("ti", "剔"),
+    ("ti", "踢"),
+    ("ti", "锑"),
+    ("ti", "提"),
+    ("ti", "题"),
+    ("ti", "蹄"),
+    ("ti", "啼"),
+    ("ti", "体"),
+    ("ti", "替"),
+    ("ti", "嚏"),
+    ("ti", "惕"),
+    ("ti", "涕"),
+    ("ti", "剃"),
+    ("ti", "屉"),
+    ("tian", "天"),
+    ("tian", "添"),
+    ("tian", "填"),
+    ("tian", "田"),
+    ("tian", "甜"),
+    ("tian", "恬"),
+    ("tian", "舔"),
+    ("tian", "腆"),
+    ("tiao", "挑"),
+    ("tiao", "条"),
+    ("tiao", "迢"),
+    ("tiao", "眺"),
+    ("tiao", "跳"),
+    ("tie", "贴"),
+    ("tie", "铁"),
+    ("tie", "帖"),
+    ("ting", "厅"),
+    ("ting", "听"),
+    ("ting", "烃"),
+    ("ting", "汀"),
+    ("ting", "廷"),
+    ("ting", "停"),
+    ("ting", "亭"),
+    ("ting", "庭"),
+    ("ting", "挺"),
+    ("ting", "艇"),
+    ("tong", "通"),
+    ("tong", "桐"),
+    ("tong", "酮"),
+    ("tong", "瞳"),
+    ("tong", "同"),
+    ("tong", "铜"),
+    ("tong", "彤"),
+    ("tong", "童"),
+    ("tong", "桶"),
+    ("tong", "捅"),
+    ("tong", "筒"),
+    ("tong", "统"),
+    ("tong", "痛"),
+    ("tou", "偷"),
+    ("tou", "投"),
+    ("tou", "头"),
+    ("tou", "透"),
+    ("tu", "凸"),
+    ("tu", "秃"),
+    ("tu", "突"),
+    ("tu", "图"),
+    ("tu", "徒"),
+    ("tu", "途"),
+    ("tu", "涂"),
+    ("tu", "屠"),
+    ("tu", "土"),
+    ("tu", "吐"),
+    ("tu", "兔"),
+    ("tuan", "湍"),
+    ("tuan", "团"),
+    ("tui", "推"),
+    ("tui", "颓"),
+    ("tui", "腿"),
+    ("tui", "蜕"),
+    ("tui", "褪"),
+    ("tui", "退"),
+    ("tun", "吞"),
+    ("tun", "屯"),
+    ("tun", "臀"),
+    ("tuo", "拖"),
+    ("tuo", "托"),
+    ("tuo", "脱"),
+    ("tuo", "鸵"),
+    ("tuo", "陀"),
+    ("tuo", "驮"),
+    ("tuo", "驼"),
+    ("tuo", "椭"),
+    ("tuo", "妥"),
+    ("ta", "拓"),
+    ("tuo", "唾"),
+    ("wa", "挖"),
+    ("wa", "哇"),
+    ("wa", "蛙"),
+    ("wa", "洼"),
+    ("wa", "娃"),
+    ("wa", "瓦"),
+    ("wa", "袜"),
+    ("wai", "歪"),
+    ("wai", "外"),
+    ("wan", "豌"),
+    ("wan", "弯"),
+    ("wan", "湾"),
+    ("wan", "玩"),
+    ("wan", "顽"),
+    ("wan", "丸"),
+    ("wan", "烷"),
+    ("wan", "完"),
+    ("wan", "碗"),
+    ("wan", "挽"),
+    ("wan", "晚"),
+    ("wan", "皖"),
+    ("wan", "惋"),
+    ("wan", "宛"),
+    ("wan", "婉"),
+    ("wan", "万"),
+    ("wan", "腕"),
+    ("wang", "汪"),
+    ("wang", "王"),
+    ("wang", "亡"),
+    ("wang", "枉"),
+    ("wang", "网"),
+    ("wang", "往"),
+    ("wang", "旺"),
+    ("wang", "望"),
+    ("wang", "忘"),
+    ("wang", "妄"),
+    ("wei", "威"),
+    ("wei", "巍"),
+    ("wei", "微"),
+    ("wei", "危"),
+    ("wei", "韦"),
+    ("wei", "违"),
+    ("wei", "桅"),
+    ("wei", "围"),
+    ("wei", "唯"),
+    ("wei", "惟"),
+    ("wei", "为"),
+    ("wei", "潍"),
+    ("wei", "维"),
+    ("wei", "苇"),
+    ("wei", "萎"),
+    ("wei", "委"),
+    ("wei", "伟"),
+    ("wei", "伪"),
+    ("wei", "尾"),
+    ("wei", "纬"),
+    ("wei", "未"),
+    ("wei", "蔚"),
+    ("wei", "味"),
+    ("wei", "畏"),
+    ("wei", "胃"),
+    ("wei", "喂"),
+    ("wei", "魏"),
+    ("wei", "位"),
+    ("wei", "渭"),
+    ("wei", "谓"),
+    ("wei", "尉"),
+    ("wei", "慰"),
+    ("wei", "卫"),
+    ("wen", "瘟"),
+    ("wen", "温"),
+    ("wen", "蚊"),
+    ("wen", "文"),
+    ("wen", "闻"),
+    ("wen", "纹"),
+    ("wen", "吻"),
+    ("wen", "稳"),
+    ("wen", "紊"),
+    ("wen", "问"),
+    ("weng", "嗡"),
+    ("weng", "翁"),
+    ("weng", "瓮"),
+    ("wo", "挝"),
+    ("wo", "蜗"),
+    ("wo", "涡"),
+    ("wo", "窝"),
+    ("wo", "我"),
+    ("wo", "斡"),
+    ("wo", "卧"),
+    ("wo", "握"),
+    ("wo", "沃"),
+    ("wu", "巫"),
+    ("wu", "呜"),
+    ("wu", "钨"),
+    ("wu", "乌"),
+    ("wu", "污"),
+    ("wu", "诬"),
+    ("wu", "屋"),
+    ("wu", "无"),
+    ("wu", "芜"),
+    ("wu", "梧"),
+    ("wu", "吾"),
+    ("wu", "吴"),
+    ("wu", "毋"),
+    ("wu", "武"),
+    ("wu", "五"),
+    ("wu", "捂"),
+    ("wu", "午"),
+    ("wu", "舞"),
+    ("wu", "伍"),
+    ("wu", "侮"),
+    ("wu", "坞"),
+    ("wu", "戊"),
+    ("wu", "雾"),
+    ("wu", "晤"),
+    ("wu", "物"),
+    ("wu", "勿"),
+    ("wu", "务"),
+    ("wu", "悟"),
+    ("wu", "误"),
+    ("xi", "昔"),
+    ("xi", "熙"),
+    ("xi", "析"),
+    ("xi", "西"),
+    ("xi", "硒"),
+    ("xi", "矽"),
+    ("xi", "晰"),
+    ("xi", "嘻"),
+    ("xi", "吸"),
+    ("xi", "锡"),
+    ("xi", "牺"),
+    ("xi", "稀"),
+    ("xi", "息"),
+    ("xi", "希"),
+    ("xi", "悉"),
+    ("xi", "膝"),
+    ("xi", "夕"),
+    ("xi", "惜"),
+    ("xi", "熄"),
+    ("xi", "烯"),
+    ("xi", "溪"),
+    ("xi", "汐"),
+    ("xi", "犀"),
+    ("xi", "檄"),
+    ("xi", "袭"),
+    ("xi", "席"),
+    ("xi", "习"),
+    ("xi", "媳"),
+    ("xi", "喜"),
+    ("xi", "铣"),
+    ("xi", "洗"),
+    ("xi", "系"),
+    ("xi", "隙"),
+    ("xi", "戏"),
+    ("xi", "细"),
+    ("xia", "瞎"),
+    ("xia", "虾"),
+    ("xia", "匣"),
+    ("xia", "霞"),
+    ("xia", "辖"),
+    ("xia", "暇"),
+    ("xia", "峡"),
+    ("xia", "侠"),
+    ("xia", "狭"),
+    ("xia", "下"),
+    ("sha", "厦"),
+    ("xia", "夏"),
+    ("xia", "吓"),
+    ("xian", "掀"),
+    ("xian", "锨"),
+    ("xian", "先"),
+    ("xian", "仙"),
+    ("xian", "鲜"),
+    ("xian", "纤"),
+    ("xian", "咸"),
+    ("xian", "贤"),
+    ("xian", "衔"),
+    ("xian", "舷"),
+    ("xian", "闲"),
+    ("xian", "涎"),
+    ("xian", "弦"),
+    ("xian", "嫌"),
+    ("xian", "显"),
+    ("xian", "险"),
+    ("xian", "现"),
+    ("xian", "献"),
+    ("xian", "县"),
+    ("xian", "腺"),
+    ("xian", "馅"),
+    ("xian", "羡"),
+    ("xian", "宪"),
+    ("xian", "陷"),
+    ("xian", "限"),
+    ("xian", "线"),
+    ("xiang", "相"),
+    ("xiang", "厢"),
+    ("xiang", "镶"),
+    ("xiang", "香"),
+    ("xiang", "箱"),
+    ("xiang", "襄"),
+    ("xiang", "湘"),
+    ("xiang", "乡"),
+    ("xiang", "翔"),
+    ("xiang", "祥"),
+    ("xiang", "详"),
+    ("xiang", "想"),
+    ("xiang", "响"),
+    ("xiang", "享"),
+    ("xiang", "项"),
+    ("xiang", "巷"),
+    ("xiang", "橡"),
+    ("xiang", "像"),
+    ("xiang", "向"),
+    ("xiang", "象"),
+    ("xiao", "萧"),
+    ("xiao", "硝"),
+    ("xiao", "霄"),
+    ("xue", "削"),
+    ("xiao", "哮"),
+    ("xiao", "嚣"),
+    ("xiao", "销"),
+    ("xiao", "消"),
+    ("xiao", "宵"),
+    ("xiao", "淆"),
+    ("xiao", "晓"),
+    ("xiao", "小"),
+    ("xiao", "孝"),
+    ("xiao", "校"),
+    ("xiao", "肖"),
+    ("xiao", "啸"),
+    ("xiao", "笑"),
+    ("xiao", "效"),
+    ("xie", "楔"),
+    ("xie", "些"),
+    ("xie", "歇"),
+    ("xie", "蝎"),
+    ("xie", "鞋"),
+    ("xie", "协"),
+    ("xie", "挟"),
+    ("xie", "携"),
+    ("xie", "邪"),
+    ("xie", "斜"),
+    ("xie", "胁"),
+    ("xie", "谐"),
+    ("xie", "写"),
+    ("xie", "械"),
+    ("xie", "卸"),
+    ("xie", "蟹"),
+    ("xie", "懈"),
+    ("xie", "泄"),
+    ("xie", "泻"),
+    ("xie", "谢"),
+    ("xie", "屑"),
+    ("xin", "薪"),
+    ("xin", "芯"),
+    ("xin", "锌"),
+    ("xin", "欣"),
+    ("xin", "辛"),
+    ("xin", "新"),
+    ("xin", "忻"),
+    ("xin", "心"),
+    ("xin", "信"),
+    ("xin", "衅"),
+    ("xing", "星"),
+    ("xing", "腥"),
+    ("xing", "猩"),
+    ("xing", "惺"),
+    ("xing", "兴"),
+    ("xing", "刑"),
+    ("xing", "型"),
+    ("xing", "形"),
+    ("xing", "邢"),
+    ("xing", "行"),
+    ("xing", "醒"),
+    ("xing", "幸"),
+    ("xing", "杏"),
+    ("xing", "性"),
+    ("xing", "姓"),
+    ("xiong", "兄"),
+    ("xiong", "凶"),
+    ("xiong", "胸"),
+    ("xiong", "匈"),
+    ("xiong", "汹"),
+    ("xiong", "雄"),
+    ("xiong", "熊"),
+    ("xiu", "休"),
+    ("xiu", "修"),
+    ("xiu", "羞"),
+    ("xiu", "朽"),
+    ("xiu", "嗅"),
+    ("xiu", "锈"),
+    ("xiu", "秀"),
+    ("xiu", "袖"),
+    ("xiu", "绣"),
+    ("xu", "墟"),
+    ("xu", "戌"),
+    ("xu", "需"),
+    ("xu", "虚"),
+    ("xu", "嘘"),
+    ("xu", "须"),
+    ("xu", "徐"),
+    ("xu", "许"),
+    ("xu", "蓄"),
+    ("xu", "酗"),
+    ("xu", "叙"),
+    ("xu", "旭"),
+    ("xu", "序"),
+    ("chu", "畜"),
+    ("xu", "恤"),
+    ("xu", "絮"),
+    ("xu", "婿"),
+    ("xu", "绪"),
+    ("xu", "续"),
+    ("xuan", "轩"),
+    ("xuan", "喧"),
+    ("xuan", "宣"),
+    ("xuan", "悬"),
+    ("xuan", "旋"),
+    ("xuan", "玄"),
+    ("xuan", "选"),
+    ("xuan", "癣"),
+    ("xuan", "眩"),
+    ("xuan", "绚"),
+    ("xue", "靴"),
+    ("xue", "薛"),
+    ("xue", "学"),
+    ("xue", "穴"),
+    ("xue", "雪"),
+    ("xue", "血"),
+    ("xun", "勋"),
+    ("xun", "熏"),
+    ("xun", "循"),
+    ("xun", "旬"),
+    ("xun", "询"),
+    ("xun", "寻"),
+    ("xun", "驯"),
+    ("xun", "巡"),
+    ("xun", "殉"),
+    ("xun", "汛"),
+    ("xun", "训"),
+    ("xun", "讯"),
+    ("xun", "逊"),
+    ("xun", "迅"),
+    ("ya", "压"),
+    ("ya", "押"),
+    ("ya", "鸦"),
+    ("ya", "鸭"),
+    ("ya", "呀"),
+    ("ya", "丫"),
+    ("ya", "芽"),
+    ("ya", "牙"),
+    ("ya", "蚜"),
+    ("ya", "崖"),
+    ("ya", "衙"),
+    ("ya", "涯"),
+    ("ya", "雅"),
+    ("ya", "哑"),
+    ("ya", "亚"),
+    ("ya", "讶"),
+    ("yan", "焉"),
+    ("yan", "咽"),
+    ("yan", "阉"),
+    ("yan", "烟"),
+    ("yan", "淹"),
+    ("yan", "盐"),
+    ("yan", "严"),
+    ("yan", "研"),
+    ("yan", "蜒"),
+    ("yan", "岩"),
+    ("yan", "延"),
+    ("yan", "言"),
+    ("yan", "颜"),
+    ("yan", "阎"),
+    ("yan", "炎"),
+    ("yan", "沿"),
+    ("yan", "奄"),
+    ("yan", "掩"),
+    ("yan", "眼"),
+    ("yan", "衍"),
+    ("yan", "演"),
+    ("yan", "艳"),
+    ("yan", "堰"),
+    ("yan", "燕"),
+    ("yan", "厌"),
+    ("yan", "砚"),
+    ("yan", "雁"),
+    ("yan", "唁"),
+    ("yan", "彦"),
+    ("yan", "焰"),
+    ("yan", "宴"),
+    ("yan", "谚"),
+    ("yan", "验"),
+    ("yang", "殃"),
+    ("yang", "央"),
+    ("yang", "鸯"),
+    ("yang", "秧"),
+    ("yang", "杨"),
+    ("yang", "扬"),
+    ("yang", "佯"),
+    ("yang", "疡"),
+    ("yang", "羊"),
+    ("yang", "洋"),
+    ("yang", "阳"),
+    ("yang", "氧"),
+    ("yang", "仰"),
+    ("yang", "痒"),
+    ("yang", "养"),
+    ("yang", "样"),
+    ("yang", "漾"),
+    ("yao", "邀"),
+    ("yao", "腰"),
+    ("yao", "妖"),
+    ("yao", "瑶"),
+    ("yao", "摇"),
+    ("yao", "尧"),
+    ("yao", "遥"),
+    ("yao", "窑"),
+    ("yao", "谣"),
+    ("yao", "姚"),
+    ("yao", "咬"),
+    ("yao", "舀"),
+    ("yao", "药"),
+    ("yao", "要"),
+    ("yao", "耀"),
+    ("ye", "椰"),
+    ("ye", "噎"),
+    ("ye", "耶"),
+    ("ye", "爷"),
+    ("ye", "野"),
+    ("ye", "冶"),
+    ("ye", "也"),
+    ("ye", "页"),
+    ("ye", "掖"),
+    ("ye", "业"),
+    ("ye", "叶"),
+    ("ye", "曳"),
+    ("ye", "腋"),
+    ("ye", "夜"),
+    ("ye", "液"),
+    ("yi", "一"),
+    ("yi", "壹"),
+    ("yi", "医"),
+    ("yi", "揖"),
+    ("yi", "铱"),
+    ("yi", "依"),
+    ("yi", "伊"),
+    ("yi", "衣"),
+    ("yi", "颐"),
+    ("yi", "夷"),
+    ("yi", "遗"),
+    ("yi", "移"),
+    ("yi", "仪"),
+    ("yi", "胰"),
+    ("yi", "疑"),
+    ("yi", "沂"),
+    ("yi", "宜"),
+    ("yi", "姨"),
+    ("yi", "彝"),
+    ("yi", "椅"),
+    ("yi", "蚁"),
+    ("yi", "倚"),
+    ("yi", "已"),
+    ("yi", "乙"),
+    ("yi", "矣"),
+    ("yi", "以"),
+    ("yi", "艺"),
+    ("yi", "抑"),
+    ("yi", "易"),
+    ("yi", "邑"),
+    ("yi", "屹"),
+    ("yi", "亿"),
+    ("yi", "役"),
+    ("yi", "臆"),
+    ("yi", "逸"),
+    ("yi", "肄"),
+    ("yi", "疫"),
+    ("yi", "亦"),
+    ("yi", "裔"),
+    ("yi", "意"),
+    ("yi", "毅"),
+    ("yi", "忆"),
+    ("yi", "义"),
+    ("yi", "益"),
+    ("yi", "溢"),
+    ("yi", "诣"),
+    ("yi", "议"),
+    ("yi", "谊"),
+    ("yi", "译"),
+    ("yi", "异"),
+    ("yi", "翼"),
+    ("yi", "翌"),
+    ("yi", "绎"),
+    ("yin", "茵"),
+    ("yin", "荫"),
+    ("yin", "因"),
+    ("yin", "殷"),
+    ("yin", "音"),
+    ("yin", "阴"),
+    ("yin", "姻"),
+    ("yin", "吟"),
+    ("yin", "银"),
+    ("yin", "淫"),
+    ("yin", "寅"),
+    ("yin", "饮"),
+    ("yin", "尹"),
+    ("yin", "引"),
+    ("yin", "隐"),
+    ("yin", "印"),
+    ("ying", "英"),
+    ("ying", "樱"),
+    ("ying", "婴"),
+    ("ying", "鹰"),
+    ("ying", "应"),
+    ("ying", "缨"),
+    ("ying", "莹"),
+    ("ying", "萤"),
+    ("ying", "营"),
+    ("ying", "荧"),
+    ("ying", "蝇"),
+    ("ying", "迎"),
+    ("ying", "赢"),
+    ("ying", "盈"),
+    ("ying", "影"),
+    ("ying", "颖"),
+    ("ying", "硬"),
+    ("ying", "映"),
+    ("yo", "哟"),
+    ("yong", "拥"),
+    ("yong", "佣"),
+    ("yong", "臃"),
+    ("yong", "痈"),
+    ("yong", "庸"),
+    ("yong", "雍"),
+    ("yong", "踊"),
+    ("yong", "蛹"),
+    ("yong", "咏"),
+    ("yong", "泳"),
+    ("yong", "涌"),
+    ("yong", "永"),
+    ("yong", "恿"),
+    ("yong", "勇"),
+    ("yong", "用"),
+    ("you", "幽"),
+    ("you", "优"),
+    ("you", "悠"),
+    ("you", "忧"),
+    ("you", "尤"),
+    ("you", "由"),
+    ("you", "邮"),
+    ("you", "铀"),
+    ("you", "犹"),
+    ("you", "油"),
+    ("you", "游"),
+    ("you", "酉"),
+    ("you", "有"),
+    ("you", "友"),
+    ("you", "右"),
+    ("you", "佑"),
+    ("you", "釉"),
+    ("you", "诱"),
+    ("you", "又"),
+    ("you", "幼"),
+    ("yu", "迂"),
+    ("yu", "淤"),
+    ("yu", "于"),
+    ("yu", "盂"),
+    ("yu", "榆"),
+    ("yu", "虞"),
+    ("yu", "愚"),
+    ("yu", "舆"),
+    ("yu", "余"),
+    ("yu", "俞"),
+    ("yu", "逾"),
+    ("yu", "鱼"),
+    ("yu", "愉"),
+    ("yu", "渝"),
+    ("yu", "渔"),
+    ("yu", "隅"),
+    ("yu", "予"),
+    ("yu", "娱"),
+    ("yu", "雨"),
+    ("yu", "与"),
+    ("yu", "屿"),
+    ("yu", "禹"),
+    ("yu", "宇"),
+    ("yu", "语"),
+    ("yu", "羽"),
+    ("yu", "玉"),
+    ("yu", "域"),
+    ("yu", "芋"),
+    ("yu", "郁"),
+    ("xu", "吁"),
+    ("yu", "遇"),
+    ("yu", "喻"),
+    ("yu", "峪"),
+    ("yu", "御"),
+    ("yu", "愈"),
+    ("yu", "欲"),
+    ("yu", "狱"),
+    ("yu", "育"),
+    ("yu", "誉"),
+    ("yu", "浴"),
+    ("yu", "寓"),
+    ("yu", "裕"),
+    ("yu", "预"),
+    ("yu", "豫"),
+    ("yu", "驭"),
+    ("yuan", "鸳"),
+    ("yuan", "渊"),
+    ("yuan", "冤"),
+    ("yuan", "元"),
+    ("yuan", "垣"),
+    ("yuan", "袁"),
+    ("yuan", "原"),
+    ("yuan", "援"),
+    ("yuan", "辕"),
+    ("yuan", "园"),
+    ("yuan", "员"),
+    ("yuan", "圆"),
+    ("yuan", "猿"),
+    ("yuan", "源"),
+    ("yuan", "缘"),
+    ("yuan", "远"),
+    ("yuan", "苑"),
+    ("yuan", "愿"),
+    ("yuan", "怨"),
+    ("yuan", "院"),
+    ("yue", "曰"),
+    ("yue", "约"),
+    ("yue", "越"),
+    ("yue", "跃"),
+    ("yao", "钥"),
+    ("yue", "岳"),
+    ("yue", "粤"),
+    ("yue", "月"),
+    ("yue", "悦"),
+    ("yue", "阅"),
+    ("yun", "耘"),
+    ("yun", "云"),
+    ("yun", "郧"),
+    ("yun", "匀"),
+    ("yun", "陨"),
+    ("yun", "允"),
+    ("yun", "运"),
+    ("yun", "蕴"),
+    ("yun", "酝"),
+    ("yun", "晕"),
+    ("yun", "韵"),
+    ("yun", "孕"),
+    ("za", "匝"),
+    ("za", "砸"),
+    ("za", "杂"),
+    ("zai", "栽"),
+    ("zai", "哉"),
+    ("zai", "灾"),
+    ("zai", "宰"),
+    ("zai", "载"),
+    ("zai", "再"),
+    ("zai", "在"),
+    ("zan", "咱"),
+    ("zan", "攒"),
+    ("zan", "暂"),
+    ("zan", "赞"),
+    ("zang", "赃"),
+    ("zang", "脏"),
+    ("zang", "葬"),
+    ("zao", "遭"),
+    ("zao", "糟"),
+    ("zao", "凿"),
+    ("zao", "藻"),
+    ("zao", "枣"),
+    ("zao", "早"),
+    ("zao", "澡"),
+    ("zao", "蚤"),
+    ("zao", "躁"),
+    ("zao", "噪"),
+    ("zao", "造"),
+    ("zao", "皂"),
+    ("zao", "灶"),
+    ("zao", "燥"),
+    ("ze", "责"),
+    ("ze", "择"),
+    ("ze", "则"),
+    ("ze", "泽"),
+    ("zei", "贼"),
+    ("zen", "怎"),
+    ("zeng", "增"),
+    ("zeng", "憎"),
+    ("ceng", "曾"),
+    ("zeng", "赠"),
+    ("zha", "扎"),
+    ("zha", "喳"),
+    ("zha", "渣"),
+    ("zha", "札"),
+    ("ya", "轧"),
+    ("zha", "铡"),
+    ("zha", "闸"),
+    ("zha", "眨"),
+    ("zha", "栅"),
+    ("zha", "榨"),
+    ("za", "咋"),
+    ("zha", "乍"),
+    ("zha", "炸"),
+    ("zha", "诈"),
+    ("zhai", "摘"),
+    ("zhai", "斋"),
+    ("zhai", "宅"),
+    ("zhai", "窄"),
+    ("zhai", "债"),
+    ("zhai", "寨"),
+    ("zhan", "瞻"),
+    ("zhan", "毡"),
+    ("zhan", "詹"),
+    ("zhan", "粘"),
+    ("zhan", "沾"),
+    ("zhan", "盏"),
+    ("zhan", "斩"),
+    ("nian", "辗"),
+    ("zhan", "崭"),
+    ("zhan", "展"),
+    ("zhan", "蘸"),
+    ("zhan", "栈"),
+    ("zhan", "占"),
+    ("zhan", "战"),
+    ("zhan", "站"),
+    ("zhan", "湛"),
+    ("zhan", "绽"),
+    ("zhang", "樟"),
+    ("zhang", "章"),
+    ("zhang", "彰"),
+    ("zhang", "漳"),
+    ("zhang", "张"),
+    ("zhang", "掌"),
+    ("zhang", "涨"),
+    ("zhang", "杖"),
+    ("zhang", "丈"),
+    ("zhang", "帐"),
+    ("zhang", "账"),
+    ("zhang", "仗"),
+    ("zhang", "胀"),
+    ("zhang", "瘴"),
+    ("zhang", "障"),
+    ("zhao", "招"),
+    ("zhao", "昭"),
+    ("zhao", "找"),
+    ("zhao", "沼"),
+    ("zhao", "赵"),
+    ("zhao", "照"),
+    ("zhao", "罩"),
+    ("zhao", "兆"),
+    ("zhao", "肇"),
+    ("zhao", "召"),
+    ("zhe", "遮"),
+    ("zhe", "折"),
+    ("zhe", "哲"),
+    ("zhe", "蛰"),
+    ("zhe", "辙"),
+    ("zhe", "者"),
+    ("zhe", "锗"),
+    ("zhe", "蔗"),
+    ("zhe", "这"),
+    ("zhe", "浙"),
+    ("zhen", "珍"),
+    ("zhen", "斟"),
+    ("zhen", "真"),
+    ("zhen", "甄"),
+    ("zhen", "砧"),
+    ("zhen", "臻"),
+    ("zhen", "贞"),
+    ("zhen", "针"),
+    ("zhen", "侦"),
+    ("zhen", "枕"),
+    ("zhen", "疹"),
+    ("zhen", "诊"),
+    ("zhen", "震"),
+    ("zhen", "振"),
+    ("zhen", "镇"),
+    ("zhen", "阵"),
+    ("zheng", "蒸"),
+    ("zheng", "挣"),
+    ("zheng", "睁"),
+    ("zheng", "征"),
+    ("zheng", "狰"),
+    ("zheng", "争"),
+    ("zheng", "怔"),
+    ("zheng", "整"),
+    ("zheng", "拯"),
+    ("zheng", "正"),
+    ("zheng", "政"),
+    ("zheng", "帧"),
+    ("zheng", "症"),
+    ("zheng", "郑"),
+    ("zheng", "证"),
+    ("zhi", "芝"),
+    ("zhi", "枝"),
+    ("zhi", "支"),
+    ("zhi", "吱"),
+    ("zhi", "蜘"),
+    ("zhi", "知"),
+    ("zhi", "肢"),
+    ("zhi", "脂"),
+    ("zhi", "汁"),
+    ("zhi", "之"),
+    ("zhi", "织"),
+    ("zhi", "职"),
+    ("zhi", "直"),
+    ("zhi", "植"),
+    ("zhi", "殖"),
+    ("zhi", "执"),
+    ("zhi", "值"),
+    ("zhi", "侄"),
+    ("zhi", "址"),
+    ("zhi", "指"),
+    ("zhi", "止"),
+    ("zhi", "趾"),
+    ("zhi", "只"),
+    ("zhi", "旨"),
+    ("zhi", "纸"),
+    ("zhi", "志"),
+    ("zhi", "挚"),
+    ("zhi", "掷"),
+    ("zhi", "至"),
+    ("zhi", "致"),
+    ("zhi", "置"),
+    ("zhi", "帜"),
+    ("zhi", "峙"),
+    ("zhi", "制"),
+    ("zhi", "智"),
+    ("zhi", "秩"),
+    ("zhi", "稚"),
+    ("zhi", "质"),
+    ("zhi", "炙"),
+    ("zhi", "痔"),
+    ("zhi", "滞"),
+    ("zhi", "治"),
+    ("zhi", "窒"),
+    ("zhong", "中"),
+    ("zhong", "盅"),
+    ("zhong", "忠"),
+    ("zhong", "钟"),
+    ("zhong", "衷"),
+    ("zhong", "终"),
+    ("zhong", "种"),
+    ("zhong", "肿"),
+    ("zhong", "重"),
+    ("zhong", "仲"),
+    ("zhong", "众"),
+    ("zhou", "舟"),
+    ("zhou", "周"),
+    ("zhou", "州"),
+    ("zhou", "洲"),
+    ("zhou", "诌"),
+    ("zhou", "粥"),
+    ("zhou", "轴"),
+    ("zhou", "肘"),
+    ("zhou", "帚"),
+    ("zhou", "咒"),
+    ("zhou", "皱"),
+    ("zhou", "宙"),
+    ("zhou", "昼"),
+    ("zhou", "骤"),
+    ("zhu", "珠"),
+    ("zhu", "株"),
+    ("zhu", "蛛"),
+    ("zhu", "朱"),
+    ("zhu", "猪"),
+    ("zhu", "诸"),
+    ("zhu", "诛"),
+    ("zhu", "逐"),
+    ("zhu", "竹"),
+    ("zhu", "烛"),
+    ("zhu", "煮"),
+    ("zhu", "拄"),
+    ("zhu", "瞩"),
+    ("zhu", "嘱"),
+    ("zhu", "主"),
+    ("zhe", "著"),
+    ("zhu", "柱"),
+    ("zhu", "助"),
+    ("zhu", "蛀"),
+    ("zhu", "贮"),
+    ("zhu", "铸"),
+    ("zhu", "筑"),
+    ("zhu", "住"),
+    ("zhu", "注"),
+    ("zhu", "祝"),
+    ("zhu", "驻"),
+    ("zhua", "抓"),
+    ("zhao", "爪"),
+    ("zhuai", "拽"),
+    ("zhuan", "专"),
+    ("zhuan", "砖"),
+    ("zhuan", "转"),
+    ("zhuan", "撰"),
+    ("zhuan", "赚"),
+    ("zhuan", "篆"),
+    ("zhuang", "桩"),
+    ("zhuang", "庄"),
+    ("zhuang", "装"),
+    ("zhuang", "妆"),
+    ("zhuang", "撞"),
+    ("zhuang", "壮"),
+    ("zhuang", "状"),
+    ("chui", "椎"),
+    ("zhui", "锥"),
+    ("zhui", "追"),
+    ("zhui", "赘"),
+    ("zhui", "坠"),
+    ("zhui", "缀"),
+    ("zhun", "谆"),
+    ("zhun", "准"),
+    ("zhuo", "捉"),
+    ("zhuo", "拙"),
+    ("zhuo", "卓"),
+    ("zhuo", "桌"),
+    ("zuo", "琢"),
+    ("zhuo", "茁"),
+    ("zhuo", "酌"),
+    ("zhuo", "啄"),
+    ("zhe", "着"),
+    ("zhuo", "灼"),
+    ("zhuo", "浊"),
+    ("zi", "兹"),
+    ("zi", "咨"),
+    ("zi", "资"),
+    ("zi", "姿"),
+    ("zi", "滋"),
+    ("zi", "淄"),
+    ("zi", "孜"),
+    ("zi", "紫"),
+    ("zi", "仔"),
+    ("zi", "籽"),
+    ("zi", "滓"),
+    ("zi", "子"),
+    ("zi", "自"),
+    ("zi", "渍"),
+    ("zi", "字"),
+    ("zong", "鬃"),
+    ("zong", "棕"),
+    ("zong", "踪"),
+    ("zong", "宗"),
+    ("zong", "综"),
+    ("zong", "总"),
+    ("zong", "纵"),
+    ("zou", "邹"),
+    ("zou", "走"),
+    ("zou", "奏"),
+    ("zou", "揍"),
+    ("zu", "租"),
+    ("zu", "足"),
+    ("zu", "卒"),
+    ("zu", "族"),
+    ("zu", "祖"),
+    ("zu", "诅"),
+    ("zu", "阻"),
+    ("zu", "组"),
+    ("zuan", "钻"),
+    ("zuan", "纂"),
+    ("zui", "嘴"),
+    ("zui", "醉"),
+    ("zui", "最"),
+    ("zui", "罪"),
+    ("zun", "尊"),
+    ("zun", "遵"),
+    ("zuo", "昨"),
+    ("zuo", "左"),
+    ("zuo", "佐"),
+    ("zha", "柞"),
+    ("zuo", "做"),
+    ("zuo", "作"),
+    ("zuo", "坐"),
+    ("zuo", "座"),
+    ("chu", "亍"),
+    ("ji", "丌"),
+    ("wu", "兀"),
+    ("gai", "丐"),
+    ("nian", "廿"),
+    ("sa", "卅"),
+    ("pi", "丕"),
+    ("gen", "亘"),
+    ("cheng", "丞"),
+    ("ge", "鬲"),
+    ("nao", "孬"),
+    ("e", "噩"),
+    ("gun", "丨"),
+    ("yu", "禺"),
+    ("pie", "丿"),
+    ("bi", "匕"),
+    ("tuo", "乇"),
+    ("yao", "夭"),
+    ("yao", "爻"),
+    ("zhi", "卮"),
+    ("di", "氐"),
+    ("xin", "囟"),
+    ("yin", "胤"),
+    ("kui", "馗"),
+    ("yu", "毓"),
+    ("gao", "睾"),
+    ("tao", "鼗"),
+    ("zhu", "丶"),
+    ("ji", "亟"),
+    ("nai", "鼐"),
+    ("mie", "乜"),
+    ("ji", "乩"),
+    ("qi", "亓"),
+    ("mi", "芈"),
+    ("bei", "孛"),
+    ("se", "啬"),
+    ("gu", "嘏"),
+    ("ze", "仄"),
+    ("she", "厍"),
+    ("cuo", "厝"),
+    ("yan", "厣"),
+    ("jue", "厥"),
+    ("si", "厮"),
+    ("ye", "靥"),
+    ("yan", "赝"),
+    ("fang", "匚"),
+    ("po", "叵"),
+    ("gui", "匦"),
+    ("kui", "匮"),
+    ("bian", "匾"),
+    ("ze", "赜"),
+    ("gua", "卦"),
+    ("you", "卣"),
+    ("dao", "刂"),
+    ("yi", "刈"),
+    ("wen", "刎"),
+    ("jing", "刭"),
+    ("ku", "刳"),
+    ("gui", "刿"),
+    ("kai", "剀"),
+    ("la", "剌"),
+    ("ji", "剞"),
+    ("shan", "剡"),
+    ("wan", "剜"),
+    ("kuai", "蒯"),
+    ("piao", "剽"),
+    ("jue", "劂"),
+    ("qiao", "劁"),
+    ("huo", "劐"),
+    ("yi", "劓"),
+    ("jiong", "冂"),
+    ("wang", "罔"),
+    ("ren", "亻"),
+    ("ding", "仃"),
+    ("zhang", "仉"),
+    ("le", "仂"),
+    ("sa", "仨"),
+    ("ge", "仡"),
+    ("mu", "仫"),
+    ("ren", "仞"),
+    ("yu", "伛"),
+    ("pi", "仳"),
+    ("ya", "伢"),
+    ("wa", "佤"),
+    ("wu", "仵"),
+    ("chang", "伥"),
+    ("cang", "伧"),
+    ("kang", "伉"),
+    ("zhu", "伫"),
+    ("ning", "佞"),
+    ("ka", "佧"),
+    ("you", "攸"),
+    ("yi", "佚"),
+    ("gou", "佝"),
+    ("tong", "佟"),
+    ("tuo", "佗"),
+    ("ni", "伲"),
+    ("jia", "伽"),
+    ("ji", "佶"),
+    ("er", "佴"),
+    ("you", "侑"),
+    ("kua", "侉"),
+    ("kan", "侃"),
+    ("zhu", "侏"),
+    ("yi", "佾"),
+    ("tiao", "佻"),
+    ("chai", "侪"),
+    ("jiao", "佼"),
+    ("nong", "侬"),
+    ("mou", "侔"),
+    ("chou", "俦"),
+    ("yan", "俨"),
+    ("li", "俪"),
+    ("qiu", "俅"),
+    ("li", "俚"),
+    ("yu", "俣"),
+    ("ping", "俜"),
+    ("yong", "俑"),
+    ("qi", "俟"),
+    ("feng", "俸"),
+    ("qian", "倩"),
+    ("ruo", "偌"),
+    ("pai", "俳"),
+    ("zhuo", "倬"),
+    ("shu", "倏"),
+    ("luo", "倮"),
+    ("wo", "倭"),
+    ("bi", "俾"),
+    ("ti", "倜"),
+    ("guan", "倌"),
+    ("kong", "倥"),
+    ("ju", "倨"),
+    ("fen", "偾"),
+    ("yan", "偃"),
+    ("xie", "偕"),
+    ("ji", "偈"),
+    ("wei", "偎"),
+    ("zong", "偬"),
+    ("lou", "偻"),
+    ("tang", "傥"),
+    ("bin", "傧"),
+    ("nuo", "傩"),
+    ("chi", "傺"),
+    ("xi", "僖"),
+    ("jing", "儆"),
+    ("jian", "僭"),
+    ("jiao", "僬"),
+    ("jiu", "僦"),
+    ("tong", "僮"),
+    ("xuan", "儇"),
+    ("dan", "儋"),
+    ("tong", "仝"),
+    ("tun", "氽"),
+    ("she", "佘"),
+    ("qian", "佥"),
+    ("zu", "俎"),
+    ("yue", "龠"),
+    ("cuan", "汆"),
+    ("di", "籴"),
+    ("xi", "兮"),
+    ("xun", "巽"),
+    ("hong", "黉"),
+    ("guo", "馘"),
+    ("chan", "冁"),
+    ("kui", "夔"),
+    ("bao", "勹"),
+    ("pu", "匍"),
+    ("hong", "訇"),
+    ("fu", "匐"),
+    ("fu", "凫"),
+    ("su", "夙"),
+    ("si", "兕"),
+    ("tou", "亠"),
+    ("yan", "兖"),
+    ("bo", "亳"),
+    ("gun", "衮"),
+    ("mao", "袤"),
+    ("xie", "亵"),
+    ("luan", "脔"),
+    ("pou", "裒"),
+    ("bing", "禀"),
+    ("ying", "嬴"),
+    ("luo", "蠃"),
+    ("lei", "羸"),
+    ("bing", "冫"),
+    ("hu", "冱"),
+    ("lie", "冽"),
+    ("xian", "冼"),
+    ("song", "凇"),
+    ("mi", "冖"),
+    ("zhong", "冢"),
+    ("ming", "冥"),
+    ("yan", "讠"),
+    ("jie", "讦"),
+    ("hong", "讧"),
+    ("shan", "讪"),
+    ("ou", "讴"),
+    ("ju", "讵"),
+    ("ne", "讷"),
+    ("gu", "诂"),
+    ("he", "诃"),
+    ("di", "诋"),
+    ("zhao", "诏"),
+    ("qu", "诎"),
+    ("yi", "诒"),
+    ("kuang", "诓"),
+    ("lei", "诔"),
+    ("gua", "诖"),
+    ("ji", "诘"),
+    ("hui", "诙"),
+    ("shen", "诜"),
+    ("gou", "诟"),
+    ("quan", "诠"),
+    ("zheng", "诤"),
+    ("hun", "诨"),
+    ("xu", "诩"),
+    ("qiao", "诮"),
+    ("gao", "诰"),
+    ("kuang", "诳"),
+    ("ei", "诶"),
+    ("zou", "诹"),
+    ("zhuo", "诼"),
+    ("wei", "诿"),
+    ("yu", "谀"),
+    ("shen", "谂"),
+    ("chan", "谄"),
+    ("sui", "谇"),
+    ("chen", "谌"),
+    ("jian", "谏"),
+    ("xue", "谑"),
+    ("ye", "谒"),
+    ("e", "谔"),
+    ("yu", "谕"),
+    ("xuan", "谖"),
+    ("an", "谙"),
+    ("di", "谛"),
+    ("zi", "谘"),
+    ("pian", "谝"),
+    ("mo", "谟"),
+    ("dang", "谠"),
+    ("su", "谡"),
+    ("shi", "谥"),
+    ("mi", "谧"),
+    ("zhe", "谪"),
+    ("jian", "谫"),
+    ("zen", "谮"),
+    ("qiao", "谯"),
+    ("jue", "谲"),
+    ("yan", "谳"),
+    ("zhan", "谵"),
+    ("chen", "谶"),
+    ("jie", "卩"),
+    ("jin", "卺"),
+    ("fu", "阝"),
+    ("wu", "阢"),
+    ("qian", "阡"),
+    ("jing", "阱"),
+    ("ban", "阪"),
+    ("dian", "阽"),
+    ("zuo", "阼"),
+    ("bei", "陂"),
+    ("xing", "陉"),
+    ("gai", "陔"),
+    ("zhi", "陟"),
+    ("nie", "陧"),
+    ("zou", "陬"),
+    ("chui", "陲"),
+    ("pi", "陴"),
+    ("wei", "隈"),
+    ("huang", "隍"),
+    ("kui", "隗"),
+    ("xi", "隰"),
+    ("han", "邗"),
+    ("qiong", "邛"),
+    ("kuang", "邝"),
+    ("mang", "邙"),
+    ("wu", "邬"),
+    ("fang", "邡"),
+    ("bing", "邴"),
+    ("pi", "邳"),
+    ("bei", "邶"),
+    ("ye", "邺"),
+    ("di", "邸"),
+    ("tai", "邰"),
+    ("jia", "郏"),
+    ("zhi", "郅"),
+    ("zhu", "邾"),
+    ("kuai", "郐"),
+    ("qie", "郄"),
+    ("huan", "郇"),
+    ("yun", "郓"),
+    ("li", "郦"),
+    ("ying", "郢"),
+    ("gao", "郜"),
+    ("xi", "郗"),
+    ("fu", "郛"),
+    ("pi", "郫"),
+    ("tan", "郯"),
+    ("yan", "郾"),
+    ("juan", "鄄"),
+    ("yan", "鄢"),
+    ("yin", "鄞"),
+    ("zhang", "鄣"),
+    ("po", "鄱"),
+    ("shan", "鄯"),
+    ("zou", "鄹"),
+    ("ling", "酃"),
+    ("feng", "酆"),
+    ("chu", "刍"),
+    ("huan", "奂"),
+    ("mai", "劢"),
+    ("qu", "劬"),
+    ("shao", "劭"),
+    ("he", "劾"),
+    ("ge", "哿"),
+    ("meng", "勐"),
+    ("xu", "勖"),
+    ("xie", "勰"),
+    ("sou", "叟"),
+    ("xie", "燮"),
+    ("jue", "矍"),
+    ("yin", "廴"),
+    ("qian", "凵"),
+    ("dang", "凼"),
+    ("chang", "鬯"),
+    ("si", "厶"),
+    ("bian", "弁"),
+    ("ben", "畚"),
+    ("qiu", "巯"),
+    ("ben", "坌"),
+    ("e", "垩"),
+    ("fa", "垡"),
+    ("shu", "塾"),
+    ("ji", "墼"),
+    ("yong", "壅"),
+    ("he", "壑"),
+    ("wei", "圩"),
+    ("wu", "圬"),
+    ("ge", "圪"),
+    ("zhen", "圳"),
+    ("kuang", "圹"),
+    ("pi", "圮"),
+    ("yi", "圯"),
+    ("li", "坜"),
+    ("qi", "圻"),
+    ("ban", "坂"),
+    ("gan", "坩"),
+    ("long", "垅"),
+    ("dian", "坫"),
+    ("lu", "垆"),
+    ("che", "坼"),
+    ("chi", "坻"),
+    ("tuo", "坨"),
+    ("ni", "坭"),
+    ("mu", "坶"),
+    ("ao", "坳"),
+    ("ya", "垭"),
+    ("die", "垤"),
+    ("dong", "垌"),
+    ("kai", "垲"),
+    ("shan", "埏"),
+    ("shang", "垧"),
+    ("nao", "垴"),
+    ("gai", "垓"),
+    ("yin", "垠"),
+    ("cheng", "埕"),
+    ("shi", "埘"),
+    ("guo", "埚"),
+    ("xun", "埙"),
+    ("lie", "埒"),
+    ("yuan", "垸"),
+    ("zhi", "埴"),
+    ("an", "埯"),
+    ("yi", "埸"),
+    ("pi", "埤"),
+    ("nian", "埝"),
+    ("peng", "堋"),
+    ("tu", "堍"),
+    ("sao", "埽"),
+    ("dai", "埭"),
+    ("ku", "堀"),
+    ("die", "堞"),
+    ("yin", "堙"),
+    ("leng", "塄"),
+    ("hou", "堠"),
+    ("ge", "塥"),
+    ("yuan", "塬"),
+    ("man", "墁"),
+    ("yong", "墉"),
+    ("liang", "墚"),
+    ("chi", "墀"),
+    ("xin", "馨"),
+    ("pi", "鼙"),
+    ("yi", "懿"),
+    ("cao", "艹"),
+    ("jiao", "艽"),
+    ("nai", "艿"),
+    ("du", "芏"),
+    ("qian", "芊"),
+    ("ji", "芨"),
+    ("wan", "芄"),
+    ("qiong", "芎"),
+    ("qi", "芑"),
+    ("xiang", "芗"),
+    ("fu", "芙"),
+    ("yan", "芫"),
+    ("yun", "芸"),
+    ("fei", "芾"),
+    ("ji", "芰"),
+    ("li", "苈"),
+    ("e", "苊"),
+    ("ju", "苣"),
+    ("pi", "芘"),
+    ("zhi", "芷"),
+    ("rui", "芮"),
+    ("xian", "苋"),
+    ("chang", "苌"),
+    ("cong", "苁"),
+    ("qin", "芩"),
+    ("wu", "芴"),
+    ("qian", "芡"),
+    ("qi", "芪"),
+    ("shan", "芟"),
+    ("bian", "苄"),
+    ("zhu", "苎"),
+    ("kou", "芤"),
+    ("yi", "苡"),
+    ("mo", "茉"),
+    ("gan", "苷"),
+    ("pie", "苤"),
+    ("long", "茏"),
+    ("ba", "茇"),
+    ("mu", "苜"),
+    ("ju", "苴"),
+    ("ran", "苒"),
+    ("qing", "苘"),
+    ("chi", "茌"),
+    ("fu", "苻"),
+    ("ling", "苓"),
+    ("niao", "茑"),
+    ("yin", "茚"),
+    ("mao", "茆"),
+    ("ying", "茔"),
+    ("qiong", "茕"),
+    ("min", "苠"),
+    ("shao", "苕"),
+    ("qian", "茜"),
+    ("ti", "荑"),
+    ("rao", "荛"),
+    ("bi", "荜"),
+    ("ci", "茈"),
+    ("ju", "莒"),
+    ("tong", "茼"),
+    ("hui", "茴"),
+    ("zhu", "茱"),
+    ("ting", "莛"),
+    ("qiao", "荞"),
+    ("fu", "茯"),
+    ("ren", "荏"),
+    ("xing", "荇"),
+    ("quan", "荃"),
+    ("hui", "荟"),
+    ("xun", "荀"),
+    ("ming", "茗"),
+    ("ji", "荠"),
+    ("jiao", "茭"),
+    ("chong", "茺"),
+    ("jiang", "茳"),
+    ("luo", "荦"),
+    ("xing", "荥"),
+    ("xun", "荨"),
+    ("gen", "茛"),
+    ("jin", "荩"),
+    ("mai", "荬"),
+    ("sun", "荪"),
+    ("hong", "荭"),
+    ("zhou", "荮"),
+    ("kan", "莰"),
+    ("bi", "荸"),
+    ("shi", "莳"),
+    ("wo", "莴"),
+    ("you", "莠"),
+    ("e", "莪"),
+    ("mei", "莓"),
+    ("you", "莜"),
+    ("li", "莅"),
+    ("tu", "荼"),
+    ("xian", "莶"),
+    ("fu", "莩"),
+    ("sui", "荽"),
+    ("you", "莸"),
+    ("di", "荻"),
+    ("shen", "莘"),
+    ("guan", "莞"),
+    ("lang", "莨"),
+    ("ying", "莺"),
+    ("chun", "莼"),
+    ("jing", "菁"),
+    ("qi", "萁"),
+    ("xi", "菥"),
+    ("song", "菘"),
+    ("jin", "堇"),
+    ("nai", "萘"),
+    ("qi", "萋"),
+    ("ba", "菝"),
+    ("shu", "菽"),
+    ("chang", "菖"),
+    ("tie", "萜"),
+    ("yu", "萸"),
+    ("huan", "萑"),
+    ("bi", "萆"),
+    ("fu", "菔"),
+    ("tu", "菟"),
+    ("dan", "萏"),
+    ("cui", "萃"),
+    ("yan", "菸"),
+    ("ju", "菹"),
+    ("dang", "菪"),
+    ("jian", "菅"),
+    ("wan", "菀"),
+    ("ying", "萦"),
+    ("gu", "菰"),
+    ("han", "菡"),
+    ("qia", "葜"),
+    ("feng", "葑"),
+    ("ren", "葚"),
+    ("xiang", "葙"),
+    ("wei", "葳"),
+    ("chan", "蒇"),
+    ("kai", "蒈"),
+    ("qi", "葺"),
+    ("kui", "蒉"),
+    ("xi", "葸"),
+    ("e", "萼"),
+    ("bao", "葆"),
+    ("pa", "葩"),
+    ("ting", "葶"),
+    ("lou", "蒌"),
+    ("pai", "蒎"),
+    ("xuan", "萱"),
+    ("jia", "葭"),
+    ("zhen", "蓁"),
+    ("shi", "蓍"),
+    ("ru", "蓐"),
+    ("mo", "蓦"),
+    ("en", "蒽"),
+    ("bei", "蓓"),
+    ("weng", "蓊"),
+    ("hao", "蒿"),
+    ("ji", "蒺"),
+    ("li", "蓠"),
+    ("bang", "蒡"),
+    ("jian", "蒹"),
+    ("shuo", "蒴"),
+    ("lang", "蒗"),
+    ("ying", "蓥"),
+    ("yu", "蓣"),
+    ("su", "蔌"),
+    ("meng", "甍"),
+    ("dou", "蔸"),
+    ("xi", "蓰"),
+    ("lian", "蔹"),
+    ("cu", "蔟"),
+    ("lin", "蔺"),
+    ("qu", "蕖"),
+    ("kou", "蔻"),
+    ("xu", "蓿"),
+    ("liao", "蓼"),
+    ("hui", "蕙"),
+    ("xun", "蕈"),
+    ("jue", "蕨"),
+    ("rui", "蕤"),
+    ("zui", "蕞"),
+    ("ji", "蕺"),
+    ("meng", "瞢"),
+    ("fan", "蕃"),
+    ("qi", "蕲"),
+    ("hong", "蕻"),
+    ("xie", "薤"),
+    ("hong", "薨"),
+    ("wei", "薇"),
+    ("yi", "薏"),
+    ("weng", "蕹"),
+    ("sou", "薮"),
+    ("bi", "薜"),
+    ("hao", "薅"),
+    ("tai", "薹"),
+    ("ru", "薷"),
+    ("xun", "薰"),
+    ("xian", "藓"),
+    ("gao", "藁"),
+    ("li", "藜"),
+    ("huo", "藿"),
+    ("qu", "蘧"),
+    ("heng", "蘅"),
+    ("fan", "蘩"),
+    ("nie", "蘖"),
+    ("mi", "蘼"),
+    ("gong", "廾"),
+    ("yi", "弈"),
+    ("kuang", "夼"),
+    ("lian", "奁"),
+    ("da", "耷"),
+    ("yi", "奕"),
+    ("xi", "奚"),
+    ("zang", "奘"),
+    ("pao", "匏"),
+    ("you", "尢"),
+    ("liao", "尥"),
+    ("ga", "尬"),
+    ("gan", "尴"),
+    ("shou", "扌"),
+    ("men", "扪"),
+    ("tuan", "抟"),
+    ("chen", "抻"),
+    ("fu", "拊"),
+    ("pan", "拚"),
+    ("ao", "拗"),
+    ("jie", "拮"),
+    ("jiao", "挢"),
+    ("za", "拶"),
+    ("yi", "挹"),
+    ("lv", "捋"),
+    ("jun", "捃"),
+    ("tian", "掭"),
+    ("ye", "揶"),
+    ("ai", "捱"),
+    ("na", "捺"),
+    ("ji", "掎"),
+    ("guai", "掴"),
+    ("bai", "捭"),
+    ("ju", "掬"),
+    ("pou", "掊"),
+    ("lie", "捩"),
+    ("qian", "掮"),
+    ("guan", "掼"),
+    ("die", "揲"),
+    ("zha", "揸"),
+    ("ya", "揠"),
+    ("qin", "揿"),
+    ("yu", "揄"),
+    ("an", "揞"),
+    ("xuan", "揎"),
+    ("bing", "摒"),
+    ("kui", "揆"),
+    ("yuan", "掾"),
+    ("shu", "摅"),
+    ("en", "摁"),
+    ("chuai", "搋"),
+    ("jian", "搛"),
+    ("shuo", "搠"),
+    ("zhan", "搌"),
+    ("nuo", "搦"),
+    ("sang", "搡"),
+    ("luo", "摞"),
+    ("ying", "撄"),
+    ("zhi", "摭"),
+    ("han", "撖"),
+    ("zhe", "摺"),
+    ("xie", "撷"),
+    ("lu", "撸"),
+    ("zun", "撙"),
+    ("cuan", "撺"),
+    ("gan", "擀"),
+    ("huan", "擐"),
+    ("pi", "擗"),
+    ("xing", "擤"),
+    ("zhuo", "擢"),
+    ("huo", "攉"),
+    ("zuan", "攥"),
+    ("nang", "攮"),
+    ("yi", "弋"),
+    ("te", "忒"),
+    ("dai", "甙"),
+    ("shi", "弑"),
+    ("bu", "卟"),
+    ("chi", "叱"),
+    ("ji", "叽"),
+    ("kou", "叩"),
+    ("dao", "叨"),
+    ("le", "叻"),
+    ("zha", "吒"),
+    ("ya", "吖"),
+    ("yao", "吆"),
+    ("fu", "呋"),
+    ("fu", "呒"),
+    ("yi", "呓"),
+    ("dai", "呔"),
+    ("li", "呖"),
+    ("e", "呃"),
+    ("bi", "吡"),
+    ("bei", "呗"),
+    ("guo", "呙"),
+    ("qin", "吣"),
+    ("yin", "吲"),
+    ("za", "咂"),
+    ("ka", "咔"),
+    ("ga", "呷"),
+    ("gu", "呱"),
+    ("ling", "呤"),
+    ("dong", "咚"),
+    ("ning", "咛"),
+    ("duo", "咄"),
+    ("nao", "呶"),
+    ("you", "呦"),
+    ("si", "咝"),
+    ("kuang", "哐"),
+    ("ji", "咭"),
+    ("shen", "哂"),
+    ("hui", "咴"),
+    ("da", "哒"),
+    ("lie", "咧"),
+    ("yi", "咦"),
+    ("xiao", "哓"),
+    ("bi", "哔"),
+    ("ci", "呲"),
+    ("guang", "咣"),
+    ("hui", "哕"),
+    ("xiu", "咻"),
+    ("yi", "咿"),
+    ("pai", "哌"),
+    ("kuai", "哙"),
+    ("duo", "哚"),
+    ("ji", "哜"),
+    ("mie", "咩"),
+    ("mi", "咪"),
+    ("zha", "咤"),
+    ("nong", "哝"),
+    ("gen", "哏"),
+    ("mou", "哞"),
+    ("ma", "唛"),
+    ("chi", "哧"),
+    ("lao", "唠"),
+    ("geng", "哽"),
+    ("wu", "唔"),
+    ("zha", "哳"),
+    ("suo", "唢"),
+    ("zao", "唣"),
+    ("xi", "唏"),
+    ("zuo", "唑"),
+    ("ji", "唧"),
+    ("feng", "唪"),
+    ("ze", "啧"),
+    ("nuo", "喏"),
+    ("miao", "喵"),
+    ("lin", "啉"),
+    ("zhuan", "啭"),
+    ("zhao", "啁"),
+    ("tao", "啕"),
+    ("hu", "唿"),
+    ("cui", "啐"),
+    ("sha", "唼"),
+    ("yo", "唷"),
+    ("dan", "啖"),
+    ("bo", "啵"),
+    ("ding", "啶"),
+    ("lang", "啷"),
+    ("li", "唳"),
+    ("shua", "唰"),
+    ("chuai", "啜"),
+    ("die", "喋"),
+    ("da", "嗒"),
+    ("nan", "喃"),
+    ("li", "喱"),
+    ("kui", "喹"),
+    ("jie", "喈"),
+    ("yong", "喁"),
+    ("kui", "喟"),
+    ("jiu", "啾"),
+    ("sou", "嗖"),
+    ("yin", "喑"),
+    ("chi", "啻"),
+    ("jie", "嗟"),
+    ("lou", "喽"),
+    ("ku", "喾"),
+    ("o", "喔"),
+    ("hui", "喙"),
+    ("qin", "嗪"),
+    ("ao", "嗷"),
+    ("su", "嗉"),
+    ("du", "嘟"),
+    ("ke", "嗑"),
+    ("nie", "嗫"),
+    ("he", "嗬"),
+    ("chen", "嗔"),
+    ("suo", "嗦"),
+    ("ge", "嗝"),
+    ("a", "嗄"),
+    ("n", "嗯"),
+    ("hao", "嗥"),
+    ("die", "嗲"),
+    ("ai", "嗳"),
+    ("ai", "嗌"),
+    ("suo", "嗍"),
+    ("hai", "嗨"),
+    ("tong", "嗵"),
+    ("chi", "嗤"),
+    ("pei", "辔"),
+    ("lei", "嘞"),
+    ("cao", "嘈"),
+    ("piao", "嘌"),
+    ("qi", "嘁"),
+    ("ying", "嘤"),
+    ("beng", "嘣"),
+    ("sou", "嗾"),
+    ("di", "嘀"),
+    ("mi", "嘧"),
+    ("peng", "嘭"),
+    ("jue", "噘"),
+    ("liao", "嘹"),
+    ("pu", "噗"),
+    ("chuai", "嘬"),
+    ("jiao", "噍"),
+    ("o", "噢"),
+    ("qin", "噙"),
+    ("lu", "噜"),
+    ("ceng", "噌"),
+    ("deng", "噔"),
+    ("hao", "嚆"),
+    ("jin", "噤"),
+    ("jue", "噱"),
+    ("yi", "噫"),
+    ("sai", "噻"),
+    ("pi", "噼"),
+    ("ru", "嚅"),
+    ("ca", "嚓"),
+    ("huo", "嚯"),
+    ("nang", "囔"),
+    ("wei", "囗"),
+    ("jian", "囝"),
+    ("nan", "囡"),
+    ("lun", "囵"),
+    ("hu", "囫"),
+    ("ling", "囹"),
+    ("you", "囿"),
+    ("yu", "圄"),
+    ("qing", "圊"),
+    ("yu", "圉"),
+    ("huan", "圜"),
+    ("wei", "帏"),
+    ("zhi", "帙"),
+    ("pei", "帔"),
+    ("tang", "帑"),
+    ("chou", "帱"),
+    ("ze", "帻"),
+    ("guo", "帼"),
+    ("wei", "帷"),
+    ("wo", "幄"),
+    ("man", "幔"),
+    ("zhang", "幛"),
+    ("fu", "幞"),
+    ("fan", "幡"),
+    ("ji", "岌"),
+    ("qi", "屺"),
+    ("qian", "岍"),
+    ("qi", "岐"),
+    ("qu", "岖"),
+    ("ya", "岈"),
+    ("xian", "岘"),
+    ("ao", "岙"),
+    ("cen", "岑"),
+    ("lan", "岚"),
+    ("ba", "岜"),
+    ("hu", "岵"),
+    ("ke", "岢"),
+    ("dong", "岽"),
+    ("jia", "岬"),
+    ("xiu", "岫"),
+    ("dai", "岱"),
+    ("gou", "岣"),
+    ("mao", "峁"),
+    ("min", "岷"),
+    ("yi", "峄"),
+    ("dong", "峒"),
+    ("jiao", "峤"),
+    ("xun", "峋"),
+    ("zheng", "峥"),
+    ("lao", "崂"),
+    ("lai", "崃"),
+    ("song", "崧"),
+    ("yan", "崦"),
+    ("gu", "崮"),
+    ("xiao", "崤"),
+    ("guo", "崞"),
+    ("kong", "崆"),
+    ("jue", "崛"),
+    ("rong", "嵘"),
+    ("yao", "崾"),
+    ("wai", "崴"),
+    ("zai", "崽"),
+    ("wei", "嵬"),
+    ("yu", "嵛"),
+    ("cuo", "嵯"),
+    ("lou", "嵝"),
+    ("zi", "嵫"),
+    ("mei", "嵋"),
+    ("sheng", "嵊"),
+    ("song", "嵩"),
+    ("ji", "嵴"),
+    ("zhang", "嶂"),
+    ("lin", "嶙"),
+    ("deng", "嶝"),
+    ("bin", "豳"),
+    ("yi", "嶷"),
+    ("dian", "巅"),
+    ("chi", "彳"),
+    ("fang", "彷"),
+    ("cu", "徂"),
+    ("xun", "徇"),
+    ("yang", "徉"),
+    ("hou", "後"),
+    ("lai", "徕"),
+    ("xi", "徙"),
+    ("chang", "徜"),
+    ("huang", "徨"),
+    ("yao", "徭"),
+    ("zhi", "徵"),
+    ("jiao", "徼"),
+    ("qu", "衢"),
+    ("shan", "彡"),
+    ("quan", "犭"),
+    ("qiu", "犰"),
+    ("an", "犴"),
+    ("guang", "犷"),
+    ("ma", "犸"),
+    ("niu", "狃"),
+    ("yun", "狁"),
+    ("xia", "狎"),
+    ("pao", "狍"),
+    ("fei", "狒"),
+    ("rong", "狨"),
+    ("kuai", "狯"),
+    ("shou", "狩"),
+    ("sun", "狲"),
+    ("bi", "狴"),
+    ("juan", "狷"),
+    ("li", "猁"),
+    ("yu", "狳"),
+    ("xian", "猃"),
+    ("yin", "狺"),
+    ("suan", "狻"),
+    ("yi", "猗"),
+    ("guo", "猓"),
+    ("luo", "猡"),
+    ("ni", "猊"),
+    ("she", "猞"),
+    ("cu", "猝"),
+    ("mi", "猕"),
+    ("hu", "猢"),
+    ("cha", "猹"),
+    ("wei", "猥"),
+    ("wei", "猬"),
+    ("mei", "猸"),
+    ("nao", "猱"),
+    ("zhang", "獐"),
+    ("jing", "獍"),
+    ("jue", "獗"),
+    ("liao", "獠"),
+    ("xie", "獬"),
+    ("xun", "獯"),
+    ("huan", "獾"),
+    ("chuan", "舛"),
+    ("huo", "夥"),
+    ("sun", "飧"),
+    ("yin", "夤"),
+    ("zhi", "夂"),
+    ("shi", "饣"),
+    ("tang", "饧"),
+    ("tun", "饨"),
+    ("xi", "饩"),
+    ("ren", "饪"),
+    ("yu", "饫"),
+    ("chi", "饬"),
+    ("yi", "饴"),
+    ("xiang", "饷"),
+    ("bo", "饽"),
+    ("yu", "馀"),
+    ("hun", "馄"),
+    ("cha", "馇"),
+    ("sou", "馊"),
+    ("mo", "馍"),
+    ("xiu", "馐"),
+    ("jin", "馑"),
+    ("san", "馓"),
+    ("zhuan", "馔"),
+    ("nang", "馕"),
+    ("pi", "庀"),
+    ("wu", "庑"),
+    ("gui", "庋"),
+    ("pao", "庖"),
+    ("xiu", "庥"),
+    ("xiang", "庠"),
+    ("tuo", "庹"),
+    ("an", "庵"),
+    ("yu", "庾"),
+    ("bi", "庳"),
+    ("geng", "赓"),
+    ("ao", "廒"),
+    ("jin", "廑"),
+    ("chan", "廛"),
+    ("xie", "廨"),
+    ("lin", "廪"),
+    ("ying", "膺"),
+    ("xin", "忄"),
+    ("dao", "忉"),
+    ("cun", "忖"),
+    ("chan", "忏"),
+    ("wu", "怃"),
+    ("zhi", "忮"),
+    ("ou", "怄"),
+    ("chong", "忡"),
+    ("wu", "忤"),
+    ("kai", "忾"),
+    ("chang", "怅"),
+    ("chuang", "怆"),
+    ("song", "忪"),
+    ("bian", "忭"),
+    ("niu", "忸"),
+    ("hu", "怙"),
+    ("chu", "怵"),
+    ("peng", "怦"),
+    ("da", "怛"),
+    ("yang", "怏"),
+    ("zuo", "怍"),
+    ("ni", "怩"),
+    ("fu", "怫"),
+    ("chao", "怊"),
+    ("yi", "怿"),
+    ("yi", "怡"),
+    ("tong", "恸"),
+    ("yan", "恹"),
+    ("ce", "恻"),
+    ("kai", "恺"),
+    ("xun", "恂"),
+    ("ke", "恪"),
+    ("yun", "恽"),
+    ("bei", "悖"),
+    ("song", "悚"),
+    ("qian", "悭"),
+    ("kui", "悝"),
+    ("kun", "悃"),
+    ("yi", "悒"),
+    ("ti", "悌"),
+    ("quan", "悛"),
+    ("qie", "惬"),
+    ("xing", "悻"),
+    ("fei", "悱"),
+    ("chang", "惝"),
+    ("wang", "惘"),
+    ("chou", "惆"),
+    ("hu", "惚"),
+    ("cui", "悴"),
+    ("yun", "愠"),
+    ("kui", "愦"),
+    ("e", "愕"),
+    ("leng", "愣"),
+    ("zhui", "惴"),
+    ("qiao", "愀"),
+    ("bi", "愎"),
+    ("su", "愫"),
+    ("qian", "慊"),
+    ("yong", "慵"),
+    ("jing", "憬"),
+    ("qiao", "憔"),
+    ("chong", "憧"),
+    ("chu", "憷"),
+    ("lin", "懔"),
+    ("meng", "懵"),
+    ("tian", "忝"),
+    ("hui", "隳"),
+    ("shuan", "闩"),
+    ("yan", "闫"),
+    ("wei", "闱"),
+    ("hong", "闳"),
+    ("min", "闵"),
+    ("kang", "闶"),
+    ("ta", "闼"),
+    ("lv", "闾"),
+    ("kun", "阃"),
+    ("jiu", "阄"),
+    ("lang", "阆"),
+    ("yu", "阈"),
+    ("chang", "阊"),
+    ("xi", "阋"),
+    ("wen", "阌"),
+    ("hun", "阍"),
+    ("e", "阏"),
+    ("qu", "阒"),
+    ("que", "阕"),
+    ("he", "阖"),
+    ("tian", "阗"),
+    ("que", "阙"),
+    ("han", "阚"),
+    ("qiang", "丬"),
+    ("pan", "爿"),
+    ("qiang", "戕"),
+    ("shui", "氵"),
+    ("qi", "汔"),
+    ("si", "汜"),
+    ("cha", "汊"),
+    ("feng", "沣"),
+    ("yuan", "沅"),
+    ("mu", "沐"),
+    ("mian", "沔"),
+    ("dun", "沌"),
+    ("mi", "汨"),
+    ("gu", "汩"),
+    ("bian", "汴"),
+    ("wen", "汶"),
+    ("hang", "沆"),
+    ("wei", "沩"),
+    ("le", "泐"),
+    ("gan", "泔"),
+    ("shu", "沭"),
+    ("long", "泷"),
+    ("lu", "泸"),
+    ("yang", "泱"),
+    ("si", "泗"),
+    ("tuo", "沲"),
+    ("ling", "泠"),
+    ("mao", "泖"),
+    ("luo", "泺"),
+    ("xuan", "泫"),
+    ("pan", "泮"),
+    ("tuo", "沱"),
+    ("hong", "泓"),
+    ("min", "泯"),
+    ("jing", "泾"),
+    ("huan", "洹"),
+    ("wei", "洧"),
+    ("lie", "洌"),
+    ("jia", "浃"),
+    ("zhen", "浈"),
+    ("yin", "洇"),
+    ("hui", "洄"),
+    ("zhu", "洙"),
+    ("ji", "洎"),
+    ("xu", "洫"),
+    ("hui", "浍"),
+    ("tao", "洮"),
+    ("xun", "洵"),
+    ("jiang", "洚"),
+    ("liu", "浏"),
+    ("hu", "浒"),
+    ("xun", "浔"),
+    ("ru", "洳"),
+    ("su", "涑"),
+    ("wu", "浯"),
+    ("lai", "涞"),
+    ("wei", "涠"),
+    ("zhuo", "浞"),
+    ("juan", "涓"),
+    ("cen", "涔"),
+    ("bang", "浜"),
+    ("xi", "浠"),
+    ("mei", "浼"),
+    ("huan", "浣"),
+    ("zhu", "渚"),
+    ("qi", "淇"),
+    ("xi", "淅"),
+    ("song", "淞"),
+    ("du", "渎"),
+    ("zhuo", "涿"),
+    ("pi", "淠"),
+    ("mian", "渑"),
+    ("gan", "淦"),
+    ("fei", "淝"),
+    ("cong", "淙"),
+    ("shen", "渖"),
+    ("guan", "涫"),
+    ("lu", "渌"),
+    ("shuan", "涮"),
+    ("xie", "渫"),
+    ("yan", "湮"),
+    ("mian", "湎"),
+    ("jiao", "湫"),
+    ("sou", "溲"),
+    ("huang", "湟"),
+    ("xu", "溆"),
+    ("pen", "湓"),
+    ("jian", "湔"),
+    ("xuan", "渲"),
+    ("wo", "渥"),
+    ("mei", "湄"),
+    ("yan", "滟"),
+    ("qin", "溱"),
+    ("ke", "溘"),
+    ("she", "滠"),
+    ("mang", "漭"),
+    ("ying", "滢"),
+    ("pu", "溥"),
+    ("li", "溧"),
+    ("ru", "溽"),
+    ("ta", "溻"),
+    ("hun", "溷"),
+    ("bi", "滗"),
+    ("xiu", "溴"),
+    ("fu", "滏"),
+    ("tang", "溏"),
+    ("pang", "滂"),
+    ("ming", "溟"),
+    ("huang", "潢"),
+    ("ying", "潆"),
+    ("xiao", "潇"),
+    ("lan", "漤"),
+    ("cao", "漕"),
+    ("hu", "滹"),
+    ("luo", "漯"),
+    ("huan", "漶"),
+    ("lian", "潋"),
+    ("zhu", "潴"),
+    ("yi", "漪"),
+    ("lu", "漉"),
+    ("xuan", "漩"),
+    ("gan", "澉"),
+    ("shu", "澍"),
+    ("si", "澌"),
+    ("shan", "潸"),
+    ("shao", "潲"),
+    ("tong", "潼"),
+    ("chan", "潺"),
+    ("lai", "濑"),
+    ("sui", "濉"),
+    ("li", "澧"),
+    ("dan", "澹"),
+    ("chan", "澶"),
+    ("lian", "濂"),
+    ("ru", "濡"),
+    ("pu", "濮"),
+    ("bi", "濞"),
+    ("hao", "濠"),
+    ("zhuo", "濯"),
+    ("han", "瀚"),
+    ("xie", "瀣"),
+    ("ying", "瀛"),
+    ("yue", "瀹"),
+    ("fen", "瀵"),
+    ("hao", "灏"),
+    ("ba", "灞"),
+    ("mian", "宀"),
+    ("gui", "宄"),
+    ("dang", "宕"),
+    ("mi", "宓"),
+    ("you", "宥"),
+    ("chen", "宸"),
+    ("ning", "甯"),
+    ("qian", "骞"),
+    ("qian", "搴"),
+    ("wu", "寤"),
+    ("liao", "寮"),
+    ("qian", "褰"),
+    ("huan", "寰"),
+    ("jian", "蹇"),
+    ("jian", "謇"),
+    ("chuo", "辶"),
+    ("ya", "迓"),
+    ("wu", "迕"),
+    ("jiong", "迥"),
+    ("ze", "迮"),
+    ("yi", "迤"),
+    ("er", "迩"),
+    ("jia", "迦"),
+    ("jing", "迳"),
+    ("dai", "迨"),
+    ("hou", "逅"),
+    ("pang", "逄"),
+    ("bu", "逋"),
+    ("li", "逦"),
+    ("qiu", "逑"),
+    ("xiao", "逍"),
+    ("ti", "逖"),
+    ("qun", "逡"),
+    ("kui", "逵"),
+    ("wei", "逶"),
+    ("huan", "逭"),
+    ("lu", "逯"),
+    ("chuan", "遄"),
+    ("huang", "遑"),
+    ("qiu", "遒"),
+    ("xia", "遐"),
+    ("ao", "遨"),
+    ("gou", "遘"),
+    ("ta", "遢"),
+    ("liu", "遛"),
+    ("xian", "暹"),
+    ("lin", "遴"),
+    ("ju", "遽"),
+    ("xie", "邂"),
+    ("miao", "邈"),
+    ("sui", "邃"),
+    ("la", "邋"),
+    ("ji", "彐"),
+    ("hui", "彗"),
+    ("tuan", "彖"),
+    ("zhi", "彘"),
+    ("kao", "尻"),
+    ("zhi", "咫"),
+    ("ji", "屐"),
+    ("e", "屙"),
+    ("can", "孱"),
+    ("xi", "屣"),
+    ("ju", "屦"),
+    ("chan", "羼"),
+    ("jing", "弪"),
+    ("nu", "弩"),
+    ("mi", "弭"),
+    ("fu", "艴"),
+    ("bi", "弼"),
+    ("yu", "鬻"),
+    ("che", "屮"),
+    ("shuo", "妁"),
+    ("fei", "妃"),
+    ("yan", "妍"),
+    ("wu", "妩"),
+    ("yu", "妪"),
+    ("bi", "妣"),
+    ("jin", "妗"),
+    ("zi", "姊"),
+    ("gui", "妫"),
+    ("niu", "妞"),
+    ("yu", "妤"),
+    ("si", "姒"),
+    ("da", "妲"),
+    ("zhou", "妯"),
+    ("shan", "姗"),
+    ("qie", "妾"),
+    ("ya", "娅"),
+    ("rao", "娆"),
+    ("shu", "姝"),
+    ("luan", "娈"),
+    ("jiao", "姣"),
+    ("pin", "姘"),
+    ("cha", "姹"),
+    ("li", "娌"),
+    ("ping", "娉"),
+    ("wa", "娲"),
+    ("xian", "娴"),
+    ("suo", "娑"),
+    ("di", "娣"),
+    ("wei", "娓"),
+    ("e", "婀"),
+    ("jing", "婧"),
+    ("biao", "婊"),
+    ("jie", "婕"),
+    ("chang", "娼"),
+    ("bi", "婢"),
+    ("chan", "婵"),
+    ("nu", "胬"),
+    ("ao", "媪"),
+    ("yuan", "媛"),
+    ("ting", "婷"),
+    ("wu", "婺"),
+    ("gou", "媾"),
+    ("mo", "嫫"),
+    ("pi", "媲"),
+    ("ai", "嫒"),
+    ("pin", "嫔"),
+    ("chi", "媸"),
+    ("li", "嫠"),
+    ("yan", "嫣"),
+    ("qiang", "嫱"),
+    ("piao", "嫖"),
+    ("chang", "嫦"),
+    ("lei", "嫘"),
+    ("zhang", "嫜"),
+    ("xi", "嬉"),
+    ("shan", "嬗"),
+    ("bi", "嬖"),
+    ("niao", "嬲"),
+    ("ma", "嬷"),
+    ("shuang", "孀"),
+    ("ga", "尕"),
+    ("ga", "尜"),
+    ("fu", "孚"),
+    ("nu", "孥"),
+    ("zi", "孳"),
+    ("jie", "孑"),
+    ("jue", "孓"),
+    ("bao", "孢"),
+    ("zang", "驵"),
+    ("si", "驷"),
+    ("fu", "驸"),
+    ("zou", "驺"),
+    ("yi", "驿"),
+    ("nu", "驽"),
+    ("dai", "骀"),
+    ("xiao", "骁"),
+    ("hua", "骅"),
+    ("pian", "骈"),
+    ("li", "骊"),
+    ("qi", "骐"),
+    ("ke", "骒"),
+    ("zhui", "骓"),
+    ("can", "骖"),
+    ("zhi", "骘"),
+    ("wu", "骛"),
+    ("ao", "骜"),
+    ("liu", "骝"),
+    ("shan", "骟"),
+    ("biao", "骠"),
+    ("cong", "骢"),
+    ("chan", "骣"),
+    ("ji", "骥"),
+    ("xiang", "骧"),
+    ("si", "纟"),
+    ("yu", "纡"),
+    ("zhou", "纣"),
+    ("ge", "纥"),
+    ("wan", "纨"),
+    ("kuang", "纩"),
+    ("yun", "纭"),
+    ("pi", "纰"),
+    ("shu", "纾"),
+    ("gan", "绀"),
+    ("xie", "绁"),
+    ("fu", "绂"),
+    ("zhou", "绉"),
+    ("fu", "绋"),
+    ("chu", "绌"),
+    ("dai", "绐"),
+    ("ku", "绔"),
+    ("hang", "绗"),
+    ("jiang", "绛"),
+    ("geng", "绠"),
+    ("xiao", "绡"),
+    ("ti", "绨"),
+    ("ling", "绫"),
+    ("qi", "绮"),
+    ("fei", "绯"),
+    ("shang", "绱"),
+    ("gun", "绲"),
+    ("duo", "缍"),
+    ("shou", "绶"),
+    ("liu", "绺"),
+    ("quan", "绻"),
+    ("wan", "绾"),
+    ("zi", "缁"),
+    ("ke", "缂"),
+    ("xiang", "缃"),
+    ("ti", "缇"),
+    ("miao", "缈"),
+    ("hui", "缋"),
+    ("si", "缌"),
+    ("bian", "缏"),
+    ("gou", "缑"),
+    ("zhui", "缒"),
+    ("min", "缗"),
+    ("jin", "缙"),
+    ("zhen", "缜"),
+    ("ru", "缛"),
+    ("gao", "缟"),
+    ("li", "缡"),
+    ("yi", "缢"),
+    ("jian", "缣"),
+    ("bin", "缤"),
+    ("piao", "缥"),
+    ("man", "缦"),
+    ("lei", "缧"),
+    ("mou", "缪"),
+    ("sao", "缫"),
+    ("xie", "缬"),
+    ("liao", "缭"),
+    ("zeng", "缯"),
+    ("jiang", "缰"),
+    ("qian", "缱"),
+    ("qiao", "缲"),
+    ("huan", "缳"),
+    ("zuan", "缵"),
+    ("yao", "幺"),
+    ("ji", "畿"),
+    ("chuan", "巛"),
+    ("zai", "甾"),
+    ("yong", "邕"),
+    ("ding", "玎"),
+    ("ji", "玑"),
+    ("wei", "玮"),
+    ("bin", "玢"),
+    ("wen", "玟"),
+    ("jue", "珏"),
+    ("ke", "珂"),
+    ("long", "珑"),
+    ("dian", "玷"),
+    ("dai", "玳"),
+    ("po", "珀"),
+    ("min", "珉"),
+    ("jia", "珈"),
+    ("er", "珥"),
+    ("gong", "珙"),
+    ("xu", "顼"),
+    ("ya", "琊"),
+    ("hang", "珩"),
+    ("yao", "珧"),
+    ("luo", "珞"),
+    ("xi", "玺"),
+    ("hui", "珲"),
+    ("lian", "琏"),
+    ("qi", "琪"),
+    ("ying", "瑛"),
+    ("qi", "琦"),
+    ("hu", "琥"),
+    ("kun", "琨"),
+    ("yan", "琰"),
+    ("cong", "琮"),
+    ("wan", "琬"),
+    ("chen", "琛"),
+    ("ju", "琚"),
+    ("mao", "瑁"),
+    ("yu", "瑜"),
+    ("yuan", "瑗"),
+    ("xia", "瑕"),
+    ("nao", "瑙"),
+    ("ai", "瑷"),
+    ("tang", "瑭"),
+    ("jin", "瑾"),
+    ("huang", "璜"),
+    ("ying", "璎"),
+    ("cui", "璀"),
+    ("cong", "璁"),
+    ("xuan", "璇"),
+    ("zhang", "璋"),
+    ("pu", "璞"),
+    ("can", "璨"),
+    ("qu", "璩"),
+    ("lu", "璐"),
+    ("bi", "璧"),
+    ("zan", "瓒"),
+    ("wen", "璺"),
+    ("wei", "韪"),
+    ("yun", "韫"),
+    ("tao", "韬"),
+    ("wu", "杌"),
+    ("biao", "杓"),
+    ("qi", "杞"),
+    ("cha", "杈"),
+    ("ma", "杩"),
+    ("li", "枥"),
+    ("pi", "枇"),
+    ("miao", "杪"),
+    ("yao", "杳"),
+    ("rui", "枘"),
+    ("jian", "枧"),
+    ("chu", "杵"),
+    ("cheng", "枨"),
+    ("cong", "枞"),
+    ("xiao", "枭"),
+    ("fang", "枋"),
+    ("pa", "杷"),
+    ("zhu", "杼"),
+    ("nai", "柰"),
+    ("zhi", "栉"),
+    ("zhe", "柘"),
+    ("long", "栊"),
+    ("jiu", "柩"),
+    ("ping", "枰"),
+    ("lu", "栌"),
+    ("xia", "柙"),
+    ("xiao", "枵"),
+    ("you", "柚"),
+    ("zhi", "枳"),
+    ("tuo", "柝"),
+    ("zhi", "栀"),
+    ("ling", "柃"),
+    ("gou", "枸"),
+    ("di", "柢"),
+    ("li", "栎"),
+    ("duo", "柁"),
+    ("cheng", "柽"),
+    ("kao", "栲"),
+    ("lao", "栳"),
+    ("ya", "桠"),
+    ("rao", "桡"),
+    ("zhi", "桎"),
+    ("zhen", "桢"),
+    ("guang", "桄"),
+    ("qi", "桤"),
+    ("ting", "梃"),
+    ("gua", "栝"),
+    ("jiu", "桕"),
+    ("hua", "桦"),
+    ("heng", "桁"),
+    ("gui", "桧"),
+    ("jie", "桀"),
+    ("luan", "栾"),
+    ("juan", "桊"),
+    ("an", "桉"),
+    ("xu", "栩"),
+    ("fan", "梵"),
+    ("gu", "梏"),
+    ("fu", "桴"),
+    ("jue", "桷"),
+    ("zi", "梓"),
+    ("suo", "桫"),
+    ("ling", "棂"),
+    ("chu", "楮"),
+    ("fen", "棼"),
+    ("du", "椟"),
+    ("qian", "椠"),
+    ("zhao", "棹"),
+    ("luo", "椤"),
+    ("chui", "棰"),
+    ("liang", "椋"),
+    ("guo", "椁"),
+    ("jian", "楗"),
+    ("di", "棣"),
+    ("ju", "椐"),
+    ("zou", "楱"),
+    ("shen", "椹"),
+    ("nan", "楠"),
+    ("zha", "楂"),
+    ("lian", "楝"),
+    ("lan", "榄"),
+    ("ji", "楫"),
+    ("pin", "榀"),
+    ("ju", "榘"),
+    ("qiu", "楸"),
+    ("duan", "椴"),
+    ("chui", "槌"),
+    ("chen", "榇"),
+    ("lv", "榈"),
+    ("cha", "槎"),
+    ("ju", "榉"),
+    ("xuan", "楦"),
+    ("mei", "楣"),
+    ("ying", "楹"),
+    ("zhen", "榛"),
+    ("fei", "榧"),
+    ("ta", "榻"),
+    ("sun", "榫"),
+    ("xie", "榭"),
+    ("gao", "槔"),
+    ("cui", "榱"),
+    ("gao", "槁"),
+    ("shuo", "槊"),
+    ("bin", "槟"),
+    ("rong", "榕"),
+    ("zhu", "槠"),
+    ("xie", "榍"),
+    ("jin", "槿"),
+    ("qiang", "樯"),
+    ("qi", "槭"),
+    ("chu", "樗"),
+    ("tang", "樘"),
+    ("zhu", "橥"),
+    ("hu", "槲"),
+    ("gan", "橄"),
+    ("yue", "樾"),
+    ("qing", "檠"),
+    ("tuo", "橐"),
+    ("jue", "橛"),
+    ("qiao", "樵"),
+    ("qin", "檎"),
+    ("lu", "橹"),
+    ("zun", "樽"),
+    ("xi", "樨"),
+    ("ju", "橘"),
+    ("yuan", "橼"),
+    ("lei", "檑"),
+    ("yan", "檐"),
+    ("lin", "檩"),
+    ("bo", "檗"),
+    ("cha", "檫"),
+    ("you", "猷"),
+    ("ao", "獒"),
+    ("mo", "殁"),
+    ("cu", "殂"),
+    ("shang", "殇"),
+    ("tian", "殄"),
+    ("yun", "殒"),
+    ("lian", "殓"),
+    ("piao", "殍"),
+    ("dan", "殚"),
+    ("ji", "殛"),
+    ("bin", "殡"),
+    ("yi", "殪"),
+    ("ren", "轫"),
+    ("e", "轭"),
+    ("gu", "轱"),
+    ("ke", "轲"),
+    ("lu", "轳"),
+    ("zhi", "轵"),
+    ("yi", "轶"),
+    ("zhen", "轸"),
+    ("hu", "轷"),
+    ("li", "轹"),
+    ("yao", "轺"),
+    ("shi", "轼"),
+    ("zhi", "轾"),
+    ("quan", "辁"),
+    ("lu", "辂"),
+    ("zhe", "辄"),
+    ("nian", "辇"),
+    ("wang", "辋"),
+    ("chuo", "辍"),
+    ("zi", "辎"),
+    ("cou", "辏"),
+    ("lu", "辘"),
+    ("lin", "辚"),
+    ("wei", "軎"),
+    ("jian", "戋"),
+    ("qiang", "戗"),
+    ("jia", "戛"),
+    ("ji", "戟"),
+    ("ji", "戢"),
+    ("kan", "戡"),
+    ("deng", "戥"),
+    ("gai", "戤"),
+    ("jian", "戬"),
+    ("zang", "臧"),
+    ("ou", "瓯"),
+    ("ling", "瓴"),
+    ("bu", "瓿"),
+    ("beng", "甏"),
+    ("zeng", "甑"),
+    ("pi", "甓"),
+    ("pu", "攴"),
+    ("ga", "旮"),
+    ("la", "旯"),
+    ("gan", "旰"),
+    ("hao", "昊"),
+    ("tan", "昙"),
+    ("gao", "杲"),
+    ("ze", "昃"),
+    ("xin", "昕"),
+    ("yun", "昀"),
+    ("jiong", "炅"),
+    ("he", "曷"),
+    ("zan", "昝"),
+    ("mao", "昴"),
+    ("yu", "昱"),
+    ("chang", "昶"),
+    ("ni", "昵"),
+    ("qi", "耆"),
+    ("cheng", "晟"),
+    ("ye", "晔"),
+    ("chao", "晁"),
+    ("yan", "晏"),
+    ("hui", "晖"),
+    ("bu", "晡"),
+    ("han", "晗"),
+    ("gui", "晷"),
+    ("xuan", "暄"),
+    ("kui", "暌"),
+    ("ai", "暧"),
+    ("ming", "暝"),
+    ("tun", "暾"),
+    ("xun", "曛"),
+    ("yao", "曜"),
+    ("xi", "曦"),
+    ("nang", "曩"),
+    ("ben", "贲"),
+    ("shi", "贳"),
+    ("kuang", "贶"),
+    ("yi", "贻"),
+    ("zhi", "贽"),
+    ("zi", "赀"),
+    ("gai", "赅"),
+    ("jin", "赆"),
+    ("zhen", "赈"),
+    ("lai", "赉"),
+    ("qiu", "赇"),
+    ("ji", "赍"),
+    ("dan", "赕"),
+    ("fu", "赙"),
+    ("chan", "觇"),
+    ("ji", "觊"),
+    ("xi", "觋"),
+    ("di", "觌"),
+    ("yu", "觎"),
+    ("gou", "觏"),
+    ("jin", "觐"),
+    ("qu", "觑"),
+    ("jian", "牮"),
+    ("jiang", "犟"),
+    ("pin", "牝"),
+    ("mao", "牦"),
+    ("gu", "牯"),
+    ("wu", "牾"),
+    ("gu", "牿"),
+    ("ji", "犄"),
+    ("ju", "犋"),
+    ("jian", "犍"),
+    ("pian", "犏"),
+    ("kao", "犒"),
+    ("qie", "挈"),
+    ("sa", "挲"),
+    ("bai", "掰"),
+    ("ge", "搿"),
+    ("bai", "擘"),
+    ("mao", "耄"),
+    ("mu", "毪"),
+    ("cui", "毳"),
+    ("jian", "毽"),
+    ("san", "毵"),
+    ("shu", "毹"),
+    ("chang", "氅"),
+    ("lu", "氇"),
+    ("pu", "氆"),
+    ("qu", "氍"),
+    ("pie", "氕"),
+    ("dao", "氘"),
+    ("xian", "氙"),
+    ("chuan", "氚"),
+    ("dong", "氡"),
+    ("ya", "氩"),
+    ("yin", "氤"),
+    ("ke", "氪"),
+    ("yun", "氲"),
+    ("pu", "攵"),
+    ("chi", "敕"),
+    ("jiao", "敫"),
+    ("du", "牍"),
+    ("die", "牒"),
+    ("you", "牖"),
+    ("yuan", "爰"),
+    ("guo", "虢"),
+    ("yue", "刖"),
+    ("wo", "肟"),
+    ("rong", "肜"),
+    ("huang", "肓"),
+    ("jing", "肼"),
+    ("ruan", "朊"),
+    ("tai", "肽"),
+    ("gong", "肱"),
+    ("zhun", "肫"),
+    ("na", "肭"),
+    ("yao", "肴"),
+    ("qian", "肷"),
+    ("long", "胧"),
+    ("dong", "胨"),
+    ("ka", "胩"),
+    ("lu", "胪"),
+    ("jia", "胛"),
+    ("shen", "胂"),
+    ("zhou", "胄"),
+    ("zuo", "胙"),
+    ("gua", "胍"),
+    ("zhen", "胗"),
+    ("qu", "朐"),
+    ("zhi", "胝"),
+    ("jing", "胫"),
+    ("guang", "胱"),
+    ("dong", "胴"),
+    ("yan", "胭"),
+    ("kuai", "脍"),
+    ("sa", "脎"),
+    ("hai", "胲"),
+    ("pian", "胼"),
+    ("zhen", "朕"),
+    ("mi", "脒"),
+    ("tun", "豚"),
+    ("luo", "脶"),
+    ("cuo", "脞"),
+    ("pao", "脬"),
+    ("wan", "脘"),
+    ("niao", "脲"),
+    ("jing", "腈"),
+    ("yan", "腌"),
+    ("fei", "腓"),
+    ("yu", "腴"),
+    ("zong", "腙"),
+    ("ding", "腚"),
+    ("jian", "腱"),
+    ("cou", "腠"),
+    ("nan", "腩"),
+    ("mian", "腼"),
+    ("wa", "腽"),
+    ("e", "腭"),
+    ("shu", "腧"),
+    ("cheng", "塍"),
+    ("ying", "媵"),
+    ("ge", "膈"),
+    ("lv", "膂"),
+    ("bin", "膑"),
+    ("teng", "滕"),
+    ("zhi", "膣"),
+    ("chuai", "膪"),
+    ("gu", "臌"),
+    ("meng", "朦"),
+    ("sao", "臊"),
+    ("shan", "膻"),
+    ("lian", "臁"),
+    ("lin", "膦"),
+    ("yu", "欤"),
+    ("xi", "欷"),
+    ("yi", "欹"),
+    ("sha", "歃"),
+    ("xin", "歆"),
+    ("she", "歙"),
+    ("biao", "飑"),
+    ("sa", "飒"),
+    ("ju", "飓"),
+    ("sou", "飕"),
+    ("biao", "飙"),
+    ("biao", "飚"),
+    ("shu", "殳"),
+    ("gou", "彀"),
+    ("gu", "毂"),
+    ("hu", "觳"),
+    ("fei", "斐"),
+    ("ji", "齑"),
+    ("lan", "斓"),
+    ("yu", "於"),
+    ("pei", "旆"),
+    ("mao", "旄"),
+    ("zhan", "旃"),
+    ("jing", "旌"),
+    ("ni", "旎"),
+    ("liu", "旒"),
+    ("yi", "旖"),
+    ("yang", "炀"),
+    ("wei", "炜"),
+    ("dun", "炖"),
+    ("qiang", "炝"),
+    ("shi", "炻"),
+    ("hu", "烀"),
+    ("zhu", "炷"),
+    ("xuan", "炫"),
+    ("tai", "炱"),
+    ("ye", "烨"),
+    ("yang", "烊"),
+    ("wu", "焐"),
+    ("han", "焓"),
+    ("men", "焖"),
+    ("chao", "焯"),
+    ("yan", "焱"),
+    ("hu", "煳"),
+    ("yu", "煜"),
+    ("wei", "煨"),
+    ("duan", "煅"),
+    ("bao", "煲"),
+    ("xuan", "煊"),
+    ("bian", "煸"),
+    ("tui", "煺"),
+    ("liu", "熘"),
+    ("man", "熳"),
+    ("shang", "熵"),
+    ("yun", "熨"),
+    ("yi", "熠"),
+    ("yu", "燠"),
+    ("fan", "燔"),
+    ("sui", "燧"),
+    ("xian", "燹"),
+    ("jue", "爝"),
+    ("cuan", "爨"),
+    ("biao", "灬"),
+    ("dao", "焘"),
+    ("xu", "煦"),
+    ("xi", "熹"),
+    ("li", "戾"),
+    ("hu", "戽"),
+    ("jiong", "扃"),
+    ("hu", "扈"),
+    ("fei", "扉"),
+    ("shi", "礻"),
+    ("si", "祀"),
+    ("xian", "祆"),
+    ("zhi", "祉"),
+    ("qu", "祛"),
+    ("hu", "祜"),
+    ("fu", "祓"),
+    ("zuo", "祚"),
+    ("mi", "祢"),
+    ("zhi", "祗"),
+    ("ci", "祠"),
+    ("zhen", "祯"),
+    ("tiao", "祧"),
+    ("qi", "祺"),
+    ("chan", "禅"),
+    ("xi", "禊"),
+    ("zhuo", "禚"),
+    ("xi", "禧"),
+    ("rang", "禳"),
+    ("te", "忑"),
+    ("tan", "忐"),
+    ("dui", "怼"),
+    ("jia", "恝"),
+    ("hui", "恚"),
+    ("nv", "恧"),
+    ("nen", "恁"),
+    ("yang", "恙"),
+    ("zi", "恣"),
+    ("que", "悫"),
+    ("qian", "愆"),
+    ("min", "愍"),
+    ("te", "慝"),
+    ("qi", "憩"),
+    ("dui", "憝"),
+    ("mao", "懋"),
+    ("men", "懑"),
+    ("gang", "戆"),
+    ("yu", "肀"),
+    ("yu", "聿"),
+    ("da", "沓"),
+    ("xue", "泶"),
+    ("miao", "淼"),
+    ("ji", "矶"),
+    ("gan", "矸"),
+    ("dang", "砀"),
+    ("huo", "砉"),
+    ("che", "砗"),
+    ("dun", "砘"),
+    ("ya", "砑"),
+    ("zhuo", "斫"),
+    ("bian", "砭"),
+    ("feng", "砜"),
+    ("fa", "砝"),
+    ("ai", "砹"),
+    ("li", "砺"),
+    ("long", "砻"),
+    ("zha", "砟"),
+    ("tong", "砼"),
+    ("di", "砥"),
+    ("la", "砬"),
+    ("tuo", "砣"),
+    ("fu", "砩"),
+    ("xing", "硎"),
+    ("mang", "硭"),
+    ("xia", "硖"),
+    ("qiao", "硗"),
+    ("zhai", "砦"),
+    ("dong", "硐"),
+    ("nao", "硇"),
+    ("ge", "硌"),
+    ("wo", "硪"),
+    ("qi", "碛"),
+    ("dui", "碓"),
+    ("bei", "碚"),
+    ("ding", "碇"),
+    ("chen", "碜"),
+    ("du", "碡"),
+    ("jie", "碣"),
+    ("di", "碲"),
+    ("xuan", "碹"),
+    ("bian", "碥"),
+    ("zhe", "磔"),
+    ("gun", "磙"),
+    ("sang", "磉"),
+    ("qing", "磬"),
+    ("qu", "磲"),
+    ("dun", "礅"),
+    ("deng", "磴"),
+    ("jiang", "礓"),
+    ("ca", "礤"),
+    ("meng", "礞"),
+    ("bo", "礴"),
+    ("kan", "龛"),
+    ("zhi", "黹"),
+    ("fu", "黻"),
+    ("fu", "黼"),
+    ("xu", "盱"),
+    ("mian", "眄"),
+    ("kou", "眍"),
+    ("dun", "盹"),
+    ("miao", "眇"),
+    ("dan", "眈"),
+    ("sheng", "眚"),
+    ("yuan", "眢"),
+    ("yi", "眙"),
+    ("sui", "眭"),
+    ("zi", "眦"),
+    ("chi", "眵"),
+    ("mou", "眸"),
+    ("lai", "睐"),
+    ("jian", "睑"),
+    ("di", "睇"),
+    ("suo", "睃"),
+    ("ya", "睚"),
+    ("ni", "睨"),
+    ("sui", "睢"),
+    ("pi", "睥"),
+    ("rui", "睿"),
+    ("sou", "瞍"),
+    ("kui", "睽"),
+    ("mao", "瞀"),
+    ("ke", "瞌"),
+    ("ming", "瞑"),
+    ("piao", "瞟"),
+    ("cheng", "瞠"),
+    ("kan", "瞰"),
+    ("lin", "瞵"),
+    ("gu", "瞽"),
+    ("ting", "町"),
+    ("bi", "畀"),
+    ("quan", "畎"),
+    ("tian", "畋"),
+    ("fan", "畈"),
+    ("zhen", "畛"),
+    ("she", "畲"),
+    ("wan", "畹"),
+    ("tuan", "疃"),
+    ("fu", "罘"),
+    ("gang", "罡"),
+    ("gu", "罟"),
+    ("li", "詈"),
+    ("yan", "罨"),
+    ("pi", "罴"),
+    ("lan", "罱"),
+    ("li", "罹"),
+    ("ji", "羁"),
+    ("zeng", "罾"),
+    ("he", "盍"),
+    ("guan", "盥"),
+    ("juan", "蠲"),
+    ("jin", "钅"),
+    ("ga", "钆"),
+    ("yi", "钇"),
+    ("po", "钋"),
+    ("zhao", "钊"),
+    ("liao", "钌"),
+    ("tu", "钍"),
+    ("chuan", "钏"),
+    ("shan", "钐"),
+    ("men", "钔"),
+    ("chai", "钗"),
+    ("nv", "钕"),
+    ("bu", "钚"),
+    ("tai", "钛"),
+    ("ju", "钜"),
+    ("ban", "钣"),
+    ("qian", "钤"),
+    ("fang", "钫"),
+    ("kang", "钪"),
+    ("tou", "钭"),
+    ("huo", "钬"),
+    ("ba", "钯"),
+    ("yu", "钰"),
+    ("zheng", "钲"),
+    ("gu", "钴"),
+    ("ke", "钶"),
+    ("po", "钷"),
+    ("bu", "钸"),
+    ("bo", "钹"),
+    ("yue", "钺"),
+    ("mu", "钼"),
+    ("tan", "钽"),
+    ("dian", "钿"),
+    ("shuo", "铄"),
+    ("shi", "铈"),
+    ("xuan", "铉"),
+    ("ta", "铊"),
+    ("bi", "铋"),
+    ("ni", "铌"),
+    ("pi", "铍"),
+    ("duo", "铎"),
+    ("kao", "铐"),
+    ("lao", "铑"),
+    ("er", "铒"),
+    ("you", "铕"),
+    ("cheng", "铖"),
+    ("jia", "铗"),
+    ("nao", "铙"),
+    ("ye", "铘"),
+    ("dang", "铛"),
+    ("diao", "铞"),
+    ("yin", "铟"),
+    ("kai", "铠"),
+    ("zhu", "铢"),
+    ("ding", "铤"),
+    ("diu", "铥"),
+    ("hua", "铧"),
+    ("quan", "铨"),
+    ("ha", "铪"),
+    ("sha", "铩"),
+    ("diao", "铫"),
+    ("zheng", "铮"),
+    ("se", "铯"),
+    ("chong", "铳"),
+    ("tang", "铴"),
+    ("an", "铵"),
+    ("ru", "铷"),
+    ("lao", "铹"),
+    ("lai", "铼"),
+    ("te", "铽"),
+    ("keng", "铿"),
+    ("zeng", "锃"),
+    ("li", "锂"),
+    ("gao", "锆"),
+    ("e", "锇"),
+    ("cuo", "锉"),
+    ("lve", "锊"),
+    ("liu", "锍"),
+    ("kai", "锎"),
+    ("jian", "锏"),
+    ("lang", "锒"),
+    ("qin", "锓"),
+    ("ju", "锔"),
+    ("a", "锕"),
+    ("qiang", "锖"),
+    ("nuo", "锘"),
+    ("ben", "锛"),
+    ("de", "锝"),
+    ("ke", "锞"),
+    ("kun", "锟"),
+    ("gu", "锢"),
+    ("huo", "锪"),
+    ("pei", "锫"),
+    ("juan", "锩"),
+    ("tan", "锬"),
+    ("zi", "锱"),
+    ("qie", "锲"),
+    ("kai", "锴"),
+    ("si", "锶"),
+    ("e", "锷"),
+    ("cha", "锸"),
+    ("sou", "锼"),
+    ("huan", "锾"),
+    ("ai", "锿"),
+    ("lou", "镂"),
+    ("qiang", "锵"),
+    ("fei", "镄"),
+    ("mei", "镅"),
+    ("mo", "镆"),
+    ("ge", "镉"),
+    ("juan", "镌"),
+    ("na", "镎"),
+    ("liu", "镏"),
+    ("yi", "镒"),
+    ("jia", "镓"),
+    ("bin", "镔"),
+    ("biao", "镖"),
+    ("tang", "镗"),
+    ("man", "镘"),
+    ("luo", "镙"),
+    ("yong", "镛"),
+    ("zu", "镞"),
+    ("xuan", "镟"),
+    ("di", "镝"),
+    ("chan", "镡"),
+    ("jue", "镢"),
+    ("pu", "镤"),
+    ("lu", "镥"),
+    ("dui", "镦"),
+    ("lan", "镧"),
+    ("pu", "镨"),
+    ("cuan", "镩"),
+    ("qiang", "镪"),
+    ("deng", "镫"),
+    ("huo", "镬"),
+    ("zhuo", "镯"),
+    ("yi", "镱"),
+    ("cha", "镲"),
+    ("biao", "镳"),
+    ("zhong", "锺"),
+    ("shen", "矧"),
+    ("cuo", "矬"),
+    ("zhi", "雉"),
+    ("bi", "秕"),
+    ("zi", "秭"),
+    ("mo", "秣"),
+    ("shu", "秫"),
+    ("lv", "稆"),
+    ("ji", "嵇"),
+    ("fu", "稃"),
+    ("lang", "稂"),
+    ("ke", "稞"),
+    ("ren", "稔"),
+    ("zhen", "稹"),
+    ("ji", "稷"),
+    ("se", "穑"),
+    ("nian", "黏"),
+    ("fu", "馥"),
+    ("rang", "穰"),
+    ("gui", "皈"),
+    ("jiao", "皎"),
+    ("hao", "皓"),
+    ("xi", "皙"),
+    ("po", "皤"),
+    ("die", "瓞"),
+    ("hu", "瓠"),
+    ("yong", "甬"),
+    ("jiu", "鸠"),
+    ("yuan", "鸢"),
+    ("bao", "鸨"),
+    ("zhen", "鸩"),
+    ("gu", "鸪"),
+    ("dong", "鸫"),
+    ("lu", "鸬"),
+    ("qu", "鸲"),
+    ("chi", "鸱"),
+    ("si", "鸶"),
+    ("er", "鸸"),
+    ("zhi", "鸷"),
+    ("gua", "鸹"),
+    ("xiu", "鸺"),
+    ("luan", "鸾"),
+    ("bo", "鹁"),
+    ("li", "鹂"),
+    ("gu", "鹄"),
+    ("yu", "鹆"),
+    ("xian", "鹇"),
+    ("ti", "鹈"),
+    ("wu", "鹉"),
+    ("miao", "鹋"),
+    ("an", "鹌"),
+    ("bei", "鹎"),
+    ("chun", "鹑"),
+    ("hu", "鹕"),
+    ("e", "鹗"),
+    ("ci", "鹚"),
+    ("mei", "鹛"),
+    ("wu", "鹜"),
+    ("yao", "鹞"),
+    ("jian", "鹣"),
+    ("ying", "鹦"),
+    ("zhe", "鹧"),
+    ("liu", "鹨"),
+    ("liao", "鹩"),
+    ("jiao", "鹪"),
+    ("jiu", "鹫"),
+    ("yu", "鹬"),
+    ("hu", "鹱"),
+    ("lu", "鹭"),
+    ("guan", "鹳"),
+    ("ne", "疒"),
+    ("ding", "疔"),
+    ("jie", "疖"),
+    ("li", "疠"),
+    ("shan", "疝"),
+    ("li", "疬"),
+    ("you", "疣"),
+    ("gan", "疳"),
+    ("ke", "疴"),
+    ("dan", "疸"),
+    ("zha", "痄"),
+    ("pao", "疱"),
+    ("zhu", "疰"),
+    ("xuan", "痃"),
+    ("jia", "痂"),
+    ("ya", "痖"),
+    ("yi", "痍"),
+    ("zhi", "痣"),
+    ("lao", "痨"),
+    ("wu", "痦"),
+    ("cuo", "痤"),
+    ("xian", "痫"),
+    ("sha", "痧"),
+    ("zhu", "瘃"),
+    ("fei", "痱"),
+    ("gu", "痼"),
+    ("wei", "痿"),
+    ("yu", "瘐"),
+    ("yu", "瘀"),
+    ("dan", "瘅"),
+    ("la", "瘌"),
+    ("yi", "瘗"),
+    ("hou", "瘊"),
+    ("chai", "瘥"),
+    ("lou", "瘘"),
+    ("jia", "瘕"),
+    ("sao", "瘙"),
+    ("chi", "瘛"),
+    ("mo", "瘼"),
+    ("ban", "瘢"),
+    ("ji", "瘠"),
+    ("huang", "癀"),
+    ("biao", "瘭"),
+    ("luo", "瘰"),
+    ("ying", "瘿"),
+    ("zhai", "瘵"),
+    ("long", "癃"),
+    ("yin", "瘾"),
+    ("chou", "瘳"),
+    ("ban", "癍"),
+    ("lai", "癞"),
+    ("yi", "癔"),
+    ("dian", "癜"),
+    ("pi", "癖"),
+    ("dian", "癫"),
+    ("qu", "癯"),
+    ("yi", "翊"),
+    ("song", "竦"),
+    ("xi", "穸"),
+    ("qiong", "穹"),
+    ("zhun", "窀"),
+    ("bian", "窆"),
+    ("yao", "窈"),
+    ("tiao", "窕"),
+    ("dou", "窦"),
+    ("ke", "窠"),
+    ("yu", "窬"),
+    ("xun", "窨"),
+    ("ju", "窭"),
+    ("yu", "窳"),
+    ("yi", "衤"),
+    ("cha", "衩"),
+    ("na", "衲"),
+    ("ren", "衽"),
+    ("jin", "衿"),
+    ("mei", "袂"),
+    ("pan", "袢"),
+    ("dang", "裆"),
+    ("qia", "袷"),
+    ("ge", "袼"),
+    ("ken", "裉"),
+    ("lian", "裢"),
+    ("cheng", "裎"),
+    ("lian", "裣"),
+    ("jian", "裥"),
+    ("biao", "裱"),
+    ("chu", "褚"),
+    ("ti", "裼"),
+    ("bi", "裨"),
+    ("ju", "裾"),
+    ("duo", "裰"),
+    ("da", "褡"),
+    ("bei", "褙"),
+    ("bao", "褓"),
+    ("lv", "褛"),
+    ("bian", "褊"),
+    ("lan", "褴"),
+    ("chi", "褫"),
+    ("zhe", "褶"),
+    ("qiang", "襁"),
+    ("ru", "襦"),
+    ("pan", "襻"),
+    ("pi", "疋"),
+    ("xu", "胥"),
+    ("jun", "皲"),
+    ("cun", "皴"),
+    ("jin", "矜"),
+    ("lei", "耒"),
+    ("zi", "耔"),
+    ("chao", "耖"),
+    ("si", "耜"),
+    ("huo", "耠"),
+    ("lao", "耢"),
+    ("tang", "耥"),
+    ("ou", "耦"),
+    ("lou", "耧"),
+    ("jiang", "耩"),
+    ("nou", "耨"),
+    ("mo", "耱"),
+    ("die", "耋"),
+    ("ding", "耵"),
+    ("dan", "聃"),
+    ("ling", "聆"),
+    ("ning", "聍"),
+    ("gua", "聒"),
+    ("kui", "聩"),
+    ("ao", "聱"),
+    ("tan", "覃"),
+    ("han", "顸"),
+    ("qi", "颀"),
+    ("hang", "颃"),
+    ("jie", "颉"),
+    ("he", "颌"),
+    ("ying", "颍"),
+    ("ke", "颏"),
+    ("han", "颔"),
+    ("e", "颚"),
+    ("zhuan", "颛"),
+    ("nie", "颞"),
+    ("man", "颟"),
+    ("sang", "颡"),
+    ("hao", "颢"),
+    ("ru", "颥"),
+    ("pin", "颦"),
+    ("hu", "虍"),
+    ("qian", "虔"),
+    ("qiu", "虬"),
+    ("ji", "虮"),
+    ("chai", "虿"),
+    ("hui", "虺"),
+    ("ge", "虼"),
+    ("meng", "虻"),
+    ("fu", "蚨"),
+    ("pi", "蚍"),
+    ("rui", "蚋"),
+    ("xian", "蚬"),
+    ("hao", "蚝"),
+    ("jie", "蚧"),
+    ("gong", "蚣"),
+    ("dou", "蚪"),
+    ("yin", "蚓"),
+    ("chi", "蚩"),
+    ("han", "蚶"),
+    ("gu", "蛄"),
+    ("he", "蚵"),
+    ("li", "蛎"),
+    ("you", "蚰"),
+    ("ran", "蚺"),
+    ("zha", "蚱"),
+    ("qiu", "蚯"),
+    ("ling", "蛉"),
+    ("cheng", "蛏"),
+    ("you", "蚴"),
+    ("qiong", "蛩"),
+    ("jia", "蛱"),
+    ("nao", "蛲"),
+    ("zhi", "蛭"),
+    ("si", "蛳"),
+    ("qu", "蛐"),
+    ("ting", "蜓"),
+    ("kuo", "蛞"),
+    ("qi", "蛴"),
+    ("jiao", "蛟"),
+    ("yang", "蛘"),
+    ("mou", "蛑"),
+    ("shen", "蜃"),
+    ("zhe", "蜇"),
+    ("shao", "蛸"),
+    ("wu", "蜈"),
+    ("li", "蜊"),
+    ("chu", "蜍"),
+    ("fu", "蜉"),
+    ("qiang", "蜣"),
+    ("qing", "蜻"),
+    ("qi", "蜞"),
+    ("xi", "蜥"),
+    ("yu", "蜮"),
+    ("fei", "蜚"),
+    ("guo", "蜾"),
+    ("guo", "蝈"),
+    ("yi", "蜴"),
+    ("pi", "蜱"),
+    ("tiao", "蜩"),
+    ("quan", "蜷"),
+    ("wan", "蜿"),
+    ("lang", "螂"),
+    ("meng", "蜢"),
+    ("chun", "蝽"),
+    ("rong", "蝾"),
+    ("nan", "蝻"),
+    ("fu", "蝠"),
+    ("kui", "蝰"),
+    ("ke", "蝌"),
+    ("fu", "蝮"),
+    ("sou", "螋"),
+    ("yu", "蝓"),
+    ("you", "蝣"),
+    ("lou", "蝼"),
+    ("qiu", "蝤"),
+    ("bian", "蝙"),
+    ("mao", "蝥"),
+    ("qin", "螓"),
+    ("ao", "螯"),
+    ("man", "螨"),
+    ("mang", "蟒"),
+    ("ma", "蟆"),
+    ("yuan", "螈"),
+    ("xi", "螅"),
+    ("chi", "螭"),
+    ("tang", "螗"),
+    ("pang", "螃"),
+    ("shi", "螫"),
+    ("huang", "蟥"),
+    ("cao", "螬"),
+    ("piao", "螵"),
+    ("tang", "螳"),
+    ("xi", "蟋"),
+    ("xiang", "蟓"),
+    ("zhong", "螽"),
+    ("zhang", "蟑"),
+    ("shuai", "蟀"),
+    ("mao", "蟊"),
+    ("peng", "蟛"),
+    ("hui", "蟪"),
+    ("pan", "蟠"),
+    ("shan", "蟮"),
+    ("huo", "蠖"),
+    ("meng", "蠓"),
+    ("chan", "蟾"),
+    ("lian", "蠊"),
+    ("mie", "蠛"),
+    ("li", "蠡"),
+    ("du", "蠹"),
+    ("qu", "蠼"),
+    ("fou", "缶"),
+    ("ying", "罂"),
+    ("qing", "罄"),
+    ("xia", "罅"),
+    ("shi", "舐"),
+    ("zhu", "竺"),
+    ("yu", "竽"),
+    ("ji", "笈"),
+    ("du", "笃"),
+    ("ji", "笄"),
+    ("jian", "笕"),
+    ("zhao", "笊"),
+    ("zi", "笫"),
+    ("hu", "笏"),
+    ("qiong", "筇"),
+    ("po", "笸"),
+    ("da", "笪"),
+    ("sheng", "笙"),
+    ("ze", "笮"),
+    ("gou", "笱"),
+    ("li", "笠"),
+    ("si", "笥"),
+    ("tiao", "笤"),
+    ("jia", "笳"),
+    ("bian", "笾"),
+    ("chi", "笞"),
+    ("kou", "筘"),
+    ("bi", "筚"),
+    ("xian", "筅"),
+    ("yan", "筵"),
+    ("quan", "筌"),
+    ("zheng", "筝"),
+    ("yun", "筠"),
+    ("shi", "筮"),
+    ("gang", "筻"),
+    ("pa", "筢"),
+    ("shao", "筲"),
+    ("xiao", "筱"),
+    ("qing", "箐"),
+    ("ze", "箦"),
+    ("qie", "箧"),
+    ("zhu", "箸"),
+    ("ruo", "箬"),
+    ("qian", "箝"),
+    ("tuo", "箨"),
+    ("bi", "箅"),
+    ("dan", "箪"),
+    ("kong", "箜"),
+    ("yuan", "箢"),
+    ("xiao", "箫"),
+    ("zhen", "箴"),
+    ("kui", "篑"),
+    ("huang", "篁"),
+    ("hou", "篌"),
+    ("gou", "篝"),
+    ("fei", "篚"),
+    ("li", "篥"),
+    ("bi", "篦"),
+    ("chi", "篪"),
+    ("su", "簌"),
+    ("mie", "篾"),
+    ("dou", "篼"),
+    ("lu", "簏"),
+    ("duan", "簖"),
+    ("gui", "簋"),
+    ("dian", "簟"),
+    ("zan", "簪"),
+    ("deng", "簦"),
+    ("bo", "簸"),
+    ("lai", "籁"),
+    ("zhou", "籀"),
+    ("yu", "臾"),
+    ("yu", "舁"),
+    ("chong", "舂"),
+    ("xi", "舄"),
+    ("nie", "臬"),
+    ("nv", "衄"),
+    ("chuan", "舡"),
+    ("shan", "舢"),
+    ("yi", "舣"),
+    ("bi", "舭"),
+    ("zhong", "舯"),
+    ("ban", "舨"),
+    ("fang", "舫"),
+    ("ge", "舸"),
+    ("lu", "舻"),
+    ("zhu", "舳"),
+    ("ze", "舴"),
+    ("xi", "舾"),
+    ("shao", "艄"),
+    ("wei", "艉"),
+    ("meng", "艋"),
+    ("shou", "艏"),
+    ("cao", "艚"),
+    ("chong", "艟"),
+    ("meng", "艨"),
+    ("qin", "衾"),
+    ("niao", "袅"),
+    ("jia", "袈"),
+    ("qiu", "裘"),
+    ("sha", "裟"),
+    ("bi", "襞"),
+    ("di", "羝"),
+    ("qiang", "羟"),
+    ("suo", "羧"),
+    ("jie", "羯"),
+    ("tang", "羰"),
+    ("xi", "羲"),
+    ("xian", "籼"),
+    ("mi", "敉"),
+    ("ba", "粑"),
+    ("li", "粝"),
+    ("tiao", "粜"),
+    ("xi", "粞"),
+    ("zi", "粢"),
+    ("can", "粲"),
+    ("lin", "粼"),
+    ("zong", "粽"),
+    ("san", "糁"),
+    ("hou", "糇"),
+    ("zan", "糌"),
+    ("ci", "糍"),
+    ("xu", "糈"),
+    ("rou", "糅"),
+    ("qiu", "糗"),
+    ("jiang", "糨"),
+    ("gen", "艮"),
+    ("ji", "暨"),
+    ("yi", "羿"),
+    ("ling", "翎"),
+    ("xi", "翕"),
+    ("zhu", "翥"),
+    ("fei", "翡"),
+    ("jian", "翦"),
+    ("pian", "翩"),
+    ("he", "翮"),
+    ("yi", "翳"),
+    ("mi", "糸"),
+    ("zhi", "絷"),
+    ("qi", "綦"),
+    ("qi", "綮"),
+    ("yao", "繇"),
+    ("dao", "纛"),
+    ("fu", "麸"),
+    ("qu", "麴"),
+    ("jiu", "赳"),
+    ("ju", "趄"),
+    ("lie", "趔"),
+    ("zi", "趑"),
+    ("zan", "趱"),
+    ("nan", "赧"),
+    ("zhe", "赭"),
+    ("jiang", "豇"),
+    ("shi", "豉"),
+    ("ding", "酊"),
+    ("gan", "酐"),
+    ("zhou", "酎"),
+    ("yi", "酏"),
+    ("gu", "酤"),
+    ("cu", "酢"),
+    ("tuo", "酡"),
+    ("xian", "酰"),
+    ("ming", "酩"),
+    ("zhi", "酯"),
+    ("yan", "酽"),
+    ("shai", "酾"),
+    ("cheng", "酲"),
+    ("tu", "酴"),
+    ("lei", "酹"),
+    ("kun", "醌"),
+    ("pei", "醅"),
+    ("hu", "醐"),
+    ("ti", "醍"),
+    ("xu", "醑"),
+    ("hai", "醢"),
+    ("tang", "醣"),
+    ("lao", "醪"),
+    ("bu", "醭"),
+    ("jiao", "醮"),
+    ("xi", "醯"),
+    ("ju", "醵"),
+    ("li", "醴"),
+    ("xun", "醺"),
+    ("shi", "豕"),
+    ("cuo", "鹾"),
+    ("dun", "趸"),
+    ("qiong", "跫"),
+    ("xue", "踅"),
+    ("cu", "蹙"),
+    ("bie", "蹩"),
+    ("bao", "趵"),
+    ("ta", "趿"),
+    ("jian", "趼"),
+    ("fu", "趺"),
+    ("qiang", "跄"),
+    ("zhi", "跖"),
+    ("fu", "跗"),
+    ("shan", "跚"),
+    ("li", "跞"),
+    ("tuo", "跎"),
+    ("jia", "跏"),
+    ("bo", "跛"),
+    ("tai", "跆"),
+    ("kui", "跬"),
+    ("qiao", "跷"),
+    ("bi", "跸"),
+    ("xian", "跣"),
+    ("xian", "跹"),
+    ("ji", "跻"),
+    ("jiao", "跤"),
+    ("liang", "踉"),
+    ("ji", "跽"),
+    ("chuo", "踔"),
+    ("huai", "踝"),
+    ("chi", "踟"),
+    ("zhi", "踬"),
+    ("dian", "踮"),
+    ("bo", "踣"),
+    ("zhi", "踯"),
+    ("jian", "踺"),
+    ("die", "蹀"),
+    ("chuai", "踹"),
+    ("zhong", "踵"),
+    ("ju", "踽"),
+    ("duo", "踱"),
+    ("cuo", "蹉"),
+    ("pian", "蹁"),
+    ("rou", "蹂"),
+    ("nie", "蹑"),
+    ("man", "蹒"),
+    ("qi", "蹊"),
+    ("chu", "蹰"),
+    ("jue", "蹶"),
+    ("pu", "蹼"),
+    ("fan", "蹯"),
+    ("cu", "蹴"),
+    ("zhu", "躅"),
+    ("lin", "躏"),
+    ("chan", "躔"),
+    ("lie", "躐"),
+    ("zuan", "躜"),
+    ("xie", "躞"),
+    ("zhi", "豸"),
+    ("diao", "貂"),
+    ("mo", "貊"),
+    ("xiu", "貅"),
+    ("mo", "貘"),
+    ("pi", "貔"),
+    ("hu", "斛"),
+    ("jue", "觖"),
+    ("shang", "觞"),
+    ("gu", "觚"),
+    ("zi", "觜"),
+    ("gong", "觥"),
+    ("su", "觫"),
+    ("zhi", "觯"),
+    ("zi", "訾"),
+    ("qing", "謦"),
+    ("jing", "靓"),
+    ("yu", "雩"),
+    ("li", "雳"),
+    ("wen", "雯"),
+    ("ting", "霆"),
+    ("ji", "霁"),
+    ("pei", "霈"),
+    ("fei", "霏"),
+    ("sha", "霎"),
+    ("yin", "霪"),
+    ("ai", "霭"),
+    ("xian", "霰"),
+    ("mai", "霾"),
+    ("chen", "龀"),
+    ("ju", "龃"),
+    ("bao", "龅"),
+    ("tiao", "龆"),
+    ("zi", "龇"),
+    ("ken", "龈"),
+    ("yu", "龉"),
+    ("chuo", "龊"),
+    ("wo", "龌"),
+    ("mian", "黾"),
+    ("yuan", "鼋"),
+    ("tuo", "鼍"),
+    ("zhui", "隹"),
+    ("sun", "隼"),
+    ("juan", "隽"),
+    ("ju", "雎"),
+    ("luo", "雒"),
+    ("qu", "瞿"),
+    ("chou", "雠"),
+    ("qiong", "銎"),
+    ("luan", "銮"),
+    ("wu", "鋈"),
+    ("zan", "錾"),
+    ("mou", "鍪"),
+    ("ao", "鏊"),
+    ("liu", "鎏"),
+    ("bei", "鐾"),
+    ("xin", "鑫"),
+    ("you", "鱿"),
+    ("fang", "鲂"),
+    ("ba", "鲅"),
+    ("ping", "鲆"),
+    ("nian", "鲇"),
+    ("lu", "鲈"),
+    ("su", "稣"),
+    ("fu", "鲋"),
+    ("hou", "鲎"),
+    ("tai", "鲐"),
+    ("gui", "鲑"),
+    ("jie", "鲒"),
+    ("wei", "鲔"),
+    ("er", "鲕"),
+    ("ji", "鲚"),
+    ("jiao", "鲛"),
+    ("xiang", "鲞"),
+    ("xun", "鲟"),
+    ("geng", "鲠"),
+    ("li", "鲡"),
+    ("lian", "鲢"),
+    ("jian", "鲣"),
+    ("shi", "鲥"),
+    ("tiao", "鲦"),
+    ("gun", "鲧"),
+    ("sha", "鲨"),
+    ("huan", "鲩"),
+    ("ji", "鲫"),
+    ("qing", "鲭"),
+    ("ling", "鲮"),
+    ("zou", "鲰"),
+    ("fei", "鲱"),
+    ("kun", "鲲"),
+    ("chang", "鲳"),
+    ("gu", "鲴"),
+    ("ni", "鲵"),
+    ("nian", "鲶"),
+    ("diao", "鲷"),
+    ("shi", "鲺"),
+    ("zi", "鲻"),
+    ("fen", "鲼"),
+    ("die", "鲽"),
+    ("e", "鳄"),
+    ("qiu", "鳅"),
+    ("fu", "鳆"),
+    ("huang", "鳇"),
+    ("bian", "鳊"),
+    ("sao", "鳋"),
+    ("ao", "鳌"),
+    ("qi", "鳍"),
+    ("ta", "鳎"),
+    ("guan", "鳏"),
+    ("yao", "鳐"),
+    ("le", "鳓"),
+    ("biao", "鳔"),
+    ("xue", "鳕"),
+    ("man", "鳗"),
+    ("min", "鳘"),
+    ("yong", "鳙"),
+    ("gui", "鳜"),
+    ("shan", "鳝"),
+    ("zun", "鳟"),
+    ("li", "鳢"),
+    ("da", "靼"),
+    ("yang", "鞅"),
+    ("da", "鞑"),
+    ("qiao", "鞒"),
+    ("man", "鞔"),
+    ("jian", "鞯"),
+    ("ju", "鞫"),
+    ("rou", "鞣"),
+    ("gou", "鞲"),
+    ("bei", "鞴"),
+    ("jie", "骱"),
+    ("tou", "骰"),
+    ("ku", "骷"),
+    ("gu", "鹘"),
+    ("di", "骶"),
+    ("hou", "骺"),
+    ("ge", "骼"),
+    ("ke", "髁"),
+    ("bi", "髀"),
+    ("lou", "髅"),
+    ("qia", "髂"),
+    ("kuan", "髋"),
+    ("bin", "髌"),
+    ("du", "髑"),
+    ("mei", "魅"),
+    ("ba", "魃"),
+    ("yan", "魇"),
+    ("liang", "魉"),
+    ("xiao", "魈"),
+    ("wang", "魍"),
+    ("chi", "魑"),
+    ("xiang", "飨"),
+    ("yan", "餍"),
+    ("tie", "餮"),
+    ("tao", "饕"),
+    ("yong", "饔"),
+    ("biao", "髟"),
+    ("kun", "髡"),
+    ("mao", "髦"),
+    ("ran", "髯"),
+    ("tiao", "髫"),
+    ("ji", "髻"),
+    ("zi", "髭"),
+    ("xiu", "髹"),
+    ("quan", "鬈"),
+    ("jiu", "鬏"),
+    ("bin", "鬓"),
+    ("huan", "鬟"),
+    ("lie", "鬣"),
+    ("mo", "麽"),
+    ("hui", "麾"),
+    ("mi", "縻"),
+    ("ji", "麂"),
+    ("jun", "麇"),
+    ("zhu", "麈"),
+    ("mi", "麋"),
+    ("qi", "麒"),
+    ("ao", "鏖"),
+    ("she", "麝"),
+    ("lin", "麟"),
+    ("dai", "黛"),
+    ("chu", "黜"),
+    ("you", "黝"),
+    ("xia", "黠"),
+    ("yi", "黟"),
+    ("qu", "黢"),
+    ("du", "黩"),
+    ("li", "黧"),
+    ("qing", "黥"),
+    ("can", "黪"),
+    ("an", "黯"),
+    ("fen", "鼢"),
+    ("you", "鼬"),
+    ("wu", "鼯"),
+    ("yan", "鼹"),
+    ("xi", "鼷"),
+    ("qiu", "鼽"),
+    ("han", "鼾"),
+    ("zha", "齄"),
+];
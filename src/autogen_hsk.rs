@@ -1053,6 +1053,7 @@ pub static PINYIN: &[u32] = &[
     0x655f8310, // banfa
     0x656d2d96, // xiande
     0x657aa6db, // faner
+    0x6587e419, // zhileng
     0x65a1becd, // shihou
     0x65a4b2ec, // weibi
     0x65a5b296, // weiqi
@@ -1153,7 +1154,6 @@ pub static PINYIN: &[u32] = &[
     0x6fc44ca2, // hongse
     0x6fda9bab, // xuxin
     0x6ffb057c, // mingpian
-    0x700167e7, // xhileng
     0x700e5482, // xiangjingrubin
     0x7016c87d, // cheng
     0x7020bfaf, // paobu
@@ -2572,5579 +2572,35346 @@ pub static PINYIN: &[u32] = &[
     0xfffc53b6, // xi
 ];
 
+// Pinyin search keys in the same order as PINYIN. Murmur3 hashes of random
+// input can collide with hashes of real keys, so a hash match only counts
+// if the key matches too.
+pub static PINYIN_KEYS: &[&str] = &[
+    "ruo",
+    "beiguan",
+    "shouhuo",
+    "xiangfang",
+    "guimo",
+    "yumi",
+    "zheli",
+    "laoying",
+    "riji",
+    "xiangjiao",
+    "linshi",
+    "sunshi",
+    "hutong",
+    "zhijie",
+    "xingqu",
+    "chengyu",
+    "jiaoshui",
+    "cai",
+    "kuazhang",
+    "choulou",
+    "wangsheng",
+    "qiaoqiao",
+    "shuaidao",
+    "laolao",
+    "zaisan",
+    "dui",
+    "xingbie",
+    "youyou",
+    "huasheng",
+    "qian",
+    "shuzi",
+    "tang",
+    "fenzhong",
+    "chengshou",
+    "leixing",
+    "juese",
+    "juban",
+    "haochu",
+    "fanrong",
+    "jiezhe",
+    "chengzuo",
+    "yidan",
+    "chongwu",
+    "yuqi",
+    "fangxin",
+    "weibo",
+    "hu",
+    "riyongpin",
+    "shouming",
+    "banshi",
+    "luxun",
+    "nongye",
+    "diqiu",
+    "huai",
+    "bian",
+    "nian",
+    "sheru",
+    "juli",
+    "tuijian",
+    "zhuangtai",
+    "lihai",
+    "renbuzhu",
+    "jianzhu",
+    "gonggongqiche",
+    "shihua",
+    "jujue",
+    "xingren",
+    "sheji",
+    "chufang",
+    "shenti",
+    "shoushu",
+    "ouran",
+    "chaoshi",
+    "bujiasisuo",
+    "chuxian",
+    "ganxiang",
+    "hangye",
+    "zui",
+    "zhuanmen",
+    "chunyou",
+    "ciyao",
+    "fannao",
+    "kexue",
+    "youmei",
+    "baochi",
+    "laoban",
+    "maibuliqi",
+    "cha",
+    "xiandai",
+    "jiating",
+    "shaowei",
+    "baobei",
+    "mianlin",
+    "zhuangkuang",
+    "meili",
+    "liqi",
+    "suoduan",
+    "zhengqi",
+    "weikou",
+    "mangren",
+    "jihu",
+    "fengfu",
+    "queren",
+    "julebu",
+    "wancheng",
+    "bukeqi",
+    "jieshu",
+    "jianjue",
+    "chengxu",
+    "mei",
+    "cuxin",
+    "zhanlan",
+    "xunsu",
+    "jiayouzhan",
+    "zhaoshe",
+    "lishi",
+    "fanwei",
+    "qiguai",
+    "gandao",
+    "banye",
+    "meitan",
+    "chuantong",
+    "keji",
+    "pingfang",
+    "lianghao",
+    "wunai",
+    "shenzhi",
+    "guoqi",
+    "jilie",
+    "panzi",
+    "zhongwu",
+    "gudai",
+    "aihao",
+    "kaiwanxiao",
+    "jingchengsuozhi",
+    "neike",
+    "yilian",
+    "laizi",
+    "kaimushi",
+    "wudao",
+    "zaihu",
+    "wuyi",
+    "didian",
+    "nanshou",
+    "yinqi",
+    "youshi",
+    "ceng",
+    "jiaozi",
+    "zhongshi",
+    "cuo",
+    "jilei",
+    "yanjiu",
+    "leguan",
+    "pingheng",
+    "chukou",
+    "gongxi",
+    "huanying",
+    "pangbian",
+    "chexiang",
+    "haiyang",
+    "wuliao",
+    "sheyingshi",
+    "tuo",
+    "jinshen",
+    "ta",
+    "gongchengshi",
+    "anpai",
+    "beijingshifandaxue",
+    "hanyu",
+    "xingyun",
+    "xiaoyu",
+    "youqu",
+    "wei",
+    "zenme",
+    "zunzhong",
+    "didi",
+    "zhongjian",
+    "danyuan",
+    "zhankai",
+    "chi",
+    "kongtiao",
+    "ai",
+    "quedian",
+    "liyou",
+    "yagao",
+    "daoyan",
+    "zhongwen",
+    "renkou",
+    "youxian",
+    "yeyu",
+    "gaosu",
+    "laohu",
+    "guitai",
+    "hushuo",
+    "peizhansen",
+    "richeng",
+    "dute",
+    "xiaoshun",
+    "darao",
+    "xuyao",
+    "zhiyao",
+    "qianbao",
+    "youdianr",
+    "pei`zhansen",
+    "xushu",
+    "gewai",
+    "zisha",
+    "zhexie",
+    "nanguo",
+    "jingzheng",
+    "yaobu",
+    "shuai",
+    "fashao",
+    "laji",
+    "mahu",
+    "jianli",
+    "`",
+    "shangdian",
+    "shao",
+    "xingkui",
+    "heshi",
+    "kunchong",
+    "jiazi",
+    "baoming",
+    "guannian",
+    "chenghu",
+    "weiba",
+    "daoyou",
+    "ganjue",
+    "liaotianr",
+    "huoban",
+    "fenpei",
+    "qiong",
+    "biaozhun",
+    "baoxian",
+    "jiti",
+    "zhiding",
+    "zishi",
+    "yinliang",
+    "tian",
+    "zhengqiu",
+    "zongjie",
+    "zhuyao",
+    "zhiliang",
+    "zhaoxiangji",
+    "yixia",
+    "(",
+    "xiangpi",
+    "yihuir",
+    "san",
+    "shengdiao",
+    "laoshi",
+    "cucao",
+    "ganjing",
+    "quanmian",
+    "daozhi",
+    "huang",
+    "ce",
+    "qiantu",
+    "zhuyi",
+    "zisi",
+    "tiaozhan",
+    "shenghuo",
+    "shizhong",
+    "sixiang",
+    "nvhair",
+    "chenshan",
+    "jingju",
+    "juti",
+    "xiqu",
+    "zuoye",
+    "bawo",
+    "shijin",
+    "yi",
+    "maojin",
+    "bier`gaici",
+    "liuli",
+    "qiaomiao",
+    "zhongda",
+    "qunzi",
+    "miqie",
+    "feihua",
+    "sheshi",
+    "ma",
+    ")",
+    "leng",
+    "tudi",
+    "zhanghu",
+    "juran",
+    "daodi",
+    "xiangshan",
+    "yaoshi",
+    "kou",
+    "qingyuanshu",
+    "la",
+    "tizuqiu",
+    "ruwei",
+    "enai",
+    "dai",
+    "nimen",
+    "yuyan",
+    "lou",
+    "deyi",
+    "jixu",
+    "sichou",
+    "renming",
+    "zhizuo",
+    "shangpin",
+    "jianpan",
+    "shushu",
+    "jiazhuang",
+    "xiangdui",
+    "zongli",
+    "zige",
+    "mutou",
+    "liyong",
+    "timu",
+    "ranhou",
+    "yuanyin",
+    "jiabin",
+    "han",
+    "heixingxing",
+    "mianshi",
+    "baoyuan",
+    "juan",
+    "nengli",
+    "keneng",
+    "xin",
+    "suowei",
+    "suishou",
+    "chuguo",
+    "nuobeierjiang",
+    "jiujing",
+    "wenju",
+    "donghuapian",
+    "zhihao",
+    "bici",
+    "xiangxin",
+    "pohuai",
+    "hou",
+    "beizi",
+    "lvshi",
+    "lengdan",
+    "xiuxi",
+    "chengshu",
+    "zaiyu",
+    "jiji",
+    "rujin",
+    "quanshenguanzhu",
+    "jiayou",
+    "langman",
+    "renwei",
+    "qingshaonian",
+    "zhixu",
+    "haohua",
+    "jinzhang",
+    "qie",
+    "baodao",
+    "luohou",
+    "zhuozi",
+    "qiju",
+    "yiwu",
+    "qingkuang",
+    "huangzhang",
+    "zhuajin",
+    "beijing",
+    "lvxing",
+    "pen",
+    "zuopin",
+    "lingjian",
+    "shihe",
+    "tianran",
+    "fanyi",
+    "zhangfu",
+    "jiaoji",
+    "chizao",
+    "taolun",
+    "dashiguan",
+    "huguosi",
+    "caiqu",
+    "biaoming",
+    "gaijin",
+    "fense",
+    "lu",
+    "kekao",
+    "guangpan",
+    "bizi",
+    "xiangchang",
+    "nianling",
+    "wuran",
+    "chuanglian",
+    "zhudong",
+    "qingdan",
+    "he",
+    "guanzi",
+    "youyisi",
+    "liushou",
+    "daiyan",
+    "guangchang",
+    "yingjian",
+    "pingdeng",
+    "huran",
+    "wangji",
+    "jingcha",
+    "taijiquan",
+    "juedui",
+    "yiyi",
+    "xinglixiang",
+    "shouju",
+    "yue",
+    "yuanliao",
+    "haizi",
+    "zhaohuo",
+    "daying",
+    "daifu",
+    "jianzhi",
+    "fanchuan",
+    "jianqing",
+    "zidong",
+    "a",
+    "zhao",
+    "jianju",
+    "jinli",
+    "meimei",
+    "budebu",
+    "shougong",
+    "jieshao",
+    "nv",
+    "geng",
+    "youqi",
+    "yilv",
+    "sihao",
+    "fahui",
+    "weixian",
+    "<maihuochaidexiaonvhaier>",
+    "jizhe",
+    "jihua",
+    "dagong",
+    "miao",
+    "shuang",
+    "yisi",
+    "gege",
+    "youhui",
+    "gongli",
+    "jianbang",
+    "goucheng",
+    "bingqie",
+    "fengxian",
+    "lianai",
+    "suoyi",
+    "jianshao",
+    "lihun",
+    "waigong",
+    "anwei",
+    "kanfa",
+    "zhongyao",
+    "hanyi",
+    "anzhao",
+    "daomei",
+    "tizi",
+    "yachi",
+    "xinhao",
+    "diantai",
+    "shenglve",
+    "shengbing",
+    "shenyuan",
+    "yezi",
+    "ceyan",
+    "baba",
+    "ganhuor",
+    "qishi",
+    "guahao",
+    "yangguang",
+    "kuangquanshui",
+    "xinxian",
+    "bunaifan",
+    "xishoujian",
+    "qiyu",
+    "fanzheng",
+    "cunzai",
+    "xiamian",
+    "diao",
+    "jiqi",
+    "guaiwan",
+    "houbei",
+    "mantou",
+    "jian",
+    "yisheng",
+    "shengming",
+    "xiaoshi",
+    "shixi",
+    "guke",
+    "nen",
+    "xiangguan",
+    "shuiping",
+    "xiezuo",
+    "bingjiling",
+    "biaoyan",
+    "jiangjiu",
+    "kuaizi",
+    "zhaoguo",
+    "zhiye",
+    "guanxin",
+    "xingfu",
+    "weiqu",
+    "bijiben",
+    "shangwang",
+    "hebi",
+    "bucuo",
+    "dianchi",
+    "xiexie",
+    "yongji",
+    "xinxin",
+    "baotuquan",
+    "yiyang",
+    "kong",
+    "qiyou",
+    "xialingying",
+    "zhengju",
+    "shai",
+    "ganqing",
+    "neibu",
+    "jiaotong",
+    "nan",
+    "suan",
+    "wusuowei",
+    "caishikou",
+    "laoshe",
+    "huaiyun",
+    "tuya",
+    "shousui",
+    "piaoliang",
+    "xinxi",
+    "shiyan",
+    "zhuchi",
+    "heiban",
+    "dapenti",
+    "qianbi",
+    "hege",
+    "mifan",
+    "shafa",
+    "tui",
+    "tiao",
+    "nali",
+    "yongbao",
+    "tou",
+    "kuaiji",
+    "guanyu",
+    "xinnian",
+    "xuanze",
+    "nongcun",
+    "ruan",
+    "chouxiang",
+    "yu",
+    "fangfa",
+    "kao",
+    "shanliang",
+    "guoqu",
+    "keen",
+    "dadao",
+    "shengdong",
+    "ziliao",
+    "kunrao",
+    "chuban",
+    "jidao",
+    "tingshuo",
+    "shubiao",
+    "shengyin",
+    "zhiliao",
+    "yazhou",
+    "peizhun",
+    "biyao",
+    "ganji",
+    "luqu",
+    "shishi",
+    "zhuanbian",
+    "danren",
+    "tu",
+    "yiwen",
+    "shaoxu",
+    "feinimoshu",
+    "kelian",
+    "chehuo",
+    "shen",
+    "deng",
+    "guilv",
+    "xiaoyuan",
+    "cun",
+    "jingguo",
+    "yiding",
+    "laoshu",
+    "yeye",
+    "meiyou",
+    "touru",
+    "genju",
+    "shiqi",
+    "tuibu",
+    "ha",
+    "gan",
+    "suishi",
+    "yun",
+    "gudian",
+    "kuang",
+    "dandu",
+    "shengyi",
+    "yange",
+    "qing",
+    "quanbu",
+    "shei",
+    "yongqi",
+    "lianhe",
+    "baohu",
+    "zhishi",
+    "xinren",
+    "zhengjian",
+    "chuanran",
+    "pindao",
+    "jindai",
+    "paidui",
+    "penzi",
+    "jiazhi",
+    "yiran",
+    "guli",
+    "kepa",
+    "du",
+    "teng",
+    "chengjiu",
+    "jieguo",
+    "yaoqing",
+    "shijuan",
+    "ku",
+    "da",
+    "yuandan",
+    "bei",
+    "xijie",
+    "jinxing",
+    "zaijian",
+    "lijie",
+    "shuju",
+    "jiekou",
+    "zenmeyang",
+    "kongzhi",
+    "renyuan",
+    "chun",
+    "kan",
+    "chuzuche",
+    "jirou",
+    "yashua",
+    "caifang",
+    "guangda",
+    "biye",
+    "zhanmushinaishimisi",
+    "wangfan",
+    "dazhen",
+    "qilai",
+    "can",
+    "wushu",
+    "niandai",
+    "guzhang",
+    "wending",
+    "zhengzai",
+    "luxu",
+    "miaotiao",
+    "gei",
+    "jilingdai",
+    "qiu",
+    "zhenglun",
+    "dedao",
+    "pang",
+    "qubie",
+    "luobo",
+    "xiayu",
+    "liuyiertongjie",
+    "putong",
+    "milu",
+    "lianhuanhua",
+    "tudou",
+    "xiguan",
+    "weixia",
+    "shuliang",
+    "nvshi",
+    "gongtong",
+    "fangjian",
+    "yuan",
+    "junyun",
+    "baoyu",
+    "yangtai",
+    "xinqing",
+    "zhanguo",
+    "yingpin",
+    "guiju",
+    "nandao",
+    "ye",
+    "fengjing",
+    "biguan",
+    "naru",
+    "lingyu",
+    "liulan",
+    "qizhong",
+    "jingshen",
+    "jianglai",
+    "xuexi",
+    "banli",
+    "gexing",
+    "gouwu",
+    "taojiahuanjia",
+    "juede",
+    "qinzi",
+    "mama",
+    ";",
+    "jingran",
+    "bangwan",
+    "wanju",
+    "kongr",
+    "zuowei",
+    "feiji",
+    "beifang",
+    "quan",
+    "huabei",
+    "baozhi",
+    "yingzi",
+    "changqi",
+    "chanpin",
+    "mingming",
+    "ciyu",
+    "linlijinzhi",
+    "lianxu",
+    "chazi",
+    "qiaokeli",
+    "zhengge",
+    "mingtian",
+    "che",
+    "ri",
+    "zhuzi",
+    "hutu",
+    "dengyu",
+    "gebie",
+    "xiaoqi",
+    "fadou",
+    "xingge",
+    "xiaopengyou",
+    "cesuo",
+    "qifa",
+    "hunyin",
+    "naozhong",
+    "nainai",
+    "sanbu",
+    "fapiao",
+    "xianzhi",
+    "shoutao",
+    "niunai",
+    "bisai",
+    "keren",
+    "kua",
+    "mima",
+    "zancheng",
+    "putao",
+    "dafang",
+    "xihongshi",
+    "bacheng",
+    "yanjing",
+    "yizai",
+    "huida",
+    "miantiao",
+    "jingji",
+    "zhaor",
+    "bujin",
+    "zuoke",
+    "dang",
+    "jushuo",
+    "hen",
+    "tiwen",
+    "chen",
+    "nanguai",
+    "chaoguo",
+    "shoudujichang",
+    "caidan",
+    "biaoqing",
+    "ti",
+    "baocun",
+    "jiaoshi",
+    "waijiao",
+    "ping",
+    "yanse",
+    "feng",
+    "yingfu",
+    "huiyi",
+    "qingyi",
+    "long",
+    "fayuan",
+    "lichade`xiersi",
+    "weidao",
+    "gaibian",
+    "chujizhongxue",
+    "keguan",
+    "zhen",
+    "zhuiqiu",
+    "xiaoying",
+    "shirun",
+    "cui",
+    "jiandao",
+    "fengzhongdinglv",
+    "tushuguan",
+    "xianxiang",
+    "xuesheng",
+    "xianggua",
+    "mi",
+    "er",
+    "fangmian",
+    "yonggong",
+    "biaoxian",
+    "yushi",
+    "shuangfang",
+    "chifan",
+    "bianpao",
+    "maobing",
+    "shoubuliao",
+    "yuding",
+    "shenme",
+    "xiongmao",
+    "qiang",
+    "xiangdang",
+    "yiner",
+    "jingzi",
+    "cong",
+    "oumeng",
+    "weixiao",
+    "mingxian",
+    "fanfu",
+    "xiawu",
+    "youeryuan",
+    "huahua",
+    "keku",
+    "fuzhuang",
+    "xiaoxin",
+    "zhidao",
+    "gaoxing",
+    "qingchu",
+    "genben",
+    "zhigengniao",
+    "taijie",
+    "ge",
+    "fangshujia",
+    "rizi",
+    "nvxing",
+    "fen",
+    "pingjia",
+    "fuhe",
+    "gebo",
+    "shuijiao",
+    "haishi",
+    "goumai",
+    "gangcai",
+    "laideji",
+    "zhunshi",
+    "guanbi",
+    "boli",
+    "zhaogu",
+    "zhidai",
+    "wenhua",
+    "yingxiong",
+    "youhao",
+    "tongkuai",
+    "weilai",
+    "wenxue",
+    "hulianwang",
+    "shiqing",
+    "buzu",
+    "duimian",
+    "xianyan",
+    "xiangmu",
+    "fanying",
+    "jiao",
+    "shuxi",
+    "quefa",
+    "xingqi",
+    "tichang",
+    "mingling",
+    "ju",
+    "fengci",
+    "buzhou",
+    "taoqi",
+    "zeren",
+    "tigang",
+    "jiankang",
+    "dengjipai",
+    "jishu",
+    "xiaofei",
+    "shouru",
+    "yanhui",
+    "difang",
+    "gai",
+    "zhubu",
+    "ke",
+    "jingse",
+    "shengqi",
+    "weile",
+    "jichu",
+    "anquan",
+    "gongbu",
+    "keshi",
+    "lajitong",
+    "shuizhao",
+    "zhengti",
+    "buduan",
+    "xingcheng",
+    "meirenyu",
+    "qiji",
+    "daban",
+    "zhexue",
+    "xiaoshou",
+    "fanhang",
+    "jieyue",
+    "zeng",
+    "zhangbei",
+    "huainian",
+    "wenjian",
+    "li",
+    "chufa",
+    "zhishangtanbing",
+    "taobi",
+    "jinliang",
+    "qiao",
+    "suishen",
+    "chuse",
+    "jinshu",
+    "chuang",
+    "youming",
+    "zhenxi",
+    "weiyang",
+    "zhaokuo",
+    "budeliao",
+    "zhaochang",
+    "tongguo",
+    "na",
+    "taoyan",
+    "kaishui",
+    "ming",
+    "bixu",
+    "xueli",
+    "qijian",
+    "weixin",
+    "liangcha",
+    "piping",
+    "chidao",
+    "renmin",
+    "ze",
+    "wenwen",
+    "shangchang",
+    "kongqi",
+    "xinwen",
+    "gongjin",
+    "shui",
+    "liuxue",
+    "jinyu",
+    "xingshi",
+    "yubao",
+    "muqin",
+    "biaoge",
+    "changjiang",
+    "mianbao",
+    "zhaofugen",
+    "tongyi",
+    "yijing",
+    "zhongqiujie",
+    "kongpa",
+    "dou",
+    "yunqi",
+    "tuici",
+    "gongxian",
+    "aodaliya",
+    "zilu",
+    "zixi",
+    "shangye",
+    "liu",
+    "jichang",
+    "zhangxiaolong",
+    "guafeng",
+    "yijian",
+    "guina",
+    "shucai",
+    "biran",
+    "nei",
+    "yanchu",
+    "lilun",
+    "chushi",
+    "wanr",
+    "zhuanye",
+    "guozhi",
+    "xiao",
+    "guocheng",
+    "manyi",
+    "juhua",
+    "jinbu",
+    "zuotian",
+    "jiang",
+    "jiangluo",
+    "luoji",
+    "youlan",
+    "rili",
+    "wulun",
+    "lai",
+    "guandian",
+    "ciwai",
+    "pianyi",
+    "jianshen",
+    "hekuang",
+    "xiaohuozi",
+    "maihuochaidexiaonvhair",
+    "pengzi",
+    "fuxi",
+    "laibuji",
+    "chuli",
+    "yiji",
+    "fenshou",
+    "jiuhuche",
+    "yuangong",
+    "maoxian",
+    "hezi",
+    "ditie",
+    "gongzhu",
+    "buchong",
+    "danwei",
+    "huochengyan",
+    "manzu",
+    "jingcai",
+    "biru",
+    "kongxian",
+    "danxiaogui",
+    "cu",
+    "daoju",
+    "jiaxiang",
+    "ng",
+    "zuozhe",
+    "duo",
+    "banfa",
+    "xiande",
+    "faner",
+    "zhileng",
+    "shihou",
+    "weibi",
+    "weiqi",
+    "ganxingqu",
+    "gainian",
+    "qu",
+    "dazhe",
+    "daxiang",
+    "huiguniang",
+    "zhaodai",
+    "yangzi",
+    "senlin",
+    "shijinr",
+    "tongyang",
+    "gua",
+    "zhangwo",
+    "youju",
+    "qingsong",
+    "dianziyoujian",
+    "baoquan",
+    "yongyuan",
+    "zhonglei",
+    "yigong",
+    "yunyong",
+    "laobaixing",
+    "bijiao",
+    "youlai",
+    "zebei",
+    "xian",
+    "mang",
+    "xiaoqu",
+    "xiangzi",
+    "youguan",
+    "yuanyi",
+    "qinqi",
+    "guowang",
+    "liangkuai",
+    "feichang",
+    "jianshe",
+    "tigao",
+    "xingfen",
+    "haixian",
+    "bai",
+    "chuchai",
+    "shiqu",
+    "keai",
+    "yajin",
+    "jiaowang",
+    "yinyue",
+    "zang",
+    "shenduan",
+    "wazi",
+    "zhuangxiu",
+    "yuxi",
+    "ba",
+    "hai",
+    "jizhen",
+    "jialifuniyazhou",
+    "tiananmendong",
+    "ren",
+    "zhendui",
+    "zhuan",
+    "tiyuguan",
+    "binggan",
+    "ganhuoer",
+    "baozheng",
+    "huiyuanka",
+    "hong",
+    "fangxiang",
+    "yilai",
+    "yuanliang",
+    "nuli",
+    "bianhua",
+    "kaolv",
+    "juda",
+    "tengai",
+    "chengji",
+    "she",
+    "yinzi",
+    "qifen",
+    "liaotian",
+    "yiqian",
+    "sousuo",
+    "zhongguo",
+    "langdu",
+    "huaxue",
+    "gaosugonglu",
+    "nongmin",
+    "zhiyu",
+    "chengzan",
+    "yiqie",
+    "qizi",
+    "juxing",
+    "zanmen",
+    "yihan",
+    "daoxiangcun",
+    "jiaolian",
+    "hongse",
+    "xuxin",
+    "mingpian",
+    "xiangjingrubin",
+    "cheng",
+    "paobu",
+    "qinglv",
+    "titie",
+    "mao",
+    "benke",
+    "xiaoguo",
+    "chengfen",
+    "zhongdian",
+    "zimu",
+    "lirun",
+    "zhenghao",
+    "ruguo",
+    "reqing",
+    "zhi",
+    "zuichu",
+    "zhiwu",
+    "ayi",
+    "zhengshi",
+    "erqie",
+    "congci",
+    "woshou",
+    "likai",
+    "qianmian",
+    "neng",
+    "fandui",
+    "jianburufei",
+    "hetong",
+    "hangjia",
+    "shenqing",
+    "ca",
+    "ziyou",
+    "gongzi",
+    "jiejin",
+    "yizi",
+    "relie",
+    "xiaoshuo",
+    "diren",
+    "shide",
+    "chao",
+    "pingjing",
+    "bangzhu",
+    "bufen",
+    "chengdan",
+    "feizao",
+    "zhishao",
+    "yufang",
+    "anshi",
+    "yan",
+    "tuiguang",
+    "shi",
+    "pai",
+    "qita",
+    "xie",
+    "duanduanxuxu",
+    "jintian",
+    "lajiao",
+    "qingjia",
+    "xiongdi",
+    "bodong",
+    "shouhuoyuan",
+    "xunzhao",
+    "mofang",
+    "diaocha",
+    "fengsu",
+    "ziran",
+    "suo",
+    "rang",
+    "liuxie",
+    "shizai",
+    "song",
+    "zuhe",
+    "kending",
+    "xiangnian",
+    "richang",
+    "touzi",
+    "xingwei",
+    "huixin",
+    "suliaodai",
+    "fouren",
+    "anna",
+    "yinggai",
+    "zhantie",
+    "guniang",
+    "danshi",
+    "mo",
+    "zongtong",
+    "cailiao",
+    "kache",
+    "zhihui",
+    "zhuo",
+    "zhe",
+    "shatan",
+    "yiqi",
+    "jisheng",
+    "guloudajie",
+    "yukuai",
+    "yunshu",
+    "shazi",
+    "zhongliang",
+    "hunli",
+    "diluo",
+    "guojun",
+    "lengjing",
+    "gaishan",
+    "shanchu",
+    "zhaopian",
+    "lvyou",
+    "huilv",
+    "huxiang",
+    "menglong",
+    "zazhi",
+    "xingzhuang",
+    "gongsi",
+    "jinian",
+    "yanyuan",
+    "mianfei",
+    "butong",
+    "duishou",
+    "wanmei",
+    "yangshi",
+    "ranshao",
+    "shishang",
+    "jiejie",
+    "huodong",
+    "pei",
+    "lianxi",
+    "zhua",
+    "xiang",
+    "cizhi",
+    "rengran",
+    "cushi",
+    "kecheng",
+    "zanshi",
+    "jiyi",
+    "gao",
+    "huode",
+    "erhuan",
+    "kexi",
+    "buran",
+    "qihou",
+    "zuo",
+    "linghuo",
+    "yudao",
+    "jiehun",
+    "guangbo",
+    "xihan",
+    "jinkuai",
+    "caiyi",
+    "shijie",
+    "shuma",
+    "biaoda",
+    "fangfu",
+    "fenbu",
+    "jueding",
+    "zou",
+    "hangban",
+    "bianji",
+    "luyin",
+    "heying",
+    "cheku",
+    "benling",
+    "weirao",
+    "jiafang",
+    "shenfen",
+    "heshang",
+    "jihui",
+    "bijing",
+    "zhuanjia",
+    "chabuduo",
+    "minju",
+    "guanggao",
+    "shijian",
+    "biergaici",
+    "shouxu",
+    "jiaju",
+    "tongchang",
+    "bi",
+    "meitian",
+    "qingqu",
+    "hongjiu",
+    "bie",
+    "hua",
+    "e",
+    "huannanyugong",
+    "zhijiao",
+    "tiankong",
+    "daoli",
+    "dayue",
+    "chaxun",
+    "chongman",
+    "yinliao",
+    "jie",
+    "jige",
+    "chengguo",
+    "sushe",
+    "ganzao",
+    "jiaohuan",
+    "yong",
+    "tongqing",
+    "faming",
+    "xiyin",
+    "limi",
+    "huayi",
+    "donghailongwang",
+    "taidu",
+    "yang",
+    "peifu",
+    "qingjing",
+    "quanli",
+    "panwang",
+    "dajia",
+    "haoxiang",
+    "dong",
+    "guo",
+    "xiaoxi",
+    "ditu",
+    "que",
+    "zhuanzhu",
+    "guan",
+    "bing",
+    "luoshanji",
+    "yewu",
+    "qiangdiao",
+    "baifenzhi",
+    "xiaweiyi",
+    "xinxilan",
+    "ruanjian",
+    "yanzhong",
+    "daiti",
+    "shifu",
+    "meng",
+    "cankao",
+    "guaiwu",
+    "yingxiang",
+    ",",
+    "duche",
+    "mou",
+    "chuanzhen",
+    "duan",
+    "dun",
+    "huozhe",
+    "kaixin",
+    "shadingyu",
+    "aixi",
+    "shicha",
+    "jianmian",
+    "pijiu",
+    "houzi",
+    "shiye",
+    "jiaocai",
+    "zhaokai",
+    "beishang",
+    "yiwai",
+    "yundong",
+    "naixin",
+    "ningke",
+    "yangcheng",
+    "fumu",
+    "kuoda",
+    "qici",
+    "zhaoliang",
+    "baokuo",
+    "qun",
+    "daode",
+    "mifeng",
+    "fayan",
+    "pingan",
+    "duli",
+    "kunnan",
+    "haixiu",
+    "fachou",
+    "dayin",
+    "qinai",
+    "zhengzhi",
+    "yanjiang",
+    "rao",
+    "jianfei",
+    "rexin",
+    "huo",
+    "re",
+    "sheng",
+    "suizhe",
+    "benpao",
+    "xinshang",
+    "gang",
+    "jizhi",
+    "xigua",
+    "queshao",
+    "zhengfu",
+    "shoubi",
+    "daizi",
+    "chenggong",
+    "yangrou",
+    "siren",
+    "guomao",
+    "!",
+    "huochai",
+    "tiaopi",
+    "caiyong",
+    "linju",
+    "shoudao",
+    "zuowen",
+    "yin",
+    "weixiu",
+    "zhongyu",
+    "kesou",
+    "zongcai",
+    "bishi",
+    "fakuan",
+    "jiaoyu",
+    "shan",
+    "xishou",
+    "toufa",
+    "yiban",
+    "weida",
+    "chusheng",
+    "qingyuan",
+    "shushi",
+    "chengba",
+    "shouxian",
+    "yinshua",
+    "duanlian",
+    "zhengteng",
+    "pinyin",
+    "ganmao",
+    "xili",
+    "xingdong",
+    "sikao",
+    "xinli",
+    "zuobian",
+    "wanzhang",
+    "baoshe",
+    "bangmang",
+    "hanzi",
+    "naodai",
+    "zonghe",
+    "meiguo",
+    "chuangban",
+    "mianji",
+    "yueqi",
+    "qianwan",
+    "xingrong",
+    "tongku",
+    "kanwang",
+    "lingqian",
+    "daxing",
+    "youxiao",
+    "qianzheng",
+    "shengzi",
+    "shuoshi",
+    "dan",
+    "chongxin",
+    "ershou",
+    "shuxue",
+    "mingshengguji",
+    "daoshihou",
+    "xuduo",
+    "xiazai",
+    "zixin",
+    "dianshi",
+    "huopo",
+    "chuji",
+    "shuyu",
+    "jide",
+    "mengxiang",
+    "genzhi",
+    "fendou",
+    "lei",
+    "po",
+    "buxing",
+    "reng",
+    "duocang",
+    "jiesheng",
+    "zhizao",
+    "<",
+    "youle",
+    "yishu",
+    "weifan",
+    "peiyang",
+    "jiujiu",
+    "pochan",
+    "chufei",
+    "wenzi",
+    "biao",
+    "yidianr",
+    "kaifa",
+    "yingjie",
+    "wanggong",
+    "guoshi",
+    "dangdi",
+    "tongxue",
+    "wen",
+    "fuzhi",
+    "dongxi",
+    "fuwuyuan",
+    "lian",
+    "wanyi",
+    "bang",
+    "wuli",
+    "jielun",
+    "fangai",
+    "baozi",
+    "xuanbu",
+    "zhang",
+    "ling",
+    "tai",
+    "guanghua",
+    "mohu",
+    "piao",
+    "?",
+    "shengri",
+    "wangwang",
+    "lan",
+    "man",
+    "canyu",
+    "wenhuo",
+    "jinkou",
+    "huati",
+    "conglai",
+    "xunwen",
+    "qinguo",
+    "zaoshang",
+    "zhenshi",
+    "meishu",
+    "dangao",
+    "shunchang",
+    "ne",
+    "dangshi",
+    "zijue",
+    "miandui",
+    "bangongshi",
+    "dongzuo",
+    "xinxiangan",
+    "shuiguo",
+    "jin",
+    "zhunbei",
+    "zengjia",
+    "jilv",
+    "kanbuqi",
+    "xiangqi",
+    "xiaohua",
+    "jiedai",
+    "zhichi",
+    "huanjie",
+    "xiaojie",
+    "zhoudao",
+    "shike",
+    "menkan",
+    "dajiaodao",
+    "chu",
+    "hanxian",
+    "fan",
+    "wuhui",
+    "shiyong",
+    "jiage",
+    "qianglie",
+    "caoxin",
+    "qingchun",
+    "siheyuan",
+    "houlai",
+    "shangdang",
+    "zhigan",
+    "zhide",
+    "zhedie",
+    "miaoxie",
+    "dizhen",
+    "bili",
+    "shibangongbei",
+    "chuan",
+    "dalanqiu",
+    "jianqiang",
+    "liguang",
+    "guiding",
+    "biji",
+    "yinyuehui",
+    "niuzaiku",
+    "jiucan",
+    "hui",
+    "shuowenjiezi",
+    "xingzhi",
+    "zhipiao",
+    "maodun",
+    "baohan",
+    "yunnan",
+    "jianwei",
+    "jiuba",
+    "shibing",
+    "chongdianqi",
+    "shenmi",
+    "huangjin",
+    "chuxi",
+    "bowuguan",
+    "mudi",
+    "erduo",
+    "aoye",
+    "qingqiu",
+    "changge",
+    "ying",
+    "guanzhong",
+    "putonghua",
+    "muqian",
+    "yueliang",
+    "fenglang",
+    "gongfu",
+    "shengli",
+    "gongye",
+    "nianqing",
+    "conglin",
+    "dianqiu",
+    "daikuan",
+    "guangdongsheng",
+    "sudu",
+    "fang",
+    "guoqingjie",
+    "buguo",
+    "caichan",
+    "nianyu",
+    "tanpan",
+    "fuyin",
+    "gui",
+    "yexu",
+    "taiyang",
+    "fudao",
+    "lixi",
+    "jia",
+    "yibanlaishuo",
+    "yuanze",
+    "pashan",
+    "gancui",
+    "chuzhong",
+    "renlei",
+    "haolinshuo",
+    "qilang",
+    "jiaoao",
+    "shouzhi",
+    "liwu",
+    "doufu",
+    "goutong",
+    "jiaoxun",
+    "tigong",
+    "gongyu",
+    "nuan",
+    "ouzhou",
+    "duoyu",
+    "jianyi",
+    "zhuxi",
+    "jingchang",
+    "pailie",
+    "congming",
+    "laopo",
+    "heping",
+    "shanghai",
+    "shunxu",
+    "zhengqu",
+    "jiaru",
+    "pingzi",
+    "qinfen",
+    "lun",
+    "gu",
+    "guang",
+    "hupenghuanyou",
+    "wang",
+    "yingye",
+    "daoyu",
+    "lukou",
+    "pingwei",
+    "liuchen",
+    "dizhi",
+    "jiaban",
+    "dannierkaneiman",
+    "liuchuan",
+    "baogao",
+    "budan",
+    "chengli",
+    "xiuli",
+    "daiyu",
+    "duihuan",
+    "jiaoshou",
+    "liuchiping",
+    "benzhi",
+    "tianqi",
+    "gankuai",
+    "fangsong",
+    "zhiyou",
+    "dadianhua",
+    "cengjing",
+    "guyi",
+    "duihua",
+    "jiaodu",
+    "tongshi",
+    "ouer",
+    "xitong",
+    "guanjian",
+    "minzu",
+    "lianmang",
+    "fouze",
+    "weishengjian",
+    "tanhua",
+    "waiwainiuniu",
+    "peichang",
+    "dei",
+    "masazhusaizhou",
+    "yanchang",
+    "shun",
+    "guai",
+    "chongfu",
+    "shenhua",
+    "nengyuan",
+    "yidong",
+    "pixie",
+    "wo",
+    "yangxiong",
+    "yao",
+    "huzhao",
+    "shangxinyuemu",
+    "jiaqian",
+    "dawei",
+    "guojia",
+    "fangzi",
+    "jieri",
+    "jieshou",
+    "fouding",
+    "congmang",
+    "xiaomai",
+    "shiwu",
+    "dianti",
+    "changcheng",
+    "ciji",
+    "diyi",
+    "youjiu",
+    "shulian",
+    "tianjinweishi",
+    "xiaoxiao",
+    "cuowu",
+    "xianzai",
+    "wangzhan",
+    "lumiaier",
+    "jing",
+    "jiezhang",
+    "xinyongka",
+    "xiangxi",
+    "hefa",
+    "shou",
+    "haiguan",
+    "luan",
+    "zhexuejia",
+    "cunhuo",
+    "huxi",
+    "juesai",
+    "chitang",
+    "kefu",
+    "baogui",
+    "xuewen",
+    "xiuxian",
+    "yongtu",
+    "duiyu",
+    "chikui",
+    "jinji",
+    "dasha",
+    "zan",
+    "chenmo",
+    "wenzhang",
+    "anjing",
+    "zhizhao",
+    "zu",
+    "chuangzao",
+    "panghuang",
+    "zunshou",
+    "cujin",
+    "yimin",
+    "xiaozhang",
+    "liaobuqi",
+    "pi",
+    "genji",
+    "yonggan",
+    "tiyu",
+    "shihuiyan",
+    "jianchi",
+    "zhaoji",
+    "zanmai",
+    "zuoyong",
+    "duokui",
+    "zhuantou",
+    "fei",
+    "maokong",
+    "liji",
+    "zhaifeng",
+    "xiangshou",
+    "shenshangxiansu",
+    "wanshang",
+    "chaoji",
+    "dao",
+    "diwei",
+    "dian",
+    "mai",
+    "sunyue",
+    "wenming",
+    "diannao",
+    "le",
+    "houguo",
+    "jiu",
+    "zhuzhang",
+    "shouji",
+    "zhenzheng",
+    "renminbi",
+    "woshi",
+    "pingchang",
+    "aihu",
+    "zuzhi",
+    "zhunque",
+    "zicong",
+    "wan",
+    "xiaojing",
+    "zuoyou",
+    "duoshao",
+    "gaodang",
+    "xiaolv",
+    "waidi",
+    "shebei",
+    "jieshi",
+    "yudafu",
+    "tixing",
+    "gou",
+    "chibang",
+    "zhufu",
+    "zhuanxin",
+    "renao",
+    "xihuan",
+    "jingshang",
+    "dengji",
+    "zhongxun",
+    "turan",
+    "nver",
+    "wendu",
+    "shitou",
+    "shoudu",
+    "huan",
+    "yibeizi",
+    "fuze",
+    "changjiangdaqiao",
+    "chuanbo",
+    "teshu",
+    "you",
+    "peixun",
+    "zhenduan",
+    "mosheng",
+    "yilun",
+    "geren",
+    "tuixiu",
+    "fuzhong",
+    "anan",
+    "duguo",
+    "huifu",
+    "moter",
+    "jishi",
+    "tiaowu",
+    "fenbie",
+    "huanjing",
+    "dongwu",
+    "jizhong",
+    "zijin",
+    "zhongxin",
+    "gaikuo",
+    "dique",
+    "chuzi",
+    "xingxiang",
+    "zhongjie",
+    "gaizheng",
+    "chuanghu",
+    "xianhua",
+    "zhijin",
+    "bu",
+    "lingdao",
+    "nanmian",
+    "zhai",
+    "yinhang",
+    "elie",
+    "fandian",
+    "kun",
+    "biaozhi",
+    "aixin",
+    "jihe",
+    "zhoumo",
+    "zuihao",
+    "ditan",
+    "xiong",
+    "tiandi",
+    "ganjin",
+    "lin",
+    "yuanwang",
+    "dianxin",
+    "kele",
+    "tuzi",
+    "chai",
+    "anzhuang",
+    "buguan",
+    "lao",
+    "jiangjin",
+    "jingli",
+    "liru",
+    "zhui",
+    "shanyu",
+    "tiaozheng",
+    "jiemu",
+    "ganxie",
+    "shangxin",
+    "bushao",
+    "dili",
+    "xianshi",
+    "fangdong",
+    "haochi",
+    "bozi",
+    "limao",
+    "bimian",
+    "didao",
+    "zuijin",
+    "keyi",
+    "zheng",
+    "shiwang",
+    "quxiao",
+    "guji",
+    "zhuming",
+    "pengyou",
+    "chixu",
+    "shuofu",
+    "youzha",
+    "zai",
+    "wai",
+    "guanjun",
+    "sha",
+    "zucheng",
+    "nvhai",
+    "hei",
+    "wenti",
+    "panduan",
+    "taitai",
+    "kaoya",
+    "kai",
+    "zhanzheng",
+    "suoyou",
+    "bingxiang",
+    "ya",
+    "xueshu",
+    "bianlun",
+    "shufu",
+    "xing",
+    "lieche",
+    "renhe",
+    "danchun",
+    "zhouwei",
+    "yufa",
+    "danwu",
+    "duibuqi",
+    "wuzi",
+    "kaoshi",
+    "nong",
+    "zhengque",
+    "gongchang",
+    "fengge",
+    "qichuang",
+    "caochang",
+    "shunli",
+    "zunjing",
+    "gaoji",
+    "zhuguan",
+    "kuan",
+    "raner",
+    "buyaojin",
+    "jiegou",
+    "laojia",
+    "duidai",
+    "funv",
+    "tianzhen",
+    "kaishi",
+    "qushi",
+    "chengken",
+    "zuojia",
+    "neirong",
+    "kafei",
+    "xianmu",
+    "kongzi",
+    "xinku",
+    "ludi",
+    "tiaojian",
+    "gongzuo",
+    "shimian",
+    "jiandan",
+    "zhuce",
+    "xianglian",
+    "dianying",
+    "zhaopin",
+    ">",
+    "jiaohua",
+    "huaiyi",
+    "jingying",
+    "dagai",
+    "guanxi",
+    "peng",
+    "shuru",
+    "guofen",
+    "fasheng",
+    "tao",
+    "caihong",
+    "wangzi",
+    "hao",
+    "haipa",
+    "jilu",
+    "guize",
+    "bofang",
+    "shunbian",
+    "guaibude",
+    "canting",
+    "guangfan",
+    "xianjin",
+    "duzi",
+    "en",
+    "gongyuan",
+    "jinan",
+    "chijing",
+    "juhui",
+    "baoqian",
+    "kanjian",
+    "qinqie",
+    "zhuti",
+    "tan",
+    "chunjie",
+    "jijie",
+    "huochezhan",
+    "laodong",
+    "shengzhang",
+    "niujindaxue",
+    "fuza",
+    "fangbian",
+    "shebude",
+    "gongneng",
+    "shehui",
+    "zihao",
+    "canjia",
+    "mulu",
+    "haoqi",
+    "rensheng",
+    "wenrou",
+    "renshi",
+    "shaozi",
+    "waimian",
+    "shidai",
+    "shoushang",
+    "fujin",
+    "fangqi",
+    "bujiande",
+    "tanshuai",
+    "xuexiao",
+    "meiti",
+    "yinjia",
+    "rongyi",
+    "jiashi",
+    "ji",
+    "fuqin",
+    "erzi",
+    "panjiayuan",
+    "sheying",
+    "dasuan",
+    "shu",
+    "qingzhuangnian",
+    "zhidu",
+    "gutou",
+    "dazhaohu",
+    "chizi",
+    "jinguan",
+    "jiangyou",
+    "zuozhan",
+    "gaige",
+    "qunian",
+    "daibiao",
+    "qi",
+    "zi",
+    "zhu",
+    "weihai",
+    "xianran",
+    "qidai",
+    "wanzheng",
+    "changshi",
+    "mingbai",
+    "zhengli",
+    "chengzhang",
+    "mafan",
+    "sihu",
+    "ci",
+    "guwu",
+    "fa",
+    "motuoche",
+    "bieren",
+    "yiyuan",
+    "rukou",
+    ":",
+    "jianku",
+    "faxian",
+    "jiehe",
+    "jidong",
+    "gandong",
+    "yihou",
+    "tese",
+    "youbian",
+    "gaobie",
+    "guoran",
+    "cidian",
+    "guancha",
+    "xue",
+    "yingyong",
+    "nar",
+    "shangban",
+    "pilao",
+    "gun",
+    "jiaoliu",
+    "gezi",
+    "zhan",
+    "chayi",
+    "shangliang",
+    "huichen",
+    "mingan",
+    "heli",
+    "zonggong",
+    "guomin",
+    "tiqian",
+    "peihe",
+    "yingyang",
+    "jubei",
+    "shencai",
+    "shibai",
+    "sui",
+    "lv",
+    "dating",
+    "jimang",
+    "yingjun",
+    "an",
+    "hanjia",
+    "tengxun",
+    "xinzang",
+    "shanzi",
+    "meixi",
+    "zongshi",
+    "gongju",
+    "zuihou",
+    "duixiang",
+    "\\",
+    "shangwu",
+    "weiyu",
+    "yansu",
+    "huoyue",
+    "chengwei",
+    "lixiang",
+    "zhengming",
+    "jidan",
+    "xiangsi",
+    "jiezhi",
+    "qiwen",
+    "pingguo",
+    "reai",
+    "chongfen",
+    "shujia",
+    "conger",
+    "chaojia",
+    "men",
+    "houmian",
+    "wu",
+    "daxue",
+    "pingshi",
+    "yinwei",
+    "tixian",
+    "hexin",
+    "zaogao",
+    "shenbian",
+    "shuaya",
+    "poqie",
+    "make",
+    "meide",
+    "aiqing",
+    "biaoshi",
+    "xuanchuan",
+    "siji",
+    "mingque",
+    "jiawu",
+    "qingwa",
+    "lianpo",
+    "chou",
+    "weiyi",
+    "guanglin",
+    "jiran",
+    "baoguo",
+    "cihui",
+    "chong",
+    "pifu",
+    "kongjian",
+    "dandiao",
+    "piqi",
+    "yinsu",
+    "gongping",
+    "langan",
+    "hudie",
+    "shifei",
+    "zhiyuanzhe",
+    "jile",
+    "chouti",
+    "fenfen",
+    "bachengr",
+    "gushi",
+    "guanzhu",
+    "diqu",
+    "maoyi",
+    "yinci",
+    "wufa",
+    "ertong",
+    "touming",
+    "nenggan",
+    "shimao",
+    "jingdian",
+    "chengxin",
+    "pingpangqiu",
+    "zaocheng",
+    "keting",
+    "xiaoji",
+    "jiangdi",
+    "tuchu",
+    "rencai",
+    "guangxian",
+    "ganshou",
+    "xinfeng",
+    "kaifang",
+    "yuehui",
+    "jiaoqu",
+    "renzhen",
+    "napa",
+    "maikefeng",
+    "duibi",
+    "zengzhang",
+    ".",
+    "gangtie",
+    "chengshi",
+    "gewu",
+    "daoda",
+    "shoushi",
+    "gupiao",
+    "biaomian",
+    "wangqiu",
+    "riqi",
+    "mishu",
+    "libaitian",
+    "langfei",
+    "nuanhuo",
+    "lingshi",
+    "guangheju",
+    "haili",
+    "mingpai",
+    "youyi",
+    "diu",
+    "wenxuejia",
+    "huoxu",
+    "zher",
+    "xiangtong",
+    "mingxing",
+    "meiguanxi",
+    "jiben",
+    "guding",
+    "zhuangao",
+    "zhendong",
+    "shoubiao",
+    "liyi",
+    "chedi",
+    "danxin",
+    "yali",
+    "cuoshi",
+    "jiancha",
+    "huanbao",
+    "pingjun",
+    "shifen",
+    "wennuan",
+    "sunzi",
+    "ban",
+    "haoma",
+    "mimi",
+    "ding",
+    "<shuowenjiezi>",
+    "congqian",
+    "guanli",
+    "wuzhi",
+    "dangran",
+    "zhou",
+    "pianmian",
+    "chaju",
+    "jieduan",
+    "suibian",
+    "lichadexiersi",
+    "shandian",
+    "tihui",
+    "shamo",
+    "shichang",
+    "gebi",
+    "yuedu",
+    "yiwei",
+    "jiejue",
+    "mingyun",
+    "lizi",
+    "nianji",
+    "meihao",
+    "qingshi",
+    "cankui",
+    "xiwang",
+    "xueqi",
+    "sitanfu",
+    "jimo",
+    "yinxiang",
+    "xiansheng",
+    "fu",
+    "biaoyang",
+    "xiangxiang",
+    "daan",
+    "huanxiang",
+    "weizhi",
+    "binguan",
+    "dangxin",
+    "youyu",
+    "baoliu",
+    "qingxu",
+    "tuichi",
+    "mubiao",
+    "changtu",
+    "yumaoqiu",
+    "jinnian",
+    "kuzi",
+    "fazhan",
+    "haoke",
+    "youxi",
+    "junshi",
+    "zhuang",
+    "renwu",
+    "ganbei",
+    "jinzhi",
+    "chang",
+    "xizao",
+    "shixian",
+    "qifei",
+    "juexin",
+    "jingyan",
+    "shenke",
+    "wanshan",
+    "xiangzheng",
+    "shiji",
+    "fenxi",
+    "yihuier",
+    "lunwen",
+    "meimao",
+    "wangluo",
+    "sa",
+    "lia",
+    "kejian",
+    "mashang",
+    "fangzong",
+    "de",
+    "duifang",
+    "gen",
+    "buru",
+    "benlai",
+    "shuohua",
+    "shang",
+    "maozi",
+    "niao",
+    "kuai",
+    "<nahan>",
+    "tuan",
+    "yuanlai",
+    "youdian",
+    "fabiao",
+    "jiangzuo",
+    "suiran",
+    "yaoqiu",
+    "chuyuan",
+    "zhujian",
+    "zhuren",
+    "huilai",
+    "shifou",
+    "jiechu",
+    "biaodian",
+    "xiugai",
+    "qiye",
+    "chule",
+    "women",
+    "tebie",
+    "tangangqin",
+    "mingzi",
+    "buan",
+    "qiumi",
+    "falv",
+    "like",
+    "fanxing",
+    "ben",
+    "shuobuding",
+    "qingzhu",
+    "secai",
+    "guangming",
+    "liaojie",
+    "ni",
+    "zhanxian",
+    "ouzhouhuanjingbaohushu",
+    "xiangchu",
+    "chuanshuo",
+    "lingwai",
+    "jisuan",
+    "jiashe",
+    "kuo",
+    "liulei",
+    "xingxing",
+    "yibian",
+    "sangzi",
+    "fenxiang",
+    "zao",
+    "yunxu",
+    "qianxu",
+    "dengdai",
+    "chansheng",
+    "boshi",
+    "cao",
+    "hushi",
+    "liuxing",
+    "zenmeban",
+    "pian",
+    "zongzhi",
+    "ruhe",
+    "duome",
+    "zhong",
+    "kuaile",
+    "daoqian",
+    "fangshi",
+    "liang",
+    "congshi",
+    "youmo",
+    "nin",
+    "di",
+    "shuo",
+    "queshi",
+    "chengren",
+    "shiying",
+    "<feinimoshu>",
+    "tedian",
+    "yizhi",
+    "chouyan",
+    "weishenme",
+    "pubian",
+    "ting",
+    "wanquan",
+    "chengdu",
+    "youyong",
+    "fengkuang",
+    "shengwuzhong",
+    "liliang",
+    "tongzhi",
+    "wenhou",
+    "duanxin",
+    "tezheng",
+    "zhuangshi",
+    "queding",
+    "fada",
+    "juzi",
+    "gongren",
+    "youxiu",
+    "shengchan",
+    "zixingche",
+    "lifadian",
+    "ziji",
+    "zanmei",
+    "liangshi",
+    "shidang",
+    "dasao",
+    "daochu",
+    "qite",
+    "bumen",
+    "kouwei",
+    "guoji",
+    "si",
+    "gongkai",
+    "canguan",
+    "bingdu",
+    "zixun",
+    "hezuo",
+    "fangan",
+    "ziyuan",
+    "huanghe",
+    "xiaochi",
+    "yifu",
+    "chunqiu",
+    "xunlian",
+    "xia",
+    "aidisheng",
+    "chui",
+    "fukuan",
+    "zongsuan",
+    "zaihai",
+    "xiangfan",
+    "tiyan",
+    "youli",
+    "houhui",
+    "lifa",
+    "jinshiweikai",
+    "lunliu",
+    "gugu",
+    "shuoming",
+    "shizi",
+    "xiju",
+    "zhengchang",
+    "weijin",
+    "toulan",
+    "bao",
+    "zhaosanmusi",
+    "zhuhe",
+    "xi",
+];
+
+// Indexes of PINYIN_KEYS in alphabetical order of the keys. Keys that
+// start with the same prefix (like "guoz" for 果汁 guozhi) are next to
+// each other, so completions of a partly typed key are a binary search
+// away.
+pub static PINYIN_SORTED: &[u16] = &[
+    1436,  // !
+    246,  // (
+    284,  // )
+    1375,  // ,
+    2276,  // .
+    2135,  // :
+    709,  // ;
+    1515,  // <
+    2482,  // <feinimoshu>
+    434,  // <maihuochaidexiaonvhaier>
+    2408,  // <nahan>
+    2322,  // <shuowenjiezi>
+    2032,  // >
+    1550,  // ?
+    2185,  // \
+    216,  // `
+    419,  // a
+    181,  // ai
+    2532,  // aidisheng
+    132,  // aihao
+    1848,  // aihu
+    2217,  // aiqing
+    1384,  // aixi
+    1921,  // aixin
+    2175,  // an
+    1891,  // anan
+    1803,  // anjing
+    1220,  // anna
+    166,  // anpai
+    898,  // anquan
+    1187,  // anshi
+    453,  // anwei
+    457,  // anzhao
+    1935,  // anzhuang
+    970,  // aodaliya
+    1635,  // aoye
+    1157,  // ayi
+    1093,  // ba
+    468,  // baba
+    759,  // bacheng
+    2245,  // bachengr
+    1081,  // bai
+    1364,  // baifenzhi
+    2318,  // ban
+    1035,  // banfa
+    1538,  // bang
+    1473,  // bangmang
+    1571,  // bangongshi
+    711,  // bangwan
+    1181,  // bangzhu
+    702,  // banli
+    49,  // banshi
+    119,  // banye
+    2551,  // bao
+    91,  // baobei
+    84,  // baochi
+    779,  // baocun
+    357,  // baodao
+    1712,  // baogao
+    1792,  // baogui
+    2229,  // baoguo
+    1623,  // baohan
+    611,  // baohu
+    1402,  // baokuo
+    2362,  // baoliu
+    223,  // baoming
+    2061,  // baoqian
+    1058,  // baoquan
+    1472,  // baoshe
+    513,  // baotuquan
+    234,  // baoxian
+    685,  // baoyu
+    323,  // baoyuan
+    1104,  // baozheng
+    719,  // baozhi
+    1542,  // baozi
+    271,  // bawo
+    633,  // bei
+    716,  // beifang
+    1,  // beiguan
+    365,  // beijing
+    167,  // beijingshifandaxue
+    1392,  // beishang
+    340,  // beizi
+    2435,  // ben
+    1145,  // benke
+    2402,  // benlai
+    1301,  // benling
+    1423,  // benpao
+    1720,  // benzhi
+    1317,  // bi
+    54,  // bian
+    1111,  // bianhua
+    1297,  // bianji
+    1984,  // bianlun
+    814,  // bianpao
+    1524,  // biao
+    1291,  // biaoda
+    2422,  // biaodian
+    958,  // biaoge
+    2283,  // biaomian
+    380,  // biaoming
+    777,  // biaoqing
+    2218,  // biaoshi
+    810,  // biaoxian
+    497,  // biaoyan
+    2354,  // biaoyang
+    1920,  // biaozhi
+    233,  // biaozhun
+    336,  // bici
+    1321,  // bie
+    275,  // bier`gaici
+    2132,  // bieren
+    1313,  // biergaici
+    694,  // biguan
+    1614,  // biji
+    1064,  // bijiao
+    505,  // bijiben
+    1307,  // bijing
+    1607,  // bili
+    1954,  // bimian
+    1360,  // bing
+    2521,  // bingdu
+    1102,  // binggan
+    496,  // bingjiling
+    446,  // bingqie
+    2359,  // binguan
+    1981,  // bingxiang
+    981,  // biran
+    1026,  // biru
+    751,  // bisai
+    1448,  // bishi
+    938,  // bixu
+    571,  // biyao
+    650,  // biye
+    386,  // bizi
+    1199,  // bodong
+    2049,  // bofang
+    856,  // boli
+    2460,  // boshi
+    1632,  // bowuguan
+    1952,  // bozi
+    1912,  // bu
+    2430,  // buan
+    1021,  // buchong
+    508,  // bucuo
+    1713,  // budan
+    424,  // budebu
+    931,  // budeliao
+    904,  // buduan
+    1182,  // bufen
+    1936,  // buguan
+    1655,  // buguo
+    2090,  // bujiande
+    73,  // bujiasisuo
+    766,  // bujin
+    105,  // bukeqi
+    2515,  // bumen
+    475,  // bunaifan
+    1279,  // buran
+    2401,  // buru
+    1947,  // bushao
+    1257,  // butong
+    1510,  // buxing
+    2008,  // buyaojin
+    880,  // buzhou
+    867,  // buzu
+    1170,  // ca
+    17,  // cai
+    1656,  // caichan
+    776,  // caidan
+    648,  // caifang
+    2043,  // caihong
+    1227,  // cailiao
+    379,  // caiqu
+    526,  // caishikou
+    1288,  // caiyi
+    1439,  // caiyong
+    655,  // can
+    2520,  // canguan
+    2078,  // canjia
+    1372,  // cankao
+    2346,  // cankui
+    2052,  // canting
+    1555,  // canyu
+    2461,  // cao
+    2001,  // caochang
+    1597,  // caoxin
+    257,  // ce
+    146,  // ceng
+    1726,  // cengjing
+    741,  // cesuo
+    467,  // ceyan
+    87,  // cha
+    1309,  // chabuduo
+    1934,  // chai
+    2329,  // chaju
+    2378,  // chang
+    1767,  // changcheng
+    1637,  // changge
+    959,  // changjiang
+    1880,  // changjiangdaqiao
+    721,  // changqi
+    2122,  // changshi
+    2366,  // changtu
+    722,  // chanpin
+    2459,  // chansheng
+    1179,  // chao
+    774,  // chaoguo
+    1831,  // chaoji
+    2202,  // chaojia
+    72,  // chaoshi
+    1329,  // chaxun
+    2157,  // chayi
+    727,  // chazi
+    731,  // che
+    2308,  // chedi
+    582,  // chehuo
+    1300,  // cheku
+    772,  // chen
+    1140,  // cheng
+    1459,  // chengba
+    1183,  // chengdan
+    2490,  // chengdu
+    1147,  // chengfen
+    1432,  // chenggong
+    1334,  // chengguo
+    225,  // chenghu
+    1115,  // chengji
+    626,  // chengjiu
+    2016,  // chengken
+    1714,  // chengli
+    2480,  // chengren
+    2278,  // chengshi
+    33,  // chengshou
+    344,  // chengshu
+    2190,  // chengwei
+    2257,  // chengxin
+    108,  // chengxu
+    15,  // chengyu
+    1128,  // chengzan
+    2125,  // chengzhang
+    40,  // chengzuo
+    1801,  // chenmo
+    266,  // chenshan
+    158,  // chexiang
+    179,  // chi
+    1864,  // chibang
+    944,  // chidao
+    813,  // chifan
+    2059,  // chijing
+    1797,  // chikui
+    1790,  // chitang
+    1964,  // chixu
+    375,  // chizao
+    2108,  // chizi
+    2231,  // chong
+    1628,  // chongdianqi
+    2199,  // chongfen
+    1746,  // chongfu
+    1330,  // chongman
+    42,  // chongwu
+    1492,  // chongxin
+    2225,  // chou
+    19,  // choulou
+    2243,  // chouti
+    552,  // chouxiang
+    2485,  // chouyan
+    1590,  // chu
+    1609,  // chuan
+    1881,  // chuanbo
+    926,  // chuang
+    1478,  // chuangban
+    1909,  // chuanghu
+    390,  // chuanglian
+    1806,  // chuangzao
+    615,  // chuanran
+    2445,  // chuanshuo
+    121,  // chuantong
+    1378,  // chuanzhen
+    563,  // chuban
+    1082,  // chuchai
+    918,  // chufa
+    68,  // chufang
+    1522,  // chufei
+    330,  // chuguo
+    2533,  // chui
+    1502,  // chuji
+    793,  // chujizhongxue
+    154,  // chukou
+    2425,  // chule
+    1012,  // chuli
+    643,  // chun
+    2066,  // chunjie
+    2529,  // chunqiu
+    79,  // chunyou
+    924,  // chuse
+    1456,  // chusheng
+    985,  // chushi
+    1631,  // chuxi
+    74,  // chuxian
+    2416,  // chuyuan
+    1670,  // chuzhong
+    1905,  // chuzi
+    645,  // chuzuche
+    2128,  // ci
+    2146,  // cidian
+    2230,  // cihui
+    1768,  // ciji
+    1003,  // ciwai
+    80,  // ciyao
+    724,  // ciyu
+    1269,  // cizhi
+    824,  // cong
+    1160,  // congci
+    2201,  // conger
+    1559,  // conglai
+    1648,  // conglin
+    1763,  // congmang
+    1689,  // congming
+    2323,  // congqian
+    2474,  // congshi
+    1029,  // cu
+    252,  // cucao
+    799,  // cui
+    1809,  // cujin
+    587,  // cun
+    1787,  // cunhuo
+    479,  // cunzai
+    149,  // cuo
+    2311,  // cuoshi
+    1774,  // cuowu
+    1271,  // cushi
+    110,  // cuxin
+    631,  // da
+    2356,  // daan
+    908,  // daban
+    559,  // dadao
+    1725,  // dadianhua
+    757,  // dafang
+    2036,  // dagai
+    437,  // dagong
+    298,  // dai
+    2114,  // daibiao
+    414,  // daifu
+    1650,  // daikuan
+    1369,  // daiti
+    397,  // daiyan
+    1716,  // daiyu
+    1431,  // daizi
+    1351,  // dajia
+    1589,  // dajiaodao
+    1610,  // dalanqiu
+    1491,  // dan
+    1989,  // danchun
+    2234,  // dandiao
+    603,  // dandu
+    768,  // dang
+    1565,  // dangao
+    1530,  // dangdi
+    2326,  // dangran
+    1568,  // dangshi
+    2360,  // dangxin
+    1710,  // dannierkaneiman
+    576,  // danren
+    1224,  // danshi
+    1022,  // danwei
+    1992,  // danwu
+    1028,  // danxiaogui
+    2309,  // danxin
+    177,  // danyuan
+    1832,  // dao
+    2513,  // daochu
+    2280,  // daoda
+    1404,  // daode
+    289,  // daodi
+    1030,  // daoju
+    1327,  // daoli
+    458,  // daomei
+    2471,  // daoqian
+    1496,  // daoshihou
+    1134,  // daoxiangcun
+    185,  // daoyan
+    227,  // daoyou
+    1704,  // daoyu
+    255,  // daozhi
+    536,  // dapenti
+    198,  // darao
+    2512,  // dasao
+    1799,  // dasha
+    377,  // dashiguan
+    2102,  // dasuan
+    2172,  // dating
+    1757,  // dawei
+    1046,  // daxiang
+    1486,  // daxing
+    2206,  // daxue
+    1412,  // dayin
+    413,  // daying
+    1328,  // dayue
+    2107,  // dazhaohu
+    1045,  // dazhe
+    653,  // dazhen
+    2398,  // de
+    667,  // dedao
+    1741,  // dei
+    584,  // deng
+    2458,  // dengdai
+    1870,  // dengji
+    885,  // dengjipai
+    735,  // dengyu
+    302,  // deyi
+    2477,  // di
+    1834,  // dian
+    509,  // dianchi
+    1838,  // diannao
+    1649,  // dianqiu
+    1500,  // dianshi
+    462,  // diantai
+    1766,  // dianti
+    1931,  // dianxin
+    2030,  // dianying
+    1057,  // dianziyoujian
+    481,  // diao
+    1203,  // diaocha
+    1955,  // didao
+    175,  // didi
+    142,  // didian
+    890,  // difang
+    1948,  // dili
+    1241,  // diluo
+    2321,  // ding
+    52,  // diqiu
+    2248,  // diqu
+    1904,  // dique
+    1177,  // diren
+    1925,  // ditan
+    1019,  // ditie
+    1356,  // ditu
+    2295,  // diu
+    1833,  // diwei
+    1769,  // diyi
+    1606,  // dizhen
+    1708,  // dizhi
+    1353,  // dong
+    1344,  // donghailongwang
+    334,  // donghuapian
+    1899,  // dongwu
+    1534,  // dongxi
+    1572,  // dongzuo
+    966,  // dou
+    1677,  // doufu
+    624,  // du
+    1379,  // duan
+    1194,  // duanduanxuxu
+    1462,  // duanlian
+    2497,  // duanxin
+    1376,  // duche
+    1892,  // duguo
+    25,  // dui
+    2274,  // duibi
+    1993,  // duibuqi
+    2011,  // duidai
+    2399,  // duifang
+    1728,  // duihua
+    1717,  // duihuan
+    868,  // duimian
+    1258,  // duishou
+    2184,  // duixiang
+    1796,  // duiyu
+    1408,  // duli
+    1380,  // dun
+    1034,  // duo
+    1512,  // duocang
+    1822,  // duokui
+    2468,  // duome
+    1855,  // duoshao
+    1684,  // duoyu
+    196,  // dute
+    2055,  // duzi
+    1323,  // e
+    1917,  // elie
+    2056,  // en
+    297,  // enai
+    807,  // er
+    1634,  // erduo
+    1277,  // erhuan
+    1159,  // erqie
+    1493,  // ershou
+    2252,  // ertong
+    2099,  // erzi
+    2130,  // fa
+    2412,  // fabiao
+    1411,  // fachou
+    2501,  // fada
+    738,  // fadou
+    432,  // fahui
+    1449,  // fakuan
+    2432,  // falv
+    1340,  // faming
+    1592,  // fan
+    416,  // fanchuan
+    1918,  // fandian
+    1165,  // fandui
+    1037,  // faner
+    828,  // fanfu
+    1653,  // fang
+    1541,  // fangai
+    2524,  // fangan
+    2073,  // fangbian
+    1950,  // fangdong
+    554,  // fangfa
+    1292,  // fangfu
+    682,  // fangjian
+    808,  // fangmian
+    2089,  // fangqi
+    2472,  // fangshi
+    842,  // fangshujia
+    1723,  // fangsong
+    1107,  // fangxiang
+    44,  // fangxin
+    1759,  // fangzi
+    2397,  // fangzong
+    911,  // fanhang
+    81,  // fannao
+    38,  // fanrong
+    116,  // fanwei
+    2434,  // fanxing
+    372,  // fanyi
+    871,  // fanying
+    478,  // fanzheng
+    747,  // fapiao
+    212,  // fashao
+    2041,  // fasheng
+    2137,  // faxian
+    1406,  // fayan
+    789,  // fayuan
+    2370,  // fazhan
+    1824,  // fei
+    1076,  // feichang
+    281,  // feihua
+    715,  // feiji
+    580,  // feinimoshu
+    1184,  // feizao
+    845,  // fen
+    1897,  // fenbie
+    1293,  // fenbu
+    1507,  // fendou
+    2244,  // fenfen
+    784,  // feng
+    879,  // fengci
+    101,  // fengfu
+    1999,  // fengge
+    693,  // fengjing
+    2492,  // fengkuang
+    1643,  // fenglang
+    1204,  // fengsu
+    447,  // fengxian
+    801,  // fengzhongdinglv
+    231,  // fenpei
+    382,  // fense
+    1014,  // fenshou
+    2388,  // fenxi
+    2454,  // fenxiang
+    32,  // fenzhong
+    1762,  // fouding
+    1219,  // fouren
+    1736,  // fouze
+    2353,  // fu
+    1663,  // fudao
+    847,  // fuhe
+    2088,  // fujin
+    2534,  // fukuan
+    1398,  // fumu
+    2012,  // funv
+    2098,  // fuqin
+    1535,  // fuwuyuan
+    1010,  // fuxi
+    1659,  // fuyin
+    2072,  // fuza
+    1879,  // fuze
+    1533,  // fuzhi
+    1890,  // fuzhong
+    833,  // fuzhuang
+    891,  // gai
+    792,  // gaibian
+    2112,  // gaige
+    381,  // gaijin
+    1903,  // gaikuo
+    1043,  // gainian
+    1244,  // gaishan
+    1908,  // gaizheng
+    598,  // gan
+    2376,  // ganbei
+    1669,  // gancui
+    118,  // gandao
+    2140,  // gandong
+    1425,  // gang
+    852,  // gangcai
+    2277,  // gangtie
+    1103,  // ganhuoer
+    469,  // ganhuor
+    572,  // ganji
+    1928,  // ganjin
+    253,  // ganjing
+    228,  // ganjue
+    1722,  // gankuai
+    1465,  // ganmao
+    520,  // ganqing
+    2266,  // ganshou
+    75,  // ganxiang
+    1945,  // ganxie
+    1042,  // ganxingqu
+    1336,  // ganzao
+    1275,  // gao
+    2144,  // gaobie
+    1856,  // gaodang
+    2004,  // gaoji
+    190,  // gaosu
+    1125,  // gaosugonglu
+    836,  // gaoxing
+    841,  // ge
+    2337,  // gebi
+    736,  // gebie
+    848,  // gebo
+    441,  // gege
+    663,  // gei
+    2400,  // gen
+    838,  // genben
+    428,  // geng
+    1814,  // genji
+    594,  // genju
+    1506,  // genzhi
+    1888,  // geren
+    205,  // gewai
+    2279,  // gewu
+    703,  // gexing
+    2155,  // gezi
+    899,  // gongbu
+    1998,  // gongchang
+    165,  // gongchengshi
+    1644,  // gongfu
+    63,  // gonggongqiche
+    951,  // gongjin
+    2182,  // gongju
+    2519,  // gongkai
+    443,  // gongli
+    2075,  // gongneng
+    2237,  // gongping
+    2503,  // gongren
+    1253,  // gongsi
+    681,  // gongtong
+    155,  // gongxi
+    969,  // gongxian
+    1646,  // gongye
+    1681,  // gongyu
+    2057,  // gongyuan
+    1020,  // gongzhu
+    1172,  // gongzi
+    2025,  // gongzuo
+    1863,  // gou
+    445,  // goucheng
+    851,  // goumai
+    1678,  // goutong
+    704,  // gouwu
+    1699,  // gu
+    1053,  // gua
+    977,  // guafeng
+    471,  // guahao
+    1745,  // guai
+    2051,  // guaibude
+    483,  // guaiwan
+    1373,  // guaiwu
+    1359,  // guan
+    855,  // guanbi
+    2147,  // guancha
+    1002,  // guandian
+    1700,  // guang
+    1285,  // guangbo
+    398,  // guangchang
+    649,  // guangda
+    1651,  // guangdongsheng
+    2053,  // guangfan
+    1311,  // guanggao
+    2291,  // guangheju
+    1547,  // guanghua
+    2227,  // guanglin
+    2439,  // guangming
+    385,  // guangpan
+    2265,  // guangxian
+    1733,  // guanjian
+    1969,  // guanjun
+    2324,  // guanli
+    224,  // guannian
+    2037,  // guanxi
+    502,  // guanxin
+    547,  // guanyu
+    1639,  // guanzhong
+    2247,  // guanzhu
+    394,  // guanzi
+    131,  // gudai
+    601,  // gudian
+    2303,  // guding
+    2544,  // gugu
+    1660,  // gui
+    1613,  // guiding
+    690,  // guiju
+    585,  // guilv
+    4,  // guimo
+    979,  // guina
+    192,  // guitai
+    2048,  // guize
+    1961,  // guji
+    491,  // guke
+    622,  // guli
+    1235,  // guloudajie
+    2153,  // gun
+    1223,  // guniang
+    1354,  // guo
+    990,  // guocheng
+    2040,  // guofen
+    2517,  // guoji
+    1758,  // guojia
+    1242,  // guojun
+    1435,  // guomao
+    2163,  // guomin
+    127,  // guoqi
+    1654,  // guoqingjie
+    557,  // guoqu
+    2145,  // guoran
+    1529,  // guoshi
+    1074,  // guowang
+    988,  // guozhi
+    2282,  // gupiao
+    2246,  // gushi
+    2106,  // gutou
+    2129,  // guwu
+    1727,  // guyi
+    658,  // guzhang
+    597,  // ha
+    1094,  // hai
+    1784,  // haiguan
+    2292,  // haili
+    2046,  // haipa
+    850,  // haishi
+    1080,  // haixian
+    1410,  // haixiu
+    159,  // haiyang
+    411,  // haizi
+    320,  // han
+    1296,  // hangban
+    1168,  // hangjia
+    76,  // hangye
+    2176,  // hanjia
+    1591,  // hanxian
+    456,  // hanyi
+    168,  // hanyu
+    1474,  // hanzi
+    2045,  // hao
+    1951,  // haochi
+    37,  // haochu
+    354,  // haohua
+    2371,  // haoke
+    1672,  // haolinshuo
+    2319,  // haoma
+    2080,  // haoqi
+    1352,  // haoxiang
+    393,  // he
+    507,  // hebi
+    1782,  // hefa
+    538,  // hege
+    1973,  // hei
+    535,  // heiban
+    321,  // heixingxing
+    1006,  // hekuang
+    2161,  // heli
+    770,  // hen
+    1691,  // heping
+    1305,  // heshang
+    220,  // heshi
+    1167,  // hetong
+    2210,  // hexin
+    1299,  // heying
+    1018,  // hezi
+    2523,  // hezuo
+    1106,  // hong
+    1320,  // hongjiu
+    1136,  // hongse
+    339,  // hou
+    484,  // houbei
+    1840,  // houguo
+    2540,  // houhui
+    1600,  // houlai
+    2204,  // houmian
+    1388,  // houzi
+    46,  // hu
+    1322,  // hua
+    718,  // huabei
+    831,  // huahua
+    53,  // huai
+    915,  // huainian
+    2034,  // huaiyi
+    528,  // huaiyun
+    1877,  // huan
+    2313,  // huanbao
+    256,  // huang
+    2526,  // huanghe
+    1630,  // huangjin
+    363,  // huangzhang
+    1584,  // huanjie
+    1898,  // huanjing
+    1324,  // huannanyugong
+    2357,  // huanxiang
+    156,  // huanying
+    28,  // huasheng
+    1558,  // huati
+    1124,  // huaxue
+    1343,  // huayi
+    2239,  // hudie
+    378,  // huguosi
+    1618,  // hui
+    2159,  // huichen
+    762,  // huida
+    1893,  // huifu
+    1047,  // huiguniang
+    2419,  // huilai
+    1248,  // huilv
+    1217,  // huixin
+    786,  // huiyi
+    1105,  // huiyuanka
+    865,  // hulianwang
+    1240,  // hunli
+    743,  // hunyin
+    1419,  // huo
+    230,  // huoban
+    1437,  // huochai
+    1023,  // huochengyan
+    2068,  // huochezhan
+    1276,  // huode
+    1264,  // huodong
+    1501,  // huopo
+    2297,  // huoxu
+    2189,  // huoyue
+    1381,  // huozhe
+    1701,  // hupenghuanyou
+    401,  // huran
+    2462,  // hushi
+    193,  // hushuo
+    12,  // hutong
+    734,  // hutu
+    1788,  // huxi
+    1249,  // huxiang
+    1754,  // huzhao
+    2097,  // ji
+    1665,  // jia
+    1709,  // jiaban
+    319,  // jiabin
+    1303,  // jiafang
+    1595,  // jiage
+    1315,  // jiaju
+    1096,  // jialifuniyazhou
+    486,  // jian
+    444,  // jianbang
+    1166,  // jianburufei
+    2312,  // jiancha
+    1818,  // jianchi
+    2027,  // jiandan
+    800,  // jiandao
+    1417,  // jianfei
+    995,  // jiang
+    2262,  // jiangdi
+    1938,  // jiangjin
+    498,  // jiangjiu
+    700,  // jianglai
+    996,  // jiangluo
+    2110,  // jiangyou
+    2413,  // jiangzuo
+    421,  // jianju
+    107,  // jianjue
+    884,  // jiankang
+    2136,  // jianku
+    215,  // jianli
+    1386,  // jianmian
+    308,  // jianpan
+    1611,  // jianqiang
+    417,  // jianqing
+    450,  // jianshao
+    1077,  // jianshe
+    1005,  // jianshen
+    1625,  // jianwei
+    1685,  // jianyi
+    415,  // jianzhi
+    62,  // jianzhu
+    872,  // jiao
+    1674,  // jiaoao
+    1390,  // jiaocai
+    1729,  // jiaodu
+    2033,  // jiaohua
+    1337,  // jiaohuan
+    374,  // jiaoji
+    1135,  // jiaolian
+    2154,  // jiaoliu
+    2270,  // jiaoqu
+    780,  // jiaoshi
+    1718,  // jiaoshou
+    16,  // jiaoshui
+    522,  // jiaotong
+    1086,  // jiaowang
+    1679,  // jiaoxun
+    1450,  // jiaoyu
+    147,  // jiaozi
+    1756,  // jiaqian
+    1695,  // jiaru
+    2448,  // jiashe
+    2096,  // jiashi
+    89,  // jiating
+    2222,  // jiawu
+    1031,  // jiaxiang
+    349,  // jiayou
+    113,  // jiayouzhan
+    620,  // jiazhi
+    310,  // jiazhuang
+    222,  // jiazi
+    2302,  // jiben
+    975,  // jichang
+    897,  // jichu
+    2193,  // jidan
+    564,  // jidao
+    1504,  // jide
+    2139,  // jidong
+    1332,  // jie
+    2421,  // jiechu
+    1582,  // jiedai
+    2330,  // jieduan
+    2009,  // jiegou
+    627,  // jieguo
+    2138,  // jiehe
+    1284,  // jiehun
+    1263,  // jiejie
+    1173,  // jiejin
+    2340,  // jiejue
+    639,  // jiekou
+    1540,  // jielun
+    1944,  // jiemu
+    1760,  // jieri
+    426,  // jieshao
+    1513,  // jiesheng
+    1860,  // jieshi
+    1761,  // jieshou
+    106,  // jieshu
+    912,  // jieyue
+    1779,  // jiezhang
+    39,  // jiezhe
+    2195,  // jiezhi
+    1333,  // jige
+    1922,  // jihe
+    100,  // jihu
+    436,  // jihua
+    1306,  // jihui
+    346,  // jiji
+    2067,  // jijie
+    2242,  // jile
+    150,  // jilei
+    128,  // jilie
+    664,  // jilingdai
+    2047,  // jilu
+    1578,  // jilv
+    2173,  // jimang
+    2350,  // jimo
+    1575,  // jin
+    2058,  // jinan
+    993,  // jinbu
+    617,  // jindai
+    1778,  // jing
+    1025,  // jingcai
+    403,  // jingcha
+    1687,  // jingchang
+    134,  // jingchengsuozhi
+    2256,  // jingdian
+    588,  // jingguo
+    764,  // jingji
+    267,  // jingju
+    1939,  // jingli
+    710,  // jingran
+    894,  // jingse
+    1869,  // jingshang
+    699,  // jingshen
+    2109,  // jinguan
+    2383,  // jingyan
+    2035,  // jingying
+    209,  // jingzheng
+    823,  // jingzi
+    1254,  // jinian
+    1798,  // jinji
+    1557,  // jinkou
+    1287,  // jinkuai
+    422,  // jinli
+    921,  // jinliang
+    2368,  // jinnian
+    163,  // jinshen
+    2542,  // jinshiweikai
+    925,  // jinshu
+    1195,  // jintian
+    635,  // jinxing
+    954,  // jinyu
+    355,  // jinzhang
+    2377,  // jinzhi
+    482,  // jiqi
+    2228,  // jiran
+    646,  // jirou
+    1234,  // jisheng
+    1895,  // jishi
+    886,  // jishu
+    2447,  // jisuan
+    235,  // jiti
+    1841,  // jiu
+    1626,  // jiuba
+    1617,  // jiucan
+    1015,  // jiuhuche
+    332,  // jiujing
+    1520,  // jiujiu
+    303,  // jixu
+    1274,  // jiyi
+    435,  // jizhe
+    1095,  // jizhen
+    1426,  // jizhi
+    1900,  // jizhong
+    878,  // ju
+    324,  // juan
+    36,  // juban
+    2167,  // jubei
+    1113,  // juda
+    706,  // juede
+    1294,  // jueding
+    405,  // juedui
+    1789,  // juesai
+    35,  // juese
+    2382,  // juexin
+    992,  // juhua
+    2060,  // juhui
+    65,  // jujue
+    103,  // julebu
+    57,  // juli
+    2373,  // junshi
+    684,  // junyun
+    288,  // juran
+    769,  // jushuo
+    268,  // juti
+    1131,  // juxing
+    2502,  // juzi
+    1228,  // kache
+    2019,  // kafei
+    1978,  // kai
+    1526,  // kaifa
+    2268,  // kaifang
+    138,  // kaimushi
+    2014,  // kaishi
+    936,  // kaishui
+    133,  // kaiwanxiao
+    1382,  // kaixin
+    644,  // kan
+    1579,  // kanbuqi
+    454,  // kanfa
+    2062,  // kanjian
+    1484,  // kanwang
+    555,  // kao
+    1112,  // kaolv
+    1995,  // kaoshi
+    1977,  // kaoya
+    893,  // ke
+    1084,  // keai
+    1272,  // kecheng
+    558,  // keen
+    1791,  // kefu
+    794,  // keguan
+    122,  // keji
+    2395,  // kejian
+    384,  // kekao
+    832,  // keku
+    1932,  // kele
+    581,  // kelian
+    1212,  // kending
+    326,  // keneng
+    623,  // kepa
+    752,  // keren
+    900,  // keshi
+    1446,  // kesou
+    2260,  // keting
+    1278,  // kexi
+    82,  // kexue
+    1957,  // keyi
+    515,  // kong
+    2233,  // kongjian
+    965,  // kongpa
+    949,  // kongqi
+    713,  // kongr
+    180,  // kongtiao
+    1027,  // kongxian
+    641,  // kongzhi
+    2021,  // kongzi
+    292,  // kou
+    2516,  // kouwei
+    630,  // ku
+    753,  // kua
+    2407,  // kuai
+    546,  // kuaiji
+    2470,  // kuaile
+    499,  // kuaizi
+    2006,  // kuan
+    602,  // kuang
+    473,  // kuangquanshui
+    18,  // kuazhang
+    1919,  // kun
+    221,  // kunchong
+    1409,  // kunnan
+    562,  // kunrao
+    2449,  // kuo
+    1399,  // kuoda
+    2369,  // kuzi
+    294,  // la
+    1001,  // lai
+    1011,  // laibuji
+    853,  // laideji
+    137,  // laizi
+    213,  // laji
+    1196,  // lajiao
+    901,  // lajitong
+    1553,  // lan
+    2238,  // langan
+    1123,  // langdu
+    2288,  // langfei
+    350,  // langman
+    1937,  // lao
+    1063,  // laobaixing
+    85,  // laoban
+    2069,  // laodong
+    191,  // laohu
+    2010,  // laojia
+    23,  // laolao
+    1690,  // laopo
+    527,  // laoshe
+    251,  // laoshi
+    590,  // laoshu
+    7,  // laoying
+    1839,  // le
+    152,  // leguan
+    1508,  // lei
+    34,  // leixing
+    285,  // leng
+    342,  // lengdan
+    1243,  // lengjing
+    917,  // li
+    2394,  // lia
+    1536,  // lian
+    448,  // lianai
+    2473,  // liang
+    942,  // liangcha
+    124,  // lianghao
+    1075,  // liangkuai
+    2510,  // liangshi
+    610,  // lianhe
+    675,  // lianhuanhua
+    1735,  // lianmang
+    2224,  // lianpo
+    1266,  // lianxi
+    726,  // lianxu
+    1812,  // liaobuqi
+    2440,  // liaojie
+    1119,  // liaotian
+    229,  // liaotianr
+    2287,  // libaitian
+    790,  // lichade`xiersi
+    2332,  // lichadexiersi
+    1987,  // lieche
+    2541,  // lifa
+    2507,  // lifadian
+    1612,  // liguang
+    60,  // lihai
+    451,  // lihun
+    1826,  // liji
+    637,  // lijie
+    1162,  // likai
+    2433,  // like
+    2494,  // liliang
+    984,  // lilun
+    1953,  // limao
+    1342,  // limi
+    1929,  // lin
+    1545,  // ling
+    1913,  // lingdao
+    1282,  // linghuo
+    369,  // lingjian
+    1485,  // lingqian
+    2290,  // lingshi
+    2446,  // lingwai
+    696,  // lingyu
+    1440,  // linju
+    725,  // linlijinzhi
+    10,  // linshi
+    95,  // liqi
+    1940,  // liru
+    1150,  // lirun
+    115,  // lishi
+    974,  // liu
+    1707,  // liuchen
+    1719,  // liuchiping
+    1711,  // liuchuan
+    697,  // liulan
+    2450,  // liulei
+    276,  // liuli
+    396,  // liushou
+    1208,  // liuxie
+    2463,  // liuxing
+    953,  // liuxue
+    672,  // liuyiertongjie
+    1676,  // liwu
+    1664,  // lixi
+    2191,  // lixiang
+    2307,  // liyi
+    315,  // liyong
+    183,  // liyou
+    2342,  // lizi
+    788,  // long
+    301,  // lou
+    383,  // lu
+    1785,  // luan
+    2023,  // ludi
+    1705,  // lukou
+    1777,  // lumiaier
+    1698,  // lun
+    2543,  // lunliu
+    2390,  // lunwen
+    670,  // luobo
+    358,  // luohou
+    997,  // luoji
+    1361,  // luoshanji
+    573,  // luqu
+    661,  // luxu
+    50,  // luxun
+    1298,  // luyin
+    2171,  // lv
+    341,  // lvshi
+    366,  // lvxing
+    1247,  // lvyou
+    283,  // ma
+    2126,  // mafan
+    214,  // mahu
+    1835,  // mai
+    86,  // maibuliqi
+    1008,  // maihuochaidexiaonvhair
+    2273,  // maikefeng
+    2215,  // make
+    708,  // mama
+    1554,  // man
+    1068,  // mang
+    99,  // mangren
+    485,  // mantou
+    991,  // manyi
+    1024,  // manzu
+    1144,  // mao
+    815,  // maobing
+    1622,  // maodun
+    274,  // maojin
+    1825,  // maokong
+    1017,  // maoxian
+    2249,  // maoyi
+    2405,  // maozi
+    1742,  // masazhusaizhou
+    2396,  // mashang
+    109,  // mei
+    2216,  // meide
+    2301,  // meiguanxi
+    1477,  // meiguo
+    2344,  // meihao
+    94,  // meili
+    2391,  // meimao
+    423,  // meimei
+    906,  // meirenyu
+    1564,  // meishu
+    120,  // meitan
+    2093,  // meiti
+    1318,  // meitian
+    2180,  // meixi
+    592,  // meiyou
+    2203,  // men
+    1371,  // meng
+    1250,  // menglong
+    1505,  // mengxiang
+    1588,  // menkan
+    806,  // mi
+    960,  // mianbao
+    1570,  // miandui
+    1256,  // mianfei
+    1479,  // mianji
+    92,  // mianlin
+    322,  // mianshi
+    763,  // miantiao
+    438,  // miao
+    662,  // miaotiao
+    1605,  // miaoxie
+    539,  // mifan
+    1405,  // mifeng
+    674,  // milu
+    754,  // mima
+    2320,  // mimi
+    937,  // ming
+    2160,  // mingan
+    2123,  // mingbai
+    877,  // mingling
+    723,  // mingming
+    2293,  // mingpai
+    1138,  // mingpian
+    2221,  // mingque
+    1495,  // mingshengguji
+    730,  // mingtian
+    827,  // mingxian
+    2300,  // mingxing
+    2341,  // mingyun
+    2429,  // mingzi
+    1310,  // minju
+    1734,  // minzu
+    280,  // miqie
+    2286,  // mishu
+    1225,  // mo
+    1202,  // mofang
+    1548,  // mohu
+    1886,  // mosheng
+    1894,  // moter
+    2131,  // motuoche
+    1377,  // mou
+    2365,  // mubiao
+    1633,  // mudi
+    2079,  // mulu
+    1641,  // muqian
+    957,  // muqin
+    314,  // mutou
+    934,  // na
+    745,  // nainai
+    1395,  // naixin
+    543,  // nali
+    523,  // nan
+    691,  // nandao
+    773,  // nanguai
+    208,  // nanguo
+    1914,  // nanmian
+    143,  // nanshou
+    1475,  // naodai
+    744,  // naozhong
+    2272,  // napa
+    2150,  // nar
+    695,  // naru
+    1567,  // ne
+    982,  // nei
+    521,  // neibu
+    135,  // neike
+    2018,  // neirong
+    492,  // nen
+    1164,  // neng
+    2254,  // nenggan
+    325,  // nengli
+    1748,  // nengyuan
+    1032,  // ng
+    2441,  // ni
+    55,  // nian
+    657,  // niandai
+    2343,  // nianji
+    388,  // nianling
+    1647,  // nianqing
+    1657,  // nianyu
+    2406,  // niao
+    299,  // nimen
+    2476,  // nin
+    1396,  // ningke
+    2071,  // niujindaxue
+    750,  // niunai
+    1616,  // niuzaiku
+    1996,  // nong
+    550,  // nongcun
+    1126,  // nongmin
+    51,  // nongye
+    1682,  // nuan
+    2289,  // nuanhuo
+    1110,  // nuli
+    331,  // nuobeierjiang
+    427,  // nv
+    1873,  // nver
+    1972,  // nvhai
+    265,  // nvhair
+    680,  // nvshi
+    844,  // nvxing
+    1731,  // ouer
+    825,  // oumeng
+    71,  // ouran
+    1683,  // ouzhou
+    2443,  // ouzhouhuanjingbaohushu
+    1191,  // pai
+    618,  // paidui
+    1688,  // pailie
+    1975,  // panduan
+    668,  // pang
+    157,  // pangbian
+    1807,  // panghuang
+    2100,  // panjiayuan
+    1350,  // panwang
+    129,  // panzi
+    1141,  // paobu
+    1668,  // pashan
+    1265,  // pei
+    203,  // pei`zhansen
+    1740,  // peichang
+    1347,  // peifu
+    2165,  // peihe
+    1884,  // peixun
+    1519,  // peiyang
+    194,  // peizhansen
+    570,  // peizhun
+    367,  // pen
+    2038,  // peng
+    1963,  // pengyou
+    1009,  // pengzi
+    619,  // penzi
+    1813,  // pi
+    2465,  // pian
+    2328,  // pianmian
+    1004,  // pianyi
+    1549,  // piao
+    531,  // piaoliang
+    2232,  // pifu
+    1387,  // pijiu
+    2152,  // pilao
+    616,  // pindao
+    782,  // ping
+    1407,  // pingan
+    1847,  // pingchang
+    400,  // pingdeng
+    123,  // pingfang
+    2197,  // pingguo
+    153,  // pingheng
+    846,  // pingjia
+    1180,  // pingjing
+    2314,  // pingjun
+    2258,  // pingpangqiu
+    2207,  // pingshi
+    1706,  // pingwei
+    1696,  // pingzi
+    1464,  // pinyin
+    943,  // piping
+    2235,  // piqi
+    1750,  // pixie
+    1509,  // po
+    1521,  // pochan
+    338,  // pohuai
+    2214,  // poqie
+    2487,  // pubian
+    756,  // putao
+    673,  // putong
+    1640,  // putonghua
+    2115,  // qi
+    29,  // qian
+    201,  // qianbao
+    537,  // qianbi
+    820,  // qiang
+    1363,  // qiangdiao
+    1596,  // qianglie
+    1163,  // qianmian
+    258,  // qiantu
+    1481,  // qianwan
+    2457,  // qianxu
+    1488,  // qianzheng
+    922,  // qiao
+    728,  // qiaokeli
+    277,  // qiaomiao
+    21,  // qiaoqiao
+    2000,  // qichuang
+    1400,  // qici
+    2120,  // qidai
+    356,  // qie
+    742,  // qifa
+    2381,  // qifei
+    1118,  // qifen
+    117,  // qiguai
+    1280,  // qihou
+    907,  // qiji
+    940,  // qijian
+    360,  // qiju
+    654,  // qilai
+    1673,  // qilang
+    1413,  // qinai
+    1697,  // qinfen
+    606,  // qing
+    837,  // qingchu
+    1598,  // qingchun
+    392,  // qingdan
+    1197,  // qingjia
+    1348,  // qingjing
+    362,  // qingkuang
+    1142,  // qinglv
+    1636,  // qingqiu
+    1319,  // qingqu
+    352,  // qingshaonian
+    2345,  // qingshi
+    1056,  // qingsong
+    1561,  // qinguo
+    2223,  // qingwa
+    2363,  // qingxu
+    787,  // qingyi
+    1457,  // qingyuan
+    293,  // qingyuanshu
+    2437,  // qingzhu
+    2104,  // qingzhuangnian
+    1073,  // qinqi
+    2063,  // qinqie
+    707,  // qinzi
+    232,  // qiong
+    470,  // qishi
+    1192,  // qita
+    2514,  // qite
+    665,  // qiu
+    2431,  // qiumi
+    2196,  // qiwen
+    2424,  // qiye
+    516,  // qiyou
+    477,  // qiyu
+    698,  // qizhong
+    1130,  // qizi
+    1044,  // qu
+    717,  // quan
+    607,  // quanbu
+    1349,  // quanli
+    254,  // quanmian
+    348,  // quanshenguanzhu
+    669,  // qubie
+    1357,  // que
+    182,  // quedian
+    2500,  // queding
+    874,  // quefa
+    102,  // queren
+    1428,  // queshao
+    2479,  // queshi
+    1403,  // qun
+    2113,  // qunian
+    279,  // qunzi
+    2015,  // qushi
+    1960,  // quxiao
+    2007,  // raner
+    1207,  // rang
+    317,  // ranhou
+    1261,  // ranshao
+    1416,  // rao
+    1420,  // re
+    2198,  // reai
+    1175,  // relie
+    1098,  // ren
+    1867,  // renao
+    61,  // renbuzhu
+    2264,  // rencai
+    1511,  // reng
+    1270,  // rengran
+    1988,  // renhe
+    187,  // renkou
+    1671,  // renlei
+    945,  // renmin
+    1845,  // renminbi
+    305,  // renming
+    2081,  // rensheng
+    2083,  // renshi
+    351,  // renwei
+    2375,  // renwu
+    642,  // renyuan
+    2271,  // renzhen
+    1153,  // reqing
+    1418,  // rexin
+    732,  // ri
+    1214,  // richang
+    195,  // richeng
+    8,  // riji
+    999,  // rili
+    2285,  // riqi
+    47,  // riyongpin
+    843,  // rizi
+    2095,  // rongyi
+    551,  // ruan
+    1367,  // ruanjian
+    1152,  // ruguo
+    2467,  // ruhe
+    347,  // rujin
+    2134,  // rukou
+    0,  // ruo
+    296,  // ruwei
+    2393,  // sa
+    249,  // san
+    746,  // sanbu
+    2453,  // sangzi
+    2438,  // secai
+    1050,  // senlin
+    1970,  // sha
+    1383,  // shadingyu
+    540,  // shafa
+    519,  // shai
+    2335,  // shamo
+    1451,  // shan
+    1245,  // shanchu
+    2333,  // shandian
+    2404,  // shang
+    2151,  // shangban
+    948,  // shangchang
+    1601,  // shangdang
+    217,  // shangdian
+    1692,  // shanghai
+    2158,  // shangliang
+    307,  // shangpin
+    506,  // shangwang
+    2186,  // shangwu
+    1946,  // shangxin
+    1755,  // shangxinyuemu
+    973,  // shangye
+    556,  // shanliang
+    1942,  // shanyu
+    2179,  // shanzi
+    218,  // shao
+    90,  // shaowei
+    579,  // shaoxu
+    2084,  // shaozi
+    1232,  // shatan
+    1238,  // shazi
+    1116,  // she
+    1859,  // shebei
+    2074,  // shebude
+    2076,  // shehui
+    608,  // shei
+    67,  // sheji
+    583,  // shen
+    2212,  // shenbian
+    2168,  // shencai
+    1089,  // shenduan
+    1304,  // shenfen
+    1421,  // sheng
+    464,  // shengbing
+    2505,  // shengchan
+    250,  // shengdiao
+    560,  // shengdong
+    262,  // shenghuo
+    1645,  // shengli
+    463,  // shenglve
+    488,  // shengming
+    895,  // shengqi
+    1551,  // shengri
+    2493,  // shengwuzhong
+    604,  // shengyi
+    567,  // shengyin
+    2070,  // shengzhang
+    1489,  // shengzi
+    1747,  // shenhua
+    2384,  // shenke
+    818,  // shenme
+    1629,  // shenmi
+    1169,  // shenqing
+    1829,  // shenshangxiansu
+    69,  // shenti
+    465,  // shenyuan
+    126,  // shenzhi
+    56,  // sheru
+    282,  // sheshi
+    2101,  // sheying
+    161,  // sheyingshi
+    1190,  // shi
+    2169,  // shibai
+    1608,  // shibangongbei
+    1627,  // shibing
+    1385,  // shicha
+    2336,  // shichang
+    2086,  // shidai
+    2511,  // shidang
+    1178,  // shide
+    2240,  // shifei
+    2315,  // shifen
+    2420,  // shifou
+    1370,  // shifu
+    370,  // shihe
+    1039,  // shihou
+    64,  // shihua
+    1817,  // shihuiyan
+    2387,  // shiji
+    1312,  // shijian
+    1289,  // shijie
+    272,  // shijin
+    1051,  // shijinr
+    629,  // shijuan
+    1587,  // shike
+    2255,  // shimao
+    2026,  // shimian
+    595,  // shiqi
+    866,  // shiqing
+    1083,  // shiqu
+    798,  // shirun
+    1262,  // shishang
+    574,  // shishi
+    1875,  // shitou
+    1959,  // shiwang
+    1765,  // shiwu
+    490,  // shixi
+    2380,  // shixian
+    533,  // shiyan
+    1389,  // shiye
+    2481,  // shiying
+    1594,  // shiyong
+    1209,  // shizai
+    263,  // shizhong
+    2546,  // shizi
+    1783,  // shou
+    1430,  // shoubi
+    2306,  // shoubiao
+    816,  // shoubuliao
+    1441,  // shoudao
+    1876,  // shoudu
+    775,  // shoudujichang
+    425,  // shougong
+    2,  // shouhuo
+    1200,  // shouhuoyuan
+    1843,  // shouji
+    408,  // shouju
+    48,  // shouming
+    888,  // shouru
+    2087,  // shoushang
+    2281,  // shoushi
+    70,  // shoushu
+    530,  // shousui
+    749,  // shoutao
+    1460,  // shouxian
+    1314,  // shouxu
+    1675,  // shouzhi
+    2103,  // shu
+    211,  // shuai
+    22,  // shuaidao
+    439,  // shuang
+    812,  // shuangfang
+    2213,  // shuaya
+    566,  // shubiao
+    980,  // shucai
+    1985,  // shufu
+    952,  // shui
+    1574,  // shuiguo
+    849,  // shuijiao
+    494,  // shuiping
+    902,  // shuizhao
+    2200,  // shujia
+    638,  // shuju
+    1771,  // shulian
+    679,  // shuliang
+    1290,  // shuma
+    1744,  // shun
+    2050,  // shunbian
+    1566,  // shunchang
+    2002,  // shunli
+    1693,  // shunxu
+    2478,  // shuo
+    2436,  // shuobuding
+    1965,  // shuofu
+    2403,  // shuohua
+    2545,  // shuoming
+    1490,  // shuoshi
+    1619,  // shuowenjiezi
+    2039,  // shuru
+    1458,  // shushi
+    309,  // shushu
+    873,  // shuxi
+    1494,  // shuxue
+    1503,  // shuyu
+    30,  // shuzi
+    2518,  // si
+    304,  // sichou
+    431,  // sihao
+    1599,  // siheyuan
+    2127,  // sihu
+    2220,  // siji
+    1468,  // sikao
+    1434,  // siren
+    2349,  // sitanfu
+    264,  // sixiang
+    1210,  // song
+    1121,  // sousuo
+    524,  // suan
+    1652,  // sudu
+    2170,  // sui
+    2331,  // suibian
+    2414,  // suiran
+    923,  // suishen
+    599,  // suishi
+    329,  // suishou
+    1422,  // suizhe
+    1218,  // suliaodai
+    11,  // sunshi
+    1836,  // sunyue
+    2317,  // sunzi
+    1206,  // suo
+    96,  // suoduan
+    328,  // suowei
+    449,  // suoyi
+    1980,  // suoyou
+    1335,  // sushe
+    164,  // ta
+    1546,  // tai
+    1345,  // taidu
+    840,  // taijie
+    404,  // taijiquan
+    1976,  // taitai
+    1662,  // taiyang
+    2065,  // tan
+    31,  // tang
+    2428,  // tangangqin
+    1738,  // tanhua
+    1658,  // tanpan
+    2091,  // tanshuai
+    2042,  // tao
+    920,  // taobi
+    705,  // taojiahuanjia
+    376,  // taolun
+    881,  // taoqi
+    935,  // taoyan
+    2427,  // tebie
+    2483,  // tedian
+    625,  // teng
+    1114,  // tengai
+    2177,  // tengxun
+    2142,  // tese
+    1882,  // teshu
+    2498,  // tezheng
+    778,  // ti
+    239,  // tian
+    1097,  // tiananmendong
+    1927,  // tiandi
+    1772,  // tianjinweishi
+    1326,  // tiankong
+    1721,  // tianqi
+    371,  // tianran
+    2013,  // tianzhen
+    542,  // tiao
+    2024,  // tiaojian
+    1438,  // tiaopi
+    1896,  // tiaowu
+    261,  // tiaozhan
+    1943,  // tiaozheng
+    876,  // tichang
+    883,  // tigang
+    1078,  // tigao
+    1680,  // tigong
+    2334,  // tihui
+    316,  // timu
+    2488,  // ting
+    565,  // tingshuo
+    2164,  // tiqian
+    1143,  // titie
+    771,  // tiwen
+    2209,  // tixian
+    1862,  // tixing
+    2538,  // tiyan
+    1816,  // tiyu
+    1101,  // tiyuguan
+    459,  // tizi
+    295,  // tizuqiu
+    1316,  // tongchang
+    933,  // tongguo
+    1483,  // tongku
+    862,  // tongkuai
+    1339,  // tongqing
+    1730,  // tongshi
+    1531,  // tongxue
+    1052,  // tongyang
+    962,  // tongyi
+    2495,  // tongzhi
+    545,  // tou
+    1453,  // toufa
+    2550,  // toulan
+    2253,  // touming
+    593,  // touru
+    1215,  // touzi
+    577,  // tu
+    2409,  // tuan
+    2263,  // tuchu
+    286,  // tudi
+    676,  // tudou
+    541,  // tui
+    596,  // tuibu
+    2364,  // tuichi
+    968,  // tuici
+    1189,  // tuiguang
+    58,  // tuijian
+    1889,  // tuixiu
+    162,  // tuo
+    1872,  // turan
+    802,  // tushuguan
+    529,  // tuya
+    1933,  // tuzi
+    1968,  // wai
+    1858,  // waidi
+    452,  // waigong
+    781,  // waijiao
+    2085,  // waimian
+    1739,  // waiwainiuniu
+    1852,  // wan
+    104,  // wancheng
+    1702,  // wang
+    652,  // wangfan
+    1528,  // wanggong
+    402,  // wangji
+    2392,  // wangluo
+    2284,  // wangqiu
+    20,  // wangsheng
+    1552,  // wangwang
+    1776,  // wangzhan
+    2044,  // wangzi
+    712,  // wanju
+    1259,  // wanmei
+    2489,  // wanquan
+    986,  // wanr
+    2385,  // wanshan
+    1830,  // wanshang
+    1537,  // wanyi
+    1471,  // wanzhang
+    2121,  // wanzheng
+    1090,  // wazi
+    172,  // wei
+    226,  // weiba
+    1040,  // weibi
+    45,  // weibo
+    1455,  // weida
+    791,  // weidao
+    1518,  // weifan
+    2118,  // weihai
+    2549,  // weijin
+    98,  // weikou
+    863,  // weilai
+    896,  // weile
+    1041,  // weiqi
+    504,  // weiqu
+    1302,  // weirao
+    1737,  // weishengjian
+    2486,  // weishenme
+    678,  // weixia
+    433,  // weixian
+    826,  // weixiao
+    941,  // weixin
+    1444,  // weixiu
+    929,  // weiyang
+    2226,  // weiyi
+    2187,  // weiyu
+    2358,  // weizhi
+    1532,  // wen
+    659,  // wending
+    1874,  // wendu
+    2496,  // wenhou
+    859,  // wenhua
+    1556,  // wenhuo
+    916,  // wenjian
+    333,  // wenju
+    1837,  // wenming
+    2316,  // wennuan
+    2082,  // wenrou
+    1974,  // wenti
+    947,  // wenwen
+    864,  // wenxue
+    2296,  // wenxuejia
+    1802,  // wenzhang
+    1523,  // wenzi
+    1751,  // wo
+    2426,  // women
+    1846,  // woshi
+    1161,  // woshou
+    2205,  // wu
+    139,  // wudao
+    2251,  // wufa
+    1593,  // wuhui
+    1539,  // wuli
+    160,  // wuliao
+    1000,  // wulun
+    125,  // wunai
+    389,  // wuran
+    656,  // wushu
+    525,  // wusuowei
+    141,  // wuyi
+    2325,  // wuzhi
+    1994,  // wuzi
+    2554,  // xi
+    2531,  // xia
+    517,  // xialingying
+    480,  // xiamian
+    1067,  // xian
+    88,  // xiandai
+    1036,  // xiande
+    1268,  // xiang
+    387,  // xiangchang
+    2444,  // xiangchu
+    821,  // xiangdang
+    311,  // xiangdui
+    2537,  // xiangfan
+    3,  // xiangfang
+    805,  // xianggua
+    493,  // xiangguan
+    9,  // xiangjiao
+    1139,  // xiangjingrubin
+    2029,  // xianglian
+    870,  // xiangmu
+    1213,  // xiangnian
+    247,  // xiangpi
+    1580,  // xiangqi
+    290,  // xiangshan
+    1828,  // xiangshou
+    2194,  // xiangsi
+    2299,  // xiangtong
+    1781,  // xiangxi
+    2355,  // xiangxiang
+    337,  // xiangxin
+    2386,  // xiangzheng
+    1070,  // xiangzi
+    1910,  // xianhua
+    2054,  // xianjin
+    2020,  // xianmu
+    2119,  // xianran
+    2352,  // xiansheng
+    1949,  // xianshi
+    803,  // xianxiang
+    869,  // xianyan
+    1775,  // xianzai
+    748,  // xianzhi
+    989,  // xiao
+    2527,  // xiaochi
+    887,  // xiaofei
+    1146,  // xiaoguo
+    1581,  // xiaohua
+    1007,  // xiaohuozi
+    2261,  // xiaoji
+    1585,  // xiaojie
+    1853,  // xiaojing
+    1857,  // xiaolv
+    1764,  // xiaomai
+    740,  // xiaopengyou
+    737,  // xiaoqi
+    1069,  // xiaoqu
+    489,  // xiaoshi
+    910,  // xiaoshou
+    197,  // xiaoshun
+    1176,  // xiaoshuo
+    1355,  // xiaoxi
+    1773,  // xiaoxiao
+    834,  // xiaoxin
+    797,  // xiaoying
+    170,  // xiaoyu
+    586,  // xiaoyuan
+    1811,  // xiaozhang
+    1365,  // xiaweiyi
+    829,  // xiawu
+    671,  // xiayu
+    1498,  // xiazai
+    1193,  // xie
+    510,  // xiexie
+    495,  // xiezuo
+    1427,  // xigua
+    677,  // xiguan
+    1286,  // xihan
+    758,  // xihongshi
+    1868,  // xihuan
+    634,  // xijie
+    2547,  // xiju
+    1466,  // xili
+    327,  // xin
+    2267,  // xinfeng
+    1986,  // xing
+    26,  // xingbie
+    905,  // xingcheng
+    1467,  // xingdong
+    1079,  // xingfen
+    503,  // xingfu
+    739,  // xingge
+    219,  // xingkui
+    407,  // xinglixiang
+    875,  // xingqi
+    14,  // xingqu
+    66,  // xingren
+    1482,  // xingrong
+    955,  // xingshi
+    1216,  // xingwei
+    1906,  // xingxiang
+    2451,  // xingxing
+    169,  // xingyun
+    1620,  // xingzhi
+    1252,  // xingzhuang
+    461,  // xinhao
+    2022,  // xinku
+    1469,  // xinli
+    548,  // xinnian
+    687,  // xinqing
+    613,  // xinren
+    1424,  // xinshang
+    950,  // xinwen
+    532,  // xinxi
+    474,  // xinxian
+    1573,  // xinxiangan
+    1366,  // xinxilan
+    512,  // xinxin
+    1780,  // xinyongka
+    2178,  // xinzang
+    1926,  // xiong
+    1198,  // xiongdi
+    819,  // xiongmao
+    269,  // xiqu
+    1452,  // xishou
+    476,  // xishoujian
+    1732,  // xitong
+    2423,  // xiugai
+    1715,  // xiuli
+    343,  // xiuxi
+    1794,  // xiuxian
+    2347,  // xiwang
+    1341,  // xiyin
+    2379,  // xizao
+    1543,  // xuanbu
+    2219,  // xuanchuan
+    549,  // xuanze
+    1497,  // xuduo
+    2148,  // xue
+    939,  // xueli
+    2348,  // xueqi
+    804,  // xuesheng
+    1983,  // xueshu
+    1793,  // xuewen
+    701,  // xuexi
+    2092,  // xuexiao
+    2530,  // xunlian
+    112,  // xunsu
+    1560,  // xunwen
+    1201,  // xunzhao
+    204,  // xushu
+    1137,  // xuxin
+    199,  // xuyao
+    1982,  // ya
+    460,  // yachi
+    184,  // yagao
+    1085,  // yajin
+    2310,  // yali
+    1188,  // yan
+    1743,  // yanchang
+    983,  // yanchu
+    1346,  // yang
+    1397,  // yangcheng
+    605,  // yange
+    472,  // yangguang
+    1433,  // yangrou
+    1260,  // yangshi
+    686,  // yangtai
+    1752,  // yangxiong
+    1049,  // yangzi
+    889,  // yanhui
+    1415,  // yanjiang
+    760,  // yanjing
+    151,  // yanjiu
+    783,  // yanse
+    2188,  // yansu
+    1255,  // yanyuan
+    1368,  // yanzhong
+    1753,  // yao
+    210,  // yaobu
+    628,  // yaoqing
+    2415,  // yaoqiu
+    291,  // yaoshi
+    647,  // yashua
+    569,  // yazhou
+    692,  // ye
+    1362,  // yewu
+    1661,  // yexu
+    591,  // yeye
+    189,  // yeyu
+    466,  // yezi
+    273,  // yi
+    1454,  // yiban
+    1666,  // yibanlaishuo
+    1878,  // yibeizi
+    2452,  // yibian
+    41,  // yidan
+    1525,  // yidianr
+    589,  // yiding
+    1749,  // yidong
+    2528,  // yifu
+    1061,  // yigong
+    1133,  // yihan
+    2141,  // yihou
+    2389,  // yihuier
+    248,  // yihuir
+    1013,  // yiji
+    978,  // yijian
+    963,  // yijing
+    1108,  // yilai
+    136,  // yilian
+    1887,  // yilun
+    430,  // yilv
+    1810,  // yimin
+    1443,  // yin
+    2250,  // yinci
+    822,  // yiner
+    1638,  // ying
+    785,  // yingfu
+    1221,  // yinggai
+    399,  // yingjian
+    1527,  // yingjie
+    2174,  // yingjun
+    689,  // yingpin
+    1374,  // yingxiang
+    860,  // yingxiong
+    2166,  // yingyang
+    1703,  // yingye
+    2149,  // yingyong
+    720,  // yingzi
+    1916,  // yinhang
+    2094,  // yinjia
+    238,  // yinliang
+    1331,  // yinliao
+    144,  // yinqi
+    1461,  // yinshua
+    2236,  // yinsu
+    2208,  // yinwei
+    2351,  // yinxiang
+    1087,  // yinyue
+    1615,  // yinyuehui
+    1117,  // yinzi
+    1233,  // yiqi
+    1120,  // yiqian
+    1129,  // yiqie
+    621,  // yiran
+    487,  // yisheng
+    1517,  // yishu
+    440,  // yisi
+    1393,  // yiwai
+    2339,  // yiwei
+    578,  // yiwen
+    361,  // yiwu
+    245,  // yixia
+    514,  // yiyang
+    406,  // yiyi
+    2133,  // yiyuan
+    761,  // yizai
+    2484,  // yizhi
+    1174,  // yizi
+    1338,  // yong
+    544,  // yongbao
+    1815,  // yonggan
+    809,  // yonggong
+    511,  // yongji
+    609,  // yongqi
+    1795,  // yongtu
+    1059,  // yongyuan
+    1883,  // you
+    2143,  // youbian
+    2411,  // youdian
+    202,  // youdianr
+    830,  // youeryuan
+    1071,  // youguan
+    861,  // youhao
+    442,  // youhui
+    1770,  // youjiu
+    1055,  // youju
+    1065,  // youlai
+    998,  // youlan
+    1516,  // youle
+    2539,  // youli
+    83,  // youmei
+    927,  // youming
+    2475,  // youmo
+    429,  // youqi
+    171,  // youqu
+    145,  // youshi
+    2372,  // youxi
+    188,  // youxian
+    1487,  // youxiao
+    2504,  // youxiu
+    2294,  // youyi
+    395,  // youyisi
+    2491,  // youyong
+    27,  // youyou
+    2361,  // youyu
+    1966,  // youzha
+    553,  // yu
+    683,  // yuan
+    632,  // yuandan
+    1016,  // yuangong
+    2410,  // yuanlai
+    1109,  // yuanliang
+    410,  // yuanliao
+    1930,  // yuanwang
+    1072,  // yuanyi
+    318,  // yuanyin
+    1667,  // yuanze
+    956,  // yubao
+    1861,  // yudafu
+    1283,  // yudao
+    817,  // yuding
+    409,  // yue
+    2338,  // yuedu
+    2269,  // yuehui
+    1642,  // yueliang
+    1480,  // yueqi
+    1991,  // yufa
+    1186,  // yufang
+    1236,  // yukuai
+    2367,  // yumaoqiu
+    5,  // yumi
+    600,  // yun
+    1394,  // yundong
+    1624,  // yunnan
+    967,  // yunqi
+    1237,  // yunshu
+    2456,  // yunxu
+    1062,  // yunyong
+    43,  // yuqi
+    811,  // yushi
+    1092,  // yuxi
+    300,  // yuyan
+    1967,  // zai
+    2536,  // zaihai
+    140,  // zaihu
+    636,  // zaijian
+    24,  // zaisan
+    345,  // zaiyu
+    1800,  // zan
+    755,  // zancheng
+    1088,  // zang
+    1820,  // zanmai
+    2509,  // zanmei
+    1132,  // zanmen
+    1273,  // zanshi
+    2455,  // zao
+    2259,  // zaocheng
+    2211,  // zaogao
+    1562,  // zaoshang
+    1251,  // zazhi
+    946,  // ze
+    1066,  // zebei
+    913,  // zeng
+    1577,  // zengjia
+    2275,  // zengzhang
+    173,  // zenme
+    2464,  // zenmeban
+    640,  // zenmeyang
+    882,  // zeren
+    1915,  // zhai
+    1827,  // zhaifeng
+    2156,  // zhan
+    1544,  // zhang
+    914,  // zhangbei
+    373,  // zhangfu
+    287,  // zhanghu
+    688,  // zhanguo
+    1054,  // zhangwo
+    976,  // zhangxiaolong
+    178,  // zhankai
+    111,  // zhanlan
+    651,  // zhanmushinaishimisi
+    1222,  // zhantie
+    2442,  // zhanxian
+    1979,  // zhanzheng
+    420,  // zhao
+    932,  // zhaochang
+    1048,  // zhaodai
+    961,  // zhaofugen
+    857,  // zhaogu
+    500,  // zhaoguo
+    412,  // zhaohuo
+    1819,  // zhaoji
+    1391,  // zhaokai
+    930,  // zhaokuo
+    1401,  // zhaoliang
+    1246,  // zhaopian
+    2031,  // zhaopin
+    765,  // zhaor
+    2552,  // zhaosanmusi
+    114,  // zhaoshe
+    244,  // zhaoxiangji
+    1231,  // zhe
+    1604,  // zhedie
+    6,  // zheli
+    795,  // zhen
+    2305,  // zhendong
+    1885,  // zhenduan
+    1099,  // zhendui
+    1958,  // zheng
+    2548,  // zhengchang
+    1429,  // zhengfu
+    729,  // zhengge
+    1151,  // zhenghao
+    614,  // zhengjian
+    518,  // zhengju
+    2124,  // zhengli
+    666,  // zhenglun
+    2192,  // zhengming
+    97,  // zhengqi
+    240,  // zhengqiu
+    1694,  // zhengqu
+    1997,  // zhengque
+    1158,  // zhengshi
+    1463,  // zhengteng
+    903,  // zhengti
+    660,  // zhengzai
+    1414,  // zhengzhi
+    1563,  // zhenshi
+    928,  // zhenxi
+    1844,  // zhenzheng
+    2298,  // zher
+    207,  // zhexie
+    909,  // zhexue
+    1786,  // zhexuejia
+    1154,  // zhi
+    1583,  // zhichi
+    858,  // zhidai
+    835,  // zhidao
+    1603,  // zhide
+    236,  // zhiding
+    2105,  // zhidu
+    1602,  // zhigan
+    839,  // zhigengniao
+    335,  // zhihao
+    1229,  // zhihui
+    1325,  // zhijiao
+    13,  // zhijie
+    1911,  // zhijin
+    1038,  // zhileng
+    243,  // zhiliang
+    568,  // zhiliao
+    1621,  // zhipiao
+    919,  // zhishangtanbing
+    1185,  // zhishao
+    612,  // zhishi
+    1156,  // zhiwu
+    353,  // zhixu
+    200,  // zhiyao
+    501,  // zhiye
+    1724,  // zhiyou
+    1127,  // zhiyu
+    2241,  // zhiyuanzhe
+    1514,  // zhizao
+    1804,  // zhizhao
+    306,  // zhizuo
+    2469,  // zhong
+    278,  // zhongda
+    1148,  // zhongdian
+    1122,  // zhongguo
+    176,  // zhongjian
+    1907,  // zhongjie
+    1060,  // zhonglei
+    1239,  // zhongliang
+    964,  // zhongqiujie
+    148,  // zhongshi
+    186,  // zhongwen
+    130,  // zhongwu
+    1902,  // zhongxin
+    1871,  // zhongxun
+    455,  // zhongyao
+    1445,  // zhongyu
+    2327,  // zhou
+    1586,  // zhoudao
+    1923,  // zhoumo
+    1990,  // zhouwei
+    2117,  // zhu
+    1267,  // zhua
+    364,  // zhuajin
+    1100,  // zhuan
+    575,  // zhuanbian
+    2374,  // zhuang
+    2304,  // zhuangao
+    93,  // zhuangkuang
+    2499,  // zhuangshi
+    59,  // zhuangtai
+    1091,  // zhuangxiu
+    1308,  // zhuanjia
+    78,  // zhuanmen
+    1823,  // zhuantou
+    1866,  // zhuanxin
+    987,  // zhuanye
+    1358,  // zhuanzhu
+    892,  // zhubu
+    2028,  // zhuce
+    534,  // zhuchi
+    391,  // zhudong
+    1865,  // zhufu
+    2005,  // zhuguan
+    2553,  // zhuhe
+    1941,  // zhui
+    796,  // zhuiqiu
+    2417,  // zhujian
+    1962,  // zhuming
+    1576,  // zhunbei
+    1850,  // zhunque
+    854,  // zhunshi
+    1230,  // zhuo
+    359,  // zhuozi
+    2418,  // zhuren
+    2064,  // zhuti
+    1686,  // zhuxi
+    242,  // zhuyao
+    259,  // zhuyi
+    1842,  // zhuzhang
+    733,  // zhuzi
+    2116,  // zi
+    1851,  // zicong
+    418,  // zidong
+    313,  // zige
+    2077,  // zihao
+    2508,  // ziji
+    1901,  // zijin
+    1569,  // zijue
+    561,  // ziliao
+    971,  // zilu
+    1149,  // zimu
+    1205,  // ziran
+    206,  // zisha
+    237,  // zishi
+    260,  // zisi
+    972,  // zixi
+    1499,  // zixin
+    2506,  // zixingche
+    2522,  // zixun
+    1171,  // ziyou
+    2525,  // ziyuan
+    1447,  // zongcai
+    2162,  // zonggong
+    1476,  // zonghe
+    241,  // zongjie
+    312,  // zongli
+    2181,  // zongshi
+    2535,  // zongsuan
+    1226,  // zongtong
+    2466,  // zongzhi
+    1295,  // zou
+    1805,  // zu
+    1971,  // zucheng
+    1211,  // zuhe
+    77,  // zui
+    1155,  // zuichu
+    1924,  // zuihao
+    2183,  // zuihou
+    1956,  // zuijin
+    2003,  // zunjing
+    1808,  // zunshou
+    174,  // zunzhong
+    1281,  // zuo
+    1470,  // zuobian
+    2017,  // zuojia
+    767,  // zuoke
+    368,  // zuopin
+    994,  // zuotian
+    714,  // zuowei
+    1442,  // zuowen
+    270,  // zuoye
+    1821,  // zuoyong
+    1854,  // zuoyou
+    2111,  // zuozhan
+    1033,  // zuozhe
+    1849,  // zuzhi
+];
+
 // Longest homophone choice size (choices joined by "\t")
 pub const CIYU_CHOICE_MAX: usize = 7;
 
-pub static CIYU: &[&'static str] = &[
-    &"弱",  // ruo
-    &"悲观",  // beiguan
-    &"收获",  // shouhuo
-    &"厢房",  // xiangfang
-    &"规模",  // guimo
-    &"玉米",  // yumi
-    &"哲理",  // zheli
-    &"老鹰",  // laoying
-    &"日记",  // riji
-    &"香蕉",  // xiangjiao
-    &"临时",  // linshi
-    &"损失",  // sunshi
-    &"胡同",  // hutong
-    &"直接",  // zhijie
-    &"兴趣",  // xingqu
-    &"成语",  // chengyu
-    &"胶水",  // jiaoshui
-    &"菜	才	猜	踩",  // cai
-    &"夸张",  // kuazhang
-    &"丑陋",  // choulou
-    &"旺盛",  // wangsheng
-    &"悄悄",  // qiaoqiao
-    &"摔倒",  // shuaidao
-    &"姥姥",  // laolao
-    &"再三",  // zaisan
-    &"对	堆",  // dui
-    &"性别",  // xingbie
-    &"悠悠",  // youyou
-    &"花生",  // huasheng
-    &"钱	前	千	签	浅	欠",  // qian
-    &"数字	梳子",  // shuzi
-    &"糖	躺	汤	趟	烫",  // tang
-    &"分钟",  // fenzhong
-    &"承受",  // chengshou
-    &"类型",  // leixing
-    &"角色",  // juese
-    &"举办",  // juban
-    &"好处",  // haochu
-    &"繁荣",  // fanrong
-    &"接着",  // jiezhe
-    &"乘坐",  // chengzuo
-    &"一旦",  // yidan
-    &"宠物",  // chongwu
-    &"语气",  // yuqi
-    &"放心",  // fangxin
-    &"微博",  // weibo
-    &"壶	糊",  // hu
-    &"日用品",  // riyongpin
-    &"寿命",  // shouming
-    &"办事",  // banshi
-    &"鲁迅",  // luxun
-    &"农业",  // nongye
-    &"地球",  // diqiu
-    &"坏",  // huai
-    &"变	遍	便",  // bian
-    &"年	念",  // nian
-    &"摄入",  // sheru
-    &"距离",  // juli
-    &"推荐",  // tuijian
-    &"状态",  // zhuangtai
-    &"厉害",  // lihai
-    &"忍不住",  // renbuzhu
-    &"建筑",  // jianzhu
-    &"公共汽车",  // gonggongqiche
-    &"实话",  // shihua
-    &"拒绝",  // jujue
-    &"行人",  // xingren
-    &"设计	射击",  // sheji
-    &"厨房",  // chufang
-    &"身体",  // shenti
-    &"手术",  // shoushu
-    &"偶然",  // ouran
-    &"超市	潮湿",  // chaoshi
-    &"不假思索",  // bujiasisuo
-    &"出现",  // chuxian
-    &"感想",  // ganxiang
-    &"行业",  // hangye
-    &"最	嘴	醉",  // zui
-    &"专门",  // zhuanmen
-    &"春游",  // chunyou
-    &"次要",  // ciyao
-    &"烦恼",  // fannao
-    &"科学",  // kexue
-    &"优美",  // youmei
-    &"保持",  // baochi
-    &"老板",  // laoban
-    &"麦布里奇",  // maibuliqi
-    &"茶	差	插",  // cha
-    &"现代",  // xiandai
-    &"家庭",  // jiating
-    &"稍微",  // shaowei
-    &"宝贝",  // baobei
-    &"面临",  // mianlin
-    &"状况",  // zhuangkuang
-    &"美丽	魅力",  // meili
-    &"力气",  // liqi
-    &"缩短",  // suoduan
-    &"整齐",  // zhengqi
-    &"胃口",  // weikou
-    &"盲人",  // mangren
-    &"几乎",  // jihu
-    &"丰富",  // fengfu
-    &"确认",  // queren
-    &"俱乐部",  // julebu
-    &"完成",  // wancheng
-    &"不客气",  // bukeqi
-    &"结束",  // jieshu
-    &"坚决",  // jianjue
-    &"程序",  // chengxu
-    &"没	每	美",  // mei
-    &"粗心",  // cuxin
-    &"展览",  // zhanlan
-    &"迅速",  // xunsu
-    &"加油站",  // jiayouzhan
-    &"赵奢",  // zhaoshe
-    &"历史",  // lishi
-    &"范围",  // fanwei
-    &"奇怪",  // qiguai
-    &"感到",  // gandao
-    &"半夜",  // banye
-    &"煤炭",  // meitan
-    &"传统",  // chuantong
-    &"科技",  // keji
-    &"平方",  // pingfang
-    &"良好",  // lianghao
-    &"无奈",  // wunai
-    &"甚至",  // shenzhi
-    &"过期",  // guoqi
-    &"激烈",  // jilie
-    &"盘子",  // panzi
-    &"中午",  // zhongwu
-    &"古代",  // gudai
-    &"爱好",  // aihao
-    &"开玩笑",  // kaiwanxiao
-    &"精诚所至",  // jingchengsuozhi
-    &"内科",  // neike
-    &"一连",  // yilian
-    &"来自",  // laizi
-    &"开幕式",  // kaimushi
-    &"舞蹈",  // wudao
-    &"在乎",  // zaihu
-    &"无意",  // wuyi
-    &"地点",  // didian
-    &"难受",  // nanshou
-    &"引起",  // yinqi
-    &"优势",  // youshi
-    &"层",  // ceng
-    &"饺子",  // jiaozi
-    &"重视",  // zhongshi
-    &"错",  // cuo
-    &"积累",  // jilei
-    &"研究",  // yanjiu
-    &"乐观",  // leguan
-    &"平衡",  // pingheng
-    &"出口",  // chukou
-    &"恭喜",  // gongxi
-    &"欢迎",  // huanying
-    &"旁边",  // pangbian
-    &"车厢",  // chexiang
-    &"海洋",  // haiyang
-    &"无聊",  // wuliao
-    &"摄影师",  // sheyingshi
-    &"脱",  // tuo
-    &"谨慎",  // jinshen
-    &"他	她	它",  // ta
-    &"工程师",  // gongchengshi
-    &"安排",  // anpai
-    &"北京师范大学",  // beijingshifandaxue
-    &"汉语",  // hanyu
-    &"幸运",  // xingyun
-    &"小雨",  // xiaoyu
-    &"有趣",  // youqu
-    &"喂	为	位	胃",  // wei
-    &"怎么",  // zenme
-    &"尊重",  // zunzhong
-    &"弟弟",  // didi
-    &"中间",  // zhongjian
-    &"单元",  // danyuan
-    &"展开",  // zhankai
-    &"吃",  // chi
-    &"空调",  // kongtiao
-    &"爱	矮	哎	唉",  // ai
-    &"缺点",  // quedian
-    &"理由",  // liyou
-    &"牙膏",  // yagao
-    &"导演",  // daoyan
-    &"中文",  // zhongwen
-    &"人口",  // renkou
-    &"有限",  // youxian
-    &"业余",  // yeyu
-    &"告诉",  // gaosu
-    &"老虎",  // laohu
-    &"柜台",  // guitai
-    &"胡说",  // hushuo
-    &"佩·詹森",  // peizhansen
-    &"日程",  // richeng
-    &"独特",  // dute
-    &"孝顺",  // xiaoshun
-    &"打扰",  // darao
-    &"需要",  // xuyao
-    &"只要",  // zhiyao
-    &"钱包",  // qianbao
-    &"有点儿",  // youdianr
-    &"佩·詹森",  // pei`zhansen
-    &"叙述",  // xushu
-    &"格外",  // gewai
-    &"自杀",  // zisha
-    &"这些",  // zhexie
-    &"难过",  // nanguo
-    &"竞争",  // jingzheng
-    &"要不",  // yaobu
-    &"帅	甩",  // shuai
-    &"发烧",  // fashao
-    &"垃圾",  // laji
-    &"马虎",  // mahu
-    &"简历	建立",  // jianli
-    &"·",  // `
-    &"商店",  // shangdian
-    &"少	勺",  // shao
-    &"幸亏",  // xingkui
-    &"合适",  // heshi
-    &"昆虫",  // kunchong
-    &"夹子",  // jiazi
-    &"报名",  // baoming
-    &"观念",  // guannian
-    &"称呼",  // chenghu
-    &"尾巴",  // weiba
-    &"导游",  // daoyou
-    &"感觉",  // ganjue
-    &"聊天儿",  // liaotianr
-    &"伙伴",  // huoban
-    &"分配",  // fenpei
-    &"穷",  // qiong
-    &"标准",  // biaozhun
-    &"保险",  // baoxian
-    &"集体",  // jiti
-    &"制定	制订",  // zhiding
-    &"姿势",  // zishi
-    &"荫凉",  // yinliang
-    &"天	甜	填",  // tian
-    &"征求",  // zhengqiu
-    &"总结",  // zongjie
-    &"主要",  // zhuyao
-    &"质量",  // zhiliang
-    &"照相机",  // zhaoxiangji
-    &"一下",  // yixia
-    &"（",  // (
-    &"橡皮",  // xiangpi
-    &"一会儿",  // yihuir
-    &"三	伞",  // san
-    &"声调",  // shengdiao
-    &"老师	老实",  // laoshi
-    &"粗糙",  // cucao
-    &"干净",  // ganjing
-    &"全面",  // quanmian
-    &"导致",  // daozhi
-    &"黄	慌",  // huang
-    &"册",  // ce
-    &"前途",  // qiantu
-    &"注意	主意",  // zhuyi
-    &"自私",  // zisi
-    &"挑战",  // tiaozhan
-    &"生活",  // shenghuo
-    &"始终",  // shizhong
-    &"思想",  // sixiang
-    &"女孩儿",  // nvhair
-    &"衬衫",  // chenshan
-    &"京剧",  // jingju
-    &"具体",  // juti
-    &"吸取",  // xiqu
-    &"作业",  // zuoye
-    &"把握",  // bawo
-    &"使劲",  // shijin
-    &"一	以	乙	亿",  // yi
-    &"毛巾",  // maojin
-    &"比尔·盖茨",  // bier`gaici
-    &"流利",  // liuli
-    &"巧妙",  // qiaomiao
-    &"重大",  // zhongda
-    &"裙子",  // qunzi
-    &"密切",  // miqie
-    &"废话",  // feihua
-    &"设施",  // sheshi
-    &"吗	马	骂",  // ma
-    &"）",  // )
-    &"冷",  // leng
-    &"土地",  // tudi
-    &"账户",  // zhanghu
-    &"居然",  // juran
-    &"到底",  // daodi
-    &"香山",  // xiangshan
-    &"要是	钥匙",  // yaoshi
-    &"口",  // kou
-    &"请愿书",  // qingyuanshu
-    &"拉	辣	啦",  // la
-    &"踢足球",  // tizuqiu
-    &"入围",  // ruwei
-    &"恩爱",  // enai
-    &"带	戴	袋	呆",  // dai
-    &"你们",  // nimen
-    &"语言	寓言",  // yuyan
-    &"楼	漏",  // lou
-    &"得意",  // deyi
-    &"继续	积蓄",  // jixu
-    &"丝绸",  // sichou
-    &"人名",  // renming
-    &"制作",  // zhizuo
-    &"商品",  // shangpin
-    &"键盘",  // jianpan
-    &"叔叔",  // shushu
-    &"假装",  // jiazhuang
-    &"相对",  // xiangdui
-    &"总理",  // zongli
-    &"资格",  // zige
-    &"木头",  // mutou
-    &"利用",  // liyong
-    &"题目",  // timu
-    &"然后",  // ranhou
-    &"原因",  // yuanyin
-    &"嘉宾",  // jiabin
-    &"汗	喊",  // han
-    &"黑猩猩",  // heixingxing
-    &"面试",  // mianshi
-    &"抱怨",  // baoyuan
-    &"捐",  // juan
-    &"能力",  // nengli
-    &"可能",  // keneng
-    &"新	信",  // xin
-    &"所谓",  // suowei
-    &"随手",  // suishou
-    &"楚国",  // chuguo
-    &"诺贝尔奖",  // nuobeierjiang
-    &"究竟",  // jiujing
-    &"文具",  // wenju
-    &"动画片",  // donghuapian
-    &"只好",  // zhihao
-    &"彼此",  // bici
-    &"相信",  // xiangxin
-    &"破坏",  // pohuai
-    &"后	厚",  // hou
-    &"杯子	被子",  // beizi
-    &"律师",  // lvshi
-    &"冷淡",  // lengdan
-    &"休息",  // xiuxi
-    &"成熟",  // chengshu
-    &"在于",  // zaiyu
-    &"积极",  // jiji
-    &"如今",  // rujin
-    &"全神贯注",  // quanshenguanzhu
-    &"加油",  // jiayou
-    &"浪漫",  // langman
-    &"认为",  // renwei
-    &"青少年",  // qingshaonian
-    &"秩序",  // zhixu
-    &"豪华",  // haohua
-    &"紧张",  // jinzhang
-    &"切",  // qie
-    &"报道	报到",  // baodao
-    &"落后",  // luohou
-    &"桌子",  // zhuozi
-    &"起居",  // qiju
-    &"义务",  // yiwu
-    &"情况",  // qingkuang
-    &"慌张",  // huangzhang
-    &"抓紧",  // zhuajin
-    &"北京	背景",  // beijing
-    &"旅行",  // lvxing
-    &"盆",  // pen
-    &"作品",  // zuopin
-    &"零件",  // lingjian
-    &"适合",  // shihe
-    &"天然",  // tianran
-    &"翻译",  // fanyi
-    &"丈夫",  // zhangfu
-    &"交际",  // jiaoji
-    &"迟早",  // chizao
-    &"讨论",  // taolun
-    &"大使馆",  // dashiguan
-    &"护国寺",  // huguosi
-    &"采取",  // caiqu
-    &"表明",  // biaoming
-    &"改进",  // gaijin
-    &"粉色",  // fense
-    &"路",  // lu
-    &"可靠",  // kekao
-    &"光盘",  // guangpan
-    &"鼻子",  // bizi
-    &"香肠",  // xiangchang
-    &"年龄",  // nianling
-    &"污染",  // wuran
-    &"窗帘",  // chuanglian
-    &"主动",  // zhudong
-    &"清淡",  // qingdan
-    &"喝	和	河",  // he
-    &"馆子	管子",  // guanzi
-    &"有意思",  // youyisi
-    &"留守",  // liushou
-    &"代言",  // daiyan
-    &"广场",  // guangchang
-    &"硬件",  // yingjian
-    &"平等",  // pingdeng
-    &"忽然",  // huran
-    &"忘记",  // wangji
-    &"警察",  // jingcha
-    &"太极拳",  // taijiquan
-    &"绝对",  // juedui
-    &"意义",  // yiyi
-    &"行李箱",  // xinglixiang
-    &"收据",  // shouju
-    &"月	越	约",  // yue
-    &"原料",  // yuanliao
-    &"孩子",  // haizi
-    &"着火",  // zhaohuo
-    &"答应",  // daying
-    &"大夫",  // daifu
-    &"简直	兼职",  // jianzhi
-    &"帆船",  // fanchuan
-    &"减轻",  // jianqing
-    &"自动",  // zidong
-    &"啊",  // a
-    &"找	照	招",  // zhao
-    &"艰巨",  // jianju
-    &"尽力",  // jinli
-    &"妹妹",  // meimei
-    &"不得不",  // budebu
-    &"手工",  // shougong
-    &"介绍",  // jieshao
-    &"女",  // nv
-    &"更",  // geng
-    &"尤其	与其",  // youqi
-    &"一律",  // yilv
-    &"丝毫",  // sihao
-    &"发挥",  // fahui
-    &"危险",  // weixian
-    &"《卖火柴的小女孩儿》",  // <maihuochaidexiaonvhaier>
-    &"记者",  // jizhe
-    &"计划",  // jihua
-    &"打工",  // dagong
-    &"秒",  // miao
-    &"双",  // shuang
-    &"意思",  // yisi
-    &"哥哥",  // gege
-    &"优惠",  // youhui
-    &"公里",  // gongli
-    &"肩膀",  // jianbang
-    &"构成",  // goucheng
-    &"并且",  // bingqie
-    &"风险",  // fengxian
-    &"恋爱",  // lianai
-    &"所以",  // suoyi
-    &"减少",  // jianshao
-    &"离婚",  // lihun
-    &"外公",  // waigong
-    &"安慰",  // anwei
-    &"看法",  // kanfa
-    &"重要",  // zhongyao
-    &"含意",  // hanyi
-    &"按照",  // anzhao
-    &"倒霉",  // daomei
-    &"蹄子",  // tizi
-    &"牙齿",  // yachi
-    &"信号",  // xinhao
-    &"电台",  // diantai
-    &"省略",  // shenglve
-    &"生病",  // shengbing
-    &"深渊",  // shenyuan
-    &"叶子",  // yezi
-    &"测验",  // ceyan
-    &"爸爸",  // baba
-    &"干活儿",  // ganhuor
-    &"其实",  // qishi
-    &"挂号",  // guahao
-    &"阳光",  // yangguang
-    &"矿泉水",  // kuangquanshui
-    &"新鲜",  // xinxian
-    &"不耐烦",  // bunaifan
-    &"洗手间",  // xishoujian
-    &"其余",  // qiyu
-    &"反正",  // fanzheng
-    &"存在",  // cunzai
-    &"下面",  // xiamian
-    &"掉	钓",  // diao
-    &"机器	极其",  // jiqi
-    &"拐弯",  // guaiwan
-    &"后背",  // houbei
-    &"馒头",  // mantou
-    &"件	减	捡	箭",  // jian
-    &"医生",  // yisheng
-    &"生命",  // shengming
-    &"小时	消失",  // xiaoshi
-    &"实习",  // shixi
-    &"顾客",  // guke
-    &"嫩",  // nen
-    &"相关",  // xiangguan
-    &"水平",  // shuiping
-    &"写作",  // xiezuo
-    &"冰激凌",  // bingjiling
-    &"表演",  // biaoyan
-    &"讲究",  // jiangjiu
-    &"筷子",  // kuaizi
-    &"赵国",  // zhaoguo
-    &"职业",  // zhiye
-    &"关心",  // guanxin
-    &"幸福",  // xingfu
-    &"委屈",  // weiqu
-    &"笔记本",  // bijiben
-    &"上网",  // shangwang
-    &"何必",  // hebi
-    &"不错",  // bucuo
-    &"电池",  // dianchi
-    &"谢谢",  // xiexie
-    &"拥挤",  // yongji
-    &"信心",  // xinxin
-    &"趵突泉",  // baotuquan
-    &"一样",  // yiyang
-    &"空",  // kong
-    &"汽油",  // qiyou
-    &"夏令营",  // xialingying
-    &"证据",  // zhengju
-    &"晒",  // shai
-    &"感情",  // ganqing
-    &"内部",  // neibu
-    &"交通",  // jiaotong
-    &"男	南	难",  // nan
-    &"酸	算",  // suan
-    &"无所谓",  // wusuowei
-    &"菜市口",  // caishikou
-    &"老舍",  // laoshe
-    &"怀孕",  // huaiyun
-    &"涂鸦",  // tuya
-    &"守岁",  // shousui
-    &"漂亮",  // piaoliang
-    &"信息",  // xinxi
-    &"实验",  // shiyan
-    &"主持",  // zhuchi
-    &"黑板",  // heiban
-    &"打喷嚏",  // dapenti
-    &"铅笔",  // qianbi
-    &"合格",  // hege
-    &"米饭",  // mifan
-    &"沙发",  // shafa
-    &"腿	推	退",  // tui
-    &"条",  // tiao
-    &"哪里",  // nali
-    &"拥抱",  // yongbao
-    &"偷",  // tou
-    &"会计",  // kuaiji
-    &"关于",  // guanyu
-    &"新年",  // xinnian
-    &"选择",  // xuanze
-    &"农村",  // nongcun
-    &"软",  // ruan
-    &"抽象",  // chouxiang
-    &"雨	鱼	与	于",  // yu
-    &"方法",  // fangfa
-    &"靠",  // kao
-    &"善良",  // shanliang
-    &"过去",  // guoqu
-    &"科恩",  // keen
-    &"达到",  // dadao
-    &"生动",  // shengdong
-    &"资料",  // ziliao
-    &"困扰",  // kunrao
-    &"出版",  // chuban
-    &"街道",  // jidao
-    &"听说",  // tingshuo
-    &"鼠标",  // shubiao
-    &"声音",  // shengyin
-    &"治疗",  // zhiliao
-    &"亚洲",  // yazhou
-    &"批准",  // peizhun
-    &"必要",  // biyao
-    &"感激",  // ganji
-    &"录取",  // luqu
-    &"事实",  // shishi
-    &"转变",  // zhuanbian
-    &"担任",  // danren
-    &"吐	图",  // tu
-    &"疑问",  // yiwen
-    &"少许",  // shaoxu
-    &"《非你莫属》",  // feinimoshu
-    &"可怜",  // kelian
-    &"车祸",  // chehuo
-    &"深	伸",  // shen
-    &"等	灯",  // deng
-    &"规律",  // guilv
-    &"校园",  // xiaoyuan
-    &"存",  // cun
-    &"经过",  // jingguo
-    &"一定",  // yiding
-    &"老鼠",  // laoshu
-    &"爷爷",  // yeye
-    &"没有",  // meiyou
-    &"投入",  // touru
-    &"根据",  // genju
-    &"时期",  // shiqi
-    &"退步",  // tuibu
-    &"哈",  // ha
-    &"赶	敢	干	杆	擀",  // gan
-    &"随时",  // suishi
-    &"云	晕",  // yun
-    &"古典",  // gudian
-    &"狂",  // kuang
-    &"单独",  // dandu
-    &"生意",  // shengyi
-    &"严格",  // yange
-    &"请	晴	轻	青",  // qing
-    &"全部",  // quanbu
-    &"谁",  // shei
-    &"勇气",  // yongqi
-    &"联合",  // lianhe
-    &"保护",  // baohu
-    &"知识",  // zhishi
-    &"信任",  // xinren
-    &"证件",  // zhengjian
-    &"传染",  // chuanran
-    &"频道",  // pindao
-    &"近代",  // jindai
-    &"排队",  // paidui
-    &"盆子",  // penzi
-    &"价值",  // jiazhi
-    &"依然",  // yiran
-    &"鼓励",  // guli
-    &"可怕",  // kepa
-    &"读	度	堵",  // du
-    &"疼",  // teng
-    &"成就",  // chengjiu
-    &"结果",  // jieguo
-    &"邀请",  // yaoqing
-    &"试卷",  // shijuan
-    &"哭	苦	库",  // ku
-    &"大	打",  // da
-    &"元旦",  // yuandan
-    &"被	倍	背	悲",  // bei
-    &"细节",  // xijie
-    &"进行",  // jinxing
-    &"再见",  // zaijian
-    &"理解",  // lijie
-    &"数据",  // shuju
-    &"借口",  // jiekou
-    &"怎么样",  // zenmeyang
-    &"控制",  // kongzhi
-    &"人员",  // renyuan
-    &"春",  // chun
-    &"看	砍",  // kan
-    &"出租车",  // chuzuche
-    &"肌肉",  // jirou
-    &"牙刷",  // yashua
-    &"采访",  // caifang
-    &"广大",  // guangda
-    &"毕业",  // biye
-    &"詹姆士·奈史密斯",  // zhanmushinaishimisi
-    &"往返",  // wangfan
-    &"打针",  // dazhen
-    &"起来",  // qilai
-    &"餐",  // can
-    &"无数	武术",  // wushu
-    &"年代",  // niandai
-    &"鼓掌",  // guzhang
-    &"稳定",  // wending
-    &"正在",  // zhengzai
-    &"陆续",  // luxu
-    &"苗条",  // miaotiao
-    &"给",  // gei
-    &"系领带",  // jilingdai
-    &"秋",  // qiu
-    &"争论",  // zhenglun
-    &"得到",  // dedao
-    &"胖",  // pang
-    &"区别",  // qubie
-    &"萝卜",  // luobo
-    &"下雨",  // xiayu
-    &"六一儿童节",  // liuyiertongjie
-    &"普通",  // putong
-    &"迷路",  // milu
-    &"连环画",  // lianhuanhua
-    &"土豆",  // tudou
-    &"习惯",  // xiguan
-    &"威胁",  // weixia
-    &"数量",  // shuliang
-    &"女士",  // nvshi
-    &"共同",  // gongtong
-    &"房间",  // fangjian
-    &"远	元	圆",  // yuan
-    &"均匀",  // junyun
-    &"暴雨",  // baoyu
-    &"阳台",  // yangtai
-    &"心情",  // xinqing
-    &"战国",  // zhanguo
-    &"应聘",  // yingpin
-    &"规矩",  // guiju
-    &"难道",  // nandao
-    &"也	页	夜",  // ye
-    &"风景",  // fengjing
-    &"闭关",  // biguan
-    &"纳入",  // naru
-    &"领域",  // lingyu
-    &"浏览",  // liulan
-    &"其中",  // qizhong
-    &"精神",  // jingshen
-    &"将来",  // jianglai
-    &"学习",  // xuexi
-    &"办理",  // banli
-    &"个性",  // gexing
-    &"购物",  // gouwu
-    &"讨价还价",  // taojiahuanjia
-    &"觉得",  // juede
-    &"亲自",  // qinzi
-    &"妈妈",  // mama
-    &"；",  // ;
-    &"竟然",  // jingran
-    &"傍晚",  // bangwan
-    &"玩具",  // wanju
-    &"空儿",  // kongr
-    &"座位	作为",  // zuowei
-    &"飞机",  // feiji
-    &"北方",  // beifang
-    &"全	圈	劝",  // quan
-    &"华北",  // huabei
-    &"报纸",  // baozhi
-    &"影子",  // yingzi
-    &"长期",  // changqi
-    &"产品",  // chanpin
-    &"明明",  // mingming
-    &"词语",  // ciyu
-    &"淋漓尽致",  // linlijinzhi
-    &"连续",  // lianxu
-    &"叉子",  // chazi
-    &"巧克力",  // qiaokeli
-    &"整个",  // zhengge
-    &"明天",  // mingtian
-    &"车",  // che
-    &"日",  // ri
-    &"竹子",  // zhuzi
-    &"糊涂",  // hutu
-    &"等于",  // dengyu
-    &"个别",  // gebie
-    &"小气",  // xiaoqi
-    &"发抖",  // fadou
-    &"性格",  // xingge
-    &"小朋友",  // xiaopengyou
-    &"厕所",  // cesuo
-    &"启发",  // qifa
-    &"婚姻",  // hunyin
-    &"闹钟",  // naozhong
-    &"奶奶",  // nainai
-    &"散步",  // sanbu
-    &"发票",  // fapiao
-    &"限制",  // xianzhi
-    &"手套",  // shoutao
-    &"牛奶",  // niunai
-    &"比赛",  // bisai
-    &"客人",  // keren
-    &"夸	垮",  // kua
-    &"密码",  // mima
-    &"赞成",  // zancheng
-    &"葡萄",  // putao
-    &"大方",  // dafang
-    &"西红柿",  // xihongshi
-    &"八成",  // bacheng
-    &"眼睛	眼镜",  // yanjing
-    &"一再",  // yizai
-    &"回答",  // huida
-    &"面条",  // miantiao
-    &"经济",  // jingji
-    &"招儿",  // zhaor
-    &"不仅",  // bujin
-    &"做客",  // zuoke
-    &"当	挡",  // dang
-    &"据说",  // jushuo
-    &"很	恨",  // hen
-    &"提问",  // tiwen
-    &"趁	沉",  // chen
-    &"难怪",  // nanguai
-    &"超过",  // chaoguo
-    &"首都机场",  // shoudujichang
-    &"菜单",  // caidan
-    &"表情",  // biaoqing
-    &"题	提	替	蹄",  // ti
-    &"保存",  // baocun
-    &"教室",  // jiaoshi
-    &"外交",  // waijiao
-    &"平	凭",  // ping
-    &"颜色",  // yanse
-    &"疯",  // feng
-    &"应付",  // yingfu
-    &"会议	回忆",  // huiyi
-    &"轻易",  // qingyi
-    &"龙",  // long
-    &"法院",  // fayuan
-    &"里查德·希尔斯",  // lichade`xiersi
-    &"味道",  // weidao
-    &"改变",  // gaibian
-    &"初级中学",  // chujizhongxue
-    &"客观",  // keguan
-    &"真	阵	镇",  // zhen
-    &"追求",  // zhuiqiu
-    &"效应",  // xiaoying
-    &"湿润",  // shirun
-    &"催",  // cui
-    &"剪刀",  // jiandao
-    &"峰终定律",  // fengzhongdinglv
-    &"图书馆",  // tushuguan
-    &"现象",  // xianxiang
-    &"学生",  // xuesheng
-    &"香瓜",  // xianggua
-    &"米",  // mi
-    &"二	而",  // er
-    &"方面",  // fangmian
-    &"用功",  // yonggong
-    &"表现",  // biaoxian
-    &"于是",  // yushi
-    &"双方",  // shuangfang
-    &"吃饭",  // chifan
-    &"鞭炮",  // bianpao
-    &"毛病",  // maobing
-    &"受不了",  // shoubuliao
-    &"预订",  // yuding
-    &"什么",  // shenme
-    &"熊猫",  // xiongmao
-    &"墙	抢	枪",  // qiang
-    &"相当",  // xiangdang
-    &"因而",  // yiner
-    &"镜子",  // jingzi
-    &"从",  // cong
-    &"欧盟",  // oumeng
-    &"微笑",  // weixiao
-    &"明显",  // mingxian
-    &"反复",  // fanfu
-    &"下午",  // xiawu
-    &"幼儿园",  // youeryuan
-    &"花花",  // huahua
-    &"刻苦",  // keku
-    &"服装",  // fuzhuang
-    &"小心",  // xiaoxin
-    &"知道	指导",  // zhidao
-    &"高兴",  // gaoxing
-    &"清楚",  // qingchu
-    &"根本",  // genben
-    &"知更鸟",  // zhigengniao
-    &"台阶",  // taijie
-    &"个	各",  // ge
-    &"放暑假",  // fangshujia
-    &"日子",  // rizi
-    &"女性",  // nvxing
-    &"粉	分	份",  // fen
-    &"评价",  // pingjia
-    &"符合",  // fuhe
-    &"胳膊",  // gebo
-    &"睡觉",  // shuijiao
-    &"还是",  // haishi
-    &"购买",  // goumai
-    &"刚才",  // gangcai
-    &"来得及",  // laideji
-    &"准时",  // zhunshi
-    &"关闭",  // guanbi
-    &"玻璃",  // boli
-    &"照顾",  // zhaogu
-    &"纸袋",  // zhidai
-    &"文化",  // wenhua
-    &"英雄",  // yingxiong
-    &"友好",  // youhao
-    &"痛快",  // tongkuai
-    &"未来",  // weilai
-    &"文学",  // wenxue
-    &"互联网",  // hulianwang
-    &"事情",  // shiqing
-    &"不足",  // buzu
-    &"对面",  // duimian
-    &"鲜艳",  // xianyan
-    &"项目",  // xiangmu
-    &"反应	反映",  // fanying
-    &"叫	教	角	脚	交	浇	焦",  // jiao
-    &"熟悉",  // shuxi
-    &"缺乏",  // quefa
-    &"星期",  // xingqi
-    &"提倡",  // tichang
-    &"命令",  // mingling
-    &"句	举	锯	局",  // ju
-    &"讽刺",  // fengci
-    &"步骤",  // buzhou
-    &"淘气",  // taoqi
-    &"责任",  // zeren
-    &"提纲",  // tigang
-    &"健康",  // jiankang
-    &"登机牌",  // dengjipai
-    &"技术",  // jishu
-    &"消费",  // xiaofei
-    &"收入",  // shouru
-    &"宴会",  // yanhui
-    &"地方",  // difang
-    &"改	盖",  // gai
-    &"逐步",  // zhubu
-    &"课	渴	刻	可	棵	颗	克",  // ke
-    &"景色",  // jingse
-    &"生气",  // shengqi
-    &"为了",  // weile
-    &"基础",  // jichu
-    &"安全",  // anquan
-    &"公布",  // gongbu
-    &"可是",  // keshi
-    &"垃圾桶",  // lajitong
-    &"睡着",  // shuizhao
-    &"整体",  // zhengti
-    &"不断",  // buduan
-    &"形成",  // xingcheng
-    &"美人鱼",  // meirenyu
-    &"奇迹",  // qiji
-    &"打扮",  // daban
-    &"哲学",  // zhexue
-    &"销售",  // xiaoshou
-    &"返航",  // fanhang
-    &"节约",  // jieyue
-    &"增",  // zeng
-    &"长辈",  // zhangbei
-    &"怀念",  // huainian
-    &"文件",  // wenjian
-    &"里	李	离	丽	梨",  // li
-    &"出发",  // chufa
-    &"纸上谈兵",  // zhishangtanbing
-    &"逃避",  // taobi
-    &"尽量",  // jinliang
-    &"敲	桥	瞧",  // qiao
-    &"随身",  // suishen
-    &"出色",  // chuse
-    &"金属",  // jinshu
-    &"窗	闯",  // chuang
-    &"有名",  // youming
-    &"珍惜",  // zhenxi
-    &"喂养",  // weiyang
-    &"赵括",  // zhaokuo
-    &"不得了",  // budeliao
-    &"照常",  // zhaochang
-    &"通过",  // tongguo
-    &"哪	那	拿",  // na
-    &"讨厌",  // taoyan
-    &"开水",  // kaishui
-    &"明",  // ming
-    &"必须",  // bixu
-    &"学历",  // xueli
-    &"期间",  // qijian
-    &"微信",  // weixin
-    &"凉茶",  // liangcha
-    &"批评",  // piping
-    &"迟到",  // chidao
-    &"人民",  // renmin
-    &"则",  // ze
-    &"文文",  // wenwen
-    &"商场",  // shangchang
-    &"空气",  // kongqi
-    &"新闻",  // xinwen
-    &"公斤",  // gongjin
-    &"水	税",  // shui
-    &"留学",  // liuxue
-    &"金鱼",  // jinyu
-    &"形式	形势",  // xingshi
-    &"预报",  // yubao
-    &"母亲",  // muqin
-    &"表格",  // biaoge
-    &"长江",  // changjiang
-    &"面包",  // mianbao
-    &"赵福根",  // zhaofugen
-    &"同意	统一",  // tongyi
-    &"已经",  // yijing
-    &"中秋节",  // zhongqiujie
-    &"恐怕",  // kongpa
-    &"都	逗",  // dou
-    &"运气",  // yunqi
-    &"推辞",  // tuici
-    &"贡献",  // gongxian
-    &"澳大利亚",  // aodaliya
-    &"子路",  // zilu
-    &"仔细",  // zixi
-    &"商业",  // shangye
-    &"六	留",  // liu
-    &"机场",  // jichang
-    &"张小龙",  // zhangxiaolong
-    &"刮风",  // guafeng
-    &"意见",  // yijian
-    &"归纳",  // guina
-    &"蔬菜",  // shucai
-    &"必然",  // biran
-    &"内",  // nei
-    &"演出",  // yanchu
-    &"理论",  // lilun
-    &"出示",  // chushi
-    &"玩儿",  // wanr
-    &"专业",  // zhuanye
-    &"果汁",  // guozhi
-    &"小	笑",  // xiao
-    &"过程",  // guocheng
-    &"满意",  // manyi
-    &"菊花",  // juhua
-    &"进步",  // jinbu
-    &"昨天",  // zuotian
-    &"讲	奖",  // jiang
-    &"降落",  // jiangluo
-    &"逻辑",  // luoji
-    &"游览",  // youlan
-    &"日历",  // rili
-    &"无论",  // wulun
-    &"来",  // lai
-    &"观点",  // guandian
-    &"此外",  // ciwai
-    &"便宜",  // pianyi
-    &"健身",  // jianshen
-    &"何况",  // hekuang
-    &"小伙子",  // xiaohuozi
-    &"《卖火柴的小女孩儿》",  // maihuochaidexiaonvhair
-    &"棚子",  // pengzi
-    &"复习",  // fuxi
-    &"来不及",  // laibuji
-    &"处理",  // chuli
-    &"以及",  // yiji
-    &"分手",  // fenshou
-    &"救护车",  // jiuhuche
-    &"员工",  // yuangong
-    &"冒险",  // maoxian
-    &"盒子",  // hezi
-    &"地铁",  // ditie
-    &"公主",  // gongzhu
-    &"补充",  // buchong
-    &"单位",  // danwei
-    &"火成岩",  // huochengyan
-    &"满足",  // manzu
-    &"精彩",  // jingcai
-    &"比如",  // biru
-    &"空闲",  // kongxian
-    &"胆小鬼",  // danxiaogui
-    &"醋",  // cu
-    &"道具",  // daoju
-    &"家乡",  // jiaxiang
-    &"嗯",  // ng
-    &"作者",  // zuozhe
-    &"多	朵",  // duo
-    &"办法",  // banfa
-    &"显得",  // xiande
-    &"反而",  // faner
-    &"时候",  // shihou
-    &"未必",  // weibi
-    &"尾气",  // weiqi
-    &"感兴趣",  // ganxingqu
-    &"概念",  // gainian
-    &"去	取	娶",  // qu
-    &"打折",  // dazhe
-    &"大象",  // daxiang
-    &"灰姑娘",  // huiguniang
-    &"招待",  // zhaodai
-    &"样子",  // yangzi
-    &"森林",  // senlin
-    &"使劲儿",  // shijinr
-    &"同样",  // tongyang
-    &"挂",  // gua
-    &"掌握",  // zhangwo
-    &"邮局",  // youju
-    &"轻松",  // qingsong
-    &"电子邮件",  // dianziyoujian
-    &"鲍全",  // baoquan
-    &"永远",  // yongyuan
-    &"种类",  // zhonglei
-    &"一共",  // yigong
-    &"运用",  // yunyong
-    &"老百姓",  // laobaixing
-    &"比较",  // bijiao
-    &"由来",  // youlai
-    &"责备",  // zebei
-    &"先	咸	县",  // xian
-    &"忙",  // mang
-    &"小区",  // xiaoqu
-    &"橡子",  // xiangzi
-    &"有关",  // youguan
-    &"愿意",  // yuanyi
-    &"亲戚",  // qinqi
-    &"国王",  // guowang
-    &"凉快",  // liangkuai
-    &"非常",  // feichang
-    &"建设",  // jianshe
-    &"提高",  // tigao
-    &"兴奋",  // xingfen
-    &"海鲜",  // haixian
-    &"白	百	摆",  // bai
-    &"出差",  // chuchai
-    &"失去",  // shiqu
-    &"可爱",  // keai
-    &"押金",  // yajin
-    &"交往",  // jiaowang
-    &"音乐",  // yinyue
-    &"脏",  // zang
-    &"身段",  // shenduan
-    &"袜子",  // wazi
-    &"装修",  // zhuangxiu
-    &"预习",  // yuxi
-    &"八	吧	把",  // ba
-    &"还	海",  // hai
-    &"急诊",  // jizhen
-    &"加利福尼亚州",  // jialifuniyazhou
-    &"天安门东",  // tiananmendong
-    &"人	认",  // ren
-    &"针对",  // zhendui
-    &"赚	转",  // zhuan
-    &"体育馆",  // tiyuguan
-    &"饼干",  // binggan
-    &"干活儿",  // ganhuoer
-    &"保证",  // baozheng
-    &"会员卡",  // huiyuanka
-    &"红",  // hong
-    &"方向",  // fangxiang
-    &"以来",  // yilai
-    &"原谅",  // yuanliang
-    &"努力",  // nuli
-    &"变化",  // bianhua
-    &"考虑",  // kaolv
-    &"巨大",  // juda
-    &"疼爱",  // tengai
-    &"成绩",  // chengji
-    &"射	蛇",  // she
-    &"银子",  // yinzi
-    &"气氛",  // qifen
-    &"聊天",  // liaotian
-    &"以前",  // yiqian
-    &"搜索",  // sousuo
-    &"中国",  // zhongguo
-    &"朗读",  // langdu
-    &"化学",  // huaxue
-    &"高速公路",  // gaosugonglu
-    &"农民",  // nongmin
-    &"至于",  // zhiyu
-    &"称赞",  // chengzan
-    &"一切",  // yiqie
-    &"妻子",  // qizi
-    &"举行",  // juxing
-    &"咱们",  // zanmen
-    &"遗憾",  // yihan
-    &"稻香村",  // daoxiangcun
-    &"教练",  // jiaolian
-    &"红色",  // hongse
-    &"虚心",  // xuxin
-    &"名片",  // mingpian
-    &"制冷",  // xhileng
-    &"相敬如宾",  // xiangjingrubin
-    &"成	称",  // cheng
-    &"跑步",  // paobu
-    &"情侣",  // qinglv
-    &"体贴",  // titie
-    &"猫	毛	冒",  // mao
-    &"本科",  // benke
-    &"效果",  // xiaoguo
-    &"成分",  // chengfen
-    &"重点",  // zhongdian
-    &"字母	字幕",  // zimu
-    &"利润",  // lirun
-    &"正好",  // zhenghao
-    &"如果",  // ruguo
-    &"热情",  // reqing
-    &"只	指	之	纸	支	治	直",  // zhi
-    &"最初",  // zuichu
-    &"植物",  // zhiwu
-    &"阿姨",  // ayi
-    &"正式",  // zhengshi
-    &"而且",  // erqie
-    &"从此",  // congci
-    &"握手",  // woshou
-    &"离开",  // likai
-    &"前面",  // qianmian
-    &"能",  // neng
-    &"反对",  // fandui
-    &"健步如飞",  // jianburufei
-    &"合同",  // hetong
-    &"行家",  // hangjia
-    &"申请",  // shenqing
-    &"擦",  // ca
-    &"自由",  // ziyou
-    &"工资",  // gongzi
-    &"接近",  // jiejin
-    &"椅子",  // yizi
-    &"热烈",  // relie
-    &"小说",  // xiaoshuo
-    &"敌人",  // diren
-    &"似的",  // shide
-    &"吵	朝	炒	抄",  // chao
-    &"平静",  // pingjing
-    &"帮助",  // bangzhu
-    &"部分",  // bufen
-    &"承担",  // chengdan
-    &"肥皂",  // feizao
-    &"至少",  // zhishao
-    &"预防",  // yufang
-    &"按时",  // anshi
-    &"盐",  // yan
-    &"推广",  // tuiguang
-    &"十	是	试	使	诗",  // shi
-    &"拍	派",  // pai
-    &"其他",  // qita
-    &"些	写	谢	斜	歇	血",  // xie
-    &"断断续续",  // duanduanxuxu
-    &"今天",  // jintian
-    &"辣椒",  // lajiao
-    &"请假",  // qingjia
-    &"兄弟",  // xiongdi
-    &"波动",  // bodong
-    &"售货员",  // shouhuoyuan
-    &"寻找",  // xunzhao
-    &"模仿",  // mofang
-    &"调查",  // diaocha
-    &"风俗",  // fengsu
-    &"自然",  // ziran
-    &"所	锁",  // suo
-    &"让",  // rang
-    &"流血",  // liuxie
-    &"实在",  // shizai
-    &"送",  // song
-    &"组合",  // zuhe
-    &"肯定",  // kending
-    &"想念",  // xiangnian
-    &"日常",  // richang
-    &"投资",  // touzi
-    &"行为",  // xingwei
-    &"灰心",  // huixin
-    &"塑料袋",  // suliaodai
-    &"否认",  // fouren
-    &"安娜",  // anna
-    &"应该",  // yinggai
-    &"粘贴",  // zhantie
-    &"姑娘",  // guniang
-    &"但是",  // danshi
-    &"摸",  // mo
-    &"总统",  // zongtong
-    &"材料",  // cailiao
-    &"卡车",  // kache
-    &"智慧	指挥",  // zhihui
-    &"啄",  // zhuo
-    &"这	着	折",  // zhe
-    &"沙滩",  // shatan
-    &"一起",  // yiqi
-    &"寄生",  // jisheng
-    &"鼓楼大街",  // guloudajie
-    &"愉快",  // yukuai
-    &"运输",  // yunshu
-    &"沙子",  // shazi
-    &"重量",  // zhongliang
-    &"婚礼",  // hunli
-    &"低落",  // diluo
-    &"国君",  // guojun
-    &"冷静",  // lengjing
-    &"改善",  // gaishan
-    &"删除",  // shanchu
-    &"照片",  // zhaopian
-    &"旅游",  // lvyou
-    &"汇率",  // huilv
-    &"互相",  // huxiang
-    &"蒙眬",  // menglong
-    &"杂志",  // zazhi
-    &"形状",  // xingzhuang
-    &"公司",  // gongsi
-    &"纪念",  // jinian
-    &"演员",  // yanyuan
-    &"免费",  // mianfei
-    &"不同",  // butong
-    &"对手",  // duishou
-    &"完美",  // wanmei
-    &"样式",  // yangshi
-    &"燃烧",  // ranshao
-    &"时尚",  // shishang
-    &"姐姐",  // jiejie
-    &"活动",  // huodong
-    &"陪",  // pei
-    &"练习	联系",  // lianxi
-    &"抓",  // zhua
-    &"想	向	像	香	响	项	象",  // xiang
-    &"辞职",  // cizhi
-    &"仍然",  // rengran
-    &"促使",  // cushi
-    &"课程",  // kecheng
-    &"暂时",  // zanshi
-    &"记忆",  // jiyi
-    &"高	搞",  // gao
-    &"获得",  // huode
-    &"耳环",  // erhuan
-    &"可惜",  // kexi
-    &"不然",  // buran
-    &"气候",  // qihou
-    &"坐	做	座",  // zuo
-    &"灵活",  // linghuo
-    &"遇到",  // yudao
-    &"结婚",  // jiehun
-    &"广播",  // guangbo
-    &"西汉",  // xihan
-    &"尽快",  // jinkuai
-    &"才艺",  // caiyi
-    &"世界",  // shijie
-    &"数码",  // shuma
-    &"表达",  // biaoda
-    &"仿佛",  // fangfu
-    &"分布",  // fenbu
-    &"决定",  // jueding
-    &"走",  // zou
-    &"航班",  // hangban
-    &"编辑",  // bianji
-    &"录音",  // luyin
-    &"合影",  // heying
-    &"车库",  // cheku
-    &"本领",  // benling
-    &"围绕",  // weirao
-    &"家访",  // jiafang
-    &"身份",  // shenfen
-    &"和尚",  // heshang
-    &"机会",  // jihui
-    &"毕竟",  // bijing
-    &"专家",  // zhuanjia
-    &"差不多",  // chabuduo
-    &"民居",  // minju
-    &"广告",  // guanggao
-    &"时间	实践",  // shijian
-    &"比尔·盖茨",  // biergaici
-    &"手续",  // shouxu
-    &"家具",  // jiaju
-    &"通常",  // tongchang
-    &"比",  // bi
-    &"每天",  // meitian
-    &"情趣",  // qingqu
-    &"红酒",  // hongjiu
-    &"别",  // bie
-    &"花	画	滑	划",  // hua
-    &"饿	额",  // e
-    &"患难与共",  // huannanyugong
-    &"支教",  // zhijiao
-    &"填空	天空",  // tiankong
-    &"道理",  // daoli
-    &"大约",  // dayue
-    &"查询",  // chaxun
-    &"充满",  // chongman
-    &"饮料",  // yinliao
-    &"接	借	节	街	戒	届",  // jie
-    &"及格",  // jige
-    &"成果",  // chengguo
-    &"宿舍",  // sushe
-    &"干燥",  // ganzao
-    &"交换",  // jiaohuan
-    &"用",  // yong
-    &"同情",  // tongqing
-    &"发明",  // faming
-    &"吸引",  // xiyin
-    &"厘米",  // limi
-    &"华裔",  // huayi
-    &"东海龙王",  // donghailongwang
-    &"态度",  // taidu
-    &"杨	养	痒",  // yang
-    &"佩服",  // peifu
-    &"情景",  // qingjing
-    &"权力	权利",  // quanli
-    &"盼望",  // panwang
-    &"大家",  // dajia
-    &"好像",  // haoxiang
-    &"懂	东	冬	冻	洞",  // dong
-    &"国	过	锅",  // guo
-    &"消息",  // xiaoxi
-    &"地图",  // ditu
-    &"却",  // que
-    &"专注",  // zhuanzhu
-    &"关	官",  // guan
-    &"并",  // bing
-    &"洛杉矶",  // luoshanji
-    &"业务",  // yewu
-    &"强调",  // qiangdiao
-    &"百分之",  // baifenzhi
-    &"夏威夷",  // xiaweiyi
-    &"新西兰",  // xinxilan
-    &"软件",  // ruanjian
-    &"严重",  // yanzhong
-    &"代替",  // daiti
-    &"师傅",  // shifu
-    &"梦",  // meng
-    &"参考",  // cankao
-    &"怪物",  // guaiwu
-    &"影响",  // yingxiang
-    &"，",  // ,
-    &"堵车",  // duche
-    &"某",  // mou
-    &"传真",  // chuanzhen
-    &"短	段	断",  // duan
-    &"蹲	顿	吨",  // dun
-    &"或者",  // huozhe
-    &"开心",  // kaixin
-    &"沙丁鱼",  // shadingyu
-    &"爱惜",  // aixi
-    &"时差",  // shicha
-    &"见面",  // jianmian
-    &"啤酒",  // pijiu
-    &"猴子",  // houzi
-    &"失业",  // shiye
-    &"教材",  // jiaocai
-    &"召开",  // zhaokai
-    &"悲伤",  // beishang
-    &"意外",  // yiwai
-    &"运动",  // yundong
-    &"耐心",  // naixin
-    &"宁可",  // ningke
-    &"养成",  // yangcheng
-    &"父母",  // fumu
-    &"扩大",  // kuoda
-    &"其次",  // qici
-    &"着凉",  // zhaoliang
-    &"包括",  // baokuo
-    &"群",  // qun
-    &"道德",  // daode
-    &"蜜蜂",  // mifeng
-    &"发言",  // fayan
-    &"平安",  // pingan
-    &"独立",  // duli
-    &"困难",  // kunnan
-    &"害羞",  // haixiu
-    &"发愁",  // fachou
-    &"打印",  // dayin
-    &"亲爱",  // qinai
-    &"政治",  // zhengzhi
-    &"演讲",  // yanjiang
-    &"绕",  // rao
-    &"减肥",  // jianfei
-    &"热心",  // rexin
-    &"火	货",  // huo
-    &"热",  // re
-    &"省	剩	升",  // sheng
-    &"随着",  // suizhe
-    &"奔跑",  // benpao
-    &"欣赏",  // xinshang
-    &"刚",  // gang
-    &"机制",  // jizhi
-    &"西瓜",  // xigua
-    &"缺少",  // queshao
-    &"政府",  // zhengfu
-    &"手笔",  // shoubi
-    &"袋子",  // daizi
-    &"成功",  // chenggong
-    &"羊肉",  // yangrou
-    &"私人",  // siren
-    &"国贸",  // guomao
-    &"！",  // !
-    &"火柴",  // huochai
-    &"调皮",  // tiaopi
-    &"采用",  // caiyong
-    &"邻居",  // linju
-    &"受到",  // shoudao
-    &"作文",  // zuowen
-    &"阴	银",  // yin
-    &"维修",  // weixiu
-    &"终于",  // zhongyu
-    &"咳嗽",  // kesou
-    &"总裁",  // zongcai
-    &"笔试",  // bishi
-    &"罚款",  // fakuan
-    &"教育",  // jiaoyu
-    &"山",  // shan
-    &"吸收",  // xishou
-    &"头发",  // toufa
-    &"一般",  // yiban
-    &"伟大",  // weida
-    &"出生",  // chusheng
-    &"情缘",  // qingyuan
-    &"舒适",  // shushi
-    &"称霸",  // chengba
-    &"首先",  // shouxian
-    &"印刷",  // yinshua
-    &"锻炼",  // duanlian
-    &"蒸腾",  // zhengteng
-    &"拼音",  // pinyin
-    &"感冒",  // ganmao
-    &"洗礼",  // xili
-    &"行动",  // xingdong
-    &"思考",  // sikao
-    &"心理",  // xinli
-    &"左边",  // zuobian
-    &"万丈",  // wanzhang
-    &"报社",  // baoshe
-    &"帮忙",  // bangmang
-    &"汉字",  // hanzi
-    &"脑袋",  // naodai
-    &"综合",  // zonghe
-    &"美国",  // meiguo
-    &"创办",  // chuangban
-    &"面积",  // mianji
-    &"乐器",  // yueqi
-    &"千万",  // qianwan
-    &"形容",  // xingrong
-    &"痛苦",  // tongku
-    &"看望",  // kanwang
-    &"零钱",  // lingqian
-    &"大型",  // daxing
-    &"有效",  // youxiao
-    &"签证",  // qianzheng
-    &"绳子",  // shengzi
-    &"硕士",  // shuoshi
-    &"淡",  // dan
-    &"重新",  // chongxin
-    &"二手",  // ershou
-    &"数学",  // shuxue
-    &"名胜古迹",  // mingshengguji
-    &"到时候",  // daoshihou
-    &"许多",  // xuduo
-    &"下载",  // xiazai
-    &"自信",  // zixin
-    &"电视",  // dianshi
-    &"活泼",  // huopo
-    &"初级",  // chuji
-    &"属于",  // shuyu
-    &"记得",  // jide
-    &"梦想",  // mengxiang
-    &"根治",  // genzhi
-    &"奋斗",  // fendou
-    &"累	雷",  // lei
-    &"破",  // po
-    &"不行",  // buxing
-    &"扔",  // reng
-    &"躲藏",  // duocang
-    &"节省",  // jiesheng
-    &"制造",  // zhizao
-    &"《",  // <
-    &"娱乐",  // youle
-    &"艺术",  // yishu
-    &"违反",  // weifan
-    &"培养",  // peiyang
-    &"舅舅",  // jiujiu
-    &"破产",  // pochan
-    &"除非",  // chufei
-    &"文字	蚊子",  // wenzi
-    &"表",  // biao
-    &"一点儿",  // yidianr
-    &"开发",  // kaifa
-    &"迎接",  // yingjie
-    &"王宫",  // wanggong
-    &"果实",  // guoshi
-    &"当地",  // dangdi
-    &"同学",  // tongxue
-    &"问	闻	吻",  // wen
-    &"复制",  // fuzhi
-    &"东西",  // dongxi
-    &"服务员",  // fuwuyuan
-    &"脸	连",  // lian
-    &"万一",  // wanyi
-    &"帮	棒",  // bang
-    &"物理",  // wuli
-    &"结论",  // jielun
-    &"妨碍",  // fangai
-    &"包子",  // baozi
-    &"宣布",  // xuanbu
-    &"张	长	涨",  // zhang
-    &"零	另	铃	令",  // ling
-    &"太	台	抬",  // tai
-    &"光滑",  // guanghua
-    &"模糊",  // mohu
-    &"票	飘",  // piao
-    &"？",  // ?
-    &"生日",  // shengri
-    &"往往",  // wangwang
-    &"蓝	懒	拦	烂",  // lan
-    &"慢	满",  // man
-    &"参与",  // canyu
-    &"文火",  // wenhuo
-    &"进口",  // jinkou
-    &"话题",  // huati
-    &"从来",  // conglai
-    &"询问",  // xunwen
-    &"秦国",  // qinguo
-    &"早上",  // zaoshang
-    &"真实",  // zhenshi
-    &"美术",  // meishu
-    &"蛋糕",  // dangao
-    &"顺畅",  // shunchang
-    &"呢",  // ne
-    &"当时",  // dangshi
-    &"自觉",  // zijue
-    &"面对",  // miandui
-    &"办公室",  // bangongshi
-    &"动作",  // dongzuo
-    &"新鲜感",  // xinxiangan
-    &"水果",  // shuiguo
-    &"进	近	尽",  // jin
-    &"准备",  // zhunbei
-    &"增加",  // zengjia
-    &"纪律",  // jilv
-    &"看不起",  // kanbuqi
-    &"象棋",  // xiangqi
-    &"笑话	消化",  // xiaohua
-    &"接待",  // jiedai
-    &"支持",  // zhichi
-    &"缓解",  // huanjie
-    &"小姐",  // xiaojie
-    &"周到",  // zhoudao
-    &"时刻",  // shike
-    &"门槛",  // menkan
-    &"打交道",  // dajiaodao
-    &"出	处	除",  // chu
-    &"汗腺",  // hanxian
-    &"翻	犯",  // fan
-    &"误会",  // wuhui
-    &"使用	实用",  // shiyong
-    &"价格",  // jiage
-    &"强烈",  // qianglie
-    &"操心",  // caoxin
-    &"青春",  // qingchun
-    &"四合院",  // siheyuan
-    &"后来",  // houlai
-    &"上当",  // shangdang
-    &"枝干	知感",  // zhigan
-    &"值得",  // zhide
-    &"折叠",  // zhedie
-    &"描写",  // miaoxie
-    &"地震",  // dizhen
-    &"比例",  // bili
-    &"事半功倍",  // shibangongbei
-    &"穿	船",  // chuan
-    &"打篮球",  // dalanqiu
-    &"坚强",  // jianqiang
-    &"李广",  // liguang
-    &"规定",  // guiding
-    &"笔记",  // biji
-    &"音乐会",  // yinyuehui
-    &"牛仔裤",  // niuzaiku
-    &"就餐",  // jiucan
-    &"回	会	挥	灰",  // hui
-    &"《说文解字》",  // shuowenjiezi
-    &"性质",  // xingzhi
-    &"支票",  // zhipiao
-    &"矛盾",  // maodun
-    &"包含",  // baohan
-    &"云南",  // yunnan
-    &"健伟",  // jianwei
-    &"酒吧",  // jiuba
-    &"士兵",  // shibing
-    &"充电器",  // chongdianqi
-    &"神秘",  // shenmi
-    &"黄金",  // huangjin
-    &"除夕	出席",  // chuxi
-    &"博物馆",  // bowuguan
-    &"目的",  // mudi
-    &"耳朵",  // erduo
-    &"熬夜",  // aoye
-    &"请求",  // qingqiu
-    &"唱歌",  // changge
-    &"赢	硬",  // ying
-    &"观众",  // guanzhong
-    &"普通话",  // putonghua
-    &"目前",  // muqian
-    &"月亮",  // yueliang
-    &"风浪",  // fenglang
-    &"功夫",  // gongfu
-    &"胜利",  // shengli
-    &"工业",  // gongye
-    &"年轻",  // nianqing
-    &"丛林",  // conglin
-    &"颠球",  // dianqiu
-    &"贷款",  // daikuan
-    &"广东省",  // guangdongsheng
-    &"速度",  // sudu
-    &"放	方",  // fang
-    &"国庆节",  // guoqingjie
-    &"不过",  // buguo
-    &"财产",  // caichan
-    &"鲇鱼",  // nianyu
-    &"谈判",  // tanpan
-    &"复印",  // fuyin
-    &"贵",  // gui
-    &"也许",  // yexu
-    &"太阳",  // taiyang
-    &"辅导",  // fudao
-    &"利息",  // lixi
-    &"家	假	加	甲	嫁	佳",  // jia
-    &"一般来说",  // yibanlaishuo
-    &"原则",  // yuanze
-    &"爬山",  // pashan
-    &"干脆",  // gancui
-    &"初中",  // chuzhong
-    &"人类",  // renlei
-    &"郝林说",  // haolinshuo
-    &"七郎",  // qilang
-    &"骄傲",  // jiaoao
-    &"手指",  // shouzhi
-    &"礼物",  // liwu
-    &"豆腐",  // doufu
-    &"沟通",  // goutong
-    &"教训",  // jiaoxun
-    &"提供",  // tigong
-    &"公寓",  // gongyu
-    &"暖",  // nuan
-    &"欧洲",  // ouzhou
-    &"多余",  // duoyu
-    &"建议",  // jianyi
-    &"主席",  // zhuxi
-    &"经常",  // jingchang
-    &"排列",  // pailie
-    &"聪明",  // congming
-    &"老婆",  // laopo
-    &"和平",  // heping
-    &"上海	伤害",  // shanghai
-    &"顺序",  // shunxu
-    &"争取",  // zhengqu
-    &"假如",  // jiaru
-    &"瓶子",  // pingzi
-    &"勤奋",  // qinfen
-    &"轮",  // lun
-    &"雇",  // gu
-    &"光	逛",  // guang
-    &"呼朋唤友",  // hupenghuanyou
-    &"王	往",  // wang
-    &"营业",  // yingye
-    &"岛屿",  // daoyu
-    &"路口",  // lukou
-    &"评委",  // pingwei
-    &"刘辰",  // liuchen
-    &"地址",  // dizhi
-    &"加班",  // jiaban
-    &"丹尼尔·卡内曼",  // dannierkaneiman
-    &"流传",  // liuchuan
-    &"报告",  // baogao
-    &"不但",  // budan
-    &"成立",  // chengli
-    &"修理",  // xiuli
-    &"待遇",  // daiyu
-    &"兑换",  // duihuan
-    &"教授",  // jiaoshou
-    &"刘炽平",  // liuchiping
-    &"本质",  // benzhi
-    &"天气",  // tianqi
-    &"赶快",  // gankuai
-    &"放松",  // fangsong
-    &"只有",  // zhiyou
-    &"打电话",  // dadianhua
-    &"曾经",  // cengjing
-    &"故意",  // guyi
-    &"对话",  // duihua
-    &"角度",  // jiaodu
-    &"同事	同时",  // tongshi
-    &"偶尔",  // ouer
-    &"系统",  // xitong
-    &"关键",  // guanjian
-    &"民族",  // minzu
-    &"连忙",  // lianmang
-    &"否则",  // fouze
-    &"卫生间",  // weishengjian
-    &"昙花",  // tanhua
-    &"歪歪扭扭",  // waiwainiuniu
-    &"赔偿",  // peichang
-    &"得",  // dei
-    &"马萨诸塞州",  // masazhusaizhou
-    &"延长",  // yanchang
-    &"舜",  // shun
-    &"怪	乖",  // guai
-    &"重复",  // chongfu
-    &"神话",  // shenhua
-    &"能源",  // nengyuan
-    &"移动",  // yidong
-    &"皮鞋",  // pixie
-    &"我",  // wo
-    &"杨雄",  // yangxiong
-    &"药	要	摇	咬	腰",  // yao
-    &"护照",  // huzhao
-    &"赏心悦目",  // shangxinyuemu
-    &"价钱",  // jiaqian
-    &"大卫",  // dawei
-    &"国家",  // guojia
-    &"房子",  // fangzi
-    &"节日",  // jieri
-    &"接受",  // jieshou
-    &"否定",  // fouding
-    &"匆忙",  // congmang
-    &"小麦",  // xiaomai
-    &"食物	事物",  // shiwu
-    &"电梯",  // dianti
-    &"长城",  // changcheng
-    &"刺激",  // ciji
-    &"第一",  // diyi
-    &"悠久",  // youjiu
-    &"熟练",  // shulian
-    &"天津卫视",  // tianjinweishi
-    &"笑笑",  // xiaoxiao
-    &"错误",  // cuowu
-    &"现在",  // xianzai
-    &"网站",  // wangzhan
-    &"卢米埃尔",  // lumiaier
-    &"静",  // jing
-    &"结账",  // jiezhang
-    &"信用卡",  // xinyongka
-    &"详细",  // xiangxi
-    &"合法",  // hefa
-    &"手	瘦	收	首	受	守",  // shou
-    &"海关",  // haiguan
-    &"乱",  // luan
-    &"哲学家",  // zhexuejia
-    &"存活",  // cunhuo
-    &"呼吸",  // huxi
-    &"决赛",  // juesai
-    &"池塘",  // chitang
-    &"克服",  // kefu
-    &"宝贵",  // baogui
-    &"学问",  // xuewen
-    &"休闲",  // xiuxian
-    &"用途",  // yongtu
-    &"对于",  // duiyu
-    &"吃亏",  // chikui
-    &"紧急",  // jinji
-    &"大厦",  // dasha
-    &"咱",  // zan
-    &"沉默",  // chenmo
-    &"文章",  // wenzhang
-    &"安静",  // anjing
-    &"执照",  // zhizhao
-    &"租	组",  // zu
-    &"创造",  // chuangzao
-    &"《彷徨》",  // panghuang
-    &"遵守",  // zunshou
-    &"促进",  // cujin
-    &"移民",  // yimin
-    &"校长",  // xiaozhang
-    &"了不起",  // liaobuqi
-    &"匹	批	披",  // pi
-    &"根基",  // genji
-    &"勇敢",  // yonggan
-    &"体育",  // tiyu
-    &"石灰岩",  // shihuiyan
-    &"坚持",  // jianchi
-    &"着急",  // zhaoji
-    &"赞美",  // zanmai
-    &"作用",  // zuoyong
-    &"多亏",  // duokui
-    &"转头",  // zhuantou
-    &"非	废",  // fei
-    &"毛孔",  // maokong
-    &"立即",  // liji
-    &"翟峰",  // zhaifeng
-    &"享受",  // xiangshou
-    &"肾上腺素",  // shenshangxiansu
-    &"晚上",  // wanshang
-    &"超级",  // chaoji
-    &"到	刀	倒",  // dao
-    &"地位",  // diwei
-    &"点",  // dian
-    &"买	卖	埋",  // mai
-    &"孙月",  // sunyue
-    &"文明",  // wenming
-    &"电脑",  // diannao
-    &"了",  // le
-    &"后果",  // houguo
-    &"九	就	久	旧	酒	救",  // jiu
-    &"主张",  // zhuzhang
-    &"手机	收集",  // shouji
-    &"真正",  // zhenzheng
-    &"人民币",  // renminbi
-    &"卧室",  // woshi
-    &"平常",  // pingchang
-    &"爱护",  // aihu
-    &"阻止	组织",  // zuzhi
-    &"准确",  // zhunque
-    &"自从",  // zicong
-    &"完	玩	碗	万",  // wan
-    &"孝敬",  // xiaojing
-    &"左右",  // zuoyou
-    &"多少",  // duoshao
-    &"高档",  // gaodang
-    &"效率",  // xiaolv
-    &"外地",  // waidi
-    &"设备",  // shebei
-    &"解释	结实	节食",  // jieshi
-    &"郁达夫",  // yudafu
-    &"提醒",  // tixing
-    &"狗	够	购",  // gou
-    &"翅膀",  // chibang
-    &"祝福",  // zhufu
-    &"专心",  // zhuanxin
-    &"热闹",  // renao
-    &"喜欢",  // xihuan
-    &"经商",  // jingshang
-    &"登记",  // dengji
-    &"中旬",  // zhongxun
-    &"突然",  // turan
-    &"女儿",  // nver
-    &"温度",  // wendu
-    &"石头",  // shitou
-    &"首都",  // shoudu
-    &"还	换",  // huan
-    &"一辈子",  // yibeizi
-    &"负责",  // fuze
-    &"长江大桥",  // changjiangdaqiao
-    &"传播",  // chuanbo
-    &"特殊",  // teshu
-    &"有	又	由",  // you
-    &"培训",  // peixun
-    &"诊断",  // zhenduan
-    &"陌生",  // mosheng
-    &"议论",  // yilun
-    &"个人",  // geren
-    &"退休",  // tuixiu
-    &"负重",  // fuzhong
-    &"暗暗",  // anan
-    &"度过",  // duguo
-    &"恢复",  // huifu
-    &"模特",  // moter
-    &"及时	即使",  // jishi
-    &"跳舞",  // tiaowu
-    &"分别",  // fenbie
-    &"环境",  // huanjing
-    &"动物",  // dongwu
-    &"集中",  // jizhong
-    &"资金",  // zijin
-    &"中心",  // zhongxin
-    &"概括",  // gaikuo
-    &"的确",  // dique
-    &"出自",  // chuzi
-    &"形象",  // xingxiang
-    &"中介",  // zhongjie
-    &"改正",  // gaizheng
-    &"窗户",  // chuanghu
-    &"鲜花",  // xianhua
-    &"至今",  // zhijin
-    &"不	部	布",  // bu
-    &"领导",  // lingdao
-    &"难免",  // nanmian
-    &"窄	摘",  // zhai
-    &"银行",  // yinhang
-    &"恶劣",  // elie
-    &"饭店",  // fandian
-    &"困",  // kun
-    &"标志",  // biaozhi
-    &"爱心",  // aixin
-    &"集合",  // jihe
-    &"周末",  // zhoumo
-    &"最好",  // zuihao
-    &"地毯",  // ditan
-    &"胸",  // xiong
-    &"天敌",  // tiandi
-    &"赶紧",  // ganjin
-    &"林	临",  // lin
-    &"愿望",  // yuanwang
-    &"点心",  // dianxin
-    &"可乐",  // kele
-    &"兔子",  // tuzi
-    &"拆",  // chai
-    &"安装",  // anzhuang
-    &"不管",  // buguan
-    &"老",  // lao
-    &"奖金",  // jiangjin
-    &"经理	经历	精力",  // jingli
-    &"例如",  // liru
-    &"追",  // zhui
-    &"善于",  // shanyu
-    &"调整",  // tiaozheng
-    &"节目",  // jiemu
-    &"感谢",  // ganxie
-    &"伤心",  // shangxin
-    &"不少",  // bushao
-    &"地理",  // dili
-    &"显示	现实",  // xianshi
-    &"房东",  // fangdong
-    &"好吃",  // haochi
-    &"脖子",  // bozi
-    &"礼貌",  // limao
-    &"避免",  // bimian
-    &"地道",  // didao
-    &"最近",  // zuijin
-    &"可以",  // keyi
-    &"挣	睁	正",  // zheng
-    &"失望",  // shiwang
-    &"取消",  // quxiao
-    &"估计",  // guji
-    &"著名",  // zhuming
-    &"朋友",  // pengyou
-    &"持续",  // chixu
-    &"说服",  // shuofu
-    &"油炸",  // youzha
-    &"在	再",  // zai
-    &"外	歪",  // wai
-    &"冠军",  // guanjun
-    &"杀	傻",  // sha
-    &"组成",  // zucheng
-    &"女孩",  // nvhai
-    &"黑",  // hei
-    &"问题",  // wenti
-    &"判断",  // panduan
-    &"太太",  // taitai
-    &"烤鸭",  // kaoya
-    &"开",  // kai
-    &"战争",  // zhanzheng
-    &"所有",  // suoyou
-    &"冰箱",  // bingxiang
-    &"呀",  // ya
-    &"学术",  // xueshu
-    &"辩论",  // bianlun
-    &"舒服",  // shufu
-    &"姓	行	醒",  // xing
-    &"列车",  // lieche
-    &"任何",  // renhe
-    &"单纯",  // danchun
-    &"周围",  // zhouwei
-    &"语法",  // yufa
-    &"耽误",  // danwu
-    &"对不起",  // duibuqi
-    &"屋子",  // wuzi
-    &"考试",  // kaoshi
-    &"弄	浓",  // nong
-    &"正确",  // zhengque
-    &"工厂",  // gongchang
-    &"风格",  // fengge
-    &"起床",  // qichuang
-    &"操场",  // caochang
-    &"顺利",  // shunli
-    &"尊敬",  // zunjing
-    &"高级",  // gaoji
-    &"主观",  // zhuguan
-    &"宽",  // kuan
-    &"然而",  // raner
-    &"不要紧",  // buyaojin
-    &"结构",  // jiegou
-    &"劳驾",  // laojia
-    &"对待",  // duidai
-    &"妇女",  // funv
-    &"天真",  // tianzhen
-    &"开始",  // kaishi
-    &"趋势	去世",  // qushi
-    &"诚恳",  // chengken
-    &"作家",  // zuojia
-    &"内容",  // neirong
-    &"咖啡",  // kafei
-    &"羡慕",  // xianmu
-    &"孔子",  // kongzi
-    &"辛苦",  // xinku
-    &"陆地",  // ludi
-    &"条件",  // tiaojian
-    &"工作",  // gongzuo
-    &"失眠",  // shimian
-    &"简单",  // jiandan
-    &"注册",  // zhuce
-    &"项链",  // xianglian
-    &"电影",  // dianying
-    &"招聘",  // zhaopin
-    &"》",  // >
-    &"狡猾",  // jiaohua
-    &"怀疑",  // huaiyi
-    &"经营",  // jingying
-    &"大概",  // dagai
-    &"关系",  // guanxi
-    &"朋	碰",  // peng
-    &"输入",  // shuru
-    &"过分",  // guofen
-    &"发生",  // fasheng
-    &"逃	桃	套",  // tao
-    &"彩虹",  // caihong
-    &"王子",  // wangzi
-    &"好	号",  // hao
-    &"害怕",  // haipa
-    &"记录	纪录",  // jilu
-    &"规则",  // guize
-    &"播放",  // bofang
-    &"顺便",  // shunbian
-    &"怪不得",  // guaibude
-    &"餐厅",  // canting
-    &"广泛",  // guangfan
-    &"现金",  // xianjin
-    &"肚子",  // duzi
-    &"嗯",  // en
-    &"公园	公元",  // gongyuan
-    &"济南",  // jinan
-    &"吃惊",  // chijing
-    &"聚会",  // juhui
-    &"抱歉",  // baoqian
-    &"看见",  // kanjian
-    &"亲切",  // qinqie
-    &"主题",  // zhuti
-    &"谈	痰",  // tan
-    &"春节",  // chunjie
-    &"季节",  // jijie
-    &"火车站",  // huochezhan
-    &"劳动",  // laodong
-    &"生长",  // shengzhang
-    &"牛津大学",  // niujindaxue
-    &"复杂",  // fuza
-    &"方便",  // fangbian
-    &"舍不得",  // shebude
-    &"功能",  // gongneng
-    &"社会",  // shehui
-    &"自豪",  // zihao
-    &"参加",  // canjia
-    &"目录",  // mulu
-    &"好奇",  // haoqi
-    &"人生",  // rensheng
-    &"温柔",  // wenrou
-    &"认识	人事",  // renshi
-    &"勺子",  // shaozi
-    &"外面",  // waimian
-    &"时代",  // shidai
-    &"受伤",  // shoushang
-    &"附近",  // fujin
-    &"放弃",  // fangqi
-    &"不见得",  // bujiande
-    &"坦率",  // tanshuai
-    &"学校",  // xuexiao
-    &"媒体",  // meiti
-    &"印加",  // yinjia
-    &"容易",  // rongyi
-    &"驾驶",  // jiashi
-    &"几	极	寄	即	击	系	挤",  // ji
-    &"父亲",  // fuqin
-    &"儿子",  // erzi
-    &"潘家园",  // panjiayuan
-    &"摄影",  // sheying
-    &"打算",  // dasuan
-    &"书	树	输	数",  // shu
-    &"青壮年",  // qingzhuangnian
-    &"制度",  // zhidu
-    &"骨头",  // gutou
-    &"打招呼",  // dazhaohu
-    &"尺子",  // chizi
-    &"尽管",  // jinguan
-    &"酱油",  // jiangyou
-    &"作战",  // zuozhan
-    &"改革",  // gaige
-    &"去年",  // qunian
-    &"代表",  // daibiao
-    &"七	骑	棋	起",  // qi
-    &"字	紫",  // zi
-    &"住	祝	猪	煮",  // zhu
-    &"危害",  // weihai
-    &"显然",  // xianran
-    &"期待",  // qidai
-    &"完整",  // wanzheng
-    &"常识",  // changshi
-    &"明白",  // mingbai
-    &"整理",  // zhengli
-    &"成长",  // chengzhang
-    &"麻烦",  // mafan
-    &"似乎",  // sihu
-    &"次",  // ci
-    &"鼓舞",  // guwu
-    &"发",  // fa
-    &"摩托车",  // motuoche
-    &"别人",  // bieren
-    &"医院",  // yiyuan
-    &"入口",  // rukou
-    &"：",  // :
-    &"艰苦",  // jianku
-    &"发现",  // faxian
-    &"结合",  // jiehe
-    &"激动",  // jidong
-    &"感动",  // gandong
-    &"以后",  // yihou
-    &"特色",  // tese
-    &"右边",  // youbian
-    &"告别",  // gaobie
-    &"果然",  // guoran
-    &"词典",  // cidian
-    &"观察",  // guancha
-    &"学	雪",  // xue
-    &"应用",  // yingyong
-    &"哪儿	那儿",  // nar
-    &"上班",  // shangban
-    &"疲劳",  // pilao
-    &"滚	棍",  // gun
-    &"交流",  // jiaoliu
-    &"个子	各自",  // gezi
-    &"站	占",  // zhan
-    &"差异",  // chayi
-    &"商量",  // shangliang
-    &"灰尘",  // huichen
-    &"敏感",  // mingan
-    &"合理",  // heli
-    &"总共",  // zonggong
-    &"过敏",  // guomin
-    &"提前",  // tiqian
-    &"配合",  // peihe
-    &"营养",  // yingyang
-    &"具备",  // jubei
-    &"身材",  // shencai
-    &"失败",  // shibai
-    &"岁	碎",  // sui
-    &"绿	屡",  // lv
-    &"打听",  // dating
-    &"急忙",  // jimang
-    &"英俊",  // yingjun
-    &"按	岸	暗",  // an
-    &"寒假",  // hanjia
-    &"腾讯",  // tengxun
-    &"心脏",  // xinzang
-    &"扇子",  // shanzi
-    &"梅西",  // meixi
-    &"总是",  // zongshi
-    &"工具",  // gongju
-    &"最后",  // zuihou
-    &"对象",  // duixiang
-    &"、",  // \
-    &"上午	商务",  // shangwu
-    &"位于",  // weiyu
-    &"严肃",  // yansu
-    &"活跃",  // huoyue
-    &"成为",  // chengwei
-    &"理想",  // lixiang
-    &"证明",  // zhengming
-    &"鸡蛋",  // jidan
-    &"相似",  // xiangsi
-    &"戒指",  // jiezhi
-    &"气温",  // qiwen
-    &"苹果",  // pingguo
-    &"热爱",  // reai
-    &"充分",  // chongfen
-    &"书架",  // shujia
-    &"从而",  // conger
-    &"吵架",  // chaojia
-    &"门",  // men
-    &"后面",  // houmian
-    &"五	无	屋	勿	雾",  // wu
-    &"大学",  // daxue
-    &"平时",  // pingshi
-    &"因为",  // yinwei
-    &"体现",  // tixian
-    &"核心",  // hexin
-    &"糟糕",  // zaogao
-    &"身边",  // shenbian
-    &"刷牙",  // shuaya
-    &"迫切",  // poqie
-    &"马克",  // make
-    &"美德",  // meide
-    &"爱情",  // aiqing
-    &"表示",  // biaoshi
-    &"宣传",  // xuanchuan
-    &"司机",  // siji
-    &"明确",  // mingque
-    &"家务",  // jiawu
-    &"青蛙",  // qingwa
-    &"廉颇",  // lianpo
-    &"丑	臭	抽",  // chou
-    &"唯一",  // weiyi
-    &"光临",  // guanglin
-    &"既然",  // jiran
-    &"包裹",  // baoguo
-    &"词汇",  // cihui
-    &"冲",  // chong
-    &"皮肤",  // pifu
-    &"空间",  // kongjian
-    &"单调",  // dandiao
-    &"脾气",  // piqi
-    &"因素",  // yinsu
-    &"公平",  // gongping
-    &"栏杆",  // langan
-    &"蝴蝶",  // hudie
-    &"施肥",  // shifei
-    &"志愿者",  // zhiyuanzhe
-    &"极了",  // jile
-    &"抽屉",  // chouti
-    &"纷纷",  // fenfen
-    &"八成儿",  // bachengr
-    &"故事",  // gushi
-    &"关注",  // guanzhu
-    &"地区",  // diqu
-    &"贸易",  // maoyi
-    &"因此",  // yinci
-    &"无法",  // wufa
-    &"儿童",  // ertong
-    &"透明",  // touming
-    &"能干",  // nenggan
-    &"时髦",  // shimao
-    &"经典",  // jingdian
-    &"诚信",  // chengxin
-    &"乒乓球",  // pingpangqiu
-    &"造成",  // zaocheng
-    &"客厅",  // keting
-    &"消极",  // xiaoji
-    &"降低",  // jiangdi
-    &"突出",  // tuchu
-    &"人才",  // rencai
-    &"光线",  // guangxian
-    &"感受",  // ganshou
-    &"信封",  // xinfeng
-    &"开放",  // kaifang
-    &"约会",  // yuehui
-    &"郊区",  // jiaoqu
-    &"认真",  // renzhen
-    &"哪怕",  // napa
-    &"麦克风",  // maikefeng
-    &"对比",  // duibi
-    &"增长",  // zengzhang
-    &"。",  // .
-    &"钢铁",  // gangtie
-    &"城市	诚实",  // chengshi
-    &"歌舞",  // gewu
-    &"到达",  // daoda
-    &"收拾",  // shoushi
-    &"股票",  // gupiao
-    &"表面",  // biaomian
-    &"网球",  // wangqiu
-    &"日期",  // riqi
-    &"秘书",  // mishu
-    &"礼拜天",  // libaitian
-    &"浪费",  // langfei
-    &"暖和",  // nuanhuo
-    &"零食",  // lingshi
-    &"广和居",  // guangheju
-    &"海里",  // haili
-    &"名牌",  // mingpai
-    &"友谊",  // youyi
-    &"丢",  // diu
-    &"文学家",  // wenxuejia
-    &"或许",  // huoxu
-    &"这儿",  // zher
-    &"相同",  // xiangtong
-    &"明星",  // mingxing
-    &"没关系",  // meiguanxi
-    &"基本",  // jiben
-    &"固定",  // guding
-    &"转告",  // zhuangao
-    &"振动",  // zhendong
-    &"手表",  // shoubiao
-    &"利益",  // liyi
-    &"彻底",  // chedi
-    &"担心",  // danxin
-    &"压力",  // yali
-    &"措施",  // cuoshi
-    &"检查",  // jiancha
-    &"环保",  // huanbao
-    &"平均",  // pingjun
-    &"十分",  // shifen
-    &"温暖",  // wennuan
-    &"孙子",  // sunzi
-    &"班	搬	半	般",  // ban
-    &"号码",  // haoma
-    &"秘密",  // mimi
-    &"顶	叮",  // ding
-    &"《说文解字》",  // <shuowenjiezi>
-    &"从前",  // congqian
-    &"管理",  // guanli
-    &"物质",  // wuzhi
-    &"当然",  // dangran
-    &"周",  // zhou
-    &"片面",  // pianmian
-    &"差距",  // chaju
-    &"阶段",  // jieduan
-    &"随便",  // suibian
-    &"里查德·希尔斯",  // lichadexiersi
-    &"闪电",  // shandian
-    &"体会",  // tihui
-    &"沙漠",  // shamo
-    &"市场",  // shichang
-    &"隔壁",  // gebi
-    &"阅读",  // yuedu
-    &"以为",  // yiwei
-    &"解决",  // jiejue
-    &"命运",  // mingyun
-    &"例子",  // lizi
-    &"年级	年纪",  // nianji
-    &"美好",  // meihao
-    &"轻视",  // qingshi
-    &"惭愧",  // cankui
-    &"希望",  // xiwang
-    &"学期",  // xueqi
-    &"斯坦福",  // sitanfu
-    &"寂寞",  // jimo
-    &"印象",  // yinxiang
-    &"先生",  // xiansheng
-    &"富	扶	幅",  // fu
-    &"表扬",  // biaoyang
-    &"想象",  // xiangxiang
-    &"答案",  // daan
-    &"幻想",  // huanxiang
-    &"位置",  // weizhi
-    &"宾馆",  // binguan
-    &"当心",  // dangxin
-    &"由于	犹豫",  // youyu
-    &"保留",  // baoliu
-    &"情绪",  // qingxu
-    &"推迟",  // tuichi
-    &"目标",  // mubiao
-    &"长途",  // changtu
-    &"羽毛球",  // yumaoqiu
-    &"今年",  // jinnian
-    &"裤子",  // kuzi
-    &"发展",  // fazhan
-    &"好客",  // haoke
-    &"游戏",  // youxi
-    &"军事",  // junshi
-    &"装	撞",  // zhuang
-    &"任务	人物",  // renwu
-    &"干杯",  // ganbei
-    &"禁止",  // jinzhi
-    &"长	尝	场",  // chang
-    &"洗澡",  // xizao
-    &"实现	事先",  // shixian
-    &"起飞",  // qifei
-    &"决心",  // juexin
-    &"经验",  // jingyan
-    &"深刻",  // shenke
-    &"完善",  // wanshan
-    &"象征",  // xiangzheng
-    &"实际	世纪",  // shiji
-    &"分析",  // fenxi
-    &"一会儿",  // yihuier
-    &"论文",  // lunwen
-    &"眉毛",  // meimao
-    &"网络",  // wangluo
-    &"洒",  // sa
-    &"俩",  // lia
-    &"可见",  // kejian
-    &"马上",  // mashang
-    &"放纵",  // fangzong
-    &"的	得	地",  // de
-    &"对方",  // duifang
-    &"跟	根",  // gen
-    &"不如",  // buru
-    &"本来",  // benlai
-    &"说话",  // shuohua
-    &"上",  // shang
-    &"帽子",  // maozi
-    &"鸟",  // niao
-    &"块	快",  // kuai
-    &"《呐喊》",  // <nahan>
-    &"团",  // tuan
-    &"原来",  // yuanlai
-    &"有点	优点",  // youdian
-    &"发表",  // fabiao
-    &"讲座",  // jiangzuo
-    &"虽然",  // suiran
-    &"要求",  // yaoqiu
-    &"出院",  // chuyuan
-    &"逐渐",  // zhujian
-    &"主人	主任",  // zhuren
-    &"回来",  // huilai
-    &"是否",  // shifou
-    &"接触",  // jiechu
-    &"标点",  // biaodian
-    &"修改",  // xiugai
-    &"企业",  // qiye
-    &"除了",  // chule
-    &"我们",  // women
-    &"特别",  // tebie
-    &"弹钢琴",  // tangangqin
-    &"名字",  // mingzi
-    &"不安",  // buan
-    &"球迷",  // qiumi
-    &"法律",  // falv
-    &"立刻",  // like
-    &"反省",  // fanxing
-    &"本	笨",  // ben
-    &"说不定",  // shuobuding
-    &"庆祝",  // qingzhu
-    &"色彩",  // secai
-    &"光明",  // guangming
-    &"了解",  // liaojie
-    &"你",  // ni
-    &"占线",  // zhanxian
-    &"欧洲环境保护署",  // ouzhouhuanjingbaohushu
-    &"相处",  // xiangchu
-    &"传说",  // chuanshuo
-    &"另外",  // lingwai
-    &"计算",  // jisuan
-    &"假设",  // jiashe
-    &"扩",  // kuo
-    &"流泪",  // liulei
-    &"星星",  // xingxing
-    &"一边",  // yibian
-    &"嗓子",  // sangzi
-    &"分享",  // fenxiang
-    &"早",  // zao
-    &"允许",  // yunxu
-    &"谦虚",  // qianxu
-    &"等待",  // dengdai
-    &"产生",  // chansheng
-    &"博士",  // boshi
-    &"草",  // cao
-    &"护士	忽视",  // hushi
-    &"流行",  // liuxing
-    &"怎么办",  // zenmeban
-    &"篇	骗	片",  // pian
-    &"总之",  // zongzhi
-    &"如何",  // ruhe
-    &"多么",  // duome
-    &"种	重	钟",  // zhong
-    &"快乐",  // kuaile
-    &"道歉",  // daoqian
-    &"方式",  // fangshi
-    &"两	辆	亮",  // liang
-    &"从事",  // congshi
-    &"幽默",  // youmo
-    &"您",  // nin
-    &"低	底	递	滴",  // di
-    &"说",  // shuo
-    &"确实",  // queshi
-    &"成人	承认",  // chengren
-    &"适应",  // shiying
-    &"《非你莫属》",  // <feinimoshu>
-    &"特点",  // tedian
-    &"一直	一致",  // yizhi
-    &"抽烟",  // chouyan
-    &"为什么",  // weishenme
-    &"普遍",  // pubian
-    &"听	挺	停",  // ting
-    &"完全",  // wanquan
-    &"程度",  // chengdu
-    &"游泳",  // youyong
-    &"疯狂",  // fengkuang
-    &"生物钟",  // shengwuzhong
-    &"力量",  // liliang
-    &"通知",  // tongzhi
-    &"问候",  // wenhou
-    &"短信",  // duanxin
-    &"特征",  // tezheng
-    &"装饰",  // zhuangshi
-    &"确定",  // queding
-    &"发达",  // fada
-    &"句子	橘子	桔子	锯子",  // juzi
-    &"工人",  // gongren
-    &"优秀",  // youxiu
-    &"生产",  // shengchan
-    &"自行车",  // zixingche
-    &"理发店",  // lifadian
-    &"自己",  // ziji
-    &"赞美",  // zanmei
-    &"粮食",  // liangshi
-    &"适当",  // shidang
-    &"打扫",  // dasao
-    &"到处",  // daochu
-    &"奇特",  // qite
-    &"部门",  // bumen
-    &"口味",  // kouwei
-    &"国际	国籍",  // guoji
-    &"四	死	私	撕	丝",  // si
-    &"公开",  // gongkai
-    &"参观",  // canguan
-    &"病毒",  // bingdu
-    &"咨询",  // zixun
-    &"合作",  // hezuo
-    &"方案",  // fangan
-    &"资源	自愿",  // ziyuan
-    &"黄河",  // huanghe
-    &"小吃",  // xiaochi
-    &"衣服",  // yifu
-    &"春秋",  // chunqiu
-    &"训练",  // xunlian
-    &"下	夏	瞎	吓",  // xia
-    &"爱迪生",  // aidisheng
-    &"吹",  // chui
-    &"付款",  // fukuan
-    &"总算",  // zongsuan
-    &"灾害",  // zaihai
-    &"相反",  // xiangfan
-    &"体验",  // tiyan
-    &"有利",  // youli
-    &"后悔",  // houhui
-    &"理发",  // lifa
-    &"金石为开",  // jinshiweikai
-    &"轮流",  // lunliu
-    &"姑姑",  // gugu
-    &"说明",  // shuoming
-    &"狮子",  // shizi
-    &"戏剧",  // xiju
-    &"正常",  // zhengchang
-    &"围巾",  // weijin
-    &"投篮",  // toulan
-    &"包	饱	抱	保	薄",  // bao
-    &"朝三暮四",  // zhaosanmusi
-    &"祝贺",  // zhuhe
-    &"洗	西	系	夕",  // xi
+pub static CIYU: &[&str] = &[
+    "弱",  // ruo
+    "悲观",  // beiguan
+    "收获",  // shouhuo
+    "厢房",  // xiangfang
+    "规模",  // guimo
+    "玉米",  // yumi
+    "哲理",  // zheli
+    "老鹰",  // laoying
+    "日记",  // riji
+    "香蕉",  // xiangjiao
+    "临时",  // linshi
+    "损失",  // sunshi
+    "胡同",  // hutong
+    "直接",  // zhijie
+    "兴趣",  // xingqu
+    "成语",  // chengyu
+    "胶水",  // jiaoshui
+    "才	菜	猜	踩",  // cai
+    "夸张",  // kuazhang
+    "丑陋",  // choulou
+    "旺盛",  // wangsheng
+    "悄悄",  // qiaoqiao
+    "摔倒",  // shuaidao
+    "姥姥",  // laolao
+    "再三",  // zaisan
+    "对	堆",  // dui
+    "性别",  // xingbie
+    "悠悠",  // youyou
+    "花生",  // huasheng
+    "前	钱	千	浅	欠	签",  // qian
+    "数字	梳子",  // shuzi
+    "汤	糖	躺	烫	趟",  // tang
+    "分钟",  // fenzhong
+    "承受",  // chengshou
+    "类型",  // leixing
+    "角色",  // juese
+    "举办",  // juban
+    "好处",  // haochu
+    "繁荣",  // fanrong
+    "接着",  // jiezhe
+    "乘坐",  // chengzuo
+    "一旦",  // yidan
+    "宠物",  // chongwu
+    "语气",  // yuqi
+    "放心",  // fangxin
+    "微博",  // weibo
+    "壶	糊",  // hu
+    "日用品",  // riyongpin
+    "寿命",  // shouming
+    "办事",  // banshi
+    "鲁迅",  // luxun
+    "农业",  // nongye
+    "地球",  // diqiu
+    "坏",  // huai
+    "便	变	遍",  // bian
+    "年	念",  // nian
+    "摄入",  // sheru
+    "距离",  // juli
+    "推荐",  // tuijian
+    "状态",  // zhuangtai
+    "厉害",  // lihai
+    "忍不住",  // renbuzhu
+    "建筑",  // jianzhu
+    "公共汽车",  // gonggongqiche
+    "实话",  // shihua
+    "拒绝",  // jujue
+    "行人",  // xingren
+    "设计	射击",  // sheji
+    "厨房",  // chufang
+    "身体",  // shenti
+    "手术",  // shoushu
+    "偶然",  // ouran
+    "潮湿	超市",  // chaoshi
+    "不假思索",  // bujiasisuo
+    "出现",  // chuxian
+    "感想",  // ganxiang
+    "行业",  // hangye
+    "最	嘴	醉",  // zui
+    "专门",  // zhuanmen
+    "春游",  // chunyou
+    "次要",  // ciyao
+    "烦恼",  // fannao
+    "科学",  // kexue
+    "优美",  // youmei
+    "保持",  // baochi
+    "老板",  // laoban
+    "麦布里奇",  // maibuliqi
+    "茶	差	插",  // cha
+    "现代",  // xiandai
+    "家庭",  // jiating
+    "稍微",  // shaowei
+    "宝贝",  // baobei
+    "面临",  // mianlin
+    "状况",  // zhuangkuang
+    "美丽	魅力",  // meili
+    "力气",  // liqi
+    "缩短",  // suoduan
+    "整齐",  // zhengqi
+    "胃口",  // weikou
+    "盲人",  // mangren
+    "几乎",  // jihu
+    "丰富",  // fengfu
+    "确认",  // queren
+    "俱乐部",  // julebu
+    "完成",  // wancheng
+    "不客气",  // bukeqi
+    "结束",  // jieshu
+    "坚决",  // jianjue
+    "程序",  // chengxu
+    "没	每	美",  // mei
+    "粗心",  // cuxin
+    "展览",  // zhanlan
+    "迅速",  // xunsu
+    "加油站",  // jiayouzhan
+    "赵奢",  // zhaoshe
+    "历史",  // lishi
+    "范围",  // fanwei
+    "奇怪",  // qiguai
+    "感到",  // gandao
+    "半夜",  // banye
+    "煤炭",  // meitan
+    "传统",  // chuantong
+    "科技",  // keji
+    "平方",  // pingfang
+    "良好",  // lianghao
+    "无奈",  // wunai
+    "甚至",  // shenzhi
+    "过期",  // guoqi
+    "激烈",  // jilie
+    "盘子",  // panzi
+    "中午",  // zhongwu
+    "古代",  // gudai
+    "爱好",  // aihao
+    "开玩笑",  // kaiwanxiao
+    "精诚所至",  // jingchengsuozhi
+    "内科",  // neike
+    "一连",  // yilian
+    "来自",  // laizi
+    "开幕式",  // kaimushi
+    "舞蹈",  // wudao
+    "在乎",  // zaihu
+    "无意",  // wuyi
+    "地点",  // didian
+    "难受",  // nanshou
+    "引起",  // yinqi
+    "优势",  // youshi
+    "层",  // ceng
+    "饺子",  // jiaozi
+    "重视",  // zhongshi
+    "错",  // cuo
+    "积累",  // jilei
+    "研究",  // yanjiu
+    "乐观",  // leguan
+    "平衡",  // pingheng
+    "出口",  // chukou
+    "恭喜",  // gongxi
+    "欢迎",  // huanying
+    "旁边",  // pangbian
+    "车厢",  // chexiang
+    "海洋",  // haiyang
+    "无聊",  // wuliao
+    "摄影师",  // sheyingshi
+    "脱",  // tuo
+    "谨慎",  // jinshen
+    "他	她	它",  // ta
+    "工程师",  // gongchengshi
+    "安排",  // anpai
+    "北京师范大学",  // beijingshifandaxue
+    "汉语",  // hanyu
+    "幸运",  // xingyun
+    "小雨",  // xiaoyu
+    "有趣",  // youqu
+    "为	位	喂	胃",  // wei
+    "怎么",  // zenme
+    "尊重",  // zunzhong
+    "弟弟",  // didi
+    "中间",  // zhongjian
+    "单元",  // danyuan
+    "展开",  // zhankai
+    "吃",  // chi
+    "空调",  // kongtiao
+    "爱	唉	矮	哎",  // ai
+    "缺点",  // quedian
+    "理由",  // liyou
+    "牙膏",  // yagao
+    "导演",  // daoyan
+    "中文",  // zhongwen
+    "人口",  // renkou
+    "有限",  // youxian
+    "业余",  // yeyu
+    "告诉",  // gaosu
+    "老虎",  // laohu
+    "柜台",  // guitai
+    "胡说",  // hushuo
+    "佩·詹森",  // peizhansen
+    "日程",  // richeng
+    "独特",  // dute
+    "孝顺",  // xiaoshun
+    "打扰",  // darao
+    "需要",  // xuyao
+    "只要",  // zhiyao
+    "钱包",  // qianbao
+    "有点儿",  // youdianr
+    "佩·詹森",  // pei`zhansen
+    "叙述",  // xushu
+    "格外",  // gewai
+    "自杀",  // zisha
+    "这些",  // zhexie
+    "难过",  // nanguo
+    "竞争",  // jingzheng
+    "要不",  // yaobu
+    "甩	帅",  // shuai
+    "发烧",  // fashao
+    "垃圾",  // laji
+    "马虎",  // mahu
+    "建立	简历",  // jianli
+    "·",  // `
+    "商店",  // shangdian
+    "少	勺",  // shao
+    "幸亏",  // xingkui
+    "合适",  // heshi
+    "昆虫",  // kunchong
+    "夹子",  // jiazi
+    "报名",  // baoming
+    "观念",  // guannian
+    "称呼",  // chenghu
+    "尾巴",  // weiba
+    "导游",  // daoyou
+    "感觉",  // ganjue
+    "聊天儿",  // liaotianr
+    "伙伴",  // huoban
+    "分配",  // fenpei
+    "穷",  // qiong
+    "标准",  // biaozhun
+    "保险",  // baoxian
+    "集体",  // jiti
+    "制定	制订",  // zhiding
+    "姿势",  // zishi
+    "荫凉",  // yinliang
+    "天	甜	填",  // tian
+    "征求",  // zhengqiu
+    "总结",  // zongjie
+    "主要",  // zhuyao
+    "质量",  // zhiliang
+    "照相机",  // zhaoxiangji
+    "一下",  // yixia
+    "（",  // (
+    "橡皮",  // xiangpi
+    "一会儿",  // yihuir
+    "三	伞",  // san
+    "声调",  // shengdiao
+    "老师	老实",  // laoshi
+    "粗糙",  // cucao
+    "干净",  // ganjing
+    "全面",  // quanmian
+    "导致",  // daozhi
+    "黄	慌",  // huang
+    "册",  // ce
+    "前途",  // qiantu
+    "注意	主意",  // zhuyi
+    "自私",  // zisi
+    "挑战",  // tiaozhan
+    "生活",  // shenghuo
+    "始终",  // shizhong
+    "思想",  // sixiang
+    "女孩儿",  // nvhair
+    "衬衫",  // chenshan
+    "京剧",  // jingju
+    "具体",  // juti
+    "吸取",  // xiqu
+    "作业",  // zuoye
+    "把握",  // bawo
+    "使劲",  // shijin
+    "一	以	亿	乙",  // yi
+    "毛巾",  // maojin
+    "比尔·盖茨",  // bier`gaici
+    "流利",  // liuli
+    "巧妙",  // qiaomiao
+    "重大",  // zhongda
+    "裙子",  // qunzi
+    "密切",  // miqie
+    "废话",  // feihua
+    "设施",  // sheshi
+    "吗	马	骂",  // ma
+    "）",  // )
+    "冷",  // leng
+    "土地",  // tudi
+    "账户",  // zhanghu
+    "居然",  // juran
+    "到底",  // daodi
+    "香山",  // xiangshan
+    "要是	钥匙",  // yaoshi
+    "口",  // kou
+    "请愿书",  // qingyuanshu
+    "拉	啦	辣",  // la
+    "踢足球",  // tizuqiu
+    "入围",  // ruwei
+    "恩爱",  // enai
+    "带	戴	呆	袋",  // dai
+    "你们",  // nimen
+    "语言	寓言",  // yuyan
+    "楼	漏",  // lou
+    "得意",  // deyi
+    "继续	积蓄",  // jixu
+    "丝绸",  // sichou
+    "人名",  // renming
+    "制作",  // zhizuo
+    "商品",  // shangpin
+    "键盘",  // jianpan
+    "叔叔",  // shushu
+    "假装",  // jiazhuang
+    "相对",  // xiangdui
+    "总理",  // zongli
+    "资格",  // zige
+    "木头",  // mutou
+    "利用",  // liyong
+    "题目",  // timu
+    "然后",  // ranhou
+    "原因",  // yuanyin
+    "嘉宾",  // jiabin
+    "喊	汗",  // han
+    "黑猩猩",  // heixingxing
+    "面试",  // mianshi
+    "抱怨",  // baoyuan
+    "捐",  // juan
+    "能力",  // nengli
+    "可能",  // keneng
+    "新	信",  // xin
+    "所谓",  // suowei
+    "随手",  // suishou
+    "楚国",  // chuguo
+    "诺贝尔奖",  // nuobeierjiang
+    "究竟",  // jiujing
+    "文具",  // wenju
+    "动画片",  // donghuapian
+    "只好",  // zhihao
+    "彼此",  // bici
+    "相信",  // xiangxin
+    "破坏",  // pohuai
+    "后	厚",  // hou
+    "被子	杯子",  // beizi
+    "律师",  // lvshi
+    "冷淡",  // lengdan
+    "休息",  // xiuxi
+    "成熟",  // chengshu
+    "在于",  // zaiyu
+    "积极",  // jiji
+    "如今",  // rujin
+    "全神贯注",  // quanshenguanzhu
+    "加油",  // jiayou
+    "浪漫",  // langman
+    "认为",  // renwei
+    "青少年",  // qingshaonian
+    "秩序",  // zhixu
+    "豪华",  // haohua
+    "紧张",  // jinzhang
+    "切",  // qie
+    "报道	报到",  // baodao
+    "落后",  // luohou
+    "桌子",  // zhuozi
+    "起居",  // qiju
+    "义务",  // yiwu
+    "情况",  // qingkuang
+    "慌张",  // huangzhang
+    "抓紧",  // zhuajin
+    "北京	背景",  // beijing
+    "旅行",  // lvxing
+    "盆",  // pen
+    "作品",  // zuopin
+    "零件",  // lingjian
+    "适合",  // shihe
+    "天然",  // tianran
+    "翻译",  // fanyi
+    "丈夫",  // zhangfu
+    "交际",  // jiaoji
+    "迟早",  // chizao
+    "讨论",  // taolun
+    "大使馆",  // dashiguan
+    "护国寺",  // huguosi
+    "采取",  // caiqu
+    "表明",  // biaoming
+    "改进",  // gaijin
+    "粉色",  // fense
+    "路",  // lu
+    "可靠",  // kekao
+    "光盘",  // guangpan
+    "鼻子",  // bizi
+    "香肠",  // xiangchang
+    "年龄",  // nianling
+    "污染",  // wuran
+    "窗帘",  // chuanglian
+    "主动",  // zhudong
+    "清淡",  // qingdan
+    "和	河	喝",  // he
+    "管子	馆子",  // guanzi
+    "有意思",  // youyisi
+    "留守",  // liushou
+    "代言",  // daiyan
+    "广场",  // guangchang
+    "硬件",  // yingjian
+    "平等",  // pingdeng
+    "忽然",  // huran
+    "忘记",  // wangji
+    "警察",  // jingcha
+    "太极拳",  // taijiquan
+    "绝对",  // juedui
+    "意义",  // yiyi
+    "行李箱",  // xinglixiang
+    "收据",  // shouju
+    "月	约	越",  // yue
+    "原料",  // yuanliao
+    "孩子",  // haizi
+    "着火",  // zhaohuo
+    "答应",  // daying
+    "大夫",  // daifu
+    "简直	兼职",  // jianzhi
+    "帆船",  // fanchuan
+    "减轻",  // jianqing
+    "自动",  // zidong
+    "啊",  // a
+    "找	招	照",  // zhao
+    "艰巨",  // jianju
+    "尽力",  // jinli
+    "妹妹",  // meimei
+    "不得不",  // budebu
+    "手工",  // shougong
+    "介绍",  // jieshao
+    "女",  // nv
+    "更",  // geng
+    "尤其	与其",  // youqi
+    "一律",  // yilv
+    "丝毫",  // sihao
+    "发挥",  // fahui
+    "危险",  // weixian
+    "《卖火柴的小女孩儿》",  // <maihuochaidexiaonvhaier>
+    "记者",  // jizhe
+    "计划",  // jihua
+    "打工",  // dagong
+    "秒",  // miao
+    "双",  // shuang
+    "意思",  // yisi
+    "哥哥",  // gege
+    "优惠",  // youhui
+    "公里",  // gongli
+    "肩膀",  // jianbang
+    "构成",  // goucheng
+    "并且",  // bingqie
+    "风险",  // fengxian
+    "恋爱",  // lianai
+    "所以",  // suoyi
+    "减少",  // jianshao
+    "离婚",  // lihun
+    "外公",  // waigong
+    "安慰",  // anwei
+    "看法",  // kanfa
+    "重要",  // zhongyao
+    "含意",  // hanyi
+    "按照",  // anzhao
+    "倒霉",  // daomei
+    "蹄子",  // tizi
+    "牙齿",  // yachi
+    "信号",  // xinhao
+    "电台",  // diantai
+    "省略",  // shenglve
+    "生病",  // shengbing
+    "深渊",  // shenyuan
+    "叶子",  // yezi
+    "测验",  // ceyan
+    "爸爸",  // baba
+    "干活儿",  // ganhuor
+    "其实",  // qishi
+    "挂号",  // guahao
+    "阳光",  // yangguang
+    "矿泉水",  // kuangquanshui
+    "新鲜",  // xinxian
+    "不耐烦",  // bunaifan
+    "洗手间",  // xishoujian
+    "其余",  // qiyu
+    "反正",  // fanzheng
+    "存在",  // cunzai
+    "下面",  // xiamian
+    "掉	钓",  // diao
+    "机器	极其",  // jiqi
+    "拐弯",  // guaiwan
+    "后背",  // houbei
+    "馒头",  // mantou
+    "件	箭	减	捡",  // jian
+    "医生",  // yisheng
+    "生命",  // shengming
+    "小时	消失",  // xiaoshi
+    "实习",  // shixi
+    "顾客",  // guke
+    "嫩",  // nen
+    "相关",  // xiangguan
+    "水平",  // shuiping
+    "写作",  // xiezuo
+    "冰激凌",  // bingjiling
+    "表演",  // biaoyan
+    "讲究",  // jiangjiu
+    "筷子",  // kuaizi
+    "赵国",  // zhaoguo
+    "职业",  // zhiye
+    "关心",  // guanxin
+    "幸福",  // xingfu
+    "委屈",  // weiqu
+    "笔记本",  // bijiben
+    "上网",  // shangwang
+    "何必",  // hebi
+    "不错",  // bucuo
+    "电池",  // dianchi
+    "谢谢",  // xiexie
+    "拥挤",  // yongji
+    "信心",  // xinxin
+    "趵突泉",  // baotuquan
+    "一样",  // yiyang
+    "空",  // kong
+    "汽油",  // qiyou
+    "夏令营",  // xialingying
+    "证据",  // zhengju
+    "晒",  // shai
+    "感情",  // ganqing
+    "内部",  // neibu
+    "交通",  // jiaotong
+    "南	难	男",  // nan
+    "算	酸",  // suan
+    "无所谓",  // wusuowei
+    "菜市口",  // caishikou
+    "老舍",  // laoshe
+    "怀孕",  // huaiyun
+    "涂鸦",  // tuya
+    "守岁",  // shousui
+    "漂亮",  // piaoliang
+    "信息",  // xinxi
+    "实验",  // shiyan
+    "主持",  // zhuchi
+    "黑板",  // heiban
+    "打喷嚏",  // dapenti
+    "铅笔",  // qianbi
+    "合格",  // hege
+    "米饭",  // mifan
+    "沙发",  // shafa
+    "退	推	腿",  // tui
+    "条",  // tiao
+    "哪里",  // nali
+    "拥抱",  // yongbao
+    "偷",  // tou
+    "会计",  // kuaiji
+    "关于",  // guanyu
+    "新年",  // xinnian
+    "选择",  // xuanze
+    "农村",  // nongcun
+    "软",  // ruan
+    "抽象",  // chouxiang
+    "与	于	鱼	雨",  // yu
+    "方法",  // fangfa
+    "靠",  // kao
+    "善良",  // shanliang
+    "过去",  // guoqu
+    "科恩",  // keen
+    "达到",  // dadao
+    "生动",  // shengdong
+    "资料",  // ziliao
+    "困扰",  // kunrao
+    "出版",  // chuban
+    "街道",  // jidao
+    "听说",  // tingshuo
+    "鼠标",  // shubiao
+    "声音",  // shengyin
+    "治疗",  // zhiliao
+    "亚洲",  // yazhou
+    "批准",  // peizhun
+    "必要",  // biyao
+    "感激",  // ganji
+    "录取",  // luqu
+    "事实",  // shishi
+    "转变",  // zhuanbian
+    "担任",  // danren
+    "图	吐",  // tu
+    "疑问",  // yiwen
+    "少许",  // shaoxu
+    "《非你莫属》",  // feinimoshu
+    "可怜",  // kelian
+    "车祸",  // chehuo
+    "深	伸",  // shen
+    "等	灯",  // deng
+    "规律",  // guilv
+    "校园",  // xiaoyuan
+    "存",  // cun
+    "经过",  // jingguo
+    "一定",  // yiding
+    "老鼠",  // laoshu
+    "爷爷",  // yeye
+    "没有",  // meiyou
+    "投入",  // touru
+    "根据",  // genju
+    "时期",  // shiqi
+    "退步",  // tuibu
+    "哈",  // ha
+    "干	敢	赶	杆	擀",  // gan
+    "随时",  // suishi
+    "云	晕",  // yun
+    "古典",  // gudian
+    "狂",  // kuang
+    "单独",  // dandu
+    "生意",  // shengyi
+    "严格",  // yange
+    "请	轻	青	晴",  // qing
+    "全部",  // quanbu
+    "谁",  // shei
+    "勇气",  // yongqi
+    "联合",  // lianhe
+    "保护",  // baohu
+    "知识",  // zhishi
+    "信任",  // xinren
+    "证件",  // zhengjian
+    "传染",  // chuanran
+    "频道",  // pindao
+    "近代",  // jindai
+    "排队",  // paidui
+    "盆子",  // penzi
+    "价值",  // jiazhi
+    "依然",  // yiran
+    "鼓励",  // guli
+    "可怕",  // kepa
+    "读	度	堵",  // du
+    "疼",  // teng
+    "成就",  // chengjiu
+    "结果",  // jieguo
+    "邀请",  // yaoqing
+    "试卷",  // shijuan
+    "哭	苦	库",  // ku
+    "大	打",  // da
+    "元旦",  // yuandan
+    "被	背	倍	悲",  // bei
+    "细节",  // xijie
+    "进行",  // jinxing
+    "再见",  // zaijian
+    "理解",  // lijie
+    "数据",  // shuju
+    "借口",  // jiekou
+    "怎么样",  // zenmeyang
+    "控制",  // kongzhi
+    "人员",  // renyuan
+    "春",  // chun
+    "看	砍",  // kan
+    "出租车",  // chuzuche
+    "肌肉",  // jirou
+    "牙刷",  // yashua
+    "采访",  // caifang
+    "广大",  // guangda
+    "毕业",  // biye
+    "詹姆士·奈史密斯",  // zhanmushinaishimisi
+    "往返",  // wangfan
+    "打针",  // dazhen
+    "起来",  // qilai
+    "餐",  // can
+    "无数	武术",  // wushu
+    "年代",  // niandai
+    "鼓掌",  // guzhang
+    "稳定",  // wending
+    "正在",  // zhengzai
+    "陆续",  // luxu
+    "苗条",  // miaotiao
+    "给",  // gei
+    "系领带",  // jilingdai
+    "秋",  // qiu
+    "争论",  // zhenglun
+    "得到",  // dedao
+    "胖",  // pang
+    "区别",  // qubie
+    "萝卜",  // luobo
+    "下雨",  // xiayu
+    "六一儿童节",  // liuyiertongjie
+    "普通",  // putong
+    "迷路",  // milu
+    "连环画",  // lianhuanhua
+    "土豆",  // tudou
+    "习惯",  // xiguan
+    "威胁",  // weixia
+    "数量",  // shuliang
+    "女士",  // nvshi
+    "共同",  // gongtong
+    "房间",  // fangjian
+    "元	远	圆",  // yuan
+    "均匀",  // junyun
+    "暴雨",  // baoyu
+    "阳台",  // yangtai
+    "心情",  // xinqing
+    "战国",  // zhanguo
+    "应聘",  // yingpin
+    "规矩",  // guiju
+    "难道",  // nandao
+    "也	页	夜",  // ye
+    "风景",  // fengjing
+    "闭关",  // biguan
+    "纳入",  // naru
+    "领域",  // lingyu
+    "浏览",  // liulan
+    "其中",  // qizhong
+    "精神",  // jingshen
+    "将来",  // jianglai
+    "学习",  // xuexi
+    "办理",  // banli
+    "个性",  // gexing
+    "购物",  // gouwu
+    "讨价还价",  // taojiahuanjia
+    "觉得",  // juede
+    "亲自",  // qinzi
+    "妈妈",  // mama
+    "；",  // ;
+    "竟然",  // jingran
+    "傍晚",  // bangwan
+    "玩具",  // wanju
+    "空儿",  // kongr
+    "作为	座位",  // zuowei
+    "飞机",  // feiji
+    "北方",  // beifang
+    "全	劝	圈",  // quan
+    "华北",  // huabei
+    "报纸",  // baozhi
+    "影子",  // yingzi
+    "长期",  // changqi
+    "产品",  // chanpin
+    "明明",  // mingming
+    "词语",  // ciyu
+    "淋漓尽致",  // linlijinzhi
+    "连续",  // lianxu
+    "叉子",  // chazi
+    "巧克力",  // qiaokeli
+    "整个",  // zhengge
+    "明天",  // mingtian
+    "车",  // che
+    "日",  // ri
+    "竹子",  // zhuzi
+    "糊涂",  // hutu
+    "等于",  // dengyu
+    "个别",  // gebie
+    "小气",  // xiaoqi
+    "发抖",  // fadou
+    "性格",  // xingge
+    "小朋友",  // xiaopengyou
+    "厕所",  // cesuo
+    "启发",  // qifa
+    "婚姻",  // hunyin
+    "闹钟",  // naozhong
+    "奶奶",  // nainai
+    "散步",  // sanbu
+    "发票",  // fapiao
+    "限制",  // xianzhi
+    "手套",  // shoutao
+    "牛奶",  // niunai
+    "比赛",  // bisai
+    "客人",  // keren
+    "垮	夸",  // kua
+    "密码",  // mima
+    "赞成",  // zancheng
+    "葡萄",  // putao
+    "大方",  // dafang
+    "西红柿",  // xihongshi
+    "八成",  // bacheng
+    "眼睛	眼镜",  // yanjing
+    "一再",  // yizai
+    "回答",  // huida
+    "面条",  // miantiao
+    "经济",  // jingji
+    "招儿",  // zhaor
+    "不仅",  // bujin
+    "做客",  // zuoke
+    "当	挡",  // dang
+    "据说",  // jushuo
+    "很	恨",  // hen
+    "提问",  // tiwen
+    "沉	趁",  // chen
+    "难怪",  // nanguai
+    "超过",  // chaoguo
+    "首都机场",  // shoudujichang
+    "菜单",  // caidan
+    "表情",  // biaoqing
+    "提	题	替	蹄",  // ti
+    "保存",  // baocun
+    "教室",  // jiaoshi
+    "外交",  // waijiao
+    "平	凭",  // ping
+    "颜色",  // yanse
+    "疯",  // feng
+    "应付",  // yingfu
+    "会议	回忆",  // huiyi
+    "轻易",  // qingyi
+    "龙",  // long
+    "法院",  // fayuan
+    "里查德·希尔斯",  // lichade`xiersi
+    "味道",  // weidao
+    "改变",  // gaibian
+    "初级中学",  // chujizhongxue
+    "客观",  // keguan
+    "真	镇	阵",  // zhen
+    "追求",  // zhuiqiu
+    "效应",  // xiaoying
+    "湿润",  // shirun
+    "催",  // cui
+    "剪刀",  // jiandao
+    "峰终定律",  // fengzhongdinglv
+    "图书馆",  // tushuguan
+    "现象",  // xianxiang
+    "学生",  // xuesheng
+    "香瓜",  // xianggua
+    "米",  // mi
+    "而	二",  // er
+    "方面",  // fangmian
+    "用功",  // yonggong
+    "表现",  // biaoxian
+    "于是",  // yushi
+    "双方",  // shuangfang
+    "吃饭",  // chifan
+    "鞭炮",  // bianpao
+    "毛病",  // maobing
+    "受不了",  // shoubuliao
+    "预订",  // yuding
+    "什么",  // shenme
+    "熊猫",  // xiongmao
+    "抢	墙	枪",  // qiang
+    "相当",  // xiangdang
+    "因而",  // yiner
+    "镜子",  // jingzi
+    "从",  // cong
+    "欧盟",  // oumeng
+    "微笑",  // weixiao
+    "明显",  // mingxian
+    "反复",  // fanfu
+    "下午",  // xiawu
+    "幼儿园",  // youeryuan
+    "花花",  // huahua
+    "刻苦",  // keku
+    "服装",  // fuzhuang
+    "小心",  // xiaoxin
+    "知道	指导",  // zhidao
+    "高兴",  // gaoxing
+    "清楚",  // qingchu
+    "根本",  // genben
+    "知更鸟",  // zhigengniao
+    "台阶",  // taijie
+    "个	各",  // ge
+    "放暑假",  // fangshujia
+    "日子",  // rizi
+    "女性",  // nvxing
+    "分	份	粉",  // fen
+    "评价",  // pingjia
+    "符合",  // fuhe
+    "胳膊",  // gebo
+    "睡觉",  // shuijiao
+    "还是",  // haishi
+    "购买",  // goumai
+    "刚才",  // gangcai
+    "来得及",  // laideji
+    "准时",  // zhunshi
+    "关闭",  // guanbi
+    "玻璃",  // boli
+    "照顾",  // zhaogu
+    "纸袋",  // zhidai
+    "文化",  // wenhua
+    "英雄",  // yingxiong
+    "友好",  // youhao
+    "痛快",  // tongkuai
+    "未来",  // weilai
+    "文学",  // wenxue
+    "互联网",  // hulianwang
+    "事情",  // shiqing
+    "不足",  // buzu
+    "对面",  // duimian
+    "鲜艳",  // xianyan
+    "项目",  // xiangmu
+    "反应	反映",  // fanying
+    "叫	教	脚	交	角	焦	浇",  // jiao
+    "熟悉",  // shuxi
+    "缺乏",  // quefa
+    "星期",  // xingqi
+    "提倡",  // tichang
+    "命令",  // mingling
+    "举	句	局	锯",  // ju
+    "讽刺",  // fengci
+    "步骤",  // buzhou
+    "淘气",  // taoqi
+    "责任",  // zeren
+    "提纲",  // tigang
+    "健康",  // jiankang
+    "登机牌",  // dengjipai
+    "技术",  // jishu
+    "消费",  // xiaofei
+    "收入",  // shouru
+    "宴会",  // yanhui
+    "地方",  // difang
+    "改	盖",  // gai
+    "逐步",  // zhubu
+    "可	克	课	刻	颗	棵	渴",  // ke
+    "景色",  // jingse
+    "生气",  // shengqi
+    "为了",  // weile
+    "基础",  // jichu
+    "安全",  // anquan
+    "公布",  // gongbu
+    "可是",  // keshi
+    "垃圾桶",  // lajitong
+    "睡着",  // shuizhao
+    "整体",  // zhengti
+    "不断",  // buduan
+    "形成",  // xingcheng
+    "美人鱼",  // meirenyu
+    "奇迹",  // qiji
+    "打扮",  // daban
+    "哲学",  // zhexue
+    "销售",  // xiaoshou
+    "返航",  // fanhang
+    "节约",  // jieyue
+    "增",  // zeng
+    "长辈",  // zhangbei
+    "怀念",  // huainian
+    "文件",  // wenjian
+    "里	离	李	丽	梨",  // li
+    "出发",  // chufa
+    "纸上谈兵",  // zhishangtanbing
+    "逃避",  // taobi
+    "尽量",  // jinliang
+    "瞧	桥	敲",  // qiao
+    "随身",  // suishen
+    "出色",  // chuse
+    "金属",  // jinshu
+    "闯	窗",  // chuang
+    "有名",  // youming
+    "珍惜",  // zhenxi
+    "喂养",  // weiyang
+    "赵括",  // zhaokuo
+    "不得了",  // budeliao
+    "照常",  // zhaochang
+    "通过",  // tongguo
+    "那	拿	哪",  // na
+    "讨厌",  // taoyan
+    "开水",  // kaishui
+    "明",  // ming
+    "必须",  // bixu
+    "学历",  // xueli
+    "期间",  // qijian
+    "微信",  // weixin
+    "凉茶",  // liangcha
+    "批评",  // piping
+    "迟到",  // chidao
+    "人民",  // renmin
+    "则",  // ze
+    "文文",  // wenwen
+    "商场",  // shangchang
+    "空气",  // kongqi
+    "新闻",  // xinwen
+    "公斤",  // gongjin
+    "水	税",  // shui
+    "留学",  // liuxue
+    "金鱼",  // jinyu
+    "形式	形势",  // xingshi
+    "预报",  // yubao
+    "母亲",  // muqin
+    "表格",  // biaoge
+    "长江",  // changjiang
+    "面包",  // mianbao
+    "赵福根",  // zhaofugen
+    "统一	同意",  // tongyi
+    "已经",  // yijing
+    "中秋节",  // zhongqiujie
+    "恐怕",  // kongpa
+    "都	逗",  // dou
+    "运气",  // yunqi
+    "推辞",  // tuici
+    "贡献",  // gongxian
+    "澳大利亚",  // aodaliya
+    "子路",  // zilu
+    "仔细",  // zixi
+    "商业",  // shangye
+    "六	留",  // liu
+    "机场",  // jichang
+    "张小龙",  // zhangxiaolong
+    "刮风",  // guafeng
+    "意见",  // yijian
+    "归纳",  // guina
+    "蔬菜",  // shucai
+    "必然",  // biran
+    "内",  // nei
+    "演出",  // yanchu
+    "理论",  // lilun
+    "出示",  // chushi
+    "玩儿",  // wanr
+    "专业",  // zhuanye
+    "果汁",  // guozhi
+    "小	笑",  // xiao
+    "过程",  // guocheng
+    "满意",  // manyi
+    "菊花",  // juhua
+    "进步",  // jinbu
+    "昨天",  // zuotian
+    "讲	奖",  // jiang
+    "降落",  // jiangluo
+    "逻辑",  // luoji
+    "游览",  // youlan
+    "日历",  // rili
+    "无论",  // wulun
+    "来",  // lai
+    "观点",  // guandian
+    "此外",  // ciwai
+    "便宜",  // pianyi
+    "健身",  // jianshen
+    "何况",  // hekuang
+    "小伙子",  // xiaohuozi
+    "《卖火柴的小女孩儿》",  // maihuochaidexiaonvhair
+    "棚子",  // pengzi
+    "复习",  // fuxi
+    "来不及",  // laibuji
+    "处理",  // chuli
+    "以及",  // yiji
+    "分手",  // fenshou
+    "救护车",  // jiuhuche
+    "员工",  // yuangong
+    "冒险",  // maoxian
+    "盒子",  // hezi
+    "地铁",  // ditie
+    "公主",  // gongzhu
+    "补充",  // buchong
+    "单位",  // danwei
+    "火成岩",  // huochengyan
+    "满足",  // manzu
+    "精彩",  // jingcai
+    "比如",  // biru
+    "空闲",  // kongxian
+    "胆小鬼",  // danxiaogui
+    "醋",  // cu
+    "道具",  // daoju
+    "家乡",  // jiaxiang
+    "嗯",  // ng
+    "作者",  // zuozhe
+    "多	朵",  // duo
+    "办法",  // banfa
+    "显得",  // xiande
+    "反而",  // faner
+    "制冷",  // zhileng
+    "时候",  // shihou
+    "未必",  // weibi
+    "尾气",  // weiqi
+    "感兴趣",  // ganxingqu
+    "概念",  // gainian
+    "去	取	娶",  // qu
+    "打折",  // dazhe
+    "大象",  // daxiang
+    "灰姑娘",  // huiguniang
+    "招待",  // zhaodai
+    "样子",  // yangzi
+    "森林",  // senlin
+    "使劲儿",  // shijinr
+    "同样",  // tongyang
+    "挂",  // gua
+    "掌握",  // zhangwo
+    "邮局",  // youju
+    "轻松",  // qingsong
+    "电子邮件",  // dianziyoujian
+    "鲍全",  // baoquan
+    "永远",  // yongyuan
+    "种类",  // zhonglei
+    "一共",  // yigong
+    "运用",  // yunyong
+    "老百姓",  // laobaixing
+    "比较",  // bijiao
+    "由来",  // youlai
+    "责备",  // zebei
+    "先	县	咸",  // xian
+    "忙",  // mang
+    "小区",  // xiaoqu
+    "橡子",  // xiangzi
+    "有关",  // youguan
+    "愿意",  // yuanyi
+    "亲戚",  // qinqi
+    "国王",  // guowang
+    "凉快",  // liangkuai
+    "非常",  // feichang
+    "建设",  // jianshe
+    "提高",  // tigao
+    "兴奋",  // xingfen
+    "海鲜",  // haixian
+    "白	摆	百",  // bai
+    "出差",  // chuchai
+    "失去",  // shiqu
+    "可爱",  // keai
+    "押金",  // yajin
+    "交往",  // jiaowang
+    "音乐",  // yinyue
+    "脏",  // zang
+    "身段",  // shenduan
+    "袜子",  // wazi
+    "装修",  // zhuangxiu
+    "预习",  // yuxi
+    "把	吧	八",  // ba
+    "还	海",  // hai
+    "急诊",  // jizhen
+    "加利福尼亚州",  // jialifuniyazhou
+    "天安门东",  // tiananmendong
+    "人	认",  // ren
+    "针对",  // zhendui
+    "转	赚",  // zhuan
+    "体育馆",  // tiyuguan
+    "饼干",  // binggan
+    "干活儿",  // ganhuoer
+    "保证",  // baozheng
+    "会员卡",  // huiyuanka
+    "红",  // hong
+    "方向",  // fangxiang
+    "以来",  // yilai
+    "原谅",  // yuanliang
+    "努力",  // nuli
+    "变化",  // bianhua
+    "考虑",  // kaolv
+    "巨大",  // juda
+    "疼爱",  // tengai
+    "成绩",  // chengji
+    "射	蛇",  // she
+    "银子",  // yinzi
+    "气氛",  // qifen
+    "聊天",  // liaotian
+    "以前",  // yiqian
+    "搜索",  // sousuo
+    "中国",  // zhongguo
+    "朗读",  // langdu
+    "化学",  // huaxue
+    "高速公路",  // gaosugonglu
+    "农民",  // nongmin
+    "至于",  // zhiyu
+    "称赞",  // chengzan
+    "一切",  // yiqie
+    "妻子",  // qizi
+    "举行",  // juxing
+    "咱们",  // zanmen
+    "遗憾",  // yihan
+    "稻香村",  // daoxiangcun
+    "教练",  // jiaolian
+    "红色",  // hongse
+    "虚心",  // xuxin
+    "名片",  // mingpian
+    "相敬如宾",  // xiangjingrubin
+    "成	称",  // cheng
+    "跑步",  // paobu
+    "情侣",  // qinglv
+    "体贴",  // titie
+    "毛	冒	猫",  // mao
+    "本科",  // benke
+    "效果",  // xiaoguo
+    "成分",  // chengfen
+    "重点",  // zhongdian
+    "字母	字幕",  // zimu
+    "利润",  // lirun
+    "正好",  // zhenghao
+    "如果",  // ruguo
+    "热情",  // reqing
+    "之	只	直	指	治	支	纸",  // zhi
+    "最初",  // zuichu
+    "植物",  // zhiwu
+    "阿姨",  // ayi
+    "正式",  // zhengshi
+    "而且",  // erqie
+    "从此",  // congci
+    "握手",  // woshou
+    "离开",  // likai
+    "前面",  // qianmian
+    "能",  // neng
+    "反对",  // fandui
+    "健步如飞",  // jianburufei
+    "合同",  // hetong
+    "行家",  // hangjia
+    "申请",  // shenqing
+    "擦",  // ca
+    "自由",  // ziyou
+    "工资",  // gongzi
+    "接近",  // jiejin
+    "椅子",  // yizi
+    "热烈",  // relie
+    "小说",  // xiaoshuo
+    "敌人",  // diren
+    "似的",  // shide
+    "朝	炒	抄	吵",  // chao
+    "平静",  // pingjing
+    "帮助",  // bangzhu
+    "部分",  // bufen
+    "承担",  // chengdan
+    "肥皂",  // feizao
+    "至少",  // zhishao
+    "预防",  // yufang
+    "按时",  // anshi
+    "盐",  // yan
+    "推广",  // tuiguang
+    "是	使	十	诗	试",  // shi
+    "派	拍",  // pai
+    "其他",  // qita
+    "些	写	血	斜	歇	谢",  // xie
+    "断断续续",  // duanduanxuxu
+    "今天",  // jintian
+    "辣椒",  // lajiao
+    "请假",  // qingjia
+    "兄弟",  // xiongdi
+    "波动",  // bodong
+    "售货员",  // shouhuoyuan
+    "寻找",  // xunzhao
+    "模仿",  // mofang
+    "调查",  // diaocha
+    "风俗",  // fengsu
+    "自然",  // ziran
+    "所	锁",  // suo
+    "让",  // rang
+    "流血",  // liuxie
+    "实在",  // shizai
+    "送",  // song
+    "组合",  // zuhe
+    "肯定",  // kending
+    "想念",  // xiangnian
+    "日常",  // richang
+    "投资",  // touzi
+    "行为",  // xingwei
+    "灰心",  // huixin
+    "塑料袋",  // suliaodai
+    "否认",  // fouren
+    "安娜",  // anna
+    "应该",  // yinggai
+    "粘贴",  // zhantie
+    "姑娘",  // guniang
+    "但是",  // danshi
+    "摸",  // mo
+    "总统",  // zongtong
+    "材料",  // cailiao
+    "卡车",  // kache
+    "指挥	智慧",  // zhihui
+    "啄",  // zhuo
+    "这	着	折",  // zhe
+    "沙滩",  // shatan
+    "一起",  // yiqi
+    "寄生",  // jisheng
+    "鼓楼大街",  // guloudajie
+    "愉快",  // yukuai
+    "运输",  // yunshu
+    "沙子",  // shazi
+    "重量",  // zhongliang
+    "婚礼",  // hunli
+    "低落",  // diluo
+    "国君",  // guojun
+    "冷静",  // lengjing
+    "改善",  // gaishan
+    "删除",  // shanchu
+    "照片",  // zhaopian
+    "旅游",  // lvyou
+    "汇率",  // huilv
+    "互相",  // huxiang
+    "蒙眬",  // menglong
+    "杂志",  // zazhi
+    "形状",  // xingzhuang
+    "公司",  // gongsi
+    "纪念",  // jinian
+    "演员",  // yanyuan
+    "免费",  // mianfei
+    "不同",  // butong
+    "对手",  // duishou
+    "完美",  // wanmei
+    "样式",  // yangshi
+    "燃烧",  // ranshao
+    "时尚",  // shishang
+    "姐姐",  // jiejie
+    "活动",  // huodong
+    "陪",  // pei
+    "联系	练习",  // lianxi
+    "抓",  // zhua
+    "向	想	像	项	象	香	响",  // xiang
+    "辞职",  // cizhi
+    "仍然",  // rengran
+    "促使",  // cushi
+    "课程",  // kecheng
+    "暂时",  // zanshi
+    "记忆",  // jiyi
+    "高	搞",  // gao
+    "获得",  // huode
+    "耳环",  // erhuan
+    "可惜",  // kexi
+    "不然",  // buran
+    "气候",  // qihou
+    "做	坐	座",  // zuo
+    "灵活",  // linghuo
+    "遇到",  // yudao
+    "结婚",  // jiehun
+    "广播",  // guangbo
+    "西汉",  // xihan
+    "尽快",  // jinkuai
+    "才艺",  // caiyi
+    "世界",  // shijie
+    "数码",  // shuma
+    "表达",  // biaoda
+    "仿佛",  // fangfu
+    "分布",  // fenbu
+    "决定",  // jueding
+    "走",  // zou
+    "航班",  // hangban
+    "编辑",  // bianji
+    "录音",  // luyin
+    "合影",  // heying
+    "车库",  // cheku
+    "本领",  // benling
+    "围绕",  // weirao
+    "家访",  // jiafang
+    "身份",  // shenfen
+    "和尚",  // heshang
+    "机会",  // jihui
+    "毕竟",  // bijing
+    "专家",  // zhuanjia
+    "差不多",  // chabuduo
+    "民居",  // minju
+    "广告",  // guanggao
+    "时间	实践",  // shijian
+    "比尔·盖茨",  // biergaici
+    "手续",  // shouxu
+    "家具",  // jiaju
+    "通常",  // tongchang
+    "比",  // bi
+    "每天",  // meitian
+    "情趣",  // qingqu
+    "红酒",  // hongjiu
+    "别",  // bie
+    "花	画	滑	划",  // hua
+    "额	饿",  // e
+    "患难与共",  // huannanyugong
+    "支教",  // zhijiao
+    "天空	填空",  // tiankong
+    "道理",  // daoli
+    "大约",  // dayue
+    "查询",  // chaxun
+    "充满",  // chongman
+    "饮料",  // yinliao
+    "接	街	届	节	借	戒",  // jie
+    "及格",  // jige
+    "成果",  // chengguo
+    "宿舍",  // sushe
+    "干燥",  // ganzao
+    "交换",  // jiaohuan
+    "用",  // yong
+    "同情",  // tongqing
+    "发明",  // faming
+    "吸引",  // xiyin
+    "厘米",  // limi
+    "华裔",  // huayi
+    "东海龙王",  // donghailongwang
+    "态度",  // taidu
+    "杨	养	痒",  // yang
+    "佩服",  // peifu
+    "情景",  // qingjing
+    "权力	权利",  // quanli
+    "盼望",  // panwang
+    "大家",  // dajia
+    "好像",  // haoxiang
+    "东	洞	懂	冬	冻",  // dong
+    "过	国	锅",  // guo
+    "消息",  // xiaoxi
+    "地图",  // ditu
+    "却",  // que
+    "专注",  // zhuanzhu
+    "官	关",  // guan
+    "并",  // bing
+    "洛杉矶",  // luoshanji
+    "业务",  // yewu
+    "强调",  // qiangdiao
+    "百分之",  // baifenzhi
+    "夏威夷",  // xiaweiyi
+    "新西兰",  // xinxilan
+    "软件",  // ruanjian
+    "严重",  // yanzhong
+    "代替",  // daiti
+    "师傅",  // shifu
+    "梦",  // meng
+    "参考",  // cankao
+    "怪物",  // guaiwu
+    "影响",  // yingxiang
+    "，",  // ,
+    "堵车",  // duche
+    "某",  // mou
+    "传真",  // chuanzhen
+    "段	断	短",  // duan
+    "吨	顿	蹲",  // dun
+    "或者",  // huozhe
+    "开心",  // kaixin
+    "沙丁鱼",  // shadingyu
+    "爱惜",  // aixi
+    "时差",  // shicha
+    "见面",  // jianmian
+    "啤酒",  // pijiu
+    "猴子",  // houzi
+    "失业",  // shiye
+    "教材",  // jiaocai
+    "召开",  // zhaokai
+    "悲伤",  // beishang
+    "意外",  // yiwai
+    "运动",  // yundong
+    "耐心",  // naixin
+    "宁可",  // ningke
+    "养成",  // yangcheng
+    "父母",  // fumu
+    "扩大",  // kuoda
+    "其次",  // qici
+    "着凉",  // zhaoliang
+    "包括",  // baokuo
+    "群",  // qun
+    "道德",  // daode
+    "蜜蜂",  // mifeng
+    "发言",  // fayan
+    "平安",  // pingan
+    "独立",  // duli
+    "困难",  // kunnan
+    "害羞",  // haixiu
+    "发愁",  // fachou
+    "打印",  // dayin
+    "亲爱",  // qinai
+    "政治",  // zhengzhi
+    "演讲",  // yanjiang
+    "绕",  // rao
+    "减肥",  // jianfei
+    "热心",  // rexin
+    "火	货",  // huo
+    "热",  // re
+    "省	升	剩",  // sheng
+    "随着",  // suizhe
+    "奔跑",  // benpao
+    "欣赏",  // xinshang
+    "刚",  // gang
+    "机制",  // jizhi
+    "西瓜",  // xigua
+    "缺少",  // queshao
+    "政府",  // zhengfu
+    "手笔",  // shoubi
+    "袋子",  // daizi
+    "成功",  // chenggong
+    "羊肉",  // yangrou
+    "私人",  // siren
+    "国贸",  // guomao
+    "！",  // !
+    "火柴",  // huochai
+    "调皮",  // tiaopi
+    "采用",  // caiyong
+    "邻居",  // linju
+    "受到",  // shoudao
+    "作文",  // zuowen
+    "银	阴",  // yin
+    "维修",  // weixiu
+    "终于",  // zhongyu
+    "咳嗽",  // kesou
+    "总裁",  // zongcai
+    "笔试",  // bishi
+    "罚款",  // fakuan
+    "教育",  // jiaoyu
+    "山",  // shan
+    "吸收",  // xishou
+    "头发",  // toufa
+    "一般",  // yiban
+    "伟大",  // weida
+    "出生",  // chusheng
+    "情缘",  // qingyuan
+    "舒适",  // shushi
+    "称霸",  // chengba
+    "首先",  // shouxian
+    "印刷",  // yinshua
+    "锻炼",  // duanlian
+    "蒸腾",  // zhengteng
+    "拼音",  // pinyin
+    "感冒",  // ganmao
+    "洗礼",  // xili
+    "行动",  // xingdong
+    "思考",  // sikao
+    "心理",  // xinli
+    "左边",  // zuobian
+    "万丈",  // wanzhang
+    "报社",  // baoshe
+    "帮忙",  // bangmang
+    "汉字",  // hanzi
+    "脑袋",  // naodai
+    "综合",  // zonghe
+    "美国",  // meiguo
+    "创办",  // chuangban
+    "面积",  // mianji
+    "乐器",  // yueqi
+    "千万",  // qianwan
+    "形容",  // xingrong
+    "痛苦",  // tongku
+    "看望",  // kanwang
+    "零钱",  // lingqian
+    "大型",  // daxing
+    "有效",  // youxiao
+    "签证",  // qianzheng
+    "绳子",  // shengzi
+    "硕士",  // shuoshi
+    "淡",  // dan
+    "重新",  // chongxin
+    "二手",  // ershou
+    "数学",  // shuxue
+    "名胜古迹",  // mingshengguji
+    "到时候",  // daoshihou
+    "许多",  // xuduo
+    "下载",  // xiazai
+    "自信",  // zixin
+    "电视",  // dianshi
+    "活泼",  // huopo
+    "初级",  // chuji
+    "属于",  // shuyu
+    "记得",  // jide
+    "梦想",  // mengxiang
+    "根治",  // genzhi
+    "奋斗",  // fendou
+    "累	雷",  // lei
+    "破",  // po
+    "不行",  // buxing
+    "扔",  // reng
+    "躲藏",  // duocang
+    "节省",  // jiesheng
+    "制造",  // zhizao
+    "《",  // <
+    "娱乐",  // youle
+    "艺术",  // yishu
+    "违反",  // weifan
+    "培养",  // peiyang
+    "舅舅",  // jiujiu
+    "破产",  // pochan
+    "除非",  // chufei
+    "文字	蚊子",  // wenzi
+    "表",  // biao
+    "一点儿",  // yidianr
+    "开发",  // kaifa
+    "迎接",  // yingjie
+    "王宫",  // wanggong
+    "果实",  // guoshi
+    "当地",  // dangdi
+    "同学",  // tongxue
+    "问	闻	吻",  // wen
+    "复制",  // fuzhi
+    "东西",  // dongxi
+    "服务员",  // fuwuyuan
+    "连	脸",  // lian
+    "万一",  // wanyi
+    "帮	棒",  // bang
+    "物理",  // wuli
+    "结论",  // jielun
+    "妨碍",  // fangai
+    "包子",  // baozi
+    "宣布",  // xuanbu
+    "长	张	涨",  // zhang
+    "另	令	零	铃",  // ling
+    "太	台	抬",  // tai
+    "光滑",  // guanghua
+    "模糊",  // mohu
+    "票	飘",  // piao
+    "？",  // ?
+    "生日",  // shengri
+    "往往",  // wangwang
+    "蓝	烂	拦	懒",  // lan
+    "满	慢",  // man
+    "参与",  // canyu
+    "文火",  // wenhuo
+    "进口",  // jinkou
+    "话题",  // huati
+    "从来",  // conglai
+    "询问",  // xunwen
+    "秦国",  // qinguo
+    "早上",  // zaoshang
+    "真实",  // zhenshi
+    "美术",  // meishu
+    "蛋糕",  // dangao
+    "顺畅",  // shunchang
+    "呢",  // ne
+    "当时",  // dangshi
+    "自觉",  // zijue
+    "面对",  // miandui
+    "办公室",  // bangongshi
+    "动作",  // dongzuo
+    "新鲜感",  // xinxiangan
+    "水果",  // shuiguo
+    "进	近	尽",  // jin
+    "准备",  // zhunbei
+    "增加",  // zengjia
+    "纪律",  // jilv
+    "看不起",  // kanbuqi
+    "象棋",  // xiangqi
+    "消化	笑话",  // xiaohua
+    "接待",  // jiedai
+    "支持",  // zhichi
+    "缓解",  // huanjie
+    "小姐",  // xiaojie
+    "周到",  // zhoudao
+    "时刻",  // shike
+    "门槛",  // menkan
+    "打交道",  // dajiaodao
+    "出	处	除",  // chu
+    "汗腺",  // hanxian
+    "翻	犯",  // fan
+    "误会",  // wuhui
+    "使用	实用",  // shiyong
+    "价格",  // jiage
+    "强烈",  // qianglie
+    "操心",  // caoxin
+    "青春",  // qingchun
+    "四合院",  // siheyuan
+    "后来",  // houlai
+    "上当",  // shangdang
+    "枝干	知感",  // zhigan
+    "值得",  // zhide
+    "折叠",  // zhedie
+    "描写",  // miaoxie
+    "地震",  // dizhen
+    "比例",  // bili
+    "事半功倍",  // shibangongbei
+    "穿	船",  // chuan
+    "打篮球",  // dalanqiu
+    "坚强",  // jianqiang
+    "李广",  // liguang
+    "规定",  // guiding
+    "笔记",  // biji
+    "音乐会",  // yinyuehui
+    "牛仔裤",  // niuzaiku
+    "就餐",  // jiucan
+    "会	回	挥	灰",  // hui
+    "《说文解字》",  // shuowenjiezi
+    "性质",  // xingzhi
+    "支票",  // zhipiao
+    "矛盾",  // maodun
+    "包含",  // baohan
+    "云南",  // yunnan
+    "健伟",  // jianwei
+    "酒吧",  // jiuba
+    "士兵",  // shibing
+    "充电器",  // chongdianqi
+    "神秘",  // shenmi
+    "黄金",  // huangjin
+    "出席	除夕",  // chuxi
+    "博物馆",  // bowuguan
+    "目的",  // mudi
+    "耳朵",  // erduo
+    "熬夜",  // aoye
+    "请求",  // qingqiu
+    "唱歌",  // changge
+    "硬	赢",  // ying
+    "观众",  // guanzhong
+    "普通话",  // putonghua
+    "目前",  // muqian
+    "月亮",  // yueliang
+    "风浪",  // fenglang
+    "功夫",  // gongfu
+    "胜利",  // shengli
+    "工业",  // gongye
+    "年轻",  // nianqing
+    "丛林",  // conglin
+    "颠球",  // dianqiu
+    "贷款",  // daikuan
+    "广东省",  // guangdongsheng
+    "速度",  // sudu
+    "放	方",  // fang
+    "国庆节",  // guoqingjie
+    "不过",  // buguo
+    "财产",  // caichan
+    "鲇鱼",  // nianyu
+    "谈判",  // tanpan
+    "复印",  // fuyin
+    "贵",  // gui
+    "也许",  // yexu
+    "太阳",  // taiyang
+    "辅导",  // fudao
+    "利息",  // lixi
+    "家	加	假	甲	佳	嫁",  // jia
+    "一般来说",  // yibanlaishuo
+    "原则",  // yuanze
+    "爬山",  // pashan
+    "干脆",  // gancui
+    "初中",  // chuzhong
+    "人类",  // renlei
+    "郝林说",  // haolinshuo
+    "七郎",  // qilang
+    "骄傲",  // jiaoao
+    "手指",  // shouzhi
+    "礼物",  // liwu
+    "豆腐",  // doufu
+    "沟通",  // goutong
+    "教训",  // jiaoxun
+    "提供",  // tigong
+    "公寓",  // gongyu
+    "暖",  // nuan
+    "欧洲",  // ouzhou
+    "多余",  // duoyu
+    "建议",  // jianyi
+    "主席",  // zhuxi
+    "经常",  // jingchang
+    "排列",  // pailie
+    "聪明",  // congming
+    "老婆",  // laopo
+    "和平",  // heping
+    "上海	伤害",  // shanghai
+    "顺序",  // shunxu
+    "争取",  // zhengqu
+    "假如",  // jiaru
+    "瓶子",  // pingzi
+    "勤奋",  // qinfen
+    "轮",  // lun
+    "雇",  // gu
+    "光	逛",  // guang
+    "呼朋唤友",  // hupenghuanyou
+    "王	往",  // wang
+    "营业",  // yingye
+    "岛屿",  // daoyu
+    "路口",  // lukou
+    "评委",  // pingwei
+    "刘辰",  // liuchen
+    "地址",  // dizhi
+    "加班",  // jiaban
+    "丹尼尔·卡内曼",  // dannierkaneiman
+    "流传",  // liuchuan
+    "报告",  // baogao
+    "不但",  // budan
+    "成立",  // chengli
+    "修理",  // xiuli
+    "待遇",  // daiyu
+    "兑换",  // duihuan
+    "教授",  // jiaoshou
+    "刘炽平",  // liuchiping
+    "本质",  // benzhi
+    "天气",  // tianqi
+    "赶快",  // gankuai
+    "放松",  // fangsong
+    "只有",  // zhiyou
+    "打电话",  // dadianhua
+    "曾经",  // cengjing
+    "故意",  // guyi
+    "对话",  // duihua
+    "角度",  // jiaodu
+    "同时	同事",  // tongshi
+    "偶尔",  // ouer
+    "系统",  // xitong
+    "关键",  // guanjian
+    "民族",  // minzu
+    "连忙",  // lianmang
+    "否则",  // fouze
+    "卫生间",  // weishengjian
+    "昙花",  // tanhua
+    "歪歪扭扭",  // waiwainiuniu
+    "赔偿",  // peichang
+    "得",  // dei
+    "马萨诸塞州",  // masazhusaizhou
+    "延长",  // yanchang
+    "舜",  // shun
+    "怪	乖",  // guai
+    "重复",  // chongfu
+    "神话",  // shenhua
+    "能源",  // nengyuan
+    "移动",  // yidong
+    "皮鞋",  // pixie
+    "我",  // wo
+    "杨雄",  // yangxiong
+    "要	药	咬	腰	摇",  // yao
+    "护照",  // huzhao
+    "赏心悦目",  // shangxinyuemu
+    "价钱",  // jiaqian
+    "大卫",  // dawei
+    "国家",  // guojia
+    "房子",  // fangzi
+    "节日",  // jieri
+    "接受",  // jieshou
+    "否定",  // fouding
+    "匆忙",  // congmang
+    "小麦",  // xiaomai
+    "事物	食物",  // shiwu
+    "电梯",  // dianti
+    "长城",  // changcheng
+    "刺激",  // ciji
+    "第一",  // diyi
+    "悠久",  // youjiu
+    "熟练",  // shulian
+    "天津卫视",  // tianjinweishi
+    "笑笑",  // xiaoxiao
+    "错误",  // cuowu
+    "现在",  // xianzai
+    "网站",  // wangzhan
+    "卢米埃尔",  // lumiaier
+    "静",  // jing
+    "结账",  // jiezhang
+    "信用卡",  // xinyongka
+    "详细",  // xiangxi
+    "合法",  // hefa
+    "手	受	首	收	守	瘦",  // shou
+    "海关",  // haiguan
+    "乱",  // luan
+    "哲学家",  // zhexuejia
+    "存活",  // cunhuo
+    "呼吸",  // huxi
+    "决赛",  // juesai
+    "池塘",  // chitang
+    "克服",  // kefu
+    "宝贵",  // baogui
+    "学问",  // xuewen
+    "休闲",  // xiuxian
+    "用途",  // yongtu
+    "对于",  // duiyu
+    "吃亏",  // chikui
+    "紧急",  // jinji
+    "大厦",  // dasha
+    "咱",  // zan
+    "沉默",  // chenmo
+    "文章",  // wenzhang
+    "安静",  // anjing
+    "执照",  // zhizhao
+    "组	租",  // zu
+    "创造",  // chuangzao
+    "《彷徨》",  // panghuang
+    "遵守",  // zunshou
+    "促进",  // cujin
+    "移民",  // yimin
+    "校长",  // xiaozhang
+    "了不起",  // liaobuqi
+    "批	披	匹",  // pi
+    "根基",  // genji
+    "勇敢",  // yonggan
+    "体育",  // tiyu
+    "石灰岩",  // shihuiyan
+    "坚持",  // jianchi
+    "着急",  // zhaoji
+    "赞美",  // zanmai
+    "作用",  // zuoyong
+    "多亏",  // duokui
+    "转头",  // zhuantou
+    "非	废",  // fei
+    "毛孔",  // maokong
+    "立即",  // liji
+    "翟峰",  // zhaifeng
+    "享受",  // xiangshou
+    "肾上腺素",  // shenshangxiansu
+    "晚上",  // wanshang
+    "超级",  // chaoji
+    "到	倒	刀",  // dao
+    "地位",  // diwei
+    "点",  // dian
+    "买	卖	埋",  // mai
+    "孙月",  // sunyue
+    "文明",  // wenming
+    "电脑",  // diannao
+    "了",  // le
+    "后果",  // houguo
+    "就	酒	九	久	旧	救",  // jiu
+    "主张",  // zhuzhang
+    "手机	收集",  // shouji
+    "真正",  // zhenzheng
+    "人民币",  // renminbi
+    "卧室",  // woshi
+    "平常",  // pingchang
+    "爱护",  // aihu
+    "组织	阻止",  // zuzhi
+    "准确",  // zhunque
+    "自从",  // zicong
+    "万	完	玩	碗",  // wan
+    "孝敬",  // xiaojing
+    "左右",  // zuoyou
+    "多少",  // duoshao
+    "高档",  // gaodang
+    "效率",  // xiaolv
+    "外地",  // waidi
+    "设备",  // shebei
+    "解释	结实	节食",  // jieshi
+    "郁达夫",  // yudafu
+    "提醒",  // tixing
+    "够	狗	购",  // gou
+    "翅膀",  // chibang
+    "祝福",  // zhufu
+    "专心",  // zhuanxin
+    "热闹",  // renao
+    "喜欢",  // xihuan
+    "经商",  // jingshang
+    "登记",  // dengji
+    "中旬",  // zhongxun
+    "突然",  // turan
+    "女儿",  // nver
+    "温度",  // wendu
+    "石头",  // shitou
+    "首都",  // shoudu
+    "还	换",  // huan
+    "一辈子",  // yibeizi
+    "负责",  // fuze
+    "长江大桥",  // changjiangdaqiao
+    "传播",  // chuanbo
+    "特殊",  // teshu
+    "有	又	由",  // you
+    "培训",  // peixun
+    "诊断",  // zhenduan
+    "陌生",  // mosheng
+    "议论",  // yilun
+    "个人",  // geren
+    "退休",  // tuixiu
+    "负重",  // fuzhong
+    "暗暗",  // anan
+    "度过",  // duguo
+    "恢复",  // huifu
+    "模特",  // moter
+    "即使	及时",  // jishi
+    "跳舞",  // tiaowu
+    "分别",  // fenbie
+    "环境",  // huanjing
+    "动物",  // dongwu
+    "集中",  // jizhong
+    "资金",  // zijin
+    "中心",  // zhongxin
+    "概括",  // gaikuo
+    "的确",  // dique
+    "出自",  // chuzi
+    "形象",  // xingxiang
+    "中介",  // zhongjie
+    "改正",  // gaizheng
+    "窗户",  // chuanghu
+    "鲜花",  // xianhua
+    "至今",  // zhijin
+    "不	部	布",  // bu
+    "领导",  // lingdao
+    "难免",  // nanmian
+    "窄	摘",  // zhai
+    "银行",  // yinhang
+    "恶劣",  // elie
+    "饭店",  // fandian
+    "困",  // kun
+    "标志",  // biaozhi
+    "爱心",  // aixin
+    "集合",  // jihe
+    "周末",  // zhoumo
+    "最好",  // zuihao
+    "地毯",  // ditan
+    "胸",  // xiong
+    "天敌",  // tiandi
+    "赶紧",  // ganjin
+    "林	临",  // lin
+    "愿望",  // yuanwang
+    "点心",  // dianxin
+    "可乐",  // kele
+    "兔子",  // tuzi
+    "拆",  // chai
+    "安装",  // anzhuang
+    "不管",  // buguan
+    "老",  // lao
+    "奖金",  // jiangjin
+    "经历	经理	精力",  // jingli
+    "例如",  // liru
+    "追",  // zhui
+    "善于",  // shanyu
+    "调整",  // tiaozheng
+    "节目",  // jiemu
+    "感谢",  // ganxie
+    "伤心",  // shangxin
+    "不少",  // bushao
+    "地理",  // dili
+    "显示	现实",  // xianshi
+    "房东",  // fangdong
+    "好吃",  // haochi
+    "脖子",  // bozi
+    "礼貌",  // limao
+    "避免",  // bimian
+    "地道",  // didao
+    "最近",  // zuijin
+    "可以",  // keyi
+    "正	睁	挣",  // zheng
+    "失望",  // shiwang
+    "取消",  // quxiao
+    "估计",  // guji
+    "著名",  // zhuming
+    "朋友",  // pengyou
+    "持续",  // chixu
+    "说服",  // shuofu
+    "油炸",  // youzha
+    "在	再",  // zai
+    "外	歪",  // wai
+    "冠军",  // guanjun
+    "杀	傻",  // sha
+    "组成",  // zucheng
+    "女孩",  // nvhai
+    "黑",  // hei
+    "问题",  // wenti
+    "判断",  // panduan
+    "太太",  // taitai
+    "烤鸭",  // kaoya
+    "开",  // kai
+    "战争",  // zhanzheng
+    "所有",  // suoyou
+    "冰箱",  // bingxiang
+    "呀",  // ya
+    "学术",  // xueshu
+    "辩论",  // bianlun
+    "舒服",  // shufu
+    "行	姓	醒",  // xing
+    "列车",  // lieche
+    "任何",  // renhe
+    "单纯",  // danchun
+    "周围",  // zhouwei
+    "语法",  // yufa
+    "耽误",  // danwu
+    "对不起",  // duibuqi
+    "屋子",  // wuzi
+    "考试",  // kaoshi
+    "弄	浓",  // nong
+    "正确",  // zhengque
+    "工厂",  // gongchang
+    "风格",  // fengge
+    "起床",  // qichuang
+    "操场",  // caochang
+    "顺利",  // shunli
+    "尊敬",  // zunjing
+    "高级",  // gaoji
+    "主观",  // zhuguan
+    "宽",  // kuan
+    "然而",  // raner
+    "不要紧",  // buyaojin
+    "结构",  // jiegou
+    "劳驾",  // laojia
+    "对待",  // duidai
+    "妇女",  // funv
+    "天真",  // tianzhen
+    "开始",  // kaishi
+    "趋势	去世",  // qushi
+    "诚恳",  // chengken
+    "作家",  // zuojia
+    "内容",  // neirong
+    "咖啡",  // kafei
+    "羡慕",  // xianmu
+    "孔子",  // kongzi
+    "辛苦",  // xinku
+    "陆地",  // ludi
+    "条件",  // tiaojian
+    "工作",  // gongzuo
+    "失眠",  // shimian
+    "简单",  // jiandan
+    "注册",  // zhuce
+    "项链",  // xianglian
+    "电影",  // dianying
+    "招聘",  // zhaopin
+    "》",  // >
+    "狡猾",  // jiaohua
+    "怀疑",  // huaiyi
+    "经营",  // jingying
+    "大概",  // dagai
+    "关系",  // guanxi
+    "碰	朋",  // peng
+    "输入",  // shuru
+    "过分",  // guofen
+    "发生",  // fasheng
+    "逃	套	桃",  // tao
+    "彩虹",  // caihong
+    "王子",  // wangzi
+    "好	号",  // hao
+    "害怕",  // haipa
+    "记录	纪录",  // jilu
+    "规则",  // guize
+    "播放",  // bofang
+    "顺便",  // shunbian
+    "怪不得",  // guaibude
+    "餐厅",  // canting
+    "广泛",  // guangfan
+    "现金",  // xianjin
+    "肚子",  // duzi
+    "嗯",  // en
+    "公元	公园",  // gongyuan
+    "济南",  // jinan
+    "吃惊",  // chijing
+    "聚会",  // juhui
+    "抱歉",  // baoqian
+    "看见",  // kanjian
+    "亲切",  // qinqie
+    "主题",  // zhuti
+    "谈	痰",  // tan
+    "春节",  // chunjie
+    "季节",  // jijie
+    "火车站",  // huochezhan
+    "劳动",  // laodong
+    "生长",  // shengzhang
+    "牛津大学",  // niujindaxue
+    "复杂",  // fuza
+    "方便",  // fangbian
+    "舍不得",  // shebude
+    "功能",  // gongneng
+    "社会",  // shehui
+    "自豪",  // zihao
+    "参加",  // canjia
+    "目录",  // mulu
+    "好奇",  // haoqi
+    "人生",  // rensheng
+    "温柔",  // wenrou
+    "认识	人事",  // renshi
+    "勺子",  // shaozi
+    "外面",  // waimian
+    "时代",  // shidai
+    "受伤",  // shoushang
+    "附近",  // fujin
+    "放弃",  // fangqi
+    "不见得",  // bujiande
+    "坦率",  // tanshuai
+    "学校",  // xuexiao
+    "媒体",  // meiti
+    "印加",  // yinjia
+    "容易",  // rongyi
+    "驾驶",  // jiashi
+    "即	极	系	几	击	寄	挤",  // ji
+    "父亲",  // fuqin
+    "儿子",  // erzi
+    "潘家园",  // panjiayuan
+    "摄影",  // sheying
+    "打算",  // dasuan
+    "书	数	树	输",  // shu
+    "青壮年",  // qingzhuangnian
+    "制度",  // zhidu
+    "骨头",  // gutou
+    "打招呼",  // dazhaohu
+    "尺子",  // chizi
+    "尽管",  // jinguan
+    "酱油",  // jiangyou
+    "作战",  // zuozhan
+    "改革",  // gaige
+    "去年",  // qunian
+    "代表",  // daibiao
+    "起	七	骑	棋",  // qi
+    "字	紫",  // zi
+    "住	猪	煮	祝",  // zhu
+    "危害",  // weihai
+    "显然",  // xianran
+    "期待",  // qidai
+    "完整",  // wanzheng
+    "常识",  // changshi
+    "明白",  // mingbai
+    "整理",  // zhengli
+    "成长",  // chengzhang
+    "麻烦",  // mafan
+    "似乎",  // sihu
+    "次",  // ci
+    "鼓舞",  // guwu
+    "发",  // fa
+    "摩托车",  // motuoche
+    "别人",  // bieren
+    "医院",  // yiyuan
+    "入口",  // rukou
+    "：",  // :
+    "艰苦",  // jianku
+    "发现",  // faxian
+    "结合",  // jiehe
+    "激动",  // jidong
+    "感动",  // gandong
+    "以后",  // yihou
+    "特色",  // tese
+    "右边",  // youbian
+    "告别",  // gaobie
+    "果然",  // guoran
+    "词典",  // cidian
+    "观察",  // guancha
+    "学	雪",  // xue
+    "应用",  // yingyong
+    "哪儿	那儿",  // nar
+    "上班",  // shangban
+    "疲劳",  // pilao
+    "滚	棍",  // gun
+    "交流",  // jiaoliu
+    "各自	个子",  // gezi
+    "占	站",  // zhan
+    "差异",  // chayi
+    "商量",  // shangliang
+    "灰尘",  // huichen
+    "敏感",  // mingan
+    "合理",  // heli
+    "总共",  // zonggong
+    "过敏",  // guomin
+    "提前",  // tiqian
+    "配合",  // peihe
+    "营养",  // yingyang
+    "具备",  // jubei
+    "身材",  // shencai
+    "失败",  // shibai
+    "岁	碎",  // sui
+    "绿	屡",  // lv
+    "打听",  // dating
+    "急忙",  // jimang
+    "英俊",  // yingjun
+    "按	暗	岸",  // an
+    "寒假",  // hanjia
+    "腾讯",  // tengxun
+    "心脏",  // xinzang
+    "扇子",  // shanzi
+    "梅西",  // meixi
+    "总是",  // zongshi
+    "工具",  // gongju
+    "最后",  // zuihou
+    "对象",  // duixiang
+    "、",  // \
+    "上午	商务",  // shangwu
+    "位于",  // weiyu
+    "严肃",  // yansu
+    "活跃",  // huoyue
+    "成为",  // chengwei
+    "理想",  // lixiang
+    "证明",  // zhengming
+    "鸡蛋",  // jidan
+    "相似",  // xiangsi
+    "戒指",  // jiezhi
+    "气温",  // qiwen
+    "苹果",  // pingguo
+    "热爱",  // reai
+    "充分",  // chongfen
+    "书架",  // shujia
+    "从而",  // conger
+    "吵架",  // chaojia
+    "门",  // men
+    "后面",  // houmian
+    "无	五	屋	雾	勿",  // wu
+    "大学",  // daxue
+    "平时",  // pingshi
+    "因为",  // yinwei
+    "体现",  // tixian
+    "核心",  // hexin
+    "糟糕",  // zaogao
+    "身边",  // shenbian
+    "刷牙",  // shuaya
+    "迫切",  // poqie
+    "马克",  // make
+    "美德",  // meide
+    "爱情",  // aiqing
+    "表示",  // biaoshi
+    "宣传",  // xuanchuan
+    "司机",  // siji
+    "明确",  // mingque
+    "家务",  // jiawu
+    "青蛙",  // qingwa
+    "廉颇",  // lianpo
+    "抽	臭	丑",  // chou
+    "唯一",  // weiyi
+    "光临",  // guanglin
+    "既然",  // jiran
+    "包裹",  // baoguo
+    "词汇",  // cihui
+    "冲",  // chong
+    "皮肤",  // pifu
+    "空间",  // kongjian
+    "单调",  // dandiao
+    "脾气",  // piqi
+    "因素",  // yinsu
+    "公平",  // gongping
+    "栏杆",  // langan
+    "蝴蝶",  // hudie
+    "施肥",  // shifei
+    "志愿者",  // zhiyuanzhe
+    "极了",  // jile
+    "抽屉",  // chouti
+    "纷纷",  // fenfen
+    "八成儿",  // bachengr
+    "故事",  // gushi
+    "关注",  // guanzhu
+    "地区",  // diqu
+    "贸易",  // maoyi
+    "因此",  // yinci
+    "无法",  // wufa
+    "儿童",  // ertong
+    "透明",  // touming
+    "能干",  // nenggan
+    "时髦",  // shimao
+    "经典",  // jingdian
+    "诚信",  // chengxin
+    "乒乓球",  // pingpangqiu
+    "造成",  // zaocheng
+    "客厅",  // keting
+    "消极",  // xiaoji
+    "降低",  // jiangdi
+    "突出",  // tuchu
+    "人才",  // rencai
+    "光线",  // guangxian
+    "感受",  // ganshou
+    "信封",  // xinfeng
+    "开放",  // kaifang
+    "约会",  // yuehui
+    "郊区",  // jiaoqu
+    "认真",  // renzhen
+    "哪怕",  // napa
+    "麦克风",  // maikefeng
+    "对比",  // duibi
+    "增长",  // zengzhang
+    "。",  // .
+    "钢铁",  // gangtie
+    "城市	诚实",  // chengshi
+    "歌舞",  // gewu
+    "到达",  // daoda
+    "收拾",  // shoushi
+    "股票",  // gupiao
+    "表面",  // biaomian
+    "网球",  // wangqiu
+    "日期",  // riqi
+    "秘书",  // mishu
+    "礼拜天",  // libaitian
+    "浪费",  // langfei
+    "暖和",  // nuanhuo
+    "零食",  // lingshi
+    "广和居",  // guangheju
+    "海里",  // haili
+    "名牌",  // mingpai
+    "友谊",  // youyi
+    "丢",  // diu
+    "文学家",  // wenxuejia
+    "或许",  // huoxu
+    "这儿",  // zher
+    "相同",  // xiangtong
+    "明星",  // mingxing
+    "没关系",  // meiguanxi
+    "基本",  // jiben
+    "固定",  // guding
+    "转告",  // zhuangao
+    "振动",  // zhendong
+    "手表",  // shoubiao
+    "利益",  // liyi
+    "彻底",  // chedi
+    "担心",  // danxin
+    "压力",  // yali
+    "措施",  // cuoshi
+    "检查",  // jiancha
+    "环保",  // huanbao
+    "平均",  // pingjun
+    "十分",  // shifen
+    "温暖",  // wennuan
+    "孙子",  // sunzi
+    "半	班	般	搬",  // ban
+    "号码",  // haoma
+    "秘密",  // mimi
+    "顶	叮",  // ding
+    "《说文解字》",  // <shuowenjiezi>
+    "从前",  // congqian
+    "管理",  // guanli
+    "物质",  // wuzhi
+    "当然",  // dangran
+    "周",  // zhou
+    "片面",  // pianmian
+    "差距",  // chaju
+    "阶段",  // jieduan
+    "随便",  // suibian
+    "里查德·希尔斯",  // lichadexiersi
+    "闪电",  // shandian
+    "体会",  // tihui
+    "沙漠",  // shamo
+    "市场",  // shichang
+    "隔壁",  // gebi
+    "阅读",  // yuedu
+    "以为",  // yiwei
+    "解决",  // jiejue
+    "命运",  // mingyun
+    "例子",  // lizi
+    "年纪	年级",  // nianji
+    "美好",  // meihao
+    "轻视",  // qingshi
+    "惭愧",  // cankui
+    "希望",  // xiwang
+    "学期",  // xueqi
+    "斯坦福",  // sitanfu
+    "寂寞",  // jimo
+    "印象",  // yinxiang
+    "先生",  // xiansheng
+    "扶	富	幅",  // fu
+    "表扬",  // biaoyang
+    "想象",  // xiangxiang
+    "答案",  // daan
+    "幻想",  // huanxiang
+    "位置",  // weizhi
+    "宾馆",  // binguan
+    "当心",  // dangxin
+    "由于	犹豫",  // youyu
+    "保留",  // baoliu
+    "情绪",  // qingxu
+    "推迟",  // tuichi
+    "目标",  // mubiao
+    "长途",  // changtu
+    "羽毛球",  // yumaoqiu
+    "今年",  // jinnian
+    "裤子",  // kuzi
+    "发展",  // fazhan
+    "好客",  // haoke
+    "游戏",  // youxi
+    "军事",  // junshi
+    "装	撞",  // zhuang
+    "任务	人物",  // renwu
+    "干杯",  // ganbei
+    "禁止",  // jinzhi
+    "长	场	尝",  // chang
+    "洗澡",  // xizao
+    "实现	事先",  // shixian
+    "起飞",  // qifei
+    "决心",  // juexin
+    "经验",  // jingyan
+    "深刻",  // shenke
+    "完善",  // wanshan
+    "象征",  // xiangzheng
+    "世纪	实际",  // shiji
+    "分析",  // fenxi
+    "一会儿",  // yihuier
+    "论文",  // lunwen
+    "眉毛",  // meimao
+    "网络",  // wangluo
+    "洒",  // sa
+    "俩",  // lia
+    "可见",  // kejian
+    "马上",  // mashang
+    "放纵",  // fangzong
+    "的	地	得",  // de
+    "对方",  // duifang
+    "跟	根",  // gen
+    "不如",  // buru
+    "本来",  // benlai
+    "说话",  // shuohua
+    "上",  // shang
+    "帽子",  // maozi
+    "鸟",  // niao
+    "快	块",  // kuai
+    "《呐喊》",  // <nahan>
+    "团",  // tuan
+    "原来",  // yuanlai
+    "有点	优点",  // youdian
+    "发表",  // fabiao
+    "讲座",  // jiangzuo
+    "虽然",  // suiran
+    "要求",  // yaoqiu
+    "出院",  // chuyuan
+    "逐渐",  // zhujian
+    "主任	主人",  // zhuren
+    "回来",  // huilai
+    "是否",  // shifou
+    "接触",  // jiechu
+    "标点",  // biaodian
+    "修改",  // xiugai
+    "企业",  // qiye
+    "除了",  // chule
+    "我们",  // women
+    "特别",  // tebie
+    "弹钢琴",  // tangangqin
+    "名字",  // mingzi
+    "不安",  // buan
+    "球迷",  // qiumi
+    "法律",  // falv
+    "立刻",  // like
+    "反省",  // fanxing
+    "本	笨",  // ben
+    "说不定",  // shuobuding
+    "庆祝",  // qingzhu
+    "色彩",  // secai
+    "光明",  // guangming
+    "了解",  // liaojie
+    "你",  // ni
+    "占线",  // zhanxian
+    "欧洲环境保护署",  // ouzhouhuanjingbaohushu
+    "相处",  // xiangchu
+    "传说",  // chuanshuo
+    "另外",  // lingwai
+    "计算",  // jisuan
+    "假设",  // jiashe
+    "扩",  // kuo
+    "流泪",  // liulei
+    "星星",  // xingxing
+    "一边",  // yibian
+    "嗓子",  // sangzi
+    "分享",  // fenxiang
+    "早",  // zao
+    "允许",  // yunxu
+    "谦虚",  // qianxu
+    "等待",  // dengdai
+    "产生",  // chansheng
+    "博士",  // boshi
+    "草",  // cao
+    "忽视	护士",  // hushi
+    "流行",  // liuxing
+    "怎么办",  // zenmeban
+    "片	篇	骗",  // pian
+    "总之",  // zongzhi
+    "如何",  // ruhe
+    "多么",  // duome
+    "种	重	钟",  // zhong
+    "快乐",  // kuaile
+    "道歉",  // daoqian
+    "方式",  // fangshi
+    "两	亮	辆",  // liang
+    "从事",  // congshi
+    "幽默",  // youmo
+    "您",  // nin
+    "低	底	递	滴",  // di
+    "说",  // shuo
+    "确实",  // queshi
+    "承认	成人",  // chengren
+    "适应",  // shiying
+    "《非你莫属》",  // <feinimoshu>
+    "特点",  // tedian
+    "一直	一致",  // yizhi
+    "抽烟",  // chouyan
+    "为什么",  // weishenme
+    "普遍",  // pubian
+    "听	停	挺",  // ting
+    "完全",  // wanquan
+    "程度",  // chengdu
+    "游泳",  // youyong
+    "疯狂",  // fengkuang
+    "生物钟",  // shengwuzhong
+    "力量",  // liliang
+    "通知",  // tongzhi
+    "问候",  // wenhou
+    "短信",  // duanxin
+    "特征",  // tezheng
+    "装饰",  // zhuangshi
+    "确定",  // queding
+    "发达",  // fada
+    "句子	橘子	桔子	锯子",  // juzi
+    "工人",  // gongren
+    "优秀",  // youxiu
+    "生产",  // shengchan
+    "自行车",  // zixingche
+    "理发店",  // lifadian
+    "自己",  // ziji
+    "赞美",  // zanmei
+    "粮食",  // liangshi
+    "适当",  // shidang
+    "打扫",  // dasao
+    "到处",  // daochu
+    "奇特",  // qite
+    "部门",  // bumen
+    "口味",  // kouwei
+    "国际	国籍",  // guoji
+    "死	四	丝	私	撕",  // si
+    "公开",  // gongkai
+    "参观",  // canguan
+    "病毒",  // bingdu
+    "咨询",  // zixun
+    "合作",  // hezuo
+    "方案",  // fangan
+    "资源	自愿",  // ziyuan
+    "黄河",  // huanghe
+    "小吃",  // xiaochi
+    "衣服",  // yifu
+    "春秋",  // chunqiu
+    "训练",  // xunlian
+    "下	夏	吓	瞎",  // xia
+    "爱迪生",  // aidisheng
+    "吹",  // chui
+    "付款",  // fukuan
+    "总算",  // zongsuan
+    "灾害",  // zaihai
+    "相反",  // xiangfan
+    "体验",  // tiyan
+    "有利",  // youli
+    "后悔",  // houhui
+    "理发",  // lifa
+    "金石为开",  // jinshiweikai
+    "轮流",  // lunliu
+    "姑姑",  // gugu
+    "说明",  // shuoming
+    "狮子",  // shizi
+    "戏剧",  // xiju
+    "正常",  // zhengchang
+    "围巾",  // weijin
+    "投篮",  // toulan
+    "抱	包	保	薄	饱",  // bao
+    "朝三暮四",  // zhaosanmusi
+    "祝贺",  // zhuhe
+    "西	系	洗	夕",  // xi
+];
+
+// Frequency weights for the choices in CIYU, in the same order. Choices
+// are sorted by descending weight, so the first choice is the default.
+pub static CIYU_WEIGHT: &[&[u32]] = &[
+    &[2984],  // ruo
+    &[279],  // beiguan
+    &[1157],  // shouhuo
+    &[546],  // xiangfang
+    &[11239],  // guimo
+    &[1722],  // yumi
+    &[258],  // zheli
+    &[107],  // laoying
+    &[639],  // riji
+    &[526],  // xiangjiao
+    &[4483],  // linshi
+    &[3510],  // sunshi
+    &[1762],  // hutong
+    &[14906],  // zhijie
+    &[3955],  // xingqu
+    &[129],  // chengyu
+    &[31],  // jiaoshui
+    &[55415, 8544, 2365, 1241],  // cai
+    &[701],  // kuazhang
+    &[368],  // choulou
+    &[604],  // wangsheng
+    &[1527],  // qiaoqiao
+    &[637],  // shuaidao
+    &[240],  // laolao
+    &[628],  // zaisan
+    &[184674, 2470],  // dui
+    &[950],  // xingbie
+    &[369],  // youyou
+    &[950],  // huasheng
+    &[62779, 17871, 3728, 2850, 1566, 1174],  // qian
+    &[4670, 85],  // shuzi
+    &[4099, 3550, 3481, 1024, 642],  // tang
+    &[6285],  // fenzhong
+    &[1458],  // chengshou
+    &[5899],  // leixing
+    &[1867],  // juese
+    &[3541],  // juban
+    &[2225],  // haochu
+    &[2081],  // fanrong
+    &[6206],  // jiezhe
+    &[687],  // chengzuo
+    &[5312],  // yidan
+    &[203],  // chongwu
+    &[951],  // yuqi
+    &[3856],  // fangxin
+    &[1],  // weibo
+    &[1392, 565],  // hu
+    &[140],  // riyongpin
+    &[1155],  // shouming
+    &[2314],  // banshi
+    &[1507],  // luxun
+    &[16233],  // nongye
+    &[4590],  // diqiu
+    &[4624],  // huai
+    &[55339, 12121, 2147],  // bian
+    &[248559, 3978],  // nian
+    &[365],  // sheru
+    &[4556],  // juli
+    &[3823],  // tuijian
+    &[7715],  // zhuangtai
+    &[3850],  // lihai
+    &[2838],  // renbuzhu
+    &[14397],  // jianzhu
+    &[56],  // gonggongqiche
+    &[454],  // shihua
+    &[3577],  // jujue
+    &[979],  // xingren
+    &[12474, 1874],  // sheji
+    &[1023],  // chufang
+    &[7962],  // shenti
+    &[3305],  // shoushu
+    &[1312],  // ouran
+    &[611, 534],  // chaoshi
+    &[83],  // bujiasisuo
+    &[25633],  // chuxian
+    &[141],  // ganxiang
+    &[8127],  // hangye
+    &[60450, 6654, 1736],  // zui
+    &[8512],  // zhuanmen
+    &[265],  // chunyou
+    &[297],  // ciyao
+    &[1026],  // fannao
+    &[13460],  // kexue
+    &[1075],  // youmei
+    &[10261],  // baochi
+    &[4402],  // laoban
+    &[1],  // maibuliqi
+    &[10497, 7254, 3939],  // cha
+    &[9035],  // xiandai
+    &[8831],  // jiating
+    &[719],  // shaowei
+    &[1073],  // baobei
+    &[4096],  // mianlin
+    &[5444],  // zhuangkuang
+    &[3036, 997],  // meili
+    &[1463],  // liqi
+    &[976],  // suoduan
+    &[1069],  // zhengqi
+    &[337],  // weikou
+    &[352],  // mangren
+    &[9754],  // jihu
+    &[11867],  // fengfu
+    &[1568],  // queren
+    &[1958],  // julebu
+    &[16365],  // wancheng
+    &[1],  // bukeqi
+    &[8944],  // jieshu
+    &[3168],  // jianjue
+    &[5610],  // chengxu
+    &[52407, 26048, 16809],  // mei
+    &[77],  // cuxin
+    &[1437],  // zhanlan
+    &[9323],  // xunsu
+    &[111],  // jiayouzhan
+    &[1],  // zhaoshe
+    &[34460],  // lishi
+    &[12101],  // fanwei
+    &[3898],  // qiguai
+    &[9561],  // gandao
+    &[970],  // banye
+    &[1617],  // meitan
+    &[11445],  // chuantong
+    &[15691],  // keji
+    &[269],  // pingfang
+    &[6044],  // lianghao
+    &[1567],  // wunai
+    &[15239],  // shenzhi
+    &[186],  // guoqi
+    &[2907],  // jilie
+    &[427],  // panzi
+    &[1449],  // zhongwu
+    &[8438],  // gudai
+    &[810],  // aihao
+    &[724],  // kaiwanxiao
+    &[7],  // jingchengsuozhi
+    &[205],  // neike
+    &[569],  // yilian
+    &[6801],  // laizi
+    &[360],  // kaimushi
+    &[2030],  // wudao
+    &[302],  // zaihu
+    &[882],  // wuyi
+    &[2357],  // didian
+    &[827],  // nanshou
+    &[12074],  // yinqi
+    &[5811],  // youshi
+    &[6209],  // ceng
+    &[378],  // jiaozi
+    &[6063],  // zhongshi
+    &[6340],  // cuo
+    &[2791],  // jilei
+    &[35029],  // yanjiu
+    &[827],  // leguan
+    &[3970],  // pingheng
+    &[8237],  // chukou
+    &[306],  // gongxi
+    &[5132],  // huanying
+    &[3728],  // pangbian
+    &[512],  // chexiang
+    &[4068],  // haiyang
+    &[524],  // wuliao
+    &[106],  // sheyingshi
+    &[3778],  // tuo
+    &[1131],  // jinshen
+    &[401339, 134035, 60864],  // ta
+    &[1569],  // gongchengshi
+    &[7836],  // anpai
+    &[112],  // beijingshifandaxue
+    &[1611],  // hanyu
+    &[670],  // xingyun
+    &[620],  // xiaoyu
+    &[1471],  // youqu
+    &[295952, 16243, 2208, 1459],  // wei
+    &[27339],  // zenme
+    &[2383],  // zunzhong
+    &[1625],  // didi
+    &[6547],  // zhongjian
+    &[795],  // danyuan
+    &[8163],  // zhankai
+    &[36799],  // chi
+    &[614],  // kongtiao
+    &[14878, 2420, 1310, 1282],  // ai
+    &[780],  // quedian
+    &[3333],  // liyou
+    &[364],  // yagao
+    &[2105],  // daoyan
+    &[1755],  // zhongwen
+    &[23243],  // renkou
+    &[2828],  // youxian
+    &[875],  // yeyu
+    &[10953],  // gaosu
+    &[732],  // laohu
+    &[373],  // guitai
+    &[699],  // hushuo
+    &[1],  // peizhansen
+    &[322],  // richeng
+    &[3638],  // dute
+    &[220],  // xiaoshun
+    &[412],  // darao
+    &[27430],  // xuyao
+    &[10675],  // zhiyao
+    &[98],  // qianbao
+    &[481],  // youdianr
+    &[1],  // pei`zhansen
+    &[1401],  // xushu
+    &[1290],  // gewai
+    &[1183],  // zisha
+    &[47400],  // zhexie
+    &[691],  // nanguo
+    &[5985],  // jingzheng
+    &[1],  // yaobu
+    &[1105, 795],  // shuai
+    &[249],  // fashao
+    &[1165],  // laji
+    &[171],  // mahu
+    &[23118, 1520],  // jianli
+    &[1],  // `
+    &[886],  // shangdian
+    &[18155, 342],  // shao
+    &[582],  // xingkui
+    &[2426],  // heshi
+    &[1321],  // kunchong
+    &[61],  // jiazi
+    &[1606],  // baoming
+    &[4115],  // guannian
+    &[1087],  // chenghu
+    &[734],  // weiba
+    &[660],  // daoyou
+    &[7767],  // ganjue
+    &[3],  // liaotianr
+    &[1611],  // huoban
+    &[5410],  // fenpei
+    &[2298],  // qiong
+    &[10012],  // biaozhun
+    &[2635],  // baoxian
+    &[3332],  // jiti
+    &[13662, 1419],  // zhiding
+    &[692],  // zishi
+    &[28],  // yinliang
+    &[35979, 2001, 1363],  // tian
+    &[1205],  // zhengqiu
+    &[3346],  // zongjie
+    &[57991],  // zhuyao
+    &[8009],  // zhiliang
+    &[275],  // zhaoxiangji
+    &[13924],  // yixia
+    &[1],  // (
+    &[120],  // xiangpi
+    &[3200],  // yihuir
+    &[42542, 1062],  // san
+    &[365],  // shengdiao
+    &[6415, 924],  // laoshi
+    &[489],  // cucao
+    &[1860],  // ganjing
+    &[9321],  // quanmian
+    &[6498],  // daozhi
+    &[11062, 1905],  // huang
+    &[1277],  // ce
+    &[1263],  // qiantu
+    &[9876, 2900],  // zhuyi
+    &[247],  // zisi
+    &[2615],  // tiaozhan
+    &[31550],  // shenghuo
+    &[5358],  // shizhong
+    &[15387],  // sixiang
+    &[351],  // nvhair
+    &[338],  // chenshan
+    &[820],  // jingju
+    &[9769],  // juti
+    &[722],  // xiqu
+    &[1525],  // zuoye
+    &[2371],  // bawo
+    &[906],  // shijin
+    &[217830, 136106, 4023, 953],  // yi
+    &[466],  // maojin
+    &[1],  // bier`gaici
+    &[215],  // liuli
+    &[932],  // qiaomiao
+    &[12193],  // zhongda
+    &[343],  // qunzi
+    &[3889],  // miqie
+    &[327],  // feihua
+    &[4803],  // sheshi
+    &[21245, 19918, 7047],  // ma
+    &[1],  // )
+    &[5144],  // leng
+    &[11240],  // tudi
+    &[489],  // zhanghu
+    &[3172],  // juran
+    &[6043],  // daodi
+    &[418],  // xiangshan
+    &[2986, 721],  // yaoshi
+    &[20778],  // kou
+    &[40],  // qingyuanshu
+    &[12817, 9891, 1024],  // la
+    &[3],  // tizuqiu
+    &[122],  // ruwei
+    &[139],  // enai
+    &[25733, 4398, 4383, 1046],  // dai
+    &[21386],  // nimen
+    &[7647, 239],  // yuyan
+    &[6245, 1057],  // lou
+    &[1454],  // deyi
+    &[14690, 542],  // jixu
+    &[413],  // sichou
+    &[78],  // renming
+    &[4804],  // zhizuo
+    &[10160],  // shangpin
+    &[378],  // jianpan
+    &[1103],  // shushu
+    &[652],  // jiazhuang
+    &[6532],  // xiangdui
+    &[8200],  // zongli
+    &[3379],  // zige
+    &[748],  // mutou
+    &[13559],  // liyong
+    &[1221],  // timu
+    &[16239],  // ranhou
+    &[12682],  // yuanyin
+    &[440],  // jiabin
+    &[4712, 4274],  // han
+    &[38],  // heixingxing
+    &[2642],  // mianshi
+    &[945],  // baoyuan
+    &[1008],  // juan
+    &[18874],  // nengli
+    &[31213],  // keneng
+    &[62626, 11188],  // xin
+    &[7657],  // suowei
+    &[1092],  // suishou
+    &[437],  // chuguo
+    &[138],  // nuobeierjiang
+    &[3981],  // jiujing
+    &[77],  // wenju
+    &[132],  // donghuapian
+    &[5466],  // zhihao
+    &[2165],  // bici
+    &[6789],  // xiangxin
+    &[4991],  // pohuai
+    &[124793, 4878],  // hou
+    &[655, 428],  // beizi
+    &[1826],  // lvshi
+    &[382],  // lengdan
+    &[3825],  // xiuxi
+    &[3742],  // chengshu
+    &[5141],  // zaiyu
+    &[8844],  // jiji
+    &[9684],  // rujin
+    &[259],  // quanshenguanzhu
+    &[417],  // jiayou
+    &[754],  // langman
+    &[30204],  // renwei
+    &[1223],  // qingshaonian
+    &[2935],  // zhixu
+    &[1043],  // haohua
+    &[4790],  // jinzhang
+    &[3026],  // qie
+    &[9955, 41],  // baodao
+    &[2713],  // luohou
+    &[2047],  // zhuozi
+    &[238],  // qiju
+    &[2059],  // yiwu
+    &[32833],  // qingkuang
+    &[258],  // huangzhang
+    &[612],  // zhuajin
+    &[34488, 3342],  // beijing
+    &[1131],  // lvxing
+    &[1064],  // pen
+    &[9248],  // zuopin
+    &[433],  // lingjian
+    &[3795],  // shihe
+    &[5328],  // tianran
+    &[2019],  // fanyi
+    &[4283],  // zhangfu
+    &[206],  // jiaoji
+    &[517],  // chizao
+    &[6464],  // taolun
+    &[279],  // dashiguan
+    &[3],  // huguosi
+    &[9791],  // caiqu
+    &[5166],  // biaoming
+    &[3234],  // gaijin
+    &[62],  // fense
+    &[27626],  // lu
+    &[1546],  // kekao
+    &[364],  // guangpan
+    &[1787],  // bizi
+    &[108],  // xiangchang
+    &[3808],  // nianling
+    &[2692],  // wuran
+    &[217],  // chuanglian
+    &[3515],  // zhudong
+    &[169],  // qingdan
+    &[555815, 12374, 10053],  // he
+    &[210, 117],  // guanzi
+    &[448],  // youyisi
+    &[452],  // liushou
+    &[96],  // daiyan
+    &[7424],  // guangchang
+    &[824],  // yingjian
+    &[4885],  // pingdeng
+    &[7645],  // huran
+    &[2410],  // wangji
+    &[1677],  // jingcha
+    &[200],  // taijiquan
+    &[5260],  // juedui
+    &[8729],  // yiyi
+    &[12],  // xinglixiang
+    &[122],  // shouju
+    &[110207, 27535, 15864],  // yue
+    &[4055],  // yuanliao
+    &[17465],  // haizi
+    &[98],  // zhaohuo
+    &[4126],  // daying
+    &[2205],  // daifu
+    &[2356, 594],  // jianzhi
+    &[336],  // fanchuan
+    &[1504],  // jianqing
+    &[2935],  // zidong
+    &[21810],  // a
+    &[20856, 5248, 5076],  // zhao
+    &[467],  // jianju
+    &[884],  // jinli
+    &[2149],  // meimei
+    &[3096],  // budebu
+    &[733],  // shougong
+    &[8926],  // jieshao
+    &[27951],  // nv
+    &[56478],  // geng
+    &[7135, 2523],  // youqi
+    &[1565],  // yilv
+    &[2603],  // sihao
+    &[5889],  // fahui
+    &[3979],  // weixian
+    &[1],  // <maihuochaidexiaonvhaier>
+    &[24649],  // jizhe
+    &[19799],  // jihua
+    &[850],  // dagong
+    &[3981],  // miao
+    &[8395],  // shuang
+    &[6089],  // yisi
+    &[4323],  // gege
+    &[1166],  // youhui
+    &[13083],  // gongli
+    &[1196],  // jianbang
+    &[6416],  // goucheng
+    &[8563],  // bingqie
+    &[4042],  // fengxian
+    &[848],  // lianai
+    &[29396],  // suoyi
+    &[7275],  // jianshao
+    &[886],  // lihun
+    &[311],  // waigong
+    &[1469],  // anwei
+    &[2491],  // kanfa
+    &[37557],  // zhongyao
+    &[309],  // hanyi
+    &[11557],  // anzhao
+    &[586],  // daomei
+    &[296],  // tizi
+    &[1188],  // yachi
+    &[2402],  // xinhao
+    &[1188],  // diantai
+    &[129],  // shenglve
+    &[469],  // shengbing
+    &[307],  // shenyuan
+    &[1205],  // yezi
+    &[418],  // ceyan
+    &[2456],  // baba
+    &[53],  // ganhuor
+    &[11950],  // qishi
+    &[69],  // guahao
+    &[3451],  // yangguang
+    &[130],  // kuangquanshui
+    &[1691],  // xinxian
+    &[664],  // bunaifan
+    &[105],  // xishoujian
+    &[4454],  // qiyu
+    &[2166],  // fanzheng
+    &[15579],  // cunzai
+    &[3980],  // xiamian
+    &[8344, 417],  // diao
+    &[3932, 2001],  // jiqi
+    &[171],  // guaiwan
+    &[310],  // houbei
+    &[810],  // mantou
+    &[6482, 3504, 2112, 1136],  // jian
+    &[4263],  // yisheng
+    &[6986],  // shengming
+    &[8143, 3067],  // xiaoshi
+    &[1023],  // shixi
+    &[1365],  // guke
+    &[2055],  // nen
+    &[12463],  // xiangguan
+    &[13880],  // shuiping
+    &[1530],  // xiezuo
+    &[42],  // bingjiling
+    &[3088],  // biaoyan
+    &[1572],  // jiangjiu
+    &[814],  // kuaizi
+    &[73],  // zhaoguo
+    &[21581],  // zhiye
+    &[3261],  // guanxin
+    &[3342],  // xingfu
+    &[961],  // weiqu
+    &[930],  // bijiben
+    &[849],  // shangwang
+    &[1876],  // hebi
+    &[5322],  // bucuo
+    &[693],  // dianchi
+    &[1089],  // xiexie
+    &[549],  // yongji
+    &[2629],  // xinxin
+    &[31],  // baotuquan
+    &[22569],  // yiyang
+    &[7470],  // kong
+    &[318],  // qiyou
+    &[66],  // xialingying
+    &[1486],  // zhengju
+    &[1465],  // shai
+    &[3806],  // ganqing
+    &[6937],  // neibu
+    &[10908],  // jiaotong
+    &[24296, 18505, 7434],  // nan
+    &[8888, 3899],  // suan
+    &[355],  // wusuowei
+    &[23],  // caishikou
+    &[85],  // laoshe
+    &[797],  // huaiyun
+    &[25],  // tuya
+    &[20],  // shousui
+    &[1921],  // piaoliang
+    &[12256],  // xinxi
+    &[5742],  // shiyan
+    &[5153],  // zhuchi
+    &[153],  // heiban
+    &[71],  // dapenti
+    &[327],  // qianbi
+    &[1664],  // hege
+    &[400],  // mifan
+    &[862],  // shafa
+    &[7280, 6324, 5139],  // tui
+    &[12583],  // tiao
+    &[7571],  // nali
+    &[377],  // yongbao
+    &[2578],  // tou
+    &[1473],  // kuaiji
+    &[13762],  // guanyu
+    &[570],  // xinnian
+    &[11160],  // xuanze
+    &[11478],  // nongcun
+    &[3730],  // ruan
+    &[797],  // chouxiang
+    &[160984, 106176, 16190, 4450],  // yu
+    &[18045],  // fangfa
+    &[8829],  // kao
+    &[641],  // shanliang
+    &[17444],  // guoqu
+    &[68],  // keen
+    &[15471],  // dadao
+    &[1266],  // shengdong
+    &[9021],  // ziliao
+    &[669],  // kunrao
+    &[5962],  // chuban
+    &[3824],  // jidao
+    &[5887],  // tingshuo
+    &[230],  // shubiao
+    &[10340],  // shengyin
+    &[7678],  // zhiliao
+    &[5863],  // yazhou
+    &[11157],  // peizhun
+    &[5175],  // biyao
+    &[1567],  // ganji
+    &[4370],  // luqu
+    &[4593],  // shishi
+    &[3510],  // zhuanbian
+    &[6409],  // danren
+    &[12112, 2930],  // tu
+    &[881],  // yiwen
+    &[176],  // shaoxu
+    &[1],  // feinimoshu
+    &[1906],  // kelian
+    &[138],  // chehuo
+    &[10646, 3417],  // shen
+    &[195934, 3427],  // deng
+    &[4888],  // guilv
+    &[2714],  // xiaoyuan
+    &[3981],  // cun
+    &[16218],  // jingguo
+    &[25293],  // yiding
+    &[747],  // laoshu
+    &[2077],  // yeye
+    &[87597],  // meiyou
+    &[6776],  // touru
+    &[24221],  // genju
+    &[19421],  // shiqi
+    &[71],  // tuibu
+    &[2482],  // ha
+    &[19811, 7965, 4518, 1441, 121],  // gan
+    &[2476],  // suishi
+    &[6353, 1303],  // yun
+    &[1245],  // gudian
+    &[1757],  // kuang
+    &[2307],  // dandu
+    &[1719],  // shengyi
+    &[4728],  // yange
+    &[23523, 7559, 5581, 916],  // qing
+    &[11398],  // quanbu
+    &[25842],  // shei
+    &[1537],  // yongqi
+    &[8569],  // lianhe
+    &[13874],  // baohu
+    &[8254],  // zhishi
+    &[1986],  // xinren
+    &[321],  // zhengjian
+    &[282],  // chuanran
+    &[1024],  // pindao
+    &[2572],  // jindai
+    &[673],  // paidui
+    &[119],  // penzi
+    &[15777],  // jiazhi
+    &[3628],  // yiran
+    &[2370],  // guli
+    &[1950],  // kepa
+    &[5660, 4995, 1193],  // du
+    &[1860],  // teng
+    &[3599],  // chengjiu
+    &[13963],  // jieguo
+    &[3258],  // yaoqing
+    &[465],  // shijuan
+    &[7478, 4796, 2071],  // ku
+    &[144099, 33853],  // da
+    &[465],  // yuandan
+    &[106845, 9853, 4282, 797],  // bei
+    &[1339],  // xijie
+    &[54355],  // jinxing
+    &[343],  // zaijian
+    &[5620],  // lijie
+    &[5232],  // shuju
+    &[1446],  // jiekou
+    &[2332],  // zenmeyang
+    &[11537],  // kongzhi
+    &[19810],  // renyuan
+    &[4753],  // chun
+    &[66641, 3318],  // kan
+    &[395],  // chuzuche
+    &[1729],  // jirou
+    &[76],  // yashua
+    &[3369],  // caifang
+    &[5043],  // guangda
+    &[5988],  // biye
+    &[1],  // zhanmushinaishimisi
+    &[488],  // wangfan
+    &[90],  // dazhen
+    &[39788],  // qilai
+    &[627],  // can
+    &[2408, 577],  // wushu
+    &[14659],  // niandai
+    &[278],  // guzhang
+    &[8439],  // wending
+    &[5041],  // zhengzai
+    &[2153],  // luxu
+    &[160],  // miaotiao
+    &[69480],  // gei
+    &[1],  // jilingdai
+    &[2855],  // qiu
+    &[1337],  // zhenglun
+    &[18464],  // dedao
+    &[1535],  // pang
+    &[3776],  // qubie
+    &[343],  // luobo
+    &[481],  // xiayu
+    &[1],  // liuyiertongjie
+    &[6768],  // putong
+    &[155],  // milu
+    &[81],  // lianhuanhua
+    &[493],  // tudou
+    &[4593],  // xiguan
+    &[3909],  // weixia
+    &[6927],  // shuliang
+    &[1448],  // nvshi
+    &[10510],  // gongtong
+    &[2489],  // fangjian
+    &[28837, 13523, 3841],  // yuan
+    &[1213],  // junyun
+    &[558],  // baoyu
+    &[242],  // yangtai
+    &[3662],  // xinqing
+    &[2735],  // zhanguo
+    &[868],  // yingpin
+    &[1594],  // guiju
+    &[6207],  // nandao
+    &[307851, 4911, 4020],  // ye
+    &[2981],  // fengjing
+    &[185],  // biguan
+    &[1491],  // naru
+    &[9771],  // lingyu
+    &[1469],  // liulan
+    &[34173],  // qizhong
+    &[12961],  // jingshen
+    &[2817],  // jianglai
+    &[13482],  // xuexi
+    &[2142],  // banli
+    &[1531],  // gexing
+    &[994],  // gouwu
+    &[185],  // taojiahuanjia
+    &[15995],  // juede
+    &[5315],  // qinzi
+    &[4525],  // mama
+    &[1],  // ;
+    &[6640],  // jingran
+    &[775],  // bangwan
+    &[768],  // wanju
+    &[119],  // kongr
+    &[28567, 947],  // zuowei
+    &[9091],  // feiji
+    &[3850],  // beifang
+    &[22165, 3543, 2645],  // quan
+    &[3074],  // huabei
+    &[2719],  // baozhi
+    &[1061],  // yingzi
+    &[8664],  // changqi
+    &[22801],  // chanpin
+    &[1305],  // mingming
+    &[288],  // ciyu
+    &[145],  // linlijinzhi
+    &[6317],  // lianxu
+    &[31],  // chazi
+    &[363],  // qiaokeli
+    &[11839],  // zhengge
+    &[3264],  // mingtian
+    &[9985],  // che
+    &[78695],  // ri
+    &[241],  // zhuzi
+    &[946],  // hutu
+    &[3258],  // dengyu
+    &[3125],  // gebie
+    &[84],  // xiaoqi
+    &[658],  // fadou
+    &[2521],  // xingge
+    &[230],  // xiaopengyou
+    &[906],  // cesuo
+    &[745],  // qifa
+    &[2714],  // hunyin
+    &[61],  // naozhong
+    &[3018],  // nainai
+    &[336],  // sanbu
+    &[271],  // fapiao
+    &[5089],  // xianzhi
+    &[231],  // shoutao
+    &[801],  // niunai
+    &[11336],  // bisai
+    &[3444],  // keren
+    &[1277, 870],  // kua
+    &[1022],  // mima
+    &[1162],  // zancheng
+    &[811],  // putao
+    &[559],  // dafang
+    &[188],  // xihongshi
+    &[260],  // bacheng
+    &[11190, 731],  // yanjing
+    &[1140],  // yizai
+    &[8822],  // huida
+    &[859],  // miantiao
+    &[48718],  // jingji
+    &[22],  // zhaor
+    &[11895],  // bujin
+    &[243],  // zuoke
+    &[42694, 2950],  // dang
+    &[2890],  // jushuo
+    &[69103, 4139],  // hen
+    &[1500],  // tiwen
+    &[2396, 1909],  // chen
+    &[728],  // nanguai
+    &[12732],  // chaoguo
+    &[107],  // shoudujichang
+    &[169],  // caidan
+    &[2049],  // biaoqing
+    &[10223, 5668, 5281, 420],  // ti
+    &[3073],  // baocun
+    &[664],  // jiaoshi
+    &[4781],  // waijiao
+    &[12100, 5717],  // ping
+    &[3100],  // yanse
+    &[1300],  // feng
+    &[1288],  // yingfu
+    &[28363, 1408],  // huiyi
+    &[1474],  // qingyi
+    &[9197],  // long
+    &[2312],  // fayuan
+    &[1],  // lichade`xiersi
+    &[2808],  // weidao
+    &[10142],  // gaibian
+    &[13],  // chujizhongxue
+    &[2924],  // keguan
+    &[19988, 11036, 3405],  // zhen
+    &[3065],  // zhuiqiu
+    &[2116],  // xiaoying
+    &[1327],  // shirun
+    &[1405],  // cui
+    &[623],  // jiandao
+    &[1],  // fengzhongdinglv
+    &[1551],  // tushuguan
+    &[9054],  // xianxiang
+    &[30776],  // xuesheng
+    &[17],  // xianggua
+    &[35136],  // mi
+    &[143233, 26135],  // er
+    &[26963],  // fangmian
+    &[292],  // yonggong
+    &[15867],  // biaoxian
+    &[13536],  // yushi
+    &[7289],  // shuangfang
+    &[3082],  // chifan
+    &[236],  // bianpao
+    &[807],  // maobing
+    &[535],  // shoubuliao
+    &[507],  // yuding
+    &[59317],  // shenme
+    &[286],  // xiongmao
+    &[5785, 5461, 5382],  // qiang
+    &[6568],  // xiangdang
+    &[6235],  // yiner
+    &[818],  // jingzi
+    &[110435],  // cong
+    &[1518],  // oumeng
+    &[3625],  // weixiao
+    &[9296],  // mingxian
+    &[2882],  // fanfu
+    &[4712],  // xiawu
+    &[405],  // youeryuan
+    &[12],  // huahua
+    &[322],  // keku
+    &[1876],  // fuzhuang
+    &[2441],  // xiaoxin
+    &[42780, 7794],  // zhidao
+    &[5387],  // gaoxing
+    &[8451],  // qingchu
+    &[9780],  // genben
+    &[5],  // zhigengniao
+    &[1041],  // taijie
+    &[125538, 44807],  // ge
+    &[3],  // fangshujia
+    &[5113],  // rizi
+    &[5214],  // nvxing
+    &[34660, 4674, 2351],  // fen
+    &[3476],  // pingjia
+    &[4421],  // fuhe
+    &[1249],  // gebo
+    &[1730],  // shuijiao
+    &[9922],  // haishi
+    &[4275],  // goumai
+    &[5617],  // gangcai
+    &[802],  // laideji
+    &[226],  // zhunshi
+    &[1892],  // guanbi
+    &[1985],  // boli
+    &[2157],  // zhaogu
+    &[49],  // zhidai
+    &[34860],  // wenhua
+    &[5354],  // yingxiong
+    &[2568],  // youhao
+    &[802],  // tongkuai
+    &[6069],  // weilai
+    &[6890],  // wenxue
+    &[1813],  // hulianwang
+    &[15354],  // shiqing
+    &[5615],  // buzu
+    &[1549],  // duimian
+    &[589],  // xianyan
+    &[13257],  // xiangmu
+    &[7652, 6618],  // fanying
+    &[43431, 12172, 6457, 6026, 3477, 1295, 605],  // jiao
+    &[3053],  // shuxi
+    &[4171],  // quefa
+    &[1330],  // xingqi
+    &[1254],  // tichang
+    &[8003],  // mingling
+    &[6506, 6294, 4001, 390],  // ju
+    &[812],  // fengci
+    &[854],  // buzhou
+    &[136],  // taoqi
+    &[5946],  // zeren
+    &[195],  // tigang
+    &[5971],  // jiankang
+    &[17],  // dengjipai
+    &[37664],  // jishu
+    &[5133],  // xiaofei
+    &[10403],  // shouru
+    &[571],  // yanhui
+    &[52641],  // difang
+    &[9286, 4175],  // gai
+    &[4848],  // zhubu
+    &[95892, 6270, 2900, 2524, 1980, 826, 506],  // ke
+    &[758],  // jingse
+    &[2416],  // shengqi
+    &[21073],  // weile
+    &[18510],  // jichu
+    &[9921],  // anquan
+    &[5476],  // gongbu
+    &[14820],  // keshi
+    &[33],  // lajitong
+    &[1057],  // shuizhao
+    &[3781],  // zhengti
+    &[14972],  // buduan
+    &[25854],  // xingcheng
+    &[36],  // meirenyu
+    &[983],  // qiji
+    &[1129],  // daban
+    &[4412],  // zhexue
+    &[6078],  // xiaoshou
+    &[94],  // fanhang
+    &[883],  // jieyue
+    &[3054],  // zeng
+    &[414],  // zhangbei
+    &[437],  // huainian
+    &[4288],  // wenjian
+    &[77054, 9736, 9566, 1145, 1110],  // li
+    &[3887],  // chufa
+    &[55],  // zhishangtanbing
+    &[532],  // taobi
+    &[2179],  // jinliang
+    &[9467, 8499, 1935],  // qiao
+    &[556],  // suishen
+    &[1260],  // chuse
+    &[4290],  // jinshu
+    &[2921, 1647],  // chuang
+    &[1255],  // youming
+    &[511],  // zhenxi
+    &[198],  // weiyang
+    &[1],  // zhaokuo
+    &[363],  // budeliao
+    &[183],  // zhaochang
+    &[35063],  // tongguo
+    &[111550, 19956, 12138],  // na
+    &[745],  // taoyan
+    &[333],  // kaishui
+    &[16120],  // ming
+    &[21884],  // bixu
+    &[1460],  // xueli
+    &[14656],  // qijian
+    &[1],  // weixin
+    &[58],  // liangcha
+    &[2972],  // piping
+    &[337],  // chidao
+    &[43719],  // renmin
+    &[32160],  // ze
+    &[23],  // wenwen
+    &[730],  // shangchang
+    &[3732],  // kongqi
+    &[12562],  // xinwen
+    &[3635],  // gongjin
+    &[24314, 2494],  // shui
+    &[1026],  // liuxue
+    &[109],  // jinyu
+    &[15188, 5510],  // xingshi
+    &[700],  // yubao
+    &[8010],  // muqin
+    &[191],  // biaoge
+    &[18930],  // changjiang
+    &[884],  // mianbao
+    &[1],  // zhaofugen
+    &[13986, 5348],  // tongyi
+    &[51289],  // yijing
+    &[133],  // zhongqiujie
+    &[3355],  // kongpa
+    &[202780, 424],  // dou
+    &[1059],  // yunqi
+    &[369],  // tuici
+    &[5762],  // gongxian
+    &[2052],  // aodaliya
+    &[15],  // zilu
+    &[2997],  // zixi
+    &[6280],  // shangye
+    &[8392, 6648],  // liu
+    &[7741],  // jichang
+    &[3],  // zhangxiaolong
+    &[102],  // guafeng
+    &[10329],  // yijian
+    &[630],  // guina
+    &[2085],  // shucai
+    &[4280],  // biran
+    &[50204],  // nei
+    &[4125],  // yanchu
+    &[15973],  // lilun
+    &[253],  // chushi
+    &[220],  // wanr
+    &[16214],  // zhuanye
+    &[188],  // guozhi
+    &[57969, 32256],  // xiao
+    &[18051],  // guocheng
+    &[3417],  // manyi
+    &[496],  // juhua
+    &[5265],  // jinbu
+    &[4707],  // zuotian
+    &[13587, 3122],  // jiang
+    &[648],  // jiangluo
+    &[2059],  // luoji
+    &[873],  // youlan
+    &[1125],  // rili
+    &[4936],  // wulun
+    &[161501],  // lai
+    &[5306],  // guandian
+    &[9832],  // ciwai
+    &[2013],  // pianyi
+    &[587],  // jianshen
+    &[2353],  // hekuang
+    &[949],  // xiaohuozi
+    &[1],  // maihuochaidexiaonvhair
+    &[104],  // pengzi
+    &[1804],  // fuxi
+    &[1178],  // laibuji
+    &[10840],  // chuli
+    &[30775],  // yiji
+    &[439],  // fenshou
+    &[166],  // jiuhuche
+    &[3748],  // yuangong
+    &[856],  // maoxian
+    &[443],  // hezi
+    &[1127],  // ditie
+    &[3531],  // gongzhu
+    &[3399],  // buchong
+    &[17532],  // danwei
+    &[101],  // huochengyan
+    &[4725],  // manzu
+    &[2048],  // jingcai
+    &[5621],  // biru
+    &[133],  // kongxian
+    &[76],  // danxiaogui
+    &[757],  // cu
+    &[1205],  // daoju
+    &[1217],  // jiaxiang
+    &[2213],  // ng
+    &[4024],  // zuozhe
+    &[98900, 1454],  // duo
+    &[10633],  // banfa
+    &[4888],  // xiande
+    &[3547],  // faner
+    &[127],  // zhileng
+    &[31521],  // shihou
+    &[2872],  // weibi
+    &[84],  // weiqi
+    &[33],  // ganxingqu
+    &[4435],  // gainian
+    &[123402, 10965, 2162],  // qu
+    &[186],  // dazhe
+    &[271],  // daxiang
+    &[25],  // huiguniang
+    &[454],  // zhaodai
+    &[5676],  // yangzi
+    &[5024],  // senlin
+    &[23],  // shijinr
+    &[7733],  // tongyang
+    &[6653],  // gua
+    &[5342],  // zhangwo
+    &[199],  // youju
+    &[1979],  // qingsong
+    &[301],  // dianziyoujian
+    &[1],  // baoquan
+    &[4554],  // yongyuan
+    &[4950],  // zhonglei
+    &[726],  // yigong
+    &[4112],  // yunyong
+    &[2994],  // laobaixing
+    &[15910],  // bijiao
+    &[188],  // youlai
+    &[524],  // zebei
+    &[25558, 25322, 440],  // xian
+    &[10154],  // mang
+    &[414],  // xiaoqu
+    &[25],  // xiangzi
+    &[20573],  // youguan
+    &[5538],  // yuanyi
+    &[1254],  // qinqi
+    &[3312],  // guowang
+    &[70],  // liangkuai
+    &[15958],  // feichang
+    &[26381],  // jianshe
+    &[16882],  // tigao
+    &[2575],  // xingfen
+    &[174],  // haixian
+    &[12266, 4413, 3336],  // bai
+    &[409],  // chuchai
+    &[4317],  // shiqu
+    &[989],  // keai
+    &[92],  // yajin
+    &[1626],  // jiaowang
+    &[6216],  // yinyue
+    &[962],  // zang
+    &[81],  // shenduan
+    &[304],  // wazi
+    &[437],  // zhuangxiu
+    &[23],  // yuxi
+    &[108066, 25526, 7422],  // ba
+    &[157058, 9676],  // hai
+    &[71],  // jizhen
+    &[176],  // jialifuniyazhou
+    &[1],  // tiananmendong
+    &[313209, 2506],  // ren
+    &[4053],  // zhendui
+    &[11622, 1870],  // zhuan
+    &[250],  // tiyuguan
+    &[202],  // binggan
+    &[53],  // ganhuoer
+    &[9062],  // baozheng
+    &[14],  // huiyuanka
+    &[14915],  // hong
+    &[8151],  // fangxiang
+    &[10402],  // yilai
+    &[617],  // yuanliang
+    &[7757],  // nuli
+    &[14935],  // bianhua
+    &[8585],  // kaolv
+    &[8034],  // juda
+    &[163],  // tengai
+    &[6217],  // chengji
+    &[4926, 2430],  // she
+    &[3704],  // yinzi
+    &[2619],  // qifen
+    &[560],  // liaotian
+    &[9343],  // yiqian
+    &[2369],  // sousuo
+    &[129470],  // zhongguo
+    &[77],  // langdu
+    &[5457],  // huaxue
+    &[2939],  // gaosugonglu
+    &[11866],  // nongmin
+    &[5254],  // zhiyu
+    &[1123],  // chengzan
+    &[16361],  // yiqie
+    &[4532],  // qizi
+    &[17900],  // juxing
+    &[14946],  // zanmen
+    &[1156],  // yihan
+    &[23],  // daoxiangcun
+    &[1665],  // jiaolian
+    &[4243],  // hongse
+    &[162],  // xuxin
+    &[1713],  // mingpian
+    &[28],  // xiangjingrubin
+    &[44880, 26275],  // cheng
+    &[164],  // paobu
+    &[196],  // qinglv
+    &[332],  // titie
+    &[7753, 5231, 1908],  // mao
+    &[2853],  // benke
+    &[4924],  // xiaoguo
+    &[4165],  // chengfen
+    &[12694],  // zhongdian
+    &[848, 31],  // zimu
+    &[4472],  // lirun
+    &[2661],  // zhenghao
+    &[38374],  // ruguo
+    &[2818],  // reqing
+    &[140957, 101442, 15928, 13375, 7500, 4883, 3727],  // zhi
+    &[2996],  // zuichu
+    &[7735],  // zhiwu
+    &[276],  // ayi
+    &[9212],  // zhengshi
+    &[22638],  // erqie
+    &[5348],  // congci
+    &[381],  // woshou
+    &[7600],  // likai
+    &[5024],  // qianmian
+    &[93096],  // neng
+    &[9366],  // fandui
+    &[17],  // jianburufei
+    &[3572],  // hetong
+    &[223],  // hangjia
+    &[2869],  // shenqing
+    &[2833],  // ca
+    &[8558],  // ziyou
+    &[4976],  // gongzi
+    &[3498],  // jiejin
+    &[1509],  // yizi
+    &[1963],  // relie
+    &[5501],  // xiaoshuo
+    &[10175],  // diren
+    &[4637],  // shide
+    &[14885, 4473, 1391, 1045],  // chao
+    &[1946],  // pingjing
+    &[9128],  // bangzhu
+    &[27619],  // bufen
+    &[4063],  // chengdan
+    &[275],  // feizao
+    &[5775],  // zhishao
+    &[2016],  // yufang
+    &[721],  // anshi
+    &[4433],  // yan
+    &[2801],  // tuiguang
+    &[796991, 64655, 7926, 6567, 3723],  // shi
+    &[21853, 6035],  // pai
+    &[25753],  // qita
+    &[17919, 17024, 7567, 2842, 2562, 2504],  // xie
+    &[371],  // duanduanxuxu
+    &[15960],  // jintian
+    &[417],  // lajiao
+    &[229],  // qingjia
+    &[9644],  // xiongdi
+    &[999],  // bodong
+    &[74],  // shouhuoyuan
+    &[3960],  // xunzhao
+    &[969],  // mofang
+    &[8332],  // diaocha
+    &[819],  // fengsu
+    &[20269],  // ziran
+    &[76462, 1505],  // suo
+    &[50310],  // rang
+    &[788],  // liuxie
+    &[7557],  // shizai
+    &[13539],  // song
+    &[2609],  // zuhe
+    &[6227],  // kending
+    &[317],  // xiangnian
+    &[4737],  // richang
+    &[13943],  // touzi
+    &[8963],  // xingwei
+    &[120],  // huixin
+    &[96],  // suliaodai
+    &[986],  // fouren
+    &[973],  // anna
+    &[21067],  // yinggai
+    &[59],  // zhantie
+    &[8853],  // guniang
+    &[28055],  // danshi
+    &[4552],  // mo
+    &[6679],  // zongtong
+    &[9137],  // cailiao
+    &[516],  // kache
+    &[7805, 1581],  // zhihui
+    &[476],  // zhuo
+    &[261791, 188584, 4410],  // zhe
+    &[993],  // shatan
+    &[15976],  // yiqi
+    &[607],  // jisheng
+    &[1],  // guloudajie
+    &[1193],  // yukuai
+    &[6308],  // yunshu
+    &[271],  // shazi
+    &[1610],  // zhongliang
+    &[890],  // hunli
+    &[215],  // diluo
+    &[190],  // guojun
+    &[1102],  // lengjing
+    &[4262],  // gaishan
+    &[358],  // shanchu
+    &[2305],  // zhaopian
+    &[16124],  // lvyou
+    &[1220],  // huilv
+    &[4546],  // huxiang
+    &[12],  // menglong
+    &[2451],  // zazhi
+    &[2015],  // xingzhuang
+    &[45604],  // gongsi
+    &[2681],  // jinian
+    &[2938],  // yanyuan
+    &[1810],  // mianfei
+    &[29383],  // butong
+    &[4064],  // duishou
+    &[1509],  // wanmei
+    &[866],  // yangshi
+    &[1553],  // ranshao
+    &[1044],  // shishang
+    &[2742],  // jiejie
+    &[27526],  // huodong
+    &[3654],  // pei
+    &[9767, 1029],  // lianxi
+    &[6491],  // zhua
+    &[75979, 61904, 31874, 8766, 8475, 5826, 5346],  // xiang
+    &[1276],  // cizhi
+    &[8526],  // rengran
+    &[1460],  // cushi
+    &[2765],  // kecheng
+    &[2763],  // zanshi
+    &[1574],  // jiyi
+    &[57483, 8414],  // gao
+    &[16128],  // huode
+    &[120],  // erhuan
+    &[3283],  // kexi
+    &[1266],  // buran
+    &[6832],  // qihou
+    &[50331, 15447, 9662],  // zuo
+    &[1444],  // linghuo
+    &[5143],  // yudao
+    &[2967],  // jiehun
+    &[1920],  // guangbo
+    &[1631],  // xihan
+    &[1832],  // jinkuai
+    &[98],  // caiyi
+    &[34387],  // shijie
+    &[804],  // shuma
+    &[2999],  // biaoda
+    &[3124],  // fangfu
+    &[12435],  // fenbu
+    &[32770],  // jueding
+    &[50437],  // zou
+    &[1479],  // hangban
+    &[21691],  // bianji
+    &[355],  // luyin
+    &[337],  // heying
+    &[32],  // cheku
+    &[1374],  // benling
+    &[2065],  // weirao
+    &[19],  // jiafang
+    &[2691],  // shenfen
+    &[3959],  // heshang
+    &[9875],  // jihui
+    &[4492],  // bijing
+    &[11094],  // zhuanjia
+    &[2548],  // chabuduo
+    &[163],  // minju
+    &[2928],  // guanggao
+    &[33288, 5441],  // shijian
+    &[1],  // biergaici
+    &[1651],  // shouxu
+    &[886],  // jiaju
+    &[8161],  // tongchang
+    &[35305],  // bi
+    &[6612],  // meitian
+    &[346],  // qingqu
+    &[75],  // hongjiu
+    &[14132],  // bie
+    &[12710, 7559, 2933, 2513],  // hua
+    &[2464, 1943],  // e
+    &[42],  // huannanyugong
+    &[88],  // zhijiao
+    &[1849, 101],  // tiankong
+    &[3304],  // daoli
+    &[3858],  // dayue
+    &[933],  // chaxun
+    &[4432],  // chongman
+    &[888],  // yinliao
+    &[10515, 8680, 6120, 4822, 4411, 1557],  // jie
+    &[148],  // jige
+    &[4800],  // chengguo
+    &[917],  // sushe
+    &[1286],  // ganzao
+    &[3579],  // jiaohuan
+    &[76586],  // yong
+    &[1092],  // tongqing
+    &[2757],  // faming
+    &[3307],  // xiyin
+    &[3894],  // limi
+    &[194],  // huayi
+    &[12],  // donghailongwang
+    &[5033],  // taidu
+    &[16649, 3311, 632],  // yang
+    &[1245],  // peifu
+    &[1770],  // qingjing
+    &[14581, 7829],  // quanli
+    &[698],  // panwang
+    &[19177],  // dajia
+    &[5782],  // haoxiang
+    &[18279, 6944, 3998, 1487, 928],  // dong
+    &[97817, 29996, 2561],  // guo
+    &[9926],  // xiaoxi
+    &[3085],  // ditu
+    &[61348],  // que
+    &[416],  // zhuanzhu
+    &[12197, 7068],  // guan
+    &[93868],  // bing
+    &[488],  // luoshanji
+    &[7737],  // yewu
+    &[6318],  // qiangdiao
+    &[51],  // baifenzhi
+    &[312],  // xiaweiyi
+    &[647],  // xinxilan
+    &[4601],  // ruanjian
+    &[10445],  // yanzhong
+    &[2262],  // daiti
+    &[1976],  // shifu
+    &[3719],  // meng
+    &[2597],  // cankao
+    &[527],  // guaiwu
+    &[24144],  // yingxiang
+    &[1],  // ,
+    &[49],  // duche
+    &[8444],  // mou
+    &[451],  // chuanzhen
+    &[23395, 7773, 6662],  // duan
+    &[5810, 2604, 1286],  // dun
+    &[16633],  // huozhe
+    &[487],  // kaixin
+    &[117],  // shadingyu
+    &[253],  // aixi
+    &[52],  // shicha
+    &[2189],  // jianmian
+    &[673],  // pijiu
+    &[549],  // houzi
+    &[1283],  // shiye
+    &[1439],  // jiaocai
+    &[9138],  // zhaokai
+    &[471],  // beishang
+    &[2197],  // yiwai
+    &[18435],  // yundong
+    &[823],  // naixin
+    &[847],  // ningke
+    &[809],  // yangcheng
+    &[5593],  // fumu
+    &[8594],  // kuoda
+    &[2777],  // qici
+    &[44],  // zhaoliang
+    &[24052],  // baokuo
+    &[8891],  // qun
+    &[3241],  // daode
+    &[360],  // mifeng
+    &[1521],  // fayan
+    &[1438],  // pingan
+    &[9907],  // duli
+    &[7599],  // kunnan
+    &[232],  // haixiu
+    &[296],  // fachou
+    &[436],  // dayin
+    &[443],  // qinai
+    &[24866],  // zhengzhi
+    &[748],  // yanjiang
+    &[3037],  // rao
+    &[530],  // jianfei
+    &[645],  // rexin
+    &[8518, 2218],  // huo
+    &[11755],  // re
+    &[29951, 4813, 1852],  // sheng
+    &[10614],  // suizhe
+    &[616],  // benpao
+    &[1827],  // xinshang
+    &[9881],  // gang
+    &[4138],  // jizhi
+    &[449],  // xigua
+    &[1951],  // queshao
+    &[23452],  // zhengfu
+    &[70],  // shoubi
+    &[181],  // daizi
+    &[10638],  // chenggong
+    &[900],  // yangrou
+    &[2443],  // siren
+    &[39],  // guomao
+    &[1],  // !
+    &[332],  // huochai
+    &[266],  // tiaopi
+    &[12193],  // caiyong
+    &[613],  // linju
+    &[11428],  // shoudao
+    &[660],  // zuowen
+    &[5956, 2300],  // yin
+    &[1678],  // weixiu
+    &[10542],  // zhongyu
+    &[1235],  // kesou
+    &[1812],  // zongcai
+    &[338],  // bishi
+    &[689],  // fakuan
+    &[23961],  // jiaoyu
+    &[23539],  // shan
+    &[4089],  // xishou
+    &[3096],  // toufa
+    &[30311],  // yiban
+    &[4150],  // weida
+    &[1979],  // chusheng
+    &[62],  // qingyuan
+    &[642],  // shushi
+    &[196],  // chengba
+    &[9248],  // shouxian
+    &[1232],  // yinshua
+    &[1689],  // duanlian
+    &[133],  // zhengteng
+    &[324],  // pinyin
+    &[604],  // ganmao
+    &[428],  // xili
+    &[9066],  // xingdong
+    &[2216],  // sikao
+    &[6715],  // xinli
+    &[754],  // zuobian
+    &[123],  // wanzhang
+    &[321],  // baoshe
+    &[991],  // bangmang
+    &[912],  // hanzi
+    &[2265],  // naodai
+    &[8250],  // zonghe
+    &[36089],  // meiguo
+    &[2160],  // chuangban
+    &[22455],  // mianji
+    &[1144],  // yueqi
+    &[2361],  // qianwan
+    &[870],  // xingrong
+    &[3520],  // tongku
+    &[627],  // kanwang
+    &[41],  // lingqian
+    &[6672],  // daxing
+    &[7151],  // youxiao
+    &[444],  // qianzheng
+    &[693],  // shengzi
+    &[1346],  // shuoshi
+    &[1451],  // dan
+    &[8297],  // chongxin
+    &[74],  // ershou
+    &[5993],  // shuxue
+    &[1360],  // mingshengguji
+    &[544],  // daoshihou
+    &[25601],  // xuduo
+    &[1151],  // xiazai
+    &[1316],  // zixin
+    &[4833],  // dianshi
+    &[740],  // huopo
+    &[1748],  // chuji
+    &[6871],  // shuyu
+    &[2545],  // jide
+    &[1194],  // mengxiang
+    &[183],  // genzhi
+    &[1682],  // fendou
+    &[2878, 1951],  // lei
+    &[9250],  // po
+    &[3515],  // buxing
+    &[2277],  // reng
+    &[163],  // duocang
+    &[695],  // jiesheng
+    &[6418],  // zhizao
+    &[1],  // <
+    &[1664],  // youle
+    &[13626],  // yishu
+    &[1512],  // weifan
+    &[5850],  // peiyang
+    &[541],  // jiujiu
+    &[994],  // pochan
+    &[1098],  // chufei
+    &[4631, 293],  // wenzi
+    &[6017],  // biao
+    &[1058],  // yidianr
+    &[10022],  // kaifa
+    &[2388],  // yingjie
+    &[374],  // wanggong
+    &[856],  // guoshi
+    &[7272],  // dangdi
+    &[4396],  // tongxue
+    &[34296, 4745, 2502],  // wen
+    &[670],  // fuzhi
+    &[18877],  // dongxi
+    &[321],  // fuwuyuan
+    &[23315, 10566],  // lian
+    &[1494],  // wanyi
+    &[6493, 2439],  // bang
+    &[2709],  // wuli
+    &[2413],  // jielun
+    &[630],  // fangai
+    &[162],  // baozi
+    &[7604],  // xuanbu
+    &[40281, 23973, 2090],  // zhang
+    &[22635, 9565, 1848, 595],  // ling
+    &[24899, 16964, 4456],  // tai
+    &[511],  // guanghua
+    &[1095],  // mohu
+    &[2353, 1507],  // piao
+    &[1],  // ?
+    &[1028],  // shengri
+    &[7443],  // wangwang
+    &[2736, 1495, 1159, 595],  // lan
+    &[9603, 2660],  // man
+    &[7060],  // canyu
+    &[33],  // wenhuo
+    &[3404],  // jinkou
+    &[1943],  // huati
+    &[3548],  // conglai
+    &[2256],  // xunwen
+    &[257],  // qinguo
+    &[1211],  // zaoshang
+    &[3212],  // zhenshi
+    &[1806],  // meishu
+    &[315],  // dangao
+    &[154],  // shunchang
+    &[28623],  // ne
+    &[19195],  // dangshi
+    &[1798],  // zijue
+    &[5937],  // miandui
+    &[4373],  // bangongshi
+    &[3344],  // dongzuo
+    &[52],  // xinxiangan
+    &[1510],  // shuiguo
+    &[25668, 17557, 10117],  // jin
+    &[16199],  // zhunbei
+    &[16195],  // zengjia
+    &[1223],  // jilv
+    &[153],  // kanbuqi
+    &[182],  // xiangqi
+    &[1444, 1087],  // xiaohua
+    &[2300],  // jiedai
+    &[10928],  // zhichi
+    &[1111],  // huanjie
+    &[4414],  // xiaojie
+    &[443],  // zhoudao
+    &[3448],  // shike
+    &[740],  // menkan
+    &[463],  // dajiaodao
+    &[85847, 24967, 16316],  // chu
+    &[76],  // hanxian
+    &[4788, 3742],  // fan
+    &[547],  // wuhui
+    &[24035, 1417],  // shiyong
+    &[11762],  // jiage
+    &[5076],  // qianglie
+    &[625],  // caoxin
+    &[1089],  // qingchun
+    &[193],  // siheyuan
+    &[13932],  // houlai
+    &[382],  // shangdang
+    &[93, 1],  // zhigan
+    &[3214],  // zhide
+    &[241],  // zhedie
+    &[1546],  // miaoxie
+    &[2663],  // dizhen
+    &[5973],  // bili
+    &[90],  // shibangongbei
+    &[11359, 7819],  // chuan
+    &[3],  // dalanqiu
+    &[1146],  // jianqiang
+    &[43],  // liguang
+    &[24568],  // guiding
+    &[515],  // biji
+    &[465],  // yinyuehui
+    &[95],  // niuzaiku
+    &[90],  // jiucan
+    &[92091, 23572, 4046, 2873],  // hui
+    &[1],  // shuowenjiezi
+    &[8018],  // xingzhi
+    &[182],  // zhipiao
+    &[5456],  // maodun
+    &[2938],  // baohan
+    &[3139],  // yunnan
+    &[1],  // jianwei
+    &[326],  // jiuba
+    &[3681],  // shibing
+    &[12],  // chongdianqi
+    &[2108],  // shenmi
+    &[3086],  // huangjin
+    &[4001, 145],  // chuxi
+    &[3272],  // bowuguan
+    &[9440],  // mudi
+    &[2141],  // erduo
+    &[161],  // aoye
+    &[2149],  // qingqiu
+    &[351],  // changge
+    &[5193, 2471],  // ying
+    &[2877],  // guanzhong
+    &[310],  // putonghua
+    &[18396],  // muqian
+    &[1037],  // yueliang
+    &[320],  // fenglang
+    &[4168],  // gongfu
+    &[8904],  // shengli
+    &[26775],  // gongye
+    &[4919],  // nianqing
+    &[744],  // conglin
+    &[1],  // dianqiu
+    &[4000],  // daikuan
+    &[924],  // guangdongsheng
+    &[8218],  // sudu
+    &[15161, 13166],  // fang
+    &[348],  // guoqingjie
+    &[17372],  // buguo
+    &[7202],  // caichan
+    &[297],  // nianyu
+    &[3141],  // tanpan
+    &[181],  // fuyin
+    &[4478],  // gui
+    &[6846],  // yexu
+    &[5044],  // taiyang
+    &[471],  // fudao
+    &[1436],  // lixi
+    &[41022, 11537, 5051, 4733, 2412, 2308],  // jia
+    &[146],  // yibanlaishuo
+    &[9541],  // yuanze
+    &[117],  // pashan
+    &[1290],  // gancui
+    &[2570],  // chuzhong
+    &[9464],  // renlei
+    &[1],  // haolinshuo
+    &[1],  // qilang
+    &[794],  // jiaoao
+    &[2516],  // shouzhi
+    &[1113],  // liwu
+    &[1078],  // doufu
+    &[2572],  // goutong
+    &[2207],  // jiaoxun
+    &[16799],  // tigong
+    &[597],  // gongyu
+    &[5727],  // nuan
+    &[9256],  // ouzhou
+    &[596],  // duoyu
+    &[9327],  // jianyi
+    &[20859],  // zhuxi
+    &[7253],  // jingchang
+    &[2737],  // pailie
+    &[2068],  // congming
+    &[3000],  // laopo
+    &[7998],  // heping
+    &[16377, 1797],  // shanghai
+    &[1265],  // shunxu
+    &[4048],  // zhengqu
+    &[1340],  // jiaru
+    &[309],  // pingzi
+    &[377],  // qinfen
+    &[4615],  // lun
+    &[663],  // gu
+    &[10895, 931],  // guang
+    &[5],  // hupenghuanyou
+    &[19704, 16974],  // wang
+    &[1214],  // yingye
+    &[1617],  // daoyu
+    &[416],  // lukou
+    &[175],  // pingwei
+    &[1],  // liuchen
+    &[3386],  // dizhi
+    &[686],  // jiaban
+    &[1],  // dannierkaneiman
+    &[1650],  // liuchuan
+    &[16715],  // baogao
+    &[4575],  // budan
+    &[14079],  // chengli
+    &[596],  // xiuli
+    &[1608],  // daiyu
+    &[1409],  // duihuan
+    &[7419],  // jiaoshou
+    &[1],  // liuchiping
+    &[2064],  // benzhi
+    &[2657],  // tianqi
+    &[3242],  // gankuai
+    &[1266],  // fangsong
+    &[5622],  // zhiyou
+    &[1305],  // dadianhua
+    &[6371],  // cengjing
+    &[2271],  // guyi
+    &[1433],  // duihua
+    &[3247],  // jiaodu
+    &[30245, 2249],  // tongshi
+    &[1361],  // ouer
+    &[20602],  // xitong
+    &[5721],  // guanjian
+    &[20524],  // minzu
+    &[2085],  // lianmang
+    &[4209],  // fouze
+    &[150],  // weishengjian
+    &[19],  // tanhua
+    &[39],  // waiwainiuniu
+    &[1072],  // peichang
+    &[134479],  // dei
+    &[113],  // masazhusaizhou
+    &[1229],  // yanchang
+    &[377],  // shun
+    &[6097, 626],  // guai
+    &[1829],  // chongfu
+    &[1378],  // shenhua
+    &[2232],  // nengyuan
+    &[3838],  // yidong
+    &[479],  // pixie
+    &[328841],  // wo
+    &[1],  // yangxiong
+    &[156581, 8404, 3558, 3532, 2706],  // yao
+    &[242],  // huzhao
+    &[68],  // shangxinyuemu
+    &[520],  // jiaqian
+    &[170],  // dawei
+    &[79520],  // guojia
+    &[3194],  // fangzi
+    &[2105],  // jieri
+    &[10415],  // jieshou
+    &[1459],  // fouding
+    &[439],  // congmang
+    &[1683],  // xiaomai
+    &[4044, 3368],  // shiwu
+    &[524],  // dianti
+    &[1559],  // changcheng
+    &[2873],  // ciji
+    &[17725],  // diyi
+    &[2461],  // youjiu
+    &[650],  // shulian
+    &[1],  // tianjinweishi
+    &[423],  // xiaoxiao
+    &[6379],  // cuowu
+    &[34145],  // xianzai
+    &[4817],  // wangzhan
+    &[10],  // lumiaier
+    &[2453],  // jing
+    &[45],  // jiezhang
+    &[329],  // xinyongka
+    &[2532],  // xiangxi
+    &[3048],  // hefa
+    &[28466, 25796, 8747, 8235, 5648, 1821],  // shou
+    &[813],  // haiguan
+    &[8329],  // luan
+    &[929],  // zhexuejia
+    &[191],  // cunhuo
+    &[2618],  // huxi
+    &[1761],  // juesai
+    &[529],  // chitang
+    &[1473],  // kefu
+    &[1041],  // baogui
+    &[992],  // xuewen
+    &[1114],  // xiuxian
+    &[1375],  // yongtu
+    &[18058],  // duiyu
+    &[282],  // chikui
+    &[2600],  // jinji
+    &[777],  // dasha
+    &[4884],  // zan
+    &[2313],  // chenmo
+    &[6728],  // wenzhang
+    &[1199],  // anjing
+    &[107],  // zhizhao
+    &[4621, 2067],  // zu
+    &[6687],  // chuangzao
+    &[1],  // panghuang
+    &[1982],  // zunshou
+    &[6674],  // cujin
+    &[1231],  // yimin
+    &[3791],  // xiaozhang
+    &[826],  // liaobuqi
+    &[5299, 1371, 1013],  // pi
+    &[364],  // genji
+    &[1436],  // yonggan
+    &[6976],  // tiyu
+    &[393],  // shihuiyan
+    &[10215],  // jianchi
+    &[1068],  // zhaoji
+    &[395],  // zanmai
+    &[22078],  // zuoyong
+    &[228],  // duokui
+    &[1480],  // zhuantou
+    &[16318, 2641],  // fei
+    &[84],  // maokong
+    &[6937],  // liji
+    &[1],  // zhaifeng
+    &[2791],  // xiangshou
+    &[140],  // shenshangxiansu
+    &[5770],  // wanshang
+    &[790],  // chaoji
+    &[205341, 21994, 9271],  // dao
+    &[11660],  // diwei
+    &[24685],  // dian
+    &[10529, 7578, 2394],  // mai
+    &[1],  // sunyue
+    &[6584],  // wenming
+    &[3427],  // diannao
+    &[883634],  // le
+    &[1766],  // houguo
+    &[273122, 9653, 8003, 7645, 6655, 6268],  // jiu
+    &[6284],  // zhuzhang
+    &[4789, 1603],  // shouji
+    &[9540],  // zhenzheng
+    &[2968],  // renminbi
+    &[596],  // woshi
+    &[1337],  // pingchang
+    &[436],  // aihu
+    &[26922, 1620],  // zuzhi
+    &[2668],  // zhunque
+    &[2476],  // zicong
+    &[29391, 13042, 4207, 3174],  // wan
+    &[483],  // xiaojing
+    &[11169],  // zuoyou
+    &[10457],  // duoshao
+    &[705],  // gaodang
+    &[2443],  // xiaolv
+    &[1226],  // waidi
+    &[8176],  // shebei
+    &[7421, 534, 56],  // jieshi
+    &[34],  // yudafu
+    &[2601],  // tixing
+    &[4088, 3801, 1008],  // gou
+    &[549],  // chibang
+    &[327],  // zhufu
+    &[436],  // zhuanxin
+    &[2022],  // renao
+    &[9783],  // xihuan
+    &[459],  // jingshang
+    &[1810],  // dengji
+    &[922],  // zhongxun
+    &[14998],  // turan
+    &[6700],  // nver
+    &[3953],  // wendu
+    &[1953],  // shitou
+    &[4278],  // shoudu
+    &[157058, 6483],  // huan
+    &[1821],  // yibeizi
+    &[13745],  // fuze
+    &[3858],  // changjiangdaqiao
+    &[3877],  // chuanbo
+    &[7015],  // teshu
+    &[423765, 150749, 78203],  // you
+    &[4676],  // peixun
+    &[1714],  // zhenduan
+    &[818],  // mosheng
+    &[1678],  // yilun
+    &[12744],  // geren
+    &[1073],  // tuixiu
+    &[204],  // fuzhong
+    &[2156],  // anan
+    &[930],  // duguo
+    &[7600],  // huifu
+    &[284],  // moter
+    &[6462, 3762],  // jishi
+    &[336],  // tiaowu
+    &[16537],  // fenbie
+    &[16811],  // huanjing
+    &[8230],  // dongwu
+    &[9232],  // jizhong
+    &[6555],  // zijin
+    &[23969],  // zhongxin
+    &[1358],  // gaikuo
+    &[2135],  // dique
+    &[847],  // chuzi
+    &[4911],  // xingxiang
+    &[606],  // zhongjie
+    &[355],  // gaizheng
+    &[707],  // chuanghu
+    &[1102],  // xianhua
+    &[3323],  // zhijin
+    &[360331, 13579, 4827],  // bu
+    &[31739],  // lingdao
+    &[1188],  // nanmian
+    &[1277, 1181],  // zhai
+    &[7684],  // yinhang
+    &[1009],  // elie
+    &[1249],  // fandian
+    &[1425],  // kun
+    &[3624],  // biaozhi
+    &[646],  // aixin
+    &[1952],  // jihe
+    &[654],  // zhoumo
+    &[721],  // zuihao
+    &[747],  // ditan
+    &[2284],  // xiong
+    &[136],  // tiandi
+    &[2603],  // ganjin
+    &[8581, 2810],  // lin
+    &[1643],  // yuanwang
+    &[339],  // dianxin
+    &[111],  // kele
+    &[343],  // tuzi
+    &[1759],  // chai
+    &[2382],  // anzhuang
+    &[4727],  // buguan
+    &[33423],  // lao
+    &[1029],  // jiangjin
+    &[5266, 2761, 1496],  // jingli
+    &[6232],  // liru
+    &[5675],  // zhui
+    &[1707],  // shanyu
+    &[8590],  // tiaozheng
+    &[2186],  // jiemu
+    &[1710],  // ganxie
+    &[1388],  // shangxin
+    &[8340],  // bushao
+    &[8066],  // dili
+    &[6872, 5080],  // xianshi
+    &[129],  // fangdong
+    &[2799],  // haochi
+    &[1810],  // bozi
+    &[516],  // limao
+    &[3827],  // bimian
+    &[431],  // didao
+    &[5355],  // zuijin
+    &[70958],  // keyi
+    &[37763, 2657, 1076],  // zheng
+    &[1234],  // shiwang
+    &[3052],  // quxiao
+    &[3893],  // guji
+    &[9342],  // zhuming
+    &[9200],  // pengyou
+    &[4676],  // chixu
+    &[610],  // shuofu
+    &[160],  // youzha
+    &[727915, 55507],  // zai
+    &[35084, 1253],  // wai
+    &[3173],  // guanjun
+    &[17552, 2114],  // sha
+    &[20572],  // zucheng
+    &[1391],  // nvhai
+    &[11296],  // hei
+    &[55563],  // wenti
+    &[4324],  // panduan
+    &[3362],  // taitai
+    &[63],  // kaoya
+    &[27900],  // kai
+    &[14823],  // zhanzheng
+    &[17464],  // suoyou
+    &[391],  // bingxiang
+    &[9580],  // ya
+    &[3206],  // xueshu
+    &[474],  // bianlun
+    &[1144],  // shufu
+    &[22128, 7240, 2793],  // xing
+    &[1780],  // lieche
+    &[14635],  // renhe
+    &[1679],  // danchun
+    &[5681],  // zhouwei
+    &[735],  // yufa
+    &[697],  // danwu
+    &[1052],  // duibuqi
+    &[1795],  // wuzi
+    &[7815],  // kaoshi
+    &[5784, 1887],  // nong
+    &[6167],  // zhengque
+    &[3460],  // gongchang
+    &[3758],  // fengge
+    &[530],  // qichuang
+    &[284],  // caochang
+    &[2948],  // shunli
+    &[840],  // zunjing
+    &[5737],  // gaoji
+    &[1139],  // zhuguan
+    &[9081],  // kuan
+    &[8350],  // raner
+    &[401],  // buyaojin
+    &[14200],  // jiegou
+    &[40],  // laojia
+    &[1920],  // duidai
+    &[5485],  // funv
+    &[520],  // tianzhen
+    &[38139],  // kaishi
+    &[3047, 1590],  // qushi
+    &[386],  // chengken
+    &[3358],  // zuojia
+    &[15468],  // neirong
+    &[1400],  // kafei
+    &[682],  // xianmu
+    &[1103],  // kongzi
+    &[1221],  // xinku
+    &[1110],  // ludi
+    &[17290],  // tiaojian
+    &[66367],  // gongzuo
+    &[366],  // shimian
+    &[6935],  // jiandan
+    &[1431],  // zhuce
+    &[187],  // xianglian
+    &[4918],  // dianying
+    &[3885],  // zhaopin
+    &[1],  // >
+    &[374],  // jiaohua
+    &[2525],  // huaiyi
+    &[7456],  // jingying
+    &[3331],  // dagai
+    &[32105],  // guanxi
+    &[1888, 278],  // peng
+    &[1700],  // shuru
+    &[1430],  // guofen
+    &[24052],  // fasheng
+    &[4781, 4300, 1494],  // tao
+    &[258],  // caihong
+    &[685],  // wangzi
+    &[92543, 44621],  // hao
+    &[3404],  // haipa
+    &[3466, 1232],  // jilu
+    &[3473],  // guize
+    &[523],  // bofang
+    &[1008],  // shunbian
+    &[504],  // guaibude
+    &[667],  // canting
+    &[8276],  // guangfan
+    &[1029],  // xianjin
+    &[1736],  // duzi
+    &[2213],  // en
+    &[6748, 4609],  // gongyuan
+    &[1417],  // jinan
+    &[942],  // chijing
+    &[797],  // juhui
+    &[270],  // baoqian
+    &[10022],  // kanjian
+    &[1104],  // qinqie
+    &[2673],  // zhuti
+    &[6712, 899],  // tan
+    &[1289],  // chunjie
+    &[3201],  // jijie
+    &[1117],  // huochezhan
+    &[11598],  // laodong
+    &[6940],  // shengzhang
+    &[185],  // niujindaxue
+    &[6117],  // fuza
+    &[3718],  // fangbian
+    &[668],  // shebude
+    &[8056],  // gongneng
+    &[43401],  // shehui
+    &[521],  // zihao
+    &[22828],  // canjia
+    &[2256],  // mulu
+    &[721],  // haoqi
+    &[4255],  // rensheng
+    &[1029],  // wenrou
+    &[8901, 1338],  // renshi
+    &[95],  // shaozi
+    &[4049],  // waimian
+    &[10799],  // shidai
+    &[2402],  // shoushang
+    &[8499],  // fujin
+    &[3181],  // fangqi
+    &[236],  // bujiande
+    &[287],  // tanshuai
+    &[17020],  // xuexiao
+    &[6101],  // meiti
+    &[122],  // yinjia
+    &[8676],  // rongyi
+    &[911],  // jiashi
+    &[34677, 15314, 10196, 8320, 4400, 2577, 2260],  // ji
+    &[9846],  // fuqin
+    &[9364],  // erzi
+    &[102],  // panjiayuan
+    &[1279],  // sheying
+    &[3154],  // dasuan
+    &[18993, 10689, 5516, 2922],  // shu
+    &[154],  // qingzhuangnian
+    &[21517],  // zhidu
+    &[851],  // gutou
+    &[370],  // dazhaohu
+    &[179],  // chizi
+    &[7018],  // jinguan
+    &[206],  // jiangyou
+    &[10812],  // zuozhan
+    &[19018],  // gaige
+    &[6730],  // qunian
+    &[32777],  // daibiao
+    &[58684, 7675, 4437, 1181],  // qi
+    &[20380, 5847],  // zi
+    &[29609, 3108, 2268, 1878],  // zhu
+    &[1781],  // weihai
+    &[5015],  // xianran
+    &[1218],  // qidai
+    &[4142],  // wanzheng
+    &[500],  // changshi
+    &[10875],  // mingbai
+    &[1932],  // zhengli
+    &[5432],  // chengzhang
+    &[2059],  // mafan
+    &[9544],  // sihu
+    &[17947],  // ci
+    &[988],  // guwu
+    &[16840],  // fa
+    &[422],  // motuoche
+    &[8252],  // bieren
+    &[7376],  // yiyuan
+    &[704],  // rukou
+    &[1],  // :
+    &[1139],  // jianku
+    &[24826],  // faxian
+    &[8462],  // jiehe
+    &[2700],  // jidong
+    &[1839],  // gandong
+    &[21386],  // yihou
+    &[8092],  // tese
+    &[672],  // youbian
+    &[973],  // gaobie
+    &[4909],  // guoran
+    &[838],  // cidian
+    &[3309],  // guancha
+    &[17482, 4284],  // xue
+    &[8796],  // yingyong
+    &[2244, 1947],  // nar
+    &[1337],  // shangban
+    &[1148],  // pilao
+    &[2737, 1180],  // gun
+    &[5027],  // jiaoliu
+    &[4330, 403],  // gezi
+    &[25139, 23194],  // zhan
+    &[2196],  // chayi
+    &[2706],  // shangliang
+    &[443],  // huichen
+    &[1693],  // mingan
+    &[3870],  // heli
+    &[660],  // zonggong
+    &[521],  // guomin
+    &[2191],  // tiqian
+    &[4307],  // peihe
+    &[4114],  // yingyang
+    &[4224],  // jubei
+    &[1275],  // shencai
+    &[4487],  // shibai
+    &[11719, 2164],  // sui
+    &[3701, 1152],  // lv
+    &[1627],  // dating
+    &[2165],  // jimang
+    &[441],  // yingjun
+    &[21008, 3348, 1925],  // an
+    &[94],  // hanjia
+    &[98],  // tengxun
+    &[1338],  // xinzang
+    &[433],  // shanzi
+    &[1],  // meixi
+    &[6089],  // zongshi
+    &[4819],  // gongju
+    &[19355],  // zuihou
+    &[4511],  // duixiang
+    &[1],  // \
+    &[3088, 1166],  // shangwu
+    &[14654],  // weiyu
+    &[1650],  // yansu
+    &[1628],  // huoyue
+    &[35966],  // chengwei
+    &[3988],  // lixiang
+    &[6425],  // zhengming
+    &[1355],  // jidan
+    &[3295],  // xiangsi
+    &[808],  // jiezhi
+    &[2830],  // qiwen
+    &[1334],  // pingguo
+    &[869],  // reai
+    &[7052],  // chongfen
+    &[210],  // shujia
+    &[7775],  // conger
+    &[347],  // chaojia
+    &[39823],  // men
+    &[4308],  // houmian
+    &[42181, 15665, 2769, 1542, 1003],  // wu
+    &[20025],  // daxue
+    &[3547],  // pingshi
+    &[35698],  // yinwei
+    &[5372],  // tixian
+    &[4919],  // hexin
+    &[755],  // zaogao
+    &[5641],  // shenbian
+    &[113],  // shuaya
+    &[756],  // poqie
+    &[491],  // make
+    &[250],  // meide
+    &[2606],  // aiqing
+    &[19238],  // biaoshi
+    &[4745],  // xuanchuan
+    &[1455],  // siji
+    &[5812],  // mingque
+    &[1019],  // jiawu
+    &[340],  // qingwa
+    &[3],  // lianpo
+    &[2778, 1827, 1462],  // chou
+    &[4448],  // weiyi
+    &[289],  // guanglin
+    &[5389],  // jiran
+    &[915],  // baoguo
+    &[752],  // cihui
+    &[8402],  // chong
+    &[2763],  // pifu
+    &[5511],  // kongjian
+    &[348],  // dandiao
+    &[1559],  // piqi
+    &[7420],  // yinsu
+    &[1646],  // gongping
+    &[776],  // langan
+    &[481],  // hudie
+    &[134],  // shifei
+    &[730],  // zhiyuanzhe
+    &[1309],  // jile
+    &[560],  // chouti
+    &[4973],  // fenfen
+    &[1],  // bachengr
+    &[5953],  // gushi
+    &[5229],  // guanzhu
+    &[39590],  // diqu
+    &[6255],  // maoyi
+    &[23294],  // yinci
+    &[9557],  // wufa
+    &[5456],  // ertong
+    &[1371],  // touming
+    &[77],  // nenggan
+    &[301],  // shimao
+    &[2096],  // jingdian
+    &[683],  // chengxin
+    &[336],  // pingpangqiu
+    &[9428],  // zaocheng
+    &[816],  // keting
+    &[795],  // xiaoji
+    &[4432],  // jiangdi
+    &[5289],  // tuchu
+    &[8742],  // rencai
+    &[802],  // guangxian
+    &[2620],  // ganshou
+    &[412],  // xinfeng
+    &[8332],  // kaifang
+    &[329],  // yuehui
+    &[668],  // jiaoqu
+    &[4703],  // renzhen
+    &[1038],  // napa
+    &[44],  // maikefeng
+    &[1175],  // duibi
+    &[20465],  // zengzhang
+    &[1],  // .
+    &[2610],  // gangtie
+    &[25084, 288],  // chengshi
+    &[609],  // gewu
+    &[3107],  // daoda
+    &[2274],  // shoushi
+    &[2923],  // gupiao
+    &[4833],  // biaomian
+    &[415],  // wangqiu
+    &[3571],  // riqi
+    &[2921],  // mishu
+    &[31],  // libaitian
+    &[1134],  // langfei
+    &[307],  // nuanhuo
+    &[397],  // lingshi
+    &[1],  // guangheju
+    &[856],  // haili
+    &[2001],  // mingpai
+    &[1499],  // youyi
+    &[2859],  // diu
+    &[280],  // wenxuejia
+    &[1849],  // huoxu
+    &[3784],  // zher
+    &[9364],  // xiangtong
+    &[2130],  // mingxing
+    &[237],  // meiguanxi
+    &[20479],  // jiben
+    &[3844],  // guding
+    &[174],  // zhuangao
+    &[993],  // zhendong
+    &[324],  // shoubiao
+    &[8351],  // liyi
+    &[4498],  // chedi
+    &[4839],  // danxin
+    &[6044],  // yali
+    &[8163],  // cuoshi
+    &[5464],  // jiancha
+    &[1045],  // huanbao
+    &[9932],  // pingjun
+    &[16428],  // shifen
+    &[1606],  // wennuan
+    &[871],  // sunzi
+    &[8201, 4674, 3306, 2869],  // ban
+    &[980],  // haoma
+    &[3833],  // mimi
+    &[7797, 529],  // ding
+    &[1],  // <shuowenjiezi>
+    &[1310],  // congqian
+    &[27191],  // guanli
+    &[8354],  // wuzhi
+    &[12865],  // dangran
+    &[8326],  // zhou
+    &[510],  // pianmian
+    &[1759],  // chaju
+    &[9574],  // jieduan
+    &[2056],  // suibian
+    &[1],  // lichadexiersi
+    &[771],  // shandian
+    &[1032],  // tihui
+    &[2193],  // shamo
+    &[26927],  // shichang
+    &[507],  // gebi
+    &[4979],  // yuedu
+    &[6133],  // yiwei
+    &[14468],  // jiejue
+    &[3117],  // mingyun
+    &[1607],  // lizi
+    &[3320, 657],  // nianji
+    &[1474],  // meihao
+    &[472],  // qingshi
+    &[682],  // cankui
+    &[12597],  // xiwang
+    &[342],  // xueqi
+    &[55],  // sitanfu
+    &[762],  // jimo
+    &[2079],  // yinxiang
+    &[14470],  // xiansheng
+    &[3105, 2682, 2365],  // fu
+    &[300],  // biaoyang
+    &[2330],  // xiangxiang
+    &[1756],  // daan
+    &[1038],  // huanxiang
+    &[7886],  // weizhi
+    &[671],  // binguan
+    &[159],  // dangxin
+    &[30181, 1190],  // youyu
+    &[2445],  // baoliu
+    &[3892],  // qingxu
+    &[484],  // tuichi
+    &[11527],  // mubiao
+    &[453],  // changtu
+    &[446],  // yumaoqiu
+    &[9959],  // jinnian
+    &[664],  // kuzi
+    &[68664],  // fazhan
+    &[118],  // haoke
+    &[2917],  // youxi
+    &[16552],  // junshi
+    &[6904, 3773],  // zhuang
+    &[15213, 10520],  // renwu
+    &[93],  // ganbei
+    &[2582],  // jinzhi
+    &[40281, 11435, 1295],  // chang
+    &[414],  // xizao
+    &[15301, 1601],  // shixian
+    &[1024],  // qifei
+    &[2405],  // juexin
+    &[9803],  // jingyan
+    &[3029],  // shenke
+    &[8085],  // wanshan
+    &[4233],  // xiangzheng
+    &[21100, 12010],  // shiji
+    &[11086],  // fenxi
+    &[3200],  // yihuier
+    &[1875],  // lunwen
+    &[612],  // meimao
+    &[8352],  // wangluo
+    &[1030],  // sa
+    &[2508],  // lia
+    &[2567],  // kejian
+    &[2306],  // mashang
+    &[339],  // fangzong
+    &[318825, 160541, 134479],  // de
+    &[6479],  // duifang
+    &[32393, 5413],  // gen
+    &[2518],  // buru
+    &[7161],  // benlai
+    &[10146],  // shuohua
+    &[258101],  // shang
+    &[1032],  // maozi
+    &[3048],  // niao
+    &[21973, 6749],  // kuai
+    &[1],  // <nahan>
+    &[6703],  // tuan
+    &[15084],  // yuanlai
+    &[3706, 1302],  // youdian
+    &[8028],  // fabiao
+    &[447],  // jiangzuo
+    &[24267],  // suiran
+    &[23944],  // yaoqiu
+    &[166],  // chuyuan
+    &[7853],  // zhujian
+    &[13853, 3865],  // zhuren
+    &[12128],  // huilai
+    &[9996],  // shifou
+    &[3348],  // jiechu
+    &[106],  // biaodian
+    &[5259],  // xiugai
+    &[34826],  // qiye
+    &[7785],  // chule
+    &[98740],  // women
+    &[19119],  // tebie
+    &[3],  // tangangqin
+    &[5359],  // mingzi
+    &[1531],  // buan
+    &[1174],  // qiumi
+    &[24213],  // falv
+    &[5871],  // like
+    &[288],  // fanxing
+    &[42207, 491],  // ben
+    &[1914],  // shuobuding
+    &[1612],  // qingzhu
+    &[3195],  // secai
+    &[1219],  // guangming
+    &[11774],  // liaojie
+    &[234587],  // ni
+    &[1],  // zhanxian
+    &[1],  // ouzhouhuanjingbaohushu
+    &[777],  // xiangchu
+    &[3372],  // chuanshuo
+    &[10239],  // lingwai
+    &[5235],  // jisuan
+    &[1026],  // jiashe
+    &[1928],  // kuo
+    &[560],  // liulei
+    &[398],  // xingxing
+    &[7114],  // yibian
+    &[772],  // sangzi
+    &[3091],  // fenxiang
+    &[14429],  // zao
+    &[3247],  // yunxu
+    &[290],  // qianxu
+    &[2998],  // dengdai
+    &[19495],  // chansheng
+    &[3373],  // boshi
+    &[5919],  // cao
+    &[1195, 676],  // hushi
+    &[3049],  // liuxing
+    &[117],  // zenmeban
+    &[6168, 3750, 1764],  // pian
+    &[1517],  // zongzhi
+    &[19871],  // ruhe
+    &[2899],  // duome
+    &[20538, 15718, 3570],  // zhong
+    &[2516],  // kuaile
+    &[457],  // daoqian
+    &[16797],  // fangshi
+    &[43011, 4307, 2547],  // liang
+    &[7036],  // congshi
+    &[614],  // youmo
+    &[14737],  // nin
+    &[15504, 7005, 2282, 1475],  // di
+    &[219817],  // shuo
+    &[5767],  // queshi
+    &[4242, 1366],  // chengren
+    &[5971],  // shiying
+    &[1],  // <feinimoshu>
+    &[9047],  // tedian
+    &[18596, 5406],  // yizhi
+    &[403],  // chouyan
+    &[9561],  // weishenme
+    &[5341],  // pubian
+    &[45776, 5934, 3742],  // ting
+    &[15627],  // wanquan
+    &[9953],  // chengdu
+    &[964],  // youyong
+    &[1399],  // fengkuang
+    &[87],  // shengwuzhong
+    &[10069],  // liliang
+    &[3130],  // tongzhi
+    &[489],  // wenhou
+    &[767],  // duanxin
+    &[5767],  // tezheng
+    &[1631],  // zhuangshi
+    &[6779],  // queding
+    &[5557],  // fada
+    &[482, 87, 35, 17],  // juzi
+    &[10209],  // gongren
+    &[5256],  // youxiu
+    &[32898],  // shengchan
+    &[1034],  // zixingche
+    &[85],  // lifadian
+    &[90933],  // ziji
+    &[395],  // zanmei
+    &[5923],  // liangshi
+    &[5584],  // shidang
+    &[386],  // dasao
+    &[3414],  // daochu
+    &[1089],  // qite
+    &[16543],  // bumen
+    &[2153],  // kouwei
+    &[24601, 471],  // guoji
+    &[29983, 19090, 2807, 1908, 912],  // si
+    &[5094],  // gongkai
+    &[1978],  // canguan
+    &[2340],  // bingdu
+    &[4342],  // zixun
+    &[13478],  // hezuo
+    &[7097],  // fangan
+    &[17453, 757],  // ziyuan
+    &[3241],  // huanghe
+    &[938],  // xiaochi
+    &[5052],  // yifu
+    &[3226],  // chunqiu
+    &[7829],  // xunlian
+    &[108294, 5257, 4156, 2831],  // xia
+    &[65],  // aidisheng
+    &[4119],  // chui
+    &[279],  // fukuan
+    &[1164],  // zongsuan
+    &[817],  // zaihai
+    &[3895],  // xiangfan
+    &[1688],  // tiyan
+    &[2291],  // youli
+    &[988],  // houhui
+    &[94],  // lifa
+    &[9],  // jinshiweikai
+    &[694],  // lunliu
+    &[2406],  // gugu
+    &[8446],  // shuoming
+    &[1192],  // shizi
+    &[2176],  // xiju
+    &[5860],  // zhengchang
+    &[103],  // weijin
+    &[232],  // toulan
+    &[6044, 5289, 5119, 2710, 1587],  // bao
+    &[20],  // zhaosanmusi
+    &[560],  // zhuhe
+    &[18324, 10196, 3202, 406],  // xi
+];
+
+// Choices in CIYU with a tone number after each syllable, in the same
+// order: 1-4, 5 for neutral tone, or 0 where neither the vocab files nor
+// the GB 2312 readings give the tone. Choices with more than one reading
+// list them all, joined by "|".
+pub static CIYU_TONED: &[&str] = &[
+    "ruo4",
+    "bei1guan1",
+    "shou1huo4",
+    "xiang1fang2",
+    "gui1mo2",
+    "yu4mi3",
+    "zhe2li3",
+    "lao3ying1",
+    "ri4ji4",
+    "xiang1jiao1",
+    "lin2shi2",
+    "sun3shi1",
+    "hu2tong2",
+    "zhi2jie1",
+    "xing4qu4",
+    "cheng2yu3",
+    "jiao1shui3",
+    "cai2	cai4	cai1	cai3",
+    "kua1zhang1",
+    "chou3lou4",
+    "wang4sheng4",
+    "qiao1qiao1",
+    "shuai1dao4",
+    "lao3lao3",
+    "zai4san1",
+    "dui4	dui1",
+    "xing4bie2",
+    "you1you1",
+    "hua1sheng1",
+    "qian2	qian2	qian1	qian3	qian4	qian1",
+    "shu4zi4	shu1zi3",
+    "tang1	tang2	tang3	tang4	tang4",
+    "fen1zhong1",
+    "cheng2shou4",
+    "lei4xing2",
+    "jue0se4",
+    "ju3ban4",
+    "hao3chu4",
+    "fan2rong2",
+    "jie1zhe0",
+    "cheng2zuo4",
+    "yi1dan4",
+    "chong3wu4",
+    "yu3qi4",
+    "fang4xin1",
+    "wei1bo2",
+    "hu2	hu2",
+    "ri4yong4pin3",
+    "shou4ming4",
+    "ban4shi4",
+    "lu3xun4",
+    "nong2ye4",
+    "di4qiu2",
+    "huai4",
+    "bian4	bian4	bian4",
+    "nian2	nian4",
+    "she4ru4",
+    "ju4li2",
+    "tui1jian4",
+    "zhuang4tai4",
+    "li4hai4",
+    "ren3bu4zhu4",
+    "jian4zhu4",
+    "gong1gong4qi4che1",
+    "shi2hua4",
+    "ju4jue2",
+    "xing2ren2",
+    "she4ji4	she4ji1",
+    "chu2fang2",
+    "shen1ti3",
+    "shou3shu4",
+    "ou3ran2",
+    "chao2shi1	chao1shi4",
+    "bu4jia3si1suo3",
+    "chu1xian4",
+    "gan3xiang3",
+    "hang2ye4",
+    "zui4	zui3	zui4",
+    "zhuan1men2",
+    "chun1you2",
+    "ci4yao4",
+    "fan2nao3",
+    "ke1xue2",
+    "you1mei3",
+    "bao3chi2",
+    "lao3ban3",
+    "mai4bu4li3qi2",
+    "cha2	cha4	cha1",
+    "xian4dai4",
+    "jia1ting2",
+    "shao1wei1",
+    "bao3bei4",
+    "mian4lin2",
+    "zhuang4kuang4",
+    "mei3li4	mei4li4",
+    "li4qi4",
+    "suo1duan3",
+    "zheng3qi2",
+    "wei4kou3",
+    "mang2ren2",
+    "ji3hu1",
+    "feng1fu4",
+    "que4ren4",
+    "ju4le4bu4",
+    "wan2cheng2",
+    "bu2ke4qi5",
+    "jie2shu4",
+    "jian1jue2",
+    "cheng2xu4",
+    "mei2	mei3	mei3",
+    "cu1xin1",
+    "zhan3lan3",
+    "xun4su4",
+    "jia1you2zhan4",
+    "zhao4she1",
+    "li4shi3",
+    "fan4wei2",
+    "qi2guai4",
+    "gan3dao4",
+    "ban4ye4",
+    "mei2tan4",
+    "chuan2tong3",
+    "ke1ji4",
+    "ping2fang1",
+    "liang2hao3",
+    "wu2nai4",
+    "shen2zhi4",
+    "guo4qi1",
+    "ji1lie4",
+    "pan2zi3",
+    "zhong1wu3",
+    "gu3dai4",
+    "ai4hao3",
+    "kai1wan2xiao4",
+    "jing1cheng2suo3zhi4",
+    "nei4ke1",
+    "yi1lian2",
+    "lai2zi4",
+    "kai1mu4shi4",
+    "wu3dao3",
+    "zai4hu1",
+    "wu2yi4",
+    "di4dian3",
+    "nan2shou4",
+    "yin3qi3",
+    "you1shi4",
+    "ceng2",
+    "jiao3zi3",
+    "zhong4shi4",
+    "cuo4",
+    "ji1lei4",
+    "yan2jiu1",
+    "le4guan1",
+    "ping2heng2",
+    "chu1kou3",
+    "gong1xi3",
+    "huan1ying2",
+    "pang2bian1",
+    "che1xiang1",
+    "hai3yang2",
+    "wu2liao2",
+    "she4ying3shi1",
+    "tuo1",
+    "jin3shen4",
+    "ta1	ta1	ta1",
+    "gong1cheng2shi1",
+    "an1pai2",
+    "bei3jing1shi1fan4da4xue2",
+    "han4yu3",
+    "xing4yun4",
+    "xiao3yu3",
+    "you3qu4",
+    "wei4|wei2	wei4	wei4	wei4",
+    "zen3me5",
+    "zun1zhong4",
+    "di4di5",
+    "zhong1jian1",
+    "dan1yuan2",
+    "zhan3kai1",
+    "chi1",
+    "kong1tiao0",
+    "ai4	ai1	ai3	ai1",
+    "que1dian3",
+    "li3you2",
+    "ya2gao1",
+    "dao3yan3",
+    "zhong1wen2",
+    "ren2kou3",
+    "you3xian4",
+    "ye4yu2",
+    "gao4su4",
+    "lao3hu3",
+    "gui4tai2",
+    "hu2shuo1",
+    "pei4zhan1sen1",
+    "ri4cheng2",
+    "du2te4",
+    "xiao4shun4",
+    "da3rao3",
+    "xu1yao4",
+    "zhi3yao4",
+    "qian2bao1",
+    "you0dianr0",
+    "pei4`zhan1sen1",
+    "xu4shu4",
+    "ge2wai4",
+    "zi4sha1",
+    "zhe4xie1",
+    "nan2guo4",
+    "jing4zheng1",
+    "yao4bu4",
+    "shuai3	shuai4",
+    "fa1shao1",
+    "la1ji1",
+    "ma3hu3",
+    "jian4li4	jian3li4",
+    "`",
+    "shang1dian4",
+    "shao3	shao2",
+    "xing4kui1",
+    "he2shi4",
+    "kun1chong2",
+    "jia1zi5",
+    "bao4ming2",
+    "guan1nian4",
+    "cheng1hu1",
+    "wei3ba1",
+    "dao3you2",
+    "gan3jue2",
+    "liao0tianr0",
+    "huo3ban4",
+    "fen1pei4",
+    "qiong2",
+    "biao1zhun3",
+    "bao3xian3",
+    "ji2ti3",
+    "zhi4ding4	zhi4ding4",
+    "zi1shi4",
+    "yin1liang2",
+    "tian1	tian2	tian2",
+    "zheng1qiu2",
+    "zong3jie2",
+    "zhu3yao4",
+    "zhi4liang4",
+    "zhao4xiang1ji1",
+    "yi2xia4",
+    "(",
+    "xiang4pi2",
+    "yi0huir0",
+    "san1	san3",
+    "sheng1diao4",
+    "lao3shi1	lao3shi5",
+    "cu1cao1",
+    "gan4jing4",
+    "quan2mian4",
+    "dao3zhi4",
+    "huang2	huang1",
+    "ce4",
+    "qian2tu2",
+    "zhu4yi4	zhu3yi4",
+    "zi4si1",
+    "tiao1zhan4",
+    "sheng1huo2",
+    "shi3zhong1",
+    "si1xiang3",
+    "nv0hair0",
+    "chen4shan1",
+    "jing1ju4",
+    "ju4ti3",
+    "xi1qu3",
+    "zuo4ye4",
+    "ba3wo4",
+    "shi3jin4",
+    "yi1	yi3	yi4	yi3",
+    "mao2jin1",
+    "bi3er3`gai4ci2",
+    "liu2li4",
+    "qiao3miao4",
+    "zhong4da4",
+    "qun2zi3",
+    "mi4qie4",
+    "fei4hua4",
+    "she4shi1",
+    "ma0	ma3	ma4",
+    ")",
+    "leng3",
+    "tu3di4",
+    "zhang4hu4",
+    "ju1ran2",
+    "dao4di3",
+    "xiang1shan1",
+    "yao4shi4	yao4shi0",
+    "kou3",
+    "qing3yuan4shu1",
+    "la1	la0	la4",
+    "ti1zu2qiu2",
+    "ru4wei2",
+    "en1ai4",
+    "dai4	dai4	dai1	dai4",
+    "ni3men0",
+    "yu3yan2	yu4yan2",
+    "lou2	lou4",
+    "de2yi4",
+    "ji4xu4	ji1xu4",
+    "si1chou2",
+    "ren2ming2",
+    "zhi4zuo4",
+    "shang1pin3",
+    "jian4pan2",
+    "shu1shu1",
+    "jia3zhuang1",
+    "xiang1dui4",
+    "zong3li3",
+    "zi1ge2",
+    "mu4tou2",
+    "li4yong4",
+    "ti2mu4",
+    "ran2hou4",
+    "yuan2yin1",
+    "jia1bin1",
+    "han3	han4",
+    "hei1xing1xing5",
+    "mian4shi4",
+    "bao4yuan4",
+    "juan1",
+    "neng2li4",
+    "ke3neng2",
+    "xin1	xin4",
+    "suo3wei4",
+    "sui2shou3",
+    "chu3guo2",
+    "nuo4bei4er3jiang3",
+    "jiu1jing4",
+    "wen2ju4",
+    "dong4hua4pian4",
+    "zhi3hao3",
+    "bi3ci3",
+    "xiang1xin4",
+    "po4huai4",
+    "hou4	hou4",
+    "bei4zi3	bei1zi5",
+    "lv4shi1",
+    "leng3dan4",
+    "xiu1xi5",
+    "cheng2shu2",
+    "zai4yu2",
+    "ji1ji2",
+    "ru2jin1",
+    "quan2shen2guan4zhu4",
+    "jia1you2",
+    "lang4man4",
+    "ren4wei4",
+    "qing1shao4nian2",
+    "zhi4xu4",
+    "hao2hua2",
+    "jin3zhang1",
+    "qie1",
+    "bao4dao4	bao4dao4",
+    "luo4hou4",
+    "zhuo1zi5",
+    "qi3ju1",
+    "yi4wu4",
+    "qing2kuang4",
+    "huang1zhang1",
+    "zhua1jin3",
+    "bei3jing1	bei4jing3",
+    "lv3xing2",
+    "pen2",
+    "zuo4pin3",
+    "ling2jian4",
+    "shi4he2",
+    "tian1ran2",
+    "fan1yi4",
+    "zhang4fu5",
+    "jiao1ji4",
+    "chi2zao3",
+    "tao3lun4",
+    "da4shi3guan3",
+    "hu4guo2si4",
+    "cai3qu3",
+    "biao3ming2",
+    "gai3jin4",
+    "fen3se4",
+    "lu4",
+    "ke3kao4",
+    "guang1pan2",
+    "bi2zi3",
+    "xiang1chang2",
+    "nian2ling2",
+    "wu1ran3",
+    "chuang1lian2",
+    "zhu3dong4",
+    "qing1dan4",
+    "he2	he2	he1",
+    "guan3zi5	guan3zi3",
+    "you3yi4si1",
+    "liu2shou3",
+    "dai4yan2",
+    "guang3chang3",
+    "ying4jian4",
+    "ping2deng3",
+    "hu1ran2",
+    "wang4ji4",
+    "jing3cha2",
+    "tai4ji2quan2",
+    "jue2dui4",
+    "yi4yi4",
+    "xing2li3xiang1",
+    "shou1ju4",
+    "yue4	yue1	yue4",
+    "yuan2liao4",
+    "hai2zi5",
+    "zhao2huo3",
+    "da2ying1",
+    "dai0fu1",
+    "jian3zhi2	jian1zhi2",
+    "fan1chuan2",
+    "jian3qing1",
+    "zi4dong4",
+    "a0",
+    "zhao3	zhao1	zhao4",
+    "jian1ju4",
+    "jin4li4",
+    "mei4mei5",
+    "bu4de2bu4",
+    "shou3gong1",
+    "jie4shao4",
+    "nv3",
+    "geng4",
+    "you2qi2	you0qi2",
+    "yi2lv4",
+    "si1hao2",
+    "fa1hui1",
+    "wei1xian3",
+    "<mai4huo3chai2de5xiao3nv3hai2er5>",
+    "ji4zhe3",
+    "ji4hua4",
+    "da3gong1",
+    "miao3",
+    "shuang1",
+    "yi4si5",
+    "ge1ge5",
+    "you1hui4",
+    "gong1li3",
+    "jian1bang3",
+    "gou4cheng2",
+    "bing4qie3",
+    "feng1xian3",
+    "lian4ai4",
+    "suo3yi3",
+    "jian3shao3",
+    "li2hun1",
+    "wai4gong1",
+    "an1wei4",
+    "kan4fa3",
+    "zhong4yao4",
+    "han2yi4",
+    "an4zhao4",
+    "dao4mei2",
+    "ti2zi5",
+    "ya2chi3",
+    "xin4hao4",
+    "dian4tai2",
+    "sheng3lve4",
+    "sheng1bing4",
+    "shen1yuan1",
+    "ye4zi3",
+    "ce4yan4",
+    "ba4ba5",
+    "gan0huor0",
+    "qi2shi2",
+    "gua4hao4",
+    "yang2guang1",
+    "kuang4quan2shui3",
+    "xin1xian1",
+    "bu4nai4fan2",
+    "xi3shou3jian1",
+    "qi2yu2",
+    "fan3zheng4",
+    "cun2zai4",
+    "xia4mian4",
+    "diao4	diao4",
+    "ji1qi4	ji2qi2",
+    "guai3wan1",
+    "hou4bei4",
+    "man2tou2",
+    "jian4	jian4	jian3	jian3",
+    "yi1sheng1",
+    "sheng1ming4",
+    "xiao3shi2	xiao1shi1",
+    "shi2xi2",
+    "gu4ke4",
+    "nen4",
+    "xiang1guan1",
+    "shui3ping2",
+    "xie3zuo4",
+    "bing1ji1ling2",
+    "biao3yan3",
+    "jiang3jiu1",
+    "kuai4zi3",
+    "zhao4guo2",
+    "zhi2ye4",
+    "guan1xin1",
+    "xing4fu2",
+    "wei3qu1",
+    "bi3ji4ben3",
+    "shang4wang3",
+    "he2bi4",
+    "bu4cuo4",
+    "dian4chi2",
+    "xie4xie5",
+    "yong1ji3",
+    "xin4xin1",
+    "bao4tu1quan2",
+    "yi1yang4",
+    "kong1",
+    "qi4you2",
+    "xia4ling4ying2",
+    "zheng4ju4",
+    "shai4",
+    "gan3qing2",
+    "nei4bu4",
+    "jiao1tong1",
+    "nan2	nan2	nan2",
+    "suan4	suan1",
+    "wu2suo3wei4",
+    "cai4shi4kou3",
+    "lao3she3",
+    "huai2yun4",
+    "tu2ya1",
+    "shou3sui4",
+    "piao4liang5",
+    "xin4xi1",
+    "shi2yan4",
+    "zhu3chi2",
+    "hei1ban3",
+    "da3pen1ti4",
+    "qian1bi3",
+    "he2ge2",
+    "mi3fan4",
+    "sha1fa1",
+    "tui4	tui1	tui3",
+    "tiao2",
+    "na3li3",
+    "yong1bao4",
+    "tou1",
+    "kuai4ji4",
+    "guan1yu2",
+    "xin1nian2",
+    "xuan3ze2",
+    "nong2cun1",
+    "ruan3",
+    "chou1xiang4",
+    "yu3	yu2	yu2	yu3",
+    "fang1fa3",
+    "kao4",
+    "shan4liang2",
+    "guo4qu4",
+    "ke1en1",
+    "da2dao4",
+    "sheng1dong4",
+    "zi1liao4",
+    "kun4rao3",
+    "chu1ban3",
+    "ji0dao4",
+    "ting1shuo1",
+    "shu3biao1",
+    "sheng1yin1",
+    "zhi4liao2",
+    "ya4zhou1",
+    "pei0zhun3",
+    "bi4yao4",
+    "gan3ji1",
+    "lu4qu3",
+    "shi4shi2",
+    "zhuan3bian4",
+    "dan1ren4",
+    "tu2	tu3",
+    "yi2wen4",
+    "shao3xu3",
+    "fei1ni3mo4shu3",
+    "ke3lian2",
+    "che1huo4",
+    "shen1	shen1",
+    "deng3	deng1",
+    "gui1lv4",
+    "xiao4yuan2",
+    "cun2",
+    "jing1guo4",
+    "yi1ding4",
+    "lao3shu3",
+    "ye2ye2",
+    "mei2you3",
+    "tou2ru4",
+    "gen1ju4",
+    "shi2qi1",
+    "tui4bu4",
+    "ha1",
+    "gan4	gan3	gan3	gan1	gan3",
+    "sui2shi2",
+    "yun2	yun1",
+    "gu3dian3",
+    "kuang2",
+    "dan1du2",
+    "sheng1yi4",
+    "yan2ge2",
+    "qing3	qing1	qing1	qing2",
+    "quan2bu4",
+    "shei2",
+    "yong3qi4",
+    "lian2he2",
+    "bao3hu4",
+    "zhi1shi2",
+    "xin4ren4",
+    "zheng4jian4",
+    "chuan2ran3",
+    "pin2dao4",
+    "jin4dai4",
+    "pai2dui4",
+    "pen2zi5",
+    "jia4zhi2",
+    "yi1ran2",
+    "gu3li4",
+    "ke3pa4",
+    "du2	du4	du3",
+    "teng2",
+    "cheng2jiu4",
+    "jie2guo3",
+    "yao1qing3",
+    "shi4juan4",
+    "ku1	ku3	ku4",
+    "da4	da3",
+    "yuan2dan4",
+    "bei4	bei4	bei4	bei1",
+    "xi4jie2",
+    "jin4xing2",
+    "zai4jian4",
+    "li3jie3",
+    "shu4ju4",
+    "jie4kou3",
+    "zen3me5yang4",
+    "kong4zhi4",
+    "ren2yuan2",
+    "chun1",
+    "kan4	kan3",
+    "chu1zu1che1",
+    "ji1rou4",
+    "ya2shua1",
+    "cai3fang3",
+    "guang3da4",
+    "bi4ye4",
+    "zhan1mu3shi4nai4shi3mi4si1",
+    "wang3fan3",
+    "da3zhen1",
+    "qi3lai2",
+    "can1",
+    "wu2shu4	wu3shu4",
+    "nian2dai4",
+    "gu3zhang3",
+    "wen3ding4",
+    "zheng4zai4",
+    "lu4xu4",
+    "miao2tiao2",
+    "gei3",
+    "ji4ling3dai4",
+    "qiu1",
+    "zheng1lun4",
+    "de2dao4",
+    "pang4",
+    "qu1bie2",
+    "luo2bo0",
+    "xia4yu3",
+    "liu4yi1er2tong2jie2",
+    "pu3tong1",
+    "mi2lu4",
+    "lian2huan2hua4",
+    "tu3dou4",
+    "xi2guan4",
+    "wei1xia0",
+    "shu4liang4",
+    "nv3shi4",
+    "gong4tong2",
+    "fang2jian1",
+    "yuan2	yuan3	yuan2",
+    "jun1yun2",
+    "bao4yu3",
+    "yang2tai2",
+    "xin1qing2",
+    "zhan4guo2",
+    "ying1pin4",
+    "gui1ju3",
+    "nan2dao4",
+    "ye3	ye4	ye4",
+    "feng1jing3",
+    "bi4guan1",
+    "na4ru4",
+    "ling3yu4",
+    "liu2lan3",
+    "qi2zhong1",
+    "jing1shen2|jing1shen5",
+    "jiang1lai2",
+    "xue2xi2",
+    "ban4li3",
+    "ge4xing4",
+    "gou4wu4",
+    "tao3jia4huan2jia4",
+    "jue2de5",
+    "qin1zi4",
+    "ma1ma5",
+    ";",
+    "jing4ran2",
+    "bang4wan3",
+    "wan2ju4",
+    "kongr0",
+    "zuo4wei4	zuo4wei4",
+    "fei1ji1",
+    "bei3fang1",
+    "quan2	quan4	quan1",
+    "hua2bei3",
+    "bao4zhi3",
+    "ying3zi3",
+    "chang2qi1",
+    "chan3pin3",
+    "ming2ming2",
+    "ci2yu3",
+    "lin2li2jin4zhi4",
+    "lian2xu4",
+    "cha1zi5",
+    "qiao3ke4li4",
+    "zheng3ge4",
+    "ming2tian1",
+    "che1",
+    "ri4",
+    "zhu2zi5",
+    "hu2tu2",
+    "deng3yu2",
+    "ge4bie2",
+    "xiao3qi5",
+    "fa1dou3",
+    "xing4ge2",
+    "xiao3peng2you3",
+    "ce4suo3",
+    "qi3fa1",
+    "hun1yin1",
+    "nao4zhong1",
+    "nai3nai3",
+    "san4bu4",
+    "fa1piao4",
+    "xian4zhi4",
+    "shou3tao4",
+    "niu2nai3",
+    "bi3sai4",
+    "ke4ren2",
+    "kua3	kua1",
+    "mi4ma3",
+    "zan4cheng2",
+    "pu2tao2",
+    "da4fang1",
+    "xi1hong2shi4",
+    "ba1cheng2",
+    "yan3jing5	yan3jing4",
+    "yi1zai4",
+    "hui2da2",
+    "mian4tiao2",
+    "jing1ji4",
+    "zhaor1",
+    "bu4jin3",
+    "zuo4ke4",
+    "dang1	dang3",
+    "ju4shuo1",
+    "hen3|hen4	hen4",
+    "ti2wen4",
+    "chen2	chen4",
+    "nan2guai4",
+    "chao1guo4",
+    "shou3du0ji1chang3",
+    "cai4dan1",
+    "biao3qing2",
+    "ti2	ti2	ti4	ti2",
+    "bao3cun2",
+    "jiao4shi4",
+    "wai4jiao1",
+    "ping2	ping2",
+    "yan2se4",
+    "feng1",
+    "ying1fu4",
+    "hui4yi4	hui2yi4",
+    "qing1yi4",
+    "long2",
+    "fa3yuan4",
+    "li3cha2de2`xi1er3si1",
+    "wei4dao4",
+    "gai3bian4",
+    "chu1ji2zhong1xue2",
+    "ke4guan1",
+    "zhen1	zhen4	zhen4",
+    "zhui1qiu2",
+    "xiao4ying1",
+    "shi1run4",
+    "cui1",
+    "jian3dao1",
+    "feng1zhong1ding4lv4",
+    "tu2shu1guan3",
+    "xian4xiang4",
+    "xue2sheng5",
+    "xiang1gua1",
+    "mi3",
+    "er2	er4",
+    "fang1mian4",
+    "yong4gong1",
+    "biao3xian4",
+    "yu2shi4",
+    "shuang1fang1",
+    "chi1fan4",
+    "bian1pao4",
+    "mao2bing4",
+    "shou4bu4liao0",
+    "yu4ding4",
+    "shen2me5",
+    "xiong2mao1",
+    "qiang3	qiang2	qiang1",
+    "xiang1dang1",
+    "yin1er2",
+    "jing4zi3",
+    "cong2",
+    "ou1meng2",
+    "wei1xiao4",
+    "ming2xian3",
+    "fan3fu4",
+    "xia4wu3",
+    "you4er2yuan2",
+    "hua1hua1",
+    "ke4ku3",
+    "fu2zhuang1",
+    "xiao3xin1",
+    "zhi1dao4	zhi3dao3",
+    "gao1xing4",
+    "qing1chu3",
+    "gen1ben3",
+    "zhi1geng4niao3",
+    "tai2jie1",
+    "ge4	ge4",
+    "fang4shu3jia3",
+    "ri4zi3",
+    "nv3xing4",
+    "fen1	fen4	fen3",
+    "ping2jia4",
+    "fu2he2",
+    "ge1bo2",
+    "shui4jiao4",
+    "hai2shi4",
+    "gou4mai3",
+    "gang1cai2",
+    "lai2de2ji2",
+    "zhun3shi2",
+    "guan1bi4",
+    "bo1li2",
+    "zhao4gu4",
+    "zhi3dai4",
+    "wen2hua4",
+    "ying1xiong2",
+    "you3hao3",
+    "tong4kuai4",
+    "wei4lai2",
+    "wen2xue2",
+    "hu4lian2wang3",
+    "shi4qing5",
+    "bu4zu2",
+    "dui4mian4",
+    "xian1yan4",
+    "xiang4mu4",
+    "fan3ying4	fan3ying4",
+    "jiao4	jiao4	jiao3	jiao1	jiao3	jiao1	jiao1",
+    "shu2xi1",
+    "que1fa2",
+    "xing1qi1",
+    "ti2chang4",
+    "ming4ling4",
+    "ju3	ju4	ju2	ju4",
+    "feng3ci4",
+    "bu4zhou4",
+    "tao2qi4",
+    "ze2ren4",
+    "ti2gang1",
+    "jian4kang1",
+    "deng1ji1pai2",
+    "ji4shu4",
+    "xiao1fei4",
+    "shou1ru4",
+    "yan4hui4",
+    "di4fang1",
+    "gai3	gai4",
+    "zhu2bu4",
+    "ke3	ke4	ke4	ke4	ke1	ke1	ke3",
+    "jing3se4",
+    "sheng1qi4",
+    "wei4le0",
+    "ji1chu3",
+    "an1quan2",
+    "gong1bu4",
+    "ke3shi4",
+    "la1ji1tong3",
+    "shui4zhao2",
+    "zheng3ti3",
+    "bu4duan4",
+    "xing2cheng2",
+    "mei3ren2yu2",
+    "qi2ji1",
+    "da3ban4",
+    "zhe2xue2",
+    "xiao1shou4",
+    "fan3hang2",
+    "jie2yue1",
+    "zeng1",
+    "zhang3bei4",
+    "huai2nian4",
+    "wen2jian4",
+    "li3	li2	li3	li4	li2",
+    "chu1fa1",
+    "zhi3shang4tan2bing1",
+    "tao2bi4",
+    "jin3liang4",
+    "qiao2	qiao2	qiao1",
+    "sui2shen1",
+    "chu1se4",
+    "jin1shu3",
+    "chuang3	chuang1",
+    "you3ming2",
+    "zhen1xi1",
+    "wei4yang3",
+    "zhao4kuo4",
+    "bu4de2liao0",
+    "zhao4chang2",
+    "tong1guo4",
+    "na4	na2	na3",
+    "tao3yan4",
+    "kai1shui3",
+    "ming2",
+    "bi4xu1",
+    "xue2li4",
+    "qi1jian1",
+    "wei1xin4",
+    "liang2cha2",
+    "pi1ping2",
+    "chi2dao4",
+    "ren2min2",
+    "ze2",
+    "wen2wen2",
+    "shang1chang3",
+    "kong1qi4",
+    "xin1wen2",
+    "gong1jin1",
+    "shui3	shui4",
+    "liu2xue2",
+    "jin1yu2",
+    "xing2shi4	xing2shi4",
+    "yu4bao4",
+    "mu3qin1",
+    "biao3ge2",
+    "chang2jiang1",
+    "mian4bao1",
+    "zhao4fu2gen1",
+    "tong3yi1	tong2yi4",
+    "yi3jing1",
+    "zhong1qiu1jie2",
+    "kong3pa4",
+    "dou1	dou4",
+    "yun4qi4",
+    "tui1ci2",
+    "gong4xian4",
+    "ao4da4li4ya4",
+    "zi3lu4",
+    "zi3xi4",
+    "shang1ye4",
+    "liu4	liu2",
+    "ji1chang3",
+    "zhang1xiao3long2",
+    "gua1feng1",
+    "yi4jian4",
+    "gui1na4",
+    "shu1cai4",
+    "bi4ran2",
+    "nei4",
+    "yan3chu1",
+    "li3lun4",
+    "chu1shi4",
+    "wanr2",
+    "zhuan1ye4",
+    "guo3zhi1",
+    "xiao3	xiao4",
+    "guo4cheng2",
+    "man3yi4",
+    "ju2hua1",
+    "jin4bu4",
+    "zuo2tian1",
+    "jiang3	jiang3",
+    "jiang4luo4",
+    "luo2ji2",
+    "you2lan3",
+    "ri4li4",
+    "wu2lun4",
+    "lai2",
+    "guan1dian3",
+    "ci3wai4",
+    "pian2yi5",
+    "jian4shen1",
+    "he2kuang4",
+    "xiao3huo3zi3",
+    "mai4huo3chai2de5xiao3nv3hair2",
+    "peng2zi3",
+    "fu4xi2",
+    "lai2bu4ji2",
+    "chu4li3",
+    "yi3ji2",
+    "fen1shou3",
+    "jiu4hu4che1",
+    "yuan2gong1",
+    "mao4xian3",
+    "he2zi3",
+    "di4tie3",
+    "gong1zhu3",
+    "bu3chong1",
+    "dan1wei4",
+    "huo3cheng2yan2",
+    "man3zu2",
+    "jing1cai3",
+    "bi3ru2",
+    "kong1xian2",
+    "dan3xiao3gui3",
+    "cu4",
+    "dao4ju4",
+    "jia1xiang1",
+    "ng4",
+    "zuo4zhe3",
+    "duo1	duo3",
+    "ban4fa3",
+    "xian3de2",
+    "fan3er2",
+    "zhi4leng3",
+    "shi2hou5",
+    "wei4bi4",
+    "wei3qi4",
+    "gan3xing4qu4",
+    "gai4nian4",
+    "qu4	qu3	qu3",
+    "da3zhe2",
+    "da4xiang4",
+    "hui1gu1niang2",
+    "zhao1dai4",
+    "yang4zi3",
+    "sen1lin2",
+    "shi3jinr4",
+    "tong2yang4",
+    "gua4",
+    "zhang3wo4",
+    "you2ju2",
+    "qing1song1",
+    "dian4zi3you2jian4",
+    "bao4quan2",
+    "yong3yuan3",
+    "zhong3lei4",
+    "yi1gong4",
+    "yun4yong4",
+    "lao3bai3xing4",
+    "bi3jiao4",
+    "you2lai2",
+    "ze2bei4",
+    "xian1	xian4	xian2",
+    "mang2",
+    "xiao3qu1",
+    "xiang4zi5",
+    "you3guan1",
+    "yuan4yi4",
+    "qin1qi1",
+    "guo2wang2",
+    "liang2kuai4",
+    "fei1chang2",
+    "jian4she4",
+    "ti2gao1",
+    "xing4fen4",
+    "hai3xian1",
+    "bai2	bai3	bai3",
+    "chu1chai0",
+    "shi1qu4",
+    "ke3ai4",
+    "ya1jin1",
+    "jiao1wang3",
+    "yin1yue4",
+    "zang4",
+    "shen1duan4",
+    "wa4zi3",
+    "zhuang1xiu1",
+    "yu4xi2",
+    "ba3	ba0	ba1",
+    "hai2	hai3",
+    "ji2zhen3",
+    "jia1li4fu2ni2ya4zhou1",
+    "tian1an1men2dong1",
+    "ren2	ren4",
+    "zhen1dui4",
+    "zhuan3	zhuan4",
+    "ti3yu4guan3",
+    "bing3gan4",
+    "gan4huo2er2",
+    "bao3zheng4",
+    "hui4yuan2ka3",
+    "hong2",
+    "fang1xiang4",
+    "yi3lai2",
+    "yuan2liang4",
+    "nu3li4",
+    "bian4hua4",
+    "kao3lv4",
+    "ju4da4",
+    "teng2ai4",
+    "cheng2ji1",
+    "she4	she2",
+    "yin2zi5",
+    "qi4fen1",
+    "liao2tian1",
+    "yi3qian2",
+    "sou1suo3",
+    "zhong1guo2",
+    "lang3du2",
+    "hua4xue2",
+    "gao1su4gong1lu4",
+    "nong2min2",
+    "zhi4yu2",
+    "cheng1zan4",
+    "yi1qie4",
+    "qi1zi5",
+    "ju3xing2",
+    "zan2men0",
+    "yi2han4",
+    "dao4xiang1cun1",
+    "jiao4lian4",
+    "hong2se4",
+    "xu1xin1",
+    "ming2pian4",
+    "xiang1jing4ru2bin1",
+    "cheng2	cheng1",
+    "pao3bu4",
+    "qing2lv3",
+    "ti3tie1",
+    "mao2	mao4	mao1",
+    "ben3ke1",
+    "xiao4guo3",
+    "cheng2fen1",
+    "zhong4dian3",
+    "zi4mu3	zi4mu4",
+    "li4run4",
+    "zheng4hao3",
+    "ru2guo3",
+    "re4qing2",
+    "zhi1	zhi3	zhi2	zhi3	zhi4	zhi1	zhi3",
+    "zui4chu1",
+    "zhi2wu4",
+    "a1yi2",
+    "zheng4shi4",
+    "er2qie3",
+    "cong2ci3",
+    "wo4shou3",
+    "li2kai1",
+    "qian2mian4",
+    "neng2",
+    "fan3dui4",
+    "jian4bu4ru2fei1",
+    "he2tong5",
+    "hang2jia5",
+    "shen1qing3",
+    "ca1",
+    "zi4you2",
+    "gong1zi1",
+    "jie1jin4",
+    "yi3zi5",
+    "re4lie4",
+    "xiao3shuo1",
+    "di2ren2",
+    "shi4de0",
+    "chao2	chao3	chao1	chao3",
+    "ping2jing4",
+    "bang1zhu4",
+    "bu4fen1",
+    "cheng2dan1",
+    "fei2zao4",
+    "zhi4shao3",
+    "yu4fang2",
+    "an4shi2",
+    "yan2",
+    "tui1guang3",
+    "shi4	shi3	shi2	shi1	shi4",
+    "pai4	pai1",
+    "qi2ta1",
+    "xie1	xie3	xie3	xie2	xie1	xie4",
+    "duan4duan4xu4xu4",
+    "jin1tian1",
+    "la4jiao1",
+    "qing3jia3",
+    "xiong1di4",
+    "bo1dong4",
+    "shou4huo4yuan2",
+    "xun2zhao3",
+    "mo2fang3",
+    "diao4cha2",
+    "feng1su2",
+    "zi4ran2",
+    "suo3	suo3",
+    "rang4",
+    "liu2xie3",
+    "shi2zai4",
+    "song4",
+    "zu3he2",
+    "ken3ding4",
+    "xiang3nian4",
+    "ri4chang2",
+    "tou2zi1",
+    "xing2wei4",
+    "hui1xin1",
+    "su4liao4dai4",
+    "fou3ren4",
+    "an1na4",
+    "ying1gai1",
+    "zhan1tie1",
+    "gu1niang2",
+    "dan4shi4",
+    "mo1",
+    "zong3tong3",
+    "cai2liao4",
+    "ka3che1",
+    "zhi3hui1	zhi4hui4",
+    "zhuo2",
+    "zhe4	zhe0	zhe2",
+    "sha1tan1",
+    "yi4qi3",
+    "ji4sheng1",
+    "gu3lou2da4jie1",
+    "yu2kuai4",
+    "yun4shu1",
+    "sha1zi3",
+    "zhong4liang4",
+    "hun1li3",
+    "di1luo4",
+    "guo2jun1",
+    "leng3jing4",
+    "gai3shan4",
+    "shan1chu2",
+    "zhao4pian4",
+    "lv3you2",
+    "hui4lv4",
+    "hu4xiang1",
+    "meng2long2",
+    "za2zhi4",
+    "xing2zhuang4",
+    "gong1si1",
+    "ji4nian4",
+    "yan3yuan2",
+    "mian3fei4",
+    "bu4tong2",
+    "dui4shou3",
+    "wan2mei3",
+    "yang4shi4",
+    "ran2shao1",
+    "shi2shang4",
+    "jie3jie5",
+    "huo2dong4",
+    "pei2",
+    "lian2xi4	lian4xi2",
+    "zhua1",
+    "xiang4	xiang3	xiang4	xiang4	xiang4	xiang1	xiang3",
+    "ci2zhi2",
+    "reng2ran2",
+    "cu4shi3",
+    "ke4cheng2",
+    "zan4shi2",
+    "ji4yi4",
+    "gao1	gao3",
+    "huo4de2",
+    "er3huan2",
+    "ke3xi1",
+    "bu4ran2",
+    "qi4hou4",
+    "zuo4	zuo4	zuo4",
+    "ling2huo2",
+    "yu4dao4",
+    "jie2hun1",
+    "guang3bo1",
+    "xi1han4",
+    "jin3kuai4",
+    "cai2yi4",
+    "shi4jie4",
+    "shu4ma3",
+    "biao3da2",
+    "fang3fu2",
+    "fen1bu4",
+    "jue2ding4",
+    "zou3",
+    "hang2ban1",
+    "bian1ji2",
+    "lu4yin1",
+    "he2ying3",
+    "che1ku4",
+    "ben3ling3",
+    "wei2rao4",
+    "jia1fang3",
+    "shen1fen4",
+    "he2shang4",
+    "ji1hui4",
+    "bi4jing4",
+    "zhuan1jia1",
+    "cha4bu4duo1",
+    "min2ju1",
+    "guang3gao4",
+    "shi2jian1	shi2jian4",
+    "bi3er3gai4ci2",
+    "shou3xu4",
+    "jia1ju4",
+    "tong1chang2",
+    "bi3",
+    "mei3tian1",
+    "qing2qu4",
+    "hong2jiu3",
+    "bie2",
+    "hua1	hua4	hua2	hua2",
+    "e2	e4",
+    "huan4nan4yu3gong4",
+    "zhi1jiao4",
+    "tian1kong1	tian2kong1",
+    "dao4li3",
+    "da4yue1",
+    "cha2xun2",
+    "chong1man3",
+    "yin3liao4",
+    "jie1	jie1	jie4	jie2	jie4	jie4",
+    "ji2ge2",
+    "cheng2guo3",
+    "su4she4",
+    "gan4zao4",
+    "jiao1huan4",
+    "yong4",
+    "tong2qing2",
+    "fa1ming2",
+    "xi1yin3",
+    "li2mi3",
+    "hua2yi4",
+    "dong1hai3long2wang2",
+    "tai4du4",
+    "yang2	yang3	yang3",
+    "pei4fu2",
+    "qing2jing3",
+    "quan2li4	quan2li4",
+    "pan4wang4",
+    "da4jia1",
+    "hao3xiang4",
+    "dong1	dong4	dong3	dong1	dong4",
+    "guo4	guo2	guo1",
+    "xiao1xi1",
+    "di4tu2",
+    "que4",
+    "zhuan1zhu4",
+    "guan1	guan1",
+    "bing4",
+    "luo4shan1ji1",
+    "ye4wu4",
+    "qiang2diao4",
+    "bai3fen1zhi1",
+    "xia4wei1yi2",
+    "xin1xi1lan2",
+    "ruan3jian4",
+    "yan2zhong4",
+    "dai4ti4",
+    "shi1fu4",
+    "meng4",
+    "can1kao3",
+    "guai4wu4",
+    "ying3xiang3",
+    ",",
+    "du3che1",
+    "mou3",
+    "chuan2zhen1",
+    "duan4	duan4	duan3",
+    "dun1	dun4	dun1",
+    "huo4zhe3",
+    "kai1xin1",
+    "sha1ding1yu2",
+    "ai4xi1",
+    "shi2cha1",
+    "jian4mian4",
+    "pi2jiu3",
+    "hou2zi3",
+    "shi1ye4",
+    "jiao4cai2",
+    "zhao4kai1",
+    "bei1shang1",
+    "yi4wai4",
+    "yun4dong4",
+    "nai4xin1",
+    "ning2ke3",
+    "yang3cheng2",
+    "fu4mu3",
+    "kuo4da4",
+    "qi2ci4",
+    "zhao2liang2",
+    "bao1kuo4",
+    "qun2",
+    "dao4de2",
+    "mi4feng1",
+    "fa1yan2",
+    "ping2an1",
+    "du2li4",
+    "kun4nan2",
+    "hai4xiu1",
+    "fa1chou2",
+    "da3yin4",
+    "qin1ai4",
+    "zheng4zhi4",
+    "yan3jiang3",
+    "rao4",
+    "jian3fei2",
+    "re4xin1",
+    "huo3	huo4",
+    "re4",
+    "sheng3	sheng1	sheng4",
+    "sui2zhe0",
+    "ben1pao3",
+    "xin1shang3",
+    "gang1",
+    "ji1zhi4",
+    "xi1gua1",
+    "que1shao3",
+    "zheng4fu3",
+    "shou3bi3",
+    "dai4zi3",
+    "cheng2gong1",
+    "yang2rou4",
+    "si1ren2",
+    "guo2mao4",
+    "!",
+    "huo3chai2",
+    "tiao0pi2",
+    "cai3yong4",
+    "lin2ju1",
+    "shou4dao4",
+    "zuo4wen2",
+    "yin2	yin1",
+    "wei2xiu1",
+    "zhong1yu2",
+    "ke0sou4",
+    "zong3cai2",
+    "bi3shi4",
+    "fa2kuan3",
+    "jiao4yu4",
+    "shan1",
+    "xi1shou1",
+    "tou2fa1",
+    "yi1ban1",
+    "wei3da4",
+    "chu1sheng1",
+    "qing2yuan2",
+    "shu1shi4",
+    "cheng1ba4",
+    "shou3xian1",
+    "yin4shua1",
+    "duan4lian4",
+    "zheng1teng2",
+    "pin1yin1",
+    "gan3mao4",
+    "xi3li3",
+    "xing2dong4",
+    "si1kao3",
+    "xin1li3",
+    "zuo3bian5",
+    "wan4zhang4",
+    "bao4she4",
+    "bang1mang2",
+    "han4zi4",
+    "nao3dai4",
+    "zong1he2",
+    "mei3guo2",
+    "chuang4ban4",
+    "mian4ji1",
+    "yue4qi4",
+    "qian1wan4",
+    "xing2rong2",
+    "tong4ku3",
+    "kan4wang5",
+    "ling2qian2",
+    "da4xing2",
+    "you3xiao4",
+    "qian1zheng4",
+    "sheng2zi3",
+    "shuo4shi4",
+    "dan4",
+    "chong0xin1",
+    "er4shou3",
+    "shu4xue2",
+    "ming2sheng4gu3ji4",
+    "dao4shi2hou4",
+    "xu3duo1",
+    "xia4zai4",
+    "zi4xin4",
+    "dian4shi4",
+    "huo2po1",
+    "chu1ji2",
+    "shu3yu2",
+    "ji4de2",
+    "meng4xiang3",
+    "gen1zhi4",
+    "fen4dou4",
+    "lei4	lei2",
+    "po4",
+    "bu4xing2",
+    "reng1",
+    "duo3cang2",
+    "jie2sheng3",
+    "zhi4zao4",
+    "<",
+    "you0le4",
+    "yi4shu4",
+    "wei2fan3",
+    "pei2yang3",
+    "jiu4jiu4",
+    "po4chan3",
+    "chu2fei1",
+    "wen2zi4	wen2zi5",
+    "biao3",
+    "yi4dianr3",
+    "kai1fa1",
+    "ying2jie1",
+    "wang2gong1",
+    "guo3shi2",
+    "dang1di4",
+    "tong2xue2",
+    "wen4	wen2	wen3",
+    "fu4zhi4",
+    "dong1xi5",
+    "fu2wu4yuan2",
+    "lian2	lian3",
+    "wan4yi1",
+    "bang1	bang4",
+    "wu4li3",
+    "jie2lun4",
+    "fang2ai4",
+    "bao1zi3",
+    "xuan1bu4",
+    "zhang3	zhang1	zhang3",
+    "ling4	ling4	ling2	ling2",
+    "tai4	tai2	tai2",
+    "guang1hua2",
+    "mo2hu2",
+    "piao4	piao1",
+    "?",
+    "sheng1ri4",
+    "wang3wang3",
+    "lan2	lan4	lan2	lan3",
+    "man3	man4",
+    "can1yu3",
+    "wen2huo3",
+    "jin4kou3",
+    "hua4ti2",
+    "cong2lai2",
+    "xun2wen4",
+    "qin2guo2",
+    "zao3shang5",
+    "zhen1shi2",
+    "mei3shu4",
+    "dan4gao1",
+    "shun4chang4",
+    "ne0",
+    "dang1shi2",
+    "zi4jue2",
+    "mian4dui4",
+    "ban4gong1shi4",
+    "dong4zuo4",
+    "xin1xian1gan3",
+    "shui3guo3",
+    "jin4	jin4	jin4",
+    "zhun3bei4",
+    "zeng1jia1",
+    "ji4lv4",
+    "kan4bu4qi3",
+    "xiang4qi2",
+    "xiao1hua4	xiao4hua4",
+    "jie1dai4",
+    "zhi1chi2",
+    "huan3jie3",
+    "xiao3jie3",
+    "zhou1dao4",
+    "shi2ke4",
+    "men2kan3",
+    "da3jiao1dao4",
+    "chu1	chu4	chu2",
+    "han4xian4",
+    "fan1	fan4",
+    "wu4hui4",
+    "shi3yong4	shi2yong4",
+    "jia4ge2",
+    "qiang2lie4",
+    "cao1xin1",
+    "qing1chun1",
+    "si4he2yuan4",
+    "hou4lai2",
+    "shang4dang1",
+    "zhi1gan4	zhi1gan3",
+    "zhi2de2",
+    "zhe2die2",
+    "miao2xie3",
+    "di4zhen4",
+    "bi3li4",
+    "shi4ban4gong1bei4",
+    "chuan1	chuan2",
+    "da3lan2qiu2",
+    "jian1qiang2",
+    "li3guang3",
+    "gui1ding4",
+    "bi3ji4",
+    "yin1yue4hui4",
+    "niu2zai3ku4",
+    "jiu4can1",
+    "hui4	hui2	hui1	hui1",
+    "shuo1wen2jie3zi4",
+    "xing4zhi4",
+    "zhi1piao4",
+    "mao2dun4",
+    "bao1han2",
+    "yun2nan2",
+    "jian4wei3",
+    "jiu3ba0",
+    "shi4bing1",
+    "chong1dian4qi4",
+    "shen2mi4",
+    "huang2jin1",
+    "chu1xi2	chu2xi1",
+    "bo2wu4guan3",
+    "mu4di0",
+    "er3duo3",
+    "ao2ye4",
+    "qing3qiu2",
+    "chang4ge1",
+    "ying4	ying2",
+    "guan1zhong4",
+    "pu3tong1hua4",
+    "mu4qian2",
+    "yue4liang4",
+    "feng1lang4",
+    "gong1fu1",
+    "sheng4li4",
+    "gong1ye4",
+    "nian2qing1",
+    "cong2lin2",
+    "dian1qiu2",
+    "dai4kuan3",
+    "guang3dong1sheng3",
+    "su4du4",
+    "fang4	fang1",
+    "guo2qing4jie2",
+    "bu4guo4",
+    "cai2chan3",
+    "nian2yu2",
+    "tan2pan4",
+    "fu4yin4",
+    "gui4",
+    "ye3xu3",
+    "tai4yang2",
+    "fu3dao3",
+    "li4xi1",
+    "jia1	jia1	jia3	jia3	jia1	jia4",
+    "yi1ban1lai2shuo1",
+    "yuan2ze2",
+    "pa2shan1",
+    "gan4cui4",
+    "chu1zhong1",
+    "ren2lei4",
+    "hao3lin2shuo1",
+    "qi1lang2",
+    "jiao1ao4",
+    "shou3zhi3",
+    "li3wu4",
+    "dou4fu5",
+    "gou1tong1",
+    "jiao4xun4",
+    "ti2gong1",
+    "gong1yu4",
+    "nuan3",
+    "ou1zhou1",
+    "duo1yu2",
+    "jian4yi4",
+    "zhu3xi2",
+    "jing1chang2",
+    "pai2lie4",
+    "cong1ming2",
+    "lao3po2",
+    "he2ping2",
+    "shang4hai3	shang1hai4",
+    "shun4xu4",
+    "zheng1qu3",
+    "jia3ru2",
+    "ping2zi3",
+    "qin2fen4",
+    "lun2",
+    "gu4",
+    "guang1	guang4",
+    "hu1peng2huan4you3",
+    "wang2	wang3",
+    "ying2ye4",
+    "dao3yu3",
+    "lu4kou3",
+    "ping2wei3",
+    "liu2chen2",
+    "di4zhi3",
+    "jia1ban1",
+    "dan1ni2er3ka3nei4man4",
+    "liu2chuan2",
+    "bao4gao4",
+    "bu4dan4",
+    "cheng2li4",
+    "xiu1li3",
+    "dai4yu4",
+    "dui4huan4",
+    "jiao4shou4",
+    "liu2chi4ping2",
+    "ben3zhi4",
+    "tian1qi4",
+    "gan3kuai4",
+    "fang4song1",
+    "zhi3you3",
+    "da3dian4hua4",
+    "ceng2jing1",
+    "gu4yi4",
+    "dui4hua4",
+    "jiao3du4",
+    "tong2shi2	tong2shi4",
+    "ou3er3",
+    "xi4tong3",
+    "guan1jian4",
+    "min2zu2",
+    "lian2mang2",
+    "fou3ze2",
+    "wei4sheng1jian1",
+    "tan2hua1",
+    "wai1wai1niu3niu3",
+    "pei2chang2",
+    "dei0",
+    "ma3sa4zhu1sai4zhou1",
+    "yan2chang2",
+    "shun4",
+    "guai4	guai1",
+    "chong0fu4",
+    "shen2hua4",
+    "neng2yuan2",
+    "yi2dong4",
+    "pi2xie2",
+    "wo3",
+    "yang2xiong2",
+    "yao4	yao4	yao3	yao1	yao2",
+    "hu4zhao4",
+    "shang3xin1yue4mu4",
+    "jia4qian2",
+    "da4wei4",
+    "guo2jia1",
+    "fang2zi3",
+    "jie2ri4",
+    "jie1shou4",
+    "fou3ding4",
+    "cong1mang2",
+    "xiao3mai4",
+    "shi4wu4	shi2wu4",
+    "dian4ti1",
+    "chang2cheng2",
+    "ci4ji1",
+    "di4yi1",
+    "you1jiu3",
+    "shu2lian4",
+    "tian1jin1wei4shi4",
+    "xiao4xiao4",
+    "cuo4wu4",
+    "xian4zai4",
+    "wang3zhan4",
+    "lu2mi3ai1er3",
+    "jing4",
+    "jie2zhang4",
+    "xin4yong4ka3",
+    "xiang2xi4",
+    "he2fa3",
+    "shou3	shou4	shou3	shou1	shou3	shou4",
+    "hai3guan1",
+    "luan4",
+    "zhe2xue2jia1",
+    "cun2huo2",
+    "hu1xi1",
+    "jue2sai4",
+    "chi2tang2",
+    "ke4fu2",
+    "bao3gui4",
+    "xue2wen4",
+    "xiu1xian2",
+    "yong4tu2",
+    "dui4yu2",
+    "chi1kui1",
+    "jin3ji2",
+    "da4sha4",
+    "zan2",
+    "chen2mo4",
+    "wen2zhang1",
+    "an1jing4",
+    "zhi2zhao4",
+    "zu3	zu1",
+    "chuang4zao4",
+    "pang2huang2",
+    "zun1shou3",
+    "cu4jin4",
+    "yi2min2",
+    "xiao4zhang3",
+    "liao0bu4qi3",
+    "pi1	pi1	pi3",
+    "gen1ji1",
+    "yong3gan3",
+    "ti3yu4",
+    "shi2hui1yan2",
+    "jian1chi2",
+    "zhao2ji2",
+    "zan4mai0",
+    "zuo4yong4",
+    "duo1kui1",
+    "zhuan3tou2",
+    "fei1	fei4",
+    "mao2kong3",
+    "li4ji2",
+    "zhai2feng1",
+    "xiang3shou4",
+    "shen4shang4xian4su4",
+    "wan3shang5",
+    "chao1ji2",
+    "dao4	dao4	dao1",
+    "di4wei4",
+    "dian3",
+    "mai3	mai4	mai2",
+    "sun1yue4",
+    "wen2ming2",
+    "dian4nao3",
+    "le0",
+    "hou4guo3",
+    "jiu4	jiu3	jiu3	jiu3	jiu4	jiu4",
+    "zhu3zhang1",
+    "shou3ji1	shou1ji2",
+    "zhen1zheng4",
+    "ren2min2bi4",
+    "wo4shi4",
+    "ping2chang2",
+    "ai4hu4",
+    "zu3zhi1	zu3zhi3",
+    "zhun3que4",
+    "zi4cong2",
+    "wan4	wan2	wan2	wan3",
+    "xiao4jing4",
+    "zuo3you4",
+    "duo1shao5",
+    "gao1dang4",
+    "xiao4lv4",
+    "wai4di4",
+    "she4bei4",
+    "jie3shi4	jie2shi2	jie2shi2",
+    "yu4da2fu1",
+    "ti2xing3",
+    "gou4	gou3	gou4",
+    "chi4bang3",
+    "zhu4fu2",
+    "zhuan1xin1",
+    "re4nao4",
+    "xi3huan5",
+    "jing1shang1",
+    "deng1ji4",
+    "zhong1xun2",
+    "tu1ran2",
+    "nv3er2",
+    "wen1du4",
+    "shi2tou2",
+    "shou3du0",
+    "huan2	huan4",
+    "yi1bei4zi3",
+    "fu4ze2",
+    "chang2jiang1da4qiao2",
+    "chuan2bo1",
+    "te4shu1",
+    "you3	you4	you2",
+    "pei2xun4",
+    "zhen3duan4",
+    "mo4sheng1",
+    "yi4lun4",
+    "ge4ren2",
+    "tui4xiu1",
+    "fu4zhong4",
+    "an4an4",
+    "du4guo4",
+    "hui1fu4",
+    "mo2ter4",
+    "ji2shi3	ji2shi2",
+    "tiao4wu3",
+    "fen1bie2",
+    "huan2jing4",
+    "dong4wu4",
+    "ji2zhong1",
+    "zi1jin1",
+    "zhong1xin1",
+    "gai4kuo4",
+    "di0que4",
+    "chu1zi4",
+    "xing2xiang4",
+    "zhong1jie4",
+    "gai3zheng4",
+    "chuang1hu4",
+    "xian1hua1",
+    "zhi4jin1",
+    "bu4	bu4	bu4",
+    "ling3dao3",
+    "nan2mian3",
+    "zhai3	zhai1",
+    "yin2hang2",
+    "e4lie4",
+    "fan4dian4",
+    "kun4",
+    "biao1zhi4",
+    "ai4xin1",
+    "ji2he2",
+    "zhou1mo4",
+    "zui4hao3",
+    "di4tan3",
+    "xiong1",
+    "tian1di2",
+    "gan3jin3",
+    "lin2	lin2",
+    "yuan4wang4",
+    "dian3xin1",
+    "ke3le4",
+    "tu4zi5",
+    "chai1",
+    "an1zhuang1",
+    "bu4guan3",
+    "lao3",
+    "jiang3jin1",
+    "jing1li4	jing1li3	jing1li4",
+    "li4ru2",
+    "zhui1",
+    "shan4yu2",
+    "tiao0zheng3",
+    "jie2mu4",
+    "gan3xie4",
+    "shang1xin1",
+    "bu4shao3",
+    "di4li3",
+    "xian3shi4	xian4shi2",
+    "fang2dong1",
+    "hao3chi1",
+    "bo2zi3",
+    "li3mao4",
+    "bi4mian3",
+    "di4dao4",
+    "zui4jin4",
+    "ke3yi3",
+    "zheng1	zheng1	zheng4",
+    "shi1wang4",
+    "qu3xiao1",
+    "gu1ji4",
+    "zhu0ming2",
+    "peng2you5",
+    "chi2xu4",
+    "shuo1fu2",
+    "you2zha4",
+    "zai4	zai4",
+    "wai4	wai1",
+    "guan1jun1",
+    "sha1	sha3",
+    "zu3cheng2",
+    "nv3hai2",
+    "hei1",
+    "wen4ti2",
+    "pan4duan4",
+    "tai4tai4",
+    "kao3ya1",
+    "kai1",
+    "zhan4zheng1",
+    "suo3you3",
+    "bing1xiang1",
+    "ya0",
+    "xue2shu4",
+    "bian4lun4",
+    "shu1fu2",
+    "xing2	xing4	xing3",
+    "lie4che1",
+    "ren4he2",
+    "dan1chun2",
+    "zhou1wei2",
+    "yu3fa3",
+    "dan1wu4",
+    "dui4bu5qi3",
+    "wu1zi5",
+    "kao3shi4",
+    "nong4	nong2",
+    "zheng4que4",
+    "gong1chang3",
+    "feng1ge2",
+    "qi3chuang2",
+    "cao1chang3",
+    "shun4li4",
+    "zun1jing4",
+    "gao1ji2",
+    "zhu3guan1",
+    "kuan1",
+    "ran2er2",
+    "bu4yao4jin3",
+    "jie2gou4",
+    "lao2jia4",
+    "dui4dai4",
+    "fu4nv3",
+    "tian1zhen1",
+    "kai1shi3",
+    "qu1shi4	qu4shi4",
+    "cheng2ken3",
+    "zuo4jia1",
+    "nei4rong2",
+    "ka1fei1",
+    "xian4mu4",
+    "kong3zi3",
+    "xin1ku3",
+    "lu4di4",
+    "tiao2jian4",
+    "gong1zuo4",
+    "shi1mian2",
+    "jian3dan1",
+    "zhu4ce4",
+    "xiang4lian4",
+    "dian4ying3",
+    "zhao1pin4",
+    ">",
+    "jiao3hua2",
+    "huai2yi2",
+    "jing1ying2",
+    "da4gai4",
+    "guan1xi4",
+    "peng4	peng2",
+    "shu1ru4",
+    "guo4fen1",
+    "fa1sheng1",
+    "tao2	tao4	tao2",
+    "cai3hong2",
+    "wang2zi3",
+    "hao3	hao4",
+    "hai4pa4",
+    "ji4lu4	ji4lu4",
+    "gui1ze2",
+    "bo1fang4",
+    "shun4bian4",
+    "guai4bu5de5",
+    "can1ting1",
+    "guang3fan4",
+    "xian4jin1",
+    "du4zi3",
+    "en0",
+    "gong1yuan2	gong1yuan2",
+    "ji3nan2",
+    "chi1jing1",
+    "ju4hui4",
+    "bao4qian4",
+    "kan4jian4",
+    "qin1qie4",
+    "zhu3ti2",
+    "tan2	tan2",
+    "chun1jie2",
+    "ji4jie2",
+    "huo3che1zhan4",
+    "lao2dong4",
+    "sheng1zhang3",
+    "niu2jin1da4xue2",
+    "fu4za2",
+    "fang1bian4",
+    "she3bu4de2",
+    "gong1neng2",
+    "she4hui4",
+    "zi4hao2",
+    "can1jia1",
+    "mu4lu4",
+    "hao3qi2",
+    "ren2sheng1",
+    "wen1rou2",
+    "ren4shi5	ren2shi4",
+    "shao2zi3",
+    "wai4mian4",
+    "shi2dai4",
+    "shou4shang1",
+    "fu4jin4",
+    "fang4qi4",
+    "bu4jian4de2",
+    "tan3shuai4",
+    "xue2xiao4",
+    "mei2ti3",
+    "yin4jia1",
+    "rong2yi4",
+    "jia4shi3",
+    "ji2	ji2	ji4	ji3	ji1	ji4	ji3",
+    "fu4qin1",
+    "er2zi5",
+    "pan1jia1yuan2",
+    "she4ying3",
+    "da3suan4",
+    "shu1	shu4	shu4	shu1",
+    "qing1zhuang4nian2",
+    "zhi4du4",
+    "gu3tou2",
+    "da3zhao1hu1",
+    "chi3zi5",
+    "jin3guan3",
+    "jiang4you2",
+    "zuo4zhan4",
+    "gai3ge2",
+    "qu4nian2",
+    "dai4biao3",
+    "qi3	qi1	qi2	qi2",
+    "zi4	zi3",
+    "zhu4	zhu1	zhu3	zhu4",
+    "wei1hai4",
+    "xian3ran2",
+    "qi1dai4",
+    "wan2zheng3",
+    "chang2shi2",
+    "ming2bai2",
+    "zheng3li3",
+    "cheng2zhang3",
+    "ma2fan2",
+    "si0hu1",
+    "ci4",
+    "gu3wu3",
+    "fa1",
+    "mo2tuo1che1",
+    "bie2ren2",
+    "yi1yuan4",
+    "ru4kou3",
+    ":",
+    "jian1ku3",
+    "fa1xian4",
+    "jie2he2",
+    "ji1dong4",
+    "gan3dong4",
+    "yi3hou4",
+    "te4se4",
+    "you4bian5",
+    "gao4bie2",
+    "guo3ran2",
+    "ci2dian3",
+    "guan1cha2",
+    "xue2	xue3",
+    "ying1yong4",
+    "nar3	nar0",
+    "shang4ban1",
+    "pi2lao2",
+    "gun3	gun4",
+    "jiao1liu2",
+    "ge4zi4	ge4zi3",
+    "zhan4	zhan4",
+    "cha4yi4",
+    "shang1liang4",
+    "hui1chen2",
+    "min3gan3",
+    "he2li3",
+    "zong3gong4",
+    "guo4min3",
+    "ti2qian2",
+    "pei4he2",
+    "ying2yang3",
+    "ju4bei4",
+    "shen1cai2",
+    "shi1bai4",
+    "sui4	sui4",
+    "lv4	lv3",
+    "da3ting1",
+    "ji2mang2",
+    "ying1jun4",
+    "an4	an4	an4",
+    "han2jia3",
+    "teng2xun4",
+    "xin1zang4",
+    "shan4zi5",
+    "mei2xi1",
+    "zong3shi4",
+    "gong1ju4",
+    "zui4hou4",
+    "dui4xiang4",
+    "\\",
+    "shang4wu3	shang1wu4",
+    "wei4yu2",
+    "yan2su4",
+    "huo2yue4",
+    "cheng2wei4",
+    "li3xiang3",
+    "zheng4ming2",
+    "ji1dan4",
+    "xiang1si0",
+    "jie4zhi5",
+    "qi4wen1",
+    "ping2guo3",
+    "re4ai4",
+    "chong1fen1",
+    "shu1jia4",
+    "cong2er2",
+    "chao3jia4",
+    "men2",
+    "hou4mian4",
+    "wu2	wu3	wu1	wu4	wu4",
+    "da4xue2",
+    "ping2shi2",
+    "yin1wei4",
+    "ti3xian4",
+    "he2xin1",
+    "zao1gao1",
+    "shen1bian1",
+    "shua1ya2",
+    "po4qie4",
+    "ma3ke4",
+    "mei3de2",
+    "ai4qing2",
+    "biao3shi4",
+    "xuan1chuan2",
+    "si1ji1",
+    "ming2que4",
+    "jia1wu4",
+    "qing1wa1",
+    "lian2po1",
+    "chou1	chou4	chou3",
+    "wei2yi1",
+    "guang1lin2",
+    "ji4ran2",
+    "bao1guo3",
+    "ci2hui4",
+    "chong1",
+    "pi2fu1",
+    "kong1jian1",
+    "dan1diao4",
+    "pi2qi4",
+    "yin1su4",
+    "gong1ping2",
+    "lan2gan1",
+    "hu2die2",
+    "shi1fei2",
+    "zhi4yuan4zhe3",
+    "ji2le0",
+    "chou1ti5",
+    "fen1fen1",
+    "ba0chengr0",
+    "gu4shi4",
+    "guan1zhu4",
+    "di4qu1",
+    "mao4yi4",
+    "yin1ci3",
+    "wu2fa3",
+    "er2tong2",
+    "tou4ming2",
+    "neng2gan4",
+    "shi2mao2",
+    "jing1dian3",
+    "cheng2xin4",
+    "ping1pang1qiu2",
+    "zao4cheng2",
+    "ke4ting1",
+    "xiao1ji2",
+    "jiang4di1",
+    "tu1chu1",
+    "ren2cai2",
+    "guang1xian4",
+    "gan3shou4",
+    "xin4feng1",
+    "kai1fang4",
+    "yue1hui4",
+    "jiao1qu1",
+    "ren4zhen1",
+    "na3pa4",
+    "mai4ke4feng1",
+    "dui4bi3",
+    "zeng1zhang3",
+    ".",
+    "gang1tie3",
+    "cheng2shi4	cheng2shi2",
+    "ge1wu3",
+    "dao4da2",
+    "shou1shi2",
+    "gu3piao4",
+    "biao3mian4",
+    "wang3qiu2",
+    "ri4qi1",
+    "mi4shu1",
+    "li3bai4tian1",
+    "lang4fei4",
+    "nuan3huo0",
+    "ling2shi2",
+    "guang3he2ju1",
+    "hai3li3",
+    "ming2pai2",
+    "you3yi4",
+    "diu1",
+    "wen2xue2jia1",
+    "huo4xu3",
+    "zher0",
+    "xiang1tong2",
+    "ming2xing1",
+    "mei2guan1xi5",
+    "ji1ben3",
+    "gu4ding4",
+    "zhuan3gao4",
+    "zhen4dong4",
+    "shou3biao3",
+    "li4yi4",
+    "che4di3",
+    "dan1xin1",
+    "ya1li4",
+    "cuo4shi1",
+    "jian3cha2",
+    "huan2bao3",
+    "ping2jun1",
+    "shi2fen1",
+    "wen1nuan3",
+    "sun1zi3",
+    "ban4	ban1	ban1	ban1",
+    "hao4ma3",
+    "mi4mi4",
+    "ding3	ding1",
+    "<shuo1wen2jie3zi4>",
+    "cong2qian2",
+    "guan3li3",
+    "wu4zhi4",
+    "dang1ran2",
+    "zhou1",
+    "pian4mian4",
+    "cha4ju4",
+    "jie1duan4",
+    "sui2bian4",
+    "li3cha2de2xi1er3si1",
+    "shan3dian4",
+    "ti3hui4",
+    "sha1mo4",
+    "shi4chang3",
+    "ge2bi4",
+    "yue4du2",
+    "yi3wei4",
+    "jie3jue2",
+    "ming4yun4",
+    "li4zi3",
+    "nian2ji4	nian2ji2",
+    "mei3hao3",
+    "qing1shi4",
+    "can2kui4",
+    "xi1wang4",
+    "xue2qi1",
+    "si1tan3fu2",
+    "ji4mo4",
+    "yin4xiang4",
+    "xian1sheng5",
+    "fu2	fu4	fu2",
+    "biao3yang2",
+    "xiang3xiang4",
+    "da2an4",
+    "huan4xiang3",
+    "wei4zhi4",
+    "bin1guan3",
+    "dang1xin1",
+    "you2yu2	you2yu4",
+    "bao3liu2",
+    "qing2xu4",
+    "tui1chi2",
+    "mu4biao1",
+    "chang2tu2",
+    "yu3mao2qiu2",
+    "jin1nian2",
+    "ku4zi3",
+    "fa1zhan3",
+    "hao3ke4",
+    "you2xi4",
+    "jun1shi4",
+    "zhuang1	zhuang4",
+    "ren4wu4	ren2wu4",
+    "gan4bei1",
+    "jin4zhi3",
+    "chang2	chang3	chang2",
+    "xi3zao3",
+    "shi2xian4	shi4xian1",
+    "qi3fei1",
+    "jue2xin1",
+    "jing1yan4",
+    "shen1ke4",
+    "wan2shan4",
+    "xiang4zheng1",
+    "shi4ji4	shi2ji4",
+    "fen1xi1",
+    "yi1hui4er2",
+    "lun4wen2",
+    "mei2mao5",
+    "wang3luo4",
+    "sa3",
+    "lia3",
+    "ke3jian4",
+    "ma3shang4",
+    "fang4zong4",
+    "de0	de0	de2",
+    "dui4fang1",
+    "gen1	gen1",
+    "bu4ru2",
+    "ben3lai2",
+    "shuo1hua4",
+    "shang4",
+    "mao4zi3",
+    "niao3",
+    "kuai4	kuai4",
+    "<na4han3>",
+    "tuan2",
+    "yuan2lai2",
+    "you3dian3	you1dian3",
+    "fa1biao3",
+    "jiang3zuo4",
+    "sui1ran2",
+    "yao4qiu2",
+    "chu1yuan4",
+    "zhu2jian4",
+    "zhu3ren4	zhu3ren2",
+    "hui2lai2",
+    "shi4fou3",
+    "jie1chu4",
+    "biao1dian3",
+    "xiu1gai3",
+    "qi3ye4",
+    "chu2le0",
+    "wo3men5",
+    "te4bie2",
+    "tan0gang1qin2",
+    "ming2zi5",
+    "bu4an1",
+    "qiu2mi2",
+    "fa3lv4",
+    "li4ke4",
+    "fan3xing0",
+    "ben3	ben4",
+    "shuo1bu4ding4",
+    "qing4zhu4",
+    "se4cai3",
+    "guang1ming2",
+    "liao0jie3",
+    "ni3",
+    "zhan4xian4",
+    "ou1zhou1huan2jing4bao3hu4shu3",
+    "xiang1chu4",
+    "chuan2shuo1",
+    "ling4wai4",
+    "ji4suan4",
+    "jia3she4",
+    "kuo4",
+    "liu2lei4",
+    "xing1xing1",
+    "yi1bian1",
+    "sang3zi5",
+    "fen1xiang3",
+    "zao3",
+    "yun3xu3",
+    "qian1xu1",
+    "deng3dai4",
+    "chan3sheng1",
+    "bo2shi4",
+    "cao3",
+    "hu1shi4	hu4shi4",
+    "liu2xing2",
+    "zen3me0ban4",
+    "pian4	pian1	pian4",
+    "zong3zhi1",
+    "ru2he2",
+    "duo1me0",
+    "zhong3|zhong4	zhong4	zhong1",
+    "kuai4le4",
+    "dao4qian4",
+    "fang1shi4",
+    "liang3	liang4	liang4",
+    "cong2shi4",
+    "you1mo4",
+    "nin2",
+    "di1	di3	di4	di1",
+    "shuo1",
+    "que4shi2",
+    "cheng2ren4	cheng2ren2",
+    "shi4ying1",
+    "<fei1ni3mo4shu3>",
+    "te4dian3",
+    "yi1zhi2	yi1zhi4",
+    "chou1yan1",
+    "wei4shen2me5",
+    "pu3bian4",
+    "ting1	ting2	ting3",
+    "wan2quan2",
+    "cheng2du4",
+    "you2yong3",
+    "feng1kuang2",
+    "sheng1wu4zhong1",
+    "li4liang4",
+    "tong1zhi1",
+    "wen4hou4",
+    "duan3xin4",
+    "te4zheng1",
+    "zhuang1shi4",
+    "que4ding4",
+    "fa1da2",
+    "ju4zi3	ju2zi3	ju2zi5	ju4zi5",
+    "gong1ren2",
+    "you1xiu4",
+    "sheng1chan3",
+    "zi4xing2che1",
+    "li3fa1dian4",
+    "zi4ji3",
+    "zan4mei3",
+    "liang2shi2",
+    "shi4dang1",
+    "da3sao3",
+    "dao4chu4",
+    "qi2te4",
+    "bu4men2",
+    "kou3wei4",
+    "guo2ji4	guo2ji2",
+    "si3	si4	si1	si1	si1",
+    "gong1kai1",
+    "can1guan1",
+    "bing4du2",
+    "zi1xun2",
+    "he2zuo4",
+    "fang1an4",
+    "zi1yuan2	zi4yuan4",
+    "huang2he2",
+    "xiao3chi1",
+    "yi1fu5",
+    "chun1qiu1",
+    "xun4lian4",
+    "xia4	xia4	xia4	xia1",
+    "ai4di2sheng1",
+    "chui1",
+    "fu4kuan3",
+    "zong3suan4",
+    "zai1hai4",
+    "xiang1fan3",
+    "ti3yan4",
+    "you3li4",
+    "hou4hui3",
+    "li3fa1",
+    "jin1shi2wei2kai1",
+    "lun2liu2",
+    "gu1gu1",
+    "shuo1ming2",
+    "shi1zi5",
+    "xi4ju4",
+    "zheng4chang2",
+    "wei2jin1",
+    "tou2lan2",
+    "bao4	bao1	bao3	bao2	bao3",
+    "zhao1san1mu4si4",
+    "zhu4he4",
+    "xi1	xi4	xi3	xi1",
+];
+
+// Choices in CIYU spelled with traditional characters, in the same order
+pub static CIYU_HANT: &[&str] = &[
+    "弱",  // ruo
+    "悲觀",  // beiguan
+    "收穫",  // shouhuo
+    "廂房",  // xiangfang
+    "規模",  // guimo
+    "玉米",  // yumi
+    "哲理",  // zheli
+    "老鷹",  // laoying
+    "日記",  // riji
+    "香蕉",  // xiangjiao
+    "臨時",  // linshi
+    "損失",  // sunshi
+    "胡同",  // hutong
+    "直接",  // zhijie
+    "興趣",  // xingqu
+    "成語",  // chengyu
+    "膠水",  // jiaoshui
+    "才	菜	猜	踩",  // cai
+    "誇張",  // kuazhang
+    "醜陋",  // choulou
+    "旺盛",  // wangsheng
+    "悄悄",  // qiaoqiao
+    "摔倒",  // shuaidao
+    "姥姥",  // laolao
+    "再三",  // zaisan
+    "對	堆",  // dui
+    "性別",  // xingbie
+    "悠悠",  // youyou
+    "花生",  // huasheng
+    "前	錢	千	淺	欠	簽",  // qian
+    "數字	梳子",  // shuzi
+    "湯	糖	躺	燙	趟",  // tang
+    "分鐘",  // fenzhong
+    "承受",  // chengshou
+    "類型",  // leixing
+    "角色",  // juese
+    "舉辦",  // juban
+    "好處",  // haochu
+    "繁榮",  // fanrong
+    "接著",  // jiezhe
+    "乘坐",  // chengzuo
+    "一旦",  // yidan
+    "寵物",  // chongwu
+    "語氣",  // yuqi
+    "放心",  // fangxin
+    "微博",  // weibo
+    "壺	糊",  // hu
+    "日用品",  // riyongpin
+    "壽命",  // shouming
+    "辦事",  // banshi
+    "魯迅",  // luxun
+    "農業",  // nongye
+    "地球",  // diqiu
+    "壞",  // huai
+    "便	變	遍",  // bian
+    "年	念",  // nian
+    "攝入",  // sheru
+    "距離",  // juli
+    "推薦",  // tuijian
+    "狀態",  // zhuangtai
+    "厲害",  // lihai
+    "忍不住",  // renbuzhu
+    "建築",  // jianzhu
+    "公共汽車",  // gonggongqiche
+    "實話",  // shihua
+    "拒絕",  // jujue
+    "行人",  // xingren
+    "設計	射擊",  // sheji
+    "廚房",  // chufang
+    "身體",  // shenti
+    "手術",  // shoushu
+    "偶然",  // ouran
+    "潮濕	超市",  // chaoshi
+    "不假思索",  // bujiasisuo
+    "出現",  // chuxian
+    "感想",  // ganxiang
+    "行業",  // hangye
+    "最	嘴	醉",  // zui
+    "專門",  // zhuanmen
+    "春遊",  // chunyou
+    "次要",  // ciyao
+    "煩惱",  // fannao
+    "科學",  // kexue
+    "優美",  // youmei
+    "保持",  // baochi
+    "老闆",  // laoban
+    "麥布里奇",  // maibuliqi
+    "茶	差	插",  // cha
+    "現代",  // xiandai
+    "家庭",  // jiating
+    "稍微",  // shaowei
+    "寶貝",  // baobei
+    "面臨",  // mianlin
+    "狀況",  // zhuangkuang
+    "美麗	魅力",  // meili
+    "力氣",  // liqi
+    "縮短",  // suoduan
+    "整齊",  // zhengqi
+    "胃口",  // weikou
+    "盲人",  // mangren
+    "幾乎",  // jihu
+    "豐富",  // fengfu
+    "確認",  // queren
+    "俱樂部",  // julebu
+    "完成",  // wancheng
+    "不客氣",  // bukeqi
+    "結束",  // jieshu
+    "堅決",  // jianjue
+    "程序",  // chengxu
+    "沒	每	美",  // mei
+    "粗心",  // cuxin
+    "展覽",  // zhanlan
+    "迅速",  // xunsu
+    "加油站",  // jiayouzhan
+    "趙奢",  // zhaoshe
+    "歷史",  // lishi
+    "範圍",  // fanwei
+    "奇怪",  // qiguai
+    "感到",  // gandao
+    "半夜",  // banye
+    "煤炭",  // meitan
+    "傳統",  // chuantong
+    "科技",  // keji
+    "平方",  // pingfang
+    "良好",  // lianghao
+    "無奈",  // wunai
+    "甚至",  // shenzhi
+    "過期",  // guoqi
+    "激烈",  // jilie
+    "盤子",  // panzi
+    "中午",  // zhongwu
+    "古代",  // gudai
+    "愛好",  // aihao
+    "開玩笑",  // kaiwanxiao
+    "精誠所至",  // jingchengsuozhi
+    "內科",  // neike
+    "一連",  // yilian
+    "來自",  // laizi
+    "開幕式",  // kaimushi
+    "舞蹈",  // wudao
+    "在乎",  // zaihu
+    "無意",  // wuyi
+    "地點",  // didian
+    "難受",  // nanshou
+    "引起",  // yinqi
+    "優勢",  // youshi
+    "層",  // ceng
+    "餃子",  // jiaozi
+    "重視",  // zhongshi
+    "錯",  // cuo
+    "積累",  // jilei
+    "研究",  // yanjiu
+    "樂觀",  // leguan
+    "平衡",  // pingheng
+    "出口",  // chukou
+    "恭喜",  // gongxi
+    "歡迎",  // huanying
+    "旁邊",  // pangbian
+    "車廂",  // chexiang
+    "海洋",  // haiyang
+    "無聊",  // wuliao
+    "攝影師",  // sheyingshi
+    "脫",  // tuo
+    "謹慎",  // jinshen
+    "他	她	它",  // ta
+    "工程師",  // gongchengshi
+    "安排",  // anpai
+    "北京師範大學",  // beijingshifandaxue
+    "漢語",  // hanyu
+    "幸運",  // xingyun
+    "小雨",  // xiaoyu
+    "有趣",  // youqu
+    "為	位	喂	胃",  // wei
+    "怎麼",  // zenme
+    "尊重",  // zunzhong
+    "弟弟",  // didi
+    "中間",  // zhongjian
+    "單元",  // danyuan
+    "展開",  // zhankai
+    "吃",  // chi
+    "空調",  // kongtiao
+    "愛	唉	矮	哎",  // ai
+    "缺點",  // quedian
+    "理由",  // liyou
+    "牙膏",  // yagao
+    "導演",  // daoyan
+    "中文",  // zhongwen
+    "人口",  // renkou
+    "有限",  // youxian
+    "業餘",  // yeyu
+    "告訴",  // gaosu
+    "老虎",  // laohu
+    "櫃台",  // guitai
+    "胡說",  // hushuo
+    "佩·詹森",  // peizhansen
+    "日程",  // richeng
+    "獨特",  // dute
+    "孝順",  // xiaoshun
+    "打擾",  // darao
+    "需要",  // xuyao
+    "只要",  // zhiyao
+    "錢包",  // qianbao
+    "有點兒",  // youdianr
+    "佩·詹森",  // pei`zhansen
+    "敘述",  // xushu
+    "格外",  // gewai
+    "自殺",  // zisha
+    "這些",  // zhexie
+    "難過",  // nanguo
+    "競爭",  // jingzheng
+    "要不",  // yaobu
+    "甩	帥",  // shuai
+    "發燒",  // fashao
+    "垃圾",  // laji
+    "馬虎",  // mahu
+    "建立	簡歷",  // jianli
+    "·",  // `
+    "商店",  // shangdian
+    "少	勺",  // shao
+    "幸虧",  // xingkui
+    "合適",  // heshi
+    "昆蟲",  // kunchong
+    "夾子",  // jiazi
+    "報名",  // baoming
+    "觀念",  // guannian
+    "稱呼",  // chenghu
+    "尾巴",  // weiba
+    "導遊",  // daoyou
+    "感覺",  // ganjue
+    "聊天兒",  // liaotianr
+    "夥伴",  // huoban
+    "分配",  // fenpei
+    "窮",  // qiong
+    "標準",  // biaozhun
+    "保險",  // baoxian
+    "集體",  // jiti
+    "制定	制訂",  // zhiding
+    "姿勢",  // zishi
+    "蔭涼",  // yinliang
+    "天	甜	填",  // tian
+    "徵求",  // zhengqiu
+    "總結",  // zongjie
+    "主要",  // zhuyao
+    "質量",  // zhiliang
+    "照相機",  // zhaoxiangji
+    "一下",  // yixia
+    "（",  // (
+    "橡皮",  // xiangpi
+    "一會兒",  // yihuir
+    "三	傘",  // san
+    "聲調",  // shengdiao
+    "老師	老實",  // laoshi
+    "粗糙",  // cucao
+    "乾淨",  // ganjing
+    "全面",  // quanmian
+    "導致",  // daozhi
+    "黃	慌",  // huang
+    "冊",  // ce
+    "前途",  // qiantu
+    "注意	主意",  // zhuyi
+    "自私",  // zisi
+    "挑戰",  // tiaozhan
+    "生活",  // shenghuo
+    "始終",  // shizhong
+    "思想",  // sixiang
+    "女孩兒",  // nvhair
+    "襯衫",  // chenshan
+    "京劇",  // jingju
+    "具體",  // juti
+    "吸取",  // xiqu
+    "作業",  // zuoye
+    "把握",  // bawo
+    "使勁",  // shijin
+    "一	以	億	乙",  // yi
+    "毛巾",  // maojin
+    "比爾·蓋茨",  // bier`gaici
+    "流利",  // liuli
+    "巧妙",  // qiaomiao
+    "重大",  // zhongda
+    "裙子",  // qunzi
+    "密切",  // miqie
+    "廢話",  // feihua
+    "設施",  // sheshi
+    "嗎	馬	罵",  // ma
+    "）",  // )
+    "冷",  // leng
+    "土地",  // tudi
+    "賬戶",  // zhanghu
+    "居然",  // juran
+    "到底",  // daodi
+    "香山",  // xiangshan
+    "要是	鑰匙",  // yaoshi
+    "口",  // kou
+    "請願書",  // qingyuanshu
+    "拉	啦	辣",  // la
+    "踢足球",  // tizuqiu
+    "入圍",  // ruwei
+    "恩愛",  // enai
+    "帶	戴	呆	袋",  // dai
+    "你們",  // nimen
+    "語言	寓言",  // yuyan
+    "樓	漏",  // lou
+    "得意",  // deyi
+    "繼續	積蓄",  // jixu
+    "絲綢",  // sichou
+    "人名",  // renming
+    "製作",  // zhizuo
+    "商品",  // shangpin
+    "鍵盤",  // jianpan
+    "叔叔",  // shushu
+    "假裝",  // jiazhuang
+    "相對",  // xiangdui
+    "總理",  // zongli
+    "資格",  // zige
+    "木頭",  // mutou
+    "利用",  // liyong
+    "題目",  // timu
+    "然後",  // ranhou
+    "原因",  // yuanyin
+    "嘉賓",  // jiabin
+    "喊	汗",  // han
+    "黑猩猩",  // heixingxing
+    "面試",  // mianshi
+    "抱怨",  // baoyuan
+    "捐",  // juan
+    "能力",  // nengli
+    "可能",  // keneng
+    "新	信",  // xin
+    "所謂",  // suowei
+    "隨手",  // suishou
+    "楚國",  // chuguo
+    "諾貝爾獎",  // nuobeierjiang
+    "究竟",  // jiujing
+    "文具",  // wenju
+    "動畫片",  // donghuapian
+    "只好",  // zhihao
+    "彼此",  // bici
+    "相信",  // xiangxin
+    "破壞",  // pohuai
+    "後	厚",  // hou
+    "被子	杯子",  // beizi
+    "律師",  // lvshi
+    "冷淡",  // lengdan
+    "休息",  // xiuxi
+    "成熟",  // chengshu
+    "在於",  // zaiyu
+    "積極",  // jiji
+    "如今",  // rujin
+    "全神貫注",  // quanshenguanzhu
+    "加油",  // jiayou
+    "浪漫",  // langman
+    "認為",  // renwei
+    "青少年",  // qingshaonian
+    "秩序",  // zhixu
+    "豪華",  // haohua
+    "緊張",  // jinzhang
+    "切",  // qie
+    "報道	報到",  // baodao
+    "落後",  // luohou
+    "桌子",  // zhuozi
+    "起居",  // qiju
+    "義務",  // yiwu
+    "情況",  // qingkuang
+    "慌張",  // huangzhang
+    "抓緊",  // zhuajin
+    "北京	背景",  // beijing
+    "旅行",  // lvxing
+    "盆",  // pen
+    "作品",  // zuopin
+    "零件",  // lingjian
+    "適合",  // shihe
+    "天然",  // tianran
+    "翻譯",  // fanyi
+    "丈夫",  // zhangfu
+    "交際",  // jiaoji
+    "遲早",  // chizao
+    "討論",  // taolun
+    "大使館",  // dashiguan
+    "護國寺",  // huguosi
+    "採取",  // caiqu
+    "表明",  // biaoming
+    "改進",  // gaijin
+    "粉色",  // fense
+    "路",  // lu
+    "可靠",  // kekao
+    "光盤",  // guangpan
+    "鼻子",  // bizi
+    "香腸",  // xiangchang
+    "年齡",  // nianling
+    "污染",  // wuran
+    "窗簾",  // chuanglian
+    "主動",  // zhudong
+    "清淡",  // qingdan
+    "和	河	喝",  // he
+    "管子	館子",  // guanzi
+    "有意思",  // youyisi
+    "留守",  // liushou
+    "代言",  // daiyan
+    "廣場",  // guangchang
+    "硬件",  // yingjian
+    "平等",  // pingdeng
+    "忽然",  // huran
+    "忘記",  // wangji
+    "警察",  // jingcha
+    "太極拳",  // taijiquan
+    "絕對",  // juedui
+    "意義",  // yiyi
+    "行李箱",  // xinglixiang
+    "收據",  // shouju
+    "月	約	越",  // yue
+    "原料",  // yuanliao
+    "孩子",  // haizi
+    "著火",  // zhaohuo
+    "答應",  // daying
+    "大夫",  // daifu
+    "簡直	兼職",  // jianzhi
+    "帆船",  // fanchuan
+    "減輕",  // jianqing
+    "自動",  // zidong
+    "啊",  // a
+    "找	招	照",  // zhao
+    "艱巨",  // jianju
+    "盡力",  // jinli
+    "妹妹",  // meimei
+    "不得不",  // budebu
+    "手工",  // shougong
+    "介紹",  // jieshao
+    "女",  // nv
+    "更",  // geng
+    "尤其	與其",  // youqi
+    "一律",  // yilv
+    "絲毫",  // sihao
+    "發揮",  // fahui
+    "危險",  // weixian
+    "《賣火柴的小女孩兒》",  // <maihuochaidexiaonvhaier>
+    "記者",  // jizhe
+    "計劃",  // jihua
+    "打工",  // dagong
+    "秒",  // miao
+    "雙",  // shuang
+    "意思",  // yisi
+    "哥哥",  // gege
+    "優惠",  // youhui
+    "公里",  // gongli
+    "肩膀",  // jianbang
+    "構成",  // goucheng
+    "並且",  // bingqie
+    "風險",  // fengxian
+    "戀愛",  // lianai
+    "所以",  // suoyi
+    "減少",  // jianshao
+    "離婚",  // lihun
+    "外公",  // waigong
+    "安慰",  // anwei
+    "看法",  // kanfa
+    "重要",  // zhongyao
+    "含意",  // hanyi
+    "按照",  // anzhao
+    "倒霉",  // daomei
+    "蹄子",  // tizi
+    "牙齒",  // yachi
+    "信號",  // xinhao
+    "電台",  // diantai
+    "省略",  // shenglve
+    "生病",  // shengbing
+    "深淵",  // shenyuan
+    "葉子",  // yezi
+    "測驗",  // ceyan
+    "爸爸",  // baba
+    "幹活兒",  // ganhuor
+    "其實",  // qishi
+    "掛號",  // guahao
+    "陽光",  // yangguang
+    "礦泉水",  // kuangquanshui
+    "新鮮",  // xinxian
+    "不耐煩",  // bunaifan
+    "洗手間",  // xishoujian
+    "其餘",  // qiyu
+    "反正",  // fanzheng
+    "存在",  // cunzai
+    "下面",  // xiamian
+    "掉	釣",  // diao
+    "機器	極其",  // jiqi
+    "拐彎",  // guaiwan
+    "後背",  // houbei
+    "饅頭",  // mantou
+    "件	箭	減	撿",  // jian
+    "醫生",  // yisheng
+    "生命",  // shengming
+    "小時	消失",  // xiaoshi
+    "實習",  // shixi
+    "顧客",  // guke
+    "嫩",  // nen
+    "相關",  // xiangguan
+    "水平",  // shuiping
+    "寫作",  // xiezuo
+    "冰激凌",  // bingjiling
+    "表演",  // biaoyan
+    "講究",  // jiangjiu
+    "筷子",  // kuaizi
+    "趙國",  // zhaoguo
+    "職業",  // zhiye
+    "關心",  // guanxin
+    "幸福",  // xingfu
+    "委屈",  // weiqu
+    "筆記本",  // bijiben
+    "上網",  // shangwang
+    "何必",  // hebi
+    "不錯",  // bucuo
+    "電池",  // dianchi
+    "謝謝",  // xiexie
+    "擁擠",  // yongji
+    "信心",  // xinxin
+    "趵突泉",  // baotuquan
+    "一樣",  // yiyang
+    "空",  // kong
+    "汽油",  // qiyou
+    "夏令營",  // xialingying
+    "證據",  // zhengju
+    "曬",  // shai
+    "感情",  // ganqing
+    "內部",  // neibu
+    "交通",  // jiaotong
+    "南	難	男",  // nan
+    "算	酸",  // suan
+    "無所謂",  // wusuowei
+    "菜市口",  // caishikou
+    "老舍",  // laoshe
+    "懷孕",  // huaiyun
+    "塗鴉",  // tuya
+    "守歲",  // shousui
+    "漂亮",  // piaoliang
+    "信息",  // xinxi
+    "實驗",  // shiyan
+    "主持",  // zhuchi
+    "黑板",  // heiban
+    "打噴嚏",  // dapenti
+    "鉛筆",  // qianbi
+    "合格",  // hege
+    "米飯",  // mifan
+    "沙發",  // shafa
+    "退	推	腿",  // tui
+    "條",  // tiao
+    "哪裡",  // nali
+    "擁抱",  // yongbao
+    "偷",  // tou
+    "會計",  // kuaiji
+    "關於",  // guanyu
+    "新年",  // xinnian
+    "選擇",  // xuanze
+    "農村",  // nongcun
+    "軟",  // ruan
+    "抽象",  // chouxiang
+    "與	於	魚	雨",  // yu
+    "方法",  // fangfa
+    "靠",  // kao
+    "善良",  // shanliang
+    "過去",  // guoqu
+    "科恩",  // keen
+    "達到",  // dadao
+    "生動",  // shengdong
+    "資料",  // ziliao
+    "困擾",  // kunrao
+    "出版",  // chuban
+    "街道",  // jidao
+    "聽說",  // tingshuo
+    "鼠標",  // shubiao
+    "聲音",  // shengyin
+    "治療",  // zhiliao
+    "亞洲",  // yazhou
+    "批准",  // peizhun
+    "必要",  // biyao
+    "感激",  // ganji
+    "錄取",  // luqu
+    "事實",  // shishi
+    "轉變",  // zhuanbian
+    "擔任",  // danren
+    "圖	吐",  // tu
+    "疑問",  // yiwen
+    "少許",  // shaoxu
+    "《非你莫屬》",  // feinimoshu
+    "可憐",  // kelian
+    "車禍",  // chehuo
+    "深	伸",  // shen
+    "等	燈",  // deng
+    "規律",  // guilv
+    "校園",  // xiaoyuan
+    "存",  // cun
+    "經過",  // jingguo
+    "一定",  // yiding
+    "老鼠",  // laoshu
+    "爺爺",  // yeye
+    "沒有",  // meiyou
+    "投入",  // touru
+    "根據",  // genju
+    "時期",  // shiqi
+    "退步",  // tuibu
+    "哈",  // ha
+    "幹	敢	趕	桿	擀",  // gan
+    "隨時",  // suishi
+    "雲	暈",  // yun
+    "古典",  // gudian
+    "狂",  // kuang
+    "單獨",  // dandu
+    "生意",  // shengyi
+    "嚴格",  // yange
+    "請	輕	青	晴",  // qing
+    "全部",  // quanbu
+    "誰",  // shei
+    "勇氣",  // yongqi
+    "聯合",  // lianhe
+    "保護",  // baohu
+    "知識",  // zhishi
+    "信任",  // xinren
+    "證件",  // zhengjian
+    "傳染",  // chuanran
+    "頻道",  // pindao
+    "近代",  // jindai
+    "排隊",  // paidui
+    "盆子",  // penzi
+    "價值",  // jiazhi
+    "依然",  // yiran
+    "鼓勵",  // guli
+    "可怕",  // kepa
+    "讀	度	堵",  // du
+    "疼",  // teng
+    "成就",  // chengjiu
+    "結果",  // jieguo
+    "邀請",  // yaoqing
+    "試卷",  // shijuan
+    "哭	苦	庫",  // ku
+    "大	打",  // da
+    "元旦",  // yuandan
+    "被	背	倍	悲",  // bei
+    "細節",  // xijie
+    "進行",  // jinxing
+    "再見",  // zaijian
+    "理解",  // lijie
+    "數據",  // shuju
+    "藉口",  // jiekou
+    "怎麼樣",  // zenmeyang
+    "控制",  // kongzhi
+    "人員",  // renyuan
+    "春",  // chun
+    "看	砍",  // kan
+    "出租車",  // chuzuche
+    "肌肉",  // jirou
+    "牙刷",  // yashua
+    "採訪",  // caifang
+    "廣大",  // guangda
+    "畢業",  // biye
+    "詹姆士·奈史密斯",  // zhanmushinaishimisi
+    "往返",  // wangfan
+    "打針",  // dazhen
+    "起來",  // qilai
+    "餐",  // can
+    "無數	武術",  // wushu
+    "年代",  // niandai
+    "鼓掌",  // guzhang
+    "穩定",  // wending
+    "正在",  // zhengzai
+    "陸續",  // luxu
+    "苗條",  // miaotiao
+    "給",  // gei
+    "繫領帶",  // jilingdai
+    "秋",  // qiu
+    "爭論",  // zhenglun
+    "得到",  // dedao
+    "胖",  // pang
+    "區別",  // qubie
+    "蘿蔔",  // luobo
+    "下雨",  // xiayu
+    "六一兒童節",  // liuyiertongjie
+    "普通",  // putong
+    "迷路",  // milu
+    "連環畫",  // lianhuanhua
+    "土豆",  // tudou
+    "習慣",  // xiguan
+    "威脅",  // weixia
+    "數量",  // shuliang
+    "女士",  // nvshi
+    "共同",  // gongtong
+    "房間",  // fangjian
+    "元	遠	圓",  // yuan
+    "均勻",  // junyun
+    "暴雨",  // baoyu
+    "陽台",  // yangtai
+    "心情",  // xinqing
+    "戰國",  // zhanguo
+    "應聘",  // yingpin
+    "規矩",  // guiju
+    "難道",  // nandao
+    "也	頁	夜",  // ye
+    "風景",  // fengjing
+    "閉關",  // biguan
+    "納入",  // naru
+    "領域",  // lingyu
+    "瀏覽",  // liulan
+    "其中",  // qizhong
+    "精神",  // jingshen
+    "將來",  // jianglai
+    "學習",  // xuexi
+    "辦理",  // banli
+    "個性",  // gexing
+    "購物",  // gouwu
+    "討價還價",  // taojiahuanjia
+    "覺得",  // juede
+    "親自",  // qinzi
+    "媽媽",  // mama
+    "；",  // ;
+    "竟然",  // jingran
+    "傍晚",  // bangwan
+    "玩具",  // wanju
+    "空兒",  // kongr
+    "作為	座位",  // zuowei
+    "飛機",  // feiji
+    "北方",  // beifang
+    "全	勸	圈",  // quan
+    "華北",  // huabei
+    "報紙",  // baozhi
+    "影子",  // yingzi
+    "長期",  // changqi
+    "產品",  // chanpin
+    "明明",  // mingming
+    "詞語",  // ciyu
+    "淋漓盡致",  // linlijinzhi
+    "連續",  // lianxu
+    "叉子",  // chazi
+    "巧克力",  // qiaokeli
+    "整個",  // zhengge
+    "明天",  // mingtian
+    "車",  // che
+    "日",  // ri
+    "竹子",  // zhuzi
+    "糊塗",  // hutu
+    "等於",  // dengyu
+    "個別",  // gebie
+    "小氣",  // xiaoqi
+    "發抖",  // fadou
+    "性格",  // xingge
+    "小朋友",  // xiaopengyou
+    "廁所",  // cesuo
+    "啟發",  // qifa
+    "婚姻",  // hunyin
+    "鬧鐘",  // naozhong
+    "奶奶",  // nainai
+    "散步",  // sanbu
+    "發票",  // fapiao
+    "限制",  // xianzhi
+    "手套",  // shoutao
+    "牛奶",  // niunai
+    "比賽",  // bisai
+    "客人",  // keren
+    "垮	誇",  // kua
+    "密碼",  // mima
+    "贊成",  // zancheng
+    "葡萄",  // putao
+    "大方",  // dafang
+    "西紅柿",  // xihongshi
+    "八成",  // bacheng
+    "眼睛	眼鏡",  // yanjing
+    "一再",  // yizai
+    "回答",  // huida
+    "麵條",  // miantiao
+    "經濟",  // jingji
+    "招兒",  // zhaor
+    "不僅",  // bujin
+    "做客",  // zuoke
+    "當	擋",  // dang
+    "據說",  // jushuo
+    "很	恨",  // hen
+    "提問",  // tiwen
+    "沉	趁",  // chen
+    "難怪",  // nanguai
+    "超過",  // chaoguo
+    "首都機場",  // shoudujichang
+    "菜單",  // caidan
+    "表情",  // biaoqing
+    "提	題	替	蹄",  // ti
+    "保存",  // baocun
+    "教室",  // jiaoshi
+    "外交",  // waijiao
+    "平	憑",  // ping
+    "顏色",  // yanse
+    "瘋",  // feng
+    "應付",  // yingfu
+    "會議	回憶",  // huiyi
+    "輕易",  // qingyi
+    "龍",  // long
+    "法院",  // fayuan
+    "里查德·希爾斯",  // lichade`xiersi
+    "味道",  // weidao
+    "改變",  // gaibian
+    "初級中學",  // chujizhongxue
+    "客觀",  // keguan
+    "真	鎮	陣",  // zhen
+    "追求",  // zhuiqiu
+    "效應",  // xiaoying
+    "濕潤",  // shirun
+    "催",  // cui
+    "剪刀",  // jiandao
+    "峰終定律",  // fengzhongdinglv
+    "圖書館",  // tushuguan
+    "現象",  // xianxiang
+    "學生",  // xuesheng
+    "香瓜",  // xianggua
+    "米",  // mi
+    "而	二",  // er
+    "方面",  // fangmian
+    "用功",  // yonggong
+    "表現",  // biaoxian
+    "於是",  // yushi
+    "雙方",  // shuangfang
+    "吃飯",  // chifan
+    "鞭炮",  // bianpao
+    "毛病",  // maobing
+    "受不了",  // shoubuliao
+    "預訂",  // yuding
+    "什麼",  // shenme
+    "熊貓",  // xiongmao
+    "搶	牆	槍",  // qiang
+    "相當",  // xiangdang
+    "因而",  // yiner
+    "鏡子",  // jingzi
+    "從",  // cong
+    "歐盟",  // oumeng
+    "微笑",  // weixiao
+    "明顯",  // mingxian
+    "反覆",  // fanfu
+    "下午",  // xiawu
+    "幼兒園",  // youeryuan
+    "花花",  // huahua
+    "刻苦",  // keku
+    "服裝",  // fuzhuang
+    "小心",  // xiaoxin
+    "知道	指導",  // zhidao
+    "高興",  // gaoxing
+    "清楚",  // qingchu
+    "根本",  // genben
+    "知更鳥",  // zhigengniao
+    "台階",  // taijie
+    "個	各",  // ge
+    "放暑假",  // fangshujia
+    "日子",  // rizi
+    "女性",  // nvxing
+    "分	份	粉",  // fen
+    "評價",  // pingjia
+    "符合",  // fuhe
+    "胳膊",  // gebo
+    "睡覺",  // shuijiao
+    "還是",  // haishi
+    "購買",  // goumai
+    "剛才",  // gangcai
+    "來得及",  // laideji
+    "準時",  // zhunshi
+    "關閉",  // guanbi
+    "玻璃",  // boli
+    "照顧",  // zhaogu
+    "紙袋",  // zhidai
+    "文化",  // wenhua
+    "英雄",  // yingxiong
+    "友好",  // youhao
+    "痛快",  // tongkuai
+    "未來",  // weilai
+    "文學",  // wenxue
+    "互聯網",  // hulianwang
+    "事情",  // shiqing
+    "不足",  // buzu
+    "對面",  // duimian
+    "鮮豔",  // xianyan
+    "項目",  // xiangmu
+    "反應	反映",  // fanying
+    "叫	教	腳	交	角	焦	澆",  // jiao
+    "熟悉",  // shuxi
+    "缺乏",  // quefa
+    "星期",  // xingqi
+    "提倡",  // tichang
+    "命令",  // mingling
+    "舉	句	局	鋸",  // ju
+    "諷刺",  // fengci
+    "步驟",  // buzhou
+    "淘氣",  // taoqi
+    "責任",  // zeren
+    "提綱",  // tigang
+    "健康",  // jiankang
+    "登機牌",  // dengjipai
+    "技術",  // jishu
+    "消費",  // xiaofei
+    "收入",  // shouru
+    "宴會",  // yanhui
+    "地方",  // difang
+    "改	蓋",  // gai
+    "逐步",  // zhubu
+    "可	克	課	刻	顆	棵	渴",  // ke
+    "景色",  // jingse
+    "生氣",  // shengqi
+    "為了",  // weile
+    "基礎",  // jichu
+    "安全",  // anquan
+    "公佈",  // gongbu
+    "可是",  // keshi
+    "垃圾桶",  // lajitong
+    "睡著",  // shuizhao
+    "整體",  // zhengti
+    "不斷",  // buduan
+    "形成",  // xingcheng
+    "美人魚",  // meirenyu
+    "奇跡",  // qiji
+    "打扮",  // daban
+    "哲學",  // zhexue
+    "銷售",  // xiaoshou
+    "返航",  // fanhang
+    "節約",  // jieyue
+    "增",  // zeng
+    "長輩",  // zhangbei
+    "懷念",  // huainian
+    "文件",  // wenjian
+    "裡	離	李	麗	梨",  // li
+    "出發",  // chufa
+    "紙上談兵",  // zhishangtanbing
+    "逃避",  // taobi
+    "儘量",  // jinliang
+    "瞧	橋	敲",  // qiao
+    "隨身",  // suishen
+    "出色",  // chuse
+    "金屬",  // jinshu
+    "闖	窗",  // chuang
+    "有名",  // youming
+    "珍惜",  // zhenxi
+    "餵養",  // weiyang
+    "趙括",  // zhaokuo
+    "不得了",  // budeliao
+    "照常",  // zhaochang
+    "通過",  // tongguo
+    "那	拿	哪",  // na
+    "討厭",  // taoyan
+    "開水",  // kaishui
+    "明",  // ming
+    "必須",  // bixu
+    "學歷",  // xueli
+    "期間",  // qijian
+    "微信",  // weixin
+    "涼茶",  // liangcha
+    "批評",  // piping
+    "遲到",  // chidao
+    "人民",  // renmin
+    "則",  // ze
+    "文文",  // wenwen
+    "商場",  // shangchang
+    "空氣",  // kongqi
+    "新聞",  // xinwen
+    "公斤",  // gongjin
+    "水	稅",  // shui
+    "留學",  // liuxue
+    "金魚",  // jinyu
+    "形式	形勢",  // xingshi
+    "預報",  // yubao
+    "母親",  // muqin
+    "表格",  // biaoge
+    "長江",  // changjiang
+    "麵包",  // mianbao
+    "趙福根",  // zhaofugen
+    "統一	同意",  // tongyi
+    "已經",  // yijing
+    "中秋節",  // zhongqiujie
+    "恐怕",  // kongpa
+    "都	逗",  // dou
+    "運氣",  // yunqi
+    "推辭",  // tuici
+    "貢獻",  // gongxian
+    "澳大利亞",  // aodaliya
+    "子路",  // zilu
+    "仔細",  // zixi
+    "商業",  // shangye
+    "六	留",  // liu
+    "機場",  // jichang
+    "張小龍",  // zhangxiaolong
+    "颳風",  // guafeng
+    "意見",  // yijian
+    "歸納",  // guina
+    "蔬菜",  // shucai
+    "必然",  // biran
+    "內",  // nei
+    "演出",  // yanchu
+    "理論",  // lilun
+    "出示",  // chushi
+    "玩兒",  // wanr
+    "專業",  // zhuanye
+    "果汁",  // guozhi
+    "小	笑",  // xiao
+    "過程",  // guocheng
+    "滿意",  // manyi
+    "菊花",  // juhua
+    "進步",  // jinbu
+    "昨天",  // zuotian
+    "講	獎",  // jiang
+    "降落",  // jiangluo
+    "邏輯",  // luoji
+    "遊覽",  // youlan
+    "日曆",  // rili
+    "無論",  // wulun
+    "來",  // lai
+    "觀點",  // guandian
+    "此外",  // ciwai
+    "便宜",  // pianyi
+    "健身",  // jianshen
+    "何況",  // hekuang
+    "小伙子",  // xiaohuozi
+    "《賣火柴的小女孩兒》",  // maihuochaidexiaonvhair
+    "棚子",  // pengzi
+    "複習",  // fuxi
+    "來不及",  // laibuji
+    "處理",  // chuli
+    "以及",  // yiji
+    "分手",  // fenshou
+    "救護車",  // jiuhuche
+    "員工",  // yuangong
+    "冒險",  // maoxian
+    "盒子",  // hezi
+    "地鐵",  // ditie
+    "公主",  // gongzhu
+    "補充",  // buchong
+    "單位",  // danwei
+    "火成岩",  // huochengyan
+    "滿足",  // manzu
+    "精彩",  // jingcai
+    "比如",  // biru
+    "空閒",  // kongxian
+    "膽小鬼",  // danxiaogui
+    "醋",  // cu
+    "道具",  // daoju
+    "家鄉",  // jiaxiang
+    "嗯",  // ng
+    "作者",  // zuozhe
+    "多	朵",  // duo
+    "辦法",  // banfa
+    "顯得",  // xiande
+    "反而",  // faner
+    "製冷",  // zhileng
+    "時候",  // shihou
+    "未必",  // weibi
+    "尾氣",  // weiqi
+    "感興趣",  // ganxingqu
+    "概念",  // gainian
+    "去	取	娶",  // qu
+    "打折",  // dazhe
+    "大象",  // daxiang
+    "灰姑娘",  // huiguniang
+    "招待",  // zhaodai
+    "樣子",  // yangzi
+    "森林",  // senlin
+    "使勁兒",  // shijinr
+    "同樣",  // tongyang
+    "掛",  // gua
+    "掌握",  // zhangwo
+    "郵局",  // youju
+    "輕鬆",  // qingsong
+    "電子郵件",  // dianziyoujian
+    "鮑全",  // baoquan
+    "永遠",  // yongyuan
+    "種類",  // zhonglei
+    "一共",  // yigong
+    "運用",  // yunyong
+    "老百姓",  // laobaixing
+    "比較",  // bijiao
+    "由來",  // youlai
+    "責備",  // zebei
+    "先	縣	鹹",  // xian
+    "忙",  // mang
+    "小區",  // xiaoqu
+    "橡子",  // xiangzi
+    "有關",  // youguan
+    "願意",  // yuanyi
+    "親戚",  // qinqi
+    "國王",  // guowang
+    "涼快",  // liangkuai
+    "非常",  // feichang
+    "建設",  // jianshe
+    "提高",  // tigao
+    "興奮",  // xingfen
+    "海鮮",  // haixian
+    "白	擺	百",  // bai
+    "出差",  // chuchai
+    "失去",  // shiqu
+    "可愛",  // keai
+    "押金",  // yajin
+    "交往",  // jiaowang
+    "音樂",  // yinyue
+    "髒",  // zang
+    "身段",  // shenduan
+    "襪子",  // wazi
+    "裝修",  // zhuangxiu
+    "預習",  // yuxi
+    "把	吧	八",  // ba
+    "還	海",  // hai
+    "急診",  // jizhen
+    "加利福尼亞州",  // jialifuniyazhou
+    "天安門東",  // tiananmendong
+    "人	認",  // ren
+    "針對",  // zhendui
+    "轉	賺",  // zhuan
+    "體育館",  // tiyuguan
+    "餅乾",  // binggan
+    "幹活兒",  // ganhuoer
+    "保證",  // baozheng
+    "會員卡",  // huiyuanka
+    "紅",  // hong
+    "方向",  // fangxiang
+    "以來",  // yilai
+    "原諒",  // yuanliang
+    "努力",  // nuli
+    "變化",  // bianhua
+    "考慮",  // kaolv
+    "巨大",  // juda
+    "疼愛",  // tengai
+    "成績",  // chengji
+    "射	蛇",  // she
+    "銀子",  // yinzi
+    "氣氛",  // qifen
+    "聊天",  // liaotian
+    "以前",  // yiqian
+    "搜索",  // sousuo
+    "中國",  // zhongguo
+    "朗讀",  // langdu
+    "化學",  // huaxue
+    "高速公路",  // gaosugonglu
+    "農民",  // nongmin
+    "至於",  // zhiyu
+    "稱讚",  // chengzan
+    "一切",  // yiqie
+    "妻子",  // qizi
+    "舉行",  // juxing
+    "咱們",  // zanmen
+    "遺憾",  // yihan
+    "稻香村",  // daoxiangcun
+    "教練",  // jiaolian
+    "紅色",  // hongse
+    "虛心",  // xuxin
+    "名片",  // mingpian
+    "相敬如賓",  // xiangjingrubin
+    "成	稱",  // cheng
+    "跑步",  // paobu
+    "情侶",  // qinglv
+    "體貼",  // titie
+    "毛	冒	貓",  // mao
+    "本科",  // benke
+    "效果",  // xiaoguo
+    "成分",  // chengfen
+    "重點",  // zhongdian
+    "字母	字幕",  // zimu
+    "利潤",  // lirun
+    "正好",  // zhenghao
+    "如果",  // ruguo
+    "熱情",  // reqing
+    "之	只	直	指	治	支	紙",  // zhi
+    "最初",  // zuichu
+    "植物",  // zhiwu
+    "阿姨",  // ayi
+    "正式",  // zhengshi
+    "而且",  // erqie
+    "從此",  // congci
+    "握手",  // woshou
+    "離開",  // likai
+    "前面",  // qianmian
+    "能",  // neng
+    "反對",  // fandui
+    "健步如飛",  // jianburufei
+    "合同",  // hetong
+    "行家",  // hangjia
+    "申請",  // shenqing
+    "擦",  // ca
+    "自由",  // ziyou
+    "工資",  // gongzi
+    "接近",  // jiejin
+    "椅子",  // yizi
+    "熱烈",  // relie
+    "小說",  // xiaoshuo
+    "敵人",  // diren
+    "似的",  // shide
+    "朝	炒	抄	吵",  // chao
+    "平靜",  // pingjing
+    "幫助",  // bangzhu
+    "部分",  // bufen
+    "承擔",  // chengdan
+    "肥皂",  // feizao
+    "至少",  // zhishao
+    "預防",  // yufang
+    "按時",  // anshi
+    "鹽",  // yan
+    "推廣",  // tuiguang
+    "是	使	十	詩	試",  // shi
+    "派	拍",  // pai
+    "其他",  // qita
+    "些	寫	血	斜	歇	謝",  // xie
+    "斷斷續續",  // duanduanxuxu
+    "今天",  // jintian
+    "辣椒",  // lajiao
+    "請假",  // qingjia
+    "兄弟",  // xiongdi
+    "波動",  // bodong
+    "售貨員",  // shouhuoyuan
+    "尋找",  // xunzhao
+    "模仿",  // mofang
+    "調查",  // diaocha
+    "風俗",  // fengsu
+    "自然",  // ziran
+    "所	鎖",  // suo
+    "讓",  // rang
+    "流血",  // liuxie
+    "實在",  // shizai
+    "送",  // song
+    "組合",  // zuhe
+    "肯定",  // kending
+    "想念",  // xiangnian
+    "日常",  // richang
+    "投資",  // touzi
+    "行為",  // xingwei
+    "灰心",  // huixin
+    "塑料袋",  // suliaodai
+    "否認",  // fouren
+    "安娜",  // anna
+    "應該",  // yinggai
+    "粘貼",  // zhantie
+    "姑娘",  // guniang
+    "但是",  // danshi
+    "摸",  // mo
+    "總統",  // zongtong
+    "材料",  // cailiao
+    "卡車",  // kache
+    "指揮	智慧",  // zhihui
+    "啄",  // zhuo
+    "這	著	折",  // zhe
+    "沙灘",  // shatan
+    "一起",  // yiqi
+    "寄生",  // jisheng
+    "鼓樓大街",  // guloudajie
+    "愉快",  // yukuai
+    "運輸",  // yunshu
+    "沙子",  // shazi
+    "重量",  // zhongliang
+    "婚禮",  // hunli
+    "低落",  // diluo
+    "國君",  // guojun
+    "冷靜",  // lengjing
+    "改善",  // gaishan
+    "刪除",  // shanchu
+    "照片",  // zhaopian
+    "旅遊",  // lvyou
+    "匯率",  // huilv
+    "互相",  // huxiang
+    "矇矓",  // menglong
+    "雜誌",  // zazhi
+    "形狀",  // xingzhuang
+    "公司",  // gongsi
+    "紀念",  // jinian
+    "演員",  // yanyuan
+    "免費",  // mianfei
+    "不同",  // butong
+    "對手",  // duishou
+    "完美",  // wanmei
+    "樣式",  // yangshi
+    "燃燒",  // ranshao
+    "時尚",  // shishang
+    "姐姐",  // jiejie
+    "活動",  // huodong
+    "陪",  // pei
+    "聯繫	練習",  // lianxi
+    "抓",  // zhua
+    "向	想	像	項	象	香	響",  // xiang
+    "辭職",  // cizhi
+    "仍然",  // rengran
+    "促使",  // cushi
+    "課程",  // kecheng
+    "暫時",  // zanshi
+    "記憶",  // jiyi
+    "高	搞",  // gao
+    "獲得",  // huode
+    "耳環",  // erhuan
+    "可惜",  // kexi
+    "不然",  // buran
+    "氣候",  // qihou
+    "做	坐	座",  // zuo
+    "靈活",  // linghuo
+    "遇到",  // yudao
+    "結婚",  // jiehun
+    "廣播",  // guangbo
+    "西漢",  // xihan
+    "儘快",  // jinkuai
+    "才藝",  // caiyi
+    "世界",  // shijie
+    "數碼",  // shuma
+    "表達",  // biaoda
+    "仿佛",  // fangfu
+    "分佈",  // fenbu
+    "決定",  // jueding
+    "走",  // zou
+    "航班",  // hangban
+    "編輯",  // bianji
+    "錄音",  // luyin
+    "合影",  // heying
+    "車庫",  // cheku
+    "本領",  // benling
+    "圍繞",  // weirao
+    "家訪",  // jiafang
+    "身份",  // shenfen
+    "和尚",  // heshang
+    "機會",  // jihui
+    "畢竟",  // bijing
+    "專家",  // zhuanjia
+    "差不多",  // chabuduo
+    "民居",  // minju
+    "廣告",  // guanggao
+    "時間	實踐",  // shijian
+    "比爾·蓋茨",  // biergaici
+    "手續",  // shouxu
+    "家具",  // jiaju
+    "通常",  // tongchang
+    "比",  // bi
+    "每天",  // meitian
+    "情趣",  // qingqu
+    "紅酒",  // hongjiu
+    "別",  // bie
+    "花	畫	滑	劃",  // hua
+    "額	餓",  // e
+    "患難與共",  // huannanyugong
+    "支教",  // zhijiao
+    "天空	填空",  // tiankong
+    "道理",  // daoli
+    "大約",  // dayue
+    "查詢",  // chaxun
+    "充滿",  // chongman
+    "飲料",  // yinliao
+    "接	街	屆	節	借	戒",  // jie
+    "及格",  // jige
+    "成果",  // chengguo
+    "宿舍",  // sushe
+    "乾燥",  // ganzao
+    "交換",  // jiaohuan
+    "用",  // yong
+    "同情",  // tongqing
+    "發明",  // faming
+    "吸引",  // xiyin
+    "釐米",  // limi
+    "華裔",  // huayi
+    "東海龍王",  // donghailongwang
+    "態度",  // taidu
+    "楊	養	癢",  // yang
+    "佩服",  // peifu
+    "情景",  // qingjing
+    "權力	權利",  // quanli
+    "盼望",  // panwang
+    "大家",  // dajia
+    "好像",  // haoxiang
+    "東	洞	懂	冬	凍",  // dong
+    "過	國	鍋",  // guo
+    "消息",  // xiaoxi
+    "地圖",  // ditu
+    "卻",  // que
+    "專注",  // zhuanzhu
+    "官	關",  // guan
+    "並",  // bing
+    "洛杉磯",  // luoshanji
+    "業務",  // yewu
+    "強調",  // qiangdiao
+    "百分之",  // baifenzhi
+    "夏威夷",  // xiaweiyi
+    "新西蘭",  // xinxilan
+    "軟件",  // ruanjian
+    "嚴重",  // yanzhong
+    "代替",  // daiti
+    "師傅",  // shifu
+    "夢",  // meng
+    "參考",  // cankao
+    "怪物",  // guaiwu
+    "影響",  // yingxiang
+    "，",  // ,
+    "堵車",  // duche
+    "某",  // mou
+    "傳真",  // chuanzhen
+    "段	斷	短",  // duan
+    "噸	頓	蹲",  // dun
+    "或者",  // huozhe
+    "開心",  // kaixin
+    "沙丁魚",  // shadingyu
+    "愛惜",  // aixi
+    "時差",  // shicha
+    "見面",  // jianmian
+    "啤酒",  // pijiu
+    "猴子",  // houzi
+    "失業",  // shiye
+    "教材",  // jiaocai
+    "召開",  // zhaokai
+    "悲傷",  // beishang
+    "意外",  // yiwai
+    "運動",  // yundong
+    "耐心",  // naixin
+    "寧可",  // ningke
+    "養成",  // yangcheng
+    "父母",  // fumu
+    "擴大",  // kuoda
+    "其次",  // qici
+    "著涼",  // zhaoliang
+    "包括",  // baokuo
+    "群",  // qun
+    "道德",  // daode
+    "蜜蜂",  // mifeng
+    "發言",  // fayan
+    "平安",  // pingan
+    "獨立",  // duli
+    "困難",  // kunnan
+    "害羞",  // haixiu
+    "發愁",  // fachou
+    "打印",  // dayin
+    "親愛",  // qinai
+    "政治",  // zhengzhi
+    "演講",  // yanjiang
+    "繞",  // rao
+    "減肥",  // jianfei
+    "熱心",  // rexin
+    "火	貨",  // huo
+    "熱",  // re
+    "省	升	剩",  // sheng
+    "隨著",  // suizhe
+    "奔跑",  // benpao
+    "欣賞",  // xinshang
+    "剛",  // gang
+    "機制",  // jizhi
+    "西瓜",  // xigua
+    "缺少",  // queshao
+    "政府",  // zhengfu
+    "手筆",  // shoubi
+    "袋子",  // daizi
+    "成功",  // chenggong
+    "羊肉",  // yangrou
+    "私人",  // siren
+    "國貿",  // guomao
+    "！",  // !
+    "火柴",  // huochai
+    "調皮",  // tiaopi
+    "採用",  // caiyong
+    "鄰居",  // linju
+    "受到",  // shoudao
+    "作文",  // zuowen
+    "銀	陰",  // yin
+    "維修",  // weixiu
+    "終於",  // zhongyu
+    "咳嗽",  // kesou
+    "總裁",  // zongcai
+    "筆試",  // bishi
+    "罰款",  // fakuan
+    "教育",  // jiaoyu
+    "山",  // shan
+    "吸收",  // xishou
+    "頭髮",  // toufa
+    "一般",  // yiban
+    "偉大",  // weida
+    "出生",  // chusheng
+    "情緣",  // qingyuan
+    "舒適",  // shushi
+    "稱霸",  // chengba
+    "首先",  // shouxian
+    "印刷",  // yinshua
+    "鍛煉",  // duanlian
+    "蒸騰",  // zhengteng
+    "拼音",  // pinyin
+    "感冒",  // ganmao
+    "洗禮",  // xili
+    "行動",  // xingdong
+    "思考",  // sikao
+    "心理",  // xinli
+    "左邊",  // zuobian
+    "萬丈",  // wanzhang
+    "報社",  // baoshe
+    "幫忙",  // bangmang
+    "漢字",  // hanzi
+    "腦袋",  // naodai
+    "綜合",  // zonghe
+    "美國",  // meiguo
+    "創辦",  // chuangban
+    "面積",  // mianji
+    "樂器",  // yueqi
+    "千萬",  // qianwan
+    "形容",  // xingrong
+    "痛苦",  // tongku
+    "看望",  // kanwang
+    "零錢",  // lingqian
+    "大型",  // daxing
+    "有效",  // youxiao
+    "簽證",  // qianzheng
+    "繩子",  // shengzi
+    "碩士",  // shuoshi
+    "淡",  // dan
+    "重新",  // chongxin
+    "二手",  // ershou
+    "數學",  // shuxue
+    "名勝古跡",  // mingshengguji
+    "到時候",  // daoshihou
+    "許多",  // xuduo
+    "下載",  // xiazai
+    "自信",  // zixin
+    "電視",  // dianshi
+    "活潑",  // huopo
+    "初級",  // chuji
+    "屬於",  // shuyu
+    "記得",  // jide
+    "夢想",  // mengxiang
+    "根治",  // genzhi
+    "奮鬥",  // fendou
+    "累	雷",  // lei
+    "破",  // po
+    "不行",  // buxing
+    "扔",  // reng
+    "躲藏",  // duocang
+    "節省",  // jiesheng
+    "製造",  // zhizao
+    "《",  // <
+    "娛樂",  // youle
+    "藝術",  // yishu
+    "違反",  // weifan
+    "培養",  // peiyang
+    "舅舅",  // jiujiu
+    "破產",  // pochan
+    "除非",  // chufei
+    "文字	蚊子",  // wenzi
+    "表",  // biao
+    "一點兒",  // yidianr
+    "開發",  // kaifa
+    "迎接",  // yingjie
+    "王宮",  // wanggong
+    "果實",  // guoshi
+    "當地",  // dangdi
+    "同學",  // tongxue
+    "問	聞	吻",  // wen
+    "複製",  // fuzhi
+    "東西",  // dongxi
+    "服務員",  // fuwuyuan
+    "連	臉",  // lian
+    "萬一",  // wanyi
+    "幫	棒",  // bang
+    "物理",  // wuli
+    "結論",  // jielun
+    "妨礙",  // fangai
+    "包子",  // baozi
+    "宣佈",  // xuanbu
+    "長	張	漲",  // zhang
+    "另	令	零	鈴",  // ling
+    "太	台	抬",  // tai
+    "光滑",  // guanghua
+    "模糊",  // mohu
+    "票	飄",  // piao
+    "？",  // ?
+    "生日",  // shengri
+    "往往",  // wangwang
+    "藍	爛	攔	懶",  // lan
+    "滿	慢",  // man
+    "參與",  // canyu
+    "文火",  // wenhuo
+    "進口",  // jinkou
+    "話題",  // huati
+    "從來",  // conglai
+    "詢問",  // xunwen
+    "秦國",  // qinguo
+    "早上",  // zaoshang
+    "真實",  // zhenshi
+    "美術",  // meishu
+    "蛋糕",  // dangao
+    "順暢",  // shunchang
+    "呢",  // ne
+    "當時",  // dangshi
+    "自覺",  // zijue
+    "面對",  // miandui
+    "辦公室",  // bangongshi
+    "動作",  // dongzuo
+    "新鮮感",  // xinxiangan
+    "水果",  // shuiguo
+    "進	近	盡",  // jin
+    "準備",  // zhunbei
+    "增加",  // zengjia
+    "紀律",  // jilv
+    "看不起",  // kanbuqi
+    "象棋",  // xiangqi
+    "消化	笑話",  // xiaohua
+    "接待",  // jiedai
+    "支持",  // zhichi
+    "緩解",  // huanjie
+    "小姐",  // xiaojie
+    "周到",  // zhoudao
+    "時刻",  // shike
+    "門檻",  // menkan
+    "打交道",  // dajiaodao
+    "出	處	除",  // chu
+    "汗腺",  // hanxian
+    "翻	犯",  // fan
+    "誤會",  // wuhui
+    "使用	實用",  // shiyong
+    "價格",  // jiage
+    "強烈",  // qianglie
+    "操心",  // caoxin
+    "青春",  // qingchun
+    "四合院",  // siheyuan
+    "後來",  // houlai
+    "上當",  // shangdang
+    "枝幹	知感",  // zhigan
+    "值得",  // zhide
+    "摺疊",  // zhedie
+    "描寫",  // miaoxie
+    "地震",  // dizhen
+    "比例",  // bili
+    "事半功倍",  // shibangongbei
+    "穿	船",  // chuan
+    "打籃球",  // dalanqiu
+    "堅強",  // jianqiang
+    "李廣",  // liguang
+    "規定",  // guiding
+    "筆記",  // biji
+    "音樂會",  // yinyuehui
+    "牛仔褲",  // niuzaiku
+    "就餐",  // jiucan
+    "會	回	揮	灰",  // hui
+    "《說文解字》",  // shuowenjiezi
+    "性質",  // xingzhi
+    "支票",  // zhipiao
+    "矛盾",  // maodun
+    "包含",  // baohan
+    "雲南",  // yunnan
+    "健偉",  // jianwei
+    "酒吧",  // jiuba
+    "士兵",  // shibing
+    "充電器",  // chongdianqi
+    "神秘",  // shenmi
+    "黃金",  // huangjin
+    "出席	除夕",  // chuxi
+    "博物館",  // bowuguan
+    "目的",  // mudi
+    "耳朵",  // erduo
+    "熬夜",  // aoye
+    "請求",  // qingqiu
+    "唱歌",  // changge
+    "硬	贏",  // ying
+    "觀眾",  // guanzhong
+    "普通話",  // putonghua
+    "目前",  // muqian
+    "月亮",  // yueliang
+    "風浪",  // fenglang
+    "功夫",  // gongfu
+    "勝利",  // shengli
+    "工業",  // gongye
+    "年輕",  // nianqing
+    "叢林",  // conglin
+    "顛球",  // dianqiu
+    "貸款",  // daikuan
+    "廣東省",  // guangdongsheng
+    "速度",  // sudu
+    "放	方",  // fang
+    "國慶節",  // guoqingjie
+    "不過",  // buguo
+    "財產",  // caichan
+    "鯰魚",  // nianyu
+    "談判",  // tanpan
+    "複印",  // fuyin
+    "貴",  // gui
+    "也許",  // yexu
+    "太陽",  // taiyang
+    "輔導",  // fudao
+    "利息",  // lixi
+    "家	加	假	甲	佳	嫁",  // jia
+    "一般來說",  // yibanlaishuo
+    "原則",  // yuanze
+    "爬山",  // pashan
+    "乾脆",  // gancui
+    "初中",  // chuzhong
+    "人類",  // renlei
+    "郝林說",  // haolinshuo
+    "七郎",  // qilang
+    "驕傲",  // jiaoao
+    "手指",  // shouzhi
+    "禮物",  // liwu
+    "豆腐",  // doufu
+    "溝通",  // goutong
+    "教訓",  // jiaoxun
+    "提供",  // tigong
+    "公寓",  // gongyu
+    "暖",  // nuan
+    "歐洲",  // ouzhou
+    "多餘",  // duoyu
+    "建議",  // jianyi
+    "主席",  // zhuxi
+    "經常",  // jingchang
+    "排列",  // pailie
+    "聰明",  // congming
+    "老婆",  // laopo
+    "和平",  // heping
+    "上海	傷害",  // shanghai
+    "順序",  // shunxu
+    "爭取",  // zhengqu
+    "假如",  // jiaru
+    "瓶子",  // pingzi
+    "勤奮",  // qinfen
+    "輪",  // lun
+    "雇",  // gu
+    "光	逛",  // guang
+    "呼朋喚友",  // hupenghuanyou
+    "王	往",  // wang
+    "營業",  // yingye
+    "島嶼",  // daoyu
+    "路口",  // lukou
+    "評委",  // pingwei
+    "劉辰",  // liuchen
+    "地址",  // dizhi
+    "加班",  // jiaban
+    "丹尼爾·卡內曼",  // dannierkaneiman
+    "流傳",  // liuchuan
+    "報告",  // baogao
+    "不但",  // budan
+    "成立",  // chengli
+    "修理",  // xiuli
+    "待遇",  // daiyu
+    "兌換",  // duihuan
+    "教授",  // jiaoshou
+    "劉熾平",  // liuchiping
+    "本質",  // benzhi
+    "天氣",  // tianqi
+    "趕快",  // gankuai
+    "放鬆",  // fangsong
+    "只有",  // zhiyou
+    "打電話",  // dadianhua
+    "曾經",  // cengjing
+    "故意",  // guyi
+    "對話",  // duihua
+    "角度",  // jiaodu
+    "同時	同事",  // tongshi
+    "偶爾",  // ouer
+    "系統",  // xitong
+    "關鍵",  // guanjian
+    "民族",  // minzu
+    "連忙",  // lianmang
+    "否則",  // fouze
+    "衛生間",  // weishengjian
+    "曇花",  // tanhua
+    "歪歪扭扭",  // waiwainiuniu
+    "賠償",  // peichang
+    "得",  // dei
+    "馬薩諸塞州",  // masazhusaizhou
+    "延長",  // yanchang
+    "舜",  // shun
+    "怪	乖",  // guai
+    "重複",  // chongfu
+    "神話",  // shenhua
+    "能源",  // nengyuan
+    "移動",  // yidong
+    "皮鞋",  // pixie
+    "我",  // wo
+    "楊雄",  // yangxiong
+    "要	藥	咬	腰	搖",  // yao
+    "護照",  // huzhao
+    "賞心悅目",  // shangxinyuemu
+    "價錢",  // jiaqian
+    "大衛",  // dawei
+    "國家",  // guojia
+    "房子",  // fangzi
+    "節日",  // jieri
+    "接受",  // jieshou
+    "否定",  // fouding
+    "匆忙",  // congmang
+    "小麥",  // xiaomai
+    "事物	食物",  // shiwu
+    "電梯",  // dianti
+    "長城",  // changcheng
+    "刺激",  // ciji
+    "第一",  // diyi
+    "悠久",  // youjiu
+    "熟練",  // shulian
+    "天津衛視",  // tianjinweishi
+    "笑笑",  // xiaoxiao
+    "錯誤",  // cuowu
+    "現在",  // xianzai
+    "網站",  // wangzhan
+    "盧米埃爾",  // lumiaier
+    "靜",  // jing
+    "結賬",  // jiezhang
+    "信用卡",  // xinyongka
+    "詳細",  // xiangxi
+    "合法",  // hefa
+    "手	受	首	收	守	瘦",  // shou
+    "海關",  // haiguan
+    "亂",  // luan
+    "哲學家",  // zhexuejia
+    "存活",  // cunhuo
+    "呼吸",  // huxi
+    "決賽",  // juesai
+    "池塘",  // chitang
+    "克服",  // kefu
+    "寶貴",  // baogui
+    "學問",  // xuewen
+    "休閒",  // xiuxian
+    "用途",  // yongtu
+    "對於",  // duiyu
+    "吃虧",  // chikui
+    "緊急",  // jinji
+    "大廈",  // dasha
+    "咱",  // zan
+    "沉默",  // chenmo
+    "文章",  // wenzhang
+    "安靜",  // anjing
+    "執照",  // zhizhao
+    "組	租",  // zu
+    "創造",  // chuangzao
+    "《彷徨》",  // panghuang
+    "遵守",  // zunshou
+    "促進",  // cujin
+    "移民",  // yimin
+    "校長",  // xiaozhang
+    "了不起",  // liaobuqi
+    "批	披	匹",  // pi
+    "根基",  // genji
+    "勇敢",  // yonggan
+    "體育",  // tiyu
+    "石灰岩",  // shihuiyan
+    "堅持",  // jianchi
+    "著急",  // zhaoji
+    "讚美",  // zanmai
+    "作用",  // zuoyong
+    "多虧",  // duokui
+    "轉頭",  // zhuantou
+    "非	廢",  // fei
+    "毛孔",  // maokong
+    "立即",  // liji
+    "翟峰",  // zhaifeng
+    "享受",  // xiangshou
+    "腎上腺素",  // shenshangxiansu
+    "晚上",  // wanshang
+    "超級",  // chaoji
+    "到	倒	刀",  // dao
+    "地位",  // diwei
+    "點",  // dian
+    "買	賣	埋",  // mai
+    "孫月",  // sunyue
+    "文明",  // wenming
+    "電腦",  // diannao
+    "了",  // le
+    "後果",  // houguo
+    "就	酒	九	久	舊	救",  // jiu
+    "主張",  // zhuzhang
+    "手機	收集",  // shouji
+    "真正",  // zhenzheng
+    "人民幣",  // renminbi
+    "臥室",  // woshi
+    "平常",  // pingchang
+    "愛護",  // aihu
+    "組織	阻止",  // zuzhi
+    "準確",  // zhunque
+    "自從",  // zicong
+    "萬	完	玩	碗",  // wan
+    "孝敬",  // xiaojing
+    "左右",  // zuoyou
+    "多少",  // duoshao
+    "高檔",  // gaodang
+    "效率",  // xiaolv
+    "外地",  // waidi
+    "設備",  // shebei
+    "解釋	結實	節食",  // jieshi
+    "郁達夫",  // yudafu
+    "提醒",  // tixing
+    "夠	狗	購",  // gou
+    "翅膀",  // chibang
+    "祝福",  // zhufu
+    "專心",  // zhuanxin
+    "熱鬧",  // renao
+    "喜歡",  // xihuan
+    "經商",  // jingshang
+    "登記",  // dengji
+    "中旬",  // zhongxun
+    "突然",  // turan
+    "女兒",  // nver
+    "溫度",  // wendu
+    "石頭",  // shitou
+    "首都",  // shoudu
+    "還	換",  // huan
+    "一輩子",  // yibeizi
+    "負責",  // fuze
+    "長江大橋",  // changjiangdaqiao
+    "傳播",  // chuanbo
+    "特殊",  // teshu
+    "有	又	由",  // you
+    "培訓",  // peixun
+    "診斷",  // zhenduan
+    "陌生",  // mosheng
+    "議論",  // yilun
+    "個人",  // geren
+    "退休",  // tuixiu
+    "負重",  // fuzhong
+    "暗暗",  // anan
+    "度過",  // duguo
+    "恢復",  // huifu
+    "模特",  // moter
+    "即使	及時",  // jishi
+    "跳舞",  // tiaowu
+    "分別",  // fenbie
+    "環境",  // huanjing
+    "動物",  // dongwu
+    "集中",  // jizhong
+    "資金",  // zijin
+    "中心",  // zhongxin
+    "概括",  // gaikuo
+    "的確",  // dique
+    "出自",  // chuzi
+    "形象",  // xingxiang
+    "中介",  // zhongjie
+    "改正",  // gaizheng
+    "窗戶",  // chuanghu
+    "鮮花",  // xianhua
+    "至今",  // zhijin
+    "不	部	布",  // bu
+    "領導",  // lingdao
+    "難免",  // nanmian
+    "窄	摘",  // zhai
+    "銀行",  // yinhang
+    "惡劣",  // elie
+    "飯店",  // fandian
+    "睏",  // kun
+    "標誌",  // biaozhi
+    "愛心",  // aixin
+    "集合",  // jihe
+    "週末",  // zhoumo
+    "最好",  // zuihao
+    "地毯",  // ditan
+    "胸",  // xiong
+    "天敵",  // tiandi
+    "趕緊",  // ganjin
+    "林	臨",  // lin
+    "願望",  // yuanwang
+    "點心",  // dianxin
+    "可樂",  // kele
+    "兔子",  // tuzi
+    "拆",  // chai
+    "安裝",  // anzhuang
+    "不管",  // buguan
+    "老",  // lao
+    "獎金",  // jiangjin
+    "經歷	經理	精力",  // jingli
+    "例如",  // liru
+    "追",  // zhui
+    "善於",  // shanyu
+    "調整",  // tiaozheng
+    "節目",  // jiemu
+    "感謝",  // ganxie
+    "傷心",  // shangxin
+    "不少",  // bushao
+    "地理",  // dili
+    "顯示	現實",  // xianshi
+    "房東",  // fangdong
+    "好吃",  // haochi
+    "脖子",  // bozi
+    "禮貌",  // limao
+    "避免",  // bimian
+    "地道",  // didao
+    "最近",  // zuijin
+    "可以",  // keyi
+    "正	睜	掙",  // zheng
+    "失望",  // shiwang
+    "取消",  // quxiao
+    "估計",  // guji
+    "著名",  // zhuming
+    "朋友",  // pengyou
+    "持續",  // chixu
+    "說服",  // shuofu
+    "油炸",  // youzha
+    "在	再",  // zai
+    "外	歪",  // wai
+    "冠軍",  // guanjun
+    "殺	傻",  // sha
+    "組成",  // zucheng
+    "女孩",  // nvhai
+    "黑",  // hei
+    "問題",  // wenti
+    "判斷",  // panduan
+    "太太",  // taitai
+    "烤鴨",  // kaoya
+    "開",  // kai
+    "戰爭",  // zhanzheng
+    "所有",  // suoyou
+    "冰箱",  // bingxiang
+    "呀",  // ya
+    "學術",  // xueshu
+    "辯論",  // bianlun
+    "舒服",  // shufu
+    "行	姓	醒",  // xing
+    "列車",  // lieche
+    "任何",  // renhe
+    "單純",  // danchun
+    "周圍",  // zhouwei
+    "語法",  // yufa
+    "耽誤",  // danwu
+    "對不起",  // duibuqi
+    "屋子",  // wuzi
+    "考試",  // kaoshi
+    "弄	濃",  // nong
+    "正確",  // zhengque
+    "工廠",  // gongchang
+    "風格",  // fengge
+    "起床",  // qichuang
+    "操場",  // caochang
+    "順利",  // shunli
+    "尊敬",  // zunjing
+    "高級",  // gaoji
+    "主觀",  // zhuguan
+    "寬",  // kuan
+    "然而",  // raner
+    "不要緊",  // buyaojin
+    "結構",  // jiegou
+    "勞駕",  // laojia
+    "對待",  // duidai
+    "婦女",  // funv
+    "天真",  // tianzhen
+    "開始",  // kaishi
+    "趨勢	去世",  // qushi
+    "誠懇",  // chengken
+    "作家",  // zuojia
+    "內容",  // neirong
+    "咖啡",  // kafei
+    "羨慕",  // xianmu
+    "孔子",  // kongzi
+    "辛苦",  // xinku
+    "陸地",  // ludi
+    "條件",  // tiaojian
+    "工作",  // gongzuo
+    "失眠",  // shimian
+    "簡單",  // jiandan
+    "註冊",  // zhuce
+    "項鏈",  // xianglian
+    "電影",  // dianying
+    "招聘",  // zhaopin
+    "》",  // >
+    "狡猾",  // jiaohua
+    "懷疑",  // huaiyi
+    "經營",  // jingying
+    "大概",  // dagai
+    "關係",  // guanxi
+    "碰	朋",  // peng
+    "輸入",  // shuru
+    "過分",  // guofen
+    "發生",  // fasheng
+    "逃	套	桃",  // tao
+    "彩虹",  // caihong
+    "王子",  // wangzi
+    "好	號",  // hao
+    "害怕",  // haipa
+    "記錄	紀錄",  // jilu
+    "規則",  // guize
+    "播放",  // bofang
+    "順便",  // shunbian
+    "怪不得",  // guaibude
+    "餐廳",  // canting
+    "廣泛",  // guangfan
+    "現金",  // xianjin
+    "肚子",  // duzi
+    "嗯",  // en
+    "公元	公園",  // gongyuan
+    "濟南",  // jinan
+    "吃驚",  // chijing
+    "聚會",  // juhui
+    "抱歉",  // baoqian
+    "看見",  // kanjian
+    "親切",  // qinqie
+    "主題",  // zhuti
+    "談	痰",  // tan
+    "春節",  // chunjie
+    "季節",  // jijie
+    "火車站",  // huochezhan
+    "勞動",  // laodong
+    "生長",  // shengzhang
+    "牛津大學",  // niujindaxue
+    "複雜",  // fuza
+    "方便",  // fangbian
+    "捨不得",  // shebude
+    "功能",  // gongneng
+    "社會",  // shehui
+    "自豪",  // zihao
+    "參加",  // canjia
+    "目錄",  // mulu
+    "好奇",  // haoqi
+    "人生",  // rensheng
+    "溫柔",  // wenrou
+    "認識	人事",  // renshi
+    "勺子",  // shaozi
+    "外面",  // waimian
+    "時代",  // shidai
+    "受傷",  // shoushang
+    "附近",  // fujin
+    "放棄",  // fangqi
+    "不見得",  // bujiande
+    "坦率",  // tanshuai
+    "學校",  // xuexiao
+    "媒體",  // meiti
+    "印加",  // yinjia
+    "容易",  // rongyi
+    "駕駛",  // jiashi
+    "即	極	繫	幾	擊	寄	擠",  // ji
+    "父親",  // fuqin
+    "兒子",  // erzi
+    "潘家園",  // panjiayuan
+    "攝影",  // sheying
+    "打算",  // dasuan
+    "書	數	樹	輸",  // shu
+    "青壯年",  // qingzhuangnian
+    "制度",  // zhidu
+    "骨頭",  // gutou
+    "打招呼",  // dazhaohu
+    "尺子",  // chizi
+    "儘管",  // jinguan
+    "醬油",  // jiangyou
+    "作戰",  // zuozhan
+    "改革",  // gaige
+    "去年",  // qunian
+    "代表",  // daibiao
+    "起	七	騎	棋",  // qi
+    "字	紫",  // zi
+    "住	豬	煮	祝",  // zhu
+    "危害",  // weihai
+    "顯然",  // xianran
+    "期待",  // qidai
+    "完整",  // wanzheng
+    "常識",  // changshi
+    "明白",  // mingbai
+    "整理",  // zhengli
+    "成長",  // chengzhang
+    "麻煩",  // mafan
+    "似乎",  // sihu
+    "次",  // ci
+    "鼓舞",  // guwu
+    "發",  // fa
+    "摩托車",  // motuoche
+    "別人",  // bieren
+    "醫院",  // yiyuan
+    "入口",  // rukou
+    "：",  // :
+    "艱苦",  // jianku
+    "發現",  // faxian
+    "結合",  // jiehe
+    "激動",  // jidong
+    "感動",  // gandong
+    "以後",  // yihou
+    "特色",  // tese
+    "右邊",  // youbian
+    "告別",  // gaobie
+    "果然",  // guoran
+    "詞典",  // cidian
+    "觀察",  // guancha
+    "學	雪",  // xue
+    "應用",  // yingyong
+    "哪兒	那兒",  // nar
+    "上班",  // shangban
+    "疲勞",  // pilao
+    "滾	棍",  // gun
+    "交流",  // jiaoliu
+    "各自	個子",  // gezi
+    "占	站",  // zhan
+    "差異",  // chayi
+    "商量",  // shangliang
+    "灰塵",  // huichen
+    "敏感",  // mingan
+    "合理",  // heli
+    "總共",  // zonggong
+    "過敏",  // guomin
+    "提前",  // tiqian
+    "配合",  // peihe
+    "營養",  // yingyang
+    "具備",  // jubei
+    "身材",  // shencai
+    "失敗",  // shibai
+    "歲	碎",  // sui
+    "綠	屢",  // lv
+    "打聽",  // dating
+    "急忙",  // jimang
+    "英俊",  // yingjun
+    "按	暗	岸",  // an
+    "寒假",  // hanjia
+    "騰訊",  // tengxun
+    "心臟",  // xinzang
+    "扇子",  // shanzi
+    "梅西",  // meixi
+    "總是",  // zongshi
+    "工具",  // gongju
+    "最後",  // zuihou
+    "對象",  // duixiang
+    "、",  // \
+    "上午	商務",  // shangwu
+    "位於",  // weiyu
+    "嚴肅",  // yansu
+    "活躍",  // huoyue
+    "成為",  // chengwei
+    "理想",  // lixiang
+    "證明",  // zhengming
+    "雞蛋",  // jidan
+    "相似",  // xiangsi
+    "戒指",  // jiezhi
+    "氣溫",  // qiwen
+    "蘋果",  // pingguo
+    "熱愛",  // reai
+    "充分",  // chongfen
+    "書架",  // shujia
+    "從而",  // conger
+    "吵架",  // chaojia
+    "門",  // men
+    "後面",  // houmian
+    "無	五	屋	霧	勿",  // wu
+    "大學",  // daxue
+    "平時",  // pingshi
+    "因為",  // yinwei
+    "體現",  // tixian
+    "核心",  // hexin
+    "糟糕",  // zaogao
+    "身邊",  // shenbian
+    "刷牙",  // shuaya
+    "迫切",  // poqie
+    "馬克",  // make
+    "美德",  // meide
+    "愛情",  // aiqing
+    "表示",  // biaoshi
+    "宣傳",  // xuanchuan
+    "司機",  // siji
+    "明確",  // mingque
+    "家務",  // jiawu
+    "青蛙",  // qingwa
+    "廉頗",  // lianpo
+    "抽	臭	醜",  // chou
+    "唯一",  // weiyi
+    "光臨",  // guanglin
+    "既然",  // jiran
+    "包裹",  // baoguo
+    "詞彙",  // cihui
+    "衝",  // chong
+    "皮膚",  // pifu
+    "空間",  // kongjian
+    "單調",  // dandiao
+    "脾氣",  // piqi
+    "因素",  // yinsu
+    "公平",  // gongping
+    "欄杆",  // langan
+    "蝴蝶",  // hudie
+    "施肥",  // shifei
+    "志願者",  // zhiyuanzhe
+    "極了",  // jile
+    "抽屜",  // chouti
+    "紛紛",  // fenfen
+    "八成兒",  // bachengr
+    "故事",  // gushi
+    "關注",  // guanzhu
+    "地區",  // diqu
+    "貿易",  // maoyi
+    "因此",  // yinci
+    "無法",  // wufa
+    "兒童",  // ertong
+    "透明",  // touming
+    "能幹",  // nenggan
+    "時髦",  // shimao
+    "經典",  // jingdian
+    "誠信",  // chengxin
+    "乒乓球",  // pingpangqiu
+    "造成",  // zaocheng
+    "客廳",  // keting
+    "消極",  // xiaoji
+    "降低",  // jiangdi
+    "突出",  // tuchu
+    "人才",  // rencai
+    "光線",  // guangxian
+    "感受",  // ganshou
+    "信封",  // xinfeng
+    "開放",  // kaifang
+    "約會",  // yuehui
+    "郊區",  // jiaoqu
+    "認真",  // renzhen
+    "哪怕",  // napa
+    "麥克風",  // maikefeng
+    "對比",  // duibi
+    "增長",  // zengzhang
+    "。",  // .
+    "鋼鐵",  // gangtie
+    "城市	誠實",  // chengshi
+    "歌舞",  // gewu
+    "到達",  // daoda
+    "收拾",  // shoushi
+    "股票",  // gupiao
+    "表面",  // biaomian
+    "網球",  // wangqiu
+    "日期",  // riqi
+    "秘書",  // mishu
+    "禮拜天",  // libaitian
+    "浪費",  // langfei
+    "暖和",  // nuanhuo
+    "零食",  // lingshi
+    "廣和居",  // guangheju
+    "海里",  // haili
+    "名牌",  // mingpai
+    "友誼",  // youyi
+    "丟",  // diu
+    "文學家",  // wenxuejia
+    "或許",  // huoxu
+    "這兒",  // zher
+    "相同",  // xiangtong
+    "明星",  // mingxing
+    "沒關係",  // meiguanxi
+    "基本",  // jiben
+    "固定",  // guding
+    "轉告",  // zhuangao
+    "振動",  // zhendong
+    "手錶",  // shoubiao
+    "利益",  // liyi
+    "徹底",  // chedi
+    "擔心",  // danxin
+    "壓力",  // yali
+    "措施",  // cuoshi
+    "檢查",  // jiancha
+    "環保",  // huanbao
+    "平均",  // pingjun
+    "十分",  // shifen
+    "溫暖",  // wennuan
+    "孫子",  // sunzi
+    "半	班	般	搬",  // ban
+    "號碼",  // haoma
+    "秘密",  // mimi
+    "頂	叮",  // ding
+    "《說文解字》",  // <shuowenjiezi>
+    "從前",  // congqian
+    "管理",  // guanli
+    "物質",  // wuzhi
+    "當然",  // dangran
+    "周",  // zhou
+    "片面",  // pianmian
+    "差距",  // chaju
+    "階段",  // jieduan
+    "隨便",  // suibian
+    "里查德·希爾斯",  // lichadexiersi
+    "閃電",  // shandian
+    "體會",  // tihui
+    "沙漠",  // shamo
+    "市場",  // shichang
+    "隔壁",  // gebi
+    "閱讀",  // yuedu
+    "以為",  // yiwei
+    "解決",  // jiejue
+    "命運",  // mingyun
+    "例子",  // lizi
+    "年紀	年級",  // nianji
+    "美好",  // meihao
+    "輕視",  // qingshi
+    "慚愧",  // cankui
+    "希望",  // xiwang
+    "學期",  // xueqi
+    "斯坦福",  // sitanfu
+    "寂寞",  // jimo
+    "印象",  // yinxiang
+    "先生",  // xiansheng
+    "扶	富	幅",  // fu
+    "表揚",  // biaoyang
+    "想象",  // xiangxiang
+    "答案",  // daan
+    "幻想",  // huanxiang
+    "位置",  // weizhi
+    "賓館",  // binguan
+    "當心",  // dangxin
+    "由於	猶豫",  // youyu
+    "保留",  // baoliu
+    "情緒",  // qingxu
+    "推遲",  // tuichi
+    "目標",  // mubiao
+    "長途",  // changtu
+    "羽毛球",  // yumaoqiu
+    "今年",  // jinnian
+    "褲子",  // kuzi
+    "發展",  // fazhan
+    "好客",  // haoke
+    "遊戲",  // youxi
+    "軍事",  // junshi
+    "裝	撞",  // zhuang
+    "任務	人物",  // renwu
+    "乾杯",  // ganbei
+    "禁止",  // jinzhi
+    "長	場	嘗",  // chang
+    "洗澡",  // xizao
+    "實現	事先",  // shixian
+    "起飛",  // qifei
+    "決心",  // juexin
+    "經驗",  // jingyan
+    "深刻",  // shenke
+    "完善",  // wanshan
+    "象徵",  // xiangzheng
+    "世紀	實際",  // shiji
+    "分析",  // fenxi
+    "一會兒",  // yihuier
+    "論文",  // lunwen
+    "眉毛",  // meimao
+    "網絡",  // wangluo
+    "灑",  // sa
+    "倆",  // lia
+    "可見",  // kejian
+    "馬上",  // mashang
+    "放縱",  // fangzong
+    "的	地	得",  // de
+    "對方",  // duifang
+    "跟	根",  // gen
+    "不如",  // buru
+    "本來",  // benlai
+    "說話",  // shuohua
+    "上",  // shang
+    "帽子",  // maozi
+    "鳥",  // niao
+    "快	塊",  // kuai
+    "《吶喊》",  // <nahan>
+    "團",  // tuan
+    "原來",  // yuanlai
+    "有點	優點",  // youdian
+    "發表",  // fabiao
+    "講座",  // jiangzuo
+    "雖然",  // suiran
+    "要求",  // yaoqiu
+    "出院",  // chuyuan
+    "逐漸",  // zhujian
+    "主任	主人",  // zhuren
+    "回來",  // huilai
+    "是否",  // shifou
+    "接觸",  // jiechu
+    "標點",  // biaodian
+    "修改",  // xiugai
+    "企業",  // qiye
+    "除了",  // chule
+    "我們",  // women
+    "特別",  // tebie
+    "彈鋼琴",  // tangangqin
+    "名字",  // mingzi
+    "不安",  // buan
+    "球迷",  // qiumi
+    "法律",  // falv
+    "立刻",  // like
+    "反省",  // fanxing
+    "本	笨",  // ben
+    "說不定",  // shuobuding
+    "慶祝",  // qingzhu
+    "色彩",  // secai
+    "光明",  // guangming
+    "瞭解",  // liaojie
+    "你",  // ni
+    "占線",  // zhanxian
+    "歐洲環境保護署",  // ouzhouhuanjingbaohushu
+    "相處",  // xiangchu
+    "傳說",  // chuanshuo
+    "另外",  // lingwai
+    "計算",  // jisuan
+    "假設",  // jiashe
+    "擴",  // kuo
+    "流淚",  // liulei
+    "星星",  // xingxing
+    "一邊",  // yibian
+    "嗓子",  // sangzi
+    "分享",  // fenxiang
+    "早",  // zao
+    "允許",  // yunxu
+    "謙虛",  // qianxu
+    "等待",  // dengdai
+    "產生",  // chansheng
+    "博士",  // boshi
+    "草",  // cao
+    "忽視	護士",  // hushi
+    "流行",  // liuxing
+    "怎麼辦",  // zenmeban
+    "片	篇	騙",  // pian
+    "總之",  // zongzhi
+    "如何",  // ruhe
+    "多麼",  // duome
+    "種	重	鐘",  // zhong
+    "快樂",  // kuaile
+    "道歉",  // daoqian
+    "方式",  // fangshi
+    "兩	亮	輛",  // liang
+    "從事",  // congshi
+    "幽默",  // youmo
+    "您",  // nin
+    "低	底	遞	滴",  // di
+    "說",  // shuo
+    "確實",  // queshi
+    "承認	成人",  // chengren
+    "適應",  // shiying
+    "《非你莫屬》",  // <feinimoshu>
+    "特點",  // tedian
+    "一直	一致",  // yizhi
+    "抽煙",  // chouyan
+    "為什麼",  // weishenme
+    "普遍",  // pubian
+    "聽	停	挺",  // ting
+    "完全",  // wanquan
+    "程度",  // chengdu
+    "游泳",  // youyong
+    "瘋狂",  // fengkuang
+    "生物鐘",  // shengwuzhong
+    "力量",  // liliang
+    "通知",  // tongzhi
+    "問候",  // wenhou
+    "短信",  // duanxin
+    "特徵",  // tezheng
+    "裝飾",  // zhuangshi
+    "確定",  // queding
+    "發達",  // fada
+    "句子	橘子	桔子	鋸子",  // juzi
+    "工人",  // gongren
+    "優秀",  // youxiu
+    "生產",  // shengchan
+    "自行車",  // zixingche
+    "理髮店",  // lifadian
+    "自己",  // ziji
+    "讚美",  // zanmei
+    "糧食",  // liangshi
+    "適當",  // shidang
+    "打掃",  // dasao
+    "到處",  // daochu
+    "奇特",  // qite
+    "部門",  // bumen
+    "口味",  // kouwei
+    "國際	國籍",  // guoji
+    "死	四	絲	私	撕",  // si
+    "公開",  // gongkai
+    "參觀",  // canguan
+    "病毒",  // bingdu
+    "諮詢",  // zixun
+    "合作",  // hezuo
+    "方案",  // fangan
+    "資源	自願",  // ziyuan
+    "黃河",  // huanghe
+    "小吃",  // xiaochi
+    "衣服",  // yifu
+    "春秋",  // chunqiu
+    "訓練",  // xunlian
+    "下	夏	嚇	瞎",  // xia
+    "愛迪生",  // aidisheng
+    "吹",  // chui
+    "付款",  // fukuan
+    "總算",  // zongsuan
+    "災害",  // zaihai
+    "相反",  // xiangfan
+    "體驗",  // tiyan
+    "有利",  // youli
+    "後悔",  // houhui
+    "理髮",  // lifa
+    "金石為開",  // jinshiweikai
+    "輪流",  // lunliu
+    "姑姑",  // gugu
+    "說明",  // shuoming
+    "獅子",  // shizi
+    "戲劇",  // xiju
+    "正常",  // zhengchang
+    "圍巾",  // weijin
+    "投籃",  // toulan
+    "抱	包	保	薄	飽",  // bao
+    "朝三暮四",  // zhaosanmusi
+    "祝賀",  // zhuhe
+    "西	系	洗	夕",  // xi
+];
+
+// u32 constants are murmur3 hash of syllable-initial abbreviations of 词语,
+// like "sxym" or "shxym" for 赏心悦目 (shangxinyuemu). Keys that are also
+// pinyin search keys are left out, since full pinyin matches come first.
+pub static ABBREV: &[u32] = &[
+    0x001589d2, // jf
+    0x00d18e37, // jch
+    0x00f6a801, // zw
+    0x01a43925, // jw
+    0x01b456c1, // lhh
+    0x01ed8460, // py
+    0x023a318a, // rx
+    0x027ee06b, // yhe
+    0x02f3aac9, // hg
+    0x030ed66c, // rmb
+    0x03430f94, // dc
+    0x03a860c4, // bnf
+    0x03b64257, // chy
+    0x0427de8d, // qsgzh
+    0x0446611a, // shch
+    0x051d51ae, // tch
+    0x05a427e1, // zh
+    0x05df6bdc, // chx
+    0x06858fc4, // zhp
+    0x0694dad0, // zz
+    0x075800cd, // zmsnsms
+    0x076a6152, // zhfg
+    0x07b3c47c, // hls
+    0x07bf9d66, // yzh
+    0x08223060, // shy
+    0x085731ec, // bc
+    0x086b324a, // ym
+    0x0873ae2e, // bh
+    0x08add319, // kj
+    0x08ec71c1, // ljt
+    0x0906325b, // pc
+    0x09403e96, // qshgz
+    0x09900e74, // yc
+    0x09a75330, // jy
+    0x09d1fbcc, // gw
+    0x0a41ae89, // sdjch
+    0x0a796b8c, // xpy
+    0x0a8d448c, // gn
+    0x0acebad1, // fnms
+    0x0b3a2750, // mgx
+    0x0bcaa278, // qsn
+    0x0c0b3e94, // wq
+    0x0c7128f3, // jchsz
+    0x0ca2551b, // rr
+    0x0cbb9dd9, // chzh
+    0x0ccdce22, // jh
+    0x0cd2c6d6, // zk
+    0x0ce704c3, // chw
+    0x0ced66c7, // ldj
+    0x0d25039b, // bm
+    0x0e6dd9bd, // qj
+    0x0e814f82, // fz
+    0x0e96f793, // hlsh
+    0x0eba8599, // mq
+    0x0ec2b009, // gc
+    0x0f27d8bd, // yyh
+    0x0fa05f9f, // lbj
+    0x1092ccec, // gy
+    0x10937d9b, // mtch
+    0x10c9db3a, // hcz
+    0x10fec715, // ny
+    0x11766708, // lj
+    0x11f0ad29, // cx
+    0x11f390b4, // mhcdxnhe
+    0x121f271f, // gchsh
+    0x12239ab5, // yd
+    0x12a756ba, // fb
+    0x12eff76e, // hb
+    0x13059f29, // zhxl
+    0x138250db, // sbl
+    0x139db314, // mf
+    0x13b05949, // gq
+    0x1514bbb4, // sk
+    0x152a2019, // yey
+    0x152fffd4, // xsh
+    0x154adcca, // qw
+    0x156d96a2, // bgs
+    0x1586c0aa, // j
+    0x15db1ba6, // ppq
+    0x160379d8, // shsh
+    0x160af53d, // gt
+    0x17acb131, // xl
+    0x18711da9, // zhl
+    0x18847b8a, // cdq
+    0x1898243c, // zj
+    0x18d17e9f, // lbq
+    0x18d9b304, // shdy
+    0x18eb62c8, // qf
+    0x195a789a, // sdy
+    0x19935691, // chzc
+    0x19b94a99, // zc
+    0x19d95093, // shj
+    0x1a1a911b, // shzh
+    0x1a3af09d, // tjwsh
+    0x1a5b6e72, // zhx
+    0x1a8590a6, // pl
+    0x1aaf13ef, // dzhh
+    0x1ac6f299, // rsh
+    0x1b95b3f6, // xc
+    0x1bf82d35, // y
+    0x1c68455c, // xg
+    0x1d1577d4, // bdb
+    0x1df23f6c, // sx
+    0x1e48bc23, // qg
+    0x1e58a735, // cw
+    0x1e8e5488, // ck
+    0x1ebfd7b6, // lsh
+    0x1ef69cd7, // qshgzh
+    0x1f1d0e65, // sld
+    0x1f8de5c2, // qysh
+    0x20362780, // qsh
+    0x20587682, // el
+    0x205d0a24, // kw
+    0x20e488c6, // sht
+    0x21318d02, // hs
+    0x2168996c, // kqs
+    0x21840e11, // q
+    0x21b07fa5, // dlq
+    0x21b53c17, // chdq
+    0x21da3435, // lg
+    0x222962b8, // xd
+    0x22a5beca, // cz
+    0x22a8844e, // sp
+    0x23416208, // as
+    0x237fe645, // cr
+    0x23d912bb, // sq
+    0x2461dcfc, // bt
+    0x246c9a70, // px
+    0x24b55eb9, // aa
+    0x24ea1d0e, // kms
+    0x24feaaa2, // ghj
+    0x256daa32, // xzh
+    0x25a7b74e, // shsxs
+    0x260b5031, // wl
+    0x263c1213, // pm
+    0x26acbfe5, // yt
+    0x27eb3cd3, // ozh
+    0x281975a7, // kwx
+    0x2846e94e, // shbd
+    0x289f0205, // bjb
+    0x28b2bfce, // byj
+    0x28bad162, // ls
+    0x28d3a823, // kq
+    0x28e3b8a9, // dt
+    0x2940692f, // shx
+    0x296650bd, // jz
+    0x29d4c1e0, // jg
+    0x29f1cf27, // gh
+    0x2a61da3d, // rc
+    0x2a7e60ab, // zfg
+    0x2ab0bff2, // qz
+    0x2b0a88a3, // czch
+    0x2b92e0fe, // by
+    0x2c0f3eda, // shc
+    0x2c17f13b, // r
+    0x2c33595b, // fy
+    0x2cfb376d, // xwy
+    0x2d2269f8, // m
+    0x2d48cdd2, // mszszh
+    0x2d95d0d3, // swjz
+    0x2db80c3b, // sxym
+    0x2dc4c530, // kt
+    0x2dcee7a0, // jsh
+    0x2de3462d, // oe
+    0x2e30bfad, // ql
+    0x2e3df487, // cg
+    0x2e865172, // nsh
+    0x2f416889, // fzhdl
+    0x2f902b60, // lf
+    0x2ff2ebe1, // jd
+    0x300e9252, // pt
+    0x30921bdf, // chzch
+    0x310830ad, // bdl
+    0x31099644, // t
+    0x312c8c03, // xly
+    0x31a1f36c, // ph
+    0x321d9e9d, // jcszh
+    0x322ee631, // shys
+    0x335cc980, // tzh
+    0x33867fa3, // sd
+    0x33e07a00, // sbgb
+    0x3431b7d1, // mm
+    0x344bc1c5, // fe
+    0x3531288c, // cm
+    0x35a3769f, // gldj
+    0x365e38a0, // tj
+    0x36a60d67, // hn
+    0x371cfc61, // rq
+    0x37f31eed, // gcsh
+    0x3858dbd3, // tb
+    0x38ca293b, // rj
+    0x39112caf, // zs
+    0x39390159, // dpt
+    0x3ad2be47, // nbej
+    0x3affa97c, // fn
+    0x3bc86598, // gsh
+    0x3cb5418c, // ld
+    0x3cc003ea, // yys
+    0x3d135d98, // xn
+    0x3d5ce14e, // ra
+    0x3d6608eb, // yp
+    0x3d956630, // rzh
+    0x3dba02b2, // xp
+    0x3e78da96, // tsh
+    0x3fb11f95, // wk
+    0x40229885, // wsj
+    0x402fb2aa, // rw
+    0x4063f714, // yl
+    0x40946e4f, // zqj
+    0x40e856b2, // lr
+    0x41034537, // xsj
+    0x414400e2, // tsg
+    0x41753081, // hl
+    0x4187682b, // rz
+    0x41e03b05, // nj
+    0x42631ccf, // zhf
+    0x427493d5, // csh
+    0x42c1c254, // tzq
+    0x42d575f1, // ads
+    0x43a4c5fc, // eh
+    0x43bc2ebf, // zg
+    0x43ef1766, // ych
+    0x4410ea5e, // zzh
+    0x44407013, // pw
+    0x448e0f28, // shb
+    0x4547b4da, // yw
+    0x46062c91, // th
+    0x464443e7, // hq
+    0x4655a424, // hczh
+    0x470ba2ff, // ll
+    0x47b28994, // lcp
+    0x47c9124a, // db
+    0x4838cd7c, // fwy
+    0x48daef70, // ty
+    0x49419c6a, // gr
+    0x4afe33be, // tl
+    0x4b17a54c, // ssh
+    0x4b182fdc, // ts
+    0x4b3e4568, // ap
+    0x4b939ebd, // zhk
+    0x4ba0c0c4, // kf
+    0x4bc048c3, // fw
+    0x4bd5b893, // wn
+    0x4bd87bac, // btq
+    0x4bfe82d1, // sf
+    0x4c0fe773, // hphy
+    0x4c7d5dbf, // lz
+    0x4d158bf5, // jhc
+    0x4d8ee785, // aj
+    0x4dab4174, // rbzh
+    0x4ea30da7, // ff
+    0x4ed37707, // tf
+    0x4f118e25, // djd
+    0x505d2502, // lx
+    0x50944e36, // shbgb
+    0x50feef42, // lbx
+    0x51409e30, // bj
+    0x516bc7bc, // sm
+    0x519c3832, // wch
+    0x51a260f9, // pzh
+    0x51af0980, // kr
+    0x51e916de, // zb
+    0x52be02c2, // nn
+    0x539b0b45, // js
+    0x53c9025f, // nk
+    0x54494f28, // mszhsz
+    0x5479cf53, // jk
+    0x54e46480, // pa
+    0x55027bdb, // dhp
+    0x55a0e118, // dxc
+    0x5703b358, // cb
+    0x573f3aad, // xq
+    0x576e30b8, // ww
+    0x57e27571, // qd
+    0x581f3f47, // jr
+    0x585b2330, // wh
+    0x58993bdd, // lc
+    0x58b3cc0d, // tgq
+    0x598c9923, // zhg
+    0x5a352e5e, // c
+    0x5a764d5b, // lk
+    0x5b307910, // yn
+    0x5b5997d9, // ed
+    0x5b68c538, // fd
+    0x5b7e98da, // zhzh
+    0x5bd91e09, // jhch
+    0x5cf9d7bf, // czc
+    0x5d285594, // mszsz
+    0x5dfd769f, // zmshnshms
+    0x5e730827, // xr
+    0x5e9d0604, // pp
+    0x5eacb126, // hy
+    0x5ee93191, // yf
+    0x5f1b68be, // sys
+    0x5f4193fc, // czh
+    0x6051b210, // shxym
+    0x60cb9c21, // msh
+    0x619bfc16, // gdsh
+    0x61e10d1a, // mb
+    0x623e9e3a, // pf
+    0x62c5ced7, // mg
+    0x62c9d1b7, // zmshnsms
+    0x63096c6e, // qt
+    0x634bfff6, // bgsh
+    0x63a42c43, // cc
+    0x63b00c3e, // ozhjbhs
+    0x63f5f7c0, // zhr
+    0x64058206, // kbq
+    0x6469c4b6, // hzh
+    0x647f7aac, // zyz
+    0x64c3a1b9, // jchszh
+    0x651457a8, // pd
+    0x65b1b4c5, // qs
+    0x6639965e, // shbl
+    0x663ddf20, // rh
+    0x66d545d8, // lmae
+    0x670b8bd5, // qa
+    0x6736b1de, // sj
+    0x6740132c, // nzk
+    0x67801dff, // gf
+    0x678ccfad, // mh
+    0x681e45e3, // gsgl
+    0x68764f74, // om
+    0x689a1d95, // tamd
+    0x68c3f454, // ks
+    0x68e60f99, // rm
+    0x690d53d5, // sg
+    0x698f0ab9, // cl
+    0x69d8db7f, // jld
+    0x6a228274, // nm
+    0x6a571bf1, // shk
+    0x6c083cc0, // xxl
+    0x6c58d25f, // dhlw
+    0x6c904697, // mtc
+    0x6d9f6165, // dr
+    0x6e85a379, // x
+    0x6e8c2e96, // pzhs
+    0x6eae57da, // dh
+    0x6eca21e8, // zhj
+    0x6ee73736, // qh
+    0x6eec7fef, // fshj
+    0x6efb9914, // csk
+    0x6f8cc887, // zt
+    0x6fd23990, // chl
+    0x6ff4ceed, // xy
+    0x6ffb99de, // zmb
+    0x701d58ed, // lw
+    0x70948bbf, // cjzx
+    0x70bbe57e, // lt
+    0x7252b7b9, // lljzh
+    0x72f7fbb9, // mz
+    0x73326a5e, // chs
+    0x73688484, // p
+    0x73b78653, // sdjc
+    0x7407bf54, // lbt
+    0x740e2ce1, // jb
+    0x742dd01f, // zch
+    0x7430ca6e, // zhc
+    0x749b7935, // qzn
+    0x74d6fce4, // zsms
+    0x75371aea, // cy
+    0x7555a3f0, // yblsh
+    0x75cd1ef0, // bg
+    0x763765e9, // gm
+    0x763b1733, // zhch
+    0x76769d40, // jzh
+    0x77109fb0, // gxq
+    0x771f8f5b, // yz
+    0x777a30a0, // ns
+    0x77c56710, // wz
+    0x77e82010, // dsg
+    0x783777a4, // ml
+    0x78710cff, // xt
+    0x789fc510, // dj
+    0x78b061fc, // zhqj
+    0x78b53633, // jlfnyzh
+    0x78d4c6dc, // qq
+    0x7945bc3d, // fx
+    0x794b4801, // bd
+    0x79f64805, // chch
+    0x7a03ebc1, // lcdxes
+    0x7a1a0a6b, // yh
+    0x7a812821, // hch
+    0x7a845759, // et
+    0x7aa7d914, // mblq
+    0x7ae16236, // zmy
+    0x7b01ea3f, // shd
+    0x7bb55abc, // qr
+    0x7c1dc152, // kl
+    0x7c62da48, // shf
+    0x7c764325, // psh
+    0x7d39b111, // sy
+    0x7d6a5b0f, // hj
+    0x7d961be7, // tk
+    0x7d9f8bd4, // qy
+    0x7da1ab4f, // jlb
+    0x7dd93170, // bz
+    0x7e4de953, // fc
+    0x7e6d16db, // gk
+    0x7e702996, // wc
+    0x7ef713e3, // ybz
+    0x7f3d29f5, // hp
+    0x7fd2909c, // xhs
+    0x7fec6d10, // ymq
+    0x80346456, // dsh
+    0x804315e0, // wxj
+    0x804d7d4a, // kp
+    0x807c14d5, // dq
+    0x80eb9f0d, // yg
+    0x817ee93f, // az
+    0x81c99179, // gch
+    0x81f9de47, // or
+    0x823262ce, // jp
+    0x829aed1f, // zr
+    0x8318bd49, // bjshfdx
+    0x83205c9c, // chjdq
+    0x835aa7d6, // shysh
+    0x837f2ed6, // fg
+    0x83e58fb4, // pq
+    0x83fa8e62, // chq
+    0x84153b05, // jyzh
+    0x8419ad96, // yr
+    0x84e5d6c0, // xz
+    0x8500e6f4, // hgs
+    0x85184f7a, // wj
+    0x852a1970, // gl
+    0x856529ed, // gj
+    0x86b522a3, // hgn
+    0x86d6ab7d, // wwnn
+    0x86e94863, // cjdq
+    0x87003ecf, // zyzh
+    0x87e88999, // nc
+    0x8833bcfd, // mt
+    0x884e6748, // rn
+    0x8862d577, // zq
+    0x8913bfc5, // fch
+    0x898de2d2, // sl
+    0x8a0c4091, // lch
+    0x8a1d4ef9, // es
+    0x8aa76c20, // fm
+    0x8acc137e, // wb
+    0x8b29dfee, // zhmsnshms
+    0x8b91aaf7, // bp
+    0x8ba3f609, // td
+    0x8bc63a11, // jyz
+    0x8c121fbd, // tp
+    0x8c1a54ee, // g
+    0x8c821fe4, // zhmshnsms
+    0x8dbf014d, // sshxs
+    0x8dceab61, // chm
+    0x8de607ab, // kc
+    0x8e2732ab, // gzh
+    0x8ec2a2f3, // shhy
+    0x8ec651ba, // shp
+    0x8f8448a8, // fsh
+    0x8fe08cd9, // lh
+    0x8feb1c4c, // hxx
+    0x9081c68f, // stf
+    0x911566cc, // jl
+    0x919b7d3b, // ct
+    0x91c1147d, // ms
+    0x936cff80, // dch
+    0x939ab097, // ddxx
+    0x944b7288, // zd
+    0x9486b8ca, // xhsh
+    0x952f919a, // zhsh
+    0x9531b9f4, // zhw
+    0x954ddd2c, // fl
+    0x955fff48, // fsj
+    0x9579364f, // ozhhjbhs
+    0x958f347e, // wd
+    0x95b60386, // ch
+    0x95e83f37, // cj
+    0x9653d88e, // hz
+    0x966629ee, // ka
+    0x96c04e4a, // zhstb
+    0x9706577f, // cch
+    0x972c9499, // yb
+    0x97508084, // rk
+    0x975a4d2d, // ddh
+    0x97e57ca4, // lq
+    0x988c8611, // hk
+    0x98d0e109, // pz
+    0x98e0cc47, // mhchdxnh
+    0x992f2f00, // mkf
+    0x99307936, // ozhjbhsh
+    0x996ebbce, // dm
+    0x998987a2, // xshj
+    0x99933c47, // w
+    0x9a82b3e3, // zxch
+    0x9aaa96b9, // sc
+    0x9afa38a2, // shdjc
+    0x9b2cb761, // shr
+    0x9baf84c4, // gds
+    0x9d78f92f, // wsm
+    0x9dc624e8, // bjsfdx
+    0x9dd33fee, // nb
+    0x9dd774dc, // tx
+    0x9e1ea036, // xk
+    0x9e4b7525, // chbd
+    0x9ec56240, // bl
+    0x9f0f1047, // hnyg
+    0x9f6bfa03, // mshgj
+    0x9f989ac6, // zhy
+    0x9f9b3d66, // mry
+    0x9fb0b02c, // gz
+    0x9fe9a462, // bwg
+    0x9fead7ce, // mszhszh
+    0xa088d152, // bx
+    0xa0908c42, // chh
+    0xa09846ce, // wshm
+    0xa0bf3ec8, // shg
+    0xa0c0c9ba, // dw
+    0xa131dbf9, // zstb
+    0xa243664d, // nr
+    0xa276597d, // zf
+    0xa27c4730, // ky
+    0xa286f950, // gqj
+    0xa28940ec, // kk
+    0xa2deca19, // wshj
+    0xa47a6879, // ds
+    0xa48b4bc0, // chp
+    0xa4a680f8, // nzh
+    0xa4ff162c, // kmsh
+    0xa5872342, // k
+    0xa59a1401, // dzyj
+    0xa6b71416, // ax
+    0xa6d42598, // bs
+    0xa6f59520, // lljz
+    0xa7121ff9, // tshg
+    0xa71a8788, // jq
+    0xa7465adf, // wy
+    0xa77635d6, // ws
+    0xa8745b8d, // hd
+    0xa94dc275, // ozhhjbhsh
+    0xa9549fca, // br
+    0xa9c9cbb7, // lyetj
+    0xa9f2c452, // qx
+    0xaa0e6b7d, // ghe
+    0xaa6ecc22, // xs
+    0xaaaaaa30, // cjzhx
+    0xab0151b1, // gb
+    0xab62cbed, // bkq
+    0xab7f9a20, // zp
+    0xac2a0f45, // xhz
+    0xaca2646e, // ybls
+    0xaca660dc, // zhyz
+    0xacd933a9, // cs
+    0xad2b7793, // jc
+    0xadabea12, // d
+    0xaddc7e1b, // zhb
+    0xae09609f, // dd
+    0xae238280, // wsh
+    0xae5a54b1, // gs
+    0xaef40c87, // fzdl
+    0xaf7ce6c1, // bjss
+    0xafbdab50, // tjq
+    0xb00b9664, // cht
+    0xb0129580, // chf
+    0xb03009d7, // jbrf
+    0xb03e5015, // wf
+    0xb04699a4, // hx
+    0xb0763f17, // adly
+    0xb0f00a72, // bw
+    0xb1260e73, // gx
+    0xb1290ad2, // zhq
+    0xb167d5c5, // kzh
+    0xb1da9f01, // shwz
+    0xb1edc168, // cshk
+    0xb1ee7f80, // chr
+    0xb20a36d5, // fs
+    0xb21e4d62, // zhsms
+    0xb222f77f, // dzh
+    0xb2cbe75e, // sb
+    0xb44eba0e, // fh
+    0xb49b9891, // xx
+    0xb4c08269, // tm
+    0xb4dee697, // qk
+    0xb5833f47, // lfd
+    0xb5efcd84, // oz
+    0xb61afe62, // xw
+    0xb633ad7d, // mj
+    0xb67351e1, // xj
+    0xb6bda1a1, // cf
+    0xb72bbcc4, // qm
+    0xb7461932, // chjzhx
+    0xb754adb5, // rl
+    0xb796dacb, // ksh
+    0xb7a36217, // tq
+    0xb7e038cd, // lchdxes
+    0xb80cf605, // kx
+    0xb850bf65, // zhs
+    0xb863cbd8, // sbd
+    0xb8a0cfa9, // cp
+    0xb8d26a2e, // lp
+    0xb8ef9181, // kn
+    0xb913de43, // nq
+    0xb945787d, // jswk
+    0xb96209bc, // wzh
+    0xb9633bdd, // zgn
+    0xb97be679, // qb
+    0xb97eb6f8, // chjzx
+    0xb9a83ee5, // chg
+    0xba8923e8, // sz
+    0xbaae6038, // azh
+    0xbad53531, // tg
+    0xbb07e992, // nh
+    0xbb37ac44, // xyk
+    0xbb8c63ea, // ggqc
+    0xbba5fe95, // rg
+    0xbc3c3a1c, // np
+    0xbc5a7045, // qzh
+    0xbc5fce4b, // pch
+    0xbc666f7b, // hchy
+    0xbc9ea06b, // nz
+    0xbd0a1054, // jlfnyz
+    0xbd0f80bc, // dy
+    0xbd5b56a6, // ly
+    0xbd662020, // chsh
+    0xbd72fa09, // ysh
+    0xbd7bda9e, // msgj
+    0xbdac4cca, // wx
+    0xbdad676c, // ydf
+    0xbe544c45, // xxg
+    0xbe56a345, // tw
+    0xbed61a5c, // ay
+    0xbeef6563, // mhcdxnh
+    0xbf6c6716, // zm
+    0xbf96dbcc, // qkl
+    0xc001ded4, // lchp
+    0xc018306f, // ash
+    0xc054027b, // dl
+    0xc09c5a2a, // ggqch
+    0xc0a02ee7, // sr
+    0xc0cc498b, // zht
+    0xc0df1aac, // fk
+    0xc0e222c6, // djp
+    0xc1209a73, // f
+    0xc169a0c4, // mk
+    0xc1946920, // wt
+    0xc2023805, // fp
+    0xc25d3593, // tc
+    0xc29dce90, // kqsh
+    0xc2d3c258, // swzh
+    0xc303ed28, // eq
+    0xc309b468, // dshh
+    0xc313816f, // hm
+    0xc339b0c8, // dk
+    0xc3b2f810, // dx
+    0xc3c2ad6e, // zxl
+    0xc3ee43c1, // pzs
+    0xc42f2455, // swz
+    0xc43049d8, // shh
+    0xc47f4231, // jshwk
+    0xc48113f2, // bf
+    0xc5084443, // jt
+    0xc5141ff0, // xch
+    0xc52dc40e, // xh
+    0xc5763b9f, // begc
+    0xc5d888d2, // rs
+    0xc5e773b7, // lm
+    0xc61d7813, // njdx
+    0xc6748ccb, // zx
+    0xc74c9a03, // zl
+    0xc7cecdbe, // xlx
+    0xc89db020, // hcy
+    0xc94ed94b, // fq
+    0xca2d7163, // pg
+    0xca3b70b0, // gp
+    0xcaaa42e4, // mhchdxnhe
+    0xcabf83d4, // tt
+    0xcaeccf17, // s
+    0xcb065af7, // ryp
+    0xcb43976e, // gcs
+    0xcb521db4, // dg
+    0xcb6ca4ee, // zhm
+    0xcb6cfe0a, // mr
+    0xcbbdb438, // qn
+    0xcbe740c9, // yj
+    0xcc20b0bc, // dshg
+    0xcccf8aeb, // fj
+    0xcda5c686, // xf
+    0xce5d6afa, // ys
+    0xce94ae25, // b
+    0xcea316a3, // chb
+    0xcef461ac, // yx
+    0xcf4463d4, // pth
+    0xcf895b99, // ps
+    0xcf92bef8, // chk
+    0xcf942eef, // zxc
+    0xcf9bf10f, // shwjz
+    0xcfd12fb7, // rbz
+    0xd0a8cd1f, // shs
+    0xd0e8d1d5, // hyk
+    0xd0eb2ada, // hchz
+    0xd0f5cff4, // zhxj
+    0xd1636e45, // gbd
+    0xd2db2e88, // ht
+    0xd35a9091, // cq
+    0xd39de2e7, // shw
+    0xd537ac7e, // dz
+    0xd56b70e9, // hsh
+    0xd5902147, // tyg
+    0xd5fca60e, // shq
+    0xd644f543, // hlw
+    0xd6adc0a6, // ja
+    0xd7b03f23, // z
+    0xd7e08c7f, // sch
+    0xd82b78ce, // cd
+    0xd85df081, // chz
+    0xd85ef5f0, // ah
+    0xd896a339, // lb
+    0xd8b750ad, // zhgn
+    0xd92e8240, // hh
+    0xd9377ebf, // jm
+    0xd9f4c567, // xjrb
+    0xda0f51e3, // bfz
+    0xdb01af22, // n
+    0xdb250f07, // shl
+    0xdb764405, // ry
+    0xdb8581dc, // ss
+    0xdb8d3288, // shm
+    0xdc080d1e, // hf
+    0xdc397509, // wr
+    0xdc686020, // dn
+    0xdd006cbc, // chd
+    0xdd7835df, // hc
+    0xdd8da26a, // zhshtb
+    0xdde5ffc1, // bjl
+    0xde29e304, // jj
+    0xde37283b, // bq
+    0xdeacf1c9, // pjy
+    0xdf26fd5b, // tz
+    0xdf899a4f, // lsj
+    0xe005415e, // zhyzh
+    0xe0149049, // bb
+    0xe060662c, // zxj
+    0xe1531bb1, // szh
+    0xe171e5cb, // bch
+    0xe1dc8a2a, // bfzh
+    0xe1eeea27, // zhh
+    0xe1f7bc16, // st
+    0xe244e84c, // fzh
+    0xe437e86f, // dbq
+    0xe455a734, // sw
+    0xe488cd7e, // adsh
+    0xe48f9c38, // pj
+    0xe4b268a5, // bzh
+    0xe4d3c8c6, // shz
+    0xe516f75a, // nd
+    0xe59002d1, // zshtb
+    0xe5a603f4, // nl
+    0xe6556d01, // h
+    0xe66eef35, // zhmsnsms
+    0xe740be40, // jcsz
+    0xe775f8f5, // qc
+    0xe95c9140, // ssxs
+    0xebae4cbe, // shshxs
+    0xec0176ab, // qys
+    0xec087e6e, // yq
+    0xec390722, // qch
+    0xec4a805e, // wm
+    0xec830c91, // dxg
+    0xec98b134, // qshn
+    0xeda4c25b, // qzhn
+    0xedb20a3d, // zmsnshms
+    0xedb6c513, // tjhj
+    0xedfa565d, // df
+    0xee2fbe40, // yk
+    0xee53030c, // gd
+    0xee868e46, // zy
+    0xef026b52, // l
+    0xef3ae05f, // xb
+    0xef42b195, // sysh
+    0xef452e9c, // nx
+    0xef9a1f15, // esh
+    0xf04074f5, // qsgz
+    0xf0701b6e, // mx
+    0xf094f7e2, // jn
+    0xf1036f28, // shwzh
+    0xf114517a, // zsh
+    0xf11b39d4, // rch
+    0xf1640017, // yy
+    0xf23bf79f, // mp
+    0xf3b732aa, // zhmshnshms
+    0xf3e46682, // kz
+    0xf3f5b4e9, // xm
+    0xf3f91bf2, // gchs
+    0xf42bd483, // fr
+    0xf51d3d56, // hchzh
+    0xf5772e21, // chc
+    0xf5d88a33, // zhd
+    0xf62ac34b, // ea
+    0xf715c4b5, // pb
+    0xf77fa5d9, // chj
+    0xf798ea61, // ez
+    0xf7b2bd44, // fnmsh
+    0xf81672d3, // tr
+    0xf8349127, // tjws
+    0xf840e041, // bk
+    0xf850e749, // md
+    0xf87c8467, // bsh
+    0xf8aaca96, // zhz
+    0xf8cf1883, // aw
+    0xf9287ecf, // kd
+    0xf928ea76, // hr
+    0xf99edac5, // gg
+    0xf9d8744b, // jx
+    0xfa32975d, // wg
+    0xfb489730, // aq
+    0xfbffbbe9, // kch
+    0xfc462975, // dneknm
+    0xfcce8a57, // bjd
+    0xfd260ce8, // shdjch
+    0xfd50d204, // sh
+    0xfd8159cf, // wsw
+    0xfdc83f27, // cbd
+    0xfede7e32, // lshj
+    0xff4415c0, // kg
+    0xffaa8def, // my
+];
+
+// Abbreviations in the same order as ABBREV
+pub static ABBREV_KEYS: &[&str] = &[
+    "jf",
+    "jch",
+    "zw",
+    "jw",
+    "lhh",
+    "py",
+    "rx",
+    "yhe",
+    "hg",
+    "rmb",
+    "dc",
+    "bnf",
+    "chy",
+    "qsgzh",
+    "shch",
+    "tch",
+    "zh",
+    "chx",
+    "zhp",
+    "zz",
+    "zmsnsms",
+    "zhfg",
+    "hls",
+    "yzh",
+    "shy",
+    "bc",
+    "ym",
+    "bh",
+    "kj",
+    "ljt",
+    "pc",
+    "qshgz",
+    "yc",
+    "jy",
+    "gw",
+    "sdjch",
+    "xpy",
+    "gn",
+    "fnms",
+    "mgx",
+    "qsn",
+    "wq",
+    "jchsz",
+    "rr",
+    "chzh",
+    "jh",
+    "zk",
+    "chw",
+    "ldj",
+    "bm",
+    "qj",
+    "fz",
+    "hlsh",
+    "mq",
+    "gc",
+    "yyh",
+    "lbj",
+    "gy",
+    "mtch",
+    "hcz",
+    "ny",
+    "lj",
+    "cx",
+    "mhcdxnhe",
+    "gchsh",
+    "yd",
+    "fb",
+    "hb",
+    "zhxl",
+    "sbl",
+    "mf",
+    "gq",
+    "sk",
+    "yey",
+    "xsh",
+    "qw",
+    "bgs",
+    "j",
+    "ppq",
+    "shsh",
+    "gt",
+    "xl",
+    "zhl",
+    "cdq",
+    "zj",
+    "lbq",
+    "shdy",
+    "qf",
+    "sdy",
+    "chzc",
+    "zc",
+    "shj",
+    "shzh",
+    "tjwsh",
+    "zhx",
+    "pl",
+    "dzhh",
+    "rsh",
+    "xc",
+    "y",
+    "xg",
+    "bdb",
+    "sx",
+    "qg",
+    "cw",
+    "ck",
+    "lsh",
+    "qshgzh",
+    "sld",
+    "qysh",
+    "qsh",
+    "el",
+    "kw",
+    "sht",
+    "hs",
+    "kqs",
+    "q",
+    "dlq",
+    "chdq",
+    "lg",
+    "xd",
+    "cz",
+    "sp",
+    "as",
+    "cr",
+    "sq",
+    "bt",
+    "px",
+    "aa",
+    "kms",
+    "ghj",
+    "xzh",
+    "shsxs",
+    "wl",
+    "pm",
+    "yt",
+    "ozh",
+    "kwx",
+    "shbd",
+    "bjb",
+    "byj",
+    "ls",
+    "kq",
+    "dt",
+    "shx",
+    "jz",
+    "jg",
+    "gh",
+    "rc",
+    "zfg",
+    "qz",
+    "czch",
+    "by",
+    "shc",
+    "r",
+    "fy",
+    "xwy",
+    "m",
+    "mszszh",
+    "swjz",
+    "sxym",
+    "kt",
+    "jsh",
+    "oe",
+    "ql",
+    "cg",
+    "nsh",
+    "fzhdl",
+    "lf",
+    "jd",
+    "pt",
+    "chzch",
+    "bdl",
+    "t",
+    "xly",
+    "ph",
+    "jcszh",
+    "shys",
+    "tzh",
+    "sd",
+    "sbgb",
+    "mm",
+    "fe",
+    "cm",
+    "gldj",
+    "tj",
+    "hn",
+    "rq",
+    "gcsh",
+    "tb",
+    "rj",
+    "zs",
+    "dpt",
+    "nbej",
+    "fn",
+    "gsh",
+    "ld",
+    "yys",
+    "xn",
+    "ra",
+    "yp",
+    "rzh",
+    "xp",
+    "tsh",
+    "wk",
+    "wsj",
+    "rw",
+    "yl",
+    "zqj",
+    "lr",
+    "xsj",
+    "tsg",
+    "hl",
+    "rz",
+    "nj",
+    "zhf",
+    "csh",
+    "tzq",
+    "ads",
+    "eh",
+    "zg",
+    "ych",
+    "zzh",
+    "pw",
+    "shb",
+    "yw",
+    "th",
+    "hq",
+    "hczh",
+    "ll",
+    "lcp",
+    "db",
+    "fwy",
+    "ty",
+    "gr",
+    "tl",
+    "ssh",
+    "ts",
+    "ap",
+    "zhk",
+    "kf",
+    "fw",
+    "wn",
+    "btq",
+    "sf",
+    "hphy",
+    "lz",
+    "jhc",
+    "aj",
+    "rbzh",
+    "ff",
+    "tf",
+    "djd",
+    "lx",
+    "shbgb",
+    "lbx",
+    "bj",
+    "sm",
+    "wch",
+    "pzh",
+    "kr",
+    "zb",
+    "nn",
+    "js",
+    "nk",
+    "mszhsz",
+    "jk",
+    "pa",
+    "dhp",
+    "dxc",
+    "cb",
+    "xq",
+    "ww",
+    "qd",
+    "jr",
+    "wh",
+    "lc",
+    "tgq",
+    "zhg",
+    "c",
+    "lk",
+    "yn",
+    "ed",
+    "fd",
+    "zhzh",
+    "jhch",
+    "czc",
+    "mszsz",
+    "zmshnshms",
+    "xr",
+    "pp",
+    "hy",
+    "yf",
+    "sys",
+    "czh",
+    "shxym",
+    "msh",
+    "gdsh",
+    "mb",
+    "pf",
+    "mg",
+    "zmshnsms",
+    "qt",
+    "bgsh",
+    "cc",
+    "ozhjbhs",
+    "zhr",
+    "kbq",
+    "hzh",
+    "zyz",
+    "jchszh",
+    "pd",
+    "qs",
+    "shbl",
+    "rh",
+    "lmae",
+    "qa",
+    "sj",
+    "nzk",
+    "gf",
+    "mh",
+    "gsgl",
+    "om",
+    "tamd",
+    "ks",
+    "rm",
+    "sg",
+    "cl",
+    "jld",
+    "nm",
+    "shk",
+    "xxl",
+    "dhlw",
+    "mtc",
+    "dr",
+    "x",
+    "pzhs",
+    "dh",
+    "zhj",
+    "qh",
+    "fshj",
+    "csk",
+    "zt",
+    "chl",
+    "xy",
+    "zmb",
+    "lw",
+    "cjzx",
+    "lt",
+    "lljzh",
+    "mz",
+    "chs",
+    "p",
+    "sdjc",
+    "lbt",
+    "jb",
+    "zch",
+    "zhc",
+    "qzn",
+    "zsms",
+    "cy",
+    "yblsh",
+    "bg",
+    "gm",
+    "zhch",
+    "jzh",
+    "gxq",
+    "yz",
+    "ns",
+    "wz",
+    "dsg",
+    "ml",
+    "xt",
+    "dj",
+    "zhqj",
+    "jlfnyzh",
+    "qq",
+    "fx",
+    "bd",
+    "chch",
+    "lcdxes",
+    "yh",
+    "hch",
+    "et",
+    "mblq",
+    "zmy",
+    "shd",
+    "qr",
+    "kl",
+    "shf",
+    "psh",
+    "sy",
+    "hj",
+    "tk",
+    "qy",
+    "jlb",
+    "bz",
+    "fc",
+    "gk",
+    "wc",
+    "ybz",
+    "hp",
+    "xhs",
+    "ymq",
+    "dsh",
+    "wxj",
+    "kp",
+    "dq",
+    "yg",
+    "az",
+    "gch",
+    "or",
+    "jp",
+    "zr",
+    "bjshfdx",
+    "chjdq",
+    "shysh",
+    "fg",
+    "pq",
+    "chq",
+    "jyzh",
+    "yr",
+    "xz",
+    "hgs",
+    "wj",
+    "gl",
+    "gj",
+    "hgn",
+    "wwnn",
+    "cjdq",
+    "zyzh",
+    "nc",
+    "mt",
+    "rn",
+    "zq",
+    "fch",
+    "sl",
+    "lch",
+    "es",
+    "fm",
+    "wb",
+    "zhmsnshms",
+    "bp",
+    "td",
+    "jyz",
+    "tp",
+    "g",
+    "zhmshnsms",
+    "sshxs",
+    "chm",
+    "kc",
+    "gzh",
+    "shhy",
+    "shp",
+    "fsh",
+    "lh",
+    "hxx",
+    "stf",
+    "jl",
+    "ct",
+    "ms",
+    "dch",
+    "ddxx",
+    "zd",
+    "xhsh",
+    "zhsh",
+    "zhw",
+    "fl",
+    "fsj",
+    "ozhhjbhs",
+    "wd",
+    "ch",
+    "cj",
+    "hz",
+    "ka",
+    "zhstb",
+    "cch",
+    "yb",
+    "rk",
+    "ddh",
+    "lq",
+    "hk",
+    "pz",
+    "mhchdxnh",
+    "mkf",
+    "ozhjbhsh",
+    "dm",
+    "xshj",
+    "w",
+    "zxch",
+    "sc",
+    "shdjc",
+    "shr",
+    "gds",
+    "wsm",
+    "bjsfdx",
+    "nb",
+    "tx",
+    "xk",
+    "chbd",
+    "bl",
+    "hnyg",
+    "mshgj",
+    "zhy",
+    "mry",
+    "gz",
+    "bwg",
+    "mszhszh",
+    "bx",
+    "chh",
+    "wshm",
+    "shg",
+    "dw",
+    "zstb",
+    "nr",
+    "zf",
+    "ky",
+    "gqj",
+    "kk",
+    "wshj",
+    "ds",
+    "chp",
+    "nzh",
+    "kmsh",
+    "k",
+    "dzyj",
+    "ax",
+    "bs",
+    "lljz",
+    "tshg",
+    "jq",
+    "wy",
+    "ws",
+    "hd",
+    "ozhhjbhsh",
+    "br",
+    "lyetj",
+    "qx",
+    "ghe",
+    "xs",
+    "cjzhx",
+    "gb",
+    "bkq",
+    "zp",
+    "xhz",
+    "ybls",
+    "zhyz",
+    "cs",
+    "jc",
+    "d",
+    "zhb",
+    "dd",
+    "wsh",
+    "gs",
+    "fzdl",
+    "bjss",
+    "tjq",
+    "cht",
+    "chf",
+    "jbrf",
+    "wf",
+    "hx",
+    "adly",
+    "bw",
+    "gx",
+    "zhq",
+    "kzh",
+    "shwz",
+    "cshk",
+    "chr",
+    "fs",
+    "zhsms",
+    "dzh",
+    "sb",
+    "fh",
+    "xx",
+    "tm",
+    "qk",
+    "lfd",
+    "oz",
+    "xw",
+    "mj",
+    "xj",
+    "cf",
+    "qm",
+    "chjzhx",
+    "rl",
+    "ksh",
+    "tq",
+    "lchdxes",
+    "kx",
+    "zhs",
+    "sbd",
+    "cp",
+    "lp",
+    "kn",
+    "nq",
+    "jswk",
+    "wzh",
+    "zgn",
+    "qb",
+    "chjzx",
+    "chg",
+    "sz",
+    "azh",
+    "tg",
+    "nh",
+    "xyk",
+    "ggqc",
+    "rg",
+    "np",
+    "qzh",
+    "pch",
+    "hchy",
+    "nz",
+    "jlfnyz",
+    "dy",
+    "ly",
+    "chsh",
+    "ysh",
+    "msgj",
+    "wx",
+    "ydf",
+    "xxg",
+    "tw",
+    "ay",
+    "mhcdxnh",
+    "zm",
+    "qkl",
+    "lchp",
+    "ash",
+    "dl",
+    "ggqch",
+    "sr",
+    "zht",
+    "fk",
+    "djp",
+    "f",
+    "mk",
+    "wt",
+    "fp",
+    "tc",
+    "kqsh",
+    "swzh",
+    "eq",
+    "dshh",
+    "hm",
+    "dk",
+    "dx",
+    "zxl",
+    "pzs",
+    "swz",
+    "shh",
+    "jshwk",
+    "bf",
+    "jt",
+    "xch",
+    "xh",
+    "begc",
+    "rs",
+    "lm",
+    "njdx",
+    "zx",
+    "zl",
+    "xlx",
+    "hcy",
+    "fq",
+    "pg",
+    "gp",
+    "mhchdxnhe",
+    "tt",
+    "s",
+    "ryp",
+    "gcs",
+    "dg",
+    "zhm",
+    "mr",
+    "qn",
+    "yj",
+    "dshg",
+    "fj",
+    "xf",
+    "ys",
+    "b",
+    "chb",
+    "yx",
+    "pth",
+    "ps",
+    "chk",
+    "zxc",
+    "shwjz",
+    "rbz",
+    "shs",
+    "hyk",
+    "hchz",
+    "zhxj",
+    "gbd",
+    "ht",
+    "cq",
+    "shw",
+    "dz",
+    "hsh",
+    "tyg",
+    "shq",
+    "hlw",
+    "ja",
+    "z",
+    "sch",
+    "cd",
+    "chz",
+    "ah",
+    "lb",
+    "zhgn",
+    "hh",
+    "jm",
+    "xjrb",
+    "bfz",
+    "n",
+    "shl",
+    "ry",
+    "ss",
+    "shm",
+    "hf",
+    "wr",
+    "dn",
+    "chd",
+    "hc",
+    "zhshtb",
+    "bjl",
+    "jj",
+    "bq",
+    "pjy",
+    "tz",
+    "lsj",
+    "zhyzh",
+    "bb",
+    "zxj",
+    "szh",
+    "bch",
+    "bfzh",
+    "zhh",
+    "st",
+    "fzh",
+    "dbq",
+    "sw",
+    "adsh",
+    "pj",
+    "bzh",
+    "shz",
+    "nd",
+    "zshtb",
+    "nl",
+    "h",
+    "zhmsnsms",
+    "jcsz",
+    "qc",
+    "ssxs",
+    "shshxs",
+    "qys",
+    "yq",
+    "qch",
+    "wm",
+    "dxg",
+    "qshn",
+    "qzhn",
+    "zmsnshms",
+    "tjhj",
+    "df",
+    "yk",
+    "gd",
+    "zy",
+    "l",
+    "xb",
+    "sysh",
+    "nx",
+    "esh",
+    "qsgz",
+    "mx",
+    "jn",
+    "shwzh",
+    "zsh",
+    "rch",
+    "yy",
+    "mp",
+    "zhmshnshms",
+    "kz",
+    "xm",
+    "gchs",
+    "fr",
+    "hchzh",
+    "chc",
+    "zhd",
+    "ea",
+    "pb",
+    "chj",
+    "ez",
+    "fnmsh",
+    "tr",
+    "tjws",
+    "bk",
+    "md",
+    "bsh",
+    "zhz",
+    "aw",
+    "kd",
+    "hr",
+    "gg",
+    "jx",
+    "wg",
+    "aq",
+    "kch",
+    "dneknm",
+    "bjd",
+    "shdjch",
+    "sh",
+    "wsw",
+    "cbd",
+    "lshj",
+    "kg",
+    "my",
+];
+
+// Choices for each abbreviation (joined by "\t") and their frequency
+// weights, sorted by descending weight. Only the 9 highest weight choices
+// are kept, since choices get picked with one digit.
+pub static ABBREV_CIYU: &[&str] = &[
+    "减肥	家访",  // jf
+    "基础	坚持	机场	经常	检查	接触	警察",  // jch
+    "作为	植物	周围	掌握	中文	中午	座位	作文",  // zw
+    "交往	家务	健伟",  // jw
+    "连环画",  // lhh
+    "朋友	培养	便宜	拼音",  // py
+    "热心",  // rx
+    "一会儿",  // yhe
+    "后果	合格	海关",  // hg
+    "人民币",  // rmb
+    "调查	到处	单纯	电池	躲藏	堵车",  // dc
+    "不耐烦",  // bnf
+    "差异	抽烟	春游	出院	成语",  // chy
+    "全神贯注",  // qsgzh
+    "生产	市场	商场	删除	顺畅	时差",  // shch
+    "通常	突出	提倡	推迟",  // tch
+    "这	着	之	只	长	正	住	占	张",  // zh
+    "出现	重新	程序	持续	出席	查询	抽象	诚信	车厢",  // chx
+    "招聘	照片	支票",  // zhp
+    "组织	政治	增长	战争	作战	真正	制造	主张	正在",  // zz
+    "詹姆士·奈史密斯",  // zmsnsms
+    "赵福根",  // zhfg
+    "郝林说",  // hls
+    "一直	严重	亚洲	一致	油炸",  // yzh
+    "使用	声音	属于	商业	适应	实验	生意	善于	实用",  // shy
+    "保持	不错	补充	保存	彼此	八成	八成儿",  // bc
+    "玉米	有名	移民	优美	幽默",  // ym
+    "变化	保护	包含",  // bh
+    "科技	看见	空间	可见	会计",  // kj
+    "垃圾桶",  // ljt
+    "平常	赔偿	破产",  // pc
+    "全神贯注",  // qshgz
+    "因此	演出	延长	牙齿	养成",  // yc
+    "教育	经验	建议	经营	记忆	均匀	节约	加油	酱油",  // jy
+    "国王	格外	购物	鼓舞	歌舞	怪物	拐弯",  // gw
+    "首都机场",  // sdjch
+    "小朋友",  // xpy
+    "姑娘	功能	概念	观念	归纳",  // gn
+    "《非你莫属》",  // fnms
+    "没关系",  // mgx
+    "青少年",  // qsn
+    "完全	委屈	网球	尾气",  // wq
+    "精诚所至",  // jchsz
+    "仍然",  // rr
+    "成长	初中	传真",  // chzh
+    "计划	机会	几乎	结合	交换	结婚	集合	聚会	菊花",  // jh
+    "召开	展开	状况	做客	赵括",  // zk
+    "成为	宠物",  // chw
+    "来得及",  // ldj
+    "部门	表明	表面	避免	报名	帮忙",  // bm
+    "期间	情景	奇迹	起居	请假",  // qj
+    "发展	负责	分钟	复杂	否则	房子	反正	服装	复制",  // fz
+    "郝林说",  // hlsh
+    "目前	母亲	明确	密切",  // mq
+    "过程	广场	构成	刚才	工厂	观察	干脆",  // gc
+    "音乐会",  // yyh
+    "来不及",  // lbj
+    "工业	关于	公元	公园	故意	公寓",  // gy
+    "摩托车",  // mtch
+    "火车站",  // hcz
+    "农业	能源	鲇鱼",  // ny
+    "了解	立即	理解	逻辑	垃圾	冷静	邻居	零件	辣椒",  // lj
+    "出现	重新	程序	持续	出席	查询	抽象	诚信	操心",  // cx
+    "《卖火柴的小女孩儿》",  // mhcdxnhe
+    "工程师",  // gchsh
+    "一定	运动	一旦	遇到	阅读	移动	有点	优点	一点儿",  // yd
+    "分别	分布	发表	方便",  // fb
+    "华北	何必	伙伴	航班	环保	后背	黑板",  // hb
+    "张小龙",  // zhxl
+    "受不了",  // sbl
+    "麻烦	免费	模仿	米饭	蜜蜂",  // mf
+    "过去	感情	过期",  // gq
+    "时刻	深刻	思考",  // sk
+    "幼儿园",  // yey
+    "学生	形式	先生	小时	显示	销售	形势	小说	现实",  // xsh
+    "气温	千万	青蛙",  // qw
+    "办公室",  // bgs
+    "就	叫	家	即	进	近	极	讲	教",  // j
+    "乒乓球",  // ppq
+    "设施	事实	手术	受伤	收拾	硕士	叔叔	时尚	舒适",  // shsh
+    "共同	钢铁	沟通	骨头	柜台",  // gt
+    "训练	心理	效率	学历	修理	洗礼	项链",  // xl
+    "质量	治疗	种类	整理	重量	展览	争论	哲理	制冷",  // zhl
+    "充电器",  // cdq
+    "自己	增加	直接	专家	逐渐	资金	中间	最近	作家",  // zj
+    "了不起",  // lbq
+    "沙丁鱼",  // shdy
+    "缺乏	气氛	起飞	启发	勤奋",  // qf
+    "沙丁鱼",  // sdy
+    "出租车",  // chzc
+    "组成	支持	造成	正常	主持	最初	自从	总裁	注册",  // zc
+    "世界	时间	世纪	设计	实际	实践	数据	手机	射击",  // shj
+    "甚至	生长	始终	手指	睡着",  // shzh
+    "天津卫视",  // tjwsh
+    "这些	中心	主席	哲学	秩序	中旬	珍惜	装修	专心",  // zhx
+    "排列	漂亮	疲劳",  // pl
+    "打招呼",  // dzhh
+    "认识	人生	燃烧	人事",  // rsh
+    "形成	宣传	小吃	相处	香肠",  // xc
+    "有	也	一	与	要	又	以	月	于",  // y
+    "相关	修改	效果	习惯	性格	西瓜	香瓜",  // xg
+    "不得不",  // bdb
+    "思想	实现	首先	数学	熟悉	手续	事先	伤心	顺序",  // sx
+    "奇怪	秦国",  // qg
+    "成为	此外	错误	宠物",  // cw
+    "出口	参考	惭愧	诚恳	吃亏	车库",  // ck
+    "历史	老师	粮食	临时	律师	老实	老鼠	留守	零食",  // lsh
+    "全神贯注",  // qshgzh
+    "塑料袋",  // sld
+    "请愿书",  // qysh
+    "其实	确实	趋势	缺少	去世	轻视",  // qsh
+    "恶劣",  // el
+    "口味	看望",  // kw
+    "身体	石头	沙滩	手套",  // sht
+    "还是	红色	和尚	合适	忽视	花生	胡说	护士",  // hs
+    "矿泉水",  // kqs
+    "去	前	却	起	请	全	钱	取	瞧",  // q
+    "打篮球",  // dlq
+    "充电器",  // chdq
+    "乐观	栏杆	李广",  // lg
+    "许多	兄弟	行动	现代	相当	相对	显得",  // xd
+    "存在	创造	成长	初中	辞职	称赞	出自	乘坐	迟早",  // cz
+    "水平	商品",  // sp
+    "按时",  // as
+    "承认	成人	传染",  // cr
+    "时期	事情	失去	申请	生气",  // sq
+    "不同",  // bt
+    "培训	皮鞋",  // px
+    "暗暗",  // aa
+    "开幕式",  // kms
+    "广和居",  // ghj
+    "性质	限制	象征	寻找	校长	形状",  // xzh
+    "肾上腺素",  // shsxs
+    "为了	网络	未来	无论	物理	无聊",  // wl
+    "片面",  // pm
+    "用途	阳台",  // yt
+    "欧洲",  // ozh
+    "开玩笑",  // kwx
+    "说不定	舍不得",  // shbd
+    "笔记本",  // bjb
+    "不要紧",  // byj
+    "历史	老师	粮食	临时	律师	老实	老鼠	留守	零食",  // ls
+    "空气",  // kq
+    "独特	地图	代替	打听	电台	地铁	地毯	电梯",  // dt
+    "实现	首先	数学	熟悉	手续	事先	伤心	顺序	实习",  // shx
+    "记者	价值	建筑	集中	接着	竞争	紧张	机制	禁止",  // jz
+    "经过	结构	结果	价格	尽管	及格",  // jg
+    "光滑	挂号	干活儿",  // gh
+    "人才	日常	日程",  // rc
+    "赵福根",  // zfg
+    "其中	亲自	妻子	庆祝	签证	裙子",  // qz
+    "出租车",  // czch
+    "毕业	必要	表演	半夜	抱怨	暴雨	表扬",  // by
+    "生产	市场	蔬菜	身材	商场	删除	顺畅	时差",  // shc
+    "人	日	让	热	软	绕	弱	认	扔",  // r
+    "反应	反映	法院	翻译	发言	复印",  // fy
+    "夏威夷",  // xwy
+    "没	门	米	每	吗	马	美	明	买",  // m
+    "马萨诸塞州",  // mszszh
+    "《说文解字》",  // swjz
+    "赏心悦目",  // sxym
+    "客厅	空调",  // kt
+    "技术	建设	军事	精神	接受	结束	介绍	解释	教授",  // jsh
+    "偶尔",  // oe
+    "起来	权力	权利	强烈	情侣	七郎",  // ql
+    "超过	成功	成果	参观	楚国	唱歌",  // cg
+    "女士	难受",  // nsh
+    "峰终定律",  // fzhdl
+    "浪费	理发",  // lf
+    "决定	觉得	阶段	巨大	简单	绝对	降低	街道	角度",  // jd
+    "普通	葡萄",  // pt
+    "出租车",  // chzch
+    "不得了",  // bdl
+    "他	她	它	听	天	太	台	条	图",  // t
+    "夏令营",  // xly
+    "破坏	配合	平衡	《彷徨》",  // ph
+    "精诚所至",  // jcszh
+    "摄影师",  // shys
+    "调整	特征	通知	挑战	天真",  // tzh
+    "受到	时代	速度	适当	似的	首都	生动	缩短	商店",  // sd
+    "事半功倍",  // sbgb
+    "妈妈	秘密	妹妹	明明	密码	眉毛",  // mm
+    "反而",  // fe
+    "充满	沉默	聪明	匆忙",  // cm
+    "鼓楼大街",  // gldj
+    "条件	推荐	台阶",  // tj
+    "怀念",  // hn
+    "日期	热情",  // rq
+    "工程师",  // gcsh
+    "特别	逃避	退步",  // tb
+    "如今	软件	日记",  // rj
+    "正式	知识	总是	重视	至少	真实	暂时	遵守	装饰",  // zs
+    "打喷嚏",  // dpt
+    "诺贝尔奖",  // nbej
+    "妇女	烦恼",  // fn
+    "故事	改善	感受	果实",  // gsh
+    "领导	劳动	陆地	冷淡	朗读",  // ld
+    "有意思",  // yys
+    "新年	想念",  // xn
+    "热爱",  // ra
+    "应聘",  // yp
+    "认真",  // rzh
+    "橡皮",  // xp
+    "同时	特殊	听说	同事	坦率",  // tsh
+    "胃口",  // wk
+    "卫生间",  // wsj
+    "认为	任务	人物	入围",  // rw
+    "原来	以来	压力	原料	有利	议论	娱乐	一律	月亮",  // yl
+    "中秋节",  // zqj
+    "例如	利润",  // lr
+    "洗手间",  // xsj
+    "图书馆",  // tsg
+    "后来	回来	合理	汇率	婚礼	海里",  // hl
+    "日子	认真",  // rz
+    "年纪	年级",  // nj
+    "政府	丈夫	祝福	翟峰",  // zhf
+    "城市	产生	措施	从事	成熟	传说	出生	促使	承受",  // csh
+    "踢足球",  // tzq
+    "爱迪生",  // ads
+    "耳环",  // eh
+    "中国	整个	资格	战国	照顾	主观	糟糕	总共	转告",  // zg
+    "演出	延长	牙齿	养成",  // ych
+    "组织	政治	增长	战争	作战	真正	主张	作者	杂志",  // zzh
+    "盼望	评委",  // pw
+    "设备	身边	失败	士兵	上班	顺便	生病	手表	鼠标",  // shb
+    "因为	业务	以为	意外	义务	愿望	疑问",  // yw
+    "体会	昙花",  // th
+    "好奇",  // hq
+    "火车站",  // hczh
+    "理论	力量	浏览	轮流	流泪	姥姥	流利",  // ll
+    "刘炽平",  // lcp
+    "代表	对比	打扮",  // db
+    "服务员",  // fwy
+    "统一	同样	体育	同意	太阳	体验	讨厌	涂鸦",  // ty
+    "个人	工人	果然",  // gr
+    "讨论	投篮",  // tl
+    "设施	事实	损失	手术	随时	受伤	收拾	硕士	叔叔",  // ssh
+    "同时	特色	特殊	听说	同事	坦率",  // ts
+    "安排",  // ap
+    "召开	展开	状况	赵括",  // zhk
+    "开发	开放	看法	克服	咖啡",  // kf
+    "范围",  // fw
+    "温暖	无奈",  // wn
+    "趵突泉",  // btq
+    "十分	是否	双方	身份	师傅	舒服	沙发	说服	施肥",  // sf
+    "呼朋唤友",  // hphy
+    "来自	例子",  // lz
+    "救护车",  // jhc
+    "安静",  // aj
+    "忍不住",  // rbzh
+    "方法	丰富	纷纷	仿佛	反复",  // ff
+    "头发",  // tf
+    "打交道",  // djd
+    "联系	连续	类型	理想	流行	陆续	鲁迅	利息	旅行",  // lx
+    "事半功倍",  // shbgb
+    "老百姓",  // lbx
+    "北京	编辑	比较	不仅	毕竟	背景	笔记",  // bj
+    "什么	说明	生命	沙漠	神秘	寿命	数码	失眠	时髦",  // sm
+    "完成",  // wch
+    "批准",  // pzh
+    "客人	困扰",  // kr
+    "准备	逐步	转变	左边	责备	长辈",  // zb
+    "奶奶	牛奶",  // nn
+    "技术	建设	军事	精神	接受	结束	介绍	解释	教授",  // js
+    "宁可	内科",  // nk
+    "马萨诸塞州",  // mszhsz
+    "健康	进口	尽快	借口	艰苦",  // jk
+    "平安",  // pa
+    "动画片",  // dhp
+    "稻香村",  // dxc
+    "出版	传播	创办	翅膀	称霸",  // cb
+    "兴趣	心情	星期	吸取	小区	学期	象棋	小气",  // xq
+    "往往	文文",  // ww
+    "确定	强调	期待	缺点	清淡",  // qd
+    "竟然	既然	居然	节日	肌肉	假如",  // jr
+    "文化	危害	误会	问候	文火",  // wh
+    "列车	流传	凉茶	刘辰",  // lc
+    "弹钢琴",  // tgq
+    "中国	整个	战国	照顾	主观	转告	枝干	赵国	知感",  // zhg
+    "从	出	才	成	长	吃	称	处	次",  // c
+    "离开	立刻	路口	凉快",  // lk
+    "云南",  // yn
+    "耳朵",  // ed
+    "反对	发达	奋斗	否定	饭店	发抖	辅导	房东",  // fd
+    "政治	战争	真正	主张	专注	执照",  // zhzh
+    "救护车",  // jhch
+    "出租车",  // czc
+    "马萨诸塞州",  // mszsz
+    "詹姆士·奈史密斯",  // zmshnshms
+    "显然	信任	行人	形容",  // xr
+    "批评",  // pp
+    "会议	行业	欢迎	海洋	婚姻	怀疑	活跃	汉语	回忆",  // hy
+    "衣服	预防	应付	语法",  // yf
+    "摄影师",  // sys
+    "成长	初中	辞职	传真",  // czh
+    "赏心悦目",  // shxym
+    "秘书	面试	马上	美术	陌生",  // msh
+    "广东省",  // gdsh
+    "目标	明白	面包	毛病",  // mb
+    "皮肤	佩服	平方",  // pf
+    "美国	敏感",  // mg
+    "詹姆士·奈史密斯",  // zmshnsms
+    "其他	前途	奇特",  // qt
+    "办公室",  // bgsh
+    "财产	从此	长城	粗糙	出差	操场",  // cc
+    "欧洲环境保护署",  // ozhjbhs
+    "主任	主人",  // zhr
+    "看不起",  // kbq
+    "或者	慌张	护照",  // hzh
+    "志愿者",  // zyz
+    "精诚所至",  // jchszh
+    "平等	判断	频道	排队",  // pd
+    "其实	确实	趋势	轻松	缺少	去世	轻视",  // qs
+    "受不了",  // shbl
+    "如何	然后	任何",  // rh
+    "卢米埃尔",  // lmae
+    "亲爱",  // qa
+    "世界	时间	世纪	设计	实际	实践	数据	手机	射击",  // sj
+    "牛仔裤",  // nzk
+    "广泛	功夫	过分	刮风",  // gf
+    "美好	模糊	马虎",  // mh
+    "高速公路",  // gsgl
+    "欧盟",  // om
+    "天安门东",  // tamd
+    "开始	可是	考试	咳嗽	开水",  // ks
+    "人民	人名",  // rm
+    "水果	手工",  // sg
+    "成立	处理	材料	除了	从来	丛林	丑陋	窗帘",  // cl
+    "系领带",  // jld
+    "你们	农民	难免",  // nm
+    "时刻	深刻",  // shk
+    "新西兰",  // xxl
+    "东海龙王",  // dhlw
+    "摩托车",  // mtc
+    "当然	敌人	担任	打扰",  // dr
+    "下	向	新	想	小	笑	像	先	县",  // x
+    "佩·詹森",  // pzhs
+    "对话	兑换",  // dh
+    "直接	专家	逐渐	中间	至今	证据	着急	抓紧	中介",  // zhj
+    "气候",  // qh
+    "放暑假",  // fshj
+    "菜市口",  // csk
+    "状态	总统	昨天	整体	主题	转头	蒸腾	粘贴",  // zt
+    "成立	处理	除了	丑陋	窗帘",  // chl
+    "需要	吸引	校园	效应	幸运	小雨	鲜艳	下雨",  // xy
+    "怎么办",  // zmb
+    "另外	论文	礼物",  // lw
+    "初级中学",  // cjzx
+    "聊天	聊天儿",  // lt
+    "淋漓尽致",  // lljzh
+    "民族	名字	满足	帽子",  // mz
+    "城市	产生	成熟	传说	出生	承受	出色	潮湿	超市",  // chs
+    "派	平	破	片	拍	凭	批	篇	陪",  // p
+    "首都机场",  // sdjc
+    "礼拜天",  // lbt
+    "基本	进步	具备	举办	肩膀	加班	嘉宾	酒吧",  // jb
+    "组成	支持	造成	正常	主持	最初	赞成	照常",  // zch
+    "支持	正常	主持	注册	照常",  // zhc
+    "青壮年",  // qzn
+    "朝三暮四",  // zsms
+    "采用	参与	差异	测验	抽烟	次要	词语	春游	出院",  // cy
+    "一般来说",  // yblsh
+    "不过	报告	不管	宝贵	包裹	宾馆	悲观	饼干	表格",  // bg
+    "规模	购买	光明	感冒	过敏	国贸",  // gm
+    "支持	正常	主持	照常",  // zhch
+    "记者	价值	建筑	集中	接着	竞争	紧张	机制	禁止",  // jzh
+    "感兴趣",  // gxq
+    "一直	严重	原则	亚洲	样子	一致	银子	椅子	叶子",  // yz
+    "女士	难受",  // ns
+    "物质	位置	文章	网站	文字	完整	屋子	王子	袜子",  // wz
+    "大使馆",  // dsg
+    "命令	面临	美丽	目录	魅力	迷路	蒙眬",  // ml
+    "系统	相同",  // xt
+    "大家	登记	道具",  // dj
+    "中秋节",  // zhqj
+    "加利福尼亚州",  // jlfnyzh
+    "请求	悄悄	亲戚	亲切	情趣",  // qq
+    "发现	分析	方向	风险	放心	分享	复习	反省",  // fx
+    "不断	报道	不但	表达	病毒	波动	标点	报到",  // bd
+    "长城	出差",  // chch
+    "里查德·希尔斯",  // lcdxes
+    "以后	银行	一会儿	友好	优惠	遗憾	宴会	约会",  // yh
+    "好吃	好处	灰尘	火柴",  // hch
+    "儿童",  // et
+    "麦布里奇",  // mblq
+    "怎么样",  // zmy
+    "受到	时代	适当	似的	首都	生动	商店	闪电	摔倒",  // shd
+    "确认",  // qr
+    "考虑	快乐	可怜	可乐",  // kl
+    "十分	是否	双方	身份	师傅	舒服	沙发	说服	施肥",  // shf
+    "平时	爬山",  // psh
+    "所以	使用	所有	声音	属于	商业	适应	实验	生意",  // sy
+    "环境	黄金	缓解	行家	寒假	红酒",  // hj
+    "痛苦	天空	痛快	填空",  // tk
+    "企业	其余	轻易	汽油	情缘",  // qy
+    "俱乐部",  // jlb
+    "标准	帮助	保证	不足	标志	报纸	本质	脖子	鼻子",  // bz
+    "非常	讽刺	帆船	发愁",  // fc
+    "公开	赶快	顾客	概括",  // gk
+    "完成",  // wc
+    "一辈子",  // ybz
+    "和平	害怕	活泼",  // hp
+    "西红柿",  // xhs
+    "羽毛球",  // ymq
+    "但是	当时	多少	电视	对手	大厦	到时候",  // dsh
+    "文学家",  // wxj
+    "恐怕	可怕",  // kp
+    "地区	地球	的确	道歉	颠球",  // dq
+    "应该	有关	严格	员工	阳光	勇敢	一共	牙膏	用功",  // yg
+    "按照	安装",  // az
+    "过程	广场	构成	工厂	观察",  // gch
+    "偶然",  // or
+    "键盘",  // jp
+    "自然	主任	责任	主人",  // zr
+    "北京师范大学",  // bjshfdx
+    "长江大桥",  // chjdq
+    "摄影师",  // shysh
+    "风格",  // fg
+    "脾气	迫切",  // pq
+    "长期	春秋",  // chq
+    "加油站",  // jyzh
+    "依然	羊肉",  // yr
+    "现在	选择	性质	限制	象征	寻找	校长	形状	写作",  // xz
+    "护国寺",  // hgs
+    "外交	文件	忘记	玩具	围巾	文具",  // wj
+    "管理	公里	规律	鼓励	光临",  // gl
+    "国家	国际	根据	感觉	高级	关键	工具	估计	公斤",  // gj
+    "灰姑娘",  // hgn
+    "歪歪扭扭",  // wwnn
+    "长江大桥",  // cjdq
+    "志愿者",  // zyzh
+    "农村",  // nc
+    "每天	媒体	明天	煤炭	面条	馒头	木头	模特	苗条",  // mt
+    "热闹",  // rn
+    "正确	争取	追求	准确	征求	整齐",  // zq
+    "非常	帆船	发愁",  // fch
+    "胜利	数量	森林	顺利	商量	熟练	善良	省略",  // sl
+    "列车	流传	凉茶	刘辰",  // lch
+    "二手",  // es
+    "方面	父母	发明",  // fm
+    "未必	尾巴	微博",  // wb
+    "詹姆士·奈史密斯",  // zhmsnshms
+    "奔跑	鞭炮",  // bp
+    "土地	特点	态度	土豆	天敌",  // td
+    "加油站",  // jyz
+    "谈判	调皮",  // tp
+    "个	过	给	高	更	各	跟	国	干",  // g
+    "詹姆士·奈史密斯",  // zhmshnsms
+    "肾上腺素",  // sshxs
+    "充满	沉默",  // chm
+    "课程	昆虫	卡车",  // kc
+    "关注	公主	观众	改正	鼓掌	果汁	根治",  // gzh
+    "石灰岩	售货员",  // shhy
+    "水平	商品",  // shp
+    "发生	方式	分手	发烧",  // fsh
+    "联合	良好	厉害	落后	灵活	离婚	老虎",  // lh
+    "黑猩猩",  // hxx
+    "斯坦福",  // stf
+    "建立	经历	交流	距离	记录	激烈	将来	积累	经理",  // jl
+    "传统	餐厅	抽屉	池塘	长途",  // ct
+    "秘书	面试	马上	美术	陌生",  // ms
+    "调查	到处	单纯	电池	堵车",  // dch
+    "断断续续",  // ddxx
+    "知道	制度	制定	重点	重大	指导	针对	主动	值得",  // zd
+    "西红柿",  // xhsh
+    "正式	知识	重视	至少	真实	装饰	准时	赵奢",  // zhsh
+    "植物	周围	掌握	中文	中午",  // zhw
+    "法律	风浪",  // fl
+    "放暑假",  // fsj
+    "欧洲环境保护署",  // ozhhjbhs
+    "稳定	伟大	温度	味道	舞蹈	外地",  // wd
+    "出	成	长	吃	称	处	除	朝	场",  // ch
+    "参加	长江	促进	曾经	成绩	成就	刺激	差距	初级",  // cj
+    "孩子	或者	合作	汉字	猴子	盒子	慌张	护照",  // hz
+    "可爱",  // ka
+    "纸上谈兵",  // zhstb
+    "财产	长城	出差	操场",  // cch
+    "一般	一边	预报	右边	拥抱	要不",  // yb
+    "人口	入口",  // rk
+    "打电话",  // ddh
+    "录取	力气	零钱",  // lq
+    "何况	好客",  // hk
+    "批准	盘子	瓶子	盆子	棚子",  // pz
+    "《卖火柴的小女孩儿》",  // mhchdxnh
+    "麦克风",  // mkf
+    "欧洲环境保护署",  // ozhjbhsh
+    "多么	对面	倒霉",  // dm
+    "洗手间",  // xshj
+    "我	为	无	外	问	万	王	往	位",  // w
+    "自行车",  // zxch
+    "生产	市场	色彩	蔬菜	身材	商场	丝绸	删除	顺畅",  // sc
+    "首都机场",  // shdjc
+    "收入	输入	湿润	生日	摄入",  // shr
+    "广东省",  // gds
+    "为什么",  // wsm
+    "北京师范大学",  // bjsfdx
+    "内部",  // nb
+    "体现	同学	提醒	退休	腾讯",  // tx
+    "辛苦	幸亏",  // xk
+    "差不多",  // chbd
+    "本来	比例	保留	办理	玻璃	本领	辩论",  // bl
+    "患难与共",  // hnyg
+    "名胜古迹",  // mshgj
+    "主要	重要	职业	专业	只要	终于	注意	只有	至于",  // zhy
+    "美人鱼",  // mry
+    "工作	关注	工资	各自	公主	规则	观众	干燥	个子",  // gz
+    "博物馆",  // bwg
+    "马萨诸塞州",  // mszhszh
+    "必须	表现	不行	保险	冰箱",  // bx
+    "称呼	窗户	车祸",  // chh
+    "为什么",  // wshm
+    "水果	手工",  // shg
+    "单位	地位	动物	耽误	大卫",  // dw
+    "纸上谈兵",  // zstb
+    "内容	纳入",  // nr
+    "政府	丈夫	祝福	翟峰",  // zf
+    "可以	烤鸭",  // ky
+    "国庆节",  // gqj
+    "可靠	刻苦",  // kk
+    "卫生间",  // wshj
+    "但是	当时	多少	电视	对手	打算	大厦	打扫",  // ds
+    "产品",  // chp
+    "闹钟",  // nzh
+    "开幕式",  // kmsh
+    "可	看	开	快	口	宽	靠	哭	空",  // k
+    "电子邮件",  // dzyj
+    "爱心	爱惜",  // ax
+    "表示	比赛	不少	博士	办事	悲伤	笔试	报社",  // bs
+    "淋漓尽致",  // lljz
+    "图书馆",  // tshg
+    "机器	极其	减轻	坚强	郊区	价钱",  // jq
+    "位于	唯一	万一	无意	喂养",  // wy
+    "完善	晚上	无数	旺盛	卧室	武术	握手",  // ws
+    "活动	获得	回答	蝴蝶",  // hd
+    "欧洲环境保护署",  // ozhhjbhsh
+    "别人	比如	必然	不如	不然",  // br
+    "六一儿童节",  // lyetj
+    "情绪	取消	谦虚",  // qx
+    "干活儿",  // ghe
+    "学生	形式	先生	迅速	小时	显示	销售	形势	小说",  // xs
+    "初级中学",  // cjzhx
+    "改变	根本	公布	个别	广播	关闭	胳膊	告别	隔壁",  // gb
+    "不客气",  // bkq
+    "作品	招聘	照片	支票",  // zp
+    "小伙子",  // xhz
+    "一般来说",  // ybls
+    "志愿者",  // zhyz
+    "城市	产生	措施	从事	成熟	传说	出生	促使	承受",  // cs
+    "基础	坚持	机场	经常	检查	接触	精彩	警察	教材",  // jc
+    "的	到	都	等	对	地	大	得	多",  // d
+    "准备	逐步	转变	长辈",  // zhb
+    "得到	达到	当地	到底	道德	到达	等待	地点	单独",  // dd
+    "完善	晚上	无数	旺盛	卧室	武术	握手",  // wsh
+    "公司	告诉	故事	改善	感受	果实",  // gs
+    "峰终定律",  // fzdl
+    "不假思索",  // bjss
+    "太极拳",  // tjq
+    "传统	抽屉	池塘	长途",  // cht
+    "充分	成分	出发	吃饭	重复	除非	厨房",  // chf
+    "健步如飞",  // jbrf
+    "无法	违反	往返",  // wf
+    "好像	化学	核心	互相	呼吸	或许	幻想	害羞	海鲜",  // hx
+    "澳大利亚",  // adly
+    "把握	傍晚",  // bw
+    "关系	贡献	高兴	关心	感谢	个性	光线	恭喜	感想",  // gx
+    "正确	争取	追求	准确	征求	整齐",  // zhq
+    "控制	夸张",  // kzh
+    "生物钟",  // shwz
+    "菜市口",  // cshk
+    "承认	成人	传染",  // chr
+    "发生	方式	放松	风俗	分手	发烧	粉色",  // fs
+    "朝三暮四",  // zhsms
+    "导致	地址	地震	打招呼	打折	打针",  // dzh
+    "设备	身边	失败	士兵	随便	上班	顺便	生病	散步",  // sb
+    "发挥	符合	废话	返航",  // fh
+    "学校	学习	信息	消息	现象	相信	形象	休息	信心",  // xx
+    "透明	题目",  // tm
+    "情况",  // qk
+    "理发店",  // lfd
+    "欧洲",  // oz
+    "希望	新闻	行为	下午	询问	学问",  // xw
+    "面积	毛巾	民居",  // mj
+    "小姐	戏剧	细节	现金	消极	香蕉	孝敬",  // xj
+    "充分	成分	出发	采访	吃饭	重复	除非	厨房",  // cf
+    "全面	前面	球迷	巧妙",  // qm
+    "初级中学",  // chjzhx
+    "人类	热烈	日历",  // rl
+    "开始	可是	考试	开水",  // ksh
+    "天气	提前	同情	淘气",  // tq
+    "里查德·希尔斯",  // lchdxes
+    "科学	可惜	开心	空闲",  // kx
+    "正式	知识	重视	至少	真实	装饰	准时	赵奢",  // zhs
+    "说不定	舍不得",  // sbd
+    "产品",  // cp
+    "老婆	廉颇",  // lp
+    "可能	困难",  // kn
+    "年轻",  // nq
+    "金石为开",  // jswk
+    "物质	位置	文章	网站	完整	万丈",  // wzh
+    "知更鸟",  // zgn
+    "全部	区别	铅笔	钱包",  // qb
+    "初级中学",  // chjzx
+    "超过	成功	成果	楚国	唱歌",  // chg
+    "甚至	随着	实在	生长	始终	数字	手指	狮子	睡着",  // sz
+    "按照	安装",  // azh
+    "通过	提高	提供	推广	提纲",  // tg
+    "女孩	女孩儿	暖和	《呐喊》",  // nh
+    "信用卡",  // xyk
+    "公共汽车",  // ggqc
+    "如果",  // rg
+    "哪怕",  // np
+    "其中	庆祝	签证",  // qzh
+    "平常	赔偿	破产",  // pch
+    "火成岩",  // hchy
+    "闹钟",  // nz
+    "加利福尼亚州",  // jlfnyz
+    "对于	第一	电影	答应	大约	等于	导演	岛屿	待遇",  // dy
+    "旅游	利用	领域	利益	理由	录音	老鹰",  // ly
+    "城市	产生	成熟	传说	出生	承受	潮湿	超市	常识",  // chsh
+    "艺术	于是	运输	优势	医生	要是	印刷	样式	钥匙",  // ysh
+    "名胜古迹",  // msgj
+    "文学	危险	威胁	微笑	维修	微信",  // wx
+    "郁达夫",  // ydf
+    "新鲜感",  // xxg
+    "提问	跳舞",  // tw
+    "阿姨	熬夜",  // ay
+    "《卖火柴的小女孩儿》",  // mhcdxnh
+    "怎么	咱们	著名	专门	证明	字母	周末	赞美	字幕",  // zm
+    "巧克力",  // qkl
+    "刘炽平",  // lchp
+    "按时",  // ash
+    "独立	地理	道理	锻炼	低落",  // dl
+    "公共汽车",  // ggqch
+    "虽然	收入	私人	输入	湿润	生日	摄入",  // sr
+    "状态	整体	主题	转头	蒸腾	粘贴",  // zht
+    "疯狂	罚款	付款",  // fk
+    "登机牌",  // djp
+    "分	发	非	放	方	翻	份	犯	扶",  // f
+    "门槛	马克	毛孔",  // mk
+    "问题",  // wt
+    "分配	发票",  // fp
+    "通常	突出	提倡	推迟	推辞",  // tc
+    "矿泉水",  // kqsh
+    "生物钟",  // swzh
+    "而且",  // eq
+    "到时候",  // dshh
+    "后面	号码",  // hm
+    "贷款	多亏",  // dk
+    "大学	东西	大型	担心	对象	短信	点心	大象	当心",  // dx
+    "张小龙",  // zxl
+    "佩·詹森",  // pzs
+    "生物钟",  // swz
+    "社会	生活	时候	上海	说话	适合	伤害	神话	收获",  // shh
+    "金石为开",  // jshwk
+    "部分	办法	北方	播放",  // bf
+    "今天	交通	具体	家庭	集体",  // jt
+    "形成	宣传	小吃	相处	香肠",  // xch
+    "喜欢	信号	西汉	消化	鲜花	笑话",  // xh
+    "比尔·盖茨",  // begc
+    "认识	人生	燃烧	人事",  // rs
+    "厘米	连忙	浪漫	礼貌",  // lm
+    "牛津大学",  // njdx
+    "这些	中心	主席	哲学	咨询	仔细	秩序	自信	中旬",  // zx
+    "资料	总理	质量	治疗	种类	整理	重量	展览	争论",  // zl
+    "行李箱",  // xlx
+    "火成岩",  // hcy
+    "父亲	放弃",  // fq
+    "苹果",  // pg
+    "股票	公平	光盘",  // gp
+    "《卖火柴的小女孩儿》",  // mhchdxnhe
+    "太太	体贴",  // tt
+    "是	上	说	所	使	三	死	省	手",  // s
+    "日用品",  // ryp
+    "工程师",  // gcs
+    "大概	度过	打工	蛋糕",  // dg
+    "著名	专门	证明	周末",  // zhm
+    "盲人",  // mr
+    "去年",  // qn
+    "已经	研究	以及	眼睛	意见	悠久	迎接	硬件	演讲",  // yj
+    "大使馆",  // dshg
+    "飞机	附近	风景	房间",  // fj
+    "消费	相反	幸福	兴奋	厢房	信封",  // xf
+    "艺术	于是	因素	运输	意思	优势	医生	颜色	要是",  // ys
+    "不	把	被	并	便	本	比	吧	别",  // b
+    "出版	传播	创办	翅膀	称霸",  // chb
+    "影响	一下	有效	也许	英雄	优秀	允许	游戏	有限",  // yx
+    "普通话",  // pth
+    "平时	爬山",  // ps
+    "出口	诚恳	吃亏	车库",  // chk
+    "自行车",  // zxc
+    "《说文解字》",  // shwjz
+    "忍不住",  // rbz
+    "设施	事实	手术	受伤	收拾	硕士	叔叔	时尚	舒适",  // shs
+    "会员卡",  // hyk
+    "火车站",  // hchz
+    "哲学家	照相机",  // zhxj
+    "怪不得",  // gbd
+    "合同	话题	胡同	糊涂",  // ht
+    "采取	长期	春秋	从前",  // cq
+    "事物	食物	上午	失望	商务	上网	稍微",  // shw
+    "导致	地址	动作	地震	肚子	打折	袋子	打针",  // dz
+    "还是	和尚	合适	忽视	花生	胡说	护士",  // hsh
+    "体育馆",  // tyg
+    "时期	事情	失去	申请	生气",  // shq
+    "互联网",  // hlw
+    "骄傲",  // ja
+    "在	这	着	之	只	最	再	走	做",  // z
+    "生产	市场	商场	丝绸	删除	顺畅	时差",  // sch
+    "程度	彻底	承担	词典	迟到	菜单",  // cd
+    "创造	成长	初中	称赞	出自	乘坐	迟早	传真	尺子",  // chz
+    "爱好	爱护",  // ah
+    "老板	萝卜",  // lb
+    "知更鸟",  // zhgn
+    "黄河	豪华	后悔	花花",  // hh
+    "见面	节目	急忙	寂寞",  // jm
+    "相敬如宾",  // xjrb
+    "百分之",  // bfz
+    "年	你	那	能	内	呢	女	南	拿",  // n
+    "胜利	数量	顺利	商量	熟练	善良	省略",  // shl
+    "人员	容易",  // ry
+    "设施	事实	损失	手术	随时	受伤	搜索	收拾	硕士",  // ss
+    "什么	说明	生命	沙漠	神秘	寿命	数码	失眠	时髦",  // shm
+    "恢复	合法",  // hf
+    "污染	围绕	温柔",  // wr
+    "电脑",  // dn
+    "程度	彻底	承担	迟到",  // chd
+    "好吃	好处	灰尘	火柴",  // hc
+    "纸上谈兵",  // zhshtb
+    "冰激凌",  // bjl
+    "经济	解决	积极	究竟	拒绝	接近	季节	坚决	姐姐",  // jj
+    "并且	表情	抱歉	鲍全",  // bq
+    "潘家园",  // pjy
+    "投资	调整	特征	通知	挑战	天真	兔子	蹄子",  // tz
+    "洛杉矶",  // lsj
+    "志愿者",  // zhyzh
+    "爸爸	宝贝",  // bb
+    "哲学家	照相机",  // zxj
+    "甚至	随着	生长	始终	手指	睡着",  // szh
+    "保持	补充	八成	八成儿",  // bch
+    "百分之",  // bfzh
+    "指挥	只好	正好	智慧	祝贺	账户	着火",  // zhh
+    "身体	石头	沙滩	手套",  // st
+    "发展	分钟	反正	服装	复制	负重",  // fzh
+    "对不起",  // dbq
+    "所谓	事物	食物	上午	失望	商务	上网	稍微",  // sw
+    "爱迪生",  // adsh
+    "平均	评价	平静	啤酒",  // pj
+    "标准	帮助	保证	标志	报纸	本质	步骤",  // bzh
+    "甚至	实在	生长	始终	数字	手指	狮子	睡着	绳子",  // shz
+    "年代	难道	脑袋",  // nd
+    "纸上谈兵",  // zshtb
+    "能力	努力	哪里	年龄",  // nl
+    "和	还	后	好	会	很	号	回	红",  // h
+    "詹姆士·奈史密斯",  // zhmsnsms
+    "精诚所至",  // jcsz
+    "清楚	其次	青春	起床",  // qc
+    "肾上腺素",  // ssxs
+    "肾上腺素",  // shshxs
+    "请愿书",  // qys
+    "要求	一切	一起	引起	以前	尤其	邀请	与其	勇气",  // yq
+    "清楚	青春	起床",  // qch
+    "我们	文明	外面	完美",  // wm
+    "胆小鬼",  // dxg
+    "青少年",  // qshn
+    "青壮年",  // qzhn
+    "詹姆士·奈史密斯",  // zmsnshms
+    "讨价还价",  // tjhj
+    "地方	对方	大夫	豆腐	大方",  // df
+    "愉快",  // yk
+    "规定	感到	古代	观点	广大	固定	感动	古典	高档",  // gd
+    "主要	重要	作用	职业	资源	专业	左右	只要	终于",  // zy
+    "了	来	里	两	老	路	连	另	拉",  // l
+    "宣布	性别",  // xb
+    "摄影师",  // sysh
+    "女性	耐心",  // nx
+    "二手",  // esh
+    "全神贯注",  // qsgz
+    "明显	明星	描写	梦想	冒险	梅西",  // mx
+    "今年	纪念	济南",  // jn
+    "生物钟",  // shwzh
+    "正式	知识	总是	重视	至少	真实	暂时	遵守	装饰",  // zsh
+    "日常	日程",  // rch
+    "由于	一样	原因	应用	意义	语言	医院	音乐	愿意",  // yy
+    "名牌	名片",  // mp
+    "詹姆士·奈史密斯",  // zhmshnshms
+    "控制	孔子	筷子	夸张	裤子",  // kz
+    "项目	下面	小麦	羡慕	熊猫",  // xm
+    "工程师",  // gchs
+    "繁荣	否认",  // fr
+    "火车站",  // hchzh
+    "长城	出差",  // chc
+    "知道	制度	制定	重点	重大	指导	针对	主动	值得",  // zhd
+    "恩爱",  // ea
+    "普遍	旁边	跑步",  // pb
+    "长江	成绩	成就	差距	初级	春节	吃惊	超级	吵架",  // chj
+    "儿子",  // ez
+    "《非你莫属》",  // fnmsh
+    "突然	投入	天然",  // tr
+    "天津卫视",  // tjws
+    "包括	本科",  // bk
+    "目的	面对	矛盾	美德",  // md
+    "表示	不少	博士	办事	悲伤	笔试	报社",  // bsh
+    "政治	战争	真正	制造	主张	正在	制作	桌子	专注",  // zhz
+    "安慰",  // aw
+    "扩大	肯定",  // kd
+    "忽然",  // hr
+    "改革	哥哥	广告	姑姑",  // gg
+    "进行	举行	继续	决心	教训	家乡	积蓄",  // jx
+    "王宫	外公",  // wg
+    "安全	爱情",  // aq
+    "课程	昆虫	卡车",  // kch
+    "丹尼尔·卡内曼",  // dneknm
+    "不见得",  // bjd
+    "首都机场",  // shdjch
+    "是	上	说	使	社会	生活	时候	省	手",  // sh
+    "无所谓",  // wsw
+    "差不多",  // cbd
+    "洛杉矶",  // lshj
+    "客观",  // kg
+    "没有	贸易	满意	命运",  // my
+];
+
+pub static ABBREV_WEIGHT: &[&[u32]] = &[
+    &[530, 19],  // jf
+    &[18510, 10215, 7741, 7253, 5464, 3348, 1677],  // jch
+    &[28567, 7735, 5681, 5342, 1755, 1449, 947, 660],  // zw
+    &[1626, 1019, 1],  // jw
+    &[81],  // lhh
+    &[9200, 5850, 2013, 324],  // py
+    &[645],  // rx
+    &[3200],  // yhe
+    &[1766, 1664, 813],  // hg
+    &[2968],  // rmb
+    &[8332, 3414, 1679, 693, 163, 49],  // dc
+    &[664],  // bnf
+    &[2196, 403, 265, 166, 129],  // chy
+    &[259],  // qsgzh
+    &[32898, 26927, 730, 358, 154, 52],  // shch
+    &[8161, 5289, 1254, 484],  // tch
+    &[261791, 188584, 140957, 101442, 40281, 37763, 29609, 25139, 23973],  // zh
+    &[25633, 8297, 5610, 4676, 4001, 933, 797, 683, 512],  // chx
+    &[3885, 2305, 182],  // zhp
+    &[26922, 24866, 20465, 14823, 10812, 9540, 6418, 6284, 5041],  // zz
+    &[1],  // zmsnsms
+    &[1],  // zhfg
+    &[1],  // hls
+    &[18596, 10445, 5863, 5406, 160],  // yzh
+    &[24035, 10340, 6871, 6280, 5971, 5742, 1719, 1707, 1417],  // shy
+    &[10261, 5322, 3399, 3073, 2165, 260, 1],  // bc
+    &[1722, 1255, 1231, 1075, 614],  // ym
+    &[14935, 13874, 2938],  // bh
+    &[15691, 10022, 5511, 2567, 1473],  // kj
+    &[33],  // ljt
+    &[1337, 1072, 994],  // pc
+    &[259],  // qshgz
+    &[23294, 4125, 1229, 1188, 809],  // yc
+    &[23961, 9803, 9327, 7456, 1574, 1213, 883, 417, 206],  // jy
+    &[3312, 1290, 994, 988, 609, 527, 171],  // gw
+    &[107],  // sdjch
+    &[230],  // xpy
+    &[8853, 8056, 4435, 4115, 630],  // gn
+    &[1],  // fnms
+    &[237],  // mgx
+    &[1223],  // qsn
+    &[15627, 961, 415, 84],  // wq
+    &[7],  // jchsz
+    &[8526],  // rr
+    &[5432, 2570, 451],  // chzh
+    &[19799, 9875, 9754, 8462, 3579, 2967, 1952, 797, 496],  // jh
+    &[9138, 8163, 5444, 243, 1],  // zk
+    &[35966, 203],  // chw
+    &[802],  // ldj
+    &[16543, 5166, 4833, 3827, 1606, 991],  // bm
+    &[14656, 1770, 983, 238, 229],  // qj
+    &[68664, 13745, 6285, 6117, 4209, 3194, 2166, 1876, 670],  // fz
+    &[1],  // hlsh
+    &[18396, 8010, 5812, 3889],  // mq
+    &[18051, 7424, 6416, 5617, 3460, 3309, 1290],  // gc
+    &[465],  // yyh
+    &[1178],  // lbj
+    &[26775, 13762, 6748, 4609, 2271, 597],  // gy
+    &[422],  // mtch
+    &[1117],  // hcz
+    &[16233, 2232, 297],  // ny
+    &[11774, 6937, 5620, 2059, 1165, 1102, 613, 433, 417],  // lj
+    &[25633, 8297, 5610, 4676, 4001, 933, 797, 683, 625],  // cx
+    &[1],  // mhcdxnhe
+    &[1569],  // gchsh
+    &[25293, 18435, 5312, 5143, 4979, 3838, 3706, 1302, 1058],  // yd
+    &[16537, 12435, 8028, 3718],  // fb
+    &[3074, 1876, 1611, 1479, 1045, 310, 153],  // hb
+    &[3],  // zhxl
+    &[535],  // sbl
+    &[2059, 1810, 969, 400, 360],  // mf
+    &[17444, 3806, 186],  // gq
+    &[3448, 3029, 2216],  // sk
+    &[405],  // yey
+    &[30776, 15188, 14470, 8143, 6872, 6078, 5510, 5501, 5080],  // xsh
+    &[2830, 2361, 340],  // qw
+    &[4373],  // bgs
+    &[273122, 43431, 41022, 34677, 25668, 17557, 15314, 13587, 12172],  // j
+    &[336],  // ppq
+    &[4803, 4593, 3305, 2402, 2274, 1346, 1103, 1044, 642],  // shsh
+    &[10510, 2610, 2572, 851, 373],  // gt
+    &[7829, 6715, 2443, 1460, 596, 428, 187],  // xl
+    &[8009, 7678, 4950, 1932, 1610, 1437, 1337, 258, 127],  // zhl
+    &[12],  // cdq
+    &[90933, 16195, 14906, 11094, 7853, 6555, 6547, 5355, 3358],  // zj
+    &[826],  // lbq
+    &[117],  // shdy
+    &[4171, 2619, 1024, 745, 377],  // qf
+    &[117],  // sdy
+    &[395],  // chzc
+    &[20572, 10928, 9428, 5860, 5153, 2996, 2476, 1812, 1431],  // zc
+    &[34387, 33288, 21100, 12474, 12010, 5441, 5232, 4789, 1874],  // shj
+    &[15239, 6940, 5358, 2516, 1057],  // shzh
+    &[1],  // tjwsh
+    &[47400, 23969, 20859, 4412, 2935, 922, 511, 437, 436],  // zhx
+    &[2737, 1921, 1148],  // pl
+    &[370],  // dzhh
+    &[8901, 4255, 1553, 1338],  // rsh
+    &[25854, 4745, 938, 777, 108],  // xc
+    &[423765, 307851, 217830, 160984, 156581, 150749, 136106, 110207, 106176],  // y
+    &[12463, 5259, 4924, 4593, 2521, 449, 17],  // xg
+    &[3096],  // bdb
+    &[15387, 15301, 9248, 5993, 3053, 1651, 1601, 1388, 1265],  // sx
+    &[3898, 257],  // qg
+    &[35966, 9832, 6379, 203],  // cw
+    &[8237, 2597, 682, 386, 282, 32],  // ck
+    &[34460, 6415, 5923, 4483, 1826, 924, 747, 452, 397],  // lsh
+    &[259],  // qshgzh
+    &[96],  // sld
+    &[40],  // qysh
+    &[11950, 5767, 3047, 1951, 1590, 472],  // qsh
+    &[1009],  // el
+    &[2153, 627],  // kw
+    &[7962, 1953, 993, 231],  // sht
+    &[9922, 4243, 3959, 2426, 1195, 950, 699, 676],  // hs
+    &[130],  // kqs
+    &[123402, 62779, 61348, 58684, 23523, 22165, 17871, 10965, 9467],  // q
+    &[3],  // dlq
+    &[12],  // chdq
+    &[827, 776, 43],  // lg
+    &[25601, 9644, 9066, 9035, 6568, 6532, 4888],  // xd
+    &[15579, 6687, 5432, 2570, 1276, 1123, 847, 687, 517],  // cz
+    &[13880, 10160],  // sp
+    &[721],  // as
+    &[4242, 1366, 282],  // cr
+    &[19421, 15354, 4317, 2869, 2416],  // sq
+    &[29383],  // bt
+    &[4676, 479],  // px
+    &[2156],  // aa
+    &[360],  // kms
+    &[1],  // ghj
+    &[8018, 5089, 4233, 3960, 3791, 2015],  // xzh
+    &[140],  // shsxs
+    &[21073, 8352, 6069, 4936, 2709, 524],  // wl
+    &[510],  // pm
+    &[1375, 242],  // yt
+    &[9256],  // ozh
+    &[724],  // kwx
+    &[1914, 668],  // shbd
+    &[930],  // bjb
+    &[401],  // byj
+    &[34460, 6415, 5923, 4483, 1826, 924, 747, 452, 397],  // ls
+    &[3732],  // kq
+    &[3638, 3085, 2262, 1627, 1188, 1127, 747, 524],  // dt
+    &[15301, 9248, 5993, 3053, 1651, 1601, 1388, 1265, 1023],  // shx
+    &[24649, 15777, 14397, 9232, 6206, 5985, 4790, 4138, 2582],  // jz
+    &[16218, 14200, 13963, 11762, 7018, 148],  // jg
+    &[511, 69, 53],  // gh
+    &[8742, 4737, 322],  // rc
+    &[1],  // zfg
+    &[34173, 5315, 4532, 1612, 444, 343],  // qz
+    &[395],  // czch
+    &[5988, 5175, 3088, 970, 945, 558, 300],  // by
+    &[32898, 26927, 2085, 1275, 730, 358, 154, 52],  // shc
+    &[313209, 78695, 50310, 11755, 3730, 3037, 2984, 2506, 2277],  // r
+    &[7652, 6618, 2312, 2019, 1521, 181],  // fy
+    &[312],  // xwy
+    &[52407, 39823, 35136, 26048, 21245, 19918, 16809, 16120, 10529],  // m
+    &[113],  // mszszh
+    &[1],  // swjz
+    &[68],  // sxym
+    &[816, 614],  // kt
+    &[37664, 26381, 16552, 12961, 10415, 8944, 8926, 7421, 7419],  // jsh
+    &[1361],  // oe
+    &[39788, 14581, 7829, 5076, 196, 1],  // ql
+    &[12732, 10638, 4800, 1978, 437, 351],  // cg
+    &[1448, 827],  // nsh
+    &[1],  // fzhdl
+    &[1134, 94],  // lf
+    &[32770, 15995, 9574, 8034, 6935, 5260, 4432, 3824, 3247],  // jd
+    &[6768, 811],  // pt
+    &[395],  // chzch
+    &[363],  // bdl
+    &[401339, 134035, 60864, 45776, 35979, 24899, 16964, 12583, 12112],  // t
+    &[66],  // xly
+    &[4991, 4307, 3970, 1],  // ph
+    &[7],  // jcszh
+    &[106],  // shys
+    &[8590, 5767, 3130, 2615, 520],  // tzh
+    &[11428, 10799, 8218, 5584, 4637, 4278, 1266, 976, 886],  // sd
+    &[90],  // sbgb
+    &[4525, 3833, 2149, 1305, 1022, 612],  // mm
+    &[3547],  // fe
+    &[4432, 2313, 2068, 439],  // cm
+    &[1],  // gldj
+    &[17290, 3823, 1041],  // tj
+    &[437],  // hn
+    &[3571, 2818],  // rq
+    &[1569],  // gcsh
+    &[19119, 532, 71],  // tb
+    &[9684, 4601, 639],  // rj
+    &[9212, 8254, 6089, 6063, 5775, 3212, 2763, 1982, 1631],  // zs
+    &[71],  // dpt
+    &[138],  // nbej
+    &[5485, 1026],  // fn
+    &[5953, 4262, 2620, 856],  // gsh
+    &[31739, 11598, 1110, 382, 77],  // ld
+    &[448],  // yys
+    &[570, 317],  // xn
+    &[869],  // ra
+    &[868],  // yp
+    &[4703],  // rzh
+    &[120],  // xp
+    &[30245, 7015, 5887, 2249, 287],  // tsh
+    &[337],  // wk
+    &[150],  // wsj
+    &[30204, 15213, 10520, 122],  // rw
+    &[15084, 10402, 6044, 4055, 2291, 1678, 1664, 1565, 1037],  // yl
+    &[133],  // zqj
+    &[6232, 4472],  // lr
+    &[105],  // xsj
+    &[1551],  // tsg
+    &[13932, 12128, 3870, 1220, 890, 856],  // hl
+    &[5113, 4703],  // rz
+    &[3320, 657],  // nj
+    &[23452, 4283, 327, 1],  // zhf
+    &[25084, 19495, 8163, 7036, 3742, 3372, 1979, 1460, 1458],  // csh
+    &[3],  // tzq
+    &[65],  // ads
+    &[120],  // eh
+    &[129470, 11839, 3379, 2735, 2157, 1139, 755, 660, 174],  // zg
+    &[4125, 1229, 1188, 809],  // ych
+    &[26922, 24866, 20465, 14823, 10812, 9540, 6284, 4024, 2451],  // zzh
+    &[698, 175],  // pw
+    &[8176, 5641, 4487, 3681, 1337, 1008, 469, 324, 230],  // shb
+    &[35698, 7737, 6133, 2197, 2059, 1643, 881],  // yw
+    &[1032, 19],  // th
+    &[721],  // hq
+    &[1117],  // hczh
+    &[15973, 10069, 1469, 694, 560, 240, 215],  // ll
+    &[1],  // lcp
+    &[32777, 1175, 1129],  // db
+    &[321],  // fwy
+    &[13986, 7733, 6976, 5348, 5044, 1688, 745, 25],  // ty
+    &[12744, 10209, 4909],  // gr
+    &[6464, 232],  // tl
+    &[4803, 4593, 3510, 3305, 2476, 2402, 2274, 1346, 1103],  // ssh
+    &[30245, 8092, 7015, 5887, 2249, 287],  // ts
+    &[7836],  // ap
+    &[9138, 8163, 5444, 1],  // zhk
+    &[10022, 8332, 2491, 1473, 1400],  // kf
+    &[12101],  // fw
+    &[1606, 1567],  // wn
+    &[31],  // btq
+    &[16428, 9996, 7289, 2691, 1976, 1144, 862, 610, 134],  // sf
+    &[5],  // hphy
+    &[6801, 1607],  // lz
+    &[166],  // jhc
+    &[1199],  // aj
+    &[2838],  // rbzh
+    &[18045, 11867, 4973, 3124, 2882],  // ff
+    &[3096],  // tf
+    &[463],  // djd
+    &[9767, 6317, 5899, 3988, 3049, 2153, 1507, 1436, 1131],  // lx
+    &[90],  // shbgb
+    &[2994],  // lbx
+    &[34488, 21691, 15910, 11895, 4492, 3342, 515],  // bj
+    &[59317, 8446, 6986, 2193, 2108, 1155, 804, 366, 301],  // sm
+    &[16365],  // wch
+    &[11157],  // pzh
+    &[3444, 669],  // kr
+    &[16199, 4848, 3510, 754, 524, 414],  // zb
+    &[3018, 801],  // nn
+    &[37664, 26381, 16552, 12961, 10415, 8944, 8926, 7421, 7419],  // js
+    &[847, 205],  // nk
+    &[113],  // mszhsz
+    &[5971, 3404, 1832, 1446, 1139],  // jk
+    &[1438],  // pa
+    &[132],  // dhp
+    &[23],  // dxc
+    &[5962, 3877, 2160, 549, 196],  // cb
+    &[3955, 3662, 1330, 722, 414, 342, 182, 84],  // xq
+    &[7443, 23],  // ww
+    &[6779, 6318, 1218, 780, 169],  // qd
+    &[6640, 5389, 3172, 2105, 1729, 1340],  // jr
+    &[34860, 1781, 547, 489, 33],  // wh
+    &[1780, 1650, 58, 1],  // lc
+    &[3],  // tgq
+    &[129470, 11839, 2735, 2157, 1139, 174, 93, 73, 1],  // zhg
+    &[110435, 85847, 55415, 44880, 40281, 36799, 26275, 24967, 17947],  // c
+    &[7600, 5871, 416, 70],  // lk
+    &[3139],  // yn
+    &[2141],  // ed
+    &[9366, 5557, 1682, 1459, 1249, 658, 471, 129],  // fd
+    &[24866, 14823, 9540, 6284, 416, 107],  // zhzh
+    &[166],  // jhch
+    &[395],  // czc
+    &[113],  // mszsz
+    &[1],  // zmshnshms
+    &[5015, 1986, 979, 870],  // xr
+    &[2972],  // pp
+    &[28363, 8127, 5132, 4068, 2714, 2525, 1628, 1611, 1408],  // hy
+    &[5052, 2016, 1288, 735],  // yf
+    &[106],  // sys
+    &[5432, 2570, 1276, 451],  // czh
+    &[68],  // shxym
+    &[2921, 2642, 2306, 1806, 818],  // msh
+    &[924],  // gdsh
+    &[11527, 10875, 884, 807],  // mb
+    &[2763, 1245, 269],  // pf
+    &[36089, 1693],  // mg
+    &[1],  // zmshnsms
+    &[25753, 1263, 1089],  // qt
+    &[4373],  // bgsh
+    &[7202, 5348, 1559, 489, 409, 284],  // cc
+    &[1],  // ozhjbhs
+    &[13853, 3865],  // zhr
+    &[153],  // kbq
+    &[16633, 258, 242],  // hzh
+    &[730],  // zyz
+    &[7],  // jchszh
+    &[4885, 4324, 1024, 673],  // pd
+    &[11950, 5767, 3047, 1979, 1951, 1590, 472],  // qs
+    &[535],  // shbl
+    &[19871, 16239, 14635],  // rh
+    &[10],  // lmae
+    &[443],  // qa
+    &[34387, 33288, 21100, 12474, 12010, 5441, 5232, 4789, 1874],  // sj
+    &[95],  // nzk
+    &[8276, 4168, 1430, 102],  // gf
+    &[1474, 1095, 171],  // mh
+    &[2939],  // gsgl
+    &[1518],  // om
+    &[1],  // tamd
+    &[38139, 14820, 7815, 1235, 333],  // ks
+    &[43719, 78],  // rm
+    &[1510, 733],  // sg
+    &[14079, 10840, 9137, 7785, 3548, 744, 368, 217],  // cl
+    &[1],  // jld
+    &[21386, 11866, 1188],  // nm
+    &[3448, 3029],  // shk
+    &[647],  // xxl
+    &[12],  // dhlw
+    &[422],  // mtc
+    &[12865, 10175, 6409, 412],  // dr
+    &[108294, 75979, 62626, 61904, 57969, 32256, 31874, 25558, 25322],  // x
+    &[1],  // pzhs
+    &[1433, 1409],  // dh
+    &[14906, 11094, 7853, 6547, 3323, 1486, 1068, 612, 606],  // zhj
+    &[6832],  // qh
+    &[3],  // fshj
+    &[23],  // csk
+    &[7715, 6679, 4707, 3781, 2673, 1480, 133, 59],  // zt
+    &[14079, 10840, 7785, 368, 217],  // chl
+    &[27430, 3307, 2714, 2116, 670, 620, 589, 481],  // xy
+    &[117],  // zmb
+    &[10239, 1875, 1113],  // lw
+    &[13],  // cjzx
+    &[560, 3],  // lt
+    &[145],  // lljzh
+    &[20524, 5359, 4725, 1032],  // mz
+    &[25084, 19495, 3742, 3372, 1979, 1458, 1260, 611, 534],  // chs
+    &[21853, 12100, 9250, 6168, 6035, 5717, 5299, 3750, 3654],  // p
+    &[107],  // sdjc
+    &[31],  // lbt
+    &[20479, 5265, 4224, 3541, 1196, 686, 440, 326],  // jb
+    &[20572, 10928, 9428, 5860, 5153, 2996, 1162, 183],  // zch
+    &[10928, 5860, 5153, 1431, 183],  // zhc
+    &[154],  // qzn
+    &[20],  // zsms
+    &[12193, 7060, 2196, 418, 403, 297, 288, 265, 166],  // cy
+    &[146],  // yblsh
+    &[17372, 16715, 4727, 1041, 915, 671, 279, 202, 191],  // bg
+    &[11239, 4275, 1219, 604, 521, 39],  // gm
+    &[10928, 5860, 5153, 183],  // zhch
+    &[24649, 15777, 14397, 9232, 6206, 5985, 4790, 4138, 2582],  // jzh
+    &[33],  // gxq
+    &[18596, 10445, 9541, 5863, 5676, 5406, 3704, 1509, 1205],  // yz
+    &[1448, 827],  // ns
+    &[8354, 7886, 6728, 4817, 4631, 4142, 1795, 685, 304],  // wz
+    &[279],  // dsg
+    &[8003, 4096, 3036, 2256, 997, 155, 12],  // ml
+    &[20602, 9364],  // xt
+    &[19177, 1810, 1205],  // dj
+    &[133],  // zhqj
+    &[176],  // jlfnyzh
+    &[2149, 1527, 1254, 1104, 346],  // qq
+    &[24826, 11086, 8151, 4042, 3856, 3091, 1804, 288],  // fx
+    &[14972, 9955, 4575, 2999, 2340, 999, 106, 41],  // bd
+    &[1559, 409],  // chch
+    &[1],  // lcdxes
+    &[21386, 7684, 3200, 2568, 1166, 1156, 571, 329],  // yh
+    &[2799, 2225, 443, 332],  // hch
+    &[5456],  // et
+    &[1],  // mblq
+    &[2332],  // zmy
+    &[11428, 10799, 5584, 4637, 4278, 1266, 886, 771, 637],  // shd
+    &[1568],  // qr
+    &[8585, 2516, 1906, 111],  // kl
+    &[16428, 9996, 7289, 2691, 1976, 1144, 862, 610, 134],  // shf
+    &[3547, 117],  // psh
+    &[29396, 24035, 17464, 10340, 6871, 6280, 5971, 5742, 1719],  // sy
+    &[16811, 3086, 1111, 223, 94, 75],  // hj
+    &[3520, 1849, 802, 101],  // tk
+    &[34826, 4454, 1474, 318, 62],  // qy
+    &[1958],  // jlb
+    &[10012, 9128, 9062, 5615, 3624, 2719, 2064, 1810, 1787],  // bz
+    &[15958, 812, 336, 296],  // fc
+    &[5094, 3242, 1365, 1358],  // gk
+    &[16365],  // wc
+    &[1821],  // ybz
+    &[7998, 3404, 740],  // hp
+    &[188],  // xhs
+    &[446],  // ymq
+    &[28055, 19195, 10457, 4833, 4064, 777, 544],  // dsh
+    &[280],  // wxj
+    &[3355, 1950],  // kp
+    &[39590, 4590, 2135, 457, 1],  // dq
+    &[21067, 20573, 4728, 3748, 3451, 1436, 726, 364, 292],  // yg
+    &[11557, 2382],  // az
+    &[18051, 7424, 6416, 3460, 3309],  // gch
+    &[1312],  // or
+    &[378],  // jp
+    &[20269, 13853, 5946, 3865],  // zr
+    &[112],  // bjshfdx
+    &[3858],  // chjdq
+    &[106],  // shysh
+    &[3758],  // fg
+    &[1559, 756],  // pq
+    &[8664, 3226],  // chq
+    &[111],  // jyzh
+    &[3628, 900],  // yr
+    &[34145, 11160, 8018, 5089, 4233, 3960, 3791, 2015, 1530],  // xz
+    &[3],  // hgs
+    &[4781, 4288, 2410, 768, 103, 77],  // wj
+    &[27191, 13083, 4888, 2370, 289],  // gl
+    &[79520, 24601, 24221, 7767, 5737, 5721, 4819, 3893, 3635],  // gj
+    &[25],  // hgn
+    &[39],  // wwnn
+    &[3858],  // cjdq
+    &[730],  // zyzh
+    &[11478],  // nc
+    &[6612, 6101, 3264, 1617, 859, 810, 748, 284, 160],  // mt
+    &[2022],  // rn
+    &[6167, 4048, 3065, 2668, 1205, 1069],  // zq
+    &[15958, 336, 296],  // fch
+    &[8904, 6927, 5024, 2948, 2706, 650, 641, 129],  // sl
+    &[1780, 1650, 58, 1],  // lch
+    &[74],  // es
+    &[26963, 5593, 2757],  // fm
+    &[2872, 734, 1],  // wb
+    &[1],  // zhmsnshms
+    &[616, 236],  // bp
+    &[11240, 9047, 5033, 493, 136],  // td
+    &[111],  // jyz
+    &[3141, 266],  // tp
+    &[125538, 97817, 69480, 57483, 56478, 44807, 32393, 29996, 19811],  // g
+    &[1],  // zhmshnsms
+    &[140],  // sshxs
+    &[4432, 2313],  // chm
+    &[2765, 1321, 516],  // kc
+    &[5229, 3531, 2877, 355, 278, 188, 183],  // gzh
+    &[393, 74],  // shhy
+    &[13880, 10160],  // shp
+    &[24052, 16797, 439, 249],  // fsh
+    &[8569, 6044, 3850, 2713, 1444, 886, 732],  // lh
+    &[38],  // hxx
+    &[55],  // stf
+    &[23118, 5266, 5027, 4556, 3466, 2907, 2817, 2791, 2761],  // jl
+    &[11445, 667, 560, 529, 453],  // ct
+    &[2921, 2642, 2306, 1806, 818],  // ms
+    &[8332, 3414, 1679, 693, 49],  // dch
+    &[371],  // ddxx
+    &[42780, 21517, 13662, 12694, 12193, 7794, 4053, 3515, 3214],  // zd
+    &[188],  // xhsh
+    &[9212, 8254, 6063, 5775, 3212, 1631, 226, 1],  // zhsh
+    &[7735, 5681, 5342, 1755, 1449],  // zhw
+    &[24213, 320],  // fl
+    &[3],  // fsj
+    &[1],  // ozhhjbhs
+    &[8439, 4150, 3953, 2808, 2030, 1226],  // wd
+    &[85847, 44880, 40281, 36799, 26275, 24967, 16316, 14885, 11435],  // ch
+    &[22828, 18930, 6674, 6371, 6217, 3599, 2873, 1759, 1748],  // cj
+    &[17465, 16633, 13478, 912, 549, 443, 258, 242],  // hz
+    &[989],  // ka
+    &[55],  // zhstb
+    &[7202, 1559, 409, 284],  // cch
+    &[30311, 7114, 700, 672, 377, 1],  // yb
+    &[23243, 704],  // rk
+    &[1305],  // ddh
+    &[4370, 1463, 41],  // lq
+    &[2353, 118],  // hk
+    &[11157, 427, 309, 119, 104],  // pz
+    &[1],  // mhchdxnh
+    &[44],  // mkf
+    &[1],  // ozhjbhsh
+    &[2899, 1549, 586],  // dm
+    &[105],  // xshj
+    &[328841, 295952, 42181, 35084, 34296, 29391, 19704, 16974, 16243],  // w
+    &[1034],  // zxch
+    &[32898, 26927, 3195, 2085, 1275, 730, 413, 358, 154],  // sc
+    &[107],  // shdjc
+    &[10403, 1700, 1327, 1028, 365],  // shr
+    &[924],  // gds
+    &[9561],  // wsm
+    &[112],  // bjsfdx
+    &[6937],  // nb
+    &[5372, 4396, 2601, 1073, 98],  // tx
+    &[1221, 582],  // xk
+    &[2548],  // chbd
+    &[7161, 5973, 2445, 2142, 1985, 1374, 474],  // bl
+    &[42],  // hnyg
+    &[1360],  // mshgj
+    &[57991, 37557, 21581, 16214, 10675, 10542, 9876, 5622, 5254],  // zhy
+    &[36],  // mry
+    &[66367, 5229, 4976, 4330, 3531, 3473, 2877, 1286, 403],  // gz
+    &[3272],  // bwg
+    &[113],  // mszhszh
+    &[21884, 15867, 3515, 2635, 391],  // bx
+    &[1087, 707, 138],  // chh
+    &[9561],  // wshm
+    &[1510, 733],  // shg
+    &[17532, 11660, 8230, 697, 170],  // dw
+    &[55],  // zstb
+    &[15468, 1491],  // nr
+    &[23452, 4283, 327, 1],  // zf
+    &[70958, 63],  // ky
+    &[348],  // gqj
+    &[1546, 322],  // kk
+    &[150],  // wshj
+    &[28055, 19195, 10457, 4833, 4064, 3154, 777, 386],  // ds
+    &[22801],  // chp
+    &[61],  // nzh
+    &[360],  // kmsh
+    &[95892, 66641, 27900, 21973, 20778, 9081, 8829, 7478, 7470],  // k
+    &[301],  // dzyj
+    &[646, 253],  // ax
+    &[19238, 11336, 8340, 3373, 2314, 471, 338, 321],  // bs
+    &[145],  // lljz
+    &[1551],  // tshg
+    &[3932, 2001, 1504, 1146, 668, 520],  // jq
+    &[14654, 4448, 1494, 882, 198],  // wy
+    &[8085, 5770, 2408, 604, 596, 577, 381],  // ws
+    &[27526, 16128, 8822, 481],  // hd
+    &[1],  // ozhhjbhsh
+    &[8252, 5621, 4280, 2518, 1266],  // br
+    &[1],  // lyetj
+    &[3892, 3052, 290],  // qx
+    &[53],  // ghe
+    &[30776, 15188, 14470, 9323, 8143, 6872, 6078, 5510, 5501],  // xs
+    &[13],  // cjzhx
+    &[10142, 9780, 5476, 3125, 1920, 1892, 1249, 973, 507],  // gb
+    &[1],  // bkq
+    &[9248, 3885, 2305, 182],  // zp
+    &[949],  // xhz
+    &[146],  // ybls
+    &[730],  // zhyz
+    &[25084, 19495, 8163, 7036, 3742, 3372, 1979, 1460, 1458],  // cs
+    &[18510, 10215, 7741, 7253, 5464, 3348, 2048, 1677, 1439],  // jc
+    &[318825, 205341, 202780, 195934, 184674, 160541, 144099, 134479, 98900],  // d
+    &[16199, 4848, 3510, 414],  // zhb
+    &[18464, 15471, 7272, 6043, 3241, 3107, 2998, 2357, 2307],  // dd
+    &[8085, 5770, 2408, 604, 596, 577, 381],  // wsh
+    &[45604, 10953, 5953, 4262, 2620, 856],  // gs
+    &[1],  // fzdl
+    &[83],  // bjss
+    &[200],  // tjq
+    &[11445, 560, 529, 453],  // cht
+    &[7052, 4165, 3887, 3082, 1829, 1098, 1023],  // chf
+    &[17],  // jbrf
+    &[9557, 1512, 488],  // wf
+    &[5782, 5457, 4919, 4546, 2618, 1849, 1038, 232, 174],  // hx
+    &[2052],  // adly
+    &[2371, 775],  // bw
+    &[32105, 5762, 5387, 3261, 1710, 1531, 802, 306, 141],  // gx
+    &[6167, 4048, 3065, 2668, 1205, 1069],  // zhq
+    &[11537, 701],  // kzh
+    &[87],  // shwz
+    &[23],  // cshk
+    &[4242, 1366, 282],  // chr
+    &[24052, 16797, 1266, 819, 439, 249, 62],  // fs
+    &[20],  // zhsms
+    &[6498, 3386, 2663, 370, 186, 90],  // dzh
+    &[8176, 5641, 4487, 3681, 2056, 1337, 1008, 469, 336],  // sb
+    &[5889, 4421, 327, 94],  // fh
+    &[17020, 13482, 12256, 9926, 9054, 6789, 4911, 3825, 2629],  // xx
+    &[1371, 1221],  // tm
+    &[32833],  // qk
+    &[85],  // lfd
+    &[9256],  // oz
+    &[12597, 12562, 8963, 4712, 2256, 992],  // xw
+    &[22455, 466, 163],  // mj
+    &[4414, 2176, 1339, 1029, 795, 526, 483],  // xj
+    &[7052, 4165, 3887, 3369, 3082, 1829, 1098, 1023],  // cf
+    &[9321, 5024, 1174, 932],  // qm
+    &[13],  // chjzhx
+    &[9464, 1963, 1125],  // rl
+    &[38139, 14820, 7815, 333],  // ksh
+    &[2657, 2191, 1092, 136],  // tq
+    &[1],  // lchdxes
+    &[13460, 3283, 487, 133],  // kx
+    &[9212, 8254, 6063, 5775, 3212, 1631, 226, 1],  // zhs
+    &[1914, 668],  // sbd
+    &[22801],  // cp
+    &[3000, 3],  // lp
+    &[31213, 7599],  // kn
+    &[4919],  // nq
+    &[9],  // jswk
+    &[8354, 7886, 6728, 4817, 4142, 123],  // wzh
+    &[5],  // zgn
+    &[11398, 3776, 327, 98],  // qb
+    &[13],  // chjzx
+    &[12732, 10638, 4800, 437, 351],  // chg
+    &[15239, 10614, 7557, 6940, 5358, 4670, 2516, 1192, 1057],  // sz
+    &[11557, 2382],  // azh
+    &[35063, 16882, 16799, 2801, 195],  // tg
+    &[1391, 351, 307, 1],  // nh
+    &[329],  // xyk
+    &[56],  // ggqc
+    &[38374],  // rg
+    &[1038],  // np
+    &[34173, 1612, 444],  // qzh
+    &[1337, 1072, 994],  // pch
+    &[101],  // hchy
+    &[61],  // nz
+    &[176],  // jlfnyz
+    &[18058, 17725, 4918, 4126, 3858, 3258, 2105, 1617, 1608],  // dy
+    &[16124, 13559, 9771, 8351, 3333, 355, 107],  // ly
+    &[25084, 19495, 3742, 3372, 1979, 1458, 611, 534, 500],  // chsh
+    &[13626, 13536, 6308, 5811, 4263, 2986, 1232, 866, 721],  // ysh
+    &[1360],  // msgj
+    &[6890, 3979, 3909, 3625, 1678, 1],  // wx
+    &[34],  // ydf
+    &[52],  // xxg
+    &[1500, 336],  // tw
+    &[276, 161],  // ay
+    &[1],  // mhcdxnh
+    &[27339, 14946, 9342, 8512, 6425, 848, 654, 395, 31],  // zm
+    &[363],  // qkl
+    &[1],  // lchp
+    &[721],  // ash
+    &[9907, 8066, 3304, 1689, 215],  // dl
+    &[56],  // ggqch
+    &[24267, 10403, 2443, 1700, 1327, 1028, 365],  // sr
+    &[7715, 3781, 2673, 1480, 133, 59],  // zht
+    &[1399, 689, 279],  // fk
+    &[17],  // djp
+    &[34660, 16840, 16318, 15161, 13166, 4788, 4674, 3742, 3105],  // f
+    &[740, 491, 84],  // mk
+    &[55563],  // wt
+    &[5410, 271],  // fp
+    &[8161, 5289, 1254, 484, 369],  // tc
+    &[130],  // kqsh
+    &[87],  // swzh
+    &[22638],  // eq
+    &[544],  // dshh
+    &[4308, 980],  // hm
+    &[4000, 228],  // dk
+    &[20025, 18877, 6672, 4839, 4511, 767, 339, 271, 159],  // dx
+    &[3],  // zxl
+    &[1],  // pzs
+    &[87],  // swz
+    &[43401, 31550, 31521, 16377, 10146, 3795, 1797, 1378, 1157],  // shh
+    &[9],  // jshwk
+    &[27619, 10633, 3850, 523],  // bf
+    &[15960, 10908, 9769, 8831, 3332],  // jt
+    &[25854, 4745, 938, 777, 108],  // xch
+    &[9783, 2402, 1631, 1444, 1102, 1087],  // xh
+    &[1],  // begc
+    &[8901, 4255, 1553, 1338],  // rs
+    &[3894, 2085, 754, 516],  // lm
+    &[185],  // njdx
+    &[47400, 23969, 20859, 4412, 4342, 2997, 2935, 1316, 922],  // zx
+    &[9021, 8200, 8009, 7678, 4950, 1932, 1610, 1437, 1337],  // zl
+    &[12],  // xlx
+    &[101],  // hcy
+    &[9846, 3181],  // fq
+    &[1334],  // pg
+    &[2923, 1646, 364],  // gp
+    &[1],  // mhchdxnhe
+    &[3362, 332],  // tt
+    &[796991, 258101, 219817, 76462, 64655, 42542, 29983, 29951, 28466],  // s
+    &[140],  // ryp
+    &[1569],  // gcs
+    &[3331, 930, 850, 315],  // dg
+    &[9342, 8512, 6425, 654],  // zhm
+    &[352],  // mr
+    &[6730],  // qn
+    &[51289, 35029, 30775, 11190, 10329, 2461, 2388, 824, 748],  // yj
+    &[279],  // dshg
+    &[9091, 8499, 2981, 2489],  // fj
+    &[5133, 3895, 3342, 2575, 546, 412],  // xf
+    &[13626, 13536, 7420, 6308, 6089, 5811, 4263, 3100, 2986],  // ys
+    &[360331, 108066, 106845, 93868, 55339, 42207, 35305, 25526, 14132],  // b
+    &[5962, 3877, 2160, 549, 196],  // chb
+    &[24144, 13924, 7151, 6846, 5354, 5256, 3247, 2917, 2828],  // yx
+    &[310],  // pth
+    &[3547, 117],  // ps
+    &[8237, 386, 282, 32],  // chk
+    &[1034],  // zxc
+    &[1],  // shwjz
+    &[2838],  // rbz
+    &[4803, 4593, 3305, 2402, 2274, 1346, 1103, 1044, 642],  // shs
+    &[14],  // hyk
+    &[1117],  // hchz
+    &[929, 275],  // zhxj
+    &[504],  // gbd
+    &[3572, 1943, 1762, 946],  // ht
+    &[9791, 8664, 3226, 1310],  // cq
+    &[4044, 3368, 3088, 1234, 1166, 849, 719],  // shw
+    &[6498, 3386, 3344, 2663, 1736, 186, 181, 90],  // dz
+    &[9922, 3959, 2426, 1195, 950, 699, 676],  // hsh
+    &[250],  // tyg
+    &[19421, 15354, 4317, 2869, 2416],  // shq
+    &[1813],  // hlw
+    &[794],  // ja
+    &[727915, 261791, 188584, 140957, 101442, 60450, 55507, 50437, 50331],  // z
+    &[32898, 26927, 730, 413, 358, 154, 52],  // sch
+    &[9953, 4498, 4063, 838, 337, 169],  // cd
+    &[6687, 5432, 2570, 1123, 847, 687, 517, 451, 179],  // chz
+    &[810, 436],  // ah
+    &[4402, 343],  // lb
+    &[5],  // zhgn
+    &[3241, 1043, 988, 12],  // hh
+    &[2189, 2186, 2165, 762],  // jm
+    &[28],  // xjrb
+    &[51],  // bfz
+    &[248559, 234587, 111550, 93096, 50204, 28623, 27951, 24296, 19956],  // n
+    &[8904, 6927, 2948, 2706, 650, 641, 129],  // shl
+    &[19810, 8676],  // ry
+    &[4803, 4593, 3510, 3305, 2476, 2402, 2369, 2274, 1346],  // ss
+    &[59317, 8446, 6986, 2193, 2108, 1155, 804, 366, 301],  // shm
+    &[7600, 3048],  // hf
+    &[2692, 2065, 1029],  // wr
+    &[3427],  // dn
+    &[9953, 4498, 4063, 337],  // chd
+    &[2799, 2225, 443, 332],  // hc
+    &[55],  // zhshtb
+    &[42],  // bjl
+    &[48718, 14468, 8844, 3981, 3577, 3498, 3201, 3168, 2742],  // jj
+    &[8563, 2049, 270, 1],  // bq
+    &[102],  // pjy
+    &[13943, 8590, 5767, 3130, 2615, 520, 343, 296],  // tz
+    &[488],  // lsj
+    &[730],  // zhyzh
+    &[2456, 1073],  // bb
+    &[929, 275],  // zxj
+    &[15239, 10614, 6940, 5358, 2516, 1057],  // szh
+    &[10261, 3399, 260, 1],  // bch
+    &[51],  // bfzh
+    &[7805, 5466, 2661, 1581, 560, 489, 98],  // zhh
+    &[7962, 1953, 993, 231],  // st
+    &[68664, 6285, 2166, 1876, 670, 204],  // fzh
+    &[1052],  // dbq
+    &[7657, 4044, 3368, 3088, 1234, 1166, 849, 719],  // sw
+    &[65],  // adsh
+    &[9932, 3476, 1946, 673],  // pj
+    &[10012, 9128, 9062, 3624, 2719, 2064, 854],  // bzh
+    &[15239, 7557, 6940, 5358, 4670, 2516, 1192, 1057, 693],  // shz
+    &[14659, 6207, 2265],  // nd
+    &[55],  // zshtb
+    &[18874, 7757, 7571, 3808],  // nl
+    &[555815, 157058, 124793, 92543, 92091, 69103, 44621, 23572, 14915],  // h
+    &[1],  // zhmsnsms
+    &[7],  // jcsz
+    &[8451, 2777, 1089, 530],  // qc
+    &[140],  // ssxs
+    &[140],  // shshxs
+    &[40],  // qys
+    &[23944, 16361, 15976, 12074, 9343, 7135, 3258, 2523, 1537],  // yq
+    &[8451, 1089, 530],  // qch
+    &[98740, 6584, 4049, 1509],  // wm
+    &[76],  // dxg
+    &[1223],  // qshn
+    &[154],  // qzhn
+    &[1],  // zmsnshms
+    &[185],  // tjhj
+    &[52641, 6479, 2205, 1078, 559],  // df
+    &[1193],  // yk
+    &[24568, 9561, 8438, 5306, 5043, 3844, 1839, 1245, 705],  // gd
+    &[57991, 37557, 22078, 21581, 17453, 16214, 11169, 10675, 10542],  // zy
+    &[883634, 161501, 77054, 43011, 33423, 27626, 23315, 22635, 12817],  // l
+    &[7604, 950],  // xb
+    &[106],  // sysh
+    &[5214, 823],  // nx
+    &[74],  // esh
+    &[259],  // qsgz
+    &[9296, 2130, 1546, 1194, 856, 1],  // mx
+    &[9959, 2681, 1417],  // jn
+    &[87],  // shwzh
+    &[9212, 8254, 6089, 6063, 5775, 3212, 2763, 1982, 1631],  // zsh
+    &[4737, 322],  // rch
+    &[30181, 22569, 12682, 8796, 8729, 7647, 7376, 6216, 5538],  // yy
+    &[2001, 1713],  // mp
+    &[1],  // zhmshnshms
+    &[11537, 1103, 814, 701, 664],  // kz
+    &[13257, 3980, 1683, 682, 286],  // xm
+    &[1569],  // gchs
+    &[2081, 986],  // fr
+    &[1117],  // hchzh
+    &[1559, 409],  // chc
+    &[42780, 21517, 13662, 12694, 12193, 7794, 4053, 3515, 3214],  // zhd
+    &[139],  // ea
+    &[5341, 3728, 164],  // pb
+    &[18930, 6217, 3599, 1759, 1748, 1289, 942, 790, 347],  // chj
+    &[9364],  // ez
+    &[1],  // fnmsh
+    &[14998, 6776, 5328],  // tr
+    &[1],  // tjws
+    &[24052, 2853],  // bk
+    &[9440, 5937, 5456, 250],  // md
+    &[19238, 8340, 3373, 2314, 471, 338, 321],  // bsh
+    &[24866, 14823, 9540, 6418, 6284, 5041, 4804, 2047, 416],  // zhz
+    &[1469],  // aw
+    &[8594, 6227],  // kd
+    &[7645],  // hr
+    &[19018, 4323, 2928, 2406],  // gg
+    &[54355, 17900, 14690, 2405, 2207, 1217, 542],  // jx
+    &[374, 311],  // wg
+    &[9921, 2606],  // aq
+    &[2765, 1321, 516],  // kch
+    &[1],  // dneknm
+    &[236],  // bjd
+    &[107],  // shdjch
+    &[796991, 258101, 219817, 64655, 43401, 31550, 31521, 29951, 28466],  // sh
+    &[355],  // wsw
+    &[2548],  // cbd
+    &[488],  // lshj
+    &[2924],  // kg
+    &[87597, 6255, 3417, 3117],  // my
+];
+
+// Choices in ABBREV_CIYU spelled with traditional characters
+pub static ABBREV_CIYU_HANT: &[&str] = &[
+    "減肥	家訪",  // jf
+    "基礎	堅持	機場	經常	檢查	接觸	警察",  // jch
+    "作為	植物	周圍	掌握	中文	中午	座位	作文",  // zw
+    "交往	家務	健偉",  // jw
+    "連環畫",  // lhh
+    "朋友	培養	便宜	拼音",  // py
+    "熱心",  // rx
+    "一會兒",  // yhe
+    "後果	合格	海關",  // hg
+    "人民幣",  // rmb
+    "調查	到處	單純	電池	躲藏	堵車",  // dc
+    "不耐煩",  // bnf
+    "差異	抽煙	春遊	出院	成語",  // chy
+    "全神貫注",  // qsgzh
+    "生產	市場	商場	刪除	順暢	時差",  // shch
+    "通常	突出	提倡	推遲",  // tch
+    "這	著	之	只	長	正	住	占	張",  // zh
+    "出現	重新	程序	持續	出席	查詢	抽象	誠信	車廂",  // chx
+    "招聘	照片	支票",  // zhp
+    "組織	政治	增長	戰爭	作戰	真正	製造	主張	正在",  // zz
+    "詹姆士·奈史密斯",  // zmsnsms
+    "趙福根",  // zhfg
+    "郝林說",  // hls
+    "一直	嚴重	亞洲	一致	油炸",  // yzh
+    "使用	聲音	屬於	商業	適應	實驗	生意	善於	實用",  // shy
+    "保持	不錯	補充	保存	彼此	八成	八成兒",  // bc
+    "玉米	有名	移民	優美	幽默",  // ym
+    "變化	保護	包含",  // bh
+    "科技	看見	空間	可見	會計",  // kj
+    "垃圾桶",  // ljt
+    "平常	賠償	破產",  // pc
+    "全神貫注",  // qshgz
+    "因此	演出	延長	牙齒	養成",  // yc
+    "教育	經驗	建議	經營	記憶	均勻	節約	加油	醬油",  // jy
+    "國王	格外	購物	鼓舞	歌舞	怪物	拐彎",  // gw
+    "首都機場",  // sdjch
+    "小朋友",  // xpy
+    "姑娘	功能	概念	觀念	歸納",  // gn
+    "《非你莫屬》",  // fnms
+    "沒關係",  // mgx
+    "青少年",  // qsn
+    "完全	委屈	網球	尾氣",  // wq
+    "精誠所至",  // jchsz
+    "仍然",  // rr
+    "成長	初中	傳真",  // chzh
+    "計劃	機會	幾乎	結合	交換	結婚	集合	聚會	菊花",  // jh
+    "召開	展開	狀況	做客	趙括",  // zk
+    "成為	寵物",  // chw
+    "來得及",  // ldj
+    "部門	表明	表面	避免	報名	幫忙",  // bm
+    "期間	情景	奇跡	起居	請假",  // qj
+    "發展	負責	分鐘	複雜	否則	房子	反正	服裝	複製",  // fz
+    "郝林說",  // hlsh
+    "目前	母親	明確	密切",  // mq
+    "過程	廣場	構成	剛才	工廠	觀察	乾脆",  // gc
+    "音樂會",  // yyh
+    "來不及",  // lbj
+    "工業	關於	公元	公園	故意	公寓",  // gy
+    "摩托車",  // mtch
+    "火車站",  // hcz
+    "農業	能源	鯰魚",  // ny
+    "瞭解	立即	理解	邏輯	垃圾	冷靜	鄰居	零件	辣椒",  // lj
+    "出現	重新	程序	持續	出席	查詢	抽象	誠信	操心",  // cx
+    "《賣火柴的小女孩兒》",  // mhcdxnhe
+    "工程師",  // gchsh
+    "一定	運動	一旦	遇到	閱讀	移動	有點	優點	一點兒",  // yd
+    "分別	分佈	發表	方便",  // fb
+    "華北	何必	夥伴	航班	環保	後背	黑板",  // hb
+    "張小龍",  // zhxl
+    "受不了",  // sbl
+    "麻煩	免費	模仿	米飯	蜜蜂",  // mf
+    "過去	感情	過期",  // gq
+    "時刻	深刻	思考",  // sk
+    "幼兒園",  // yey
+    "學生	形式	先生	小時	顯示	銷售	形勢	小說	現實",  // xsh
+    "氣溫	千萬	青蛙",  // qw
+    "辦公室",  // bgs
+    "就	叫	家	即	進	近	極	講	教",  // j
+    "乒乓球",  // ppq
+    "設施	事實	手術	受傷	收拾	碩士	叔叔	時尚	舒適",  // shsh
+    "共同	鋼鐵	溝通	骨頭	櫃台",  // gt
+    "訓練	心理	效率	學歷	修理	洗禮	項鏈",  // xl
+    "質量	治療	種類	整理	重量	展覽	爭論	哲理	製冷",  // zhl
+    "充電器",  // cdq
+    "自己	增加	直接	專家	逐漸	資金	中間	最近	作家",  // zj
+    "了不起",  // lbq
+    "沙丁魚",  // shdy
+    "缺乏	氣氛	起飛	啟發	勤奮",  // qf
+    "沙丁魚",  // sdy
+    "出租車",  // chzc
+    "組成	支持	造成	正常	主持	最初	自從	總裁	註冊",  // zc
+    "世界	時間	世紀	設計	實際	實踐	數據	手機	射擊",  // shj
+    "甚至	生長	始終	手指	睡著",  // shzh
+    "天津衛視",  // tjwsh
+    "這些	中心	主席	哲學	秩序	中旬	珍惜	裝修	專心",  // zhx
+    "排列	漂亮	疲勞",  // pl
+    "打招呼",  // dzhh
+    "認識	人生	燃燒	人事",  // rsh
+    "形成	宣傳	小吃	相處	香腸",  // xc
+    "有	也	一	與	要	又	以	月	於",  // y
+    "相關	修改	效果	習慣	性格	西瓜	香瓜",  // xg
+    "不得不",  // bdb
+    "思想	實現	首先	數學	熟悉	手續	事先	傷心	順序",  // sx
+    "奇怪	秦國",  // qg
+    "成為	此外	錯誤	寵物",  // cw
+    "出口	參考	慚愧	誠懇	吃虧	車庫",  // ck
+    "歷史	老師	糧食	臨時	律師	老實	老鼠	留守	零食",  // lsh
+    "全神貫注",  // qshgzh
+    "塑料袋",  // sld
+    "請願書",  // qysh
+    "其實	確實	趨勢	缺少	去世	輕視",  // qsh
+    "惡劣",  // el
+    "口味	看望",  // kw
+    "身體	石頭	沙灘	手套",  // sht
+    "還是	紅色	和尚	合適	忽視	花生	胡說	護士",  // hs
+    "礦泉水",  // kqs
+    "去	前	卻	起	請	全	錢	取	瞧",  // q
+    "打籃球",  // dlq
+    "充電器",  // chdq
+    "樂觀	欄杆	李廣",  // lg
+    "許多	兄弟	行動	現代	相當	相對	顯得",  // xd
+    "存在	創造	成長	初中	辭職	稱讚	出自	乘坐	遲早",  // cz
+    "水平	商品",  // sp
+    "按時",  // as
+    "承認	成人	傳染",  // cr
+    "時期	事情	失去	申請	生氣",  // sq
+    "不同",  // bt
+    "培訓	皮鞋",  // px
+    "暗暗",  // aa
+    "開幕式",  // kms
+    "廣和居",  // ghj
+    "性質	限制	象徵	尋找	校長	形狀",  // xzh
+    "腎上腺素",  // shsxs
+    "為了	網絡	未來	無論	物理	無聊",  // wl
+    "片面",  // pm
+    "用途	陽台",  // yt
+    "歐洲",  // ozh
+    "開玩笑",  // kwx
+    "說不定	捨不得",  // shbd
+    "筆記本",  // bjb
+    "不要緊",  // byj
+    "歷史	老師	糧食	臨時	律師	老實	老鼠	留守	零食",  // ls
+    "空氣",  // kq
+    "獨特	地圖	代替	打聽	電台	地鐵	地毯	電梯",  // dt
+    "實現	首先	數學	熟悉	手續	事先	傷心	順序	實習",  // shx
+    "記者	價值	建築	集中	接著	競爭	緊張	機制	禁止",  // jz
+    "經過	結構	結果	價格	儘管	及格",  // jg
+    "光滑	掛號	幹活兒",  // gh
+    "人才	日常	日程",  // rc
+    "趙福根",  // zfg
+    "其中	親自	妻子	慶祝	簽證	裙子",  // qz
+    "出租車",  // czch
+    "畢業	必要	表演	半夜	抱怨	暴雨	表揚",  // by
+    "生產	市場	蔬菜	身材	商場	刪除	順暢	時差",  // shc
+    "人	日	讓	熱	軟	繞	弱	認	扔",  // r
+    "反應	反映	法院	翻譯	發言	複印",  // fy
+    "夏威夷",  // xwy
+    "沒	門	米	每	嗎	馬	美	明	買",  // m
+    "馬薩諸塞州",  // mszszh
+    "《說文解字》",  // swjz
+    "賞心悅目",  // sxym
+    "客廳	空調",  // kt
+    "技術	建設	軍事	精神	接受	結束	介紹	解釋	教授",  // jsh
+    "偶爾",  // oe
+    "起來	權力	權利	強烈	情侶	七郎",  // ql
+    "超過	成功	成果	參觀	楚國	唱歌",  // cg
+    "女士	難受",  // nsh
+    "峰終定律",  // fzhdl
+    "浪費	理髮",  // lf
+    "決定	覺得	階段	巨大	簡單	絕對	降低	街道	角度",  // jd
+    "普通	葡萄",  // pt
+    "出租車",  // chzch
+    "不得了",  // bdl
+    "他	她	它	聽	天	太	台	條	圖",  // t
+    "夏令營",  // xly
+    "破壞	配合	平衡	《彷徨》",  // ph
+    "精誠所至",  // jcszh
+    "攝影師",  // shys
+    "調整	特徵	通知	挑戰	天真",  // tzh
+    "受到	時代	速度	適當	似的	首都	生動	縮短	商店",  // sd
+    "事半功倍",  // sbgb
+    "媽媽	秘密	妹妹	明明	密碼	眉毛",  // mm
+    "反而",  // fe
+    "充滿	沉默	聰明	匆忙",  // cm
+    "鼓樓大街",  // gldj
+    "條件	推薦	台階",  // tj
+    "懷念",  // hn
+    "日期	熱情",  // rq
+    "工程師",  // gcsh
+    "特別	逃避	退步",  // tb
+    "如今	軟件	日記",  // rj
+    "正式	知識	總是	重視	至少	真實	暫時	遵守	裝飾",  // zs
+    "打噴嚏",  // dpt
+    "諾貝爾獎",  // nbej
+    "婦女	煩惱",  // fn
+    "故事	改善	感受	果實",  // gsh
+    "領導	勞動	陸地	冷淡	朗讀",  // ld
+    "有意思",  // yys
+    "新年	想念",  // xn
+    "熱愛",  // ra
+    "應聘",  // yp
+    "認真",  // rzh
+    "橡皮",  // xp
+    "同時	特殊	聽說	同事	坦率",  // tsh
+    "胃口",  // wk
+    "衛生間",  // wsj
+    "認為	任務	人物	入圍",  // rw
+    "原來	以來	壓力	原料	有利	議論	娛樂	一律	月亮",  // yl
+    "中秋節",  // zqj
+    "例如	利潤",  // lr
+    "洗手間",  // xsj
+    "圖書館",  // tsg
+    "後來	回來	合理	匯率	婚禮	海里",  // hl
+    "日子	認真",  // rz
+    "年紀	年級",  // nj
+    "政府	丈夫	祝福	翟峰",  // zhf
+    "城市	產生	措施	從事	成熟	傳說	出生	促使	承受",  // csh
+    "踢足球",  // tzq
+    "愛迪生",  // ads
+    "耳環",  // eh
+    "中國	整個	資格	戰國	照顧	主觀	糟糕	總共	轉告",  // zg
+    "演出	延長	牙齒	養成",  // ych
+    "組織	政治	增長	戰爭	作戰	真正	主張	作者	雜誌",  // zzh
+    "盼望	評委",  // pw
+    "設備	身邊	失敗	士兵	上班	順便	生病	手錶	鼠標",  // shb
+    "因為	業務	以為	意外	義務	願望	疑問",  // yw
+    "體會	曇花",  // th
+    "好奇",  // hq
+    "火車站",  // hczh
+    "理論	力量	瀏覽	輪流	流淚	姥姥	流利",  // ll
+    "劉熾平",  // lcp
+    "代表	對比	打扮",  // db
+    "服務員",  // fwy
+    "統一	同樣	體育	同意	太陽	體驗	討厭	塗鴉",  // ty
+    "個人	工人	果然",  // gr
+    "討論	投籃",  // tl
+    "設施	事實	損失	手術	隨時	受傷	收拾	碩士	叔叔",  // ssh
+    "同時	特色	特殊	聽說	同事	坦率",  // ts
+    "安排",  // ap
+    "召開	展開	狀況	趙括",  // zhk
+    "開發	開放	看法	克服	咖啡",  // kf
+    "範圍",  // fw
+    "溫暖	無奈",  // wn
+    "趵突泉",  // btq
+    "十分	是否	雙方	身份	師傅	舒服	沙發	說服	施肥",  // sf
+    "呼朋喚友",  // hphy
+    "來自	例子",  // lz
+    "救護車",  // jhc
+    "安靜",  // aj
+    "忍不住",  // rbzh
+    "方法	豐富	紛紛	仿佛	反覆",  // ff
+    "頭髮",  // tf
+    "打交道",  // djd
+    "聯繫	連續	類型	理想	流行	陸續	魯迅	利息	旅行",  // lx
+    "事半功倍",  // shbgb
+    "老百姓",  // lbx
+    "北京	編輯	比較	不僅	畢竟	背景	筆記",  // bj
+    "什麼	說明	生命	沙漠	神秘	壽命	數碼	失眠	時髦",  // sm
+    "完成",  // wch
+    "批准",  // pzh
+    "客人	困擾",  // kr
+    "準備	逐步	轉變	左邊	責備	長輩",  // zb
+    "奶奶	牛奶",  // nn
+    "技術	建設	軍事	精神	接受	結束	介紹	解釋	教授",  // js
+    "寧可	內科",  // nk
+    "馬薩諸塞州",  // mszhsz
+    "健康	進口	儘快	藉口	艱苦",  // jk
+    "平安",  // pa
+    "動畫片",  // dhp
+    "稻香村",  // dxc
+    "出版	傳播	創辦	翅膀	稱霸",  // cb
+    "興趣	心情	星期	吸取	小區	學期	象棋	小氣",  // xq
+    "往往	文文",  // ww
+    "確定	強調	期待	缺點	清淡",  // qd
+    "竟然	既然	居然	節日	肌肉	假如",  // jr
+    "文化	危害	誤會	問候	文火",  // wh
+    "列車	流傳	涼茶	劉辰",  // lc
+    "彈鋼琴",  // tgq
+    "中國	整個	戰國	照顧	主觀	轉告	枝幹	趙國	知感",  // zhg
+    "從	出	才	成	長	吃	稱	處	次",  // c
+    "離開	立刻	路口	涼快",  // lk
+    "雲南",  // yn
+    "耳朵",  // ed
+    "反對	發達	奮鬥	否定	飯店	發抖	輔導	房東",  // fd
+    "政治	戰爭	真正	主張	專注	執照",  // zhzh
+    "救護車",  // jhch
+    "出租車",  // czc
+    "馬薩諸塞州",  // mszsz
+    "詹姆士·奈史密斯",  // zmshnshms
+    "顯然	信任	行人	形容",  // xr
+    "批評",  // pp
+    "會議	行業	歡迎	海洋	婚姻	懷疑	活躍	漢語	回憶",  // hy
+    "衣服	預防	應付	語法",  // yf
+    "攝影師",  // sys
+    "成長	初中	辭職	傳真",  // czh
+    "賞心悅目",  // shxym
+    "秘書	面試	馬上	美術	陌生",  // msh
+    "廣東省",  // gdsh
+    "目標	明白	麵包	毛病",  // mb
+    "皮膚	佩服	平方",  // pf
+    "美國	敏感",  // mg
+    "詹姆士·奈史密斯",  // zmshnsms
+    "其他	前途	奇特",  // qt
+    "辦公室",  // bgsh
+    "財產	從此	長城	粗糙	出差	操場",  // cc
+    "歐洲環境保護署",  // ozhjbhs
+    "主任	主人",  // zhr
+    "看不起",  // kbq
+    "或者	慌張	護照",  // hzh
+    "志願者",  // zyz
+    "精誠所至",  // jchszh
+    "平等	判斷	頻道	排隊",  // pd
+    "其實	確實	趨勢	輕鬆	缺少	去世	輕視",  // qs
+    "受不了",  // shbl
+    "如何	然後	任何",  // rh
+    "盧米埃爾",  // lmae
+    "親愛",  // qa
+    "世界	時間	世紀	設計	實際	實踐	數據	手機	射擊",  // sj
+    "牛仔褲",  // nzk
+    "廣泛	功夫	過分	颳風",  // gf
+    "美好	模糊	馬虎",  // mh
+    "高速公路",  // gsgl
+    "歐盟",  // om
+    "天安門東",  // tamd
+    "開始	可是	考試	咳嗽	開水",  // ks
+    "人民	人名",  // rm
+    "水果	手工",  // sg
+    "成立	處理	材料	除了	從來	叢林	醜陋	窗簾",  // cl
+    "繫領帶",  // jld
+    "你們	農民	難免",  // nm
+    "時刻	深刻",  // shk
+    "新西蘭",  // xxl
+    "東海龍王",  // dhlw
+    "摩托車",  // mtc
+    "當然	敵人	擔任	打擾",  // dr
+    "下	向	新	想	小	笑	像	先	縣",  // x
+    "佩·詹森",  // pzhs
+    "對話	兌換",  // dh
+    "直接	專家	逐漸	中間	至今	證據	著急	抓緊	中介",  // zhj
+    "氣候",  // qh
+    "放暑假",  // fshj
+    "菜市口",  // csk
+    "狀態	總統	昨天	整體	主題	轉頭	蒸騰	粘貼",  // zt
+    "成立	處理	除了	醜陋	窗簾",  // chl
+    "需要	吸引	校園	效應	幸運	小雨	鮮豔	下雨",  // xy
+    "怎麼辦",  // zmb
+    "另外	論文	禮物",  // lw
+    "初級中學",  // cjzx
+    "聊天	聊天兒",  // lt
+    "淋漓盡致",  // lljzh
+    "民族	名字	滿足	帽子",  // mz
+    "城市	產生	成熟	傳說	出生	承受	出色	潮濕	超市",  // chs
+    "派	平	破	片	拍	憑	批	篇	陪",  // p
+    "首都機場",  // sdjc
+    "禮拜天",  // lbt
+    "基本	進步	具備	舉辦	肩膀	加班	嘉賓	酒吧",  // jb
+    "組成	支持	造成	正常	主持	最初	贊成	照常",  // zch
+    "支持	正常	主持	註冊	照常",  // zhc
+    "青壯年",  // qzn
+    "朝三暮四",  // zsms
+    "採用	參與	差異	測驗	抽煙	次要	詞語	春遊	出院",  // cy
+    "一般來說",  // yblsh
+    "不過	報告	不管	寶貴	包裹	賓館	悲觀	餅乾	表格",  // bg
+    "規模	購買	光明	感冒	過敏	國貿",  // gm
+    "支持	正常	主持	照常",  // zhch
+    "記者	價值	建築	集中	接著	競爭	緊張	機制	禁止",  // jzh
+    "感興趣",  // gxq
+    "一直	嚴重	原則	亞洲	樣子	一致	銀子	椅子	葉子",  // yz
+    "女士	難受",  // ns
+    "物質	位置	文章	網站	文字	完整	屋子	王子	襪子",  // wz
+    "大使館",  // dsg
+    "命令	面臨	美麗	目錄	魅力	迷路	矇矓",  // ml
+    "系統	相同",  // xt
+    "大家	登記	道具",  // dj
+    "中秋節",  // zhqj
+    "加利福尼亞州",  // jlfnyzh
+    "請求	悄悄	親戚	親切	情趣",  // qq
+    "發現	分析	方向	風險	放心	分享	複習	反省",  // fx
+    "不斷	報道	不但	表達	病毒	波動	標點	報到",  // bd
+    "長城	出差",  // chch
+    "里查德·希爾斯",  // lcdxes
+    "以後	銀行	一會兒	友好	優惠	遺憾	宴會	約會",  // yh
+    "好吃	好處	灰塵	火柴",  // hch
+    "兒童",  // et
+    "麥布里奇",  // mblq
+    "怎麼樣",  // zmy
+    "受到	時代	適當	似的	首都	生動	商店	閃電	摔倒",  // shd
+    "確認",  // qr
+    "考慮	快樂	可憐	可樂",  // kl
+    "十分	是否	雙方	身份	師傅	舒服	沙發	說服	施肥",  // shf
+    "平時	爬山",  // psh
+    "所以	使用	所有	聲音	屬於	商業	適應	實驗	生意",  // sy
+    "環境	黃金	緩解	行家	寒假	紅酒",  // hj
+    "痛苦	天空	痛快	填空",  // tk
+    "企業	其餘	輕易	汽油	情緣",  // qy
+    "俱樂部",  // jlb
+    "標準	幫助	保證	不足	標誌	報紙	本質	脖子	鼻子",  // bz
+    "非常	諷刺	帆船	發愁",  // fc
+    "公開	趕快	顧客	概括",  // gk
+    "完成",  // wc
+    "一輩子",  // ybz
+    "和平	害怕	活潑",  // hp
+    "西紅柿",  // xhs
+    "羽毛球",  // ymq
+    "但是	當時	多少	電視	對手	大廈	到時候",  // dsh
+    "文學家",  // wxj
+    "恐怕	可怕",  // kp
+    "地區	地球	的確	道歉	顛球",  // dq
+    "應該	有關	嚴格	員工	陽光	勇敢	一共	牙膏	用功",  // yg
+    "按照	安裝",  // az
+    "過程	廣場	構成	工廠	觀察",  // gch
+    "偶然",  // or
+    "鍵盤",  // jp
+    "自然	主任	責任	主人",  // zr
+    "北京師範大學",  // bjshfdx
+    "長江大橋",  // chjdq
+    "攝影師",  // shysh
+    "風格",  // fg
+    "脾氣	迫切",  // pq
+    "長期	春秋",  // chq
+    "加油站",  // jyzh
+    "依然	羊肉",  // yr
+    "現在	選擇	性質	限制	象徵	尋找	校長	形狀	寫作",  // xz
+    "護國寺",  // hgs
+    "外交	文件	忘記	玩具	圍巾	文具",  // wj
+    "管理	公里	規律	鼓勵	光臨",  // gl
+    "國家	國際	根據	感覺	高級	關鍵	工具	估計	公斤",  // gj
+    "灰姑娘",  // hgn
+    "歪歪扭扭",  // wwnn
+    "長江大橋",  // cjdq
+    "志願者",  // zyzh
+    "農村",  // nc
+    "每天	媒體	明天	煤炭	麵條	饅頭	木頭	模特	苗條",  // mt
+    "熱鬧",  // rn
+    "正確	爭取	追求	準確	徵求	整齊",  // zq
+    "非常	帆船	發愁",  // fch
+    "勝利	數量	森林	順利	商量	熟練	善良	省略",  // sl
+    "列車	流傳	涼茶	劉辰",  // lch
+    "二手",  // es
+    "方面	父母	發明",  // fm
+    "未必	尾巴	微博",  // wb
+    "詹姆士·奈史密斯",  // zhmsnshms
+    "奔跑	鞭炮",  // bp
+    "土地	特點	態度	土豆	天敵",  // td
+    "加油站",  // jyz
+    "談判	調皮",  // tp
+    "個	過	給	高	更	各	跟	國	幹",  // g
+    "詹姆士·奈史密斯",  // zhmshnsms
+    "腎上腺素",  // sshxs
+    "充滿	沉默",  // chm
+    "課程	昆蟲	卡車",  // kc
+    "關注	公主	觀眾	改正	鼓掌	果汁	根治",  // gzh
+    "石灰岩	售貨員",  // shhy
+    "水平	商品",  // shp
+    "發生	方式	分手	發燒",  // fsh
+    "聯合	良好	厲害	落後	靈活	離婚	老虎",  // lh
+    "黑猩猩",  // hxx
+    "斯坦福",  // stf
+    "建立	經歷	交流	距離	記錄	激烈	將來	積累	經理",  // jl
+    "傳統	餐廳	抽屜	池塘	長途",  // ct
+    "秘書	面試	馬上	美術	陌生",  // ms
+    "調查	到處	單純	電池	堵車",  // dch
+    "斷斷續續",  // ddxx
+    "知道	制度	制定	重點	重大	指導	針對	主動	值得",  // zd
+    "西紅柿",  // xhsh
+    "正式	知識	重視	至少	真實	裝飾	準時	趙奢",  // zhsh
+    "植物	周圍	掌握	中文	中午",  // zhw
+    "法律	風浪",  // fl
+    "放暑假",  // fsj
+    "歐洲環境保護署",  // ozhhjbhs
+    "穩定	偉大	溫度	味道	舞蹈	外地",  // wd
+    "出	成	長	吃	稱	處	除	朝	場",  // ch
+    "參加	長江	促進	曾經	成績	成就	刺激	差距	初級",  // cj
+    "孩子	或者	合作	漢字	猴子	盒子	慌張	護照",  // hz
+    "可愛",  // ka
+    "紙上談兵",  // zhstb
+    "財產	長城	出差	操場",  // cch
+    "一般	一邊	預報	右邊	擁抱	要不",  // yb
+    "人口	入口",  // rk
+    "打電話",  // ddh
+    "錄取	力氣	零錢",  // lq
+    "何況	好客",  // hk
+    "批准	盤子	瓶子	盆子	棚子",  // pz
+    "《賣火柴的小女孩兒》",  // mhchdxnh
+    "麥克風",  // mkf
+    "歐洲環境保護署",  // ozhjbhsh
+    "多麼	對面	倒霉",  // dm
+    "洗手間",  // xshj
+    "我	為	無	外	問	萬	王	往	位",  // w
+    "自行車",  // zxch
+    "生產	市場	色彩	蔬菜	身材	商場	絲綢	刪除	順暢",  // sc
+    "首都機場",  // shdjc
+    "收入	輸入	濕潤	生日	攝入",  // shr
+    "廣東省",  // gds
+    "為什麼",  // wsm
+    "北京師範大學",  // bjsfdx
+    "內部",  // nb
+    "體現	同學	提醒	退休	騰訊",  // tx
+    "辛苦	幸虧",  // xk
+    "差不多",  // chbd
+    "本來	比例	保留	辦理	玻璃	本領	辯論",  // bl
+    "患難與共",  // hnyg
+    "名勝古跡",  // mshgj
+    "主要	重要	職業	專業	只要	終於	注意	只有	至於",  // zhy
+    "美人魚",  // mry
+    "工作	關注	工資	各自	公主	規則	觀眾	乾燥	個子",  // gz
+    "博物館",  // bwg
+    "馬薩諸塞州",  // mszhszh
+    "必須	表現	不行	保險	冰箱",  // bx
+    "稱呼	窗戶	車禍",  // chh
+    "為什麼",  // wshm
+    "水果	手工",  // shg
+    "單位	地位	動物	耽誤	大衛",  // dw
+    "紙上談兵",  // zstb
+    "內容	納入",  // nr
+    "政府	丈夫	祝福	翟峰",  // zf
+    "可以	烤鴨",  // ky
+    "國慶節",  // gqj
+    "可靠	刻苦",  // kk
+    "衛生間",  // wshj
+    "但是	當時	多少	電視	對手	打算	大廈	打掃",  // ds
+    "產品",  // chp
+    "鬧鐘",  // nzh
+    "開幕式",  // kmsh
+    "可	看	開	快	口	寬	靠	哭	空",  // k
+    "電子郵件",  // dzyj
+    "愛心	愛惜",  // ax
+    "表示	比賽	不少	博士	辦事	悲傷	筆試	報社",  // bs
+    "淋漓盡致",  // lljz
+    "圖書館",  // tshg
+    "機器	極其	減輕	堅強	郊區	價錢",  // jq
+    "位於	唯一	萬一	無意	餵養",  // wy
+    "完善	晚上	無數	旺盛	臥室	武術	握手",  // ws
+    "活動	獲得	回答	蝴蝶",  // hd
+    "歐洲環境保護署",  // ozhhjbhsh
+    "別人	比如	必然	不如	不然",  // br
+    "六一兒童節",  // lyetj
+    "情緒	取消	謙虛",  // qx
+    "幹活兒",  // ghe
+    "學生	形式	先生	迅速	小時	顯示	銷售	形勢	小說",  // xs
+    "初級中學",  // cjzhx
+    "改變	根本	公佈	個別	廣播	關閉	胳膊	告別	隔壁",  // gb
+    "不客氣",  // bkq
+    "作品	招聘	照片	支票",  // zp
+    "小伙子",  // xhz
+    "一般來說",  // ybls
+    "志願者",  // zhyz
+    "城市	產生	措施	從事	成熟	傳說	出生	促使	承受",  // cs
+    "基礎	堅持	機場	經常	檢查	接觸	精彩	警察	教材",  // jc
+    "的	到	都	等	對	地	大	得	多",  // d
+    "準備	逐步	轉變	長輩",  // zhb
+    "得到	達到	當地	到底	道德	到達	等待	地點	單獨",  // dd
+    "完善	晚上	無數	旺盛	臥室	武術	握手",  // wsh
+    "公司	告訴	故事	改善	感受	果實",  // gs
+    "峰終定律",  // fzdl
+    "不假思索",  // bjss
+    "太極拳",  // tjq
+    "傳統	抽屜	池塘	長途",  // cht
+    "充分	成分	出發	吃飯	重複	除非	廚房",  // chf
+    "健步如飛",  // jbrf
+    "無法	違反	往返",  // wf
+    "好像	化學	核心	互相	呼吸	或許	幻想	害羞	海鮮",  // hx
+    "澳大利亞",  // adly
+    "把握	傍晚",  // bw
+    "關係	貢獻	高興	關心	感謝	個性	光線	恭喜	感想",  // gx
+    "正確	爭取	追求	準確	徵求	整齊",  // zhq
+    "控制	誇張",  // kzh
+    "生物鐘",  // shwz
+    "菜市口",  // cshk
+    "承認	成人	傳染",  // chr
+    "發生	方式	放鬆	風俗	分手	發燒	粉色",  // fs
+    "朝三暮四",  // zhsms
+    "導致	地址	地震	打招呼	打折	打針",  // dzh
+    "設備	身邊	失敗	士兵	隨便	上班	順便	生病	散步",  // sb
+    "發揮	符合	廢話	返航",  // fh
+    "學校	學習	信息	消息	現象	相信	形象	休息	信心",  // xx
+    "透明	題目",  // tm
+    "情況",  // qk
+    "理髮店",  // lfd
+    "歐洲",  // oz
+    "希望	新聞	行為	下午	詢問	學問",  // xw
+    "面積	毛巾	民居",  // mj
+    "小姐	戲劇	細節	現金	消極	香蕉	孝敬",  // xj
+    "充分	成分	出發	採訪	吃飯	重複	除非	廚房",  // cf
+    "全面	前面	球迷	巧妙",  // qm
+    "初級中學",  // chjzhx
+    "人類	熱烈	日曆",  // rl
+    "開始	可是	考試	開水",  // ksh
+    "天氣	提前	同情	淘氣",  // tq
+    "里查德·希爾斯",  // lchdxes
+    "科學	可惜	開心	空閒",  // kx
+    "正式	知識	重視	至少	真實	裝飾	準時	趙奢",  // zhs
+    "說不定	捨不得",  // sbd
+    "產品",  // cp
+    "老婆	廉頗",  // lp
+    "可能	困難",  // kn
+    "年輕",  // nq
+    "金石為開",  // jswk
+    "物質	位置	文章	網站	完整	萬丈",  // wzh
+    "知更鳥",  // zgn
+    "全部	區別	鉛筆	錢包",  // qb
+    "初級中學",  // chjzx
+    "超過	成功	成果	楚國	唱歌",  // chg
+    "甚至	隨著	實在	生長	始終	數字	手指	獅子	睡著",  // sz
+    "按照	安裝",  // azh
+    "通過	提高	提供	推廣	提綱",  // tg
+    "女孩	女孩兒	暖和	《吶喊》",  // nh
+    "信用卡",  // xyk
+    "公共汽車",  // ggqc
+    "如果",  // rg
+    "哪怕",  // np
+    "其中	慶祝	簽證",  // qzh
+    "平常	賠償	破產",  // pch
+    "火成岩",  // hchy
+    "鬧鐘",  // nz
+    "加利福尼亞州",  // jlfnyz
+    "對於	第一	電影	答應	大約	等於	導演	島嶼	待遇",  // dy
+    "旅遊	利用	領域	利益	理由	錄音	老鷹",  // ly
+    "城市	產生	成熟	傳說	出生	承受	潮濕	超市	常識",  // chsh
+    "藝術	於是	運輸	優勢	醫生	要是	印刷	樣式	鑰匙",  // ysh
+    "名勝古跡",  // msgj
+    "文學	危險	威脅	微笑	維修	微信",  // wx
+    "郁達夫",  // ydf
+    "新鮮感",  // xxg
+    "提問	跳舞",  // tw
+    "阿姨	熬夜",  // ay
+    "《賣火柴的小女孩兒》",  // mhcdxnh
+    "怎麼	咱們	著名	專門	證明	字母	週末	讚美	字幕",  // zm
+    "巧克力",  // qkl
+    "劉熾平",  // lchp
+    "按時",  // ash
+    "獨立	地理	道理	鍛煉	低落",  // dl
+    "公共汽車",  // ggqch
+    "雖然	收入	私人	輸入	濕潤	生日	攝入",  // sr
+    "狀態	整體	主題	轉頭	蒸騰	粘貼",  // zht
+    "瘋狂	罰款	付款",  // fk
+    "登機牌",  // djp
+    "分	發	非	放	方	翻	份	犯	扶",  // f
+    "門檻	馬克	毛孔",  // mk
+    "問題",  // wt
+    "分配	發票",  // fp
+    "通常	突出	提倡	推遲	推辭",  // tc
+    "礦泉水",  // kqsh
+    "生物鐘",  // swzh
+    "而且",  // eq
+    "到時候",  // dshh
+    "後面	號碼",  // hm
+    "貸款	多虧",  // dk
+    "大學	東西	大型	擔心	對象	短信	點心	大象	當心",  // dx
+    "張小龍",  // zxl
+    "佩·詹森",  // pzs
+    "生物鐘",  // swz
+    "社會	生活	時候	上海	說話	適合	傷害	神話	收穫",  // shh
+    "金石為開",  // jshwk
+    "部分	辦法	北方	播放",  // bf
+    "今天	交通	具體	家庭	集體",  // jt
+    "形成	宣傳	小吃	相處	香腸",  // xch
+    "喜歡	信號	西漢	消化	鮮花	笑話",  // xh
+    "比爾·蓋茨",  // begc
+    "認識	人生	燃燒	人事",  // rs
+    "釐米	連忙	浪漫	禮貌",  // lm
+    "牛津大學",  // njdx
+    "這些	中心	主席	哲學	諮詢	仔細	秩序	自信	中旬",  // zx
+    "資料	總理	質量	治療	種類	整理	重量	展覽	爭論",  // zl
+    "行李箱",  // xlx
+    "火成岩",  // hcy
+    "父親	放棄",  // fq
+    "蘋果",  // pg
+    "股票	公平	光盤",  // gp
+    "《賣火柴的小女孩兒》",  // mhchdxnhe
+    "太太	體貼",  // tt
+    "是	上	說	所	使	三	死	省	手",  // s
+    "日用品",  // ryp
+    "工程師",  // gcs
+    "大概	度過	打工	蛋糕",  // dg
+    "著名	專門	證明	週末",  // zhm
+    "盲人",  // mr
+    "去年",  // qn
+    "已經	研究	以及	眼睛	意見	悠久	迎接	硬件	演講",  // yj
+    "大使館",  // dshg
+    "飛機	附近	風景	房間",  // fj
+    "消費	相反	幸福	興奮	廂房	信封",  // xf
+    "藝術	於是	因素	運輸	意思	優勢	醫生	顏色	要是",  // ys
+    "不	把	被	並	便	本	比	吧	別",  // b
+    "出版	傳播	創辦	翅膀	稱霸",  // chb
+    "影響	一下	有效	也許	英雄	優秀	允許	遊戲	有限",  // yx
+    "普通話",  // pth
+    "平時	爬山",  // ps
+    "出口	誠懇	吃虧	車庫",  // chk
+    "自行車",  // zxc
+    "《說文解字》",  // shwjz
+    "忍不住",  // rbz
+    "設施	事實	手術	受傷	收拾	碩士	叔叔	時尚	舒適",  // shs
+    "會員卡",  // hyk
+    "火車站",  // hchz
+    "哲學家	照相機",  // zhxj
+    "怪不得",  // gbd
+    "合同	話題	胡同	糊塗",  // ht
+    "採取	長期	春秋	從前",  // cq
+    "事物	食物	上午	失望	商務	上網	稍微",  // shw
+    "導致	地址	動作	地震	肚子	打折	袋子	打針",  // dz
+    "還是	和尚	合適	忽視	花生	胡說	護士",  // hsh
+    "體育館",  // tyg
+    "時期	事情	失去	申請	生氣",  // shq
+    "互聯網",  // hlw
+    "驕傲",  // ja
+    "在	這	著	之	只	最	再	走	做",  // z
+    "生產	市場	商場	絲綢	刪除	順暢	時差",  // sch
+    "程度	徹底	承擔	詞典	遲到	菜單",  // cd
+    "創造	成長	初中	稱讚	出自	乘坐	遲早	傳真	尺子",  // chz
+    "愛好	愛護",  // ah
+    "老闆	蘿蔔",  // lb
+    "知更鳥",  // zhgn
+    "黃河	豪華	後悔	花花",  // hh
+    "見面	節目	急忙	寂寞",  // jm
+    "相敬如賓",  // xjrb
+    "百分之",  // bfz
+    "年	你	那	能	內	呢	女	南	拿",  // n
+    "勝利	數量	順利	商量	熟練	善良	省略",  // shl
+    "人員	容易",  // ry
+    "設施	事實	損失	手術	隨時	受傷	搜索	收拾	碩士",  // ss
+    "什麼	說明	生命	沙漠	神秘	壽命	數碼	失眠	時髦",  // shm
+    "恢復	合法",  // hf
+    "污染	圍繞	溫柔",  // wr
+    "電腦",  // dn
+    "程度	徹底	承擔	遲到",  // chd
+    "好吃	好處	灰塵	火柴",  // hc
+    "紙上談兵",  // zhshtb
+    "冰激凌",  // bjl
+    "經濟	解決	積極	究竟	拒絕	接近	季節	堅決	姐姐",  // jj
+    "並且	表情	抱歉	鮑全",  // bq
+    "潘家園",  // pjy
+    "投資	調整	特徵	通知	挑戰	天真	兔子	蹄子",  // tz
+    "洛杉磯",  // lsj
+    "志願者",  // zhyzh
+    "爸爸	寶貝",  // bb
+    "哲學家	照相機",  // zxj
+    "甚至	隨著	生長	始終	手指	睡著",  // szh
+    "保持	補充	八成	八成兒",  // bch
+    "百分之",  // bfzh
+    "指揮	只好	正好	智慧	祝賀	賬戶	著火",  // zhh
+    "身體	石頭	沙灘	手套",  // st
+    "發展	分鐘	反正	服裝	複製	負重",  // fzh
+    "對不起",  // dbq
+    "所謂	事物	食物	上午	失望	商務	上網	稍微",  // sw
+    "愛迪生",  // adsh
+    "平均	評價	平靜	啤酒",  // pj
+    "標準	幫助	保證	標誌	報紙	本質	步驟",  // bzh
+    "甚至	實在	生長	始終	數字	手指	獅子	睡著	繩子",  // shz
+    "年代	難道	腦袋",  // nd
+    "紙上談兵",  // zshtb
+    "能力	努力	哪裡	年齡",  // nl
+    "和	還	後	好	會	很	號	回	紅",  // h
+    "詹姆士·奈史密斯",  // zhmsnsms
+    "精誠所至",  // jcsz
+    "清楚	其次	青春	起床",  // qc
+    "腎上腺素",  // ssxs
+    "腎上腺素",  // shshxs
+    "請願書",  // qys
+    "要求	一切	一起	引起	以前	尤其	邀請	與其	勇氣",  // yq
+    "清楚	青春	起床",  // qch
+    "我們	文明	外面	完美",  // wm
+    "膽小鬼",  // dxg
+    "青少年",  // qshn
+    "青壯年",  // qzhn
+    "詹姆士·奈史密斯",  // zmsnshms
+    "討價還價",  // tjhj
+    "地方	對方	大夫	豆腐	大方",  // df
+    "愉快",  // yk
+    "規定	感到	古代	觀點	廣大	固定	感動	古典	高檔",  // gd
+    "主要	重要	作用	職業	資源	專業	左右	只要	終於",  // zy
+    "了	來	裡	兩	老	路	連	另	拉",  // l
+    "宣佈	性別",  // xb
+    "攝影師",  // sysh
+    "女性	耐心",  // nx
+    "二手",  // esh
+    "全神貫注",  // qsgz
+    "明顯	明星	描寫	夢想	冒險	梅西",  // mx
+    "今年	紀念	濟南",  // jn
+    "生物鐘",  // shwzh
+    "正式	知識	總是	重視	至少	真實	暫時	遵守	裝飾",  // zsh
+    "日常	日程",  // rch
+    "由於	一樣	原因	應用	意義	語言	醫院	音樂	願意",  // yy
+    "名牌	名片",  // mp
+    "詹姆士·奈史密斯",  // zhmshnshms
+    "控制	孔子	筷子	誇張	褲子",  // kz
+    "項目	下面	小麥	羨慕	熊貓",  // xm
+    "工程師",  // gchs
+    "繁榮	否認",  // fr
+    "火車站",  // hchzh
+    "長城	出差",  // chc
+    "知道	制度	制定	重點	重大	指導	針對	主動	值得",  // zhd
+    "恩愛",  // ea
+    "普遍	旁邊	跑步",  // pb
+    "長江	成績	成就	差距	初級	春節	吃驚	超級	吵架",  // chj
+    "兒子",  // ez
+    "《非你莫屬》",  // fnmsh
+    "突然	投入	天然",  // tr
+    "天津衛視",  // tjws
+    "包括	本科",  // bk
+    "目的	面對	矛盾	美德",  // md
+    "表示	不少	博士	辦事	悲傷	筆試	報社",  // bsh
+    "政治	戰爭	真正	製造	主張	正在	製作	桌子	專注",  // zhz
+    "安慰",  // aw
+    "擴大	肯定",  // kd
+    "忽然",  // hr
+    "改革	哥哥	廣告	姑姑",  // gg
+    "進行	舉行	繼續	決心	教訓	家鄉	積蓄",  // jx
+    "王宮	外公",  // wg
+    "安全	愛情",  // aq
+    "課程	昆蟲	卡車",  // kch
+    "丹尼爾·卡內曼",  // dneknm
+    "不見得",  // bjd
+    "首都機場",  // shdjch
+    "是	上	說	使	社會	生活	時候	省	手",  // sh
+    "無所謂",  // wsw
+    "差不多",  // cbd
+    "洛杉磯",  // lshj
+    "客觀",  // kg
+    "沒有	貿易	滿意	命運",  // my
+];
+
+// u32 constants are murmur3 hash of 词语, for reverse lookup of pinyin from
+// hanzi text. The longest 词语 has HANZI_SIZE_MAX characters.
+pub const HANZI_SIZE_MAX: usize = 7;
+pub static HANZI: &[u32] = &[
+    0x0004e43b, // 打扮
+    0x0017e507, // 寒假
+    0x001ef582, // 哪里
+    0x0026709f, // 世纪
+    0x0078891c, // 饼干
+    0x007f26f7, // 聚会
+    0x0089eae0, // 退休
+    0x0094bd55, // 背
+    0x00a230fa, // 产生
+    0x00af67b9, // 裙子
+    0x00dfab05, // 渴
+    0x00ead05a, // 潘家园
+    0x010612fc, // 对于
+    0x01096bdb, // 输入
+    0x013c76d9, // 停
+    0x015331e0, // 根本
+    0x0165ca51, // 济南
+    0x019614db, // 武术
+    0x01c2d727, // 失业
+    0x01cc8e9f, // 十分
+    0x01ced252, // 辣
+    0x020ddabf, // 近
+    0x0216568c, // 瓶子
+    0x0242eacb, // 机场
+    0x02534bef, // 盖
+    0x0258b3d9, // 深
+    0x02740c78, // 承担
+    0x029857d9, // 试
+    0x029d660c, // 小吃
+    0x02a9102a, // 幸运
+    0x02b3be5f, // 挣
+    0x02cf8890, // 届
+    0x0301ad4f, // 昙花
+    0x031d30f5, // 报到
+    0x034b2b2f, // 除
+    0x03547720, // 议论
+    0x0378f7e0, // 账户
+    0x03a97700, // 友好
+    0x03ab85f0, // 米
+    0x03b0d153, // 逐步
+    0x03b8c6f5, // 赔偿
+    0x03c7d913, // 知道
+    0x03d7a638, // 嫁
+    0x03dbc949, // 过期
+    0x03f7162f, // 负重
+    0x0409bccb, // 班
+    0x0414fec0, // 雷
+    0x04240e48, // 沙子
+    0x046c80ef, // 代替
+    0x04808c0d, // 破坏
+    0x048fcb6e, // 工业
+    0x04a0ea39, // 洗礼
+    0x04a59d9b, // 接触
+    0x04dbaf3d, // 网络
+    0x04dd355a, // 锅
+    0x04e7f9f0, // 存在
+    0x04f48d38, // 能源
+    0x0500a945, // 耳朵
+    0x051ce01a, // 直
+    0x0529a905, // 顺畅
+    0x052c532f, // 舜
+    0x055baf0a, // 扔
+    0x056039d1, // 表明
+    0x0563ee9d, // 分别
+    0x057d3134, // 总之
+    0x05801f8a, // 亲爱
+    0x058033cd, // 台
+    0x05d560c3, // 中秋节
+    0x05db5550, // 思考
+    0x06289ce9, // 餐
+    0x062fc9e0, // 照常
+    0x0665ed77, // 自觉
+    0x067d8f64, // 撕
+    0x069c02cf, // 申请
+    0x06b282b2, // 发烧
+    0x06c43383, // 鼠标
+    0x06cee49d, // 称
+    0x06d73fae, // 什么
+    0x06f78cf2, // 勇气
+    0x073434c0, // 感情
+    0x073d1203, // 宁可
+    0x075fea57, // 悲观
+    0x07620c5f, // 现实
+    0x0767f8e2, // 奖
+    0x078d30c6, // 淋漓尽致
+    0x07a3df70, // 写
+    0x07b1cd47, // 汤
+    0x07c3fe8c, // 着
+    0x07cd980a, // 冬
+    0x0817c952, // 声调
+    0x084fd072, // 汗腺
+    0x085414d2, // 饱
+    0x08825fee, // 开发
+    0x08940473, // 打折
+    0x089d3adb, // 文学
+    0x08a6c9a6, // 咬
+    0x08b0a61b, // 借口
+    0x08b16922, // 尾气
+    0x08d2bd5a, // 永远
+    0x08d88f1e, // 九
+    0x0915fa73, // 其实
+    0x09177eaf, // 个性
+    0x091fb8db, // 等于
+    0x09256fef, // 舒服
+    0x0932b528, // 报名
+    0x09ccb1f3, // 行李箱
+    0x09f216e3, // 音乐会
+    0x09f62065, // 日子
+    0x0a01c4e2, // 位置
+    0x0a0775d5, // 沉
+    0x0a2e2c50, // 受到
+    0x0a557dfb, // 中旬
+    0x0a5f8228, // 出生
+    0x0a707993, // 利息
+    0x0a812fbc, // 养
+    0x0a9e1d03, // 行人
+    0x0ac3a2a6, // 软件
+    0x0ae6f193, // 蒸腾
+    0x0b053bd9, // 糟糕
+    0x0b0db19e, // 反省
+    0x0b2cf1f3, // 赵福根
+    0x0b334bf6, // 形象
+    0x0b3c4b1e, // 老板
+    0x0b526dd3, // 国王
+    0x0b886fd7, // 主席
+    0x0b9a0f60, // 里
+    0x0ba9c414, // 得意
+    0x0bd59c32, // 隔壁
+    0x0c0f0322, // 焦
+    0x0c2ac37e, // 我
+    0x0c4ce2d4, // 赶快
+    0x0c6ffa35, // 公斤
+    0x0c7fa77d, // 墙
+    0x0ca6a355, // 层
+    0x0cbf3d51, // 普遍
+    0x0ccbe5d2, // 手套
+    0x0cde8c52, // 抽象
+    0x0ce3afb5, // 圈
+    0x0d0036ca, // 退步
+    0x0d0283a9, // 满意
+    0x0d093416, // 蛋糕
+    0x0d18c6ef, // 别
+    0x0d1b4f06, // 受伤
+    0x0d1eb54a, // 闹钟
+    0x0d50b5d0, // 理发店
+    0x0d5873a3, // 活动
+    0x0d65630d, // 矛盾
+    0x0d669c7c, // 农业
+    0x0d77167a, // 愿意
+    0x0d80ad1e, // 包裹
+    0x0d9613d8, // 下
+    0x0db2628c, // 仍然
+    0x0dc2325b, // 片
+    0x0def5498, // 奇迹
+    0x0df9ec04, // 盆子
+    0x0e0dfa71, // 吸引
+    0x0e150bf7, // 全面
+    0x0e48b6de, // 兴奋
+    0x0e59187e, // 文件
+    0x0e65f693, // 打招呼
+    0x0e6f1092, // 人类
+    0x0e7f245b, // 地球
+    0x0e962382, // 马虎
+    0x0ea467db, // 小麦
+    0x0eae23b0, // 部门
+    0x0eda0402, // 挥
+    0x0f08edbd, // 战争
+    0x0f51a31b, // 爱迪生
+    0x0f5a9cd4, // 学问
+    0x0f62425e, // 教室
+    0x0f8e4ac0, // 设备
+    0x0fb22bb1, // 转
+    0x0fb286f3, // 元旦
+    0x0fdab415, // 库
+    0x0ffa36a4, // 孝敬
+    0x100b7d80, // 初级
+    0x104d4a64, // 健康
+    0x1074ffda, // 好
+    0x10935ade, // 姿势
+    0x10a80af0, // 口
+    0x10c19c79, // 共同
+    0x10ee8656, // 硬件
+    0x10f90e8e, // 种类
+    0x11047398, // 健身
+    0x110c23bd, // 他
+    0x111ffc24, // 体育馆
+    0x115308d7, // 禁止
+    0x1185ff0b, // 宴会
+    0x11c273d1, // 困难
+    0x11c2a263, // 自然
+    0x11c72bea, // 猫
+    0x11e70098, // 围巾
+    0x11eb8987, // 糖
+    0x11f9a909, // 澳大利亚
+    0x1219f759, // 早上
+    0x121ff991, // 最后
+    0x122c23eb, // 少许
+    0x122f109f, // 街
+    0x1233054b, // 胃
+    0x12534073, // 作用
+    0x1259ee2a, // 经营
+    0x1260f36a, // 询问
+    0x12704454, // 形容
+    0x1272e1a4, // 啄
+    0x127a583a, // 洗手间
+    0x128c45fd, // 测验
+    0x128c8dd4, // 身段
+    0x1298373f, // 挂号
+    0x12ce1b40, // 爱惜
+    0x139437ce, // 信号
+    0x1399ec3c, // 目的
+    0x139d9007, // 矿泉水
+    0x139ef31b, // 要求
+    0x13bce583, // 系领带
+    0x13c9dc69, // 治
+    0x13d93c15, // 虽然
+    0x13f09b81, // 只要
+    0x140428c6, // 从来
+    0x14305480, // 随时
+    0x144fd446, // 后悔
+    0x145f9397, // 打针
+    0x146a0a29, // 收入
+    0x146cfff8, // 不客气
+    0x146dfe2b, // 放纵
+    0x1484f8d9, // 从事
+    0x1486750b, // 慌
+    0x1494abd5, // 热心
+    0x149c6ad3, // 不然
+    0x15a81f9d, // 冰箱
+    0x15b58631, // 客观
+    0x15c30fc6, // 长辈
+    0x15c7bfbe, // 利用
+    0x15d6b74b, // 大
+    0x15df754a, // 交
+    0x160eb5b9, // 只
+    0x1646a900, // 戴
+    0x164e2518, // 忍不住
+    0x167ad464, // 流利
+    0x167d3db3, // 去
+    0x16867984, // 快乐
+    0x16bbbf5f, // 已经
+    0x16c3e4a4, // 点心
+    0x16de0501, // 夸张
+    0x16e4dc8e, // 笑
+    0x16e7aab2, // 趋势
+    0x16f468cd, // 游泳
+    0x1713c828, // 赵国
+    0x1717881d, // 实验
+    0x17288e38, // 沉默
+    0x176362c9, // 车厢
+    0x1785bfec, // 尤其
+    0x179de1e7, // 恨
+    0x17a2f55f, // 再见
+    0x17afe172, // 专家
+    0x17ecda92, // 节目
+    0x17fa505b, // 体育
+    0x180cf89b, // 公寓
+    0x181076a0, // 毛孔
+    0x181ad5e9, // 显示
+    0x18262375, // 讽刺
+    0x1828223a, // 云南
+    0x1833f002, // 盘子
+    0x183cec74, // 汽油
+    0x18480237, // 录音
+    0x18531cdc, // 商场
+    0x187cd9e4, // 下午
+    0x18a3452a, // 步骤
+    0x18a66551, // 公主
+    0x18bf2faf, // 寄生
+    0x18f05477, // 记得
+    0x190fdf55, // 感受
+    0x1916e068, // 加
+    0x191ea8d4, // 小
+    0x192f700c, // 部
+    0x194ae962, // 地位
+    0x1958e170, // 孙月
+    0x195eddc6, // 愉快
+    0x1989f3fa, // 羊肉
+    0x199252ae, // 故事
+    0x19c8b8a5, // 够
+    0x19d33277, // 内科
+    0x19f49cb5, // 按
+    0x19f9cd75, // 表演
+    0x1a1a0cd1, // 关系
+    0x1a28b2d5, // 公共汽车
+    0x1a430e24, // 故意
+    0x1a4d902c, // 零件
+    0x1a6f0279, // 自私
+    0x1a820694, // 蹲
+    0x1aa4e2fb, // 难免
+    0x1ab9ab6c, // 千万
+    0x1ac2fe0f, // 抱怨
+    0x1ac90929, // 年纪
+    0x1ada8e69, // 用功
+    0x1aee7741, // 威胁
+    0x1afc2bf1, // 年轻
+    0x1b05b471, // 增加
+    0x1b0f5610, // 零食
+    0x1b3df49d, // 改革
+    0x1b597b35, // 延长
+    0x1b840a88, // 平方
+    0x1b8eb556, // 趁
+    0x1bba472f, // 破
+    0x1bbbce68, // 哥哥
+    0x1bd96baa, // 迎接
+    0x1bf33165, // 短信
+    0x1c09adff, // 教训
+    0x1c0f16f2, // 同事
+    0x1c152852, // 观点
+    0x1c17bfa3, // 甩
+    0x1c302361, // 对手
+    0x1c690ba4, // 蛇
+    0x1c987181, // 京剧
+    0x1c9ab320, // 广和居
+    0x1cae7f31, // 最好
+    0x1cc56182, // 些
+    0x1ccfa2ea, // 埋
+    0x1cf1ee5d, // 袋
+    0x1d099c94, // 移民
+    0x1d11ee8d, // 牙齿
+    0x1d196428, // 脸
+    0x1d2e272e, // 装饰
+    0x1d3b625a, // 多少
+    0x1d55487d, // 了解
+    0x1d588949, // 姓
+    0x1d6111b2, // 洗
+    0x1d711a35, // 家庭
+    0x1d7a708b, // 歌舞
+    0x1d7d4584, // 比例
+    0x1d866390, // 盆
+    0x1dbf2d14, // 充满
+    0x1dd517d7, // 谨慎
+    0x1de77be3, // 打算
+    0x1de7e209, // 米饭
+    0x1e1e829a, // 检查
+    0x1e549326, // 压力
+    0x1e6c966b, // 张小龙
+    0x1e768f36, // 天气
+    0x1e7d7c44, // 毕竟
+    0x1e957878, // 勇敢
+    0x1e9b41bb, // 扩大
+    0x1ecc3fa0, // 消极
+    0x1ed935f6, // 反对
+    0x1ee7c667, // 志愿者
+    0x1ef03168, // 热
+    0x1ef9475e, // 游览
+    0x1f0adc7d, // 气氛
+    0x1f3490cd, // 难过
+    0x1f459bf1, // 一旦
+    0x1f64cfc5, // 条
+    0x1f7a5c03, // 解释
+    0x1f7fbe2c, // 十
+    0x1f889e09, // 车祸
+    0x1fe9f1d4, // 双方
+    0x1ffa3ef0, // 支票
+    0x2004329e, // 范围
+    0x2004b8ee, // 最
+    0x200a96e7, // 在
+    0x20156c5f, // 阻止
+    0x20195124, // 统一
+    0x202329d3, // 摄入
+    0x2032f9b0, // 主观
+    0x2033ddbf, // 否定
+    0x2043502c, // 类型
+    0x20491437, // 几
+    0x208aaa23, // 骑
+    0x20900bd9, // 整体
+    0x20d8cc23, // 哈
+    0x20f58b1b, // 图
+    0x211426cb, // 次要
+    0x2114bb5f, // 脑袋
+    0x2124ed6f, // 毛病
+    0x2133546f, // 不见得
+    0x213fb284, // 球迷
+    0x2143561a, // 根
+    0x215c88b9, // 伤心
+    0x216833eb, // 臭
+    0x2177bab7, // 教练
+    0x21857a26, // 盐
+    0x21bf11ab, // 充分
+    0x21d69197, // 正常
+    0x21d7ca8a, // 主要
+    0x21dbdf4e, // 商量
+    0x21ede9db, // 哲理
+    0x2209c864, // 开水
+    0x223f7c99, // 去世
+    0x2246de89, // 胡同
+    0x225f3a65, // 电池
+    0x2273a07e, // 会计
+    0x228b2625, // 说不定
+    0x230630e3, // 保留
+    0x23363baa, // 花
+    0x23636e9b, // 毛巾
+    0x23949530, // 晕
+    0x23be8d8a, // 天敌
+    0x23c3693f, // 重
+    0x23d3ae9f, // 鲁迅
+    0x23f5e01c, // 翅膀
+    0x23fc9082, // 调皮
+    0x240171df, // 质量
+    0x240a6ed8, // 笔记本
+    0x2428bf42, // 有关
+    0x2432a83e, // 运气
+    0x243609d4, // 抬
+    0x2448d7a3, // 感想
+    0x246fac42, // 句子
+    0x24a00304, // 迟到
+    0x24a10dc9, // 与
+    0x24b3792c, // 亚洲
+    0x24ba608c, // 打电话
+    0x24d23849, // 女孩
+    0x251f9a0b, // 据说
+    0x252365e0, // 谦虚
+    0x2525869c, // 观念
+    0x25291de3, // 鲇鱼
+    0x25484d38, // 点
+    0x254bebd8, // 兑换
+    0x25514d66, // 广泛
+    0x2551f6df, // 咨询
+    0x25629ddb, // 待遇
+    0x2578f835, // 休息
+    0x258e0935, // 降落
+    0x25c4ffce, // 拍
+    0x25cea4d1, // 不耐烦
+    0x25fc2588, // 中间
+    0x26246048, // 发
+    0x26305efb, // 马萨诸塞州
+    0x26354dc6, // 差距
+    0x264b31d0, // 如今
+    0x264ff84b, // 结婚
+    0x266bb210, // 痒
+    0x26a4f9f6, // 放
+    0x26c8358d, // 圆
+    0x26db8813, // 急忙
+    0x26e3c551, // 责任
+    0x26ea0d93, // 长江大桥
+    0x270324dd, // 新鲜
+    0x270faf4d, // 实际
+    0x27247bec, // 演出
+    0x272c3dbb, // 字母
+    0x273083f3, // 前途
+    0x273dff5c, // 麻烦
+    0x274c0aee, // 玩具
+    0x275902d9, // 手表
+    0x275ff248, // 知感
+    0x277b4c65, // 胜利
+    0x2789f6fd, // 前面
+    0x27b7f349, // 鼻子
+    0x27c0e577, // 离婚
+    0x27c5620c, // 兼职
+    0x28294680, // 讨厌
+    0x282c0273, // 印加
+    0x2834b406, // 标点
+    0x2844f106, // 刺激
+    0x285181f9, // 不得不
+    0x2854166f, // 成语
+    0x2858d9f3, // 杨
+    0x2875cdb6, // 搜索
+    0x28841205, // 事半功倍
+    0x288d18b1, // 传染
+    0x28960667, // 朝三暮四
+    0x28a3ca31, // 羡慕
+    0x28c75c00, // 模仿
+    0x28d0a360, // 白
+    0x28d95171, // 火车站
+    0x28f0a397, // 花花
+    0x28fe2f1e, // 甚至
+    0x294174f2, // 确实
+    0x2967d6e3, // 即使
+    0x297c9cb6, // 背景
+    0x29853767, // 注意
+    0x2987deb2, // 返航
+    0x298c24b2, // 规律
+    0x2993cfeb, // 首都
+    0x299a70c4, // 翻译
+    0x29b1428f, // 相信
+    0x29dffccc, // 歪歪扭扭
+    0x29e9cca8, // 复杂
+    0x29fbe370, // 敲
+    0x2a00d829, // 试卷
+    0x2a014eee, // 好奇
+    0x2a3ef376, // 发抖
+    0x2a6ca3b4, // 激烈
+    0x2a971443, // 菊花
+    0x2aa8c909, // 嘴
+    0x2ab10a17, // 猪
+    0x2ab11bdc, // 萝卜
+    0x2b060758, // 装
+    0x2b0a9c7a, // 放心
+    0x2b22f62e, // 形状
+    0x2b2efa9d, // 由来
+    0x2b31ebd9, // 有趣
+    0x2b355f84, // 困
+    0x2b3a3014, // 钱
+    0x2b3ac362, // 古典
+    0x2b4cb843, // 管子
+    0x2b6123fa, // 专业
+    0x2b738ee6, // 并
+    0x2b7b6792, // 赚
+    0x2b87a8f4, // 离开
+    0x2b8f70c0, // 传真
+    0x2be7418d, // 胳膊
+    0x2bf1d832, // 功能
+    0x2bfe5aea, // 脾气
+    0x2c06d604, // 温暖
+    0x2c0ea5ba, // 高级
+    0x2c152f09, // 聪明
+    0x2c16cdfd, // 慢
+    0x2c1779f2, // 时髦
+    0x2c2864a6, // 经商
+    0x2c3aa482, // 包含
+    0x2c4caa09, // 项
+    0x2c5bea83, // 筷子
+    0x2c6d074e, // 孩子
+    0x2c6ed06c, // 假
+    0x2c6f981b, // 常识
+    0x2c71559b, // 表格
+    0x2c7310fb, // 初级中学
+    0x2c787c4a, // 告诉
+    0x2c87f153, // 竹子
+    0x2c9f6757, // 打喷嚏
+    0x2ca19aec, // 违反
+    0x2cbe4dec, // 详细
+    0x2cc06b37, // 彻底
+    0x2ccfc138, // 作战
+    0x2d055b44, // 擦
+    0x2d24640b, // 洗澡
+    0x2d75f0e5, // 不安
+    0x2d82bc0e, // 罚款
+    0x2d927e92, // 极了
+    0x2da16149, // 厨房
+    0x2ddc5a64, // 极
+    0x2de7da66, // 可能
+    0x2de99bbe, // 而
+    0x2deedb0a, // 假设
+    0x2dfc2cee, // 重点
+    0x2e1a291a, // 怪物
+    0x2e2fba10, // 用途
+    0x2e52806c, // 不假思索
+    0x2e5d37ab, // 吸收
+    0x2e6149b3, // 画
+    0x2e80a36f, // 风浪
+    0x2e907109, // 布
+    0x2e94f66b, // 成果
+    0x2ebbc0e2, // 美丽
+    0x2ec20740, // 图书馆
+    0x2ecad490, // 内
+    0x2ecc175f, // 年龄
+    0x2ecd30b9, // 宣布
+    0x2ecebe83, // 为什么
+    0x2ef0bba1, // 台阶
+    0x2ef0ed03, // 一律
+    0x2f18e4c2, // 看望
+    0x2f1b4e14, // 互联网
+    0x2f37c8d9, // 影响
+    0x2f3bc12f, // 歪
+    0x2f4d5eb0, // 葡萄
+    0x2f5b7945, // 遍
+    0x2f69d7db, // 诺贝尔奖
+    0x2f6f4d13, // 窗
+    0x2f7f27fb, // 艺术
+    0x2f87b976, // 主题
+    0x2f932171, // 皮鞋
+    0x2f9d5263, // 肾上腺素
+    0x2fa6e753, // 鼓舞
+    0x2fb2f8f5, // 实现
+    0x300653bd, // 完整
+    0x30087c73, // 空调
+    0x3018488f, // 棒
+    0x304e1b04, // 美人鱼
+    0x305a3cf4, // 喂
+    0x306e7823, // 放暑假
+    0x309915c3, // 升
+    0x309a7f7e, // 下雨
+    0x30c4de98, // 引起
+    0x30e2b056, // 倒霉
+    0x30f1af00, // 客厅
+    0x30fcf466, // 远
+    0x3180aa71, // 建议
+    0x319c4045, // 主动
+    0x31c36912, // 晚上
+    0x31d5d777, // 命运
+    0x3211a145, // 精彩
+    0x3223d4ee, // 改
+    0x322c1266, // 老师
+    0x32406759, // 话题
+    0x324cbbfb, // 观众
+    0x3265240e, // 失败
+    0x326d06ef, // 硕士
+    0x327b4cfa, // 春游
+    0x328238aa, // 遗憾
+    0x32987e5e, // 占
+    0x32a6c3ca, // 指
+    0x32acb023, // 演讲
+    0x32caf2de, // 同学
+    0x32cf3a45, // 军事
+    0x330a46bd, // 肚子
+    0x3313789e, // 欧洲环境保护署
+    0x33418b7e, // 恶劣
+    0x3355e9e1, // 权力
+    0x3366f2f3, // 角色
+    0x3370c631, // 酒吧
+    0x337564d3, // 右边
+    0x33811e87, // 短
+    0x3386e991, // 本质
+    0x338e459b, // 扶
+    0x33a1f515, // 交换
+    0x33a39b5f, // 八成
+    0x33a400d8, // 秦国
+    0x33dfd5d2, // 使劲儿
+    0x33f3b8ff, // 盲人
+    0x33f64649, // 例子
+    0x3404b07c, // 欧洲
+    0x34117db5, // 一再
+    0x3417b651, // 峰终定律
+    0x34197c89, // 轻
+    0x342c5b81, // 帆船
+    0x3431a437, // 折
+    0x343a626b, // 进口
+    0x344381d0, // 概括
+    0x34498f7a, // 起
+    0x3453beea, // 肯定
+    0x34818597, // 东海龙王
+    0x3497a2d9, // 存
+    0x34a4ec50, // 唉
+    0x34ab671c, // 咱们
+    0x34b35c59, // 来得及
+    0x353f2109, // 理解
+    0x35432450, // 必须
+    0x3543f283, // 文火
+    0x35445310, // 海洋
+    0x3596d278, // 结构
+    0x359bf10e, // 笑话
+    0x35aad165, // 出口
+    0x35b7c6fb, // 太极拳
+    0x35c99fb2, // 新年
+    0x3600ca1a, // 特殊
+    0x3613b810, // 象
+    0x361c8ce7, // 危害
+    0x361e82b0, // 陪
+    0x362d04e9, // 坏
+    0x363e774f, // 讲
+    0x3655bbd5, // 亲戚
+    0x3662049e, // 春节
+    0x366b06ba, // 谈判
+    0x367384ca, // 胶水
+    0x368f8445, // 属于
+    0x36932b77, // 嗯
+    0x36a11b49, // 首都机场
+    0x36b49b6b, // 地毯
+    0x36cbd44a, // 克
+    0x36f03f9c, // 打交道
+    0x36f9ac5f, // 顿
+    0x37010b67, // 身体
+    0x37247d78, // 群
+    0x3750c4e7, // 星期
+    0x3759a7a4, // 套
+    0x378b91d8, // 干燥
+    0x378d83fc, // 搞
+    0x37acebb7, // 不得了
+    0x37afcaa1, // 坦率
+    0x37b6bbcf, // 椅子
+    0x37c12f8d, // 参与
+    0x37c53681, // 功夫
+    0x3828d8a3, // 青蛙
+    0x38617963, // 想念
+    0x387c5968, // 领导
+    0x389ee828, // 看见
+    0x38fc1db2, // 糊涂
+    0x390f491b, // 讲座
+    0x3916095a, // 厕所
+    0x3917a67d, // 魅力
+    0x39256a58, // 轮
+    0x394744dd, // 入围
+    0x3950612e, // 进行
+    0x39648225, // 稻香村
+    0x3972dbf5, // 牛仔裤
+    0x397dd251, // 一连
+    0x398f0c61, // 丝毫
+    0x399350b2, // 孙子
+    0x39d05428, // 早
+    0x39dd0bb1, // 业务
+    0x39f8b7bf, // 主意
+    0x3a0608fb, // 后面
+    0x3a0d769c, // 喝
+    0x3a5c5246, // 摘
+    0x3a611410, // 摩托车
+    0x3abca436, // 护士
+    0x3b0ada97, // 人事
+    0x3b44e249, // 重要
+    0x3b60e7e4, // 这些
+    0x3b7a19c0, // 爱情
+    0x3b8a595b, // 叮
+    0x3bb23c9a, // 般
+    0x3bd38b3f, // 着凉
+    0x3bf1b4bd, // 玩
+    0x3c178bf6, // 不过
+    0x3c3c601a, // 导游
+    0x3c783eaa, // 断
+    0x3cb0806a, // 高速公路
+    0x3cbb8b5d, // 神话
+    0x3cdc8968, // 改正
+    0x3cfacb9c, // 空儿
+    0x3cfe41f2, // 橘子
+    0x3d211c6b, // 兴趣
+    0x3d23ce7f, // 年
+    0x3d29f447, // 力量
+    0x3d83ba09, // 关
+    0x3d8d5d11, // 分享
+    0x3da7a30c, // 护照
+    0x3ddff9f9, // 沟通
+    0x3e028cd0, // 倒
+    0x3e1158a9, // 迅速
+    0x3e294d27, // 国君
+    0x3e53aae1, // 黄
+    0x3ea7395f, // 力气
+    0x3ee95a60, // 木头
+    0x3f1f1ce1, // 流泪
+    0x3f668580, // 杆
+    0x3f7c8ecc, // 佳
+    0x3f88c7f7, // 六
+    0x3f9e7a31, // 久
+    0x3fbab089, // 金属
+    0x3fc50583, // 冷静
+    0x3fd3bd38, // 填空
+    0x3fd4ef8a, // 景色
+    0x402a3353, // 今年
+    0x403136b0, // 信心
+    0x40658323, // 绕
+    0x40a84379, // 发票
+    0x40a8bb71, // 雪
+    0x40ecfaca, // 酱油
+    0x41033497, // 模特
+    0x4105b442, // 成熟
+    0x4112e9a5, // 租
+    0x41306c1e, // 以
+    0x415f51c9, // 工程师
+    0x4184ed42, // 家访
+    0x41b19ca9, // 熟悉
+    0x41caffb9, // 过敏
+    0x41d5785f, // 门槛
+    0x41dfdd32, // 想象
+    0x4209cfa3, // 生病
+    0x42505dde, // 思想
+    0x4254418c, // 集合
+    0x4258fc55, // 太阳
+    0x426648f6, // 次
+    0x4297a395, // 几乎
+    0x42c4f1ec, // 手术
+    0x42cc9056, // 姑姑
+    0x42cff261, // 设计
+    0x42d61e5e, // 成立
+    0x42dd3dc3, // 老鼠
+    0x42e8b54a, // 百分之
+    0x42f9bfec, // 洛杉矶
+    0x4304e45a, // 复习
+    0x43202c43, // 食物
+    0x432130fe, // 愿望
+    0x43301305, // 让
+    0x434954ef, // 出院
+    0x4378ae16, // 偶尔
+    0x4392b381, // 谢谢
+    0x43988a9a, // 受不了
+    0x43a51085, // 态度
+    0x43b0e0d3, // 全神贯注
+    0x43bda6ae, // 星星
+    0x4433c728, // 打篮球
+    0x4435c073, // 难
+    0x443ed295, // 太太
+    0x44507fb5, // 黄河
+    0x4479708c, // 天真
+    0x44820ece, // 捡
+    0x448a2ea3, // 面包
+    0x44c50d07, // 表
+    0x44eace30, // 行家
+    0x44f129b7, // 填
+    0x45139d80, // 售货员
+    0x4551acf6, // 船
+    0x456bf99b, // 听
+    0x45751134, // 由于
+    0x457611b9, // 伞
+    0x45ae31ab, // 挺
+    0x45b1072d, // 风景
+    0x45c960cc, // 华北
+    0x45d33789, // 吧
+    0x45f3f1ec, // 处
+    0x45fe7fa3, // 买
+    0x4614dd6c, // 日
+    0x464be9c6, // 敌人
+    0x465df36c, // 寿命
+    0x466074ed, // 旅游
+    0x466f3171, // 奔跑
+    0x4673c968, // 香瓜
+    0x46852538, // 黑猩猩
+    0x4697a67b, // 以及
+    0x46d46efa, // 挂
+    0x46d77945, // 啊
+    0x46edfdd4, // 总裁
+    0x46f7c52f, // 风格
+    0x46fd7a1c, // 成就
+    0x4702fa4d, // 浏览
+    0x472da144, // 凉快
+    0x473305b4, // 作家
+    0x473d9581, // 闯
+    0x479e038e, // 折叠
+    0x47a623d6, // 突然
+    0x47a965ec, // 小心
+    0x47bbaed5, // 透明
+    0x47d11301, // 使用
+    0x47d732b1, // 道理
+    0x47dcfc09, // 丝
+    0x47e30aef, // 降低
+    0x47fe4a45, // 驾驶
+    0x4812d7c4, // 凭
+    0x481a283d, // 预防
+    0x484f61f9, // 浪费
+    0x48981dda, // 累
+    0x489e6537, // 博物馆
+    0x48af7a5c, // 豆腐
+    0x48b38e68, // 化学
+    0x48d06f2f, // 经历
+    0x48fd7742, // 学术
+    0x490ac3e5, // 万丈
+    0x490d8837, // 宽
+    0x4911d27e, // 灵活
+    0x4914159e, // 逃避
+    0x491e6367, // 角
+    0x495cff07, // 更
+    0x496f19e8, // 命令
+    0x497c20c6, // 年级
+    0x49879892, // 来自
+    0x498eb10c, // 灰姑娘
+    0x4990e72f, // 日记
+    0x4993bd34, // 从
+    0x49e08671, // 寂寞
+    0x49f08baa, // 舍不得
+    0x4a2f3f32, // 月
+    0x4a45624c, // 拉
+    0x4a5cfd70, // 舞蹈
+    0x4a85c2f8, // 微笑
+    0x4a89c186, // 颠球
+    0x4aa25991, // 嗓子
+    0x4adec4f7, // 尽
+    0x4aef4f59, // 丑
+    0x4afa733e, // 半
+    0x4b0f0059, // 上当
+    0x4b150db3, // 数
+    0x4b228406, // 匆忙
+    0x4b4065b7, // 忽视
+    0x4b4161dc, // 号码
+    0x4b6a9f51, // 投篮
+    0x4b88312d, // 很
+    0x4ba8c7c3, // 或者
+    0x4bad69db, // 仔细
+    0x4bb2f4da, // 总理
+    0x4bbc9bce, // 可见
+    0x4bd4a547, // 怀念
+    0x4be3fcd0, // 可以
+    0x4c26e5d3, // 口味
+    0x4c4a57d7, // 权利
+    0x4c6395a5, // 分配
+    0x4c72fc3c, // 方法
+    0x4c9b5b51, // 热情
+    0x4caac80d, // 没有
+    0x4cae9260, // 做
+    0x4cda8792, // 获得
+    0x4cda8ed4, // 健伟
+    0x4cdba272, // 石头
+    0x4cdf45d9, // 否则
+    0x4cf5df63, // 底
+    0x4d1c65a8, // 保险
+    0x4d252929, // 对象
+    0x4d367cc3, // 影子
+    0x4d3fb942, // 责备
+    0x4d4f00e5, // 决定
+    0x4d50828c, // 半夜
+    0x4d9c0279, // 煮
+    0x4dc65063, // 西
+    0x4dca9b82, // 平静
+    0x4de7a5cc, // 枝干
+    0x4de9abfb, // 叉子
+    0x4df5b9cf, // 行业
+    0x4e267e83, // 应用
+    0x4e2be313, // 来
+    0x4e3f6697, // 词语
+    0x4e4a51c0, // 出发
+    0x4e5107bb, // 工作
+    0x4ea1cef6, // 角度
+    0x4ea8c755, // 丝绸
+    0x4ed5f5ba, // 相对
+    0x4efcff27, // 欠
+    0x4f03de91, // 管理
+    0x4f2699cd, // 电影
+    0x4f62b980, // 为
+    0x4f8ac2c1, // 快
+    0x4f949ecf, // 删除
+    0x4fc41c59, // 高
+    0x4ff8569a, // 厢房
+    0x501ae1b1, // 严肃
+    0x5042f20c, // 执照
+    0x505036e4, // 桌子
+    0x507a2f53, // 冷
+    0x50a8013b, // 难受
+    0x50a8785e, // 贸易
+    0x50bd0e08, // 开心
+    0x50faa632, // 逛
+    0x51147949, // 赢
+    0x511636af, // 时期
+    0x511b7aa6, // 导致
+    0x511fd1da, // 把
+    0x516209bb, // 投资
+    0x518f8c03, // 明明
+    0x5190e45b, // 上午
+    0x519f6272, // 努力
+    0x51a87cfe, // 县
+    0x51b14b40, // 特别
+    0x51b9eeb9, // 跟
+    0x51ff63a6, // 也许
+    0x52437720, // 招
+    0x52519a64, // 老实
+    0x528fda8e, // 从而
+    0x529a89f6, // 交际
+    0x52bb4075, // 精力
+    0x52cb9aa2, // 灾害
+    0x52dc9a9c, // 报道
+    0x52eeb235, // 补充
+    0x5319d1b5, // 悠久
+    0x532beae5, // 名片
+    0x53877bc1, // 固定
+    0x5395889e, // 悠悠
+    0x53a14699, // 趟
+    0x53bd1e14, // 采取
+    0x53c0bd1b, // 失望
+    0x53d92d5a, // 损失
+    0x541cb9ab, // 鲜艳
+    0x5443bb62, // 越
+    0x544df645, // 就
+    0x545ee60f, // 节省
+    0x54a97233, // 担任
+    0x54aac1d4, // 一点儿
+    0x54b15e35, // 照片
+    0x54b91da2, // 篇
+    0x54bda7b2, // 广场
+    0x54ca735c, // 起来
+    0x54d11340, // 振动
+    0x54ddb3bf, // 安静
+    0x54f2f9a5, // 周
+    0x554b0c19, // 基础
+    0x555a2f75, // 物质
+    0x557b105c, // 破产
+    0x559333f4, // 重新
+    0x55e0bce7, // 以后
+    0x55f37b17, // 热烈
+    0x56045b99, // 阿姨
+    0x561bcffe, // 反而
+    0x5639836c, // 手指
+    0x5652b6d9, // 完成
+    0x5671335e, // 形式
+    0x56859f25, // 积蓄
+    0x569085d9, // 七
+    0x56a7aef5, // 标准
+    0x56adcc9a, // 方
+    0x56c7cf1c, // 岛屿
+    0x56cf2edc, // 良好
+    0x56d05609, // 老虎
+    0x56d6e92f, // 平均
+    0x56f6bec6, // 批准
+    0x570c8967, // 效率
+    0x5729a604, // 安排
+    0x572aff50, // 湿润
+    0x5761f367, // 贡献
+    0x57939dcc, // 形成
+    0x57987002, // 司机
+    0x579e19ae, // 还是
+    0x57ad22ef, // 提问
+    0x57cfe3e1, // 财产
+    0x57cfec1d, // 贷款
+    0x57d1ac30, // 项链
+    0x57d9419d, // 缺乏
+    0x57e67afb, // 睡觉
+    0x58206d4d, // 真正
+    0x586a16bc, // 手笔
+    0x588125e3, // 西红柿
+    0x5886ae9d, // 绿
+    0x589a8c56, // 地点
+    0x589c6641, // 本科
+    0x58b32d21, // 光盘
+    0x58b83854, // 小区
+    0x58be6543, // 购
+    0x58c3dc4b, // 吵
+    0x58d3c7ce, // 恭喜
+    0x58fba38e, // 凉茶
+    0x59325107, // 使
+    0x59335537, // 同样
+    0x595bbcee, // 不如
+    0x595d8d2b, // 疼爱
+    0x599f7721, // 其他
+    0x59a33288, // 婚姻
+    0x59aa496f, // 怀疑
+    0x59bb9bee, // 著名
+    0x59bd7422, // 提前
+    0x59c09ad6, // 利益
+    0x59c1d86c, // 遇到
+    0x59dd81c4, // 双
+    0x59e27ebd, // 巧妙
+    0x5a019672, // 请求
+    0x5a1b2974, // 住
+    0x5a1e4c83, // 丑陋
+    0x5a23c84d, // 每
+    0x5a396298, // 美好
+    0x5a407d0f, // 主任
+    0x5a5e8522, // 狡猾
+    0x5ab9c7a4, // 发表
+    0x5af7a498, // 婚礼
+    0x5b26c4c1, // 生产
+    0x5b653fbd, // 因此
+    0x5b660083, // 节食
+    0x5b785749, // 忽然
+    0x5b838fe8, // 正在
+    0x5b885c0b, // 顾客
+    0x5b8e7fda, // 烦恼
+    0x5baf0d13, // 卡车
+    0x5bafe51d, // 治疗
+    0x5bcbadc8, // 专门
+    0x5bcfb1b3, // 平衡
+    0x5bcffb29, // 气温
+    0x5bfe3250, // 英俊
+    0x5c1a81e9, // 学习
+    0x5c2b5eae, // 东
+    0x5c6e8ef7, // 着火
+    0x5c8d0947, // 随便
+    0x5c920b82, // 连环画
+    0x5ca7651e, // 预习
+    0x5cc13155, // 守岁
+    0x5ceef4c7, // 各自
+    0x5d03493b, // 女士
+    0x5d09ed4f, // 个别
+    0x5d22c876, // 失眠
+    0x5d2f02f1, // 程度
+    0x5d387224, // 月亮
+    0x5d558a1d, // 镜子
+    0x5d87c65a, // 香肠
+    0x5d90e87b, // 支教
+    0x5d92f27f, // 包
+    0x5d97c25b, // 地图
+    0x5dacfb8f, // 开始
+    0x5dc18197, // 上班
+    0x5ddccdd1, // 递
+    0x5e24280c, // 晴
+    0x5e43b143, // 骄傲
+    0x5e6118f6, // 而且
+    0x5e6b2584, // 留守
+    0x5e97cacb, // 优美
+    0x5e9ca581, // 承认
+    0x5ea013e6, // 觉得
+    0x5ea15a29, // 粉
+    0x5eaef4f5, // 招待
+    0x5ee73a90, // 爷爷
+    0x5f341ea6, // 提供
+    0x5f7b0791, // 坚持
+    0x5f8048c9, // 时刻
+    0x5f833704, // 呼朋唤友
+    0x5fcb0b0a, // 地道
+    0x5fd8aebf, // 同时
+    0x5fe884dd, // 鼓掌
+    0x5ff6b075, // 分钟
+    0x5ffeff6e, // 催
+    0x600c2570, // 热闹
+    0x60139e5a, // 患难与共
+    0x60256d6a, // 窗帘
+    0x60303f49, // 国庆节
+    0x6031cecd, // 大卫
+    0x604518aa, // 梨
+    0x604cb283, // 父母
+    0x604f0a53, // 生气
+    0x6092c596, // 儿子
+    0x60b36475, // 日期
+    0x60c2371e, // 象棋
+    0x60d62130, // 世界
+    0x610b2421, // 便
+    0x6138df4f, // 自杀
+    0x61438c23, // 不
+    0x614e2b82, // 工厂
+    0x615a4971, // 组
+    0x617db6be, // 学
+    0x61823a53, // 幅
+    0x61af18be, // 差
+    0x61bd976a, // 概念
+    0x61d54f76, // 同情
+    0x61ef78e7, // 发展
+    0x621a8aac, // 照相机
+    0x621cc70d, // 机会
+    0x6228a09e, // 往往
+    0x622c63b3, // 派
+    0x622d874a, // 上网
+    0x623ccc4c, // 玩儿
+    0x625c31fd, // 脖子
+    0x627a283b, // 冷淡
+    0x6289e9ab, // 暖和
+    0x62bceccb, // 飘
+    0x62bf3c01, // 当时
+    0x62ca79a3, // 海鲜
+    0x62dc719a, // 首先
+    0x63003dac, // 微信
+    0x631d236d, // 似的
+    0x634d7be1, // 叔叔
+    0x6374d698, // 寓言
+    0x637ab9a4, // 经理
+    0x637f2cfb, // 配合
+    0x638644f7, // 消息
+    0x63a41cc3, // 关闭
+    0x63a65a71, // 日用品
+    0x63b10bd9, // 明星
+    0x6415e650, // 粗心
+    0x641d0b6f, // 位
+    0x642917ad, // 激动
+    0x64661596, // 要是
+    0x6485f247, // 敢
+    0x648aa474, // 宾馆
+    0x64d06d30, // 劳驾
+    0x64f71f3f, // 正
+    0x651f67de, // 单独
+    0x6520ab57, // 何况
+    0x65300cf6, // 抓紧
+    0x65345c6f, // 编辑
+    0x6534f3f0, // 做客
+    0x653aab77, // 呼吸
+    0x654578db, // 紧张
+    0x656ee7b6, // 可靠
+    0x658ac9b6, // 邮局
+    0x658f6c37, // 脱
+    0x65de056e, // 情景
+    0x660ca61b, // 夏
+    0x661e3416, // 规矩
+    0x663fd0c5, // 调查
+    0x6643803b, // 以前
+    0x6647898a, // 组成
+    0x665545c5, // 朋
+    0x665f85f7, // 特点
+    0x6666a03e, // 高档
+    0x667110d3, // 客人
+    0x6699011a, // 报社
+    0x66b49511, // 着急
+    0x66bad787, // 像
+    0x66c21f77, // 教材
+    0x66c69d60, // 神秘
+    0x66e1fd2c, // 联合
+    0x66feac8b, // 唱歌
+    0x67066f29, // 明
+    0x6717aaaa, // 干净
+    0x675201d7, // 周到
+    0x67570ff3, // 石灰岩
+    0x677ce378, // 企业
+    0x679b5e7d, // 多
+    0x67b4e1ec, // 在乎
+    0x67b6b880, // 饭店
+    0x67d00d89, // 导演
+    0x67e05dc8, // 拒绝
+    0x67e17401, // 到时候
+    0x67e4fad2, // 植物
+    0x67ee3bb7, // 约会
+    0x681cf4a8, // 黑板
+    0x685816e2, // 煤炭
+    0x685a2c24, // 保护
+    0x68651abe, // 天空
+    0x68710b92, // 朗读
+    0x689caecc, // 真实
+    0x68abff1b, // 讨价还价
+    0x68bdf273, // 荫凉
+    0x68d7659c, // 盼望
+    0x68f10cff, // 日历
+    0x68f26a65, // 参考
+    0x6925d6ae, // 袋子
+    0x693d99f8, // 醒
+    0x695ee6f9, // 创造
+    0x699df7ca, // 勤奋
+    0x69aa41d9, // 取消
+    0x69b2cbf1, // 豪华
+    0x69ba660e, // 岁
+    0x69d0bfb1, // 路
+    0x69d17bc9, // 关注
+    0x69db6ff0, // 签
+    0x69e6b6e7, // 满足
+    0x69eb9ed4, // 锁
+    0x69ebb62f, // 吃亏
+    0x6a4e9bb3, // 建立
+    0x6a655243, // 断断续续
+    0x6a6a1990, // 状态
+    0x6a7dca34, // 轮流
+    0x6a8d84b0, // 希望
+    0x6ae1031f, // 鼓励
+    0x6aea3342, // 数据
+    0x6aeb6e5a, // 录取
+    0x6b0af8e5, // 吸取
+    0x6b2e3dfd, // 说
+    0x6b3d0707, // 退
+    0x6b4f4919, // 文明
+    0x6ba3ca02, // 冰激凌
+    0x6ba908ab, // 网球
+    0x6bc0cf34, // 指挥
+    0x6c1d981c, // 提倡
+    0x6c3850b7, // 商店
+    0x6c4db621, // 民族
+    0x6c4f67e1, // 记录
+    0x6c5d29e2, // 举
+    0x6c5f142f, // 争取
+    0x6c706ba3, // 别人
+    0x6c9e8e0d, // 制冷
+    0x6c9efb68, // 艰巨
+    0x6cc72640, // 妈妈
+    0x6cd09bfa, // 任务
+    0x6cef54ea, // 生命
+    0x6d279d94, // 大方
+    0x6d316281, // 多亏
+    0x6d3dc948, // 怀孕
+    0x6d4013d0, // 启发
+    0x6d6d289a, // 行
+    0x6d70d015, // 道歉
+    0x6d869559, // 单调
+    0x6d88f742, // 却
+    0x6d890911, // 电梯
+    0x6d9972e0, // 邻居
+    0x6dc70b08, // 林
+    0x6defdf83, // 起居
+    0x6e071f5a, // 如何
+    0x6e1de64d, // 尽力
+    0x6e32433c, // 烤鸭
+    0x6e35951d, // 医生
+    0x6e3890b9, // 刘辰
+    0x6e50e22e, // 按照
+    0x6e581ba4, // 构成
+    0x6e92f270, // 保
+    0x6e9e1355, // 国籍
+    0x6eb8eb78, // 到
+    0x6ed26ff9, // 病毒
+    0x6efaca89, // 堆
+    0x6efed768, // 护国寺
+    0x6f0d4ed7, // 公布
+    0x6f2bf989, // 馒头
+    0x6f3d9ca7, // 实在
+    0x6f3f1c4d, // 场
+    0x6f5bc194, // 糊
+    0x6f66f1c7, // 香
+    0x6fc14768, // 心理
+    0x6fc765c9, // 家乡
+    0x6fdec646, // 形势
+    0x6fe7fb8f, // 剩
+    0x6ff220c8, // 舅舅
+    0x7005e712, // 到处
+    0x70300904, // 目录
+    0x704536be, // 心情
+    0x7066cfe7, // 北京师范大学
+    0x706c20cb, // 握手
+    0x707833ba, // 幸福
+    0x707e44d6, // 光临
+    0x708e0b4f, // 娶
+    0x709c1762, // 地方
+    0x70cbafa6, // 母亲
+    0x70cd3233, // 恐怕
+    0x70d10355, // 刚才
+    0x70df2db8, // 英雄
+    0x70e88067, // 妻子
+    0x710c2176, // 照
+    0x711fcc9d, // 绝对
+    0x7131f873, // 通过
+    0x7146f257, // 名胜古迹
+    0x7161043f, // 废话
+    0x718a21ec, // 不行
+    0x71a47e9c, // 天安门东
+    0x71bd5378, // 到底
+    0x71bdc110, // 腾讯
+    0x71c176b5, // 女儿
+    0x71cb9dd6, // 漂亮
+    0x71e37ed6, // 乘坐
+    0x71fedd40, // 物理
+    0x72078d82, // 预报
+    0x720c1149, // 外地
+    0x720f42c3, // 孝顺
+    0x72133524, // 纪律
+    0x7219143e, // 假如
+    0x72254f24, // 懒
+    0x72353966, // 散步
+    0x7281c7db, // 本来
+    0x72931c99, // 春秋
+    0x7294336d, // 发言
+    0x72ca4915, // 印象
+    0x72d39797, // 消费
+    0x73086db3, // 大约
+    0x730a0b63, // 书
+    0x73359c84, // 弟弟
+    0x733c5800, // 休闲
+    0x733d6ca8, // 时尚
+    0x7356476d, // 无所谓
+    0x7371d36e, // 修理
+    0x738a3601, // 一起
+    0x739f97a7, // 展览
+    0x73a64b0d, // 方便
+    0x73bbe0f0, // 建筑
+    0x73f9ac41, // 太
+    0x73fe0961, // 规定
+    0x73fe72f4, // 赵括
+    0x744c883e, // 姥姥
+    0x747b790a, // 外
+    0x74cfe26c, // 地区
+    0x74ef2b74, // 吨
+    0x7505199b, // 味道
+    0x750a4a7f, // 痛快
+    0x75180b62, // 根治
+    0x753f967e, // 健步如飞
+    0x7546af04, // 专注
+    0x7557a4f7, // 王子
+    0x755f0e7d, // 温柔
+    0x75717883, // 课程
+    0x757d2539, // 水果
+    0x758b5068, // 表达
+    0x7593b6e3, // 爬山
+    0x759a89f3, // 再
+    0x75ba6559, // 方式
+    0x75d68f04, // 疼
+    0x75f1ef01, // 抢
+    0x76144742, // 认为
+    0x763d92e6, // 发明
+    0x7640d2a3, // 广东省
+    0x764c43d1, // 栏杆
+    0x7682800e, // 留学
+    0x768a0032, // 清淡
+    0x76f5e0f5, // 成为
+    0x7736ae38, // 有限
+    0x77511eac, // 一般来说
+    0x776f3dc9, // 官
+    0x77735683, // 活跃
+    0x778d04b3, // 其中
+    0x77953363, // 龙
+    0x77991035, // 带
+    0x77a6591d, // 起飞
+    0x77bb05d7, // 捐
+    0x77c861c6, // 发挥
+    0x77c9afbb, // 除非
+    0x77ccced7, // 李广
+    0x77dc0ae2, // 块
+    0x77e40978, // 整理
+    0x7804bed8, // 生物钟
+    0x780c34e6, // 位于
+    0x780caa3a, // 新
+    0x7819198c, // 淘气
+    0x7835a521, // 好像
+    0x78414412, // 日常
+    0x785182fa, // 得到
+    0x785a8afc, // 小说
+    0x785ed2de, // 滚
+    0x78974ccc, // 广大
+    0x78b4be2e, // 孔子
+    0x78b50e2c, // 仿佛
+    0x78f14ad1, // 美
+    0x78f4e731, // 连
+    0x78f60cd2, // 资源
+    0x790125ee, // 对话
+    0x790a933a, // 舒适
+    0x791f25b5, // 签证
+    0x79482596, // 昆虫
+    0x794b1682, // 结论
+    0x794b6c6e, // 尝
+    0x795e36e1, // 问题
+    0x797b1355, // 接待
+    0x79999511, // 正确
+    0x79c8b91d, // 实习
+    0x79cca5b9, // 一下
+    0x79d553cf, // 收
+    0x79edccec, // 面试
+    0x7a31bf83, // 绳子
+    0x7a40e032, // 计算
+    0x7a4f7427, // 摆
+    0x7a5371b6, // 夹子
+    0x7a5e0911, // 胆小鬼
+    0x7a6a6ca0, // 准时
+    0x7a7aaca3, // 也
+    0x7a7b5aef, // 吻
+    0x7aa84888, // 皮肤
+    0x7abde1e8, // 伟大
+    0x7ae53bc4, // 陆地
+    0x7aff3da7, // 缩短
+    0x7b3523d0, // 惭愧
+    0x7b453eb2, // 期待
+    0x7b567c57, // 朵
+    0x7b661b2f, // 显然
+    0x7b79c396, // 报纸
+    0x7b8ac877, // 诚实
+    0x7be22361, // 整个
+    0x7becf7a6, // 网站
+    0x7c0e003b, // 设施
+    0x7c0fc0f1, // 应付
+    0x7c43c9be, // 通知
+    0x7c5d79f8, // 原则
+    0x7c607f78, // 四合院
+    0x7c749cbc, // 青少年
+    0x7c82e5f2, // 善良
+    0x7c9633db, // 消失
+    0x7c9945de, // 有意思
+    0x7c9a5563, // 元
+    0x7cf35872, // 比
+    0x7cf668d7, // 五
+    0x7d1fbd2f, // 六一儿童节
+    0x7d2b8ae6, // 纳入
+    0x7d430fee, // 词典
+    0x7d4e7639, // 限制
+    0x7d51d74a, // 采用
+    0x7da3de06, // 便宜
+    0x7db1c86c, // 空气
+    0x7dcdd505, // 座
+    0x7e0a1ef0, // 冒
+    0x7e204a04, // 保证
+    0x7e2e2b4e, // 帮助
+    0x7e467391, // 巨大
+    0x7e49ed76, // 严重
+    0x7e5517e7, // 感激
+    0x7e785929, // 摇
+    0x7e976ba6, // 本
+    0x7e9fc0c0, // 后
+    0x7eaf5900, // 字幕
+    0x7ec5de5d, // 毕业
+    0x7ed9be0c, // 付款
+    0x7efb4f34, // 旺盛
+    0x7f3afb34, // 票
+    0x7f3d0e30, // 达到
+    0x7f68fa58, // 销售
+    0x7f8d7748, // 动作
+    0x7fa5be82, // 救
+    0x7faa673e, // 组合
+    0x7fba98a4, // 杨雄
+    0x7fbea365, // 决心
+    0x7fc19962, // 问候
+    0x7fcd6cd5, // 以来
+    0x7fd60d5f, // 必然
+    0x7fda49c7, // 不少
+    0x7fe4d848, // 最近
+    0x7fe84d12, // 参加
+    0x7fea8452, // 义务
+    0x7fefcef6, // 国
+    0x7ff247e1, // 认
+    0x8002088b, // 诊断
+    0x8006d535, // 总结
+    0x8007f453, // 梦想
+    0x800c14c8, // 摄影
+    0x800c6a51, // 歇
+    0x80298fed, // 苹果
+    0x803b4b9c, // 减肥
+    0x8051a6ec, // 手工
+    0x80567927, // 骨头
+    0x8057eacc, // 吗
+    0x8060dbc2, // 举办
+    0x8081efa4, // 不断
+    0x80a50924, // 咱
+    0x80b86bb4, // 过程
+    0x80ba5e4f, // 担心
+    0x80e70b66, // 帽子
+    0x80e9645d, // 工资
+    0x810180f0, // 满
+    0x8106bbe2, // 小气
+    0x81704187, // 秋
+    0x81bd4fef, // 国贸
+    0x81c17a7b, // 眼镜
+    0x81c5bafb, // 无奈
+    0x81d62067, // 将来
+    0x81e3bb95, // 辅导
+    0x81e7278e, // 香蕉
+    0x81f3a433, // 条件
+    0x8207d07d, // 天
+    0x8240a884, // 文文
+    0x825f18b4, // 自信
+    0x827306c6, // 选择
+    0x82cca639, // 题
+    0x82d379f0, // 飞机
+    0x82d5aaaf, // 完美
+    0x82eb6a6e, // 超市
+    0x82ecefeb, // 挑战
+    0x82f7a9e5, // 只好
+    0x8303d2ea, // 价钱
+    0x830a9b50, // 楚国
+    0x831bcd79, // 被
+    0x832f2af4, // 铃
+    0x83577940, // 放弃
+    0x838a1cb1, // 爸爸
+    0x838abf2c, // 航班
+    0x838b781b, // 笔试
+    0x838d2d9d, // 措施
+    0x83946090, // 钢铁
+    0x83a2e3e8, // 信封
+    0x83a5c088, // 马克
+    0x83a895de, // 菜单
+    0x83b58a41, // 放松
+    0x83c87cc8, // 怎么
+    0x83d7dc38, // 亲自
+    0x83eeac81, // 习惯
+    0x83ff390e, // 滴
+    0x8432fb91, // 战国
+    0x843a75f3, // 制度
+    0x846b1ace, // 有点儿
+    0x849136b0, // 脏
+    0x84a48fff, // 拥抱
+    0x84ac4a29, // 会议
+    0x84fd3aa7, // 夸
+    0x85057ea7, // 意见
+    0x85094c04, // 段
+    0x8528a005, // 光明
+    0x8545141e, // 偶然
+    0x8554fc10, // 例如
+    0x856920ad, // 操心
+    0x857880fd, // 先生
+    0x858328c2, // 怪不得
+    0x85906633, // 节
+    0x859cd21d, // 出现
+    0x85bb89ae, // 塑料袋
+    0x85bbb119, // 情侣
+    0x85d85c2e, // 写作
+    0x85dd24a0, // 装修
+    0x85e4893c, // 则
+    0x860032ff, // 空闲
+    0x860f5589, // 三
+    0x8628b0ed, // 至今
+    0x8633f4bc, // 效果
+    0x86372c42, // 回忆
+    0x863cdcb3, // 丰富
+    0x863fb62e, // 蒙眬
+    0x86409e1c, // 联系
+    0x864f57cf, // 叫
+    0x8650380b, // 擀
+    0x8656f86d, // 反复
+    0x8679a8be, // 法律
+    0x86a3c341, // 低落
+    0x86ae9192, // 专心
+    0x86be8c7f, // 大厦
+    0x86e0324a, // 所谓
+    0x86e0b3dd, // 火柴
+    0x86e87879, // 勺
+    0x8702fde5, // 卧室
+    0x8725efec, // 沙滩
+    0x875ef278, // 组织
+    0x8779f70b, // 熟练
+    0x879c64f1, // 意义
+    0x87a20eb5, // 脚
+    0x87b5621c, // 老鹰
+    0x87c8d8f6, // 关于
+    0x87e313ca, // 赶紧
+    0x87ffe261, // 才艺
+    0x880a9458, // 交流
+    0x882bf4e6, // 念
+    0x88433848, // 妇女
+    0x886bc603, // 少
+    0x88739345, // 戏剧
+    0x887e2678, // 还
+    0x88a1d2fa, // 慌张
+    0x88a63206, // 出
+    0x88c1518d, // 要
+    0x88d90310, // 宝贵
+    0x88facedf, // 赏心悦目
+    0x890fa6b7, // 王
+    0x8931b47b, // 告别
+    0x893b36cc, // 打
+    0x896dbd8d, // 无论
+    0x896f3f9d, // 印刷
+    0x897ab9f2, // 麦布里奇
+    0x8990fa66, // 现在
+    0x899958cc, // 那
+    0x899ef1b3, // 感动
+    0x89a0bff8, // 撞
+    0x89d41225, // 菜市口
+    0x89d69d2a, // 阅读
+    0x89e653c1, // 提纲
+    0x8a1bb2f1, // 页
+    0x8a2a5483, // 抽
+    0x8a3531ef, // 小时
+    0x8a5087d6, // 体验
+    0x8a68b5d8, // 优势
+    0x8a6e4829, // 青春
+    0x8a8febc1, // 想
+    0x8a96fbdc, // 商业
+    0x8abb4c79, // 基本
+    0x8ae43804, // 饺子
+    0x8b0ddd95, // 法院
+    0x8b1e8381, // 划
+    0x8b2f423d, // 时代
+    0x8b4688e9, // 乐器
+    0x8b5b265b, // 红
+    0x8b92356f, // 明白
+    0x8b9679b4, // 爱心
+    0x8ba83066, // 屋
+    0x8bbb95df, // 词汇
+    0x8bc70945, // 体现
+    0x8bcaf7bb, // 紧急
+    0x8bd30d16, // 总是
+    0x8bf3b250, // 油炸
+    0x8bfc3a69, // 开放
+    0x8bfe018a, // 闻
+    0x8c0c5bf6, // 微博
+    0x8c0d8bd1, // 符合
+    0x8c1bf1e5, // 剪刀
+    0x8c8b1a3f, // 电脑
+    0x8cdf9c7d, // 平等
+    0x8cfe0df6, // 方案
+    0x8d0d1dbf, // 麦克风
+    0x8d402d0e, // 因素
+    0x8da76bbe, // 突出
+    0x8dc7642b, // 结账
+    0x8dcdf8d4, // 的确
+    0x8dee6e0a, // 烫
+    0x8df6719d, // 的
+    0x8e11a81e, // 算
+    0x8e15a195, // 核心
+    0x8e459cc1, // 夏威夷
+    0x8e4f81fa, // 成人
+    0x8e558c67, // 抄
+    0x8eac9a23, // 具体
+    0x8ede2e97, // 吃饭
+    0x8ef58c2e, // 可怜
+    0x8efa2eff, // 土地
+    0x8efcc979, // 服装
+    0x8efecf35, // 相当
+    0x8f1c30a7, // 批
+    0x8f24783e, // 没
+    0x8f3b09cf, // 原来
+    0x8f46444b, // 电子邮件
+    0x8f5a8c79, // 碰
+    0x8f75fb94, // 和
+    0x8f8ae6b6, // 亮
+    0x8f9e297b, // 射击
+    0x8fba7d6f, // 夜
+    0x8ff136a6, // 打印
+    0x90387dc6, // 自行车
+    0x904cd431, // 征求
+    0x906b6e85, // 讨论
+    0x906f8894, // 规则
+    0x90a5f674, // 水
+    0x90aec4d3, // 到达
+    0x90c064ce, // 弹钢琴
+    0x90d2c92b, // 主持
+    0x90f64d03, // 记忆
+    0x90fd7cba, // 初中
+    0x90fee909, // 交往
+    0x90ff8489, // 密码
+    0x910047c5, // 民居
+    0x9118300e, // 父亲
+    0x9123c25f, // 准确
+    0x913fab7d, // 适当
+    0x91500d31, // 换
+    0x9152dfaa, // 痰
+    0x91717674, // 汗
+    0x9192cace, // 苦
+    0x91cb0f53, // 减
+    0x91d6fa32, // 分析
+    0x91e5780a, // 系统
+    0x91efea44, // 傻
+    0x92152468, // 遵守
+    0x9229ba94, // 等待
+    0x92405116, // 钱包
+    0x9259ff8d, // 沙漠
+    0x92648223, // 练习
+    0x92743292, // 七郎
+    0x9274b03d, // 重复
+    0x928aa45b, // 轻易
+    0x92a553dc, // 贵
+    0x92bb61bc, // 明天
+    0x92cb41b8, // 省
+    0x92dadbd4, // 特征
+    0x92e3d2bf, // 关心
+    0x92f29ad7, // 蔬菜
+    0x93067841, // 本领
+    0x932aebf6, // 出示
+    0x932e1a9d, // 出差
+    0x9338dfbc, // 有效
+    0x934c6c13, // 私
+    0x935d538b, // 暴雨
+    0x936883ef, // 烂
+    0x93b0990d, // 暂时
+    0x93b0cdb2, // 笑笑
+    0x93c1e482, // 性格
+    0x93d59cf5, // 校长
+    0x93eb0de0, // 依然
+    0x93f63f92, // 房间
+    0x93f9ef53, // 头发
+    0x93ff31f7, // 辛苦
+    0x94141f17, // 耽误
+    0x9421bfff, // 某
+    0x9429aafc, // 保持
+    0x94332946, // 上海
+    0x9449ec1c, // 汇率
+    0x946cdf8d, // 胖
+    0x9498b1c7, // 巧克力
+    0x94bcec35, // 妹妹
+    0x94ee39bb, // 你们
+    0x94efd1c1, // 吹
+    0x94f28812, // 冻
+    0x94ff624e, // 人生
+    0x950403c1, // 有名
+    0x9507f68b, // 我们
+    0x950b8d34, // 存活
+    0x9512305b, // 刷牙
+    0x9512d7d8, // 键盘
+    0x9523ef32, // 奖金
+    0x9546d48d, // 乖
+    0x9566f020, // 宠物
+    0x95a59592, // 季节
+    0x95ab8942, // 离
+    0x95e594c1, // 转变
+    0x95f9f708, // 追求
+    0x960249af, // 静
+    0x9618ef26, // 座位
+    0x96216b0b, // 合同
+    0x96354e21, // 可乐
+    0x9665009c, // 祝
+    0x9667376f, // 犯
+    0x968cf44c, // 打工
+    0x969a38e6, // 无聊
+    0x96bf5915, // 裤子
+    0x96ccadcc, // 究竟
+    0x96f3892a, // 认识
+    0x96ff7625, // 高兴
+    0x97027391, // 华裔
+    0x9706bb16, // 躲藏
+    0x9720205a, // 繁荣
+    0x9727d693, // 程序
+    0x9728ea1f, // 幽默
+    0x973ff2e9, // 看法
+    0x974ade06, // 机器
+    0x9754a5f8, // 周末
+    0x9758332e, // 乱
+    0x975d0f74, // 长途
+    0x976a40cb, // 猴子
+    0x976d1377, // 优惠
+    0x97aba979, // 后来
+    0x97b6d1ff, // 尊敬
+    0x97bafb3c, // 纸上谈兵
+    0x97c5ca52, // 好吃
+    0x97d4c52c, // 喂养
+    0x98264074, // 肌肉
+    0x98280915, // 诗
+    0x98342cf8, // 燃烧
+    0x98385919, // 分手
+    0x983ca745, // 逐渐
+    0x983dc081, // 项目
+    0x984d695c, // 事先
+    0x9865d243, // 棵
+    0x987acd2f, // 个人
+    0x9881232b, // 熬夜
+    0x98968421, // 啦
+    0x98b7bf73, // 投入
+    0x98d92a26, // 要不
+    0x98d9761e, // 明确
+    0x98ddcccd, // 逃
+    0x98de9a63, // 强烈
+    0x98e887f2, // 好客
+    0x98e9ef24, // 省略
+    0x993ba511, // 保存
+    0x9955ecfc, // 涂鸦
+    0x995b5033, // 完
+    0x995f2b2a, // 女孩儿
+    0x995ffcf2, // 敏感
+    0x99754e33, // 唯一
+    0x998064e1, // 棍
+    0x9983adfb, // 梦
+    0x9986f511, // 办理
+    0x998b29c0, // 地震
+    0x999f2371, // 平常
+    0x99d83403, // 风俗
+    0x99ebffc1, // 决赛
+    0x99ec2087, // 号
+    0x99fade09, // 错误
+    0x9a18fcb9, // 面对
+    0x9a31c222, // 草
+    0x9a39103b, // 成长
+    0x9a3ff96d, // 粗糙
+    0x9a4fd2f4, // 恢复
+    0x9a52b302, // 喜欢
+    0x9a7751de, // 外交
+    0x9a88cef9, // 过去
+    0x9a9d8c24, // 笔记
+    0x9a9f095b, // 完善
+    0x9aa83d52, // 悄悄
+    0x9ab6df92, // 师傅
+    0x9ae7ddab, // 应聘
+    0x9af8b29d, // 合理
+    0x9b056c1d, // 实用
+    0x9b15fb74, // 对待
+    0x9b44967f, // 句
+    0x9b5199bc, // 电台
+    0x9b569fe2, // 灯
+    0x9b6d3823, // 零钱
+    0x9b9585d3, // 公里
+    0x9bcf6667, // 朋友
+    0x9bd1c1f6, // 秩序
+    0x9bda7f55, // 请
+    0x9beca9cf, // 业余
+    0x9becaef8, // 玉米
+    0x9bf5b4e5, // 兄弟
+    0x9bf6fadd, // 摔倒
+    0x9c23751e, // 经验
+    0x9c45864a, // 表扬
+    0x9c6d8c7f, // 又
+    0x9c7a22a5, // 证据
+    0x9c81f3e8, // 作品
+    0x9c839601, // 然后
+    0x9c9ba5e4, // 理由
+    0x9ca00f00, // 完全
+    0x9ccc12bd, // 锯
+    0x9cd92e6d, // 道具
+    0x9cef186f, // 都
+    0x9d13eec8, // 哲学
+    0x9d21ed4b, // 合影
+    0x9d25b37d, // 活泼
+    0x9d53c5b6, // 技术
+    0x9d58cc87, // 广告
+    0x9d686f7b, // 只有
+    0x9d6daecd, // 促使
+    0x9d745600, // 没关系
+    0x9d8e7016, // 蝴蝶
+    0x9d97b5b0, // 疯狂
+    0x9daf2c59, // 自己
+    0x9db469a1, // 紫
+    0x9dd8e9d7, // 钟
+    0x9ddca0a6, // 经济
+    0x9e0724b2, // 纸
+    0x9e51afae, // 抓
+    0x9e541fd6, // 变化
+    0x9e885922, // 税
+    0x9e8dbce0, // 长城
+    0x9e93e8d8, // 真
+    0x9e94ace1, // 醋
+    0x9e98214b, // 甜
+    0x9e9c819f, // 大使馆
+    0x9ebea91b, // 找
+    0x9ec9939b, // 狂
+    0x9ee5d8c2, // 水平
+    0x9ef815a0, // 允许
+    0x9f0d78f4, // 地
+    0x9f223421, // 浅
+    0x9f34fd2d, // 耐心
+    0x9f399eff, // 相同
+    0x9f872a94, // 公元
+    0x9f8cb145, // 精诚所至
+    0x9f95021b, // 跳舞
+    0x9fa4174b, // 顺利
+    0x9faee3be, // 正好
+    0x9fbace3d, // 能力
+    0x9fc135ab, // 摄影师
+    0x9fc2e91f, // 垮
+    0x9fc35376, // 机制
+    0x9ff14d0f, // 证件
+    0x9fff6504, // 走
+    0xa011d6d0, // 律师
+    0xa02f30fe, // 生长
+    0xa058367f, // 衣服
+    0xa06176a0, // 错
+    0xa063e16b, // 收拾
+    0xa06f09e8, // 人口
+    0xa090dbc8, // 文字
+    0xa0999ff6, // 浓
+    0xa0de1be2, // 通常
+    0xa0dfe797, // 部分
+    0xa0ee428c, // 解决
+    0xa0f0c8e7, // 尊重
+    0xa0f4f31b, // 无法
+    0xa10de192, // 踢足球
+    0xa1237c25, // 竞争
+    0xa1364d3d, // 复印
+    0xa14cf4da, // 送
+    0xa163905f, // 包括
+    0xa1780d79, // 关键
+    0xa194ca21, // 果实
+    0xa195bfb4, // 根基
+    0xa1b62e9a, // 使劲
+    0xa1bfda50, // 恋爱
+    0xa1c2e3c2, // 中国
+    0xa1d922c8, // 响
+    0xa1e62060, // 可爱
+    0xa1f6e732, // 波动
+    0xa20251ea, // 目标
+    0xa222ff62, // 传说
+    0xa2415d61, // 立刻
+    0xa24bdd1f, // 阳光
+    0xa24d5f8b, // 后果
+    0xa276d02d, // 学期
+    0xa28896c3, // 应该
+    0xa2bfed0e, // 件
+    0xa2c6967c, // 大夫
+    0xa2f68d65, // 发达
+    0xa304e1e5, // 促进
+    0xa3392299, // 顺便
+    0xa38657c6, // 厘米
+    0xa3ac5a61, // 站
+    0xa3be1e1e, // 风险
+    0xa41fc3d3, // 面临
+    0xa430f5c9, // 距离
+    0xa44137a3, // 表情
+    0xa44f1ee4, // 讲究
+    0xa460243e, // 终于
+    0xa47137d8, // 雾
+    0xa4801b49, // 门
+    0xa48e2d59, // 干杯
+    0xa49065d4, // 爱护
+    0xa49295d8, // 光
+    0xa4b571d7, // 学历
+    0xa4bbb3d5, // 入口
+    0xa4e12e3c, // 胸
+    0xa4f77e7d, // 利润
+    0xa51b06e5, // 杀
+    0xa52fe62f, // 按时
+    0xa5507d74, // 光线
+    0xa5543d88, // 严格
+    0xa574df48, // 政治
+    0xa57d787d, // 附近
+    0xa5aecaad, // 字
+    0xa5c14d6e, // 当然
+    0xa5c6b8c0, // 抱歉
+    0xa5ca0ee9, // 桔子
+    0xa60c3618, // 任何
+    0xa6382ee2, // 铅笔
+    0xa64fac51, // 度
+    0xa67c06a6, // 另外
+    0xa68b8a97, // 屋子
+    0xa6a2b2e6, // 缺点
+    0xa6b62deb, // 宿舍
+    0xa6d8f877, // 由
+    0xa6de1af4, // 犹豫
+    0xa6ed8087, // 一会儿
+    0xa700d03b, // 自从
+    0xa704dab4, // 幸亏
+    0xa715ab10, // 实话
+    0xa71c7baa, // 可
+    0xa72d979e, // 硬
+    0xa73ed15c, // 国家
+    0xa743e77d, // 现代
+    0xa746432e, // 平时
+    0xa751776f, // 团
+    0xa75d80b3, // 蜜蜂
+    0xa79de5a6, // 现象
+    0xa7b8b196, // 多么
+    0xa7c1e47f, // 如果
+    0xa7ed3c91, // 亲切
+    0xa8189032, // 灰尘
+    0xa8195d3b, // 雇
+    0xa84debae, // 清楚
+    0xa86a8bc8, // 合作
+    0xa86b9dbd, // 主张
+    0xa86e1bf7, // 海
+    0xa8741a0d, // 瞎
+    0xa8799a75, // 合法
+    0xa87f7660, // 赞美
+    0xa8bd0f85, // 干
+    0xa8c338f4, // 信息
+    0xa8de23b2, // 优点
+    0xa8eeeaf4, // 办法
+    0xa90e87c6, // 情趣
+    0xa929934e, // 红酒
+    0xa92dc29e, // 造成
+    0xa92ff1b4, // 宝贝
+    0xa95453ac, // 左右
+    0xa97c5409, // 挤
+    0xa97eebd1, // 公司
+    0xa995880e, // 超过
+    0xa9c25658, // 忘记
+    0xa9da20af, // 回答
+    0xa9eb7a2d, // 会
+    0xaa03f677, // 守
+    0xaa0ba897, // 或许
+    0xaa2a06dd, // 光滑
+    0xaa2cdc3e, // 气候
+    0xaa4055e8, // 必要
+    0xaa681543, // 锻炼
+    0xaa6882d3, // 警察
+    0xaa8724ac, // 炒
+    0xaa9bba19, // 反正
+    0xaabc7ef0, // 为了
+    0xaad3a5a8, // 丈夫
+    0xaad481e4, // 支
+    0xaae5907c, // 价格
+    0xaaecea33, // 总算
+    0xaaf2b449, // 怪
+    0xaaf3cd9e, // 另
+    0xab0851aa, // 丢
+    0xab22788e, // 祝福
+    0xab2a68e5, // 认真
+    0xab4e3b97, // 火成岩
+    0xab6a3411, // 其次
+    0xab8b9fea, // 可惜
+    0xabdf38fc, // 感觉
+    0xabfa2c41, // 于
+    0xabff875d, // 重视
+    0xabff9ac3, // 面积
+    0xac30f2e6, // 误会
+    0xac434aca, // 今天
+    0xac503ce1, // 比赛
+    0xac674c6f, // 问
+    0xac76aa52, // 精神
+    0xacb6dabd, // 经典
+    0xacd40559, // 抽烟
+    0xacd4e942, // 李
+    0xace5101d, // 负责
+    0xad09f498, // 一切
+    0xad295aa0, // 作为
+    0xad2d9eb6, // 制订
+    0xad3fa46e, // 茶
+    0xad490cfc, // 运用
+    0xad73ad9b, // 借
+    0xad76e7c9, // 自愿
+    0xada5b9c5, // 车
+    0xadab333b, // 商务
+    0xadac7f77, // 计划
+    0xadc5bd9e, // 手机
+    0xadd74d42, // 粘贴
+    0xade3c234, // 爱好
+    0xadef85aa, // 教
+    0xae120dbf, // 给
+    0xae2bbba6, // 安慰
+    0xae2dd183, // 再三
+    0xae487664, // 值得
+    0xae6027c5, // 深刻
+    0xaea8db40, // 去年
+    0xaeb10b8d, // 悲伤
+    0xaebc2887, // 夕
+    0xaed67ec1, // 商品
+    0xaef3d69a, // 之
+    0xaef7b09e, // 直接
+    0xaf19da61, // 进
+    0xaf286444, // 注册
+    0xaf3379fa, // 称霸
+    0xaf4e1374, // 留
+    0xaf7f2a6f, // 上
+    0xaf91d745, // 翻
+    0xafa96c45, // 称赞
+    0xafabe1b3, // 动画片
+    0xafb75f0c, // 东西
+    0xafbfc6e8, // 对不起
+    0xafe35978, // 得
+    0xafea18de, // 嫩
+    0xafef167f, // 骗
+    0xafffe181, // 地铁
+    0xb0235d7a, // 营养
+    0xb0355652, // 幻想
+    0xb0418cce, // 政府
+    0xb048dd78, // 银子
+    0xb0506453, // 改变
+    0xb06c44f1, // 安全
+    0xb07be839, // 知识
+    0xb07e2f25, // 处理
+    0xb07fd20a, // 过分
+    0xb0a365b8, // 情况
+    0xb0a4bcd3, // 浪漫
+    0xb0afc0ea, // 小雨
+    0xb0c5572e, // 节约
+    0xb0ce1380, // 身材
+    0xb0ef8d49, // 云
+    0xb108ce3f, // 系
+    0xb10abf88, // 怎么样
+    0xb1223c38, // 斯坦福
+    0xb1294a67, // 亿
+    0xb1579140, // 浇
+    0xb17cbfe1, // 嘉宾
+    0xb190fe09, // 控制
+    0xb1a7c004, // 题目
+    0xb1b5b418, // 所有
+    0xb1c06539, // 然而
+    0xb1ce9a25, // 虚心
+    0xb1daecda, // 睁
+    0xb1e32115, // 提醒
+    0xb1f7078e, // 显得
+    0xb21cb1a7, // 工人
+    0xb2574751, // 综合
+    0xb260d493, // 吐
+    0xb2638489, // 评委
+    0xb264e374, // 插
+    0xb26d767f, // 奶奶
+    0xb27b1ec9, // 二
+    0xb28bbf37, // 笨
+    0xb29102be, // 办事
+    0xb2b1db3f, // 乙
+    0xb2be4afb, // 平
+    0xb2ca3354, // 鞭炮
+    0xb2d1a917, // 介绍
+    0xb2ea0042, // 冒险
+    0xb300c5cd, // 哲学家
+    0xb304444c, // 营业
+    0xb3057efb, // 失去
+    0xb309c47b, // 灰心
+    0xb35f67df, // 血
+    0xb362fb73, // 眉毛
+    0xb36a470f, // 卫生间
+    0xb3783fc9, // 此外
+    0xb37aed0a, // 粉色
+    0xb385c468, // 频道
+    0xb3937d22, // 万
+    0xb39f64ca, // 沙丁鱼
+    0xb3a9c69a, // 区别
+    0xb3ae17d1, // 即
+    0xb3c489e9, // 盒子
+    0xb3c9225e, // 鸟
+    0xb3ceeab2, // 蓝
+    0xb3d5d4ef, // 潮湿
+    0xb3d6ae8e, // 单位
+    0xb3e1f117, // 哎
+    0xb3eaffee, // 主人
+    0xb3eb4899, // 山
+    0xb40a533f, // 非常
+    0xb4142b7c, // 周围
+    0xb43ba23c, // 救护车
+    0xb43fbff3, // 发愁
+    0xb46a3969, // 镇
+    0xb4920a45, // 千
+    0xb4d0ae63, // 家
+    0xb4ddf7ea, // 请假
+    0xb4dfb2d7, // 包子
+    0xb4e8ce67, // 相处
+    0xb53e6b60, // 戒
+    0xb55b14c4, // 咸
+    0xb55f96b0, // 寄
+    0xb56fdf9a, // 环保
+    0xb575d9df, // 游戏
+    0xb5859fdf, // 语法
+    0xb5acac4f, // 称呼
+    0xb5c6d0b5, // 戒指
+    0xb5c82bb6, // 差不多
+    0xb5ed9ef2, // 一边
+    0xb5f14301, // 册
+    0xb5f1a68e, // 邀请
+    0xb604afba, // 特色
+    0xb611a43a, // 相反
+    0xb6221560, // 新鲜感
+    0xb625e990, // 各
+    0xb62be1e3, // 欢迎
+    0xb6750863, // 大象
+    0xb675e937, // 顶
+    0xb6a641d8, // 除夕
+    0xb6b3f692, // 把握
+    0xb6be44ca, // 及时
+    0xb6c8da39, // 森林
+    0xb6cc7c28, // 欧盟
+    0xb6d95ce0, // 劝
+    0xb73012e6, // 表示
+    0xb74ea0c2, // 研究
+    0xb75cc2a2, // 以为
+    0xb7620de4, // 简单
+    0xb763946d, // 最初
+    0xb76a7147, // 傍晚
+    0xb77714ff, // 生活
+    0xb77a19d4, // 科学
+    0xb7e74916, // 样子
+    0xb8080f6d, // 需要
+    0xb8095344, // 体会
+    0xb83b31c3, // 雨
+    0xb852c121, // 结实
+    0xb85accef, // 古代
+    0xb864f217, // 苗条
+    0xb8692ec6, // 减少
+    0xb86dd1e9, // 乐观
+    0xb8795a82, // 打听
+    0xb889acfe, // 减轻
+    0xb890a283, // 那儿
+    0xb8a9c7ad, // 除了
+    0xb8cbe316, // 人
+    0xb8fe8906, // 家具
+    0xb901d838, // 一般
+    0xb90afcbf, // 急诊
+    0xb9376fb0, // 智慧
+    0xb95a8e25, // 善于
+    0xb974388e, // 喊
+    0xb976aeff, // 旧
+    0xb978c61c, // 四
+    0xb98fd81e, // 书架
+    0xb99fcde3, // 叶子
+    0xb9ba3179, // 成功
+    0xb9f51ad2, // 简直
+    0xba066138, // 连续
+    0xba072774, // 辣椒
+    0xba1485e0, // 不要紧
+    0xba584402, // 新闻
+    0xba5bf753, // 杯子
+    0xba6da06a, // 运动
+    0xbab0677e, // 色彩
+    0xbab595a0, // 片面
+    0xbabed761, // 结合
+    0xbacb6199, // 被子
+    0xbad9a368, // 洞
+    0xbb031ed2, // 在于
+    0xbb155f1f, // 医院
+    0xbb1a73da, // 局
+    0xbb21b24d, // 回
+    0xbb221af2, // 城市
+    0xbb3066c6, // 读
+    0xbb33515f, // 现金
+    0xbb496b66, // 人员
+    0xbb4b6bc8, // 适应
+    0xbb7a3bb5, // 材料
+    0xbbd3139b, // 独立
+    0xbbde915c, // 性别
+    0xbbf3ca00, // 海里
+    0xbc0011ee, // 自动
+    0xbc0d35e5, // 从前
+    0xbc1e14e9, // 跑步
+    0xbc2d42a1, // 彩虹
+    0xbc34073e, // 单纯
+    0xbc3efdd1, // 报告
+    0xbc93b260, // 逻辑
+    0xbcffa47b, // 奇特
+    0xbd06d419, // 价值
+    0xbd094b5e, // 令
+    0xbd68a461, // 无
+    0xbd6ea4aa, // 狮子
+    0xbd85cc2b, // 牙膏
+    0xbd8bdffd, // 淡
+    0xbd9f35c6, // 效应
+    0xbda65410, // 请愿书
+    0xbda7939d, // 切
+    0xbdc30d39, // 用
+    0xbdcc48a7, // 经过
+    0xbde6fdfd, // 等
+    0xbde9ef7b, // 发生
+    0xbdeb21f3, // 涨
+    0xbe0498dd, // 批评
+    0xbe095270, // 推迟
+    0xbe48814f, // 富
+    0xbe4fc7f9, // 生动
+    0xbec61aef, // 二手
+    0xbec87e60, // 重大
+    0xbf4583e7, // 梳子
+    0xbf4929a1, // 论文
+    0xbf6d69ed, // 垃圾桶
+    0xbf87c514, // 含意
+    0xbf87daea, // 针对
+    0xbf8f3bde, // 鲜花
+    0xbfb14d88, // 沙发
+    0xbfbfb8f9, // 中心
+    0xbfc0e250, // 至于
+    0xbfe1ecc1, // 瘦
+    0xbfeca8d2, // 对比
+    0xbff03ab6, // 公园
+    0xbff9e4b1, // 看不起
+    0xc0046615, // 奇怪
+    0xc0342963, // 药
+    0xc04515f1, // 晒
+    0xc09fabfe, // 餐厅
+    0xc0af8736, // 额
+    0xc0d4cd45, // 时候
+    0xc0ded294, // 郊区
+    0xc11138e6, // 八成儿
+    0xc116dedb, // 眼睛
+    0xc137a892, // 细节
+    0xc167b133, // 曾经
+    0xc1750661, // 橡子
+    0xc19b9fe5, // 首
+    0xc1dc963f, // 南
+    0xc1e21480, // 暗
+    0xc20f7534, // 尽快
+    0xc216ce98, // 始终
+    0xc2256784, // 加班
+    0xc23cf2bb, // 迫切
+    0xc24d8c9e, // 子路
+    0xc25a3a7b, // 害怕
+    0xc25d3a5e, // 居然
+    0xc28122be, // 她
+    0xc287419f, // 火
+    0xc29cc2df, // 射
+    0xc2c3cb75, // 迷路
+    0xc2dd7740, // 银
+    0xc2df4fd0, // 滑
+    0xc2ebc7f7, // 规模
+    0xc2f7bafe, // 名字
+    0xc306cc78, // 制定
+    0xc30ffea1, // 改善
+    0xc314621b, // 前
+    0xc31e5581, // 聊天
+    0xc31f5a6a, // 争论
+    0xc324d182, // 合格
+    0xc32bc4d4, // 辩论
+    0xc3734c78, // 砍
+    0xc3c7b1d0, // 金石为开
+    0xc3d6798c, // 青
+    0xc3df3471, // 夏令营
+    0xc4145eb0, // 未必
+    0xc416fe09, // 说服
+    0xc4192877, // 辞职
+    0xc445373f, // 聊天儿
+    0xc46bd644, // 先
+    0xc49d6375, // 资格
+    0xc49f760d, // 谢
+    0xc4b88ad5, // 礼拜天
+    0xc4f1f35b, // 排列
+    0xc4f5c78f, // 老舍
+    0xc4faa80b, // 文章
+    0xc50dcfb6, // 干活儿
+    0xc545e41d, // 推
+    0xc5474264, // 蚊子
+    0xc5520e2f, // 展开
+    0xc5674cef, // 蹄
+    0xc57e0820, // 一样
+    0xc58c1a3e, // 马
+    0xc5ba549f, // 堵车
+    0xc5c642b0, // 老婆
+    0xc5ed8adb, // 证明
+    0xc64005eb, // 年代
+    0xc6423a5c, // 状况
+    0xc6603224, // 扩
+    0xc66569be, // 张
+    0xc6a4b2fb, // 廉颇
+    0xc6bde908, // 第一
+    0xc6cbbb4b, // 郁达夫
+    0xc6cd7a99, // 积累
+    0xc6dbb469, // 非
+    0xc6e83ee7, // 作业
+    0xc7105d86, // 数量
+    0xc72c440a, // 农民
+    0xc74118dd, // 佩服
+    0xc7489301, // 饿
+    0xc74ef426, // 怎么办
+    0xc75ff0ac, // 办公室
+    0xc78a32cf, // 抱
+    0xc7bb9408, // 文学家
+    0xc7bcf1e3, // 修改
+    0xc7d9baa8, // 身边
+    0xc7dc77d9, // 学生
+    0xc81775f7, // 耳环
+    0xc82fc1ad, // 同意
+    0xc84d79cd, // 天然
+    0xc851b65f, // 所以
+    0xc86664c9, // 积极
+    0xc878e1c1, // 美术
+    0xc8906948, // 发现
+    0xc8eb3978, // 咳嗽
+    0xc8f29323, // 货
+    0xc901f729, // 纪录
+    0xc904c575, // 拿
+    0xc9118798, // 科技
+    0xc931b0f6, // 信
+    0xc93ac348, // 经常
+    0xc9551671, // 评价
+    0xc97948bb, // 复制
+    0xc97d11f3, // 矮
+    0xc9cb5c9a, // 似乎
+    0xc9d9c3c3, // 当地
+    0xc9e3de6a, // 它
+    0xc9ed54f8, // 坚决
+    0xca0fc86e, // 丽
+    0xca11c21a, // 牛津大学
+    0xca9dd1d3, // 操场
+    0xcac90701, // 考试
+    0xcadc4c1a, // 照顾
+    0xcaee7a9a, // 垃圾
+    0xcb104443, // 押金
+    0xcb11ac82, // 抽屉
+    0xcb5879b6, // 街道
+    0xcb684591, // 人名
+    0xcb9c1c79, // 从此
+    0xcba7418e, // 心脏
+    0xcbca998f, // 顺序
+    0xcbdd539d, // 不仅
+    0xcc1facc1, // 黄金
+    0xcc77aafc, // 登记
+    0xcc8741d5, // 刻苦
+    0xcc8ba11b, // 窄
+    0xcca41ad9, // 安装
+    0xccae678f, // 旁边
+    0xccb18be1, // 肩膀
+    0xcccddcdd, // 西汉
+    0xccde9f3d, // 北方
+    0xccdfd15f, // 数码
+    0xcce6a7b4, // 人才
+    0xcceeaf21, // 闭关
+    0xccf411ad, // 其余
+    0xcd00d7c1, // 所
+    0xcd079d1e, // 纷纷
+    0xcd430e2e, // 见面
+    0xcd679600, // 祝贺
+    0xcd69c99d, // 懂
+    0xcdb2d9ef, // 模糊
+    0xcdccc4f9, // 陌生
+    0xcdde0dd7, // 红色
+    0xcde323bc, // 趵突泉
+    0xcde5fdc1, // 适合
+    0xcdfe3a86, // 举行
+    0xce017bcf, // 手续
+    0xce0a9588, // 重量
+    0xce16ea8d, // 卢米埃尔
+    0xce3b2b30, // 击
+    0xce46a60e, // 陆续
+    0xce471e8b, // 文具
+    0xce4a0437, // 传统
+    0xce85fc3d, // 产品
+    0xcea62030, // 信任
+    0xceaa95d2, // 当
+    0xceb178bf, // 观察
+    0xcebc269d, // 一辈子
+    0xcec98220, // 当心
+    0xcecaab57, // 出席
+    0xcee8316d, // 男
+    0xceee7b9b, // 杂志
+    0xcf002fb8, // 预订
+    0xcf0f374e, // 并且
+    0xcf28a6d9, // 人民
+    0xcf49e73c, // 楼
+    0xcf535d64, // 传播
+    0xcf5f4482, // 疑问
+    0xcf92388e, // 长
+    0xcf9357c5, // 向
+    0xcfae797e, // 颗
+    0xcfb6664a, // 果汁
+    0xcfc5e9b2, // 饮料
+    0xcfe6aca2, // 呆
+    0xcff9dab1, // 老
+    0xd0013872, // 人民币
+    0xd00a1631, // 语气
+    0xd01efe89, // 袜子
+    0xd04362b8, // 媒体
+    0xd04e9115, // 电视
+    0xd05b620c, // 阵
+    0xd0767ae1, // 落后
+    0xd0771b60, // 及格
+    0xd0dedc58, // 道德
+    0xd11e6f5e, // 答应
+    0xd123948e, // 桥
+    0xd12ba7c0, // 刻
+    0xd1791308, // 温度
+    0xd17dc96a, // 帮忙
+    0xd1929d35, // 享受
+    0xd1978cd4, // 灰
+    0xd1b6c3a9, // 大型
+    0xd1bc717f, // 空间
+    0xd1df626b, // 目前
+    0xd20b1318, // 未来
+    0xd2217919, // 腰
+    0xd2576b0d, // 难道
+    0xd25e70c4, // 流血
+    0xd273d1a9, // 度过
+    0xd28ab36d, // 训练
+    0xd2a0fcca, // 倍
+    0xd2a7b92c, // 私人
+    0xd2bdabd1, // 吓
+    0xd2e2dce5, // 困扰
+    0xd2fd28c6, // 薄
+    0xd310e996, // 自豪
+    0xd31c4ec3, // 闪电
+    0xd31e96d0, // 女性
+    0xd336cfb4, // 逗
+    0xd337ac1f, // 甲
+    0xd34a7948, // 中文
+    0xd35023e1, // 指导
+    0xd3701fd6, // 出自
+    0xd3750b36, // 往
+    0xd3bdc423, // 是
+    0xd3c4c756, // 查询
+    0xd3c791ee, // 一共
+    0xd3dec1e1, // 就餐
+    0xd3e9db2d, // 一
+    0xd3f16711, // 整齐
+    0xd403331c, // 天津卫视
+    0xd41e2d33, // 出色
+    0xd4547f5f, // 科恩
+    0xd46c52a0, // 百
+    0xd4733804, // 小姐
+    0xd4735374, // 往返
+    0xd4750d82, // 啤酒
+    0xd47d7a03, // 有
+    0xd498ab8a, // 中午
+    0xd4a8f45c, // 掉
+    0xd4adefe4, // 培训
+    0xd4b43e3b, // 情绪
+    0xd4cf5d2d, // 强调
+    0xd50c5370, // 信用卡
+    0xd5398692, // 钥匙
+    0xd558b801, // 朝
+    0xd55953cf, // 估计
+    0xd56d9a66, // 集中
+    0xd595f08a, // 调整
+    0xd5a8822a, // 份
+    0xd5bcd54b, // 和尚
+    0xd5c4d598, // 会员卡
+    0xd5cdd2a1, // 合适
+    0xd5ce9237, // 尽管
+    0xd60fc278, // 好处
+    0xd623486e, // 回来
+    0xd65113e1, // 可是
+    0xd6521015, // 对
+    0xd65362bd, // 收集
+    0xd67ec234, // 表现
+    0xd69ba365, // 无意
+    0xd6a18120, // 分
+    0xd6f92277, // 时间
+    0xd6fadc7c, // 课
+    0xd710db45, // 穷
+    0xd723df5d, // 屡
+    0xd7251323, // 呢
+    0xd75982b2, // 承受
+    0xd7868842, // 反映
+    0xd7a6834e, // 北京
+    0xd7cf987b, // 堵
+    0xd7d8d9af, // 辆
+    0xd7e73149, // 作文
+    0xd7effcff, // 格外
+    0xd808cc52, // 人物
+    0xd80fdf7a, // 教育
+    0xd824a97f, // 能干
+    0xd840d3f1, // 偷
+    0xd85258fc, // 吵架
+    0xd86340c2, // 瞧
+    0xd8807e5c, // 梅西
+    0xd88b0734, // 能
+    0xd8a052f9, // 弄
+    0xd8ba52cb, // 排队
+    0xd8c68545, // 时差
+    0xd8c68900, // 提高
+    0xd8e9729f, // 肥皂
+    0xd92385c8, // 维修
+    0xd95a7331, // 是否
+    0xd95fb495, // 全部
+    0xd96079f0, // 开幕式
+    0xd98751f5, // 感冒
+    0xd9934c3c, // 听说
+    0xd99ab61a, // 赶
+    0xd9d9e90c, // 成
+    0xda055a6a, // 记者
+    0xda18fe6a, // 样式
+    0xda52c897, // 接着
+    0xda5e0365, // 土豆
+    0xda7fc6f0, // 极其
+    0xda910536, // 穿
+    0xdac0803b, // 有利
+    0xdb10645f, // 哭
+    0xdb1a2bc1, // 演员
+    0xdb27bdc3, // 尾巴
+    0xdb3d8d76, // 尺子
+    0xdb5b6352, // 正式
+    0xdb6ece2a, // 校园
+    0xdb9662ce, // 事物
+    0xdba3661e, // 大家
+    0xdbad9db0, // 相关
+    0xdbb67c6c, // 加油
+    0xdc3ae384, // 树
+    0xdc4351b9, // 转告
+    0xdc47df7c, // 博士
+    0xdc80c191, // 呀
+    0xdc829be4, // 标志
+    0xdcb3fa0f, // 个子
+    0xdced7ef9, // 宣传
+    0xdcf114b5, // 行动
+    0xdcf1654c, // 召开
+    0xdcf904c6, // 彼此
+    0xdd31ce02, // 因而
+    0xdd3e8d2c, // 酒
+    0xdd3f5080, // 看
+    0xdd46a416, // 娱乐
+    0xdd53739b, // 日程
+    0xdd7027a9, // 收获
+    0xdd7cc3c9, // 国际
+    0xdd998834, // 理发
+    0xdd9eb425, // 准备
+    0xdda987dc, // 黑
+    0xddb8c612, // 寻找
+    0xddcc0a6f, // 地址
+    0xdde82ad2, // 手
+    0xddfe0452, // 实践
+    0xde059e93, // 稳定
+    0xde08fab9, // 乒乓球
+    0xde27c1e5, // 于是
+    0xde2f4ee1, // 鱼
+    0xde47374e, // 暗暗
+    0xde9fa2df, // 培养
+    0xdea62073, // 暖
+    0xdeba1ae3, // 帮
+    0xdec6ab4d, // 废
+    0xdece10a3, // 密切
+    0xded05f43, // 交通
+    0xdedc9d79, // 说话
+    0xdef42087, // 意思
+    0xdefb7fc7, // 原谅
+    0xdf015aaa, // 改进
+    0xdf1f3833, // 青壮年
+    0xdf333fe4, // 声音
+    0xdf3a241c, // 了
+    0xdf3c0d30, // 归纳
+    0xdf4d3c74, // 方向
+    0xdf6210be, // 优秀
+    0xdf77d73f, // 克服
+    0xdfce5cee, // 冲
+    0xdfd9df9f, // 音乐
+    0xe008f77a, // 阳台
+    0xe01db7a4, // 斜
+    0xe02c9e56, // 种
+    0xe030514d, // 个
+    0xe033f357, // 中介
+    0xe04451c3, // 恩爱
+    0xe081a9f5, // 胃口
+    0xe09c6e83, // 安娜
+    0xe0b2a1d2, // 接近
+    0xe0cf263c, // 女
+    0xe0dec7b7, // 替
+    0xe0df0b53, // 郝林说
+    0xe0f49159, // 伸
+    0xe101152b, // 成分
+    0xe11878ca, // 公开
+    0xe11b3b97, // 地理
+    0xe120aee7, // 欣赏
+    0xe12677af, // 招儿
+    0xe1544cc9, // 鲍全
+    0xe163676b, // 痛苦
+    0xe19a71ba, // 刚
+    0xe1bbb8e3, // 避免
+    0xe1be3bfa, // 马上
+    0xe1cf2326, // 追
+    0xe1ee2532, // 文化
+    0xe1fa9f31, // 总统
+    0xe205e355, // 与其
+    0xe20baefb, // 左边
+    0xe2166c7f, // 士兵
+    0xe23c71d8, // 靠
+    0xe289cbfd, // 进步
+    0xe2cbb2c4, // 创办
+    0xe2f0fe9a, // 一定
+    0xe2f9a7ba, // 感兴趣
+    0xe324ae05, // 感到
+    0xe33ffd2b, // 友谊
+    0xe3814d3b, // 打扰
+    0xe3cc40bc, // 无数
+    0xe3e187e6, // 每天
+    0xe3ec02ce, // 市场
+    0xe3f09947, // 爱
+    0xe42a616b, // 银行
+    0xe4363fe0, // 超级
+    0xe4384d7b, // 热爱
+    0xe440cb31, // 临时
+    0xe4462980, // 小伙子
+    0xe44deb70, // 服务员
+    0xe472710e, // 变
+    0xe475e320, // 纪念
+    0xe4931c0d, // 拼音
+    0xe4b9f80b, // 移动
+    0xe4d37b71, // 象征
+    0xe4f40408, // 对方
+    0xe4fd6d8d, // 结束
+    0xe5011bcd, // 纸袋
+    0xe55f9587, // 酸
+    0xe55fe39a, // 吃
+    0xe5682386, // 内部
+    0xe5808611, // 窗户
+    0xe593c124, // 占线
+    0xe59e4aeb, // 了不起
+    0xe5a0ed1a, // 万一
+    0xe5c77de6, // 结果
+    0xe5e04fc5, // 总共
+    0xe5ebb44b, // 碗
+    0xe60aa915, // 广播
+    0xe614ee8f, // 工具
+    0xe6185003, // 王宫
+    0xe64e728e, // 危险
+    0xe651bd61, // 家务
+    0xe655a5aa, // 庆祝
+    0xe69d481c, // 害羞
+    0xe6a7e67d, // 小朋友
+    0xe6d54cae, // 起床
+    0xe6e4d5a5, // 受
+    0xe70f3a1c, // 箭
+    0xe710fa54, // 死
+    0xe72c688c, // 稍微
+    0xe73a39f8, // 流行
+    0xe73ea34f, // 推荐
+    0xe7414a25, // 不管
+    0xe7725efb, // 轻松
+    0xe77608ec, // 拆
+    0xe795ba2d, // 单元
+    0xe7eabe88, // 加油站
+    0xe8134b09, // 疲劳
+    0xe81d0e63, // 悲
+    0xe8288fb7, // 谈
+    0xe8420fb9, // 勺子
+    0xe856027f, // 妨碍
+    0xe85b1808, // 醉
+    0xe896a63f, // 外公
+    0xe8a6c1a9, // 既然
+    0xe8d465bb, // 明显
+    0xe8d69693, // 反应
+    0xe8e2ea1c, // 幼儿园
+    0xe90eef06, // 农村
+    0xe939d546, // 姐姐
+    0xe9464108, // 生日
+    0xe9808c92, // 相敬如宾
+    0xe986ef25, // 老百姓
+    0xe99b2b15, // 随着
+    0xe99d3f28, // 儿童
+    0xe9b7f78f, // 艰苦
+    0xe9d4ee5b, // 房子
+    0xe9eb7f9b, // 一致
+    0xea140d3c, // 取
+    0xea45d130, // 资金
+    0xea558298, // 运输
+    0xea5f3e4f, // 身份
+    0xea9cc15e, // 教授
+    0xeaa22c21, // 美国
+    0xeab4e4b7, // 养成
+    0xeab78b89, // 期间
+    0xeabf4cf1, // 和平
+    0xead611db, // 搬
+    0xeae93458, // 缺少
+    0xeb07117d, // 提
+    0xeb35436b, // 语言
+    0xeb39b4ec, // 拥挤
+    0xeb51db10, // 才
+    0xeb5429bd, // 职业
+    0xeb6c0580, // 约
+    0xeb876d43, // 美德
+    0xebaaf1e2, // 假装
+    0xebb1b0c5, // 厉害
+    0xebb6ef9e, // 颜色
+    0xebb77d51, // 比较
+    0xec17f717, // 帅
+    0xec2b92ae, // 持续
+    0xec2ba275, // 分布
+    0xec3143b2, // 熊猫
+    0xec48f45e, // 答案
+    0xec6a496b, // 鼓楼大街
+    0xec92713d, // 播放
+    0xec9a0d82, // 下面
+    0xecce560c, // 随手
+    0xecf06b86, // 员工
+    0xed010665, // 深渊
+    0xed130a7f, // 对面
+    0xed20d139, // 竟然
+    0xed4c07c9, // 知更鸟
+    0xed521a0d, // 性质
+    0xed56b71c, // 劳动
+    0xed7128a1, // 确定
+    0xed7526f1, // 不但
+    0xed764bf5, // 登机牌
+    0xed79e2c3, // 赵奢
+    0xedb01476, // 伙伴
+    0xedd89f19, // 昨天
+    0xedef23b2, // 猜
+    0xee031711, // 根据
+    0xee462b7e, // 转头
+    0xee79c4d5, // 骂
+    0xee827fad, // 一直
+    0xeea74a94, // 扇子
+    0xeeac48dc, // 拦
+    0xeec6615d, // 全
+    0xeecab3fb, // 名牌
+    0xeececf12, // 牛奶
+    0xeed6ca06, // 毛
+    0xeedb48f8, // 代言
+    0xeef0c63f, // 阶段
+    0xef465557, // 许多
+    0xef7b9a8b, // 支持
+    0xef8ba564, // 采访
+    0xefa5f5ac, // 缓解
+    0xefa88f09, // 旅行
+    0xefc653b6, // 疯
+    0xefcc70e7, // 厚
+    0xefe169e7, // 软
+    0xefe907ea, // 坐
+    0xf0068c31, // 独特
+    0xf00768fd, // 漏
+    0xf03d3d51, // 可怕
+    0xf06ae873, // 秘书
+    0xf0985b96, // 制造
+    0xf09b5201, // 叙述
+    0xf0a71a63, // 数学
+    0xf0df9c6d, // 棋
+    0xf11260e2, // 碎
+    0xf114040e, // 招聘
+    0xf16be3fd, // 池塘
+    0xf16c3aea, // 摸
+    0xf18649bb, // 速度
+    0xf195fd76, // 接受
+    0xf1a2e7ef, // 出租车
+    0xf1cf6009, // 施肥
+    0xf1dcc61b, // 鸡蛋
+    0xf229c266, // 具备
+    0xf2365a31, // 阴
+    0xf23dd3c9, // 刘炽平
+    0xf2988d8c, // 珍惜
+    0xf2c00181, // 空
+    0xf2c76f20, // 岸
+    0xf2c9d0f3, // 方面
+    0xf2f72183, // 香山
+    0xf30ffd47, // 否认
+    0xf311d270, // 消化
+    0xf318c1c3, // 花生
+    0xf3252da6, // 来不及
+    0xf327a92d, // 下载
+    0xf32b806e, // 迟早
+    0xf33690e2, // 有点
+    0xf34f2620, // 忙
+    0xf34f900f, // 近代
+    0xf35f5419, // 这儿
+    0xf360703f, // 制作
+    0xf3665cfa, // 集体
+    0xf36781b6, // 增
+    0xf387a481, // 车库
+    0xf393e959, // 西瓜
+    0xf39b051e, // 春
+    0xf3d69763, // 哪
+    0xf3f5e925, // 尽量
+    0xf3feba05, // 接
+    0xf40c0ef5, // 连忙
+    0xf418f423, // 增长
+    0xf41ab2c7, // 房东
+    0xf41df374, // 领域
+    0xf42a021a, // 奋斗
+    0xf42b4e8a, // 推辞
+    0xf43196f9, // 出版
+    0xf4501bfb, // 菜
+    0xf45c8a51, // 后背
+    0xf46083be, // 零
+    0xf460a4a2, // 委屈
+    0xf4626939, // 行为
+    0xf48d5043, // 表面
+    0xf49d2cf8, // 数字
+    0xf4dcd623, // 外面
+    0xf4ed0ed3, // 路口
+    0xf4ef4b74, // 刀
+    0xf5154f0f, // 勿
+    0xf53236b5, // 代表
+    0xf56b61a4, // 输
+    0xf598f3f6, // 玻璃
+    0xf5c0d91a, // 衬衫
+    0xf5cc4fd0, // 何必
+    0xf5e55fc3, // 普通
+    0xf5f9de5d, // 狗
+    0xf60f2d44, // 继续
+    0xf6422850, // 不错
+    0xf643d4af, // 立即
+    0xf6508a5b, // 社会
+    0xf65e2793, // 原因
+    0xf667ab53, // 拐弯
+    0xf66deb2e, // 建设
+    0xf694c5b0, // 收据
+    0xf6a8b466, // 节日
+    0xf6d56bcf, // 内容
+    0xf6dcaec8, // 锯子
+    0xf7021216, // 掌握
+    0xf7178297, // 胡说
+    0xf73cba83, // 公平
+    0xf75ab967, // 描写
+    0xf75d1b50, // 粮食
+    0xf762d730, // 诚信
+    0xf76c7bdf, // 踩
+    0xf775e522, // 过
+    0xf7793511, // 作者
+    0xf77a8286, // 秘密
+    0xf7858bb6, // 自由
+    0xf7bb52b3, // 列车
+    0xf7bc772c, // 理论
+    0xf7bebe52, // 弱
+    0xf7c2847f, // 秒
+    0xf7ca3ed8, // 赞成
+    0xf7d3e7b2, // 原料
+    0xf7dc678a, // 礼物
+    0xf7e951ee, // 枪
+    0xf7eed210, // 不同
+    0xf806da67, // 参观
+    0xf8119deb, // 事实
+    0xf8279105, // 长江
+    0xf85db9c7, // 丛林
+    0xf881a72e, // 理想
+    0xf88bcf67, // 考虑
+    0xf88d52f8, // 咖啡
+    0xf890437d, // 挡
+    0xf893796e, // 干脆
+    0xf8c17e93, // 河
+    0xf8f985fc, // 随身
+    0xf90f3edb, // 躺
+    0xf9147f53, // 比如
+    0xf920193a, // 两
+    0xf925dab2, // 大概
+    0xf927190c, // 但是
+    0xf96677ce, // 打扫
+    0xf96f16f8, // 腿
+    0xf975f43d, // 伤害
+    0xf98f4d2f, // 流传
+    0xf9c97fb3, // 汉语
+    0xf9d7a2ea, // 开
+    0xf9e15b21, // 低
+    0xf9f7a224, // 普通话
+    0xfa08da75, // 卖
+    0xfa2838df, // 感谢
+    0xfa2b5f00, // 礼貌
+    0xfa5f9a47, // 环境
+    0xfa614eaa, // 因为
+    0xfab59761, // 桃
+    0xfac19ba3, // 匹
+    0xfac46ed4, // 兔子
+    0xfacb3a1f, // 围绕
+    0xfacca5f3, // 意外
+    0xfaee092c, // 刮风
+    0xfaf01cf3, // 动物
+    0xfb005e2b, // 推广
+    0xfb0082ba, // 体贴
+    0xfb01fd68, // 轻视
+    0xfb1c696a, // 坚强
+    0xfb1d13dc, // 谁
+    0xfb54e802, // 翟峰
+    0xfb72a7ed, // 蹄子
+    0xfb900e34, // 橡皮
+    0xfbc4ef10, // 生意
+    0xfbe979cc, // 牙刷
+    0xfbfd6bea, // 说明
+    0xfbfe5014, // 确认
+    0xfc0041b4, // 难怪
+    0xfc10b54a, // 充电器
+    0xfc21e546, // 金鱼
+    0xfc258858, // 您
+    0xfc2f0e07, // 均匀
+    0xfc390dc7, // 面条
+    0xfc5de0d6, // 免费
+    0xfc63e2ba, // 棚子
+    0xfc66c7d1, // 吃惊
+    0xfc69b3b6, // 资料
+    0xfc6ee0a5, // 情缘
+    0xfc836b7a, // 互相
+    0xfc97adf1, // 长期
+    0xfc9dd952, // 简历
+    0xfcaefdb5, // 容易
+    0xfccfbf97, // 股票
+    0xfcd3f227, // 壶
+    0xfd0c9f35, // 你
+    0xfd0fbb2c, // 钓
+    0xfd3c5e98, // 汉字
+    0xfd697027, // 事情
+    0xfd793bfc, // 披
+    0xfd7a8a06, // 多余
+    0xfd7bd185, // 平安
+    0xfd840186, // 羽毛球
+    0xfd912314, // 柜台
+    0xfd97c0d2, // 相似
+    0xfd9f78dc, // 哪儿
+    0xfda74e44, // 新西兰
+    0xfda97756, // 不足
+    0xfde65a91, // 污染
+    0xfdfa875b, // 八
+    0xfe09dc21, // 成绩
+    0xfe4f2994, // 历史
+    0xfe54032e, // 购买
+    0xfe7a8616, // 馆子
+    0xfe953302, // 诚恳
+    0xfeb97399, // 俱乐部
+    0xfebbeae9, // 哪怕
+    0xfecbdb62, // 俩
+    0xfed80586, // 判断
+    0xfedb6be0, // 至少
+    0xfeec9ac1, // 购物
+    0xfefb3590, // 洒
+    0xff1a68a6, // 海关
+    0xff4877dc, // 冠军
+    0xff4e430d, // 大学
+    0xff64c108, // 临
+    0xff749f02, // 学校
+    0xff7bd04a, // 睡着
+    0xff9e8053, // 差异
+    0xffaafab7, // 开玩笑
+    0xffb3e00b, // 姑娘
+    0xffb6817d, // 加利福尼亚州
+    0xffc5bf66, // 果然
+    0xffe89344, // 这
+];
+
+// 词语 in the same order as HANZI
+pub static HANZI_KEYS: &[&str] = &[
+    "打扮",
+    "寒假",
+    "哪里",
+    "世纪",
+    "饼干",
+    "聚会",
+    "退休",
+    "背",
+    "产生",
+    "裙子",
+    "渴",
+    "潘家园",
+    "对于",
+    "输入",
+    "停",
+    "根本",
+    "济南",
+    "武术",
+    "失业",
+    "十分",
+    "辣",
+    "近",
+    "瓶子",
+    "机场",
+    "盖",
+    "深",
+    "承担",
+    "试",
+    "小吃",
+    "幸运",
+    "挣",
+    "届",
+    "昙花",
+    "报到",
+    "除",
+    "议论",
+    "账户",
+    "友好",
+    "米",
+    "逐步",
+    "赔偿",
+    "知道",
+    "嫁",
+    "过期",
+    "负重",
+    "班",
+    "雷",
+    "沙子",
+    "代替",
+    "破坏",
+    "工业",
+    "洗礼",
+    "接触",
+    "网络",
+    "锅",
+    "存在",
+    "能源",
+    "耳朵",
+    "直",
+    "顺畅",
+    "舜",
+    "扔",
+    "表明",
+    "分别",
+    "总之",
+    "亲爱",
+    "台",
+    "中秋节",
+    "思考",
+    "餐",
+    "照常",
+    "自觉",
+    "撕",
+    "申请",
+    "发烧",
+    "鼠标",
+    "称",
+    "什么",
+    "勇气",
+    "感情",
+    "宁可",
+    "悲观",
+    "现实",
+    "奖",
+    "淋漓尽致",
+    "写",
+    "汤",
+    "着",
+    "冬",
+    "声调",
+    "汗腺",
+    "饱",
+    "开发",
+    "打折",
+    "文学",
+    "咬",
+    "借口",
+    "尾气",
+    "永远",
+    "九",
+    "其实",
+    "个性",
+    "等于",
+    "舒服",
+    "报名",
+    "行李箱",
+    "音乐会",
+    "日子",
+    "位置",
+    "沉",
+    "受到",
+    "中旬",
+    "出生",
+    "利息",
+    "养",
+    "行人",
+    "软件",
+    "蒸腾",
+    "糟糕",
+    "反省",
+    "赵福根",
+    "形象",
+    "老板",
+    "国王",
+    "主席",
+    "里",
+    "得意",
+    "隔壁",
+    "焦",
+    "我",
+    "赶快",
+    "公斤",
+    "墙",
+    "层",
+    "普遍",
+    "手套",
+    "抽象",
+    "圈",
+    "退步",
+    "满意",
+    "蛋糕",
+    "别",
+    "受伤",
+    "闹钟",
+    "理发店",
+    "活动",
+    "矛盾",
+    "农业",
+    "愿意",
+    "包裹",
+    "下",
+    "仍然",
+    "片",
+    "奇迹",
+    "盆子",
+    "吸引",
+    "全面",
+    "兴奋",
+    "文件",
+    "打招呼",
+    "人类",
+    "地球",
+    "马虎",
+    "小麦",
+    "部门",
+    "挥",
+    "战争",
+    "爱迪生",
+    "学问",
+    "教室",
+    "设备",
+    "转",
+    "元旦",
+    "库",
+    "孝敬",
+    "初级",
+    "健康",
+    "好",
+    "姿势",
+    "口",
+    "共同",
+    "硬件",
+    "种类",
+    "健身",
+    "他",
+    "体育馆",
+    "禁止",
+    "宴会",
+    "困难",
+    "自然",
+    "猫",
+    "围巾",
+    "糖",
+    "澳大利亚",
+    "早上",
+    "最后",
+    "少许",
+    "街",
+    "胃",
+    "作用",
+    "经营",
+    "询问",
+    "形容",
+    "啄",
+    "洗手间",
+    "测验",
+    "身段",
+    "挂号",
+    "爱惜",
+    "信号",
+    "目的",
+    "矿泉水",
+    "要求",
+    "系领带",
+    "治",
+    "虽然",
+    "只要",
+    "从来",
+    "随时",
+    "后悔",
+    "打针",
+    "收入",
+    "不客气",
+    "放纵",
+    "从事",
+    "慌",
+    "热心",
+    "不然",
+    "冰箱",
+    "客观",
+    "长辈",
+    "利用",
+    "大",
+    "交",
+    "只",
+    "戴",
+    "忍不住",
+    "流利",
+    "去",
+    "快乐",
+    "已经",
+    "点心",
+    "夸张",
+    "笑",
+    "趋势",
+    "游泳",
+    "赵国",
+    "实验",
+    "沉默",
+    "车厢",
+    "尤其",
+    "恨",
+    "再见",
+    "专家",
+    "节目",
+    "体育",
+    "公寓",
+    "毛孔",
+    "显示",
+    "讽刺",
+    "云南",
+    "盘子",
+    "汽油",
+    "录音",
+    "商场",
+    "下午",
+    "步骤",
+    "公主",
+    "寄生",
+    "记得",
+    "感受",
+    "加",
+    "小",
+    "部",
+    "地位",
+    "孙月",
+    "愉快",
+    "羊肉",
+    "故事",
+    "够",
+    "内科",
+    "按",
+    "表演",
+    "关系",
+    "公共汽车",
+    "故意",
+    "零件",
+    "自私",
+    "蹲",
+    "难免",
+    "千万",
+    "抱怨",
+    "年纪",
+    "用功",
+    "威胁",
+    "年轻",
+    "增加",
+    "零食",
+    "改革",
+    "延长",
+    "平方",
+    "趁",
+    "破",
+    "哥哥",
+    "迎接",
+    "短信",
+    "教训",
+    "同事",
+    "观点",
+    "甩",
+    "对手",
+    "蛇",
+    "京剧",
+    "广和居",
+    "最好",
+    "些",
+    "埋",
+    "袋",
+    "移民",
+    "牙齿",
+    "脸",
+    "装饰",
+    "多少",
+    "了解",
+    "姓",
+    "洗",
+    "家庭",
+    "歌舞",
+    "比例",
+    "盆",
+    "充满",
+    "谨慎",
+    "打算",
+    "米饭",
+    "检查",
+    "压力",
+    "张小龙",
+    "天气",
+    "毕竟",
+    "勇敢",
+    "扩大",
+    "消极",
+    "反对",
+    "志愿者",
+    "热",
+    "游览",
+    "气氛",
+    "难过",
+    "一旦",
+    "条",
+    "解释",
+    "十",
+    "车祸",
+    "双方",
+    "支票",
+    "范围",
+    "最",
+    "在",
+    "阻止",
+    "统一",
+    "摄入",
+    "主观",
+    "否定",
+    "类型",
+    "几",
+    "骑",
+    "整体",
+    "哈",
+    "图",
+    "次要",
+    "脑袋",
+    "毛病",
+    "不见得",
+    "球迷",
+    "根",
+    "伤心",
+    "臭",
+    "教练",
+    "盐",
+    "充分",
+    "正常",
+    "主要",
+    "商量",
+    "哲理",
+    "开水",
+    "去世",
+    "胡同",
+    "电池",
+    "会计",
+    "说不定",
+    "保留",
+    "花",
+    "毛巾",
+    "晕",
+    "天敌",
+    "重",
+    "鲁迅",
+    "翅膀",
+    "调皮",
+    "质量",
+    "笔记本",
+    "有关",
+    "运气",
+    "抬",
+    "感想",
+    "句子",
+    "迟到",
+    "与",
+    "亚洲",
+    "打电话",
+    "女孩",
+    "据说",
+    "谦虚",
+    "观念",
+    "鲇鱼",
+    "点",
+    "兑换",
+    "广泛",
+    "咨询",
+    "待遇",
+    "休息",
+    "降落",
+    "拍",
+    "不耐烦",
+    "中间",
+    "发",
+    "马萨诸塞州",
+    "差距",
+    "如今",
+    "结婚",
+    "痒",
+    "放",
+    "圆",
+    "急忙",
+    "责任",
+    "长江大桥",
+    "新鲜",
+    "实际",
+    "演出",
+    "字母",
+    "前途",
+    "麻烦",
+    "玩具",
+    "手表",
+    "知感",
+    "胜利",
+    "前面",
+    "鼻子",
+    "离婚",
+    "兼职",
+    "讨厌",
+    "印加",
+    "标点",
+    "刺激",
+    "不得不",
+    "成语",
+    "杨",
+    "搜索",
+    "事半功倍",
+    "传染",
+    "朝三暮四",
+    "羡慕",
+    "模仿",
+    "白",
+    "火车站",
+    "花花",
+    "甚至",
+    "确实",
+    "即使",
+    "背景",
+    "注意",
+    "返航",
+    "规律",
+    "首都",
+    "翻译",
+    "相信",
+    "歪歪扭扭",
+    "复杂",
+    "敲",
+    "试卷",
+    "好奇",
+    "发抖",
+    "激烈",
+    "菊花",
+    "嘴",
+    "猪",
+    "萝卜",
+    "装",
+    "放心",
+    "形状",
+    "由来",
+    "有趣",
+    "困",
+    "钱",
+    "古典",
+    "管子",
+    "专业",
+    "并",
+    "赚",
+    "离开",
+    "传真",
+    "胳膊",
+    "功能",
+    "脾气",
+    "温暖",
+    "高级",
+    "聪明",
+    "慢",
+    "时髦",
+    "经商",
+    "包含",
+    "项",
+    "筷子",
+    "孩子",
+    "假",
+    "常识",
+    "表格",
+    "初级中学",
+    "告诉",
+    "竹子",
+    "打喷嚏",
+    "违反",
+    "详细",
+    "彻底",
+    "作战",
+    "擦",
+    "洗澡",
+    "不安",
+    "罚款",
+    "极了",
+    "厨房",
+    "极",
+    "可能",
+    "而",
+    "假设",
+    "重点",
+    "怪物",
+    "用途",
+    "不假思索",
+    "吸收",
+    "画",
+    "风浪",
+    "布",
+    "成果",
+    "美丽",
+    "图书馆",
+    "内",
+    "年龄",
+    "宣布",
+    "为什么",
+    "台阶",
+    "一律",
+    "看望",
+    "互联网",
+    "影响",
+    "歪",
+    "葡萄",
+    "遍",
+    "诺贝尔奖",
+    "窗",
+    "艺术",
+    "主题",
+    "皮鞋",
+    "肾上腺素",
+    "鼓舞",
+    "实现",
+    "完整",
+    "空调",
+    "棒",
+    "美人鱼",
+    "喂",
+    "放暑假",
+    "升",
+    "下雨",
+    "引起",
+    "倒霉",
+    "客厅",
+    "远",
+    "建议",
+    "主动",
+    "晚上",
+    "命运",
+    "精彩",
+    "改",
+    "老师",
+    "话题",
+    "观众",
+    "失败",
+    "硕士",
+    "春游",
+    "遗憾",
+    "占",
+    "指",
+    "演讲",
+    "同学",
+    "军事",
+    "肚子",
+    "欧洲环境保护署",
+    "恶劣",
+    "权力",
+    "角色",
+    "酒吧",
+    "右边",
+    "短",
+    "本质",
+    "扶",
+    "交换",
+    "八成",
+    "秦国",
+    "使劲儿",
+    "盲人",
+    "例子",
+    "欧洲",
+    "一再",
+    "峰终定律",
+    "轻",
+    "帆船",
+    "折",
+    "进口",
+    "概括",
+    "起",
+    "肯定",
+    "东海龙王",
+    "存",
+    "唉",
+    "咱们",
+    "来得及",
+    "理解",
+    "必须",
+    "文火",
+    "海洋",
+    "结构",
+    "笑话",
+    "出口",
+    "太极拳",
+    "新年",
+    "特殊",
+    "象",
+    "危害",
+    "陪",
+    "坏",
+    "讲",
+    "亲戚",
+    "春节",
+    "谈判",
+    "胶水",
+    "属于",
+    "嗯",
+    "首都机场",
+    "地毯",
+    "克",
+    "打交道",
+    "顿",
+    "身体",
+    "群",
+    "星期",
+    "套",
+    "干燥",
+    "搞",
+    "不得了",
+    "坦率",
+    "椅子",
+    "参与",
+    "功夫",
+    "青蛙",
+    "想念",
+    "领导",
+    "看见",
+    "糊涂",
+    "讲座",
+    "厕所",
+    "魅力",
+    "轮",
+    "入围",
+    "进行",
+    "稻香村",
+    "牛仔裤",
+    "一连",
+    "丝毫",
+    "孙子",
+    "早",
+    "业务",
+    "主意",
+    "后面",
+    "喝",
+    "摘",
+    "摩托车",
+    "护士",
+    "人事",
+    "重要",
+    "这些",
+    "爱情",
+    "叮",
+    "般",
+    "着凉",
+    "玩",
+    "不过",
+    "导游",
+    "断",
+    "高速公路",
+    "神话",
+    "改正",
+    "空儿",
+    "橘子",
+    "兴趣",
+    "年",
+    "力量",
+    "关",
+    "分享",
+    "护照",
+    "沟通",
+    "倒",
+    "迅速",
+    "国君",
+    "黄",
+    "力气",
+    "木头",
+    "流泪",
+    "杆",
+    "佳",
+    "六",
+    "久",
+    "金属",
+    "冷静",
+    "填空",
+    "景色",
+    "今年",
+    "信心",
+    "绕",
+    "发票",
+    "雪",
+    "酱油",
+    "模特",
+    "成熟",
+    "租",
+    "以",
+    "工程师",
+    "家访",
+    "熟悉",
+    "过敏",
+    "门槛",
+    "想象",
+    "生病",
+    "思想",
+    "集合",
+    "太阳",
+    "次",
+    "几乎",
+    "手术",
+    "姑姑",
+    "设计",
+    "成立",
+    "老鼠",
+    "百分之",
+    "洛杉矶",
+    "复习",
+    "食物",
+    "愿望",
+    "让",
+    "出院",
+    "偶尔",
+    "谢谢",
+    "受不了",
+    "态度",
+    "全神贯注",
+    "星星",
+    "打篮球",
+    "难",
+    "太太",
+    "黄河",
+    "天真",
+    "捡",
+    "面包",
+    "表",
+    "行家",
+    "填",
+    "售货员",
+    "船",
+    "听",
+    "由于",
+    "伞",
+    "挺",
+    "风景",
+    "华北",
+    "吧",
+    "处",
+    "买",
+    "日",
+    "敌人",
+    "寿命",
+    "旅游",
+    "奔跑",
+    "香瓜",
+    "黑猩猩",
+    "以及",
+    "挂",
+    "啊",
+    "总裁",
+    "风格",
+    "成就",
+    "浏览",
+    "凉快",
+    "作家",
+    "闯",
+    "折叠",
+    "突然",
+    "小心",
+    "透明",
+    "使用",
+    "道理",
+    "丝",
+    "降低",
+    "驾驶",
+    "凭",
+    "预防",
+    "浪费",
+    "累",
+    "博物馆",
+    "豆腐",
+    "化学",
+    "经历",
+    "学术",
+    "万丈",
+    "宽",
+    "灵活",
+    "逃避",
+    "角",
+    "更",
+    "命令",
+    "年级",
+    "来自",
+    "灰姑娘",
+    "日记",
+    "从",
+    "寂寞",
+    "舍不得",
+    "月",
+    "拉",
+    "舞蹈",
+    "微笑",
+    "颠球",
+    "嗓子",
+    "尽",
+    "丑",
+    "半",
+    "上当",
+    "数",
+    "匆忙",
+    "忽视",
+    "号码",
+    "投篮",
+    "很",
+    "或者",
+    "仔细",
+    "总理",
+    "可见",
+    "怀念",
+    "可以",
+    "口味",
+    "权利",
+    "分配",
+    "方法",
+    "热情",
+    "没有",
+    "做",
+    "获得",
+    "健伟",
+    "石头",
+    "否则",
+    "底",
+    "保险",
+    "对象",
+    "影子",
+    "责备",
+    "决定",
+    "半夜",
+    "煮",
+    "西",
+    "平静",
+    "枝干",
+    "叉子",
+    "行业",
+    "应用",
+    "来",
+    "词语",
+    "出发",
+    "工作",
+    "角度",
+    "丝绸",
+    "相对",
+    "欠",
+    "管理",
+    "电影",
+    "为",
+    "快",
+    "删除",
+    "高",
+    "厢房",
+    "严肃",
+    "执照",
+    "桌子",
+    "冷",
+    "难受",
+    "贸易",
+    "开心",
+    "逛",
+    "赢",
+    "时期",
+    "导致",
+    "把",
+    "投资",
+    "明明",
+    "上午",
+    "努力",
+    "县",
+    "特别",
+    "跟",
+    "也许",
+    "招",
+    "老实",
+    "从而",
+    "交际",
+    "精力",
+    "灾害",
+    "报道",
+    "补充",
+    "悠久",
+    "名片",
+    "固定",
+    "悠悠",
+    "趟",
+    "采取",
+    "失望",
+    "损失",
+    "鲜艳",
+    "越",
+    "就",
+    "节省",
+    "担任",
+    "一点儿",
+    "照片",
+    "篇",
+    "广场",
+    "起来",
+    "振动",
+    "安静",
+    "周",
+    "基础",
+    "物质",
+    "破产",
+    "重新",
+    "以后",
+    "热烈",
+    "阿姨",
+    "反而",
+    "手指",
+    "完成",
+    "形式",
+    "积蓄",
+    "七",
+    "标准",
+    "方",
+    "岛屿",
+    "良好",
+    "老虎",
+    "平均",
+    "批准",
+    "效率",
+    "安排",
+    "湿润",
+    "贡献",
+    "形成",
+    "司机",
+    "还是",
+    "提问",
+    "财产",
+    "贷款",
+    "项链",
+    "缺乏",
+    "睡觉",
+    "真正",
+    "手笔",
+    "西红柿",
+    "绿",
+    "地点",
+    "本科",
+    "光盘",
+    "小区",
+    "购",
+    "吵",
+    "恭喜",
+    "凉茶",
+    "使",
+    "同样",
+    "不如",
+    "疼爱",
+    "其他",
+    "婚姻",
+    "怀疑",
+    "著名",
+    "提前",
+    "利益",
+    "遇到",
+    "双",
+    "巧妙",
+    "请求",
+    "住",
+    "丑陋",
+    "每",
+    "美好",
+    "主任",
+    "狡猾",
+    "发表",
+    "婚礼",
+    "生产",
+    "因此",
+    "节食",
+    "忽然",
+    "正在",
+    "顾客",
+    "烦恼",
+    "卡车",
+    "治疗",
+    "专门",
+    "平衡",
+    "气温",
+    "英俊",
+    "学习",
+    "东",
+    "着火",
+    "随便",
+    "连环画",
+    "预习",
+    "守岁",
+    "各自",
+    "女士",
+    "个别",
+    "失眠",
+    "程度",
+    "月亮",
+    "镜子",
+    "香肠",
+    "支教",
+    "包",
+    "地图",
+    "开始",
+    "上班",
+    "递",
+    "晴",
+    "骄傲",
+    "而且",
+    "留守",
+    "优美",
+    "承认",
+    "觉得",
+    "粉",
+    "招待",
+    "爷爷",
+    "提供",
+    "坚持",
+    "时刻",
+    "呼朋唤友",
+    "地道",
+    "同时",
+    "鼓掌",
+    "分钟",
+    "催",
+    "热闹",
+    "患难与共",
+    "窗帘",
+    "国庆节",
+    "大卫",
+    "梨",
+    "父母",
+    "生气",
+    "儿子",
+    "日期",
+    "象棋",
+    "世界",
+    "便",
+    "自杀",
+    "不",
+    "工厂",
+    "组",
+    "学",
+    "幅",
+    "差",
+    "概念",
+    "同情",
+    "发展",
+    "照相机",
+    "机会",
+    "往往",
+    "派",
+    "上网",
+    "玩儿",
+    "脖子",
+    "冷淡",
+    "暖和",
+    "飘",
+    "当时",
+    "海鲜",
+    "首先",
+    "微信",
+    "似的",
+    "叔叔",
+    "寓言",
+    "经理",
+    "配合",
+    "消息",
+    "关闭",
+    "日用品",
+    "明星",
+    "粗心",
+    "位",
+    "激动",
+    "要是",
+    "敢",
+    "宾馆",
+    "劳驾",
+    "正",
+    "单独",
+    "何况",
+    "抓紧",
+    "编辑",
+    "做客",
+    "呼吸",
+    "紧张",
+    "可靠",
+    "邮局",
+    "脱",
+    "情景",
+    "夏",
+    "规矩",
+    "调查",
+    "以前",
+    "组成",
+    "朋",
+    "特点",
+    "高档",
+    "客人",
+    "报社",
+    "着急",
+    "像",
+    "教材",
+    "神秘",
+    "联合",
+    "唱歌",
+    "明",
+    "干净",
+    "周到",
+    "石灰岩",
+    "企业",
+    "多",
+    "在乎",
+    "饭店",
+    "导演",
+    "拒绝",
+    "到时候",
+    "植物",
+    "约会",
+    "黑板",
+    "煤炭",
+    "保护",
+    "天空",
+    "朗读",
+    "真实",
+    "讨价还价",
+    "荫凉",
+    "盼望",
+    "日历",
+    "参考",
+    "袋子",
+    "醒",
+    "创造",
+    "勤奋",
+    "取消",
+    "豪华",
+    "岁",
+    "路",
+    "关注",
+    "签",
+    "满足",
+    "锁",
+    "吃亏",
+    "建立",
+    "断断续续",
+    "状态",
+    "轮流",
+    "希望",
+    "鼓励",
+    "数据",
+    "录取",
+    "吸取",
+    "说",
+    "退",
+    "文明",
+    "冰激凌",
+    "网球",
+    "指挥",
+    "提倡",
+    "商店",
+    "民族",
+    "记录",
+    "举",
+    "争取",
+    "别人",
+    "制冷",
+    "艰巨",
+    "妈妈",
+    "任务",
+    "生命",
+    "大方",
+    "多亏",
+    "怀孕",
+    "启发",
+    "行",
+    "道歉",
+    "单调",
+    "却",
+    "电梯",
+    "邻居",
+    "林",
+    "起居",
+    "如何",
+    "尽力",
+    "烤鸭",
+    "医生",
+    "刘辰",
+    "按照",
+    "构成",
+    "保",
+    "国籍",
+    "到",
+    "病毒",
+    "堆",
+    "护国寺",
+    "公布",
+    "馒头",
+    "实在",
+    "场",
+    "糊",
+    "香",
+    "心理",
+    "家乡",
+    "形势",
+    "剩",
+    "舅舅",
+    "到处",
+    "目录",
+    "心情",
+    "北京师范大学",
+    "握手",
+    "幸福",
+    "光临",
+    "娶",
+    "地方",
+    "母亲",
+    "恐怕",
+    "刚才",
+    "英雄",
+    "妻子",
+    "照",
+    "绝对",
+    "通过",
+    "名胜古迹",
+    "废话",
+    "不行",
+    "天安门东",
+    "到底",
+    "腾讯",
+    "女儿",
+    "漂亮",
+    "乘坐",
+    "物理",
+    "预报",
+    "外地",
+    "孝顺",
+    "纪律",
+    "假如",
+    "懒",
+    "散步",
+    "本来",
+    "春秋",
+    "发言",
+    "印象",
+    "消费",
+    "大约",
+    "书",
+    "弟弟",
+    "休闲",
+    "时尚",
+    "无所谓",
+    "修理",
+    "一起",
+    "展览",
+    "方便",
+    "建筑",
+    "太",
+    "规定",
+    "赵括",
+    "姥姥",
+    "外",
+    "地区",
+    "吨",
+    "味道",
+    "痛快",
+    "根治",
+    "健步如飞",
+    "专注",
+    "王子",
+    "温柔",
+    "课程",
+    "水果",
+    "表达",
+    "爬山",
+    "再",
+    "方式",
+    "疼",
+    "抢",
+    "认为",
+    "发明",
+    "广东省",
+    "栏杆",
+    "留学",
+    "清淡",
+    "成为",
+    "有限",
+    "一般来说",
+    "官",
+    "活跃",
+    "其中",
+    "龙",
+    "带",
+    "起飞",
+    "捐",
+    "发挥",
+    "除非",
+    "李广",
+    "块",
+    "整理",
+    "生物钟",
+    "位于",
+    "新",
+    "淘气",
+    "好像",
+    "日常",
+    "得到",
+    "小说",
+    "滚",
+    "广大",
+    "孔子",
+    "仿佛",
+    "美",
+    "连",
+    "资源",
+    "对话",
+    "舒适",
+    "签证",
+    "昆虫",
+    "结论",
+    "尝",
+    "问题",
+    "接待",
+    "正确",
+    "实习",
+    "一下",
+    "收",
+    "面试",
+    "绳子",
+    "计算",
+    "摆",
+    "夹子",
+    "胆小鬼",
+    "准时",
+    "也",
+    "吻",
+    "皮肤",
+    "伟大",
+    "陆地",
+    "缩短",
+    "惭愧",
+    "期待",
+    "朵",
+    "显然",
+    "报纸",
+    "诚实",
+    "整个",
+    "网站",
+    "设施",
+    "应付",
+    "通知",
+    "原则",
+    "四合院",
+    "青少年",
+    "善良",
+    "消失",
+    "有意思",
+    "元",
+    "比",
+    "五",
+    "六一儿童节",
+    "纳入",
+    "词典",
+    "限制",
+    "采用",
+    "便宜",
+    "空气",
+    "座",
+    "冒",
+    "保证",
+    "帮助",
+    "巨大",
+    "严重",
+    "感激",
+    "摇",
+    "本",
+    "后",
+    "字幕",
+    "毕业",
+    "付款",
+    "旺盛",
+    "票",
+    "达到",
+    "销售",
+    "动作",
+    "救",
+    "组合",
+    "杨雄",
+    "决心",
+    "问候",
+    "以来",
+    "必然",
+    "不少",
+    "最近",
+    "参加",
+    "义务",
+    "国",
+    "认",
+    "诊断",
+    "总结",
+    "梦想",
+    "摄影",
+    "歇",
+    "苹果",
+    "减肥",
+    "手工",
+    "骨头",
+    "吗",
+    "举办",
+    "不断",
+    "咱",
+    "过程",
+    "担心",
+    "帽子",
+    "工资",
+    "满",
+    "小气",
+    "秋",
+    "国贸",
+    "眼镜",
+    "无奈",
+    "将来",
+    "辅导",
+    "香蕉",
+    "条件",
+    "天",
+    "文文",
+    "自信",
+    "选择",
+    "题",
+    "飞机",
+    "完美",
+    "超市",
+    "挑战",
+    "只好",
+    "价钱",
+    "楚国",
+    "被",
+    "铃",
+    "放弃",
+    "爸爸",
+    "航班",
+    "笔试",
+    "措施",
+    "钢铁",
+    "信封",
+    "马克",
+    "菜单",
+    "放松",
+    "怎么",
+    "亲自",
+    "习惯",
+    "滴",
+    "战国",
+    "制度",
+    "有点儿",
+    "脏",
+    "拥抱",
+    "会议",
+    "夸",
+    "意见",
+    "段",
+    "光明",
+    "偶然",
+    "例如",
+    "操心",
+    "先生",
+    "怪不得",
+    "节",
+    "出现",
+    "塑料袋",
+    "情侣",
+    "写作",
+    "装修",
+    "则",
+    "空闲",
+    "三",
+    "至今",
+    "效果",
+    "回忆",
+    "丰富",
+    "蒙眬",
+    "联系",
+    "叫",
+    "擀",
+    "反复",
+    "法律",
+    "低落",
+    "专心",
+    "大厦",
+    "所谓",
+    "火柴",
+    "勺",
+    "卧室",
+    "沙滩",
+    "组织",
+    "熟练",
+    "意义",
+    "脚",
+    "老鹰",
+    "关于",
+    "赶紧",
+    "才艺",
+    "交流",
+    "念",
+    "妇女",
+    "少",
+    "戏剧",
+    "还",
+    "慌张",
+    "出",
+    "要",
+    "宝贵",
+    "赏心悦目",
+    "王",
+    "告别",
+    "打",
+    "无论",
+    "印刷",
+    "麦布里奇",
+    "现在",
+    "那",
+    "感动",
+    "撞",
+    "菜市口",
+    "阅读",
+    "提纲",
+    "页",
+    "抽",
+    "小时",
+    "体验",
+    "优势",
+    "青春",
+    "想",
+    "商业",
+    "基本",
+    "饺子",
+    "法院",
+    "划",
+    "时代",
+    "乐器",
+    "红",
+    "明白",
+    "爱心",
+    "屋",
+    "词汇",
+    "体现",
+    "紧急",
+    "总是",
+    "油炸",
+    "开放",
+    "闻",
+    "微博",
+    "符合",
+    "剪刀",
+    "电脑",
+    "平等",
+    "方案",
+    "麦克风",
+    "因素",
+    "突出",
+    "结账",
+    "的确",
+    "烫",
+    "的",
+    "算",
+    "核心",
+    "夏威夷",
+    "成人",
+    "抄",
+    "具体",
+    "吃饭",
+    "可怜",
+    "土地",
+    "服装",
+    "相当",
+    "批",
+    "没",
+    "原来",
+    "电子邮件",
+    "碰",
+    "和",
+    "亮",
+    "射击",
+    "夜",
+    "打印",
+    "自行车",
+    "征求",
+    "讨论",
+    "规则",
+    "水",
+    "到达",
+    "弹钢琴",
+    "主持",
+    "记忆",
+    "初中",
+    "交往",
+    "密码",
+    "民居",
+    "父亲",
+    "准确",
+    "适当",
+    "换",
+    "痰",
+    "汗",
+    "苦",
+    "减",
+    "分析",
+    "系统",
+    "傻",
+    "遵守",
+    "等待",
+    "钱包",
+    "沙漠",
+    "练习",
+    "七郎",
+    "重复",
+    "轻易",
+    "贵",
+    "明天",
+    "省",
+    "特征",
+    "关心",
+    "蔬菜",
+    "本领",
+    "出示",
+    "出差",
+    "有效",
+    "私",
+    "暴雨",
+    "烂",
+    "暂时",
+    "笑笑",
+    "性格",
+    "校长",
+    "依然",
+    "房间",
+    "头发",
+    "辛苦",
+    "耽误",
+    "某",
+    "保持",
+    "上海",
+    "汇率",
+    "胖",
+    "巧克力",
+    "妹妹",
+    "你们",
+    "吹",
+    "冻",
+    "人生",
+    "有名",
+    "我们",
+    "存活",
+    "刷牙",
+    "键盘",
+    "奖金",
+    "乖",
+    "宠物",
+    "季节",
+    "离",
+    "转变",
+    "追求",
+    "静",
+    "座位",
+    "合同",
+    "可乐",
+    "祝",
+    "犯",
+    "打工",
+    "无聊",
+    "裤子",
+    "究竟",
+    "认识",
+    "高兴",
+    "华裔",
+    "躲藏",
+    "繁荣",
+    "程序",
+    "幽默",
+    "看法",
+    "机器",
+    "周末",
+    "乱",
+    "长途",
+    "猴子",
+    "优惠",
+    "后来",
+    "尊敬",
+    "纸上谈兵",
+    "好吃",
+    "喂养",
+    "肌肉",
+    "诗",
+    "燃烧",
+    "分手",
+    "逐渐",
+    "项目",
+    "事先",
+    "棵",
+    "个人",
+    "熬夜",
+    "啦",
+    "投入",
+    "要不",
+    "明确",
+    "逃",
+    "强烈",
+    "好客",
+    "省略",
+    "保存",
+    "涂鸦",
+    "完",
+    "女孩儿",
+    "敏感",
+    "唯一",
+    "棍",
+    "梦",
+    "办理",
+    "地震",
+    "平常",
+    "风俗",
+    "决赛",
+    "号",
+    "错误",
+    "面对",
+    "草",
+    "成长",
+    "粗糙",
+    "恢复",
+    "喜欢",
+    "外交",
+    "过去",
+    "笔记",
+    "完善",
+    "悄悄",
+    "师傅",
+    "应聘",
+    "合理",
+    "实用",
+    "对待",
+    "句",
+    "电台",
+    "灯",
+    "零钱",
+    "公里",
+    "朋友",
+    "秩序",
+    "请",
+    "业余",
+    "玉米",
+    "兄弟",
+    "摔倒",
+    "经验",
+    "表扬",
+    "又",
+    "证据",
+    "作品",
+    "然后",
+    "理由",
+    "完全",
+    "锯",
+    "道具",
+    "都",
+    "哲学",
+    "合影",
+    "活泼",
+    "技术",
+    "广告",
+    "只有",
+    "促使",
+    "没关系",
+    "蝴蝶",
+    "疯狂",
+    "自己",
+    "紫",
+    "钟",
+    "经济",
+    "纸",
+    "抓",
+    "变化",
+    "税",
+    "长城",
+    "真",
+    "醋",
+    "甜",
+    "大使馆",
+    "找",
+    "狂",
+    "水平",
+    "允许",
+    "地",
+    "浅",
+    "耐心",
+    "相同",
+    "公元",
+    "精诚所至",
+    "跳舞",
+    "顺利",
+    "正好",
+    "能力",
+    "摄影师",
+    "垮",
+    "机制",
+    "证件",
+    "走",
+    "律师",
+    "生长",
+    "衣服",
+    "错",
+    "收拾",
+    "人口",
+    "文字",
+    "浓",
+    "通常",
+    "部分",
+    "解决",
+    "尊重",
+    "无法",
+    "踢足球",
+    "竞争",
+    "复印",
+    "送",
+    "包括",
+    "关键",
+    "果实",
+    "根基",
+    "使劲",
+    "恋爱",
+    "中国",
+    "响",
+    "可爱",
+    "波动",
+    "目标",
+    "传说",
+    "立刻",
+    "阳光",
+    "后果",
+    "学期",
+    "应该",
+    "件",
+    "大夫",
+    "发达",
+    "促进",
+    "顺便",
+    "厘米",
+    "站",
+    "风险",
+    "面临",
+    "距离",
+    "表情",
+    "讲究",
+    "终于",
+    "雾",
+    "门",
+    "干杯",
+    "爱护",
+    "光",
+    "学历",
+    "入口",
+    "胸",
+    "利润",
+    "杀",
+    "按时",
+    "光线",
+    "严格",
+    "政治",
+    "附近",
+    "字",
+    "当然",
+    "抱歉",
+    "桔子",
+    "任何",
+    "铅笔",
+    "度",
+    "另外",
+    "屋子",
+    "缺点",
+    "宿舍",
+    "由",
+    "犹豫",
+    "一会儿",
+    "自从",
+    "幸亏",
+    "实话",
+    "可",
+    "硬",
+    "国家",
+    "现代",
+    "平时",
+    "团",
+    "蜜蜂",
+    "现象",
+    "多么",
+    "如果",
+    "亲切",
+    "灰尘",
+    "雇",
+    "清楚",
+    "合作",
+    "主张",
+    "海",
+    "瞎",
+    "合法",
+    "赞美",
+    "干",
+    "信息",
+    "优点",
+    "办法",
+    "情趣",
+    "红酒",
+    "造成",
+    "宝贝",
+    "左右",
+    "挤",
+    "公司",
+    "超过",
+    "忘记",
+    "回答",
+    "会",
+    "守",
+    "或许",
+    "光滑",
+    "气候",
+    "必要",
+    "锻炼",
+    "警察",
+    "炒",
+    "反正",
+    "为了",
+    "丈夫",
+    "支",
+    "价格",
+    "总算",
+    "怪",
+    "另",
+    "丢",
+    "祝福",
+    "认真",
+    "火成岩",
+    "其次",
+    "可惜",
+    "感觉",
+    "于",
+    "重视",
+    "面积",
+    "误会",
+    "今天",
+    "比赛",
+    "问",
+    "精神",
+    "经典",
+    "抽烟",
+    "李",
+    "负责",
+    "一切",
+    "作为",
+    "制订",
+    "茶",
+    "运用",
+    "借",
+    "自愿",
+    "车",
+    "商务",
+    "计划",
+    "手机",
+    "粘贴",
+    "爱好",
+    "教",
+    "给",
+    "安慰",
+    "再三",
+    "值得",
+    "深刻",
+    "去年",
+    "悲伤",
+    "夕",
+    "商品",
+    "之",
+    "直接",
+    "进",
+    "注册",
+    "称霸",
+    "留",
+    "上",
+    "翻",
+    "称赞",
+    "动画片",
+    "东西",
+    "对不起",
+    "得",
+    "嫩",
+    "骗",
+    "地铁",
+    "营养",
+    "幻想",
+    "政府",
+    "银子",
+    "改变",
+    "安全",
+    "知识",
+    "处理",
+    "过分",
+    "情况",
+    "浪漫",
+    "小雨",
+    "节约",
+    "身材",
+    "云",
+    "系",
+    "怎么样",
+    "斯坦福",
+    "亿",
+    "浇",
+    "嘉宾",
+    "控制",
+    "题目",
+    "所有",
+    "然而",
+    "虚心",
+    "睁",
+    "提醒",
+    "显得",
+    "工人",
+    "综合",
+    "吐",
+    "评委",
+    "插",
+    "奶奶",
+    "二",
+    "笨",
+    "办事",
+    "乙",
+    "平",
+    "鞭炮",
+    "介绍",
+    "冒险",
+    "哲学家",
+    "营业",
+    "失去",
+    "灰心",
+    "血",
+    "眉毛",
+    "卫生间",
+    "此外",
+    "粉色",
+    "频道",
+    "万",
+    "沙丁鱼",
+    "区别",
+    "即",
+    "盒子",
+    "鸟",
+    "蓝",
+    "潮湿",
+    "单位",
+    "哎",
+    "主人",
+    "山",
+    "非常",
+    "周围",
+    "救护车",
+    "发愁",
+    "镇",
+    "千",
+    "家",
+    "请假",
+    "包子",
+    "相处",
+    "戒",
+    "咸",
+    "寄",
+    "环保",
+    "游戏",
+    "语法",
+    "称呼",
+    "戒指",
+    "差不多",
+    "一边",
+    "册",
+    "邀请",
+    "特色",
+    "相反",
+    "新鲜感",
+    "各",
+    "欢迎",
+    "大象",
+    "顶",
+    "除夕",
+    "把握",
+    "及时",
+    "森林",
+    "欧盟",
+    "劝",
+    "表示",
+    "研究",
+    "以为",
+    "简单",
+    "最初",
+    "傍晚",
+    "生活",
+    "科学",
+    "样子",
+    "需要",
+    "体会",
+    "雨",
+    "结实",
+    "古代",
+    "苗条",
+    "减少",
+    "乐观",
+    "打听",
+    "减轻",
+    "那儿",
+    "除了",
+    "人",
+    "家具",
+    "一般",
+    "急诊",
+    "智慧",
+    "善于",
+    "喊",
+    "旧",
+    "四",
+    "书架",
+    "叶子",
+    "成功",
+    "简直",
+    "连续",
+    "辣椒",
+    "不要紧",
+    "新闻",
+    "杯子",
+    "运动",
+    "色彩",
+    "片面",
+    "结合",
+    "被子",
+    "洞",
+    "在于",
+    "医院",
+    "局",
+    "回",
+    "城市",
+    "读",
+    "现金",
+    "人员",
+    "适应",
+    "材料",
+    "独立",
+    "性别",
+    "海里",
+    "自动",
+    "从前",
+    "跑步",
+    "彩虹",
+    "单纯",
+    "报告",
+    "逻辑",
+    "奇特",
+    "价值",
+    "令",
+    "无",
+    "狮子",
+    "牙膏",
+    "淡",
+    "效应",
+    "请愿书",
+    "切",
+    "用",
+    "经过",
+    "等",
+    "发生",
+    "涨",
+    "批评",
+    "推迟",
+    "富",
+    "生动",
+    "二手",
+    "重大",
+    "梳子",
+    "论文",
+    "垃圾桶",
+    "含意",
+    "针对",
+    "鲜花",
+    "沙发",
+    "中心",
+    "至于",
+    "瘦",
+    "对比",
+    "公园",
+    "看不起",
+    "奇怪",
+    "药",
+    "晒",
+    "餐厅",
+    "额",
+    "时候",
+    "郊区",
+    "八成儿",
+    "眼睛",
+    "细节",
+    "曾经",
+    "橡子",
+    "首",
+    "南",
+    "暗",
+    "尽快",
+    "始终",
+    "加班",
+    "迫切",
+    "子路",
+    "害怕",
+    "居然",
+    "她",
+    "火",
+    "射",
+    "迷路",
+    "银",
+    "滑",
+    "规模",
+    "名字",
+    "制定",
+    "改善",
+    "前",
+    "聊天",
+    "争论",
+    "合格",
+    "辩论",
+    "砍",
+    "金石为开",
+    "青",
+    "夏令营",
+    "未必",
+    "说服",
+    "辞职",
+    "聊天儿",
+    "先",
+    "资格",
+    "谢",
+    "礼拜天",
+    "排列",
+    "老舍",
+    "文章",
+    "干活儿",
+    "推",
+    "蚊子",
+    "展开",
+    "蹄",
+    "一样",
+    "马",
+    "堵车",
+    "老婆",
+    "证明",
+    "年代",
+    "状况",
+    "扩",
+    "张",
+    "廉颇",
+    "第一",
+    "郁达夫",
+    "积累",
+    "非",
+    "作业",
+    "数量",
+    "农民",
+    "佩服",
+    "饿",
+    "怎么办",
+    "办公室",
+    "抱",
+    "文学家",
+    "修改",
+    "身边",
+    "学生",
+    "耳环",
+    "同意",
+    "天然",
+    "所以",
+    "积极",
+    "美术",
+    "发现",
+    "咳嗽",
+    "货",
+    "纪录",
+    "拿",
+    "科技",
+    "信",
+    "经常",
+    "评价",
+    "复制",
+    "矮",
+    "似乎",
+    "当地",
+    "它",
+    "坚决",
+    "丽",
+    "牛津大学",
+    "操场",
+    "考试",
+    "照顾",
+    "垃圾",
+    "押金",
+    "抽屉",
+    "街道",
+    "人名",
+    "从此",
+    "心脏",
+    "顺序",
+    "不仅",
+    "黄金",
+    "登记",
+    "刻苦",
+    "窄",
+    "安装",
+    "旁边",
+    "肩膀",
+    "西汉",
+    "北方",
+    "数码",
+    "人才",
+    "闭关",
+    "其余",
+    "所",
+    "纷纷",
+    "见面",
+    "祝贺",
+    "懂",
+    "模糊",
+    "陌生",
+    "红色",
+    "趵突泉",
+    "适合",
+    "举行",
+    "手续",
+    "重量",
+    "卢米埃尔",
+    "击",
+    "陆续",
+    "文具",
+    "传统",
+    "产品",
+    "信任",
+    "当",
+    "观察",
+    "一辈子",
+    "当心",
+    "出席",
+    "男",
+    "杂志",
+    "预订",
+    "并且",
+    "人民",
+    "楼",
+    "传播",
+    "疑问",
+    "长",
+    "向",
+    "颗",
+    "果汁",
+    "饮料",
+    "呆",
+    "老",
+    "人民币",
+    "语气",
+    "袜子",
+    "媒体",
+    "电视",
+    "阵",
+    "落后",
+    "及格",
+    "道德",
+    "答应",
+    "桥",
+    "刻",
+    "温度",
+    "帮忙",
+    "享受",
+    "灰",
+    "大型",
+    "空间",
+    "目前",
+    "未来",
+    "腰",
+    "难道",
+    "流血",
+    "度过",
+    "训练",
+    "倍",
+    "私人",
+    "吓",
+    "困扰",
+    "薄",
+    "自豪",
+    "闪电",
+    "女性",
+    "逗",
+    "甲",
+    "中文",
+    "指导",
+    "出自",
+    "往",
+    "是",
+    "查询",
+    "一共",
+    "就餐",
+    "一",
+    "整齐",
+    "天津卫视",
+    "出色",
+    "科恩",
+    "百",
+    "小姐",
+    "往返",
+    "啤酒",
+    "有",
+    "中午",
+    "掉",
+    "培训",
+    "情绪",
+    "强调",
+    "信用卡",
+    "钥匙",
+    "朝",
+    "估计",
+    "集中",
+    "调整",
+    "份",
+    "和尚",
+    "会员卡",
+    "合适",
+    "尽管",
+    "好处",
+    "回来",
+    "可是",
+    "对",
+    "收集",
+    "表现",
+    "无意",
+    "分",
+    "时间",
+    "课",
+    "穷",
+    "屡",
+    "呢",
+    "承受",
+    "反映",
+    "北京",
+    "堵",
+    "辆",
+    "作文",
+    "格外",
+    "人物",
+    "教育",
+    "能干",
+    "偷",
+    "吵架",
+    "瞧",
+    "梅西",
+    "能",
+    "弄",
+    "排队",
+    "时差",
+    "提高",
+    "肥皂",
+    "维修",
+    "是否",
+    "全部",
+    "开幕式",
+    "感冒",
+    "听说",
+    "赶",
+    "成",
+    "记者",
+    "样式",
+    "接着",
+    "土豆",
+    "极其",
+    "穿",
+    "有利",
+    "哭",
+    "演员",
+    "尾巴",
+    "尺子",
+    "正式",
+    "校园",
+    "事物",
+    "大家",
+    "相关",
+    "加油",
+    "树",
+    "转告",
+    "博士",
+    "呀",
+    "标志",
+    "个子",
+    "宣传",
+    "行动",
+    "召开",
+    "彼此",
+    "因而",
+    "酒",
+    "看",
+    "娱乐",
+    "日程",
+    "收获",
+    "国际",
+    "理发",
+    "准备",
+    "黑",
+    "寻找",
+    "地址",
+    "手",
+    "实践",
+    "稳定",
+    "乒乓球",
+    "于是",
+    "鱼",
+    "暗暗",
+    "培养",
+    "暖",
+    "帮",
+    "废",
+    "密切",
+    "交通",
+    "说话",
+    "意思",
+    "原谅",
+    "改进",
+    "青壮年",
+    "声音",
+    "了",
+    "归纳",
+    "方向",
+    "优秀",
+    "克服",
+    "冲",
+    "音乐",
+    "阳台",
+    "斜",
+    "种",
+    "个",
+    "中介",
+    "恩爱",
+    "胃口",
+    "安娜",
+    "接近",
+    "女",
+    "替",
+    "郝林说",
+    "伸",
+    "成分",
+    "公开",
+    "地理",
+    "欣赏",
+    "招儿",
+    "鲍全",
+    "痛苦",
+    "刚",
+    "避免",
+    "马上",
+    "追",
+    "文化",
+    "总统",
+    "与其",
+    "左边",
+    "士兵",
+    "靠",
+    "进步",
+    "创办",
+    "一定",
+    "感兴趣",
+    "感到",
+    "友谊",
+    "打扰",
+    "无数",
+    "每天",
+    "市场",
+    "爱",
+    "银行",
+    "超级",
+    "热爱",
+    "临时",
+    "小伙子",
+    "服务员",
+    "变",
+    "纪念",
+    "拼音",
+    "移动",
+    "象征",
+    "对方",
+    "结束",
+    "纸袋",
+    "酸",
+    "吃",
+    "内部",
+    "窗户",
+    "占线",
+    "了不起",
+    "万一",
+    "结果",
+    "总共",
+    "碗",
+    "广播",
+    "工具",
+    "王宫",
+    "危险",
+    "家务",
+    "庆祝",
+    "害羞",
+    "小朋友",
+    "起床",
+    "受",
+    "箭",
+    "死",
+    "稍微",
+    "流行",
+    "推荐",
+    "不管",
+    "轻松",
+    "拆",
+    "单元",
+    "加油站",
+    "疲劳",
+    "悲",
+    "谈",
+    "勺子",
+    "妨碍",
+    "醉",
+    "外公",
+    "既然",
+    "明显",
+    "反应",
+    "幼儿园",
+    "农村",
+    "姐姐",
+    "生日",
+    "相敬如宾",
+    "老百姓",
+    "随着",
+    "儿童",
+    "艰苦",
+    "房子",
+    "一致",
+    "取",
+    "资金",
+    "运输",
+    "身份",
+    "教授",
+    "美国",
+    "养成",
+    "期间",
+    "和平",
+    "搬",
+    "缺少",
+    "提",
+    "语言",
+    "拥挤",
+    "才",
+    "职业",
+    "约",
+    "美德",
+    "假装",
+    "厉害",
+    "颜色",
+    "比较",
+    "帅",
+    "持续",
+    "分布",
+    "熊猫",
+    "答案",
+    "鼓楼大街",
+    "播放",
+    "下面",
+    "随手",
+    "员工",
+    "深渊",
+    "对面",
+    "竟然",
+    "知更鸟",
+    "性质",
+    "劳动",
+    "确定",
+    "不但",
+    "登机牌",
+    "赵奢",
+    "伙伴",
+    "昨天",
+    "猜",
+    "根据",
+    "转头",
+    "骂",
+    "一直",
+    "扇子",
+    "拦",
+    "全",
+    "名牌",
+    "牛奶",
+    "毛",
+    "代言",
+    "阶段",
+    "许多",
+    "支持",
+    "采访",
+    "缓解",
+    "旅行",
+    "疯",
+    "厚",
+    "软",
+    "坐",
+    "独特",
+    "漏",
+    "可怕",
+    "秘书",
+    "制造",
+    "叙述",
+    "数学",
+    "棋",
+    "碎",
+    "招聘",
+    "池塘",
+    "摸",
+    "速度",
+    "接受",
+    "出租车",
+    "施肥",
+    "鸡蛋",
+    "具备",
+    "阴",
+    "刘炽平",
+    "珍惜",
+    "空",
+    "岸",
+    "方面",
+    "香山",
+    "否认",
+    "消化",
+    "花生",
+    "来不及",
+    "下载",
+    "迟早",
+    "有点",
+    "忙",
+    "近代",
+    "这儿",
+    "制作",
+    "集体",
+    "增",
+    "车库",
+    "西瓜",
+    "春",
+    "哪",
+    "尽量",
+    "接",
+    "连忙",
+    "增长",
+    "房东",
+    "领域",
+    "奋斗",
+    "推辞",
+    "出版",
+    "菜",
+    "后背",
+    "零",
+    "委屈",
+    "行为",
+    "表面",
+    "数字",
+    "外面",
+    "路口",
+    "刀",
+    "勿",
+    "代表",
+    "输",
+    "玻璃",
+    "衬衫",
+    "何必",
+    "普通",
+    "狗",
+    "继续",
+    "不错",
+    "立即",
+    "社会",
+    "原因",
+    "拐弯",
+    "建设",
+    "收据",
+    "节日",
+    "内容",
+    "锯子",
+    "掌握",
+    "胡说",
+    "公平",
+    "描写",
+    "粮食",
+    "诚信",
+    "踩",
+    "过",
+    "作者",
+    "秘密",
+    "自由",
+    "列车",
+    "理论",
+    "弱",
+    "秒",
+    "赞成",
+    "原料",
+    "礼物",
+    "枪",
+    "不同",
+    "参观",
+    "事实",
+    "长江",
+    "丛林",
+    "理想",
+    "考虑",
+    "咖啡",
+    "挡",
+    "干脆",
+    "河",
+    "随身",
+    "躺",
+    "比如",
+    "两",
+    "大概",
+    "但是",
+    "打扫",
+    "腿",
+    "伤害",
+    "流传",
+    "汉语",
+    "开",
+    "低",
+    "普通话",
+    "卖",
+    "感谢",
+    "礼貌",
+    "环境",
+    "因为",
+    "桃",
+    "匹",
+    "兔子",
+    "围绕",
+    "意外",
+    "刮风",
+    "动物",
+    "推广",
+    "体贴",
+    "轻视",
+    "坚强",
+    "谁",
+    "翟峰",
+    "蹄子",
+    "橡皮",
+    "生意",
+    "牙刷",
+    "说明",
+    "确认",
+    "难怪",
+    "充电器",
+    "金鱼",
+    "您",
+    "均匀",
+    "面条",
+    "免费",
+    "棚子",
+    "吃惊",
+    "资料",
+    "情缘",
+    "互相",
+    "长期",
+    "简历",
+    "容易",
+    "股票",
+    "壶",
+    "你",
+    "钓",
+    "汉字",
+    "事情",
+    "披",
+    "多余",
+    "平安",
+    "羽毛球",
+    "柜台",
+    "相似",
+    "哪儿",
+    "新西兰",
+    "不足",
+    "污染",
+    "八",
+    "成绩",
+    "历史",
+    "购买",
+    "馆子",
+    "诚恳",
+    "俱乐部",
+    "哪怕",
+    "俩",
+    "判断",
+    "至少",
+    "购物",
+    "洒",
+    "海关",
+    "冠军",
+    "大学",
+    "临",
+    "学校",
+    "睡着",
+    "差异",
+    "开玩笑",
+    "姑娘",
+    "加利福尼亚州",
+    "果然",
+    "这",
+];
+
+// Reading of each 词语 in HANZI_KEYS, with a tone number after each syllable
+// as in CIYU_TONED. 词语 with more than one reading (like 长 for cháng or
+// zhǎng) get the one with the highest weight.
+pub static HANZI_PINYIN: &[&str] = &[
+    "da3ban4",  // 打扮
+    "han2jia3",  // 寒假
+    "na3li3",  // 哪里
+    "shi4ji4",  // 世纪
+    "bing3gan4",  // 饼干
+    "ju4hui4",  // 聚会
+    "tui4xiu1",  // 退休
+    "bei4",  // 背
+    "chan3sheng1",  // 产生
+    "qun2zi3",  // 裙子
+    "ke3",  // 渴
+    "pan1jia1yuan2",  // 潘家园
+    "dui4yu2",  // 对于
+    "shu1ru4",  // 输入
+    "ting2",  // 停
+    "gen1ben3",  // 根本
+    "ji3nan2",  // 济南
+    "wu3shu4",  // 武术
+    "shi1ye4",  // 失业
+    "shi2fen1",  // 十分
+    "la4",  // 辣
+    "jin4",  // 近
+    "ping2zi3",  // 瓶子
+    "ji1chang3",  // 机场
+    "gai4",  // 盖
+    "shen1",  // 深
+    "cheng2dan1",  // 承担
+    "shi4",  // 试
+    "xiao3chi1",  // 小吃
+    "xing4yun4",  // 幸运
+    "zheng4",  // 挣
+    "jie4",  // 届
+    "tan2hua1",  // 昙花
+    "bao4dao4",  // 报到
+    "chu2",  // 除
+    "yi4lun4",  // 议论
+    "zhang4hu4",  // 账户
+    "you3hao3",  // 友好
+    "mi3",  // 米
+    "zhu2bu4",  // 逐步
+    "pei2chang2",  // 赔偿
+    "zhi1dao4",  // 知道
+    "jia4",  // 嫁
+    "guo4qi1",  // 过期
+    "fu4zhong4",  // 负重
+    "ban1",  // 班
+    "lei2",  // 雷
+    "sha1zi3",  // 沙子
+    "dai4ti4",  // 代替
+    "po4huai4",  // 破坏
+    "gong1ye4",  // 工业
+    "xi3li3",  // 洗礼
+    "jie1chu4",  // 接触
+    "wang3luo4",  // 网络
+    "guo1",  // 锅
+    "cun2zai4",  // 存在
+    "neng2yuan2",  // 能源
+    "er3duo3",  // 耳朵
+    "zhi2",  // 直
+    "shun4chang4",  // 顺畅
+    "shun4",  // 舜
+    "reng1",  // 扔
+    "biao3ming2",  // 表明
+    "fen1bie2",  // 分别
+    "zong3zhi1",  // 总之
+    "qin1ai4",  // 亲爱
+    "tai2",  // 台
+    "zhong1qiu1jie2",  // 中秋节
+    "si1kao3",  // 思考
+    "can1",  // 餐
+    "zhao4chang2",  // 照常
+    "zi4jue2",  // 自觉
+    "si1",  // 撕
+    "shen1qing3",  // 申请
+    "fa1shao1",  // 发烧
+    "shu3biao1",  // 鼠标
+    "cheng1",  // 称
+    "shen2me5",  // 什么
+    "yong3qi4",  // 勇气
+    "gan3qing2",  // 感情
+    "ning2ke3",  // 宁可
+    "bei1guan1",  // 悲观
+    "xian4shi2",  // 现实
+    "jiang3",  // 奖
+    "lin2li2jin4zhi4",  // 淋漓尽致
+    "xie3",  // 写
+    "tang1",  // 汤
+    "zhe0",  // 着
+    "dong1",  // 冬
+    "sheng1diao4",  // 声调
+    "han4xian4",  // 汗腺
+    "bao3",  // 饱
+    "kai1fa1",  // 开发
+    "da3zhe2",  // 打折
+    "wen2xue2",  // 文学
+    "yao3",  // 咬
+    "jie4kou3",  // 借口
+    "wei3qi4",  // 尾气
+    "yong3yuan3",  // 永远
+    "jiu3",  // 九
+    "qi2shi2",  // 其实
+    "ge4xing4",  // 个性
+    "deng3yu2",  // 等于
+    "shu1fu2",  // 舒服
+    "bao4ming2",  // 报名
+    "xing2li3xiang1",  // 行李箱
+    "yin1yue4hui4",  // 音乐会
+    "ri4zi3",  // 日子
+    "wei4zhi4",  // 位置
+    "chen2",  // 沉
+    "shou4dao4",  // 受到
+    "zhong1xun2",  // 中旬
+    "chu1sheng1",  // 出生
+    "li4xi1",  // 利息
+    "yang3",  // 养
+    "xing2ren2",  // 行人
+    "ruan3jian4",  // 软件
+    "zheng1teng2",  // 蒸腾
+    "zao1gao1",  // 糟糕
+    "fan3xing0",  // 反省
+    "zhao4fu2gen1",  // 赵福根
+    "xing2xiang4",  // 形象
+    "lao3ban3",  // 老板
+    "guo2wang2",  // 国王
+    "zhu3xi2",  // 主席
+    "li3",  // 里
+    "de2yi4",  // 得意
+    "ge2bi4",  // 隔壁
+    "jiao1",  // 焦
+    "wo3",  // 我
+    "gan3kuai4",  // 赶快
+    "gong1jin1",  // 公斤
+    "qiang2",  // 墙
+    "ceng2",  // 层
+    "pu3bian4",  // 普遍
+    "shou3tao4",  // 手套
+    "chou1xiang4",  // 抽象
+    "quan1",  // 圈
+    "tui4bu4",  // 退步
+    "man3yi4",  // 满意
+    "dan4gao1",  // 蛋糕
+    "bie2",  // 别
+    "shou4shang1",  // 受伤
+    "nao4zhong1",  // 闹钟
+    "li3fa1dian4",  // 理发店
+    "huo2dong4",  // 活动
+    "mao2dun4",  // 矛盾
+    "nong2ye4",  // 农业
+    "yuan4yi4",  // 愿意
+    "bao1guo3",  // 包裹
+    "xia4",  // 下
+    "reng2ran2",  // 仍然
+    "pian4",  // 片
+    "qi2ji1",  // 奇迹
+    "pen2zi5",  // 盆子
+    "xi1yin3",  // 吸引
+    "quan2mian4",  // 全面
+    "xing4fen4",  // 兴奋
+    "wen2jian4",  // 文件
+    "da3zhao1hu1",  // 打招呼
+    "ren2lei4",  // 人类
+    "di4qiu2",  // 地球
+    "ma3hu3",  // 马虎
+    "xiao3mai4",  // 小麦
+    "bu4men2",  // 部门
+    "hui1",  // 挥
+    "zhan4zheng1",  // 战争
+    "ai4di2sheng1",  // 爱迪生
+    "xue2wen4",  // 学问
+    "jiao4shi4",  // 教室
+    "she4bei4",  // 设备
+    "zhuan3",  // 转
+    "yuan2dan4",  // 元旦
+    "ku4",  // 库
+    "xiao4jing4",  // 孝敬
+    "chu1ji2",  // 初级
+    "jian4kang1",  // 健康
+    "hao3",  // 好
+    "zi1shi4",  // 姿势
+    "kou3",  // 口
+    "gong4tong2",  // 共同
+    "ying4jian4",  // 硬件
+    "zhong3lei4",  // 种类
+    "jian4shen1",  // 健身
+    "ta1",  // 他
+    "ti3yu4guan3",  // 体育馆
+    "jin4zhi3",  // 禁止
+    "yan4hui4",  // 宴会
+    "kun4nan2",  // 困难
+    "zi4ran2",  // 自然
+    "mao1",  // 猫
+    "wei2jin1",  // 围巾
+    "tang2",  // 糖
+    "ao4da4li4ya4",  // 澳大利亚
+    "zao3shang5",  // 早上
+    "zui4hou4",  // 最后
+    "shao3xu3",  // 少许
+    "jie1",  // 街
+    "wei4",  // 胃
+    "zuo4yong4",  // 作用
+    "jing1ying2",  // 经营
+    "xun2wen4",  // 询问
+    "xing2rong2",  // 形容
+    "zhuo2",  // 啄
+    "xi3shou3jian1",  // 洗手间
+    "ce4yan4",  // 测验
+    "shen1duan4",  // 身段
+    "gua4hao4",  // 挂号
+    "ai4xi1",  // 爱惜
+    "xin4hao4",  // 信号
+    "mu4di0",  // 目的
+    "kuang4quan2shui3",  // 矿泉水
+    "yao4qiu2",  // 要求
+    "ji4ling3dai4",  // 系领带
+    "zhi4",  // 治
+    "sui1ran2",  // 虽然
+    "zhi3yao4",  // 只要
+    "cong2lai2",  // 从来
+    "sui2shi2",  // 随时
+    "hou4hui3",  // 后悔
+    "da3zhen1",  // 打针
+    "shou1ru4",  // 收入
+    "bu2ke4qi5",  // 不客气
+    "fang4zong4",  // 放纵
+    "cong2shi4",  // 从事
+    "huang1",  // 慌
+    "re4xin1",  // 热心
+    "bu4ran2",  // 不然
+    "bing1xiang1",  // 冰箱
+    "ke4guan1",  // 客观
+    "zhang3bei4",  // 长辈
+    "li4yong4",  // 利用
+    "da4",  // 大
+    "jiao1",  // 交
+    "zhi3",  // 只
+    "dai4",  // 戴
+    "ren3bu4zhu4",  // 忍不住
+    "liu2li4",  // 流利
+    "qu4",  // 去
+    "kuai4le4",  // 快乐
+    "yi3jing1",  // 已经
+    "dian3xin1",  // 点心
+    "kua1zhang1",  // 夸张
+    "xiao4",  // 笑
+    "qu1shi4",  // 趋势
+    "you2yong3",  // 游泳
+    "zhao4guo2",  // 赵国
+    "shi2yan4",  // 实验
+    "chen2mo4",  // 沉默
+    "che1xiang1",  // 车厢
+    "you2qi2",  // 尤其
+    "hen4",  // 恨
+    "zai4jian4",  // 再见
+    "zhuan1jia1",  // 专家
+    "jie2mu4",  // 节目
+    "ti3yu4",  // 体育
+    "gong1yu4",  // 公寓
+    "mao2kong3",  // 毛孔
+    "xian3shi4",  // 显示
+    "feng3ci4",  // 讽刺
+    "yun2nan2",  // 云南
+    "pan2zi3",  // 盘子
+    "qi4you2",  // 汽油
+    "lu4yin1",  // 录音
+    "shang1chang3",  // 商场
+    "xia4wu3",  // 下午
+    "bu4zhou4",  // 步骤
+    "gong1zhu3",  // 公主
+    "ji4sheng1",  // 寄生
+    "ji4de2",  // 记得
+    "gan3shou4",  // 感受
+    "jia1",  // 加
+    "xiao3",  // 小
+    "bu4",  // 部
+    "di4wei4",  // 地位
+    "sun1yue4",  // 孙月
+    "yu2kuai4",  // 愉快
+    "yang2rou4",  // 羊肉
+    "gu4shi4",  // 故事
+    "gou4",  // 够
+    "nei4ke1",  // 内科
+    "an4",  // 按
+    "biao3yan3",  // 表演
+    "guan1xi4",  // 关系
+    "gong1gong4qi4che1",  // 公共汽车
+    "gu4yi4",  // 故意
+    "ling2jian4",  // 零件
+    "zi4si1",  // 自私
+    "dun1",  // 蹲
+    "nan2mian3",  // 难免
+    "qian1wan4",  // 千万
+    "bao4yuan4",  // 抱怨
+    "nian2ji4",  // 年纪
+    "yong4gong1",  // 用功
+    "wei1xia0",  // 威胁
+    "nian2qing1",  // 年轻
+    "zeng1jia1",  // 增加
+    "ling2shi2",  // 零食
+    "gai3ge2",  // 改革
+    "yan2chang2",  // 延长
+    "ping2fang1",  // 平方
+    "chen4",  // 趁
+    "po4",  // 破
+    "ge1ge5",  // 哥哥
+    "ying2jie1",  // 迎接
+    "duan3xin4",  // 短信
+    "jiao4xun4",  // 教训
+    "tong2shi4",  // 同事
+    "guan1dian3",  // 观点
+    "shuai3",  // 甩
+    "dui4shou3",  // 对手
+    "she2",  // 蛇
+    "jing1ju4",  // 京剧
+    "guang3he2ju1",  // 广和居
+    "zui4hao3",  // 最好
+    "xie1",  // 些
+    "mai2",  // 埋
+    "dai4",  // 袋
+    "yi2min2",  // 移民
+    "ya2chi3",  // 牙齿
+    "lian3",  // 脸
+    "zhuang1shi4",  // 装饰
+    "duo1shao5",  // 多少
+    "liao0jie3",  // 了解
+    "xing4",  // 姓
+    "xi3",  // 洗
+    "jia1ting2",  // 家庭
+    "ge1wu3",  // 歌舞
+    "bi3li4",  // 比例
+    "pen2",  // 盆
+    "chong1man3",  // 充满
+    "jin3shen4",  // 谨慎
+    "da3suan4",  // 打算
+    "mi3fan4",  // 米饭
+    "jian3cha2",  // 检查
+    "ya1li4",  // 压力
+    "zhang1xiao3long2",  // 张小龙
+    "tian1qi4",  // 天气
+    "bi4jing4",  // 毕竟
+    "yong3gan3",  // 勇敢
+    "kuo4da4",  // 扩大
+    "xiao1ji2",  // 消极
+    "fan3dui4",  // 反对
+    "zhi4yuan4zhe3",  // 志愿者
+    "re4",  // 热
+    "you2lan3",  // 游览
+    "qi4fen1",  // 气氛
+    "nan2guo4",  // 难过
+    "yi1dan4",  // 一旦
+    "tiao2",  // 条
+    "jie3shi4",  // 解释
+    "shi2",  // 十
+    "che1huo4",  // 车祸
+    "shuang1fang1",  // 双方
+    "zhi1piao4",  // 支票
+    "fan4wei2",  // 范围
+    "zui4",  // 最
+    "zai4",  // 在
+    "zu3zhi3",  // 阻止
+    "tong3yi1",  // 统一
+    "she4ru4",  // 摄入
+    "zhu3guan1",  // 主观
+    "fou3ding4",  // 否定
+    "lei4xing2",  // 类型
+    "ji3",  // 几
+    "qi2",  // 骑
+    "zheng3ti3",  // 整体
+    "ha1",  // 哈
+    "tu2",  // 图
+    "ci4yao4",  // 次要
+    "nao3dai4",  // 脑袋
+    "mao2bing4",  // 毛病
+    "bu4jian4de2",  // 不见得
+    "qiu2mi2",  // 球迷
+    "gen1",  // 根
+    "shang1xin1",  // 伤心
+    "chou4",  // 臭
+    "jiao4lian4",  // 教练
+    "yan2",  // 盐
+    "chong1fen1",  // 充分
+    "zheng4chang2",  // 正常
+    "zhu3yao4",  // 主要
+    "shang1liang4",  // 商量
+    "zhe2li3",  // 哲理
+    "kai1shui3",  // 开水
+    "qu4shi4",  // 去世
+    "hu2tong2",  // 胡同
+    "dian4chi2",  // 电池
+    "kuai4ji4",  // 会计
+    "shuo1bu4ding4",  // 说不定
+    "bao3liu2",  // 保留
+    "hua1",  // 花
+    "mao2jin1",  // 毛巾
+    "yun1",  // 晕
+    "tian1di2",  // 天敌
+    "zhong4",  // 重
+    "lu3xun4",  // 鲁迅
+    "chi4bang3",  // 翅膀
+    "tiao0pi2",  // 调皮
+    "zhi4liang4",  // 质量
+    "bi3ji4ben3",  // 笔记本
+    "you3guan1",  // 有关
+    "yun4qi4",  // 运气
+    "tai2",  // 抬
+    "gan3xiang3",  // 感想
+    "ju4zi3",  // 句子
+    "chi2dao4",  // 迟到
+    "yu3",  // 与
+    "ya4zhou1",  // 亚洲
+    "da3dian4hua4",  // 打电话
+    "nv3hai2",  // 女孩
+    "ju4shuo1",  // 据说
+    "qian1xu1",  // 谦虚
+    "guan1nian4",  // 观念
+    "nian2yu2",  // 鲇鱼
+    "dian3",  // 点
+    "dui4huan4",  // 兑换
+    "guang3fan4",  // 广泛
+    "zi1xun2",  // 咨询
+    "dai4yu4",  // 待遇
+    "xiu1xi5",  // 休息
+    "jiang4luo4",  // 降落
+    "pai1",  // 拍
+    "bu4nai4fan2",  // 不耐烦
+    "zhong1jian1",  // 中间
+    "fa1",  // 发
+    "ma3sa4zhu1sai4zhou1",  // 马萨诸塞州
+    "cha4ju4",  // 差距
+    "ru2jin1",  // 如今
+    "jie2hun1",  // 结婚
+    "yang3",  // 痒
+    "fang4",  // 放
+    "yuan2",  // 圆
+    "ji2mang2",  // 急忙
+    "ze2ren4",  // 责任
+    "chang2jiang1da4qiao2",  // 长江大桥
+    "xin1xian1",  // 新鲜
+    "shi2ji4",  // 实际
+    "yan3chu1",  // 演出
+    "zi4mu3",  // 字母
+    "qian2tu2",  // 前途
+    "ma2fan2",  // 麻烦
+    "wan2ju4",  // 玩具
+    "shou3biao3",  // 手表
+    "zhi1gan3",  // 知感
+    "sheng4li4",  // 胜利
+    "qian2mian4",  // 前面
+    "bi2zi3",  // 鼻子
+    "li2hun1",  // 离婚
+    "jian1zhi2",  // 兼职
+    "tao3yan4",  // 讨厌
+    "yin4jia1",  // 印加
+    "biao1dian3",  // 标点
+    "ci4ji1",  // 刺激
+    "bu4de2bu4",  // 不得不
+    "cheng2yu3",  // 成语
+    "yang2",  // 杨
+    "sou1suo3",  // 搜索
+    "shi4ban4gong1bei4",  // 事半功倍
+    "chuan2ran3",  // 传染
+    "zhao1san1mu4si4",  // 朝三暮四
+    "xian4mu4",  // 羡慕
+    "mo2fang3",  // 模仿
+    "bai2",  // 白
+    "huo3che1zhan4",  // 火车站
+    "hua1hua1",  // 花花
+    "shen2zhi4",  // 甚至
+    "que4shi2",  // 确实
+    "ji2shi3",  // 即使
+    "bei4jing3",  // 背景
+    "zhu4yi4",  // 注意
+    "fan3hang2",  // 返航
+    "gui1lv4",  // 规律
+    "shou3du0",  // 首都
+    "fan1yi4",  // 翻译
+    "xiang1xin4",  // 相信
+    "wai1wai1niu3niu3",  // 歪歪扭扭
+    "fu4za2",  // 复杂
+    "qiao1",  // 敲
+    "shi4juan4",  // 试卷
+    "hao3qi2",  // 好奇
+    "fa1dou3",  // 发抖
+    "ji1lie4",  // 激烈
+    "ju2hua1",  // 菊花
+    "zui3",  // 嘴
+    "zhu1",  // 猪
+    "luo2bo0",  // 萝卜
+    "zhuang1",  // 装
+    "fang4xin1",  // 放心
+    "xing2zhuang4",  // 形状
+    "you2lai2",  // 由来
+    "you3qu4",  // 有趣
+    "kun4",  // 困
+    "qian2",  // 钱
+    "gu3dian3",  // 古典
+    "guan3zi5",  // 管子
+    "zhuan1ye4",  // 专业
+    "bing4",  // 并
+    "zhuan4",  // 赚
+    "li2kai1",  // 离开
+    "chuan2zhen1",  // 传真
+    "ge1bo2",  // 胳膊
+    "gong1neng2",  // 功能
+    "pi2qi4",  // 脾气
+    "wen1nuan3",  // 温暖
+    "gao1ji2",  // 高级
+    "cong1ming2",  // 聪明
+    "man4",  // 慢
+    "shi2mao2",  // 时髦
+    "jing1shang1",  // 经商
+    "bao1han2",  // 包含
+    "xiang4",  // 项
+    "kuai4zi3",  // 筷子
+    "hai2zi5",  // 孩子
+    "jia3",  // 假
+    "chang2shi2",  // 常识
+    "biao3ge2",  // 表格
+    "chu1ji2zhong1xue2",  // 初级中学
+    "gao4su4",  // 告诉
+    "zhu2zi5",  // 竹子
+    "da3pen1ti4",  // 打喷嚏
+    "wei2fan3",  // 违反
+    "xiang2xi4",  // 详细
+    "che4di3",  // 彻底
+    "zuo4zhan4",  // 作战
+    "ca1",  // 擦
+    "xi3zao3",  // 洗澡
+    "bu4an1",  // 不安
+    "fa2kuan3",  // 罚款
+    "ji2le0",  // 极了
+    "chu2fang2",  // 厨房
+    "ji2",  // 极
+    "ke3neng2",  // 可能
+    "er2",  // 而
+    "jia3she4",  // 假设
+    "zhong4dian3",  // 重点
+    "guai4wu4",  // 怪物
+    "yong4tu2",  // 用途
+    "bu4jia3si1suo3",  // 不假思索
+    "xi1shou1",  // 吸收
+    "hua4",  // 画
+    "feng1lang4",  // 风浪
+    "bu4",  // 布
+    "cheng2guo3",  // 成果
+    "mei3li4",  // 美丽
+    "tu2shu1guan3",  // 图书馆
+    "nei4",  // 内
+    "nian2ling2",  // 年龄
+    "xuan1bu4",  // 宣布
+    "wei4shen2me5",  // 为什么
+    "tai2jie1",  // 台阶
+    "yi2lv4",  // 一律
+    "kan4wang5",  // 看望
+    "hu4lian2wang3",  // 互联网
+    "ying3xiang3",  // 影响
+    "wai1",  // 歪
+    "pu2tao2",  // 葡萄
+    "bian4",  // 遍
+    "nuo4bei4er3jiang3",  // 诺贝尔奖
+    "chuang1",  // 窗
+    "yi4shu4",  // 艺术
+    "zhu3ti2",  // 主题
+    "pi2xie2",  // 皮鞋
+    "shen4shang4xian4su4",  // 肾上腺素
+    "gu3wu3",  // 鼓舞
+    "shi2xian4",  // 实现
+    "wan2zheng3",  // 完整
+    "kong1tiao0",  // 空调
+    "bang4",  // 棒
+    "mei3ren2yu2",  // 美人鱼
+    "wei4",  // 喂
+    "fang4shu3jia3",  // 放暑假
+    "sheng1",  // 升
+    "xia4yu3",  // 下雨
+    "yin3qi3",  // 引起
+    "dao4mei2",  // 倒霉
+    "ke4ting1",  // 客厅
+    "yuan3",  // 远
+    "jian4yi4",  // 建议
+    "zhu3dong4",  // 主动
+    "wan3shang5",  // 晚上
+    "ming4yun4",  // 命运
+    "jing1cai3",  // 精彩
+    "gai3",  // 改
+    "lao3shi1",  // 老师
+    "hua4ti2",  // 话题
+    "guan1zhong4",  // 观众
+    "shi1bai4",  // 失败
+    "shuo4shi4",  // 硕士
+    "chun1you2",  // 春游
+    "yi2han4",  // 遗憾
+    "zhan4",  // 占
+    "zhi3",  // 指
+    "yan3jiang3",  // 演讲
+    "tong2xue2",  // 同学
+    "jun1shi4",  // 军事
+    "du4zi3",  // 肚子
+    "ou1zhou1huan2jing4bao3hu4shu3",  // 欧洲环境保护署
+    "e4lie4",  // 恶劣
+    "quan2li4",  // 权力
+    "jue0se4",  // 角色
+    "jiu3ba0",  // 酒吧
+    "you4bian5",  // 右边
+    "duan3",  // 短
+    "ben3zhi4",  // 本质
+    "fu2",  // 扶
+    "jiao1huan4",  // 交换
+    "ba1cheng2",  // 八成
+    "qin2guo2",  // 秦国
+    "shi3jinr4",  // 使劲儿
+    "mang2ren2",  // 盲人
+    "li4zi3",  // 例子
+    "ou1zhou1",  // 欧洲
+    "yi1zai4",  // 一再
+    "feng1zhong1ding4lv4",  // 峰终定律
+    "qing1",  // 轻
+    "fan1chuan2",  // 帆船
+    "zhe2",  // 折
+    "jin4kou3",  // 进口
+    "gai4kuo4",  // 概括
+    "qi3",  // 起
+    "ken3ding4",  // 肯定
+    "dong1hai3long2wang2",  // 东海龙王
+    "cun2",  // 存
+    "ai1",  // 唉
+    "zan2men0",  // 咱们
+    "lai2de2ji2",  // 来得及
+    "li3jie3",  // 理解
+    "bi4xu1",  // 必须
+    "wen2huo3",  // 文火
+    "hai3yang2",  // 海洋
+    "jie2gou4",  // 结构
+    "xiao4hua4",  // 笑话
+    "chu1kou3",  // 出口
+    "tai4ji2quan2",  // 太极拳
+    "xin1nian2",  // 新年
+    "te4shu1",  // 特殊
+    "xiang4",  // 象
+    "wei1hai4",  // 危害
+    "pei2",  // 陪
+    "huai4",  // 坏
+    "jiang3",  // 讲
+    "qin1qi1",  // 亲戚
+    "chun1jie2",  // 春节
+    "tan2pan4",  // 谈判
+    "jiao1shui3",  // 胶水
+    "shu3yu2",  // 属于
+    "ng4",  // 嗯
+    "shou3du0ji1chang3",  // 首都机场
+    "di4tan3",  // 地毯
+    "ke4",  // 克
+    "da3jiao1dao4",  // 打交道
+    "dun4",  // 顿
+    "shen1ti3",  // 身体
+    "qun2",  // 群
+    "xing1qi1",  // 星期
+    "tao4",  // 套
+    "gan4zao4",  // 干燥
+    "gao3",  // 搞
+    "bu4de2liao0",  // 不得了
+    "tan3shuai4",  // 坦率
+    "yi3zi5",  // 椅子
+    "can1yu3",  // 参与
+    "gong1fu1",  // 功夫
+    "qing1wa1",  // 青蛙
+    "xiang3nian4",  // 想念
+    "ling3dao3",  // 领导
+    "kan4jian4",  // 看见
+    "hu2tu2",  // 糊涂
+    "jiang3zuo4",  // 讲座
+    "ce4suo3",  // 厕所
+    "mei4li4",  // 魅力
+    "lun2",  // 轮
+    "ru4wei2",  // 入围
+    "jin4xing2",  // 进行
+    "dao4xiang1cun1",  // 稻香村
+    "niu2zai3ku4",  // 牛仔裤
+    "yi1lian2",  // 一连
+    "si1hao2",  // 丝毫
+    "sun1zi3",  // 孙子
+    "zao3",  // 早
+    "ye4wu4",  // 业务
+    "zhu3yi4",  // 主意
+    "hou4mian4",  // 后面
+    "he1",  // 喝
+    "zhai1",  // 摘
+    "mo2tuo1che1",  // 摩托车
+    "hu4shi4",  // 护士
+    "ren2shi4",  // 人事
+    "zhong4yao4",  // 重要
+    "zhe4xie1",  // 这些
+    "ai4qing2",  // 爱情
+    "ding1",  // 叮
+    "ban1",  // 般
+    "zhao2liang2",  // 着凉
+    "wan2",  // 玩
+    "bu4guo4",  // 不过
+    "dao3you2",  // 导游
+    "duan4",  // 断
+    "gao1su4gong1lu4",  // 高速公路
+    "shen2hua4",  // 神话
+    "gai3zheng4",  // 改正
+    "kongr0",  // 空儿
+    "ju2zi3",  // 橘子
+    "xing4qu4",  // 兴趣
+    "nian2",  // 年
+    "li4liang4",  // 力量
+    "guan1",  // 关
+    "fen1xiang3",  // 分享
+    "hu4zhao4",  // 护照
+    "gou1tong1",  // 沟通
+    "dao4",  // 倒
+    "xun4su4",  // 迅速
+    "guo2jun1",  // 国君
+    "huang2",  // 黄
+    "li4qi4",  // 力气
+    "mu4tou2",  // 木头
+    "liu2lei4",  // 流泪
+    "gan1",  // 杆
+    "jia1",  // 佳
+    "liu4",  // 六
+    "jiu3",  // 久
+    "jin1shu3",  // 金属
+    "leng3jing4",  // 冷静
+    "tian2kong1",  // 填空
+    "jing3se4",  // 景色
+    "jin1nian2",  // 今年
+    "xin4xin1",  // 信心
+    "rao4",  // 绕
+    "fa1piao4",  // 发票
+    "xue3",  // 雪
+    "jiang4you2",  // 酱油
+    "mo2ter4",  // 模特
+    "cheng2shu2",  // 成熟
+    "zu1",  // 租
+    "yi3",  // 以
+    "gong1cheng2shi1",  // 工程师
+    "jia1fang3",  // 家访
+    "shu2xi1",  // 熟悉
+    "guo4min3",  // 过敏
+    "men2kan3",  // 门槛
+    "xiang3xiang4",  // 想象
+    "sheng1bing4",  // 生病
+    "si1xiang3",  // 思想
+    "ji2he2",  // 集合
+    "tai4yang2",  // 太阳
+    "ci4",  // 次
+    "ji3hu1",  // 几乎
+    "shou3shu4",  // 手术
+    "gu1gu1",  // 姑姑
+    "she4ji4",  // 设计
+    "cheng2li4",  // 成立
+    "lao3shu3",  // 老鼠
+    "bai3fen1zhi1",  // 百分之
+    "luo4shan1ji1",  // 洛杉矶
+    "fu4xi2",  // 复习
+    "shi2wu4",  // 食物
+    "yuan4wang4",  // 愿望
+    "rang4",  // 让
+    "chu1yuan4",  // 出院
+    "ou3er3",  // 偶尔
+    "xie4xie5",  // 谢谢
+    "shou4bu4liao0",  // 受不了
+    "tai4du4",  // 态度
+    "quan2shen2guan4zhu4",  // 全神贯注
+    "xing1xing1",  // 星星
+    "da3lan2qiu2",  // 打篮球
+    "nan2",  // 难
+    "tai4tai4",  // 太太
+    "huang2he2",  // 黄河
+    "tian1zhen1",  // 天真
+    "jian3",  // 捡
+    "mian4bao1",  // 面包
+    "biao3",  // 表
+    "hang2jia5",  // 行家
+    "tian2",  // 填
+    "shou4huo4yuan2",  // 售货员
+    "chuan2",  // 船
+    "ting1",  // 听
+    "you2yu2",  // 由于
+    "san3",  // 伞
+    "ting3",  // 挺
+    "feng1jing3",  // 风景
+    "hua2bei3",  // 华北
+    "ba0",  // 吧
+    "chu4",  // 处
+    "mai3",  // 买
+    "ri4",  // 日
+    "di2ren2",  // 敌人
+    "shou4ming4",  // 寿命
+    "lv3you2",  // 旅游
+    "ben1pao3",  // 奔跑
+    "xiang1gua1",  // 香瓜
+    "hei1xing1xing5",  // 黑猩猩
+    "yi3ji2",  // 以及
+    "gua4",  // 挂
+    "a0",  // 啊
+    "zong3cai2",  // 总裁
+    "feng1ge2",  // 风格
+    "cheng2jiu4",  // 成就
+    "liu2lan3",  // 浏览
+    "liang2kuai4",  // 凉快
+    "zuo4jia1",  // 作家
+    "chuang3",  // 闯
+    "zhe2die2",  // 折叠
+    "tu1ran2",  // 突然
+    "xiao3xin1",  // 小心
+    "tou4ming2",  // 透明
+    "shi3yong4",  // 使用
+    "dao4li3",  // 道理
+    "si1",  // 丝
+    "jiang4di1",  // 降低
+    "jia4shi3",  // 驾驶
+    "ping2",  // 凭
+    "yu4fang2",  // 预防
+    "lang4fei4",  // 浪费
+    "lei4",  // 累
+    "bo2wu4guan3",  // 博物馆
+    "dou4fu5",  // 豆腐
+    "hua4xue2",  // 化学
+    "jing1li4",  // 经历
+    "xue2shu4",  // 学术
+    "wan4zhang4",  // 万丈
+    "kuan1",  // 宽
+    "ling2huo2",  // 灵活
+    "tao2bi4",  // 逃避
+    "jiao3",  // 角
+    "geng4",  // 更
+    "ming4ling4",  // 命令
+    "nian2ji2",  // 年级
+    "lai2zi4",  // 来自
+    "hui1gu1niang2",  // 灰姑娘
+    "ri4ji4",  // 日记
+    "cong2",  // 从
+    "ji4mo4",  // 寂寞
+    "she3bu4de2",  // 舍不得
+    "yue4",  // 月
+    "la1",  // 拉
+    "wu3dao3",  // 舞蹈
+    "wei1xiao4",  // 微笑
+    "dian1qiu2",  // 颠球
+    "sang3zi5",  // 嗓子
+    "jin4",  // 尽
+    "chou3",  // 丑
+    "ban4",  // 半
+    "shang4dang1",  // 上当
+    "shu4",  // 数
+    "cong1mang2",  // 匆忙
+    "hu1shi4",  // 忽视
+    "hao4ma3",  // 号码
+    "tou2lan2",  // 投篮
+    "hen3",  // 很
+    "huo4zhe3",  // 或者
+    "zi3xi4",  // 仔细
+    "zong3li3",  // 总理
+    "ke3jian4",  // 可见
+    "huai2nian4",  // 怀念
+    "ke3yi3",  // 可以
+    "kou3wei4",  // 口味
+    "quan2li4",  // 权利
+    "fen1pei4",  // 分配
+    "fang1fa3",  // 方法
+    "re4qing2",  // 热情
+    "mei2you3",  // 没有
+    "zuo4",  // 做
+    "huo4de2",  // 获得
+    "jian4wei3",  // 健伟
+    "shi2tou2",  // 石头
+    "fou3ze2",  // 否则
+    "di3",  // 底
+    "bao3xian3",  // 保险
+    "dui4xiang4",  // 对象
+    "ying3zi3",  // 影子
+    "ze2bei4",  // 责备
+    "jue2ding4",  // 决定
+    "ban4ye4",  // 半夜
+    "zhu3",  // 煮
+    "xi1",  // 西
+    "ping2jing4",  // 平静
+    "zhi1gan4",  // 枝干
+    "cha1zi5",  // 叉子
+    "hang2ye4",  // 行业
+    "ying1yong4",  // 应用
+    "lai2",  // 来
+    "ci2yu3",  // 词语
+    "chu1fa1",  // 出发
+    "gong1zuo4",  // 工作
+    "jiao3du4",  // 角度
+    "si1chou2",  // 丝绸
+    "xiang1dui4",  // 相对
+    "qian4",  // 欠
+    "guan3li3",  // 管理
+    "dian4ying3",  // 电影
+    "wei4",  // 为
+    "kuai4",  // 快
+    "shan1chu2",  // 删除
+    "gao1",  // 高
+    "xiang1fang2",  // 厢房
+    "yan2su4",  // 严肃
+    "zhi2zhao4",  // 执照
+    "zhuo1zi5",  // 桌子
+    "leng3",  // 冷
+    "nan2shou4",  // 难受
+    "mao4yi4",  // 贸易
+    "kai1xin1",  // 开心
+    "guang4",  // 逛
+    "ying2",  // 赢
+    "shi2qi1",  // 时期
+    "dao3zhi4",  // 导致
+    "ba3",  // 把
+    "tou2zi1",  // 投资
+    "ming2ming2",  // 明明
+    "shang4wu3",  // 上午
+    "nu3li4",  // 努力
+    "xian4",  // 县
+    "te4bie2",  // 特别
+    "gen1",  // 跟
+    "ye3xu3",  // 也许
+    "zhao1",  // 招
+    "lao3shi5",  // 老实
+    "cong2er2",  // 从而
+    "jiao1ji4",  // 交际
+    "jing1li4",  // 精力
+    "zai1hai4",  // 灾害
+    "bao4dao4",  // 报道
+    "bu3chong1",  // 补充
+    "you1jiu3",  // 悠久
+    "ming2pian4",  // 名片
+    "gu4ding4",  // 固定
+    "you1you1",  // 悠悠
+    "tang4",  // 趟
+    "cai3qu3",  // 采取
+    "shi1wang4",  // 失望
+    "sun3shi1",  // 损失
+    "xian1yan4",  // 鲜艳
+    "yue4",  // 越
+    "jiu4",  // 就
+    "jie2sheng3",  // 节省
+    "dan1ren4",  // 担任
+    "yi4dianr3",  // 一点儿
+    "zhao4pian4",  // 照片
+    "pian1",  // 篇
+    "guang3chang3",  // 广场
+    "qi3lai2",  // 起来
+    "zhen4dong4",  // 振动
+    "an1jing4",  // 安静
+    "zhou1",  // 周
+    "ji1chu3",  // 基础
+    "wu4zhi4",  // 物质
+    "po4chan3",  // 破产
+    "chong0xin1",  // 重新
+    "yi3hou4",  // 以后
+    "re4lie4",  // 热烈
+    "a1yi2",  // 阿姨
+    "fan3er2",  // 反而
+    "shou3zhi3",  // 手指
+    "wan2cheng2",  // 完成
+    "xing2shi4",  // 形式
+    "ji1xu4",  // 积蓄
+    "qi1",  // 七
+    "biao1zhun3",  // 标准
+    "fang1",  // 方
+    "dao3yu3",  // 岛屿
+    "liang2hao3",  // 良好
+    "lao3hu3",  // 老虎
+    "ping2jun1",  // 平均
+    "pei0zhun3",  // 批准
+    "xiao4lv4",  // 效率
+    "an1pai2",  // 安排
+    "shi1run4",  // 湿润
+    "gong4xian4",  // 贡献
+    "xing2cheng2",  // 形成
+    "si1ji1",  // 司机
+    "hai2shi4",  // 还是
+    "ti2wen4",  // 提问
+    "cai2chan3",  // 财产
+    "dai4kuan3",  // 贷款
+    "xiang4lian4",  // 项链
+    "que1fa2",  // 缺乏
+    "shui4jiao4",  // 睡觉
+    "zhen1zheng4",  // 真正
+    "shou3bi3",  // 手笔
+    "xi1hong2shi4",  // 西红柿
+    "lv4",  // 绿
+    "di4dian3",  // 地点
+    "ben3ke1",  // 本科
+    "guang1pan2",  // 光盘
+    "xiao3qu1",  // 小区
+    "gou4",  // 购
+    "chao3",  // 吵
+    "gong1xi3",  // 恭喜
+    "liang2cha2",  // 凉茶
+    "shi3",  // 使
+    "tong2yang4",  // 同样
+    "bu4ru2",  // 不如
+    "teng2ai4",  // 疼爱
+    "qi2ta1",  // 其他
+    "hun1yin1",  // 婚姻
+    "huai2yi2",  // 怀疑
+    "zhu0ming2",  // 著名
+    "ti2qian2",  // 提前
+    "li4yi4",  // 利益
+    "yu4dao4",  // 遇到
+    "shuang1",  // 双
+    "qiao3miao4",  // 巧妙
+    "qing3qiu2",  // 请求
+    "zhu4",  // 住
+    "chou3lou4",  // 丑陋
+    "mei3",  // 每
+    "mei3hao3",  // 美好
+    "zhu3ren4",  // 主任
+    "jiao3hua2",  // 狡猾
+    "fa1biao3",  // 发表
+    "hun1li3",  // 婚礼
+    "sheng1chan3",  // 生产
+    "yin1ci3",  // 因此
+    "jie2shi2",  // 节食
+    "hu1ran2",  // 忽然
+    "zheng4zai4",  // 正在
+    "gu4ke4",  // 顾客
+    "fan2nao3",  // 烦恼
+    "ka3che1",  // 卡车
+    "zhi4liao2",  // 治疗
+    "zhuan1men2",  // 专门
+    "ping2heng2",  // 平衡
+    "qi4wen1",  // 气温
+    "ying1jun4",  // 英俊
+    "xue2xi2",  // 学习
+    "dong1",  // 东
+    "zhao2huo3",  // 着火
+    "sui2bian4",  // 随便
+    "lian2huan2hua4",  // 连环画
+    "yu4xi2",  // 预习
+    "shou3sui4",  // 守岁
+    "ge4zi4",  // 各自
+    "nv3shi4",  // 女士
+    "ge4bie2",  // 个别
+    "shi1mian2",  // 失眠
+    "cheng2du4",  // 程度
+    "yue4liang4",  // 月亮
+    "jing4zi3",  // 镜子
+    "xiang1chang2",  // 香肠
+    "zhi1jiao4",  // 支教
+    "bao1",  // 包
+    "di4tu2",  // 地图
+    "kai1shi3",  // 开始
+    "shang4ban1",  // 上班
+    "di4",  // 递
+    "qing2",  // 晴
+    "jiao1ao4",  // 骄傲
+    "er2qie3",  // 而且
+    "liu2shou3",  // 留守
+    "you1mei3",  // 优美
+    "cheng2ren4",  // 承认
+    "jue2de5",  // 觉得
+    "fen3",  // 粉
+    "zhao1dai4",  // 招待
+    "ye2ye2",  // 爷爷
+    "ti2gong1",  // 提供
+    "jian1chi2",  // 坚持
+    "shi2ke4",  // 时刻
+    "hu1peng2huan4you3",  // 呼朋唤友
+    "di4dao4",  // 地道
+    "tong2shi2",  // 同时
+    "gu3zhang3",  // 鼓掌
+    "fen1zhong1",  // 分钟
+    "cui1",  // 催
+    "re4nao4",  // 热闹
+    "huan4nan4yu3gong4",  // 患难与共
+    "chuang1lian2",  // 窗帘
+    "guo2qing4jie2",  // 国庆节
+    "da4wei4",  // 大卫
+    "li2",  // 梨
+    "fu4mu3",  // 父母
+    "sheng1qi4",  // 生气
+    "er2zi5",  // 儿子
+    "ri4qi1",  // 日期
+    "xiang4qi2",  // 象棋
+    "shi4jie4",  // 世界
+    "bian4",  // 便
+    "zi4sha1",  // 自杀
+    "bu4",  // 不
+    "gong1chang3",  // 工厂
+    "zu3",  // 组
+    "xue2",  // 学
+    "fu2",  // 幅
+    "cha4",  // 差
+    "gai4nian4",  // 概念
+    "tong2qing2",  // 同情
+    "fa1zhan3",  // 发展
+    "zhao4xiang1ji1",  // 照相机
+    "ji1hui4",  // 机会
+    "wang3wang3",  // 往往
+    "pai4",  // 派
+    "shang4wang3",  // 上网
+    "wanr2",  // 玩儿
+    "bo2zi3",  // 脖子
+    "leng3dan4",  // 冷淡
+    "nuan3huo0",  // 暖和
+    "piao1",  // 飘
+    "dang1shi2",  // 当时
+    "hai3xian1",  // 海鲜
+    "shou3xian1",  // 首先
+    "wei1xin4",  // 微信
+    "shi4de0",  // 似的
+    "shu1shu1",  // 叔叔
+    "yu4yan2",  // 寓言
+    "jing1li3",  // 经理
+    "pei4he2",  // 配合
+    "xiao1xi1",  // 消息
+    "guan1bi4",  // 关闭
+    "ri4yong4pin3",  // 日用品
+    "ming2xing1",  // 明星
+    "cu1xin1",  // 粗心
+    "wei4",  // 位
+    "ji1dong4",  // 激动
+    "yao4shi4",  // 要是
+    "gan3",  // 敢
+    "bin1guan3",  // 宾馆
+    "lao2jia4",  // 劳驾
+    "zheng1",  // 正
+    "dan1du2",  // 单独
+    "he2kuang4",  // 何况
+    "zhua1jin3",  // 抓紧
+    "bian1ji2",  // 编辑
+    "zuo4ke4",  // 做客
+    "hu1xi1",  // 呼吸
+    "jin3zhang1",  // 紧张
+    "ke3kao4",  // 可靠
+    "you2ju2",  // 邮局
+    "tuo1",  // 脱
+    "qing2jing3",  // 情景
+    "xia4",  // 夏
+    "gui1ju3",  // 规矩
+    "diao4cha2",  // 调查
+    "yi3qian2",  // 以前
+    "zu3cheng2",  // 组成
+    "peng2",  // 朋
+    "te4dian3",  // 特点
+    "gao1dang4",  // 高档
+    "ke4ren2",  // 客人
+    "bao4she4",  // 报社
+    "zhao2ji2",  // 着急
+    "xiang4",  // 像
+    "jiao4cai2",  // 教材
+    "shen2mi4",  // 神秘
+    "lian2he2",  // 联合
+    "chang4ge1",  // 唱歌
+    "ming2",  // 明
+    "gan4jing4",  // 干净
+    "zhou1dao4",  // 周到
+    "shi2hui1yan2",  // 石灰岩
+    "qi3ye4",  // 企业
+    "duo1",  // 多
+    "zai4hu1",  // 在乎
+    "fan4dian4",  // 饭店
+    "dao3yan3",  // 导演
+    "ju4jue2",  // 拒绝
+    "dao4shi2hou4",  // 到时候
+    "zhi2wu4",  // 植物
+    "yue1hui4",  // 约会
+    "hei1ban3",  // 黑板
+    "mei2tan4",  // 煤炭
+    "bao3hu4",  // 保护
+    "tian1kong1",  // 天空
+    "lang3du2",  // 朗读
+    "zhen1shi2",  // 真实
+    "tao3jia4huan2jia4",  // 讨价还价
+    "yin1liang2",  // 荫凉
+    "pan4wang4",  // 盼望
+    "ri4li4",  // 日历
+    "can1kao3",  // 参考
+    "dai4zi3",  // 袋子
+    "xing3",  // 醒
+    "chuang4zao4",  // 创造
+    "qin2fen4",  // 勤奋
+    "qu3xiao1",  // 取消
+    "hao2hua2",  // 豪华
+    "sui4",  // 岁
+    "lu4",  // 路
+    "guan1zhu4",  // 关注
+    "qian1",  // 签
+    "man3zu2",  // 满足
+    "suo3",  // 锁
+    "chi1kui1",  // 吃亏
+    "jian4li4",  // 建立
+    "duan4duan4xu4xu4",  // 断断续续
+    "zhuang4tai4",  // 状态
+    "lun2liu2",  // 轮流
+    "xi1wang4",  // 希望
+    "gu3li4",  // 鼓励
+    "shu4ju4",  // 数据
+    "lu4qu3",  // 录取
+    "xi1qu3",  // 吸取
+    "shuo1",  // 说
+    "tui4",  // 退
+    "wen2ming2",  // 文明
+    "bing1ji1ling2",  // 冰激凌
+    "wang3qiu2",  // 网球
+    "zhi3hui1",  // 指挥
+    "ti2chang4",  // 提倡
+    "shang1dian4",  // 商店
+    "min2zu2",  // 民族
+    "ji4lu4",  // 记录
+    "ju3",  // 举
+    "zheng1qu3",  // 争取
+    "bie2ren2",  // 别人
+    "zhi4leng3",  // 制冷
+    "jian1ju4",  // 艰巨
+    "ma1ma5",  // 妈妈
+    "ren4wu4",  // 任务
+    "sheng1ming4",  // 生命
+    "da4fang1",  // 大方
+    "duo1kui1",  // 多亏
+    "huai2yun4",  // 怀孕
+    "qi3fa1",  // 启发
+    "xing2",  // 行
+    "dao4qian4",  // 道歉
+    "dan1diao4",  // 单调
+    "que4",  // 却
+    "dian4ti1",  // 电梯
+    "lin2ju1",  // 邻居
+    "lin2",  // 林
+    "qi3ju1",  // 起居
+    "ru2he2",  // 如何
+    "jin4li4",  // 尽力
+    "kao3ya1",  // 烤鸭
+    "yi1sheng1",  // 医生
+    "liu2chen2",  // 刘辰
+    "an4zhao4",  // 按照
+    "gou4cheng2",  // 构成
+    "bao3",  // 保
+    "guo2ji2",  // 国籍
+    "dao4",  // 到
+    "bing4du2",  // 病毒
+    "dui1",  // 堆
+    "hu4guo2si4",  // 护国寺
+    "gong1bu4",  // 公布
+    "man2tou2",  // 馒头
+    "shi2zai4",  // 实在
+    "chang3",  // 场
+    "hu2",  // 糊
+    "xiang1",  // 香
+    "xin1li3",  // 心理
+    "jia1xiang1",  // 家乡
+    "xing2shi4",  // 形势
+    "sheng4",  // 剩
+    "jiu4jiu4",  // 舅舅
+    "dao4chu4",  // 到处
+    "mu4lu4",  // 目录
+    "xin1qing2",  // 心情
+    "bei3jing1shi1fan4da4xue2",  // 北京师范大学
+    "wo4shou3",  // 握手
+    "xing4fu2",  // 幸福
+    "guang1lin2",  // 光临
+    "qu3",  // 娶
+    "di4fang1",  // 地方
+    "mu3qin1",  // 母亲
+    "kong3pa4",  // 恐怕
+    "gang1cai2",  // 刚才
+    "ying1xiong2",  // 英雄
+    "qi1zi5",  // 妻子
+    "zhao4",  // 照
+    "jue2dui4",  // 绝对
+    "tong1guo4",  // 通过
+    "ming2sheng4gu3ji4",  // 名胜古迹
+    "fei4hua4",  // 废话
+    "bu4xing2",  // 不行
+    "tian1an1men2dong1",  // 天安门东
+    "dao4di3",  // 到底
+    "teng2xun4",  // 腾讯
+    "nv3er2",  // 女儿
+    "piao4liang5",  // 漂亮
+    "cheng2zuo4",  // 乘坐
+    "wu4li3",  // 物理
+    "yu4bao4",  // 预报
+    "wai4di4",  // 外地
+    "xiao4shun4",  // 孝顺
+    "ji4lv4",  // 纪律
+    "jia3ru2",  // 假如
+    "lan3",  // 懒
+    "san4bu4",  // 散步
+    "ben3lai2",  // 本来
+    "chun1qiu1",  // 春秋
+    "fa1yan2",  // 发言
+    "yin4xiang4",  // 印象
+    "xiao1fei4",  // 消费
+    "da4yue1",  // 大约
+    "shu1",  // 书
+    "di4di5",  // 弟弟
+    "xiu1xian2",  // 休闲
+    "shi2shang4",  // 时尚
+    "wu2suo3wei4",  // 无所谓
+    "xiu1li3",  // 修理
+    "yi4qi3",  // 一起
+    "zhan3lan3",  // 展览
+    "fang1bian4",  // 方便
+    "jian4zhu4",  // 建筑
+    "tai4",  // 太
+    "gui1ding4",  // 规定
+    "zhao4kuo4",  // 赵括
+    "lao3lao3",  // 姥姥
+    "wai4",  // 外
+    "di4qu1",  // 地区
+    "dun1",  // 吨
+    "wei4dao4",  // 味道
+    "tong4kuai4",  // 痛快
+    "gen1zhi4",  // 根治
+    "jian4bu4ru2fei1",  // 健步如飞
+    "zhuan1zhu4",  // 专注
+    "wang2zi3",  // 王子
+    "wen1rou2",  // 温柔
+    "ke4cheng2",  // 课程
+    "shui3guo3",  // 水果
+    "biao3da2",  // 表达
+    "pa2shan1",  // 爬山
+    "zai4",  // 再
+    "fang1shi4",  // 方式
+    "teng2",  // 疼
+    "qiang3",  // 抢
+    "ren4wei4",  // 认为
+    "fa1ming2",  // 发明
+    "guang3dong1sheng3",  // 广东省
+    "lan2gan1",  // 栏杆
+    "liu2xue2",  // 留学
+    "qing1dan4",  // 清淡
+    "cheng2wei4",  // 成为
+    "you3xian4",  // 有限
+    "yi1ban1lai2shuo1",  // 一般来说
+    "guan1",  // 官
+    "huo2yue4",  // 活跃
+    "qi2zhong1",  // 其中
+    "long2",  // 龙
+    "dai4",  // 带
+    "qi3fei1",  // 起飞
+    "juan1",  // 捐
+    "fa1hui1",  // 发挥
+    "chu2fei1",  // 除非
+    "li3guang3",  // 李广
+    "kuai4",  // 块
+    "zheng3li3",  // 整理
+    "sheng1wu4zhong1",  // 生物钟
+    "wei4yu2",  // 位于
+    "xin1",  // 新
+    "tao2qi4",  // 淘气
+    "hao3xiang4",  // 好像
+    "ri4chang2",  // 日常
+    "de2dao4",  // 得到
+    "xiao3shuo1",  // 小说
+    "gun3",  // 滚
+    "guang3da4",  // 广大
+    "kong3zi3",  // 孔子
+    "fang3fu2",  // 仿佛
+    "mei3",  // 美
+    "lian2",  // 连
+    "zi1yuan2",  // 资源
+    "dui4hua4",  // 对话
+    "shu1shi4",  // 舒适
+    "qian1zheng4",  // 签证
+    "kun1chong2",  // 昆虫
+    "jie2lun4",  // 结论
+    "chang2",  // 尝
+    "wen4ti2",  // 问题
+    "jie1dai4",  // 接待
+    "zheng4que4",  // 正确
+    "shi2xi2",  // 实习
+    "yi2xia4",  // 一下
+    "shou1",  // 收
+    "mian4shi4",  // 面试
+    "sheng2zi3",  // 绳子
+    "ji4suan4",  // 计算
+    "bai3",  // 摆
+    "jia1zi5",  // 夹子
+    "dan3xiao3gui3",  // 胆小鬼
+    "zhun3shi2",  // 准时
+    "ye3",  // 也
+    "wen3",  // 吻
+    "pi2fu1",  // 皮肤
+    "wei3da4",  // 伟大
+    "lu4di4",  // 陆地
+    "suo1duan3",  // 缩短
+    "can2kui4",  // 惭愧
+    "qi1dai4",  // 期待
+    "duo3",  // 朵
+    "xian3ran2",  // 显然
+    "bao4zhi3",  // 报纸
+    "cheng2shi2",  // 诚实
+    "zheng3ge4",  // 整个
+    "wang3zhan4",  // 网站
+    "she4shi1",  // 设施
+    "ying1fu4",  // 应付
+    "tong1zhi1",  // 通知
+    "yuan2ze2",  // 原则
+    "si4he2yuan4",  // 四合院
+    "qing1shao4nian2",  // 青少年
+    "shan4liang2",  // 善良
+    "xiao1shi1",  // 消失
+    "you3yi4si1",  // 有意思
+    "yuan2",  // 元
+    "bi3",  // 比
+    "wu3",  // 五
+    "liu4yi1er2tong2jie2",  // 六一儿童节
+    "na4ru4",  // 纳入
+    "ci2dian3",  // 词典
+    "xian4zhi4",  // 限制
+    "cai3yong4",  // 采用
+    "pian2yi5",  // 便宜
+    "kong1qi4",  // 空气
+    "zuo4",  // 座
+    "mao4",  // 冒
+    "bao3zheng4",  // 保证
+    "bang1zhu4",  // 帮助
+    "ju4da4",  // 巨大
+    "yan2zhong4",  // 严重
+    "gan3ji1",  // 感激
+    "yao2",  // 摇
+    "ben3",  // 本
+    "hou4",  // 后
+    "zi4mu4",  // 字幕
+    "bi4ye4",  // 毕业
+    "fu4kuan3",  // 付款
+    "wang4sheng4",  // 旺盛
+    "piao4",  // 票
+    "da2dao4",  // 达到
+    "xiao1shou4",  // 销售
+    "dong4zuo4",  // 动作
+    "jiu4",  // 救
+    "zu3he2",  // 组合
+    "yang2xiong2",  // 杨雄
+    "jue2xin1",  // 决心
+    "wen4hou4",  // 问候
+    "yi3lai2",  // 以来
+    "bi4ran2",  // 必然
+    "bu4shao3",  // 不少
+    "zui4jin4",  // 最近
+    "can1jia1",  // 参加
+    "yi4wu4",  // 义务
+    "guo2",  // 国
+    "ren4",  // 认
+    "zhen3duan4",  // 诊断
+    "zong3jie2",  // 总结
+    "meng4xiang3",  // 梦想
+    "she4ying3",  // 摄影
+    "xie1",  // 歇
+    "ping2guo3",  // 苹果
+    "jian3fei2",  // 减肥
+    "shou3gong1",  // 手工
+    "gu3tou2",  // 骨头
+    "ma0",  // 吗
+    "ju3ban4",  // 举办
+    "bu4duan4",  // 不断
+    "zan2",  // 咱
+    "guo4cheng2",  // 过程
+    "dan1xin1",  // 担心
+    "mao4zi3",  // 帽子
+    "gong1zi1",  // 工资
+    "man3",  // 满
+    "xiao3qi5",  // 小气
+    "qiu1",  // 秋
+    "guo2mao4",  // 国贸
+    "yan3jing4",  // 眼镜
+    "wu2nai4",  // 无奈
+    "jiang1lai2",  // 将来
+    "fu3dao3",  // 辅导
+    "xiang1jiao1",  // 香蕉
+    "tiao2jian4",  // 条件
+    "tian1",  // 天
+    "wen2wen2",  // 文文
+    "zi4xin4",  // 自信
+    "xuan3ze2",  // 选择
+    "ti2",  // 题
+    "fei1ji1",  // 飞机
+    "wan2mei3",  // 完美
+    "chao1shi4",  // 超市
+    "tiao1zhan4",  // 挑战
+    "zhi3hao3",  // 只好
+    "jia4qian2",  // 价钱
+    "chu3guo2",  // 楚国
+    "bei4",  // 被
+    "ling2",  // 铃
+    "fang4qi4",  // 放弃
+    "ba4ba5",  // 爸爸
+    "hang2ban1",  // 航班
+    "bi3shi4",  // 笔试
+    "cuo4shi1",  // 措施
+    "gang1tie3",  // 钢铁
+    "xin4feng1",  // 信封
+    "ma3ke4",  // 马克
+    "cai4dan1",  // 菜单
+    "fang4song1",  // 放松
+    "zen3me5",  // 怎么
+    "qin1zi4",  // 亲自
+    "xi2guan4",  // 习惯
+    "di1",  // 滴
+    "zhan4guo2",  // 战国
+    "zhi4du4",  // 制度
+    "you0dianr0",  // 有点儿
+    "zang4",  // 脏
+    "yong1bao4",  // 拥抱
+    "hui4yi4",  // 会议
+    "kua1",  // 夸
+    "yi4jian4",  // 意见
+    "duan4",  // 段
+    "guang1ming2",  // 光明
+    "ou3ran2",  // 偶然
+    "li4ru2",  // 例如
+    "cao1xin1",  // 操心
+    "xian1sheng5",  // 先生
+    "guai4bu5de5",  // 怪不得
+    "jie2",  // 节
+    "chu1xian4",  // 出现
+    "su4liao4dai4",  // 塑料袋
+    "qing2lv3",  // 情侣
+    "xie3zuo4",  // 写作
+    "zhuang1xiu1",  // 装修
+    "ze2",  // 则
+    "kong1xian2",  // 空闲
+    "san1",  // 三
+    "zhi4jin1",  // 至今
+    "xiao4guo3",  // 效果
+    "hui2yi4",  // 回忆
+    "feng1fu4",  // 丰富
+    "meng2long2",  // 蒙眬
+    "lian2xi4",  // 联系
+    "jiao4",  // 叫
+    "gan3",  // 擀
+    "fan3fu4",  // 反复
+    "fa3lv4",  // 法律
+    "di1luo4",  // 低落
+    "zhuan1xin1",  // 专心
+    "da4sha4",  // 大厦
+    "suo3wei4",  // 所谓
+    "huo3chai2",  // 火柴
+    "shao2",  // 勺
+    "wo4shi4",  // 卧室
+    "sha1tan1",  // 沙滩
+    "zu3zhi1",  // 组织
+    "shu2lian4",  // 熟练
+    "yi4yi4",  // 意义
+    "jiao3",  // 脚
+    "lao3ying1",  // 老鹰
+    "guan1yu2",  // 关于
+    "gan3jin3",  // 赶紧
+    "cai2yi4",  // 才艺
+    "jiao1liu2",  // 交流
+    "nian4",  // 念
+    "fu4nv3",  // 妇女
+    "shao3",  // 少
+    "xi4ju4",  // 戏剧
+    "hai2",  // 还
+    "huang1zhang1",  // 慌张
+    "chu1",  // 出
+    "yao4",  // 要
+    "bao3gui4",  // 宝贵
+    "shang3xin1yue4mu4",  // 赏心悦目
+    "wang2",  // 王
+    "gao4bie2",  // 告别
+    "da3",  // 打
+    "wu2lun4",  // 无论
+    "yin4shua1",  // 印刷
+    "mai4bu4li3qi2",  // 麦布里奇
+    "xian4zai4",  // 现在
+    "na4",  // 那
+    "gan3dong4",  // 感动
+    "zhuang4",  // 撞
+    "cai4shi4kou3",  // 菜市口
+    "yue4du2",  // 阅读
+    "ti2gang1",  // 提纲
+    "ye4",  // 页
+    "chou1",  // 抽
+    "xiao3shi2",  // 小时
+    "ti3yan4",  // 体验
+    "you1shi4",  // 优势
+    "qing1chun1",  // 青春
+    "xiang3",  // 想
+    "shang1ye4",  // 商业
+    "ji1ben3",  // 基本
+    "jiao3zi3",  // 饺子
+    "fa3yuan4",  // 法院
+    "hua2",  // 划
+    "shi2dai4",  // 时代
+    "yue4qi4",  // 乐器
+    "hong2",  // 红
+    "ming2bai2",  // 明白
+    "ai4xin1",  // 爱心
+    "wu1",  // 屋
+    "ci2hui4",  // 词汇
+    "ti3xian4",  // 体现
+    "jin3ji2",  // 紧急
+    "zong3shi4",  // 总是
+    "you2zha4",  // 油炸
+    "kai1fang4",  // 开放
+    "wen2",  // 闻
+    "wei1bo2",  // 微博
+    "fu2he2",  // 符合
+    "jian3dao1",  // 剪刀
+    "dian4nao3",  // 电脑
+    "ping2deng3",  // 平等
+    "fang1an4",  // 方案
+    "mai4ke4feng1",  // 麦克风
+    "yin1su4",  // 因素
+    "tu1chu1",  // 突出
+    "jie2zhang4",  // 结账
+    "di0que4",  // 的确
+    "tang4",  // 烫
+    "de0",  // 的
+    "suan4",  // 算
+    "he2xin1",  // 核心
+    "xia4wei1yi2",  // 夏威夷
+    "cheng2ren2",  // 成人
+    "chao1",  // 抄
+    "ju4ti3",  // 具体
+    "chi1fan4",  // 吃饭
+    "ke3lian2",  // 可怜
+    "tu3di4",  // 土地
+    "fu2zhuang1",  // 服装
+    "xiang1dang1",  // 相当
+    "pi1",  // 批
+    "mei2",  // 没
+    "yuan2lai2",  // 原来
+    "dian4zi3you2jian4",  // 电子邮件
+    "peng4",  // 碰
+    "he2",  // 和
+    "liang4",  // 亮
+    "she4ji1",  // 射击
+    "ye4",  // 夜
+    "da3yin4",  // 打印
+    "zi4xing2che1",  // 自行车
+    "zheng1qiu2",  // 征求
+    "tao3lun4",  // 讨论
+    "gui1ze2",  // 规则
+    "shui3",  // 水
+    "dao4da2",  // 到达
+    "tan0gang1qin2",  // 弹钢琴
+    "zhu3chi2",  // 主持
+    "ji4yi4",  // 记忆
+    "chu1zhong1",  // 初中
+    "jiao1wang3",  // 交往
+    "mi4ma3",  // 密码
+    "min2ju1",  // 民居
+    "fu4qin1",  // 父亲
+    "zhun3que4",  // 准确
+    "shi4dang1",  // 适当
+    "huan4",  // 换
+    "tan2",  // 痰
+    "han4",  // 汗
+    "ku3",  // 苦
+    "jian3",  // 减
+    "fen1xi1",  // 分析
+    "xi4tong3",  // 系统
+    "sha3",  // 傻
+    "zun1shou3",  // 遵守
+    "deng3dai4",  // 等待
+    "qian2bao1",  // 钱包
+    "sha1mo4",  // 沙漠
+    "lian4xi2",  // 练习
+    "qi1lang2",  // 七郎
+    "chong0fu4",  // 重复
+    "qing1yi4",  // 轻易
+    "gui4",  // 贵
+    "ming2tian1",  // 明天
+    "sheng3",  // 省
+    "te4zheng1",  // 特征
+    "guan1xin1",  // 关心
+    "shu1cai4",  // 蔬菜
+    "ben3ling3",  // 本领
+    "chu1shi4",  // 出示
+    "chu1chai0",  // 出差
+    "you3xiao4",  // 有效
+    "si1",  // 私
+    "bao4yu3",  // 暴雨
+    "lan4",  // 烂
+    "zan4shi2",  // 暂时
+    "xiao4xiao4",  // 笑笑
+    "xing4ge2",  // 性格
+    "xiao4zhang3",  // 校长
+    "yi1ran2",  // 依然
+    "fang2jian1",  // 房间
+    "tou2fa1",  // 头发
+    "xin1ku3",  // 辛苦
+    "dan1wu4",  // 耽误
+    "mou3",  // 某
+    "bao3chi2",  // 保持
+    "shang4hai3",  // 上海
+    "hui4lv4",  // 汇率
+    "pang4",  // 胖
+    "qiao3ke4li4",  // 巧克力
+    "mei4mei5",  // 妹妹
+    "ni3men0",  // 你们
+    "chui1",  // 吹
+    "dong4",  // 冻
+    "ren2sheng1",  // 人生
+    "you3ming2",  // 有名
+    "wo3men5",  // 我们
+    "cun2huo2",  // 存活
+    "shua1ya2",  // 刷牙
+    "jian4pan2",  // 键盘
+    "jiang3jin1",  // 奖金
+    "guai1",  // 乖
+    "chong3wu4",  // 宠物
+    "ji4jie2",  // 季节
+    "li2",  // 离
+    "zhuan3bian4",  // 转变
+    "zhui1qiu2",  // 追求
+    "jing4",  // 静
+    "zuo4wei4",  // 座位
+    "he2tong5",  // 合同
+    "ke3le4",  // 可乐
+    "zhu4",  // 祝
+    "fan4",  // 犯
+    "da3gong1",  // 打工
+    "wu2liao2",  // 无聊
+    "ku4zi3",  // 裤子
+    "jiu1jing4",  // 究竟
+    "ren4shi5",  // 认识
+    "gao1xing4",  // 高兴
+    "hua2yi4",  // 华裔
+    "duo3cang2",  // 躲藏
+    "fan2rong2",  // 繁荣
+    "cheng2xu4",  // 程序
+    "you1mo4",  // 幽默
+    "kan4fa3",  // 看法
+    "ji1qi4",  // 机器
+    "zhou1mo4",  // 周末
+    "luan4",  // 乱
+    "chang2tu2",  // 长途
+    "hou2zi3",  // 猴子
+    "you1hui4",  // 优惠
+    "hou4lai2",  // 后来
+    "zun1jing4",  // 尊敬
+    "zhi3shang4tan2bing1",  // 纸上谈兵
+    "hao3chi1",  // 好吃
+    "wei4yang3",  // 喂养
+    "ji1rou4",  // 肌肉
+    "shi1",  // 诗
+    "ran2shao1",  // 燃烧
+    "fen1shou3",  // 分手
+    "zhu2jian4",  // 逐渐
+    "xiang4mu4",  // 项目
+    "shi4xian1",  // 事先
+    "ke1",  // 棵
+    "ge4ren2",  // 个人
+    "ao2ye4",  // 熬夜
+    "la0",  // 啦
+    "tou2ru4",  // 投入
+    "yao4bu4",  // 要不
+    "ming2que4",  // 明确
+    "tao2",  // 逃
+    "qiang2lie4",  // 强烈
+    "hao3ke4",  // 好客
+    "sheng3lve4",  // 省略
+    "bao3cun2",  // 保存
+    "tu2ya1",  // 涂鸦
+    "wan2",  // 完
+    "nv0hair0",  // 女孩儿
+    "min3gan3",  // 敏感
+    "wei2yi1",  // 唯一
+    "gun4",  // 棍
+    "meng4",  // 梦
+    "ban4li3",  // 办理
+    "di4zhen4",  // 地震
+    "ping2chang2",  // 平常
+    "feng1su2",  // 风俗
+    "jue2sai4",  // 决赛
+    "hao4",  // 号
+    "cuo4wu4",  // 错误
+    "mian4dui4",  // 面对
+    "cao3",  // 草
+    "cheng2zhang3",  // 成长
+    "cu1cao1",  // 粗糙
+    "hui1fu4",  // 恢复
+    "xi3huan5",  // 喜欢
+    "wai4jiao1",  // 外交
+    "guo4qu4",  // 过去
+    "bi3ji4",  // 笔记
+    "wan2shan4",  // 完善
+    "qiao1qiao1",  // 悄悄
+    "shi1fu4",  // 师傅
+    "ying1pin4",  // 应聘
+    "he2li3",  // 合理
+    "shi2yong4",  // 实用
+    "dui4dai4",  // 对待
+    "ju4",  // 句
+    "dian4tai2",  // 电台
+    "deng1",  // 灯
+    "ling2qian2",  // 零钱
+    "gong1li3",  // 公里
+    "peng2you5",  // 朋友
+    "zhi4xu4",  // 秩序
+    "qing3",  // 请
+    "ye4yu2",  // 业余
+    "yu4mi3",  // 玉米
+    "xiong1di4",  // 兄弟
+    "shuai1dao4",  // 摔倒
+    "jing1yan4",  // 经验
+    "biao3yang2",  // 表扬
+    "you4",  // 又
+    "zheng4ju4",  // 证据
+    "zuo4pin3",  // 作品
+    "ran2hou4",  // 然后
+    "li3you2",  // 理由
+    "wan2quan2",  // 完全
+    "ju4",  // 锯
+    "dao4ju4",  // 道具
+    "dou1",  // 都
+    "zhe2xue2",  // 哲学
+    "he2ying3",  // 合影
+    "huo2po1",  // 活泼
+    "ji4shu4",  // 技术
+    "guang3gao4",  // 广告
+    "zhi3you3",  // 只有
+    "cu4shi3",  // 促使
+    "mei2guan1xi5",  // 没关系
+    "hu2die2",  // 蝴蝶
+    "feng1kuang2",  // 疯狂
+    "zi4ji3",  // 自己
+    "zi3",  // 紫
+    "zhong1",  // 钟
+    "jing1ji4",  // 经济
+    "zhi3",  // 纸
+    "zhua1",  // 抓
+    "bian4hua4",  // 变化
+    "shui4",  // 税
+    "chang2cheng2",  // 长城
+    "zhen1",  // 真
+    "cu4",  // 醋
+    "tian2",  // 甜
+    "da4shi3guan3",  // 大使馆
+    "zhao3",  // 找
+    "kuang2",  // 狂
+    "shui3ping2",  // 水平
+    "yun3xu3",  // 允许
+    "de0",  // 地
+    "qian3",  // 浅
+    "nai4xin1",  // 耐心
+    "xiang1tong2",  // 相同
+    "gong1yuan2",  // 公元
+    "jing1cheng2suo3zhi4",  // 精诚所至
+    "tiao4wu3",  // 跳舞
+    "shun4li4",  // 顺利
+    "zheng4hao3",  // 正好
+    "neng2li4",  // 能力
+    "she4ying3shi1",  // 摄影师
+    "kua3",  // 垮
+    "ji1zhi4",  // 机制
+    "zheng4jian4",  // 证件
+    "zou3",  // 走
+    "lv4shi1",  // 律师
+    "sheng1zhang3",  // 生长
+    "yi1fu5",  // 衣服
+    "cuo4",  // 错
+    "shou1shi2",  // 收拾
+    "ren2kou3",  // 人口
+    "wen2zi4",  // 文字
+    "nong2",  // 浓
+    "tong1chang2",  // 通常
+    "bu4fen1",  // 部分
+    "jie3jue2",  // 解决
+    "zun1zhong4",  // 尊重
+    "wu2fa3",  // 无法
+    "ti1zu2qiu2",  // 踢足球
+    "jing4zheng1",  // 竞争
+    "fu4yin4",  // 复印
+    "song4",  // 送
+    "bao1kuo4",  // 包括
+    "guan1jian4",  // 关键
+    "guo3shi2",  // 果实
+    "gen1ji1",  // 根基
+    "shi3jin4",  // 使劲
+    "lian4ai4",  // 恋爱
+    "zhong1guo2",  // 中国
+    "xiang3",  // 响
+    "ke3ai4",  // 可爱
+    "bo1dong4",  // 波动
+    "mu4biao1",  // 目标
+    "chuan2shuo1",  // 传说
+    "li4ke4",  // 立刻
+    "yang2guang1",  // 阳光
+    "hou4guo3",  // 后果
+    "xue2qi1",  // 学期
+    "ying1gai1",  // 应该
+    "jian4",  // 件
+    "dai0fu1",  // 大夫
+    "fa1da2",  // 发达
+    "cu4jin4",  // 促进
+    "shun4bian4",  // 顺便
+    "li2mi3",  // 厘米
+    "zhan4",  // 站
+    "feng1xian3",  // 风险
+    "mian4lin2",  // 面临
+    "ju4li2",  // 距离
+    "biao3qing2",  // 表情
+    "jiang3jiu1",  // 讲究
+    "zhong1yu2",  // 终于
+    "wu4",  // 雾
+    "men2",  // 门
+    "gan4bei1",  // 干杯
+    "ai4hu4",  // 爱护
+    "guang1",  // 光
+    "xue2li4",  // 学历
+    "ru4kou3",  // 入口
+    "xiong1",  // 胸
+    "li4run4",  // 利润
+    "sha1",  // 杀
+    "an4shi2",  // 按时
+    "guang1xian4",  // 光线
+    "yan2ge2",  // 严格
+    "zheng4zhi4",  // 政治
+    "fu4jin4",  // 附近
+    "zi4",  // 字
+    "dang1ran2",  // 当然
+    "bao4qian4",  // 抱歉
+    "ju2zi5",  // 桔子
+    "ren4he2",  // 任何
+    "qian1bi3",  // 铅笔
+    "du4",  // 度
+    "ling4wai4",  // 另外
+    "wu1zi5",  // 屋子
+    "que1dian3",  // 缺点
+    "su4she4",  // 宿舍
+    "you2",  // 由
+    "you2yu4",  // 犹豫
+    "yi0huir0",  // 一会儿
+    "zi4cong2",  // 自从
+    "xing4kui1",  // 幸亏
+    "shi2hua4",  // 实话
+    "ke3",  // 可
+    "ying4",  // 硬
+    "guo2jia1",  // 国家
+    "xian4dai4",  // 现代
+    "ping2shi2",  // 平时
+    "tuan2",  // 团
+    "mi4feng1",  // 蜜蜂
+    "xian4xiang4",  // 现象
+    "duo1me0",  // 多么
+    "ru2guo3",  // 如果
+    "qin1qie4",  // 亲切
+    "hui1chen2",  // 灰尘
+    "gu4",  // 雇
+    "qing1chu3",  // 清楚
+    "he2zuo4",  // 合作
+    "zhu3zhang1",  // 主张
+    "hai3",  // 海
+    "xia1",  // 瞎
+    "he2fa3",  // 合法
+    "zan4mei3",  // 赞美
+    "gan4",  // 干
+    "xin4xi1",  // 信息
+    "you1dian3",  // 优点
+    "ban4fa3",  // 办法
+    "qing2qu4",  // 情趣
+    "hong2jiu3",  // 红酒
+    "zao4cheng2",  // 造成
+    "bao3bei4",  // 宝贝
+    "zuo3you4",  // 左右
+    "ji3",  // 挤
+    "gong1si1",  // 公司
+    "chao1guo4",  // 超过
+    "wang4ji4",  // 忘记
+    "hui2da2",  // 回答
+    "hui4",  // 会
+    "shou3",  // 守
+    "huo4xu3",  // 或许
+    "guang1hua2",  // 光滑
+    "qi4hou4",  // 气候
+    "bi4yao4",  // 必要
+    "duan4lian4",  // 锻炼
+    "jing3cha2",  // 警察
+    "chao3",  // 炒
+    "fan3zheng4",  // 反正
+    "wei4le0",  // 为了
+    "zhang4fu5",  // 丈夫
+    "zhi1",  // 支
+    "jia4ge2",  // 价格
+    "zong3suan4",  // 总算
+    "guai4",  // 怪
+    "ling4",  // 另
+    "diu1",  // 丢
+    "zhu4fu2",  // 祝福
+    "ren4zhen1",  // 认真
+    "huo3cheng2yan2",  // 火成岩
+    "qi2ci4",  // 其次
+    "ke3xi1",  // 可惜
+    "gan3jue2",  // 感觉
+    "yu2",  // 于
+    "zhong4shi4",  // 重视
+    "mian4ji1",  // 面积
+    "wu4hui4",  // 误会
+    "jin1tian1",  // 今天
+    "bi3sai4",  // 比赛
+    "wen4",  // 问
+    "jing1shen2",  // 精神
+    "jing1dian3",  // 经典
+    "chou1yan1",  // 抽烟
+    "li3",  // 李
+    "fu4ze2",  // 负责
+    "yi1qie4",  // 一切
+    "zuo4wei4",  // 作为
+    "zhi4ding4",  // 制订
+    "cha2",  // 茶
+    "yun4yong4",  // 运用
+    "jie4",  // 借
+    "zi4yuan4",  // 自愿
+    "che1",  // 车
+    "shang1wu4",  // 商务
+    "ji4hua4",  // 计划
+    "shou3ji1",  // 手机
+    "zhan1tie1",  // 粘贴
+    "ai4hao3",  // 爱好
+    "jiao4",  // 教
+    "gei3",  // 给
+    "an1wei4",  // 安慰
+    "zai4san1",  // 再三
+    "zhi2de2",  // 值得
+    "shen1ke4",  // 深刻
+    "qu4nian2",  // 去年
+    "bei1shang1",  // 悲伤
+    "xi1",  // 夕
+    "shang1pin3",  // 商品
+    "zhi1",  // 之
+    "zhi2jie1",  // 直接
+    "jin4",  // 进
+    "zhu4ce4",  // 注册
+    "cheng1ba4",  // 称霸
+    "liu2",  // 留
+    "shang4",  // 上
+    "fan1",  // 翻
+    "cheng1zan4",  // 称赞
+    "dong4hua4pian4",  // 动画片
+    "dong1xi5",  // 东西
+    "dui4bu5qi3",  // 对不起
+    "de2",  // 得
+    "nen4",  // 嫩
+    "pian4",  // 骗
+    "di4tie3",  // 地铁
+    "ying2yang3",  // 营养
+    "huan4xiang3",  // 幻想
+    "zheng4fu3",  // 政府
+    "yin2zi5",  // 银子
+    "gai3bian4",  // 改变
+    "an1quan2",  // 安全
+    "zhi1shi2",  // 知识
+    "chu4li3",  // 处理
+    "guo4fen1",  // 过分
+    "qing2kuang4",  // 情况
+    "lang4man4",  // 浪漫
+    "xiao3yu3",  // 小雨
+    "jie2yue1",  // 节约
+    "shen1cai2",  // 身材
+    "yun2",  // 云
+    "ji4",  // 系
+    "zen3me5yang4",  // 怎么样
+    "si1tan3fu2",  // 斯坦福
+    "yi4",  // 亿
+    "jiao1",  // 浇
+    "jia1bin1",  // 嘉宾
+    "kong4zhi4",  // 控制
+    "ti2mu4",  // 题目
+    "suo3you3",  // 所有
+    "ran2er2",  // 然而
+    "xu1xin1",  // 虚心
+    "zheng1",  // 睁
+    "ti2xing3",  // 提醒
+    "xian3de2",  // 显得
+    "gong1ren2",  // 工人
+    "zong1he2",  // 综合
+    "tu3",  // 吐
+    "ping2wei3",  // 评委
+    "cha1",  // 插
+    "nai3nai3",  // 奶奶
+    "er4",  // 二
+    "ben4",  // 笨
+    "ban4shi4",  // 办事
+    "yi3",  // 乙
+    "ping2",  // 平
+    "bian1pao4",  // 鞭炮
+    "jie4shao4",  // 介绍
+    "mao4xian3",  // 冒险
+    "zhe2xue2jia1",  // 哲学家
+    "ying2ye4",  // 营业
+    "shi1qu4",  // 失去
+    "hui1xin1",  // 灰心
+    "xie3",  // 血
+    "mei2mao5",  // 眉毛
+    "wei4sheng1jian1",  // 卫生间
+    "ci3wai4",  // 此外
+    "fen3se4",  // 粉色
+    "pin2dao4",  // 频道
+    "wan4",  // 万
+    "sha1ding1yu2",  // 沙丁鱼
+    "qu1bie2",  // 区别
+    "ji2",  // 即
+    "he2zi3",  // 盒子
+    "niao3",  // 鸟
+    "lan2",  // 蓝
+    "chao2shi1",  // 潮湿
+    "dan1wei4",  // 单位
+    "ai1",  // 哎
+    "zhu3ren2",  // 主人
+    "shan1",  // 山
+    "fei1chang2",  // 非常
+    "zhou1wei2",  // 周围
+    "jiu4hu4che1",  // 救护车
+    "fa1chou2",  // 发愁
+    "zhen4",  // 镇
+    "qian1",  // 千
+    "jia1",  // 家
+    "qing3jia3",  // 请假
+    "bao1zi3",  // 包子
+    "xiang1chu4",  // 相处
+    "jie4",  // 戒
+    "xian2",  // 咸
+    "ji4",  // 寄
+    "huan2bao3",  // 环保
+    "you2xi4",  // 游戏
+    "yu3fa3",  // 语法
+    "cheng1hu1",  // 称呼
+    "jie4zhi5",  // 戒指
+    "cha4bu4duo1",  // 差不多
+    "yi1bian1",  // 一边
+    "ce4",  // 册
+    "yao1qing3",  // 邀请
+    "te4se4",  // 特色
+    "xiang1fan3",  // 相反
+    "xin1xian1gan3",  // 新鲜感
+    "ge4",  // 各
+    "huan1ying2",  // 欢迎
+    "da4xiang4",  // 大象
+    "ding3",  // 顶
+    "chu2xi1",  // 除夕
+    "ba3wo4",  // 把握
+    "ji2shi2",  // 及时
+    "sen1lin2",  // 森林
+    "ou1meng2",  // 欧盟
+    "quan4",  // 劝
+    "biao3shi4",  // 表示
+    "yan2jiu1",  // 研究
+    "yi3wei4",  // 以为
+    "jian3dan1",  // 简单
+    "zui4chu1",  // 最初
+    "bang4wan3",  // 傍晚
+    "sheng1huo2",  // 生活
+    "ke1xue2",  // 科学
+    "yang4zi3",  // 样子
+    "xu1yao4",  // 需要
+    "ti3hui4",  // 体会
+    "yu3",  // 雨
+    "jie2shi2",  // 结实
+    "gu3dai4",  // 古代
+    "miao2tiao2",  // 苗条
+    "jian3shao3",  // 减少
+    "le4guan1",  // 乐观
+    "da3ting1",  // 打听
+    "jian3qing1",  // 减轻
+    "nar0",  // 那儿
+    "chu2le0",  // 除了
+    "ren2",  // 人
+    "jia1ju4",  // 家具
+    "yi1ban1",  // 一般
+    "ji2zhen3",  // 急诊
+    "zhi4hui4",  // 智慧
+    "shan4yu2",  // 善于
+    "han3",  // 喊
+    "jiu4",  // 旧
+    "si4",  // 四
+    "shu1jia4",  // 书架
+    "ye4zi3",  // 叶子
+    "cheng2gong1",  // 成功
+    "jian3zhi2",  // 简直
+    "lian2xu4",  // 连续
+    "la4jiao1",  // 辣椒
+    "bu4yao4jin3",  // 不要紧
+    "xin1wen2",  // 新闻
+    "bei1zi5",  // 杯子
+    "yun4dong4",  // 运动
+    "se4cai3",  // 色彩
+    "pian4mian4",  // 片面
+    "jie2he2",  // 结合
+    "bei4zi3",  // 被子
+    "dong4",  // 洞
+    "zai4yu2",  // 在于
+    "yi1yuan4",  // 医院
+    "ju2",  // 局
+    "hui2",  // 回
+    "cheng2shi4",  // 城市
+    "du2",  // 读
+    "xian4jin1",  // 现金
+    "ren2yuan2",  // 人员
+    "shi4ying1",  // 适应
+    "cai2liao4",  // 材料
+    "du2li4",  // 独立
+    "xing4bie2",  // 性别
+    "hai3li3",  // 海里
+    "zi4dong4",  // 自动
+    "cong2qian2",  // 从前
+    "pao3bu4",  // 跑步
+    "cai3hong2",  // 彩虹
+    "dan1chun2",  // 单纯
+    "bao4gao4",  // 报告
+    "luo2ji2",  // 逻辑
+    "qi2te4",  // 奇特
+    "jia4zhi2",  // 价值
+    "ling4",  // 令
+    "wu2",  // 无
+    "shi1zi5",  // 狮子
+    "ya2gao1",  // 牙膏
+    "dan4",  // 淡
+    "xiao4ying1",  // 效应
+    "qing3yuan4shu1",  // 请愿书
+    "qie1",  // 切
+    "yong4",  // 用
+    "jing1guo4",  // 经过
+    "deng3",  // 等
+    "fa1sheng1",  // 发生
+    "zhang3",  // 涨
+    "pi1ping2",  // 批评
+    "tui1chi2",  // 推迟
+    "fu4",  // 富
+    "sheng1dong4",  // 生动
+    "er4shou3",  // 二手
+    "zhong4da4",  // 重大
+    "shu1zi3",  // 梳子
+    "lun4wen2",  // 论文
+    "la1ji1tong3",  // 垃圾桶
+    "han2yi4",  // 含意
+    "zhen1dui4",  // 针对
+    "xian1hua1",  // 鲜花
+    "sha1fa1",  // 沙发
+    "zhong1xin1",  // 中心
+    "zhi4yu2",  // 至于
+    "shou4",  // 瘦
+    "dui4bi3",  // 对比
+    "gong1yuan2",  // 公园
+    "kan4bu4qi3",  // 看不起
+    "qi2guai4",  // 奇怪
+    "yao4",  // 药
+    "shai4",  // 晒
+    "can1ting1",  // 餐厅
+    "e2",  // 额
+    "shi2hou5",  // 时候
+    "jiao1qu1",  // 郊区
+    "ba0chengr0",  // 八成儿
+    "yan3jing5",  // 眼睛
+    "xi4jie2",  // 细节
+    "ceng2jing1",  // 曾经
+    "xiang4zi5",  // 橡子
+    "shou3",  // 首
+    "nan2",  // 南
+    "an4",  // 暗
+    "jin3kuai4",  // 尽快
+    "shi3zhong1",  // 始终
+    "jia1ban1",  // 加班
+    "po4qie4",  // 迫切
+    "zi3lu4",  // 子路
+    "hai4pa4",  // 害怕
+    "ju1ran2",  // 居然
+    "ta1",  // 她
+    "huo3",  // 火
+    "she4",  // 射
+    "mi2lu4",  // 迷路
+    "yin2",  // 银
+    "hua2",  // 滑
+    "gui1mo2",  // 规模
+    "ming2zi5",  // 名字
+    "zhi4ding4",  // 制定
+    "gai3shan4",  // 改善
+    "qian2",  // 前
+    "liao2tian1",  // 聊天
+    "zheng1lun4",  // 争论
+    "he2ge2",  // 合格
+    "bian4lun4",  // 辩论
+    "kan3",  // 砍
+    "jin1shi2wei2kai1",  // 金石为开
+    "qing1",  // 青
+    "xia4ling4ying2",  // 夏令营
+    "wei4bi4",  // 未必
+    "shuo1fu2",  // 说服
+    "ci2zhi2",  // 辞职
+    "liao0tianr0",  // 聊天儿
+    "xian1",  // 先
+    "zi1ge2",  // 资格
+    "xie4",  // 谢
+    "li3bai4tian1",  // 礼拜天
+    "pai2lie4",  // 排列
+    "lao3she3",  // 老舍
+    "wen2zhang1",  // 文章
+    "gan0huor0",  // 干活儿
+    "tui1",  // 推
+    "wen2zi5",  // 蚊子
+    "zhan3kai1",  // 展开
+    "ti2",  // 蹄
+    "yi1yang4",  // 一样
+    "ma3",  // 马
+    "du3che1",  // 堵车
+    "lao3po2",  // 老婆
+    "zheng4ming2",  // 证明
+    "nian2dai4",  // 年代
+    "zhuang4kuang4",  // 状况
+    "kuo4",  // 扩
+    "zhang1",  // 张
+    "lian2po1",  // 廉颇
+    "di4yi1",  // 第一
+    "yu4da2fu1",  // 郁达夫
+    "ji1lei4",  // 积累
+    "fei1",  // 非
+    "zuo4ye4",  // 作业
+    "shu4liang4",  // 数量
+    "nong2min2",  // 农民
+    "pei4fu2",  // 佩服
+    "e4",  // 饿
+    "zen3me0ban4",  // 怎么办
+    "ban4gong1shi4",  // 办公室
+    "bao4",  // 抱
+    "wen2xue2jia1",  // 文学家
+    "xiu1gai3",  // 修改
+    "shen1bian1",  // 身边
+    "xue2sheng5",  // 学生
+    "er3huan2",  // 耳环
+    "tong2yi4",  // 同意
+    "tian1ran2",  // 天然
+    "suo3yi3",  // 所以
+    "ji1ji2",  // 积极
+    "mei3shu4",  // 美术
+    "fa1xian4",  // 发现
+    "ke0sou4",  // 咳嗽
+    "huo4",  // 货
+    "ji4lu4",  // 纪录
+    "na2",  // 拿
+    "ke1ji4",  // 科技
+    "xin4",  // 信
+    "jing1chang2",  // 经常
+    "ping2jia4",  // 评价
+    "fu4zhi4",  // 复制
+    "ai3",  // 矮
+    "si0hu1",  // 似乎
+    "dang1di4",  // 当地
+    "ta1",  // 它
+    "jian1jue2",  // 坚决
+    "li4",  // 丽
+    "niu2jin1da4xue2",  // 牛津大学
+    "cao1chang3",  // 操场
+    "kao3shi4",  // 考试
+    "zhao4gu4",  // 照顾
+    "la1ji1",  // 垃圾
+    "ya1jin1",  // 押金
+    "chou1ti5",  // 抽屉
+    "ji0dao4",  // 街道
+    "ren2ming2",  // 人名
+    "cong2ci3",  // 从此
+    "xin1zang4",  // 心脏
+    "shun4xu4",  // 顺序
+    "bu4jin3",  // 不仅
+    "huang2jin1",  // 黄金
+    "deng1ji4",  // 登记
+    "ke4ku3",  // 刻苦
+    "zhai3",  // 窄
+    "an1zhuang1",  // 安装
+    "pang2bian1",  // 旁边
+    "jian1bang3",  // 肩膀
+    "xi1han4",  // 西汉
+    "bei3fang1",  // 北方
+    "shu4ma3",  // 数码
+    "ren2cai2",  // 人才
+    "bi4guan1",  // 闭关
+    "qi2yu2",  // 其余
+    "suo3",  // 所
+    "fen1fen1",  // 纷纷
+    "jian4mian4",  // 见面
+    "zhu4he4",  // 祝贺
+    "dong3",  // 懂
+    "mo2hu2",  // 模糊
+    "mo4sheng1",  // 陌生
+    "hong2se4",  // 红色
+    "bao4tu1quan2",  // 趵突泉
+    "shi4he2",  // 适合
+    "ju3xing2",  // 举行
+    "shou3xu4",  // 手续
+    "zhong4liang4",  // 重量
+    "lu2mi3ai1er3",  // 卢米埃尔
+    "ji1",  // 击
+    "lu4xu4",  // 陆续
+    "wen2ju4",  // 文具
+    "chuan2tong3",  // 传统
+    "chan3pin3",  // 产品
+    "xin4ren4",  // 信任
+    "dang1",  // 当
+    "guan1cha2",  // 观察
+    "yi1bei4zi3",  // 一辈子
+    "dang1xin1",  // 当心
+    "chu1xi2",  // 出席
+    "nan2",  // 男
+    "za2zhi4",  // 杂志
+    "yu4ding4",  // 预订
+    "bing4qie3",  // 并且
+    "ren2min2",  // 人民
+    "lou2",  // 楼
+    "chuan2bo1",  // 传播
+    "yi2wen4",  // 疑问
+    "chang2",  // 长
+    "xiang4",  // 向
+    "ke1",  // 颗
+    "guo3zhi1",  // 果汁
+    "yin3liao4",  // 饮料
+    "dai1",  // 呆
+    "lao3",  // 老
+    "ren2min2bi4",  // 人民币
+    "yu3qi4",  // 语气
+    "wa4zi3",  // 袜子
+    "mei2ti3",  // 媒体
+    "dian4shi4",  // 电视
+    "zhen4",  // 阵
+    "luo4hou4",  // 落后
+    "ji2ge2",  // 及格
+    "dao4de2",  // 道德
+    "da2ying1",  // 答应
+    "qiao2",  // 桥
+    "ke4",  // 刻
+    "wen1du4",  // 温度
+    "bang1mang2",  // 帮忙
+    "xiang3shou4",  // 享受
+    "hui1",  // 灰
+    "da4xing2",  // 大型
+    "kong1jian1",  // 空间
+    "mu4qian2",  // 目前
+    "wei4lai2",  // 未来
+    "yao1",  // 腰
+    "nan2dao4",  // 难道
+    "liu2xie3",  // 流血
+    "du4guo4",  // 度过
+    "xun4lian4",  // 训练
+    "bei4",  // 倍
+    "si1ren2",  // 私人
+    "xia4",  // 吓
+    "kun4rao3",  // 困扰
+    "bao2",  // 薄
+    "zi4hao2",  // 自豪
+    "shan3dian4",  // 闪电
+    "nv3xing4",  // 女性
+    "dou4",  // 逗
+    "jia3",  // 甲
+    "zhong1wen2",  // 中文
+    "zhi3dao3",  // 指导
+    "chu1zi4",  // 出自
+    "wang3",  // 往
+    "shi4",  // 是
+    "cha2xun2",  // 查询
+    "yi1gong4",  // 一共
+    "jiu4can1",  // 就餐
+    "yi1",  // 一
+    "zheng3qi2",  // 整齐
+    "tian1jin1wei4shi4",  // 天津卫视
+    "chu1se4",  // 出色
+    "ke1en1",  // 科恩
+    "bai3",  // 百
+    "xiao3jie3",  // 小姐
+    "wang3fan3",  // 往返
+    "pi2jiu3",  // 啤酒
+    "you3",  // 有
+    "zhong1wu3",  // 中午
+    "diao4",  // 掉
+    "pei2xun4",  // 培训
+    "qing2xu4",  // 情绪
+    "qiang2diao4",  // 强调
+    "xin4yong4ka3",  // 信用卡
+    "yao4shi0",  // 钥匙
+    "chao2",  // 朝
+    "gu1ji4",  // 估计
+    "ji2zhong1",  // 集中
+    "tiao0zheng3",  // 调整
+    "fen4",  // 份
+    "he2shang4",  // 和尚
+    "hui4yuan2ka3",  // 会员卡
+    "he2shi4",  // 合适
+    "jin3guan3",  // 尽管
+    "hao3chu4",  // 好处
+    "hui2lai2",  // 回来
+    "ke3shi4",  // 可是
+    "dui4",  // 对
+    "shou1ji2",  // 收集
+    "biao3xian4",  // 表现
+    "wu2yi4",  // 无意
+    "fen1",  // 分
+    "shi2jian1",  // 时间
+    "ke4",  // 课
+    "qiong2",  // 穷
+    "lv3",  // 屡
+    "ne0",  // 呢
+    "cheng2shou4",  // 承受
+    "fan3ying4",  // 反映
+    "bei3jing1",  // 北京
+    "du3",  // 堵
+    "liang4",  // 辆
+    "zuo4wen2",  // 作文
+    "ge2wai4",  // 格外
+    "ren2wu4",  // 人物
+    "jiao4yu4",  // 教育
+    "neng2gan4",  // 能干
+    "tou1",  // 偷
+    "chao3jia4",  // 吵架
+    "qiao2",  // 瞧
+    "mei2xi1",  // 梅西
+    "neng2",  // 能
+    "nong4",  // 弄
+    "pai2dui4",  // 排队
+    "shi2cha1",  // 时差
+    "ti2gao1",  // 提高
+    "fei2zao4",  // 肥皂
+    "wei2xiu1",  // 维修
+    "shi4fou3",  // 是否
+    "quan2bu4",  // 全部
+    "kai1mu4shi4",  // 开幕式
+    "gan3mao4",  // 感冒
+    "ting1shuo1",  // 听说
+    "gan3",  // 赶
+    "cheng2",  // 成
+    "ji4zhe3",  // 记者
+    "yang4shi4",  // 样式
+    "jie1zhe0",  // 接着
+    "tu3dou4",  // 土豆
+    "ji2qi2",  // 极其
+    "chuan1",  // 穿
+    "you3li4",  // 有利
+    "ku1",  // 哭
+    "yan3yuan2",  // 演员
+    "wei3ba1",  // 尾巴
+    "chi3zi5",  // 尺子
+    "zheng4shi4",  // 正式
+    "xiao4yuan2",  // 校园
+    "shi4wu4",  // 事物
+    "da4jia1",  // 大家
+    "xiang1guan1",  // 相关
+    "jia1you2",  // 加油
+    "shu4",  // 树
+    "zhuan3gao4",  // 转告
+    "bo2shi4",  // 博士
+    "ya0",  // 呀
+    "biao1zhi4",  // 标志
+    "ge4zi3",  // 个子
+    "xuan1chuan2",  // 宣传
+    "xing2dong4",  // 行动
+    "zhao4kai1",  // 召开
+    "bi3ci3",  // 彼此
+    "yin1er2",  // 因而
+    "jiu3",  // 酒
+    "kan4",  // 看
+    "you0le4",  // 娱乐
+    "ri4cheng2",  // 日程
+    "shou1huo4",  // 收获
+    "guo2ji4",  // 国际
+    "li3fa1",  // 理发
+    "zhun3bei4",  // 准备
+    "hei1",  // 黑
+    "xun2zhao3",  // 寻找
+    "di4zhi3",  // 地址
+    "shou3",  // 手
+    "shi2jian4",  // 实践
+    "wen3ding4",  // 稳定
+    "ping1pang1qiu2",  // 乒乓球
+    "yu2shi4",  // 于是
+    "yu2",  // 鱼
+    "an4an4",  // 暗暗
+    "pei2yang3",  // 培养
+    "nuan3",  // 暖
+    "bang1",  // 帮
+    "fei4",  // 废
+    "mi4qie4",  // 密切
+    "jiao1tong1",  // 交通
+    "shuo1hua4",  // 说话
+    "yi4si5",  // 意思
+    "yuan2liang4",  // 原谅
+    "gai3jin4",  // 改进
+    "qing1zhuang4nian2",  // 青壮年
+    "sheng1yin1",  // 声音
+    "le0",  // 了
+    "gui1na4",  // 归纳
+    "fang1xiang4",  // 方向
+    "you1xiu4",  // 优秀
+    "ke4fu2",  // 克服
+    "chong1",  // 冲
+    "yin1yue4",  // 音乐
+    "yang2tai2",  // 阳台
+    "xie2",  // 斜
+    "zhong3",  // 种
+    "ge4",  // 个
+    "zhong1jie4",  // 中介
+    "en1ai4",  // 恩爱
+    "wei4kou3",  // 胃口
+    "an1na4",  // 安娜
+    "jie1jin4",  // 接近
+    "nv3",  // 女
+    "ti4",  // 替
+    "hao3lin2shuo1",  // 郝林说
+    "shen1",  // 伸
+    "cheng2fen1",  // 成分
+    "gong1kai1",  // 公开
+    "di4li3",  // 地理
+    "xin1shang3",  // 欣赏
+    "zhaor1",  // 招儿
+    "bao4quan2",  // 鲍全
+    "tong4ku3",  // 痛苦
+    "gang1",  // 刚
+    "bi4mian3",  // 避免
+    "ma3shang4",  // 马上
+    "zhui1",  // 追
+    "wen2hua4",  // 文化
+    "zong3tong3",  // 总统
+    "you0qi2",  // 与其
+    "zuo3bian5",  // 左边
+    "shi4bing1",  // 士兵
+    "kao4",  // 靠
+    "jin4bu4",  // 进步
+    "chuang4ban4",  // 创办
+    "yi1ding4",  // 一定
+    "gan3xing4qu4",  // 感兴趣
+    "gan3dao4",  // 感到
+    "you3yi4",  // 友谊
+    "da3rao3",  // 打扰
+    "wu2shu4",  // 无数
+    "mei3tian1",  // 每天
+    "shi4chang3",  // 市场
+    "ai4",  // 爱
+    "yin2hang2",  // 银行
+    "chao1ji2",  // 超级
+    "re4ai4",  // 热爱
+    "lin2shi2",  // 临时
+    "xiao3huo3zi3",  // 小伙子
+    "fu2wu4yuan2",  // 服务员
+    "bian4",  // 变
+    "ji4nian4",  // 纪念
+    "pin1yin1",  // 拼音
+    "yi2dong4",  // 移动
+    "xiang4zheng1",  // 象征
+    "dui4fang1",  // 对方
+    "jie2shu4",  // 结束
+    "zhi3dai4",  // 纸袋
+    "suan1",  // 酸
+    "chi1",  // 吃
+    "nei4bu4",  // 内部
+    "chuang1hu4",  // 窗户
+    "zhan4xian4",  // 占线
+    "liao0bu4qi3",  // 了不起
+    "wan4yi1",  // 万一
+    "jie2guo3",  // 结果
+    "zong3gong4",  // 总共
+    "wan3",  // 碗
+    "guang3bo1",  // 广播
+    "gong1ju4",  // 工具
+    "wang2gong1",  // 王宫
+    "wei1xian3",  // 危险
+    "jia1wu4",  // 家务
+    "qing4zhu4",  // 庆祝
+    "hai4xiu1",  // 害羞
+    "xiao3peng2you3",  // 小朋友
+    "qi3chuang2",  // 起床
+    "shou4",  // 受
+    "jian4",  // 箭
+    "si3",  // 死
+    "shao1wei1",  // 稍微
+    "liu2xing2",  // 流行
+    "tui1jian4",  // 推荐
+    "bu4guan3",  // 不管
+    "qing1song1",  // 轻松
+    "chai1",  // 拆
+    "dan1yuan2",  // 单元
+    "jia1you2zhan4",  // 加油站
+    "pi2lao2",  // 疲劳
+    "bei1",  // 悲
+    "tan2",  // 谈
+    "shao2zi3",  // 勺子
+    "fang2ai4",  // 妨碍
+    "zui4",  // 醉
+    "wai4gong1",  // 外公
+    "ji4ran2",  // 既然
+    "ming2xian3",  // 明显
+    "fan3ying4",  // 反应
+    "you4er2yuan2",  // 幼儿园
+    "nong2cun1",  // 农村
+    "jie3jie5",  // 姐姐
+    "sheng1ri4",  // 生日
+    "xiang1jing4ru2bin1",  // 相敬如宾
+    "lao3bai3xing4",  // 老百姓
+    "sui2zhe0",  // 随着
+    "er2tong2",  // 儿童
+    "jian1ku3",  // 艰苦
+    "fang2zi3",  // 房子
+    "yi1zhi4",  // 一致
+    "qu3",  // 取
+    "zi1jin1",  // 资金
+    "yun4shu1",  // 运输
+    "shen1fen4",  // 身份
+    "jiao4shou4",  // 教授
+    "mei3guo2",  // 美国
+    "yang3cheng2",  // 养成
+    "qi1jian1",  // 期间
+    "he2ping2",  // 和平
+    "ban1",  // 搬
+    "que1shao3",  // 缺少
+    "ti2",  // 提
+    "yu3yan2",  // 语言
+    "yong1ji3",  // 拥挤
+    "cai2",  // 才
+    "zhi2ye4",  // 职业
+    "yue1",  // 约
+    "mei3de2",  // 美德
+    "jia3zhuang1",  // 假装
+    "li4hai4",  // 厉害
+    "yan2se4",  // 颜色
+    "bi3jiao4",  // 比较
+    "shuai4",  // 帅
+    "chi2xu4",  // 持续
+    "fen1bu4",  // 分布
+    "xiong2mao1",  // 熊猫
+    "da2an4",  // 答案
+    "gu3lou2da4jie1",  // 鼓楼大街
+    "bo1fang4",  // 播放
+    "xia4mian4",  // 下面
+    "sui2shou3",  // 随手
+    "yuan2gong1",  // 员工
+    "shen1yuan1",  // 深渊
+    "dui4mian4",  // 对面
+    "jing4ran2",  // 竟然
+    "zhi1geng4niao3",  // 知更鸟
+    "xing4zhi4",  // 性质
+    "lao2dong4",  // 劳动
+    "que4ding4",  // 确定
+    "bu4dan4",  // 不但
+    "deng1ji1pai2",  // 登机牌
+    "zhao4she1",  // 赵奢
+    "huo3ban4",  // 伙伴
+    "zuo2tian1",  // 昨天
+    "cai1",  // 猜
+    "gen1ju4",  // 根据
+    "zhuan3tou2",  // 转头
+    "ma4",  // 骂
+    "yi1zhi2",  // 一直
+    "shan4zi5",  // 扇子
+    "lan2",  // 拦
+    "quan2",  // 全
+    "ming2pai2",  // 名牌
+    "niu2nai3",  // 牛奶
+    "mao2",  // 毛
+    "dai4yan2",  // 代言
+    "jie1duan4",  // 阶段
+    "xu3duo1",  // 许多
+    "zhi1chi2",  // 支持
+    "cai3fang3",  // 采访
+    "huan3jie3",  // 缓解
+    "lv3xing2",  // 旅行
+    "feng1",  // 疯
+    "hou4",  // 厚
+    "ruan3",  // 软
+    "zuo4",  // 坐
+    "du2te4",  // 独特
+    "lou4",  // 漏
+    "ke3pa4",  // 可怕
+    "mi4shu1",  // 秘书
+    "zhi4zao4",  // 制造
+    "xu4shu4",  // 叙述
+    "shu4xue2",  // 数学
+    "qi2",  // 棋
+    "sui4",  // 碎
+    "zhao1pin4",  // 招聘
+    "chi2tang2",  // 池塘
+    "mo1",  // 摸
+    "su4du4",  // 速度
+    "jie1shou4",  // 接受
+    "chu1zu1che1",  // 出租车
+    "shi1fei2",  // 施肥
+    "ji1dan4",  // 鸡蛋
+    "ju4bei4",  // 具备
+    "yin1",  // 阴
+    "liu2chi4ping2",  // 刘炽平
+    "zhen1xi1",  // 珍惜
+    "kong1",  // 空
+    "an4",  // 岸
+    "fang1mian4",  // 方面
+    "xiang1shan1",  // 香山
+    "fou3ren4",  // 否认
+    "xiao1hua4",  // 消化
+    "hua1sheng1",  // 花生
+    "lai2bu4ji2",  // 来不及
+    "xia4zai4",  // 下载
+    "chi2zao3",  // 迟早
+    "you3dian3",  // 有点
+    "mang2",  // 忙
+    "jin4dai4",  // 近代
+    "zher0",  // 这儿
+    "zhi4zuo4",  // 制作
+    "ji2ti3",  // 集体
+    "zeng1",  // 增
+    "che1ku4",  // 车库
+    "xi1gua1",  // 西瓜
+    "chun1",  // 春
+    "na3",  // 哪
+    "jin3liang4",  // 尽量
+    "jie1",  // 接
+    "lian2mang2",  // 连忙
+    "zeng1zhang3",  // 增长
+    "fang2dong1",  // 房东
+    "ling3yu4",  // 领域
+    "fen4dou4",  // 奋斗
+    "tui1ci2",  // 推辞
+    "chu1ban3",  // 出版
+    "cai4",  // 菜
+    "hou4bei4",  // 后背
+    "ling2",  // 零
+    "wei3qu1",  // 委屈
+    "xing2wei4",  // 行为
+    "biao3mian4",  // 表面
+    "shu4zi4",  // 数字
+    "wai4mian4",  // 外面
+    "lu4kou3",  // 路口
+    "dao1",  // 刀
+    "wu4",  // 勿
+    "dai4biao3",  // 代表
+    "shu1",  // 输
+    "bo1li2",  // 玻璃
+    "chen4shan1",  // 衬衫
+    "he2bi4",  // 何必
+    "pu3tong1",  // 普通
+    "gou3",  // 狗
+    "ji4xu4",  // 继续
+    "bu4cuo4",  // 不错
+    "li4ji2",  // 立即
+    "she4hui4",  // 社会
+    "yuan2yin1",  // 原因
+    "guai3wan1",  // 拐弯
+    "jian4she4",  // 建设
+    "shou1ju4",  // 收据
+    "jie2ri4",  // 节日
+    "nei4rong2",  // 内容
+    "ju4zi5",  // 锯子
+    "zhang3wo4",  // 掌握
+    "hu2shuo1",  // 胡说
+    "gong1ping2",  // 公平
+    "miao2xie3",  // 描写
+    "liang2shi2",  // 粮食
+    "cheng2xin4",  // 诚信
+    "cai3",  // 踩
+    "guo4",  // 过
+    "zuo4zhe3",  // 作者
+    "mi4mi4",  // 秘密
+    "zi4you2",  // 自由
+    "lie4che1",  // 列车
+    "li3lun4",  // 理论
+    "ruo4",  // 弱
+    "miao3",  // 秒
+    "zan4cheng2",  // 赞成
+    "yuan2liao4",  // 原料
+    "li3wu4",  // 礼物
+    "qiang1",  // 枪
+    "bu4tong2",  // 不同
+    "can1guan1",  // 参观
+    "shi4shi2",  // 事实
+    "chang2jiang1",  // 长江
+    "cong2lin2",  // 丛林
+    "li3xiang3",  // 理想
+    "kao3lv4",  // 考虑
+    "ka1fei1",  // 咖啡
+    "dang3",  // 挡
+    "gan4cui4",  // 干脆
+    "he2",  // 河
+    "sui2shen1",  // 随身
+    "tang3",  // 躺
+    "bi3ru2",  // 比如
+    "liang3",  // 两
+    "da4gai4",  // 大概
+    "dan4shi4",  // 但是
+    "da3sao3",  // 打扫
+    "tui3",  // 腿
+    "shang1hai4",  // 伤害
+    "liu2chuan2",  // 流传
+    "han4yu3",  // 汉语
+    "kai1",  // 开
+    "di1",  // 低
+    "pu3tong1hua4",  // 普通话
+    "mai4",  // 卖
+    "gan3xie4",  // 感谢
+    "li3mao4",  // 礼貌
+    "huan2jing4",  // 环境
+    "yin1wei4",  // 因为
+    "tao2",  // 桃
+    "pi3",  // 匹
+    "tu4zi5",  // 兔子
+    "wei2rao4",  // 围绕
+    "yi4wai4",  // 意外
+    "gua1feng1",  // 刮风
+    "dong4wu4",  // 动物
+    "tui1guang3",  // 推广
+    "ti3tie1",  // 体贴
+    "qing1shi4",  // 轻视
+    "jian1qiang2",  // 坚强
+    "shei2",  // 谁
+    "zhai2feng1",  // 翟峰
+    "ti2zi5",  // 蹄子
+    "xiang4pi2",  // 橡皮
+    "sheng1yi4",  // 生意
+    "ya2shua1",  // 牙刷
+    "shuo1ming2",  // 说明
+    "que4ren4",  // 确认
+    "nan2guai4",  // 难怪
+    "chong1dian4qi4",  // 充电器
+    "jin1yu2",  // 金鱼
+    "nin2",  // 您
+    "jun1yun2",  // 均匀
+    "mian4tiao2",  // 面条
+    "mian3fei4",  // 免费
+    "peng2zi3",  // 棚子
+    "chi1jing1",  // 吃惊
+    "zi1liao4",  // 资料
+    "qing2yuan2",  // 情缘
+    "hu4xiang1",  // 互相
+    "chang2qi1",  // 长期
+    "jian3li4",  // 简历
+    "rong2yi4",  // 容易
+    "gu3piao4",  // 股票
+    "hu2",  // 壶
+    "ni3",  // 你
+    "diao4",  // 钓
+    "han4zi4",  // 汉字
+    "shi4qing5",  // 事情
+    "pi1",  // 披
+    "duo1yu2",  // 多余
+    "ping2an1",  // 平安
+    "yu3mao2qiu2",  // 羽毛球
+    "gui4tai2",  // 柜台
+    "xiang1si0",  // 相似
+    "nar3",  // 哪儿
+    "xin1xi1lan2",  // 新西兰
+    "bu4zu2",  // 不足
+    "wu1ran3",  // 污染
+    "ba1",  // 八
+    "cheng2ji1",  // 成绩
+    "li4shi3",  // 历史
+    "gou4mai3",  // 购买
+    "guan3zi3",  // 馆子
+    "cheng2ken3",  // 诚恳
+    "ju4le4bu4",  // 俱乐部
+    "na3pa4",  // 哪怕
+    "lia3",  // 俩
+    "pan4duan4",  // 判断
+    "zhi4shao3",  // 至少
+    "gou4wu4",  // 购物
+    "sa3",  // 洒
+    "hai3guan1",  // 海关
+    "guan1jun1",  // 冠军
+    "da4xue2",  // 大学
+    "lin2",  // 临
+    "xue2xiao4",  // 学校
+    "shui4zhao2",  // 睡着
+    "cha4yi4",  // 差异
+    "kai1wan2xiao4",  // 开玩笑
+    "gu1niang2",  // 姑娘
+    "jia1li4fu2ni2ya4zhou1",  // 加利福尼亚州
+    "guo3ran2",  // 果然
+    "zhe4",  // 这
 ];
 
 // Tuples are (normalized_pinyin, 词语) from early in vocab file code
 // generation precompute pipeline. These correspond closely to lines of
 // vocab .tsv files prior to any sorting or merging of duplicates.
 #[cfg(test)]
-pub static PINYIN_CIYU_TEST_DATA: &[(&'static str, &'static str)] = &[
-    (&"ai", &"爱"),
-    (&"ba", &"八"),
-    (&"baba", &"爸爸"),
-    (&"beizi", &"杯子"),
-    (&"beijing", &"北京"),
-    (&"ben", &"本"),
-    (&"bukeqi", &"不客气"),
-    (&"bu", &"不"),
-    (&"cai", &"菜"),
-    (&"cha", &"茶"),
-    (&"chi", &"吃"),
-    (&"chuzuche", &"出租车"),
-    (&"dadianhua", &"打电话"),
-    (&"da", &"大"),
-    (&"de", &"的"),
-    (&"dian", &"点"),
-    (&"diannao", &"电脑"),
-    (&"dianshi", &"电视"),
-    (&"dianying", &"电影"),
-    (&"dongxi", &"东西"),
-    (&"dou", &"都"),
-    (&"du", &"读"),
-    (&"duibuqi", &"对不起"),
-    (&"duo", &"多"),
-    (&"duoshao", &"多少"),
-    (&"erzi", &"儿子"),
-    (&"er", &"二"),
-    (&"fandian", &"饭店"),
-    (&"feiji", &"飞机"),
-    (&"fenzhong", &"分钟"),
-    (&"gaoxing", &"高兴"),
-    (&"ge", &"个"),
-    (&"gongzuo", &"工作"),
-    (&"gou", &"狗"),
-    (&"hanyu", &"汉语"),
-    (&"hao", &"好"),
-    (&"hao", &"号"),
-    (&"he", &"喝"),
-    (&"he", &"和"),
-    (&"hen", &"很"),
-    (&"houmian", &"后面"),
-    (&"hui", &"回"),
-    (&"hui", &"会"),
-    (&"ji", &"几"),
-    (&"jia", &"家"),
-    (&"jiao", &"叫"),
-    (&"jintian", &"今天"),
-    (&"jiu", &"九"),
-    (&"kai", &"开"),
-    (&"kan", &"看"),
-    (&"kanjian", &"看见"),
-    (&"kuai", &"块"),
-    (&"lai", &"来"),
-    (&"laoshi", &"老师"),
-    (&"le", &"了"),
-    (&"leng", &"冷"),
-    (&"li", &"里"),
-    (&"liu", &"六"),
-    (&"mama", &"妈妈"),
-    (&"ma", &"吗"),
-    (&"mai", &"买"),
-    (&"mao", &"猫"),
-    (&"meiguanxi", &"没关系"),
-    (&"meiyou", &"没有"),
-    (&"mifan", &"米饭"),
-    (&"mingzi", &"名字"),
-    (&"mingtian", &"明天"),
-    (&"na", &"哪"),
-    (&"nar", &"哪儿"),
-    (&"na", &"那"),
-    (&"ne", &"呢"),
-    (&"neng", &"能"),
-    (&"ni", &"你"),
-    (&"nian", &"年"),
-    (&"nver", &"女儿"),
-    (&"pengyou", &"朋友"),
-    (&"piaoliang", &"漂亮"),
-    (&"pingguo", &"苹果"),
-    (&"qi", &"七"),
-    (&"qianmian", &"前面"),
-    (&"qian", &"钱"),
-    (&"qing", &"请"),
-    (&"qu", &"去"),
-    (&"re", &"热"),
-    (&"ren", &"人"),
-    (&"renshi", &"认识"),
-    (&"san", &"三"),
-    (&"shangdian", &"商店"),
-    (&"shang", &"上"),
-    (&"shangwu", &"上午"),
-    (&"shao", &"少"),
-    (&"shei", &"谁"),
-    (&"shenme", &"什么"),
-    (&"shi", &"十"),
-    (&"shihou", &"时候"),
-    (&"shi", &"是"),
-    (&"shu", &"书"),
-    (&"shui", &"水"),
-    (&"shuiguo", &"水果"),
-    (&"shuijiao", &"睡觉"),
-    (&"shuo", &"说"),
-    (&"si", &"四"),
-    (&"sui", &"岁"),
-    (&"ta", &"他"),
-    (&"ta", &"她"),
-    (&"tai", &"太"),
-    (&"tianqi", &"天气"),
-    (&"ting", &"听"),
-    (&"tongxue", &"同学"),
-    (&"wei", &"喂"),
-    (&"wo", &"我"),
-    (&"women", &"我们"),
-    (&"wu", &"五"),
-    (&"xihuan", &"喜欢"),
-    (&"xia", &"下"),
-    (&"xiawu", &"下午"),
-    (&"xiayu", &"下雨"),
-    (&"xiansheng", &"先生"),
-    (&"xianzai", &"现在"),
-    (&"xiang", &"想"),
-    (&"xiao", &"小"),
-    (&"xiaojie", &"小姐"),
-    (&"xie", &"些"),
-    (&"xie", &"写"),
-    (&"xiexie", &"谢谢"),
-    (&"xingqi", &"星期"),
-    (&"xuesheng", &"学生"),
-    (&"xuexi", &"学习"),
-    (&"xuexiao", &"学校"),
-    (&"yi", &"一"),
-    (&"yifu", &"衣服"),
-    (&"yisheng", &"医生"),
-    (&"yiyuan", &"医院"),
-    (&"yizi", &"椅子"),
-    (&"yidianr", &"一点儿"),
-    (&"you", &"有"),
-    (&"yue", &"月"),
-    (&"zaijian", &"再见"),
-    (&"zai", &"在"),
-    (&"zenme", &"怎么"),
-    (&"zenmeyang", &"怎么样"),
-    (&"zhe", &"这"),
-    (&"zhongguo", &"中国"),
-    (&"zhongwu", &"中午"),
-    (&"zhu", &"住"),
-    (&"zhuozi", &"桌子"),
-    (&"zi", &"字"),
-    (&"zuotian", &"昨天"),
-    (&"zuo", &"坐"),
-    (&"zuo", &"做"),
-    (&"dawei", &"大卫"),
-    (&"li", &"李"),
-    (&"meiguo", &"美国"),
-    (&"wang", &"王"),
-    (&"xie", &"谢"),
-    (&"peng", &"朋"),
-    (&"bushao", &"不少"),
-    (&"che", &"车"),
-    (&"chifan", &"吃饭"),
-    (&"daxue", &"大学"),
-    (&"guo", &"国"),
-    (&"hanzi", &"汉字"),
-    (&"hou", &"后"),
-    (&"huilai", &"回来"),
-    (&"jinnian", &"今年"),
-    (&"mei", &"没"),
-    (&"nar", &"那儿"),
-    (&"nimen", &"你们"),
-    (&"qian", &"前"),
-    (&"xiamian", &"下面"),
-    (&"xue", &"学"),
-    (&"yu", &"雨"),
-    (&"zher", &"这儿"),
-    (&"zhexie", &"这些"),
-    (&"ba", &"吧"),
-    (&"bai", &"白"),
-    (&"bai", &"百"),
-    (&"bangzhu", &"帮助"),
-    (&"baozhi", &"报纸"),
-    (&"bi", &"比"),
-    (&"bie", &"别"),
-    (&"binguan", &"宾馆"),
-    (&"chang", &"长"),
-    (&"changge", &"唱歌"),
-    (&"chu", &"出"),
-    (&"chuan", &"穿"),
-    (&"ci", &"次"),
-    (&"cong", &"从"),
-    (&"cuo", &"错"),
-    (&"dalanqiu", &"打篮球"),
-    (&"dajia", &"大家"),
-    (&"danshi", &"但是"),
-    (&"dao", &"到"),
-    (&"de", &"得"),
-    (&"deng", &"等"),
-    (&"didi", &"弟弟"),
-    (&"diyi", &"第一"),
-    (&"dong", &"懂"),
-    (&"dui", &"对"),
-    (&"fangjian", &"房间"),
-    (&"feichang", &"非常"),
-    (&"fuwuyuan", &"服务员"),
-    (&"gao", &"高"),
-    (&"gaosu", &"告诉"),
-    (&"gege", &"哥哥"),
-    (&"gei", &"给"),
-    (&"gonggongqiche", &"公共汽车"),
-    (&"gongsi", &"公司"),
-    (&"gui", &"贵"),
-    (&"guo", &"过"),
-    (&"hai", &"还"),
-    (&"haizi", &"孩子"),
-    (&"haochi", &"好吃"),
-    (&"hei", &"黑"),
-    (&"hong", &"红"),
-    (&"huochezhan", &"火车站"),
-    (&"jichang", &"机场"),
-    (&"jidan", &"鸡蛋"),
-    (&"jian", &"件"),
-    (&"juede", &"觉得"),
-    (&"jiaoshi", &"教室"),
-    (&"jiejie", &"姐姐"),
-    (&"jieshao", &"介绍"),
-    (&"jin", &"进"),
-    (&"jin", &"近"),
-    (&"jiu", &"就"),
-    (&"kafei", &"咖啡"),
-    (&"kaishi", &"开始"),
-    (&"kaoshi", &"考试"),
-    (&"keneng", &"可能"),
-    (&"keyi", &"可以"),
-    (&"ke", &"课"),
-    (&"kuai", &"快"),
-    (&"kuaile", &"快乐"),
-    (&"lei", &"累"),
-    (&"li", &"离"),
-    (&"liang", &"两"),
-    (&"ling", &"零"),
-    (&"lu", &"路"),
-    (&"lvyou", &"旅游"),
-    (&"mai", &"卖"),
-    (&"man", &"慢"),
-    (&"mang", &"忙"),
-    (&"mei", &"每"),
-    (&"meimei", &"妹妹"),
-    (&"men", &"门"),
-    (&"miantiao", &"面条"),
-    (&"nan", &"男"),
-    (&"nin", &"您"),
-    (&"niunai", &"牛奶"),
-    (&"nv", &"女"),
-    (&"pangbian", &"旁边"),
-    (&"paobu", &"跑步"),
-    (&"pianyi", &"便宜"),
-    (&"piao", &"票"),
-    (&"qizi", &"妻子"),
-    (&"qichuang", &"起床"),
-    (&"qian", &"千"),
-    (&"qianbi", &"铅笔"),
-    (&"qing", &"晴"),
-    (&"qunian", &"去年"),
-    (&"rang", &"让"),
-    (&"ri", &"日"),
-    (&"shangban", &"上班"),
-    (&"shenti", &"身体"),
-    (&"shengbing", &"生病"),
-    (&"shengri", &"生日"),
-    (&"shijian", &"时间"),
-    (&"shiqing", &"事情"),
-    (&"shoubiao", &"手表"),
-    (&"shouji", &"手机"),
-    (&"shuohua", &"说话"),
-    (&"song", &"送"),
-    (&"suiran", &"虽然"),
-    (&"suoyi", &"所以"),
-    (&"ta", &"它"),
-    (&"tizuqiu", &"踢足球"),
-    (&"ti", &"题"),
-    (&"tiaowu", &"跳舞"),
-    (&"wai", &"外"),
-    (&"wan", &"完"),
-    (&"wan", &"玩"),
-    (&"wanr", &"玩儿"),
-    (&"wanshang", &"晚上"),
-    (&"wang", &"往"),
-    (&"weishenme", &"为什么"),
-    (&"wen", &"问"),
-    (&"wenti", &"问题"),
-    (&"xigua", &"西瓜"),
-    (&"xiwang", &"希望"),
-    (&"xi", &"洗"),
-    (&"xiaoshi", &"小时"),
-    (&"xiao", &"笑"),
-    (&"xin", &"新"),
-    (&"xing", &"姓"),
-    (&"xiuxi", &"休息"),
-    (&"xue", &"雪"),
-    (&"yanse", &"颜色"),
-    (&"yanjing", &"眼睛"),
-    (&"yangrou", &"羊肉"),
-    (&"yao", &"药"),
-    (&"yao", &"要"),
-    (&"ye", &"也"),
-    (&"yixia", &"一下"),
-    (&"yijing", &"已经"),
-    (&"yiqi", &"一起"),
-    (&"yisi", &"意思"),
-    (&"yinwei", &"因为"),
-    (&"yin", &"阴"),
-    (&"youyong", &"游泳"),
-    (&"youbian", &"右边"),
-    (&"yu", &"鱼"),
-    (&"yuan", &"远"),
-    (&"yundong", &"运动"),
-    (&"zai", &"再"),
-    (&"zaoshang", &"早上"),
-    (&"zhangfu", &"丈夫"),
-    (&"zhao", &"找"),
-    (&"zhe", &"着"),
-    (&"zhen", &"真"),
-    (&"zhengzai", &"正在"),
-    (&"zhidao", &"知道"),
-    (&"zhunbei", &"准备"),
-    (&"zou", &"走"),
-    (&"zui", &"最"),
-    (&"zuobian", &"左边"),
-    (&"huahua", &"花花"),
-    (&"yang", &"杨"),
-    (&"xiaoxiao", &"笑笑"),
-    (&"du", &"度"),
-    (&"fense", &"粉色"),
-    (&"yihou", &"以后"),
-    (&"bang", &"帮"),
-    (&"bucuo", &"不错"),
-    (&"chuyuan", &"出院"),
-    (&"fen", &"粉"),
-    (&"hongse", &"红色"),
-    (&"lukou", &"路口"),
-    (&"shou", &"手"),
-    (&"waimian", &"外面"),
-    (&"xinnian", &"新年"),
-    (&"youyisi", &"有意思"),
-    (&"ayi", &"阿姨"),
-    (&"a", &"啊"),
-    (&"ai", &"矮"),
-    (&"aihao", &"爱好"),
-    (&"anjing", &"安静"),
-    (&"ba", &"把"),
-    (&"ban", &"班"),
-    (&"ban", &"搬"),
-    (&"banfa", &"办法"),
-    (&"bangongshi", &"办公室"),
-    (&"ban", &"半"),
-    (&"bangmang", &"帮忙"),
-    (&"bao", &"包"),
-    (&"bao", &"饱"),
-    (&"beifang", &"北方"),
-    (&"bei", &"被"),
-    (&"bizi", &"鼻子"),
-    (&"bijiao", &"比较"),
-    (&"bisai", &"比赛"),
-    (&"bijiben", &"笔记本"),
-    (&"bixu", &"必须"),
-    (&"bianhua", &"变化"),
-    (&"bieren", &"别人"),
-    (&"bingxiang", &"冰箱"),
-    (&"budan", &"不但"),
-    (&"erqie", &"而且"),
-    (&"caidan", &"菜单"),
-    (&"canjia", &"参加"),
-    (&"cao", &"草"),
-    (&"ceng", &"层"),
-    (&"cha", &"差"),
-    (&"chaoshi", &"超市"),
-    (&"chenshan", &"衬衫"),
-    (&"chengji", &"成绩"),
-    (&"chengshi", &"城市"),
-    (&"chidao", &"迟到"),
-    (&"chule", &"除了"),
-    (&"chuan", &"船"),
-    (&"chun", &"春"),
-    (&"tian", &"天"),
-    (&"cidian", &"词典"),
-    (&"congming", &"聪明"),
-    (&"dasao", &"打扫"),
-    (&"dasuan", &"打算"),
-    (&"dai", &"带"),
-    (&"danxin", &"担心"),
-    (&"dangao", &"蛋糕"),
-    (&"dangran", &"当然"),
-    (&"de", &"地"),
-    (&"deng", &"灯"),
-    (&"difang", &"地方"),
-    (&"ditie", &"地铁"),
-    (&"ditu", &"地图"),
-    (&"dianti", &"电梯"),
-    (&"dianziyoujian", &"电子邮件"),
-    (&"dong", &"东"),
-    (&"dong", &"冬"),
-    (&"dongwu", &"动物"),
-    (&"duan", &"短"),
-    (&"duan", &"段"),
-    (&"duanlian", &"锻炼"),
-    (&"duome", &"多么"),
-    (&"e", &"饿"),
-    (&"erduo", &"耳朵"),
-    (&"fa", &"发"),
-    (&"fashao", &"发烧"),
-    (&"faxian", &"发现"),
-    (&"fangbian", &"方便"),
-    (&"fang", &"放"),
-    (&"fangxin", &"放心"),
-    (&"fen", &"分"),
-    (&"fujin", &"附近"),
-    (&"fuxi", &"复习"),
-    (&"ganjing", &"干净"),
-    (&"ganmao", &"感冒"),
-    (&"ganxingqu", &"感兴趣"),
-    (&"gangcai", &"刚才"),
-    (&"gezi", &"个子"),
-    (&"genju", &"根据"),
-    (&"gen", &"跟"),
-    (&"geng", &"更"),
-    (&"gongjin", &"公斤"),
-    (&"gongyuan", &"公园"),
-    (&"gushi", &"故事"),
-    (&"guafeng", &"刮风"),
-    (&"guan", &"关"),
-    (&"guanxi", &"关系"),
-    (&"guanxin", &"关心"),
-    (&"guanyu", &"关于"),
-    (&"guojia", &"国家"),
-    (&"guo", &"过"),
-    (&"guoqu", &"过去"),
-    (&"haishi", &"还是"),
-    (&"haipa", &"害怕"),
-    (&"heiban", &"黑板"),
-    (&"houlai", &"后来"),
-    (&"huzhao", &"护照"),
-    (&"hua", &"花"),
-    (&"hua", &"画"),
-    (&"huai", &"坏"),
-    (&"huanying", &"欢迎"),
-    (&"huan", &"还"),
-    (&"huanjing", &"环境"),
-    (&"huan", &"换"),
-    (&"huida", &"回答"),
-    (&"huiyi", &"会议"),
-    (&"huozhe", &"或者"),
-    (&"jihu", &"几乎"),
-    (&"jihui", &"机会"),
-    (&"ji", &"极"),
-    (&"jide", &"记得"),
-    (&"jijie", &"季节"),
-    (&"jiancha", &"检查"),
-    (&"jiandan", &"简单"),
-    (&"jianmian", &"见面"),
-    (&"jiankang", &"健康"),
-    (&"jiang", &"讲"),
-    (&"jiao", &"教"),
-    (&"jiao", &"角"),
-    (&"jiao", &"脚"),
-    (&"jie", &"接"),
-    (&"jidao", &"街道"),
-    (&"jiemu", &"节目"),
-    (&"jieri", &"节日"),
-    (&"jiehun", &"结婚"),
-    (&"jieshu", &"结束"),
-    (&"jiejue", &"解决"),
-    (&"jie", &"借"),
-    (&"jingchang", &"经常"),
-    (&"jingguo", &"经过"),
-    (&"jingli", &"经理"),
-    (&"jiu", &"久"),
-    (&"jiu", &"旧"),
-    (&"juzi", &"句子"),
-    (&"jueding", &"决定"),
-    (&"keai", &"可爱"),
-    (&"ke", &"渴"),
-    (&"ke", &"刻"),
-    (&"keren", &"客人"),
-    (&"kongtiao", &"空调"),
-    (&"kou", &"口"),
-    (&"ku", &"哭"),
-    (&"kuzi", &"裤子"),
-    (&"kuaizi", &"筷子"),
-    (&"lan", &"蓝"),
-    (&"lao", &"老"),
-    (&"likai", &"离开"),
-    (&"liwu", &"礼物"),
-    (&"lishi", &"历史"),
-    (&"lian", &"脸"),
-    (&"lianxi", &"练习"),
-    (&"liang", &"辆"),
-    (&"liaotian", &"聊天"),
-    (&"liaotianr", &"聊天儿"),
-    (&"liaojie", &"了解"),
-    (&"linju", &"邻居"),
-    (&"liuxue", &"留学"),
-    (&"lou", &"楼"),
-    (&"lv", &"绿"),
-    (&"ma", &"马"),
-    (&"mashang", &"马上"),
-    (&"manyi", &"满意"),
-    (&"maozi", &"帽子"),
-    (&"mi", &"米"),
-    (&"mianbao", &"面包"),
-    (&"mingbai", &"明白"),
-    (&"na", &"拿"),
-    (&"nainai", &"奶奶"),
-    (&"nan", &"南"),
-    (&"nan", &"难"),
-    (&"nanguo", &"难过"),
-    (&"nianji", &"年级"),
-    (&"nianqing", &"年轻"),
-    (&"niao", &"鸟"),
-    (&"nuli", &"努力"),
-    (&"pashan", &"爬山"),
-    (&"panzi", &"盘子"),
-    (&"pang", &"胖"),
-    (&"pixie", &"皮鞋"),
-    (&"pijiu", &"啤酒"),
-    (&"pingzi", &"瓶子"),
-    (&"qishi", &"其实"),
-    (&"qita", &"其他"),
-    (&"qiguai", &"奇怪"),
-    (&"qi", &"骑"),
-    (&"qifei", &"起飞"),
-    (&"qilai", &"起来"),
-    (&"qingchu", &"清楚"),
-    (&"qingjia", &"请假"),
-    (&"qiu", &"秋"),
-    (&"qunzi", &"裙子"),
-    (&"ranhou", &"然后"),
-    (&"reqing", &"热情"),
-    (&"renwei", &"认为"),
-    (&"renzhen", &"认真"),
-    (&"rongyi", &"容易"),
-    (&"ruguo", &"如果"),
-    (&"san", &"伞"),
-    (&"shangwang", &"上网"),
-    (&"shengqi", &"生气"),
-    (&"shengyin", &"声音"),
-    (&"shijie", &"世界"),
-    (&"shi", &"试"),
-    (&"shou", &"瘦"),
-    (&"shushu", &"叔叔"),
-    (&"shufu", &"舒服"),
-    (&"shu", &"树"),
-    (&"shuxue", &"数学"),
-    (&"shuaya", &"刷牙"),
-    (&"shuang", &"双"),
-    (&"shuiping", &"水平"),
-    (&"siji", &"司机"),
-    (&"taiyang", &"太阳"),
-    (&"tebie", &"特别"),
-    (&"teng", &"疼"),
-    (&"tigao", &"提高"),
-    (&"tiyu", &"体育"),
-    (&"tian", &"甜"),
-    (&"tiao", &"条"),
-    (&"tongshi", &"同事"),
-    (&"tongyi", &"同意"),
-    (&"toufa", &"头发"),
-    (&"turan", &"突然"),
-    (&"tushuguan", &"图书馆"),
-    (&"tui", &"腿"),
-    (&"wancheng", &"完成"),
-    (&"wan", &"碗"),
-    (&"wan", &"万"),
-    (&"wangji", &"忘记"),
-    (&"wei", &"为"),
-    (&"weile", &"为了"),
-    (&"wei", &"位"),
-    (&"wenhua", &"文化"),
-    (&"xi", &"西"),
-    (&"xiguan", &"习惯"),
-    (&"xishoujian", &"洗手间"),
-    (&"xizao", &"洗澡"),
-    (&"xia", &"夏"),
-    (&"xian", &"先"),
-    (&"xiangxin", &"相信"),
-    (&"xiangjiao", &"香蕉"),
-    (&"xiang", &"向"),
-    (&"xiang", &"像"),
-    (&"xiaoxin", &"小心"),
-    (&"xiaozhang", &"校长"),
-    (&"xinwen", &"新闻"),
-    (&"xinxian", &"新鲜"),
-    (&"xinyongka", &"信用卡"),
-    (&"xinglixiang", &"行李箱"),
-    (&"xiongmao", &"熊猫"),
-    (&"xuyao", &"需要"),
-    (&"xuanze", &"选择"),
-    (&"yaoqiu", &"要求"),
-    (&"yeye", &"爷爷"),
-    (&"yiding", &"一定"),
-    (&"yigong", &"一共"),
-    (&"yihuir", &"一会儿"),
-    (&"yihuier", &"一会儿"),
-    (&"yiyang", &"一样"),
-    (&"yiqian", &"以前"),
-    (&"yiban", &"一般"),
-    (&"yibian", &"一边"),
-    (&"yizhi", &"一直"),
-    (&"yinyue", &"音乐"),
-    (&"yinhang", &"银行"),
-    (&"yinliao", &"饮料"),
-    (&"yinggai", &"应该"),
-    (&"yingxiang", &"影响"),
-    (&"yong", &"用"),
-    (&"youxi", &"游戏"),
-    (&"youming", &"有名"),
-    (&"you", &"又"),
-    (&"yudao", &"遇到"),
-    (&"yuan", &"元"),
-    (&"yuanyi", &"愿意"),
-    (&"yueliang", &"月亮"),
-    (&"yue", &"越"),
-    (&"zhan", &"站"),
-    (&"zhang", &"张"),
-    (&"zhang", &"长"),
-    (&"zhaoji", &"着急"),
-    (&"zhaogu", &"照顾"),
-    (&"zhaopian", &"照片"),
-    (&"zhaoxiangji", &"照相机"),
-    (&"zhi", &"只"),
-    (&"zhiyou", &"只有"),
-    (&"cai", &"才"),
-    (&"zhongjian", &"中间"),
-    (&"zhongyu", &"终于"),
-    (&"zhong", &"种"),
-    (&"zhongyao", &"重要"),
-    (&"zhoumo", &"周末"),
-    (&"zhuyao", &"主要"),
-    (&"zhuyi", &"注意"),
-    (&"ziji", &"自己"),
-    (&"zixingche", &"自行车"),
-    (&"zongshi", &"总是"),
-    (&"zui", &"嘴"),
-    (&"zuihou", &"最后"),
-    (&"zuijin", &"最近"),
-    (&"zuoye", &"作业"),
-    (&"huanghe", &"黄河"),
-    (&"zhongwen", &"中文"),
-    (&"shan", &"山"),
-    (&"huang", &"黄"),
-    (&"he", &"河"),
-    (&"jing", &"静"),
-    (&"ke", &"可"),
-    (&"li", &"丽"),
-    (&"ming", &"明"),
-    (&"zhou", &"周"),
-    (&"kele", &"可乐"),
-    (&"shuizhao", &"睡着"),
-    (&"banshi", &"办事"),
-    (&"bian", &"变"),
-    (&"chunjie", &"春节"),
-    (&"da", &"打"),
-    (&"daoshihou", &"到时候"),
-    (&"fangzi", &"房子"),
-    (&"gewu", &"歌舞"),
-    (&"hongjiu", &"红酒"),
-    (&"jiu", &"酒"),
-    (&"ju", &"句"),
-    (&"meitian", &"每天"),
-    (&"nali", &"哪里"),
-    (&"nvhair", &"女孩儿"),
-    (&"nvhai", &"女孩"),
-    (&"qianbao", &"钱包"),
-    (&"renming", &"人名"),
-    (&"ren", &"认"),
-    (&"tingshuo", &"听说"),
-    (&"waidi", &"外地"),
-    (&"xianhua", &"鲜花"),
-    (&"xianggua", &"香瓜"),
-    (&"yibanlaishuo", &"一般来说"),
-    (&"yinyuehui", &"音乐会"),
-    (&"youdianr", &"有点儿"),
-    (&"youdian", &"有点"),
-    (&"zao", &"早"),
-    (&"zenmeban", &"怎么办"),
-    (&"zhongqiujie", &"中秋节"),
-    (&"aiqing", &"爱情"),
-    (&"anpai", &"安排"),
-    (&"anshi", &"按时"),
-    (&"anzhao", &"按照"),
-    (&"baifenzhi", &"百分之"),
-    (&"baozheng", &"保证"),
-    (&"bei", &"倍"),
-    (&"benlai", &"本来"),
-    (&"biru", &"比如"),
-    (&"biye", &"毕业"),
-    (&"biaozhun", &"标准"),
-    (&"binggan", &"饼干"),
-    (&"budebu", &"不得不"),
-    (&"buguan", &"不管"),
-    (&"buguo", &"不过"),
-    (&"bujin", &"不仅"),
-    (&"ca", &"擦"),
-    (&"cailiao", &"材料"),
-    (&"chabuduo", &"差不多"),
-    (&"chang", &"尝"),
-    (&"chaoguo", &"超过"),
-    (&"chenggong", &"成功"),
-    (&"chengwei", &"成为"),
-    (&"chengshi", &"诚实"),
-    (&"chongxin", &"重新"),
-    (&"chouyan", &"抽烟"),
-    (&"chuxian", &"出现"),
-    (&"chuanghu", &"窗户"),
-    (&"conglai", &"从来"),
-    (&"daan", &"答案"),
-    (&"darao", &"打扰"),
-    (&"dazhe", &"打折"),
-    (&"dashiguan", &"大使馆"),
-    (&"daifu", &"大夫"),
-    (&"dang", &"当"),
-    (&"dangshi", &"当时"),
-    (&"daochu", &"到处"),
-    (&"dei", &"得"),
-    (&"deng", &"等"),
-    (&"di", &"低"),
-    (&"diaocha", &"调查"),
-    (&"diao", &"掉"),
-    (&"dongzuo", &"动作"),
-    (&"duche", &"堵车"),
-    (&"duzi", &"肚子"),
-    (&"duanxin", &"短信"),
-    (&"er", &"而"),
-    (&"fasheng", &"发生"),
-    (&"fazhan", &"发展"),
-    (&"falv", &"法律"),
-    (&"fanyi", &"翻译"),
-    (&"fannao", &"烦恼"),
-    (&"fandui", &"反对"),
-    (&"fangfa", &"方法"),
-    (&"fangmian", &"方面"),
-    (&"fangxiang", &"方向"),
-    (&"fangqi", &"放弃"),
-    (&"fangsong", &"放松"),
-    (&"fen", &"份"),
-    (&"fengfu", &"丰富"),
-    (&"fuhe", &"符合"),
-    (&"fuze", &"负责"),
-    (&"fu", &"富"),
-    (&"gaibian", &"改变"),
-    (&"gandong", &"感动"),
-    (&"ganjue", &"感觉"),
-    (&"ganqing", &"感情"),
-    (&"ganxie", &"感谢"),
-    (&"gang", &"刚"),
-    (&"ge", &"各"),
-    (&"gongzi", &"工资"),
-    (&"gongtong", &"共同"),
-    (&"gouwu", &"购物"),
-    (&"gou", &"够"),
-    (&"guji", &"估计"),
-    (&"guke", &"顾客"),
-    (&"guanjian", &"关键"),
-    (&"guang", &"光"),
-    (&"guanggao", &"广告"),
-    (&"guang", &"逛"),
-    (&"guoji", &"国际"),
-    (&"guozhi", &"果汁"),
-    (&"guocheng", &"过程"),
-    (&"han", &"汗"),
-    (&"haochu", &"好处"),
-    (&"haoxiang", &"好像"),
-    (&"houhui", &"后悔"),
-    (&"huxiang", &"互相"),
-    (&"huiyi", &"回忆"),
-    (&"huodong", &"活动"),
-    (&"huode", &"获得"),
-    (&"jiji", &"积极"),
-    (&"jilei", &"积累"),
-    (&"jishi", &"及时"),
-    (&"jishi", &"即使"),
-    (&"jihua", &"计划"),
-    (&"ji", &"寄"),
-    (&"jiaban", &"加班"),
-    (&"jiaju", &"家具"),
-    (&"jiage", &"价格"),
-    (&"jianchi", &"坚持"),
-    (&"jianfei", &"减肥"),
-    (&"jianyi", &"建议"),
-    (&"jianglai", &"将来"),
-    (&"jiangjin", &"奖金"),
-    (&"jiangdi", &"降低"),
-    (&"jiao", &"交"),
-    (&"jiaoliu", &"交流"),
-    (&"jiaoshou", &"教授"),
-    (&"jieshou", &"接受"),
-    (&"jieguo", &"结果"),
-    (&"jinguan", &"尽管"),
-    (&"jinzhang", &"紧张"),
-    (&"jingji", &"经济"),
-    (&"jingli", &"经历"),
-    (&"jingyan", &"经验"),
-    (&"jingse", &"景色"),
-    (&"jingran", &"竟然"),
-    (&"jingzi", &"镜子"),
-    (&"juban", &"举办"),
-    (&"juxing", &"举行"),
-    (&"juli", &"距离"),
-    (&"juhui", &"聚会"),
-    (&"kaiwanxiao", &"开玩笑"),
-    (&"kaolv", &"考虑"),
-    (&"kexue", &"科学"),
-    (&"kesou", &"咳嗽"),
-    (&"keshi", &"可是"),
-    (&"kending", &"肯定"),
-    (&"kongqi", &"空气"),
-    (&"kongr", &"空儿"),
-    (&"kun", &"困"),
-    (&"kunnan", &"困难"),
-    (&"la", &"拉"),
-    (&"laibuji", &"来不及"),
-    (&"langfei", &"浪费"),
-    (&"langman", &"浪漫"),
-    (&"libaitian", &"礼拜天"),
-    (&"lijie", &"理解"),
-    (&"lixiang", &"理想"),
-    (&"liru", &"例如"),
-    (&"lia", &"俩"),
-    (&"lianxi", &"联系"),
-    (&"lingwai", &"另外"),
-    (&"liu", &"留"),
-    (&"liuxing", &"流行"),
-    (&"lvshi", &"律师"),
-    (&"luan", &"乱"),
-    (&"mafan", &"麻烦"),
-    (&"man", &"满"),
-    (&"mianfei", &"免费"),
-    (&"muqin", &"母亲"),
-    (&"naixin", &"耐心"),
-    (&"nandao", &"难道"),
-    (&"nei", &"内"),
-    (&"nengli", &"能力"),
-    (&"nianling", &"年龄"),
-    (&"panduan", &"判断"),
-    (&"pei", &"陪"),
-    (&"pifu", &"皮肤"),
-    (&"piqi", &"脾气"),
-    (&"pian", &"篇"),
-    (&"pingshi", &"平时"),
-    (&"putao", &"葡萄"),
-    (&"qici", &"其次"),
-    (&"qizhong", &"其中"),
-    (&"qihou", &"气候"),
-    (&"qiaokeli", &"巧克力"),
-    (&"qinqi", &"亲戚"),
-    (&"qing", &"轻"),
-    (&"qingsong", &"轻松"),
-    (&"qingkuang", &"情况"),
-    (&"qiong", &"穷"),
-    (&"qubie", &"区别"),
-    (&"qu", &"取"),
-    (&"quedian", &"缺点"),
-    (&"queshao", &"缺少"),
-    (&"que", &"却"),
-    (&"queshi", &"确实"),
-    (&"renhe", &"任何"),
-    (&"sanbu", &"散步"),
-    (&"shafa", &"沙发"),
-    (&"shangxin", &"伤心"),
-    (&"shen", &"深"),
-    (&"shenzhi", &"甚至"),
-    (&"shenghuo", &"生活"),
-    (&"shengming", &"生命"),
-    (&"shengyi", &"生意"),
-    (&"shibai", &"失败"),
-    (&"shifu", &"师傅"),
-    (&"shiji", &"实际"),
-    (&"shizai", &"实在"),
-    (&"shi", &"使"),
-    (&"shihe", &"适合"),
-    (&"shiying", &"适应"),
-    (&"shouru", &"收入"),
-    (&"shouxian", &"首先"),
-    (&"shoudao", &"受到"),
-    (&"shouhuoyuan", &"售货员"),
-    (&"shuxi", &"熟悉"),
-    (&"shuzi", &"数字"),
-    (&"shuai", &"帅"),
-    (&"shunbian", &"顺便"),
-    (&"shunli", &"顺利"),
-    (&"shuoming", &"说明"),
-    (&"shuoshi", &"硕士"),
-    (&"suibian", &"随便"),
-    (&"suoyou", &"所有"),
-    (&"tai", &"台"),
-    (&"taidu", &"态度"),
-    (&"tan", &"谈"),
-    (&"tang", &"糖"),
-    (&"tang", &"躺"),
-    (&"taoyan", &"讨厌"),
-    (&"tedian", &"特点"),
-    (&"ti", &"提"),
-    (&"tigong", &"提供"),
-    (&"tiqian", &"提前"),
-    (&"tixing", &"提醒"),
-    (&"tiaojian", &"条件"),
-    (&"ting", &"挺"),
-    (&"tongguo", &"通过"),
-    (&"tongzhi", &"通知"),
-    (&"wazi", &"袜子"),
-    (&"wanquan", &"完全"),
-    (&"wangqiu", &"网球"),
-    (&"wangwang", &"往往"),
-    (&"weidao", &"味道"),
-    (&"wuliao", &"无聊"),
-    (&"xihongshi", &"西红柿"),
-    (&"xiyin", &"吸引"),
-    (&"xianjin", &"现金"),
-    (&"xianmu", &"羡慕"),
-    (&"xiangpi", &"橡皮"),
-    (&"xiaoxi", &"消息"),
-    (&"xiaoshuo", &"小说"),
-    (&"xiaoguo", &"效果"),
-    (&"xinqing", &"心情"),
-    (&"xinku", &"辛苦"),
-    (&"xinxin", &"信心"),
-    (&"xingfen", &"兴奋"),
-    (&"xingfu", &"幸福"),
-    (&"xingge", &"性格"),
-    (&"xiuli", &"修理"),
-    (&"xuduo", &"许多"),
-    (&"yali", &"压力"),
-    (&"yanzhong", &"严重"),
-    (&"yanjiu", &"研究"),
-    (&"yangguang", &"阳光"),
-    (&"yangzi", &"样子"),
-    (&"yaoqing", &"邀请"),
-    (&"yaoshi", &"要是"),
-    (&"yiqie", &"一切"),
-    (&"yiwei", &"以为"),
-    (&"yishu", &"艺术"),
-    (&"yinci", &"因此"),
-    (&"yinxiang", &"印象"),
-    (&"ying", &"赢"),
-    (&"yingpin", &"应聘"),
-    (&"yongyuan", &"永远"),
-    (&"yonggan", &"勇敢"),
-    (&"youdian", &"优点"),
-    (&"youxiu", &"优秀"),
-    (&"youmo", &"幽默"),
-    (&"youqi", &"尤其"),
-    (&"youyu", &"由于"),
-    (&"youyi", &"友谊"),
-    (&"yukuai", &"愉快"),
-    (&"yu", &"与"),
-    (&"yuanlai", &"原来"),
-    (&"yuanyin", &"原因"),
-    (&"yuehui", &"约会"),
-    (&"zanmen", &"咱们"),
-    (&"zanshi", &"暂时"),
-    (&"zeren", &"责任"),
-    (&"zhaopin", &"招聘"),
-    (&"zhenzheng", &"真正"),
-    (&"zhenghao", &"正好"),
-    (&"zhengque", &"正确"),
-    (&"zhengshi", &"正式"),
-    (&"zhengming", &"证明"),
-    (&"zhichi", &"支持"),
-    (&"zhishi", &"知识"),
-    (&"zhide", &"值得"),
-    (&"zhiye", &"职业"),
-    (&"zhiwu", &"植物"),
-    (&"zhiyao", &"只要"),
-    (&"zhi", &"指"),
-    (&"zhishao", &"至少"),
-    (&"zhiliang", &"质量"),
-    (&"zhouwei", &"周围"),
-    (&"zhuyi", &"主意"),
-    (&"zhuanmen", &"专门"),
-    (&"zhuanye", &"专业"),
-    (&"zhuan", &"赚"),
-    (&"zhunshi", &"准时"),
-    (&"ziran", &"自然"),
-    (&"zongjie", &"总结"),
-    (&"zuihao", &"最好"),
-    (&"zuojia", &"作家"),
-    (&"anquan", &"安全"),
-    (&"bang", &"棒"),
-    (&"baozi", &"包子"),
-    (&"baohu", &"保护"),
-    (&"baoming", &"报名"),
-    (&"bao", &"抱"),
-    (&"baoqian", &"抱歉"),
-    (&"ben", &"笨"),
-    (&"bian", &"遍"),
-    (&"biaoge", &"表格"),
-    (&"biaoshi", &"表示"),
-    (&"biaoyan", &"表演"),
-    (&"biaoyang", &"表扬"),
-    (&"bingqie", &"并且"),
-    (&"boshi", &"博士"),
-    (&"bufen", &"部分"),
-    (&"cai", &"猜"),
-    (&"canguan", &"参观"),
-    (&"canting", &"餐厅"),
-    (&"cesuo", &"厕所"),
-    (&"chang", &"场"),
-    (&"chengzuo", &"乘坐"),
-    (&"chijing", &"吃惊"),
-    (&"chuchai", &"出差"),
-    (&"chufa", &"出发"),
-    (&"chusheng", &"出生"),
-    (&"chufang", &"厨房"),
-    (&"chuanzhen", &"传真"),
-    (&"ciyu", &"词语"),
-    (&"cuxin", &"粗心"),
-    (&"cun", &"存"),
-    (&"cuowu", &"错误"),
-    (&"daban", &"打扮"),
-    (&"dayin", &"打印"),
-    (&"dazhaohu", &"打招呼"),
-    (&"dazhen", &"打针"),
-    (&"dagai", &"大概"),
-    (&"dayue", &"大约"),
-    (&"dai", &"戴"),
-    (&"dao", &"刀"),
-    (&"daoyou", &"导游"),
-    (&"daodi", &"到底"),
-    (&"dao", &"倒"),
-    (&"daoqian", &"道歉"),
-    (&"deyi", &"得意"),
-    (&"dengjipai", &"登机牌"),
-    (&"di", &"底"),
-    (&"didian", &"地点"),
-    (&"diqiu", &"地球"),
-    (&"dizhi", &"地址"),
-    (&"diu", &"丢"),
-    (&"duihua", &"对话"),
-    (&"duimian", &"对面"),
-    (&"duiyu", &"对于"),
-    (&"ertong", &"儿童"),
-    (&"fangdong", &"房东"),
-    (&"fangshujia", &"放暑假"),
-    (&"fouze", &"否则"),
-    (&"fuqin", &"父亲"),
-    (&"fukuan", &"付款"),
-    (&"fuyin", &"复印"),
-    (&"fuza", &"复杂"),
-    (&"ganbei", &"干杯"),
-    (&"gan", &"赶"),
-    (&"gan", &"敢"),
-    (&"gan", &"干"),
-    (&"gaosugonglu", &"高速公路"),
-    (&"gebo", &"胳膊"),
-    (&"gongli", &"公里"),
-    (&"gongfu", &"功夫"),
-    (&"guli", &"鼓励"),
-    (&"guyi", &"故意"),
-    (&"gua", &"挂"),
-    (&"guanzhong", &"观众"),
-    (&"guanli", &"管理"),
-    (&"guangbo", &"广播"),
-    (&"guiding", &"规定"),
-    (&"guoji", &"国籍"),
-    (&"haiyang", &"海洋"),
-    (&"haixiu", &"害羞"),
-    (&"hanjia", &"寒假"),
-    (&"hangban", &"航班"),
-    (&"haoma", &"号码"),
-    (&"hege", &"合格"),
-    (&"heshi", &"合适"),
-    (&"hezi", &"盒子"),
-    (&"hou", &"厚"),
-    (&"hulianwang", &"互联网"),
-    (&"hushi", &"护士"),
-    (&"huaiyi", &"怀疑"),
-    (&"huopo", &"活泼"),
-    (&"huo", &"火"),
-    (&"jichu", &"基础"),
-    (&"jidong", &"激动"),
-    (&"jizhe", &"记者"),
-    (&"jishu", &"技术"),
-    (&"jiran", &"既然"),
-    (&"jixu", &"继续"),
-    (&"jiayouzhan", &"加油站"),
-    (&"jia", &"假"),
-    (&"jianshao", &"减少"),
-    (&"jiangluo", &"降落"),
-    (&"jiaotong", &"交通"),
-    (&"jiaoqu", &"郊区"),
-    (&"jiaoao", &"骄傲"),
-    (&"jiaozi", &"饺子"),
-    (&"jiaoyu", &"教育"),
-    (&"jiezhe", &"接着"),
-    (&"jie", &"节"),
-    (&"jieyue", &"节约"),
-    (&"jieshi", &"解释"),
-    (&"jinxing", &"进行"),
-    (&"jinzhi", &"禁止"),
-    (&"jingju", &"京剧"),
-    (&"jingcai", &"精彩"),
-    (&"jingcha", &"警察"),
-    (&"jingzheng", &"竞争"),
-    (&"jiujing", &"究竟"),
-    (&"ju", &"举"),
-    (&"jujue", &"拒绝"),
-    (&"kaixin", &"开心"),
-    (&"kanfa", &"看法"),
-    (&"kaoya", &"烤鸭"),
-    (&"ke", &"棵"),
-    (&"kelian", &"可怜"),
-    (&"kexi", &"可惜"),
-    (&"keting", &"客厅"),
-    (&"kong", &"空"),
-    (&"kongpa", &"恐怕"),
-    (&"ku", &"苦"),
-    (&"kuangquanshui", &"矿泉水"),
-    (&"lajitong", &"垃圾桶"),
-    (&"la", &"辣"),
-    (&"laideji", &"来得及"),
-    (&"laizi", &"来自"),
-    (&"lan", &"懒"),
-    (&"laohu", &"老虎"),
-    (&"lengjing", &"冷静"),
-    (&"limao", &"礼貌"),
-    (&"lifa", &"理发"),
-    (&"liqi", &"力气"),
-    (&"lihai", &"厉害"),
-    (&"lian", &"连"),
-    (&"liangkuai", &"凉快"),
-    (&"lingqian", &"零钱"),
-    (&"liuli", &"流利"),
-    (&"lvxing", &"旅行"),
-    (&"mahu", &"马虎"),
-    (&"mao", &"毛"),
-    (&"maojin", &"毛巾"),
-    (&"meili", &"美丽"),
-    (&"meng", &"梦"),
-    (&"milu", &"迷路"),
-    (&"mima", &"密码"),
-    (&"miao", &"秒"),
-    (&"minzu", &"民族"),
-    (&"mudi", &"目的"),
-    (&"nanshou", &"难受"),
-    (&"neirong", &"内容"),
-    (&"nong", &"弄"),
-    (&"nuanhuo", &"暖和"),
-    (&"ouer", &"偶尔"),
-    (&"paidui", &"排队"),
-    (&"pailie", &"排列"),
-    (&"piping", &"批评"),
-    (&"pian", &"骗"),
-    (&"pingpangqiu", &"乒乓球"),
-    (&"po", &"破"),
-    (&"pubian", &"普遍"),
-    (&"putonghua", &"普通话"),
-    (&"qianwan", &"千万"),
-    (&"qianzheng", &"签证"),
-    (&"qiao", &"敲"),
-    (&"qiao", &"桥"),
-    (&"quanbu", &"全部"),
-    (&"raner", &"然而"),
-    (&"renao", &"热闹"),
-    (&"renwu", &"任务"),
-    (&"reng", &"扔"),
-    (&"rengran", &"仍然"),
-    (&"riji", &"日记"),
-    (&"rukou", &"入口"),
-    (&"senlin", &"森林"),
-    (&"shangliang", &"商量"),
-    (&"shaowei", &"稍微"),
-    (&"shao", &"勺"),
-    (&"shaozi", &"勺子"),
-    (&"shehui", &"社会"),
-    (&"shenqing", &"申请"),
-    (&"sheng", &"省"),
-    (&"sheng", &"省"),
-    (&"sheng", &"剩"),
-    (&"shiwang", &"失望"),
-    (&"shifen", &"十分"),
-    (&"shiyong", &"使用"),
-    (&"shiji", &"世纪"),
-    (&"shifou", &"是否"),
-    (&"shou", &"收"),
-    (&"shoushi", &"收拾"),
-    (&"shoudu", &"首都"),
-    (&"shoubuliao", &"受不了"),
-    (&"shu", &"输"),
-    (&"shuliang", &"数量"),
-    (&"shunxu", &"顺序"),
-    (&"si", &"死"),
-    (&"sudu", &"速度"),
-    (&"suliaodai", &"塑料袋"),
-    (&"suan", &"酸"),
-    (&"suizhe", &"随着"),
-    (&"sunzi", &"孙子"),
-    (&"tai", &"抬"),
-    (&"tangangqin", &"弹钢琴"),
-    (&"tang", &"汤"),
-    (&"tang", &"趟"),
-    (&"taolun", &"讨论"),
-    (&"tiankong", &"填空"),
-    (&"ting", &"停"),
-    (&"tongqing", &"同情"),
-    (&"tongshi", &"同时"),
-    (&"tui", &"推"),
-    (&"tuichi", &"推迟"),
-    (&"tuo", &"脱"),
-    (&"wangzhan", &"网站"),
-    (&"weixian", &"危险"),
-    (&"weishengjian", &"卫生间"),
-    (&"wendu", &"温度"),
-    (&"wenzhang", &"文章"),
-    (&"wuran", &"污染"),
-    (&"wu", &"无"),
-    (&"wufa", &"无法"),
-    (&"wulun", &"无论"),
-    (&"wuhui", &"误会"),
-    (&"xian", &"咸"),
-    (&"xiangfan", &"相反"),
-    (&"xiangtong", &"相同"),
-    (&"xiang", &"香"),
-    (&"xiangxi", &"详细"),
-    (&"xiang", &"响"),
-    (&"xiaochi", &"小吃"),
-    (&"xiaohuozi", &"小伙子"),
-    (&"xiaohua", &"笑话"),
-    (&"xinfeng", &"信封"),
-    (&"xinxi", &"信息"),
-    (&"xing", &"行"),
-    (&"xing", &"醒"),
-    (&"xingbie", &"性别"),
-    (&"xueqi", &"学期"),
-    (&"yagao", &"牙膏"),
-    (&"ya", &"呀"),
-    (&"yange", &"严格"),
-    (&"yan", &"盐"),
-    (&"yanjing", &"眼镜"),
-    (&"yanchu", &"演出"),
-    (&"yanyuan", &"演员"),
-    (&"yangcheng", &"养成"),
-    (&"yaoshi", &"钥匙"),
-    (&"yexu", &"也许"),
-    (&"yezi", &"叶子"),
-    (&"ye", &"页"),
-    (&"yi", &"以"),
-    (&"yijian", &"意见"),
-    (&"yinqi", &"引起"),
-    (&"you", &"由"),
-    (&"youju", &"邮局"),
-    (&"youhao", &"友好"),
-    (&"youqu", &"有趣"),
-    (&"yushi", &"于是"),
-    (&"yumaoqiu", &"羽毛球"),
-    (&"yufa", &"语法"),
-    (&"yuyan", &"语言"),
-    (&"yuxi", &"预习"),
-    (&"yuanliang", &"原谅"),
-    (&"yuedu", &"阅读"),
-    (&"yun", &"云"),
-    (&"yunxu", &"允许"),
-    (&"zazhi", &"杂志"),
-    (&"zang", &"脏"),
-    (&"zengjia", &"增加"),
-    (&"zhanxian", &"占线"),
-    (&"zhao", &"照"),
-    (&"zhengli", &"整理"),
-    (&"zhengchang", &"正常"),
-    (&"zhi", &"之"),
-    (&"zhijie", &"直接"),
-    (&"zhihao", &"只好"),
-    (&"zhidai", &"纸袋"),
-    (&"zhong", &"重"),
-    (&"zhongdian", &"重点"),
-    (&"zhongshi", &"重视"),
-    (&"zhuhe", &"祝贺"),
-    (&"zhuming", &"著名"),
-    (&"zhuan", &"转"),
-    (&"zhunque", &"准确"),
-    (&"zixi", &"仔细"),
-    (&"zixin", &"自信"),
-    (&"zu", &"租"),
-    (&"zunzhong", &"尊重"),
-    (&"zuoyou", &"左右"),
-    (&"zuoyong", &"作用"),
-    (&"zuozhe", &"作者"),
-    (&"zuo", &"座"),
-    (&"zuowei", &"座位"),
-    (&"changcheng", &"长城"),
-    (&"changjiang", &"长江"),
-    (&"yazhou", &"亚洲"),
-    (&"aidisheng", &"爱迪生"),
-    (&"lin", &"林"),
-    (&"make", &"马克"),
-    (&"shanghai", &"上海"),
-    (&"sunyue", &"孙月"),
-    (&"xiaoyu", &"小雨"),
-    (&"bing", &"并"),
-    (&"huiyuanka", &"会员卡"),
-    (&"ji", &"即"),
-    (&"liuxie", &"流血"),
-    (&"xingxing", &"星星"),
-    (&"xhileng", &"制冷"),
-    (&"an", &"按"),
-    (&"bao", &"保"),
-    (&"bishi", &"笔试"),
-    (&"butong", &"不同"),
-    (&"can", &"餐"),
-    (&"changqi", &"长期"),
-    (&"cheng", &"成"),
-    (&"chu", &"处"),
-    (&"chuang", &"窗"),
-    (&"dedao", &"得到"),
-    (&"du", &"堵"),
-    (&"fumu", &"父母"),
-    (&"gandao", &"感到"),
-    (&"gou", &"购"),
-    (&"goumai", &"购买"),
-    (&"huo", &"货"),
-    (&"jia", &"加"),
-    (&"jiaqian", &"价钱"),
-    (&"jiang", &"奖"),
-    (&"jie", &"街"),
-    (&"mei", &"美"),
-    (&"mianshi", &"面试"),
-    (&"nvxing", &"女性"),
-    (&"putong", &"普通"),
-    (&"shangchang", &"商场"),
-    (&"shenbian", &"身边"),
-    (&"tiyuguan", &"体育馆"),
-    (&"xiaopengyou", &"小朋友"),
-    (&"xiaoyuan", &"校园"),
-    (&"xinxiangan", &"新鲜感"),
-    (&"youguan", &"有关"),
-    (&"zan", &"咱"),
-    (&"zhe", &"折"),
-    (&"zhi", &"纸"),
-    (&"zhu", &"祝"),
-    (&"zuoke", &"做客"),
-    (&"anna", &"安娜"),
-    (&"changjiangdaqiao", &"长江大桥"),
-    (&"guangdongsheng", &"广东省"),
-    (&"liuyiertongjie", &"六一儿童节"),
-    (&"shoudujichang", &"首都机场"),
-    (&"xiangshan", &"香山"),
-    (&"guai", &"怪"),
-    (&"meirenyu", &"美人鱼"),
-    (&"shibangongbei", &"事半功倍"),
-    (&"wudao", &"舞蹈"),
-    (&"biji", &"笔记"),
-    (&"biao", &"表"),
-    (&"bu", &"部"),
-    (&"chunyou", &"春游"),
-    (&"dai", &"袋"),
-    (&"daizi", &"袋子"),
-    (&"gai", &"改"),
-    (&"guanzhu", &"关注"),
-    (&"hai", &"海"),
-    (&"huanbao", &"环保"),
-    (&"jile", &"极了"),
-    (&"jiayou", &"加油"),
-    (&"jian", &"减"),
-    (&"jianqing", &"减轻"),
-    (&"keji", &"科技"),
-    (&"laji", &"垃圾"),
-    (&"lifadian", &"理发店"),
-    (&"lizi", &"例子"),
-    (&"liangcha", &"凉茶"),
-    (&"ling", &"另"),
-    (&"meihao", &"美好"),
-    (&"nuan", &"暖"),
-    (&"qite", &"奇特"),
-    (&"qiwen", &"气温"),
-    (&"quan", &"全"),
-    (&"tian", &"填"),
-    (&"tongyang", &"同样"),
-    (&"xiaoqu", &"小区"),
-    (&"xin", &"信"),
-    (&"xingqu", &"兴趣"),
-    (&"yashua", &"牙刷"),
-    (&"youxiao", &"有效"),
-    (&"youxian", &"有限"),
-    (&"yue", &"约"),
-    (&"zeng", &"增"),
-    (&"zengzhang", &"增长"),
-    (&"ai", &"哎"),
-    (&"ai", &"唉"),
-    (&"aihu", &"爱护"),
-    (&"anwei", &"安慰"),
-    (&"anzhuang", &"安装"),
-    (&"anan", &"暗暗"),
-    (&"aoye", &"熬夜"),
-    (&"bai", &"摆"),
-    (&"banye", &"半夜"),
-    (&"bangwan", &"傍晚"),
-    (&"baokuo", &"包括"),
-    (&"baobei", &"宝贝"),
-    (&"baogui", &"宝贵"),
-    (&"baocun", &"保存"),
-    (&"baodao", &"报道"),
-    (&"baoyuan", &"抱怨"),
-    (&"bei", &"背"),
-    (&"beijing", &"背景"),
-    (&"beizi", &"被子"),
-    (&"benling", &"本领"),
-    (&"bici", &"彼此"),
-    (&"biyao", &"必要"),
-    (&"bijing", &"毕竟"),
-    (&"bimian", &"避免"),
-    (&"bianpao", &"鞭炮"),
-    (&"bian", &"便"),
-    (&"bianlun", &"辩论"),
-    (&"biaoda", &"表达"),
-    (&"biaomian", &"表面"),
-    (&"biaoming", &"表明"),
-    (&"biaoqing", &"表情"),
-    (&"biaoxian", &"表现"),
-    (&"bofang", &"播放"),
-    (&"bozi", &"脖子"),
-    (&"bunaifan", &"不耐烦"),
-    (&"buyaojin", &"不要紧"),
-    (&"budeliao", &"不得了"),
-    (&"buxing", &"不行"),
-    (&"buzu", &"不足"),
-    (&"bu", &"布"),
-    (&"caichan", &"财产"),
-    (&"caiqu", &"采取"),
-    (&"caiyong", &"采用"),
-    (&"caihong", &"彩虹"),
-    (&"cai", &"踩"),
-    (&"canyu", &"参与"),
-    (&"cankui", &"惭愧"),
-    (&"duocang", &"躲藏"),
-    (&"caochang", &"操场"),
-    (&"cengjing", &"曾经"),
-    (&"chaju", &"差距"),
-    (&"chayi", &"差异"),
-    (&"cha", &"插"),
-    (&"chanpin", &"产品"),
-    (&"chansheng", &"产生"),
-    (&"changtu", &"长途"),
-    (&"chao", &"吵"),
-    (&"chaojia", &"吵架"),
-    (&"cheng", &"称"),
-    (&"cheng", &"称"),
-    (&"chengguo", &"成果"),
-    (&"chengjiu", &"成就"),
-    (&"chengshu", &"成熟"),
-    (&"chengyu", &"成语"),
-    (&"chengken", &"诚恳"),
-    (&"chengdu", &"程度"),
-    (&"chikui", &"吃亏"),
-    (&"chizao", &"迟早"),
-    (&"chixu", &"持续"),
-    (&"chong", &"冲"),
-    (&"chongfen", &"充分"),
-    (&"chongman", &"充满"),
-    (&"chongfu", &"重复"),
-    (&"chongwu", &"宠物"),
-    (&"chouxiang", &"抽象"),
-    (&"choulou", &"丑陋"),
-    (&"chuzi", &"出自"),
-    (&"chu", &"除"),
-    (&"chuxi", &"除夕"),
-    (&"chuanshuo", &"传说"),
-    (&"chuantong", &"传统"),
-    (&"chuanglian", &"窗帘"),
-    (&"chuangzao", &"创造"),
-    (&"cihui", &"词汇"),
-    (&"cizhi", &"辞职"),
-    (&"ciwai", &"此外"),
-    (&"conger", &"从而"),
-    (&"congqian", &"从前"),
-    (&"cui", &"催"),
-    (&"cunzai", &"存在"),
-    (&"cuoshi", &"措施"),
-    (&"dadao", &"达到"),
-    (&"dagong", &"打工"),
-    (&"dajiaodao", &"打交道"),
-    (&"dating", &"打听"),
-    (&"dafang", &"大方"),
-    (&"dai", &"呆"),
-    (&"daibiao", &"代表"),
-    (&"daiyu", &"待遇"),
-    (&"danxiaogui", &"胆小鬼"),
-    (&"dangdi", &"当地"),
-    (&"dang", &"挡"),
-    (&"daoyan", &"导演"),
-    (&"daozhi", &"导致"),
-    (&"dao", &"倒"),
-    (&"daoli", &"道理"),
-    (&"dengdai", &"等待"),
-    (&"dengyu", &"等于"),
-    (&"diluo", &"低落"),
-    (&"diren", &"敌人"),
-    (&"didao", &"地道"),
-    (&"diqu", &"地区"),
-    (&"diwei", &"地位"),
-    (&"di", &"递"),
-    (&"dianxin", &"点心"),
-    (&"diantai", &"电台"),
-    (&"diao", &"钓"),
-    (&"ding", &"顶"),
-    (&"dong", &"冻"),
-    (&"dong", &"洞"),
-    (&"dou", &"逗"),
-    (&"duli", &"独立"),
-    (&"dute", &"独特"),
-    (&"duguo", &"度过"),
-    (&"duan", &"断"),
-    (&"duanduanxuxu", &"断断续续"),
-    (&"duibi", &"对比"),
-    (&"duifang", &"对方"),
-    (&"dun", &"蹲"),
-    (&"dun", &"顿"),
-    (&"duokui", &"多亏"),
-    (&"fachou", &"发愁"),
-    (&"fada", &"发达"),
-    (&"faner", &"反而"),
-    (&"fanying", &"反应"),
-    (&"fanying", &"反映"),
-    (&"fang", &"方"),
-    (&"fangan", &"方案"),
-    (&"fangshi", &"方式"),
-    (&"fangfu", &"仿佛"),
-    (&"fei", &"非"),
-    (&"fenbie", &"分别"),
-    (&"fenbu", &"分布"),
-    (&"fenxi", &"分析"),
-    (&"fenfen", &"纷纷"),
-    (&"fengsu", &"风俗"),
-    (&"feng", &"疯"),
-    (&"fengci", &"讽刺"),
-    (&"fu", &"扶"),
-    (&"fuzhuang", &"服装"),
-    (&"fu", &"幅"),
-    (&"gaijin", &"改进"),
-    (&"ganjin", &"赶紧"),
-    (&"ganji", &"感激"),
-    (&"ganshou", &"感受"),
-    (&"ganhuor", &"干活儿"),
-    (&"ganhuoer", &"干活儿"),
-    (&"gaodang", &"高档"),
-    (&"gaoji", &"高级"),
-    (&"gaobie", &"告别"),
-    (&"gewai", &"格外"),
-    (&"gebie", &"个别"),
-    (&"geren", &"个人"),
-    (&"gezi", &"各自"),
-    (&"gen", &"根"),
-    (&"gongchengshi", &"工程师"),
-    (&"gongju", &"工具"),
-    (&"gongren", &"工人"),
-    (&"gongyuan", &"公元"),
-    (&"gongneng", &"功能"),
-    (&"gugu", &"姑姑"),
-    (&"guniang", &"姑娘"),
-    (&"gudai", &"古代"),
-    (&"gudian", &"古典"),
-    (&"gutou", &"骨头"),
-    (&"guding", &"固定"),
-    (&"guanbi", &"关闭"),
-    (&"guan", &"官"),
-    (&"guanglin", &"光临"),
-    (&"guangxian", &"光线"),
-    (&"guangda", &"广大"),
-    (&"guangfan", &"广泛"),
-    (&"guilv", &"规律"),
-    (&"guize", &"规则"),
-    (&"guo", &"锅"),
-    (&"guoran", &"果然"),
-    (&"guoshi", &"果实"),
-    (&"ha", &"哈"),
-    (&"haixian", &"海鲜"),
-    (&"han", &"喊"),
-    (&"haohua", &"豪华"),
-    (&"haoke", &"好客"),
-    (&"haoqi", &"好奇"),
-    (&"hezuo", &"合作"),
-    (&"hebi", &"何必"),
-    (&"hekuang", &"何况"),
-    (&"hen", &"很"),
-    (&"houzi", &"猴子"),
-    (&"houbei", &"后背"),
-    (&"hupenghuanyou", &"呼朋唤友"),
-    (&"huxi", &"呼吸"),
-    (&"huran", &"忽然"),
-    (&"hushi", &"忽视"),
-    (&"hushuo", &"胡说"),
-    (&"hutong", &"胡同"),
-    (&"hu", &"壶"),
-    (&"hutu", &"糊涂"),
-    (&"huayi", &"华裔"),
-    (&"hua", &"滑"),
-    (&"huati", &"话题"),
-    (&"huang", &"慌"),
-    (&"huangzhang", &"慌张"),
-    (&"huichen", &"灰尘"),
-    (&"hui", &"挥"),
-    (&"hunli", &"婚礼"),
-    (&"hunyin", &"婚姻"),
-    (&"huoyue", &"活跃"),
-    (&"huoban", &"伙伴"),
-    (&"huoxu", &"或许"),
-    (&"ji", &"击"),
-    (&"jiqi", &"机器"),
-    (&"jilie", &"激烈"),
-    (&"jiqi", &"极其"),
-    (&"ji", &"即"),
-    (&"jizhong", &"集中"),
-    (&"jisuan", &"计算"),
-    (&"jiyi", &"记忆"),
-    (&"jinian", &"纪念"),
-    (&"ji", &"系"),
-    (&"jiating", &"家庭"),
-    (&"jia", &"甲"),
-    (&"jiashi", &"驾驶"),
-    (&"jianjue", &"坚决"),
-    (&"jianbang", &"肩膀"),
-    (&"jianku", &"艰苦"),
-    (&"jianzhu", &"建筑"),
-    (&"jiangjiu", &"讲究"),
-    (&"jiaoji", &"交际"),
-    (&"jiaodu", &"角度"),
-    (&"jieshi", &"结实"),
-    (&"jiedai", &"接待"),
-    (&"jiesheng", &"节省"),
-    (&"jieshi", &"节食"),
-    (&"jiegou", &"结构"),
-    (&"jielun", &"结论"),
-    (&"jie", &"戒"),
-    (&"jiekou", &"借口"),
-    (&"jinshu", &"金属"),
-    (&"jin", &"尽"),
-    (&"jinli", &"尽力"),
-    (&"jindai", &"近代"),
-    (&"jingying", &"经营"),
-    (&"jingshen", &"精神"),
-    (&"jingshen", &"精神"),
-    (&"jiu", &"救"),
-    (&"jiujiu", &"舅舅"),
-    (&"juran", &"居然"),
-    (&"jubei", &"具备"),
-    (&"juexin", &"决心"),
-    (&"juedui", &"绝对"),
-    (&"junshi", &"军事"),
-    (&"kaifa", &"开发"),
-    (&"kaimushi", &"开幕式"),
-    (&"kao", &"靠"),
-    (&"ke", &"颗"),
-    (&"kejian", &"可见"),
-    (&"kekao", &"可靠"),
-    (&"kongjian", &"空间"),
-    (&"la", &"啦"),
-    (&"lan", &"拦"),
-    (&"lan", &"烂"),
-    (&"laodong", &"劳动"),
-    (&"laobaixing", &"老百姓"),
-    (&"laopo", &"老婆"),
-    (&"laoshi", &"老实"),
-    (&"laolao", &"姥姥"),
-    (&"lei", &"雷"),
-    (&"leixing", &"类型"),
-    (&"lihun", &"离婚"),
-    (&"lilun", &"理论"),
-    (&"liji", &"立即"),
-    (&"like", &"立刻"),
-    (&"lianmang", &"连忙"),
-    (&"lianxu", &"连续"),
-    (&"lianhe", &"联合"),
-    (&"lianghao", &"良好"),
-    (&"liangshi", &"粮食"),
-    (&"liang", &"亮"),
-    (&"lin", &"临"),
-    (&"linshi", &"临时"),
-    (&"linghuo", &"灵活"),
-    (&"ling", &"铃"),
-    (&"ling", &"令"),
-    (&"liuchuan", &"流传"),
-    (&"liulei", &"流泪"),
-    (&"long", &"龙"),
-    (&"lou", &"漏"),
-    (&"ludi", &"陆地"),
-    (&"lun", &"轮"),
-    (&"lunliu", &"轮流"),
-    (&"ma", &"骂"),
-    (&"mantou", &"馒头"),
-    (&"manzu", &"满足"),
-    (&"maobing", &"毛病"),
-    (&"maodun", &"矛盾"),
-    (&"meishu", &"美术"),
-    (&"meili", &"魅力"),
-    (&"miaotiao", &"苗条"),
-    (&"miaoxie", &"描写"),
-    (&"mingming", &"明明"),
-    (&"mingxian", &"明显"),
-    (&"mingxing", &"明星"),
-    (&"mingling", &"命令"),
-    (&"mo", &"摸"),
-    (&"mofang", &"模仿"),
-    (&"muqian", &"目前"),
-    (&"napa", &"哪怕"),
-    (&"naodai", &"脑袋"),
-    (&"naozhong", &"闹钟"),
-    (&"ningke", &"宁可"),
-    (&"nongcun", &"农村"),
-    (&"nongmin", &"农民"),
-    (&"nong", &"浓"),
-    (&"nvshi", &"女士"),
-    (&"pai", &"拍"),
-    (&"pai", &"派"),
-    (&"pai", &"派"),
-    (&"panwang", &"盼望"),
-    (&"pen", &"盆"),
-    (&"penzi", &"盆子"),
-    (&"peng", &"碰"),
-    (&"pi", &"匹"),
-    (&"pianmian", &"片面"),
-    (&"piao", &"飘"),
-    (&"ping", &"平"),
-    (&"pingchang", &"平常"),
-    (&"pingjing", &"平静"),
-    (&"pingjun", &"平均"),
-    (&"pingjia", &"评价"),
-    (&"qiyu", &"其余"),
-    (&"qiji", &"奇迹"),
-    (&"qiye", &"企业"),
-    (&"qifa", &"启发"),
-    (&"qifen", &"气氛"),
-    (&"qian", &"签"),
-    (&"qian", &"浅"),
-    (&"qianglie", &"强烈"),
-    (&"qiang", &"墙"),
-    (&"qiang", &"抢"),
-    (&"qiaoqiao", &"悄悄"),
-    (&"qiao", &"瞧"),
-    (&"qinqie", &"亲切"),
-    (&"qinzi", &"亲自"),
-    (&"qinfen", &"勤奋"),
-    (&"qingshi", &"轻视"),
-    (&"qingjing", &"情景"),
-    (&"qingxu", &"情绪"),
-    (&"qingqiu", &"请求"),
-    (&"qiumi", &"球迷"),
-    (&"qushi", &"趋势"),
-    (&"qushi", &"去世"),
-    (&"quefa", &"缺乏"),
-    (&"queding", &"确定"),
-    (&"queren", &"确认"),
-    (&"qun", &"群"),
-    (&"rencai", &"人才"),
-    (&"renlei", &"人类"),
-    (&"renmin", &"人民"),
-    (&"rensheng", &"人生"),
-    (&"renyuan", &"人员"),
-    (&"richang", &"日常"),
-    (&"rizi", &"日子"),
-    (&"ruhe", &"如何"),
-    (&"rujin", &"如今"),
-    (&"ruo", &"弱"),
-    (&"sa", &"洒"),
-    (&"sha", &"杀"),
-    (&"shai", &"晒"),
-    (&"shandian", &"闪电"),
-    (&"shanzi", &"扇子"),
-    (&"shanliang", &"善良"),
-    (&"shanyu", &"善于"),
-    (&"shanghai", &"伤害"),
-    (&"shangdang", &"上当"),
-    (&"sheji", &"设计"),
-    (&"she", &"射"),
-    (&"sheji", &"射击"),
-    (&"sheyingshi", &"摄影师"),
-    (&"shen", &"伸"),
-    (&"shenfen", &"身份"),
-    (&"shenke", &"深刻"),
-    (&"shenmi", &"神秘"),
-    (&"sheng", &"升"),
-    (&"shengzi", &"绳子"),
-    (&"shengli", &"胜利"),
-    (&"shimian", &"失眠"),
-    (&"shi", &"诗"),
-    (&"shitou", &"石头"),
-    (&"shidai", &"时代"),
-    (&"shike", &"时刻"),
-    (&"shiqi", &"时期"),
-    (&"shishang", &"时尚"),
-    (&"shixian", &"实现"),
-    (&"shiyan", &"实验"),
-    (&"shiwu", &"食物"),
-    (&"shizhong", &"始终"),
-    (&"shibing", &"士兵"),
-    (&"shichang", &"市场"),
-    (&"shide", &"似的"),
-    (&"shishi", &"事实"),
-    (&"shiwu", &"事物"),
-    (&"shouzhi", &"手指"),
-    (&"shousui", &"守岁"),
-    (&"shou", &"首"),
-    (&"shushi", &"舒适"),
-    (&"shucai", &"蔬菜"),
-    (&"shu", &"数"),
-    (&"shuai", &"甩"),
-    (&"shuobuding", &"说不定"),
-    (&"shuofu", &"说服"),
-    (&"sihu", &"似乎"),
-    (&"suan", &"算"),
-    (&"suishi", &"随时"),
-    (&"suishou", &"随手"),
-    (&"sui", &"碎"),
-    (&"suo", &"所"),
-    (&"suowei", &"所谓"),
-    (&"suo", &"锁"),
-    (&"taijie", &"台阶"),
-    (&"taitai", &"太太"),
-    (&"tao", &"逃"),
-    (&"tao", &"桃"),
-    (&"taoqi", &"淘气"),
-    (&"tao", &"套"),
-    (&"tihui", &"体会"),
-    (&"daiti", &"代替"),
-    (&"ti", &"替"),
-    (&"tianzhen", &"天真"),
-    (&"tiaopi", &"调皮"),
-    (&"tiaozheng", &"调整"),
-    (&"tiaozhan", &"挑战"),
-    (&"tongchang", &"通常"),
-    (&"toulan", &"投篮"),
-    (&"touru", &"投入"),
-    (&"tuchu", &"突出"),
-    (&"tuiguang", &"推广"),
-    (&"tuijian", &"推荐"),
-    (&"waigong", &"外公"),
-    (&"wanzheng", &"完整"),
-    (&"weihai", &"危害"),
-    (&"weixiao", &"微笑"),
-    (&"wei", &"为"),
-    (&"wei", &"为"),
-    (&"weirao", &"围绕"),
-    (&"weiba", &"尾巴"),
-    (&"weiqu", &"委屈"),
-    (&"weilai", &"未来"),
-    (&"weiyu", &"位于"),
-    (&"weizhi", &"位置"),
-    (&"wei", &"胃"),
-    (&"weikou", &"胃口"),
-    (&"wennuan", &"温暖"),
-    (&"wenxuejia", &"文学家"),
-    (&"wenzi", &"文字"),
-    (&"wending", &"稳定"),
-    (&"woshi", &"卧室"),
-    (&"wuzi", &"屋子"),
-    (&"wu", &"屋"),
-    (&"wunai", &"无奈"),
-    (&"wu", &"勿"),
-    (&"wuzhi", &"物质"),
-    (&"xiju", &"戏剧"),
-    (&"xijie", &"细节"),
-    (&"xia", &"瞎"),
-    (&"xia", &"吓"),
-    (&"xiande", &"显得"),
-    (&"xianshi", &"显示"),
-    (&"xian", &"县"),
-    (&"xiandai", &"现代"),
-    (&"xianshi", &"现实"),
-    (&"xianxiang", &"现象"),
-    (&"xianzhi", &"限制"),
-    (&"xiangchu", &"相处"),
-    (&"xiangdang", &"相当"),
-    (&"xiangdui", &"相对"),
-    (&"xiangguan", &"相关"),
-    (&"xiangshou", &"享受"),
-    (&"xiangxiang", &"想象"),
-    (&"xiang", &"项"),
-    (&"xiangmu", &"项目"),
-    (&"daxiang", &"大象"),
-    (&"xiang", &"象"),
-    (&"xiaofei", &"消费"),
-    (&"xiaoshou", &"销售"),
-    (&"xiaoshun", &"孝顺"),
-    (&"xie", &"斜"),
-    (&"xiezuo", &"写作"),
-    (&"xinli", &"心理"),
-    (&"xinshang", &"欣赏"),
-    (&"xingwei", &"行为"),
-    (&"xingcheng", &"形成"),
-    (&"xingrong", &"形容"),
-    (&"xingshi", &"形式"),
-    (&"xingshi", &"形势"),
-    (&"xingxiang", &"形象"),
-    (&"xingzhuang", &"形状"),
-    (&"xiongdi", &"兄弟"),
-    (&"xushu", &"叙述"),
-    (&"xuanchuan", &"宣传"),
-    (&"xuewen", &"学问"),
-    (&"xunzhao", &"寻找"),
-    (&"xunwen", &"询问"),
-    (&"xunlian", &"训练"),
-    (&"xunsu", &"迅速"),
-    (&"yachi", &"牙齿"),
-    (&"yangshi", &"样式"),
-    (&"yao", &"摇"),
-    (&"yao", &"咬"),
-    (&"yaobu", &"要不"),
-    (&"yewu", &"业务"),
-    (&"yeyu", &"业余"),
-    (&"ye", &"夜"),
-    (&"yiran", &"依然"),
-    (&"yibeizi", &"一辈子"),
-    (&"yizai", &"一再"),
-    (&"yidong", &"移动"),
-    (&"yimin", &"移民"),
-    (&"yi", &"乙"),
-    (&"yi", &"以"),
-    (&"yiji", &"以及"),
-    (&"yilai", &"以来"),
-    (&"yi", &"亿"),
-    (&"yiwai", &"意外"),
-    (&"yiyi", &"意义"),
-    (&"yiner", &"因而"),
-    (&"yinzi", &"银子"),
-    (&"yin", &"银"),
-    (&"yingjun", &"英俊"),
-    (&"yingxiong", &"英雄"),
-    (&"yingyang", &"营养"),
-    (&"yingzi", &"影子"),
-    (&"yingyong", &"应用"),
-    (&"ying", &"硬"),
-    (&"yongbao", &"拥抱"),
-    (&"yongtu", &"用途"),
-    (&"youmei", &"优美"),
-    (&"youjiu", &"悠久"),
-    (&"youeryuan", &"幼儿园"),
-    (&"yu", &"于"),
-    (&"yuanwang", &"愿望"),
-    (&"yun", &"晕"),
-    (&"yunyong", &"运用"),
-    (&"zaihai", &"灾害"),
-    (&"zaisan", &"再三"),
-    (&"zanmei", &"赞美"),
-    (&"zanmai", &"赞美"),
-    (&"zaogao", &"糟糕"),
-    (&"zaocheng", &"造成"),
-    (&"ze", &"则"),
-    (&"ze", &"则"),
-    (&"zhan", &"占"),
-    (&"zhanzheng", &"战争"),
-    (&"zhangbei", &"长辈"),
-    (&"zhaodai", &"招待"),
-    (&"zhaohuo", &"着火"),
-    (&"zhaokai", &"召开"),
-    (&"zhendui", &"针对"),
-    (&"zhen", &"阵"),
-    (&"zhenglun", &"争论"),
-    (&"zhengqu", &"争取"),
-    (&"zhengge", &"整个"),
-    (&"zhengti", &"整体"),
-    (&"zhengju", &"证据"),
-    (&"zheng", &"挣"),
-    (&"zhi", &"支"),
-    (&"zhijin", &"至今"),
-    (&"zhiyuanzhe", &"志愿者"),
-    (&"zhizao", &"制造"),
-    (&"zhiliao", &"治疗"),
-    (&"zhi", &"治"),
-    (&"zhihui", &"智慧"),
-    (&"zhongxin", &"中心"),
-    (&"zhongda", &"重大"),
-    (&"zhu", &"猪"),
-    (&"zhuzi", &"竹子"),
-    (&"zhujian", &"逐渐"),
-    (&"zhuchi", &"主持"),
-    (&"zhudong", &"主动"),
-    (&"zhuren", &"主人"),
-    (&"zhuce", &"注册"),
-    (&"zhuanjia", &"专家"),
-    (&"zhuang", &"装"),
-    (&"zhuangxiu", &"装修"),
-    (&"zhuangtai", &"状态"),
-    (&"zhuang", &"撞"),
-    (&"zhui", &"追"),
-    (&"zishi", &"姿势"),
-    (&"zige", &"资格"),
-    (&"ziliao", &"资料"),
-    (&"zisha", &"自杀"),
-    (&"ziyou", &"自由"),
-    (&"zonghe", &"综合"),
-    (&"zongcai", &"总裁"),
-    (&"zonggong", &"总共"),
-    (&"zuzhi", &"阻止"),
-    (&"zu", &"组"),
-    (&"zucheng", &"组成"),
-    (&"zuhe", &"组合"),
-    (&"zui", &"醉"),
-    (&"zuopin", &"作品"),
-    (&"zuowei", &"作为"),
-    (&"zuozhan", &"作战"),
-    (&"aixi", &"爱惜"),
-    (&"aixin", &"爱心"),
-    (&"an", &"岸"),
-    (&"an", &"暗"),
-    (&"bawo", &"把握"),
-    (&"banli", &"办理"),
-    (&"baohan", &"包含"),
-    (&"bao", &"薄"),
-    (&"baoliu", &"保留"),
-    (&"baoxian", &"保险"),
-    (&"baogao", &"报告"),
-    (&"baoshe", &"报社"),
-    (&"beishang", &"悲伤"),
-    (&"bei", &"悲"),
-    (&"benke", &"本科"),
-    (&"benzhi", &"本质"),
-    (&"bili", &"比例"),
-    (&"biran", &"必然"),
-    (&"bianji", &"编辑"),
-    (&"biaozhi", &"标志"),
-    (&"bingjiling", &"冰激凌"),
-    (&"boli", &"玻璃"),
-    (&"buduan", &"不断"),
-    (&"bujiande", &"不见得"),
-    (&"buchong", &"补充"),
-    (&"buan", &"不安"),
-    (&"buran", &"不然"),
-    (&"buru", &"不如"),
-    (&"buzhou", &"步骤"),
-    (&"bumen", &"部门"),
-    (&"caifang", &"采访"),
-    (&"cankao", &"参考"),
-    (&"caoxin", &"操心"),
-    (&"ce", &"册"),
-    (&"ceyan", &"测验"),
-    (&"changshi", &"常识"),
-    (&"chao", &"朝"),
-    (&"chaoshi", &"潮湿"),
-    (&"chao", &"炒"),
-    (&"chedi", &"彻底"),
-    (&"chenmo", &"沉默"),
-    (&"chen", &"趁"),
-    (&"chenghu", &"称呼"),
-    (&"chengzan", &"称赞"),
-    (&"chengfen", &"成分"),
-    (&"chengli", &"成立"),
-    (&"chengren", &"成人"),
-    (&"chengzhang", &"成长"),
-    (&"chengdan", &"承担"),
-    (&"chengren", &"承认"),
-    (&"chengshou", &"承受"),
-    (&"chitang", &"池塘"),
-    (&"chibang", &"翅膀"),
-    (&"chuban", &"出版"),
-    (&"chuse", &"出色"),
-    (&"chushi", &"出示"),
-    (&"chuxi", &"出席"),
-    (&"chujizhongxue", &"初级中学"),
-    (&"chuzhong", &"初中"),
-    (&"chufei", &"除非"),
-    (&"chuli", &"处理"),
-    (&"chuang", &"闯"),
-    (&"chui", &"吹"),
-    (&"ciyao", &"次要"),
-    (&"ciji", &"刺激"),
-    (&"congci", &"从此"),
-    (&"congshi", &"从事"),
-    (&"cucao", &"粗糙"),
-    (&"cujin", &"促进"),
-    (&"cushi", &"促使"),
-    (&"cu", &"醋"),
-    (&"daying", &"答应"),
-    (&"dasha", &"大厦"),
-    (&"daxing", &"大型"),
-    (&"danren", &"担任"),
-    (&"danchun", &"单纯"),
-    (&"dandiao", &"单调"),
-    (&"dandu", &"单独"),
-    (&"danwei", &"单位"),
-    (&"danwu", &"耽误"),
-    (&"daoyu", &"岛屿"),
-    (&"daomei", &"倒霉"),
-    (&"daoda", &"到达"),
-    (&"daode", &"道德"),
-    (&"dengji", &"登记"),
-    (&"dique", &"的确"),
-    (&"dili", &"地理"),
-    (&"dizhen", &"地震"),
-    (&"dianchi", &"电池"),
-    (&"donghuapian", &"动画片"),
-    (&"dui", &"堆"),
-    (&"duidai", &"对待"),
-    (&"duishou", &"对手"),
-    (&"duixiang", &"对象"),
-    (&"dun", &"吨"),
-    (&"duo", &"朵"),
-    (&"elie", &"恶劣"),
-    (&"fadou", &"发抖"),
-    (&"fahui", &"发挥"),
-    (&"fayan", &"发言"),
-    (&"fan", &"翻"),
-    (&"fanrong", &"繁荣"),
-    (&"fanfu", &"反复"),
-    (&"fanzheng", &"反正"),
-    (&"fanwei", &"范围"),
-    (&"fangai", &"妨碍"),
-    (&"feizao", &"肥皂"),
-    (&"fei", &"废"),
-    (&"fendou", &"奋斗"),
-    (&"fengjing", &"风景"),
-    (&"fengxian", &"风险"),
-    (&"fouren", &"否认"),
-    (&"funv", &"妇女"),
-    (&"gaige", &"改革"),
-    (&"gaishan", &"改善"),
-    (&"gai", &"盖"),
-    (&"gaikuo", &"概括"),
-    (&"gainian", &"概念"),
-    (&"gancui", &"干脆"),
-    (&"ganzao", &"干燥"),
-    (&"gankuai", &"赶快"),
-    (&"ganxiang", &"感想"),
-    (&"gangtie", &"钢铁"),
-    (&"gao", &"搞"),
-    (&"gexing", &"个性"),
-    (&"genben", &"根本"),
-    (&"gongchang", &"工厂"),
-    (&"gongye", &"工业"),
-    (&"gongbu", &"公布"),
-    (&"gongkai", &"公开"),
-    (&"gongyu", &"公寓"),
-    (&"gongxian", &"贡献"),
-    (&"goutong", &"沟通"),
-    (&"goucheng", &"构成"),
-    (&"guwu", &"鼓舞"),
-    (&"guzhang", &"鼓掌"),
-    (&"guai", &"乖"),
-    (&"guancha", &"观察"),
-    (&"guandian", &"观点"),
-    (&"guannian", &"观念"),
-    (&"guanzi", &"馆子"),
-    (&"guanjun", &"冠军"),
-    (&"guanghua", &"光滑"),
-    (&"guangming", &"光明"),
-    (&"guangchang", &"广场"),
-    (&"guina", &"归纳"),
-    (&"guiju", &"规矩"),
-    (&"gun", &"滚"),
-    (&"guofen", &"过分"),
-    (&"hangye", &"行业"),
-    (&"heli", &"合理"),
-    (&"heping", &"和平"),
-    (&"hexin", &"核心"),
-    (&"houguo", &"后果"),
-    (&"hudie", &"蝴蝶"),
-    (&"huainian", &"怀念"),
-    (&"huanjie", &"缓解"),
-    (&"huanxiang", &"幻想"),
-    (&"hui", &"灰"),
-    (&"huixin", &"灰心"),
-    (&"huifu", &"恢复"),
-    (&"huochai", &"火柴"),
-    (&"jirou", &"肌肉"),
-    (&"jiben", &"基本"),
-    (&"jige", &"及格"),
-    (&"ji", &"挤"),
-    (&"jilu", &"记录"),
-    (&"jilu", &"纪录"),
-    (&"jilv", &"纪律"),
-    (&"jimo", &"寂寞"),
-    (&"jiawu", &"家务"),
-    (&"jiaxiang", &"家乡"),
-    (&"jiaru", &"假如"),
-    (&"jiashe", &"假设"),
-    (&"jiazhi", &"价值"),
-    (&"jianju", &"艰巨"),
-    (&"jian", &"捡"),
-    (&"jiandao", &"剪刀"),
-    (&"jianli", &"简历"),
-    (&"jianzhi", &"简直"),
-    (&"jianli", &"建立"),
-    (&"jianshe", &"建设"),
-    (&"jianshen", &"健身"),
-    (&"jiangzuo", &"讲座"),
-    (&"jiangyou", &"酱油"),
-    (&"jiaohuan", &"交换"),
-    (&"jiaowang", &"交往"),
-    (&"jiao", &"浇"),
-    (&"jiaohua", &"狡猾"),
-    (&"jiaolian", &"教练"),
-    (&"jiaoxun", &"教训"),
-    (&"jieduan", &"阶段"),
-    (&"jiechu", &"接触"),
-    (&"jiejin", &"接近"),
-    (&"jiehe", &"结合"),
-    (&"jie", &"届"),
-    (&"jinkuai", &"尽快"),
-    (&"jinliang", &"尽量"),
-    (&"jinji", &"紧急"),
-    (&"jinshen", &"谨慎"),
-    (&"jinbu", &"进步"),
-    (&"jingdian", &"经典"),
-    (&"jingshang", &"经商"),
-    (&"jiuba", &"酒吧"),
-    (&"juzi", &"橘子"),
-    (&"juda", &"巨大"),
-    (&"juti", &"具体"),
-    (&"jushuo", &"据说"),
-    (&"juesai", &"决赛"),
-    (&"juese", &"角色"),
-    (&"junyun", &"均匀"),
-    (&"kaifang", &"开放"),
-    (&"kaishui", &"开水"),
-    (&"kan", &"砍"),
-    (&"kanbuqi", &"看不起"),
-    (&"kepa", &"可怕"),
-    (&"kefu", &"克服"),
-    (&"keku", &"刻苦"),
-    (&"keguan", &"客观"),
-    (&"kongxian", &"空闲"),
-    (&"kongzhi", &"控制"),
-    (&"kouwei", &"口味"),
-    (&"ku", &"库"),
-    (&"kua", &"夸"),
-    (&"kuazhang", &"夸张"),
-    (&"kuan", &"宽"),
-    (&"kuang", &"狂"),
-    (&"kunchong", &"昆虫"),
-    (&"kuoda", &"扩大"),
-    (&"kuo", &"扩"),
-    (&"langdu", &"朗读"),
-    (&"laojia", &"劳驾"),
-    (&"laoban", &"老板"),
-    (&"leguan", &"乐观"),
-    (&"lengdan", &"冷淡"),
-    (&"li", &"梨"),
-    (&"liyou", &"理由"),
-    (&"liliang", &"力量"),
-    (&"lirun", &"利润"),
-    (&"liyi", &"利益"),
-    (&"liyong", &"利用"),
-    (&"lianai", &"恋爱"),
-    (&"liaobuqi", &"了不起"),
-    (&"lingyu", &"领域"),
-    (&"luxu", &"陆续"),
-    (&"luqu", &"录取"),
-    (&"luoji", &"逻辑"),
-    (&"luohou", &"落后"),
-    (&"maoxian", &"冒险"),
-    (&"meiti", &"媒体"),
-    (&"meitan", &"煤炭"),
-    (&"mengxiang", &"梦想"),
-    (&"mimi", &"秘密"),
-    (&"miqie", &"密切"),
-    (&"miandui", &"面对"),
-    (&"mianji", &"面积"),
-    (&"mianlin", &"面临"),
-    (&"mingan", &"敏感"),
-    (&"mingpai", &"名牌"),
-    (&"mingque", &"明确"),
-    (&"mingyun", &"命运"),
-    (&"mohu", &"模糊"),
-    (&"mosheng", &"陌生"),
-    (&"mou", &"某"),
-    (&"mutou", &"木头"),
-    (&"mubiao", &"目标"),
-    (&"nanguai", &"难怪"),
-    (&"nanmian", &"难免"),
-    (&"neibu", &"内部"),
-    (&"nen", &"嫩"),
-    (&"nenggan", &"能干"),
-    (&"nengyuan", &"能源"),
-    (&"ng", &"嗯"),
-    (&"en", &"嗯"),
-    (&"niandai", &"年代"),
-    (&"nianji", &"年纪"),
-    (&"nian", &"念"),
-    (&"nongye", &"农业"),
-    (&"ouran", &"偶然"),
-    (&"peixun", &"培训"),
-    (&"peiyang", &"培养"),
-    (&"peichang", &"赔偿"),
-    (&"peifu", &"佩服"),
-    (&"pi", &"批"),
-    (&"peizhun", &"批准"),
-    (&"pilao", &"疲劳"),
-    (&"pian", &"片"),
-    (&"pingan", &"平安"),
-    (&"pingdeng", &"平等"),
-    (&"pingheng", &"平衡"),
-    (&"ping", &"凭"),
-    (&"poqie", &"迫切"),
-    (&"pohuai", &"破坏"),
-    (&"qidai", &"期待"),
-    (&"qijian", &"期间"),
-    (&"xiangqi", &"象棋"),
-    (&"qi", &"棋"),
-    (&"qi", &"起"),
-    (&"qiyou", &"汽油"),
-    (&"qianxu", &"谦虚"),
-    (&"qiantu", &"前途"),
-    (&"qiangdiao", &"强调"),
-    (&"qiaomiao", &"巧妙"),
-    (&"qie", &"切"),
-    (&"qinai", &"亲爱"),
-    (&"qing", &"青"),
-    (&"qingshaonian", &"青少年"),
-    (&"qingyi", &"轻易"),
-    (&"qingdan", &"清淡"),
-    (&"qingzhu", &"庆祝"),
-    (&"quxiao", &"取消"),
-    (&"quan", &"圈"),
-    (&"quanmian", &"全面"),
-    (&"quan", &"劝"),
-    (&"ranshao", &"燃烧"),
-    (&"reai", &"热爱"),
-    (&"relie", &"热烈"),
-    (&"rexin", &"热心"),
-    (&"renwu", &"人物"),
-    (&"renbuzhu", &"忍不住"),
-    (&"richeng", &"日程"),
-    (&"riyongpin", &"日用品"),
-    (&"secai", &"色彩"),
-    (&"shamo", &"沙漠"),
-    (&"shatan", &"沙滩"),
-    (&"sha", &"傻"),
-    (&"shangpin", &"商品"),
-    (&"shangwu", &"商务"),
-    (&"shangye", &"商业"),
-    (&"shebude", &"舍不得"),
-    (&"shebei", &"设备"),
-    (&"sheshi", &"设施"),
-    (&"shencai", &"身材"),
-    (&"shengchan", &"生产"),
-    (&"shengzhang", &"生长"),
-    (&"shiqu", &"失去"),
-    (&"shirun", &"湿润"),
-    (&"shihua", &"实话"),
-    (&"shijian", &"实践"),
-    (&"shiyong", &"实用"),
-    (&"shijinr", &"使劲儿"),
-    (&"shijin", &"使劲"),
-    (&"shixian", &"事先"),
-    (&"shouhuo", &"收获"),
-    (&"shouxu", &"手续"),
-    (&"shoushang", &"受伤"),
-    (&"shou", &"受"),
-    (&"shuzi", &"梳子"),
-    (&"shuru", &"输入"),
-    (&"shulian", &"熟练"),
-    (&"shuyu", &"属于"),
-    (&"shuju", &"数据"),
-    (&"shuaidao", &"摔倒"),
-    (&"sihao", &"丝毫"),
-    (&"siren", &"私人"),
-    (&"si", &"私"),
-    (&"sikao", &"思考"),
-    (&"sixiang", &"思想"),
-    (&"suishen", &"随身"),
-    (&"sunshi", &"损失"),
-    (&"suoduan", &"缩短"),
-    (&"tang", &"烫"),
-    (&"taobi", &"逃避"),
-    (&"tese", &"特色"),
-    (&"teshu", &"特殊"),
-    (&"tezheng", &"特征"),
-    (&"tengai", &"疼爱"),
-    (&"tichang", &"提倡"),
-    (&"tiwen", &"提问"),
-    (&"timu", &"题目"),
-    (&"titie", &"体贴"),
-    (&"tixian", &"体现"),
-    (&"tiyan", &"体验"),
-    (&"tiankong", &"天空"),
-    (&"tongku", &"痛苦"),
-    (&"tongkuai", &"痛快"),
-    (&"touzi", &"投资"),
-    (&"touming", &"透明"),
-    (&"tuixiu", &"退休"),
-    (&"waijiao", &"外交"),
-    (&"wanmei", &"完美"),
-    (&"wanshan", &"完善"),
-    (&"wanyi", &"万一"),
-    (&"wangzi", &"王子"),
-    (&"wangluo", &"网络"),
-    (&"weixia", &"威胁"),
-    (&"weifan", &"违反"),
-    (&"weiyi", &"唯一"),
-    (&"weibi", &"未必"),
-    (&"wenrou", &"温柔"),
-    (&"wenju", &"文具"),
-    (&"wenming", &"文明"),
-    (&"wen", &"闻"),
-    (&"wen", &"吻"),
-    (&"wushu", &"无数"),
-    (&"wusuowei", &"无所谓"),
-    (&"wuli", &"物理"),
-    (&"xiqu", &"吸取"),
-    (&"xishou", &"吸收"),
-    (&"xi", &"系"),
-    (&"xitong", &"系统"),
-    (&"xiazai", &"下载"),
-    (&"xianyan", &"鲜艳"),
-    (&"xianran", &"显然"),
-    (&"xiangsi", &"相似"),
-    (&"xiangnian", &"想念"),
-    (&"xiaoji", &"消极"),
-    (&"xiaoshi", &"消失"),
-    (&"xiaolv", &"效率"),
-    (&"xie", &"歇"),
-    (&"xinzang", &"心脏"),
-    (&"xingdong", &"行动"),
-    (&"xingren", &"行人"),
-    (&"xingkui", &"幸亏"),
-    (&"xingyun", &"幸运"),
-    (&"xiong", &"胸"),
-    (&"xiuxian", &"休闲"),
-    (&"xuxin", &"虚心"),
-    (&"yajin", &"押金"),
-    (&"yanchang", &"延长"),
-    (&"yansu", &"严肃"),
-    (&"yang", &"养"),
-    (&"yao", &"腰"),
-    (&"yidan", &"一旦"),
-    (&"yilv", &"一律"),
-    (&"yizhi", &"一致"),
-    (&"yihan", &"遗憾"),
-    (&"yiwen", &"疑问"),
-    (&"yiwu", &"义务"),
-    (&"yinsu", &"因素"),
-    (&"yinshua", &"印刷"),
-    (&"yingjie", &"迎接"),
-    (&"yingye", &"营业"),
-    (&"yingfu", &"应付"),
-    (&"ying", &"硬"),
-    (&"yongji", &"拥挤"),
-    (&"yongqi", &"勇气"),
-    (&"yonggong", &"用功"),
-    (&"youshi", &"优势"),
-    (&"youyu", &"犹豫"),
-    (&"youzha", &"油炸"),
-    (&"youlan", &"游览"),
-    (&"youli", &"有利"),
-    (&"youle", &"娱乐"),
-    (&"youqi", &"与其"),
-    (&"yufang", &"预防"),
-    (&"yuandan", &"元旦"),
-    (&"yuangong", &"员工"),
-    (&"yuanliao", &"原料"),
-    (&"yuanze", &"原则"),
-    (&"yuan", &"圆"),
-    (&"yunqi", &"运气"),
-    (&"yunshu", &"运输"),
-    (&"zaihu", &"在乎"),
-    (&"zaiyu", &"在于"),
-    (&"zancheng", &"赞成"),
-    (&"zebei", &"责备"),
-    (&"zhai", &"窄"),
-    (&"zhankai", &"展开"),
-    (&"zhang", &"涨"),
-    (&"zhangwo", &"掌握"),
-    (&"zhaochang", &"照常"),
-    (&"zhenxi", &"珍惜"),
-    (&"zhenshi", &"真实"),
-    (&"zhengqiu", &"征求"),
-    (&"zheng", &"睁"),
-    (&"zhengqi", &"整齐"),
-    (&"zhengfu", &"政府"),
-    (&"zhi", &"支"),
-    (&"zhi", &"直"),
-    (&"zhidao", &"指导"),
-    (&"zhihui", &"指挥"),
-    (&"zhiyu", &"至于"),
-    (&"zhizuo", &"制作"),
-    (&"zhixu", &"秩序"),
-    (&"zhongxun", &"中旬"),
-    (&"zhonglei", &"种类"),
-    (&"zhongliang", &"重量"),
-    (&"zhubu", &"逐步"),
-    (&"zhuguan", &"主观"),
-    (&"zhuti", &"主题"),
-    (&"zhuxi", &"主席"),
-    (&"zhuzhang", &"主张"),
-    (&"zhu", &"煮"),
-    (&"zhua", &"抓"),
-    (&"zhuajin", &"抓紧"),
-    (&"zhuanbian", &"转变"),
-    (&"zhuangshi", &"装饰"),
-    (&"zhuangkuang", &"状况"),
-    (&"zhuiqiu", &"追求"),
-    (&"zixun", &"咨询"),
-    (&"zijin", &"资金"),
-    (&"ziyuan", &"资源"),
-    (&"zi", &"紫"),
-    (&"zicong", &"自从"),
-    (&"zidong", &"自动"),
-    (&"zihao", &"自豪"),
-    (&"zijue", &"自觉"),
-    (&"zisi", &"自私"),
-    (&"ziyuan", &"自愿"),
-    (&"zongsuan", &"总算"),
-    (&"zongzhi", &"总之"),
-    (&"zuzhi", &"组织"),
-    (&"zuichu", &"最初"),
-    (&"zunjing", &"尊敬"),
-    (&"zunshou", &"遵守"),
-    (&"zuowen", &"作文"),
-    (&"baoguo", &"包裹"),
-    (&"baochi", &"保持"),
-    (&"baodao", &"报到"),
-    (&"beiguan", &"悲观"),
-    (&"biaodian", &"标点"),
-    (&"bingdu", &"病毒"),
-    (&"bowuguan", &"博物馆"),
-    (&"chazi", &"叉子"),
-    (&"chai", &"拆"),
-    (&"chao", &"抄"),
-    (&"chaoji", &"超级"),
-    (&"cheku", &"车库"),
-    (&"chexiang", &"车厢"),
-    (&"chengxu", &"程序"),
-    (&"chizi", &"尺子"),
-    (&"chongdianqi", &"充电器"),
-    (&"chouti", &"抽屉"),
-    (&"chou", &"丑"),
-    (&"chou", &"臭"),
-    (&"chukou", &"出口"),
-    (&"chuji", &"初级"),
-    (&"chuanbo", &"传播"),
-    (&"chuanran", &"传染"),
-    (&"congmang", &"匆忙"),
-    (&"dapenti", &"打喷嚏"),
-    (&"daikuan", &"贷款"),
-    (&"danyuan", &"单元"),
-    (&"dan", &"淡"),
-    (&"dangxin", &"当心"),
-    (&"di", &"滴"),
-    (&"ditan", &"地毯"),
-    (&"doufu", &"豆腐"),
-    (&"duihuan", &"兑换"),
-    (&"duoyu", &"多余"),
-    (&"erhuan", &"耳环"),
-    (&"fabiao", &"发表"),
-    (&"faming", &"发明"),
-    (&"fapiao", &"发票"),
-    (&"fakuan", &"罚款"),
-    (&"fayuan", &"法院"),
-    (&"feihua", &"废话"),
-    (&"fenpei", &"分配"),
-    (&"fenshou", &"分手"),
-    (&"fengge", &"风格"),
-    (&"fengkuang", &"疯狂"),
-    (&"fouding", &"否定"),
-    (&"fudao", &"辅导"),
-    (&"fuzhi", &"复制"),
-    (&"gaizheng", &"改正"),
-    (&"gebi", &"隔壁"),
-    (&"gongping", &"公平"),
-    (&"gongzhu", &"公主"),
-    (&"gongxi", &"恭喜"),
-    (&"gupiao", &"股票"),
-    (&"guahao", &"挂号"),
-    (&"guaiwan", &"拐弯"),
-    (&"guaibude", &"怪不得"),
-    (&"guanzi", &"管子"),
-    (&"guangpan", &"光盘"),
-    (&"guimo", &"规模"),
-    (&"guitai", &"柜台"),
-    (&"guoqingjie", &"国庆节"),
-    (&"guowang", &"国王"),
-    (&"guomin", &"过敏"),
-    (&"guoqi", &"过期"),
-    (&"haiguan", &"海关"),
-    (&"hefa", &"合法"),
-    (&"hetong", &"合同"),
-    (&"heying", &"合影"),
-    (&"hen", &"恨"),
-    (&"huasheng", &"花生"),
-    (&"hua", &"划"),
-    (&"huaxue", &"化学"),
-    (&"huaiyun", &"怀孕"),
-    (&"huangjin", &"黄金"),
-    (&"huilv", &"汇率"),
-    (&"jimang", &"急忙"),
-    (&"jizhen", &"急诊"),
-    (&"jihe", &"集合"),
-    (&"jiti", &"集体"),
-    (&"jilingdai", &"系领带"),
-    (&"jiazi", &"夹子"),
-    (&"jiabin", &"嘉宾"),
-    (&"jiazhuang", &"假装"),
-    (&"jia", &"嫁"),
-    (&"jianqiang", &"坚强"),
-    (&"jianzhi", &"兼职"),
-    (&"jianpan", &"键盘"),
-    (&"jiaoshui", &"胶水"),
-    (&"jiaocai", &"教材"),
-    (&"jiezhang", &"结账"),
-    (&"jiezhi", &"戒指"),
-    (&"jinkou", &"进口"),
-    (&"jingli", &"精力"),
-    (&"jiuhuche", &"救护车"),
-    (&"juzi", &"桔子"),
-    (&"julebu", &"俱乐部"),
-    (&"juan", &"捐"),
-    (&"kache", &"卡车"),
-    (&"kanwang", &"看望"),
-    (&"ke", &"克"),
-    (&"kecheng", &"课程"),
-    (&"kuaiji", &"会计"),
-    (&"lajiao", &"辣椒"),
-    (&"laoshu", &"老鼠"),
-    (&"limi", &"厘米"),
-    (&"lixi", &"利息"),
-    (&"lieche", &"列车"),
-    (&"lingjian", &"零件"),
-    (&"lingshi", &"零食"),
-    (&"lingdao", &"领导"),
-    (&"liulan", &"浏览"),
-    (&"luyin", &"录音"),
-    (&"lunwen", &"论文"),
-    (&"maikefeng", &"麦克风"),
-    (&"maoyi", &"贸易"),
-    (&"meimao", &"眉毛"),
-    (&"mishu", &"秘书"),
-    (&"mifeng", &"蜜蜂"),
-    (&"mingpian", &"名片"),
-    (&"mingshengguji", &"名胜古迹"),
-    (&"moter", &"模特"),
-    (&"motuoche", &"摩托车"),
-    (&"mulu", &"目录"),
-    (&"neike", &"内科"),
-    (&"niuzaiku", &"牛仔裤"),
-    (&"ouzhou", &"欧洲"),
-    (&"peihe", &"配合"),
-    (&"pi", &"披"),
-    (&"pinyin", &"拼音"),
-    (&"pindao", &"频道"),
-    (&"pingfang", &"平方"),
-    (&"pochan", &"破产"),
-    (&"qian", &"欠"),
-    (&"qiang", &"枪"),
-    (&"qingchun", &"青春"),
-    (&"qu", &"娶"),
-    (&"quanli", &"权力"),
-    (&"quanli", &"权利"),
-    (&"rao", &"绕"),
-    (&"renkou", &"人口"),
-    (&"renminbi", &"人民币"),
-    (&"renshi", &"人事"),
-    (&"rili", &"日历"),
-    (&"riqi", &"日期"),
-    (&"ruan", &"软"),
-    (&"ruanjian", &"软件"),
-    (&"sangzi", &"嗓子"),
-    (&"shanchu", &"删除"),
-    (&"she", &"蛇"),
-    (&"sheying", &"摄影"),
-    (&"shenhua", &"神话"),
-    (&"shengdong", &"生动"),
-    (&"shengdiao", &"声调"),
-    (&"shenglve", &"省略"),
-    (&"shiye", &"失业"),
-    (&"shizi", &"狮子"),
-    (&"shicha", &"时差"),
-    (&"shimao", &"时髦"),
-    (&"shixi", &"实习"),
-    (&"shijuan", &"试卷"),
-    (&"shouju", &"收据"),
-    (&"shougong", &"手工"),
-    (&"shoushu", &"手术"),
-    (&"shoutao", &"手套"),
-    (&"shouming", &"寿命"),
-    (&"shujia", &"书架"),
-    (&"shubiao", &"鼠标"),
-    (&"shuma", &"数码"),
-    (&"shuangfang", &"双方"),
-    (&"shui", &"税"),
-    (&"sichou", &"丝绸"),
-    (&"si", &"撕"),
-    (&"sousuo", &"搜索"),
-    (&"sushe", &"宿舍"),
-    (&"taijiquan", &"太极拳"),
-    (&"tanpan", &"谈判"),
-    (&"tanshuai", &"坦率"),
-    (&"taojiahuanjia", &"讨价还价"),
-    (&"tigang", &"提纲"),
-    (&"tongyi", &"统一"),
-    (&"tou", &"偷"),
-    (&"tudi", &"土地"),
-    (&"tudou", &"土豆"),
-    (&"tu", &"吐"),
-    (&"tuzi", &"兔子"),
-    (&"tuan", &"团"),
-    (&"tuici", &"推辞"),
-    (&"tui", &"退"),
-    (&"tuibu", &"退步"),
-    (&"wai", &"歪"),
-    (&"wanju", &"玩具"),
-    (&"wangfan", &"往返"),
-    (&"weijin", &"围巾"),
-    (&"weixiu", &"维修"),
-    (&"weida", &"伟大"),
-    (&"wenjian", &"文件"),
-    (&"wenxue", &"文学"),
-    (&"wenhou", &"问候"),
-    (&"woshou", &"握手"),
-    (&"wushu", &"武术"),
-    (&"wu", &"雾"),
-    (&"xialingying", &"夏令营"),
-    (&"xiangchang", &"香肠"),
-    (&"xianglian", &"项链"),
-    (&"xiangzheng", &"象征"),
-    (&"xiaohua", &"消化"),
-    (&"xiaomai", &"小麦"),
-    (&"xiaoqi", &"小气"),
-    (&"xie", &"血"),
-    (&"xinhao", &"信号"),
-    (&"xinren", &"信任"),
-    (&"xingzhi", &"性质"),
-    (&"xiugai", &"修改"),
-    (&"xuanbu", &"宣布"),
-    (&"xueli", &"学历"),
-    (&"xueshu", &"学术"),
-    (&"yanjiang", &"演讲"),
-    (&"yanhui", &"宴会"),
-    (&"yangtai", &"阳台"),
-    (&"yang", &"痒"),
-    (&"yilun", &"议论"),
-    (&"yingjian", &"硬件"),
-    (&"youhui", &"优惠"),
-    (&"yuqi", &"语气"),
-    (&"yumi", &"玉米"),
-    (&"yubao", &"预报"),
-    (&"yuding", &"预订"),
-    (&"yueqi", &"乐器"),
-    (&"zhai", &"摘"),
-    (&"zhantie", &"粘贴"),
-    (&"zhanlan", &"展览"),
-    (&"zhanghu", &"账户"),
-    (&"zhaoliang", &"着凉"),
-    (&"zhexue", &"哲学"),
-    (&"zhenduan", &"诊断"),
-    (&"zhendong", &"振动"),
-    (&"zheng", &"正"),
-    (&"zhengjian", &"证件"),
-    (&"zhengzhi", &"政治"),
-    (&"zhipiao", &"支票"),
-    (&"zhizhao", &"执照"),
-    (&"zhiding", &"制定"),
-    (&"zhidu", &"制度"),
-    (&"zhongjie", &"中介"),
-    (&"zhoudao", &"周到"),
-    (&"zhuren", &"主任"),
-    (&"zhufu", &"祝福"),
-    (&"zhuanxin", &"专心"),
-    (&"zhuangao", &"转告"),
-    (&"zimu", &"字母"),
-    (&"zimu", &"字幕"),
-    (&"zongli", &"总理"),
-    (&"zongtong", &"总统"),
-    (&"aodaliya", &"澳大利亚"),
-    (&"baotuquan", &"趵突泉"),
-    (&"baoquan", &"鲍全"),
-    (&"caishikou", &"菜市口"),
-    (&"chuguo", &"楚国"),
-    (&"chunqiu", &"春秋"),
-    (&"dannierkaneiman", &"丹尼尔·卡内曼"),
-    (&"daoxiangcun", &"稻香村"),
-    (&"donghailongwang", &"东海龙王"),
-    (&"fengzhongdinglv", &"峰终定律"),
-    (&"guangheju", &"广和居"),
-    (&"huabei", &"华北"),
-    (&"jinan", &"济南"),
-    (&"jialifuniyazhou", &"加利福尼亚州"),
-    (&"keen", &"科恩"),
-    (&"kongzi", &"孔子"),
-    (&"liguang", &"李广"),
-    (&"lianpo", &"廉颇"),
-    (&"liuchiping", &"刘炽平"),
-    (&"lumiaier", &"卢米埃尔"),
-    (&"luxun", &"鲁迅"),
-    (&"masazhusaizhou", &"马萨诸塞州"),
-    (&"maibuliqi", &"麦布里奇"),
-    (&"meixi", &"梅西"),
-    (&"<nahan>", &"《呐喊》"),
-    (&"nuobeierjiang", &"诺贝尔奖"),
-    (&"panghuang", &"《彷徨》"),
-    (&"qilang", &"七郎"),
-    (&"qinguo", &"秦国"),
-    (&"shun", &"舜"),
-    (&"sitanfu", &"斯坦福"),
-    (&"tengxun", &"腾讯"),
-    (&"weixin", &"微信"),
-    (&"xi", &"夕"),
-    (&"xihan", &"西汉"),
-    (&"xinxilan", &"新西兰"),
-    (&"yangxiong", &"杨雄"),
-    (&"yudafu", &"郁达夫"),
-    (&"zhaifeng", &"翟峰"),
-    (&"zhanmushinaishimisi", &"詹姆士·奈史密斯"),
-    (&"zhanguo", &"战国"),
-    (&"zhangxiaolong", &"张小龙"),
-    (&"zhaoguo", &"赵国"),
-    (&"zhaokuo", &"赵括"),
-    (&"zhaoshe", &"赵奢"),
-    (&"zilu", &"子路"),
-    (&"benpao", &"奔跑"),
-    (&"bodong", &"波动"),
-    (&"chengba", &"称霸"),
-    (&"daiyan", &"代言"),
-    (&"daoju", &"道具"),
-    (&"dianqiu", &"颠球"),
-    (&"ding", &"叮"),
-    (&"enai", &"恩爱"),
-    (&"ershou", &"二手"),
-    (&"fanchuan", &"帆船"),
-    (&"fangzong", &"放纵"),
-    (&"gan", &"杆"),
-    (&"guaiwu", &"怪物"),
-    (&"gun", &"棍"),
-    (&"guojun", &"国君"),
-    (&"haili", &"海里"),
-    (&"hanyi", &"含意"),
-    (&"hangjia", &"行家"),
-    (&"heixingxing", &"黑猩猩"),
-    (&"huannanyugong", &"患难与共"),
-    (&"huochengyan", &"火成岩"),
-    (&"jizhi", &"机制"),
-    (&"jixu", &"积蓄"),
-    (&"jian", &"箭"),
-    (&"jinyu", &"金鱼"),
-    (&"jingchengsuozhi", &"精诚所至"),
-    (&"jinshiweikai", &"金石为开"),
-    (&"jiucan", &"就餐"),
-    (&"juzi", &"锯子"),
-    (&"ju", &"锯"),
-    (&"kunrao", &"困扰"),
-    (&"langan", &"栏杆"),
-    (&"linlijinzhi", &"淋漓尽致"),
-    (&"mai", &"埋"),
-    (&"mangren", &"盲人"),
-    (&"meide", &"美德"),
-    (&"menglong", &"蒙眬"),
-    (&"minju", &"民居"),
-    (&"naru", &"纳入"),
-    (&"pingwei", &"评委"),
-    (&"qiju", &"起居"),
-    (&"qingwa", &"青蛙"),
-    (&"qingqu", &"情趣"),
-    (&"quanshenguanzhu", &"全神贯注"),
-    (&"ruwei", &"入围"),
-    (&"sheru", &"摄入"),
-    (&"shenshangxiansu", &"肾上腺素"),
-    (&"shengwuzhong", &"生物钟"),
-    (&"shihuiyan", &"石灰岩"),
-    (&"shoubi", &"手笔"),
-    (&"shou", &"守"),
-    (&"shunchang", &"顺畅"),
-    (&"siheyuan", &"四合院"),
-    (&"tizi", &"蹄子"),
-    (&"ti", &"蹄"),
-    (&"tianran", &"天然"),
-    (&"tuya", &"涂鸦"),
-    (&"waiwainiuniu", &"歪歪扭扭"),
-    (&"weiyang", &"喂养"),
-    (&"wenzi", &"蚊子"),
-    (&"xiangjingrubin", &"相敬如宾"),
-    (&"xiangfang", &"厢房"),
-    (&"xiangzi", &"橡子"),
-    (&"xiaojing", &"孝敬"),
-    (&"youyou", &"悠悠"),
-    (&"youlai", &"由来"),
-    (&"yuyan", &"寓言"),
-    (&"zhaosanmusi", &"朝三暮四"),
-    (&"zhexuejia", &"哲学家"),
-    (&"zhen", &"镇"),
-    (&"zhishangtanbing", &"纸上谈兵"),
-    (&"zhong", &"种"),
-    (&"beijingshifandaxue", &"北京师范大学"),
-    (&"biergaici", &"比尔·盖茨"),
-    (&"bier`gaici", &"比尔·盖茨"),
-    (&"<feinimoshu>", &"《非你莫属》"),
-    (&"feinimoshu", &"《非你莫属》"),
-    (&"guomao", &"国贸"),
-    (&"guloudajie", &"鼓楼大街"),
-    (&"haolinshuo", &"郝林说"),
-    (&"huguosi", &"护国寺"),
-    (&"huiguniang", &"灰姑娘"),
-    (&"jianwei", &"健伟"),
-    (&"laoshe", &"老舍"),
-    (&"lichadexiersi", &"里查德·希尔斯"),
-    (&"lichade`xiersi", &"里查德·希尔斯"),
-    (&"liuchen", &"刘辰"),
-    (&"luoshanji", &"洛杉矶"),
-    (&"<maihuochaidexiaonvhaier>", &"《卖火柴的小女孩儿》"),
-    (&"maihuochaidexiaonvhair", &"《卖火柴的小女孩儿》"),
-    (&"niujindaxue", &"牛津大学"),
-    (&"oumeng", &"欧盟"),
-    (&"ouzhouhuanjingbaohushu", &"欧洲环境保护署"),
-    (&"panjiayuan", &"潘家园"),
-    (&"peizhansen", &"佩·詹森"),
-    (&"pei`zhansen", &"佩·詹森"),
-    (&"shuowenjiezi", &"《说文解字》"),
-    (&"<shuowenjiezi>", &"《说文解字》"),
-    (&"tiananmendong", &"天安门东"),
-    (&"tianjinweishi", &"天津卫视"),
-    (&"wenwen", &"文文"),
-    (&"xiaweiyi", &"夏威夷"),
-    (&"yinjia", &"印加"),
-    (&"yunnan", &"云南"),
-    (&"zhaofugen", &"赵福根"),
-    (&"bachengr", &"八成儿"),
-    (&"bacheng", &"八成"),
-    (&"ban", &"般"),
-    (&"baoyu", &"暴雨"),
-    (&"biguan", &"闭关"),
-    (&"bujiasisuo", &"不假思索"),
-    (&"caiyi", &"才艺"),
-    (&"chaxun", &"查询"),
-    (&"chehuo", &"车祸"),
-    (&"chen", &"沉"),
-    (&"chengxin", &"诚信"),
-    (&"chou", &"抽"),
-    (&"chuangban", &"创办"),
-    (&"conglin", &"丛林"),
-    (&"cunhuo", &"存活"),
-    (&"e", &"额"),
-    (&"fanxing", &"反省"),
-    (&"fanhang", &"返航"),
-    (&"fan", &"犯"),
-    (&"fenxiang", &"分享"),
-    (&"fenglang", &"风浪"),
-    (&"fuzhong", &"负重"),
-    (&"gan", &"擀"),
-    (&"genji", &"根基"),
-    (&"genzhi", &"根治"),
-    (&"gu", &"雇"),
-    (&"hanxian", &"汗腺"),
-    (&"heshang", &"和尚"),
-    (&"hu", &"糊"),
-    (&"jisheng", &"寄生"),
-    (&"jia", &"佳"),
-    (&"jiafang", &"家访"),
-    (&"jianburufei", &"健步如飞"),
-    (&"jiao", &"焦"),
-    (&"ju", &"局"),
-    (&"juhua", &"菊花"),
-    (&"kua", &"垮"),
-    (&"lai", &"来"),
-    (&"laoying", &"老鹰"),
-    (&"lianhuanhua", &"连环画"),
-    (&"liushou", &"留守"),
-    (&"lv", &"屡"),
-    (&"luobo", &"萝卜"),
-    (&"maokong", &"毛孔"),
-    (&"mao", &"冒"),
-    (&"menkan", &"门槛"),
-    (&"nianyu", &"鲇鱼"),
-    (&"pengzi", &"棚子"),
-    (&"qingzhuangnian", &"青壮年"),
-    (&"qinglv", &"情侣"),
-    (&"qingyuan", &"情缘"),
-    (&"qingyuanshu", &"请愿书"),
-    (&"shadingyu", &"沙丁鱼"),
-    (&"shazi", &"沙子"),
-    (&"shangxinyuemu", &"赏心悦目"),
-    (&"shaoxu", &"少许"),
-    (&"shenduan", &"身段"),
-    (&"shenyuan", &"深渊"),
-    (&"shifei", &"施肥"),
-    (&"shidang", &"适当"),
-    (&"shouji", &"收集"),
-    (&"si", &"丝"),
-    (&"tanhua", &"昙花"),
-    (&"tan", &"痰"),
-    (&"tiandi", &"天敌"),
-    (&"tu", &"图"),
-    (&"wanzhang", &"万丈"),
-    (&"wanggong", &"王宫"),
-    (&"wangsheng", &"旺盛"),
-    (&"weibo", &"微博"),
-    (&"weiqi", &"尾气"),
-    (&"wenhuo", &"文火"),
-    (&"wuyi", &"无意"),
-    (&"xili", &"洗礼"),
-    (&"xiaoying", &"效应"),
-    (&"yilian", &"一连"),
-    (&"yinliang", &"荫凉"),
-    (&"zhaor", &"招儿"),
-    (&"zhao", &"招"),
-    (&"zhedie", &"折叠"),
-    (&"zheli", &"哲理"),
-    (&"zhengteng", &"蒸腾"),
-    (&"zhijiao", &"支教"),
-    (&"zhigan", &"枝干"),
-    (&"zhigan", &"知感"),
-    (&"zhigengniao", &"知更鸟"),
-    (&"zhiding", &"制订"),
-    (&"zhong", &"钟"),
-    (&"zhuanzhu", &"专注"),
-    (&"zhuantou", &"转头"),
-    (&"zhuo", &"啄"),
-    (&"<", &"《"),
-    (&">", &"》"),
-    (&".", &"。"),
-    (&"`", &"·"),
-    (&"\\", &"、"),
-    (&",", &"，"),
-    (&"!", &"！"),
-    (&"?", &"？"),
-    (&":", &"："),
-    (&";", &"；"),
-    (&"(", &"（"),
-    (&")", &"）"),
+pub static PINYIN_CIYU_TEST_DATA: &[(&str, &str)] = &[
+    ("ai", "爱"),
+    ("ba", "八"),
+    ("baba", "爸爸"),
+    ("beizi", "杯子"),
+    ("beijing", "北京"),
+    ("ben", "本"),
+    ("bukeqi", "不客气"),
+    ("bu", "不"),
+    ("cai", "菜"),
+    ("cha", "茶"),
+    ("chi", "吃"),
+    ("chuzuche", "出租车"),
+    ("dadianhua", "打电话"),
+    ("da", "大"),
+    ("de", "的"),
+    ("dian", "点"),
+    ("diannao", "电脑"),
+    ("dianshi", "电视"),
+    ("dianying", "电影"),
+    ("dongxi", "东西"),
+    ("dou", "都"),
+    ("du", "读"),
+    ("duibuqi", "对不起"),
+    ("duo", "多"),
+    ("duoshao", "多少"),
+    ("erzi", "儿子"),
+    ("er", "二"),
+    ("fandian", "饭店"),
+    ("feiji", "飞机"),
+    ("fenzhong", "分钟"),
+    ("gaoxing", "高兴"),
+    ("ge", "个"),
+    ("gongzuo", "工作"),
+    ("gou", "狗"),
+    ("hanyu", "汉语"),
+    ("hao", "好"),
+    ("hao", "号"),
+    ("he", "喝"),
+    ("he", "和"),
+    ("hen", "很"),
+    ("houmian", "后面"),
+    ("hui", "回"),
+    ("hui", "会"),
+    ("ji", "几"),
+    ("jia", "家"),
+    ("jiao", "叫"),
+    ("jintian", "今天"),
+    ("jiu", "九"),
+    ("kai", "开"),
+    ("kan", "看"),
+    ("kanjian", "看见"),
+    ("kuai", "块"),
+    ("lai", "来"),
+    ("laoshi", "老师"),
+    ("le", "了"),
+    ("leng", "冷"),
+    ("li", "里"),
+    ("liu", "六"),
+    ("mama", "妈妈"),
+    ("ma", "吗"),
+    ("mai", "买"),
+    ("mao", "猫"),
+    ("meiguanxi", "没关系"),
+    ("meiyou", "没有"),
+    ("mifan", "米饭"),
+    ("mingzi", "名字"),
+    ("mingtian", "明天"),
+    ("na", "哪"),
+    ("nar", "哪儿"),
+    ("na", "那"),
+    ("ne", "呢"),
+    ("neng", "能"),
+    ("ni", "你"),
+    ("nian", "年"),
+    ("nver", "女儿"),
+    ("pengyou", "朋友"),
+    ("piaoliang", "漂亮"),
+    ("pingguo", "苹果"),
+    ("qi", "七"),
+    ("qianmian", "前面"),
+    ("qian", "钱"),
+    ("qing", "请"),
+    ("qu", "去"),
+    ("re", "热"),
+    ("ren", "人"),
+    ("renshi", "认识"),
+    ("san", "三"),
+    ("shangdian", "商店"),
+    ("shang", "上"),
+    ("shangwu", "上午"),
+    ("shao", "少"),
+    ("shei", "谁"),
+    ("shenme", "什么"),
+    ("shi", "十"),
+    ("shihou", "时候"),
+    ("shi", "是"),
+    ("shu", "书"),
+    ("shui", "水"),
+    ("shuiguo", "水果"),
+    ("shuijiao", "睡觉"),
+    ("shuo", "说"),
+    ("si", "四"),
+    ("sui", "岁"),
+    ("ta", "他"),
+    ("ta", "她"),
+    ("tai", "太"),
+    ("tianqi", "天气"),
+    ("ting", "听"),
+    ("tongxue", "同学"),
+    ("wei", "喂"),
+    ("wo", "我"),
+    ("women", "我们"),
+    ("wu", "五"),
+    ("xihuan", "喜欢"),
+    ("xia", "下"),
+    ("xiawu", "下午"),
+    ("xiayu", "下雨"),
+    ("xiansheng", "先生"),
+    ("xianzai", "现在"),
+    ("xiang", "想"),
+    ("xiao", "小"),
+    ("xiaojie", "小姐"),
+    ("xie", "些"),
+    ("xie", "写"),
+    ("xiexie", "谢谢"),
+    ("xingqi", "星期"),
+    ("xuesheng", "学生"),
+    ("xuexi", "学习"),
+    ("xuexiao", "学校"),
+    ("yi", "一"),
+    ("yifu", "衣服"),
+    ("yisheng", "医生"),
+    ("yiyuan", "医院"),
+    ("yizi", "椅子"),
+    ("yidianr", "一点儿"),
+    ("you", "有"),
+    ("yue", "月"),
+    ("zaijian", "再见"),
+    ("zai", "在"),
+    ("zenme", "怎么"),
+    ("zenmeyang", "怎么样"),
+    ("zhe", "这"),
+    ("zhongguo", "中国"),
+    ("zhongwu", "中午"),
+    ("zhu", "住"),
+    ("zhuozi", "桌子"),
+    ("zi", "字"),
+    ("zuotian", "昨天"),
+    ("zuo", "坐"),
+    ("zuo", "做"),
+    ("dawei", "大卫"),
+    ("li", "李"),
+    ("meiguo", "美国"),
+    ("wang", "王"),
+    ("xie", "谢"),
+    ("peng", "朋"),
+    ("bushao", "不少"),
+    ("che", "车"),
+    ("chifan", "吃饭"),
+    ("daxue", "大学"),
+    ("guo", "国"),
+    ("hanzi", "汉字"),
+    ("hou", "后"),
+    ("huilai", "回来"),
+    ("jinnian", "今年"),
+    ("mei", "没"),
+    ("nar", "那儿"),
+    ("nimen", "你们"),
+    ("qian", "前"),
+    ("xiamian", "下面"),
+    ("xue", "学"),
+    ("yu", "雨"),
+    ("zher", "这儿"),
+    ("zhexie", "这些"),
+    ("ba", "吧"),
+    ("bai", "白"),
+    ("bai", "百"),
+    ("bangzhu", "帮助"),
+    ("baozhi", "报纸"),
+    ("bi", "比"),
+    ("bie", "别"),
+    ("binguan", "宾馆"),
+    ("chang", "长"),
+    ("changge", "唱歌"),
+    ("chu", "出"),
+    ("chuan", "穿"),
+    ("ci", "次"),
+    ("cong", "从"),
+    ("cuo", "错"),
+    ("dalanqiu", "打篮球"),
+    ("dajia", "大家"),
+    ("danshi", "但是"),
+    ("dao", "到"),
+    ("de", "得"),
+    ("deng", "等"),
+    ("didi", "弟弟"),
+    ("diyi", "第一"),
+    ("dong", "懂"),
+    ("dui", "对"),
+    ("fangjian", "房间"),
+    ("feichang", "非常"),
+    ("fuwuyuan", "服务员"),
+    ("gao", "高"),
+    ("gaosu", "告诉"),
+    ("gege", "哥哥"),
+    ("gei", "给"),
+    ("gonggongqiche", "公共汽车"),
+    ("gongsi", "公司"),
+    ("gui", "贵"),
+    ("guo", "过"),
+    ("hai", "还"),
+    ("haizi", "孩子"),
+    ("haochi", "好吃"),
+    ("hei", "黑"),
+    ("hong", "红"),
+    ("huochezhan", "火车站"),
+    ("jichang", "机场"),
+    ("jidan", "鸡蛋"),
+    ("jian", "件"),
+    ("juede", "觉得"),
+    ("jiaoshi", "教室"),
+    ("jiejie", "姐姐"),
+    ("jieshao", "介绍"),
+    ("jin", "进"),
+    ("jin", "近"),
+    ("jiu", "就"),
+    ("kafei", "咖啡"),
+    ("kaishi", "开始"),
+    ("kaoshi", "考试"),
+    ("keneng", "可能"),
+    ("keyi", "可以"),
+    ("ke", "课"),
+    ("kuai", "快"),
+    ("kuaile", "快乐"),
+    ("lei", "累"),
+    ("li", "离"),
+    ("liang", "两"),
+    ("ling", "零"),
+    ("lu", "路"),
+    ("lvyou", "旅游"),
+    ("mai", "卖"),
+    ("man", "慢"),
+    ("mang", "忙"),
+    ("mei", "每"),
+    ("meimei", "妹妹"),
+    ("men", "门"),
+    ("miantiao", "面条"),
+    ("nan", "男"),
+    ("nin", "您"),
+    ("niunai", "牛奶"),
+    ("nv", "女"),
+    ("pangbian", "旁边"),
+    ("paobu", "跑步"),
+    ("pianyi", "便宜"),
+    ("piao", "票"),
+    ("qizi", "妻子"),
+    ("qichuang", "起床"),
+    ("qian", "千"),
+    ("qianbi", "铅笔"),
+    ("qing", "晴"),
+    ("qunian", "去年"),
+    ("rang", "让"),
+    ("ri", "日"),
+    ("shangban", "上班"),
+    ("shenti", "身体"),
+    ("shengbing", "生病"),
+    ("shengri", "生日"),
+    ("shijian", "时间"),
+    ("shiqing", "事情"),
+    ("shoubiao", "手表"),
+    ("shouji", "手机"),
+    ("shuohua", "说话"),
+    ("song", "送"),
+    ("suiran", "虽然"),
+    ("suoyi", "所以"),
+    ("ta", "它"),
+    ("tizuqiu", "踢足球"),
+    ("ti", "题"),
+    ("tiaowu", "跳舞"),
+    ("wai", "外"),
+    ("wan", "完"),
+    ("wan", "玩"),
+    ("wanr", "玩儿"),
+    ("wanshang", "晚上"),
+    ("wang", "往"),
+    ("weishenme", "为什么"),
+    ("wen", "问"),
+    ("wenti", "问题"),
+    ("xigua", "西瓜"),
+    ("xiwang", "希望"),
+    ("xi", "洗"),
+    ("xiaoshi", "小时"),
+    ("xiao", "笑"),
+    ("xin", "新"),
+    ("xing", "姓"),
+    ("xiuxi", "休息"),
+    ("xue", "雪"),
+    ("yanse", "颜色"),
+    ("yanjing", "眼睛"),
+    ("yangrou", "羊肉"),
+    ("yao", "药"),
+    ("yao", "要"),
+    ("ye", "也"),
+    ("yixia", "一下"),
+    ("yijing", "已经"),
+    ("yiqi", "一起"),
+    ("yisi", "意思"),
+    ("yinwei", "因为"),
+    ("yin", "阴"),
+    ("youyong", "游泳"),
+    ("youbian", "右边"),
+    ("yu", "鱼"),
+    ("yuan", "远"),
+    ("yundong", "运动"),
+    ("zai", "再"),
+    ("zaoshang", "早上"),
+    ("zhangfu", "丈夫"),
+    ("zhao", "找"),
+    ("zhe", "着"),
+    ("zhen", "真"),
+    ("zhengzai", "正在"),
+    ("zhidao", "知道"),
+    ("zhunbei", "准备"),
+    ("zou", "走"),
+    ("zui", "最"),
+    ("zuobian", "左边"),
+    ("huahua", "花花"),
+    ("yang", "杨"),
+    ("xiaoxiao", "笑笑"),
+    ("du", "度"),
+    ("fense", "粉色"),
+    ("yihou", "以后"),
+    ("bang", "帮"),
+    ("bucuo", "不错"),
+    ("chuyuan", "出院"),
+    ("fen", "粉"),
+    ("hongse", "红色"),
+    ("lukou", "路口"),
+    ("shou", "手"),
+    ("waimian", "外面"),
+    ("xinnian", "新年"),
+    ("youyisi", "有意思"),
+    ("ayi", "阿姨"),
+    ("a", "啊"),
+    ("ai", "矮"),
+    ("aihao", "爱好"),
+    ("anjing", "安静"),
+    ("ba", "把"),
+    ("ban", "班"),
+    ("ban", "搬"),
+    ("banfa", "办法"),
+    ("bangongshi", "办公室"),
+    ("ban", "半"),
+    ("bangmang", "帮忙"),
+    ("bao", "包"),
+    ("bao", "饱"),
+    ("beifang", "北方"),
+    ("bei", "被"),
+    ("bizi", "鼻子"),
+    ("bijiao", "比较"),
+    ("bisai", "比赛"),
+    ("bijiben", "笔记本"),
+    ("bixu", "必须"),
+    ("bianhua", "变化"),
+    ("bieren", "别人"),
+    ("bingxiang", "冰箱"),
+    ("budan", "不但"),
+    ("erqie", "而且"),
+    ("caidan", "菜单"),
+    ("canjia", "参加"),
+    ("cao", "草"),
+    ("ceng", "层"),
+    ("cha", "差"),
+    ("chaoshi", "超市"),
+    ("chenshan", "衬衫"),
+    ("chengji", "成绩"),
+    ("chengshi", "城市"),
+    ("chidao", "迟到"),
+    ("chule", "除了"),
+    ("chuan", "船"),
+    ("chun", "春"),
+    ("tian", "天"),
+    ("cidian", "词典"),
+    ("congming", "聪明"),
+    ("dasao", "打扫"),
+    ("dasuan", "打算"),
+    ("dai", "带"),
+    ("danxin", "担心"),
+    ("dangao", "蛋糕"),
+    ("dangran", "当然"),
+    ("de", "地"),
+    ("deng", "灯"),
+    ("difang", "地方"),
+    ("ditie", "地铁"),
+    ("ditu", "地图"),
+    ("dianti", "电梯"),
+    ("dianziyoujian", "电子邮件"),
+    ("dong", "东"),
+    ("dong", "冬"),
+    ("dongwu", "动物"),
+    ("duan", "短"),
+    ("duan", "段"),
+    ("duanlian", "锻炼"),
+    ("duome", "多么"),
+    ("e", "饿"),
+    ("erduo", "耳朵"),
+    ("fa", "发"),
+    ("fashao", "发烧"),
+    ("faxian", "发现"),
+    ("fangbian", "方便"),
+    ("fang", "放"),
+    ("fangxin", "放心"),
+    ("fen", "分"),
+    ("fujin", "附近"),
+    ("fuxi", "复习"),
+    ("ganjing", "干净"),
+    ("ganmao", "感冒"),
+    ("ganxingqu", "感兴趣"),
+    ("gangcai", "刚才"),
+    ("gezi", "个子"),
+    ("genju", "根据"),
+    ("gen", "跟"),
+    ("geng", "更"),
+    ("gongjin", "公斤"),
+    ("gongyuan", "公园"),
+    ("gushi", "故事"),
+    ("guafeng", "刮风"),
+    ("guan", "关"),
+    ("guanxi", "关系"),
+    ("guanxin", "关心"),
+    ("guanyu", "关于"),
+    ("guojia", "国家"),
+    ("guo", "过"),
+    ("guoqu", "过去"),
+    ("haishi", "还是"),
+    ("haipa", "害怕"),
+    ("heiban", "黑板"),
+    ("houlai", "后来"),
+    ("huzhao", "护照"),
+    ("hua", "花"),
+    ("hua", "画"),
+    ("huai", "坏"),
+    ("huanying", "欢迎"),
+    ("huan", "还"),
+    ("huanjing", "环境"),
+    ("huan", "换"),
+    ("huida", "回答"),
+    ("huiyi", "会议"),
+    ("huozhe", "或者"),
+    ("jihu", "几乎"),
+    ("jihui", "机会"),
+    ("ji", "极"),
+    ("jide", "记得"),
+    ("jijie", "季节"),
+    ("jiancha", "检查"),
+    ("jiandan", "简单"),
+    ("jianmian", "见面"),
+    ("jiankang", "健康"),
+    ("jiang", "讲"),
+    ("jiao", "教"),
+    ("jiao", "角"),
+    ("jiao", "脚"),
+    ("jie", "接"),
+    ("jidao", "街道"),
+    ("jiemu", "节目"),
+    ("jieri", "节日"),
+    ("jiehun", "结婚"),
+    ("jieshu", "结束"),
+    ("jiejue", "解决"),
+    ("jie", "借"),
+    ("jingchang", "经常"),
+    ("jingguo", "经过"),
+    ("jingli", "经理"),
+    ("jiu", "久"),
+    ("jiu", "旧"),
+    ("juzi", "句子"),
+    ("jueding", "决定"),
+    ("keai", "可爱"),
+    ("ke", "渴"),
+    ("ke", "刻"),
+    ("keren", "客人"),
+    ("kongtiao", "空调"),
+    ("kou", "口"),
+    ("ku", "哭"),
+    ("kuzi", "裤子"),
+    ("kuaizi", "筷子"),
+    ("lan", "蓝"),
+    ("lao", "老"),
+    ("likai", "离开"),
+    ("liwu", "礼物"),
+    ("lishi", "历史"),
+    ("lian", "脸"),
+    ("lianxi", "练习"),
+    ("liang", "辆"),
+    ("liaotian", "聊天"),
+    ("liaotianr", "聊天儿"),
+    ("liaojie", "了解"),
+    ("linju", "邻居"),
+    ("liuxue", "留学"),
+    ("lou", "楼"),
+    ("lv", "绿"),
+    ("ma", "马"),
+    ("mashang", "马上"),
+    ("manyi", "满意"),
+    ("maozi", "帽子"),
+    ("mi", "米"),
+    ("mianbao", "面包"),
+    ("mingbai", "明白"),
+    ("na", "拿"),
+    ("nainai", "奶奶"),
+    ("nan", "南"),
+    ("nan", "难"),
+    ("nanguo", "难过"),
+    ("nianji", "年级"),
+    ("nianqing", "年轻"),
+    ("niao", "鸟"),
+    ("nuli", "努力"),
+    ("pashan", "爬山"),
+    ("panzi", "盘子"),
+    ("pang", "胖"),
+    ("pixie", "皮鞋"),
+    ("pijiu", "啤酒"),
+    ("pingzi", "瓶子"),
+    ("qishi", "其实"),
+    ("qita", "其他"),
+    ("qiguai", "奇怪"),
+    ("qi", "骑"),
+    ("qifei", "起飞"),
+    ("qilai", "起来"),
+    ("qingchu", "清楚"),
+    ("qingjia", "请假"),
+    ("qiu", "秋"),
+    ("qunzi", "裙子"),
+    ("ranhou", "然后"),
+    ("reqing", "热情"),
+    ("renwei", "认为"),
+    ("renzhen", "认真"),
+    ("rongyi", "容易"),
+    ("ruguo", "如果"),
+    ("san", "伞"),
+    ("shangwang", "上网"),
+    ("shengqi", "生气"),
+    ("shengyin", "声音"),
+    ("shijie", "世界"),
+    ("shi", "试"),
+    ("shou", "瘦"),
+    ("shushu", "叔叔"),
+    ("shufu", "舒服"),
+    ("shu", "树"),
+    ("shuxue", "数学"),
+    ("shuaya", "刷牙"),
+    ("shuang", "双"),
+    ("shuiping", "水平"),
+    ("siji", "司机"),
+    ("taiyang", "太阳"),
+    ("tebie", "特别"),
+    ("teng", "疼"),
+    ("tigao", "提高"),
+    ("tiyu", "体育"),
+    ("tian", "甜"),
+    ("tiao", "条"),
+    ("tongshi", "同事"),
+    ("tongyi", "同意"),
+    ("toufa", "头发"),
+    ("turan", "突然"),
+    ("tushuguan", "图书馆"),
+    ("tui", "腿"),
+    ("wancheng", "完成"),
+    ("wan", "碗"),
+    ("wan", "万"),
+    ("wangji", "忘记"),
+    ("wei", "为"),
+    ("weile", "为了"),
+    ("wei", "位"),
+    ("wenhua", "文化"),
+    ("xi", "西"),
+    ("xiguan", "习惯"),
+    ("xishoujian", "洗手间"),
+    ("xizao", "洗澡"),
+    ("xia", "夏"),
+    ("xian", "先"),
+    ("xiangxin", "相信"),
+    ("xiangjiao", "香蕉"),
+    ("xiang", "向"),
+    ("xiang", "像"),
+    ("xiaoxin", "小心"),
+    ("xiaozhang", "校长"),
+    ("xinwen", "新闻"),
+    ("xinxian", "新鲜"),
+    ("xinyongka", "信用卡"),
+    ("xinglixiang", "行李箱"),
+    ("xiongmao", "熊猫"),
+    ("xuyao", "需要"),
+    ("xuanze", "选择"),
+    ("yaoqiu", "要求"),
+    ("yeye", "爷爷"),
+    ("yiding", "一定"),
+    ("yigong", "一共"),
+    ("yihuir", "一会儿"),
+    ("yihuier", "一会儿"),
+    ("yiyang", "一样"),
+    ("yiqian", "以前"),
+    ("yiban", "一般"),
+    ("yibian", "一边"),
+    ("yizhi", "一直"),
+    ("yinyue", "音乐"),
+    ("yinhang", "银行"),
+    ("yinliao", "饮料"),
+    ("yinggai", "应该"),
+    ("yingxiang", "影响"),
+    ("yong", "用"),
+    ("youxi", "游戏"),
+    ("youming", "有名"),
+    ("you", "又"),
+    ("yudao", "遇到"),
+    ("yuan", "元"),
+    ("yuanyi", "愿意"),
+    ("yueliang", "月亮"),
+    ("yue", "越"),
+    ("zhan", "站"),
+    ("zhang", "张"),
+    ("zhang", "长"),
+    ("zhaoji", "着急"),
+    ("zhaogu", "照顾"),
+    ("zhaopian", "照片"),
+    ("zhaoxiangji", "照相机"),
+    ("zhi", "只"),
+    ("zhiyou", "只有"),
+    ("cai", "才"),
+    ("zhongjian", "中间"),
+    ("zhongyu", "终于"),
+    ("zhong", "种"),
+    ("zhongyao", "重要"),
+    ("zhoumo", "周末"),
+    ("zhuyao", "主要"),
+    ("zhuyi", "注意"),
+    ("ziji", "自己"),
+    ("zixingche", "自行车"),
+    ("zongshi", "总是"),
+    ("zui", "嘴"),
+    ("zuihou", "最后"),
+    ("zuijin", "最近"),
+    ("zuoye", "作业"),
+    ("huanghe", "黄河"),
+    ("zhongwen", "中文"),
+    ("shan", "山"),
+    ("huang", "黄"),
+    ("he", "河"),
+    ("jing", "静"),
+    ("ke", "可"),
+    ("li", "丽"),
+    ("ming", "明"),
+    ("zhou", "周"),
+    ("kele", "可乐"),
+    ("shuizhao", "睡着"),
+    ("banshi", "办事"),
+    ("bian", "变"),
+    ("chunjie", "春节"),
+    ("da", "打"),
+    ("daoshihou", "到时候"),
+    ("fangzi", "房子"),
+    ("gewu", "歌舞"),
+    ("hongjiu", "红酒"),
+    ("jiu", "酒"),
+    ("ju", "句"),
+    ("meitian", "每天"),
+    ("nali", "哪里"),
+    ("nvhair", "女孩儿"),
+    ("nvhai", "女孩"),
+    ("qianbao", "钱包"),
+    ("renming", "人名"),
+    ("ren", "认"),
+    ("tingshuo", "听说"),
+    ("waidi", "外地"),
+    ("xianhua", "鲜花"),
+    ("xianggua", "香瓜"),
+    ("yibanlaishuo", "一般来说"),
+    ("yinyuehui", "音乐会"),
+    ("youdianr", "有点儿"),
+    ("youdian", "有点"),
+    ("zao", "早"),
+    ("zenmeban", "怎么办"),
+    ("zhongqiujie", "中秋节"),
+    ("aiqing", "爱情"),
+    ("anpai", "安排"),
+    ("anshi", "按时"),
+    ("anzhao", "按照"),
+    ("baifenzhi", "百分之"),
+    ("baozheng", "保证"),
+    ("bei", "倍"),
+    ("benlai", "本来"),
+    ("biru", "比如"),
+    ("biye", "毕业"),
+    ("biaozhun", "标准"),
+    ("binggan", "饼干"),
+    ("budebu", "不得不"),
+    ("buguan", "不管"),
+    ("buguo", "不过"),
+    ("bujin", "不仅"),
+    ("ca", "擦"),
+    ("cailiao", "材料"),
+    ("chabuduo", "差不多"),
+    ("chang", "尝"),
+    ("chaoguo", "超过"),
+    ("chenggong", "成功"),
+    ("chengwei", "成为"),
+    ("chengshi", "诚实"),
+    ("chongxin", "重新"),
+    ("chouyan", "抽烟"),
+    ("chuxian", "出现"),
+    ("chuanghu", "窗户"),
+    ("conglai", "从来"),
+    ("daan", "答案"),
+    ("darao", "打扰"),
+    ("dazhe", "打折"),
+    ("dashiguan", "大使馆"),
+    ("daifu", "大夫"),
+    ("dang", "当"),
+    ("dangshi", "当时"),
+    ("daochu", "到处"),
+    ("dei", "得"),
+    ("deng", "等"),
+    ("di", "低"),
+    ("diaocha", "调查"),
+    ("diao", "掉"),
+    ("dongzuo", "动作"),
+    ("duche", "堵车"),
+    ("duzi", "肚子"),
+    ("duanxin", "短信"),
+    ("er", "而"),
+    ("fasheng", "发生"),
+    ("fazhan", "发展"),
+    ("falv", "法律"),
+    ("fanyi", "翻译"),
+    ("fannao", "烦恼"),
+    ("fandui", "反对"),
+    ("fangfa", "方法"),
+    ("fangmian", "方面"),
+    ("fangxiang", "方向"),
+    ("fangqi", "放弃"),
+    ("fangsong", "放松"),
+    ("fen", "份"),
+    ("fengfu", "丰富"),
+    ("fuhe", "符合"),
+    ("fuze", "负责"),
+    ("fu", "富"),
+    ("gaibian", "改变"),
+    ("gandong", "感动"),
+    ("ganjue", "感觉"),
+    ("ganqing", "感情"),
+    ("ganxie", "感谢"),
+    ("gang", "刚"),
+    ("ge", "各"),
+    ("gongzi", "工资"),
+    ("gongtong", "共同"),
+    ("gouwu", "购物"),
+    ("gou", "够"),
+    ("guji", "估计"),
+    ("guke", "顾客"),
+    ("guanjian", "关键"),
+    ("guang", "光"),
+    ("guanggao", "广告"),
+    ("guang", "逛"),
+    ("guoji", "国际"),
+    ("guozhi", "果汁"),
+    ("guocheng", "过程"),
+    ("han", "汗"),
+    ("haochu", "好处"),
+    ("haoxiang", "好像"),
+    ("houhui", "后悔"),
+    ("huxiang", "互相"),
+    ("huiyi", "回忆"),
+    ("huodong", "活动"),
+    ("huode", "获得"),
+    ("jiji", "积极"),
+    ("jilei", "积累"),
+    ("jishi", "及时"),
+    ("jishi", "即使"),
+    ("jihua", "计划"),
+    ("ji", "寄"),
+    ("jiaban", "加班"),
+    ("jiaju", "家具"),
+    ("jiage", "价格"),
+    ("jianchi", "坚持"),
+    ("jianfei", "减肥"),
+    ("jianyi", "建议"),
+    ("jianglai", "将来"),
+    ("jiangjin", "奖金"),
+    ("jiangdi", "降低"),
+    ("jiao", "交"),
+    ("jiaoliu", "交流"),
+    ("jiaoshou", "教授"),
+    ("jieshou", "接受"),
+    ("jieguo", "结果"),
+    ("jinguan", "尽管"),
+    ("jinzhang", "紧张"),
+    ("jingji", "经济"),
+    ("jingli", "经历"),
+    ("jingyan", "经验"),
+    ("jingse", "景色"),
+    ("jingran", "竟然"),
+    ("jingzi", "镜子"),
+    ("juban", "举办"),
+    ("juxing", "举行"),
+    ("juli", "距离"),
+    ("juhui", "聚会"),
+    ("kaiwanxiao", "开玩笑"),
+    ("kaolv", "考虑"),
+    ("kexue", "科学"),
+    ("kesou", "咳嗽"),
+    ("keshi", "可是"),
+    ("kending", "肯定"),
+    ("kongqi", "空气"),
+    ("kongr", "空儿"),
+    ("kun", "困"),
+    ("kunnan", "困难"),
+    ("la", "拉"),
+    ("laibuji", "来不及"),
+    ("langfei", "浪费"),
+    ("langman", "浪漫"),
+    ("libaitian", "礼拜天"),
+    ("lijie", "理解"),
+    ("lixiang", "理想"),
+    ("liru", "例如"),
+    ("lia", "俩"),
+    ("lianxi", "联系"),
+    ("lingwai", "另外"),
+    ("liu", "留"),
+    ("liuxing", "流行"),
+    ("lvshi", "律师"),
+    ("luan", "乱"),
+    ("mafan", "麻烦"),
+    ("man", "满"),
+    ("mianfei", "免费"),
+    ("muqin", "母亲"),
+    ("naixin", "耐心"),
+    ("nandao", "难道"),
+    ("nei", "内"),
+    ("nengli", "能力"),
+    ("nianling", "年龄"),
+    ("panduan", "判断"),
+    ("pei", "陪"),
+    ("pifu", "皮肤"),
+    ("piqi", "脾气"),
+    ("pian", "篇"),
+    ("pingshi", "平时"),
+    ("putao", "葡萄"),
+    ("qici", "其次"),
+    ("qizhong", "其中"),
+    ("qihou", "气候"),
+    ("qiaokeli", "巧克力"),
+    ("qinqi", "亲戚"),
+    ("qing", "轻"),
+    ("qingsong", "轻松"),
+    ("qingkuang", "情况"),
+    ("qiong", "穷"),
+    ("qubie", "区别"),
+    ("qu", "取"),
+    ("quedian", "缺点"),
+    ("queshao", "缺少"),
+    ("que", "却"),
+    ("queshi", "确实"),
+    ("renhe", "任何"),
+    ("sanbu", "散步"),
+    ("shafa", "沙发"),
+    ("shangxin", "伤心"),
+    ("shen", "深"),
+    ("shenzhi", "甚至"),
+    ("shenghuo", "生活"),
+    ("shengming", "生命"),
+    ("shengyi", "生意"),
+    ("shibai", "失败"),
+    ("shifu", "师傅"),
+    ("shiji", "实际"),
+    ("shizai", "实在"),
+    ("shi", "使"),
+    ("shihe", "适合"),
+    ("shiying", "适应"),
+    ("shouru", "收入"),
+    ("shouxian", "首先"),
+    ("shoudao", "受到"),
+    ("shouhuoyuan", "售货员"),
+    ("shuxi", "熟悉"),
+    ("shuzi", "数字"),
+    ("shuai", "帅"),
+    ("shunbian", "顺便"),
+    ("shunli", "顺利"),
+    ("shuoming", "说明"),
+    ("shuoshi", "硕士"),
+    ("suibian", "随便"),
+    ("suoyou", "所有"),
+    ("tai", "台"),
+    ("taidu", "态度"),
+    ("tan", "谈"),
+    ("tang", "糖"),
+    ("tang", "躺"),
+    ("taoyan", "讨厌"),
+    ("tedian", "特点"),
+    ("ti", "提"),
+    ("tigong", "提供"),
+    ("tiqian", "提前"),
+    ("tixing", "提醒"),
+    ("tiaojian", "条件"),
+    ("ting", "挺"),
+    ("tongguo", "通过"),
+    ("tongzhi", "通知"),
+    ("wazi", "袜子"),
+    ("wanquan", "完全"),
+    ("wangqiu", "网球"),
+    ("wangwang", "往往"),
+    ("weidao", "味道"),
+    ("wuliao", "无聊"),
+    ("xihongshi", "西红柿"),
+    ("xiyin", "吸引"),
+    ("xianjin", "现金"),
+    ("xianmu", "羡慕"),
+    ("xiangpi", "橡皮"),
+    ("xiaoxi", "消息"),
+    ("xiaoshuo", "小说"),
+    ("xiaoguo", "效果"),
+    ("xinqing", "心情"),
+    ("xinku", "辛苦"),
+    ("xinxin", "信心"),
+    ("xingfen", "兴奋"),
+    ("xingfu", "幸福"),
+    ("xingge", "性格"),
+    ("xiuli", "修理"),
+    ("xuduo", "许多"),
+    ("yali", "压力"),
+    ("yanzhong", "严重"),
+    ("yanjiu", "研究"),
+    ("yangguang", "阳光"),
+    ("yangzi", "样子"),
+    ("yaoqing", "邀请"),
+    ("yaoshi", "要是"),
+    ("yiqie", "一切"),
+    ("yiwei", "以为"),
+    ("yishu", "艺术"),
+    ("yinci", "因此"),
+    ("yinxiang", "印象"),
+    ("ying", "赢"),
+    ("yingpin", "应聘"),
+    ("yongyuan", "永远"),
+    ("yonggan", "勇敢"),
+    ("youdian", "优点"),
+    ("youxiu", "优秀"),
+    ("youmo", "幽默"),
+    ("youqi", "尤其"),
+    ("youyu", "由于"),
+    ("youyi", "友谊"),
+    ("yukuai", "愉快"),
+    ("yu", "与"),
+    ("yuanlai", "原来"),
+    ("yuanyin", "原因"),
+    ("yuehui", "约会"),
+    ("zanmen", "咱们"),
+    ("zanshi", "暂时"),
+    ("zeren", "责任"),
+    ("zhaopin", "招聘"),
+    ("zhenzheng", "真正"),
+    ("zhenghao", "正好"),
+    ("zhengque", "正确"),
+    ("zhengshi", "正式"),
+    ("zhengming", "证明"),
+    ("zhichi", "支持"),
+    ("zhishi", "知识"),
+    ("zhide", "值得"),
+    ("zhiye", "职业"),
+    ("zhiwu", "植物"),
+    ("zhiyao", "只要"),
+    ("zhi", "指"),
+    ("zhishao", "至少"),
+    ("zhiliang", "质量"),
+    ("zhouwei", "周围"),
+    ("zhuyi", "主意"),
+    ("zhuanmen", "专门"),
+    ("zhuanye", "专业"),
+    ("zhuan", "赚"),
+    ("zhunshi", "准时"),
+    ("ziran", "自然"),
+    ("zongjie", "总结"),
+    ("zuihao", "最好"),
+    ("zuojia", "作家"),
+    ("anquan", "安全"),
+    ("bang", "棒"),
+    ("baozi", "包子"),
+    ("baohu", "保护"),
+    ("baoming", "报名"),
+    ("bao", "抱"),
+    ("baoqian", "抱歉"),
+    ("ben", "笨"),
+    ("bian", "遍"),
+    ("biaoge", "表格"),
+    ("biaoshi", "表示"),
+    ("biaoyan", "表演"),
+    ("biaoyang", "表扬"),
+    ("bingqie", "并且"),
+    ("boshi", "博士"),
+    ("bufen", "部分"),
+    ("cai", "猜"),
+    ("canguan", "参观"),
+    ("canting", "餐厅"),
+    ("cesuo", "厕所"),
+    ("chang", "场"),
+    ("chengzuo", "乘坐"),
+    ("chijing", "吃惊"),
+    ("chuchai", "出差"),
+    ("chufa", "出发"),
+    ("chusheng", "出生"),
+    ("chufang", "厨房"),
+    ("chuanzhen", "传真"),
+    ("ciyu", "词语"),
+    ("cuxin", "粗心"),
+    ("cun", "存"),
+    ("cuowu", "错误"),
+    ("daban", "打扮"),
+    ("dayin", "打印"),
+    ("dazhaohu", "打招呼"),
+    ("dazhen", "打针"),
+    ("dagai", "大概"),
+    ("dayue", "大约"),
+    ("dai", "戴"),
+    ("dao", "刀"),
+    ("daoyou", "导游"),
+    ("daodi", "到底"),
+    ("dao", "倒"),
+    ("daoqian", "道歉"),
+    ("deyi", "得意"),
+    ("dengjipai", "登机牌"),
+    ("di", "底"),
+    ("didian", "地点"),
+    ("diqiu", "地球"),
+    ("dizhi", "地址"),
+    ("diu", "丢"),
+    ("duihua", "对话"),
+    ("duimian", "对面"),
+    ("duiyu", "对于"),
+    ("ertong", "儿童"),
+    ("fangdong", "房东"),
+    ("fangshujia", "放暑假"),
+    ("fouze", "否则"),
+    ("fuqin", "父亲"),
+    ("fukuan", "付款"),
+    ("fuyin", "复印"),
+    ("fuza", "复杂"),
+    ("ganbei", "干杯"),
+    ("gan", "赶"),
+    ("gan", "敢"),
+    ("gan", "干"),
+    ("gaosugonglu", "高速公路"),
+    ("gebo", "胳膊"),
+    ("gongli", "公里"),
+    ("gongfu", "功夫"),
+    ("guli", "鼓励"),
+    ("guyi", "故意"),
+    ("gua", "挂"),
+    ("guanzhong", "观众"),
+    ("guanli", "管理"),
+    ("guangbo", "广播"),
+    ("guiding", "规定"),
+    ("guoji", "国籍"),
+    ("haiyang", "海洋"),
+    ("haixiu", "害羞"),
+    ("hanjia", "寒假"),
+    ("hangban", "航班"),
+    ("haoma", "号码"),
+    ("hege", "合格"),
+    ("heshi", "合适"),
+    ("hezi", "盒子"),
+    ("hou", "厚"),
+    ("hulianwang", "互联网"),
+    ("hushi", "护士"),
+    ("huaiyi", "怀疑"),
+    ("huopo", "活泼"),
+    ("huo", "火"),
+    ("jichu", "基础"),
+    ("jidong", "激动"),
+    ("jizhe", "记者"),
+    ("jishu", "技术"),
+    ("jiran", "既然"),
+    ("jixu", "继续"),
+    ("jiayouzhan", "加油站"),
+    ("jia", "假"),
+    ("jianshao", "减少"),
+    ("jiangluo", "降落"),
+    ("jiaotong", "交通"),
+    ("jiaoqu", "郊区"),
+    ("jiaoao", "骄傲"),
+    ("jiaozi", "饺子"),
+    ("jiaoyu", "教育"),
+    ("jiezhe", "接着"),
+    ("jie", "节"),
+    ("jieyue", "节约"),
+    ("jieshi", "解释"),
+    ("jinxing", "进行"),
+    ("jinzhi", "禁止"),
+    ("jingju", "京剧"),
+    ("jingcai", "精彩"),
+    ("jingcha", "警察"),
+    ("jingzheng", "竞争"),
+    ("jiujing", "究竟"),
+    ("ju", "举"),
+    ("jujue", "拒绝"),
+    ("kaixin", "开心"),
+    ("kanfa", "看法"),
+    ("kaoya", "烤鸭"),
+    ("ke", "棵"),
+    ("kelian", "可怜"),
+    ("kexi", "可惜"),
+    ("keting", "客厅"),
+    ("kong", "空"),
+    ("kongpa", "恐怕"),
+    ("ku", "苦"),
+    ("kuangquanshui", "矿泉水"),
+    ("lajitong", "垃圾桶"),
+    ("la", "辣"),
+    ("laideji", "来得及"),
+    ("laizi", "来自"),
+    ("lan", "懒"),
+    ("laohu", "老虎"),
+    ("lengjing", "冷静"),
+    ("limao", "礼貌"),
+    ("lifa", "理发"),
+    ("liqi", "力气"),
+    ("lihai", "厉害"),
+    ("lian", "连"),
+    ("liangkuai", "凉快"),
+    ("lingqian", "零钱"),
+    ("liuli", "流利"),
+    ("lvxing", "旅行"),
+    ("mahu", "马虎"),
+    ("mao", "毛"),
+    ("maojin", "毛巾"),
+    ("meili", "美丽"),
+    ("meng", "梦"),
+    ("milu", "迷路"),
+    ("mima", "密码"),
+    ("miao", "秒"),
+    ("minzu", "民族"),
+    ("mudi", "目的"),
+    ("nanshou", "难受"),
+    ("neirong", "内容"),
+    ("nong", "弄"),
+    ("nuanhuo", "暖和"),
+    ("ouer", "偶尔"),
+    ("paidui", "排队"),
+    ("pailie", "排列"),
+    ("piping", "批评"),
+    ("pian", "骗"),
+    ("pingpangqiu", "乒乓球"),
+    ("po", "破"),
+    ("pubian", "普遍"),
+    ("putonghua", "普通话"),
+    ("qianwan", "千万"),
+    ("qianzheng", "签证"),
+    ("qiao", "敲"),
+    ("qiao", "桥"),
+    ("quanbu", "全部"),
+    ("raner", "然而"),
+    ("renao", "热闹"),
+    ("renwu", "任务"),
+    ("reng", "扔"),
+    ("rengran", "仍然"),
+    ("riji", "日记"),
+    ("rukou", "入口"),
+    ("senlin", "森林"),
+    ("shangliang", "商量"),
+    ("shaowei", "稍微"),
+    ("shao", "勺"),
+    ("shaozi", "勺子"),
+    ("shehui", "社会"),
+    ("shenqing", "申请"),
+    ("sheng", "省"),
+    ("sheng", "省"),
+    ("sheng", "剩"),
+    ("shiwang", "失望"),
+    ("shifen", "十分"),
+    ("shiyong", "使用"),
+    ("shiji", "世纪"),
+    ("shifou", "是否"),
+    ("shou", "收"),
+    ("shoushi", "收拾"),
+    ("shoudu", "首都"),
+    ("shoubuliao", "受不了"),
+    ("shu", "输"),
+    ("shuliang", "数量"),
+    ("shunxu", "顺序"),
+    ("si", "死"),
+    ("sudu", "速度"),
+    ("suliaodai", "塑料袋"),
+    ("suan", "酸"),
+    ("suizhe", "随着"),
+    ("sunzi", "孙子"),
+    ("tai", "抬"),
+    ("tangangqin", "弹钢琴"),
+    ("tang", "汤"),
+    ("tang", "趟"),
+    ("taolun", "讨论"),
+    ("tiankong", "填空"),
+    ("ting", "停"),
+    ("tongqing", "同情"),
+    ("tongshi", "同时"),
+    ("tui", "推"),
+    ("tuichi", "推迟"),
+    ("tuo", "脱"),
+    ("wangzhan", "网站"),
+    ("weixian", "危险"),
+    ("weishengjian", "卫生间"),
+    ("wendu", "温度"),
+    ("wenzhang", "文章"),
+    ("wuran", "污染"),
+    ("wu", "无"),
+    ("wufa", "无法"),
+    ("wulun", "无论"),
+    ("wuhui", "误会"),
+    ("xian", "咸"),
+    ("xiangfan", "相反"),
+    ("xiangtong", "相同"),
+    ("xiang", "香"),
+    ("xiangxi", "详细"),
+    ("xiang", "响"),
+    ("xiaochi", "小吃"),
+    ("xiaohuozi", "小伙子"),
+    ("xiaohua", "笑话"),
+    ("xinfeng", "信封"),
+    ("xinxi", "信息"),
+    ("xing", "行"),
+    ("xing", "醒"),
+    ("xingbie", "性别"),
+    ("xueqi", "学期"),
+    ("yagao", "牙膏"),
+    ("ya", "呀"),
+    ("yange", "严格"),
+    ("yan", "盐"),
+    ("yanjing", "眼镜"),
+    ("yanchu", "演出"),
+    ("yanyuan", "演员"),
+    ("yangcheng", "养成"),
+    ("yaoshi", "钥匙"),
+    ("yexu", "也许"),
+    ("yezi", "叶子"),
+    ("ye", "页"),
+    ("yi", "以"),
+    ("yijian", "意见"),
+    ("yinqi", "引起"),
+    ("you", "由"),
+    ("youju", "邮局"),
+    ("youhao", "友好"),
+    ("youqu", "有趣"),
+    ("yushi", "于是"),
+    ("yumaoqiu", "羽毛球"),
+    ("yufa", "语法"),
+    ("yuyan", "语言"),
+    ("yuxi", "预习"),
+    ("yuanliang", "原谅"),
+    ("yuedu", "阅读"),
+    ("yun", "云"),
+    ("yunxu", "允许"),
+    ("zazhi", "杂志"),
+    ("zang", "脏"),
+    ("zengjia", "增加"),
+    ("zhanxian", "占线"),
+    ("zhao", "照"),
+    ("zhengli", "整理"),
+    ("zhengchang", "正常"),
+    ("zhi", "之"),
+    ("zhijie", "直接"),
+    ("zhihao", "只好"),
+    ("zhidai", "纸袋"),
+    ("zhong", "重"),
+    ("zhongdian", "重点"),
+    ("zhongshi", "重视"),
+    ("zhuhe", "祝贺"),
+    ("zhuming", "著名"),
+    ("zhuan", "转"),
+    ("zhunque", "准确"),
+    ("zixi", "仔细"),
+    ("zixin", "自信"),
+    ("zu", "租"),
+    ("zunzhong", "尊重"),
+    ("zuoyou", "左右"),
+    ("zuoyong", "作用"),
+    ("zuozhe", "作者"),
+    ("zuo", "座"),
+    ("zuowei", "座位"),
+    ("changcheng", "长城"),
+    ("changjiang", "长江"),
+    ("yazhou", "亚洲"),
+    ("aidisheng", "爱迪生"),
+    ("lin", "林"),
+    ("make", "马克"),
+    ("shanghai", "上海"),
+    ("sunyue", "孙月"),
+    ("xiaoyu", "小雨"),
+    ("bing", "并"),
+    ("huiyuanka", "会员卡"),
+    ("ji", "即"),
+    ("liuxie", "流血"),
+    ("xingxing", "星星"),
+    ("zhileng", "制冷"),
+    ("an", "按"),
+    ("bao", "保"),
+    ("bishi", "笔试"),
+    ("butong", "不同"),
+    ("can", "餐"),
+    ("changqi", "长期"),
+    ("cheng", "成"),
+    ("chu", "处"),
+    ("chuang", "窗"),
+    ("dedao", "得到"),
+    ("du", "堵"),
+    ("fumu", "父母"),
+    ("gandao", "感到"),
+    ("gou", "购"),
+    ("goumai", "购买"),
+    ("huo", "货"),
+    ("jia", "加"),
+    ("jiaqian", "价钱"),
+    ("jiang", "奖"),
+    ("jie", "街"),
+    ("mei", "美"),
+    ("mianshi", "面试"),
+    ("nvxing", "女性"),
+    ("putong", "普通"),
+    ("shangchang", "商场"),
+    ("shenbian", "身边"),
+    ("tiyuguan", "体育馆"),
+    ("xiaopengyou", "小朋友"),
+    ("xiaoyuan", "校园"),
+    ("xinxiangan", "新鲜感"),
+    ("youguan", "有关"),
+    ("zan", "咱"),
+    ("zhe", "折"),
+    ("zhi", "纸"),
+    ("zhu", "祝"),
+    ("zuoke", "做客"),
+    ("anna", "安娜"),
+    ("changjiangdaqiao", "长江大桥"),
+    ("guangdongsheng", "广东省"),
+    ("liuyiertongjie", "六一儿童节"),
+    ("shoudujichang", "首都机场"),
+    ("xiangshan", "香山"),
+    ("guai", "怪"),
+    ("meirenyu", "美人鱼"),
+    ("shibangongbei", "事半功倍"),
+    ("wudao", "舞蹈"),
+    ("biji", "笔记"),
+    ("biao", "表"),
+    ("bu", "部"),
+    ("chunyou", "春游"),
+    ("dai", "袋"),
+    ("daizi", "袋子"),
+    ("gai", "改"),
+    ("guanzhu", "关注"),
+    ("hai", "海"),
+    ("huanbao", "环保"),
+    ("jile", "极了"),
+    ("jiayou", "加油"),
+    ("jian", "减"),
+    ("jianqing", "减轻"),
+    ("keji", "科技"),
+    ("laji", "垃圾"),
+    ("lifadian", "理发店"),
+    ("lizi", "例子"),
+    ("liangcha", "凉茶"),
+    ("ling", "另"),
+    ("meihao", "美好"),
+    ("nuan", "暖"),
+    ("qite", "奇特"),
+    ("qiwen", "气温"),
+    ("quan", "全"),
+    ("tian", "填"),
+    ("tongyang", "同样"),
+    ("xiaoqu", "小区"),
+    ("xin", "信"),
+    ("xingqu", "兴趣"),
+    ("yashua", "牙刷"),
+    ("youxiao", "有效"),
+    ("youxian", "有限"),
+    ("yue", "约"),
+    ("zeng", "增"),
+    ("zengzhang", "增长"),
+    ("ai", "哎"),
+    ("ai", "唉"),
+    ("aihu", "爱护"),
+    ("anwei", "安慰"),
+    ("anzhuang", "安装"),
+    ("anan", "暗暗"),
+    ("aoye", "熬夜"),
+    ("bai", "摆"),
+    ("banye", "半夜"),
+    ("bangwan", "傍晚"),
+    ("baokuo", "包括"),
+    ("baobei", "宝贝"),
+    ("baogui", "宝贵"),
+    ("baocun", "保存"),
+    ("baodao", "报道"),
+    ("baoyuan", "抱怨"),
+    ("bei", "背"),
+    ("beijing", "背景"),
+    ("beizi", "被子"),
+    ("benling", "本领"),
+    ("bici", "彼此"),
+    ("biyao", "必要"),
+    ("bijing", "毕竟"),
+    ("bimian", "避免"),
+    ("bianpao", "鞭炮"),
+    ("bian", "便"),
+    ("bianlun", "辩论"),
+    ("biaoda", "表达"),
+    ("biaomian", "表面"),
+    ("biaoming", "表明"),
+    ("biaoqing", "表情"),
+    ("biaoxian", "表现"),
+    ("bofang", "播放"),
+    ("bozi", "脖子"),
+    ("bunaifan", "不耐烦"),
+    ("buyaojin", "不要紧"),
+    ("budeliao", "不得了"),
+    ("buxing", "不行"),
+    ("buzu", "不足"),
+    ("bu", "布"),
+    ("caichan", "财产"),
+    ("caiqu", "采取"),
+    ("caiyong", "采用"),
+    ("caihong", "彩虹"),
+    ("cai", "踩"),
+    ("canyu", "参与"),
+    ("cankui", "惭愧"),
+    ("duocang", "躲藏"),
+    ("caochang", "操场"),
+    ("cengjing", "曾经"),
+    ("chaju", "差距"),
+    ("chayi", "差异"),
+    ("cha", "插"),
+    ("chanpin", "产品"),
+    ("chansheng", "产生"),
+    ("changtu", "长途"),
+    ("chao", "吵"),
+    ("chaojia", "吵架"),
+    ("cheng", "称"),
+    ("cheng", "称"),
+    ("chengguo", "成果"),
+    ("chengjiu", "成就"),
+    ("chengshu", "成熟"),
+    ("chengyu", "成语"),
+    ("chengken", "诚恳"),
+    ("chengdu", "程度"),
+    ("chikui", "吃亏"),
+    ("chizao", "迟早"),
+    ("chixu", "持续"),
+    ("chong", "冲"),
+    ("chongfen", "充分"),
+    ("chongman", "充满"),
+    ("chongfu", "重复"),
+    ("chongwu", "宠物"),
+    ("chouxiang", "抽象"),
+    ("choulou", "丑陋"),
+    ("chuzi", "出自"),
+    ("chu", "除"),
+    ("chuxi", "除夕"),
+    ("chuanshuo", "传说"),
+    ("chuantong", "传统"),
+    ("chuanglian", "窗帘"),
+    ("chuangzao", "创造"),
+    ("cihui", "词汇"),
+    ("cizhi", "辞职"),
+    ("ciwai", "此外"),
+    ("conger", "从而"),
+    ("congqian", "从前"),
+    ("cui", "催"),
+    ("cunzai", "存在"),
+    ("cuoshi", "措施"),
+    ("dadao", "达到"),
+    ("dagong", "打工"),
+    ("dajiaodao", "打交道"),
+    ("dating", "打听"),
+    ("dafang", "大方"),
+    ("dai", "呆"),
+    ("daibiao", "代表"),
+    ("daiyu", "待遇"),
+    ("danxiaogui", "胆小鬼"),
+    ("dangdi", "当地"),
+    ("dang", "挡"),
+    ("daoyan", "导演"),
+    ("daozhi", "导致"),
+    ("dao", "倒"),
+    ("daoli", "道理"),
+    ("dengdai", "等待"),
+    ("dengyu", "等于"),
+    ("diluo", "低落"),
+    ("diren", "敌人"),
+    ("didao", "地道"),
+    ("diqu", "地区"),
+    ("diwei", "地位"),
+    ("di", "递"),
+    ("dianxin", "点心"),
+    ("diantai", "电台"),
+    ("diao", "钓"),
+    ("ding", "顶"),
+    ("dong", "冻"),
+    ("dong", "洞"),
+    ("dou", "逗"),
+    ("duli", "独立"),
+    ("dute", "独特"),
+    ("duguo", "度过"),
+    ("duan", "断"),
+    ("duanduanxuxu", "断断续续"),
+    ("duibi", "对比"),
+    ("duifang", "对方"),
+    ("dun", "蹲"),
+    ("dun", "顿"),
+    ("duokui", "多亏"),
+    ("fachou", "发愁"),
+    ("fada", "发达"),
+    ("faner", "反而"),
+    ("fanying", "反应"),
+    ("fanying", "反映"),
+    ("fang", "方"),
+    ("fangan", "方案"),
+    ("fangshi", "方式"),
+    ("fangfu", "仿佛"),
+    ("fei", "非"),
+    ("fenbie", "分别"),
+    ("fenbu", "分布"),
+    ("fenxi", "分析"),
+    ("fenfen", "纷纷"),
+    ("fengsu", "风俗"),
+    ("feng", "疯"),
+    ("fengci", "讽刺"),
+    ("fu", "扶"),
+    ("fuzhuang", "服装"),
+    ("fu", "幅"),
+    ("gaijin", "改进"),
+    ("ganjin", "赶紧"),
+    ("ganji", "感激"),
+    ("ganshou", "感受"),
+    ("ganhuor", "干活儿"),
+    ("ganhuoer", "干活儿"),
+    ("gaodang", "高档"),
+    ("gaoji", "高级"),
+    ("gaobie", "告别"),
+    ("gewai", "格外"),
+    ("gebie", "个别"),
+    ("geren", "个人"),
+    ("gezi", "各自"),
+    ("gen", "根"),
+    ("gongchengshi", "工程师"),
+    ("gongju", "工具"),
+    ("gongren", "工人"),
+    ("gongyuan", "公元"),
+    ("gongneng", "功能"),
+    ("gugu", "姑姑"),
+    ("guniang", "姑娘"),
+    ("gudai", "古代"),
+    ("gudian", "古典"),
+    ("gutou", "骨头"),
+    ("guding", "固定"),
+    ("guanbi", "关闭"),
+    ("guan", "官"),
+    ("guanglin", "光临"),
+    ("guangxian", "光线"),
+    ("guangda", "广大"),
+    ("guangfan", "广泛"),
+    ("guilv", "规律"),
+    ("guize", "规则"),
+    ("guo", "锅"),
+    ("guoran", "果然"),
+    ("guoshi", "果实"),
+    ("ha", "哈"),
+    ("haixian", "海鲜"),
+    ("han", "喊"),
+    ("haohua", "豪华"),
+    ("haoke", "好客"),
+    ("haoqi", "好奇"),
+    ("hezuo", "合作"),
+    ("hebi", "何必"),
+    ("hekuang", "何况"),
+    ("hen", "很"),
+    ("houzi", "猴子"),
+    ("houbei", "后背"),
+    ("hupenghuanyou", "呼朋唤友"),
+    ("huxi", "呼吸"),
+    ("huran", "忽然"),
+    ("hushi", "忽视"),
+    ("hushuo", "胡说"),
+    ("hutong", "胡同"),
+    ("hu", "壶"),
+    ("hutu", "糊涂"),
+    ("huayi", "华裔"),
+    ("hua", "滑"),
+    ("huati", "话题"),
+    ("huang", "慌"),
+    ("huangzhang", "慌张"),
+    ("huichen", "灰尘"),
+    ("hui", "挥"),
+    ("hunli", "婚礼"),
+    ("hunyin", "婚姻"),
+    ("huoyue", "活跃"),
+    ("huoban", "伙伴"),
+    ("huoxu", "或许"),
+    ("ji", "击"),
+    ("jiqi", "机器"),
+    ("jilie", "激烈"),
+    ("jiqi", "极其"),
+    ("ji", "即"),
+    ("jizhong", "集中"),
+    ("jisuan", "计算"),
+    ("jiyi", "记忆"),
+    ("jinian", "纪念"),
+    ("ji", "系"),
+    ("jiating", "家庭"),
+    ("jia", "甲"),
+    ("jiashi", "驾驶"),
+    ("jianjue", "坚决"),
+    ("jianbang", "肩膀"),
+    ("jianku", "艰苦"),
+    ("jianzhu", "建筑"),
+    ("jiangjiu", "讲究"),
+    ("jiaoji", "交际"),
+    ("jiaodu", "角度"),
+    ("jieshi", "结实"),
+    ("jiedai", "接待"),
+    ("jiesheng", "节省"),
+    ("jieshi", "节食"),
+    ("jiegou", "结构"),
+    ("jielun", "结论"),
+    ("jie", "戒"),
+    ("jiekou", "借口"),
+    ("jinshu", "金属"),
+    ("jin", "尽"),
+    ("jinli", "尽力"),
+    ("jindai", "近代"),
+    ("jingying", "经营"),
+    ("jingshen", "精神"),
+    ("jingshen", "精神"),
+    ("jiu", "救"),
+    ("jiujiu", "舅舅"),
+    ("juran", "居然"),
+    ("jubei", "具备"),
+    ("juexin", "决心"),
+    ("juedui", "绝对"),
+    ("junshi", "军事"),
+    ("kaifa", "开发"),
+    ("kaimushi", "开幕式"),
+    ("kao", "靠"),
+    ("ke", "颗"),
+    ("kejian", "可见"),
+    ("kekao", "可靠"),
+    ("kongjian", "空间"),
+    ("la", "啦"),
+    ("lan", "拦"),
+    ("lan", "烂"),
+    ("laodong", "劳动"),
+    ("laobaixing", "老百姓"),
+    ("laopo", "老婆"),
+    ("laoshi", "老实"),
+    ("laolao", "姥姥"),
+    ("lei", "雷"),
+    ("leixing", "类型"),
+    ("lihun", "离婚"),
+    ("lilun", "理论"),
+    ("liji", "立即"),
+    ("like", "立刻"),
+    ("lianmang", "连忙"),
+    ("lianxu", "连续"),
+    ("lianhe", "联合"),
+    ("lianghao", "良好"),
+    ("liangshi", "粮食"),
+    ("liang", "亮"),
+    ("lin", "临"),
+    ("linshi", "临时"),
+    ("linghuo", "灵活"),
+    ("ling", "铃"),
+    ("ling", "令"),
+    ("liuchuan", "流传"),
+    ("liulei", "流泪"),
+    ("long", "龙"),
+    ("lou", "漏"),
+    ("ludi", "陆地"),
+    ("lun", "轮"),
+    ("lunliu", "轮流"),
+    ("ma", "骂"),
+    ("mantou", "馒头"),
+    ("manzu", "满足"),
+    ("maobing", "毛病"),
+    ("maodun", "矛盾"),
+    ("meishu", "美术"),
+    ("meili", "魅力"),
+    ("miaotiao", "苗条"),
+    ("miaoxie", "描写"),
+    ("mingming", "明明"),
+    ("mingxian", "明显"),
+    ("mingxing", "明星"),
+    ("mingling", "命令"),
+    ("mo", "摸"),
+    ("mofang", "模仿"),
+    ("muqian", "目前"),
+    ("napa", "哪怕"),
+    ("naodai", "脑袋"),
+    ("naozhong", "闹钟"),
+    ("ningke", "宁可"),
+    ("nongcun", "农村"),
+    ("nongmin", "农民"),
+    ("nong", "浓"),
+    ("nvshi", "女士"),
+    ("pai", "拍"),
+    ("pai", "派"),
+    ("pai", "派"),
+    ("panwang", "盼望"),
+    ("pen", "盆"),
+    ("penzi", "盆子"),
+    ("peng", "碰"),
+    ("pi", "匹"),
+    ("pianmian", "片面"),
+    ("piao", "飘"),
+    ("ping", "平"),
+    ("pingchang", "平常"),
+    ("pingjing", "平静"),
+    ("pingjun", "平均"),
+    ("pingjia", "评价"),
+    ("qiyu", "其余"),
+    ("qiji", "奇迹"),
+    ("qiye", "企业"),
+    ("qifa", "启发"),
+    ("qifen", "气氛"),
+    ("qian", "签"),
+    ("qian", "浅"),
+    ("qianglie", "强烈"),
+    ("qiang", "墙"),
+    ("qiang", "抢"),
+    ("qiaoqiao", "悄悄"),
+    ("qiao", "瞧"),
+    ("qinqie", "亲切"),
+    ("qinzi", "亲自"),
+    ("qinfen", "勤奋"),
+    ("qingshi", "轻视"),
+    ("qingjing", "情景"),
+    ("qingxu", "情绪"),
+    ("qingqiu", "请求"),
+    ("qiumi", "球迷"),
+    ("qushi", "趋势"),
+    ("qushi", "去世"),
+    ("quefa", "缺乏"),
+    ("queding", "确定"),
+    ("queren", "确认"),
+    ("qun", "群"),
+    ("rencai", "人才"),
+    ("renlei", "人类"),
+    ("renmin", "人民"),
+    ("rensheng", "人生"),
+    ("renyuan", "人员"),
+    ("richang", "日常"),
+    ("rizi", "日子"),
+    ("ruhe", "如何"),
+    ("rujin", "如今"),
+    ("ruo", "弱"),
+    ("sa", "洒"),
+    ("sha", "杀"),
+    ("shai", "晒"),
+    ("shandian", "闪电"),
+    ("shanzi", "扇子"),
+    ("shanliang", "善良"),
+    ("shanyu", "善于"),
+    ("shanghai", "伤害"),
+    ("shangdang", "上当"),
+    ("sheji", "设计"),
+    ("she", "射"),
+    ("sheji", "射击"),
+    ("sheyingshi", "摄影师"),
+    ("shen", "伸"),
+    ("shenfen", "身份"),
+    ("shenke", "深刻"),
+    ("shenmi", "神秘"),
+    ("sheng", "升"),
+    ("shengzi", "绳子"),
+    ("shengli", "胜利"),
+    ("shimian", "失眠"),
+    ("shi", "诗"),
+    ("shitou", "石头"),
+    ("shidai", "时代"),
+    ("shike", "时刻"),
+    ("shiqi", "时期"),
+    ("shishang", "时尚"),
+    ("shixian", "实现"),
+    ("shiyan", "实验"),
+    ("shiwu", "食物"),
+    ("shizhong", "始终"),
+    ("shibing", "士兵"),
+    ("shichang", "市场"),
+    ("shide", "似的"),
+    ("shishi", "事实"),
+    ("shiwu", "事物"),
+    ("shouzhi", "手指"),
+    ("shousui", "守岁"),
+    ("shou", "首"),
+    ("shushi", "舒适"),
+    ("shucai", "蔬菜"),
+    ("shu", "数"),
+    ("shuai", "甩"),
+    ("shuobuding", "说不定"),
+    ("shuofu", "说服"),
+    ("sihu", "似乎"),
+    ("suan", "算"),
+    ("suishi", "随时"),
+    ("suishou", "随手"),
+    ("sui", "碎"),
+    ("suo", "所"),
+    ("suowei", "所谓"),
+    ("suo", "锁"),
+    ("taijie", "台阶"),
+    ("taitai", "太太"),
+    ("tao", "逃"),
+    ("tao", "桃"),
+    ("taoqi", "淘气"),
+    ("tao", "套"),
+    ("tihui", "体会"),
+    ("daiti", "代替"),
+    ("ti", "替"),
+    ("tianzhen", "天真"),
+    ("tiaopi", "调皮"),
+    ("tiaozheng", "调整"),
+    ("tiaozhan", "挑战"),
+    ("tongchang", "通常"),
+    ("toulan", "投篮"),
+    ("touru", "投入"),
+    ("tuchu", "突出"),
+    ("tuiguang", "推广"),
+    ("tuijian", "推荐"),
+    ("waigong", "外公"),
+    ("wanzheng", "完整"),
+    ("weihai", "危害"),
+    ("weixiao", "微笑"),
+    ("wei", "为"),
+    ("wei", "为"),
+    ("weirao", "围绕"),
+    ("weiba", "尾巴"),
+    ("weiqu", "委屈"),
+    ("weilai", "未来"),
+    ("weiyu", "位于"),
+    ("weizhi", "位置"),
+    ("wei", "胃"),
+    ("weikou", "胃口"),
+    ("wennuan", "温暖"),
+    ("wenxuejia", "文学家"),
+    ("wenzi", "文字"),
+    ("wending", "稳定"),
+    ("woshi", "卧室"),
+    ("wuzi", "屋子"),
+    ("wu", "屋"),
+    ("wunai", "无奈"),
+    ("wu", "勿"),
+    ("wuzhi", "物质"),
+    ("xiju", "戏剧"),
+    ("xijie", "细节"),
+    ("xia", "瞎"),
+    ("xia", "吓"),
+    ("xiande", "显得"),
+    ("xianshi", "显示"),
+    ("xian", "县"),
+    ("xiandai", "现代"),
+    ("xianshi", "现实"),
+    ("xianxiang", "现象"),
+    ("xianzhi", "限制"),
+    ("xiangchu", "相处"),
+    ("xiangdang", "相当"),
+    ("xiangdui", "相对"),
+    ("xiangguan", "相关"),
+    ("xiangshou", "享受"),
+    ("xiangxiang", "想象"),
+    ("xiang", "项"),
+    ("xiangmu", "项目"),
+    ("daxiang", "大象"),
+    ("xiang", "象"),
+    ("xiaofei", "消费"),
+    ("xiaoshou", "销售"),
+    ("xiaoshun", "孝顺"),
+    ("xie", "斜"),
+    ("xiezuo", "写作"),
+    ("xinli", "心理"),
+    ("xinshang", "欣赏"),
+    ("xingwei", "行为"),
+    ("xingcheng", "形成"),
+    ("xingrong", "形容"),
+    ("xingshi", "形式"),
+    ("xingshi", "形势"),
+    ("xingxiang", "形象"),
+    ("xingzhuang", "形状"),
+    ("xiongdi", "兄弟"),
+    ("xushu", "叙述"),
+    ("xuanchuan", "宣传"),
+    ("xuewen", "学问"),
+    ("xunzhao", "寻找"),
+    ("xunwen", "询问"),
+    ("xunlian", "训练"),
+    ("xunsu", "迅速"),
+    ("yachi", "牙齿"),
+    ("yangshi", "样式"),
+    ("yao", "摇"),
+    ("yao", "咬"),
+    ("yaobu", "要不"),
+    ("yewu", "业务"),
+    ("yeyu", "业余"),
+    ("ye", "夜"),
+    ("yiran", "依然"),
+    ("yibeizi", "一辈子"),
+    ("yizai", "一再"),
+    ("yidong", "移动"),
+    ("yimin", "移民"),
+    ("yi", "乙"),
+    ("yi", "以"),
+    ("yiji", "以及"),
+    ("yilai", "以来"),
+    ("yi", "亿"),
+    ("yiwai", "意外"),
+    ("yiyi", "意义"),
+    ("yiner", "因而"),
+    ("yinzi", "银子"),
+    ("yin", "银"),
+    ("yingjun", "英俊"),
+    ("yingxiong", "英雄"),
+    ("yingyang", "营养"),
+    ("yingzi", "影子"),
+    ("yingyong", "应用"),
+    ("ying", "硬"),
+    ("yongbao", "拥抱"),
+    ("yongtu", "用途"),
+    ("youmei", "优美"),
+    ("youjiu", "悠久"),
+    ("youeryuan", "幼儿园"),
+    ("yu", "于"),
+    ("yuanwang", "愿望"),
+    ("yun", "晕"),
+    ("yunyong", "运用"),
+    ("zaihai", "灾害"),
+    ("zaisan", "再三"),
+    ("zanmei", "赞美"),
+    ("zanmai", "赞美"),
+    ("zaogao", "糟糕"),
+    ("zaocheng", "造成"),
+    ("ze", "则"),
+    ("ze", "则"),
+    ("zhan", "占"),
+    ("zhanzheng", "战争"),
+    ("zhangbei", "长辈"),
+    ("zhaodai", "招待"),
+    ("zhaohuo", "着火"),
+    ("zhaokai", "召开"),
+    ("zhendui", "针对"),
+    ("zhen", "阵"),
+    ("zhenglun", "争论"),
+    ("zhengqu", "争取"),
+    ("zhengge", "整个"),
+    ("zhengti", "整体"),
+    ("zhengju", "证据"),
+    ("zheng", "挣"),
+    ("zhi", "支"),
+    ("zhijin", "至今"),
+    ("zhiyuanzhe", "志愿者"),
+    ("zhizao", "制造"),
+    ("zhiliao", "治疗"),
+    ("zhi", "治"),
+    ("zhihui", "智慧"),
+    ("zhongxin", "中心"),
+    ("zhongda", "重大"),
+    ("zhu", "猪"),
+    ("zhuzi", "竹子"),
+    ("zhujian", "逐渐"),
+    ("zhuchi", "主持"),
+    ("zhudong", "主动"),
+    ("zhuren", "主人"),
+    ("zhuce", "注册"),
+    ("zhuanjia", "专家"),
+    ("zhuang", "装"),
+    ("zhuangxiu", "装修"),
+    ("zhuangtai", "状态"),
+    ("zhuang", "撞"),
+    ("zhui", "追"),
+    ("zishi", "姿势"),
+    ("zige", "资格"),
+    ("ziliao", "资料"),
+    ("zisha", "自杀"),
+    ("ziyou", "自由"),
+    ("zonghe", "综合"),
+    ("zongcai", "总裁"),
+    ("zonggong", "总共"),
+    ("zuzhi", "阻止"),
+    ("zu", "组"),
+    ("zucheng", "组成"),
+    ("zuhe", "组合"),
+    ("zui", "醉"),
+    ("zuopin", "作品"),
+    ("zuowei", "作为"),
+    ("zuozhan", "作战"),
+    ("aixi", "爱惜"),
+    ("aixin", "爱心"),
+    ("an", "岸"),
+    ("an", "暗"),
+    ("bawo", "把握"),
+    ("banli", "办理"),
+    ("baohan", "包含"),
+    ("bao", "薄"),
+    ("baoliu", "保留"),
+    ("baoxian", "保险"),
+    ("baogao", "报告"),
+    ("baoshe", "报社"),
+    ("beishang", "悲伤"),
+    ("bei", "悲"),
+    ("benke", "本科"),
+    ("benzhi", "本质"),
+    ("bili", "比例"),
+    ("biran", "必然"),
+    ("bianji", "编辑"),
+    ("biaozhi", "标志"),
+    ("bingjiling", "冰激凌"),
+    ("boli", "玻璃"),
+    ("buduan", "不断"),
+    ("bujiande", "不见得"),
+    ("buchong", "补充"),
+    ("buan", "不安"),
+    ("buran", "不然"),
+    ("buru", "不如"),
+    ("buzhou", "步骤"),
+    ("bumen", "部门"),
+    ("caifang", "采访"),
+    ("cankao", "参考"),
+    ("caoxin", "操心"),
+    ("ce", "册"),
+    ("ceyan", "测验"),
+    ("changshi", "常识"),
+    ("chao", "朝"),
+    ("chaoshi", "潮湿"),
+    ("chao", "炒"),
+    ("chedi", "彻底"),
+    ("chenmo", "沉默"),
+    ("chen", "趁"),
+    ("chenghu", "称呼"),
+    ("chengzan", "称赞"),
+    ("chengfen", "成分"),
+    ("chengli", "成立"),
+    ("chengren", "成人"),
+    ("chengzhang", "成长"),
+    ("chengdan", "承担"),
+    ("chengren", "承认"),
+    ("chengshou", "承受"),
+    ("chitang", "池塘"),
+    ("chibang", "翅膀"),
+    ("chuban", "出版"),
+    ("chuse", "出色"),
+    ("chushi", "出示"),
+    ("chuxi", "出席"),
+    ("chujizhongxue", "初级中学"),
+    ("chuzhong", "初中"),
+    ("chufei", "除非"),
+    ("chuli", "处理"),
+    ("chuang", "闯"),
+    ("chui", "吹"),
+    ("ciyao", "次要"),
+    ("ciji", "刺激"),
+    ("congci", "从此"),
+    ("congshi", "从事"),
+    ("cucao", "粗糙"),
+    ("cujin", "促进"),
+    ("cushi", "促使"),
+    ("cu", "醋"),
+    ("daying", "答应"),
+    ("dasha", "大厦"),
+    ("daxing", "大型"),
+    ("danren", "担任"),
+    ("danchun", "单纯"),
+    ("dandiao", "单调"),
+    ("dandu", "单独"),
+    ("danwei", "单位"),
+    ("danwu", "耽误"),
+    ("daoyu", "岛屿"),
+    ("daomei", "倒霉"),
+    ("daoda", "到达"),
+    ("daode", "道德"),
+    ("dengji", "登记"),
+    ("dique", "的确"),
+    ("dili", "地理"),
+    ("dizhen", "地震"),
+    ("dianchi", "电池"),
+    ("donghuapian", "动画片"),
+    ("dui", "堆"),
+    ("duidai", "对待"),
+    ("duishou", "对手"),
+    ("duixiang", "对象"),
+    ("dun", "吨"),
+    ("duo", "朵"),
+    ("elie", "恶劣"),
+    ("fadou", "发抖"),
+    ("fahui", "发挥"),
+    ("fayan", "发言"),
+    ("fan", "翻"),
+    ("fanrong", "繁荣"),
+    ("fanfu", "反复"),
+    ("fanzheng", "反正"),
+    ("fanwei", "范围"),
+    ("fangai", "妨碍"),
+    ("feizao", "肥皂"),
+    ("fei", "废"),
+    ("fendou", "奋斗"),
+    ("fengjing", "风景"),
+    ("fengxian", "风险"),
+    ("fouren", "否认"),
+    ("funv", "妇女"),
+    ("gaige", "改革"),
+    ("gaishan", "改善"),
+    ("gai", "盖"),
+    ("gaikuo", "概括"),
+    ("gainian", "概念"),
+    ("gancui", "干脆"),
+    ("ganzao", "干燥"),
+    ("gankuai", "赶快"),
+    ("ganxiang", "感想"),
+    ("gangtie", "钢铁"),
+    ("gao", "搞"),
+    ("gexing", "个性"),
+    ("genben", "根本"),
+    ("gongchang", "工厂"),
+    ("gongye", "工业"),
+    ("gongbu", "公布"),
+    ("gongkai", "公开"),
+    ("gongyu", "公寓"),
+    ("gongxian", "贡献"),
+    ("goutong", "沟通"),
+    ("goucheng", "构成"),
+    ("guwu", "鼓舞"),
+    ("guzhang", "鼓掌"),
+    ("guai", "乖"),
+    ("guancha", "观察"),
+    ("guandian", "观点"),
+    ("guannian", "观念"),
+    ("guanzi", "馆子"),
+    ("guanjun", "冠军"),
+    ("guanghua", "光滑"),
+    ("guangming", "光明"),
+    ("guangchang", "广场"),
+    ("guina", "归纳"),
+    ("guiju", "规矩"),
+    ("gun", "滚"),
+    ("guofen", "过分"),
+    ("hangye", "行业"),
+    ("heli", "合理"),
+    ("heping", "和平"),
+    ("hexin", "核心"),
+    ("houguo", "后果"),
+    ("hudie", "蝴蝶"),
+    ("huainian", "怀念"),
+    ("huanjie", "缓解"),
+    ("huanxiang", "幻想"),
+    ("hui", "灰"),
+    ("huixin", "灰心"),
+    ("huifu", "恢复"),
+    ("huochai", "火柴"),
+    ("jirou", "肌肉"),
+    ("jiben", "基本"),
+    ("jige", "及格"),
+    ("ji", "挤"),
+    ("jilu", "记录"),
+    ("jilu", "纪录"),
+    ("jilv", "纪律"),
+    ("jimo", "寂寞"),
+    ("jiawu", "家务"),
+    ("jiaxiang", "家乡"),
+    ("jiaru", "假如"),
+    ("jiashe", "假设"),
+    ("jiazhi", "价值"),
+    ("jianju", "艰巨"),
+    ("jian", "捡"),
+    ("jiandao", "剪刀"),
+    ("jianli", "简历"),
+    ("jianzhi", "简直"),
+    ("jianli", "建立"),
+    ("jianshe", "建设"),
+    ("jianshen", "健身"),
+    ("jiangzuo", "讲座"),
+    ("jiangyou", "酱油"),
+    ("jiaohuan", "交换"),
+    ("jiaowang", "交往"),
+    ("jiao", "浇"),
+    ("jiaohua", "狡猾"),
+    ("jiaolian", "教练"),
+    ("jiaoxun", "教训"),
+    ("jieduan", "阶段"),
+    ("jiechu", "接触"),
+    ("jiejin", "接近"),
+    ("jiehe", "结合"),
+    ("jie", "届"),
+    ("jinkuai", "尽快"),
+    ("jinliang", "尽量"),
+    ("jinji", "紧急"),
+    ("jinshen", "谨慎"),
+    ("jinbu", "进步"),
+    ("jingdian", "经典"),
+    ("jingshang", "经商"),
+    ("jiuba", "酒吧"),
+    ("juzi", "橘子"),
+    ("juda", "巨大"),
+    ("juti", "具体"),
+    ("jushuo", "据说"),
+    ("juesai", "决赛"),
+    ("juese", "角色"),
+    ("junyun", "均匀"),
+    ("kaifang", "开放"),
+    ("kaishui", "开水"),
+    ("kan", "砍"),
+    ("kanbuqi", "看不起"),
+    ("kepa", "可怕"),
+    ("kefu", "克服"),
+    ("keku", "刻苦"),
+    ("keguan", "客观"),
+    ("kongxian", "空闲"),
+    ("kongzhi", "控制"),
+    ("kouwei", "口味"),
+    ("ku", "库"),
+    ("kua", "夸"),
+    ("kuazhang", "夸张"),
+    ("kuan", "宽"),
+    ("kuang", "狂"),
+    ("kunchong", "昆虫"),
+    ("kuoda", "扩大"),
+    ("kuo", "扩"),
+    ("langdu", "朗读"),
+    ("laojia", "劳驾"),
+    ("laoban", "老板"),
+    ("leguan", "乐观"),
+    ("lengdan", "冷淡"),
+    ("li", "梨"),
+    ("liyou", "理由"),
+    ("liliang", "力量"),
+    ("lirun", "利润"),
+    ("liyi", "利益"),
+    ("liyong", "利用"),
+    ("lianai", "恋爱"),
+    ("liaobuqi", "了不起"),
+    ("lingyu", "领域"),
+    ("luxu", "陆续"),
+    ("luqu", "录取"),
+    ("luoji", "逻辑"),
+    ("luohou", "落后"),
+    ("maoxian", "冒险"),
+    ("meiti", "媒体"),
+    ("meitan", "煤炭"),
+    ("mengxiang", "梦想"),
+    ("mimi", "秘密"),
+    ("miqie", "密切"),
+    ("miandui", "面对"),
+    ("mianji", "面积"),
+    ("mianlin", "面临"),
+    ("mingan", "敏感"),
+    ("mingpai", "名牌"),
+    ("mingque", "明确"),
+    ("mingyun", "命运"),
+    ("mohu", "模糊"),
+    ("mosheng", "陌生"),
+    ("mou", "某"),
+    ("mutou", "木头"),
+    ("mubiao", "目标"),
+    ("nanguai", "难怪"),
+    ("nanmian", "难免"),
+    ("neibu", "内部"),
+    ("nen", "嫩"),
+    ("nenggan", "能干"),
+    ("nengyuan", "能源"),
+    ("ng", "嗯"),
+    ("en", "嗯"),
+    ("niandai", "年代"),
+    ("nianji", "年纪"),
+    ("nian", "念"),
+    ("nongye", "农业"),
+    ("ouran", "偶然"),
+    ("peixun", "培训"),
+    ("peiyang", "培养"),
+    ("peichang", "赔偿"),
+    ("peifu", "佩服"),
+    ("pi", "批"),
+    ("peizhun", "批准"),
+    ("pilao", "疲劳"),
+    ("pian", "片"),
+    ("pingan", "平安"),
+    ("pingdeng", "平等"),
+    ("pingheng", "平衡"),
+    ("ping", "凭"),
+    ("poqie", "迫切"),
+    ("pohuai", "破坏"),
+    ("qidai", "期待"),
+    ("qijian", "期间"),
+    ("xiangqi", "象棋"),
+    ("qi", "棋"),
+    ("qi", "起"),
+    ("qiyou", "汽油"),
+    ("qianxu", "谦虚"),
+    ("qiantu", "前途"),
+    ("qiangdiao", "强调"),
+    ("qiaomiao", "巧妙"),
+    ("qie", "切"),
+    ("qinai", "亲爱"),
+    ("qing", "青"),
+    ("qingshaonian", "青少年"),
+    ("qingyi", "轻易"),
+    ("qingdan", "清淡"),
+    ("qingzhu", "庆祝"),
+    ("quxiao", "取消"),
+    ("quan", "圈"),
+    ("quanmian", "全面"),
+    ("quan", "劝"),
+    ("ranshao", "燃烧"),
+    ("reai", "热爱"),
+    ("relie", "热烈"),
+    ("rexin", "热心"),
+    ("renwu", "人物"),
+    ("renbuzhu", "忍不住"),
+    ("richeng", "日程"),
+    ("riyongpin", "日用品"),
+    ("secai", "色彩"),
+    ("shamo", "沙漠"),
+    ("shatan", "沙滩"),
+    ("sha", "傻"),
+    ("shangpin", "商品"),
+    ("shangwu", "商务"),
+    ("shangye", "商业"),
+    ("shebude", "舍不得"),
+    ("shebei", "设备"),
+    ("sheshi", "设施"),
+    ("shencai", "身材"),
+    ("shengchan", "生产"),
+    ("shengzhang", "生长"),
+    ("shiqu", "失去"),
+    ("shirun", "湿润"),
+    ("shihua", "实话"),
+    ("shijian", "实践"),
+    ("shiyong", "实用"),
+    ("shijinr", "使劲儿"),
+    ("shijin", "使劲"),
+    ("shixian", "事先"),
+    ("shouhuo", "收获"),
+    ("shouxu", "手续"),
+    ("shoushang", "受伤"),
+    ("shou", "受"),
+    ("shuzi", "梳子"),
+    ("shuru", "输入"),
+    ("shulian", "熟练"),
+    ("shuyu", "属于"),
+    ("shuju", "数据"),
+    ("shuaidao", "摔倒"),
+    ("sihao", "丝毫"),
+    ("siren", "私人"),
+    ("si", "私"),
+    ("sikao", "思考"),
+    ("sixiang", "思想"),
+    ("suishen", "随身"),
+    ("sunshi", "损失"),
+    ("suoduan", "缩短"),
+    ("tang", "烫"),
+    ("taobi", "逃避"),
+    ("tese", "特色"),
+    ("teshu", "特殊"),
+    ("tezheng", "特征"),
+    ("tengai", "疼爱"),
+    ("tichang", "提倡"),
+    ("tiwen", "提问"),
+    ("timu", "题目"),
+    ("titie", "体贴"),
+    ("tixian", "体现"),
+    ("tiyan", "体验"),
+    ("tiankong", "天空"),
+    ("tongku", "痛苦"),
+    ("tongkuai", "痛快"),
+    ("touzi", "投资"),
+    ("touming", "透明"),
+    ("tuixiu", "退休"),
+    ("waijiao", "外交"),
+    ("wanmei", "完美"),
+    ("wanshan", "完善"),
+    ("wanyi", "万一"),
+    ("wangzi", "王子"),
+    ("wangluo", "网络"),
+    ("weixia", "威胁"),
+    ("weifan", "违反"),
+    ("weiyi", "唯一"),
+    ("weibi", "未必"),
+    ("wenrou", "温柔"),
+    ("wenju", "文具"),
+    ("wenming", "文明"),
+    ("wen", "闻"),
+    ("wen", "吻"),
+    ("wushu", "无数"),
+    ("wusuowei", "无所谓"),
+    ("wuli", "物理"),
+    ("xiqu", "吸取"),
+    ("xishou", "吸收"),
+    ("xi", "系"),
+    ("xitong", "系统"),
+    ("xiazai", "下载"),
+    ("xianyan", "鲜艳"),
+    ("xianran", "显然"),
+    ("xiangsi", "相似"),
+    ("xiangnian", "想念"),
+    ("xiaoji", "消极"),
+    ("xiaoshi", "消失"),
+    ("xiaolv", "效率"),
+    ("xie", "歇"),
+    ("xinzang", "心脏"),
+    ("xingdong", "行动"),
+    ("xingren", "行人"),
+    ("xingkui", "幸亏"),
+    ("xingyun", "幸运"),
+    ("xiong", "胸"),
+    ("xiuxian", "休闲"),
+    ("xuxin", "虚心"),
+    ("yajin", "押金"),
+    ("yanchang", "延长"),
+    ("yansu", "严肃"),
+    ("yang", "养"),
+    ("yao", "腰"),
+    ("yidan", "一旦"),
+    ("yilv", "一律"),
+    ("yizhi", "一致"),
+    ("yihan", "遗憾"),
+    ("yiwen", "疑问"),
+    ("yiwu", "义务"),
+    ("yinsu", "因素"),
+    ("yinshua", "印刷"),
+    ("yingjie", "迎接"),
+    ("yingye", "营业"),
+    ("yingfu", "应付"),
+    ("ying", "硬"),
+    ("yongji", "拥挤"),
+    ("yongqi", "勇气"),
+    ("yonggong", "用功"),
+    ("youshi", "优势"),
+    ("youyu", "犹豫"),
+    ("youzha", "油炸"),
+    ("youlan", "游览"),
+    ("youli", "有利"),
+    ("youle", "娱乐"),
+    ("youqi", "与其"),
+    ("yufang", "预防"),
+    ("yuandan", "元旦"),
+    ("yuangong", "员工"),
+    ("yuanliao", "原料"),
+    ("yuanze", "原则"),
+    ("yuan", "圆"),
+    ("yunqi", "运气"),
+    ("yunshu", "运输"),
+    ("zaihu", "在乎"),
+    ("zaiyu", "在于"),
+    ("zancheng", "赞成"),
+    ("zebei", "责备"),
+    ("zhai", "窄"),
+    ("zhankai", "展开"),
+    ("zhang", "涨"),
+    ("zhangwo", "掌握"),
+    ("zhaochang", "照常"),
+    ("zhenxi", "珍惜"),
+    ("zhenshi", "真实"),
+    ("zhengqiu", "征求"),
+    ("zheng", "睁"),
+    ("zhengqi", "整齐"),
+    ("zhengfu", "政府"),
+    ("zhi", "支"),
+    ("zhi", "直"),
+    ("zhidao", "指导"),
+    ("zhihui", "指挥"),
+    ("zhiyu", "至于"),
+    ("zhizuo", "制作"),
+    ("zhixu", "秩序"),
+    ("zhongxun", "中旬"),
+    ("zhonglei", "种类"),
+    ("zhongliang", "重量"),
+    ("zhubu", "逐步"),
+    ("zhuguan", "主观"),
+    ("zhuti", "主题"),
+    ("zhuxi", "主席"),
+    ("zhuzhang", "主张"),
+    ("zhu", "煮"),
+    ("zhua", "抓"),
+    ("zhuajin", "抓紧"),
+    ("zhuanbian", "转变"),
+    ("zhuangshi", "装饰"),
+    ("zhuangkuang", "状况"),
+    ("zhuiqiu", "追求"),
+    ("zixun", "咨询"),
+    ("zijin", "资金"),
+    ("ziyuan", "资源"),
+    ("zi", "紫"),
+    ("zicong", "自从"),
+    ("zidong", "自动"),
+    ("zihao", "自豪"),
+    ("zijue", "自觉"),
+    ("zisi", "自私"),
+    ("ziyuan", "自愿"),
+    ("zongsuan", "总算"),
+    ("zongzhi", "总之"),
+    ("zuzhi", "组织"),
+    ("zuichu", "最初"),
+    ("zunjing", "尊敬"),
+    ("zunshou", "遵守"),
+    ("zuowen", "作文"),
+    ("baoguo", "包裹"),
+    ("baochi", "保持"),
+    ("baodao", "报到"),
+    ("beiguan", "悲观"),
+    ("biaodian", "标点"),
+    ("bingdu", "病毒"),
+    ("bowuguan", "博物馆"),
+    ("chazi", "叉子"),
+    ("chai", "拆"),
+    ("chao", "抄"),
+    ("chaoji", "超级"),
+    ("cheku", "车库"),
+    ("chexiang", "车厢"),
+    ("chengxu", "程序"),
+    ("chizi", "尺子"),
+    ("chongdianqi", "充电器"),
+    ("chouti", "抽屉"),
+    ("chou", "丑"),
+    ("chou", "臭"),
+    ("chukou", "出口"),
+    ("chuji", "初级"),
+    ("chuanbo", "传播"),
+    ("chuanran", "传染"),
+    ("congmang", "匆忙"),
+    ("dapenti", "打喷嚏"),
+    ("daikuan", "贷款"),
+    ("danyuan", "单元"),
+    ("dan", "淡"),
+    ("dangxin", "当心"),
+    ("di", "滴"),
+    ("ditan", "地毯"),
+    ("doufu", "豆腐"),
+    ("duihuan", "兑换"),
+    ("duoyu", "多余"),
+    ("erhuan", "耳环"),
+    ("fabiao", "发表"),
+    ("faming", "发明"),
+    ("fapiao", "发票"),
+    ("fakuan", "罚款"),
+    ("fayuan", "法院"),
+    ("feihua", "废话"),
+    ("fenpei", "分配"),
+    ("fenshou", "分手"),
+    ("fengge", "风格"),
+    ("fengkuang", "疯狂"),
+    ("fouding", "否定"),
+    ("fudao", "辅导"),
+    ("fuzhi", "复制"),
+    ("gaizheng", "改正"),
+    ("gebi", "隔壁"),
+    ("gongping", "公平"),
+    ("gongzhu", "公主"),
+    ("gongxi", "恭喜"),
+    ("gupiao", "股票"),
+    ("guahao", "挂号"),
+    ("guaiwan", "拐弯"),
+    ("guaibude", "怪不得"),
+    ("guanzi", "管子"),
+    ("guangpan", "光盘"),
+    ("guimo", "规模"),
+    ("guitai", "柜台"),
+    ("guoqingjie", "国庆节"),
+    ("guowang", "国王"),
+    ("guomin", "过敏"),
+    ("guoqi", "过期"),
+    ("haiguan", "海关"),
+    ("hefa", "合法"),
+    ("hetong", "合同"),
+    ("heying", "合影"),
+    ("hen", "恨"),
+    ("huasheng", "花生"),
+    ("hua", "划"),
+    ("huaxue", "化学"),
+    ("huaiyun", "怀孕"),
+    ("huangjin", "黄金"),
+    ("huilv", "汇率"),
+    ("jimang", "急忙"),
+    ("jizhen", "急诊"),
+    ("jihe", "集合"),
+    ("jiti", "集体"),
+    ("jilingdai", "系领带"),
+    ("jiazi", "夹子"),
+    ("jiabin", "嘉宾"),
+    ("jiazhuang", "假装"),
+    ("jia", "嫁"),
+    ("jianqiang", "坚强"),
+    ("jianzhi", "兼职"),
+    ("jianpan", "键盘"),
+    ("jiaoshui", "胶水"),
+    ("jiaocai", "教材"),
+    ("jiezhang", "结账"),
+    ("jiezhi", "戒指"),
+    ("jinkou", "进口"),
+    ("jingli", "精力"),
+    ("jiuhuche", "救护车"),
+    ("juzi", "桔子"),
+    ("julebu", "俱乐部"),
+    ("juan", "捐"),
+    ("kache", "卡车"),
+    ("kanwang", "看望"),
+    ("ke", "克"),
+    ("kecheng", "课程"),
+    ("kuaiji", "会计"),
+    ("lajiao", "辣椒"),
+    ("laoshu", "老鼠"),
+    ("limi", "厘米"),
+    ("lixi", "利息"),
+    ("lieche", "列车"),
+    ("lingjian", "零件"),
+    ("lingshi", "零食"),
+    ("lingdao", "领导"),
+    ("liulan", "浏览"),
+    ("luyin", "录音"),
+    ("lunwen", "论文"),
+    ("maikefeng", "麦克风"),
+    ("maoyi", "贸易"),
+    ("meimao", "眉毛"),
+    ("mishu", "秘书"),
+    ("mifeng", "蜜蜂"),
+    ("mingpian", "名片"),
+    ("mingshengguji", "名胜古迹"),
+    ("moter", "模特"),
+    ("motuoche", "摩托车"),
+    ("mulu", "目录"),
+    ("neike", "内科"),
+    ("niuzaiku", "牛仔裤"),
+    ("ouzhou", "欧洲"),
+    ("peihe", "配合"),
+    ("pi", "披"),
+    ("pinyin", "拼音"),
+    ("pindao", "频道"),
+    ("pingfang", "平方"),
+    ("pochan", "破产"),
+    ("qian", "欠"),
+    ("qiang", "枪"),
+    ("qingchun", "青春"),
+    ("qu", "娶"),
+    ("quanli", "权力"),
+    ("quanli", "权利"),
+    ("rao", "绕"),
+    ("renkou", "人口"),
+    ("renminbi", "人民币"),
+    ("renshi", "人事"),
+    ("rili", "日历"),
+    ("riqi", "日期"),
+    ("ruan", "软"),
+    ("ruanjian", "软件"),
+    ("sangzi", "嗓子"),
+    ("shanchu", "删除"),
+    ("she", "蛇"),
+    ("sheying", "摄影"),
+    ("shenhua", "神话"),
+    ("shengdong", "生动"),
+    ("shengdiao", "声调"),
+    ("shenglve", "省略"),
+    ("shiye", "失业"),
+    ("shizi", "狮子"),
+    ("shicha", "时差"),
+    ("shimao", "时髦"),
+    ("shixi", "实习"),
+    ("shijuan", "试卷"),
+    ("shouju", "收据"),
+    ("shougong", "手工"),
+    ("shoushu", "手术"),
+    ("shoutao", "手套"),
+    ("shouming", "寿命"),
+    ("shujia", "书架"),
+    ("shubiao", "鼠标"),
+    ("shuma", "数码"),
+    ("shuangfang", "双方"),
+    ("shui", "税"),
+    ("sichou", "丝绸"),
+    ("si", "撕"),
+    ("sousuo", "搜索"),
+    ("sushe", "宿舍"),
+    ("taijiquan", "太极拳"),
+    ("tanpan", "谈判"),
+    ("tanshuai", "坦率"),
+    ("taojiahuanjia", "讨价还价"),
+    ("tigang", "提纲"),
+    ("tongyi", "统一"),
+    ("tou", "偷"),
+    ("tudi", "土地"),
+    ("tudou", "土豆"),
+    ("tu", "吐"),
+    ("tuzi", "兔子"),
+    ("tuan", "团"),
+    ("tuici", "推辞"),
+    ("tui", "退"),
+    ("tuibu", "退步"),
+    ("wai", "歪"),
+    ("wanju", "玩具"),
+    ("wangfan", "往返"),
+    ("weijin", "围巾"),
+    ("weixiu", "维修"),
+    ("weida", "伟大"),
+    ("wenjian", "文件"),
+    ("wenxue", "文学"),
+    ("wenhou", "问候"),
+    ("woshou", "握手"),
+    ("wushu", "武术"),
+    ("wu", "雾"),
+    ("xialingying", "夏令营"),
+    ("xiangchang", "香肠"),
+    ("xianglian", "项链"),
+    ("xiangzheng", "象征"),
+    ("xiaohua", "消化"),
+    ("xiaomai", "小麦"),
+    ("xiaoqi", "小气"),
+    ("xie", "血"),
+    ("xinhao", "信号"),
+    ("xinren", "信任"),
+    ("xingzhi", "性质"),
+    ("xiugai", "修改"),
+    ("xuanbu", "宣布"),
+    ("xueli", "学历"),
+    ("xueshu", "学术"),
+    ("yanjiang", "演讲"),
+    ("yanhui", "宴会"),
+    ("yangtai", "阳台"),
+    ("yang", "痒"),
+    ("yilun", "议论"),
+    ("yingjian", "硬件"),
+    ("youhui", "优惠"),
+    ("yuqi", "语气"),
+    ("yumi", "玉米"),
+    ("yubao", "预报"),
+    ("yuding", "预订"),
+    ("yueqi", "乐器"),
+    ("zhai", "摘"),
+    ("zhantie", "粘贴"),
+    ("zhanlan", "展览"),
+    ("zhanghu", "账户"),
+    ("zhaoliang", "着凉"),
+    ("zhexue", "哲学"),
+    ("zhenduan", "诊断"),
+    ("zhendong", "振动"),
+    ("zheng", "正"),
+    ("zhengjian", "证件"),
+    ("zhengzhi", "政治"),
+    ("zhipiao", "支票"),
+    ("zhizhao", "执照"),
+    ("zhiding", "制定"),
+    ("zhidu", "制度"),
+    ("zhongjie", "中介"),
+    ("zhoudao", "周到"),
+    ("zhuren", "主任"),
+    ("zhufu", "祝福"),
+    ("zhuanxin", "专心"),
+    ("zhuangao", "转告"),
+    ("zimu", "字母"),
+    ("zimu", "字幕"),
+    ("zongli", "总理"),
+    ("zongtong", "总统"),
+    ("aodaliya", "澳大利亚"),
+    ("baotuquan", "趵突泉"),
+    ("baoquan", "鲍全"),
+    ("caishikou", "菜市口"),
+    ("chuguo", "楚国"),
+    ("chunqiu", "春秋"),
+    ("dannierkaneiman", "丹尼尔·卡内曼"),
+    ("daoxiangcun", "稻香村"),
+    ("donghailongwang", "东海龙王"),
+    ("fengzhongdinglv", "峰终定律"),
+    ("guangheju", "广和居"),
+    ("huabei", "华北"),
+    ("jinan", "济南"),
+    ("jialifuniyazhou", "加利福尼亚州"),
+    ("keen", "科恩"),
+    ("kongzi", "孔子"),
+    ("liguang", "李广"),
+    ("lianpo", "廉颇"),
+    ("liuchiping", "刘炽平"),
+    ("lumiaier", "卢米埃尔"),
+    ("luxun", "鲁迅"),
+    ("masazhusaizhou", "马萨诸塞州"),
+    ("maibuliqi", "麦布里奇"),
+    ("meixi", "梅西"),
+    ("<nahan>", "《呐喊》"),
+    ("nuobeierjiang", "诺贝尔奖"),
+    ("panghuang", "《彷徨》"),
+    ("qilang", "七郎"),
+    ("qinguo", "秦国"),
+    ("shun", "舜"),
+    ("sitanfu", "斯坦福"),
+    ("tengxun", "腾讯"),
+    ("weixin", "微信"),
+    ("xi", "夕"),
+    ("xihan", "西汉"),
+    ("xinxilan", "新西兰"),
+    ("yangxiong", "杨雄"),
+    ("yudafu", "郁达夫"),
+    ("zhaifeng", "翟峰"),
+    ("zhanmushinaishimisi", "詹姆士·奈史密斯"),
+    ("zhanguo", "战国"),
+    ("zhangxiaolong", "张小龙"),
+    ("zhaoguo", "赵国"),
+    ("zhaokuo", "赵括"),
+    ("zhaoshe", "赵奢"),
+    ("zilu", "子路"),
+    ("benpao", "奔跑"),
+    ("bodong", "波动"),
+    ("chengba", "称霸"),
+    ("daiyan", "代言"),
+    ("daoju", "道具"),
+    ("dianqiu", "颠球"),
+    ("ding", "叮"),
+    ("enai", "恩爱"),
+    ("ershou", "二手"),
+    ("fanchuan", "帆船"),
+    ("fangzong", "放纵"),
+    ("gan", "杆"),
+    ("guaiwu", "怪物"),
+    ("gun", "棍"),
+    ("guojun", "国君"),
+    ("haili", "海里"),
+    ("hanyi", "含意"),
+    ("hangjia", "行家"),
+    ("heixingxing", "黑猩猩"),
+    ("huannanyugong", "患难与共"),
+    ("huochengyan", "火成岩"),
+    ("jizhi", "机制"),
+    ("jixu", "积蓄"),
+    ("jian", "箭"),
+    ("jinyu", "金鱼"),
+    ("jingchengsuozhi", "精诚所至"),
+    ("jinshiweikai", "金石为开"),
+    ("jiucan", "就餐"),
+    ("juzi", "锯子"),
+    ("ju", "锯"),
+    ("kunrao", "困扰"),
+    ("langan", "栏杆"),
+    ("linlijinzhi", "淋漓尽致"),
+    ("mai", "埋"),
+    ("mangren", "盲人"),
+    ("meide", "美德"),
+    ("menglong", "蒙眬"),
+    ("minju", "民居"),
+    ("naru", "纳入"),
+    ("pingwei", "评委"),
+    ("qiju", "起居"),
+    ("qingwa", "青蛙"),
+    ("qingqu", "情趣"),
+    ("quanshenguanzhu", "全神贯注"),
+    ("ruwei", "入围"),
+    ("sheru", "摄入"),
+    ("shenshangxiansu", "肾上腺素"),
+    ("shengwuzhong", "生物钟"),
+    ("shihuiyan", "石灰岩"),
+    ("shoubi", "手笔"),
+    ("shou", "守"),
+    ("shunchang", "顺畅"),
+    ("siheyuan", "四合院"),
+    ("tizi", "蹄子"),
+    ("ti", "蹄"),
+    ("tianran", "天然"),
+    ("tuya", "涂鸦"),
+    ("waiwainiuniu", "歪歪扭扭"),
+    ("weiyang", "喂养"),
+    ("wenzi", "蚊子"),
+    ("xiangjingrubin", "相敬如宾"),
+    ("xiangfang", "厢房"),
+    ("xiangzi", "橡子"),
+    ("xiaojing", "孝敬"),
+    ("youyou", "悠悠"),
+    ("youlai", "由来"),
+    ("yuyan", "寓言"),
+    ("zhaosanmusi", "朝三暮四"),
+    ("zhexuejia", "哲学家"),
+    ("zhen", "镇"),
+    ("zhishangtanbing", "纸上谈兵"),
+    ("zhong", "种"),
+    ("beijingshifandaxue", "北京师范大学"),
+    ("biergaici", "比尔·盖茨"),
+    ("bier`gaici", "比尔·盖茨"),
+    ("<feinimoshu>", "《非你莫属》"),
+    ("feinimoshu", "《非你莫属》"),
+    ("guomao", "国贸"),
+    ("guloudajie", "鼓楼大街"),
+    ("haolinshuo", "郝林说"),
+    ("huguosi", "护国寺"),
+    ("huiguniang", "灰姑娘"),
+    ("jianwei", "健伟"),
+    ("laoshe", "老舍"),
+    ("lichadexiersi", "里查德·希尔斯"),
+    ("lichade`xiersi", "里查德·希尔斯"),
+    ("liuchen", "刘辰"),
+    ("luoshanji", "洛杉矶"),
+    ("<maihuochaidexiaonvhaier>", "《卖火柴的小女孩儿》"),
+    ("maihuochaidexiaonvhair", "《卖火柴的小女孩儿》"),
+    ("niujindaxue", "牛津大学"),
+    ("oumeng", "欧盟"),
+    ("ouzhouhuanjingbaohushu", "欧洲环境保护署"),
+    ("panjiayuan", "潘家园"),
+    ("peizhansen", "佩·詹森"),
+    ("pei`zhansen", "佩·詹森"),
+    ("shuowenjiezi", "《说文解字》"),
+    ("<shuowenjiezi>", "《说文解字》"),
+    ("tiananmendong", "天安门东"),
+    ("tianjinweishi", "天津卫视"),
+    ("wenwen", "文文"),
+    ("xiaweiyi", "夏威夷"),
+    ("yinjia", "印加"),
+    ("yunnan", "云南"),
+    ("zhaofugen", "赵福根"),
+    ("bachengr", "八成儿"),
+    ("bacheng", "八成"),
+    ("ban", "般"),
+    ("baoyu", "暴雨"),
+    ("biguan", "闭关"),
+    ("bujiasisuo", "不假思索"),
+    ("caiyi", "才艺"),
+    ("chaxun", "查询"),
+    ("chehuo", "车祸"),
+    ("chen", "沉"),
+    ("chengxin", "诚信"),
+    ("chou", "抽"),
+    ("chuangban", "创办"),
+    ("conglin", "丛林"),
+    ("cunhuo", "存活"),
+    ("e", "额"),
+    ("fanxing", "反省"),
+    ("fanhang", "返航"),
+    ("fan", "犯"),
+    ("fenxiang", "分享"),
+    ("fenglang", "风浪"),
+    ("fuzhong", "负重"),
+    ("gan", "擀"),
+    ("genji", "根基"),
+    ("genzhi", "根治"),
+    ("gu", "雇"),
+    ("hanxian", "汗腺"),
+    ("heshang", "和尚"),
+    ("hu", "糊"),
+    ("jisheng", "寄生"),
+    ("jia", "佳"),
+    ("jiafang", "家访"),
+    ("jianburufei", "健步如飞"),
+    ("jiao", "焦"),
+    ("ju", "局"),
+    ("juhua", "菊花"),
+    ("kua", "垮"),
+    ("lai", "来"),
+    ("laoying", "老鹰"),
+    ("lianhuanhua", "连环画"),
+    ("liushou", "留守"),
+    ("lv", "屡"),
+    ("luobo", "萝卜"),
+    ("maokong", "毛孔"),
+    ("mao", "冒"),
+    ("menkan", "门槛"),
+    ("nianyu", "鲇鱼"),
+    ("pengzi", "棚子"),
+    ("qingzhuangnian", "青壮年"),
+    ("qinglv", "情侣"),
+    ("qingyuan", "情缘"),
+    ("qingyuanshu", "请愿书"),
+    ("shadingyu", "沙丁鱼"),
+    ("shazi", "沙子"),
+    ("shangxinyuemu", "赏心悦目"),
+    ("shaoxu", "少许"),
+    ("shenduan", "身段"),
+    ("shenyuan", "深渊"),
+    ("shifei", "施肥"),
+    ("shidang", "适当"),
+    ("shouji", "收集"),
+    ("si", "丝"),
+    ("tanhua", "昙花"),
+    ("tan", "痰"),
+    ("tiandi", "天敌"),
+    ("tu", "图"),
+    ("wanzhang", "万丈"),
+    ("wanggong", "王宫"),
+    ("wangsheng", "旺盛"),
+    ("weibo", "微博"),
+    ("weiqi", "尾气"),
+    ("wenhuo", "文火"),
+    ("wuyi", "无意"),
+    ("xili", "洗礼"),
+    ("xiaoying", "效应"),
+    ("yilian", "一连"),
+    ("yinliang", "荫凉"),
+    ("zhaor", "招儿"),
+    ("zhao", "招"),
+    ("zhedie", "折叠"),
+    ("zheli", "哲理"),
+    ("zhengteng", "蒸腾"),
+    ("zhijiao", "支教"),
+    ("zhigan", "枝干"),
+    ("zhigan", "知感"),
+    ("zhigengniao", "知更鸟"),
+    ("zhiding", "制订"),
+    ("zhong", "钟"),
+    ("zhuanzhu", "专注"),
+    ("zhuantou", "转头"),
+    ("zhuo", "啄"),
+    ("<", "《"),
+    (">", "》"),
+    (".", "。"),
+    ("`", "·"),
+    ("\\", "、"),
+    (",", "，"),
+    ("!", "！"),
+    ("?", "？"),
+    (":", "："),
+    (";", "；"),
+    ("(", "（"),
+    (")", "）"),
+];
+
+// Tuples are (abbreviation, 词语) for every 词语 with each abbreviation,
+// including choices that didn't make the cut for ABBREV_CIYU.
+#[cfg(test)]
+pub static ABBREV_CIYU_TEST_DATA: &[(&str, &str)] = &[
+    ("b", "八"),
+    ("bb", "爸爸"),
+    ("bz", "杯子"),
+    ("bj", "北京"),
+    ("b", "本"),
+    ("bkq", "不客气"),
+    ("b", "不"),
+    ("c", "菜"),
+    ("ch", "茶"),
+    ("c", "茶"),
+    ("ch", "吃"),
+    ("c", "吃"),
+    ("chzch", "出租车"),
+    ("chzc", "出租车"),
+    ("czch", "出租车"),
+    ("czc", "出租车"),
+    ("ddh", "打电话"),
+    ("d", "大"),
+    ("d", "的"),
+    ("d", "点"),
+    ("dn", "电脑"),
+    ("dsh", "电视"),
+    ("ds", "电视"),
+    ("dy", "电影"),
+    ("dx", "东西"),
+    ("d", "都"),
+    ("d", "读"),
+    ("dbq", "对不起"),
+    ("d", "多"),
+    ("dsh", "多少"),
+    ("ds", "多少"),
+    ("ez", "儿子"),
+    ("fd", "饭店"),
+    ("fj", "飞机"),
+    ("fzh", "分钟"),
+    ("fz", "分钟"),
+    ("gx", "高兴"),
+    ("g", "个"),
+    ("gz", "工作"),
+    ("g", "狗"),
+    ("hy", "汉语"),
+    ("h", "好"),
+    ("h", "号"),
+    ("h", "喝"),
+    ("h", "和"),
+    ("h", "很"),
+    ("hm", "后面"),
+    ("h", "回"),
+    ("h", "会"),
+    ("j", "几"),
+    ("j", "家"),
+    ("j", "叫"),
+    ("jt", "今天"),
+    ("j", "九"),
+    ("k", "开"),
+    ("k", "看"),
+    ("kj", "看见"),
+    ("k", "块"),
+    ("l", "来"),
+    ("lsh", "老师"),
+    ("ls", "老师"),
+    ("l", "了"),
+    ("l", "冷"),
+    ("l", "里"),
+    ("l", "六"),
+    ("mm", "妈妈"),
+    ("m", "吗"),
+    ("m", "买"),
+    ("m", "猫"),
+    ("mgx", "没关系"),
+    ("my", "没有"),
+    ("mf", "米饭"),
+    ("mz", "名字"),
+    ("mt", "明天"),
+    ("n", "哪"),
+    ("n", "哪儿"),
+    ("n", "那"),
+    ("n", "呢"),
+    ("n", "能"),
+    ("n", "你"),
+    ("n", "年"),
+    ("py", "朋友"),
+    ("pl", "漂亮"),
+    ("pg", "苹果"),
+    ("q", "七"),
+    ("qm", "前面"),
+    ("q", "钱"),
+    ("q", "请"),
+    ("q", "去"),
+    ("r", "热"),
+    ("r", "人"),
+    ("rsh", "认识"),
+    ("rs", "认识"),
+    ("s", "三"),
+    ("shd", "商店"),
+    ("sd", "商店"),
+    ("sh", "上"),
+    ("s", "上"),
+    ("shw", "上午"),
+    ("sw", "上午"),
+    ("sh", "少"),
+    ("s", "少"),
+    ("sh", "谁"),
+    ("s", "谁"),
+    ("shm", "什么"),
+    ("sm", "什么"),
+    ("sh", "十"),
+    ("s", "十"),
+    ("shh", "时候"),
+    ("sh", "时候"),
+    ("sh", "是"),
+    ("s", "是"),
+    ("sh", "书"),
+    ("s", "书"),
+    ("sh", "水"),
+    ("s", "水"),
+    ("shg", "水果"),
+    ("sg", "水果"),
+    ("shj", "睡觉"),
+    ("sj", "睡觉"),
+    ("sh", "说"),
+    ("s", "说"),
+    ("s", "四"),
+    ("s", "岁"),
+    ("t", "他"),
+    ("t", "她"),
+    ("t", "太"),
+    ("tq", "天气"),
+    ("t", "听"),
+    ("tx", "同学"),
+    ("w", "喂"),
+    ("w", "我"),
+    ("wm", "我们"),
+    ("w", "五"),
+    ("xh", "喜欢"),
+    ("x", "下"),
+    ("xw", "下午"),
+    ("xy", "下雨"),
+    ("xsh", "先生"),
+    ("xs", "先生"),
+    ("xz", "现在"),
+    ("x", "想"),
+    ("x", "小"),
+    ("xj", "小姐"),
+    ("x", "些"),
+    ("x", "写"),
+    ("xx", "谢谢"),
+    ("xq", "星期"),
+    ("xsh", "学生"),
+    ("xs", "学生"),
+    ("xx", "学习"),
+    ("xx", "学校"),
+    ("y", "一"),
+    ("yf", "衣服"),
+    ("ysh", "医生"),
+    ("ys", "医生"),
+    ("yy", "医院"),
+    ("yz", "椅子"),
+    ("yd", "一点儿"),
+    ("y", "有"),
+    ("y", "月"),
+    ("zj", "再见"),
+    ("z", "在"),
+    ("zm", "怎么"),
+    ("zmy", "怎么样"),
+    ("zh", "这"),
+    ("z", "这"),
+    ("zhg", "中国"),
+    ("zg", "中国"),
+    ("zhw", "中午"),
+    ("zw", "中午"),
+    ("zh", "住"),
+    ("z", "住"),
+    ("zhz", "桌子"),
+    ("zz", "桌子"),
+    ("z", "字"),
+    ("zt", "昨天"),
+    ("z", "坐"),
+    ("z", "做"),
+    ("dw", "大卫"),
+    ("l", "李"),
+    ("mg", "美国"),
+    ("w", "王"),
+    ("x", "谢"),
+    ("p", "朋"),
+    ("bsh", "不少"),
+    ("bs", "不少"),
+    ("ch", "车"),
+    ("c", "车"),
+    ("chf", "吃饭"),
+    ("cf", "吃饭"),
+    ("dx", "大学"),
+    ("g", "国"),
+    ("hz", "汉字"),
+    ("h", "后"),
+    ("hl", "回来"),
+    ("jn", "今年"),
+    ("m", "没"),
+    ("n", "那儿"),
+    ("nm", "你们"),
+    ("q", "前"),
+    ("xm", "下面"),
+    ("x", "学"),
+    ("y", "雨"),
+    ("zh", "这儿"),
+    ("z", "这儿"),
+    ("zhx", "这些"),
+    ("zx", "这些"),
+    ("b", "吧"),
+    ("b", "白"),
+    ("b", "百"),
+    ("bzh", "帮助"),
+    ("bz", "帮助"),
+    ("bzh", "报纸"),
+    ("bz", "报纸"),
+    ("b", "比"),
+    ("b", "别"),
+    ("bg", "宾馆"),
+    ("ch", "长"),
+    ("c", "长"),
+    ("chg", "唱歌"),
+    ("cg", "唱歌"),
+    ("ch", "出"),
+    ("c", "出"),
+    ("ch", "穿"),
+    ("c", "穿"),
+    ("c", "次"),
+    ("c", "从"),
+    ("c", "错"),
+    ("dlq", "打篮球"),
+    ("dj", "大家"),
+    ("dsh", "但是"),
+    ("ds", "但是"),
+    ("d", "到"),
+    ("d", "得"),
+    ("d", "等"),
+    ("dd", "弟弟"),
+    ("dy", "第一"),
+    ("d", "懂"),
+    ("d", "对"),
+    ("fj", "房间"),
+    ("fch", "非常"),
+    ("fc", "非常"),
+    ("fwy", "服务员"),
+    ("g", "高"),
+    ("gs", "告诉"),
+    ("gg", "哥哥"),
+    ("g", "给"),
+    ("ggqch", "公共汽车"),
+    ("ggqc", "公共汽车"),
+    ("gs", "公司"),
+    ("g", "贵"),
+    ("g", "过"),
+    ("h", "还"),
+    ("hz", "孩子"),
+    ("hch", "好吃"),
+    ("hc", "好吃"),
+    ("h", "黑"),
+    ("h", "红"),
+    ("hchzh", "火车站"),
+    ("hchz", "火车站"),
+    ("hczh", "火车站"),
+    ("hcz", "火车站"),
+    ("jch", "机场"),
+    ("jc", "机场"),
+    ("jd", "鸡蛋"),
+    ("j", "件"),
+    ("jd", "觉得"),
+    ("jsh", "教室"),
+    ("js", "教室"),
+    ("jj", "姐姐"),
+    ("jsh", "介绍"),
+    ("js", "介绍"),
+    ("j", "进"),
+    ("j", "近"),
+    ("j", "就"),
+    ("kf", "咖啡"),
+    ("ksh", "开始"),
+    ("ks", "开始"),
+    ("ksh", "考试"),
+    ("ks", "考试"),
+    ("kn", "可能"),
+    ("ky", "可以"),
+    ("k", "课"),
+    ("k", "快"),
+    ("kl", "快乐"),
+    ("l", "累"),
+    ("l", "离"),
+    ("l", "两"),
+    ("l", "零"),
+    ("l", "路"),
+    ("ly", "旅游"),
+    ("m", "卖"),
+    ("m", "慢"),
+    ("m", "忙"),
+    ("m", "每"),
+    ("mm", "妹妹"),
+    ("m", "门"),
+    ("mt", "面条"),
+    ("n", "男"),
+    ("n", "您"),
+    ("nn", "牛奶"),
+    ("n", "女"),
+    ("pb", "旁边"),
+    ("pb", "跑步"),
+    ("py", "便宜"),
+    ("p", "票"),
+    ("qz", "妻子"),
+    ("qch", "起床"),
+    ("qc", "起床"),
+    ("q", "千"),
+    ("qb", "铅笔"),
+    ("q", "晴"),
+    ("qn", "去年"),
+    ("r", "让"),
+    ("r", "日"),
+    ("shb", "上班"),
+    ("sb", "上班"),
+    ("sht", "身体"),
+    ("st", "身体"),
+    ("shb", "生病"),
+    ("sb", "生病"),
+    ("shr", "生日"),
+    ("sr", "生日"),
+    ("shj", "时间"),
+    ("sj", "时间"),
+    ("shq", "事情"),
+    ("sq", "事情"),
+    ("shb", "手表"),
+    ("sb", "手表"),
+    ("shj", "手机"),
+    ("sj", "手机"),
+    ("shh", "说话"),
+    ("sh", "说话"),
+    ("s", "送"),
+    ("sr", "虽然"),
+    ("sy", "所以"),
+    ("t", "它"),
+    ("tzq", "踢足球"),
+    ("t", "题"),
+    ("tw", "跳舞"),
+    ("w", "外"),
+    ("w", "完"),
+    ("w", "玩"),
+    ("w", "玩儿"),
+    ("wsh", "晚上"),
+    ("ws", "晚上"),
+    ("w", "往"),
+    ("wshm", "为什么"),
+    ("wsm", "为什么"),
+    ("w", "问"),
+    ("wt", "问题"),
+    ("xg", "西瓜"),
+    ("xw", "希望"),
+    ("x", "洗"),
+    ("xsh", "小时"),
+    ("xs", "小时"),
+    ("x", "笑"),
+    ("x", "新"),
+    ("x", "姓"),
+    ("xx", "休息"),
+    ("x", "雪"),
+    ("ys", "颜色"),
+    ("yj", "眼睛"),
+    ("yr", "羊肉"),
+    ("y", "药"),
+    ("y", "要"),
+    ("y", "也"),
+    ("yx", "一下"),
+    ("yj", "已经"),
+    ("yq", "一起"),
+    ("ys", "意思"),
+    ("yw", "因为"),
+    ("y", "阴"),
+    ("yy", "游泳"),
+    ("yb", "右边"),
+    ("y", "鱼"),
+    ("y", "远"),
+    ("yd", "运动"),
+    ("z", "再"),
+    ("zsh", "早上"),
+    ("zs", "早上"),
+    ("zhf", "丈夫"),
+    ("zf", "丈夫"),
+    ("zh", "找"),
+    ("z", "找"),
+    ("zh", "着"),
+    ("z", "着"),
+    ("zh", "真"),
+    ("z", "真"),
+    ("zhz", "正在"),
+    ("zz", "正在"),
+    ("zhd", "知道"),
+    ("zd", "知道"),
+    ("zhb", "准备"),
+    ("zb", "准备"),
+    ("z", "走"),
+    ("z", "最"),
+    ("zb", "左边"),
+    ("hh", "花花"),
+    ("y", "杨"),
+    ("xx", "笑笑"),
+    ("d", "度"),
+    ("fs", "粉色"),
+    ("yh", "以后"),
+    ("b", "帮"),
+    ("bc", "不错"),
+    ("chy", "出院"),
+    ("cy", "出院"),
+    ("f", "粉"),
+    ("hs", "红色"),
+    ("lk", "路口"),
+    ("sh", "手"),
+    ("s", "手"),
+    ("wm", "外面"),
+    ("xn", "新年"),
+    ("yys", "有意思"),
+    ("ay", "阿姨"),
+    ("ah", "爱好"),
+    ("aj", "安静"),
+    ("b", "把"),
+    ("b", "班"),
+    ("b", "搬"),
+    ("bf", "办法"),
+    ("bgsh", "办公室"),
+    ("bgs", "办公室"),
+    ("b", "半"),
+    ("bm", "帮忙"),
+    ("b", "包"),
+    ("b", "饱"),
+    ("bf", "北方"),
+    ("b", "被"),
+    ("bz", "鼻子"),
+    ("bj", "比较"),
+    ("bs", "比赛"),
+    ("bjb", "笔记本"),
+    ("bx", "必须"),
+    ("bh", "变化"),
+    ("br", "别人"),
+    ("bx", "冰箱"),
+    ("bd", "不但"),
+    ("eq", "而且"),
+    ("cd", "菜单"),
+    ("cj", "参加"),
+    ("c", "草"),
+    ("c", "层"),
+    ("ch", "差"),
+    ("c", "差"),
+    ("chsh", "超市"),
+    ("chs", "超市"),
+    ("csh", "超市"),
+    ("cs", "超市"),
+    ("chsh", "衬衫"),
+    ("chs", "衬衫"),
+    ("csh", "衬衫"),
+    ("cs", "衬衫"),
+    ("chj", "成绩"),
+    ("cj", "成绩"),
+    ("chsh", "城市"),
+    ("chs", "城市"),
+    ("csh", "城市"),
+    ("cs", "城市"),
+    ("chd", "迟到"),
+    ("cd", "迟到"),
+    ("chl", "除了"),
+    ("cl", "除了"),
+    ("ch", "船"),
+    ("c", "船"),
+    ("ch", "春"),
+    ("c", "春"),
+    ("t", "天"),
+    ("cd", "词典"),
+    ("cm", "聪明"),
+    ("ds", "打扫"),
+    ("ds", "打算"),
+    ("d", "带"),
+    ("dx", "担心"),
+    ("dg", "蛋糕"),
+    ("dr", "当然"),
+    ("d", "地"),
+    ("d", "灯"),
+    ("df", "地方"),
+    ("dt", "地铁"),
+    ("dt", "地图"),
+    ("dt", "电梯"),
+    ("dzyj", "电子邮件"),
+    ("d", "东"),
+    ("d", "冬"),
+    ("dw", "动物"),
+    ("d", "短"),
+    ("d", "段"),
+    ("dl", "锻炼"),
+    ("dm", "多么"),
+    ("ed", "耳朵"),
+    ("f", "发"),
+    ("fsh", "发烧"),
+    ("fs", "发烧"),
+    ("fx", "发现"),
+    ("fb", "方便"),
+    ("f", "放"),
+    ("fx", "放心"),
+    ("f", "分"),
+    ("fj", "附近"),
+    ("fx", "复习"),
+    ("gj", "干净"),
+    ("gm", "感冒"),
+    ("gxq", "感兴趣"),
+    ("gc", "刚才"),
+    ("gz", "个子"),
+    ("gj", "根据"),
+    ("g", "跟"),
+    ("g", "更"),
+    ("gj", "公斤"),
+    ("gy", "公园"),
+    ("gsh", "故事"),
+    ("gs", "故事"),
+    ("gf", "刮风"),
+    ("g", "关"),
+    ("gx", "关系"),
+    ("gx", "关心"),
+    ("gy", "关于"),
+    ("gj", "国家"),
+    ("gq", "过去"),
+    ("hsh", "还是"),
+    ("hs", "还是"),
+    ("hp", "害怕"),
+    ("hb", "黑板"),
+    ("hl", "后来"),
+    ("hzh", "护照"),
+    ("hz", "护照"),
+    ("h", "花"),
+    ("h", "画"),
+    ("h", "坏"),
+    ("hy", "欢迎"),
+    ("hj", "环境"),
+    ("h", "换"),
+    ("hd", "回答"),
+    ("hy", "会议"),
+    ("hzh", "或者"),
+    ("hz", "或者"),
+    ("jh", "几乎"),
+    ("jh", "机会"),
+    ("j", "极"),
+    ("jd", "记得"),
+    ("jj", "季节"),
+    ("jch", "检查"),
+    ("jc", "检查"),
+    ("jd", "简单"),
+    ("jm", "见面"),
+    ("jk", "健康"),
+    ("j", "讲"),
+    ("j", "教"),
+    ("j", "角"),
+    ("j", "脚"),
+    ("j", "接"),
+    ("jd", "街道"),
+    ("jm", "节目"),
+    ("jr", "节日"),
+    ("jh", "结婚"),
+    ("jsh", "结束"),
+    ("js", "结束"),
+    ("jj", "解决"),
+    ("j", "借"),
+    ("jch", "经常"),
+    ("jc", "经常"),
+    ("jg", "经过"),
+    ("jl", "经理"),
+    ("j", "久"),
+    ("j", "旧"),
+    ("jz", "句子"),
+    ("jd", "决定"),
+    ("ka", "可爱"),
+    ("k", "渴"),
+    ("k", "刻"),
+    ("kr", "客人"),
+    ("kt", "空调"),
+    ("k", "口"),
+    ("k", "哭"),
+    ("kz", "裤子"),
+    ("kz", "筷子"),
+    ("l", "蓝"),
+    ("l", "老"),
+    ("lk", "离开"),
+    ("lw", "礼物"),
+    ("lsh", "历史"),
+    ("ls", "历史"),
+    ("l", "脸"),
+    ("lx", "练习"),
+    ("l", "辆"),
+    ("lt", "聊天"),
+    ("lt", "聊天儿"),
+    ("lj", "了解"),
+    ("lj", "邻居"),
+    ("lx", "留学"),
+    ("l", "楼"),
+    ("l", "绿"),
+    ("m", "马"),
+    ("msh", "马上"),
+    ("ms", "马上"),
+    ("my", "满意"),
+    ("mz", "帽子"),
+    ("m", "米"),
+    ("mb", "面包"),
+    ("mb", "明白"),
+    ("n", "拿"),
+    ("nn", "奶奶"),
+    ("n", "南"),
+    ("n", "难"),
+    ("nj", "年级"),
+    ("nq", "年轻"),
+    ("n", "鸟"),
+    ("nl", "努力"),
+    ("psh", "爬山"),
+    ("ps", "爬山"),
+    ("pz", "盘子"),
+    ("p", "胖"),
+    ("px", "皮鞋"),
+    ("pj", "啤酒"),
+    ("pz", "瓶子"),
+    ("qsh", "其实"),
+    ("qs", "其实"),
+    ("qt", "其他"),
+    ("qg", "奇怪"),
+    ("q", "骑"),
+    ("qf", "起飞"),
+    ("ql", "起来"),
+    ("qch", "清楚"),
+    ("qc", "清楚"),
+    ("qj", "请假"),
+    ("q", "秋"),
+    ("qz", "裙子"),
+    ("rh", "然后"),
+    ("rq", "热情"),
+    ("rw", "认为"),
+    ("rzh", "认真"),
+    ("rz", "认真"),
+    ("ry", "容易"),
+    ("rg", "如果"),
+    ("s", "伞"),
+    ("shw", "上网"),
+    ("sw", "上网"),
+    ("shq", "生气"),
+    ("sq", "生气"),
+    ("shy", "声音"),
+    ("sy", "声音"),
+    ("shj", "世界"),
+    ("sj", "世界"),
+    ("sh", "试"),
+    ("s", "试"),
+    ("sh", "瘦"),
+    ("s", "瘦"),
+    ("shsh", "叔叔"),
+    ("shs", "叔叔"),
+    ("ssh", "叔叔"),
+    ("ss", "叔叔"),
+    ("shf", "舒服"),
+    ("sf", "舒服"),
+    ("sh", "树"),
+    ("s", "树"),
+    ("shx", "数学"),
+    ("sx", "数学"),
+    ("shy", "刷牙"),
+    ("sy", "刷牙"),
+    ("sh", "双"),
+    ("s", "双"),
+    ("shp", "水平"),
+    ("sp", "水平"),
+    ("sj", "司机"),
+    ("ty", "太阳"),
+    ("tb", "特别"),
+    ("t", "疼"),
+    ("tg", "提高"),
+    ("ty", "体育"),
+    ("t", "甜"),
+    ("t", "条"),
+    ("tsh", "同事"),
+    ("ts", "同事"),
+    ("ty", "同意"),
+    ("tf", "头发"),
+    ("tr", "突然"),
+    ("tshg", "图书馆"),
+    ("tsg", "图书馆"),
+    ("t", "腿"),
+    ("wch", "完成"),
+    ("wc", "完成"),
+    ("w", "碗"),
+    ("w", "万"),
+    ("wj", "忘记"),
+    ("w", "为"),
+    ("wl", "为了"),
+    ("w", "位"),
+    ("wh", "文化"),
+    ("x", "西"),
+    ("xg", "习惯"),
+    ("xshj", "洗手间"),
+    ("xsj", "洗手间"),
+    ("xz", "洗澡"),
+    ("x", "夏"),
+    ("x", "先"),
+    ("xx", "相信"),
+    ("xj", "香蕉"),
+    ("x", "向"),
+    ("x", "像"),
+    ("xx", "小心"),
+    ("xzh", "校长"),
+    ("xz", "校长"),
+    ("xw", "新闻"),
+    ("xx", "新鲜"),
+    ("xyk", "信用卡"),
+    ("xlx", "行李箱"),
+    ("xm", "熊猫"),
+    ("xy", "需要"),
+    ("xz", "选择"),
+    ("yq", "要求"),
+    ("yy", "爷爷"),
+    ("yd", "一定"),
+    ("yg", "一共"),
+    ("yh", "一会儿"),
+    ("yhe", "一会儿"),
+    ("yy", "一样"),
+    ("yq", "以前"),
+    ("yb", "一般"),
+    ("yb", "一边"),
+    ("yzh", "一直"),
+    ("yz", "一直"),
+    ("yy", "音乐"),
+    ("yh", "银行"),
+    ("yl", "饮料"),
+    ("yg", "应该"),
+    ("yx", "影响"),
+    ("y", "用"),
+    ("yx", "游戏"),
+    ("ym", "有名"),
+    ("y", "又"),
+    ("yd", "遇到"),
+    ("y", "元"),
+    ("yy", "愿意"),
+    ("yl", "月亮"),
+    ("y", "越"),
+    ("zh", "站"),
+    ("z", "站"),
+    ("zh", "张"),
+    ("z", "张"),
+    ("zh", "长"),
+    ("z", "长"),
+    ("zhj", "着急"),
+    ("zj", "着急"),
+    ("zhg", "照顾"),
+    ("zg", "照顾"),
+    ("zhp", "照片"),
+    ("zp", "照片"),
+    ("zhxj", "照相机"),
+    ("zxj", "照相机"),
+    ("zh", "只"),
+    ("z", "只"),
+    ("zhy", "只有"),
+    ("zy", "只有"),
+    ("c", "才"),
+    ("zhj", "中间"),
+    ("zj", "中间"),
+    ("zhy", "终于"),
+    ("zy", "终于"),
+    ("zh", "种"),
+    ("z", "种"),
+    ("zhy", "重要"),
+    ("zy", "重要"),
+    ("zhm", "周末"),
+    ("zm", "周末"),
+    ("zhy", "主要"),
+    ("zy", "主要"),
+    ("zhy", "注意"),
+    ("zy", "注意"),
+    ("zj", "自己"),
+    ("zxch", "自行车"),
+    ("zxc", "自行车"),
+    ("zsh", "总是"),
+    ("zs", "总是"),
+    ("z", "嘴"),
+    ("zh", "最后"),
+    ("zj", "最近"),
+    ("zy", "作业"),
+    ("hh", "黄河"),
+    ("zhw", "中文"),
+    ("zw", "中文"),
+    ("sh", "山"),
+    ("s", "山"),
+    ("h", "黄"),
+    ("h", "河"),
+    ("j", "静"),
+    ("k", "可"),
+    ("l", "丽"),
+    ("m", "明"),
+    ("zh", "周"),
+    ("z", "周"),
+    ("kl", "可乐"),
+    ("shzh", "睡着"),
+    ("shz", "睡着"),
+    ("szh", "睡着"),
+    ("sz", "睡着"),
+    ("bsh", "办事"),
+    ("bs", "办事"),
+    ("b", "变"),
+    ("chj", "春节"),
+    ("cj", "春节"),
+    ("d", "打"),
+    ("dshh", "到时候"),
+    ("dsh", "到时候"),
+    ("fz", "房子"),
+    ("gw", "歌舞"),
+    ("hj", "红酒"),
+    ("j", "酒"),
+    ("j", "句"),
+    ("mt", "每天"),
+    ("nl", "哪里"),
+    ("nh", "女孩儿"),
+    ("nh", "女孩"),
+    ("qb", "钱包"),
+    ("rm", "人名"),
+    ("r", "认"),
+    ("tsh", "听说"),
+    ("ts", "听说"),
+    ("wd", "外地"),
+    ("xh", "鲜花"),
+    ("xg", "香瓜"),
+    ("yblsh", "一般来说"),
+    ("ybls", "一般来说"),
+    ("yyh", "音乐会"),
+    ("yd", "有点儿"),
+    ("yd", "有点"),
+    ("z", "早"),
+    ("zmb", "怎么办"),
+    ("zhqj", "中秋节"),
+    ("zqj", "中秋节"),
+    ("aq", "爱情"),
+    ("ap", "安排"),
+    ("ash", "按时"),
+    ("as", "按时"),
+    ("azh", "按照"),
+    ("az", "按照"),
+    ("bfzh", "百分之"),
+    ("bfz", "百分之"),
+    ("bzh", "保证"),
+    ("bz", "保证"),
+    ("b", "倍"),
+    ("bl", "本来"),
+    ("br", "比如"),
+    ("by", "毕业"),
+    ("bzh", "标准"),
+    ("bz", "标准"),
+    ("bg", "饼干"),
+    ("bdb", "不得不"),
+    ("bg", "不管"),
+    ("bg", "不过"),
+    ("bj", "不仅"),
+    ("c", "擦"),
+    ("cl", "材料"),
+    ("chbd", "差不多"),
+    ("cbd", "差不多"),
+    ("ch", "尝"),
+    ("c", "尝"),
+    ("chg", "超过"),
+    ("cg", "超过"),
+    ("chg", "成功"),
+    ("cg", "成功"),
+    ("chw", "成为"),
+    ("cw", "成为"),
+    ("chsh", "诚实"),
+    ("chs", "诚实"),
+    ("csh", "诚实"),
+    ("cs", "诚实"),
+    ("chx", "重新"),
+    ("cx", "重新"),
+    ("chy", "抽烟"),
+    ("cy", "抽烟"),
+    ("chx", "出现"),
+    ("cx", "出现"),
+    ("chh", "窗户"),
+    ("ch", "窗户"),
+    ("cl", "从来"),
+    ("dr", "打扰"),
+    ("dzh", "打折"),
+    ("dz", "打折"),
+    ("dshg", "大使馆"),
+    ("dsg", "大使馆"),
+    ("df", "大夫"),
+    ("d", "当"),
+    ("dsh", "当时"),
+    ("ds", "当时"),
+    ("dch", "到处"),
+    ("dc", "到处"),
+    ("d", "低"),
+    ("dch", "调查"),
+    ("dc", "调查"),
+    ("d", "掉"),
+    ("dz", "动作"),
+    ("dch", "堵车"),
+    ("dc", "堵车"),
+    ("dz", "肚子"),
+    ("dx", "短信"),
+    ("fsh", "发生"),
+    ("fs", "发生"),
+    ("fzh", "发展"),
+    ("fz", "发展"),
+    ("fl", "法律"),
+    ("fy", "翻译"),
+    ("fn", "烦恼"),
+    ("fd", "反对"),
+    ("ff", "方法"),
+    ("fm", "方面"),
+    ("fx", "方向"),
+    ("fq", "放弃"),
+    ("fs", "放松"),
+    ("f", "份"),
+    ("ff", "丰富"),
+    ("fh", "符合"),
+    ("fz", "负责"),
+    ("f", "富"),
+    ("gb", "改变"),
+    ("gd", "感动"),
+    ("gj", "感觉"),
+    ("gq", "感情"),
+    ("gx", "感谢"),
+    ("g", "刚"),
+    ("g", "各"),
+    ("gz", "工资"),
+    ("gt", "共同"),
+    ("gw", "购物"),
+    ("g", "够"),
+    ("gj", "估计"),
+    ("gk", "顾客"),
+    ("gj", "关键"),
+    ("g", "光"),
+    ("gg", "广告"),
+    ("g", "逛"),
+    ("gj", "国际"),
+    ("gzh", "果汁"),
+    ("gz", "果汁"),
+    ("gch", "过程"),
+    ("gc", "过程"),
+    ("h", "汗"),
+    ("hch", "好处"),
+    ("hc", "好处"),
+    ("hx", "好像"),
+    ("hh", "后悔"),
+    ("hx", "互相"),
+    ("hy", "回忆"),
+    ("hd", "活动"),
+    ("hd", "获得"),
+    ("jj", "积极"),
+    ("jl", "积累"),
+    ("jsh", "及时"),
+    ("js", "及时"),
+    ("jsh", "即使"),
+    ("js", "即使"),
+    ("jh", "计划"),
+    ("j", "寄"),
+    ("jb", "加班"),
+    ("jj", "家具"),
+    ("jg", "价格"),
+    ("jch", "坚持"),
+    ("jc", "坚持"),
+    ("jf", "减肥"),
+    ("jy", "建议"),
+    ("jl", "将来"),
+    ("jj", "奖金"),
+    ("jd", "降低"),
+    ("j", "交"),
+    ("jl", "交流"),
+    ("jsh", "教授"),
+    ("js", "教授"),
+    ("jsh", "接受"),
+    ("js", "接受"),
+    ("jg", "结果"),
+    ("jg", "尽管"),
+    ("jzh", "紧张"),
+    ("jz", "紧张"),
+    ("jj", "经济"),
+    ("jl", "经历"),
+    ("jy", "经验"),
+    ("js", "景色"),
+    ("jr", "竟然"),
+    ("jz", "镜子"),
+    ("jb", "举办"),
+    ("jx", "举行"),
+    ("jl", "距离"),
+    ("jh", "聚会"),
+    ("kwx", "开玩笑"),
+    ("kl", "考虑"),
+    ("kx", "科学"),
+    ("ks", "咳嗽"),
+    ("ksh", "可是"),
+    ("ks", "可是"),
+    ("kd", "肯定"),
+    ("kq", "空气"),
+    ("k", "空儿"),
+    ("k", "困"),
+    ("kn", "困难"),
+    ("l", "拉"),
+    ("lbj", "来不及"),
+    ("lf", "浪费"),
+    ("lm", "浪漫"),
+    ("lbt", "礼拜天"),
+    ("lj", "理解"),
+    ("lx", "理想"),
+    ("lr", "例如"),
+    ("l", "俩"),
+    ("lx", "联系"),
+    ("lw", "另外"),
+    ("l", "留"),
+    ("lx", "流行"),
+    ("lsh", "律师"),
+    ("ls", "律师"),
+    ("l", "乱"),
+    ("mf", "麻烦"),
+    ("m", "满"),
+    ("mf", "免费"),
+    ("mq", "母亲"),
+    ("nx", "耐心"),
+    ("nd", "难道"),
+    ("n", "内"),
+    ("nl", "能力"),
+    ("nl", "年龄"),
+    ("pd", "判断"),
+    ("p", "陪"),
+    ("pf", "皮肤"),
+    ("pq", "脾气"),
+    ("p", "篇"),
+    ("psh", "平时"),
+    ("ps", "平时"),
+    ("pt", "葡萄"),
+    ("qc", "其次"),
+    ("qzh", "其中"),
+    ("qz", "其中"),
+    ("qh", "气候"),
+    ("qkl", "巧克力"),
+    ("qq", "亲戚"),
+    ("q", "轻"),
+    ("qs", "轻松"),
+    ("qk", "情况"),
+    ("q", "穷"),
+    ("qb", "区别"),
+    ("q", "取"),
+    ("qd", "缺点"),
+    ("qsh", "缺少"),
+    ("qs", "缺少"),
+    ("q", "却"),
+    ("qsh", "确实"),
+    ("qs", "确实"),
+    ("rh", "任何"),
+    ("sb", "散步"),
+    ("shf", "沙发"),
+    ("sf", "沙发"),
+    ("shx", "伤心"),
+    ("sx", "伤心"),
+    ("sh", "深"),
+    ("s", "深"),
+    ("shzh", "甚至"),
+    ("shz", "甚至"),
+    ("szh", "甚至"),
+    ("sz", "甚至"),
+    ("shh", "生活"),
+    ("sh", "生活"),
+    ("shm", "生命"),
+    ("sm", "生命"),
+    ("shy", "生意"),
+    ("sy", "生意"),
+    ("shb", "失败"),
+    ("sb", "失败"),
+    ("shf", "师傅"),
+    ("sf", "师傅"),
+    ("shj", "实际"),
+    ("sj", "实际"),
+    ("shz", "实在"),
+    ("sz", "实在"),
+    ("sh", "使"),
+    ("s", "使"),
+    ("shh", "适合"),
+    ("sh", "适合"),
+    ("shy", "适应"),
+    ("sy", "适应"),
+    ("shr", "收入"),
+    ("sr", "收入"),
+    ("shx", "首先"),
+    ("sx", "首先"),
+    ("shd", "受到"),
+    ("sd", "受到"),
+    ("shhy", "售货员"),
+    ("shy", "售货员"),
+    ("shx", "熟悉"),
+    ("sx", "熟悉"),
+    ("shz", "数字"),
+    ("sz", "数字"),
+    ("sh", "帅"),
+    ("s", "帅"),
+    ("shb", "顺便"),
+    ("sb", "顺便"),
+    ("shl", "顺利"),
+    ("sl", "顺利"),
+    ("shm", "说明"),
+    ("sm", "说明"),
+    ("shsh", "硕士"),
+    ("shs", "硕士"),
+    ("ssh", "硕士"),
+    ("ss", "硕士"),
+    ("sb", "随便"),
+    ("sy", "所有"),
+    ("t", "台"),
+    ("td", "态度"),
+    ("t", "谈"),
+    ("t", "糖"),
+    ("t", "躺"),
+    ("ty", "讨厌"),
+    ("td", "特点"),
+    ("t", "提"),
+    ("tg", "提供"),
+    ("tq", "提前"),
+    ("tx", "提醒"),
+    ("tj", "条件"),
+    ("t", "挺"),
+    ("tg", "通过"),
+    ("tzh", "通知"),
+    ("tz", "通知"),
+    ("wz", "袜子"),
+    ("wq", "完全"),
+    ("wq", "网球"),
+    ("ww", "往往"),
+    ("wd", "味道"),
+    ("wl", "无聊"),
+    ("xhsh", "西红柿"),
+    ("xhs", "西红柿"),
+    ("xy", "吸引"),
+    ("xj", "现金"),
+    ("xm", "羡慕"),
+    ("xp", "橡皮"),
+    ("xx", "消息"),
+    ("xsh", "小说"),
+    ("xs", "小说"),
+    ("xg", "效果"),
+    ("xq", "心情"),
+    ("xk", "辛苦"),
+    ("xx", "信心"),
+    ("xf", "兴奋"),
+    ("xf", "幸福"),
+    ("xg", "性格"),
+    ("xl", "修理"),
+    ("xd", "许多"),
+    ("yl", "压力"),
+    ("yzh", "严重"),
+    ("yz", "严重"),
+    ("yj", "研究"),
+    ("yg", "阳光"),
+    ("yz", "样子"),
+    ("yq", "邀请"),
+    ("ysh", "要是"),
+    ("ys", "要是"),
+    ("yq", "一切"),
+    ("yw", "以为"),
+    ("ysh", "艺术"),
+    ("ys", "艺术"),
+    ("yc", "因此"),
+    ("yx", "印象"),
+    ("y", "赢"),
+    ("yp", "应聘"),
+    ("yy", "永远"),
+    ("yg", "勇敢"),
+    ("yd", "优点"),
+    ("yx", "优秀"),
+    ("ym", "幽默"),
+    ("yq", "尤其"),
+    ("yy", "由于"),
+    ("yy", "友谊"),
+    ("yk", "愉快"),
+    ("y", "与"),
+    ("yl", "原来"),
+    ("yy", "原因"),
+    ("yh", "约会"),
+    ("zm", "咱们"),
+    ("zsh", "暂时"),
+    ("zs", "暂时"),
+    ("zr", "责任"),
+    ("zhp", "招聘"),
+    ("zp", "招聘"),
+    ("zhzh", "真正"),
+    ("zhz", "真正"),
+    ("zzh", "真正"),
+    ("zz", "真正"),
+    ("zhh", "正好"),
+    ("zh", "正好"),
+    ("zhq", "正确"),
+    ("zq", "正确"),
+    ("zhsh", "正式"),
+    ("zhs", "正式"),
+    ("zsh", "正式"),
+    ("zs", "正式"),
+    ("zhm", "证明"),
+    ("zm", "证明"),
+    ("zhch", "支持"),
+    ("zhc", "支持"),
+    ("zch", "支持"),
+    ("zc", "支持"),
+    ("zhsh", "知识"),
+    ("zhs", "知识"),
+    ("zsh", "知识"),
+    ("zs", "知识"),
+    ("zhd", "值得"),
+    ("zd", "值得"),
+    ("zhy", "职业"),
+    ("zy", "职业"),
+    ("zhw", "植物"),
+    ("zw", "植物"),
+    ("zhy", "只要"),
+    ("zy", "只要"),
+    ("zh", "指"),
+    ("z", "指"),
+    ("zhsh", "至少"),
+    ("zhs", "至少"),
+    ("zsh", "至少"),
+    ("zs", "至少"),
+    ("zhl", "质量"),
+    ("zl", "质量"),
+    ("zhw", "周围"),
+    ("zw", "周围"),
+    ("zhy", "主意"),
+    ("zy", "主意"),
+    ("zhm", "专门"),
+    ("zm", "专门"),
+    ("zhy", "专业"),
+    ("zy", "专业"),
+    ("zh", "赚"),
+    ("z", "赚"),
+    ("zhsh", "准时"),
+    ("zhs", "准时"),
+    ("zsh", "准时"),
+    ("zs", "准时"),
+    ("zr", "自然"),
+    ("zj", "总结"),
+    ("zh", "最好"),
+    ("zj", "作家"),
+    ("aq", "安全"),
+    ("b", "棒"),
+    ("bz", "包子"),
+    ("bh", "保护"),
+    ("bm", "报名"),
+    ("b", "抱"),
+    ("bq", "抱歉"),
+    ("b", "笨"),
+    ("b", "遍"),
+    ("bg", "表格"),
+    ("bsh", "表示"),
+    ("bs", "表示"),
+    ("by", "表演"),
+    ("by", "表扬"),
+    ("bq", "并且"),
+    ("bsh", "博士"),
+    ("bs", "博士"),
+    ("bf", "部分"),
+    ("c", "猜"),
+    ("cg", "参观"),
+    ("ct", "餐厅"),
+    ("cs", "厕所"),
+    ("ch", "场"),
+    ("c", "场"),
+    ("chz", "乘坐"),
+    ("cz", "乘坐"),
+    ("chj", "吃惊"),
+    ("cj", "吃惊"),
+    ("chch", "出差"),
+    ("chc", "出差"),
+    ("cch", "出差"),
+    ("cc", "出差"),
+    ("chf", "出发"),
+    ("cf", "出发"),
+    ("chsh", "出生"),
+    ("chs", "出生"),
+    ("csh", "出生"),
+    ("cs", "出生"),
+    ("chf", "厨房"),
+    ("cf", "厨房"),
+    ("chzh", "传真"),
+    ("chz", "传真"),
+    ("czh", "传真"),
+    ("cz", "传真"),
+    ("cy", "词语"),
+    ("cx", "粗心"),
+    ("c", "存"),
+    ("cw", "错误"),
+    ("db", "打扮"),
+    ("dy", "打印"),
+    ("dzhh", "打招呼"),
+    ("dzh", "打招呼"),
+    ("dzh", "打针"),
+    ("dz", "打针"),
+    ("dg", "大概"),
+    ("dy", "大约"),
+    ("d", "戴"),
+    ("d", "刀"),
+    ("dy", "导游"),
+    ("dd", "到底"),
+    ("d", "倒"),
+    ("dq", "道歉"),
+    ("dy", "得意"),
+    ("djp", "登机牌"),
+    ("d", "底"),
+    ("dd", "地点"),
+    ("dq", "地球"),
+    ("dzh", "地址"),
+    ("dz", "地址"),
+    ("d", "丢"),
+    ("dh", "对话"),
+    ("dm", "对面"),
+    ("dy", "对于"),
+    ("et", "儿童"),
+    ("fd", "房东"),
+    ("fshj", "放暑假"),
+    ("fsj", "放暑假"),
+    ("fz", "否则"),
+    ("fq", "父亲"),
+    ("fk", "付款"),
+    ("fy", "复印"),
+    ("fz", "复杂"),
+    ("gb", "干杯"),
+    ("g", "赶"),
+    ("g", "敢"),
+    ("g", "干"),
+    ("gsgl", "高速公路"),
+    ("gb", "胳膊"),
+    ("gl", "公里"),
+    ("gf", "功夫"),
+    ("gl", "鼓励"),
+    ("gy", "故意"),
+    ("g", "挂"),
+    ("gzh", "观众"),
+    ("gz", "观众"),
+    ("gl", "管理"),
+    ("gb", "广播"),
+    ("gd", "规定"),
+    ("gj", "国籍"),
+    ("hy", "海洋"),
+    ("hx", "害羞"),
+    ("hj", "寒假"),
+    ("hb", "航班"),
+    ("hm", "号码"),
+    ("hg", "合格"),
+    ("hsh", "合适"),
+    ("hs", "合适"),
+    ("hz", "盒子"),
+    ("h", "厚"),
+    ("hlw", "互联网"),
+    ("hsh", "护士"),
+    ("hs", "护士"),
+    ("hy", "怀疑"),
+    ("hp", "活泼"),
+    ("h", "火"),
+    ("jch", "基础"),
+    ("jc", "基础"),
+    ("jd", "激动"),
+    ("jzh", "记者"),
+    ("jz", "记者"),
+    ("jsh", "技术"),
+    ("js", "技术"),
+    ("jr", "既然"),
+    ("jx", "继续"),
+    ("jyzh", "加油站"),
+    ("jyz", "加油站"),
+    ("j", "假"),
+    ("jsh", "减少"),
+    ("js", "减少"),
+    ("jl", "降落"),
+    ("jt", "交通"),
+    ("jq", "郊区"),
+    ("ja", "骄傲"),
+    ("jz", "饺子"),
+    ("jy", "教育"),
+    ("jzh", "接着"),
+    ("jz", "接着"),
+    ("j", "节"),
+    ("jy", "节约"),
+    ("jsh", "解释"),
+    ("js", "解释"),
+    ("jx", "进行"),
+    ("jzh", "禁止"),
+    ("jz", "禁止"),
+    ("jj", "京剧"),
+    ("jc", "精彩"),
+    ("jch", "警察"),
+    ("jc", "警察"),
+    ("jzh", "竞争"),
+    ("jz", "竞争"),
+    ("jj", "究竟"),
+    ("j", "举"),
+    ("jj", "拒绝"),
+    ("kx", "开心"),
+    ("kf", "看法"),
+    ("ky", "烤鸭"),
+    ("k", "棵"),
+    ("kl", "可怜"),
+    ("kx", "可惜"),
+    ("kt", "客厅"),
+    ("k", "空"),
+    ("kp", "恐怕"),
+    ("k", "苦"),
+    ("kqsh", "矿泉水"),
+    ("kqs", "矿泉水"),
+    ("ljt", "垃圾桶"),
+    ("l", "辣"),
+    ("ldj", "来得及"),
+    ("lz", "来自"),
+    ("l", "懒"),
+    ("lh", "老虎"),
+    ("lj", "冷静"),
+    ("lm", "礼貌"),
+    ("lf", "理发"),
+    ("lq", "力气"),
+    ("lh", "厉害"),
+    ("l", "连"),
+    ("lk", "凉快"),
+    ("lq", "零钱"),
+    ("ll", "流利"),
+    ("lx", "旅行"),
+    ("mh", "马虎"),
+    ("m", "毛"),
+    ("mj", "毛巾"),
+    ("ml", "美丽"),
+    ("m", "梦"),
+    ("ml", "迷路"),
+    ("mm", "密码"),
+    ("m", "秒"),
+    ("mz", "民族"),
+    ("md", "目的"),
+    ("nsh", "难受"),
+    ("ns", "难受"),
+    ("nr", "内容"),
+    ("n", "弄"),
+    ("nh", "暖和"),
+    ("oe", "偶尔"),
+    ("pd", "排队"),
+    ("pl", "排列"),
+    ("pp", "批评"),
+    ("p", "骗"),
+    ("ppq", "乒乓球"),
+    ("p", "破"),
+    ("pb", "普遍"),
+    ("pth", "普通话"),
+    ("qw", "千万"),
+    ("qzh", "签证"),
+    ("qz", "签证"),
+    ("q", "敲"),
+    ("q", "桥"),
+    ("qb", "全部"),
+    ("rn", "热闹"),
+    ("rw", "任务"),
+    ("r", "扔"),
+    ("rr", "仍然"),
+    ("rj", "日记"),
+    ("rk", "入口"),
+    ("sl", "森林"),
+    ("shl", "商量"),
+    ("sl", "商量"),
+    ("shw", "稍微"),
+    ("sw", "稍微"),
+    ("sh", "勺"),
+    ("s", "勺"),
+    ("shz", "勺子"),
+    ("sz", "勺子"),
+    ("shh", "社会"),
+    ("sh", "社会"),
+    ("shq", "申请"),
+    ("sq", "申请"),
+    ("sh", "省"),
+    ("s", "省"),
+    ("sh", "剩"),
+    ("s", "剩"),
+    ("shw", "失望"),
+    ("sw", "失望"),
+    ("shf", "十分"),
+    ("sf", "十分"),
+    ("shy", "使用"),
+    ("sy", "使用"),
+    ("shj", "世纪"),
+    ("sj", "世纪"),
+    ("shf", "是否"),
+    ("sf", "是否"),
+    ("sh", "收"),
+    ("s", "收"),
+    ("shsh", "收拾"),
+    ("shs", "收拾"),
+    ("ssh", "收拾"),
+    ("ss", "收拾"),
+    ("shd", "首都"),
+    ("sd", "首都"),
+    ("shbl", "受不了"),
+    ("sbl", "受不了"),
+    ("sh", "输"),
+    ("s", "输"),
+    ("shl", "数量"),
+    ("sl", "数量"),
+    ("shx", "顺序"),
+    ("sx", "顺序"),
+    ("s", "死"),
+    ("sd", "速度"),
+    ("sld", "塑料袋"),
+    ("s", "酸"),
+    ("szh", "随着"),
+    ("sz", "随着"),
+    ("sz", "孙子"),
+    ("t", "抬"),
+    ("tgq", "弹钢琴"),
+    ("t", "汤"),
+    ("t", "趟"),
+    ("tl", "讨论"),
+    ("tk", "填空"),
+    ("t", "停"),
+    ("tq", "同情"),
+    ("tsh", "同时"),
+    ("ts", "同时"),
+    ("t", "推"),
+    ("tch", "推迟"),
+    ("tc", "推迟"),
+    ("t", "脱"),
+    ("wzh", "网站"),
+    ("wz", "网站"),
+    ("wx", "危险"),
+    ("wshj", "卫生间"),
+    ("wsj", "卫生间"),
+    ("wd", "温度"),
+    ("wzh", "文章"),
+    ("wz", "文章"),
+    ("wr", "污染"),
+    ("w", "无"),
+    ("wf", "无法"),
+    ("wl", "无论"),
+    ("wh", "误会"),
+    ("x", "咸"),
+    ("xf", "相反"),
+    ("xt", "相同"),
+    ("x", "香"),
+    ("xx", "详细"),
+    ("x", "响"),
+    ("xch", "小吃"),
+    ("xc", "小吃"),
+    ("xhz", "小伙子"),
+    ("xh", "笑话"),
+    ("xf", "信封"),
+    ("xx", "信息"),
+    ("x", "行"),
+    ("x", "醒"),
+    ("xb", "性别"),
+    ("xq", "学期"),
+    ("yg", "牙膏"),
+    ("y", "呀"),
+    ("yg", "严格"),
+    ("y", "盐"),
+    ("yj", "眼镜"),
+    ("ych", "演出"),
+    ("yc", "演出"),
+    ("yy", "演员"),
+    ("ych", "养成"),
+    ("yc", "养成"),
+    ("ysh", "钥匙"),
+    ("ys", "钥匙"),
+    ("yx", "也许"),
+    ("yz", "叶子"),
+    ("y", "页"),
+    ("y", "以"),
+    ("yj", "意见"),
+    ("yq", "引起"),
+    ("y", "由"),
+    ("yj", "邮局"),
+    ("yh", "友好"),
+    ("yq", "有趣"),
+    ("ysh", "于是"),
+    ("ys", "于是"),
+    ("ymq", "羽毛球"),
+    ("yf", "语法"),
+    ("yy", "语言"),
+    ("yx", "预习"),
+    ("yl", "原谅"),
+    ("yd", "阅读"),
+    ("y", "云"),
+    ("yx", "允许"),
+    ("zzh", "杂志"),
+    ("zz", "杂志"),
+    ("z", "脏"),
+    ("zj", "增加"),
+    ("zhx", "占线"),
+    ("zx", "占线"),
+    ("zh", "照"),
+    ("z", "照"),
+    ("zhl", "整理"),
+    ("zl", "整理"),
+    ("zhch", "正常"),
+    ("zhc", "正常"),
+    ("zch", "正常"),
+    ("zc", "正常"),
+    ("zh", "之"),
+    ("z", "之"),
+    ("zhj", "直接"),
+    ("zj", "直接"),
+    ("zhh", "只好"),
+    ("zh", "只好"),
+    ("zhd", "纸袋"),
+    ("zd", "纸袋"),
+    ("zh", "重"),
+    ("z", "重"),
+    ("zhd", "重点"),
+    ("zd", "重点"),
+    ("zhsh", "重视"),
+    ("zhs", "重视"),
+    ("zsh", "重视"),
+    ("zs", "重视"),
+    ("zhh", "祝贺"),
+    ("zh", "祝贺"),
+    ("zhm", "著名"),
+    ("zm", "著名"),
+    ("zh", "转"),
+    ("z", "转"),
+    ("zhq", "准确"),
+    ("zq", "准确"),
+    ("zx", "仔细"),
+    ("zx", "自信"),
+    ("z", "租"),
+    ("zzh", "尊重"),
+    ("zz", "尊重"),
+    ("zy", "左右"),
+    ("zy", "作用"),
+    ("zzh", "作者"),
+    ("zz", "作者"),
+    ("z", "座"),
+    ("zw", "座位"),
+    ("chch", "长城"),
+    ("chc", "长城"),
+    ("cch", "长城"),
+    ("cc", "长城"),
+    ("chj", "长江"),
+    ("cj", "长江"),
+    ("yzh", "亚洲"),
+    ("yz", "亚洲"),
+    ("adsh", "爱迪生"),
+    ("ads", "爱迪生"),
+    ("l", "林"),
+    ("mk", "马克"),
+    ("shh", "上海"),
+    ("sh", "上海"),
+    ("sy", "孙月"),
+    ("xy", "小雨"),
+    ("b", "并"),
+    ("hyk", "会员卡"),
+    ("j", "即"),
+    ("lx", "流血"),
+    ("xx", "星星"),
+    ("zhl", "制冷"),
+    ("zl", "制冷"),
+    ("b", "保"),
+    ("bsh", "笔试"),
+    ("bs", "笔试"),
+    ("bt", "不同"),
+    ("c", "餐"),
+    ("chq", "长期"),
+    ("cq", "长期"),
+    ("ch", "成"),
+    ("c", "成"),
+    ("ch", "处"),
+    ("c", "处"),
+    ("ch", "窗"),
+    ("c", "窗"),
+    ("dd", "得到"),
+    ("d", "堵"),
+    ("fm", "父母"),
+    ("gd", "感到"),
+    ("g", "购"),
+    ("gm", "购买"),
+    ("h", "货"),
+    ("j", "加"),
+    ("jq", "价钱"),
+    ("j", "奖"),
+    ("j", "街"),
+    ("m", "美"),
+    ("msh", "面试"),
+    ("ms", "面试"),
+    ("nx", "女性"),
+    ("pt", "普通"),
+    ("shch", "商场"),
+    ("shc", "商场"),
+    ("sch", "商场"),
+    ("sc", "商场"),
+    ("shb", "身边"),
+    ("sb", "身边"),
+    ("tyg", "体育馆"),
+    ("xpy", "小朋友"),
+    ("xy", "校园"),
+    ("xxg", "新鲜感"),
+    ("yg", "有关"),
+    ("z", "咱"),
+    ("zh", "折"),
+    ("z", "折"),
+    ("zh", "纸"),
+    ("z", "纸"),
+    ("zh", "祝"),
+    ("z", "祝"),
+    ("zk", "做客"),
+    ("chjdq", "长江大桥"),
+    ("cjdq", "长江大桥"),
+    ("gdsh", "广东省"),
+    ("gds", "广东省"),
+    ("lyetj", "六一儿童节"),
+    ("shdjch", "首都机场"),
+    ("shdjc", "首都机场"),
+    ("sdjch", "首都机场"),
+    ("sdjc", "首都机场"),
+    ("xsh", "香山"),
+    ("xs", "香山"),
+    ("g", "怪"),
+    ("mry", "美人鱼"),
+    ("shbgb", "事半功倍"),
+    ("sbgb", "事半功倍"),
+    ("wd", "舞蹈"),
+    ("bj", "笔记"),
+    ("b", "表"),
+    ("b", "部"),
+    ("chy", "春游"),
+    ("cy", "春游"),
+    ("d", "袋"),
+    ("dz", "袋子"),
+    ("g", "改"),
+    ("gzh", "关注"),
+    ("gz", "关注"),
+    ("h", "海"),
+    ("hb", "环保"),
+    ("jl", "极了"),
+    ("jy", "加油"),
+    ("j", "减"),
+    ("jq", "减轻"),
+    ("kj", "科技"),
+    ("lj", "垃圾"),
+    ("lfd", "理发店"),
+    ("lz", "例子"),
+    ("lch", "凉茶"),
+    ("lc", "凉茶"),
+    ("l", "另"),
+    ("mh", "美好"),
+    ("n", "暖"),
+    ("qt", "奇特"),
+    ("qw", "气温"),
+    ("q", "全"),
+    ("t", "填"),
+    ("ty", "同样"),
+    ("xq", "小区"),
+    ("x", "信"),
+    ("xq", "兴趣"),
+    ("ysh", "牙刷"),
+    ("ys", "牙刷"),
+    ("yx", "有效"),
+    ("yx", "有限"),
+    ("y", "约"),
+    ("z", "增"),
+    ("zzh", "增长"),
+    ("zz", "增长"),
+    ("ah", "爱护"),
+    ("aw", "安慰"),
+    ("azh", "安装"),
+    ("az", "安装"),
+    ("aa", "暗暗"),
+    ("ay", "熬夜"),
+    ("b", "摆"),
+    ("by", "半夜"),
+    ("bw", "傍晚"),
+    ("bk", "包括"),
+    ("bb", "宝贝"),
+    ("bg", "宝贵"),
+    ("bc", "保存"),
+    ("bd", "报道"),
+    ("by", "抱怨"),
+    ("b", "背"),
+    ("bj", "背景"),
+    ("bz", "被子"),
+    ("bl", "本领"),
+    ("bc", "彼此"),
+    ("by", "必要"),
+    ("bj", "毕竟"),
+    ("bm", "避免"),
+    ("bp", "鞭炮"),
+    ("b", "便"),
+    ("bl", "辩论"),
+    ("bd", "表达"),
+    ("bm", "表面"),
+    ("bm", "表明"),
+    ("bq", "表情"),
+    ("bx", "表现"),
+    ("bf", "播放"),
+    ("bz", "脖子"),
+    ("bnf", "不耐烦"),
+    ("byj", "不要紧"),
+    ("bdl", "不得了"),
+    ("bx", "不行"),
+    ("bz", "不足"),
+    ("b", "布"),
+    ("cch", "财产"),
+    ("cc", "财产"),
+    ("cq", "采取"),
+    ("cy", "采用"),
+    ("ch", "彩虹"),
+    ("c", "踩"),
+    ("cy", "参与"),
+    ("ck", "惭愧"),
+    ("dc", "躲藏"),
+    ("cch", "操场"),
+    ("cc", "操场"),
+    ("cj", "曾经"),
+    ("chj", "差距"),
+    ("cj", "差距"),
+    ("chy", "差异"),
+    ("cy", "差异"),
+    ("ch", "插"),
+    ("c", "插"),
+    ("chp", "产品"),
+    ("cp", "产品"),
+    ("chsh", "产生"),
+    ("chs", "产生"),
+    ("csh", "产生"),
+    ("cs", "产生"),
+    ("cht", "长途"),
+    ("ct", "长途"),
+    ("ch", "吵"),
+    ("c", "吵"),
+    ("chj", "吵架"),
+    ("cj", "吵架"),
+    ("ch", "称"),
+    ("c", "称"),
+    ("chg", "成果"),
+    ("cg", "成果"),
+    ("chj", "成就"),
+    ("cj", "成就"),
+    ("chsh", "成熟"),
+    ("chs", "成熟"),
+    ("csh", "成熟"),
+    ("cs", "成熟"),
+    ("chy", "成语"),
+    ("cy", "成语"),
+    ("chk", "诚恳"),
+    ("ck", "诚恳"),
+    ("chd", "程度"),
+    ("cd", "程度"),
+    ("chk", "吃亏"),
+    ("ck", "吃亏"),
+    ("chz", "迟早"),
+    ("cz", "迟早"),
+    ("chx", "持续"),
+    ("cx", "持续"),
+    ("ch", "冲"),
+    ("c", "冲"),
+    ("chf", "充分"),
+    ("cf", "充分"),
+    ("chm", "充满"),
+    ("cm", "充满"),
+    ("chf", "重复"),
+    ("cf", "重复"),
+    ("chw", "宠物"),
+    ("cw", "宠物"),
+    ("chx", "抽象"),
+    ("cx", "抽象"),
+    ("chl", "丑陋"),
+    ("cl", "丑陋"),
+    ("chz", "出自"),
+    ("cz", "出自"),
+    ("ch", "除"),
+    ("c", "除"),
+    ("chx", "除夕"),
+    ("cx", "除夕"),
+    ("chsh", "传说"),
+    ("chs", "传说"),
+    ("csh", "传说"),
+    ("cs", "传说"),
+    ("cht", "传统"),
+    ("ct", "传统"),
+    ("chl", "窗帘"),
+    ("cl", "窗帘"),
+    ("chz", "创造"),
+    ("cz", "创造"),
+    ("ch", "词汇"),
+    ("czh", "辞职"),
+    ("cz", "辞职"),
+    ("cw", "此外"),
+    ("cq", "从前"),
+    ("c", "催"),
+    ("cz", "存在"),
+    ("csh", "措施"),
+    ("cs", "措施"),
+    ("dd", "达到"),
+    ("dg", "打工"),
+    ("djd", "打交道"),
+    ("dt", "打听"),
+    ("df", "大方"),
+    ("d", "呆"),
+    ("db", "代表"),
+    ("dy", "待遇"),
+    ("dxg", "胆小鬼"),
+    ("dd", "当地"),
+    ("d", "挡"),
+    ("dy", "导演"),
+    ("dzh", "导致"),
+    ("dz", "导致"),
+    ("dl", "道理"),
+    ("dd", "等待"),
+    ("dy", "等于"),
+    ("dl", "低落"),
+    ("dr", "敌人"),
+    ("dd", "地道"),
+    ("dq", "地区"),
+    ("dw", "地位"),
+    ("d", "递"),
+    ("dx", "点心"),
+    ("dt", "电台"),
+    ("d", "钓"),
+    ("d", "顶"),
+    ("d", "冻"),
+    ("d", "洞"),
+    ("d", "逗"),
+    ("dl", "独立"),
+    ("dt", "独特"),
+    ("dg", "度过"),
+    ("d", "断"),
+    ("ddxx", "断断续续"),
+    ("db", "对比"),
+    ("df", "对方"),
+    ("d", "蹲"),
+    ("d", "顿"),
+    ("dk", "多亏"),
+    ("fch", "发愁"),
+    ("fc", "发愁"),
+    ("fd", "发达"),
+    ("fe", "反而"),
+    ("fy", "反应"),
+    ("fy", "反映"),
+    ("f", "方"),
+    ("fsh", "方式"),
+    ("fs", "方式"),
+    ("ff", "仿佛"),
+    ("f", "非"),
+    ("fb", "分别"),
+    ("fb", "分布"),
+    ("fx", "分析"),
+    ("ff", "纷纷"),
+    ("fs", "风俗"),
+    ("f", "疯"),
+    ("fc", "讽刺"),
+    ("f", "扶"),
+    ("fzh", "服装"),
+    ("fz", "服装"),
+    ("f", "幅"),
+    ("gj", "改进"),
+    ("gj", "赶紧"),
+    ("gj", "感激"),
+    ("gsh", "感受"),
+    ("gs", "感受"),
+    ("gh", "干活儿"),
+    ("ghe", "干活儿"),
+    ("gd", "高档"),
+    ("gj", "高级"),
+    ("gb", "告别"),
+    ("gw", "格外"),
+    ("gb", "个别"),
+    ("gr", "个人"),
+    ("gz", "各自"),
+    ("g", "根"),
+    ("gchsh", "工程师"),
+    ("gchs", "工程师"),
+    ("gcsh", "工程师"),
+    ("gcs", "工程师"),
+    ("gj", "工具"),
+    ("gr", "工人"),
+    ("gy", "公元"),
+    ("gn", "功能"),
+    ("gg", "姑姑"),
+    ("gn", "姑娘"),
+    ("gd", "古代"),
+    ("gd", "古典"),
+    ("gt", "骨头"),
+    ("gd", "固定"),
+    ("gb", "关闭"),
+    ("g", "官"),
+    ("gl", "光临"),
+    ("gx", "光线"),
+    ("gd", "广大"),
+    ("gf", "广泛"),
+    ("gl", "规律"),
+    ("gz", "规则"),
+    ("g", "锅"),
+    ("gr", "果然"),
+    ("gsh", "果实"),
+    ("gs", "果实"),
+    ("h", "哈"),
+    ("hx", "海鲜"),
+    ("h", "喊"),
+    ("hh", "豪华"),
+    ("hk", "好客"),
+    ("hq", "好奇"),
+    ("hz", "合作"),
+    ("hb", "何必"),
+    ("hk", "何况"),
+    ("hz", "猴子"),
+    ("hb", "后背"),
+    ("hphy", "呼朋唤友"),
+    ("hx", "呼吸"),
+    ("hr", "忽然"),
+    ("hsh", "忽视"),
+    ("hs", "忽视"),
+    ("hsh", "胡说"),
+    ("hs", "胡说"),
+    ("ht", "胡同"),
+    ("h", "壶"),
+    ("ht", "糊涂"),
+    ("hy", "华裔"),
+    ("h", "滑"),
+    ("ht", "话题"),
+    ("h", "慌"),
+    ("hzh", "慌张"),
+    ("hz", "慌张"),
+    ("hch", "灰尘"),
+    ("hc", "灰尘"),
+    ("h", "挥"),
+    ("hl", "婚礼"),
+    ("hy", "婚姻"),
+    ("hy", "活跃"),
+    ("hb", "伙伴"),
+    ("hx", "或许"),
+    ("j", "击"),
+    ("jq", "机器"),
+    ("jl", "激烈"),
+    ("jq", "极其"),
+    ("jzh", "集中"),
+    ("jz", "集中"),
+    ("js", "计算"),
+    ("jy", "记忆"),
+    ("jn", "纪念"),
+    ("j", "系"),
+    ("jt", "家庭"),
+    ("j", "甲"),
+    ("jsh", "驾驶"),
+    ("js", "驾驶"),
+    ("jj", "坚决"),
+    ("jb", "肩膀"),
+    ("jk", "艰苦"),
+    ("jzh", "建筑"),
+    ("jz", "建筑"),
+    ("jj", "讲究"),
+    ("jj", "交际"),
+    ("jd", "角度"),
+    ("jsh", "结实"),
+    ("js", "结实"),
+    ("jd", "接待"),
+    ("jsh", "节省"),
+    ("js", "节省"),
+    ("jsh", "节食"),
+    ("js", "节食"),
+    ("jg", "结构"),
+    ("jl", "结论"),
+    ("j", "戒"),
+    ("jk", "借口"),
+    ("jsh", "金属"),
+    ("js", "金属"),
+    ("j", "尽"),
+    ("jl", "尽力"),
+    ("jd", "近代"),
+    ("jy", "经营"),
+    ("jsh", "精神"),
+    ("js", "精神"),
+    ("j", "救"),
+    ("jj", "舅舅"),
+    ("jr", "居然"),
+    ("jb", "具备"),
+    ("jx", "决心"),
+    ("jd", "绝对"),
+    ("jsh", "军事"),
+    ("js", "军事"),
+    ("kf", "开发"),
+    ("kmsh", "开幕式"),
+    ("kms", "开幕式"),
+    ("k", "靠"),
+    ("k", "颗"),
+    ("kj", "可见"),
+    ("kk", "可靠"),
+    ("kj", "空间"),
+    ("l", "啦"),
+    ("l", "拦"),
+    ("l", "烂"),
+    ("ld", "劳动"),
+    ("lbx", "老百姓"),
+    ("lp", "老婆"),
+    ("lsh", "老实"),
+    ("ls", "老实"),
+    ("ll", "姥姥"),
+    ("l", "雷"),
+    ("lx", "类型"),
+    ("lh", "离婚"),
+    ("ll", "理论"),
+    ("lj", "立即"),
+    ("lk", "立刻"),
+    ("lm", "连忙"),
+    ("lx", "连续"),
+    ("lh", "联合"),
+    ("lh", "良好"),
+    ("lsh", "粮食"),
+    ("ls", "粮食"),
+    ("l", "亮"),
+    ("l", "临"),
+    ("lsh", "临时"),
+    ("ls", "临时"),
+    ("lh", "灵活"),
+    ("l", "铃"),
+    ("l", "令"),
+    ("lch", "流传"),
+    ("lc", "流传"),
+    ("ll", "流泪"),
+    ("l", "龙"),
+    ("l", "漏"),
+    ("ld", "陆地"),
+    ("l", "轮"),
+    ("ll", "轮流"),
+    ("m", "骂"),
+    ("mt", "馒头"),
+    ("mz", "满足"),
+    ("mb", "毛病"),
+    ("md", "矛盾"),
+    ("msh", "美术"),
+    ("ms", "美术"),
+    ("ml", "魅力"),
+    ("mt", "苗条"),
+    ("mx", "描写"),
+    ("mm", "明明"),
+    ("mx", "明显"),
+    ("mx", "明星"),
+    ("ml", "命令"),
+    ("m", "摸"),
+    ("mf", "模仿"),
+    ("mq", "目前"),
+    ("np", "哪怕"),
+    ("nd", "脑袋"),
+    ("nzh", "闹钟"),
+    ("nz", "闹钟"),
+    ("nk", "宁可"),
+    ("nc", "农村"),
+    ("nm", "农民"),
+    ("n", "浓"),
+    ("nsh", "女士"),
+    ("ns", "女士"),
+    ("p", "拍"),
+    ("p", "派"),
+    ("pw", "盼望"),
+    ("p", "盆"),
+    ("pz", "盆子"),
+    ("p", "碰"),
+    ("p", "匹"),
+    ("pm", "片面"),
+    ("p", "飘"),
+    ("p", "平"),
+    ("pch", "平常"),
+    ("pc", "平常"),
+    ("pj", "平静"),
+    ("pj", "平均"),
+    ("pj", "评价"),
+    ("qy", "其余"),
+    ("qj", "奇迹"),
+    ("qy", "企业"),
+    ("qf", "启发"),
+    ("qf", "气氛"),
+    ("q", "签"),
+    ("q", "浅"),
+    ("ql", "强烈"),
+    ("q", "墙"),
+    ("q", "抢"),
+    ("qq", "悄悄"),
+    ("q", "瞧"),
+    ("qq", "亲切"),
+    ("qz", "亲自"),
+    ("qf", "勤奋"),
+    ("qsh", "轻视"),
+    ("qs", "轻视"),
+    ("qj", "情景"),
+    ("qx", "情绪"),
+    ("qq", "请求"),
+    ("qm", "球迷"),
+    ("qsh", "趋势"),
+    ("qs", "趋势"),
+    ("qsh", "去世"),
+    ("qs", "去世"),
+    ("qf", "缺乏"),
+    ("qd", "确定"),
+    ("qr", "确认"),
+    ("q", "群"),
+    ("rc", "人才"),
+    ("rl", "人类"),
+    ("rm", "人民"),
+    ("rsh", "人生"),
+    ("rs", "人生"),
+    ("ry", "人员"),
+    ("rch", "日常"),
+    ("rc", "日常"),
+    ("rz", "日子"),
+    ("rh", "如何"),
+    ("rj", "如今"),
+    ("r", "弱"),
+    ("s", "洒"),
+    ("sh", "杀"),
+    ("s", "杀"),
+    ("sh", "晒"),
+    ("s", "晒"),
+    ("shd", "闪电"),
+    ("sd", "闪电"),
+    ("shz", "扇子"),
+    ("sz", "扇子"),
+    ("shl", "善良"),
+    ("sl", "善良"),
+    ("shy", "善于"),
+    ("sy", "善于"),
+    ("shh", "伤害"),
+    ("sh", "伤害"),
+    ("shd", "上当"),
+    ("sd", "上当"),
+    ("shj", "设计"),
+    ("sj", "设计"),
+    ("sh", "射"),
+    ("s", "射"),
+    ("shj", "射击"),
+    ("sj", "射击"),
+    ("shysh", "摄影师"),
+    ("shys", "摄影师"),
+    ("sysh", "摄影师"),
+    ("sys", "摄影师"),
+    ("sh", "伸"),
+    ("s", "伸"),
+    ("shf", "身份"),
+    ("sf", "身份"),
+    ("shk", "深刻"),
+    ("sk", "深刻"),
+    ("shm", "神秘"),
+    ("sm", "神秘"),
+    ("sh", "升"),
+    ("s", "升"),
+    ("shz", "绳子"),
+    ("sz", "绳子"),
+    ("shl", "胜利"),
+    ("sl", "胜利"),
+    ("shm", "失眠"),
+    ("sm", "失眠"),
+    ("sh", "诗"),
+    ("s", "诗"),
+    ("sht", "石头"),
+    ("st", "石头"),
+    ("shd", "时代"),
+    ("sd", "时代"),
+    ("shk", "时刻"),
+    ("sk", "时刻"),
+    ("shq", "时期"),
+    ("sq", "时期"),
+    ("shsh", "时尚"),
+    ("shs", "时尚"),
+    ("ssh", "时尚"),
+    ("ss", "时尚"),
+    ("shx", "实现"),
+    ("sx", "实现"),
+    ("shy", "实验"),
+    ("sy", "实验"),
+    ("shw", "食物"),
+    ("sw", "食物"),
+    ("shzh", "始终"),
+    ("shz", "始终"),
+    ("szh", "始终"),
+    ("sz", "始终"),
+    ("shb", "士兵"),
+    ("sb", "士兵"),
+    ("shch", "市场"),
+    ("shc", "市场"),
+    ("sch", "市场"),
+    ("sc", "市场"),
+    ("shd", "似的"),
+    ("sd", "似的"),
+    ("shsh", "事实"),
+    ("shs", "事实"),
+    ("ssh", "事实"),
+    ("ss", "事实"),
+    ("shw", "事物"),
+    ("sw", "事物"),
+    ("shzh", "手指"),
+    ("shz", "手指"),
+    ("szh", "手指"),
+    ("sz", "手指"),
+    ("shs", "守岁"),
+    ("ss", "守岁"),
+    ("sh", "首"),
+    ("s", "首"),
+    ("shsh", "舒适"),
+    ("shs", "舒适"),
+    ("ssh", "舒适"),
+    ("ss", "舒适"),
+    ("shc", "蔬菜"),
+    ("sc", "蔬菜"),
+    ("sh", "数"),
+    ("s", "数"),
+    ("sh", "甩"),
+    ("s", "甩"),
+    ("shbd", "说不定"),
+    ("sbd", "说不定"),
+    ("shf", "说服"),
+    ("sf", "说服"),
+    ("sh", "似乎"),
+    ("s", "算"),
+    ("ssh", "随时"),
+    ("ss", "随时"),
+    ("ssh", "随手"),
+    ("ss", "随手"),
+    ("s", "碎"),
+    ("s", "所"),
+    ("sw", "所谓"),
+    ("s", "锁"),
+    ("tj", "台阶"),
+    ("tt", "太太"),
+    ("t", "逃"),
+    ("t", "桃"),
+    ("tq", "淘气"),
+    ("t", "套"),
+    ("th", "体会"),
+    ("dt", "代替"),
+    ("t", "替"),
+    ("tzh", "天真"),
+    ("tz", "天真"),
+    ("tp", "调皮"),
+    ("tzh", "调整"),
+    ("tz", "调整"),
+    ("tzh", "挑战"),
+    ("tz", "挑战"),
+    ("tch", "通常"),
+    ("tc", "通常"),
+    ("tl", "投篮"),
+    ("tr", "投入"),
+    ("tch", "突出"),
+    ("tc", "突出"),
+    ("tg", "推广"),
+    ("tj", "推荐"),
+    ("wg", "外公"),
+    ("wzh", "完整"),
+    ("wz", "完整"),
+    ("wh", "危害"),
+    ("wx", "微笑"),
+    ("wr", "围绕"),
+    ("wb", "尾巴"),
+    ("wq", "委屈"),
+    ("wl", "未来"),
+    ("wy", "位于"),
+    ("wzh", "位置"),
+    ("wz", "位置"),
+    ("w", "胃"),
+    ("wk", "胃口"),
+    ("wn", "温暖"),
+    ("wxj", "文学家"),
+    ("wz", "文字"),
+    ("wd", "稳定"),
+    ("wsh", "卧室"),
+    ("ws", "卧室"),
+    ("wz", "屋子"),
+    ("w", "屋"),
+    ("wn", "无奈"),
+    ("w", "勿"),
+    ("wzh", "物质"),
+    ("wz", "物质"),
+    ("xj", "戏剧"),
+    ("xj", "细节"),
+    ("x", "瞎"),
+    ("x", "吓"),
+    ("xd", "显得"),
+    ("xsh", "显示"),
+    ("xs", "显示"),
+    ("x", "县"),
+    ("xd", "现代"),
+    ("xsh", "现实"),
+    ("xs", "现实"),
+    ("xx", "现象"),
+    ("xzh", "限制"),
+    ("xz", "限制"),
+    ("xch", "相处"),
+    ("xc", "相处"),
+    ("xd", "相当"),
+    ("xd", "相对"),
+    ("xg", "相关"),
+    ("xsh", "享受"),
+    ("xs", "享受"),
+    ("xx", "想象"),
+    ("x", "项"),
+    ("xm", "项目"),
+    ("dx", "大象"),
+    ("x", "象"),
+    ("xf", "消费"),
+    ("xsh", "销售"),
+    ("xs", "销售"),
+    ("xsh", "孝顺"),
+    ("xs", "孝顺"),
+    ("x", "斜"),
+    ("xz", "写作"),
+    ("xl", "心理"),
+    ("xsh", "欣赏"),
+    ("xs", "欣赏"),
+    ("xw", "行为"),
+    ("xch", "形成"),
+    ("xc", "形成"),
+    ("xr", "形容"),
+    ("xsh", "形式"),
+    ("xs", "形式"),
+    ("xsh", "形势"),
+    ("xs", "形势"),
+    ("xx", "形象"),
+    ("xzh", "形状"),
+    ("xz", "形状"),
+    ("xd", "兄弟"),
+    ("xsh", "叙述"),
+    ("xs", "叙述"),
+    ("xch", "宣传"),
+    ("xc", "宣传"),
+    ("xw", "学问"),
+    ("xzh", "寻找"),
+    ("xz", "寻找"),
+    ("xw", "询问"),
+    ("xl", "训练"),
+    ("xs", "迅速"),
+    ("ych", "牙齿"),
+    ("yc", "牙齿"),
+    ("ysh", "样式"),
+    ("ys", "样式"),
+    ("y", "摇"),
+    ("y", "咬"),
+    ("yb", "要不"),
+    ("yw", "业务"),
+    ("yy", "业余"),
+    ("y", "夜"),
+    ("yr", "依然"),
+    ("ybz", "一辈子"),
+    ("yz", "一再"),
+    ("yd", "移动"),
+    ("ym", "移民"),
+    ("y", "乙"),
+    ("yj", "以及"),
+    ("yl", "以来"),
+    ("y", "亿"),
+    ("yw", "意外"),
+    ("yy", "意义"),
+    ("yz", "银子"),
+    ("y", "银"),
+    ("yj", "英俊"),
+    ("yx", "英雄"),
+    ("yy", "营养"),
+    ("yz", "影子"),
+    ("yy", "应用"),
+    ("y", "硬"),
+    ("yb", "拥抱"),
+    ("yt", "用途"),
+    ("ym", "优美"),
+    ("yj", "悠久"),
+    ("yey", "幼儿园"),
+    ("y", "于"),
+    ("yw", "愿望"),
+    ("y", "晕"),
+    ("yy", "运用"),
+    ("zh", "灾害"),
+    ("zs", "再三"),
+    ("zm", "赞美"),
+    ("zg", "糟糕"),
+    ("zch", "造成"),
+    ("zc", "造成"),
+    ("z", "则"),
+    ("zh", "占"),
+    ("z", "占"),
+    ("zhzh", "战争"),
+    ("zhz", "战争"),
+    ("zzh", "战争"),
+    ("zz", "战争"),
+    ("zhb", "长辈"),
+    ("zb", "长辈"),
+    ("zhd", "招待"),
+    ("zd", "招待"),
+    ("zhh", "着火"),
+    ("zh", "着火"),
+    ("zhk", "召开"),
+    ("zk", "召开"),
+    ("zhd", "针对"),
+    ("zd", "针对"),
+    ("zh", "阵"),
+    ("z", "阵"),
+    ("zhl", "争论"),
+    ("zl", "争论"),
+    ("zhq", "争取"),
+    ("zq", "争取"),
+    ("zhg", "整个"),
+    ("zg", "整个"),
+    ("zht", "整体"),
+    ("zt", "整体"),
+    ("zhj", "证据"),
+    ("zj", "证据"),
+    ("zh", "挣"),
+    ("z", "挣"),
+    ("zh", "支"),
+    ("z", "支"),
+    ("zhj", "至今"),
+    ("zj", "至今"),
+    ("zhyzh", "志愿者"),
+    ("zhyz", "志愿者"),
+    ("zyzh", "志愿者"),
+    ("zyz", "志愿者"),
+    ("zhz", "制造"),
+    ("zz", "制造"),
+    ("zhl", "治疗"),
+    ("zl", "治疗"),
+    ("zh", "治"),
+    ("z", "治"),
+    ("zhh", "智慧"),
+    ("zh", "智慧"),
+    ("zhx", "中心"),
+    ("zx", "中心"),
+    ("zhd", "重大"),
+    ("zd", "重大"),
+    ("zh", "猪"),
+    ("z", "猪"),
+    ("zhz", "竹子"),
+    ("zz", "竹子"),
+    ("zhj", "逐渐"),
+    ("zj", "逐渐"),
+    ("zhch", "主持"),
+    ("zhc", "主持"),
+    ("zch", "主持"),
+    ("zc", "主持"),
+    ("zhd", "主动"),
+    ("zd", "主动"),
+    ("zhr", "主人"),
+    ("zr", "主人"),
+    ("zhc", "注册"),
+    ("zc", "注册"),
+    ("zhj", "专家"),
+    ("zj", "专家"),
+    ("zh", "装"),
+    ("z", "装"),
+    ("zhx", "装修"),
+    ("zx", "装修"),
+    ("zht", "状态"),
+    ("zt", "状态"),
+    ("zh", "撞"),
+    ("z", "撞"),
+    ("zh", "追"),
+    ("z", "追"),
+    ("zsh", "姿势"),
+    ("zs", "姿势"),
+    ("zg", "资格"),
+    ("zl", "资料"),
+    ("zsh", "自杀"),
+    ("zs", "自杀"),
+    ("zy", "自由"),
+    ("zh", "综合"),
+    ("zc", "总裁"),
+    ("zg", "总共"),
+    ("zzh", "阻止"),
+    ("zz", "阻止"),
+    ("z", "组"),
+    ("zch", "组成"),
+    ("zc", "组成"),
+    ("zh", "组合"),
+    ("z", "醉"),
+    ("zp", "作品"),
+    ("zw", "作为"),
+    ("zzh", "作战"),
+    ("zz", "作战"),
+    ("ax", "爱惜"),
+    ("ax", "爱心"),
+    ("bw", "把握"),
+    ("bl", "办理"),
+    ("bh", "包含"),
+    ("b", "薄"),
+    ("bl", "保留"),
+    ("bx", "保险"),
+    ("bg", "报告"),
+    ("bsh", "报社"),
+    ("bs", "报社"),
+    ("bsh", "悲伤"),
+    ("bs", "悲伤"),
+    ("b", "悲"),
+    ("bk", "本科"),
+    ("bzh", "本质"),
+    ("bz", "本质"),
+    ("bl", "比例"),
+    ("br", "必然"),
+    ("bj", "编辑"),
+    ("bzh", "标志"),
+    ("bz", "标志"),
+    ("bjl", "冰激凌"),
+    ("bl", "玻璃"),
+    ("bd", "不断"),
+    ("bjd", "不见得"),
+    ("bch", "补充"),
+    ("bc", "补充"),
+    ("br", "不然"),
+    ("br", "不如"),
+    ("bzh", "步骤"),
+    ("bz", "步骤"),
+    ("bm", "部门"),
+    ("cf", "采访"),
+    ("ck", "参考"),
+    ("cx", "操心"),
+    ("c", "册"),
+    ("cy", "测验"),
+    ("chsh", "常识"),
+    ("chs", "常识"),
+    ("csh", "常识"),
+    ("cs", "常识"),
+    ("ch", "朝"),
+    ("c", "朝"),
+    ("chsh", "潮湿"),
+    ("chs", "潮湿"),
+    ("csh", "潮湿"),
+    ("cs", "潮湿"),
+    ("ch", "炒"),
+    ("c", "炒"),
+    ("chd", "彻底"),
+    ("cd", "彻底"),
+    ("chm", "沉默"),
+    ("cm", "沉默"),
+    ("ch", "趁"),
+    ("c", "趁"),
+    ("chh", "称呼"),
+    ("ch", "称呼"),
+    ("chz", "称赞"),
+    ("cz", "称赞"),
+    ("chf", "成分"),
+    ("cf", "成分"),
+    ("chl", "成立"),
+    ("cl", "成立"),
+    ("chr", "成人"),
+    ("cr", "成人"),
+    ("chzh", "成长"),
+    ("chz", "成长"),
+    ("czh", "成长"),
+    ("cz", "成长"),
+    ("chd", "承担"),
+    ("cd", "承担"),
+    ("chr", "承认"),
+    ("cr", "承认"),
+    ("chsh", "承受"),
+    ("chs", "承受"),
+    ("csh", "承受"),
+    ("cs", "承受"),
+    ("cht", "池塘"),
+    ("ct", "池塘"),
+    ("chb", "翅膀"),
+    ("cb", "翅膀"),
+    ("chb", "出版"),
+    ("cb", "出版"),
+    ("chs", "出色"),
+    ("cs", "出色"),
+    ("chsh", "出示"),
+    ("chs", "出示"),
+    ("csh", "出示"),
+    ("cs", "出示"),
+    ("chx", "出席"),
+    ("cx", "出席"),
+    ("chjzhx", "初级中学"),
+    ("chjzx", "初级中学"),
+    ("cjzhx", "初级中学"),
+    ("cjzx", "初级中学"),
+    ("chzh", "初中"),
+    ("chz", "初中"),
+    ("czh", "初中"),
+    ("cz", "初中"),
+    ("chf", "除非"),
+    ("cf", "除非"),
+    ("chl", "处理"),
+    ("cl", "处理"),
+    ("ch", "闯"),
+    ("c", "闯"),
+    ("ch", "吹"),
+    ("c", "吹"),
+    ("cy", "次要"),
+    ("cj", "刺激"),
+    ("cc", "从此"),
+    ("csh", "从事"),
+    ("cs", "从事"),
+    ("cc", "粗糙"),
+    ("cj", "促进"),
+    ("csh", "促使"),
+    ("cs", "促使"),
+    ("c", "醋"),
+    ("dy", "答应"),
+    ("dsh", "大厦"),
+    ("ds", "大厦"),
+    ("dx", "大型"),
+    ("dr", "担任"),
+    ("dch", "单纯"),
+    ("dc", "单纯"),
+    ("dd", "单调"),
+    ("dd", "单独"),
+    ("dw", "单位"),
+    ("dw", "耽误"),
+    ("dy", "岛屿"),
+    ("dm", "倒霉"),
+    ("dd", "到达"),
+    ("dd", "道德"),
+    ("dj", "登记"),
+    ("dq", "的确"),
+    ("dl", "地理"),
+    ("dzh", "地震"),
+    ("dz", "地震"),
+    ("dch", "电池"),
+    ("dc", "电池"),
+    ("dhp", "动画片"),
+    ("d", "堆"),
+    ("dd", "对待"),
+    ("dsh", "对手"),
+    ("ds", "对手"),
+    ("dx", "对象"),
+    ("d", "吨"),
+    ("d", "朵"),
+    ("el", "恶劣"),
+    ("fd", "发抖"),
+    ("fh", "发挥"),
+    ("fy", "发言"),
+    ("f", "翻"),
+    ("fr", "繁荣"),
+    ("ff", "反复"),
+    ("fzh", "反正"),
+    ("fz", "反正"),
+    ("fw", "范围"),
+    ("fz", "肥皂"),
+    ("f", "废"),
+    ("fd", "奋斗"),
+    ("fj", "风景"),
+    ("fx", "风险"),
+    ("fr", "否认"),
+    ("fn", "妇女"),
+    ("gg", "改革"),
+    ("gsh", "改善"),
+    ("gs", "改善"),
+    ("g", "盖"),
+    ("gk", "概括"),
+    ("gn", "概念"),
+    ("gc", "干脆"),
+    ("gz", "干燥"),
+    ("gk", "赶快"),
+    ("gx", "感想"),
+    ("gt", "钢铁"),
+    ("g", "搞"),
+    ("gx", "个性"),
+    ("gb", "根本"),
+    ("gch", "工厂"),
+    ("gc", "工厂"),
+    ("gy", "工业"),
+    ("gb", "公布"),
+    ("gk", "公开"),
+    ("gy", "公寓"),
+    ("gx", "贡献"),
+    ("gt", "沟通"),
+    ("gch", "构成"),
+    ("gc", "构成"),
+    ("gw", "鼓舞"),
+    ("gzh", "鼓掌"),
+    ("gz", "鼓掌"),
+    ("g", "乖"),
+    ("gch", "观察"),
+    ("gc", "观察"),
+    ("gd", "观点"),
+    ("gn", "观念"),
+    ("gz", "馆子"),
+    ("gj", "冠军"),
+    ("gh", "光滑"),
+    ("gm", "光明"),
+    ("gch", "广场"),
+    ("gc", "广场"),
+    ("gn", "归纳"),
+    ("gj", "规矩"),
+    ("g", "滚"),
+    ("gf", "过分"),
+    ("hy", "行业"),
+    ("hl", "合理"),
+    ("hp", "和平"),
+    ("hx", "核心"),
+    ("hg", "后果"),
+    ("hd", "蝴蝶"),
+    ("hn", "怀念"),
+    ("hj", "缓解"),
+    ("hx", "幻想"),
+    ("h", "灰"),
+    ("hx", "灰心"),
+    ("hf", "恢复"),
+    ("hch", "火柴"),
+    ("hc", "火柴"),
+    ("jr", "肌肉"),
+    ("jb", "基本"),
+    ("jg", "及格"),
+    ("j", "挤"),
+    ("jl", "记录"),
+    ("jl", "纪录"),
+    ("jl", "纪律"),
+    ("jm", "寂寞"),
+    ("jw", "家务"),
+    ("jx", "家乡"),
+    ("jr", "假如"),
+    ("jsh", "假设"),
+    ("js", "假设"),
+    ("jzh", "价值"),
+    ("jz", "价值"),
+    ("jj", "艰巨"),
+    ("j", "捡"),
+    ("jd", "剪刀"),
+    ("jl", "简历"),
+    ("jzh", "简直"),
+    ("jz", "简直"),
+    ("jl", "建立"),
+    ("jsh", "建设"),
+    ("js", "建设"),
+    ("jsh", "健身"),
+    ("js", "健身"),
+    ("jz", "讲座"),
+    ("jy", "酱油"),
+    ("jh", "交换"),
+    ("jw", "交往"),
+    ("j", "浇"),
+    ("jh", "狡猾"),
+    ("jl", "教练"),
+    ("jx", "教训"),
+    ("jd", "阶段"),
+    ("jch", "接触"),
+    ("jc", "接触"),
+    ("jj", "接近"),
+    ("jh", "结合"),
+    ("j", "届"),
+    ("jk", "尽快"),
+    ("jl", "尽量"),
+    ("jj", "紧急"),
+    ("jsh", "谨慎"),
+    ("js", "谨慎"),
+    ("jb", "进步"),
+    ("jd", "经典"),
+    ("jsh", "经商"),
+    ("js", "经商"),
+    ("jb", "酒吧"),
+    ("jz", "橘子"),
+    ("jd", "巨大"),
+    ("jt", "具体"),
+    ("jsh", "据说"),
+    ("js", "据说"),
+    ("js", "决赛"),
+    ("js", "角色"),
+    ("jy", "均匀"),
+    ("kf", "开放"),
+    ("ksh", "开水"),
+    ("ks", "开水"),
+    ("k", "砍"),
+    ("kbq", "看不起"),
+    ("kp", "可怕"),
+    ("kf", "克服"),
+    ("kk", "刻苦"),
+    ("kg", "客观"),
+    ("kx", "空闲"),
+    ("kzh", "控制"),
+    ("kz", "控制"),
+    ("kw", "口味"),
+    ("k", "库"),
+    ("k", "夸"),
+    ("kzh", "夸张"),
+    ("kz", "夸张"),
+    ("k", "宽"),
+    ("k", "狂"),
+    ("kch", "昆虫"),
+    ("kc", "昆虫"),
+    ("kd", "扩大"),
+    ("k", "扩"),
+    ("ld", "朗读"),
+    ("lj", "劳驾"),
+    ("lb", "老板"),
+    ("lg", "乐观"),
+    ("ld", "冷淡"),
+    ("l", "梨"),
+    ("ly", "理由"),
+    ("ll", "力量"),
+    ("lr", "利润"),
+    ("ly", "利益"),
+    ("ly", "利用"),
+    ("lbq", "了不起"),
+    ("ly", "领域"),
+    ("lx", "陆续"),
+    ("lq", "录取"),
+    ("lj", "逻辑"),
+    ("lh", "落后"),
+    ("mx", "冒险"),
+    ("mt", "媒体"),
+    ("mt", "煤炭"),
+    ("mx", "梦想"),
+    ("mm", "秘密"),
+    ("mq", "密切"),
+    ("md", "面对"),
+    ("mj", "面积"),
+    ("ml", "面临"),
+    ("mg", "敏感"),
+    ("mp", "名牌"),
+    ("mq", "明确"),
+    ("my", "命运"),
+    ("mh", "模糊"),
+    ("msh", "陌生"),
+    ("ms", "陌生"),
+    ("m", "某"),
+    ("mt", "木头"),
+    ("mb", "目标"),
+    ("nm", "难免"),
+    ("nb", "内部"),
+    ("n", "嫩"),
+    ("ny", "能源"),
+    ("n", "嗯"),
+    ("nd", "年代"),
+    ("nj", "年纪"),
+    ("n", "念"),
+    ("ny", "农业"),
+    ("or", "偶然"),
+    ("px", "培训"),
+    ("py", "培养"),
+    ("pch", "赔偿"),
+    ("pc", "赔偿"),
+    ("pf", "佩服"),
+    ("p", "批"),
+    ("pzh", "批准"),
+    ("pz", "批准"),
+    ("pl", "疲劳"),
+    ("p", "片"),
+    ("pa", "平安"),
+    ("pd", "平等"),
+    ("ph", "平衡"),
+    ("p", "凭"),
+    ("pq", "迫切"),
+    ("ph", "破坏"),
+    ("qd", "期待"),
+    ("qj", "期间"),
+    ("xq", "象棋"),
+    ("q", "棋"),
+    ("q", "起"),
+    ("qy", "汽油"),
+    ("qx", "谦虚"),
+    ("qt", "前途"),
+    ("qd", "强调"),
+    ("qm", "巧妙"),
+    ("q", "切"),
+    ("qa", "亲爱"),
+    ("q", "青"),
+    ("qshn", "青少年"),
+    ("qsn", "青少年"),
+    ("qy", "轻易"),
+    ("qd", "清淡"),
+    ("qzh", "庆祝"),
+    ("qz", "庆祝"),
+    ("qx", "取消"),
+    ("q", "圈"),
+    ("qm", "全面"),
+    ("q", "劝"),
+    ("rsh", "燃烧"),
+    ("rs", "燃烧"),
+    ("ra", "热爱"),
+    ("rl", "热烈"),
+    ("rx", "热心"),
+    ("rw", "人物"),
+    ("rbzh", "忍不住"),
+    ("rbz", "忍不住"),
+    ("rch", "日程"),
+    ("rc", "日程"),
+    ("ryp", "日用品"),
+    ("sc", "色彩"),
+    ("shm", "沙漠"),
+    ("sm", "沙漠"),
+    ("sht", "沙滩"),
+    ("st", "沙滩"),
+    ("sh", "傻"),
+    ("s", "傻"),
+    ("shp", "商品"),
+    ("sp", "商品"),
+    ("shw", "商务"),
+    ("sw", "商务"),
+    ("shy", "商业"),
+    ("sy", "商业"),
+    ("shbd", "舍不得"),
+    ("sbd", "舍不得"),
+    ("shb", "设备"),
+    ("sb", "设备"),
+    ("shsh", "设施"),
+    ("shs", "设施"),
+    ("ssh", "设施"),
+    ("ss", "设施"),
+    ("shc", "身材"),
+    ("sc", "身材"),
+    ("shch", "生产"),
+    ("shc", "生产"),
+    ("sch", "生产"),
+    ("sc", "生产"),
+    ("shzh", "生长"),
+    ("shz", "生长"),
+    ("szh", "生长"),
+    ("sz", "生长"),
+    ("shq", "失去"),
+    ("sq", "失去"),
+    ("shr", "湿润"),
+    ("sr", "湿润"),
+    ("shh", "实话"),
+    ("sh", "实话"),
+    ("shj", "实践"),
+    ("sj", "实践"),
+    ("shy", "实用"),
+    ("sy", "实用"),
+    ("shj", "使劲儿"),
+    ("sj", "使劲儿"),
+    ("shj", "使劲"),
+    ("sj", "使劲"),
+    ("shx", "事先"),
+    ("sx", "事先"),
+    ("shh", "收获"),
+    ("sh", "收获"),
+    ("shx", "手续"),
+    ("sx", "手续"),
+    ("shsh", "受伤"),
+    ("shs", "受伤"),
+    ("ssh", "受伤"),
+    ("ss", "受伤"),
+    ("sh", "受"),
+    ("s", "受"),
+    ("shz", "梳子"),
+    ("sz", "梳子"),
+    ("shr", "输入"),
+    ("sr", "输入"),
+    ("shl", "熟练"),
+    ("sl", "熟练"),
+    ("shy", "属于"),
+    ("sy", "属于"),
+    ("shj", "数据"),
+    ("sj", "数据"),
+    ("shd", "摔倒"),
+    ("sd", "摔倒"),
+    ("sh", "丝毫"),
+    ("sr", "私人"),
+    ("s", "私"),
+    ("sk", "思考"),
+    ("sx", "思想"),
+    ("ssh", "随身"),
+    ("ss", "随身"),
+    ("ssh", "损失"),
+    ("ss", "损失"),
+    ("sd", "缩短"),
+    ("t", "烫"),
+    ("tb", "逃避"),
+    ("ts", "特色"),
+    ("tsh", "特殊"),
+    ("ts", "特殊"),
+    ("tzh", "特征"),
+    ("tz", "特征"),
+    ("tch", "提倡"),
+    ("tc", "提倡"),
+    ("tw", "提问"),
+    ("tm", "题目"),
+    ("tt", "体贴"),
+    ("tx", "体现"),
+    ("ty", "体验"),
+    ("tk", "天空"),
+    ("tk", "痛苦"),
+    ("tk", "痛快"),
+    ("tz", "投资"),
+    ("tm", "透明"),
+    ("tx", "退休"),
+    ("wj", "外交"),
+    ("wm", "完美"),
+    ("wsh", "完善"),
+    ("ws", "完善"),
+    ("wy", "万一"),
+    ("wz", "王子"),
+    ("wl", "网络"),
+    ("wx", "威胁"),
+    ("wf", "违反"),
+    ("wy", "唯一"),
+    ("wb", "未必"),
+    ("wr", "温柔"),
+    ("wj", "文具"),
+    ("wm", "文明"),
+    ("w", "闻"),
+    ("w", "吻"),
+    ("wsh", "无数"),
+    ("ws", "无数"),
+    ("wsw", "无所谓"),
+    ("wl", "物理"),
+    ("xq", "吸取"),
+    ("xsh", "吸收"),
+    ("xs", "吸收"),
+    ("x", "系"),
+    ("xt", "系统"),
+    ("xz", "下载"),
+    ("xy", "鲜艳"),
+    ("xr", "显然"),
+    ("xs", "相似"),
+    ("xn", "想念"),
+    ("xj", "消极"),
+    ("xsh", "消失"),
+    ("xs", "消失"),
+    ("xl", "效率"),
+    ("x", "歇"),
+    ("xz", "心脏"),
+    ("xd", "行动"),
+    ("xr", "行人"),
+    ("xk", "幸亏"),
+    ("xy", "幸运"),
+    ("x", "胸"),
+    ("xx", "休闲"),
+    ("xx", "虚心"),
+    ("yj", "押金"),
+    ("ych", "延长"),
+    ("yc", "延长"),
+    ("ys", "严肃"),
+    ("y", "养"),
+    ("y", "腰"),
+    ("yd", "一旦"),
+    ("yl", "一律"),
+    ("yzh", "一致"),
+    ("yz", "一致"),
+    ("yh", "遗憾"),
+    ("yw", "疑问"),
+    ("yw", "义务"),
+    ("ys", "因素"),
+    ("ysh", "印刷"),
+    ("ys", "印刷"),
+    ("yj", "迎接"),
+    ("yy", "营业"),
+    ("yf", "应付"),
+    ("yj", "拥挤"),
+    ("yq", "勇气"),
+    ("yg", "用功"),
+    ("ysh", "优势"),
+    ("ys", "优势"),
+    ("yy", "犹豫"),
+    ("yzh", "油炸"),
+    ("yz", "油炸"),
+    ("yl", "游览"),
+    ("yl", "有利"),
+    ("yl", "娱乐"),
+    ("yq", "与其"),
+    ("yf", "预防"),
+    ("yd", "元旦"),
+    ("yg", "员工"),
+    ("yl", "原料"),
+    ("yz", "原则"),
+    ("y", "圆"),
+    ("yq", "运气"),
+    ("ysh", "运输"),
+    ("ys", "运输"),
+    ("zh", "在乎"),
+    ("zy", "在于"),
+    ("zch", "赞成"),
+    ("zc", "赞成"),
+    ("zb", "责备"),
+    ("zh", "窄"),
+    ("z", "窄"),
+    ("zhk", "展开"),
+    ("zk", "展开"),
+    ("zh", "涨"),
+    ("z", "涨"),
+    ("zhw", "掌握"),
+    ("zw", "掌握"),
+    ("zhch", "照常"),
+    ("zhc", "照常"),
+    ("zch", "照常"),
+    ("zc", "照常"),
+    ("zhx", "珍惜"),
+    ("zx", "珍惜"),
+    ("zhsh", "真实"),
+    ("zhs", "真实"),
+    ("zsh", "真实"),
+    ("zs", "真实"),
+    ("zhq", "征求"),
+    ("zq", "征求"),
+    ("zh", "睁"),
+    ("z", "睁"),
+    ("zhq", "整齐"),
+    ("zq", "整齐"),
+    ("zhf", "政府"),
+    ("zf", "政府"),
+    ("zh", "直"),
+    ("z", "直"),
+    ("zhd", "指导"),
+    ("zd", "指导"),
+    ("zhh", "指挥"),
+    ("zh", "指挥"),
+    ("zhy", "至于"),
+    ("zy", "至于"),
+    ("zhz", "制作"),
+    ("zz", "制作"),
+    ("zhx", "秩序"),
+    ("zx", "秩序"),
+    ("zhx", "中旬"),
+    ("zx", "中旬"),
+    ("zhl", "种类"),
+    ("zl", "种类"),
+    ("zhl", "重量"),
+    ("zl", "重量"),
+    ("zhb", "逐步"),
+    ("zb", "逐步"),
+    ("zhg", "主观"),
+    ("zg", "主观"),
+    ("zht", "主题"),
+    ("zt", "主题"),
+    ("zhx", "主席"),
+    ("zx", "主席"),
+    ("zhzh", "主张"),
+    ("zhz", "主张"),
+    ("zzh", "主张"),
+    ("zz", "主张"),
+    ("zh", "煮"),
+    ("z", "煮"),
+    ("zh", "抓"),
+    ("z", "抓"),
+    ("zhj", "抓紧"),
+    ("zj", "抓紧"),
+    ("zhb", "转变"),
+    ("zb", "转变"),
+    ("zhsh", "装饰"),
+    ("zhs", "装饰"),
+    ("zsh", "装饰"),
+    ("zs", "装饰"),
+    ("zhk", "状况"),
+    ("zk", "状况"),
+    ("zhq", "追求"),
+    ("zq", "追求"),
+    ("zx", "咨询"),
+    ("zj", "资金"),
+    ("zy", "资源"),
+    ("z", "紫"),
+    ("zc", "自从"),
+    ("zd", "自动"),
+    ("zh", "自豪"),
+    ("zj", "自觉"),
+    ("zs", "自私"),
+    ("zy", "自愿"),
+    ("zs", "总算"),
+    ("zzh", "总之"),
+    ("zz", "总之"),
+    ("zzh", "组织"),
+    ("zz", "组织"),
+    ("zch", "最初"),
+    ("zc", "最初"),
+    ("zj", "尊敬"),
+    ("zsh", "遵守"),
+    ("zs", "遵守"),
+    ("zw", "作文"),
+    ("bg", "包裹"),
+    ("bch", "保持"),
+    ("bc", "保持"),
+    ("bd", "报到"),
+    ("bg", "悲观"),
+    ("bd", "标点"),
+    ("bd", "病毒"),
+    ("bwg", "博物馆"),
+    ("chz", "叉子"),
+    ("cz", "叉子"),
+    ("ch", "拆"),
+    ("c", "拆"),
+    ("ch", "抄"),
+    ("c", "抄"),
+    ("chj", "超级"),
+    ("cj", "超级"),
+    ("chk", "车库"),
+    ("ck", "车库"),
+    ("chx", "车厢"),
+    ("cx", "车厢"),
+    ("chx", "程序"),
+    ("cx", "程序"),
+    ("chz", "尺子"),
+    ("cz", "尺子"),
+    ("chdq", "充电器"),
+    ("cdq", "充电器"),
+    ("cht", "抽屉"),
+    ("ct", "抽屉"),
+    ("ch", "丑"),
+    ("c", "丑"),
+    ("ch", "臭"),
+    ("c", "臭"),
+    ("chk", "出口"),
+    ("ck", "出口"),
+    ("chj", "初级"),
+    ("cj", "初级"),
+    ("chb", "传播"),
+    ("cb", "传播"),
+    ("chr", "传染"),
+    ("cr", "传染"),
+    ("cm", "匆忙"),
+    ("dpt", "打喷嚏"),
+    ("dk", "贷款"),
+    ("dy", "单元"),
+    ("d", "淡"),
+    ("dx", "当心"),
+    ("d", "滴"),
+    ("dt", "地毯"),
+    ("df", "豆腐"),
+    ("dh", "兑换"),
+    ("dy", "多余"),
+    ("eh", "耳环"),
+    ("fb", "发表"),
+    ("fm", "发明"),
+    ("fp", "发票"),
+    ("fk", "罚款"),
+    ("fy", "法院"),
+    ("fh", "废话"),
+    ("fp", "分配"),
+    ("fsh", "分手"),
+    ("fs", "分手"),
+    ("fg", "风格"),
+    ("fk", "疯狂"),
+    ("fd", "否定"),
+    ("fd", "辅导"),
+    ("fzh", "复制"),
+    ("fz", "复制"),
+    ("gzh", "改正"),
+    ("gz", "改正"),
+    ("gb", "隔壁"),
+    ("gp", "公平"),
+    ("gzh", "公主"),
+    ("gz", "公主"),
+    ("gx", "恭喜"),
+    ("gp", "股票"),
+    ("gh", "挂号"),
+    ("gw", "拐弯"),
+    ("gbd", "怪不得"),
+    ("gz", "管子"),
+    ("gp", "光盘"),
+    ("gm", "规模"),
+    ("gt", "柜台"),
+    ("gqj", "国庆节"),
+    ("gw", "国王"),
+    ("gm", "过敏"),
+    ("gq", "过期"),
+    ("hg", "海关"),
+    ("hf", "合法"),
+    ("ht", "合同"),
+    ("hy", "合影"),
+    ("h", "恨"),
+    ("hsh", "花生"),
+    ("hs", "花生"),
+    ("h", "划"),
+    ("hx", "化学"),
+    ("hy", "怀孕"),
+    ("hj", "黄金"),
+    ("hl", "汇率"),
+    ("jm", "急忙"),
+    ("jzh", "急诊"),
+    ("jz", "急诊"),
+    ("jh", "集合"),
+    ("jt", "集体"),
+    ("jld", "系领带"),
+    ("jz", "夹子"),
+    ("jb", "嘉宾"),
+    ("jzh", "假装"),
+    ("jz", "假装"),
+    ("j", "嫁"),
+    ("jq", "坚强"),
+    ("jzh", "兼职"),
+    ("jz", "兼职"),
+    ("jp", "键盘"),
+    ("jsh", "胶水"),
+    ("js", "胶水"),
+    ("jc", "教材"),
+    ("jzh", "结账"),
+    ("jz", "结账"),
+    ("jzh", "戒指"),
+    ("jz", "戒指"),
+    ("jk", "进口"),
+    ("jl", "精力"),
+    ("jhch", "救护车"),
+    ("jhc", "救护车"),
+    ("jz", "桔子"),
+    ("jlb", "俱乐部"),
+    ("j", "捐"),
+    ("kch", "卡车"),
+    ("kc", "卡车"),
+    ("kw", "看望"),
+    ("k", "克"),
+    ("kch", "课程"),
+    ("kc", "课程"),
+    ("kj", "会计"),
+    ("lj", "辣椒"),
+    ("lsh", "老鼠"),
+    ("ls", "老鼠"),
+    ("lm", "厘米"),
+    ("lx", "利息"),
+    ("lch", "列车"),
+    ("lc", "列车"),
+    ("lj", "零件"),
+    ("lsh", "零食"),
+    ("ls", "零食"),
+    ("ld", "领导"),
+    ("ll", "浏览"),
+    ("ly", "录音"),
+    ("lw", "论文"),
+    ("mkf", "麦克风"),
+    ("my", "贸易"),
+    ("mm", "眉毛"),
+    ("msh", "秘书"),
+    ("ms", "秘书"),
+    ("mf", "蜜蜂"),
+    ("mp", "名片"),
+    ("mshgj", "名胜古迹"),
+    ("msgj", "名胜古迹"),
+    ("mt", "模特"),
+    ("mtch", "摩托车"),
+    ("mtc", "摩托车"),
+    ("ml", "目录"),
+    ("nk", "内科"),
+    ("nzk", "牛仔裤"),
+    ("ozh", "欧洲"),
+    ("oz", "欧洲"),
+    ("ph", "配合"),
+    ("p", "披"),
+    ("py", "拼音"),
+    ("pd", "频道"),
+    ("pf", "平方"),
+    ("pch", "破产"),
+    ("pc", "破产"),
+    ("q", "欠"),
+    ("q", "枪"),
+    ("qch", "青春"),
+    ("qc", "青春"),
+    ("q", "娶"),
+    ("ql", "权力"),
+    ("ql", "权利"),
+    ("r", "绕"),
+    ("rk", "人口"),
+    ("rmb", "人民币"),
+    ("rsh", "人事"),
+    ("rs", "人事"),
+    ("rl", "日历"),
+    ("rq", "日期"),
+    ("r", "软"),
+    ("rj", "软件"),
+    ("sz", "嗓子"),
+    ("shch", "删除"),
+    ("shc", "删除"),
+    ("sch", "删除"),
+    ("sc", "删除"),
+    ("sh", "蛇"),
+    ("s", "蛇"),
+    ("shy", "摄影"),
+    ("sy", "摄影"),
+    ("shh", "神话"),
+    ("sh", "神话"),
+    ("shd", "生动"),
+    ("sd", "生动"),
+    ("shd", "声调"),
+    ("sd", "声调"),
+    ("shl", "省略"),
+    ("sl", "省略"),
+    ("shy", "失业"),
+    ("sy", "失业"),
+    ("shz", "狮子"),
+    ("sz", "狮子"),
+    ("shch", "时差"),
+    ("shc", "时差"),
+    ("sch", "时差"),
+    ("sc", "时差"),
+    ("shm", "时髦"),
+    ("sm", "时髦"),
+    ("shx", "实习"),
+    ("sx", "实习"),
+    ("shj", "试卷"),
+    ("sj", "试卷"),
+    ("shj", "收据"),
+    ("sj", "收据"),
+    ("shg", "手工"),
+    ("sg", "手工"),
+    ("shsh", "手术"),
+    ("shs", "手术"),
+    ("ssh", "手术"),
+    ("ss", "手术"),
+    ("sht", "手套"),
+    ("st", "手套"),
+    ("shm", "寿命"),
+    ("sm", "寿命"),
+    ("shj", "书架"),
+    ("sj", "书架"),
+    ("shb", "鼠标"),
+    ("sb", "鼠标"),
+    ("shm", "数码"),
+    ("sm", "数码"),
+    ("shf", "双方"),
+    ("sf", "双方"),
+    ("sh", "税"),
+    ("s", "税"),
+    ("sch", "丝绸"),
+    ("sc", "丝绸"),
+    ("s", "撕"),
+    ("ss", "搜索"),
+    ("ssh", "宿舍"),
+    ("ss", "宿舍"),
+    ("tjq", "太极拳"),
+    ("tp", "谈判"),
+    ("tsh", "坦率"),
+    ("ts", "坦率"),
+    ("tjhj", "讨价还价"),
+    ("tg", "提纲"),
+    ("ty", "统一"),
+    ("t", "偷"),
+    ("td", "土地"),
+    ("td", "土豆"),
+    ("t", "吐"),
+    ("tz", "兔子"),
+    ("t", "团"),
+    ("tc", "推辞"),
+    ("t", "退"),
+    ("tb", "退步"),
+    ("w", "歪"),
+    ("wj", "玩具"),
+    ("wf", "往返"),
+    ("wj", "围巾"),
+    ("wx", "维修"),
+    ("wd", "伟大"),
+    ("wj", "文件"),
+    ("wx", "文学"),
+    ("wh", "问候"),
+    ("wsh", "握手"),
+    ("ws", "握手"),
+    ("wsh", "武术"),
+    ("ws", "武术"),
+    ("w", "雾"),
+    ("xly", "夏令营"),
+    ("xch", "香肠"),
+    ("xc", "香肠"),
+    ("xl", "项链"),
+    ("xzh", "象征"),
+    ("xz", "象征"),
+    ("xh", "消化"),
+    ("xm", "小麦"),
+    ("xq", "小气"),
+    ("x", "血"),
+    ("xh", "信号"),
+    ("xr", "信任"),
+    ("xzh", "性质"),
+    ("xz", "性质"),
+    ("xg", "修改"),
+    ("xb", "宣布"),
+    ("xl", "学历"),
+    ("xsh", "学术"),
+    ("xs", "学术"),
+    ("yj", "演讲"),
+    ("yh", "宴会"),
+    ("yt", "阳台"),
+    ("y", "痒"),
+    ("yl", "议论"),
+    ("yj", "硬件"),
+    ("yh", "优惠"),
+    ("yq", "语气"),
+    ("ym", "玉米"),
+    ("yb", "预报"),
+    ("yd", "预订"),
+    ("yq", "乐器"),
+    ("zh", "摘"),
+    ("z", "摘"),
+    ("zht", "粘贴"),
+    ("zt", "粘贴"),
+    ("zhl", "展览"),
+    ("zl", "展览"),
+    ("zhh", "账户"),
+    ("zh", "账户"),
+    ("zhl", "着凉"),
+    ("zl", "着凉"),
+    ("zhx", "哲学"),
+    ("zx", "哲学"),
+    ("zhd", "诊断"),
+    ("zd", "诊断"),
+    ("zhd", "振动"),
+    ("zd", "振动"),
+    ("zh", "正"),
+    ("z", "正"),
+    ("zhj", "证件"),
+    ("zj", "证件"),
+    ("zhzh", "政治"),
+    ("zhz", "政治"),
+    ("zzh", "政治"),
+    ("zz", "政治"),
+    ("zhp", "支票"),
+    ("zp", "支票"),
+    ("zhzh", "执照"),
+    ("zhz", "执照"),
+    ("zzh", "执照"),
+    ("zz", "执照"),
+    ("zhd", "制定"),
+    ("zd", "制定"),
+    ("zhd", "制度"),
+    ("zd", "制度"),
+    ("zhj", "中介"),
+    ("zj", "中介"),
+    ("zhd", "周到"),
+    ("zd", "周到"),
+    ("zhr", "主任"),
+    ("zr", "主任"),
+    ("zhf", "祝福"),
+    ("zf", "祝福"),
+    ("zhx", "专心"),
+    ("zx", "专心"),
+    ("zhg", "转告"),
+    ("zg", "转告"),
+    ("zm", "字母"),
+    ("zm", "字幕"),
+    ("zl", "总理"),
+    ("zt", "总统"),
+    ("adly", "澳大利亚"),
+    ("btq", "趵突泉"),
+    ("bq", "鲍全"),
+    ("cshk", "菜市口"),
+    ("csk", "菜市口"),
+    ("chg", "楚国"),
+    ("cg", "楚国"),
+    ("chq", "春秋"),
+    ("cq", "春秋"),
+    ("dneknm", "丹尼尔·卡内曼"),
+    ("dxc", "稻香村"),
+    ("dhlw", "东海龙王"),
+    ("fzhdl", "峰终定律"),
+    ("fzdl", "峰终定律"),
+    ("ghj", "广和居"),
+    ("hb", "华北"),
+    ("jn", "济南"),
+    ("jlfnyzh", "加利福尼亚州"),
+    ("jlfnyz", "加利福尼亚州"),
+    ("kz", "孔子"),
+    ("lg", "李广"),
+    ("lp", "廉颇"),
+    ("lchp", "刘炽平"),
+    ("lcp", "刘炽平"),
+    ("lmae", "卢米埃尔"),
+    ("lx", "鲁迅"),
+    ("mszhszh", "马萨诸塞州"),
+    ("mszhsz", "马萨诸塞州"),
+    ("mszszh", "马萨诸塞州"),
+    ("mszsz", "马萨诸塞州"),
+    ("mblq", "麦布里奇"),
+    ("mx", "梅西"),
+    ("nh", "《呐喊》"),
+    ("nbej", "诺贝尔奖"),
+    ("ph", "《彷徨》"),
+    ("ql", "七郎"),
+    ("qg", "秦国"),
+    ("sh", "舜"),
+    ("s", "舜"),
+    ("stf", "斯坦福"),
+    ("tx", "腾讯"),
+    ("wx", "微信"),
+    ("x", "夕"),
+    ("xh", "西汉"),
+    ("xxl", "新西兰"),
+    ("yx", "杨雄"),
+    ("ydf", "郁达夫"),
+    ("zhf", "翟峰"),
+    ("zf", "翟峰"),
+    ("zhmshnshms", "詹姆士·奈史密斯"),
+    ("zhmshnsms", "詹姆士·奈史密斯"),
+    ("zhmsnshms", "詹姆士·奈史密斯"),
+    ("zhmsnsms", "詹姆士·奈史密斯"),
+    ("zmshnshms", "詹姆士·奈史密斯"),
+    ("zmshnsms", "詹姆士·奈史密斯"),
+    ("zmsnshms", "詹姆士·奈史密斯"),
+    ("zmsnsms", "詹姆士·奈史密斯"),
+    ("zhg", "战国"),
+    ("zg", "战国"),
+    ("zhxl", "张小龙"),
+    ("zxl", "张小龙"),
+    ("zhg", "赵国"),
+    ("zg", "赵国"),
+    ("zhk", "赵括"),
+    ("zk", "赵括"),
+    ("zhsh", "赵奢"),
+    ("zhs", "赵奢"),
+    ("zsh", "赵奢"),
+    ("zs", "赵奢"),
+    ("zl", "子路"),
+    ("bp", "奔跑"),
+    ("bd", "波动"),
+    ("chb", "称霸"),
+    ("cb", "称霸"),
+    ("dy", "代言"),
+    ("dj", "道具"),
+    ("dq", "颠球"),
+    ("d", "叮"),
+    ("ea", "恩爱"),
+    ("esh", "二手"),
+    ("es", "二手"),
+    ("fch", "帆船"),
+    ("fc", "帆船"),
+    ("fz", "放纵"),
+    ("g", "杆"),
+    ("gw", "怪物"),
+    ("g", "棍"),
+    ("gj", "国君"),
+    ("hl", "海里"),
+    ("hy", "含意"),
+    ("hj", "行家"),
+    ("hxx", "黑猩猩"),
+    ("hnyg", "患难与共"),
+    ("hchy", "火成岩"),
+    ("hcy", "火成岩"),
+    ("jzh", "机制"),
+    ("jz", "机制"),
+    ("jx", "积蓄"),
+    ("j", "箭"),
+    ("jy", "金鱼"),
+    ("jchszh", "精诚所至"),
+    ("jchsz", "精诚所至"),
+    ("jcszh", "精诚所至"),
+    ("jcsz", "精诚所至"),
+    ("jshwk", "金石为开"),
+    ("jswk", "金石为开"),
+    ("jc", "就餐"),
+    ("jz", "锯子"),
+    ("j", "锯"),
+    ("kr", "困扰"),
+    ("lg", "栏杆"),
+    ("lljzh", "淋漓尽致"),
+    ("lljz", "淋漓尽致"),
+    ("m", "埋"),
+    ("mr", "盲人"),
+    ("md", "美德"),
+    ("ml", "蒙眬"),
+    ("mj", "民居"),
+    ("nr", "纳入"),
+    ("pw", "评委"),
+    ("qj", "起居"),
+    ("qw", "青蛙"),
+    ("qq", "情趣"),
+    ("qshgzh", "全神贯注"),
+    ("qshgz", "全神贯注"),
+    ("qsgzh", "全神贯注"),
+    ("qsgz", "全神贯注"),
+    ("rw", "入围"),
+    ("shr", "摄入"),
+    ("sr", "摄入"),
+    ("shshxs", "肾上腺素"),
+    ("shsxs", "肾上腺素"),
+    ("sshxs", "肾上腺素"),
+    ("ssxs", "肾上腺素"),
+    ("shwzh", "生物钟"),
+    ("shwz", "生物钟"),
+    ("swzh", "生物钟"),
+    ("swz", "生物钟"),
+    ("shhy", "石灰岩"),
+    ("shy", "石灰岩"),
+    ("shb", "手笔"),
+    ("sb", "手笔"),
+    ("sh", "守"),
+    ("s", "守"),
+    ("shch", "顺畅"),
+    ("shc", "顺畅"),
+    ("sch", "顺畅"),
+    ("sc", "顺畅"),
+    ("shy", "四合院"),
+    ("tz", "蹄子"),
+    ("t", "蹄"),
+    ("tr", "天然"),
+    ("ty", "涂鸦"),
+    ("wwnn", "歪歪扭扭"),
+    ("wy", "喂养"),
+    ("wz", "蚊子"),
+    ("xjrb", "相敬如宾"),
+    ("xf", "厢房"),
+    ("xz", "橡子"),
+    ("xj", "孝敬"),
+    ("yy", "悠悠"),
+    ("yl", "由来"),
+    ("yy", "寓言"),
+    ("zhsms", "朝三暮四"),
+    ("zsms", "朝三暮四"),
+    ("zhxj", "哲学家"),
+    ("zxj", "哲学家"),
+    ("zh", "镇"),
+    ("z", "镇"),
+    ("zhshtb", "纸上谈兵"),
+    ("zhstb", "纸上谈兵"),
+    ("zshtb", "纸上谈兵"),
+    ("zstb", "纸上谈兵"),
+    ("bjshfdx", "北京师范大学"),
+    ("bjsfdx", "北京师范大学"),
+    ("begc", "比尔·盖茨"),
+    ("fnmsh", "《非你莫属》"),
+    ("fnms", "《非你莫属》"),
+    ("gm", "国贸"),
+    ("gldj", "鼓楼大街"),
+    ("hlsh", "郝林说"),
+    ("hls", "郝林说"),
+    ("hgs", "护国寺"),
+    ("hgn", "灰姑娘"),
+    ("jw", "健伟"),
+    ("lsh", "老舍"),
+    ("ls", "老舍"),
+    ("lchdxes", "里查德·希尔斯"),
+    ("lcdxes", "里查德·希尔斯"),
+    ("lch", "刘辰"),
+    ("lc", "刘辰"),
+    ("lshj", "洛杉矶"),
+    ("lsj", "洛杉矶"),
+    ("mhchdxnhe", "《卖火柴的小女孩儿》"),
+    ("mhcdxnhe", "《卖火柴的小女孩儿》"),
+    ("mhchdxnh", "《卖火柴的小女孩儿》"),
+    ("mhcdxnh", "《卖火柴的小女孩儿》"),
+    ("njdx", "牛津大学"),
+    ("om", "欧盟"),
+    ("ozhhjbhsh", "欧洲环境保护署"),
+    ("ozhhjbhs", "欧洲环境保护署"),
+    ("ozhjbhsh", "欧洲环境保护署"),
+    ("ozhjbhs", "欧洲环境保护署"),
+    ("pjy", "潘家园"),
+    ("pzhs", "佩·詹森"),
+    ("pzs", "佩·詹森"),
+    ("shwjz", "《说文解字》"),
+    ("swjz", "《说文解字》"),
+    ("tamd", "天安门东"),
+    ("tjwsh", "天津卫视"),
+    ("tjws", "天津卫视"),
+    ("ww", "文文"),
+    ("xwy", "夏威夷"),
+    ("yj", "印加"),
+    ("yn", "云南"),
+    ("zhfg", "赵福根"),
+    ("zfg", "赵福根"),
+    ("bch", "八成儿"),
+    ("bc", "八成儿"),
+    ("bch", "八成"),
+    ("bc", "八成"),
+    ("b", "般"),
+    ("by", "暴雨"),
+    ("bg", "闭关"),
+    ("bjss", "不假思索"),
+    ("cy", "才艺"),
+    ("chx", "查询"),
+    ("cx", "查询"),
+    ("chh", "车祸"),
+    ("ch", "车祸"),
+    ("ch", "沉"),
+    ("c", "沉"),
+    ("chx", "诚信"),
+    ("cx", "诚信"),
+    ("ch", "抽"),
+    ("c", "抽"),
+    ("chb", "创办"),
+    ("cb", "创办"),
+    ("cl", "丛林"),
+    ("ch", "存活"),
+    ("fx", "反省"),
+    ("fh", "返航"),
+    ("f", "犯"),
+    ("fx", "分享"),
+    ("fl", "风浪"),
+    ("fzh", "负重"),
+    ("fz", "负重"),
+    ("g", "擀"),
+    ("gj", "根基"),
+    ("gzh", "根治"),
+    ("gz", "根治"),
+    ("g", "雇"),
+    ("hx", "汗腺"),
+    ("hsh", "和尚"),
+    ("hs", "和尚"),
+    ("h", "糊"),
+    ("jsh", "寄生"),
+    ("js", "寄生"),
+    ("j", "佳"),
+    ("jf", "家访"),
+    ("jbrf", "健步如飞"),
+    ("j", "焦"),
+    ("j", "局"),
+    ("jh", "菊花"),
+    ("k", "垮"),
+    ("ly", "老鹰"),
+    ("lhh", "连环画"),
+    ("lsh", "留守"),
+    ("ls", "留守"),
+    ("l", "屡"),
+    ("lb", "萝卜"),
+    ("mk", "毛孔"),
+    ("m", "冒"),
+    ("mk", "门槛"),
+    ("ny", "鲇鱼"),
+    ("pz", "棚子"),
+    ("qzhn", "青壮年"),
+    ("qzn", "青壮年"),
+    ("ql", "情侣"),
+    ("qy", "情缘"),
+    ("qysh", "请愿书"),
+    ("qys", "请愿书"),
+    ("shdy", "沙丁鱼"),
+    ("sdy", "沙丁鱼"),
+    ("shz", "沙子"),
+    ("sz", "沙子"),
+    ("shxym", "赏心悦目"),
+    ("sxym", "赏心悦目"),
+    ("shx", "少许"),
+    ("sx", "少许"),
+    ("shd", "身段"),
+    ("sd", "身段"),
+    ("shy", "深渊"),
+    ("sy", "深渊"),
+    ("shf", "施肥"),
+    ("sf", "施肥"),
+    ("shd", "适当"),
+    ("sd", "适当"),
+    ("shj", "收集"),
+    ("sj", "收集"),
+    ("s", "丝"),
+    ("th", "昙花"),
+    ("t", "痰"),
+    ("td", "天敌"),
+    ("t", "图"),
+    ("wzh", "万丈"),
+    ("wz", "万丈"),
+    ("wg", "王宫"),
+    ("wsh", "旺盛"),
+    ("ws", "旺盛"),
+    ("wb", "微博"),
+    ("wq", "尾气"),
+    ("wh", "文火"),
+    ("wy", "无意"),
+    ("xl", "洗礼"),
+    ("xy", "效应"),
+    ("yl", "一连"),
+    ("yl", "荫凉"),
+    ("zh", "招儿"),
+    ("z", "招儿"),
+    ("zh", "招"),
+    ("z", "招"),
+    ("zhd", "折叠"),
+    ("zd", "折叠"),
+    ("zhl", "哲理"),
+    ("zl", "哲理"),
+    ("zht", "蒸腾"),
+    ("zt", "蒸腾"),
+    ("zhj", "支教"),
+    ("zj", "支教"),
+    ("zhg", "枝干"),
+    ("zg", "枝干"),
+    ("zhg", "知感"),
+    ("zg", "知感"),
+    ("zhgn", "知更鸟"),
+    ("zgn", "知更鸟"),
+    ("zhd", "制订"),
+    ("zd", "制订"),
+    ("zh", "钟"),
+    ("z", "钟"),
+    ("zhzh", "专注"),
+    ("zhz", "专注"),
+    ("zzh", "专注"),
+    ("zz", "专注"),
+    ("zht", "转头"),
+    ("zt", "转头"),
+    ("zh", "啄"),
+    ("z", "啄"),
 ];
@@ -0,0 +1,1043 @@
+// This file is automatically generated. DO NOT MAKE EDITS HERE!
+// To make changes, see ../vocab/autogen-yue.rb
+//
+// Copyright (c) 2021 Sam Blenny
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+#![forbid(unsafe_code)]
+#![allow(dead_code)]
+
+// The longest Jyutping search key in the list below has JYUTPING_SIZE_MAX
+// characters. Search keys leave out the tone numbers.
+pub const JYUTPING_SIZE_MAX: usize = 15;
+
+// u32 constants are murmur3 hash of Jyutping search keys, with the same
+// seed as autogen_hsk::MURMUR3_SEED
+pub const MURMUR3_SEED: u32 = 0;
+pub static JYUTPING: &[u32] = &[
+    0x06104c8c, // naaicaa
+    0x0804db2e, // janwai
+    0x0ab789c9, // gwo
+    0x0acc01cb, // nam
+    0x0bc475e7, // gungzok
+    0x0cd95682, // tinhei
+    0x0da7ef59, // dung
+    0x0f53f0cb, // houdo
+    0x11dddcb6, // baabaa
+    0x1672836b, // `
+    0x1780d4f8, // luk
+    0x17c01507, // deitit
+    0x182ddbdf, // keoidei
+    0x18c44cca, // ukkeijan
+    0x18d53bb6, // (
+    0x19468f84, // caacaanteng
+    0x1a1476e3, // ce
+    0x1b5ee93c, // zousan
+    0x1cfcd293, // )
+    0x1d10148b, // leng
+    0x2223552c, // soji
+    0x2271e4aa, // hou
+    0x228ae718, // gwai
+    0x2364c5db, // me
+    0x24efdf41, // faangung
+    0x2a5742dd, // siu
+    0x2aa4f6f1, // doze
+    0x2bd8f0df, // singkei
+    0x2d2269f8, // m
+    0x2f777299, // co
+    0x3294caad, // gogo
+    0x34c2a6a9, // mdak
+    0x391d2faa, // sai
+    0x39f953fd, // gau
+    0x3a86a04b, // gan
+    0x3b014e4a, // zung
+    0x3bbda799, // dimjoeng
+    0x3c95f3aa, // geicin
+    0x3e1b056e, // teng
+    0x4131c68d, // houcoi
+    0x41c69145, // jijyun
+    0x427a743f, // haagaau
+    0x43e7c4d2, // ziuzou
+    0x44324f5d, // matje
+    0x44dd6b03, // dimgaai
+    0x450a2945, // heoi
+    0x45ef573f, // ;
+    0x4856a4c7, // je
+    0x4ba7180e, // mantai
+    0x4d4d8ed1, // faan
+    0x4e1bcd97, // cat
+    0x4fef842e, // coi
+    0x51ddd819, // tingjat
+    0x51e75c37, // ge
+    0x51ea6a1f, // geido
+    0x549f98e9, // cin
+    0x5519b4ee, // sikaan
+    0x56cd7969, // gai
+    0x58c397d3, // gwongzau
+    0x5d0a58fe, // hoksaang
+    0x5dfa15f6, // dou
+    0x5fd4237b, // nei
+    0x6082c8ad, // haiaa
+    0x61d8d67e, // lai
+    0x652985ad, // ng
+    0x6764f801, // jyu
+    0x68209bca, // gwongdungwaa
+    0x68a17c5f, // loeng
+    0x68b57ab6, // zo
+    0x6b230d9c, // hai
+    0x6c4e0116, // baasi
+    0x6df74fe7, // moumantai
+    0x6f8d47bb, // toungo
+    0x70304bfb, // lokjyu
+    0x7124da2e, // maamaa
+    0x728445b6, // jamcaa
+    0x730cebe8, // diksi
+    0x7320e0d1, // gong
+    0x7435564b, // kei
+    0x7842b7d9, // kamjat
+    0x790ad746, // naamzai
+    0x7996da90, // zunggwok
+    0x7a30db58, // do
+    0x7a7deacf, // maaije
+    0x7b02348f, // caa
+    0x7c2d4fab, // houmei
+    0x7cd44817, // zou
+    0x809e8211, // godou
+    0x81250b15, // dim
+    0x81e8160f, // bindou
+    0x8208ce79, // sanninfaailok
+    0x8333a604, // caasiu
+    0x8430cddf, // gaafe
+    0x843a3e1a, // neihou
+    0x84aa0d4e, // hoenggong
+    0x86a8b043, // ,
+    0x86e0f80b, // mou
+    0x897d2a33, // camjat
+    0x8a75b9e1, // lengzai
+    0x8b1bc877, // faanhok
+    0x8ce113a9, // hokhaau
+    0x8db20a6b, // !
+    0x9036e713, // haimai
+    0x94e46295, // fonggung
+    0x9500c437, // <
+    0x95b88641, // feigei
+    0x96f85796, // dinwaa
+    0x97c3d221, // tai
+    0x98453fd6, // ?
+    0x994975ad, // man
+    0x9c6db128, // fan
+    0x9e74cb87, // soeng
+    0x9f0c7183, // sigaan
+    0x9f6cab7d, // zungji
+    0xa15fa766, // jemaan
+    0xa1ef34e1, // gui
+    0xa2bf03e0, // keoi
+    0xa38a3a85, // ngau
+    0xa4362743, // houci
+    0xa7620216, // nidou
+    0xa7fd2fb7, // ukkei
+    0xac378905, // lousi
+    0xace0c268, // tung
+    0xad8e5348, // gam
+    0xaf28e6b6, // sikfaan
+    0xafa68901, // caanteng
+    0xb0fa8072, // haang
+    0xb116db51, // mganjiu
+    0xb191838c, // dimsam
+    0xb2c684d2, // neoizai
+    0xb34e22e8, // mai
+    0xb462a1f6, // jiu
+    0xb637e881, // daanhai
+    0xb662c68b, // lengneoi
+    0xb678a95d, // aanzau
+    0xb8b65435, // saugei
+    0xb8bf2d2a, // gongje
+    0xb9439a88, // jit
+    0xbacd3d63, // gaajau
+    0xbad4fb6a, // gamjat
+    0xbbfe7c1c, // saam
+    0xbc90b94c, // housik
+    0xbe34a510, // jigaa
+    0xc0373118, // seoi
+    0xc1747541, // jyugwo
+    0xc1aebe68, // zyu
+    0xc28216c9, // sap
+    0xc2d19f38, // hoisam
+    0xc4b572ed, // jat
+    0xc53510ce, // daai
+    0xc7f7e168, // bingo
+    0xc890e3dd, // >
+    0xc92a3ab2, // peng
+    0xca6ac53f, // fangaau
+    0xca7bf78a, // sailou
+    0xcc42dab6, // pangjau
+    0xcee17c70, // mousowai
+    0xd004cd1a, // ji
+    0xd1516631, // zikhai
+    0xd24c8bd1, // ngaamngaam
+    0xd2e71433, // zi
+    0xd449fadc, // neidei
+    0xd4ffa898, // :
+    0xd57624eb, // siumaai
+    0xd638cabc, // houtai
+    0xd728ac54, // zidou
+    0xd7d45289, // daapce
+    0xd7e1feba, // ngaam
+    0xda2b151d, // \
+    0xdc3ded04, // baat
+    0xdd95a4b2, // gokdak
+    0xddb7e833, // maai
+    0xde149ad3, // sei
+    0xe05881f3, // nigo
+    0xe1c7c649, // jan
+    0xe204bac7, // mhai
+    0xe2aa1ebb, // .
+    0xe3b87f3d, // min
+    0xe677325b, // sik
+    0xe690770d, // saangjatfaailok
+    0xe73a2580, // jam
+    0xea188089, // jau
+    0xead15bff, // mgoi
+    0xeb510f78, // zuk
+    0xebebbf51, // go
+    0xeda88301, // zek
+    0xedc29d45, // gaaze
+    0xee092b90, // dinnou
+    0xf0deec6c, // zouje
+    0xf13f36a9, // dak
+    0xf20e5415, // ngo
+    0xf36b8ff7, // ngodei
+    0xf4901c5c, // caasiubaau
+    0xf4dcd646, // laibaai
+    0xf5a46ff7, // hoji
+    0xf5f79917, // geisi
+    0xf6610b71, // di
+    0xf8382f39, // gungheifaatcoi
+    0xfb5ce697, // zau
+    0xfd811569, // saimui
+    0xff872776, // deoimzyu
+];
+
+// Jyutping search keys in the same order as JYUTPING
+pub static JYUTPING_KEYS: &[&str] = &[
+    "naaicaa",
+    "janwai",
+    "gwo",
+    "nam",
+    "gungzok",
+    "tinhei",
+    "dung",
+    "houdo",
+    "baabaa",
+    "`",
+    "luk",
+    "deitit",
+    "keoidei",
+    "ukkeijan",
+    "(",
+    "caacaanteng",
+    "ce",
+    "zousan",
+    ")",
+    "leng",
+    "soji",
+    "hou",
+    "gwai",
+    "me",
+    "faangung",
+    "siu",
+    "doze",
+    "singkei",
+    "m",
+    "co",
+    "gogo",
+    "mdak",
+    "sai",
+    "gau",
+    "gan",
+    "zung",
+    "dimjoeng",
+    "geicin",
+    "teng",
+    "houcoi",
+    "jijyun",
+    "haagaau",
+    "ziuzou",
+    "matje",
+    "dimgaai",
+    "heoi",
+    ";",
+    "je",
+    "mantai",
+    "faan",
+    "cat",
+    "coi",
+    "tingjat",
+    "ge",
+    "geido",
+    "cin",
+    "sikaan",
+    "gai",
+    "gwongzau",
+    "hoksaang",
+    "dou",
+    "nei",
+    "haiaa",
+    "lai",
+    "ng",
+    "jyu",
+    "gwongdungwaa",
+    "loeng",
+    "zo",
+    "hai",
+    "baasi",
+    "moumantai",
+    "toungo",
+    "lokjyu",
+    "maamaa",
+    "jamcaa",
+    "diksi",
+    "gong",
+    "kei",
+    "kamjat",
+    "naamzai",
+    "zunggwok",
+    "do",
+    "maaije",
+    "caa",
+    "houmei",
+    "zou",
+    "godou",
+    "dim",
+    "bindou",
+    "sanninfaailok",
+    "caasiu",
+    "gaafe",
+    "neihou",
+    "hoenggong",
+    ",",
+    "mou",
+    "camjat",
+    "lengzai",
+    "faanhok",
+    "hokhaau",
+    "!",
+    "haimai",
+    "fonggung",
+    "<",
+    "feigei",
+    "dinwaa",
+    "tai",
+    "?",
+    "man",
+    "fan",
+    "soeng",
+    "sigaan",
+    "zungji",
+    "jemaan",
+    "gui",
+    "keoi",
+    "ngau",
+    "houci",
+    "nidou",
+    "ukkei",
+    "lousi",
+    "tung",
+    "gam",
+    "sikfaan",
+    "caanteng",
+    "haang",
+    "mganjiu",
+    "dimsam",
+    "neoizai",
+    "mai",
+    "jiu",
+    "daanhai",
+    "lengneoi",
+    "aanzau",
+    "saugei",
+    "gongje",
+    "jit",
+    "gaajau",
+    "gamjat",
+    "saam",
+    "housik",
+    "jigaa",
+    "seoi",
+    "jyugwo",
+    "zyu",
+    "sap",
+    "hoisam",
+    "jat",
+    "daai",
+    "bingo",
+    ">",
+    "peng",
+    "fangaau",
+    "sailou",
+    "pangjau",
+    "mousowai",
+    "ji",
+    "zikhai",
+    "ngaamngaam",
+    "zi",
+    "neidei",
+    ":",
+    "siumaai",
+    "houtai",
+    "zidou",
+    "daapce",
+    "ngaam",
+    "\\",
+    "baat",
+    "gokdak",
+    "maai",
+    "sei",
+    "nigo",
+    "jan",
+    "mhai",
+    ".",
+    "min",
+    "sik",
+    "saangjatfaailok",
+    "jam",
+    "jau",
+    "mgoi",
+    "zuk",
+    "go",
+    "zek",
+    "gaaze",
+    "dinnou",
+    "zouje",
+    "dak",
+    "ngo",
+    "ngodei",
+    "caasiubaau",
+    "laibaai",
+    "hoji",
+    "geisi",
+    "di",
+    "gungheifaatcoi",
+    "zau",
+    "saimui",
+    "deoimzyu",
+];
+
+// Longest homophone choice size (choices joined by "\t")
+pub const CIYU_CHOICE_MAX: usize = 2;
+
+// Choices for each search key, in the order of the vocab files, so the
+// first choice is the default
+pub static CIYU: &[&str] = &[
+    "奶茶",  // naaicaa
+    "因為",  // janwai
+    "過",  // gwo
+    "諗",  // nam
+    "工作",  // gungzok
+    "天氣",  // tinhei
+    "凍",  // dung
+    "好多",  // houdo
+    "爸爸",  // baabaa
+    "·",  // `
+    "六",  // luk
+    "地鐵",  // deitit
+    "佢哋",  // keoidei
+    "屋企人",  // ukkeijan
+    "（",  // (
+    "茶餐廳",  // caacaanteng
+    "車",  // ce
+    "早晨",  // zousan
+    "）",  // )
+    "靚",  // leng
+    "所以",  // soji
+    "好",  // hou
+    "貴",  // gwai
+    "咩",  // me
+    "返工",  // faangung
+    "少",  // siu
+    "多謝",  // doze
+    "星期",  // singkei
+    "唔",  // m
+    "坐",  // co
+    "嗰個	哥哥",  // gogo
+    "唔得",  // mdak
+    "細",  // sai
+    "九",  // gau
+    "緊",  // gan
+    "仲	鐘",  // zung
+    "點樣",  // dimjoeng
+    "幾錢",  // geicin
+    "聽",  // teng
+    "好彩",  // houcoi
+    "醫院",  // jijyun
+    "蝦餃",  // haagaau
+    "朝早",  // ziuzou
+    "乜嘢",  // matje
+    "點解",  // dimgaai
+    "去",  // heoi
+    "；",  // ;
+    "嘢	夜",  // je
+    "問題",  // mantai
+    "飯	返",  // faan
+    "七",  // cat
+    "菜",  // coi
+    "聽日",  // tingjat
+    "嘅",  // ge
+    "幾多",  // geido
+    "錢",  // cin
+    "食晏",  // sikaan
+    "雞",  // gai
+    "廣州",  // gwongzau
+    "學生",  // hoksaang
+    "都",  // dou
+    "你",  // nei
+    "係呀",  // haiaa
+    "嚟",  // lai
+    "五",  // ng
+    "魚",  // jyu
+    "廣東話",  // gwongdungwaa
+    "兩",  // loeng
+    "咗",  // zo
+    "係	喺",  // hai
+    "巴士",  // baasi
+    "冇問題",  // moumantai
+    "肚餓",  // toungo
+    "落雨",  // lokjyu
+    "媽媽",  // maamaa
+    "飲茶",  // jamcaa
+    "的士",  // diksi
+    "講",  // gong
+    "企",  // kei
+    "琴日",  // kamjat
+    "男仔",  // naamzai
+    "中國",  // zunggwok
+    "多",  // do
+    "買嘢",  // maaije
+    "茶",  // caa
+    "好味",  // houmei
+    "做	早",  // zou
+    "嗰度",  // godou
+    "點",  // dim
+    "邊度",  // bindou
+    "新年快樂",  // sanninfaailok
+    "叉燒",  // caasiu
+    "咖啡",  // gaafe
+    "你好",  // neihou
+    "香港",  // hoenggong
+    "，",  // ,
+    "冇",  // mou
+    "尋日",  // camjat
+    "靚仔",  // lengzai
+    "返學",  // faanhok
+    "學校",  // hokhaau
+    "！",  // !
+    "係咪",  // haimai
+    "放工",  // fonggung
+    "《",  // <
+    "飛機",  // feigei
+    "電話",  // dinwaa
+    "睇",  // tai
+    "？",  // ?
+    "蚊",  // man
+    "瞓",  // fan
+    "想",  // soeng
+    "時間",  // sigaan
+    "鍾意",  // zungji
+    "夜晚",  // jemaan
+    "攰",  // gui
+    "佢",  // keoi
+    "牛",  // ngau
+    "好似",  // houci
+    "呢度",  // nidou
+    "屋企",  // ukkei
+    "老師",  // lousi
+    "同",  // tung
+    "咁",  // gam
+    "食飯",  // sikfaan
+    "餐廳",  // caanteng
+    "行",  // haang
+    "唔緊要",  // mganjiu
+    "點心",  // dimsam
+    "女仔",  // neoizai
+    "米",  // mai
+    "要",  // jiu
+    "但係",  // daanhai
+    "靚女",  // lengneoi
+    "晏晝",  // aanzau
+    "手機",  // saugei
+    "講嘢",  // gongje
+    "熱",  // jit
+    "加油",  // gaajau
+    "今日",  // gamjat
+    "三",  // saam
+    "好食",  // housik
+    "而家",  // jigaa
+    "水",  // seoi
+    "如果",  // jyugwo
+    "豬",  // zyu
+    "十",  // sap
+    "開心",  // hoisam
+    "一",  // jat
+    "大",  // daai
+    "邊個",  // bingo
+    "》",  // >
+    "平",  // peng
+    "瞓覺",  // fangaau
+    "細路	細佬",  // sailou
+    "朋友",  // pangjau
+    "冇所謂",  // mousowai
+    "二",  // ji
+    "即係",  // zikhai
+    "啱啱",  // ngaamngaam
+    "知",  // zi
+    "你哋",  // neidei
+    "：",  // :
+    "燒賣",  // siumaai
+    "好睇",  // houtai
+    "知道",  // zidou
+    "搭車",  // daapce
+    "啱",  // ngaam
+    "、",  // \
+    "八",  // baat
+    "覺得",  // gokdak
+    "買	賣",  // maai
+    "四",  // sei
+    "呢個",  // nigo
+    "人",  // jan
+    "唔係",  // mhai
+    "。",  // .
+    "麵",  // min
+    "食	識",  // sik
+    "生日快樂",  // saangjatfaailok
+    "飲",  // jam
+    "有	又",  // jau
+    "唔該",  // mgoi
+    "粥",  // zuk
+    "個",  // go
+    "隻",  // zek
+    "家姐",  // gaaze
+    "電腦",  // dinnou
+    "做嘢",  // zouje
+    "得",  // dak
+    "我",  // ngo
+    "我哋",  // ngodei
+    "叉燒包",  // caasiubaau
+    "禮拜",  // laibaai
+    "可以",  // hoji
+    "幾時",  // geisi
+    "啲",  // di
+    "恭喜發財",  // gungheifaatcoi
+    "就",  // zau
+    "細妹",  // saimui
+    "對唔住",  // deoimzyu
+];
+
+// Choices in CIYU with a tone number (1-6) after each syllable, in the
+// same order
+pub static CIYU_TONED: &[&str] = &[
+    "naai5caa4",
+    "jan1wai6",
+    "gwo3",
+    "nam2",
+    "gung1zok3",
+    "tin1hei3",
+    "dung3",
+    "hou2do1",
+    "baa4baa1",
+    "`",
+    "luk6",
+    "dei6tit3",
+    "keoi5dei6",
+    "uk1kei2jan4",
+    "(",
+    "caa4caan1teng1",
+    "ce1",
+    "zou2san4",
+    ")",
+    "leng3",
+    "so2ji5",
+    "hou2",
+    "gwai3",
+    "me1",
+    "faan1gung1",
+    "siu2",
+    "do1ze6",
+    "sing1kei4",
+    "m4",
+    "co5",
+    "go2go3	go4go1",
+    "m4dak1",
+    "sai3",
+    "gau2",
+    "gan2",
+    "zung6	zung1",
+    "dim2joeng2",
+    "gei2cin2",
+    "teng1",
+    "hou2coi2",
+    "ji1jyun2",
+    "haa1gaau2",
+    "ziu1zou2",
+    "mat1je5",
+    "dim2gaai2",
+    "heoi3",
+    ";",
+    "je5	je6",
+    "man6tai4",
+    "faan6	faan1",
+    "cat1",
+    "coi3",
+    "ting1jat6",
+    "ge3",
+    "gei2do1",
+    "cin2",
+    "sik6aan3",
+    "gai1",
+    "gwong2zau1",
+    "hok6saang1",
+    "dou1",
+    "nei5",
+    "hai6aa3",
+    "lai4",
+    "ng5",
+    "jyu2",
+    "gwong2dung1waa2",
+    "loeng5",
+    "zo2",
+    "hai6	hai2",
+    "baa1si2",
+    "mou5man6tai4",
+    "tou5ngo6",
+    "lok6jyu5",
+    "maa4maa1",
+    "jam2caa4",
+    "dik1si2",
+    "gong2",
+    "kei5",
+    "kam4jat6",
+    "naam4zai2",
+    "zung1gwok3",
+    "do1",
+    "maai5je5",
+    "caa4",
+    "hou2mei6",
+    "zou6	zou2",
+    "go2dou6",
+    "dim2",
+    "bin1dou6",
+    "san1nin4faai3lok6",
+    "caa1siu1",
+    "gaa3fe1",
+    "nei5hou2",
+    "hoeng1gong2",
+    ",",
+    "mou5",
+    "cam4jat6",
+    "leng3zai2",
+    "faan1hok6",
+    "hok6haau6",
+    "!",
+    "hai6mai6",
+    "fong3gung1",
+    "<",
+    "fei1gei1",
+    "din6waa2",
+    "tai2",
+    "?",
+    "man1",
+    "fan3",
+    "soeng2",
+    "si4gaan3",
+    "zung1ji3",
+    "je6maan5",
+    "gui6",
+    "keoi5",
+    "ngau4",
+    "hou2ci5",
+    "ni1dou6",
+    "uk1kei2",
+    "lou5si1",
+    "tung4",
+    "gam3",
+    "sik6faan6",
+    "caan1teng1",
+    "haang4",
+    "m4gan2jiu3",
+    "dim2sam1",
+    "neoi5zai2",
+    "mai5",
+    "jiu3",
+    "daan6hai6",
+    "leng3neoi2",
+    "aan3zau3",
+    "sau2gei1",
+    "gong2je5",
+    "jit6",
+    "gaa1jau2",
+    "gam1jat6",
+    "saam1",
+    "hou2sik6",
+    "ji4gaa1",
+    "seoi2",
+    "jyu4gwo2",
+    "zyu1",
+    "sap6",
+    "hoi1sam1",
+    "jat1",
+    "daai6",
+    "bin1go3",
+    ">",
+    "peng4",
+    "fan3gaau3",
+    "sai3lou6	sai3lou2",
+    "pang4jau5",
+    "mou5so2wai6",
+    "ji6",
+    "zik1hai6",
+    "ngaam1ngaam1",
+    "zi1",
+    "nei5dei6",
+    ":",
+    "siu1maai2",
+    "hou2tai2",
+    "zi1dou3",
+    "daap3ce1",
+    "ngaam1",
+    "\\",
+    "baat3",
+    "gok3dak1",
+    "maai5	maai6",
+    "sei3",
+    "ni1go3",
+    "jan4",
+    "m4hai6",
+    ".",
+    "min6",
+    "sik6	sik1",
+    "saang1jat6faai3lok6",
+    "jam2",
+    "jau5	jau6",
+    "m4goi1",
+    "zuk1",
+    "go3",
+    "zek3",
+    "gaa1ze1",
+    "din6nou5",
+    "zou6je5",
+    "dak1",
+    "ngo5",
+    "ngo5dei6",
+    "caa1siu1baau1",
+    "lai5baai3",
+    "ho2ji5",
+    "gei2si4",
+    "di1",
+    "gung1hei2faat3coi4",
+    "zau6",
+    "sai3mui2",
+    "deoi3m4zyu6",
+];
+
+// Tuples are (search key, 詞語) for each line of the vocab files
+#[cfg(test)]
+pub static JYUTPING_CIYU_TEST_DATA: &[(&str, &str)] = &[
+    ("nei", "你"),
+    ("ngo", "我"),
+    ("keoi", "佢"),
+    ("neidei", "你哋"),
+    ("ngodei", "我哋"),
+    ("keoidei", "佢哋"),
+    ("hai", "係"),
+    ("hai", "喺"),
+    ("m", "唔"),
+    ("mhai", "唔係"),
+    ("haimai", "係咪"),
+    ("haiaa", "係呀"),
+    ("zikhai", "即係"),
+    ("hou", "好"),
+    ("neihou", "你好"),
+    ("zousan", "早晨"),
+    ("doze", "多謝"),
+    ("mgoi", "唔該"),
+    ("deoimzyu", "對唔住"),
+    ("mganjiu", "唔緊要"),
+    ("mousowai", "冇所謂"),
+    ("jau", "有"),
+    ("jau", "又"),
+    ("mou", "冇"),
+    ("moumantai", "冇問題"),
+    ("mantai", "問題"),
+    ("dak", "得"),
+    ("mdak", "唔得"),
+    ("ngaam", "啱"),
+    ("ngaamngaam", "啱啱"),
+    ("sik", "食"),
+    ("sik", "識"),
+    ("sikfaan", "食飯"),
+    ("sikaan", "食晏"),
+    ("faan", "飯"),
+    ("jam", "飲"),
+    ("jamcaa", "飲茶"),
+    ("caa", "茶"),
+    ("seoi", "水"),
+    ("heoi", "去"),
+    ("lai", "嚟"),
+    ("faan", "返"),
+    ("faangung", "返工"),
+    ("faanhok", "返學"),
+    ("fonggung", "放工"),
+    ("zou", "做"),
+    ("zou", "早"),
+    ("zouje", "做嘢"),
+    ("je", "嘢"),
+    ("je", "夜"),
+    ("matje", "乜嘢"),
+    ("me", "咩"),
+    ("dim", "點"),
+    ("dimgaai", "點解"),
+    ("dimjoeng", "點樣"),
+    ("bingo", "邊個"),
+    ("bindou", "邊度"),
+    ("geido", "幾多"),
+    ("geisi", "幾時"),
+    ("geicin", "幾錢"),
+    ("nidou", "呢度"),
+    ("godou", "嗰度"),
+    ("nigo", "呢個"),
+    ("gogo", "嗰個"),
+    ("jigaa", "而家"),
+    ("gamjat", "今日"),
+    ("tingjat", "聽日"),
+    ("camjat", "尋日"),
+    ("kamjat", "琴日"),
+    ("zungji", "鍾意"),
+    ("zi", "知"),
+    ("zidou", "知道"),
+    ("tai", "睇"),
+    ("gong", "講"),
+    ("gongje", "講嘢"),
+    ("teng", "聽"),
+    ("haang", "行"),
+    ("kei", "企"),
+    ("co", "坐"),
+    ("fan", "瞓"),
+    ("fangaau", "瞓覺"),
+    ("leng", "靚"),
+    ("lengzai", "靚仔"),
+    ("lengneoi", "靚女"),
+    ("houmei", "好味"),
+    ("housik", "好食"),
+    ("houtai", "好睇"),
+    ("houdo", "好多"),
+    ("houci", "好似"),
+    ("houcoi", "好彩"),
+    ("hoenggong", "香港"),
+    ("gwongdungwaa", "廣東話"),
+    ("gwongzau", "廣州"),
+    ("zunggwok", "中國"),
+    ("hoksaang", "學生"),
+    ("lousi", "老師"),
+    ("pangjau", "朋友"),
+    ("ukkei", "屋企"),
+    ("ukkeijan", "屋企人"),
+    ("jan", "人"),
+    ("naamzai", "男仔"),
+    ("neoizai", "女仔"),
+    ("sailou", "細路"),
+    ("sailou", "細佬"),
+    ("saimui", "細妹"),
+    ("baabaa", "爸爸"),
+    ("maamaa", "媽媽"),
+    ("gogo", "哥哥"),
+    ("gaaze", "家姐"),
+    ("cin", "錢"),
+    ("man", "蚊"),
+    ("maai", "買"),
+    ("maai", "賣"),
+    ("maaije", "買嘢"),
+    ("peng", "平"),
+    ("gwai", "貴"),
+    ("daai", "大"),
+    ("sai", "細"),
+    ("do", "多"),
+    ("siu", "少"),
+    ("jat", "一"),
+    ("ji", "二"),
+    ("saam", "三"),
+    ("sei", "四"),
+    ("ng", "五"),
+    ("luk", "六"),
+    ("cat", "七"),
+    ("baat", "八"),
+    ("gau", "九"),
+    ("sap", "十"),
+    ("loeng", "兩"),
+    ("go", "個"),
+    ("zek", "隻"),
+    ("di", "啲"),
+    ("ge", "嘅"),
+    ("zo", "咗"),
+    ("gan", "緊"),
+    ("gwo", "過"),
+    ("tung", "同"),
+    ("dou", "都"),
+    ("zau", "就"),
+    ("zung", "仲"),
+    ("gam", "咁"),
+    ("daanhai", "但係"),
+    ("janwai", "因為"),
+    ("soji", "所以"),
+    ("jyugwo", "如果"),
+    ("hoji", "可以"),
+    ("jiu", "要"),
+    ("soeng", "想"),
+    ("nam", "諗"),
+    ("gokdak", "覺得"),
+    ("hoisam", "開心"),
+    ("gui", "攰"),
+    ("toungo", "肚餓"),
+    ("tinhei", "天氣"),
+    ("lokjyu", "落雨"),
+    ("jit", "熱"),
+    ("dung", "凍"),
+    ("baasi", "巴士"),
+    ("diksi", "的士"),
+    ("deitit", "地鐵"),
+    ("ce", "車"),
+    ("daapce", "搭車"),
+    ("feigei", "飛機"),
+    ("dinwaa", "電話"),
+    ("dinnou", "電腦"),
+    ("saugei", "手機"),
+    ("sigaan", "時間"),
+    ("zung", "鐘"),
+    ("ziuzou", "朝早"),
+    ("aanzau", "晏晝"),
+    ("jemaan", "夜晚"),
+    ("singkei", "星期"),
+    ("laibaai", "禮拜"),
+    ("gungzok", "工作"),
+    ("hokhaau", "學校"),
+    ("jijyun", "醫院"),
+    ("caanteng", "餐廳"),
+    ("caacaanteng", "茶餐廳"),
+    ("naaicaa", "奶茶"),
+    ("gaafe", "咖啡"),
+    ("gai", "雞"),
+    ("jyu", "魚"),
+    ("ngau", "牛"),
+    ("zyu", "豬"),
+    ("coi", "菜"),
+    ("mai", "米"),
+    ("min", "麵"),
+    ("zuk", "粥"),
+    ("dimsam", "點心"),
+    ("haagaau", "蝦餃"),
+    ("siumaai", "燒賣"),
+    ("caasiu", "叉燒"),
+    ("caasiubaau", "叉燒包"),
+    ("gaajau", "加油"),
+    ("gungheifaatcoi", "恭喜發財"),
+    ("sanninfaailok", "新年快樂"),
+    ("saangjatfaailok", "生日快樂"),
+];
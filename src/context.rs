@@ -0,0 +1,130 @@
+// Copyright (c) 2021 Sam Blenny
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+#![forbid(unsafe_code)]
+
+#[cfg(feature = "bigram")]
+use crate::autogen_bigram;
+use crate::autogen_hsk;
+use crate::dialects::Writer;
+#[cfg(feature = "bigram")]
+use crate::predict;
+
+// Context for ranking candidates. The text right before a pinyin key says a
+// lot about which homophone it means: he is 喝 after 想 (想喝), but 和 after
+// 我 (我和你). With the "bigram" feature, candidate lists move the homophone
+// that most often follows the context in the corpus to the front, as
+// ChoiceHistory does for learned choices. Context is the text committed
+// before a query, so it only ranks the first segment of the query; later
+// segments keep their usual order, so that typing a query the same way picks
+// the same 词语. Without the feature there is no bigram table, and context
+// changes nothing.
+
+// Most characters of context that matter: the longest 词语
+const CONTEXT_SIZE: usize = autogen_hsk::HANZI_SIZE_MAX;
+
+// Tail end of committed text, kept as context for the next composition.
+// Writing to it appends, dropping characters off the front to keep only the
+// last CONTEXT_SIZE.
+pub struct Context {
+    buf: [u8; 4 * CONTEXT_SIZE],
+    len: usize,
+}
+impl Context {
+    // Return empty context ready for use.
+    pub fn new() -> Context {
+        Context {
+            buf: [0; 4 * CONTEXT_SIZE],
+            len: 0,
+        }
+    }
+
+    // Replace the context with the end of text.
+    pub fn set(&mut self, text: &str) {
+        self.clear();
+        self.write(text);
+    }
+
+    // Forget the context.
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    // Return the context as a string slice.
+    pub fn as_str(&self) -> &str {
+        // Bad utf8 can't happen since buf only gets whole chars.
+        core::str::from_utf8(&self.buf[..self.len]).unwrap_or_default()
+    }
+}
+impl Writer for Context {
+    // Append message, keeping only the last CONTEXT_SIZE characters
+    fn write(&mut self, message: &str) {
+        for c in message.chars() {
+            if self.as_str().chars().count() == CONTEXT_SIZE {
+                let first = self.as_str().chars().next().map_or(0, char::len_utf8);
+                self.buf.copy_within(first..self.len, 0);
+                self.len -= first;
+            }
+            let n = c.len_utf8();
+            c.encode_utf8(&mut self.buf[self.len..self.len + n]);
+            self.len += n;
+        }
+    }
+
+    // Ignore traces
+    fn trace(&mut self, _: i32) {}
+
+    // Return string slice of context.
+    fn to_s(&self) -> &str {
+        self.as_str()
+    }
+}
+
+// How many times ciyu (in Simplified characters) follows the last word of
+// context in the bigram corpus, or 0 if it never does.
+#[cfg(feature = "bigram")]
+pub fn follow_count(context: &str, ciyu: &str) -> u32 {
+    let i = match predict::match_last_word(context) {
+        Some(i) => i,
+        None => return 0,
+    };
+    let mut words = autogen_bigram::NEXT[i].split('\t');
+    match words.position(|w| w == ciyu) {
+        Some(k) => autogen_bigram::NEXT_COUNT[i][k],
+        None => 0,
+    }
+}
+
+// Without the bigram table, nothing follows anything.
+#[cfg(not(feature = "bigram"))]
+pub fn follow_count(_context: &str, _ciyu: &str) -> u32 {
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn context_keeps_the_end_of_committed_text() {
+        let mut context = Context::new();
+        assert_eq!(context.as_str(), "");
+        context.write("我想");
+        context.write("喝");
+        assert_eq!(context.as_str(), "我想喝");
+        context.set("abcdefghij我想");
+        assert_eq!(context.as_str().chars().count(), CONTEXT_SIZE);
+        assert!(context.as_str().ends_with("ij我想"));
+        context.clear();
+        assert_eq!(context.to_s(), "");
+    }
+
+    #[test]
+    #[cfg(feature = "bigram")]
+    fn follow_counts_come_from_the_last_word() {
+        assert!(follow_count("我想", "喝") > 0);
+        assert!(follow_count("想", "喝") > follow_count("想", "和"));
+        assert_eq!(follow_count("想。", "喝"), 0);
+        assert_eq!(follow_count("", "喝"), 0);
+    }
+}
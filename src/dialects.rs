@@ -3,39 +3,112 @@
 //
 #![forbid(unsafe_code)]
 
+#[cfg(feature = "bigram")]
+use crate::autogen_bigram;
+#[cfg(feature = "gb2312")]
+use crate::autogen_gb2312;
 use crate::autogen_hsk;
+use crate::autogen_yue;
 use crate::constants;
+use crate::fuzzy::{self, FuzzyRules};
+use crate::lattice;
+use crate::learn::ChoiceHistory;
 use crate::lex;
 use crate::m3hash;
+use crate::segment;
+use crate::shuangpin::ShuangpinLayout;
+use crate::user_dict::{UserDict, UserEntry};
+use crate::zhuyin;
 
-pub fn translate_zh_hans(pinyin_ascii: &str) -> &str {
-    let _ = m3hash::grapheme_cluster(&"", 0, 1);
-    pinyin_ascii
+/// Written form of Chinese for lookup results. Both use the same pinyin keys.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum Dialect {
+    /// Simplified characters (zh-Hans), as used in mainland China
+    #[default]
+    ZhHans,
+    /// Traditional characters (zh-Hant), as used in Taiwan
+    ZhHant,
 }
 
 // Static word list arrays generated by vocab precompute ruby script
 // CiyuIndex is type for phrases listed in autogen_hsk::CIYU array
 pub type CiyuIndex = usize;
+// AbbrevIndex is type for phrases listed in autogen_hsk::ABBREV_CIYU array
+pub type AbbrevIndex = usize;
+// ZiIndex is type for characters listed in autogen_gb2312::ZI array
+#[cfg(feature = "gb2312")]
+pub type ZiIndex = usize;
 
-fn min(a: usize, b: usize) -> usize {
-    if a < b {
-        a
-    } else {
-        b
+// Built-in match for a search key. Keys match 词语 by full pinyin, or else by
+// syllable-initial abbreviation (like "sxym" for 赏心悦目). With the gb2312
+// feature, single syllables that match neither get single characters.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum KeyMatch {
+    Ciyu(CiyuIndex),
+    Abbrev(AbbrevIndex),
+    #[cfg(feature = "gb2312")]
+    Zi(ZiIndex),
+}
+
+// Most fuzzy pinyin matches to keep for one search key
+pub const FUZZY_MATCH_MAX: usize = 4;
+
+// Built-in matches for a search key: the key's own match, if any, and matches
+// for fuzzy pinyin variants of the key.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct KeyMatches {
+    pub key: Option<KeyMatch>,
+    fuzzy: [CiyuIndex; FUZZY_MATCH_MAX],
+    fuzzy_count: usize,
+}
+impl KeyMatches {
+    pub const NONE: KeyMatches = KeyMatches {
+        key: None,
+        fuzzy: [0; FUZZY_MATCH_MAX],
+        fuzzy_count: 0,
+    };
+
+    // Find built-in matches for key. Abbreviations are only a fall back for
+    // keys that don't match anything as pinyin, including user words, and
+    // single characters (with the gb2312 feature) are the last resort.
+    pub fn find(key: &str, has_user_words: bool, rules: FuzzyRules) -> KeyMatches {
+        let mut matches = KeyMatches::NONE;
+        matches.key = match_key(key).map(KeyMatch::Ciyu);
+        fuzzy::for_each_variant(key, rules, &mut |variant| {
+            if let Some(i) = match_key(variant) {
+                if matches.fuzzy_count < FUZZY_MATCH_MAX && !matches.fuzzy().contains(&i) {
+                    matches.fuzzy[matches.fuzzy_count] = i;
+                    matches.fuzzy_count += 1;
+                }
+            }
+        });
+        if matches.key.is_none() && matches.fuzzy_count == 0 && !has_user_words {
+            matches.key = match_abbrev(key).map(KeyMatch::Abbrev);
+        }
+        #[cfg(feature = "gb2312")]
+        if matches.key.is_none() && matches.fuzzy_count == 0 && !has_user_words {
+            matches.key = match_zi(key).map(KeyMatch::Zi);
+        }
+        matches
+    }
+
+    // Matches for fuzzy variants of the key, in the order they were found.
+    pub fn fuzzy(&self) -> &[CiyuIndex] {
+        &self.fuzzy[..self.fuzzy_count]
     }
 }
 
 // Utf8Str adds character boundary metadata to &str to help with safely slicing
 // substrings. "Safely" means avoid panic from requesting slice with byte range
 // not aligned on encoded Unicode character boundaries.
-struct Utf8Str<'a> {
+pub struct Utf8Str<'a> {
     str_slice: &'a str,
     char_start_list: [usize; constants::BUF_SIZE],
     char_end_list: [usize; constants::BUF_SIZE],
-    char_count: usize,
+    pub char_count: usize,
 }
 impl<'a> Utf8Str<'a> {
-    pub fn new(str_slice: &'a str) -> Utf8Str {
+    pub fn new(str_slice: &'a str) -> Utf8Str<'a> {
         // Find start (inclusive lower bound) and end (exclusive upper bound) byte
         // index of each UTF-8 character in string slice
         let mut char_start_list: [usize; constants::BUF_SIZE] = [0; constants::BUF_SIZE];
@@ -54,8 +127,8 @@ impl<'a> Utf8Str<'a> {
         }
         Utf8Str {
             str_slice,
-            char_start_list: char_start_list,
-            char_end_list: char_end_list,
+            char_start_list,
+            char_end_list,
             char_count,
         }
     }
@@ -63,7 +136,7 @@ impl<'a> Utf8Str<'a> {
     // Slice a substring using character range (not bytes!).
     // Using get(start..end) instead of [start..end] avoids possible panic.
     // This follows start..end range semantics (upper bound exclusive).
-    pub fn char_slice(&self, start: usize, end: usize) -> Option<&str> {
+    pub fn char_slice(&self, start: usize, end: usize) -> Option<&'a str> {
         // Subtle point: implicit test for end > 0
         if start < end && end <= constants::BUF_SIZE {
             let start_b = self.char_start_list[start];
@@ -80,185 +153,259 @@ impl<'a> Utf8Str<'a> {
 // Credits: Derived from MurmurHash3.cpp (public domain) by Austin Appleby.
 // Returns: u32 hash
 pub fn murmur3(key: &str, seed: u32) -> u32 {
-    let mut h = seed;
-    let mut k;
-    // Hash each character as its own u32 block
-    for c in key.chars() {
-        k = c as u32;
-        k = k.wrapping_mul(0xcc9e2d51);
-        k = k.rotate_left(15);
-        k = k.wrapping_mul(0x1b873593);
-        h ^= k;
-        h = h.rotate_left(13);
-        h = h.wrapping_mul(5);
-        h = h.wrapping_add(0xe6546b64);
-    }
-    h ^= key.bytes().count() as u32;
-    // Finalize with avalanche
-    h ^= h >> 16;
-    h = h.wrapping_mul(0x85ebca6b);
-    h ^= h >> 13;
-    h = h.wrapping_mul(0xc2b2ae35);
-    h ^ (h >> 16)
-}
-
-// Find longest 词语 match in start..end character window of query buffer.
+    let (h, _) = m3hash::grapheme_cluster(key, seed, u32::MAX);
+    h
+}
+
+// Find 词语 match for a pinyin search key.
 // Side-effect: None.
-// Return: (index in 词语 array for match, end boundary character in query for match)
-fn longest_match(query: &Utf8Str, start: usize, mut end: usize) -> Option<(CiyuIndex, usize)> {
-    end = min(query.char_count, end);
-    // Subtle point: implicit test for end > 0
-    while end > start {
-        if let Some(query_slice) = query.char_slice(start, end) {
-            let key = murmur3(&query_slice, autogen_hsk::MURMUR3_SEED);
-            if let Ok(ciyu) = autogen_hsk::PINYIN.binary_search(&key) {
-                return Some((ciyu, end));
-            }
-        }
-        // Must not allow end==0 here. For usize, (0 - 1) will panic.
-        end -= 1;
-    }
-    return None;
+// Return: index in 词语 array for match
+pub fn match_key(key: &str) -> Option<CiyuIndex> {
+    find_key(
+        key,
+        autogen_hsk::MURMUR3_SEED,
+        autogen_hsk::PINYIN,
+        autogen_hsk::PINYIN_KEYS,
+    )
 }
 
-// Render 词语 multi-matches as resolved choice or prompt for choice.
-// Side-effect: render strings into buffer provided by Writer.
-// Return: Was the maybe_choice token used to resolve a choice?
-pub enum ExpandChoiceResult {
-    WasChoice,
-    WasNotChoice,
+// Find 词语 match for a syllable-initial abbreviation like "sxym".
+// Side-effect: None.
+// Return: index in abbreviation 词语 array for match
+pub fn match_abbrev(key: &str) -> Option<AbbrevIndex> {
+    find_key(
+        key,
+        autogen_hsk::MURMUR3_SEED,
+        autogen_hsk::ABBREV,
+        autogen_hsk::ABBREV_KEYS,
+    )
 }
-pub fn expand_choice_and_write(
-    ciyu: &str,
-    maybe_choice: char,
-    sink: &mut impl Writer,
-) -> ExpandChoiceResult {
-    let n = ciyu.split("\t").count();
-    if n == 1 {
-        // If this ever happens, there's a bug. Log and recover.
-        sink.trace(901);
-        sink.write(ciyu);
-        return ExpandChoiceResult::WasNotChoice;
-    }
-    // Try to pick a choice (return immediately if number out of range)
-    let pick = match maybe_choice {
-        ' ' => 1, // Spacebar picks default option (label=1)
-        '1' => 1,
-        '2' => 2,
-        '3' => 3,
-        '4' => 4,
-        '5' => 5,
-        '6' => 6,
-        '7' => 7,
-        '8' => 8,
-        '9' => 9, // TODO: Fix. This only works for <=9 choices
-        _ => 0,
-    };
-    if pick > 0 {
-        for (i, choice) in ciyu.split("\t").enumerate() {
-            if i + 1 == pick {
-                sink.write(choice);
-                return ExpandChoiceResult::WasChoice;
-            }
-        }
-        // Out of range for possible choice, so return without sink.write() to
-        // prevent duplicate choice prompting
-        return ExpandChoiceResult::WasNotChoice;
-    }
-    // Show all choices
-    sink.write(&" (");
-    for (i, choice) in ciyu.split("\t").enumerate() {
-        sink.write(match i {
-            0 => &"1",
-            1 => &"2",
-            2 => &"3",
-            3 => &"4",
-            4 => &"5",
-            5 => &"6",
-            6 => &"7",
-            7 => &"8",
-            _ => &"9",
-        });
-        sink.write(choice);
-        if i + 1 < n {
-            sink.write(&" ");
+
+// Find single character match for a pinyin syllable like "xiong".
+// Side-effect: None.
+// Return: index in single character array for match
+#[cfg(feature = "gb2312")]
+pub fn match_zi(key: &str) -> Option<ZiIndex> {
+    find_key(
+        key,
+        autogen_gb2312::MURMUR3_SEED,
+        autogen_gb2312::PINYIN,
+        autogen_gb2312::PINYIN_KEYS,
+    )
+}
+
+// Find reverse index match for a 词语 written in hanzi, like "果汁".
+// Side-effect: None.
+// Return: index in reverse index arrays for match
+pub fn match_hanzi(key: &str) -> Option<usize> {
+    find_key(
+        key,
+        autogen_hsk::MURMUR3_SEED,
+        autogen_hsk::HANZI,
+        autogen_hsk::HANZI_KEYS,
+    )
+}
+
+// Find bigram table match for the 词语 before a next word, like "想".
+// Side-effect: None.
+// Return: index in next word arrays for match
+#[cfg(feature = "bigram")]
+pub fn match_bigram(key: &str) -> Option<usize> {
+    find_key(
+        key,
+        autogen_bigram::MURMUR3_SEED,
+        autogen_bigram::PREV,
+        autogen_bigram::PREV_KEYS,
+    )
+}
+
+// Find Cantonese 詞語 match for a Jyutping search key without tone numbers.
+// Side-effect: None.
+// Return: index in Cantonese 詞語 array for match
+pub fn match_jyutping(key: &str) -> Option<usize> {
+    find_key(
+        key,
+        autogen_yue::MURMUR3_SEED,
+        autogen_yue::JYUTPING,
+        autogen_yue::JYUTPING_KEYS,
+    )
+}
+
+// Find key in a table of sorted murmur3 hashes and the keys they came from.
+fn find_key(key: &str, seed: u32, hashes: &[u32], keys: &[&str]) -> Option<usize> {
+    let hash = murmur3(key, seed);
+    if let Ok(i) = hashes.binary_search(&hash) {
+        // Random input can collide with the hash of a real key, so
+        // only accept the match if the keys are really the same.
+        if keys[i] == key {
+            return Some(i);
         }
     }
-    sink.write(&") ");
-    return ExpandChoiceResult::WasNotChoice;
+    None
 }
 
-// Search for 词语 matches in substrings of query.
-// Side-effect: Push tokens into queue.
-fn search(
+// Search for the lowest cost segmentation of query into 词语 matches and
+// unmatched characters.
+// Side-effect: Push tokens into queue. Token spans are relative to query.
+pub fn search(
     query: &Utf8Str,
     queue: &mut lex::TokenQueue,
-    mut start: usize,
-    end: usize,
+    cost: &impl lattice::SegmentCost,
+    user: &[UserEntry],
+    options: lattice::KeyOptions,
     sink: &mut impl Writer,
 ) {
-    while start < end {
-        // Limit window size to length of longest phrase in pinyin array
-        let window_end = min(start + autogen_hsk::PINYIN_SIZE_MAX, end);
-        if let Some((ciyu_i, match_end)) = longest_match(query, start, window_end) {
-            // Got Match: push match, continue search in remainder of query
-            if autogen_hsk::CIYU[ciyu_i].contains("\t") {
-                queue.push(lex::Token::CiOpenChoice(ciyu_i));
-            } else {
-                queue.push(lex::Token::CiOne(ciyu_i));
-            }
-            start = match_end;
-        } else {
-            // No match... push one character, continue search in remainder of query
-            if let Some(s) = query.char_slice(start, start + 1) {
-                // TODO: Better solution than silently ignoring possible full queue
-                let _ = match s {
-                    // Space and digit characters may be intended to resolve a
-                    // choice of homophone 词语 from an earlier CiOpenChoice
-                    // token. Spaces may separate the pinyin from a CiOne token
-                    // so the pinyin does not get consumed as the prefix to a
-                    // longer 词语. Spaces and digits may also be intended to
-                    // pass through as ASCII.
-                    " " => queue.push(lex::Token::MaybeChoice(' ')),
-                    "1" => queue.push(lex::Token::MaybeChoice('1')),
-                    "2" => queue.push(lex::Token::MaybeChoice('2')),
-                    "3" => queue.push(lex::Token::MaybeChoice('3')),
-                    "4" => queue.push(lex::Token::MaybeChoice('4')),
-                    "5" => queue.push(lex::Token::MaybeChoice('5')),
-                    "6" => queue.push(lex::Token::MaybeChoice('6')),
-                    "7" => queue.push(lex::Token::MaybeChoice('7')),
-                    "8" => queue.push(lex::Token::MaybeChoice('8')),
-                    "9" => queue.push(lex::Token::MaybeChoice('9')),
-                    _ => {
-                        if let Some(c) = s.chars().nth(0) {
-                            // This covers stuff like "UPPER CASE" and emoji
-                            queue.push(lex::Token::Other(c))
-                        } else {
-                            // Reaching this branch is a bug. For nth(0) to
-                            // return None, s would have to be "" when
-                            // s.chars() gets called. The `if let Some(s)` and
-                            // `while start < end` above should not allow that
-                            // to happen.
-                            sink.trace(902);
-                            false
-                        }
-                    }
-                };
-            }
-            start += 1;
-        }
+    let mut lattice = lattice::Lattice::new();
+    for _ in 0..query.char_count {
+        lattice.extend(query, 0, cost, user, options, sink);
     }
+    lattice.tokens(queue);
 }
 
 // Look up 词语 for search query (pinyin keys are ASCII, but inbox is UTF-8).
 // Side-effect: renders utf8 result string into buffer provided by Writer.
 pub fn look_up(query_bytes: &str, sink: &mut impl Writer) {
+    look_up_with_options(query_bytes, &LookupOptions::default(), sink);
+}
+
+/// Options for look_up_with_options() and look_up_segments_with_options().
+/// Start from LookupOptions::default(), or LookupOptions::with_cost() for a
+/// custom segmentation cost, then set the fields you need.
+#[derive(Copy, Clone)]
+pub struct LookupOptions<'a, C = lattice::DefaultCost> {
+    /// Cost used to pick the best segmentation of the query
+    pub cost: C,
+    /// User dictionary to look up words in alongside the built-in tables.
+    /// User words come before built-in homophones in candidate lists.
+    pub dict: Option<&'a UserDict<'a>>,
+    /// Learned choices, which come first in candidate lists
+    pub history: Option<&'a ChoiceHistory<'a>>,
+    /// Text committed right before the query (a few hanzi are enough). With
+    /// the "bigram" feature, the candidate list of the first segment puts the
+    /// 词语 that most often follows context first, so "he" gives 喝 after 想.
+    pub context: &'a str,
+    /// Fuzzy pinyin variants to match too. Exact matches come before fuzzy
+    /// ones.
+    pub fuzzy: FuzzyRules,
+    /// Tone input: digits 1-5 after pinyin are tones (5 for neutral tone)
+    /// that narrow down the candidates, and the shifted digits "!@#$%^&*("
+    /// pick choices 1-9.
+    pub tones: bool,
+    /// Keys typed on a Zhuyin keyboard with the Dachen layout, so "5j/ ej6"
+    /// is ㄓㄨㄥ ㄍㄨㄛˊ. Tone keys end a syllable and narrow down the
+    /// candidates, and the shifted digits "!@#$%^&*(" pick choices 1-9.
+    /// Segments borrow from the query, so look_up_segments_with_options()
+    /// takes the zhuyin itself rather than the keys, with the same tone and
+    /// choice keys.
+    pub zhuyin: bool,
+    /// Shuangpin (double pinyin) layout, if keys are typed that way, so
+    /// "vsgo" is zhong guo on the built-in layouts. Keys pair up into
+    /// syllables from the start of each run of them.
+    pub shuangpin: Option<ShuangpinLayout>,
+    /// Written form of the results. User dictionary words don't get
+    /// converted.
+    pub dialect: Dialect,
+}
+impl Default for LookupOptions<'_> {
+    fn default() -> Self {
+        LookupOptions::with_cost(lattice::DefaultCost)
+    }
+}
+impl<'a, C: lattice::SegmentCost> LookupOptions<'a, C> {
+    /// Return default options, but with cost for picking segmentations.
+    pub fn with_cost(cost: C) -> LookupOptions<'a, C> {
+        LookupOptions {
+            cost,
+            dict: None,
+            history: None,
+            context: "",
+            fuzzy: FuzzyRules::NONE,
+            tones: false,
+            zhuyin: false,
+            shuangpin: None,
+            dialect: Dialect::ZhHans,
+        }
+    }
+
+    // User dictionary words, or none.
+    fn user(&self) -> &'a [UserEntry] {
+        self.dict.map_or(&[], |dict| dict.entries())
+    }
+
+    // What to rank candidate lists by.
+    fn ranking(&self) -> lex::Ranking<'a> {
+        lex::Ranking {
+            history: self.history.map_or(&[], |history| history.entries()),
+            context: self.context,
+        }
+    }
+
+    // How to spell search keys.
+    fn key_options(&self) -> lattice::KeyOptions {
+        lattice::KeyOptions {
+            fuzzy: self.fuzzy,
+            tones: self.tones,
+            zhuyin: self.zhuyin,
+            shuangpin: self.shuangpin,
+        }
+    }
+}
+
+/// Look up 词语 for search query, with opts for the cost, user dictionary,
+/// learned choices, context, fuzzy pinyin rules, tone, Zhuyin or Shuangpin
+/// input, and dialect.
+/// Side-effect: renders utf8 result string into buffer provided by Writer.
+pub fn look_up_with_options<C: lattice::SegmentCost>(
+    query_bytes: &str,
+    opts: &LookupOptions<'_, C>,
+    sink: &mut impl Writer,
+) {
+    let mut buf = [0; zhuyin::DACHEN_BUF_SIZE];
+    let query = if opts.zhuyin {
+        zhuyin::from_dachen(query_bytes, &mut buf)
+    } else {
+        query_bytes
+    };
+    let mut segments = segment::SegmentList::new();
+    look_up_segments_and_trace(query, opts, &mut segments, sink);
+    segments.render_and_write(segment::RenderMode::Compose, sink);
+}
+
+/// Look up 词语 for search query, returning the results as typed data rather
+/// than rendered text.
+/// Side-effect: fills out with one Segment per 词语 match or run of
+/// unmatched characters, in query order.
+pub fn look_up_segments<'a>(query_bytes: &'a str, out: &mut segment::SegmentList<'a>) {
+    look_up_segments_with_options(query_bytes, &LookupOptions::default(), out);
+}
+
+/// Look up 词语 for search query with opts, returning the results as typed
+/// data rather than rendered text.
+/// Side-effect: fills out with one Segment per 词语 match or run of
+/// unmatched characters, in query order.
+pub fn look_up_segments_with_options<'a, C: lattice::SegmentCost>(
+    query_bytes: &'a str,
+    opts: &LookupOptions<'a, C>,
+    out: &mut segment::SegmentList<'a>,
+) {
+    let mut sink = BufWriter::new();
+    look_up_segments_and_trace(query_bytes, opts, out, &mut sink);
+}
+
+// Shared part of look_up and look_up_segments. Traces go to sink.
+fn look_up_segments_and_trace<'a, C: lattice::SegmentCost>(
+    query_bytes: &'a str,
+    opts: &LookupOptions<'a, C>,
+    out: &mut segment::SegmentList<'a>,
+    sink: &mut impl Writer,
+) {
+    let options = opts.key_options();
     let query = Utf8Str::new(query_bytes);
     let mut queue = lex::TokenQueue::new();
-    let start = 0;
-    let end = query.char_count;
-    search(&query, &mut queue, start, end, sink);
-    queue.render_and_write(sink);
+    let user = opts.user();
+    search(&query, &mut queue, &opts.cost, user, options, sink);
+    out.clear();
+    queue.resolve(&query, user, opts.ranking(), options, opts.dialect, out);
 }
 
 // Writer decouples query response formatting from stream IO implementation details.
@@ -286,6 +433,11 @@ impl BufWriter {
         self.buf_pos = 0;
     }
 }
+impl Default for BufWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 impl Writer for BufWriter {
     // Append message to buffer
     fn write(&mut self, message: &str) {
@@ -303,18 +455,45 @@ impl Writer for BufWriter {
 
     // Return string slice of buffer contents.
     fn to_s(&self) -> &str {
-        match core::str::from_utf8(&self.buf[0..self.buf_pos]) {
-            Ok(s) => &s,
-            Err(_) => &"", // TODO: handle mal-formed utf8 strings better
-        }
+        // TODO: handle mal-formed utf8 strings better.
+        core::str::from_utf8(&self.buf[0..self.buf_pos]).unwrap_or_default()
     }
 }
 
+/// Convert a whole string of pinyin to Simplified hanzi in one shot, as if it
+/// had been typed and then committed: open choices take their default
+/// candidate, and nothing gets rendered as a choice prompt. Spaces and digits
+/// after pinyin still pick choices, and unmatched characters pass through.
+/// Side-effect: renders utf8 result string into buffer provided by Writer.
+/// Return: string slice of results backed by sink.
+pub fn translate_zh_hans<'a>(pinyin: &str, sink: &'a mut impl Writer) -> &'a str {
+    translate(pinyin, Dialect::ZhHans, sink)
+}
+
+/// Same as translate_zh_hans, but giving Traditional hanzi.
+/// Side-effect: renders utf8 result string into buffer provided by Writer.
+/// Return: string slice of results backed by sink.
+pub fn translate_zh_hant<'a>(pinyin: &str, sink: &'a mut impl Writer) -> &'a str {
+    translate(pinyin, Dialect::ZhHant, sink)
+}
+
+// Shared part of translate_zh_hans and translate_zh_hant.
+fn translate<'a>(pinyin: &str, dialect: Dialect, sink: &'a mut impl Writer) -> &'a str {
+    let mut segments = segment::SegmentList::new();
+    let opts = LookupOptions {
+        dialect,
+        ..Default::default()
+    };
+    look_up_segments_and_trace(pinyin, &opts, &mut segments, sink);
+    segments.render_and_write(segment::RenderMode::Commit, sink);
+    sink.to_s()
+}
+
 // Look up query, write results to sink.
 // This is for calling as a library function from rust.
 // Returns: string slice of results backed by sink.
 pub fn query<'a>(qry: &str, sink: &'a mut impl Writer) -> &'a str {
-    look_up(&qry, sink);
+    look_up(qry, sink);
     sink.to_s()
 }
 
@@ -324,12 +503,12 @@ mod tests {
 
     #[test]
     fn min_query() {
-        assert_eq!("", query(&"", &mut BufWriter::new()));
+        assert_eq!("", query("", &mut BufWriter::new()));
     }
 
     #[test]
     fn max_query() {
-        let buf_max = ['A' as u8; constants::BUF_SIZE];
+        let buf_max = [b'A'; constants::BUF_SIZE];
         let qry_max = core::str::from_utf8(&buf_max).unwrap();
         // This should be passed through unchanged as ASCII
         assert_eq!(qry_max, query(qry_max, &mut BufWriter::new()));
@@ -337,9 +516,9 @@ mod tests {
 
     #[test]
     fn max_query_plus_1_truncate() {
-        let buf_max = ['A' as u8; constants::BUF_SIZE];
+        let buf_max = [b'A'; constants::BUF_SIZE];
         let qry_max = core::str::from_utf8(&buf_max).unwrap();
-        let buf_1_too_big = ['A' as u8; constants::BUF_SIZE + 1];
+        let buf_1_too_big = [b'A'; constants::BUF_SIZE + 1];
         let qry_1_too_big = core::str::from_utf8(&buf_1_too_big).unwrap();
         // This should truncate the query
         assert_eq!(qry_max, query(qry_1_too_big, &mut BufWriter::new()));
@@ -347,13 +526,13 @@ mod tests {
 
     #[test]
     fn choice_xiang1() {
-        assert_eq!("想", query(&"xiang1", &mut BufWriter::new()));
+        assert_eq!("向", query("xiang1", &mut BufWriter::new()));
     }
 
     #[test]
     fn zhang3chang2() {
-        assert!(query(&"zhang", &mut BufWriter::new()).contains("长"));
-        assert!(query(&"chang", &mut BufWriter::new()).contains("长"));
+        assert!(query("zhang", &mut BufWriter::new()).contains("长"));
+        assert!(query("chang", &mut BufWriter::new()).contains("长"));
     }
 
     #[test]
@@ -366,46 +545,94 @@ mod tests {
 
     #[test]
     fn choosing_ciyu_with_numbers_and_spaces() {
-        assert!(query(&"xiang", &mut BufWriter::new()).contains("(1想"));
-        assert!(query(&"xiang", &mut BufWriter::new()).contains("2向"));
-        assert_eq!(query(&"xiang ", &mut BufWriter::new()), "想");
-        assert!(query(&" xiang", &mut BufWriter::new()).starts_with(" "));
-        assert!(query(&" xiang", &mut BufWriter::new()).contains("(1想"));
-        assert_eq!(query(&"xiang1", &mut BufWriter::new()), "想");
-        assert_eq!(query(&"xiang2", &mut BufWriter::new()), "向");
-        assert!(query(&"xianghe", &mut BufWriter::new()).contains("(1想"));
-        assert!(query(&"xianghe", &mut BufWriter::new()).contains("2向"));
-        assert!(query(&"xianghe", &mut BufWriter::new()).contains("(1喝"));
-        assert!(query(&"xianghe", &mut BufWriter::new()).contains("2和"));
-        assert!(query(&"xiang he", &mut BufWriter::new()).starts_with("想"));
-        assert!(query(&"xiang he", &mut BufWriter::new()).contains("(1喝"));
-        assert!(query(&"xiang1he", &mut BufWriter::new()).starts_with("想"));
-        assert!(query(&"xiang1he", &mut BufWriter::new()).contains("(1喝"));
-        assert!(query(&"xianghe1", &mut BufWriter::new()).starts_with("想"));
-        assert!(query(&"xianghe1", &mut BufWriter::new()).contains("(1喝"));
-        assert!(query(&"xianghe ", &mut BufWriter::new()).starts_with("想"));
-        assert!(query(&"xianghe ", &mut BufWriter::new()).contains("(1喝"));
-        assert_eq!(query(&"xianghe 1", &mut BufWriter::new()), "想喝");
-        assert_eq!(query(&"xianghe11", &mut BufWriter::new()), "想喝");
-        assert_eq!(query(&"xiang he1", &mut BufWriter::new()), "想喝");
-        assert_eq!(query(&"xiang he ", &mut BufWriter::new()), "想喝");
-        assert_eq!(query(&"xianghe 2", &mut BufWriter::new()), "想和");
+        assert!(query("xiang", &mut BufWriter::new()).contains("(1向"));
+        assert!(query("xiang", &mut BufWriter::new()).contains("2想"));
+        assert_eq!(query("xiang ", &mut BufWriter::new()), "向");
+        assert!(query(" xiang", &mut BufWriter::new()).starts_with(" "));
+        assert!(query(" xiang", &mut BufWriter::new()).contains("(1向"));
+        assert_eq!(query("xiang1", &mut BufWriter::new()), "向");
+        assert_eq!(query("xiang2", &mut BufWriter::new()), "想");
+        assert!(query("xianghe", &mut BufWriter::new()).contains("(1向"));
+        assert!(query("xianghe", &mut BufWriter::new()).contains("2想"));
+        assert!(query("xianghe", &mut BufWriter::new()).contains("(1和"));
+        assert!(query("xianghe", &mut BufWriter::new()).contains("2河"));
+        assert!(query("xiang he", &mut BufWriter::new()).starts_with("向"));
+        assert!(query("xiang he", &mut BufWriter::new()).contains("(1和"));
+        assert!(query("xiang1he", &mut BufWriter::new()).starts_with("向"));
+        assert!(query("xiang1he", &mut BufWriter::new()).contains("(1和"));
+        assert!(query("xianghe1", &mut BufWriter::new()).starts_with("向"));
+        assert!(query("xianghe1", &mut BufWriter::new()).contains("(1和"));
+        assert!(query("xianghe ", &mut BufWriter::new()).starts_with("向"));
+        assert!(query("xianghe ", &mut BufWriter::new()).contains("(1和"));
+        assert_eq!(query("xianghe 1", &mut BufWriter::new()), "向和");
+        assert_eq!(query("xianghe11", &mut BufWriter::new()), "向和");
+        assert_eq!(query("xiang he1", &mut BufWriter::new()), "向和");
+        assert_eq!(query("xiang he ", &mut BufWriter::new()), "向和");
+        assert_eq!(query("xianghe 2", &mut BufWriter::new()), "向河");
+    }
+
+    #[test]
+    fn choices_are_ordered_by_frequency() {
+        // 和 is more common than 喝, even though 喝 comes first in hsk1.tsv
+        assert!(query("he", &mut BufWriter::new()).starts_with(" (1和 2河 3喝"));
+        assert_eq!(query("he ", &mut BufWriter::new()), "和");
+        for i in 0..autogen_hsk::CIYU.len() {
+            let weights = autogen_hsk::CIYU_WEIGHT[i];
+            assert_eq!(weights.len(), autogen_hsk::CIYU[i].split('\t').count());
+            assert!(weights.windows(2).all(|w| w[0] >= w[1]));
+        }
+    }
+
+    #[test]
+    fn context_ranks_candidates() {
+        let look_up_with_context = |query, context, sink: &mut BufWriter| {
+            let opts = LookupOptions {
+                context,
+                ..Default::default()
+            };
+            look_up_with_options(query, &opts, sink);
+        };
+        let mut sink = BufWriter::new();
+        // Without context, it's the same as look_up()
+        look_up_with_context("he", "", &mut sink);
+        assert!(sink.to_s().starts_with(" (1和 2河 3喝"));
+        sink.rewind();
+        look_up_with_context("he", "我", &mut sink);
+        assert!(sink.to_s().starts_with(" (1和 2河 3喝"));
+        sink.rewind();
+        look_up_with_context("he", "我想", &mut sink);
+        #[cfg(feature = "bigram")]
+        assert!(sink.to_s().starts_with(" (1喝 2和 3河)"));
+        #[cfg(not(feature = "bigram"))]
+        assert!(sink.to_s().starts_with(" (1和 2河 3喝"));
+        // Later segments are ranked by the 词语 before them, whether it's the
+        // default choice or a picked one
+        sink.rewind();
+        look_up_with_context("xianghe", "我", &mut sink);
+        #[cfg(feature = "bigram")]
+        assert!(sink.to_s().ends_with(" (1喝 2和 3河) "));
+        #[cfg(not(feature = "bigram"))]
+        assert!(sink.to_s().ends_with(" (1和 2河 3喝) "));
+        sink.rewind();
+        look_up_with_context("xiang2he", "我", &mut sink);
+        #[cfg(feature = "bigram")]
+        assert_eq!(sink.to_s(), "向 (1和 2河 3喝) ");
     }
 
     #[test]
     fn query_chars_not_matched_should_pass_through() {
-        assert_eq!(query(&"🐇✨", &mut BufWriter::new()), "🐇✨");
-        assert_eq!(query(&"baiSEde🐇✨11", &mut BufWriter::new()), "白SE的🐇✨");
+        assert_eq!(query("🐇✨", &mut BufWriter::new()), "🐇✨");
+        assert_eq!(query("baiSEde🐇✨11", &mut BufWriter::new()), "白SE的🐇✨");
         assert_eq!(
-            query(&"RABBIT SPARKLES 11", &mut BufWriter::new()),
+            query("RABBIT SPARKLES 11", &mut BufWriter::new()),
             "RABBIT SPARKLES 11"
         );
-        assert_eq!(query(&"XIANGHE", &mut BufWriter::new()), "XIANGHE");
+        assert_eq!(query("XIANGHE", &mut BufWriter::new()), "XIANGHE");
     }
 
     #[test]
     fn matching_buffer_sizes() {
-        let utf8s = super::Utf8Str::new(&"slice");
+        let utf8s = super::Utf8Str::new("slice");
         let u_len_s = utf8s.char_start_list.len();
         let u_len_e = utf8s.char_end_list.len();
         let tq = super::lex::TokenQueue::new();
@@ -420,21 +647,184 @@ mod tests {
 
     #[test]
     fn space_disambiguating_pinyin_prefix_is_consumed() {
-        assert_eq!("昆虫", query(&"kunchong", &mut BufWriter::new()));
-        assert_eq!("困冲", query(&"kun chong", &mut BufWriter::new()));
-        assert_eq!("困冲", query(&"kun chong ", &mut BufWriter::new()));
+        assert_eq!("昆虫", query("kunchong", &mut BufWriter::new()));
+        assert_eq!("困冲", query("kun chong", &mut BufWriter::new()));
+        assert_eq!("困冲", query("kun chong ", &mut BufWriter::new()));
+        // With the bigram table, each 词语 ranks the choices after it
+        #[cfg(feature = "bigram")]
         assert_eq!(
             "我想喝果汁",
-            query(&"wo xiang he guozhi", &mut BufWriter::new())
+            query("wo xiang he guozhi", &mut BufWriter::new())
+        );
+        #[cfg(not(feature = "bigram"))]
+        assert_eq!(
+            "我向和果汁",
+            query("wo xiang he guozhi", &mut BufWriter::new())
         );
     }
 
-    // This might fail some day as consequence of vocab data entry. As long as
-    // this test continues to pass, using single digit choice picking protocol
-    // is okay. Fail means time for fancier algorithm to resolve choices.
+    // These strings have the same murmur3 hash as the keys for 购买 (goumai),
+    // 身体 (shenti), 民族 (minzu), and 烤鸭 (kaoya). They can still match as
+    // abbreviations, but not as those keys.
+    #[test]
+    fn murmur3_collisions_do_not_match() {
+        for q in ["hfkcmls", "rpbdbtkkgo", "ikxgocx", "kcxgdvjztw"].iter() {
+            let key = murmur3(q, autogen_hsk::MURMUR3_SEED);
+            assert!(autogen_hsk::PINYIN.binary_search(&key).is_ok());
+            assert_no_spurious_matches(q);
+        }
+        assert_eq!(query("HFKCMLS", &mut BufWriter::new()), "HFKCMLS");
+    }
+
+    // Check every match in look_up_segments() for a pinyin search key or
+    // abbreviation and 词语 from the vocab files (or a single character from
+    // gb2312.tsv, with the gb2312 feature). This is independent of the
+    // murmur3 tables, so a hash collision would show up as a match that isn't
+    // in the lists. Abbreviations match so much random input that checking
+    // just their first candidate keeps this fast enough. A collision would
+    // show up there too.
+    fn assert_no_spurious_matches(qry: &str) {
+        let chars = Utf8Str::new(qry);
+        let mut segments = segment::SegmentList::new();
+        look_up_segments(qry, &mut segments);
+        for seg in segments.iter() {
+            let key = chars.char_slice(seg.start, seg.end).unwrap();
+            let is_key = |ciyu| {
+                autogen_hsk::PINYIN_CIYU_TEST_DATA.contains(&(key, ciyu)) || is_zi(key, ciyu)
+            };
+            let is_abbrev = |ciyu| autogen_hsk::ABBREV_CIYU_TEST_DATA.contains(&(key, ciyu));
+            if let Some(candidates) = seg.candidates() {
+                if candidates.is_abbreviation() {
+                    assert!(candidates.iter().take(1).all(is_abbrev));
+                } else {
+                    assert!(candidates.iter().all(is_key));
+                }
+            } else if let segment::SegmentKind::Ciyu(ciyu) = seg.kind {
+                assert!(is_key(ciyu) || is_abbrev(ciyu));
+            }
+        }
+    }
+
+    #[cfg(feature = "gb2312")]
+    fn is_zi(key: &str, zi: &str) -> bool {
+        autogen_gb2312::PINYIN_ZI_TEST_DATA.contains(&(key, zi))
+    }
+
+    #[cfg(not(feature = "gb2312"))]
+    fn is_zi(_: &str, _: &str) -> bool {
+        false
+    }
+
     #[test]
-    fn longest_choice_has_nine_or_less_options() {
-        assert!(autogen_hsk::CIYU_CHOICE_MAX <= 9);
+    fn english_words_have_no_spurious_matches() {
+        let corpus = "the quick brown fox jumps over the lazy dog while seven \
+            wizards quietly box jackets of extremely vexing quality pack my \
+            box with five dozen liquor jugs sphinx of black quartz judge my \
+            vow hello world keyboard computer language program function \
+            variable structure enumeration iterator reference borrowing \
+            lifetime compiler memory buffer string character unicode \
+            pronunciation dictionary vocabulary sentence paragraph chapter \
+            umbrella elephant giraffe penguin kangaroo octopus butterfly \
+            strawberry pineapple watermelon chocolate sandwich breakfast";
+        for word in corpus.split_whitespace() {
+            assert_no_spurious_matches(word);
+        }
+        assert_no_spurious_matches(corpus);
+    }
+
+    #[test]
+    fn random_strings_have_no_spurious_matches() {
+        // Xorshift PRNG, so the strings are the same for every test run
+        let mut state: u32 = 2463534242;
+        let mut buf = [b'a'; 24];
+        for _ in 0..20000 {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            let len = 1 + (state as usize % buf.len());
+            for b in buf.iter_mut().take(len) {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                *b = b'a' + (state % 26) as u8;
+            }
+            assert_no_spurious_matches(core::str::from_utf8(&buf[..len]).unwrap());
+        }
+    }
+
+    #[test]
+    fn initials_match_when_full_pinyin_does_not() {
+        assert_eq!(query("sxym", &mut BufWriter::new()), "赏心悦目");
+        assert_eq!(query("shxym", &mut BufWriter::new()), "赏心悦目");
+        assert!(query("gzh", &mut BufWriter::new()).contains(" 6果汁 "));
+        #[cfg(feature = "bigram")]
+        assert_eq!(query("w x h gzh6", &mut BufWriter::new()), "我想和果汁");
+        #[cfg(not(feature = "bigram"))]
+        assert_eq!(query("w x h gzh6", &mut BufWriter::new()), "我下和果汁");
+        // Full pinyin wins, and covers as much of the query as it can
+        assert_eq!(query("zhongguo", &mut BufWriter::new()), "中国");
+        assert!(query("woxiangh", &mut BufWriter::new()).starts_with("我 ("));
+    }
+
+    #[test]
+    fn zh_hant_spells_the_same_keys_in_traditional() {
+        let hant = |q, expected| {
+            let mut sink = BufWriter::new();
+            let opts = LookupOptions {
+                dialect: Dialect::ZhHant,
+                ..Default::default()
+            };
+            look_up_with_options(q, &opts, &mut sink);
+            assert_eq!(sink.to_s(), expected);
+        };
+        hant("zhongguo", "中國");
+        hant("lianxi", " (1聯繫 2練習) ");
+        hant("lianxi2", "練習");
+        hant("sxym", "賞心悅目");
+        // One simplified character can stand for more than one traditional one
+        hant("fa", "發");
+        hant("toufa", "頭髮");
+        hant("gan", " (1幹 2敢 3趕 4桿 5擀) ");
+        hant("ganjing", "乾淨");
+        hant("meiguanxi", "沒關係");
+        let mut sink = BufWriter::new();
+        look_up_with_options("toufa", &LookupOptions::default(), &mut sink);
+        assert_eq!(sink.to_s(), "头发");
+        // Conversion goes character by character
+        let tables = [
+            (autogen_hsk::CIYU, autogen_hsk::CIYU_HANT),
+            (autogen_hsk::ABBREV_CIYU, autogen_hsk::ABBREV_CIYU_HANT),
+        ];
+        for (hans, hant) in tables.iter() {
+            assert_eq!(hans.len(), hant.len());
+            for (hans, hant) in hans.iter().zip(hant.iter()) {
+                assert_eq!(hans.chars().count(), hant.chars().count());
+                assert_eq!(hans.split('\t').count(), hant.split('\t').count());
+            }
+        }
+    }
+
+    #[test]
+    fn translate_commits_default_choices_without_prompts() {
+        let hans = |q, expected| {
+            assert_eq!(translate_zh_hans(q, &mut BufWriter::new()), expected);
+        };
+        #[cfg(feature = "bigram")]
+        hans("woxiang he guozhi", "我想喝果汁");
+        #[cfg(not(feature = "bigram"))]
+        hans("woxiang he guozhi", "我向和果汁");
+        hans("xianghe", "向和");
+        hans("lianxi", "联系");
+        hans("lianxi2", "练习");
+        hans("he", "和");
+        hans("nǚ", "女");
+        hans("", "");
+        hans("Hi!", "Hi！");
+        assert_eq!(translate_zh_hant("lianxi", &mut BufWriter::new()), "聯繫");
+        assert_eq!(
+            translate_zh_hant("wo xihuan toufa", &mut BufWriter::new()),
+            "我喜歡頭髮"
+        );
     }
 
     // This might fail some day as a consequence of vocab data entry. In case
@@ -449,4 +839,48 @@ mod tests {
             prev = curr;
         }
     }
+
+    #[cfg(feature = "gb2312")]
+    #[test]
+    fn single_characters_fall_back_for_syllables_without_ciyu() {
+        // Nothing in the vocab files is just niu, so its characters come up,
+        // most frequent first
+        assert!(query("niu", &mut BufWriter::new()).starts_with(" (1牛 2扭 3纽"));
+        assert_eq!(query("niu ", &mut BufWriter::new()), "牛");
+        assert_eq!(query("niu2", &mut BufWriter::new()), "扭");
+        assert_eq!(query("niunai", &mut BufWriter::new()), "牛奶");
+        assert_eq!(translate_zh_hans("tie", &mut BufWriter::new()), "铁");
+        assert_eq!(translate_zh_hant("tie", &mut BufWriter::new()), "鐵");
+        let mut sink = BufWriter::new();
+        let opts = LookupOptions {
+            tones: true,
+            ..Default::default()
+        };
+        look_up_with_options("niu3 ", &opts, &mut sink);
+        assert_eq!(sink.to_s(), "扭");
+        // 词语 and abbreviations still come first
+        assert_eq!(query("wo", &mut BufWriter::new()), "我");
+        assert_eq!(query("sxym", &mut BufWriter::new()), "赏心悦目");
+        assert!(!query("n", &mut BufWriter::new()).contains('嗯'));
+    }
+
+    #[cfg(feature = "gb2312")]
+    #[test]
+    fn single_character_tables_match_their_keys() {
+        for i in 1..autogen_gb2312::PINYIN.len() {
+            assert!(autogen_gb2312::PINYIN[i] > autogen_gb2312::PINYIN[i - 1]);
+        }
+        for (i, key) in autogen_gb2312::PINYIN_KEYS.iter().enumerate() {
+            assert_eq!(match_zi(key), Some(i));
+            assert!(crate::syllable::is_syllable(key), "{}", key);
+            let count = autogen_gb2312::ZI[i].split('\t').count();
+            assert_eq!(autogen_gb2312::ZI_WEIGHT[i].len(), count);
+            assert_eq!(autogen_gb2312::ZI_TONED[i].split('\t').count(), count);
+            assert_eq!(autogen_gb2312::ZI_HANT[i].split('\t').count(), count);
+        }
+        for &(key, zi) in autogen_gb2312::PINYIN_ZI_TEST_DATA {
+            let i = match_zi(key).unwrap();
+            assert!(autogen_gb2312::ZI[i].split('\t').any(|c| c == zi));
+        }
+    }
 }
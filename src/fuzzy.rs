@@ -0,0 +1,220 @@
+// Copyright (c) 2021 Sam Blenny
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+#![forbid(unsafe_code)]
+
+use crate::autogen_hsk;
+
+// Fuzzy pinyin, for sounds that speakers of some regional accents don't tell
+// apart, so "zi" can also find 只 (zhi) and "lan" can find 难 (nan). Variants
+// of a search key come from applying the rules that are on at each place in
+// the key where they fit, in every combination. Lookups check the variants
+// along with the key itself, and list their matches after the key's own.
+
+/// Fuzzy pinyin rules. Each one treats two sounds as the same.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum FuzzyRule {
+    /// z = zh
+    ZZh,
+    /// c = ch
+    CCh,
+    /// s = sh
+    SSh,
+    /// n = l
+    NL,
+    /// en = eng
+    EnEng,
+    /// in = ing
+    InIng,
+}
+
+/// Set of fuzzy pinyin rules to use for lookups. The default is no rules,
+/// which means exact matching only.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct FuzzyRules {
+    bits: u8,
+}
+impl FuzzyRules {
+    /// No rules (exact matching only).
+    pub const NONE: FuzzyRules = FuzzyRules { bits: 0 };
+    /// All the rules.
+    pub const ALL: FuzzyRules = FuzzyRules { bits: 0x3f };
+
+    /// Turn rule on or off.
+    pub fn set(&mut self, rule: FuzzyRule, on: bool) {
+        if on {
+            self.bits |= bit(rule);
+        } else {
+            self.bits &= !bit(rule);
+        }
+    }
+
+    /// Is rule on?
+    pub fn is_on(&self, rule: FuzzyRule) -> bool {
+        self.bits & bit(rule) != 0
+    }
+
+    /// Are all the rules off?
+    pub fn is_empty(&self) -> bool {
+        self.bits == 0
+    }
+}
+
+fn bit(rule: FuzzyRule) -> u8 {
+    1 << rule as u8
+}
+
+// Replacements for each rule, as (rule, from, to). Growing a sound that is
+// already the longer one (z of zh, en of eng, ...) gets skipped, since the
+// replacement for the longer sound covers it.
+const SWAPS: [(FuzzyRule, &str, &str); 12] = [
+    (FuzzyRule::ZZh, "zh", "z"),
+    (FuzzyRule::ZZh, "z", "zh"),
+    (FuzzyRule::CCh, "ch", "c"),
+    (FuzzyRule::CCh, "c", "ch"),
+    (FuzzyRule::SSh, "sh", "s"),
+    (FuzzyRule::SSh, "s", "sh"),
+    (FuzzyRule::NL, "n", "l"),
+    (FuzzyRule::NL, "l", "n"),
+    (FuzzyRule::EnEng, "eng", "en"),
+    (FuzzyRule::EnEng, "en", "eng"),
+    (FuzzyRule::InIng, "ing", "in"),
+    (FuzzyRule::InIng, "in", "ing"),
+];
+
+// Most variants of one key to check. Long keys with lots of places where rules
+// fit have too many combinations to check them all.
+const VARIANT_MAX: usize = 64;
+
+// Buffer for building variants. Variants longer than the longest pinyin search
+// key can't match anything, so they don't need to fit.
+struct Variant {
+    buf: [u8; autogen_hsk::PINYIN_SIZE_MAX],
+    count: usize,
+}
+
+// Call f with each fuzzy variant of key (not including key itself), up to
+// VARIANT_MAX of them.
+pub fn for_each_variant(key: &str, rules: FuzzyRules, f: &mut impl FnMut(&str)) {
+    if rules.is_empty() || !key.is_ascii() {
+        return;
+    }
+    let mut variant = Variant {
+        buf: [0; autogen_hsk::PINYIN_SIZE_MAX],
+        count: 0,
+    };
+    variant.build(key.as_bytes(), 0, false, rules, f);
+}
+
+impl Variant {
+    // Append the variants of rest to the first n bytes of buf. Changed says
+    // whether the first n bytes differ from the key.
+    fn build(
+        &mut self,
+        rest: &[u8],
+        n: usize,
+        changed: bool,
+        rules: FuzzyRules,
+        f: &mut impl FnMut(&str),
+    ) {
+        if self.count >= VARIANT_MAX {
+            return;
+        }
+        if rest.is_empty() {
+            if changed {
+                self.count += 1;
+                if let Ok(s) = core::str::from_utf8(&self.buf[..n]) {
+                    f(s);
+                }
+            }
+            return;
+        }
+        // Keep the next character as is
+        if n < self.buf.len() {
+            self.buf[n] = rest[0];
+            self.build(&rest[1..], n + 1, changed, rules, f);
+        }
+        // Or replace a sound that starts here
+        for &(rule, from, to) in SWAPS.iter() {
+            let (from, to) = (from.as_bytes(), to.as_bytes());
+            let fits = match rule {
+                // n and l only swap as initials, which have a vowel after
+                // them (n also ends syllables like an)
+                FuzzyRule::NL => rest.starts_with(from) && rest.len() > 1 && is_vowel(rest[1]),
+                _ => rest.starts_with(from) && !(to.len() > from.len() && rest.starts_with(to)),
+            };
+            if rules.is_on(rule) && fits && n + to.len() <= self.buf.len() {
+                self.buf[n..n + to.len()].copy_from_slice(to);
+                self.build(&rest[from.len()..], n + to.len(), true, rules, f);
+            }
+        }
+    }
+}
+
+fn is_vowel(b: u8) -> bool {
+    matches!(b, b'a' | b'e' | b'i' | b'o' | b'u' | b'v')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dialects::{look_up_with_options, BufWriter, LookupOptions, Writer};
+
+    // Collect variants of key as a space separated string.
+    fn variants<'a>(key: &str, rules: FuzzyRules, sink: &'a mut BufWriter) -> &'a str {
+        for_each_variant(key, rules, &mut |v| {
+            sink.write(v);
+            sink.write(" ");
+        });
+        sink.to_s()
+    }
+
+    #[test]
+    fn rules_toggle_one_at_a_time() {
+        let mut rules = FuzzyRules::NONE;
+        assert_eq!(variants("zhisen", rules, &mut BufWriter::new()), "");
+        rules.set(FuzzyRule::ZZh, true);
+        assert_eq!(variants("zhisen", rules, &mut BufWriter::new()), "zisen ");
+        rules.set(FuzzyRule::EnEng, true);
+        assert_eq!(
+            variants("zhisen", rules, &mut BufWriter::new()),
+            "zhiseng zisen ziseng "
+        );
+        rules.set(FuzzyRule::ZZh, false);
+        assert!(rules.is_on(FuzzyRule::EnEng));
+        assert!(!rules.is_on(FuzzyRule::ZZh));
+        assert_eq!(variants("zhisen", rules, &mut BufWriter::new()), "zhiseng ");
+        assert_eq!(
+            variants("lan", FuzzyRules::ALL, &mut BufWriter::new()),
+            "nan "
+        );
+    }
+
+    fn look_up_with_fuzzy(query: &str, fuzzy: FuzzyRules, sink: &mut BufWriter) {
+        let opts = LookupOptions {
+            fuzzy,
+            ..Default::default()
+        };
+        look_up_with_options(query, &opts, sink);
+    }
+
+    #[test]
+    fn exact_matches_come_before_fuzzy_ones() {
+        let mut sink = BufWriter::new();
+        look_up_with_fuzzy("zi", FuzzyRules::NONE, &mut sink);
+        assert!(!sink.to_s().contains("只"));
+        let mut rules = FuzzyRules::NONE;
+        rules.set(FuzzyRule::ZZh, true);
+        sink.rewind();
+        look_up_with_fuzzy("zi", rules, &mut sink);
+        assert!(sink.to_s().starts_with(" (1字 2紫 3之"));
+        // Keys with only fuzzy matches work too
+        rules.set(FuzzyRule::NL, true);
+        sink.rewind();
+        look_up_with_fuzzy("liulai ", rules, &mut sink);
+        assert_eq!(sink.to_s(), "牛奶");
+        sink.rewind();
+        look_up_with_fuzzy("zongguo", FuzzyRules::ALL, &mut sink);
+        assert_eq!(sink.to_s(), "中国");
+    }
+}
@@ -0,0 +1,461 @@
+// Copyright (c) 2021 Sam Blenny
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+#![forbid(unsafe_code)]
+
+use crate::autogen_hsk;
+use crate::constants;
+use crate::dialects::{KeyMatches, Utf8Str, Writer};
+use crate::fuzzy::FuzzyRules;
+use crate::lex;
+use crate::segment::Candidates;
+use crate::shuangpin::{self, ShuangpinLayout};
+use crate::tones;
+use crate::user_dict::{self, UserEntry};
+
+// Segmentation lattice for finding the lowest cost way to split a query into
+// 词语 matches and unmatched characters (Viterbi style dynamic programming).
+//
+// Position i of the lattice is the boundary before character i of the query.
+// For each position, the lattice remembers the cost of the best path from the
+// start of the query and the last token on that path. Extending the lattice by
+// one character only needs to look back as far as the longest search key
+// (PINYIN_SIZE_MAX for pinyin), so adding characters at the end of a query is
+// cheap, and removing characters from the end is just a truncate.
+
+const LATTICE_SIZE: usize = constants::BUF_SIZE + 1;
+
+/// Costs for the segmenter to minimise. The segmenter picks the path through
+/// the query with the lowest total cost.
+pub trait SegmentCost {
+    /// Cost of matching len characters of the query as a pinyin search key for
+    /// the given homophone candidates. Keys that only match by abbreviation
+    /// or fuzzy pinyin (see Candidates::is_abbreviation and is_fuzzy) come
+    /// here too.
+    fn word(&self, candidates: Candidates<'_>, len: usize) -> u32;
+    /// Cost of passing character c through without a match.
+    fn unmatched(&self, c: char) -> u32;
+}
+
+/// Default costs: fewest unmatched characters first, then fewest characters
+/// matched by abbreviation, then fewest words. Keys that only match by fuzzy
+/// pinyin count as one and a half words, so an exact match beats a fuzzy one
+/// for the same span, but one fuzzy word beats two exact ones.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct DefaultCost;
+impl SegmentCost for DefaultCost {
+    // Each character of an abbreviation costs more than the most words that a
+    // query could be split into, so as much as possible gets matched as full
+    // pinyin (say, "xiang h" rather than "xian gh" for "xiangh").
+    fn word(&self, candidates: Candidates<'_>, len: usize) -> u32 {
+        if candidates.is_abbreviation() {
+            ABBREV_CHAR_COST * len as u32
+        } else if candidates.is_fuzzy(0) {
+            3
+        } else {
+            2
+        }
+    }
+    // Likewise, no number of characters matched by abbreviation can outweigh
+    // one unmatched character.
+    fn unmatched(&self, _: char) -> u32 {
+        ABBREV_CHAR_COST * ABBREV_CHAR_COST
+    }
+}
+const ABBREV_CHAR_COST: u32 = 3 * constants::BUF_SIZE as u32 + 1;
+
+// Options for how the lattice matches search keys
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct KeyOptions {
+    // Fuzzy pinyin rules to use
+    pub fuzzy: FuzzyRules,
+    // Are digits after pinyin tone numbers (tone input) rather than choices?
+    pub tones: bool,
+    // Are keys typed on a Zhuyin keyboard, which takes the digits?
+    pub zhuyin: bool,
+    // Shuangpin layout, if keys are typed as Shuangpin pairs
+    pub shuangpin: Option<ShuangpinLayout>,
+}
+impl KeyOptions {
+    // Spell key as the plain pinyin to search for, writing it into buf if it
+    // needs changing. Keys with tones give the typed key too, for narrowing
+    // down the candidates.
+    // Return: (pinyin, typed tones), or None for runs of Shuangpin keys that
+    // aren't whole syllables.
+    pub fn search_key<'b, 'k: 'b>(
+        &self,
+        key: &'k str,
+        buf: &'b mut [u8; autogen_hsk::PINYIN_SIZE_MAX],
+    ) -> Option<(&'b str, &'k str)> {
+        if let Some(layout) = self.shuangpin {
+            if key.chars().all(|c| layout.is_key(c)) {
+                return shuangpin::to_pinyin(key, &layout, buf).map(|pinyin| (pinyin, ""));
+            }
+        }
+        Some(match tones::plain(key, self.tones, buf) {
+            Some(pinyin) => (pinyin, key),
+            None => (key, ""),
+        })
+    }
+
+    // How many characters at the end of text a search key that ends with its
+    // last character can span: PINYIN_SIZE_MAX, plus the tone digits among
+    // them with tone input, since those don't count toward the pinyin.
+    pub fn key_window(&self, text: &str) -> usize {
+        let mut window = 0;
+        let mut pinyin_len = 0;
+        for c in text.chars().rev() {
+            if pinyin_len == autogen_hsk::PINYIN_SIZE_MAX {
+                break;
+            }
+            window += 1;
+            if !(self.tones && c.is_ascii_digit()) {
+                pinyin_len += 1;
+            }
+        }
+        window
+    }
+}
+
+// Search keys for a lattice to match, and the costs of matching them. Pinyin
+// keys (PinyinKeys) are the main source, and Cantonese has its own (see yue.rs).
+pub trait KeySource {
+    // What the lattice remembers about the last segment of a path
+    type Token: Copy + Default;
+    // Token and cost for passing character c through without a match.
+    fn unmatched(&self, c: char) -> (Self::Token, u32);
+    // How many characters at the end of text a search key that ends with its
+    // last character can span.
+    fn key_window(&self, text: &str) -> usize;
+    // With keys that pair up into syllables (Shuangpin), is c one of them?
+    // Return: None if keys don't pair up
+    fn pair_key(&self, _c: char) -> Option<bool> {
+        None
+    }
+    // Token and cost for matching key, or None if it doesn't match.
+    fn find(&self, key: &str) -> Option<(Self::Token, u32)>;
+}
+
+// Pinyin search keys in the built-in tables and a user dictionary
+pub struct PinyinKeys<'k, C> {
+    pub cost: &'k C,
+    pub user: &'k [UserEntry],
+    pub options: KeyOptions,
+}
+impl<C: SegmentCost> KeySource for PinyinKeys<'_, C> {
+    type Token = lex::Token;
+
+    fn unmatched(&self, c: char) -> (lex::Token, u32) {
+        let token = match c {
+            // Space and digit characters may be intended to resolve a choice
+            // of homophone 词语 from an earlier CiOpenChoice token. Spaces may
+            // separate the pinyin from a CiOne token so the pinyin does not
+            // get consumed as the prefix to a longer 词语. Spaces and digits
+            // may also be intended to pass through as ASCII. The same goes for
+            // "-" and "=", which turn pages of long candidate lists.
+            ' ' | '-' | '=' => lex::Token::MaybeChoice(c),
+            '1'..='9' if !self.options.tones => lex::Token::MaybeChoice(c),
+            // Tone input and Zhuyin keyboards need the digits for tones, so
+            // shifted digits pick choices instead. They turn into the digit
+            // labels they stand for. Otherwise, this covers stuff like
+            // "UPPER CASE" and emoji.
+            _ => match self.choice_label(c) {
+                Some(label) => lex::Token::MaybeChoice(label),
+                None => lex::Token::Other,
+            },
+        };
+        (token, self.cost.unmatched(c))
+    }
+
+    fn key_window(&self, text: &str) -> usize {
+        self.options.key_window(text)
+    }
+
+    fn pair_key(&self, c: char) -> Option<bool> {
+        self.options.shuangpin.map(|layout| layout.is_key(c))
+    }
+
+    fn find(&self, key: &str) -> Option<(lex::Token, u32)> {
+        // Shifted digits that pick choices don't match by themselves as
+        // punctuation search keys (like "!" for "！").
+        let mut chars = key.chars();
+        if let (Some(c), None) = (chars.next(), chars.next()) {
+            if self.choice_label(c).is_some() {
+                return None;
+            }
+        }
+        // Keys can have tone marks, or tone numbers with tone input,
+        // which narrow down the candidates for the pinyin without them
+        let mut plain = [0; autogen_hsk::PINYIN_SIZE_MAX];
+        let (pinyin, typed_tones) = self.options.search_key(key, &mut plain)?;
+        let user_words = user_dict::find(self.user, pinyin);
+        let matches = KeyMatches::find(pinyin, !user_words.is_empty(), self.options.fuzzy);
+        let candidates = Candidates::new(matches, user_words).with_tones(typed_tones);
+        if candidates.is_empty() {
+            return None;
+        }
+        let token = if candidates.len() > 1 {
+            lex::Token::CiOpenChoice(matches)
+        } else {
+            lex::Token::CiOne(matches)
+        };
+        Some((token, self.cost.word(candidates, key.chars().count())))
+    }
+}
+impl<C> PinyinKeys<'_, C> {
+    // Digit label that c picks choices by, if it's a shifted digit and the
+    // digits are taken.
+    fn choice_label(&self, c: char) -> Option<char> {
+        if self.options.tones || self.options.zhuyin {
+            tones::choice_label(c)
+        } else {
+            None
+        }
+    }
+}
+
+// Holds best path to each position of the query
+pub struct Lattice<T = lex::Token> {
+    cost: [u32; LATTICE_SIZE],
+    // Start position of the last token on the best path to each position
+    start: [usize; LATTICE_SIZE],
+    token: [T; LATTICE_SIZE],
+    // Start of the run of Shuangpin keys that ends at each position (or the
+    // position itself, if the character before it isn't one)
+    run: [usize; LATTICE_SIZE],
+    len: usize,
+}
+impl<T: Copy + Default> Lattice<T> {
+    // Initialize empty lattice (just the start position).
+    pub fn new() -> Lattice<T> {
+        Lattice {
+            cost: [0; LATTICE_SIZE],
+            start: [0; LATTICE_SIZE],
+            token: [T::default(); LATTICE_SIZE],
+            run: [0; LATTICE_SIZE],
+            len: 0,
+        }
+    }
+
+    // Forget about characters after the first len.
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len {
+            self.len = len;
+        }
+    }
+
+    // Add the best path to the next position of the lattice, matching keys
+    // from keys. Character k of query is character offset+k of the lattice,
+    // and query must reach at least as far as the new position.
+    // Return: false if lattice is full or query does not reach far enough.
+    pub fn extend_with(
+        &mut self,
+        query: &Utf8Str,
+        offset: usize,
+        keys: &impl KeySource<Token = T>,
+        sink: &mut impl Writer,
+    ) -> bool {
+        let end = self.len + 1;
+        if end >= LATTICE_SIZE || end <= offset {
+            return false;
+        }
+        // Option 1: Pass the last character through unmatched
+        let s = match query.char_slice(end - 1 - offset, end - offset) {
+            Some(s) => s,
+            None => return false,
+        };
+        let c = match s.chars().next() {
+            Some(c) => c,
+            None => {
+                // Reaching this branch is a bug. For s to be "", char_slice
+                // would have to return an empty slice for a 1 char range.
+                sink.trace(902);
+                return false;
+            }
+        };
+        let (mut best_token, unmatched) = keys.unmatched(c);
+        let mut best_cost = self.cost[end - 1].saturating_add(unmatched);
+        let mut best_start = end - 1;
+        // Option 2: Match a search key that ends here, within the window that
+        // keys can span. Checking longer matches first means ties go to the
+        // longest match.
+        let before = query.char_slice(0, end - offset).unwrap_or("");
+        let window_start = end - keys.key_window(before);
+        // Shuangpin keys pair up from the start of their run
+        let pair_key = keys.pair_key(c);
+        let run = match pair_key {
+            Some(true) => self.run[end - 1],
+            _ => end,
+        };
+        for start in window_start..end {
+            if pair_key.is_some() && !pairs_up(run, start, end) {
+                continue;
+            }
+            let key = match query.char_slice(start - offset, end - offset) {
+                Some(key) => key,
+                None => continue,
+            };
+            if let Some((token, word)) = keys.find(key) {
+                let path_cost = self.cost[start].saturating_add(word);
+                if path_cost < best_cost || (path_cost == best_cost && best_start == end - 1) {
+                    best_cost = path_cost;
+                    best_start = start;
+                    best_token = token;
+                }
+            }
+        }
+        self.cost[end] = best_cost;
+        self.start[end] = best_start;
+        self.token[end] = best_token;
+        self.run[end] = run;
+        self.len = end;
+        true
+    }
+
+    // Follow the best path back from the end of the lattice.
+    // Side-effect: Call f with the token and span of each segment on the
+    // path, in query order.
+    pub fn for_each_segment(&self, mut f: impl FnMut(T, lex::Span)) {
+        // Collect the end positions of the tokens, last token first
+        let mut ends = [0usize; LATTICE_SIZE];
+        let mut n = 0;
+        let mut end = self.len;
+        while end > 0 {
+            ends[n] = end;
+            n += 1;
+            end = self.start[end];
+        }
+        for &end in ends[..n].iter().rev() {
+            let span = lex::Span {
+                start: self.start[end],
+                end,
+            };
+            f(self.token[end], span);
+        }
+    }
+}
+impl Lattice<lex::Token> {
+    // Add the best path to the next position of the lattice, matching pinyin
+    // keys (see extend_with).
+    pub fn extend(
+        &mut self,
+        query: &Utf8Str,
+        offset: usize,
+        cost: &impl SegmentCost,
+        user: &[UserEntry],
+        options: KeyOptions,
+        sink: &mut impl Writer,
+    ) -> bool {
+        let keys = PinyinKeys {
+            cost,
+            user,
+            options,
+        };
+        self.extend_with(query, offset, &keys, sink)
+    }
+
+    // Follow the best path back from the end of the lattice.
+    // Side-effect: Push tokens for the path into queue, in query order.
+    pub fn tokens(&self, queue: &mut lex::TokenQueue) {
+        self.for_each_segment(|token, span| {
+            // TODO: Better solution than silently ignoring possible full queue
+            let _ = queue.push(token, span);
+        });
+    }
+}
+
+// With Shuangpin keys, can start..end be a search key? Keys in a run pair up
+// from its start (run), so a key has to be whole pairs. Other characters, like
+// punctuation, only match by themselves.
+fn pairs_up(run: usize, start: usize, end: usize) -> bool {
+    if run == end {
+        start + 1 == end
+    } else {
+        start >= run && (start - run) % 2 == 0 && (end - start) % 2 == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dialects::{query, search, BufWriter};
+
+    // Cost that likes splitting into short words, but not abbreviations.
+    struct ShortWords;
+    impl SegmentCost for ShortWords {
+        fn word(&self, candidates: Candidates<'_>, len: usize) -> u32 {
+            if candidates.is_abbreviation() {
+                return 1000;
+            }
+            (len * len) as u32
+        }
+        fn unmatched(&self, _: char) -> u32 {
+            1000
+        }
+    }
+
+    #[test]
+    fn prefer_full_parse_over_longest_first_word() {
+        // Longest match from the start would take xiang (想), then be stuck
+        // with a worse split for the rest
+        assert_eq!(query("xianganjue ", &mut BufWriter::new()), "先感觉");
+        assert_eq!(query("kunchong", &mut BufWriter::new()), "昆虫");
+        assert_eq!(query("kun chong", &mut BufWriter::new()), "困冲");
+    }
+
+    #[test]
+    fn costs_are_pluggable() {
+        let q = Utf8Str::new("kunchong");
+        let mut queue = lex::TokenQueue::new();
+        let options = KeyOptions::default();
+        search(
+            &q,
+            &mut queue,
+            &DefaultCost,
+            &[],
+            options,
+            &mut BufWriter::new(),
+        );
+        assert_eq!(queue.count, 1);
+        let mut queue = lex::TokenQueue::new();
+        search(
+            &q,
+            &mut queue,
+            &ShortWords,
+            &[],
+            options,
+            &mut BufWriter::new(),
+        );
+        assert_eq!(queue.count, 2);
+    }
+
+    #[test]
+    fn truncate_then_extend_matches_fresh_lattice() {
+        let text = "woxiangheguozhi woxiangheguozhi woxiangheguozhi";
+        let q = Utf8Str::new(text);
+        let mut sink = BufWriter::new();
+        let user = [];
+        let options = KeyOptions::default();
+        let mut lattice = Lattice::new();
+        for _ in 0..q.char_count {
+            assert!(lattice.extend(&q, 0, &DefaultCost, &user, options, &mut sink));
+        }
+        lattice.truncate(30);
+        // Extend using a window that only covers the lookback needed for
+        // positions past 30
+        let offset = 30 - autogen_hsk::PINYIN_SIZE_MAX;
+        let tail = Utf8Str::new(&text[offset..]);
+        for _ in 30..q.char_count {
+            assert!(lattice.extend(&tail, offset, &DefaultCost, &user, options, &mut sink));
+        }
+        let mut a = lex::TokenQueue::new();
+        lattice.tokens(&mut a);
+        let mut b = lex::TokenQueue::new();
+        search(&q, &mut b, &DefaultCost, &user, options, &mut sink);
+        assert_eq!(a.count, b.count);
+        for i in 0..a.count {
+            assert_eq!(a.spans[i].start, b.spans[i].start);
+            assert_eq!(a.spans[i].end, b.spans[i].end);
+        }
+    }
+}
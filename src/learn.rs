@@ -0,0 +1,263 @@
+// Copyright (c) 2021 Sam Blenny
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+#![forbid(unsafe_code)]
+
+use crate::user_dict::UserEntry;
+
+// Adaptive candidate ranking. ChoiceHistory remembers which candidate the
+// user picked for each pinyin search key, and candidate lists promote that
+// candidate to the front next time. Like UserDict, the caller provides the
+// storage. Entries are kept in most recently used order, so when the history
+// is full, recording a new key forgets the least recently used one.
+
+/// Learned choices: the candidate most recently picked for each pinyin key.
+pub struct ChoiceHistory<'s> {
+    store: &'s mut [UserEntry],
+    count: usize,
+}
+impl<'s> ChoiceHistory<'s> {
+    /// Return an empty history that can hold up to store.len() keys.
+    pub fn new(store: &'s mut [UserEntry]) -> ChoiceHistory<'s> {
+        ChoiceHistory { store, count: 0 }
+    }
+
+    /// Remember that ciyu was picked for the pinyin search key.
+    /// Return: false if pinyin or ciyu can't be stored (see UserDict::add), or
+    /// the history has no storage.
+    pub fn record(&mut self, pinyin: &str, ciyu: &str) -> bool {
+        let entry = match UserEntry::new(pinyin, ciyu) {
+            Some(entry) => entry,
+            None => return false,
+        };
+        if self.store.is_empty() {
+            return false;
+        }
+        // Take out the old entry for this key, if any, or else make room by
+        // dropping the least recently used entry
+        let key = entry.pinyin();
+        let end = match self.entries().iter().position(|e| e.pinyin() == key) {
+            Some(i) => i,
+            None if self.count < self.store.len() => {
+                self.count += 1;
+                self.count - 1
+            }
+            None => self.count - 1,
+        };
+        self.store.copy_within(0..end, 1);
+        self.store[0] = entry;
+        true
+    }
+
+    /// Candidate most recently picked for the pinyin search key, if any.
+    pub fn get(&self, pinyin: &str) -> Option<&str> {
+        chosen(self.entries(), pinyin)
+    }
+
+    /// Forget all learned choices.
+    pub fn clear(&mut self) {
+        self.count = 0;
+    }
+
+    /// Number of keys with a learned choice.
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Is the history empty?
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Iterate over learned choices, most recently used first.
+    pub fn iter(&self) -> core::slice::Iter<'_, UserEntry> {
+        self.entries().iter()
+    }
+
+    /// Save learned choices to store as text with one "pinyin\t词语\n" line per
+    /// key, most recently used first.
+    pub fn save(&self, store: &mut impl ChoiceStore) {
+        store.clear();
+        for entry in self.iter() {
+            store.append(entry.pinyin());
+            store.append("\t");
+            store.append(entry.ciyu());
+            store.append("\n");
+        }
+        store.flush();
+    }
+
+    /// Replace learned choices with ones saved in store. Lines that can't be
+    /// parsed get skipped, as do lines past the capacity of the history.
+    /// Return: false if any lines were skipped.
+    pub fn load(&mut self, store: &mut impl ChoiceStore) -> bool {
+        self.clear();
+        let mut ok = true;
+        for line in store.load().lines() {
+            let mut fields = line.split('\t');
+            let entry = match (fields.next(), fields.next(), fields.next()) {
+                (Some(pinyin), Some(ciyu), None) => UserEntry::new(pinyin, ciyu),
+                _ => None,
+            };
+            match entry {
+                Some(entry)
+                    if self.count < self.store.len() && self.get(entry.pinyin()).is_none() =>
+                {
+                    self.store[self.count] = entry;
+                    self.count += 1;
+                }
+                _ => ok = false,
+            }
+        }
+        ok
+    }
+
+    // Entries in most recently used order. The lookup code takes this rather
+    // than &ChoiceHistory so that an empty &[] can stand in for no history.
+    pub(crate) fn entries(&self) -> &[UserEntry] {
+        &self.store[..self.count]
+    }
+}
+
+// Candidate most recently picked for a pinyin search key, from entries.
+pub(crate) fn chosen<'a>(entries: &'a [UserEntry], key: &str) -> Option<&'a str> {
+    entries.iter().find(|e| e.pinyin() == key).map(|e| e.ciyu())
+}
+
+/// Persistence for a ChoiceHistory. Implement this for wherever a front-end
+/// keeps state between runs (a file for a CLI, a static buffer for wasm, ...).
+pub trait ChoiceStore {
+    /// Return the saved text, or "" if nothing has been saved.
+    fn load(&mut self) -> &str;
+    /// Throw away the saved text, to start a new save.
+    fn clear(&mut self);
+    /// Append text to the save.
+    fn append(&mut self, text: &str);
+    /// Finish the save. The default does nothing, which suits stores that
+    /// write through on append.
+    fn flush(&mut self) {}
+}
+
+/// ChoiceStore backed by a caller-provided byte buffer, for no_std builds.
+/// If the buffer fills up, the rest of the save gets dropped.
+pub struct SliceStore<'s> {
+    buf: &'s mut [u8],
+    len: usize,
+    full: bool,
+}
+impl<'s> SliceStore<'s> {
+    /// Return a store holding the first len bytes of buf as saved text (use
+    /// 0 for an empty store).
+    pub fn new(buf: &'s mut [u8], len: usize) -> SliceStore<'s> {
+        let len = len.min(buf.len());
+        SliceStore {
+            buf,
+            len,
+            full: false,
+        }
+    }
+
+    /// Number of bytes of saved text at the start of the buffer.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Is the store empty?
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+impl<'s> ChoiceStore for SliceStore<'s> {
+    fn load(&mut self) -> &str {
+        core::str::from_utf8(&self.buf[..self.len]).unwrap_or_default()
+    }
+
+    fn clear(&mut self) {
+        self.len = 0;
+        self.full = false;
+    }
+
+    // Only append whole strings, so the saved text stays valid UTF-8. Once
+    // one doesn't fit, drop the rest so the text doesn't skip any pieces.
+    fn append(&mut self, text: &str) {
+        let end = self.len + text.len();
+        if self.full || end > self.buf.len() {
+            self.full = true;
+            return;
+        }
+        self.buf[self.len..end].copy_from_slice(text.as_bytes());
+        self.len = end;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dialects::{look_up_with_options, BufWriter, LookupOptions, Writer};
+
+    #[test]
+    fn record_keeps_most_recent_choice_per_key() {
+        let mut store = [UserEntry::EMPTY; 2];
+        let mut history = ChoiceHistory::new(&mut store);
+        assert!(history.record("xiang", "向"));
+        assert!(history.record("he", "喝"));
+        assert!(history.record("xiang", "像"));
+        assert_eq!(history.len(), 2);
+        assert_eq!(history.get("xiang"), Some("像"));
+        // Full, so this forgets he, the least recently used key
+        assert!(history.record("de", "得"));
+        assert_eq!(history.get("he"), None);
+        assert_eq!(history.get("de"), Some("得"));
+        assert_eq!(history.get("xiang"), Some("像"));
+        assert!(!history.record("Xiang", "象"));
+    }
+
+    #[test]
+    fn save_then_load() {
+        let mut store = [UserEntry::EMPTY; 4];
+        let mut history = ChoiceHistory::new(&mut store);
+        history.record("xiang", "向");
+        history.record("he", "喝");
+        let mut buf = [0u8; 64];
+        let mut slice_store = SliceStore::new(&mut buf, 0);
+        history.save(&mut slice_store);
+        assert_eq!(slice_store.load(), "he\t喝\nxiang\t向\n");
+        let saved = slice_store.len();
+        let mut store2 = [UserEntry::EMPTY; 4];
+        let mut loaded = ChoiceHistory::new(&mut store2);
+        assert!(loaded.load(&mut SliceStore::new(&mut buf, saved)));
+        let a = history.iter().map(|e| (e.pinyin(), e.ciyu()));
+        assert!(a.eq(loaded.iter().map(|e| (e.pinyin(), e.ciyu()))));
+    }
+
+    #[test]
+    fn load_skips_bad_lines_and_full_store_drops_rest() {
+        let mut buf = *b"he\t\xe5\x96\x9d\nbad line\nXIANG\tx\nde\t\xe5\xbe\x97\n";
+        let n = buf.len();
+        let mut store = [UserEntry::EMPTY; 4];
+        let mut history = ChoiceHistory::new(&mut store);
+        assert!(!history.load(&mut SliceStore::new(&mut buf, n)));
+        assert_eq!(history.len(), 2);
+        assert_eq!(history.get("he"), Some("喝"));
+        assert_eq!(history.get("de"), Some("得"));
+        // Too small for the second line
+        let mut small = [0u8; 8];
+        let mut slice_store = SliceStore::new(&mut small, 0);
+        history.save(&mut slice_store);
+        assert_eq!(slice_store.load(), "he\t喝\n");
+    }
+
+    #[test]
+    fn look_up_offers_learned_choices_first() {
+        let mut store = [UserEntry::EMPTY; 2];
+        let mut history = ChoiceHistory::new(&mut store);
+        assert!(history.record("xiang", "像"));
+        let opts = LookupOptions {
+            history: Some(&history),
+            ..Default::default()
+        };
+        let mut sink = BufWriter::new();
+        look_up_with_options("xiang", &opts, &mut sink);
+        assert!(sink.to_s().starts_with(" (1像 2向 3想"));
+    }
+}
@@ -6,24 +6,46 @@
 use crate::autogen_hsk;
 use crate::constants;
 use crate::dialects;
+use crate::lattice;
+use crate::learn;
+use crate::segment;
+use crate::user_dict::{self, UserEntry};
 
 // Data structure for tracking lexemes of query input and their meanings.
 // TokenQueue is no_std, stack-only substitute for Vec<Token>. If TokenQueue
 // were Vec<Token>, it would require heap allocation and linking std.
 
 const TOKEN_QUEUE_SIZE: usize = constants::BUF_SIZE;
-// Holds one Token.
-#[derive(Copy, Clone)]
+// Holds one Token. Ci tokens hold the built-in matches, if any.
+// User dictionary matches get looked up again from the token's span of the
+// query, since tokens can't borrow from the dictionary.
+#[derive(Copy, Clone, Default)]
 pub enum Token {
-    CiOne(dialects::CiyuIndex),
-    CiOpenChoice(dialects::CiyuIndex),
+    CiOne(dialects::KeyMatches),
+    CiOpenChoice(dialects::KeyMatches),
     MaybeChoice(char),
-    Other(char),
+    Other,
+    #[default]
     Skip,
 }
-// Holds queue of Tokens (append only)
+// Character range of the query that a Token was lexed from. This follows
+// start..end range semantics (upper bound exclusive).
+#[derive(Copy, Clone)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+// What came before a query, for ranking candidates: learned choices from
+// history, and context, the end of the text committed right before it.
+#[derive(Copy, Clone)]
+pub struct Ranking<'r> {
+    pub history: &'r [UserEntry],
+    pub context: &'r str,
+}
+// Holds queue of Tokens (append only, but may be truncated)
 pub struct TokenQueue {
     pub queue: [Token; TOKEN_QUEUE_SIZE],
+    pub spans: [Span; TOKEN_QUEUE_SIZE],
     pub count: usize,
 }
 impl TokenQueue {
@@ -31,13 +53,15 @@ impl TokenQueue {
     pub fn new() -> TokenQueue {
         TokenQueue {
             queue: [Token::Skip; TOKEN_QUEUE_SIZE],
+            spans: [Span { start: 0, end: 0 }; TOKEN_QUEUE_SIZE],
             count: 0,
         }
     }
-    // Add Token to queue.
-    pub fn push(&mut self, tk: Token) -> bool {
+    // Add Token, along with the query character range it came from, to queue.
+    pub fn push(&mut self, tk: Token, span: Span) -> bool {
         if self.count < TOKEN_QUEUE_SIZE {
             self.queue[self.count] = tk;
+            self.spans[self.count] = span;
             self.count += 1;
             true
         } else {
@@ -45,19 +69,62 @@ impl TokenQueue {
             false
         }
     }
-    // Iterate through tokens, resolve choices, render as strings.
-    // Side-effect: render strings into buffer provided by Writer.
+    // Drop tokens from the end of the queue so that only count remain.
+    pub fn truncate(&mut self, count: usize) {
+        if count < self.count {
+            self.count = count;
+        }
+    }
+    // Iterate through tokens, resolve choices, add Segments to list.
+    // Side-effect: push Segments into out. Segments borrow from query and user.
+    // Learned choices from ranking's history come first in candidate lists, or
+    // else the choice that most often follows the text before it: ranking's
+    // context for the first segment, and the 词语 of the segment before for the
+    // rest. Passed through text breaks the chain. Built-in candidates
+    // are spelled in the written form of dialect. Keys get read as pinyin the
+    // same way the lattice read them, as per options.
     // Possible surprising behavior:
     // - Value of CiOpenChoice depends on lookahead for MaybeChoice
-    // - MaybeChoice gets consumed (skipped) if used to resolve choice
-    pub fn render_and_write(&mut self, sink: &mut impl dialects::Writer) {
+    // - MaybeChoice gets consumed (skipped) if used to resolve choice or to
+    //   turn the page of its candidate list
+    // Consumed tokens are tracked in a local array rather than by overwriting
+    // the queue, so resolving the same queue twice gives the same result.
+    pub fn resolve<'a>(
+        &self,
+        query: &dialects::Utf8Str<'a>,
+        user: &'a [UserEntry],
+        ranking: Ranking,
+        options: lattice::KeyOptions,
+        dialect: dialects::Dialect,
+        out: &mut segment::SegmentList<'a>,
+    ) {
+        let mut consumed = [false; TOKEN_QUEUE_SIZE];
         let mut current = 0;
-        let mut utf8_buf = [0u8; 4];
+        // Simplified spelling of the text before the current segment
+        let mut before = ranking.context;
         while current < self.count {
+            if consumed[current] {
+                current += 1;
+                continue;
+            }
+            let span = self.spans[current];
             match self.queue[current] {
                 // CiOne: This is an clear pinyin match for just one 词语
-                Token::CiOne(ciyu_i) => {
-                    sink.write(&autogen_hsk::CIYU[ciyu_i]);
+                Token::CiOne(matches) => {
+                    let ranking = Ranking {
+                        context: before,
+                        ..ranking
+                    };
+                    let candidates =
+                        candidates(query, span, matches, user, ranking, options, dialect);
+                    before = candidates.hans(0).unwrap_or("");
+                    if let Some(ciyu) = candidates.get(0) {
+                        let _ = out.push(segment::Segment {
+                            start: span.start,
+                            end: span.end,
+                            kind: segment::SegmentKind::Ciyu(ciyu),
+                        });
+                    }
                     // Look ahead for adjacent space that might be intended
                     // to prevent this ciyu from getting matched as part
                     // of the pinyin for another longer ciyu
@@ -65,7 +132,7 @@ impl TokenQueue {
                         if let Token::MaybeChoice(tk) = self.queue[current + 1] {
                             // Consume the space
                             if tk == ' ' {
-                                self.queue[current + 1] = Token::Skip;
+                                consumed[current + 1] = true;
                             }
                         }
                     }
@@ -74,43 +141,101 @@ impl TokenQueue {
                 // CiOpenChoice: This is an ambiguous pinyin match for
                 // a set of homphone 词语 that require further input to
                 // resolve the choice between them
-                Token::CiOpenChoice(ciyu_i) => {
-                    let ciyu = &autogen_hsk::CIYU[ciyu_i];
+                Token::CiOpenChoice(matches) => {
+                    let ranking = Ranking {
+                        context: before,
+                        ..ranking
+                    };
+                    let mut candidates =
+                        candidates(query, span, matches, user, ranking, options, dialect);
                     // Look ahead for a possible MaybeChoice token to
-                    // resolve the open choice
-                    let mut choice_resolved = false;
-                    for i in current..self.count {
+                    // resolve the open choice, turning pages on the way
+                    let mut pick = None;
+                    let lookahead = consumed
+                        .iter_mut()
+                        .enumerate()
+                        .take(self.count)
+                        .skip(current);
+                    for (i, was_consumed) in lookahead {
+                        if *was_consumed {
+                            continue;
+                        }
                         if let Token::MaybeChoice(tk) = self.queue[i] {
-                            match dialects::expand_choice_and_write(ciyu, tk, sink) {
-                                dialects::ExpandChoiceResult::WasChoice => {
-                                    self.queue[i] = Token::Skip;
-                                    choice_resolved = true;
-                                    break;
-                                }
-                                dialects::ExpandChoiceResult::WasNotChoice => {}
+                            if candidates.turn_page(tk) {
+                                *was_consumed = true;
+                            } else if let Some(i) = candidates.pick(tk) {
+                                *was_consumed = true;
+                                pick = Some(i);
+                                break;
                             }
                         }
                     }
-                    if !choice_resolved {
-                        // TODO: use enum variant instead of '0' to indicate no MaybeChoice found
-                        let _ = dialects::expand_choice_and_write(ciyu, '0', sink);
-                    }
+                    before = candidates.hans(pick.unwrap_or(0)).unwrap_or("");
+                    let kind = match pick {
+                        Some(i) => segment::SegmentKind::Chosen(candidates, i),
+                        None => segment::SegmentKind::OpenChoice(candidates),
+                    };
+                    let _ = out.push(segment::Segment {
+                        start: span.start,
+                        end: span.end,
+                        kind,
+                    });
                 }
 
                 // MaybeChoice: This is for spaces or numbers that should
                 // be passed through unchanged because they were not
                 // consumed by the lookahead from a CiOne or CiOpenChoice
-                Token::MaybeChoice(tk) => sink.write(tk.encode_utf8(&mut utf8_buf)),
-
                 // Other: This is for stuff like "UPPER CASE" or emoji
-                Token::Other(tk) => sink.write(tk.encode_utf8(&mut utf8_buf)),
+                Token::MaybeChoice(_) | Token::Other => {
+                    before = "";
+                    // Merge runs of adjacent pass through characters
+                    if let Some(prev) = out.last_mut() {
+                        if let segment::SegmentKind::Text(_) = prev.kind {
+                            if prev.end == span.start {
+                                if let Some(s) = query.char_slice(prev.start, span.end) {
+                                    prev.kind = segment::SegmentKind::Text(s);
+                                    prev.end = span.end;
+                                    current += 1;
+                                    continue;
+                                }
+                            }
+                        }
+                    }
+                    if let Some(s) = query.char_slice(span.start, span.end) {
+                        let _ = out.push(segment::Segment {
+                            start: span.start,
+                            end: span.end,
+                            kind: segment::SegmentKind::Text(s),
+                        });
+                    }
+                }
 
-                // Skip: This marks spaces and numbers consumed by the
-                // lookahead for CiOne or CiOpenChoice, and it fills empty
-                // region of buffer
+                // Skip: This fills empty region of buffer
                 Token::Skip => {}
             }
             current += 1;
         } // end while
-    } // end render_and_write()
+    } // end resolve()
 } // end impl TokenQueue
+
+// Candidates for the pinyin search key at span of query. Keys can have tone
+// marks, and they only have tone numbers if the lattice matched them with
+// tone input on.
+fn candidates<'a>(
+    query: &dialects::Utf8Str<'a>,
+    span: Span,
+    matches: dialects::KeyMatches,
+    user: &'a [UserEntry],
+    ranking: Ranking,
+    options: lattice::KeyOptions,
+    dialect: dialects::Dialect,
+) -> segment::Candidates<'a> {
+    let key = query.char_slice(span.start, span.end).unwrap_or("");
+    let mut plain = [0; autogen_hsk::PINYIN_SIZE_MAX];
+    let (pinyin, typed_tones) = options.search_key(key, &mut plain).unwrap_or((key, ""));
+    segment::Candidates::new(matches, user_dict::find(user, pinyin))
+        .with_dialect(dialect)
+        .with_tones(typed_tones)
+        .promote(learn::chosen(ranking.history, pinyin))
+        .promote_in_context(ranking.context)
+}
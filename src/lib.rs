@@ -5,11 +5,31 @@
 #![forbid(unsafe_code)]
 
 mod api;
+#[cfg(feature = "bigram")]
+mod autogen_bigram;
+#[cfg(feature = "gb2312")]
+mod autogen_gb2312;
 mod autogen_hsk;
+mod autogen_yue;
 pub mod constants;
+mod context;
 mod dialects;
+mod fuzzy;
+mod lattice;
+mod learn;
 mod lex;
 mod m3hash;
+mod predict;
+mod preedit;
+mod reverse;
+mod segment;
+mod session;
+mod shuangpin;
+mod syllable;
+mod tones;
+mod user_dict;
+mod yue;
+mod zhuyin;
 
 // Export v1 api names. The point of using re-exports is to allow for splitting
 // the crate implementation into relatively small modules that are easy to
@@ -24,13 +44,14 @@ mod tests {
 
     #[test]
     fn test_translate_zh_hans_one() {
-        assert_eq!(&translate_zh_hans(&"1"), &"1");
+        let mut sink = BufWriter::new();
+        assert_eq!(translate_zh_hans("1", &mut sink), "1");
     }
 
     #[test]
     fn test_hash_one() {
         let seed = 0;
         let limit = 1;
-        assert_eq!(m3hash::grapheme_cluster(&"1", seed, limit), (1527182858, 1));
+        assert_eq!(m3hash::grapheme_cluster("1", seed, limit), (1527182858, 1));
     }
 }
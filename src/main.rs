@@ -2,16 +2,39 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 //
 #![forbid(unsafe_code)]
+use hanzi_ime::{ChoiceHistory, ChoiceStore, ImeSession, Key, UserEntry, Writer};
+use std::{env, fs};
 
-use hanzi_ime;
+// ChoiceStore that keeps learned choices in a file between runs
+struct FileStore {
+    path: String,
+    text: String,
+}
+impl ChoiceStore for FileStore {
+    fn load(&mut self) -> &str {
+        self.text = fs::read_to_string(&self.path).unwrap_or_default();
+        &self.text
+    }
+    fn clear(&mut self) {
+        self.text.clear();
+    }
+    fn append(&mut self, text: &str) {
+        self.text.push_str(text);
+    }
+    fn flush(&mut self) {
+        if let Err(e) = fs::write(&self.path, &self.text) {
+            eprintln!("Could not save {}: {}", self.path, e);
+        }
+    }
+}
 
 // Minimal example of using hanzi_ime as library with std and CLI
 fn main() {
     let queries = &[
-        &"woxiangheguozhi",
-        &"woxiang heguozhi",
-        &"woxiang he guozhi",
-        &"woxianheguozhi11",
+        "woxiangheguozhi",
+        "woxiang heguozhi",
+        "woxiang he guozhi",
+        "woxianheguozhi11",
     ];
     // Make a stack allocated string buffer with the Writer trait
     // that hanzi_ime::query() expects
@@ -21,4 +44,30 @@ fn main() {
         println!("\n{}\n{}", q, hanzi_ime::query(q, &mut sink));
         sink.rewind();
     }
+
+    // If HANZI_IME_HISTORY names a file, commit the queries in a session that
+    // learns from choices, and keep what it learned in the file between runs.
+    // The second query gets the choice made with "3" in the first one as its
+    // default.
+    if let Ok(path) = env::var("HANZI_IME_HISTORY") {
+        let mut file_store = FileStore {
+            path,
+            text: String::new(),
+        };
+        let mut entries = [UserEntry::EMPTY; 100];
+        let mut history = ChoiceHistory::new(&mut entries);
+        history.load(&mut file_store);
+        let mut session = ImeSession::with_history(history);
+        for q in ["xiang3", "xiang"].iter() {
+            for c in q.chars() {
+                sink.rewind();
+                session.key(Key::Char(c), &mut sink);
+            }
+            println!("\n{}\n{}", q, sink.to_s());
+            sink.rewind();
+            session.key(Key::Commit, &mut sink);
+            println!("{}", sink.to_s());
+        }
+        session.history().save(&mut file_store);
+    }
 }
@@ -0,0 +1,317 @@
+// Copyright (c) 2021 Sam Blenny
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+#![forbid(unsafe_code)]
+
+#[cfg(feature = "bigram")]
+use crate::autogen_bigram;
+use crate::autogen_hsk;
+use crate::dialects::{Dialect, Utf8Str};
+use crate::segment;
+
+// Predictions for pinyin that is still being typed. Lookups only match whole
+// search keys, so "guoz" matches nothing until the "hi" of 果汁 guozhi gets
+// typed. The generator also emits PINYIN_SORTED, the search keys in
+// alphabetical order, where all the keys that start with "guoz" are next to
+// each other. Two binary searches find them, and their 词语 get ranked by
+// weight, as homophones are.
+//
+// With the "bigram" feature, there are also predictions for the word after
+// committed text, from a table of which vocab words follow which in a small
+// corpus (autogen_bigram, from vocab/bigram-corpus.txt).
+
+/// Most predictions that a PredictionList holds
+pub const PREDICTION_LIST_SIZE: usize = segment::CANDIDATE_PAGE_SIZE;
+
+/// A built-in 词语 whose search key starts with the letters at the end of the
+/// input, like 果汁 (guozhi) for "guoz". Predictions are offered alongside the
+/// candidates, not as candidates, since the input doesn't match them yet. The
+/// character range is the part of the input that the prediction completes,
+/// with start..end semantics (upper bound exclusive).
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Prediction {
+    pub start: usize,
+    pub end: usize,
+    /// Predicted 词语, spelled for the dialect of the lookup
+    pub ciyu: &'static str,
+    /// Whole search key of the 词语 (like "guozhi")
+    pub pinyin: &'static str,
+    /// Frequency weight of the 词语 (higher is more common)
+    pub weight: u32,
+}
+
+/// Holds list of Predictions, most likely first
+pub struct PredictionList {
+    list: [Prediction; PREDICTION_LIST_SIZE],
+    count: usize,
+}
+impl PredictionList {
+    /// Return empty list ready for use.
+    pub fn new() -> PredictionList {
+        let empty = Prediction {
+            start: 0,
+            end: 0,
+            ciyu: "",
+            pinyin: "",
+            weight: 0,
+        };
+        PredictionList {
+            list: [empty; PREDICTION_LIST_SIZE],
+            count: 0,
+        }
+    }
+
+    /// Remove all predictions.
+    pub fn clear(&mut self) {
+        self.count = 0;
+    }
+
+    /// Number of predictions.
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Is the list empty?
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Prediction at index i.
+    pub fn get(&self, i: usize) -> Option<&Prediction> {
+        self.list[..self.count].get(i)
+    }
+
+    /// Iterate over predictions, most likely first.
+    pub fn iter(&self) -> core::slice::Iter<'_, Prediction> {
+        self.list[..self.count].iter()
+    }
+
+    // Insert p in rank order: higher weight first, then shorter key. Ties
+    // stay in the order they came in. When the list is full, whatever ranks
+    // last drops off the end.
+    fn insert(&mut self, p: Prediction) {
+        let outranks = |q: &Prediction| {
+            p.weight > q.weight || (p.weight == q.weight && p.pinyin.len() < q.pinyin.len())
+        };
+        let i = match self.iter().position(outranks) {
+            Some(i) => i,
+            None if self.count < PREDICTION_LIST_SIZE => self.count,
+            None => return,
+        };
+        let last = self.count.min(PREDICTION_LIST_SIZE - 1);
+        self.list.copy_within(i..last, i + 1);
+        self.list[i] = p;
+        self.count = (self.count + 1).min(PREDICTION_LIST_SIZE);
+    }
+}
+impl Default for PredictionList {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Predict the 词语 that the input is on its way to, from the run of
+/// lowercase letters at its end. The longest tail of that run which some
+/// longer search key starts with gets completed, so "woxiangguoz" offers
+/// 果汁 (guozhi) for "guoz". Keys that the tail already matches in full
+/// don't count, since lookups find those anyway. Input that doesn't end with
+/// a letter gets no predictions.
+/// Side-effect: fills out with up to PREDICTION_LIST_SIZE predictions, most
+/// common first.
+pub fn predict_completions(input: &str, dialect: Dialect, out: &mut PredictionList) {
+    out.clear();
+    let text = Utf8Str::new(input);
+    let end = text.char_count;
+    // Only ASCII letters count, so bytes of the tail are characters of it
+    let run = match text.char_slice(0, end) {
+        Some(s) => s.bytes().rev().take_while(u8::is_ascii_lowercase).count(),
+        None => return,
+    };
+    let longest = run.min(autogen_hsk::PINYIN_SIZE_MAX - 1);
+    for len in (1..=longest).rev() {
+        let prefix = match text.char_slice(end - len, end) {
+            Some(s) => s,
+            None => return,
+        };
+        let mut found = false;
+        for &k in &autogen_hsk::PINYIN_SORTED[prefix_range(prefix)] {
+            let i = k as usize;
+            let pinyin = autogen_hsk::PINYIN_KEYS[i];
+            if pinyin.len() == prefix.len() {
+                continue;
+            }
+            found = true;
+            let ciyu_list = match dialect {
+                Dialect::ZhHans => autogen_hsk::CIYU[i],
+                Dialect::ZhHant => autogen_hsk::CIYU_HANT[i],
+            };
+            for (ciyu, &weight) in ciyu_list.split('\t').zip(autogen_hsk::CIYU_WEIGHT[i]) {
+                out.insert(Prediction {
+                    start: end - len,
+                    end,
+                    ciyu,
+                    pinyin,
+                    weight,
+                });
+            }
+        }
+        if found {
+            return;
+        }
+    }
+}
+
+/// Predict the 词语 that is likely to come after committed text, from the
+/// word bigram table (with the "bigram" feature). The longest word at the end
+/// of last_committed that the table knows picks the predictions, so "我想"
+/// offers 喝, 去, 吃, and so on. Text that ends with punctuation gets none.
+/// The predictions are in Simplified characters, and their character range
+/// is empty (0..0), since nothing has been typed for them yet. Their weight
+/// is how often they follow the word in the corpus.
+/// Side-effect: fills out with up to PREDICTION_LIST_SIZE predictions, most
+/// common first.
+#[cfg(feature = "bigram")]
+pub fn predict_next(last_committed: &str, out: &mut PredictionList) {
+    out.clear();
+    let i = match match_last_word(last_committed) {
+        Some(i) => i,
+        None => return,
+    };
+    let words = autogen_bigram::NEXT[i].split('\t');
+    let keys = autogen_bigram::NEXT_PINYIN[i].split('\t');
+    for ((ciyu, pinyin), &weight) in words.zip(keys).zip(autogen_bigram::NEXT_COUNT[i]) {
+        out.insert(Prediction {
+            start: 0,
+            end: 0,
+            ciyu,
+            pinyin,
+            weight,
+        });
+    }
+}
+
+// Find the longest word at the end of text that the bigram table has next
+// words for.
+// Return: index in next word arrays for match
+#[cfg(feature = "bigram")]
+pub(crate) fn match_last_word(text: &str) -> Option<usize> {
+    // Utf8Str only indexes the first BUF_SIZE characters, so start from the
+    // last few characters
+    let tail = text
+        .char_indices()
+        .rev()
+        .take(autogen_bigram::WORD_SIZE_MAX)
+        .last()
+        .map_or(0, |(i, _)| i);
+    let text = Utf8Str::new(&text[tail..]);
+    let end = text.char_count;
+    let longest = autogen_bigram::WORD_SIZE_MAX.min(end);
+    (1..=longest).rev().find_map(|len| {
+        let word = text.char_slice(end - len, end)?;
+        crate::dialects::match_bigram(word)
+    })
+}
+
+// Range of PINYIN_SORTED for the search keys that start with prefix
+fn prefix_range(prefix: &str) -> core::ops::Range<usize> {
+    let sorted = autogen_hsk::PINYIN_SORTED;
+    let key = |k: &u16| autogen_hsk::PINYIN_KEYS[*k as usize];
+    let start = sorted.partition_point(|k| key(k) < prefix);
+    let len = sorted[start..].partition_point(|k| key(k).starts_with(prefix));
+    start..start + len
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Predicted 词语 for input, most likely first
+    fn predict<'a>(
+        input: &str,
+        dialect: Dialect,
+        buf: &'a mut [&'static str],
+    ) -> &'a [&'static str] {
+        let mut out = PredictionList::new();
+        predict_completions(input, dialect, &mut out);
+        for (slot, p) in buf.iter_mut().zip(out.iter()) {
+            *slot = p.ciyu;
+        }
+        &buf[..out.len()]
+    }
+
+    #[test]
+    fn sorted_keys_are_in_order() {
+        let keys = autogen_hsk::PINYIN_KEYS;
+        assert_eq!(autogen_hsk::PINYIN_SORTED.len(), keys.len());
+        for w in autogen_hsk::PINYIN_SORTED.windows(2) {
+            assert!(keys[w[0] as usize] < keys[w[1] as usize]);
+        }
+    }
+
+    #[test]
+    fn partly_typed_keys_offer_completions() {
+        let mut buf = [""; PREDICTION_LIST_SIZE];
+        assert!(predict("guoz", Dialect::ZhHans, &mut buf).contains(&"果汁"));
+        assert!(predict("woxiangheguoz", Dialect::ZhHans, &mut buf).contains(&"果汁"));
+        assert!(predict("guoz", Dialect::ZhHant, &mut buf).contains(&"果汁"));
+        assert!(predict("touf", Dialect::ZhHant, &mut buf).contains(&"頭髮"));
+        // Keys that the tail matches in full aren't predictions
+        assert!(!predict("xia", Dialect::ZhHans, &mut buf).contains(&"下"));
+        assert!(predict("xia", Dialect::ZhHans, &mut buf).contains(&"现在"));
+        // Nothing to complete
+        assert!(predict("guozhi ", Dialect::ZhHans, &mut buf).is_empty());
+        assert!(predict("xia4", Dialect::ZhHans, &mut buf).is_empty());
+        assert!(predict("", Dialect::ZhHans, &mut buf).is_empty());
+        assert!(predict("你好", Dialect::ZhHans, &mut buf).is_empty());
+    }
+
+    #[test]
+    fn predictions_are_ranked_and_cover_the_tail() {
+        let mut out = PredictionList::new();
+        predict_completions("nihao x", Dialect::ZhHans, &mut out);
+        assert_eq!(out.len(), PREDICTION_LIST_SIZE);
+        for (a, b) in out.iter().zip(out.iter().skip(1)) {
+            assert!(a.weight >= b.weight);
+        }
+        for p in out.iter() {
+            assert_eq!((p.start, p.end), (6, 7));
+            assert!(p.pinyin.starts_with('x') && p.pinyin.len() > 1);
+        }
+        predict_completions("我guoz", Dialect::ZhHans, &mut out);
+        let p = out.iter().find(|p| p.ciyu == "果汁").unwrap();
+        assert_eq!((p.start, p.end, p.pinyin), (1, 5, "guozhi"));
+    }
+
+    #[test]
+    #[cfg(feature = "bigram")]
+    fn committed_text_predicts_next_word() {
+        let mut out = PredictionList::new();
+        predict_next("我想", &mut out);
+        assert_eq!(out.get(0).map(|p| (p.ciyu, p.pinyin)), Some(("喝", "he")));
+        assert!(out.iter().any(|p| p.ciyu == "去") && out.iter().all(|p| p.start == p.end));
+        predict_next("我想喝", &mut out);
+        assert!(out.iter().any(|p| p.ciyu == "果汁"));
+        // The longest word at the end picks the predictions (喜欢, not 欢)
+        predict_next("他喜欢", &mut out);
+        assert!(out.iter().any(|p| p.ciyu == "喝"));
+        predict_next("我想。", &mut out);
+        assert!(out.is_empty());
+        predict_next("", &mut out);
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "bigram")]
+    fn bigram_tables_match_their_keys() {
+        for (i, key) in autogen_bigram::PREV_KEYS.iter().enumerate() {
+            assert_eq!(crate::dialects::match_bigram(key), Some(i));
+            let words = autogen_bigram::NEXT[i].split('\t');
+            let keys = autogen_bigram::NEXT_PINYIN[i].split('\t');
+            assert_eq!(words.clone().count(), autogen_bigram::NEXT_COUNT[i].len());
+            for (ciyu, pinyin) in words.zip(keys) {
+                let k = crate::dialects::match_key(pinyin).unwrap();
+                assert!(autogen_hsk::CIYU[k].split('\t').any(|c| c == ciyu));
+            }
+        }
+    }
+}
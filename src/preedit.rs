@@ -0,0 +1,384 @@
+// Copyright (c) 2021 Sam Blenny
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+#![forbid(unsafe_code)]
+
+use crate::constants;
+use crate::dialects::{Utf8Str, Writer};
+use crate::segment::{Candidates, Segment, SegmentList};
+use crate::syllable::{self, SyllableKind, SyllableList};
+
+// Output of an ImeSession split up the way IBus- and fcitx-style hosts use
+// it: text that is final, preedit text that is still being composed, where
+// the cursor is in the preedit, and the candidates that a choice key picks
+// from. ImeSession::render() mixes all of these into one string, so hosts
+// can't tell which parts of it are final. Committed and preedit text both
+// keep the character range of the input that each of their segments came
+// from, so hosts can map a 词语 back to the keys that were typed for it.
+
+// Text can be longer than the input it came from: one letter of pinyin can
+// turn into a 3 byte hanzi, and abbreviations and user words into more
+const TEXT_BUF_SIZE: usize = 4 * constants::BUF_SIZE;
+const OUTPUT_LIST_SIZE: usize = constants::BUF_SIZE;
+
+// Separator between syllables of pinyin in the preedit, as in xiang'he
+const SYLLABLE_SEPARATOR: &str = "'";
+
+// Writer for text that gets kept between calls. Characters that don't fit get
+// dropped whole, so the text is always valid UTF-8.
+#[derive(Copy, Clone)]
+struct TextBuf {
+    buf: [u8; TEXT_BUF_SIZE],
+    len: usize,
+    chars: usize,
+}
+impl TextBuf {
+    // Return empty buffer ready for use.
+    pub fn new() -> TextBuf {
+        TextBuf {
+            buf: [0; TEXT_BUF_SIZE],
+            len: 0,
+            chars: 0,
+        }
+    }
+
+    // Forget the text.
+    pub fn clear(&mut self) {
+        self.len = 0;
+        self.chars = 0;
+    }
+
+    // Number of characters in the text.
+    pub fn char_count(&self) -> usize {
+        self.chars
+    }
+}
+impl Writer for TextBuf {
+    // Append message, dropping characters that don't fit
+    fn write(&mut self, message: &str) {
+        for c in message.chars() {
+            let n = c.len_utf8();
+            if self.len + n > self.buf.len() {
+                return;
+            }
+            c.encode_utf8(&mut self.buf[self.len..self.len + n]);
+            self.len += n;
+            self.chars += 1;
+        }
+    }
+
+    // Ignore traces
+    fn trace(&mut self, _: i32) {}
+
+    // Return string slice of the text.
+    fn to_s(&self) -> &str {
+        // Bad utf8 can't happen since buf only gets whole chars.
+        core::str::from_utf8(&self.buf[..self.len]).unwrap_or_default()
+    }
+}
+
+/// One piece of committed or preedit text, along with the character range
+/// of the input that it came from and its own character range in the text.
+/// Ranges follow start..end semantics (upper bound exclusive).
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct OutputSegment {
+    pub start: usize,
+    pub end: usize,
+    pub text_start: usize,
+    pub text_end: usize,
+    /// Is the text settled (a 词语, or characters that pass through), rather
+    /// than pinyin of a choice that hasn't been made yet?
+    pub resolved: bool,
+}
+impl OutputSegment {
+    /// Part of text (the committed or preedit text that the segment belongs
+    /// to) that the segment covers.
+    pub fn text_in<'t>(&self, text: &'t str) -> &'t str {
+        let byte = |i| text.char_indices().nth(i).map_or(text.len(), |(b, _)| b);
+        &text[byte(self.text_start)..byte(self.text_end)]
+    }
+}
+
+// Text along with the segments of input that it came from.
+#[derive(Copy, Clone)]
+pub(crate) struct SegmentedText {
+    text: TextBuf,
+    list: [OutputSegment; OUTPUT_LIST_SIZE],
+    count: usize,
+}
+impl SegmentedText {
+    // Return empty text ready for use.
+    pub fn new() -> SegmentedText {
+        let empty = OutputSegment {
+            start: 0,
+            end: 0,
+            text_start: 0,
+            text_end: 0,
+            resolved: true,
+        };
+        SegmentedText {
+            text: TextBuf::new(),
+            list: [empty; OUTPUT_LIST_SIZE],
+            count: 0,
+        }
+    }
+
+    // Forget the text and its segments.
+    pub fn clear(&mut self) {
+        self.text.clear();
+        self.count = 0;
+    }
+
+    // Return the text as a string slice.
+    pub fn as_str(&self) -> &str {
+        self.text.to_s()
+    }
+
+    // Iterate over segments in input order.
+    pub fn segments(&self) -> core::slice::Iter<'_, OutputSegment> {
+        self.list[..self.count].iter()
+    }
+
+    // Fill with the text that committing segments gives, taking the default
+    // choice for open choices.
+    pub fn set_committed(&mut self, segments: &SegmentList) {
+        self.clear();
+        for seg in segments.iter() {
+            let text_start = self.text.char_count();
+            if let Some(text) = seg.commit_text() {
+                self.text.write(text);
+            }
+            let _ = self.push(seg, text_start);
+        }
+    }
+
+    // Add the text written since text_start as the segment for seg.
+    fn push(&mut self, seg: &Segment, text_start: usize) -> bool {
+        if self.count < OUTPUT_LIST_SIZE {
+            self.list[self.count] = OutputSegment {
+                start: seg.start,
+                end: seg.end,
+                text_start,
+                text_end: self.text.char_count(),
+                resolved: seg.is_resolved(),
+            };
+            self.count += 1;
+            true
+        } else {
+            // Error: List is full
+            false
+        }
+    }
+}
+
+/// What an ImeSession has for the front-end after a key event, split into
+/// the text that the key committed, the preedit text of the composition, the
+/// cursor position in the preedit, and the active candidate list. In the
+/// preedit, resolved segments show their 词语, and open choices show their
+/// pinyin split into syllables, like xiang'he. Segments of both texts say
+/// which characters of the input they came from.
+pub struct ImeOutput<'a> {
+    committed: SegmentedText,
+    preedit: SegmentedText,
+    candidates: Option<Candidates<'a>>,
+}
+impl<'a> ImeOutput<'a> {
+    /// Return empty output ready for use.
+    pub fn new() -> ImeOutput<'a> {
+        ImeOutput {
+            committed: SegmentedText::new(),
+            preedit: SegmentedText::new(),
+            candidates: None,
+        }
+    }
+
+    /// Remove all text, segments, and candidates.
+    pub fn clear(&mut self) {
+        self.committed.clear();
+        self.preedit.clear();
+        self.candidates = None;
+    }
+
+    /// Text that is final: what the key event committed, or "" if it didn't
+    /// commit anything.
+    pub fn committed(&self) -> &str {
+        self.committed.as_str()
+    }
+
+    /// Iterate over the segments of the committed text in input order. Their
+    /// input ranges are in the composition that got committed.
+    pub fn committed_segments(&self) -> core::slice::Iter<'_, OutputSegment> {
+        self.committed.segments()
+    }
+
+    /// Text of the composition that is still being edited, or "" if there is
+    /// no composition.
+    pub fn preedit(&self) -> &str {
+        self.preedit.as_str()
+    }
+
+    /// Iterate over the segments of the preedit text in input order.
+    pub fn preedit_segments(&self) -> core::slice::Iter<'_, OutputSegment> {
+        self.preedit.segments()
+    }
+
+    /// Cursor position in the preedit text, in characters. Edits only happen
+    /// at the end of the composition, so the cursor is at the end.
+    pub fn cursor(&self) -> usize {
+        self.preedit.text.char_count()
+    }
+
+    /// Candidates of the first open choice, which space or a digit picks
+    /// from next, or None if there is no open choice.
+    pub fn candidates(&self) -> Option<Candidates<'a>> {
+        self.candidates
+    }
+
+    // Set the committed text and its segments.
+    pub(crate) fn set_committed(&mut self, committed: &SegmentedText) {
+        self.committed = *committed;
+    }
+
+    // Fill the preedit from the segments of the composition in input.
+    pub(crate) fn set_preedit(&mut self, input: &str, segments: &SegmentList<'a>) {
+        let query = Utf8Str::new(input);
+        self.preedit.clear();
+        self.candidates = None;
+        for seg in segments.iter() {
+            let resolved = seg.is_resolved();
+            let key = query.char_slice(seg.start, seg.end).unwrap_or("");
+            if !resolved && self.continues_pinyin(seg.start, key) {
+                self.preedit.text.write(SYLLABLE_SEPARATOR);
+            }
+            let text_start = self.preedit.text.char_count();
+            match seg.text() {
+                Some(text) => self.preedit.text.write(text),
+                None => write_syllables(key, &mut self.preedit.text),
+            }
+            if !resolved && self.candidates.is_none() {
+                self.candidates = seg.candidates();
+            }
+            let _ = self.preedit.push(seg, text_start);
+        }
+    }
+
+    // Does the pinyin key of an open choice at start carry on the pinyin of
+    // an open choice right before it? Then the two are one run of pinyin, and
+    // they get a separator between them, as syllables of one key do.
+    fn continues_pinyin(&self, start: usize, key: &str) -> bool {
+        let prev = self.preedit.segments().last();
+        let letter = |c: char| c.is_ascii_alphabetic();
+        prev.is_some_and(|p| !p.resolved && p.end == start)
+            && self.preedit().ends_with(letter)
+            && key.starts_with(letter)
+    }
+}
+impl<'a> Default for ImeOutput<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Write key as typed, with a separator between syllables that are right next
+// to each other, so xianghe comes out as xiang'he. Letters that aren't part
+// of a syllable (like the z of guoz) don't get separated.
+// Side-effect: render strings into buffer provided by Writer.
+fn write_syllables(key: &str, sink: &mut impl Writer) {
+    let mut pieces = SyllableList::new();
+    syllable::split_syllables(key, &mut pieces);
+    let mut next = pieces.iter().peekable();
+    let mut prev = None;
+    for (i, c) in key.chars().enumerate() {
+        if let Some(piece) = next.next_if(|p| p.start == i) {
+            let is_syllable = piece.kind == SyllableKind::Syllable;
+            let joins = prev.is_some_and(|(end, was_syllable)| end == i && was_syllable);
+            if is_syllable && joins {
+                sink.write(SYLLABLE_SEPARATOR);
+            }
+            prev = Some((piece.end, is_syllable));
+        }
+        let mut buf = [0; 4];
+        sink.write(c.encode_utf8(&mut buf));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dialects::look_up_segments;
+
+    // Output with the preedit for query, as looked up without context or
+    // history
+    fn preedit_of(query: &str) -> ImeOutput<'_> {
+        let mut segments = SegmentList::new();
+        look_up_segments(query, &mut segments);
+        let mut out = ImeOutput::new();
+        out.set_preedit(query, &segments);
+        out
+    }
+
+    #[test]
+    fn open_choices_show_pinyin_syllables() {
+        let out = preedit_of("woxianghe");
+        assert_eq!(out.preedit(), "我xiang'he");
+        assert_eq!(out.cursor(), 9);
+        let ranges = |s: &OutputSegment| (s.start, s.end, s.text_start, s.text_end, s.resolved);
+        let expected = [(0, 2, 0, 1, true), (2, 7, 1, 6, false), (7, 9, 7, 9, false)];
+        assert_eq!(out.preedit_segments().count(), expected.len());
+        for (seg, want) in out.preedit_segments().zip(expected.iter()) {
+            assert_eq!(ranges(seg), *want);
+        }
+        let he = out.preedit_segments().last().unwrap();
+        assert_eq!(he.text_in(out.preedit()), "he");
+        let candidates = out.candidates().unwrap();
+        #[cfg(feature = "bigram")]
+        assert_eq!(candidates.get(0), Some("想"));
+        #[cfg(not(feature = "bigram"))]
+        assert_eq!(candidates.get(0), Some("向"));
+        // Resolved choices and text that passes through show as they are
+        let out = preedit_of("xiang3 OK");
+        assert_eq!(out.preedit(), "像 OK");
+        assert!(out.preedit_segments().all(|s| s.resolved));
+        assert!(out.candidates().is_none());
+    }
+
+    #[test]
+    fn committed_text_maps_back_to_input() {
+        let query = "woxiang3he3 OK";
+        let mut segments = SegmentList::new();
+        look_up_segments(query, &mut segments);
+        let mut committed = SegmentedText::new();
+        committed.set_committed(&segments);
+        let text = committed.as_str();
+        assert_eq!(text, "我像喝 OK");
+        let spans = [("我", "wo"), ("像", "xiang"), ("喝", "he"), (" OK", " OK")];
+        assert_eq!(committed.segments().count(), spans.len());
+        for (seg, &(ciyu, key)) in committed.segments().zip(spans.iter()) {
+            assert_eq!(seg.text_in(text), ciyu);
+            assert_eq!(&query[seg.start..seg.end], key);
+            assert!(seg.resolved);
+        }
+    }
+
+    #[test]
+    fn syllables_get_separated_within_a_key() {
+        let mut sink = TextBuf::new();
+        write_syllables("xianghe", &mut sink);
+        assert_eq!(sink.to_s(), "xiang'he");
+        for (key, shown) in [("xi'an", "xi'an"), ("guoz", "guoz"), ("ㄏㄜ", "ㄏㄜ")].iter() {
+            sink.clear();
+            write_syllables(key, &mut sink);
+            assert_eq!(sink.to_s(), *shown);
+        }
+    }
+
+    #[test]
+    fn text_buf_drops_characters_that_dont_fit() {
+        let mut text = TextBuf::new();
+        for _ in 0..TEXT_BUF_SIZE {
+            text.write("我");
+        }
+        assert_eq!(text.to_s().len(), TEXT_BUF_SIZE / 3 * 3);
+        assert_eq!(text.char_count(), TEXT_BUF_SIZE / 3);
+    }
+}
@@ -0,0 +1,271 @@
+// Copyright (c) 2021 Sam Blenny
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+#![forbid(unsafe_code)]
+
+#[cfg(feature = "gb2312")]
+use crate::autogen_gb2312;
+use crate::autogen_hsk;
+use crate::constants;
+use crate::dialects::{self, Utf8Str, Writer};
+use crate::tones;
+
+// Reverse lookup, from hanzi text back to pinyin. Text gets split into the
+// fewest 词语 from the reverse index (autogen_hsk::HANZI), so polyphones take
+// the reading of the word they are part of: 长 is cháng in 长城 but zhǎng in
+// 校长, and 觉 is jiào in 睡觉 but jué in 觉得. Characters that aren't vocab
+// words by themselves get their own reading: the GB 2312 one (with the
+// "gb2312" feature), or else the one they have in a vocab word, like ān from
+// 安全 for the 安 of 天安门. Characters with no reading at all pass through
+// unchanged.
+
+const PATH_SIZE: usize = constants::BUF_SIZE + 1;
+
+/// How to spell tones in the output of to_pinyin
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum PinyinStyle {
+    /// Tone marks over vowels, like "wǒ xiǎng hē guǒzhī"
+    #[default]
+    ToneMarks,
+    /// Tone numbers after syllables (5 for neutral tone), like
+    /// "wo3 xiang3 he1 guo3zhi1", as typed for tone input
+    ToneNumbers,
+}
+
+/// Convert hanzi text to pinyin, one word at a time with spaces between the
+/// words. Syllables with no known tone (see autogen_hsk::CIYU_TONED) come
+/// out without one.
+/// Side-effect: renders utf8 result string into buffer provided by Writer.
+/// Return: string slice of results backed by sink.
+pub fn to_pinyin<'a>(text: &str, style: PinyinStyle, sink: &'a mut impl Writer) -> &'a str {
+    let mut rest = text;
+    let mut prev_word = false;
+    while !rest.is_empty() {
+        // Utf8Str only indexes the first BUF_SIZE characters, so go through
+        // long text in chunks
+        let chunk = Utf8Str::new(rest);
+        prev_word = write_chunk(&chunk, style, prev_word, sink);
+        let done = chunk
+            .char_slice(0, chunk.char_count)
+            .map_or(rest.len(), str::len);
+        rest = &rest[done..];
+    }
+    sink.to_s()
+}
+
+// Segment one chunk of text and write its pinyin. prev_word says whether the
+// chunk comes right after a word, which needs a space before the next one.
+// Return: whether the chunk ends with a word
+fn write_chunk(
+    text: &Utf8Str,
+    style: PinyinStyle,
+    prev_word: bool,
+    sink: &mut impl Writer,
+) -> bool {
+    // Fewest segments to reach each position, and where the last one starts
+    let mut count = [0; PATH_SIZE];
+    let mut start = [0; PATH_SIZE];
+    let n = text.char_count;
+    for end in 1..=n {
+        count[end] = usize::MAX;
+        // Going from the longest word down means ties go to the longer last
+        // word, which is right more often than the longer first word
+        for len in (1..=autogen_hsk::HANZI_SIZE_MAX.min(end)).rev() {
+            let matched = len == 1
+                || text
+                    .char_slice(end - len, end)
+                    .and_then(dialects::match_hanzi)
+                    .is_some();
+            if matched && count[end - len] + 1 < count[end] {
+                count[end] = count[end - len] + 1;
+                start[end] = end - len;
+            }
+        }
+    }
+    // Walk the path back from the end, then write it out front to back
+    let mut bounds = [0; PATH_SIZE];
+    let mut k = 0;
+    let mut end = n;
+    while end > 0 {
+        bounds[k] = end;
+        k += 1;
+        end = start[end];
+    }
+    let mut prev_word = prev_word;
+    let mut begin = 0;
+    for &end in bounds[..k].iter().rev() {
+        if let Some(s) = text.char_slice(begin, end) {
+            let reading = match dialects::match_hanzi(s) {
+                Some(i) => Some(autogen_hsk::HANZI_PINYIN[i]),
+                None => char_reading(s),
+            };
+            match reading {
+                Some(reading) => {
+                    if prev_word {
+                        sink.write(" ");
+                    }
+                    write_reading(reading, style, sink);
+                    prev_word = true;
+                }
+                None => {
+                    sink.write(s);
+                    prev_word = false;
+                }
+            }
+        }
+        begin = end;
+    }
+    prev_word
+}
+
+// Tone-numbered reading of a single character that isn't a vocab word by
+// itself: its GB 2312 reading (with the "gb2312" feature), or else the
+// syllable it has in the first vocab word with it.
+// Return: reading like "an1", or None if c isn't a character with a reading
+fn char_reading(c: &str) -> Option<&'static str> {
+    let mut chars = c.chars();
+    let c = match (chars.next(), chars.next()) {
+        (Some(c), None) => c,
+        _ => return None,
+    };
+    #[cfg(feature = "gb2312")]
+    for (zi, toned) in autogen_gb2312::ZI.iter().zip(autogen_gb2312::ZI_TONED) {
+        if let Some(k) = zi.split('\t').position(|z| z.chars().eq(Some(c))) {
+            let toned = toned.split('\t').nth(k)?;
+            return toned.split('|').next();
+        }
+    }
+    let words = autogen_hsk::HANZI_KEYS
+        .iter()
+        .zip(autogen_hsk::HANZI_PINYIN);
+    for (key, reading) in words {
+        if let Some(k) = key.chars().position(|h| h == c) {
+            // Erhua and punctuation make the syllables not line up with the
+            // characters, so skip those words
+            let syllables = || reading.split_inclusive(|b: char| b.is_ascii_digit());
+            if syllables().count() == key.chars().count() {
+                return syllables().nth(k);
+            }
+        }
+    }
+    None
+}
+
+// Write a tone-numbered reading like "guo3zhi1" in the given style. Syllables
+// after the first that start with a, o, or e get an apostrophe in tone mark
+// style ("kě'ài"), since they would run into the syllable before.
+fn write_reading(reading: &str, style: PinyinStyle, sink: &mut impl Writer) {
+    let bytes = reading.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        let len = bytes[i..]
+            .iter()
+            .take_while(|b| b.is_ascii_lowercase())
+            .count();
+        if len == 0 {
+            // Tone numbers only get written in tone number style, and tone 0
+            // (not known) never does. Anything else passes through.
+            let b = bytes[i];
+            if !b.is_ascii_digit() || (style == PinyinStyle::ToneNumbers && b != b'0') {
+                sink.write(&reading[i..i + 1]);
+            }
+            i += 1;
+            continue;
+        }
+        let syllable = &bytes[i..i + len];
+        let tone = match bytes.get(i + len) {
+            Some(&d) if d.is_ascii_digit() => d - b'0',
+            _ => 0,
+        };
+        match style {
+            PinyinStyle::ToneMarks => {
+                if i > 0 && matches!(syllable[0], b'a' | b'o' | b'e') {
+                    sink.write("'");
+                }
+                write_marked(syllable, tone, sink);
+            }
+            PinyinStyle::ToneNumbers => sink.write(&reading[i..i + len]),
+        }
+        i += len;
+    }
+}
+
+// Write a syllable with its tone mark. The mark goes on a or e if there is
+// one, on the o of ou, or else on the last vowel.
+fn write_marked(syllable: &[u8], tone: u8, sink: &mut impl Writer) {
+    let vowel = |b: &u8| matches!(b, b'a' | b'e' | b'i' | b'o' | b'u' | b'v');
+    let at = syllable
+        .iter()
+        .position(|&b| b == b'a' || b == b'e')
+        .or_else(|| syllable.windows(2).position(|w| w == b"ou"))
+        .or_else(|| syllable.iter().rposition(vowel));
+    let mut buf = [0; 4];
+    for (k, &b) in syllable.iter().enumerate() {
+        let t = if Some(k) == at { tone } else { 0 };
+        sink.write(tones::marked(b, t).encode_utf8(&mut buf));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dialects::BufWriter;
+
+    #[test]
+    fn hanzi_come_back_as_pinyin() {
+        let marks = |text, expected| {
+            assert_eq!(
+                to_pinyin(text, PinyinStyle::ToneMarks, &mut BufWriter::new()),
+                expected
+            );
+        };
+        marks("我想喝果汁", "wǒ xiǎng hē guǒzhī");
+        marks("可爱", "kě'ài");
+        marks("女儿", "nǚ'ér");
+        marks("我想喝果汁。", "wǒ xiǎng hē guǒzhī。");
+        marks("Hi!", "Hi!");
+        marks("", "");
+        // Polyphones take the reading of the word they are in
+        marks("睡觉", "shuìjiào");
+        marks("觉得", "juéde");
+        marks("音乐", "yīnyuè");
+        marks("快乐", "kuàilè");
+        marks("银行", "yínháng");
+        // Syllables split the way the vocab pinyin does, including ones that
+        // start with a vowel
+        marks("加利福尼亚州", "jiālìfúníyàzhōu");
+        marks("妇女", "fùnǚ");
+        marks("答案", "dá'àn");
+        marks("恩爱", "ēn'ài");
+        // Characters that aren't words by themselves get their own reading,
+        // and ones with no reading pass through
+        marks("天安门", "tiān ān mén");
+        marks("龘", "龘");
+        let numbers = |text, expected| {
+            assert_eq!(
+                to_pinyin(text, PinyinStyle::ToneNumbers, &mut BufWriter::new()),
+                expected
+            );
+        };
+        numbers("我想喝果汁", "wo3 xiang3 he1 guo3zhi1");
+        numbers("女儿", "nv3er2");
+        // Tones that aren't known get left out
+        numbers("长城", "chang2cheng2");
+        numbers("角色", "juese4");
+    }
+
+    #[test]
+    fn reverse_index_is_sorted_and_matches_its_keys() {
+        for i in 1..autogen_hsk::HANZI.len() {
+            assert!(autogen_hsk::HANZI[i] > autogen_hsk::HANZI[i - 1]);
+        }
+        for (i, key) in autogen_hsk::HANZI_KEYS.iter().enumerate() {
+            assert_eq!(dialects::match_hanzi(key), Some(i));
+            assert!(key.chars().count() <= autogen_hsk::HANZI_SIZE_MAX);
+        }
+        assert_eq!(
+            autogen_hsk::HANZI_KEYS.len(),
+            autogen_hsk::HANZI_PINYIN.len()
+        );
+    }
+}
@@ -0,0 +1,685 @@
+// Copyright (c) 2021 Sam Blenny
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+#![forbid(unsafe_code)]
+
+#[cfg(feature = "gb2312")]
+use crate::autogen_gb2312;
+use crate::autogen_hsk;
+use crate::constants;
+use crate::context;
+use crate::dialects::{Dialect, KeyMatch, KeyMatches, Writer};
+use crate::tones;
+use crate::user_dict::UserEntry;
+
+// Data structures for returning lookup results as typed data rather than as
+// rendered text. SegmentList is a no_std, stack-only substitute for
+// Vec<Segment>, in the same way that TokenQueue substitutes for Vec<Token>.
+
+const SEGMENT_LIST_SIZE: usize = constants::BUF_SIZE;
+
+/// Number of candidates shown at once. Longer candidate lists get split into
+/// pages, with labels 1-9 on each page.
+pub const CANDIDATE_PAGE_SIZE: usize = 9;
+
+// Keys to turn candidate pages, as in other pinyin IMEs
+const PREV_PAGE: char = '-';
+const NEXT_PAGE: char = '=';
+
+/// Ordered list of homophone 词语 that a pinyin search key could mean. The
+/// candidate the user picked last time for the key (if learning is on) comes
+/// first, or else the one that most often follows the text before the key
+/// (with the "bigram" feature), then user dictionary words, then built-in
+/// words, most frequent first.
+/// Built-in words for fuzzy pinyin variants of the key (if fuzzy rules are on)
+/// come last. With tone input, built-in words that don't have the typed tones
+/// are left out. Tone marks in the key put words with the marked tones first.
+/// Built-in words are spelled in the written form of the lookup's dialect.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Candidates<'a> {
+    // User dictionary entries for the key
+    user: &'a [UserEntry],
+    // Built-in matches for the key
+    matches: KeyMatches,
+    // Index (before promotion) of the learned or context choice to put
+    // first, if any
+    promoted: Option<usize>,
+    // Did the promoted choice come from context rather than history?
+    contextual: bool,
+    // Typed key with tone numbers (like "xiang3"), or "" for no tone filter
+    tones: &'a str,
+    // Number of built-in choices left after the tone filter
+    builtin_count: usize,
+    // Page of candidates being shown
+    page: usize,
+    // Written form for built-in choices
+    dialect: Dialect,
+}
+impl<'a> Candidates<'a> {
+    pub(crate) fn new(matches: KeyMatches, user: &'a [UserEntry]) -> Candidates<'a> {
+        let mut candidates = Candidates {
+            user,
+            matches,
+            promoted: None,
+            contextual: false,
+            tones: "",
+            builtin_count: 0,
+            page: 0,
+            dialect: Dialect::ZhHans,
+        };
+        candidates.builtin_count = candidates.builtins().count();
+        candidates
+    }
+
+    // Spell built-in choices in the written form of dialect.
+    pub(crate) fn with_dialect(mut self, dialect: Dialect) -> Candidates<'a> {
+        self.dialect = dialect;
+        self.promoted = None;
+        self.contextual = false;
+        self
+    }
+
+    // Leave out built-in choices that don't have the tone numbers typed in key,
+    // or put the ones with its tone marks first. User dictionary words don't
+    // have tones, so they all stay where they are.
+    pub(crate) fn with_tones(mut self, key: &'a str) -> Candidates<'a> {
+        self.tones = key;
+        self.builtin_count = self.builtins().count();
+        self.promoted = None;
+        self.contextual = false;
+        self
+    }
+
+    // Move learned choice to the front. Choices that aren't candidates (say,
+    // a user dictionary word that has since been removed) get ignored.
+    pub(crate) fn promote(mut self, learned: Option<&str>) -> Candidates<'a> {
+        self.promoted = None;
+        self.contextual = false;
+        if let Some(learned) = learned {
+            self.promoted = self.unpromoted_iter().position(|c| c == learned);
+        }
+        self
+    }
+
+    // Move the choice that most often follows context in the bigram corpus to
+    // the front, unless a learned choice is there already. Ties go to the
+    // choice that comes first, and choices that never follow context keep
+    // their order, so without context nothing changes.
+    pub(crate) fn promote_in_context(mut self, context: &str) -> Candidates<'a> {
+        if self.promoted.is_some() || context.is_empty() {
+            return self;
+        }
+        // The bigram table is in Simplified characters, in the same order
+        let hans = Candidates {
+            dialect: Dialect::ZhHans,
+            ..self
+        };
+        let mut best = (0, 0);
+        for (i, choice) in hans.unpromoted_iter().enumerate() {
+            let count = context::follow_count(context, choice);
+            if count > best.1 {
+                best = (i, count);
+            }
+        }
+        if best.0 > 0 {
+            self.promoted = Some(best.0);
+            self.contextual = true;
+        }
+        self
+    }
+
+    /// Number of candidates.
+    pub fn len(&self) -> usize {
+        self.user.len() + self.builtin_count
+    }
+
+    /// Lookups never produce an empty candidate list, so this is for
+    /// completeness.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Candidate at index i (0 is the default choice).
+    pub fn get(&self, i: usize) -> Option<&'a str> {
+        self.unpromoted(self.source_index(i))
+    }
+
+    // Candidate at index i spelled in Simplified characters, as the bigram
+    // table has it.
+    pub(crate) fn hans(&self, i: usize) -> Option<&'a str> {
+        let hans = Candidates {
+            dialect: Dialect::ZhHans,
+            ..*self
+        };
+        hans.get(i)
+    }
+
+    /// Frequency weight of candidate at index i (higher is more common), or
+    /// None for user dictionary words.
+    pub fn weight(&self, i: usize) -> Option<u32> {
+        match self.source_index(i).checked_sub(self.user.len()) {
+            Some(i) => self.builtins().nth(i).map(|(_, weight, _)| weight),
+            None => None,
+        }
+    }
+
+    /// Did the key match these candidates by syllable-initial abbreviation
+    /// (like "sxym" for 赏心悦目) rather than by full pinyin?
+    pub fn is_abbreviation(&self) -> bool {
+        matches!(self.matches.key, Some(KeyMatch::Abbrev(_)))
+    }
+
+    /// Did candidate at index i come from a fuzzy pinyin variant of the key
+    /// rather than the key itself?
+    pub fn is_fuzzy(&self, i: usize) -> bool {
+        match self.source_index(i).checked_sub(self.user.len()) {
+            Some(i) => matches!(self.builtins().nth(i), Some((_, _, true))),
+            None => false,
+        }
+    }
+
+    /// Was candidate at index i promoted to the front because the user picked
+    /// it last time?
+    pub fn is_learned(&self, i: usize) -> bool {
+        i == 0 && self.promoted.is_some() && !self.contextual
+    }
+
+    /// Was candidate at index i promoted to the front because it often
+    /// follows the text before it?
+    pub fn is_contextual(&self, i: usize) -> bool {
+        i == 0 && self.promoted.is_some() && self.contextual
+    }
+
+    /// Page of candidates being shown (0 is the first page).
+    pub fn page(&self) -> usize {
+        self.page
+    }
+
+    /// Number of pages of up to CANDIDATE_PAGE_SIZE candidates.
+    pub fn page_count(&self) -> usize {
+        self.len().div_ceil(CANDIDATE_PAGE_SIZE)
+    }
+
+    /// Range of candidate indexes on the page being shown.
+    pub fn page_range(&self) -> core::ops::Range<usize> {
+        let start = self.page * CANDIDATE_PAGE_SIZE;
+        start..self.len().min(start + CANDIDATE_PAGE_SIZE)
+    }
+
+    /// Label ('1' to '9') that picks candidate at index i, or None if it
+    /// isn't on the page being shown.
+    pub fn label(&self, i: usize) -> Option<char> {
+        let range = self.page_range();
+        if range.contains(&i) {
+            core::char::from_digit((i - range.start) as u32 + 1, 10)
+        } else {
+            None
+        }
+    }
+
+    /// Iterate over candidates in order, starting with the default choice.
+    pub fn iter(&self) -> impl Iterator<Item = &'a str> {
+        let promoted = self.promoted;
+        let first = promoted.and_then(|p| self.unpromoted(p));
+        let rest = self.unpromoted_iter().enumerate();
+        let rest = rest.filter(move |&(i, _)| Some(i) != promoted);
+        first.into_iter().chain(rest.map(|(_, choice)| choice))
+    }
+
+    // Map index in promoted order to index in unpromoted order.
+    fn source_index(&self, i: usize) -> usize {
+        match self.promoted {
+            Some(p) if i == 0 => p,
+            Some(p) if i <= p => i - 1,
+            _ => i,
+        }
+    }
+
+    // Candidate at index i, ignoring promotion.
+    fn unpromoted(&self, i: usize) -> Option<&'a str> {
+        match self.user.get(i) {
+            Some(entry) => Some(entry.ciyu()),
+            None => {
+                let i = i - self.user.len();
+                self.builtins().nth(i).map(|(ciyu, _, _)| ciyu)
+            }
+        }
+    }
+
+    // Iterate over candidates in order, ignoring promotion.
+    fn unpromoted_iter(self) -> impl Iterator<Item = &'a str> {
+        let user = self.user.iter().map(|entry| entry.ciyu());
+        user.chain(self.builtins().map(|(ciyu, _, _)| ciyu))
+    }
+
+    // Iterate over built-in candidates in order, as (ciyu, weight, is_fuzzy).
+    // Tone numbers in the key leave out the ones with other tones, and tone
+    // marks put the ones with the marked tones first.
+    fn builtins(self) -> impl Iterator<Item = (&'static str, u32, bool)> + 'a {
+        let typed = self.tones;
+        let rank = !tones::has_numbers(typed);
+        let agree = move |toned: Option<&str>| match toned {
+            _ if typed.is_empty() => true,
+            Some(toned) => tones::matches(typed, toned),
+            None => false,
+        };
+        let first = self.all_builtins().filter(move |c| agree(c.3));
+        let rest = self.all_builtins().filter(move |c| rank && !agree(c.3));
+        first
+            .chain(rest)
+            .map(|(ciyu, weight, is_fuzzy, _)| (ciyu, weight, is_fuzzy))
+    }
+
+    // Iterate over all built-in choices as (ciyu, weight, is_fuzzy, toned):
+    // the key's own, then fuzzy ones. Toned is None for abbreviations.
+    fn all_builtins(
+        self,
+    ) -> impl Iterator<Item = (&'static str, u32, bool, Option<&'static str>)> + 'a {
+        let dialect = self.dialect;
+        let key = self.matches.key.map(|m| (choice_list(m, dialect), false));
+        let matches = self.matches;
+        let fuzzy = (0..matches.fuzzy().len()).map(move |k| {
+            let i = matches.fuzzy()[k];
+            (choice_list(KeyMatch::Ciyu(i), dialect), true)
+        });
+        key.into_iter()
+            .chain(fuzzy)
+            .flat_map(|((ciyu, weights, toned), is_fuzzy)| {
+                let toned = toned.into_iter().flat_map(|toned| toned.split('\t'));
+                let toned = toned.map(Some).chain(core::iter::repeat(None));
+                ciyu.split('\t')
+                    .zip(weights.iter())
+                    .zip(toned)
+                    .map(move |((ciyu, &weight), toned)| (ciyu, weight, is_fuzzy, toned))
+            })
+    }
+
+    // Candidate that space picks: the first one on the page being shown.
+    pub(crate) fn default_choice(&self) -> usize {
+        self.page_range().start
+    }
+
+    // Turn the page for a page key ("-" for previous, "=" for next), stopping
+    // at the first and last pages.
+    // Return: true if key is a page key and there is more than one page.
+    pub(crate) fn turn_page(&mut self, key: char) -> bool {
+        let last = self.page_count().saturating_sub(1);
+        if last == 0 {
+            return false;
+        }
+        match key {
+            PREV_PAGE => self.page = self.page.saturating_sub(1),
+            NEXT_PAGE => self.page = last.min(self.page + 1),
+            _ => return false,
+        }
+        true
+    }
+
+    // Map a choice key to a candidate index. Space picks the default choice
+    // (label=1), and digits pick by their label on the page being shown. Keys
+    // that aren't labels for one of the candidates don't count as a choice.
+    pub(crate) fn pick(&self, maybe_choice: char) -> Option<usize> {
+        let pick = match maybe_choice {
+            ' ' => 0,
+            '1'..='9' => maybe_choice as usize - '1' as usize,
+            _ => return None,
+        };
+        let range = self.page_range();
+        if range.start + pick < range.end {
+            Some(range.start + pick)
+        } else {
+            None
+        }
+    }
+
+    // Render choices on the page being shown as a prompt like " (1想 2向) ".
+    // When there are more pages, "-" before the choices or "=" after them say
+    // which way they are, like " (- 1享 2项 =) ".
+    // Side-effect: render strings into buffer provided by Writer.
+    fn write_prompt(&self, sink: &mut impl Writer) {
+        let range = self.page_range();
+        sink.write(" (");
+        if self.page > 0 {
+            sink.write("- ");
+        }
+        for i in range.clone() {
+            if let (Some(label), Some(choice)) = (self.label(i), self.get(i)) {
+                let mut buf = [0; 4];
+                sink.write(label.encode_utf8(&mut buf));
+                sink.write(choice);
+            }
+            if i + 1 < range.end {
+                sink.write(" ");
+            }
+        }
+        if self.page + 1 < self.page_count() {
+            sink.write(" =");
+        }
+        sink.write(") ");
+    }
+}
+
+// Built-in choices joined by "\t", the frequency weight of each choice, and
+// the choices' tone-numbered pinyin, as in autogen_hsk::CIYU (or CIYU_HANT),
+// CIYU_WEIGHT, and CIYU_TONED. Abbreviations (ABBREV_CIYU or ABBREV_CIYU_HANT,
+// and ABBREV_WEIGHT) have no tones. Single characters come from the same
+// kind of arrays in autogen_gb2312.
+type ChoiceList = (&'static str, &'static [u32], Option<&'static str>);
+
+fn choice_list(key_match: KeyMatch, dialect: Dialect) -> ChoiceList {
+    let hant = dialect == Dialect::ZhHant;
+    match key_match {
+        KeyMatch::Ciyu(i) => (
+            if hant {
+                autogen_hsk::CIYU_HANT[i]
+            } else {
+                autogen_hsk::CIYU[i]
+            },
+            autogen_hsk::CIYU_WEIGHT[i],
+            Some(autogen_hsk::CIYU_TONED[i]),
+        ),
+        KeyMatch::Abbrev(i) => (
+            if hant {
+                autogen_hsk::ABBREV_CIYU_HANT[i]
+            } else {
+                autogen_hsk::ABBREV_CIYU[i]
+            },
+            autogen_hsk::ABBREV_WEIGHT[i],
+            None,
+        ),
+        #[cfg(feature = "gb2312")]
+        KeyMatch::Zi(i) => (
+            if hant {
+                autogen_gb2312::ZI_HANT[i]
+            } else {
+                autogen_gb2312::ZI[i]
+            },
+            autogen_gb2312::ZI_WEIGHT[i],
+            Some(autogen_gb2312::ZI_TONED[i]),
+        ),
+    }
+}
+
+/// What a segment of the query turned into.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum SegmentKind<'a> {
+    /// Pinyin matched just one 词语
+    Ciyu(&'a str),
+    /// Pinyin matched a set of homophones, and a space or digit picked the
+    /// candidate at the given index
+    Chosen(Candidates<'a>, usize),
+    /// Pinyin matched a set of homophones, and no choice has been made yet
+    OpenChoice(Candidates<'a>),
+    /// Characters that pass through unchanged (unmatched pinyin, spaces and
+    /// digits not used for choosing, "UPPER CASE", emoji, ...)
+    Text(&'a str),
+}
+
+/// One segment of a query along with the character range of the query that
+/// it came from. Ranges follow start..end semantics (upper bound exclusive).
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Segment<'a> {
+    pub start: usize,
+    pub end: usize,
+    pub kind: SegmentKind<'a>,
+}
+impl<'a> Segment<'a> {
+    /// Is the output text for this segment settled? (false for open choices)
+    pub fn is_resolved(&self) -> bool {
+        !matches!(self.kind, SegmentKind::OpenChoice(_))
+    }
+
+    /// Output text for resolved segments, or None for open choices.
+    pub fn text(&self) -> Option<&'a str> {
+        match self.kind {
+            SegmentKind::Ciyu(ciyu) => Some(ciyu),
+            SegmentKind::Chosen(candidates, i) => candidates.get(i),
+            SegmentKind::OpenChoice(_) => None,
+            SegmentKind::Text(s) => Some(s),
+        }
+    }
+
+    // Output text when the segment gets committed: its text, or the default
+    // choice for open choices.
+    pub(crate) fn commit_text(&self) -> Option<&'a str> {
+        match self.kind {
+            SegmentKind::OpenChoice(candidates) => candidates.get(candidates.default_choice()),
+            _ => self.text(),
+        }
+    }
+
+    /// Candidate list for segments that matched a set of homophones.
+    pub fn candidates(&self) -> Option<Candidates<'a>> {
+        match self.kind {
+            SegmentKind::Chosen(candidates, _) => Some(candidates),
+            SegmentKind::OpenChoice(candidates) => Some(candidates),
+            _ => None,
+        }
+    }
+}
+
+/// Controls how open choices get rendered as text.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum RenderMode {
+    /// Prompt for a choice like " (1想 2向) "
+    Compose,
+    /// Take the default choice, as if space had been pressed
+    Commit,
+}
+
+/// Holds list of Segments (append only, until cleared)
+pub struct SegmentList<'a> {
+    list: [Segment<'a>; SEGMENT_LIST_SIZE],
+    count: usize,
+}
+impl<'a> SegmentList<'a> {
+    /// Return empty list ready for use.
+    pub fn new() -> SegmentList<'a> {
+        let empty = Segment {
+            start: 0,
+            end: 0,
+            kind: SegmentKind::Text(""),
+        };
+        SegmentList {
+            list: [empty; SEGMENT_LIST_SIZE],
+            count: 0,
+        }
+    }
+
+    /// Remove all segments.
+    pub fn clear(&mut self) {
+        self.count = 0;
+    }
+
+    /// Number of segments.
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Is the list empty?
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Segment at index i.
+    pub fn get(&self, i: usize) -> Option<&Segment<'a>> {
+        self.list[..self.count].get(i)
+    }
+
+    /// Iterate over segments in query order.
+    pub fn iter(&self) -> core::slice::Iter<'_, Segment<'a>> {
+        self.list[..self.count].iter()
+    }
+
+    // Add Segment to list.
+    pub(crate) fn push(&mut self, seg: Segment<'a>) -> bool {
+        if self.count < SEGMENT_LIST_SIZE {
+            self.list[self.count] = seg;
+            self.count += 1;
+            true
+        } else {
+            // Error: List is full
+            false
+        }
+    }
+
+    // Most recently added Segment, if any.
+    pub(crate) fn last_mut(&mut self) -> Option<&mut Segment<'a>> {
+        self.list[..self.count].last_mut()
+    }
+
+    /// Render segments as text.
+    /// Side-effect: render strings into buffer provided by Writer.
+    pub fn render_and_write(&self, mode: RenderMode, sink: &mut impl Writer) {
+        for seg in self.iter() {
+            match (seg.kind, mode) {
+                (SegmentKind::OpenChoice(candidates), RenderMode::Compose) => {
+                    candidates.write_prompt(sink)
+                }
+                _ => {
+                    if let Some(s) = seg.commit_text() {
+                        sink.write(s);
+                    }
+                }
+            }
+        }
+    }
+}
+impl<'a> Default for SegmentList<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dialects::{
+        look_up_segments, look_up_segments_with_options, look_up_with_options, LookupOptions,
+    };
+    use crate::dialects::{BufWriter, Writer};
+    use crate::fuzzy::FuzzyRules;
+    use crate::user_dict::UserDict;
+
+    #[test]
+    fn segments_have_source_spans() {
+        let mut segs = SegmentList::new();
+        look_up_segments("wo xiang", &mut segs);
+        assert_eq!(segs.len(), 2);
+        let wo = segs.get(0).unwrap();
+        assert_eq!((wo.start, wo.end), (0, 2));
+        assert_eq!(wo.kind, SegmentKind::Ciyu("我"));
+        let xiang = segs.get(1).unwrap();
+        assert_eq!((xiang.start, xiang.end), (3, 8));
+        assert!(!xiang.is_resolved());
+        assert_eq!(xiang.text(), None);
+    }
+
+    #[test]
+    fn open_choice_lists_candidates_in_order() {
+        let mut segs = SegmentList::new();
+        look_up_segments("xiang", &mut segs);
+        let candidates = segs.get(0).unwrap().candidates().unwrap();
+        assert!(candidates.len() >= 2);
+        assert_eq!(candidates.get(0), Some("向"));
+        assert_eq!(candidates.get(1), Some("想"));
+        assert!(candidates.weight(0) >= candidates.weight(1));
+        let mut it = candidates.iter();
+        assert_eq!(it.next(), Some("向"));
+        assert_eq!(it.next(), Some("想"));
+    }
+
+    #[test]
+    fn digit_resolves_choice() {
+        let mut segs = SegmentList::new();
+        look_up_segments("xianghe 2", &mut segs);
+        assert_eq!(segs.len(), 2);
+        let xiang = segs.get(0).unwrap();
+        let he = segs.get(1).unwrap();
+        assert!(xiang.is_resolved());
+        assert_eq!(xiang.text(), Some("向"));
+        assert!(matches!(he.kind, SegmentKind::Chosen(_, 1)));
+        assert_eq!(he.text(), Some("河"));
+        assert_eq!((he.start, he.end), (5, 7));
+    }
+
+    #[test]
+    fn unmatched_chars_merge_into_text() {
+        let mut segs = SegmentList::new();
+        look_up_segments("baiSEde🐇✨11", &mut segs);
+        let texts = ["白", "SE", "的", "🐇✨"];
+        assert_eq!(segs.len(), texts.len());
+        for (seg, text) in segs.iter().zip(texts.iter()) {
+            assert_eq!(seg.text(), Some(*text));
+        }
+        let se = segs.get(1).unwrap();
+        assert_eq!(se.kind, SegmentKind::Text("SE"));
+        assert_eq!((se.start, se.end), (3, 5));
+        // The 11 resolved the choices for bai and de, so it isn't text
+        let last = segs.get(3).unwrap();
+        assert_eq!((last.start, last.end), (7, 9));
+    }
+
+    #[test]
+    fn pick_only_accepts_labels_in_range() {
+        let candidates = Candidates::new(KeyMatches::find("he", false, FuzzyRules::NONE), &[]);
+        assert_eq!(candidates.pick(' '), Some(0));
+        assert_eq!(candidates.pick('1'), Some(0));
+        assert_eq!(candidates.pick('2'), Some(1));
+        assert_eq!(candidates.pick('3'), Some(2));
+        assert_eq!(candidates.pick('4'), None);
+        assert_eq!(candidates.pick('0'), None);
+        assert_eq!(candidates.pick('x'), None);
+    }
+
+    #[test]
+    fn long_candidate_lists_have_pages() {
+        let mut store = [UserEntry::EMPTY; 16];
+        let mut dict = UserDict::new(&mut store);
+        for ciyu in ["禾", "荷", "盒", "贺", "鹤", "赫", "褐", "壑"].iter() {
+            assert!(dict.add("he", ciyu));
+        }
+        let opts = LookupOptions {
+            dict: Some(&dict),
+            ..Default::default()
+        };
+        let mut segs = SegmentList::new();
+        look_up_segments_with_options("he", &opts, &mut segs);
+        let candidates = segs.get(0).unwrap().candidates().unwrap();
+        assert!(candidates.len() > CANDIDATE_PAGE_SIZE);
+        assert_eq!(candidates.page_count(), 2);
+        assert_eq!((candidates.page(), candidates.page_range()), (0, 0..9));
+        assert_eq!(candidates.label(8), Some('9'));
+        assert_eq!(candidates.label(9), None);
+        let mut sink = BufWriter::new();
+        look_up_with_options("he", &opts, &mut sink);
+        assert!(sink.to_s().starts_with(" (1禾 2荷"));
+        assert!(sink.to_s().ends_with(" 8壑 9和 =) "));
+        // Labels start over on the next page, and stay within it
+        sink.rewind();
+        look_up_with_options("he=", &opts, &mut sink);
+        assert!(sink.to_s().starts_with(" (- 1河"));
+        assert!(!sink.to_s().contains(" ="));
+        segs.clear();
+        look_up_segments_with_options("he=", &opts, &mut segs);
+        let candidates = segs.get(0).unwrap().candidates().unwrap();
+        assert_eq!(candidates.page(), 1);
+        assert_eq!(candidates.label(9), Some('1'));
+        assert_eq!(candidates.get(9), Some("河"));
+        assert_eq!(candidates.label(0), None);
+        sink.rewind();
+        look_up_with_options("he=1", &opts, &mut sink);
+        assert_eq!(sink.to_s(), "河");
+        sink.rewind();
+        look_up_with_options("he= ", &opts, &mut sink);
+        assert_eq!(sink.to_s(), "河");
+        sink.rewind();
+        look_up_with_options("he==-3", &opts, &mut sink);
+        assert_eq!(sink.to_s(), "盒");
+        // Page keys pass through when there's only one page
+        sink.rewind();
+        look_up_with_options("xiang=", &opts, &mut sink);
+        assert!(sink.to_s().starts_with(" (1向"));
+        assert!(sink.to_s().ends_with(") ="));
+    }
+}
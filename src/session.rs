@@ -0,0 +1,853 @@
+// Copyright (c) 2021 Sam Blenny
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+#![forbid(unsafe_code)]
+
+use crate::autogen_hsk;
+use crate::constants;
+use crate::context::Context;
+use crate::dialects::{self, Writer};
+use crate::fuzzy::FuzzyRules;
+use crate::lattice;
+use crate::learn::ChoiceHistory;
+use crate::lex;
+use crate::predict;
+use crate::preedit::{ImeOutput, SegmentedText};
+use crate::segment;
+use crate::shuangpin::ShuangpinLayout;
+use crate::user_dict::UserDict;
+use crate::zhuyin;
+
+/// Key events that an ImeSession knows how to handle.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Key {
+    /// Append a character to the end of the composition
+    Char(char),
+    /// Remove the last character of the composition
+    Backspace,
+    /// Finish the composition, taking default choices where needed
+    Commit,
+    /// Throw away the composition
+    Cancel,
+}
+
+/// What ImeSession::key() did with a key event, and what it wrote to the sink.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum KeyResult {
+    /// Composition changed; sink holds the rendered composition
+    Composing,
+    /// Composition was committed; sink holds the committed text
+    Committed,
+    /// Composition was thrown away; nothing was written
+    Cancelled,
+    /// Key had no effect (buffer full, or backspace with nothing to delete)
+    Ignored,
+}
+
+/// ImeSession holds the raw input buffer and its segmentation between key
+/// events, so front-ends don't have to manage the pinyin buffer themselves.
+///
+/// Edits only happen at the end of the buffer. The segmentation lattice keeps
+/// the best path to every position of the buffer, so typing a character only
+/// has to check for matches in the last PINYIN_SIZE_MAX characters, and
+/// backspace doesn't have to search at all.
+///
+/// A session can own a UserDict, whose entries it consults alongside the
+/// built-in tables, and a ChoiceHistory, where it remembers which candidates
+/// got picked in committed text so it can offer them first next time.
+///
+/// The session also keeps the end of the text it last committed, as context
+/// for ranking the candidates of the next composition (with the "bigram"
+/// feature).
+///
+/// Hosts that show committed text, preedit, and candidates apart can get
+/// them from output() after each key event, rather than from the sink.
+pub struct ImeSession<'s, C = lattice::DefaultCost> {
+    input: [u8; constants::BUF_SIZE],
+    input_len: usize,
+    // Byte index in input where each character starts
+    char_start_list: [usize; constants::BUF_SIZE],
+    char_count: usize,
+    lattice: lattice::Lattice,
+    queue: lex::TokenQueue,
+    cost: C,
+    user: UserDict<'s>,
+    history: ChoiceHistory<'s>,
+    options: lattice::KeyOptions,
+    dialect: dialects::Dialect,
+    context: Context,
+    // Text that the last key event committed, with its segments
+    committed: SegmentedText,
+}
+impl ImeSession<'static> {
+    /// Return an empty session ready for use.
+    pub fn new() -> ImeSession<'static> {
+        ImeSession::with_cost(lattice::DefaultCost)
+    }
+}
+impl<'s> ImeSession<'s> {
+    /// Return an empty session that also looks up words in user.
+    pub fn with_user_dict(user: UserDict<'s>) -> ImeSession<'s> {
+        let history = ChoiceHistory::new(&mut []);
+        ImeSession::with_storage(lattice::DefaultCost, user, history)
+    }
+
+    /// Return an empty session that learns from choices in committed text.
+    pub fn with_history(history: ChoiceHistory<'s>) -> ImeSession<'s> {
+        let user = UserDict::new(&mut []);
+        ImeSession::with_storage(lattice::DefaultCost, user, history)
+    }
+}
+impl<C: lattice::SegmentCost> ImeSession<'static, C> {
+    /// Return an empty session that uses cost to pick the best segmentation.
+    pub fn with_cost(cost: C) -> ImeSession<'static, C> {
+        let history = ChoiceHistory::new(&mut []);
+        ImeSession::with_storage(cost, UserDict::new(&mut []), history)
+    }
+}
+impl<'s, C: lattice::SegmentCost> ImeSession<'s, C> {
+    /// Return an empty session that uses cost to pick the best segmentation,
+    /// also looks up words in user, and learns choices in history.
+    pub fn with_storage(
+        cost: C,
+        user: UserDict<'s>,
+        history: ChoiceHistory<'s>,
+    ) -> ImeSession<'s, C> {
+        ImeSession {
+            input: [0; constants::BUF_SIZE],
+            input_len: 0,
+            char_start_list: [0; constants::BUF_SIZE],
+            char_count: 0,
+            lattice: lattice::Lattice::new(),
+            queue: lex::TokenQueue::new(),
+            cost,
+            user,
+            history,
+            options: lattice::KeyOptions::default(),
+            dialect: dialects::Dialect::default(),
+            context: Context::new(),
+            committed: SegmentedText::new(),
+        }
+    }
+
+    /// Handle one key event.
+    /// Side-effect: Write the composition or the committed text to sink.
+    pub fn key(&mut self, key: Key, sink: &mut impl Writer) -> KeyResult {
+        self.committed.clear();
+        match key {
+            Key::Char(c) => {
+                let prev = self.input().chars().next_back();
+                let c = match zhuyin::dachen_key(c, prev) {
+                    Some(z) if self.options.zhuyin => z,
+                    _ => c,
+                };
+                let n = c.len_utf8();
+                if self.input_len + n > self.input.len() || self.char_count >= constants::BUF_SIZE {
+                    return KeyResult::Ignored;
+                }
+                c.encode_utf8(&mut self.input[self.input_len..self.input_len + n]);
+                self.char_start_list[self.char_count] = self.input_len;
+                self.input_len += n;
+                self.char_count += 1;
+                self.extend(sink);
+                self.render(sink);
+                KeyResult::Composing
+            }
+            Key::Backspace => {
+                if self.char_count == 0 {
+                    return KeyResult::Ignored;
+                }
+                self.char_count -= 1;
+                self.input_len = self.char_start_list[self.char_count];
+                self.lattice.truncate(self.char_count);
+                self.update_queue();
+                self.render(sink);
+                KeyResult::Composing
+            }
+            Key::Commit => {
+                let committed = self.committed_text();
+                sink.write(committed.as_str());
+                self.learn();
+                // Context changes the candidates, so only update it once the
+                // composition's choices have been learned
+                self.context.write(committed.as_str());
+                self.clear();
+                self.committed = committed;
+                KeyResult::Committed
+            }
+            Key::Cancel => {
+                self.clear();
+                KeyResult::Cancelled
+            }
+        }
+    }
+
+    /// Render the current composition, including prompts for open choices.
+    /// Side-effect: render strings into buffer provided by Writer.
+    pub fn render(&self, sink: &mut impl Writer) {
+        self.render_mode(segment::RenderMode::Compose, sink);
+    }
+
+    /// Return the current composition as typed data rather than rendered text.
+    /// Side-effect: fills out with one Segment per 词语 match or run of
+    /// unmatched characters, in input order.
+    pub fn segments<'a>(&'a self, out: &mut segment::SegmentList<'a>) {
+        let query = dialects::Utf8Str::new(self.input());
+        out.clear();
+        self.queue.resolve(
+            &query,
+            self.user.entries(),
+            self.ranking(),
+            self.options,
+            self.dialect,
+            out,
+        );
+    }
+
+    /// Return what the last key event produced, split into the text it
+    /// committed, the preedit text of the composition with open choices shown
+    /// as pinyin syllables (like xiang'he), the cursor position, and the
+    /// candidates that space or a digit picks from next.
+    /// Side-effect: fills out (see ImeOutput).
+    pub fn output<'a>(&'a self, out: &mut ImeOutput<'a>) {
+        let mut segments = segment::SegmentList::new();
+        self.segments(&mut segments);
+        out.set_committed(&self.committed);
+        out.set_preedit(self.input(), &segments);
+    }
+
+    /// Return built-in 词语 that the pinyin at the end of the composition is
+    /// on its way to, like 果汁 while "guoz" is being typed. With the
+    /// "bigram" feature and nothing typed yet, return the 词语 likely to come
+    /// after the context instead (see predict_next).
+    /// Side-effect: fills out with the predictions, most common first (none
+    /// with Zhuyin or Shuangpin input on, since the letters aren't pinyin).
+    pub fn predictions(&self, out: &mut predict::PredictionList) {
+        out.clear();
+        if self.options.zhuyin || self.options.shuangpin.is_some() {
+            return;
+        }
+        #[cfg(feature = "bigram")]
+        {
+            if self.char_count == 0 {
+                predict::predict_next(self.context.as_str(), out);
+                return;
+            }
+        }
+        predict::predict_completions(self.input(), self.dialect, out);
+    }
+
+    // What came before the composition, for ranking its candidates.
+    fn ranking(&self) -> lex::Ranking<'_> {
+        lex::Ranking {
+            history: self.history.entries(),
+            context: self.context.as_str(),
+        }
+    }
+
+    // Text that committing the current composition gives, with its segments.
+    fn committed_text(&self) -> SegmentedText {
+        let mut segments = segment::SegmentList::new();
+        self.segments(&mut segments);
+        let mut committed = SegmentedText::new();
+        committed.set_committed(&segments);
+        committed
+    }
+
+    // Render the current composition with open choices handled as per mode.
+    fn render_mode(&self, mode: segment::RenderMode, sink: &mut impl Writer) {
+        let mut segments = segment::SegmentList::new();
+        self.segments(&mut segments);
+        segments.render_and_write(mode, sink);
+    }
+
+    /// Return the raw input buffer that the composition was made from.
+    pub fn input(&self) -> &str {
+        // Bad utf8 can't happen since input only gets whole chars.
+        core::str::from_utf8(&self.input[..self.input_len]).unwrap_or_default()
+    }
+
+    /// Return the user dictionary.
+    pub fn user_dict(&self) -> &UserDict<'s> {
+        &self.user
+    }
+
+    /// Add an entry to the user dictionary (see UserDict::add), and update
+    /// the segmentation of the current composition to match.
+    /// Return: false if the entry could not be added.
+    pub fn add_user_word(&mut self, pinyin: &str, ciyu: &str) -> bool {
+        let ok = self.user.add(pinyin, ciyu);
+        self.rebuild();
+        ok
+    }
+
+    /// Remove an entry from the user dictionary, and update the segmentation
+    /// of the current composition to match.
+    /// Return: false if there was no such entry.
+    pub fn remove_user_word(&mut self, pinyin: &str, ciyu: &str) -> bool {
+        let ok = self.user.remove(pinyin, ciyu);
+        self.rebuild();
+        ok
+    }
+
+    /// Return the fuzzy pinyin rules that lookups use.
+    pub fn fuzzy(&self) -> FuzzyRules {
+        self.options.fuzzy
+    }
+
+    /// Set the fuzzy pinyin rules that lookups use (the default is none), and
+    /// update the segmentation of the current composition to match.
+    pub fn set_fuzzy(&mut self, rules: FuzzyRules) {
+        self.options.fuzzy = rules;
+        self.rebuild();
+    }
+
+    /// Is tone input on?
+    pub fn tone_input(&self) -> bool {
+        self.options.tones
+    }
+
+    /// Turn tone input on or off (the default is off), and update the
+    /// segmentation of the current composition to match. With tone input on,
+    /// digits 1-5 after pinyin are tones (5 for neutral tone) that narrow down
+    /// the candidates, and the shifted digits "!@#$%^&*(" pick choices 1-9.
+    pub fn set_tone_input(&mut self, on: bool) {
+        self.options.tones = on;
+        self.rebuild();
+    }
+
+    /// Is Zhuyin keyboard input on?
+    pub fn zhuyin_input(&self) -> bool {
+        self.options.zhuyin
+    }
+
+    /// Turn Zhuyin keyboard input on or off (the default is off). With it on,
+    /// Char keys are typed on the Dachen layout and turn into zhuyin as they
+    /// get added, so '5' adds ㄓ and '6' adds the second tone key ˊ. Tone
+    /// keys narrow down the candidates, and the shifted digits "!@#$%^&*("
+    /// pick choices 1-9. Zhuyin characters work either way.
+    pub fn set_zhuyin_input(&mut self, on: bool) {
+        self.options.zhuyin = on;
+        self.rebuild();
+    }
+
+    /// Return the Shuangpin layout, if Shuangpin input is on.
+    pub fn shuangpin(&self) -> Option<ShuangpinLayout> {
+        self.options.shuangpin
+    }
+
+    /// Turn Shuangpin input on with a layout, or off with None (the default),
+    /// and update the segmentation of the current composition to match. With
+    /// it on, letters pair up into syllables from the start of each run of
+    /// them, so "vsgo" is 中国 on the built-in layouts, and a key without a
+    /// pair yet passes through.
+    pub fn set_shuangpin(&mut self, layout: Option<ShuangpinLayout>) {
+        self.options.shuangpin = layout;
+        self.rebuild();
+    }
+
+    /// Return the written form that the composition is spelled in.
+    pub fn dialect(&self) -> dialects::Dialect {
+        self.dialect
+    }
+
+    /// Set the written form that the composition is spelled in (the default
+    /// is Simplified). Pinyin keys stay the same, so the segmentation does too.
+    pub fn set_dialect(&mut self, dialect: dialects::Dialect) {
+        self.dialect = dialect;
+    }
+
+    /// Return the end of the text committed before the composition.
+    pub fn context(&self) -> &str {
+        self.context.as_str()
+    }
+
+    /// Set the text before the composition (only the last few characters
+    /// matter), as when the cursor moves in the editor, or "" for none. Each
+    /// commit adds the committed text to it. With the "bigram" feature,
+    /// candidate lists put the 词语 that most often follows it first, so "he"
+    /// gives 喝 after 想.
+    pub fn set_context(&mut self, text: &str) {
+        self.context.set(text);
+    }
+
+    /// Return the history of learned choices.
+    pub fn history(&self) -> &ChoiceHistory<'s> {
+        &self.history
+    }
+
+    /// Return the history of learned choices, for loading or clearing it.
+    pub fn history_mut(&mut self) -> &mut ChoiceHistory<'s> {
+        &mut self.history
+    }
+
+    /// Throw away the input buffer and its segmentation.
+    pub fn clear(&mut self) {
+        self.input_len = 0;
+        self.char_count = 0;
+        self.lattice.truncate(0);
+        self.queue.truncate(0);
+    }
+
+    // Remember the candidate that was picked for each choice in the
+    // composition. Open choices don't count, since nobody picked them.
+    fn learn(&mut self) {
+        // Bad utf8 can't happen since input only gets whole chars.
+        let input = core::str::from_utf8(&self.input[..self.input_len]).unwrap_or_default();
+        let query = dialects::Utf8Str::new(input);
+        let mut segments = segment::SegmentList::new();
+        self.queue.resolve(
+            &query,
+            self.user.entries(),
+            self.ranking(),
+            self.options,
+            self.dialect,
+            &mut segments,
+        );
+        for seg in segments.iter() {
+            if let segment::SegmentKind::Chosen(candidates, i) = seg.kind {
+                if let (Some(key), Some(ciyu)) =
+                    (query.char_slice(seg.start, seg.end), candidates.get(i))
+                {
+                    // Learn choices for the pinyin, whatever tones were typed
+                    let mut plain = [0; autogen_hsk::PINYIN_SIZE_MAX];
+                    let key = self
+                        .options
+                        .search_key(key, &mut plain)
+                        .map_or(key, |(p, _)| p);
+                    self.history.record(key, ciyu);
+                }
+            }
+        }
+    }
+
+    // Extend the lattice to cover the character that was just added.
+    fn extend(&mut self, sink: &mut impl Writer) {
+        self.extend_to(self.char_count, sink);
+        self.update_queue();
+    }
+
+    // Redo the segmentation of the whole input (after a dictionary or option
+    // change).
+    fn rebuild(&mut self) {
+        let mut sink = dialects::BufWriter::new();
+        self.lattice.truncate(0);
+        for count in 1..=self.char_count {
+            self.extend_to(count, &mut sink);
+        }
+        self.update_queue();
+    }
+
+    // Extend the lattice to cover the first count characters of input. Only
+    // the last PINYIN_SIZE_MAX characters (plus tone digits, with tone input)
+    // can be part of a match that ends with the last character, so that's all
+    // the lattice needs to see.
+    fn extend_to(&mut self, count: usize, sink: &mut impl Writer) {
+        let window_end = if count < self.char_count {
+            self.char_start_list[count]
+        } else {
+            self.input_len
+        };
+        // Bad utf8 can't happen since input only gets whole chars.
+        let text = core::str::from_utf8(&self.input[..window_end]).unwrap_or_default();
+        let offset = count - self.options.key_window(text);
+        let window = text.get(self.char_start_list[offset]..).unwrap_or("");
+        let query = dialects::Utf8Str::new(window);
+        let user = self.user.entries();
+        let options = self.options;
+        self.lattice
+            .extend(&query, offset, &self.cost, user, options, sink);
+    }
+
+    // Rebuild token queue from best path through the lattice.
+    fn update_queue(&mut self) {
+        self.queue.truncate(0);
+        self.lattice.tokens(&mut self.queue);
+    }
+}
+impl Default for ImeSession<'static> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dialects::{look_up_segments, query, BufWriter, Dialect};
+    use crate::learn::ChoiceHistory;
+    use crate::predict::PredictionList;
+    use crate::segment::SegmentList;
+    use crate::user_dict::UserEntry;
+
+    // Type each character of s into session, discarding the output.
+    fn type_str<C: lattice::SegmentCost>(session: &mut ImeSession<C>, s: &str) {
+        let mut sink = BufWriter::new();
+        for c in s.chars() {
+            sink.rewind();
+            session.key(Key::Char(c), &mut sink);
+        }
+    }
+
+    #[test]
+    fn each_keystroke_matches_query_of_whole_buffer() {
+        let text = "woxiangheguozhi11 kunchong kun chong xiang he1 baiSEde🐇✨11";
+        let mut session = ImeSession::new();
+        let mut sink = BufWriter::new();
+        for (i, c) in text.char_indices() {
+            sink.rewind();
+            assert_eq!(session.key(Key::Char(c), &mut sink), KeyResult::Composing);
+            let prefix = &text[..i + c.len_utf8()];
+            assert_eq!(session.input(), prefix);
+            assert_eq!(sink.to_s(), query(prefix, &mut BufWriter::new()));
+        }
+    }
+
+    #[test]
+    fn backspace_matches_query_of_whole_buffer() {
+        let text = "kunchongwoxiangheguozhi";
+        let mut session = ImeSession::new();
+        type_str(&mut session, text);
+        let mut sink = BufWriter::new();
+        for i in (0..text.len()).rev() {
+            sink.rewind();
+            assert_eq!(session.key(Key::Backspace, &mut sink), KeyResult::Composing);
+            assert_eq!(session.input(), &text[..i]);
+            assert_eq!(sink.to_s(), query(&text[..i], &mut BufWriter::new()));
+        }
+        assert_eq!(session.key(Key::Backspace, &mut sink), KeyResult::Ignored);
+    }
+
+    #[test]
+    fn long_input_matches_query_of_whole_buffer() {
+        // Longer than PINYIN_SIZE_MAX, so the lattice only sees part of it
+        let text = "woxiangheguozhi woxiangheguozhi woxiangheguozhi kunchong";
+        let mut session = ImeSession::new();
+        type_str(&mut session, text);
+        let mut sink = BufWriter::new();
+        session.render(&mut sink);
+        assert_eq!(sink.to_s(), query(text, &mut BufWriter::new()));
+    }
+
+    #[test]
+    fn commit_takes_default_choices_and_clears() {
+        let mut session = ImeSession::new();
+        type_str(&mut session, "woxiangheguozhi");
+        let mut sink = BufWriter::new();
+        assert_eq!(session.key(Key::Commit, &mut sink), KeyResult::Committed);
+        #[cfg(feature = "bigram")]
+        assert_eq!(sink.to_s(), "我想喝果汁");
+        #[cfg(not(feature = "bigram"))]
+        assert_eq!(sink.to_s(), "我向和果汁");
+        assert_eq!(session.input(), "");
+        // The 2 resolves xiang, so he gets its default choice
+        type_str(&mut session, "xianghe2");
+        sink.rewind();
+        assert_eq!(session.key(Key::Commit, &mut sink), KeyResult::Committed);
+        #[cfg(feature = "bigram")]
+        assert_eq!(sink.to_s(), "想喝");
+        #[cfg(not(feature = "bigram"))]
+        assert_eq!(sink.to_s(), "想和");
+    }
+
+    #[test]
+    fn cancel_clears_without_writing() {
+        let mut session = ImeSession::new();
+        type_str(&mut session, "xiang");
+        let mut sink = BufWriter::new();
+        assert_eq!(session.key(Key::Cancel, &mut sink), KeyResult::Cancelled);
+        assert_eq!(sink.to_s(), "");
+        assert_eq!(session.input(), "");
+        assert_eq!(session.key(Key::Char('A'), &mut sink), KeyResult::Composing);
+        assert_eq!(sink.to_s(), "A");
+    }
+
+    #[test]
+    fn segments_match_look_up_segments() {
+        let text = "woxiang he2 guozhi";
+        let mut session = ImeSession::new();
+        type_str(&mut session, text);
+        let mut from_session = SegmentList::new();
+        session.segments(&mut from_session);
+        let mut from_look_up = SegmentList::new();
+        look_up_segments(text, &mut from_look_up);
+        assert_eq!(from_session.len(), from_look_up.len());
+        for (a, b) in from_session.iter().zip(from_look_up.iter()) {
+            assert_eq!(a, b);
+        }
+    }
+
+    #[test]
+    fn user_dict_changes_update_composition() {
+        let mut store = [UserEntry::EMPTY; 4];
+        let mut session = ImeSession::with_user_dict(UserDict::new(&mut store));
+        type_str(&mut session, "wo zhangwei");
+        let mut sink = BufWriter::new();
+        session.render(&mut sink);
+        assert!(!sink.to_s().contains("张伟"));
+        assert!(session.add_user_word("zhangwei", "张伟"));
+        sink.rewind();
+        session.render(&mut sink);
+        assert_eq!(sink.to_s(), "我张伟");
+        assert_eq!(session.user_dict().len(), 1);
+        assert!(session.remove_user_word("zhangwei", "张伟"));
+        assert!(session.user_dict().is_empty());
+        let mut after = BufWriter::new();
+        session.render(&mut after);
+        assert_eq!(after.to_s(), query("wo zhangwei", &mut BufWriter::new()));
+    }
+
+    #[test]
+    fn committed_choices_get_promoted() {
+        let mut store = [UserEntry::EMPTY; 4];
+        let mut session = ImeSession::with_history(ChoiceHistory::new(&mut store));
+        let mut sink = BufWriter::new();
+        type_str(&mut session, "xiang3");
+        session.key(Key::Commit, &mut sink);
+        assert_eq!(session.history().get("xiang"), Some("像"));
+        // Now 像 comes first, so space picks it
+        type_str(&mut session, "xiang");
+        sink.rewind();
+        session.render(&mut sink);
+        assert!(sink.to_s().starts_with(" (1像 2向 3想 4项"));
+        type_str(&mut session, " ");
+        sink.rewind();
+        session.key(Key::Commit, &mut sink);
+        assert_eq!(sink.to_s(), "像");
+        // Picking something else replaces the learned choice
+        type_str(&mut session, "xiang2");
+        sink.rewind();
+        session.key(Key::Commit, &mut sink);
+        assert_eq!(sink.to_s(), "向");
+        assert_eq!(session.history().get("xiang"), Some("向"));
+        // Open choices that get the default on commit aren't learned
+        type_str(&mut session, "he");
+        session.key(Key::Commit, &mut sink);
+        assert_eq!(session.history().get("he"), None);
+        session.history_mut().clear();
+        assert!(session.history().is_empty());
+    }
+
+    #[test]
+    fn committed_text_is_context_for_the_next_composition() {
+        let mut session = ImeSession::new();
+        let mut sink = BufWriter::new();
+        type_str(&mut session, "woxiang");
+        session.key(Key::Commit, &mut sink);
+        #[cfg(feature = "bigram")]
+        assert_eq!(session.context(), "我想");
+        #[cfg(not(feature = "bigram"))]
+        assert_eq!(session.context(), "我向");
+        type_str(&mut session, "he");
+        {
+            let mut segments = SegmentList::new();
+            session.segments(&mut segments);
+            let candidates = segments.get(0).and_then(|s| s.candidates()).unwrap();
+            #[cfg(feature = "bigram")]
+            assert_eq!(candidates.get(0), Some("喝"));
+            #[cfg(not(feature = "bigram"))]
+            assert_eq!(candidates.get(0), Some("和"));
+            assert_eq!(candidates.is_contextual(0), cfg!(feature = "bigram"));
+            assert!(!candidates.is_learned(0));
+        }
+        // Setting the context changes the ranking
+        session.set_context("");
+        sink.rewind();
+        session.render(&mut sink);
+        assert!(sink.to_s().starts_with(" (1和 2河 3喝"));
+        // With nothing typed, predictions are for the word after the context
+        session.key(Key::Cancel, &mut sink);
+        session.set_context("我想");
+        let mut out = PredictionList::new();
+        session.predictions(&mut out);
+        #[cfg(feature = "bigram")]
+        assert_eq!(out.get(0).map(|p| p.ciyu), Some("喝"));
+        #[cfg(not(feature = "bigram"))]
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    fn output_splits_committed_text_from_preedit() {
+        let mut session = ImeSession::new();
+        let mut sink = BufWriter::new();
+        type_str(&mut session, "woxianghe");
+        {
+            let mut out = ImeOutput::new();
+            session.output(&mut out);
+            assert_eq!((out.committed(), out.preedit()), ("", "我xiang'he"));
+            assert_eq!(out.cursor(), 9);
+            assert_eq!(out.preedit_segments().filter(|s| !s.resolved).count(), 2);
+            let candidates = out.candidates().unwrap();
+            assert_eq!(candidates.get(2), Some("像"));
+        }
+        type_str(&mut session, "3");
+        session.key(Key::Commit, &mut sink);
+        {
+            let mut out = ImeOutput::new();
+            session.output(&mut out);
+            assert_eq!((out.committed(), out.preedit()), ("我像和", ""));
+            assert_eq!(out.cursor(), 0);
+            // Each 词语 of the committed text maps back to its pinyin
+            let xiang = out.committed_segments().nth(1).unwrap();
+            assert_eq!(xiang.text_in(out.committed()), "像");
+            assert_eq!((xiang.start, xiang.end), (2, 7));
+            assert!(out.candidates().is_none());
+        }
+        // Committed text is only there right after the commit
+        type_str(&mut session, "A");
+        let mut out = ImeOutput::new();
+        session.output(&mut out);
+        assert_eq!((out.committed(), out.preedit()), ("", "A"));
+    }
+
+    #[test]
+    fn fuzzy_rule_changes_update_composition() {
+        let mut session = ImeSession::new();
+        type_str(&mut session, "liulai ");
+        let mut sink = BufWriter::new();
+        session.render(&mut sink);
+        assert!(!sink.to_s().contains("牛奶"));
+        let mut rules = FuzzyRules::NONE;
+        rules.set(crate::fuzzy::FuzzyRule::NL, true);
+        session.set_fuzzy(rules);
+        assert_eq!(session.fuzzy(), rules);
+        sink.rewind();
+        session.render(&mut sink);
+        assert_eq!(sink.to_s(), "牛奶");
+        type_str(&mut session, "zi");
+        sink.rewind();
+        session.render(&mut sink);
+        assert!(!sink.to_s().contains("只"));
+    }
+
+    #[test]
+    fn tone_input_takes_digits_for_tones() {
+        let mut store = [UserEntry::EMPTY; 4];
+        let mut session = ImeSession::with_history(ChoiceHistory::new(&mut store));
+        type_str(&mut session, "xiang3");
+        let mut sink = BufWriter::new();
+        session.render(&mut sink);
+        assert_eq!(sink.to_s(), query("xiang3", &mut BufWriter::new()));
+        session.set_tone_input(true);
+        assert!(session.tone_input());
+        sink.rewind();
+        session.render(&mut sink);
+        assert!(sink.to_s().starts_with(" (1想 2响"));
+        assert!(!sink.to_s().contains("像"));
+        // Shifted digits pick choices, and the choice is learned for the
+        // pinyin without tones
+        type_str(&mut session, "@");
+        sink.rewind();
+        session.key(Key::Commit, &mut sink);
+        assert_eq!(sink.to_s(), "响");
+        assert_eq!(session.history().get("xiang"), Some("响"));
+        // Keys can be longer than PINYIN_SIZE_MAX by their tone digits
+        type_str(&mut session, "mai4huo3chai2de5xiao3nv3hai2r");
+        sink.rewind();
+        session.key(Key::Commit, &mut sink);
+        assert_eq!(sink.to_s(), "《卖火柴的小女孩儿》");
+    }
+
+    #[test]
+    fn zhuyin_input_maps_dachen_keys() {
+        let mut store = [UserEntry::EMPTY; 4];
+        let mut session = ImeSession::with_history(ChoiceHistory::new(&mut store));
+        session.set_zhuyin_input(true);
+        assert!(session.zhuyin_input());
+        type_str(&mut session, "5j/ eji6");
+        assert_eq!(session.input(), "ㄓㄨㄥˉㄍㄨㄛˊ");
+        let mut sink = BufWriter::new();
+        session.render(&mut sink);
+        assert_eq!(sink.to_s(), "中国");
+        // Choices get learned for the pinyin
+        sink.rewind();
+        session.key(Key::Commit, &mut sink);
+        type_str(&mut session, "ck#");
+        sink.rewind();
+        session.key(Key::Commit, &mut sink);
+        assert_eq!(sink.to_s(), "喝");
+        assert_eq!(session.history().get("he"), Some("喝"));
+        // Backspace takes back one zhuyin symbol
+        type_str(&mut session, "ck6");
+        sink.rewind();
+        session.key(Key::Backspace, &mut sink);
+        assert_eq!(session.input(), "ㄏㄜ");
+    }
+
+    #[test]
+    fn shuangpin_input_pairs_keys() {
+        let mut store = [UserEntry::EMPTY; 4];
+        let mut session = ImeSession::with_history(ChoiceHistory::new(&mut store));
+        session.set_shuangpin(Some(ShuangpinLayout::XIAOHE));
+        assert_eq!(session.shuangpin(), Some(ShuangpinLayout::XIAOHE));
+        let mut sink = BufWriter::new();
+        // A key without its pair yet passes through
+        type_str(&mut session, "vsg");
+        session.render(&mut sink);
+        assert_eq!(sink.to_s(), " (1种 2重 3钟) g");
+        type_str(&mut session, "o");
+        sink.rewind();
+        session.render(&mut sink);
+        assert_eq!(sink.to_s(), "中国");
+        // Choices get learned for the pinyin
+        sink.rewind();
+        session.key(Key::Commit, &mut sink);
+        type_str(&mut session, "he3");
+        sink.rewind();
+        session.key(Key::Commit, &mut sink);
+        assert_eq!(sink.to_s(), "喝");
+        assert_eq!(session.history().get("he"), Some("喝"));
+        // Turning it off reads the same keys as pinyin
+        type_str(&mut session, "vsgo");
+        session.set_shuangpin(None);
+        sink.rewind();
+        session.render(&mut sink);
+        assert!(sink.to_s().starts_with("v (1水果 2手工) "));
+    }
+
+    #[test]
+    fn dialect_changes_spelling_of_composition() {
+        let mut session = ImeSession::new();
+        type_str(&mut session, "toufa");
+        let mut sink = BufWriter::new();
+        session.render(&mut sink);
+        assert_eq!(sink.to_s(), "头发");
+        session.set_dialect(Dialect::ZhHant);
+        assert_eq!(session.dialect(), Dialect::ZhHant);
+        sink.rewind();
+        session.render(&mut sink);
+        assert_eq!(sink.to_s(), "頭髮");
+        type_str(&mut session, "xianghe");
+        sink.rewind();
+        session.key(Key::Commit, &mut sink);
+        assert_eq!(sink.to_s(), "頭髮向和");
+    }
+
+    #[test]
+    fn partly_typed_pinyin_gets_predictions() {
+        let mut session = ImeSession::new();
+        let mut out = PredictionList::new();
+        type_str(&mut session, "woxiangheguoz");
+        session.predictions(&mut out);
+        let p = out.iter().find(|p| p.ciyu == "果汁").unwrap();
+        assert_eq!((p.start, p.end, p.pinyin), (9, 13, "guozhi"));
+        type_str(&mut session, "hi ");
+        session.predictions(&mut out);
+        assert!(out.is_empty());
+        session.set_shuangpin(Some(ShuangpinLayout::XIAOHE));
+        type_str(&mut session, "guoz");
+        session.predictions(&mut out);
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    fn full_buffer_ignores_keys() {
+        let mut session = ImeSession::new();
+        let mut sink = BufWriter::new();
+        for _ in 0..constants::BUF_SIZE {
+            sink.rewind();
+            assert_eq!(session.key(Key::Char('A'), &mut sink), KeyResult::Composing);
+        }
+        assert_eq!(session.key(Key::Char('A'), &mut sink), KeyResult::Ignored);
+    }
+}
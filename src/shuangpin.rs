@@ -0,0 +1,353 @@
+// Copyright (c) 2021 Sam Blenny
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+#![forbid(unsafe_code)]
+
+use crate::autogen_hsk;
+
+// Shuangpin (double pinyin) input. Every syllable takes exactly two keys: the
+// first for its initial and the second for its final, so "vsgo" is zhong guo
+// on the usual layouts. Keys pair up from the start of each run of layout
+// keys, which makes the segmentation into syllables unambiguous ("xdhe" is
+// xiang he on the Microsoft layout, and never xian ghe). Pairs turn into the
+// same pinyin as everything else before the dictionary search.
+//
+// Some keys stand for two finals, like d for iang or uang. Only one of them
+// makes a syllable with a given initial (jiang but guang), so the initial
+// picks which one a pair means.
+
+/// Keyboard layout for Shuangpin (double pinyin) input. Built-in layouts are
+/// `MICROSOFT`, `ZIRANMA`, and `XIAOHE`. For another layout, fill in the
+/// tables for its keys:
+///
+/// ```rust
+/// use hanzi_ime::ShuangpinLayout;
+/// const MY_LAYOUT: ShuangpinLayout = ShuangpinLayout {
+///     finals: &[('d', "ai"), ('l', "iang|uang") /* ... */],
+///     ..ShuangpinLayout::XIAOHE
+/// };
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ShuangpinLayout {
+    /// First key for the initial zh. Initials spelled with one letter are
+    /// typed as that letter.
+    pub zh: char,
+    /// First key for the initial ch
+    pub ch: char,
+    /// First key for the initial sh
+    pub sh: char,
+    /// Second keys and the finals they stand for. A key that stands for more
+    /// than one final lists them with "|" between, like "iang|uang", and the
+    /// initial picks the one that makes a syllable. Spell ü as v.
+    pub finals: &'static [(char, &'static str)],
+    /// Key pairs for syllables without an initial, like ("oa", "a")
+    pub zero: &'static [(&'static str, &'static str)],
+}
+impl ShuangpinLayout {
+    /// Microsoft Pinyin layout. Syllables without an initial start with o,
+    /// like "oa" for a and "ol" for ai, and ; is ing.
+    pub const MICROSOFT: ShuangpinLayout = ShuangpinLayout {
+        zh: 'v',
+        ch: 'i',
+        sh: 'u',
+        finals: &[
+            ('q', "iu"),
+            ('w', "ia|ua"),
+            ('e', "e"),
+            ('r', "uan"),
+            ('t', "ve|ue"),
+            ('y', "v|uai"),
+            ('u', "u"),
+            ('i', "i"),
+            ('o', "o|uo"),
+            ('p', "un"),
+            ('a', "a"),
+            ('s', "iong|ong"),
+            ('d', "iang|uang"),
+            ('f', "en"),
+            ('g', "eng"),
+            ('h', "ang"),
+            ('j', "an"),
+            ('k', "ao"),
+            ('l', "ai"),
+            (';', "ing"),
+            ('z', "ei"),
+            ('x', "ie"),
+            ('c', "iao"),
+            ('v', "ui"),
+            ('b', "ou"),
+            ('n', "in"),
+            ('m', "ian"),
+        ],
+        zero: &[
+            ("oa", "a"),
+            ("oo", "o"),
+            ("oe", "e"),
+            ("ol", "ai"),
+            ("oz", "ei"),
+            ("ok", "ao"),
+            ("ob", "ou"),
+            ("oj", "an"),
+            ("of", "en"),
+            ("oh", "ang"),
+            ("og", "eng"),
+            ("or", "er"),
+        ],
+    };
+
+    /// Ziranma layout
+    pub const ZIRANMA: ShuangpinLayout = ShuangpinLayout {
+        zh: 'v',
+        ch: 'i',
+        sh: 'u',
+        finals: &[
+            ('q', "iu"),
+            ('w', "ia|ua"),
+            ('e', "e"),
+            ('r', "uan"),
+            ('t', "ve|ue"),
+            ('y', "ing|uai"),
+            ('u', "u"),
+            ('i', "i"),
+            ('o', "o|uo"),
+            ('p', "un"),
+            ('a', "a"),
+            ('s', "iong|ong"),
+            ('d', "iang|uang"),
+            ('f', "en"),
+            ('g', "eng"),
+            ('h', "ang"),
+            ('j', "an"),
+            ('k', "ao"),
+            ('l', "ai"),
+            ('z', "ei"),
+            ('x', "ie"),
+            ('c', "iao"),
+            ('v', "v|ui"),
+            ('b', "ou"),
+            ('n', "in"),
+            ('m', "ian"),
+        ],
+        zero: &ZERO_SPELLED_OUT,
+    };
+
+    /// Xiaohe (Flypy) layout
+    pub const XIAOHE: ShuangpinLayout = ShuangpinLayout {
+        zh: 'v',
+        ch: 'i',
+        sh: 'u',
+        finals: &[
+            ('q', "iu"),
+            ('w', "ei"),
+            ('e', "e"),
+            ('r', "uan"),
+            ('t', "ve|ue"),
+            ('y', "un"),
+            ('u', "u"),
+            ('i', "i"),
+            ('o', "o|uo"),
+            ('p', "ie"),
+            ('a', "a"),
+            ('s', "iong|ong"),
+            ('d', "ai"),
+            ('f', "en"),
+            ('g', "eng"),
+            ('h', "ang"),
+            ('j', "an"),
+            ('k', "ing|uai"),
+            ('l', "iang|uang"),
+            ('z', "ou"),
+            ('x', "ia|ua"),
+            ('c', "ao"),
+            ('v', "v|ui"),
+            ('b', "in"),
+            ('n', "iao"),
+            ('m', "ian"),
+        ],
+        zero: &ZERO_SPELLED_OUT,
+    };
+
+    // Is c one of the keys of this layout? Runs of these pair up.
+    pub fn is_key(&self, c: char) -> bool {
+        c.is_ascii_lowercase() || self.finals.iter().any(|&(k, _)| k == c)
+    }
+
+    // Initial for the first key of a pair
+    fn initial(&self, c: char) -> Option<&'static str> {
+        if c == self.zh {
+            return Some("zh");
+        } else if c == self.ch {
+            return Some("ch");
+        } else if c == self.sh {
+            return Some("sh");
+        }
+        INITIALS.iter().find(|s| s.starts_with(c)).copied()
+    }
+
+    // Final for the second key of a pair, as it goes after initial
+    fn final_after(&self, initial: &str, c: char) -> Option<&'static str> {
+        let &(_, finals) = self.finals.iter().find(|&&(k, _)| k == c)?;
+        finals
+            .split('|')
+            .find(|f| fits(initial, f))
+            .or_else(|| finals.split('|').next())
+    }
+}
+
+// Syllables without an initial for Ziranma and Xiaohe: single vowels get
+// doubled, two letter finals are typed as they are spelled, and the others
+// take the key of their final.
+const ZERO_SPELLED_OUT: [(&str, &str); 12] = [
+    ("aa", "a"),
+    ("oo", "o"),
+    ("ee", "e"),
+    ("ai", "ai"),
+    ("ei", "ei"),
+    ("ao", "ao"),
+    ("ou", "ou"),
+    ("an", "an"),
+    ("en", "en"),
+    ("er", "er"),
+    ("ah", "ang"),
+    ("eg", "eng"),
+];
+
+// Initials that are typed as the letter they are spelled with
+const INITIALS: [&str; 20] = [
+    "b", "p", "m", "f", "d", "t", "n", "l", "g", "k", "h", "j", "q", "x", "r", "z", "c", "s", "y",
+    "w",
+];
+
+// Can initial go with fin? This only rules out enough syllables to tell
+// apart the finals that share a key in the built-in layouts.
+fn fits(initial: &str, fin: &str) -> bool {
+    let labial = matches!(initial, "b" | "p" | "m" | "f");
+    let palatal = matches!(initial, "j" | "q" | "x");
+    let glide = matches!(initial, "y" | "w");
+    match fin {
+        "v" | "ve" => matches!(initial, "n" | "l"),
+        "o" => labial || glide,
+        "ong" => !labial && !palatal && initial != "w",
+        "iong" => palatal,
+        // After j, q, x, and y, these are spelled with u but said with ü
+        "ue" | "uan" | "un" => !labial,
+        _ if fin.len() > 1 && fin.starts_with('u') => !labial && !palatal && !glide,
+        _ if fin.len() > 1 && fin.starts_with('i') => !matches!(
+            initial,
+            "g" | "k" | "h" | "zh" | "ch" | "sh" | "r" | "z" | "c" | "s" | "f" | "w"
+        ),
+        _ => true,
+    }
+}
+
+// Turn a key typed as Shuangpin pairs with layout, like "vsgo", into plain
+// pinyin, like "zhongguo", writing it into buf.
+// Return: pinyin, or None if key has an odd number of keys or a pair that
+// isn't a syllable.
+pub fn to_pinyin<'b>(
+    key: &str,
+    layout: &ShuangpinLayout,
+    buf: &'b mut [u8; autogen_hsk::PINYIN_SIZE_MAX],
+) -> Option<&'b str> {
+    if key.is_empty() || !key.is_ascii() || key.len() % 2 != 0 {
+        return None;
+    }
+    let mut n = 0;
+    for pair in key.as_bytes().chunks(2) {
+        let (a, b) = (pair[0] as char, pair[1] as char);
+        let zero = layout
+            .zero
+            .iter()
+            .find(|&&(code, _)| code.as_bytes() == pair);
+        let (initial, fin) = match zero {
+            Some(&(_, syllable)) => ("", syllable),
+            None => {
+                let initial = layout.initial(a)?;
+                (initial, layout.final_after(initial, b)?)
+            }
+        };
+        for s in [initial, fin] {
+            let dest = buf.get_mut(n..n + s.len())?;
+            dest.copy_from_slice(s.as_bytes());
+            n += s.len();
+        }
+    }
+    core::str::from_utf8(&buf[..n]).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dialects::{look_up_with_options, BufWriter, LookupOptions, Writer};
+
+    #[test]
+    fn pairs_spell_pinyin_syllables() {
+        let check = |key, layout, expected: Option<&str>| {
+            let mut buf = [0; autogen_hsk::PINYIN_SIZE_MAX];
+            assert_eq!(to_pinyin(key, &layout, &mut buf), expected);
+        };
+        let ms = ShuangpinLayout::MICROSOFT;
+        check("vsgo", ms, Some("zhongguo"));
+        check("woxdhe", ms, Some("woxianghe"));
+        check("jsxd", ms, Some("jiongxiang"));
+        check("gdud", ms, Some("guangshuang"));
+        check("nyly", ms, Some("nvlv"));
+        check("hyxt", ms, Some("huaixue"));
+        check("yo", ms, Some("yo"));
+        check("bo", ms, Some("bo"));
+        check("x;", ms, Some("xing"));
+        check("olob", ms, Some("aiou"));
+        check("ab", ms, None);
+        check("vsg", ms, None);
+        let zrm = ShuangpinLayout::ZIRANMA;
+        check("ky", zrm, Some("kuai"));
+        check("xy", zrm, Some("xing"));
+        check("lv", zrm, Some("lv"));
+        check("iv", zrm, Some("chui"));
+        check("aiah", zrm, Some("aiang"));
+        let xh = ShuangpinLayout::XIAOHE;
+        check("kk", xh, Some("kuai"));
+        check("bk", xh, Some("bing"));
+        check("ll", xh, Some("liang"));
+        check("hl", xh, Some("huang"));
+        check("xx", xh, Some("xia"));
+        check("ux", xh, Some("shua"));
+        check("ww", xh, Some("wei"));
+        check("eg", xh, Some("eng"));
+    }
+
+    #[test]
+    fn shuangpin_keys_look_up_like_pinyin() {
+        let mut sink = BufWriter::new();
+        let look_up = |query, layout, sink: &mut BufWriter| {
+            sink.rewind();
+            let opts = LookupOptions {
+                shuangpin: Some(layout),
+                ..Default::default()
+            };
+            look_up_with_options(query, &opts, sink);
+        };
+        look_up("vsgo", ShuangpinLayout::MICROSOFT, &mut sink);
+        assert_eq!(sink.to_s(), "中国");
+        look_up("vsgo", ShuangpinLayout::XIAOHE, &mut sink);
+        assert_eq!(sink.to_s(), "中国");
+        look_up("woxd he2", ShuangpinLayout::MICROSOFT, &mut sink);
+        #[cfg(feature = "bigram")]
+        assert_eq!(sink.to_s(), "我想和");
+        #[cfg(not(feature = "bigram"))]
+        assert_eq!(sink.to_s(), "我向河");
+        look_up("ol ", ShuangpinLayout::MICROSOFT, &mut sink);
+        assert_eq!(sink.to_s(), "爱");
+        look_up("ai ", ShuangpinLayout::ZIRANMA, &mut sink);
+        assert_eq!(sink.to_s(), "爱");
+        // Pairs line up from the start of each run of keys, so "xwo" is xia
+        // and a key left over, not x and wo. A key left over at the end
+        // passes through while its pair is being typed.
+        look_up("xwo", ShuangpinLayout::MICROSOFT, &mut sink);
+        assert_eq!(sink.to_s(), " (1下 2夏 3吓 4瞎) o");
+        look_up("wo x", ShuangpinLayout::MICROSOFT, &mut sink);
+        assert_eq!(sink.to_s(), "我x");
+        look_up("xm ", ShuangpinLayout::XIAOHE, &mut sink);
+        assert_eq!(sink.to_s(), "先");
+    }
+}
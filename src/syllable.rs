@@ -0,0 +1,333 @@
+// Copyright (c) 2021 Sam Blenny
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+#![forbid(unsafe_code)]
+
+use crate::constants;
+
+// Pinyin syllables. Lookups hash whole 词语 search keys, so they don't need to
+// know where syllables start, but telling pinyin from other text does: xian
+// is one syllable (先) where xi'an is two (西安), and an unmatched run of
+// letters may be pinyin or may be an English word.
+//
+// Runs of letters get split into legal syllables by dynamic programming, much
+// as the lattice splits queries into 词语. Pinyin spelling puts an apostrophe
+// before a syllable that starts with a, o, or e (xi'an), but typists often
+// leave it out (keai for kě'ài), so such syllables in the middle of a run just
+// cost more: xian is one syllable and fangan is fan gan, but keai is ke ai.
+
+const SYLLABLE_LIST_SIZE: usize = constants::BUF_SIZE;
+const PATH_SIZE: usize = constants::BUF_SIZE + 1;
+
+// Longest syllable ("zhuang"), not counting erhua r
+const SYLLABLE_SIZE_MAX: usize = 6;
+
+// Legal Mandarin syllables, sorted for binary search. As in search keys, ü is
+// spelled v (nv, lve), but nue and lue count too, since people type them.
+// Interjections without a vowel (m, n, ng, hm, hng) are here as well.
+const SYLLABLES: [&str; 418] = [
+    "a", "ai", "an", "ang", "ao", "ba", "bai", "ban", "bang", "bao", "bei", "ben", "beng", "bi",
+    "bian", "biao", "bie", "bin", "bing", "bo", "bu", "ca", "cai", "can", "cang", "cao", "ce",
+    "cen", "ceng", "cha", "chai", "chan", "chang", "chao", "che", "chen", "cheng", "chi", "chong",
+    "chou", "chu", "chua", "chuai", "chuan", "chuang", "chui", "chun", "chuo", "ci", "cong", "cou",
+    "cu", "cuan", "cui", "cun", "cuo", "da", "dai", "dan", "dang", "dao", "de", "dei", "den",
+    "deng", "di", "dia", "dian", "diao", "die", "ding", "diu", "dong", "dou", "du", "duan", "dui",
+    "dun", "duo", "e", "ei", "en", "eng", "er", "fa", "fan", "fang", "fei", "fen", "feng", "fo",
+    "fou", "fu", "ga", "gai", "gan", "gang", "gao", "ge", "gei", "gen", "geng", "gong", "gou",
+    "gu", "gua", "guai", "guan", "guang", "gui", "gun", "guo", "ha", "hai", "han", "hang", "hao",
+    "he", "hei", "hen", "heng", "hm", "hng", "hong", "hou", "hu", "hua", "huai", "huan", "huang",
+    "hui", "hun", "huo", "ji", "jia", "jian", "jiang", "jiao", "jie", "jin", "jing", "jiong",
+    "jiu", "ju", "juan", "jue", "jun", "ka", "kai", "kan", "kang", "kao", "ke", "kei", "ken",
+    "keng", "kong", "kou", "ku", "kua", "kuai", "kuan", "kuang", "kui", "kun", "kuo", "la", "lai",
+    "lan", "lang", "lao", "le", "lei", "leng", "li", "lia", "lian", "liang", "liao", "lie", "lin",
+    "ling", "liu", "lo", "long", "lou", "lu", "luan", "lue", "lun", "luo", "lv", "lve", "m", "ma",
+    "mai", "man", "mang", "mao", "me", "mei", "men", "meng", "mi", "mian", "miao", "mie", "min",
+    "ming", "miu", "mo", "mou", "mu", "n", "na", "nai", "nan", "nang", "nao", "ne", "nei", "nen",
+    "neng", "ng", "ni", "nian", "niang", "niao", "nie", "nin", "ning", "niu", "nong", "nou", "nu",
+    "nuan", "nue", "nuo", "nv", "nve", "o", "ou", "pa", "pai", "pan", "pang", "pao", "pei", "pen",
+    "peng", "pi", "pian", "piao", "pie", "pin", "ping", "po", "pou", "pu", "qi", "qia", "qian",
+    "qiang", "qiao", "qie", "qin", "qing", "qiong", "qiu", "qu", "quan", "que", "qun", "ran",
+    "rang", "rao", "re", "ren", "reng", "ri", "rong", "rou", "ru", "rua", "ruan", "rui", "run",
+    "ruo", "sa", "sai", "san", "sang", "sao", "se", "sen", "seng", "sha", "shai", "shan", "shang",
+    "shao", "she", "shei", "shen", "sheng", "shi", "shou", "shu", "shua", "shuai", "shuan",
+    "shuang", "shui", "shun", "shuo", "si", "song", "sou", "su", "suan", "sui", "sun", "suo", "ta",
+    "tai", "tan", "tang", "tao", "te", "tei", "teng", "ti", "tian", "tiao", "tie", "ting", "tong",
+    "tou", "tu", "tuan", "tui", "tun", "tuo", "wa", "wai", "wan", "wang", "wei", "wen", "weng",
+    "wo", "wu", "xi", "xia", "xian", "xiang", "xiao", "xie", "xin", "xing", "xiong", "xiu", "xu",
+    "xuan", "xue", "xun", "ya", "yan", "yang", "yao", "ye", "yi", "yin", "ying", "yo", "yong",
+    "you", "yu", "yuan", "yue", "yun", "za", "zai", "zan", "zang", "zao", "ze", "zei", "zen",
+    "zeng", "zha", "zhai", "zhan", "zhang", "zhao", "zhe", "zhei", "zhen", "zheng", "zhi", "zhong",
+    "zhou", "zhu", "zhua", "zhuai", "zhuan", "zhuang", "zhui", "zhun", "zhuo", "zi", "zong", "zou",
+    "zu", "zuan", "zui", "zun", "zuo",
+];
+
+/// Is s one legal pinyin syllable (lowercase, without tone), like "xian"?
+pub fn is_syllable(s: &str) -> bool {
+    SYLLABLES.binary_search(&s).is_ok()
+}
+
+/// Kind of a piece of pinyin input
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum SyllableKind {
+    /// A legal syllable, maybe with erhua r (like "nar")
+    Syllable,
+    /// Letters that don't make legal syllables
+    Invalid,
+}
+
+/// One piece of pinyin input along with the character range of the input
+/// that it came from. Ranges follow start..end semantics (upper bound
+/// exclusive).
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Syllable {
+    pub start: usize,
+    pub end: usize,
+    pub kind: SyllableKind,
+}
+
+/// Holds list of Syllables (append only, until cleared)
+pub struct SyllableList {
+    list: [Syllable; SYLLABLE_LIST_SIZE],
+    count: usize,
+}
+impl SyllableList {
+    /// Return empty list ready for use.
+    pub fn new() -> SyllableList {
+        let empty = Syllable {
+            start: 0,
+            end: 0,
+            kind: SyllableKind::Invalid,
+        };
+        SyllableList {
+            list: [empty; SYLLABLE_LIST_SIZE],
+            count: 0,
+        }
+    }
+
+    /// Remove all syllables.
+    pub fn clear(&mut self) {
+        self.count = 0;
+    }
+
+    /// Number of syllables (and invalid pieces).
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Is the list empty?
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Syllable at index i.
+    pub fn get(&self, i: usize) -> Option<&Syllable> {
+        self.list[..self.count].get(i)
+    }
+
+    /// Iterate over syllables in input order.
+    pub fn iter(&self) -> core::slice::Iter<'_, Syllable> {
+        self.list[..self.count].iter()
+    }
+
+    /// Is all of the input that got split valid pinyin?
+    pub fn is_valid(&self) -> bool {
+        self.iter().all(|s| s.kind == SyllableKind::Syllable)
+    }
+
+    // Add Syllable to list, merging it into the one before if both are
+    // invalid letters.
+    fn push(&mut self, syl: Syllable) -> bool {
+        if let Some(last) = self.list[..self.count].last_mut() {
+            if last.kind == SyllableKind::Invalid
+                && syl.kind == SyllableKind::Invalid
+                && last.end == syl.start
+            {
+                last.end = syl.end;
+                return true;
+            }
+        }
+        if self.count < SYLLABLE_LIST_SIZE {
+            self.list[self.count] = syl;
+            self.count += 1;
+            true
+        } else {
+            // Error: List is full
+            false
+        }
+    }
+}
+impl Default for SyllableList {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Split the pinyin in input into syllables. Each run of letters splits into
+/// legal syllables, and letters that can't be part of one come out as
+/// invalid pieces, so "xian" is one syllable, "xi'an" is two, and "xyz" is
+/// invalid. Apostrophes only separate syllables, and they don't belong to any
+/// piece. Neither do spaces, digits, or other characters that aren't
+/// letters. Capitals count as their lowercase letters (as in "Beijing").
+/// Side-effect: fills out with pieces in input order.
+pub fn split_syllables(input: &str, out: &mut SyllableList) {
+    out.clear();
+    let mut run = [0; constants::BUF_SIZE];
+    let mut len = 0;
+    let mut start = 0;
+    for (i, c) in input.chars().take(constants::BUF_SIZE).enumerate() {
+        if c.is_ascii_alphabetic() {
+            run[len] = c.to_ascii_lowercase() as u8;
+            len += 1;
+        } else {
+            split_run(&run[..len], start, out);
+            len = 0;
+            start = i + 1;
+        }
+    }
+    split_run(&run[..len], start, out);
+}
+
+// Cost of a split, to minimise: invalid letters first, then erhua, then
+// syllables that start with a, o, or e in the middle of a run, then syllables
+// (so nver is nv er rather than nve with erhua)
+type Cost = (usize, usize, usize, usize);
+
+// Split run, a run of lowercase letters that starts at character offset of
+// the input, into syllables.
+// Side-effect: push syllables into out.
+fn split_run(run: &[u8], offset: usize, out: &mut SyllableList) {
+    // Lowest cost to reach each position of the run, and where the last
+    // piece on that path starts
+    let mut cost: [Option<Cost>; PATH_SIZE] = [None; PATH_SIZE];
+    let mut from = [0; PATH_SIZE];
+    let mut valid = [false; PATH_SIZE];
+    cost[0] = Some((0, 0, 0, 0));
+    for i in 0..run.len() {
+        let (bad, er, mid, n) = match cost[i] {
+            Some(c) => c,
+            None => continue,
+        };
+        let mut relax = |end: usize, c: Cost, is_valid: bool| {
+            if cost[end].map_or(true, |old| c < old) {
+                cost[end] = Some(c);
+                from[end] = i;
+                valid[end] = is_valid;
+            }
+        };
+        relax(i + 1, (bad + 1, er, mid, n), false);
+        let mid = mid + usize::from(i > 0 && matches!(run[i], b'a' | b'o' | b'e'));
+        for len in 1..=SYLLABLE_SIZE_MAX.min(run.len() - i) {
+            let syl = core::str::from_utf8(&run[i..i + len]).unwrap_or("");
+            if !is_syllable(syl) {
+                continue;
+            }
+            relax(i + len, (bad, er, mid, n + 1), true);
+            if run.get(i + len) == Some(&b'r') && syl != "er" {
+                relax(i + len + 1, (bad, er + 1, mid, n + 1), true);
+            }
+        }
+    }
+    // Walk the path back from the end, then push it front to back
+    let mut bounds = [0; PATH_SIZE];
+    let mut k = 0;
+    let mut end = run.len();
+    while end > 0 {
+        bounds[k] = end;
+        k += 1;
+        end = from[end];
+    }
+    let mut begin = 0;
+    for &end in bounds[..k].iter().rev() {
+        let kind = if valid[end] {
+            SyllableKind::Syllable
+        } else {
+            SyllableKind::Invalid
+        };
+        // TODO: Better solution than silently ignoring possible full list
+        let _ = out.push(Syllable {
+            start: offset + begin,
+            end: offset + end,
+            kind,
+        });
+        begin = end;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::autogen_hsk;
+
+    // Check that ASCII input splits into the expected pieces, given as their
+    // text, with "!" in front of invalid ones
+    fn check(input: &str, expected: &[&str]) {
+        let mut out = SyllableList::new();
+        split_syllables(input, &mut out);
+        assert_eq!(out.len(), expected.len(), "{}", input);
+        for (syl, &piece) in out.iter().zip(expected) {
+            let (text, kind) = match piece.strip_prefix('!') {
+                Some(text) => (text, SyllableKind::Invalid),
+                None => (piece, SyllableKind::Syllable),
+            };
+            assert_eq!(&input[syl.start..syl.end], text, "{}", input);
+            assert_eq!(syl.kind, kind, "{}", input);
+        }
+    }
+
+    #[test]
+    fn runs_of_letters_split_into_syllables() {
+        check("xian", &["xian"]);
+        check("xi'an", &["xi", "an"]);
+        check("fangan", &["fan", "gan"]);
+        check("fang'an", &["fang", "an"]);
+        check("keai", &["ke", "ai"]);
+        check("nver", &["nv", "er"]);
+        check("zhongguoren", &["zhong", "guo", "ren"]);
+        check("yidianr", &["yi", "dianr"]);
+        check("Beijing", &["Bei", "jing"]);
+        check("wo 3ge", &["wo", "ge"]);
+        check("", &[]);
+        // Letters that aren't pinyin come out as invalid pieces
+        check("xyz", &["!xyz"]);
+        check("hello", &["he", "!l", "lo"]);
+        check("strong", &["!st", "rong"]);
+        let mut out = SyllableList::new();
+        split_syllables("wo'xiang he", &mut out);
+        assert!(out.is_valid());
+        split_syllables("English", &mut out);
+        assert!(!out.is_valid());
+    }
+
+    #[test]
+    fn syllable_table_covers_vocab() {
+        for i in 1..SYLLABLES.len() {
+            assert!(SYLLABLES[i] > SYLLABLES[i - 1]);
+        }
+        let mut out = SyllableList::new();
+        for key in autogen_hsk::PINYIN_KEYS.iter() {
+            if key.bytes().all(|b| b.is_ascii_lowercase()) {
+                split_syllables(key, &mut out);
+                assert!(out.is_valid(), "{}", key);
+            }
+        }
+    }
+
+    #[test]
+    fn generated_readings_are_legal_syllables() {
+        let readings = autogen_hsk::CIYU_TONED
+            .iter()
+            .chain(autogen_hsk::HANZI_PINYIN.iter())
+            .flat_map(|r| r.split(['\t', '|']));
+        for reading in readings {
+            // Each syllable ends with its tone number. Erhua r goes on the
+            // syllable before it, and other text like punctuation gets skipped.
+            for syl in reading.split(|c: char| !c.is_ascii_lowercase()) {
+                let plain = match syl.strip_suffix('r') {
+                    Some(s) if !is_syllable(syl) => s,
+                    _ => syl,
+                };
+                assert!(plain.is_empty() || is_syllable(plain), "{}", reading);
+            }
+        }
+    }
+}
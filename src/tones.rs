@@ -0,0 +1,404 @@
+// Copyright (c) 2021 Sam Blenny
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+#![forbid(unsafe_code)]
+
+use crate::autogen_hsk;
+use crate::zhuyin;
+
+// Tone-aware input. With tone input on, digits 1-5 after pinyin say the tone
+// of the syllable they follow (5 for neutral tone), so "xiang3" only offers
+// 词语 read with a third tone, like 想. Tones don't have to be typed for every
+// syllable. Zhuyin keys (see zhuyin.rs) turn into pinyin with tone numbers,
+// so their tone keys work the same way, with or without tone input. Since the
+// digits are taken, choices get picked with the shifted digits of a US
+// keyboard instead ("!" for 1, "@" for 2, ...).
+//
+// Pinyin with tone marks, like "xiǎng" (say, copied from a textbook), works
+// with or without tone input. The marks only change the order of candidates,
+// putting the ones with the marked tones first, since marks in running text
+// often follow tone changes like yí or bú that the vocab files don't.
+//
+// The tones of built-in 词语 come from autogen_hsk::CIYU_TONED, which spells
+// each choice with a tone number after each syllable, like "xiang3fa3". Tone
+// 0 there means neither the vocab files nor the GB 2312 readings say, and it
+// matches any typed tone.
+
+// Choice keys for tone input, in label order
+const CHOICE_KEYS: [char; 9] = ['!', '@', '#', '$', '%', '^', '&', '*', '('];
+
+// Vowels with tone marks (and ü), as (marked, plain, tone). Pinyin search keys
+// spell ü as v.
+const MARKED: [(char, u8, u8); 26] = [
+    ('ā', b'a', b'1'),
+    ('á', b'a', b'2'),
+    ('ǎ', b'a', b'3'),
+    ('à', b'a', b'4'),
+    ('ē', b'e', b'1'),
+    ('é', b'e', b'2'),
+    ('ě', b'e', b'3'),
+    ('è', b'e', b'4'),
+    ('ī', b'i', b'1'),
+    ('í', b'i', b'2'),
+    ('ǐ', b'i', b'3'),
+    ('ì', b'i', b'4'),
+    ('ō', b'o', b'1'),
+    ('ó', b'o', b'2'),
+    ('ǒ', b'o', b'3'),
+    ('ò', b'o', b'4'),
+    ('ū', b'u', b'1'),
+    ('ú', b'u', b'2'),
+    ('ǔ', b'u', b'3'),
+    ('ù', b'u', b'4'),
+    ('ǖ', b'v', b'1'),
+    ('ǘ', b'v', b'2'),
+    ('ǚ', b'v', b'3'),
+    ('ǜ', b'v', b'4'),
+    ('ǹ', b'n', b'4'),
+    ('ü', b'v', 0),
+];
+
+// Map a tone input choice key to the digit label it stands for.
+pub fn choice_label(c: char) -> Option<char> {
+    let i = CHOICE_KEYS.iter().position(|&k| k == c)?;
+    core::char::from_digit(i as u32 + 1, 10)
+}
+
+// Map a typed character to (lowercase ASCII character, tone), where tone is 0
+// unless the character has a tone mark.
+fn plain_char(c: char) -> Option<(u8, u8)> {
+    if c.is_ascii() {
+        return Some((c.to_ascii_lowercase() as u8, 0));
+    }
+    MARKED
+        .iter()
+        .find(|&&(marked, _, _)| marked == c)
+        .map(|&(_, plain, tone)| (plain, tone))
+}
+
+// Map a plain pinyin vowel (v for ü) and tone number to the vowel with its
+// tone mark, like ('a', 3) to 'ǎ'. Tones other than 1-4 leave the vowel
+// unmarked, except that v still becomes ü.
+pub fn marked(plain: u8, tone: u8) -> char {
+    let tone = match tone {
+        1..=4 => b'0' + tone,
+        _ => 0,
+    };
+    MARKED
+        .iter()
+        .find(|&&(_, p, t)| p == plain && t == tone)
+        .map_or(plain as char, |&(marked, _, _)| marked)
+}
+
+// Turn a typed key like "xiang3fa" (if digits are tone numbers), "xiǎngfǎ",
+// or "ㄒㄧㄤˇㄈㄚ" into plain pinyin ("xiangfa"), writing it into buf. Keys
+// with tone marks can start with a capital, as names do in textbook pinyin
+// ("Zhōngguó").
+// Return: plain pinyin, or None if key has no tones or ü to take out, or has
+// characters that aren't pinyin (digits that can't be tone numbers, because
+// digits is false, or they aren't 1-5 right after a letter, or capitals
+// other than a first letter before tone marks).
+pub fn plain<'b>(
+    key: &str,
+    digits: bool,
+    buf: &'b mut [u8; autogen_hsk::PINYIN_SIZE_MAX],
+) -> Option<&'b str> {
+    let mut zhuyin_buf = [0; zhuyin::PINYIN_SIZE_MAX];
+    if let Some(toned) = zhuyin::to_pinyin(key, &mut zhuyin_buf) {
+        let mut n = 0;
+        for b in toned.bytes().filter(|b| !b.is_ascii_digit()) {
+            *buf.get_mut(n)? = b;
+            n += 1;
+        }
+        return core::str::from_utf8(&buf[..n]).ok();
+    }
+    let mut n = 0;
+    let mut prev = b' ';
+    let mut changed = false;
+    let mut capital = false;
+    for (i, c) in key.chars().enumerate() {
+        if c.is_ascii_uppercase() {
+            if i > 0 {
+                return None;
+            }
+            capital = true;
+        }
+        let (b, _) = plain_char(c)?;
+        if b.is_ascii_digit() {
+            if !digits || !matches!(b, b'1'..=b'5') || !prev.is_ascii_lowercase() {
+                return None;
+            }
+        } else {
+            *buf.get_mut(n)? = b;
+            n += 1;
+        }
+        changed |= b.is_ascii_digit() || !c.is_ascii();
+        prev = b;
+    }
+    let marked = !key.is_ascii();
+    if changed && (marked || !capital) {
+        core::str::from_utf8(&buf[..n]).ok()
+    } else {
+        None
+    }
+}
+
+// Does typed key have tone numbers (or zhuyin tone keys)? Those filter
+// candidates, where tone marks only rank them.
+pub fn has_numbers(typed: &str) -> bool {
+    typed.bytes().any(|b| b.is_ascii_digit()) || zhuyin::has_tone(typed)
+}
+
+// Typed key as plain letters and tone numbers, with the tone of each marked
+// vowel. Tone numbers make keys longer than their pinyin, up to twice as long.
+const TYPED_SIZE_MAX: usize = 2 * autogen_hsk::PINYIN_SIZE_MAX;
+struct Typed {
+    letters: [u8; TYPED_SIZE_MAX],
+    marks: [u8; TYPED_SIZE_MAX],
+    len: usize,
+}
+impl Typed {
+    fn new(typed: &str) -> Option<Typed> {
+        let mut t = Typed {
+            letters: [0; TYPED_SIZE_MAX],
+            marks: [0; TYPED_SIZE_MAX],
+            len: 0,
+        };
+        for c in typed.chars() {
+            let (b, tone) = plain_char(c)?;
+            *t.letters.get_mut(t.len)? = b;
+            t.marks[t.len] = tone;
+            t.len += 1;
+        }
+        Some(t)
+    }
+}
+
+// Do the tones typed in key agree with a choice's readings from CIYU_TONED
+// (like "wei4|wei2")? Syllables without a typed tone match any tone.
+pub fn matches(typed: &str, readings: &str) -> bool {
+    let mut buf = [0; zhuyin::PINYIN_SIZE_MAX];
+    let typed = zhuyin::to_pinyin(typed, &mut buf).unwrap_or(typed);
+    match Typed::new(typed) {
+        Some(t) => readings
+            .split('|')
+            .any(|toned| matches_reading(&t, toned.as_bytes())),
+        None => false,
+    }
+}
+
+// Walk typed and toned together, checking typed tones against the tone
+// numbers in toned. A tone mark counts for the next tone number in toned,
+// which ends its syllable. Choices from fuzzy pinyin variants of the key can
+// spell sounds differently than the key (z or zh, en or eng, n or l), so those
+// differences get skipped over.
+fn matches_reading(t: &Typed, toned: &[u8]) -> bool {
+    matches_from(t, toned, 0, 0, 0)
+}
+
+// Walk from toned[i] and typed letter j on, where mark is the tone of the last
+// marked vowel, until its syllable ends.
+fn matches_from(t: &Typed, toned: &[u8], mut i: usize, mut j: usize, mut mark: u8) -> bool {
+    let typed = &t.letters[..t.len];
+    loop {
+        match (toned.get(i).copied(), typed.get(j).copied()) {
+            (None, _) if j == typed.len() => return true,
+            (Some(a), Some(b)) if a == b || matches!((a, b), (b'n', b'l') | (b'l', b'n')) => {
+                if t.marks[j] != 0 {
+                    mark = t.marks[j];
+                }
+                if a.is_ascii_digit() {
+                    mark = 0;
+                }
+                i += 1;
+                j += 1;
+            }
+            // Erhua tones can be typed before the r (hai2r) or after it
+            // (hair2), where the readings have them
+            (Some(b'r'), Some(b)) if b.is_ascii_digit() && typed.get(j + 1) == Some(&b'r') => {
+                match toned.get(i + 1) {
+                    Some(&a) if a == b || a == b'0' => {
+                        mark = 0;
+                        i += 2;
+                        j += 2;
+                    }
+                    _ => return false,
+                }
+            }
+            (Some(a), Some(b)) if a.is_ascii_digit() && b.is_ascii_digit() => {
+                if a != b'0' {
+                    return false;
+                }
+                mark = 0;
+                i += 1;
+                j += 1;
+            }
+            (Some(a), _) if is_fuzzy_extra(toned, i, a) => i += 1,
+            // A g after n could start the next syllable rather than be a
+            // fuzzy extra, like in "tangang1" for tan0gang1
+            (Some(a), Some(b'g'))
+                if a.is_ascii_digit()
+                    && (mark == 0 || a == b'0' || a == mark)
+                    && matches_from(t, toned, i + 1, j, 0) =>
+            {
+                return true
+            }
+            (_, Some(b)) if is_fuzzy_extra(typed, j, b) => j += 1,
+            (Some(a), _) if a.is_ascii_digit() => {
+                if mark != 0 && a != b'0' && a != mark {
+                    return false;
+                }
+                mark = 0;
+                i += 1;
+            }
+            _ => return false,
+        }
+    }
+}
+
+// Is the letter at s[i] the h of zh, ch, sh, or the g of ng?
+fn is_fuzzy_extra(s: &[u8], i: usize, letter: u8) -> bool {
+    let prev = if i > 0 { s[i - 1] } else { b' ' };
+    match letter {
+        b'h' => matches!(prev, b'z' | b'c' | b's'),
+        b'g' => prev == b'n',
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dialects::{look_up, look_up_with_options, BufWriter, LookupOptions, Writer};
+
+    fn look_up_with_tones(query: &str, sink: &mut BufWriter) {
+        let opts = LookupOptions {
+            tones: true,
+            ..Default::default()
+        };
+        look_up_with_options(query, &opts, sink);
+    }
+
+    #[test]
+    fn tone_numbers_come_out_of_keys() {
+        let mut buf = [0; autogen_hsk::PINYIN_SIZE_MAX];
+        assert_eq!(plain("xiang3fa", true, &mut buf), Some("xiangfa"));
+        assert_eq!(plain("xiang3fa3", true, &mut buf), Some("xiangfa"));
+        assert_eq!(plain("xiang3fa3", false, &mut buf), None);
+        assert_eq!(plain("xiangfa", true, &mut buf), None);
+        assert_eq!(plain("xiang6", true, &mut buf), None);
+        assert_eq!(plain("3xiang", true, &mut buf), None);
+        assert_eq!(plain("xiang33", true, &mut buf), None);
+        assert!(matches("xiang3fa", "xiang3fa3"));
+        assert!(!matches("xiang4", "xiang3"));
+        assert!(matches("wei2", "wei4|wei2"));
+        assert!(matches("xiang2", "xiang0"));
+        assert!(matches("zi3", "zhi3"));
+        assert!(matches("zhen1", "zheng1"));
+        assert!(matches("nv3hai2r", "nv3hair2"));
+        assert!(!matches("nv3hai3r", "nv3hair2"));
+        assert_eq!(plain("xiǎngfǎ", false, &mut buf), Some("xiangfa"));
+        assert_eq!(plain("nǚ", false, &mut buf), Some("nv"));
+        assert_eq!(plain("xiǎng🐇", false, &mut buf), None);
+        assert_eq!(plain("Běijīng", false, &mut buf), Some("beijing"));
+        assert_eq!(plain("BěiJīng", false, &mut buf), None);
+        assert_eq!(plain("Xiang3", true, &mut buf), None);
+        assert!(matches("xiǎngfa", "xiang3fa3"));
+        assert!(!matches("xiàng", "xiang3"));
+        assert!(matches("xiàng", "xiang0"));
+        assert_eq!(choice_label('@'), Some('2'));
+        assert_eq!(choice_label('2'), None);
+    }
+
+    #[test]
+    fn digits_after_pinyin_filter_by_tone() {
+        let mut sink = BufWriter::new();
+        look_up_with_tones("shi", &mut sink);
+        assert!(sink.to_s().starts_with(" (1是 2使 3十"));
+        sink.rewind();
+        look_up_with_tones("shi2", &mut sink);
+        assert_eq!(sink.to_s(), "十");
+        sink.rewind();
+        look_up_with_tones("shi4@", &mut sink);
+        assert_eq!(sink.to_s(), "试");
+        sink.rewind();
+        look_up_with_tones("wo3 xiang3 he1 ", &mut sink);
+        assert_eq!(sink.to_s(), "我想喝");
+        sink.rewind();
+        look_up_with_tones("zhong1guo2", &mut sink);
+        assert_eq!(sink.to_s(), "中国");
+        sink.rewind();
+        look_up_with_tones("zhongguo4", &mut sink);
+        assert_ne!(sink.to_s(), "中国");
+        // Digits that aren't tones pass through, and don't pick choices
+        sink.rewind();
+        look_up_with_tones("shi 2", &mut sink);
+        assert_eq!(sink.to_s(), "是2");
+        // "!" picks the first choice rather than being punctuation
+        sink.rewind();
+        look_up_with_tones("shi!", &mut sink);
+        assert_eq!(sink.to_s(), "是");
+        // Tone digits don't count toward the longest key, so long words match
+        // with a tone on every syllable
+        sink.rewind();
+        look_up_with_tones("mai4huo3chai2de5xiao3nv3hai2r", &mut sink);
+        assert_eq!(sink.to_s(), "《卖火柴的小女孩儿》");
+    }
+
+    #[test]
+    fn toned_readings_offer_their_ciyu() {
+        let mut sink = BufWriter::new();
+        for (choices, toned) in autogen_hsk::CIYU.iter().zip(autogen_hsk::CIYU_TONED) {
+            for (ciyu, readings) in choices.split('\t').zip(toned.split('\t')) {
+                for reading in readings.split('|') {
+                    if !reading.bytes().all(|b| b.is_ascii_alphanumeric()) {
+                        continue;
+                    }
+                    // Type the reading with its tones, leaving out unknown ones
+                    let mut typed = BufWriter::new();
+                    for part in reading.split('0') {
+                        typed.write(part);
+                    }
+                    sink.rewind();
+                    look_up_with_tones(typed.to_s(), &mut sink);
+                    assert!(sink.to_s().contains(ciyu), "{} {}", reading, ciyu);
+                }
+            }
+        }
+        sink.rewind();
+        look_up_with_tones("nan2guo4", &mut sink);
+        assert_eq!(sink.to_s(), "难过");
+        sink.rewind();
+        look_up_with_tones("can1guan1", &mut sink);
+        assert_eq!(sink.to_s(), "参观");
+        sink.rewind();
+        look_up_with_tones("gu1niang", &mut sink);
+        assert_eq!(sink.to_s(), "姑娘");
+        sink.rewind();
+        look_up_with_tones("xiang3", &mut sink);
+        assert!(!sink.to_s().contains('像'));
+        assert!(!sink.to_s().contains('向'));
+        assert!(!sink.to_s().contains('相'));
+    }
+
+    #[test]
+    fn tone_marks_rank_candidates() {
+        let mut sink = BufWriter::new();
+        look_up("wǒ xiǎng hē guǒzhī", &mut sink);
+        assert_eq!(sink.to_s(), "我想喝果汁");
+        sink.rewind();
+        look_up("xiang", &mut sink);
+        assert!(sink.to_s().starts_with(" (1向 2想"));
+        // Other tones come last rather than getting left out
+        sink.rewind();
+        look_up("xiǎng", &mut sink);
+        assert!(sink.to_s().starts_with(" (1想"));
+        assert!(sink.to_s().contains("向"));
+        sink.rewind();
+        look_up("nǚ lǜ1", &mut sink);
+        assert_eq!(sink.to_s(), "女绿");
+        sink.rewind();
+        look_up("Zhōngguó", &mut sink);
+        assert_eq!(sink.to_s(), "中国");
+    }
+}
@@ -0,0 +1,266 @@
+// Copyright (c) 2021 Sam Blenny
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+#![forbid(unsafe_code)]
+
+use crate::autogen_hsk;
+
+// Runtime user dictionary for words that aren't in the built-in HSK tables
+// (names, product terms, ...). The caller provides the storage as a slice of
+// UserEntry, so this works without heap allocation. Entries are kept sorted by
+// pinyin search key so all the entries for one key form a contiguous slice.
+
+/// Longest pinyin search key (bytes) that a UserEntry can hold. Keys can't be
+/// longer than the longest built-in key, because the segmenter doesn't look
+/// further back than that for matches.
+pub const USER_PINYIN_MAX: usize = autogen_hsk::PINYIN_SIZE_MAX;
+
+/// Longest 词语 (UTF-8 bytes) that a UserEntry can hold.
+pub const USER_CIYU_MAX: usize = 32;
+
+/// One pinyin→词语 entry of a UserDict or ChoiceHistory.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct UserEntry {
+    pinyin: [u8; USER_PINYIN_MAX],
+    pinyin_len: usize,
+    ciyu: [u8; USER_CIYU_MAX],
+    ciyu_len: usize,
+}
+impl UserEntry {
+    /// Unused entry, for initializing storage arrays.
+    pub const EMPTY: UserEntry = UserEntry {
+        pinyin: [0; USER_PINYIN_MAX],
+        pinyin_len: 0,
+        ciyu: [0; USER_CIYU_MAX],
+        ciyu_len: 0,
+    };
+
+    // Make an entry, normalizing pinyin as a search key.
+    // Return: None if pinyin or ciyu is empty, too long, or has characters
+    // that aren't allowed (see UserDict::add).
+    pub(crate) fn new(pinyin: &str, ciyu: &str) -> Option<UserEntry> {
+        let mut entry = UserEntry::EMPTY;
+        if !normalize(pinyin, &mut entry) {
+            return None;
+        }
+        if ciyu.is_empty()
+            || ciyu.len() > USER_CIYU_MAX
+            || ciyu.contains('\t')
+            || ciyu.contains('\n')
+        {
+            return None;
+        }
+        entry.ciyu[..ciyu.len()].copy_from_slice(ciyu.as_bytes());
+        entry.ciyu_len = ciyu.len();
+        Some(entry)
+    }
+
+    /// Normalized pinyin search key.
+    pub fn pinyin(&self) -> &str {
+        // Bad utf8 can't happen since add() only stores whole strs.
+        core::str::from_utf8(&self.pinyin[..self.pinyin_len]).unwrap_or_default()
+    }
+
+    /// 词语 that the key stands for.
+    pub fn ciyu(&self) -> &str {
+        // Bad utf8 can't happen since add() only stores whole strs.
+        core::str::from_utf8(&self.ciyu[..self.ciyu_len]).unwrap_or_default()
+    }
+}
+
+/// User dictionary of pinyin→词语 entries, backed by caller-provided storage.
+/// Lookups consult it alongside the built-in tables. User entries come before
+/// built-in homophones in candidate lists.
+pub struct UserDict<'s> {
+    store: &'s mut [UserEntry],
+    count: usize,
+}
+impl<'s> UserDict<'s> {
+    /// Return an empty dictionary that can hold up to store.len() entries.
+    pub fn new(store: &'s mut [UserEntry]) -> UserDict<'s> {
+        UserDict { store, count: 0 }
+    }
+
+    /// Add an entry. The pinyin may include spaces and apostrophes, which get
+    /// removed, but otherwise must be lowercase ASCII letters (no tone marks).
+    /// Adding an entry that is already present does nothing.
+    /// Return: false if the dictionary is full, the pinyin or 词语 is empty or
+    /// too long, or the pinyin has other characters.
+    pub fn add(&mut self, pinyin: &str, ciyu: &str) -> bool {
+        let entry = match UserEntry::new(pinyin, ciyu) {
+            Some(entry) => entry,
+            None => return false,
+        };
+        let key = entry.pinyin();
+        // Insert after existing entries for the same key, so candidates stay
+        // in the order they were added
+        let entries = self.entries();
+        let at = entries.partition_point(|e| e.pinyin() <= key);
+        if entries[..at]
+            .iter()
+            .rev()
+            .take_while(|e| e.pinyin() == key)
+            .any(|e| e.ciyu() == ciyu)
+        {
+            return true;
+        }
+        if self.count >= self.store.len() {
+            return false;
+        }
+        self.store.copy_within(at..self.count, at + 1);
+        self.store[at] = entry;
+        self.count += 1;
+        true
+    }
+
+    /// Remove an entry.
+    /// Return: false if there was no such entry.
+    pub fn remove(&mut self, pinyin: &str, ciyu: &str) -> bool {
+        let mut entry = UserEntry::EMPTY;
+        if !normalize(pinyin, &mut entry) {
+            return false;
+        }
+        let key = entry.pinyin();
+        match self.find(key).iter().position(|e| e.ciyu() == ciyu) {
+            Some(i) => {
+                let at = self.entries().partition_point(|e| e.pinyin() < key) + i;
+                self.store.copy_within(at + 1..self.count, at);
+                self.count -= 1;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Remove all entries.
+    pub fn clear(&mut self) {
+        self.count = 0;
+    }
+
+    /// Number of entries.
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Is the dictionary empty?
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Most entries the dictionary can hold.
+    pub fn capacity(&self) -> usize {
+        self.store.len()
+    }
+
+    /// Iterate over entries, sorted by pinyin search key.
+    pub fn iter(&self) -> core::slice::Iter<'_, UserEntry> {
+        self.entries().iter()
+    }
+
+    // Entries sorted by pinyin search key. The lookup code takes this rather
+    // than &UserDict so that an empty &[] can stand in for no dictionary.
+    pub(crate) fn entries(&self) -> &[UserEntry] {
+        &self.store[..self.count]
+    }
+
+    // Entries for a normalized pinyin search key, in the order they were added.
+    fn find(&self, key: &str) -> &[UserEntry] {
+        find(self.entries(), key)
+    }
+}
+
+// Entries for a normalized pinyin search key, from entries sorted by key.
+pub(crate) fn find<'a>(entries: &'a [UserEntry], key: &str) -> &'a [UserEntry] {
+    let start = entries.partition_point(|e| e.pinyin() < key);
+    let end = entries.partition_point(|e| e.pinyin() <= key);
+    &entries[start..end]
+}
+
+// Copy pinyin into entry as a search key, removing spaces and apostrophes.
+// Return: false if pinyin is empty, too long, or has other non [a-z] chars.
+fn normalize(pinyin: &str, entry: &mut UserEntry) -> bool {
+    let mut n = 0;
+    for b in pinyin.bytes() {
+        match b {
+            b' ' | b'\'' => continue,
+            b'a'..=b'z' if n < USER_PINYIN_MAX => {
+                entry.pinyin[n] = b;
+                n += 1;
+            }
+            _ => return false,
+        }
+    }
+    entry.pinyin_len = n;
+    n > 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dialects::{look_up_with_options, BufWriter, LookupOptions, Writer};
+
+    #[test]
+    fn add_find_remove() {
+        let mut store = [UserEntry::EMPTY; 4];
+        let mut dict = UserDict::new(&mut store);
+        assert!(dict.add("zhang wei", "张伟"));
+        assert!(dict.add("zhang'wei", "张薇"));
+        assert!(dict.add("ai", "艾"));
+        // Duplicates don't use up space
+        assert!(dict.add("zhangwei", "张伟"));
+        assert_eq!(dict.len(), 3);
+        let found: [&str; 2] = [
+            dict.find("zhangwei")[0].ciyu(),
+            dict.find("zhangwei")[1].ciyu(),
+        ];
+        assert_eq!(found, ["张伟", "张薇"]);
+        assert!(dict.remove("zhangwei", "张伟"));
+        assert!(!dict.remove("zhangwei", "张伟"));
+        assert_eq!(dict.find("zhangwei").len(), 1);
+        assert_eq!(dict.find("ai")[0].ciyu(), "艾");
+    }
+
+    #[test]
+    fn add_rejects_bad_entries() {
+        let mut store = [UserEntry::EMPTY; 1];
+        let mut dict = UserDict::new(&mut store);
+        assert!(!dict.add("Zhang", "张"));
+        assert!(!dict.add("zhāng", "张"));
+        assert!(!dict.add("", "张"));
+        assert!(!dict.add("zhang", ""));
+        assert!(!dict.add("zhang", "一二三四五六七八九十一"));
+        assert!(!dict.add("abcdefghijklmnopqrstuvwxyz", "字"));
+        assert!(dict.add("zhang", "张"));
+        // Full
+        assert!(!dict.add("wei", "伟"));
+    }
+
+    fn look_up_with_dict(query: &str, dict: &UserDict, sink: &mut BufWriter) {
+        let opts = LookupOptions {
+            dict: Some(dict),
+            ..Default::default()
+        };
+        look_up_with_options(query, &opts, sink);
+    }
+
+    #[test]
+    fn look_up_consults_user_dict() {
+        let mut store = [UserEntry::EMPTY; 8];
+        let mut dict = UserDict::new(&mut store);
+        let mut sink = BufWriter::new();
+        look_up_with_dict("zhangwei", &dict, &mut sink);
+        assert!(!sink.to_s().contains("张伟"));
+        assert!(dict.add("zhangwei", "张伟"));
+        sink.rewind();
+        look_up_with_dict("wo zhangwei", &dict, &mut sink);
+        assert_eq!(sink.to_s(), "我张伟");
+        // User entries come before built-in homophones
+        assert!(dict.add("xiang", "湘"));
+        sink.rewind();
+        look_up_with_dict("xiang", &dict, &mut sink);
+        assert!(sink.to_s().starts_with(" (1湘 2向"));
+        sink.rewind();
+        look_up_with_dict("xiang2", &dict, &mut sink);
+        assert_eq!(sink.to_s(), "向");
+    }
+}
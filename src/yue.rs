@@ -0,0 +1,181 @@
+// Copyright (c) 2021 Sam Blenny
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+#![forbid(unsafe_code)]
+
+use crate::autogen_yue;
+use crate::constants;
+use crate::dialects::{self, Utf8Str, Writer};
+use crate::lattice::{KeySource, Lattice};
+
+// Cantonese, typed as Jyutping. Cantonese has its own vocab tables
+// (autogen_yue, from vocab/yue.tsv), written in Traditional characters as in
+// Hong Kong. Search keys leave out the tone numbers, so "neihou" and
+// "nei5hou2" both give 你好. Tone numbers that do get typed narrow down the
+// homophones: "sik" gives 食 (sik6), but "sik1" gives 識. Since the digits
+// are taken by tones, there are no choice prompts, and each word takes the
+// first of its homophones that fits the typed tones.
+
+// Longest typed key: a search key with a tone number after each letter
+const KEY_SIZE_MAX: usize = 2 * autogen_yue::JYUTPING_SIZE_MAX;
+
+// Cost of an unmatched character, more than any number of words in a chunk,
+// so the lattice finds the path with the fewest unmatched characters, then
+// the fewest words.
+const UNMATCHED_COST: u32 = constants::BUF_SIZE as u32 + 1;
+
+/// Convert a whole string of Jyutping to Cantonese hanzi at once, as for
+/// batch conversion of text. Tone numbers (1-6) after syllables are optional,
+/// and pick between homophones. A space after a word just separates it from
+/// the next one, and text that doesn't match passes through.
+/// Side-effect: renders utf8 result string into buffer provided by Writer.
+/// Return: string slice of results backed by sink.
+pub fn translate_yue<'a>(jyutping: &str, sink: &'a mut impl Writer) -> &'a str {
+    let mut rest = jyutping;
+    let mut prev_word = false;
+    while !rest.is_empty() {
+        // Utf8Str only indexes the first BUF_SIZE characters, so go through
+        // long text in chunks
+        let chunk = Utf8Str::new(rest);
+        prev_word = write_chunk(&chunk, prev_word, sink);
+        let done = chunk
+            .char_slice(0, chunk.char_count)
+            .map_or(rest.len(), str::len);
+        rest = &rest[done..];
+    }
+    sink.to_s()
+}
+
+// Jyutping search keys in the Cantonese tables. Matches are (index in
+// autogen_yue::CIYU, index of the choice there), and None is unmatched text.
+struct JyutpingKeys;
+impl KeySource for JyutpingKeys {
+    type Token = Option<(usize, usize)>;
+
+    fn unmatched(&self, _: char) -> (Self::Token, u32) {
+        (None, UNMATCHED_COST)
+    }
+
+    fn key_window(&self, text: &str) -> usize {
+        text.chars().rev().take(KEY_SIZE_MAX).count()
+    }
+
+    fn find(&self, key: &str) -> Option<(Self::Token, u32)> {
+        find(key).map(|word| (Some(word), 1))
+    }
+}
+
+// Segment one chunk of text and write its hanzi. prev_word says whether the
+// chunk comes right after a word, which makes a space at its start a
+// separator.
+// Return: whether the chunk ends with a word
+fn write_chunk(text: &Utf8Str, prev_word: bool, sink: &mut impl Writer) -> bool {
+    let mut lattice = Lattice::new();
+    for _ in 0..text.char_count {
+        lattice.extend_with(text, 0, &JyutpingKeys, sink);
+    }
+    let mut prev_word = prev_word;
+    lattice.for_each_segment(|word, span| match word {
+        Some((i, choice)) => {
+            if let Some(ciyu) = autogen_yue::CIYU[i].split('\t').nth(choice) {
+                sink.write(ciyu);
+            }
+            prev_word = true;
+        }
+        None => {
+            if let Some(s) = text.char_slice(span.start, span.end) {
+                if !(prev_word && s == " ") {
+                    sink.write(s);
+                }
+            }
+            prev_word = false;
+        }
+    });
+    prev_word
+}
+
+// Find the word for a typed key, like "nei5hou" (tone numbers are optional).
+// Return: (index in autogen_yue::CIYU, index of the first choice there that
+// fits the typed tones), or None if nothing fits
+fn find(key: &str) -> Option<(usize, usize)> {
+    // Take out the tone numbers, which have to come right after a letter
+    let mut buf = [0; KEY_SIZE_MAX];
+    let mut n = 0;
+    let mut prev = b' ';
+    for b in key.bytes() {
+        if b.is_ascii_digit() {
+            if !matches!(b, b'1'..=b'6') || !prev.is_ascii_lowercase() {
+                return None;
+            }
+        } else {
+            *buf.get_mut(n)? = b;
+            n += 1;
+        }
+        prev = b;
+    }
+    let plain = core::str::from_utf8(&buf[..n]).ok()?;
+    let i = dialects::match_jyutping(plain)?;
+    let choice = autogen_yue::CIYU_TONED[i]
+        .split('\t')
+        .position(|toned| tones_fit(key, toned))?;
+    Some((i, choice))
+}
+
+// Do the tone numbers in typed agree with the reading toned, like "nei5hou2"?
+// Syllables typed without a tone number fit any tone.
+fn tones_fit(typed: &str, toned: &str) -> bool {
+    let mut typed = typed.bytes().peekable();
+    for b in toned.bytes() {
+        let tone_typed = typed.peek().is_some_and(u8::is_ascii_digit);
+        if b.is_ascii_digit() && !tone_typed {
+            continue;
+        }
+        if typed.next() != Some(b) {
+            return false;
+        }
+    }
+    typed.next().is_none()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dialects::BufWriter;
+
+    #[test]
+    fn jyutping_translates_to_cantonese() {
+        let check = |jyutping, expected| {
+            assert_eq!(translate_yue(jyutping, &mut BufWriter::new()), expected);
+        };
+        check("nei5hou2", "你好");
+        check("neihou", "你好");
+        check("ngodei jam2caa4", "我哋飲茶");
+        check("ngo5 soeng2 heoi3 hoeng1gong2!", "我想去香港！");
+        check("m4goi1, ngo jiu jat go caasiubaau", "唔該，我要一個叉燒包");
+        check("Hi 7", "Hi 7");
+        check("", "");
+        // Tone numbers pick between homophones, and the first one that fits
+        // is the default
+        check("sik", "食");
+        check("sik1", "識");
+        check("hai2", "喺");
+        check("sailou2", "細佬");
+        check("sailou", "細路");
+        // Tone numbers that fit no homophone pass through
+        check("sik3", "食3");
+    }
+
+    #[test]
+    fn jyutping_tables_are_sorted_and_match_their_keys() {
+        for i in 1..autogen_yue::JYUTPING.len() {
+            assert!(autogen_yue::JYUTPING[i] > autogen_yue::JYUTPING[i - 1]);
+        }
+        for (i, key) in autogen_yue::JYUTPING_KEYS.iter().enumerate() {
+            assert_eq!(dialects::match_jyutping(key), Some(i));
+        }
+        for &(key, ciyu) in autogen_yue::JYUTPING_CIYU_TEST_DATA {
+            let i = dialects::match_jyutping(key).unwrap();
+            assert!(autogen_yue::CIYU[i].split('\t').any(|c| c == ciyu));
+        }
+    }
+}
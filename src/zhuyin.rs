@@ -0,0 +1,410 @@
+// Copyright (c) 2021 Sam Blenny
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+#![forbid(unsafe_code)]
+
+use crate::autogen_hsk;
+use crate::constants;
+
+// Zhuyin (Bopomofo) input. Keys spelled with Zhuyin, like "ㄓㄨㄥㄍㄨㄛˊ",
+// turn into the same pinyin as everything else ("zhong1guo2"), so they can
+// match anywhere that pinyin with tone numbers can. Tone keys (ˉ ˊ ˇ ˋ ˙) end
+// a syllable and narrow down the candidates, like tone numbers with tone
+// input. Syllables without one (as first tone usually is) match any tone.
+//
+// Zhuyin keyboards put the symbols on ASCII keys. With the Dachen layout, the
+// standard one in Taiwan, "5j/ ej6" types ㄓㄨㄥ ㄍㄨㄛˊ. Since that takes
+// most of the digits, the shifted digits pick choices, as with tone input.
+
+// Longest pinyin that a Zhuyin key can turn into, tone numbers included
+pub const PINYIN_SIZE_MAX: usize = 2 * autogen_hsk::PINYIN_SIZE_MAX;
+
+// Room for a query's worth of keys after from_dachen (zhuyin takes 3 bytes)
+pub const DACHEN_BUF_SIZE: usize = 3 * constants::BUF_SIZE;
+
+// Initials, as (zhuyin, pinyin)
+const INITIALS: [(char, &str); 21] = [
+    ('ㄅ', "b"),
+    ('ㄆ', "p"),
+    ('ㄇ', "m"),
+    ('ㄈ', "f"),
+    ('ㄉ', "d"),
+    ('ㄊ', "t"),
+    ('ㄋ', "n"),
+    ('ㄌ', "l"),
+    ('ㄍ', "g"),
+    ('ㄎ', "k"),
+    ('ㄏ', "h"),
+    ('ㄐ', "j"),
+    ('ㄑ', "q"),
+    ('ㄒ', "x"),
+    ('ㄓ', "zh"),
+    ('ㄔ', "ch"),
+    ('ㄕ', "sh"),
+    ('ㄖ', "r"),
+    ('ㄗ', "z"),
+    ('ㄘ', "c"),
+    ('ㄙ', "s"),
+];
+
+// Medials
+const MEDIALS: [char; 3] = ['ㄧ', 'ㄨ', 'ㄩ'];
+
+// Finals, as (zhuyin, pinyin) for syllables without a medial. ㄝ on its own
+// is ê, which search keys spell as e.
+const FINALS: [(char, &str); 13] = [
+    ('ㄚ', "a"),
+    ('ㄛ', "o"),
+    ('ㄜ', "e"),
+    ('ㄝ', "e"),
+    ('ㄞ', "ai"),
+    ('ㄟ', "ei"),
+    ('ㄠ', "ao"),
+    ('ㄡ', "ou"),
+    ('ㄢ', "an"),
+    ('ㄣ', "en"),
+    ('ㄤ', "ang"),
+    ('ㄥ', "eng"),
+    ('ㄦ', "er"),
+];
+
+// Medial and final pairs, as (medial, final, pinyin after an initial, pinyin
+// on its own). Search keys spell ü as v, except after j, q, and x, where it
+// is u.
+const RHYMES: [(char, Option<char>, &str, &str); 25] = [
+    ('ㄧ', None, "i", "yi"),
+    ('ㄧ', Some('ㄚ'), "ia", "ya"),
+    ('ㄧ', Some('ㄛ'), "io", "yo"),
+    ('ㄧ', Some('ㄝ'), "ie", "ye"),
+    ('ㄧ', Some('ㄞ'), "iai", "yai"),
+    ('ㄧ', Some('ㄠ'), "iao", "yao"),
+    ('ㄧ', Some('ㄡ'), "iu", "you"),
+    ('ㄧ', Some('ㄢ'), "ian", "yan"),
+    ('ㄧ', Some('ㄣ'), "in", "yin"),
+    ('ㄧ', Some('ㄤ'), "iang", "yang"),
+    ('ㄧ', Some('ㄥ'), "ing", "ying"),
+    ('ㄨ', None, "u", "wu"),
+    ('ㄨ', Some('ㄚ'), "ua", "wa"),
+    ('ㄨ', Some('ㄛ'), "uo", "wo"),
+    ('ㄨ', Some('ㄞ'), "uai", "wai"),
+    ('ㄨ', Some('ㄟ'), "ui", "wei"),
+    ('ㄨ', Some('ㄢ'), "uan", "wan"),
+    ('ㄨ', Some('ㄣ'), "un", "wen"),
+    ('ㄨ', Some('ㄤ'), "uang", "wang"),
+    ('ㄨ', Some('ㄥ'), "ong", "weng"),
+    ('ㄩ', None, "v", "yu"),
+    ('ㄩ', Some('ㄝ'), "ve", "yue"),
+    ('ㄩ', Some('ㄢ'), "van", "yuan"),
+    ('ㄩ', Some('ㄣ'), "vn", "yun"),
+    ('ㄩ', Some('ㄥ'), "iong", "yong"),
+];
+
+// Tone keys, as (zhuyin, tone number)
+const TONES: [(char, &str); 5] = [('ˉ', "1"), ('ˊ', "2"), ('ˇ', "3"), ('ˋ', "4"), ('˙', "5")];
+
+// Dachen keyboard layout, as (ASCII key, zhuyin)
+const DACHEN: [(char, char); 41] = [
+    ('1', 'ㄅ'),
+    ('q', 'ㄆ'),
+    ('a', 'ㄇ'),
+    ('z', 'ㄈ'),
+    ('2', 'ㄉ'),
+    ('w', 'ㄊ'),
+    ('s', 'ㄋ'),
+    ('x', 'ㄌ'),
+    ('e', 'ㄍ'),
+    ('d', 'ㄎ'),
+    ('c', 'ㄏ'),
+    ('r', 'ㄐ'),
+    ('f', 'ㄑ'),
+    ('v', 'ㄒ'),
+    ('5', 'ㄓ'),
+    ('t', 'ㄔ'),
+    ('g', 'ㄕ'),
+    ('b', 'ㄖ'),
+    ('y', 'ㄗ'),
+    ('h', 'ㄘ'),
+    ('n', 'ㄙ'),
+    ('u', 'ㄧ'),
+    ('j', 'ㄨ'),
+    ('m', 'ㄩ'),
+    ('8', 'ㄚ'),
+    ('i', 'ㄛ'),
+    ('k', 'ㄜ'),
+    (',', 'ㄝ'),
+    ('9', 'ㄞ'),
+    ('o', 'ㄟ'),
+    ('l', 'ㄠ'),
+    ('.', 'ㄡ'),
+    ('0', 'ㄢ'),
+    ('p', 'ㄣ'),
+    (';', 'ㄤ'),
+    ('/', 'ㄥ'),
+    ('-', 'ㄦ'),
+    ('6', 'ˊ'),
+    ('3', 'ˇ'),
+    ('4', 'ˋ'),
+    ('7', '˙'),
+];
+
+// Map a key typed on a Dachen layout keyboard, after prev, to the zhuyin it
+// stands for. Space ends a syllable that doesn't have a tone key yet, so it
+// stands for first tone there, and is just a space elsewhere.
+pub fn dachen_key(c: char, prev: Option<char>) -> Option<char> {
+    if c == ' ' {
+        let open = prev.is_some_and(|p| is_zhuyin(p) && !is_tone(p));
+        return if open { Some('ˉ') } else { None };
+    }
+    DACHEN.iter().find(|&&(k, _)| k == c).map(|&(_, z)| z)
+}
+
+// Map the keys typed on a Dachen layout keyboard to zhuyin, writing the
+// result into buf. Other characters stay as they are. Characters that don't
+// fit get left out.
+pub fn from_dachen<'b>(keys: &str, buf: &'b mut [u8; DACHEN_BUF_SIZE]) -> &'b str {
+    let mut n = 0;
+    let mut prev = None;
+    for c in keys.chars() {
+        let z = dachen_key(c, prev).unwrap_or(c);
+        prev = Some(z);
+        match buf.get_mut(n..n + z.len_utf8()) {
+            Some(dest) => n += z.encode_utf8(dest).len(),
+            None => break,
+        }
+    }
+    core::str::from_utf8(&buf[..n]).unwrap_or("")
+}
+
+// Is c a zhuyin symbol or tone key?
+pub fn is_zhuyin(c: char) -> bool {
+    ('\u{3105}'..='\u{3129}').contains(&c) || is_tone(c)
+}
+
+// Is c a zhuyin tone key?
+fn is_tone(c: char) -> bool {
+    TONES.iter().any(|&(t, _)| t == c)
+}
+
+// Does key have a zhuyin tone key in it? Those filter candidates the same way
+// as tone numbers.
+pub fn has_tone(key: &str) -> bool {
+    key.chars().any(is_tone)
+}
+
+// Zhuyin syllable being put together, with the index of each part in INITIALS,
+// MEDIALS, and FINALS
+#[derive(Copy, Clone, Default)]
+struct Syllable {
+    initial: Option<usize>,
+    medial: Option<usize>,
+    fin: Option<usize>,
+}
+impl Syllable {
+    // How far along the syllable is (0 for empty, 3 for up to the final).
+    // Parts have to come in order, so a part that isn't past that starts a
+    // new syllable.
+    fn stage(&self) -> u8 {
+        match (self.initial, self.medial, self.fin) {
+            (_, _, Some(_)) => 3,
+            (_, Some(_), None) => 2,
+            (Some(_), None, None) => 1,
+            (None, None, None) => 0,
+        }
+    }
+
+    // Spell the syllable in pinyin.
+    // Return: None if it isn't a syllable or doesn't fit in out.
+    fn write(&self, out: &mut Out) -> Option<()> {
+        let initial = self.initial.map(|i| INITIALS[i].1);
+        let fin = self.fin.map(|i| FINALS[i].0);
+        let rhyme = match (self.medial, fin) {
+            (Some(m), _) => {
+                let &(_, _, with, alone) = RHYMES
+                    .iter()
+                    .find(|&&(medial, f, _, _)| medial == MEDIALS[m] && f == fin)?;
+                match initial {
+                    Some("j" | "q" | "x") if with.starts_with('v') => {
+                        out.push(initial?)?;
+                        out.push("u")?;
+                        return out.push(&with[1..]);
+                    }
+                    Some(_) => with,
+                    None => alone,
+                }
+            }
+            (None, Some(_)) => self.fin.map(|i| FINALS[i].1)?,
+            // Initials that make a syllable on their own, like ㄓ for zhi
+            (None, None) => match initial? {
+                "zh" | "ch" | "sh" | "r" | "z" | "c" | "s" => "i",
+                _ => return None,
+            },
+        };
+        out.push(initial.unwrap_or(""))?;
+        out.push(rhyme)
+    }
+}
+
+// Bounded output buffer for the pinyin
+struct Out<'b> {
+    buf: &'b mut [u8; PINYIN_SIZE_MAX],
+    len: usize,
+}
+impl Out<'_> {
+    fn push(&mut self, s: &str) -> Option<()> {
+        let dest = self.buf.get_mut(self.len..self.len + s.len())?;
+        dest.copy_from_slice(s.as_bytes());
+        self.len += s.len();
+        Some(())
+    }
+}
+
+// Turn a key spelled in zhuyin, like "ㄓㄨㄥㄍㄨㄛˊ", into pinyin with a tone
+// number after each syllable that has a tone key, like "zhongguo2".
+// Return: pinyin, or None if key isn't all zhuyin syllables.
+pub fn to_pinyin<'b>(key: &str, buf: &'b mut [u8; PINYIN_SIZE_MAX]) -> Option<&'b str> {
+    if !key.chars().next().is_some_and(is_zhuyin) {
+        return None;
+    }
+    let mut out = Out { buf, len: 0 };
+    let mut syl = Syllable::default();
+    for c in key.chars() {
+        if let Some(i) = INITIALS.iter().position(|&(z, _)| z == c) {
+            if syl.stage() > 0 {
+                syl.write(&mut out)?;
+            }
+            syl = Syllable {
+                initial: Some(i),
+                ..Default::default()
+            };
+        } else if let Some(i) = MEDIALS.iter().position(|&z| z == c) {
+            if syl.stage() >= 2 {
+                syl.write(&mut out)?;
+                syl = Syllable::default();
+            }
+            syl.medial = Some(i);
+        } else if let Some(i) = FINALS.iter().position(|&(z, _)| z == c) {
+            if syl.stage() >= 3 {
+                syl.write(&mut out)?;
+                syl = Syllable::default();
+            }
+            syl.fin = Some(i);
+        } else {
+            // Tone keys end the syllable
+            let &(_, tone) = TONES.iter().find(|&&(z, _)| z == c)?;
+            if syl.stage() == 0 {
+                return None;
+            }
+            syl.write(&mut out)?;
+            out.push(tone)?;
+            syl = Syllable::default();
+        }
+    }
+    if syl.stage() > 0 {
+        syl.write(&mut out)?;
+    }
+    let len = out.len;
+    core::str::from_utf8(&out.buf[..len]).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dialects::{
+        look_up, look_up_segments_with_options, look_up_with_options, BufWriter, LookupOptions,
+        Writer,
+    };
+    use crate::segment::SegmentList;
+
+    fn look_up_with_zhuyin(query: &str, sink: &mut BufWriter) {
+        let opts = LookupOptions {
+            zhuyin: true,
+            ..Default::default()
+        };
+        look_up_with_options(query, &opts, sink);
+    }
+
+    #[test]
+    fn zhuyin_spells_pinyin_syllables() {
+        let check = |key, expected: Option<&str>| {
+            let mut buf = [0; PINYIN_SIZE_MAX];
+            assert_eq!(to_pinyin(key, &mut buf), expected);
+        };
+        check("ㄓㄨㄥㄍㄨㄛˊ", Some("zhongguo2"));
+        check("ㄒㄧㄤˇ", Some("xiang3"));
+        check("ㄨㄛˇ", Some("wo3"));
+        check("ㄧ", Some("yi"));
+        check("ㄩㄢˊ", Some("yuan2"));
+        check("ㄐㄩ", Some("ju"));
+        check("ㄒㄩㄝˊ", Some("xue2"));
+        check("ㄋㄩˇ", Some("nv3"));
+        check("ㄌㄩㄝˋ", Some("lve4"));
+        check("ㄑㄩㄥˊ", Some("qiong2"));
+        check("ㄓ", Some("zhi"));
+        check("ㄦˊ", Some("er2"));
+        check("ㄉㄜ˙", Some("de5"));
+        check("ㄏㄜˊㄏㄜ", Some("he2he"));
+        check("ㄅ", None);
+        check("ˊ", None);
+        check("ㄓong", None);
+        check("zhong", None);
+        assert_eq!(dachen_key('5', None), Some('ㄓ'));
+        assert_eq!(dachen_key('6', Some('ㄓ')), Some('ˊ'));
+        assert_eq!(dachen_key(' ', Some('ㄓ')), Some('ˉ'));
+        assert_eq!(dachen_key(' ', Some('ˊ')), None);
+        assert_eq!(dachen_key(' ', None), None);
+        assert_eq!(dachen_key('!', None), None);
+        assert!(has_tone("ㄍㄨㄛˊ"));
+        assert!(!has_tone("ㄍㄨㄛ"));
+    }
+    #[test]
+    fn zhuyin_keys_look_up_like_pinyin() {
+        let mut sink = BufWriter::new();
+        look_up("ㄓㄨㄥㄍㄨㄛˊ", &mut sink);
+        assert_eq!(sink.to_s(), "中国");
+        // Tone keys narrow down the candidates
+        sink.rewind();
+        look_up("ㄏㄜ", &mut sink);
+        assert_eq!(sink.to_s(), " (1和 2河 3喝) ");
+        sink.rewind();
+        look_up("ㄏㄜˊ", &mut sink);
+        assert_eq!(sink.to_s(), " (1和 2河) ");
+        sink.rewind();
+        look_up("ㄋㄩˇ", &mut sink);
+        assert_eq!(sink.to_s(), "女");
+        sink.rewind();
+        look_up("ㄏㄜ2", &mut sink);
+        assert_eq!(sink.to_s(), "河");
+    }
+
+    #[test]
+    fn dachen_keys_type_zhuyin() {
+        let mut sink = BufWriter::new();
+        look_up_with_zhuyin("5j/eji6", &mut sink);
+        assert_eq!(sink.to_s(), "中国");
+        // Space is first tone after a syllable, and picks a choice after that
+        sink.rewind();
+        look_up_with_zhuyin("5j/ eji6", &mut sink);
+        assert_eq!(sink.to_s(), "中国");
+        sink.rewind();
+        look_up_with_zhuyin("ck6", &mut sink);
+        assert_eq!(sink.to_s(), " (1和 2河) ");
+        sink.rewind();
+        look_up_with_zhuyin("ck6 ", &mut sink);
+        assert_eq!(sink.to_s(), "和");
+        // Shifted digits pick choices
+        sink.rewind();
+        look_up_with_zhuyin("ck@", &mut sink);
+        assert_eq!(sink.to_s(), "河");
+        sink.rewind();
+        look_up_with_zhuyin("ck!", &mut sink);
+        assert_eq!(sink.to_s(), "和");
+        // Segments take the zhuyin rather than the keys
+        let opts = LookupOptions {
+            zhuyin: true,
+            ..Default::default()
+        };
+        let mut segs = SegmentList::new();
+        look_up_segments_with_options("ㄏㄜ@", &opts, &mut segs);
+        assert_eq!(segs.get(0).and_then(|s| s.text()), Some("河"));
+    }
+}
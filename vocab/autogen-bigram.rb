@@ -0,0 +1,206 @@
+#!/usr/bin/ruby
+# coding: utf-8
+# Copyright (c) 2021 Sam Blenny
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+#
+# Generates the word bigram table that the "bigram" cargo feature uses to
+# predict the next 词语 after committed text. Lines of the corpus get split
+# into vocab words, and each word keeps the words that most often follow it.
+require 'erb'
+require 'set'
+
+RUST_FILE = "../src/autogen_bigram.rs"
+CORPUS_FILE = "bigram-corpus.txt"
+# Same list as autogen-hsk.rb
+WORD_FILES = [
+  "hsk1.tsv",
+  "hsk1-extra.tsv",
+  "hsk2.tsv",
+  "hsk2-extra.tsv",
+  "hsk3.tsv",
+  "hsk3-extra.tsv",
+  "hsk4.tsv",
+  "hsk4-extra.tsv",
+  "hsk5.tsv",
+  "hsk5-extra.tsv",
+]
+NEXT_LIMIT = 9
+
+# Returns array: [[ciyu, pinyin, ...], ...] (see autogen-hsk.rb)
+def read_tsv(file)
+  File.read(file).lines
+    .select { |n| !n.start_with?("#") && n.include?("\t") }
+    .map { |n| n.chomp.split("\t") }
+end
+
+# Normalize pinyin to a search key, as in autogen-hsk.rb
+TR_FROM = " '-<>`abcdefghijklmnopqrstuwxyzàáèéìíòóùúüāēěīōūǎǐǒǔǚǜǹ"
+TR_TO   = " '-<>`abcdefghijklmnopqrstuwxyzaaeeiioouuvaeeiouaiouvvn"
+# CAUTION: "-" must go last or String.delete will interpret it as indicating a range
+ELIDE   = " '-"
+def normalize(pinyin)
+  pinyin.downcase.delete(ELIDE).tr(TR_FROM, TR_TO)
+end
+
+# Search key of each vocab word. Words with more than one reading keep the
+# first one, as the corpus doesn't say which reading it means.
+pinyin_of = {}
+for wf in WORD_FILES
+  for ciyu, pinyin in read_tsv(wf)
+    pinyin_of[ciyu] ||= normalize(pinyin)
+  end
+end
+word_size_max = pinyin_of.keys.map(&:size).max
+
+# Split line into vocab words with the fewest characters left over, then the
+# fewest words, as to_pinyin() does.
+# Returns array: [[text, is_word], ...]
+def segment(line, pinyin_of, word_size_max)
+  cost = [[0, 0]]
+  start = [0]
+  word = [false]
+  for stop in 1..line.size
+    cost[stop] = [cost[stop - 1][0] + 1, cost[stop - 1][1]]
+    start[stop] = stop - 1
+    word[stop] = false
+    [word_size_max, stop].min.downto(1) do |len|
+      next if !pinyin_of[line[stop - len, len]]
+      path = [cost[stop - len][0], cost[stop - len][1] + 1]
+      if (path <=> cost[stop]) < 0
+        cost[stop] = path
+        start[stop] = stop - len
+        word[stop] = true
+      end
+    end
+  end
+  pieces = []
+  stop = line.size
+  while stop > 0
+    pieces.unshift([line[start[stop]...stop], word[stop]])
+    stop = start[stop]
+  end
+  pieces
+end
+
+# Count the words that follow each word. Hashes keep insertion order, so
+# ties keep the order the pairs first turned up in.
+# next_of is hash of {prev => {next => count, ...}, ...}
+next_of = {}
+pair_count = 0
+unmatched = Set.new
+lines = File.read(CORPUS_FILE).lines.map(&:chomp).select { |n| !n.empty? && !n.start_with?("#") }
+for line in lines
+  prev = nil
+  for text, is_word in segment(line, pinyin_of, word_size_max)
+    if !is_word
+      unmatched << text if text =~ /\p{Han}/
+      prev = nil
+      next
+    end
+    if prev
+      counts = (next_of[prev] ||= {})
+      counts[text] = (counts[text] || 0) + 1
+      pair_count += 1
+    end
+    prev = text
+  end
+end
+
+# Keep the most common words after each word
+next_of.each do |prev, counts|
+  next_of[prev] = counts.each_with_index.sort_by { |(_, n), i| [-n, i] }.map(&:first).first(NEXT_LIMIT)
+end
+
+# Murmur3 hash function; key is UTF-8 string (max 4 bytes/char) so take each
+# ord(char) as one u32 block. Same as autogen-hsk.rb.
+# Credits: Derived from MurmurHash3.cpp (public domain) by Austin Appleby.
+def murmur3(key, seed)
+  def rotl32(x, r)
+    ((x << r) & 0xffff_ffff) | (x >> (32 - r))
+  end
+  h = seed
+  for c in key.chars
+    k = c.ord
+    k = (k * 0xcc9e2d51) & 0xffff_ffff
+    k = rotl32(k, 15)
+    k = (k * 0x1b873593) & 0xffff_ffff
+    h = h ^ k
+    h = rotl32(h, 13)
+    h = ((h * 5) + 0xe6546b64) & 0xffff_ffff
+  end
+  # Length in bytes, as on the rust side
+  h = h ^ key.bytesize
+  # Finalize with avalanche
+  h = h ^ (h >> 16)
+  h = (h * 0x85ebca6b) & 0xffff_ffff
+  h = h ^ (h >> 13)
+  h = (h * 0xc2b2ae35) & 0xffff_ffff
+  h ^ (h >> 16)
+end
+
+# Calculate murmur3(prev) and sort the words in hash order
+m3_seed = 0
+prev_keys = next_of.keys
+prev_m3 = prev_keys.map { |k| murmur3(k, m3_seed) }
+abort "Error: murmur3 collision in bigram words" if Set.new(prev_m3).size != prev_m3.size
+prev_m3, prev_keys = prev_m3.zip(prev_keys).sort.transpose
+next_ciyu = prev_keys.map { |k| next_of[k].map { |w, _| w } }
+next_pinyin = prev_keys.map { |k| next_of[k].map { |w, _| pinyin_of[w] } }
+next_count = prev_keys.map { |k| next_of[k].map { |_, n| n } }
+
+# Print statistics
+puts "\nCorpus lines: #{lines.size}"
+puts "Word pairs: #{pair_count}"
+puts "Words with a next word: #{prev_keys.size}"
+puts "Characters outside vocab words: #{unmatched.to_a.join(" ")}" if !unmatched.empty?
+
+# Ask about updating the Rust array source code
+puts "\nPreparing to generate rust source code..."
+print "This will overwrite #{RUST_FILE}\nDo you want to continue? [y/N] "
+abort "no changes made" if !["y", "Y"].include? gets.chomp
+
+# Generate rust source code with bigram arrays
+File.open(RUST_FILE, "w") { |rf|
+  TEMPLATE = <<~RUST
+    // This file is automatically generated. DO NOT MAKE EDITS HERE!
+    // To make changes, see ../vocab/autogen-bigram.rb
+    //
+    // Copyright (c) 2021 Sam Blenny
+    // SPDX-License-Identifier: Apache-2.0 OR MIT
+    //
+    #![forbid(unsafe_code)]
+    #![allow(dead_code)]
+
+    // The longest 词语 in the vocab has WORD_SIZE_MAX characters
+    pub const WORD_SIZE_MAX: usize = <%= word_size_max %>;
+
+    // u32 constants are murmur3 hash of the 词语 that have a next word, with
+    // the same seed as autogen_hsk::MURMUR3_SEED
+    pub const MURMUR3_SEED: u32 = <%= m3_seed %>;
+    pub static PREV: &[u32] = &[
+    <% prev_m3.zip(prev_keys).each do |m3,k| %>    <%= "0x%08x, // %s" % [m3, k] %>
+    <% end %>];
+
+    // 词语 in the same order as PREV
+    pub static PREV_KEYS: &[&str] = &[
+    <% prev_keys.each do |k| %>    "<%= k %>",
+    <% end %>];
+
+    // Words that follow each word of PREV_KEYS in the corpus (joined by
+    // "\\t"), most common first
+    pub static NEXT: &[&str] = &[
+    <% next_ciyu.zip(prev_keys).each do |n,k| %>    "<%= n.join("\t") %>",  // <%= k %>
+    <% end %>];
+
+    // Search keys of the words in NEXT, in the same order
+    pub static NEXT_PINYIN: &[&str] = &[
+    <% next_pinyin.each do |p| %>    "<%= p.join("\t") %>",
+    <% end %>];
+
+    // How many times each word in NEXT follows its word in the corpus
+    pub static NEXT_COUNT: &[&[u32]] = &[
+    <% next_count.zip(prev_keys).each do |n,k| %>    &[<%= n.join(", ") %>],  // <%= k %>
+    <% end %>];
+    RUST
+  rf.puts ERB.new(TEMPLATE).result(binding)
+}
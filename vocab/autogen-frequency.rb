@@ -0,0 +1,100 @@
+#!/usr/bin/ruby
+# coding: utf-8
+# Copyright (c) 2021 Sam Blenny
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+#
+# Generates the frequency weights that autogen-hsk.rb uses to rank choices,
+# from the word counts in the dict.txt of the jieba Chinese word segmentation
+# library, as shipped in src/data/ of the jieba-rs crate (version 0.7.4,
+# https://github.com/messense/jieba-rs, MIT License, see ../CREDITS.md).
+#
+# Usage: ruby autogen-frequency.rb path/to/jieba-rs/src/data/dict.txt
+require 'set'
+
+FREQUENCY_FILE = "frequency.tsv"
+# Same list as autogen-hsk.rb
+WORD_FILES = [
+  "hsk1.tsv",
+  "hsk1-extra.tsv",
+  "hsk2.tsv",
+  "hsk2-extra.tsv",
+  "hsk3.tsv",
+  "hsk3-extra.tsv",
+  "hsk4.tsv",
+  "hsk4-extra.tsv",
+  "hsk5.tsv",
+  "hsk5-extra.tsv",
+]
+# Weight of 词语 that aren't in dict.txt
+MISSING_WEIGHT = 1
+
+# Returns array: [[ciyu, pinyin, ...], ...] (see autogen-hsk.rb)
+def read_tsv(file)
+  File.read(file).lines
+    .select { |n| !n.start_with?("#") && n.include?("\t") }
+    .map { |n| n.chomp.split("\t") }
+end
+
+# Normalize pinyin to a search key, as in autogen-hsk.rb
+TR_FROM = " '-<>`abcdefghijklmnopqrstuwxyzàáèéìíòóùúüāēěīōūǎǐǒǔǚǜǹ"
+TR_TO   = " '-<>`abcdefghijklmnopqrstuwxyzaaeeiioouuvaeeiouaiouvvn"
+# CAUTION: "-" must go last or String.delete will interpret it as indicating a range
+ELIDE   = " '-"
+def normalize(pinyin)
+  pinyin.downcase.delete(ELIDE).tr(TR_FROM, TR_TO)
+end
+
+abort "Usage: ruby autogen-frequency.rb path/to/jieba/dict.txt" if ARGV.size != 1
+
+# Lines of dict.txt are like "果汁 188 n" for word, count, and part of speech
+count_of = {}
+for line in File.read(ARGV[0]).lines
+  word, count = line.split(" ")
+  count_of[word] = Integer(count) if count
+end
+
+# One line for each (词语, search key) pair of the word files, in word file
+# order. 词语 with more than one reading get the same count for each, since
+# dict.txt doesn't say which reading it counted.
+entries = []
+for wf in WORD_FILES
+  for ciyu, pinyin in read_tsv(wf)
+    entries << [ciyu, normalize(pinyin)]
+  end
+end
+entries.uniq!
+missing = entries.map(&:first).uniq.select { |ciyu| !count_of[ciyu] }
+
+HEADER = <<~EOF
+  # Frequency weights for vocab entries
+  #
+  # Generated by autogen-frequency.rb from the word counts in the dict.txt of
+  # the jieba Chinese word segmentation library, as shipped in the jieba-rs
+  # crate (version 0.7.4, https://github.com/messense/jieba-rs, MIT License).
+  # Edit the script or the word files rather than this file.
+  #
+  # Format: 词语<TAB>normalized pinyin<TAB>weight
+  #
+  # Weights are the jieba counts, which add up to about #{count_of.values.sum / 1_000_000} million. They set
+  # the order of homophone choices (highest weight first, so that gets picked by
+  # space) and of syllable-initial abbreviation choices like "w" or "gzh", which
+  # keep only the 9 highest weight choices. 词语 with more than one reading get
+  # the same weight for each one. 词语 that aren't in dict.txt (mostly names and
+  # titles) get a weight of #{MISSING_WEIGHT}.
+
+EOF
+
+puts "Entries: #{entries.size}"
+puts "词语 not in dict.txt: #{missing.size}"
+puts "  #{missing.join(" ")}"
+
+# Ask about updating the frequency file
+print "\nThis will overwrite #{FREQUENCY_FILE}\nDo you want to continue? [y/N] "
+abort "no changes made" if !["y", "Y"].include? $stdin.gets.chomp
+File.open(FREQUENCY_FILE, "w") do |f|
+  f << HEADER
+  for ciyu, pinyin in entries
+    f << "#{ciyu}\t#{pinyin}\t#{count_of.fetch(ciyu, MISSING_WEIGHT)}\n"
+  end
+end
+puts "Done"
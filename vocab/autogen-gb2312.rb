@@ -0,0 +1,174 @@
+#!/usr/bin/ruby
+# coding: utf-8
+# Copyright (c) 2021 Sam Blenny
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+#
+# Single character counterpart of autogen-hsk.rb: generates the GB 2312
+# character tables that the "gb2312" cargo feature falls back to for pinyin
+# that doesn't match any 词语.
+require 'erb'
+require 'set'
+
+RUST_FILE = "../src/autogen_gb2312.rs"
+CHAR_FILE = "gb2312.tsv"
+HANT_FILE = "zh-hant.tsv"
+
+# Returns array: [[hanzi, pinyin, frequency], ...]
+# Note: The `select {..."#"..."\t"}` filters out blank lines and comments
+def read_tsv(file)
+  File.read(file).lines
+    .select { |n| !n.start_with?("#") && n.include?("\t") }
+    .map { |n| n.chomp.split("\t") }
+end
+
+# Normalize pinyin to lowercase ASCII, as in autogen-hsk.rb. The tone marks
+# on n (for 嗯) only turn up in single character readings.
+TR_FROM = "àáèéìíòóùúüāēěīōūǎǐǒǔǖǘǚǜńňǹ"
+TR_TO   = "aaeeiioouuvaeeiouaiouvvvvnnn"
+def normalize(pinyin)
+  pinyin.downcase.tr(TR_FROM, TR_TO)
+end
+
+# Tone numbers for the tone marks on pinyin vowels
+TONE_MARKS = ["āēīōūǖ", "áéíóúǘń", "ǎěǐǒǔǚň", "àèìòùǜǹ"]
+TONE_OF = {}
+TONE_MARKS.each_with_index { |marks, k| marks.each_char { |c| TONE_OF[c] = k + 1 } }
+
+# Traditional form of each character, for ZI_HANT. The first line for a
+# character gives its default form (see zh-hant.tsv).
+hant_of = {}
+for simplified, traditional, pinyin in read_tsv(HANT_FILE)
+  next if simplified.size != 1 || pinyin
+  hant_of[simplified] ||= traditional
+end
+
+# Merge the characters read as each syllable, keeping every reading of a
+# character that has more than one.
+# choices_of is hash of {key => [[hanzi, frequency, [toned, ...]], ...], ...}
+choices_of = {}
+char_count = 0
+pinyin_zi_test_data = []
+for hanzi, pinyin, frequency in read_tsv(CHAR_FILE)
+  key = normalize(pinyin)
+  abort "Error: #{CHAR_FILE}: #{hanzi}: pinyin \"#{pinyin}\" is not one syllable" if key !~ /\A[a-z]+\z/
+  tone = pinyin.chars.map { |c| TONE_OF[c] }.compact.first || 5
+  toned = "#{key}#{tone}"
+  pinyin_zi_test_data << [key, hanzi]
+  choices = (choices_of[key] ||= [])
+  same = choices.find { |h, _, _| h == hanzi }
+  if same
+    same[2] << toned if !same[2].include?(toned)
+  else
+    choices << [hanzi, frequency.to_i, [toned]]
+    char_count += 1
+  end
+end
+
+# Sort the choices for each syllable by descending frequency. Ties keep the
+# order of the file (sort_by alone isn't stable).
+for key, choices in choices_of
+  choices_of[key] = choices.each_with_index.sort_by { |(_, f, _), i| [-f, i] }.map(&:first)
+end
+keys = choices_of.keys
+
+# Murmur3 hash function; key is UTF-8 string (max 4 bytes/char) so take each
+# ord(char) as one u32 block. Same as autogen-hsk.rb.
+# Credits: Derived from MurmurHash3.cpp (public domain) by Austin Appleby.
+def murmur3(key, seed)
+  def rotl32(x, r)
+    ((x << r) & 0xffff_ffff) | (x >> (32 - r))
+  end
+  h = seed
+  for c in key.chars
+    k = c.ord
+    k = (k * 0xcc9e2d51) & 0xffff_ffff
+    k = rotl32(k, 15)
+    k = (k * 0x1b873593) & 0xffff_ffff
+    h = h ^ k
+    h = rotl32(h, 13)
+    h = ((h * 5) + 0xe6546b64) & 0xffff_ffff
+  end
+  # Length in bytes, as on the rust side (same as characters for ASCII keys)
+  h = h ^ key.bytesize
+  # Finalize with avalanche
+  h = h ^ (h >> 16)
+  h = (h * 0x85ebca6b) & 0xffff_ffff
+  h = h ^ (h >> 13)
+  h = (h * 0xc2b2ae35) & 0xffff_ffff
+  h ^ (h >> 16)
+end
+
+# Calculate murmur3(key) and sort the keys in hash order
+m3_seed = 0
+keys_m3 = keys.map { |k| murmur3(k, m3_seed) }
+abort "Error: murmur3 collision in pinyin syllables" if Set.new(keys_m3).size != keys_m3.size
+keys_m3, keys = keys_m3.zip(keys).sort.transpose
+zi = keys.map { |k| choices_of[k].map { |h, _, _| h } }
+zi_weight = keys.map { |k| choices_of[k].map { |_, f, _| f } }
+zi_toned = keys.map { |k| choices_of[k].map { |_, _, t| t.join("|") } }
+zi_hant = zi.map { |choices| choices.map { |h| hant_of[h] || h } }
+
+# Print statistics
+puts "\nCharacters: #{char_count}"
+puts "Unique pinyin syllables: #{keys.size}"
+
+# Ask about updating the Rust array source code
+puts "\nPreparing to generate rust source code..."
+print "This will overwrite #{RUST_FILE}\nDo you want to continue? [y/N] "
+abort "no changes made" if !["y", "Y"].include? gets.chomp
+
+# Generate rust source code with character and pinyin arrays
+File.open(RUST_FILE, "w") { |rf|
+  TEMPLATE = <<~RUST
+    // This file is automatically generated. DO NOT MAKE EDITS HERE!
+    // To make changes, see ../vocab/autogen-gb2312.rb
+    //
+    // Copyright (c) 2021 Sam Blenny
+    // SPDX-License-Identifier: Apache-2.0 OR MIT
+    //
+    #![forbid(unsafe_code)]
+    #![allow(dead_code)]
+
+    // u32 constants are murmur3 hash of the pinyin syllables that GB 2312
+    // characters are read as, with the same seed as autogen_hsk::MURMUR3_SEED
+    pub const MURMUR3_SEED: u32 = <%= m3_seed %>;
+    pub static PINYIN: &[u32] = &[
+    <% keys_m3.zip(keys).each do |m3,k| %>    <%= "0x%08x, // %s" % [m3, k] %>
+    <% end %>];
+
+    // Pinyin syllables in the same order as PINYIN
+    pub static PINYIN_KEYS: &[&str] = &[
+    <% keys.each do |k| %>    "<%= k %>",
+    <% end %>];
+
+    // Characters read as each syllable (joined by "\\t"), sorted by descending
+    // frequency, so the first choice is the most common character
+    pub static ZI: &[&str] = &[
+    <% zi.zip(keys).each do |h,k| %>    "<%= h.join("\t") %>",  // <%= k %>
+    <% end %>];
+
+    // Frequency weights for the choices in ZI, in the same order
+    pub static ZI_WEIGHT: &[&[u32]] = &[
+    <% zi_weight.zip(keys).each do |w,k| %>    &[<%= w.join(", ") %>],  // <%= k %>
+    <% end %>];
+
+    // Choices in ZI with a tone number after the syllable: 1-4, or 5 for
+    // neutral tone. Choices with more than one reading list them all, joined
+    // by "|".
+    pub static ZI_TONED: &[&str] = &[
+    <% zi_toned.each do |t| %>    "<%= t.join("\t") %>",
+    <% end %>];
+
+    // Choices in ZI spelled with traditional characters, in the same order
+    pub static ZI_HANT: &[&str] = &[
+    <% zi_hant.each do |h| %>    "<%= h.join("\t") %>",
+    <% end %>];
+
+    // Tuples are (pinyin syllable, 字) for each line of gb2312.tsv
+    #[cfg(test)]
+    pub static PINYIN_ZI_TEST_DATA: &[(&str, &str)] = &[
+    <% pinyin_zi_test_data.each do |k, h| %>    ("<%= k %>", "<%= h %>"),
+    <% end %>];
+    RUST
+  rf.puts ERB.new(TEMPLATE).result(binding)
+}
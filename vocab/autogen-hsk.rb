@@ -19,6 +19,12 @@ WORD_FILES = [
   "hsk5-extra.tsv",
 ]
 PUNCTUATION_FILE = "pinyin-punctuation.tsv"
+FREQUENCY_FILE = "frequency.tsv"
+HANT_FILE = "zh-hant.tsv"
+GB2312_FILE = "gb2312.tsv"
+SYLLABLE_FILE = "../src/syllable.rs"
+DEFAULT_WEIGHT = 1
+ABBREV_CHOICE_LIMIT = 9
 
 # Returns array: [[ciyu, pinyin], [ciyu, pinyin], ...] (see note 2)
 # Notes:
@@ -51,6 +57,80 @@ def normalize(pinyin)
   return n
 end
 
+# Legal pinyin syllables, for splitting vocab pinyin to find syllable initials
+# and tones. They come from the table in SYLLABLE_FILE that the preedit uses,
+# so that both split pinyin the same way.
+SYLLABLES = Set.new(File.read(SYLLABLE_FILE)[/const SYLLABLES: \[&str; \d+\] = \[(.*?)\];/m, 1]
+  .to_s.scan(/"([a-z]+)"/).flatten)
+abort "Error: Can't find the SYLLABLES table in #{SYLLABLE_FILE}" if SYLLABLES.empty?
+
+# Split a run of normalized pinyin into syllables, trying longest syllables
+# first and backtracking. Syllables that start with a vowel only come first in
+# a run (pinyin puts an apostrophe before them otherwise), except for erhua
+# "er" or a final "r".
+# Returns array of syllables, or nil if the pinyin doesn't split.
+def syllables(pinyin, first = true)
+  return [] if pinyin.empty?
+  [6, pinyin.size].min.downto(1) do |n|
+    syl = pinyin[0, n]
+    ok = SYLLABLES.include?(syl) && (first || !"aoe".include?(syl[0]) || syl == "er")
+    ok ||= (syl == "r" && !first && n == pinyin.size)
+    next if !ok
+    rest = syllables(pinyin[n..-1], false)
+    return [syl] + rest if rest
+  end
+  nil
+end
+
+# Syllable-initial abbreviations of pinyin, like ["shxym", "sxym"] for
+# "shǎngxīn-yuèmù". Initials zh, ch, and sh can be typed in full or as just
+# their first letter. Erhua "r" gets left out.
+# Returns array of abbreviations (empty if the pinyin doesn't split)
+def abbreviations(pinyin)
+  runs = pinyin.downcase.tr(TR_FROM, TR_TO).split(/[^a-z]+/).reject(&:empty?)
+  syls = runs.map { |run| syllables(run) }
+  return [] if syls.empty? || syls.include?(nil)
+  initials = syls.flatten.reject { |syl| syl == "r" }
+    .map { |syl| ["zh", "ch", "sh"].include?(syl[0, 2]) ? [syl[0, 2], syl[0]] : [syl[0]] }
+  initials[0].product(*initials[1..-1]).map(&:join)
+end
+
+# Tone numbers for the tone marks on pinyin vowels
+TONE_MARKS = ["āēīōūǖ", "áéíóúǘ", "ǎěǐǒǔǚ", "àèìòùǜǹ"]
+TONE_OF = {}
+TONE_MARKS.each_with_index { |marks, k| marks.each_char { |c| TONE_OF[c] = k + 1 } }
+
+# Normalized pinyin with a tone number after each syllable, like "xiang3fa3"
+# for "xiǎngfǎ". Syllables without a tone mark get 5 (neutral tone), unless
+# the pinyin has no tone marks at all, in which case they get 0 (tone not
+# known). Erhua "r" goes before the tone number of the syllable it joins.
+# Apostrophes split syllables, as in "kě'ài".
+# Returns normalized pinyin without tone numbers if the pinyin doesn't split.
+def toned(pinyin)
+  marked = pinyin.downcase.delete(ELIDE)
+  plain = normalize(pinyin)
+  known = marked.chars.any? { |c| TONE_OF[c] }
+  out = ""
+  pos = 0
+  runs = pinyin.split("'").map { |part| normalize(part).scan(/[a-z]+|[^a-z]+/) }.flatten
+  for run in runs
+    syls = run =~ /[a-z]/ ? syllables(run) : [run]
+    return plain if !syls
+    for syl in syls
+      tone = marked[pos, syl.size].chars.map { |c| TONE_OF[c] }.compact.first
+      pos += syl.size
+      if run !~ /[a-z]/
+        out << syl
+      elsif syl == "r"
+        out.insert(-2, "r")
+      else
+        out << syl << (tone || (known ? 5 : 0)).to_s
+      end
+    end
+  end
+  out
+end
+
 # Check integrity and coverage of the character transposition table. The
 # map/reduce uses set algebra to build a sorted string of unique characters
 # from all the files.
@@ -74,6 +154,10 @@ abort "Error: Check for TR_FROM/TR_TO length mismatch" if TR_FROM.size != TR_TO.
 #    grep on the .tsv files to check for duplicate entries.
 merged_ciyu = []
 merged_pinyin = []
+merged_weights = []
+merged_toned = []
+merged_hant = []
+vocab_entries = Set.new
 pinyin_ciyu_test_data = []
 ciyu_choice_max = 1;
 first_index_of = {}
@@ -86,6 +170,7 @@ for wf in WORD_FILES
     normalized_pinyin = normalize(pinyin)
     # First, save unprocessed (pinyin, 词语) pairs for generating rust test data
     pinyin_ciyu_test_data << [normalized_pinyin, ciyu]
+    vocab_entries << [ciyu, normalized_pinyin]
     # Proceed with merging homophones for generating rust query lookup data
     if first_index_of[normalized_pinyin]
       # Conditionally append 词语 for duplicate pinyin search key
@@ -117,6 +202,76 @@ for wf in WORD_FILES
   end
 end
 
+# Tone-numbered readings of each 词语 for tone-aware input. Plenty of vocab
+# entries have no tone marks, so those take the tone of each hanzi's reading
+# in GB2312_FILE where it has the same syllable (the usual reading of the
+# hanzi), else borrow the tones of the same hanzi and syllable from entries
+# that do have marks, where the entries agree on the tone.
+# gb2312_tones is hash of {[hanzi, syllable] => tone, ...}
+# hanzi_tones is hash of {[hanzi, syllable] => Set[tone, ...], ...}
+# toned_of is hash of {[ciyu, normalized_pinyin] => [toned, ...], ...}
+word_entries = WORD_FILES.map { |wf| read_tsv(wf) }.flatten(1)
+gb2312_tones = {}
+for hanzi, pinyin in read_tsv(GB2312_FILE)
+  # Skip the few readings (like lǘ) with marks that TR_FROM doesn't cover
+  next if !pinyin.chars.all? { |c| TR_FROM.include?(c) }
+  gb2312_tones[[hanzi, $1]] = $2 if toned(pinyin) =~ /\A([a-z]+)([1-4])\z/
+end
+hanzi_tones = {}
+for ciyu, pinyin in word_entries
+  t = toned(pinyin)
+  hanzi = ciyu.scan(/\p{Han}/)
+  syls = t.scan(/([a-z]+)([0-5])/)
+  next if t.include?("0") || hanzi.size != syls.size
+  for h, (syl, tone) in hanzi.zip(syls)
+    (hanzi_tones[[h, syl]] ||= Set.new) << tone if tone != "5"
+  end
+end
+toned_of = {}
+for ciyu, pinyin in word_entries
+  t = toned(pinyin)
+  hanzi = ciyu.scan(/\p{Han}/)
+  syls = t.scan(/([a-z]+)([0-5])/)
+  if t.include?("0") && hanzi.size == syls.size
+    k = -1
+    t = t.gsub("0") do
+      k += 1
+      tones = hanzi_tones[[hanzi[k], syls[k][0]]]
+      gb2312_tones[[hanzi[k], syls[k][0]]] || (tones && tones.size == 1 ? tones.first : "0")
+    end
+  end
+  # Keep every reading of the 词语 (like wéi and wèi for 为)
+  key = [ciyu, normalize(pinyin)]
+  toned_of[key] = (toned_of[key] || []) | [t]
+end
+
+# Traditional spelling of each 词语 for zh_hant output. Characters get their
+# default traditional form, unless the 词语 has its own line in HANT_FILE (for
+# one-to-many conversions like 发 in 头发 => 頭髮).
+# hant_chars is hash of {hanzi => [default, other, ...], ...}
+# hant_words is hash of {[ciyu, normalized_pinyin or nil] => traditional, ...}
+hant_chars = {}
+hant_words = {}
+for simplified, traditional, pinyin in read_tsv(HANT_FILE)
+  if simplified.size == 1 && !pinyin
+    (hant_chars[simplified] ||= []) << traditional
+  else
+    hant_words[[simplified, pinyin && normalize(pinyin)]] = traditional
+  end
+end
+hant_words.each do |(ciyu, pinyin), traditional|
+  if !vocab_entries.any? { |cy, py| cy == ciyu && (!pinyin || py == pinyin) }
+    abort "Error: #{HANT_FILE}: #{ciyu} #{pinyin} is not in the word files"
+  end
+  listed = ciyu.size == traditional.size && ciyu.chars.zip(traditional.chars)
+    .all? { |s, t| s == t || (hant_chars[s] || []).include?(t) }
+  abort "Error: #{HANT_FILE}: #{traditional} uses characters not listed for #{ciyu}" if !listed
+end
+hant_of = lambda do |ciyu, pinyin|
+  hant_words[[ciyu, pinyin]] || hant_words[[ciyu, nil]] ||
+    ciyu.chars.map { |c| (hant_chars[c] || [c]).first }.join
+end
+
 # Add the punctuation separately because it should not by normalized.
 # Using .sub("\\","\\\\\\\\") adds an extra backslash to line with "\ 、"
 # to prevent a single backslash from escaping its closing quote
@@ -129,6 +284,85 @@ for ciyu, pinyin in read_tsv(PUNCTUATION_FILE)
   pinyin_key_count += 1;
 end
 
+# Frequency weights: hash of {[ciyu, normalized_pinyin] => weight, ...}
+weight_of = {}
+for ciyu, pinyin, weight in read_tsv(FREQUENCY_FILE)
+  abort "Error: #{FREQUENCY_FILE}: #{ciyu} #{pinyin} is not in the word files" if !vocab_entries.include?([ciyu, pinyin])
+  weight_of[[ciyu, pinyin]] = Integer(weight)
+end
+
+# Sort homophone choices by descending weight so the most common 词语 is the
+# default choice. Sorting on [-weight, index] keeps word file order for ties
+# (ruby's sort_by is not stable).
+for n in 0...merged_ciyu.size
+  py = merged_pinyin[n]
+  if merged_ciyu[n].size > 1
+    merged_ciyu[n].each { |cy| warn "No weight?: #{cy}:#{"%10s" % py}" if !weight_of[[cy, py]] }
+  end
+  merged_ciyu[n] = merged_ciyu[n].each_with_index
+    .sort_by { |cy, k| [-weight_of.fetch([cy, py], DEFAULT_WEIGHT), k] }
+    .map { |cy, _| cy }
+  merged_weights[n] = merged_ciyu[n].map { |cy| weight_of.fetch([cy, py], DEFAULT_WEIGHT) }
+  # Punctuation has no tones, so it keeps its plain search key
+  merged_toned[n] = merged_ciyu[n].map { |cy| toned_of.fetch([cy, py], [py]).join("|") }
+  merged_hant[n] = merged_ciyu[n].map { |cy| hant_of.call(cy, py) }
+end
+
+# Reverse index of readings for converting hanzi text back to pinyin. 词语
+# with more than one reading (like 长 for cháng or zhǎng) get the one with the
+# highest weight, or the first one in the word files for ties. Longer 词语
+# that include them (like 校长 xiàozhǎng) have their own readings.
+# reading_of is hash of {ciyu => [toned, weight], ...}
+# Titles like 《呐喊》 are left out, so their punctuation passes through.
+reading_of = {}
+for ciyu, pinyin in word_entries
+  next if ciyu !~ /\A\p{Han}+\z/
+  np = normalize(pinyin)
+  w = weight_of.fetch([ciyu, np], DEFAULT_WEIGHT)
+  if !reading_of[ciyu] || w > reading_of[ciyu][1]
+    reading_of[ciyu] = [toned_of[[ciyu, np]].first, w]
+  end
+end
+hanzi_keys = reading_of.keys
+hanzi_size_max = hanzi_keys.map(&:size).max
+
+# Index syllable-initial abbreviations of each 词语 for initials-only input.
+# Abbreviations that are also pinyin search keys get skipped, since full
+# pinyin matches come first.
+# abbrev_entries is hash of {abbreviation => [[ciyu, weight, hant], ...], ...}
+abbrev_entries = {}
+abbrev_ciyu_test_data = []
+for wf in WORD_FILES
+  for ciyu, pinyin in read_tsv(wf)
+    abbrevs = abbreviations(pinyin)
+    warn "No abbreviation?: #{"%14s" % wf}:  #{ciyu}:#{"%10s" % pinyin}" if abbrevs.empty?
+    for a in abbrevs
+      next if first_index_of[a]
+      entries = (abbrev_entries[a] ||= [])
+      next if entries.any? { |cy, _| cy == ciyu }
+      np = normalize(pinyin)
+      entries << [ciyu, weight_of.fetch([ciyu, np], DEFAULT_WEIGHT), hant_of.call(ciyu, np)]
+      abbrev_ciyu_test_data << [a, ciyu]
+    end
+  end
+end
+
+# Sort abbreviation choices by descending weight, keeping only as many as can
+# be picked with one digit
+abbrev_keys = abbrev_entries.keys
+abbrev_ciyu = []
+abbrev_weights = []
+abbrev_hant = []
+for a in abbrev_keys
+  top = abbrev_entries[a].each_with_index
+    .sort_by { |(_, w), k| [-w, k] }
+    .map { |e, _| e }
+    .first(ABBREV_CHOICE_LIMIT)
+  abbrev_ciyu << top.map { |cy, _| cy }
+  abbrev_weights << top.map { |_, w| w }
+  abbrev_hant << top.map { |_, _, h| h }
+end
+
 # Murmur3 hash function; key is UTF-8 string (max 4 bytes/char) so take each
 # ord(char) as one u32 block.
 # Credits: Derived from MurmurHash3.cpp (public domain) by Austin Appleby.
@@ -146,7 +380,8 @@ def murmur3(key, seed)
     h = rotl32(h, 13)
     h = ((h * 5) + 0xe6546b64) & 0xffff_ffff
   end
-  h = h ^ key.size
+  # Length in bytes, as on the rust side (same as characters for ASCII keys)
+  h = h ^ key.bytesize
   # Finalize with avalanche
   h = h ^ (h >> 16)
   h = (h * 0x85ebca6b) & 0xffff_ffff
@@ -166,7 +401,25 @@ puts "Unique murmur3 hashes: #{m3_uniq}"
 puts "Diff: #{m3_total-m3_uniq}"
 
 # Sort the merged vocab lists in pinyin order
-merged_m3, merged_pinyin, merged_ciyu = merged_m3.zip(merged_pinyin, merged_ciyu).sort.transpose
+merged_m3, merged_pinyin, merged_ciyu, merged_weights, merged_toned, merged_hant = merged_m3.zip(merged_pinyin, merged_ciyu, merged_weights, merged_toned, merged_hant).sort.transpose
+
+# Indexes of the search keys in alphabetical order, for prefix search
+pinyin_sorted = (0...merged_pinyin.size).sort_by { |k| merged_pinyin[k] }
+
+# Same for abbreviations
+abbrev_m3 = abbrev_keys.map {|a| murmur3(a, m3_seed)}
+abort "Error: murmur3 collision in abbreviations" if Set.new(abbrev_m3).size != abbrev_m3.size
+abbrev_m3, abbrev_keys, abbrev_ciyu, abbrev_weights, abbrev_hant = abbrev_m3.zip(abbrev_keys, abbrev_ciyu, abbrev_weights, abbrev_hant).sort.transpose
+
+# Same for the reverse index
+hanzi_m3 = hanzi_keys.map {|cy| murmur3(cy, m3_seed)}
+abort "Error: murmur3 collision in reverse index" if Set.new(hanzi_m3).size != hanzi_m3.size
+hanzi_pinyin = hanzi_keys.map { |cy| reading_of[cy][0] }
+hanzi_m3, hanzi_keys, hanzi_pinyin = hanzi_m3.zip(hanzi_keys, hanzi_pinyin).sort.transpose
+
+# Escape backslash in pinyin search keys for use in rust string literals
+merged_keys = merged_pinyin.map { |py| py.sub("\\","\\\\\\\\") }
+merged_toned = merged_toned.map { |t| t.map { |py| py.sub("\\","\\\\\\\\") } }
 
 # Print statistics
 avg_pinyin_key_len = Float(pinyin_char_count) / pinyin_key_count
@@ -201,19 +454,107 @@ File.open(RUST_FILE, "w") { |rf|
     <% merged_m3.zip(merged_pinyin).each do |m3,py| %>    <%= "0x%08x, // %s" % [m3, py] %>
     <% end %>];
 
+    // Pinyin search keys in the same order as PINYIN. Murmur3 hashes of random
+    // input can collide with hashes of real keys, so a hash match only counts
+    // if the key matches too.
+    pub static PINYIN_KEYS: &[&str] = &[
+    <% merged_keys.each do |k| %>    "<%= k %>",
+    <% end %>];
+
+    // Indexes of PINYIN_KEYS in alphabetical order of the keys. Keys that
+    // start with the same prefix (like "guoz" for 果汁 guozhi) are next to
+    // each other, so completions of a partly typed key are a binary search
+    // away.
+    pub static PINYIN_SORTED: &[u16] = &[
+    <% pinyin_sorted.each do |k| %>    <%= k %>,  // <%= merged_pinyin[k] %>
+    <% end %>];
+
     // Longest homophone choice size (choices joined by "\\t")
     pub const CIYU_CHOICE_MAX: usize = <%= ciyu_choice_max %>;
 
-    pub static CIYU: &[&'static str] = &[
-    <% merged_ciyu.zip(merged_pinyin).each do |h,py| %>    &"<%= h.join("\t") %>",  // <%= py %>
+    pub static CIYU: &[&str] = &[
+    <% merged_ciyu.zip(merged_pinyin).each do |h,py| %>    "<%= h.join("\t") %>",  // <%= py %>
+    <% end %>];
+
+    // Frequency weights for the choices in CIYU, in the same order. Choices
+    // are sorted by descending weight, so the first choice is the default.
+    pub static CIYU_WEIGHT: &[&[u32]] = &[
+    <% merged_weights.zip(merged_pinyin).each do |w,py| %>    &[<%= w.join(", ") %>],  // <%= py %>
+    <% end %>];
+
+    // Choices in CIYU with a tone number after each syllable, in the same
+    // order: 1-4, 5 for neutral tone, or 0 where neither the vocab files nor
+    // the GB 2312 readings give the tone. Choices with more than one reading
+    // list them all, joined by "|".
+    pub static CIYU_TONED: &[&str] = &[
+    <% merged_toned.each do |t| %>    "<%= t.join("\t") %>",
+    <% end %>];
+
+    // Choices in CIYU spelled with traditional characters, in the same order
+    pub static CIYU_HANT: &[&str] = &[
+    <% merged_hant.zip(merged_pinyin).each do |h,py| %>    "<%= h.join("\t") %>",  // <%= py %>
+    <% end %>];
+
+    // u32 constants are murmur3 hash of syllable-initial abbreviations of 词语,
+    // like "sxym" or "shxym" for 赏心悦目 (shangxinyuemu). Keys that are also
+    // pinyin search keys are left out, since full pinyin matches come first.
+    pub static ABBREV: &[u32] = &[
+    <% abbrev_m3.zip(abbrev_keys).each do |m3,a| %>    <%= "0x%08x, // %s" % [m3, a] %>
+    <% end %>];
+
+    // Abbreviations in the same order as ABBREV
+    pub static ABBREV_KEYS: &[&str] = &[
+    <% abbrev_keys.each do |a| %>    "<%= a %>",
+    <% end %>];
+
+    // Choices for each abbreviation (joined by "\\t") and their frequency
+    // weights, sorted by descending weight. Only the <%= ABBREV_CHOICE_LIMIT %> highest weight choices
+    // are kept, since choices get picked with one digit.
+    pub static ABBREV_CIYU: &[&str] = &[
+    <% abbrev_ciyu.zip(abbrev_keys).each do |h,a| %>    "<%= h.join("\t") %>",  // <%= a %>
+    <% end %>];
+
+    pub static ABBREV_WEIGHT: &[&[u32]] = &[
+    <% abbrev_weights.zip(abbrev_keys).each do |w,a| %>    &[<%= w.join(", ") %>],  // <%= a %>
+    <% end %>];
+
+    // Choices in ABBREV_CIYU spelled with traditional characters
+    pub static ABBREV_CIYU_HANT: &[&str] = &[
+    <% abbrev_hant.zip(abbrev_keys).each do |h,a| %>    "<%= h.join("\t") %>",  // <%= a %>
+    <% end %>];
+
+    // u32 constants are murmur3 hash of 词语, for reverse lookup of pinyin from
+    // hanzi text. The longest 词语 has HANZI_SIZE_MAX characters.
+    pub const HANZI_SIZE_MAX: usize = <%= hanzi_size_max %>;
+    pub static HANZI: &[u32] = &[
+    <% hanzi_m3.zip(hanzi_keys).each do |m3,cy| %>    <%= "0x%08x, // %s" % [m3, cy] %>
+    <% end %>];
+
+    // 词语 in the same order as HANZI
+    pub static HANZI_KEYS: &[&str] = &[
+    <% hanzi_keys.each do |cy| %>    "<%= cy %>",
+    <% end %>];
+
+    // Reading of each 词语 in HANZI_KEYS, with a tone number after each syllable
+    // as in CIYU_TONED. 词语 with more than one reading (like 长 for cháng or
+    // zhǎng) get the one with the highest weight.
+    pub static HANZI_PINYIN: &[&str] = &[
+    <% hanzi_pinyin.zip(hanzi_keys).each do |r,cy| %>    "<%= r %>",  // <%= cy %>
     <% end %>];
 
     // Tuples are (normalized_pinyin, 词语) from early in vocab file code
     // generation precompute pipeline. These correspond closely to lines of
     // vocab .tsv files prior to any sorting or merging of duplicates.
     #[cfg(test)]
-    pub static PINYIN_CIYU_TEST_DATA: &[(&'static str, &'static str)] = &[
-    <% pinyin_ciyu_test_data.each do |np, cy| %>    (&"<%= np %>", &"<%= cy %>"),
+    pub static PINYIN_CIYU_TEST_DATA: &[(&str, &str)] = &[
+    <% pinyin_ciyu_test_data.each do |np, cy| %>    ("<%= np %>", "<%= cy %>"),
+    <% end %>];
+
+    // Tuples are (abbreviation, 词语) for every 词语 with each abbreviation,
+    // including choices that didn't make the cut for ABBREV_CIYU.
+    #[cfg(test)]
+    pub static ABBREV_CIYU_TEST_DATA: &[(&str, &str)] = &[
+    <% abbrev_ciyu_test_data.each do |a, cy| %>    ("<%= a %>", "<%= cy %>"),
     <% end %>];
     RUST
   rf.puts ERB.new(TEMPLATE).result(binding)
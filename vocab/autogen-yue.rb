@@ -0,0 +1,163 @@
+#!/usr/bin/ruby
+# coding: utf-8
+# Copyright (c) 2021 Sam Blenny
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+#
+# Cantonese counterpart of autogen-hsk.rb: generates the Jyutping vocab tables
+# that translate_yue() searches.
+require 'erb'
+require 'set'
+
+RUST_FILE = "../src/autogen_yue.rs"
+WORD_FILES = [
+  "yue.tsv",
+]
+PUNCTUATION_FILE = "pinyin-punctuation.tsv"
+
+# Returns array: [[ciyu, jyutping], [ciyu, jyutping], ...]
+# Note: The `select {..."#"..."\t"}` filters out blank lines and comments
+def read_tsv(file)
+  File.read(file).lines
+    .select { |n| !n.start_with?("#") && n.include?("\t") }
+    .map { |n| n.chomp.split("\t") }
+end
+
+# Jyutping is lowercase ASCII syllables with a tone number (1-6) after each
+# one, and a space between syllables, like "nei5 hou2"
+JYUTPING = /\A[a-z]+[1-6]( [a-z]+[1-6])*\z/
+
+# Collect vocab entries as [ciyu, search key, toned], where the toned reading
+# is the Jyutping without spaces ("nei5hou2"), and the search key leaves out
+# the tone numbers too ("neihou"). Punctuation is its own search key.
+entries = []
+jyutping_ciyu_test_data = []
+for wf in WORD_FILES
+  for ciyu, jyutping in read_tsv(wf)
+    abort "Error: #{wf}: #{ciyu}: Jyutping \"#{jyutping}\" needs tone numbers" if jyutping !~ JYUTPING
+    syllable_count = jyutping.split(" ").size
+    warn "Syllable count?: #{"%10s" % wf}:  #{ciyu}:#{jyutping}" if ciyu.size != syllable_count
+    toned = jyutping.delete(" ")
+    key = toned.delete("1-6")
+    entries << [ciyu, key, toned]
+    jyutping_ciyu_test_data << [key, ciyu]
+  end
+end
+for ciyu, key in read_tsv(PUNCTUATION_FILE)
+  entries << [ciyu, key, key]
+end
+
+# Merge homophones, keeping them in file order so the first one is the default
+# choices_of is hash of {key => [[ciyu, toned], ...], ...}
+choices_of = {}
+for ciyu, key, toned in entries
+  choices = (choices_of[key] ||= [])
+  if choices.any? { |cy, _| cy == ciyu }
+    warn "Duplicate?: #{ciyu}:#{key}"
+    next
+  end
+  choices << [ciyu, toned]
+end
+keys = choices_of.keys
+jyutping_size_max = keys.map(&:size).max
+ciyu_choice_max = choices_of.values.map(&:size).max
+
+# Murmur3 hash function; key is UTF-8 string (max 4 bytes/char) so take each
+# ord(char) as one u32 block. Same as autogen-hsk.rb.
+# Credits: Derived from MurmurHash3.cpp (public domain) by Austin Appleby.
+def murmur3(key, seed)
+  def rotl32(x, r)
+    ((x << r) & 0xffff_ffff) | (x >> (32 - r))
+  end
+  h = seed
+  for c in key.chars
+    k = c.ord
+    k = (k * 0xcc9e2d51) & 0xffff_ffff
+    k = rotl32(k, 15)
+    k = (k * 0x1b873593) & 0xffff_ffff
+    h = h ^ k
+    h = rotl32(h, 13)
+    h = ((h * 5) + 0xe6546b64) & 0xffff_ffff
+  end
+  # Length in bytes, as on the rust side (same as characters for ASCII keys)
+  h = h ^ key.bytesize
+  # Finalize with avalanche
+  h = h ^ (h >> 16)
+  h = (h * 0x85ebca6b) & 0xffff_ffff
+  h = h ^ (h >> 13)
+  h = (h * 0xc2b2ae35) & 0xffff_ffff
+  h ^ (h >> 16)
+end
+
+# Calculate murmur3(key) and sort the keys in hash order. The seed must match
+# autogen-hsk.rb, since the rust side looks up both tables the same way.
+m3_seed = 0
+keys_m3 = keys.map { |k| murmur3(k, m3_seed) }
+abort "Error: murmur3 collision in Jyutping search keys" if Set.new(keys_m3).size != keys_m3.size
+keys_m3, keys = keys_m3.zip(keys).sort.transpose
+ciyu = keys.map { |k| choices_of[k].map { |cy, _| cy } }
+toned = keys.map { |k| choices_of[k].map { |_, t| t } }
+
+# Escape backslash in search keys for use in rust string literals
+escaped_keys = keys.map { |k| k.sub("\\","\\\\\\\\") }
+toned = toned.map { |t| t.map { |k| k.sub("\\","\\\\\\\\") } }
+
+# Print statistics
+puts "\nVocab entries: #{jyutping_ciyu_test_data.size}"
+puts "Unique Jyutping search keys: #{keys.size}"
+
+# Ask about updating the Rust array source code
+puts "\nPreparing to generate rust source code..."
+print "This will overwrite #{RUST_FILE}\nDo you want to continue? [y/N] "
+abort "no changes made" if !["y", "Y"].include? gets.chomp
+
+# Generate rust source code with ciyu and jyutping arrays
+File.open(RUST_FILE, "w") { |rf|
+  TEMPLATE = <<~RUST
+    // This file is automatically generated. DO NOT MAKE EDITS HERE!
+    // To make changes, see ../vocab/autogen-yue.rb
+    //
+    // Copyright (c) 2021 Sam Blenny
+    // SPDX-License-Identifier: Apache-2.0 OR MIT
+    //
+    #![forbid(unsafe_code)]
+    #![allow(dead_code)]
+
+    // The longest Jyutping search key in the list below has JYUTPING_SIZE_MAX
+    // characters. Search keys leave out the tone numbers.
+    pub const JYUTPING_SIZE_MAX: usize = <%= jyutping_size_max %>;
+
+    // u32 constants are murmur3 hash of Jyutping search keys, with the same
+    // seed as autogen_hsk::MURMUR3_SEED
+    pub const MURMUR3_SEED: u32 = <%= m3_seed %>;
+    pub static JYUTPING: &[u32] = &[
+    <% keys_m3.zip(keys).each do |m3,k| %>    <%= "0x%08x, // %s" % [m3, k] %>
+    <% end %>];
+
+    // Jyutping search keys in the same order as JYUTPING
+    pub static JYUTPING_KEYS: &[&str] = &[
+    <% escaped_keys.each do |k| %>    "<%= k %>",
+    <% end %>];
+
+    // Longest homophone choice size (choices joined by "\\t")
+    pub const CIYU_CHOICE_MAX: usize = <%= ciyu_choice_max %>;
+
+    // Choices for each search key, in the order of the vocab files, so the
+    // first choice is the default
+    pub static CIYU: &[&str] = &[
+    <% ciyu.zip(keys).each do |h,k| %>    "<%= h.join("\t") %>",  // <%= k %>
+    <% end %>];
+
+    // Choices in CIYU with a tone number (1-6) after each syllable, in the
+    // same order
+    pub static CIYU_TONED: &[&str] = &[
+    <% toned.each do |t| %>    "<%= t.join("\t") %>",
+    <% end %>];
+
+    // Tuples are (search key, 詞語) for each line of the vocab files
+    #[cfg(test)]
+    pub static JYUTPING_CIYU_TEST_DATA: &[(&str, &str)] = &[
+    <% jyutping_ciyu_test_data.each do |k, cy| %>    ("<%= k %>", "<%= cy %>"),
+    <% end %>];
+    RUST
+  rf.puts ERB.new(TEMPLATE).result(binding)
+}
@@ -31,10 +31,11 @@ function showHelp() {
                     '<ul><li> Use lowercase. </li>\n' +
                     '<li> Omit tone marks. For á, type <strong>a</strong> </li>\n' +
                     '<li> Umlaut is special. For ü, type <strong>v</strong> </li>\n' +
-                    '<li> For choices like (1喝 2和 3河), pick with numbers or space </li>\n' +
+                    '<li> For choices like (1和 2河 3喝), pick with numbers or space </li>\n' +
                     '<li> Send with return or enter. </li> </ul>\n' +
                     '<p> Example: <br>\n' +
-                    '&nbsp; "woxiang he guozhi", plus return, makes "<span lang="zh-CN">我想喝果汁</span>" </p>\n' +
+                    '&nbsp; "woxiang he guozhi", plus return, makes "<span lang="zh-CN">我想喝果汁</span>" <br>\n' +
+                    '&nbsp; "woxiang he2guozhi", with choice 2 for he, makes "<span lang="zh-CN">我想和果汁</span>" </p>\n' +
                     '<p> Slash Commands: <span>/</span>help <span>/</span>about <span>/</span>clear </p>');
 }
 
@@ -100,8 +101,8 @@ function enableChatMode() {
 function wasmDemo() {
     chatLogSafeHTML("<p>This chat simulator has a built in Simplified Chinese IME. " +
                     "You can type pinyin phrases, other text, or <span>/</span>commands.<br> " +
-                    "Try <strong>wo xiang he guozhi</strong> or <strong>woxiangheguozhi11</strong> " +
-                    "(...one one) <br> " +
+                    "Try <strong>wo xiang he guozhi</strong> or <strong>woxiangheguozhi12</strong> " +
+                    "(...one two, for 和 instead of 喝) <br> " +
                     "Try <strong><span>/</span>help</strong> or <strong><span>/</span>about</strong>.</p>");
     enableChatMode();
 }
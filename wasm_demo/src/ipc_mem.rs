@@ -17,7 +17,7 @@ pub fn get_query(n: usize) -> &'static str {
     unsafe {
         match core::str::from_utf8(&IN[0..n]) {
             Ok(s) => &s,
-            Err(_) => &"", // TODO: handle mal-formed utf8 strings better
+            Err(_) => "", // TODO: handle mal-formed utf8 strings better
         }
     }
 }
@@ -54,7 +54,7 @@ pub fn out_to_s() -> &'static str {
     unsafe {
         match core::str::from_utf8(&OUT[0..OUT_POS]) {
             Ok(s) => &s,
-            Err(_) => &"", // TODO: handle mal-formed utf8 strings better
+            Err(_) => "", // TODO: handle mal-formed utf8 strings better
         }
     }
 }
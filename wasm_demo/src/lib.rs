@@ -51,8 +51,6 @@ pub extern "C" fn translate_zh_hans(n: usize) -> usize {
     let mut ipc_writer = IPCWriter {};
     let qry = ipc_mem::get_query(n);
     ipc_mem::rewind();
-    // TODO: fix this
-    let _ = hanzi_ime::translate_zh_hans(&"");
     hanzi_ime::look_up(&qry, &mut ipc_writer);
     ipc_mem::position()
 }
@@ -84,7 +82,7 @@ mod tests {
 
     #[test]
     fn min_query() {
-        assert_eq!("", query(&""));
+        assert_eq!("", query(""));
     }
 
     #[test]
@@ -107,20 +105,20 @@ mod tests {
 
     #[test]
     fn choosing_xiang() {
-        assert!(query(&"xiang").contains("(1想"));
-        assert!(query(&"xiang").contains("2向"));
-        assert!(query(&" xiang").starts_with(" "));
-        assert_eq!(query(&"xiang "), "想");
-        assert_eq!(query(&"xiang1"), "想");
-        assert_eq!(query(&"xiang2"), "向");
+        assert!(query("xiang").contains("(1向"));
+        assert!(query("xiang").contains("2想"));
+        assert!(query(" xiang").starts_with(" "));
+        assert_eq!(query("xiang "), "向");
+        assert_eq!(query("xiang1"), "向");
+        assert_eq!(query("xiang2"), "想");
     }
 
     #[test]
     fn query_chars_not_matched_should_pass_through() {
-        assert_eq!(query(&"🐇✨"), "🐇✨");
-        assert_eq!(query(&"baiSEde🐇✨11"), "白SE的🐇✨");
-        assert_eq!(query(&"RABBIT SPARKLES 11"), "RABBIT SPARKLES 11");
-        assert_eq!(query(&"XIANGHE"), "XIANGHE");
+        assert_eq!(query("🐇✨"), "🐇✨");
+        assert_eq!(query("baiSEde🐇✨11"), "白SE的🐇✨");
+        assert_eq!(query("RABBIT SPARKLES 11"), "RABBIT SPARKLES 11");
+        assert_eq!(query("XIANGHE"), "XIANGHE");
     }
 
     #[test]